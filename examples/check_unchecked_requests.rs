@@ -8,11 +8,11 @@
 extern crate x11rb;
 
 use x11rb::connection::Connection;
-use x11rb::protocol::xproto::ConnectionExt as _;
+use x11rb::protocol::xproto::{ConnectionExt as _, Window};
 use x11rb::protocol::Event;
 use x11rb::wrapper::ConnectionExt as _;
 
-const INVALID_WINDOW: u32 = 0;
+const INVALID_WINDOW: Window = Window::NONE;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let (conn, _) = x11rb::connect(None).unwrap();
@@ -23,7 +23,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Create a window
-    let win_id = conn.generate_id()?;
+    let win_id = conn.generate_id()?.into();
     let win_aux = CreateWindowAux::new().background_pixel(screen.white_pixel);
     conn.create_window(
         COPY_DEPTH_FROM_PARENT,
@@ -40,7 +40,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     )?;
 
     // Ask for present ConfigureNotify events
-    let event_id = conn.generate_id()?;
+    let event_id = conn.generate_id()?.into();
     present::select_input(&conn, event_id, win_id, present::EventMask::ConfigureNotify)?;
 
     // Cause an event
@@ -57,7 +57,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
     println!(
         "Got a Present ConfigureNotify event for event ID 0x{:x} and window 0x{:x}.",
-        event.event, event.window
+        u32::from(event.event),
+        u32::from(event.window)
     );
     println!("x={}, y={}, width={}, height={}, off_x={}, off_y={}, pixmap_width={}, pixmap_height={}, pixmap_flags={:x}",
              event.x, event.y, event.width, event.height, event.off_x, event.off_y, event.pixmap_width, event.pixmap_height, event.pixmap_flags);
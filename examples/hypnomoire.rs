@@ -36,8 +36,8 @@ fn main() {
     let conn = Arc::new(conn);
     let screen = &conn.setup().roots[screen_num];
 
-    let white = conn.generate_id().unwrap();
-    let black = conn.generate_id().unwrap();
+    let white = conn.generate_id().unwrap().into();
+    let black = conn.generate_id().unwrap().into();
 
     conn.create_gc(
         white,
@@ -78,8 +78,8 @@ fn run<C: Connection>(
 ) -> Result<(), ReplyOrIdError<C::Buf>> {
     let screen = &conn.setup().roots[screen_num];
     let default_size = 300;
-    let pixmap = conn.generate_id()?;
-    let window = conn.generate_id()?;
+    let pixmap = conn.generate_id()?.into();
+    let window = conn.generate_id()?.into();
 
     {
         let mut guard = window_state.lock().unwrap();
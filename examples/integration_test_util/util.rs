@@ -34,7 +34,8 @@ mod util {
             thread::sleep(Duration::from_secs(timeout));
 
             let mut data = [0; 20];
-            data[..4].copy_from_slice(&wm_delete_window.reply().unwrap().atom.to_ne_bytes());
+            let atom = u32::from(wm_delete_window.reply().unwrap().atom);
+            data[..4].copy_from_slice(&atom.to_ne_bytes());
             let (data, _): (ClientMessageData, _) = TryParse::try_parse(&data).unwrap();
             let event = ClientMessageEvent {
                 response_type: CLIENT_MESSAGE_EVENT,
@@ -49,7 +49,7 @@ fn get_shared_memory_content_at_offset<C: Connection>(
         8 => 4,
         _ => panic!("I do not know how to handle depth {}", screen.root_depth),
     };
-    let pixmap = conn.generate_id()?;
+    let pixmap = conn.generate_id()?.into();
     conn.shm_create_pixmap(
         pixmap,
         screen.root,
@@ -101,7 +101,7 @@ fn send_fd<C: Connection>(
     screen_num: usize,
     file: File,
 ) -> Result<(), ReplyOrIdError<C::Buf>> {
-    let shmseg = conn.generate_id()?;
+    let shmseg = conn.generate_id()?.into();
     conn.shm_attach_fd(shmseg, file, false)?;
 
     use_shared_mem(conn, screen_num, shmseg)?;
@@ -112,7 +112,7 @@ fn send_fd<C: Connection>(
 }
 
 fn receive_fd<C: Connection>(conn: &C, screen_num: usize) -> Result<(), ReplyOrIdError<C::Buf>> {
-    let shmseg = conn.generate_id()?;
+    let shmseg = conn.generate_id()?.into();
     let segment_size = TEMP_FILE_CONTENT.len() as _;
     let reply = conn
         .shm_create_segment(shmseg, segment_size, false)?
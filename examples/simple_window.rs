@@ -14,8 +14,8 @@ fn main() {
     let conn = &*conn1;
 
     let screen = &conn.setup().roots[screen_num];
-    let win_id = conn.generate_id().unwrap();
-    let gc_id = conn.generate_id().unwrap();
+    let win_id = conn.generate_id().unwrap().into();
+    let gc_id = conn.generate_id().unwrap().into();
     let cursor_handle = CursorHandle::new(conn, screen_num).unwrap();
 
     let wm_protocols = conn.intern_atom(false, b"WM_PROTOCOLS").unwrap();
@@ -78,7 +78,7 @@ fn main() {
         win_id,
         wm_protocols,
         AtomEnum::ATOM,
-        &[wm_delete_window],
+        &[wm_delete_window.into()],
     )
     .unwrap();
     conn.change_property8(
@@ -149,7 +149,10 @@ fn main() {
             }
             Event::ClientMessage(event) => {
                 let data = event.data.as_data32();
-                if event.format == 32 && event.window == win_id && data[0] == wm_delete_window {
+                if event.format == 32
+                    && event.window == win_id
+                    && data[0] == u32::from(wm_delete_window)
+                {
                     println!("Window was asked to close");
                     return;
                 }
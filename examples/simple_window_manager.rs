@@ -57,8 +57,8 @@ struct WMState<'a, C: Connection> {
 impl<'a, C: Connection> WMState<'a, C> {
     fn new(conn: &'a C, screen_num: usize) -> Result<WMState<'a, C>, ReplyOrIdError<C::Buf>> {
         let screen = &conn.setup().roots[screen_num];
-        let black_gc = conn.generate_id()?;
-        let font = conn.generate_id()?;
+        let black_gc = conn.generate_id()?.into();
+        let font = conn.generate_id()?.into();
         conn.open_font(font, b"9x15")?;
 
         let gc_aux = CreateGCAux::new()
@@ -122,7 +122,7 @@ impl<'a, C: Connection> WMState<'a, C> {
         let screen = &self.conn.setup().roots[self.screen_num];
         assert!(self.find_window_by_id(win).is_none());
 
-        let frame_win = self.conn.generate_id()?;
+        let frame_win = self.conn.generate_id()?.into();
         let win_aux = CreateWindowAux::new()
             .event_mask(
                 EventMask::Exposure | EventMask::SubstructureNotify | EventMask::ButtonRelease,
@@ -261,16 +261,16 @@ impl<'a, C: Connection> WMState<'a, C> {
             unimplemented!();
         }
         let mut aux = ConfigureWindowAux::default();
-        if event.value_mask & u16::from(ConfigWindow::X) != 0 {
+        if event.value_mask.contains(ConfigWindow::X) {
             aux = aux.x(i32::from(event.x));
         }
-        if event.value_mask & u16::from(ConfigWindow::Y) != 0 {
+        if event.value_mask.contains(ConfigWindow::Y) {
             aux = aux.y(i32::from(event.y));
         }
-        if event.value_mask & u16::from(ConfigWindow::Width) != 0 {
+        if event.value_mask.contains(ConfigWindow::Width) {
             aux = aux.width(u32::from(event.width));
         }
-        if event.value_mask & u16::from(ConfigWindow::Height) != 0 {
+        if event.value_mask.contains(ConfigWindow::Height) {
             aux = aux.height(u32::from(event.height));
         }
         println!("Configure: {:?}", aux);
@@ -306,7 +306,7 @@ impl<'a, C: Connection> WMState<'a, C> {
         event: ButtonReleaseEvent,
     ) -> Result<(), ReplyError<C::Buf>> {
         if let Some(state) = self.find_window_by_id(event.event) {
-            let data = [self.wm_delete_window, 0, 0, 0, 0];
+            let data = [u32::from(self.wm_delete_window), 0, 0, 0, 0];
             let event = ClientMessageEvent {
                 response_type: CLIENT_MESSAGE_EVENT,
                 format: 32,
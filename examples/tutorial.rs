@@ -204,7 +204,7 @@ fn example1() -> Result<(), Box<dyn Error>> {
 
     let (conn, _) = x11rb::connect(None)?;
     const COUNT: usize = 500;
-    let mut atoms = [Into::<u32>::into(AtomEnum::None); COUNT];
+    let mut atoms = [Atom::NONE; COUNT];
 
     // Init names
     let names = (0..COUNT).map(|i| format!("NAME{}", i)).collect::<Vec<_>>();
@@ -304,8 +304,8 @@ fn example2() -> Result<(), Box<dyn Error>> {
 #[allow(unused)]
 #[derive(Debug, Clone)]
 pub struct RenamedScreen {
-    pub root: u32,
-    pub default_colormap: u32,
+    pub root: Window,
+    pub default_colormap: Colormap,
     pub white_pixel: u32,
     pub black_pixel: u32,
     pub current_input_masks: u32,
@@ -332,7 +332,7 @@ fn example3() -> Result<(), Box<dyn Error>> {
     let screen = &conn.setup().roots[screen_num];
 
     println!();
-    println!("Informations of screen {}:", screen.root);
+    println!("Informations of screen {}:", u32::from(screen.root));
     println!("  width.........: {}", screen.width_in_pixels);
     println!("  height........: {}", screen.height_in_pixels);
     println!("  white pixel...: {}", screen.white_pixel);
@@ -345,11 +345,15 @@ fn example3() -> Result<(), Box<dyn Error>> {
 //     ===================================================
 //
 // After we got some basic information about our screen, we can create our first window. In the X
-// Window System, a window is characterized by an Id. So, in XCB, a window is of type:
+// Window System, a window is characterized by an Id. So, in x11rb, a window is of type:
 //
 
 #[allow(unused)]
-pub type Window = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RenamedWindow(u32);
+
+// The Id is wrapped in its own type so that it cannot be mixed up with other kinds of Ids, like
+// the Id of a pixmap. `From` conversions to and from `u32` exist.
 
 //  We first ask for a new Id for our window, with this function:
 //
@@ -357,14 +361,16 @@ pub type Window = u32;
 //    fn generate_id(&self) -> u32;
 // }
 //
+// The returned `u32` can be turned into a `Window` with `.into()`.
+//
 // Then, XCB supplies the following function to create new windows:
 
 #[allow(unused, clippy::too_many_arguments)]
 fn own_create_window<A: Connection, B>(
     c: &A,             // The connection to use
     depth: u8,         // Depth of the screen
-    wid: u32,          // Id of the window
-    parent: u32,       // Id of an existing window that should be the parent of the new window
+    wid: Window,       // Id of the window
+    parent: Window,    // Id of an existing window that should be the parent of the new window
     x: i16,            // X position of the top-left corner of the window (in pixels)
     y: i16,            // Y position of the top-left corner of the window (in pixels)
     width: u16,        // Width of the window (in pixels)
@@ -384,7 +390,7 @@ where
 // newly created windows are not mapped on the screen (they are invisible). In order to make our
 // window visible, we use the function `map_window()`, whose prototype is
 //
-//   fn map_window(&self, window: u32) -> Result<SequenceNumber, ConnectionError>;
+//   fn map_window(&self, window: Window) -> Result<SequenceNumber, ConnectionError>;
 //
 // Finally, here is a small program to create a window of size 150x150 pixels, positioned at the top-left corner of the screen:
 
@@ -396,7 +402,7 @@ fn example4() -> Result<(), Box<dyn Error>> {
     let screen = &conn.setup().roots[screen_num];
 
     // Ask for our window's Id
-    let win = conn.generate_id()?;
+    let win = conn.generate_id()?.into();
 
     // Create the window
     conn.create_window(
@@ -467,7 +473,7 @@ fn example4() -> Result<(), Box<dyn Error>> {
 // with a single window, in order to draw in multiple styles (different colors, different line
 // widths, etc). In XCB, a Graphics Context is, as a window, characterized by an Id:
 //
-//      pub type Gcontext = u32;
+//      pub struct Gcontext(u32);
 //
 // We first ask the X server to attribute an Id to our graphic context with this function:
 //
@@ -481,8 +487,8 @@ fn example4() -> Result<(), Box<dyn Error>> {
 #[allow(unused)]
 fn my_create_gc<A: Connection>(
     c: &A,
-    cid: u32,
-    drawable: u32,
+    cid: Gcontext,
+    drawable: Drawable,
     value_list: &CreateGCAux,
 ) -> Result<SequenceNumber, ConnectionError> {
     unimplemented!();
@@ -531,7 +537,7 @@ fn example5() -> Result<(), Box<dyn Error>> {
 
     // Create a black graphic context for drawing in the foreground.
     let win = screen.root;
-    let black = conn.generate_id()?;
+    let black = conn.generate_id()?.into();
     let values = CreateGCAux::default().foreground(screen.black_pixel);
     conn.create_gc(black, win, &values)?;
 
@@ -750,14 +756,14 @@ fn example6() -> Result<(), Box<dyn Error>> {
     // Create black (foreground) graphic context
     let win = screen.root;
 
-    let foreground = conn.generate_id()?;
+    let foreground = conn.generate_id()?.into();
     let values = CreateGCAux::default()
         .foreground(screen.black_pixel)
         .graphics_exposures(0);
     conn.create_gc(foreground, win, &values)?;
 
     // Ask for our window's Id
-    let win = conn.generate_id()?;
+    let win = conn.generate_id()?.into();
 
     // Create the window
     let values = CreateWindowAux::default()
@@ -839,7 +845,7 @@ fn example_expose<C: Connection>(
     screen: &Screen,
 ) -> Result<(), Box<dyn Error>> {
     let values = CreateWindowAux::default().event_mask(EventMask::Exposure);
-    let win = conn.generate_id()?;
+    let win = conn.generate_id()?.into();
     conn.create_window(
         depth,
         win,
@@ -865,7 +871,7 @@ fn example_expose<C: Connection>(
 fn example_or<C: Connection>(conn: &C, depth: u8, screen: &Screen) -> Result<(), Box<dyn Error>> {
     let values =
         CreateWindowAux::default().event_mask(EventMask::Exposure | EventMask::ButtonPress);
-    let win = conn.generate_id()?;
+    let win = conn.generate_id()?.into();
     conn.create_window(
         depth,
         win,
@@ -1233,27 +1239,26 @@ pub struct RenamedKeyPressEvent {
 // characteristics of the event. With this code, it should be easy to add drawing operations, like
 // those which have been described above.
 
-fn print_modifiers(mask: u16) {
-    use KeyButMask::*;
+fn print_modifiers(mask: KeyButMask) {
     let mods = [
-        (Shift, "Shift"),
-        (Lock, "Lock"),
-        (Control, "Ctrl"),
-        (Mod1, "Alt"),
-        (Mod2, "Mod2"),
-        (Mod3, "Mod3"),
-        (Mod4, "Mod4"),
-        (Mod5, "Mod5"),
-        (Button1, "Button1"),
-        (Button2, "Button2"),
-        (Button3, "Button3"),
-        (Button4, "Button4"),
-        (Button5, "Button5"),
+        (KeyButMask::Shift, "Shift"),
+        (KeyButMask::Lock, "Lock"),
+        (KeyButMask::Control, "Ctrl"),
+        (KeyButMask::Mod1, "Alt"),
+        (KeyButMask::Mod2, "Mod2"),
+        (KeyButMask::Mod3, "Mod3"),
+        (KeyButMask::Mod4, "Mod4"),
+        (KeyButMask::Mod5, "Mod5"),
+        (KeyButMask::Button1, "Button1"),
+        (KeyButMask::Button2, "Button2"),
+        (KeyButMask::Button3, "Button3"),
+        (KeyButMask::Button4, "Button4"),
+        (KeyButMask::Button5, "Button5"),
     ];
 
     let active = mods
         .iter()
-        .filter(|(m, _)| mask & u16::from(*m) != 0) // FIXME: This should be made nicer
+        .filter(|(m, _)| mask.contains(*m))
         .map(|(_, name)| name)
         .collect::<Vec<_>>();
     println!("Modifier mask: {:?}", active);
@@ -1267,7 +1272,7 @@ fn example7() -> Result<(), Box<dyn Error>> {
     let screen = &conn.setup().roots[screen_num];
 
     // Ask for our window's Id
-    let win = conn.generate_id()?;
+    let win = conn.generate_id()?.into();
 
     // Create the window
     let values = CreateWindowAux::default()
@@ -1307,7 +1312,11 @@ fn example7() -> Result<(), Box<dyn Error>> {
                 println!(
                     "Window {} exposed. Region to be redrawn at location ({},{}) \
                          with dimensions ({},{})",
-                    event.window, event.x, event.y, event.width, event.height
+                    u32::from(event.window),
+                    event.x,
+                    event.y,
+                    event.width,
+                    event.height
                 );
             }
             Event::ButtonPress(event) => {
@@ -1315,15 +1324,22 @@ fn example7() -> Result<(), Box<dyn Error>> {
                 match event.detail {
                     4 => println!(
                         "Wheel Button up in window {}, at coordinates ({},{})",
-                        event.event, event.event_x, event.event_y
+                        u32::from(event.event),
+                        event.event_x,
+                        event.event_y
                     ),
                     5 => println!(
                         "Wheel Button down in window {}, at coordinates ({},{})",
-                        event.event, event.event_x, event.event_y
+                        u32::from(event.event),
+                        event.event_x,
+                        event.event_y
                     ),
                     _ => println!(
                         "Button {} pressed in window {}, at coordinates ({},{})",
-                        event.detail, event.event, event.event_x, event.event_y
+                        event.detail,
+                        u32::from(event.event),
+                        event.event_x,
+                        event.event_y
                     ),
                 }
             }
@@ -1331,34 +1347,43 @@ fn example7() -> Result<(), Box<dyn Error>> {
                 print_modifiers(event.state);
                 println!(
                     "Button {} released in window {}, at coordinates ({},{})",
-                    event.detail, event.event, event.event_x, event.event_y
+                    event.detail,
+                    u32::from(event.event),
+                    event.event_x,
+                    event.event_y
                 );
             }
             Event::MotionNotify(event) => {
                 println!(
                     "Mouse moved in window {} at coordinates ({},{})",
-                    event.event, event.event_x, event.event_y
+                    u32::from(event.event),
+                    event.event_x,
+                    event.event_y
                 );
             }
             Event::EnterNotify(event) => {
                 println!(
                     "Mouse entered window {} at coordinates ({},{})",
-                    event.event, event.event_x, event.event_y
+                    u32::from(event.event),
+                    event.event_x,
+                    event.event_y
                 );
             }
             Event::LeaveNotify(event) => {
                 println!(
                     "Mouse left window {} at coordinates ({},{})",
-                    event.event, event.event_x, event.event_y
+                    u32::from(event.event),
+                    event.event_x,
+                    event.event_y
                 );
             }
             Event::KeyPress(event) => {
                 print_modifiers(event.state);
-                println!("Key pressed in window {}", event.event);
+                println!("Key pressed in window {}", u32::from(event.event));
             }
             Event::KeyRelease(event) => {
                 print_modifiers(event.state);
-                println!("Key released in window {}", event.event);
+                println!("Key released in window {}", u32::from(event.event));
             }
             _ => {
                 // Unknown event type, ignore it
@@ -1420,7 +1445,7 @@ fn example_assign_font<C: Connection>(
     window: Window,
     font: Font,
 ) -> Result<(), Box<dyn Error>> {
-    let gc = conn.generate_id()?;
+    let gc = conn.generate_id()?.into();
     let values = CreateGCAux::default()
         .foreground(screen.black_pixel)
         .background(screen.white_pixel)
@@ -1476,11 +1501,11 @@ fn gc_font_get<C: Connection>(
     window: Window,
     font_name: &str,
 ) -> Result<Gcontext, ReplyOrIdError<C::Buf>> {
-    let font = conn.generate_id()?;
+    let font = conn.generate_id()?.into();
 
     conn.open_font(font, font_name.as_bytes())?;
 
-    let gc = conn.generate_id()?;
+    let gc = conn.generate_id()?.into();
     let values = CreateGCAux::default()
         .foreground(screen.black_pixel)
         .background(screen.white_pixel)
@@ -1503,7 +1528,7 @@ fn example8() -> Result<(), Box<dyn Error>> {
     const HEIGHT: u16 = 100;
 
     // Creating the window
-    let window = conn.generate_id()?;
+    let window = conn.generate_id()?.into();
     let values = CreateWindowAux::default()
         .background_pixel(screen.white_pixel)
         .event_mask(
@@ -1641,7 +1666,7 @@ fn example9() -> Result<(), Box<dyn Error>> {
     let screen = &conn.setup().roots[screen_num];
 
     // Ask for our window's Id
-    let win = conn.generate_id()?;
+    let win = conn.generate_id()?.into();
 
     // Create the window
     conn.create_window(
@@ -2042,7 +2067,7 @@ fn example_create_colormap<C: Connection>(
     win: Window,
     screen: &Screen,
 ) -> Result<(), ReplyOrIdError<C::Buf>> {
-    let cmap = conn.generate_id()?;
+    let cmap = conn.generate_id()?.into();
     conn.create_colormap(ColormapAlloc::None, cmap, win, screen.root_visual)?;
 
     Ok(())
@@ -2084,7 +2109,7 @@ fn example_fill_colormap<C: Connection>(
     win: Window,
     screen: &Screen,
 ) -> Result<(), ReplyOrIdError<C::Buf>> {
-    let cmap = conn.generate_id()?;
+    let cmap = conn.generate_id()?.into();
     conn.create_colormap(ColormapAlloc::None, cmap, win, screen.root_visual)?;
     let _rep = conn.alloc_color(cmap, 65535, 0, 0)?.reply()?;
 
@@ -2237,10 +2262,10 @@ fn example_create_glyph_cursor<C: Connection>(
     win: Window,
     screen: &Screen,
 ) -> Result<(), ReplyOrIdError<C::Buf>> {
-    let font = conn.generate_id()?;
+    let font = conn.generate_id()?.into();
     conn.open_font(font, b"cursor")?;
 
-    let cursor = conn.generate_id()?;
+    let cursor = conn.generate_id()?.into();
     conn.create_glyph_cursor(cursor, font, font, 58, 58 + 1, 0, 0, 0, 0, 0, 0)?;
 
     Ok(())
@@ -2335,10 +2360,10 @@ fn cursor_set<C: Connection>(
     window: Window,
     cursor_id: u16,
 ) -> Result<(), ReplyOrIdError<C::Buf>> {
-    let font = conn.generate_id()?;
+    let font = conn.generate_id()?.into();
     conn.open_font(font, b"cursor")?;
 
-    let cursor = conn.generate_id()?;
+    let cursor = conn.generate_id()?.into();
     conn.create_glyph_cursor(
         cursor,
         font,
@@ -2353,7 +2378,7 @@ fn cursor_set<C: Connection>(
         0,
     )?;
 
-    let gc = conn.generate_id()?;
+    let gc = conn.generate_id()?.into();
     let values = CreateGCAux::default()
         .foreground(screen.black_pixel)
         .background(screen.black_pixel)
@@ -2379,7 +2404,7 @@ fn example10() -> Result<(), Box<dyn Error>> {
     let screen = &conn.setup().roots[screen_num];
 
     // Creating the window
-    let window = conn.generate_id()?;
+    let window = conn.generate_id()?.into();
     let values = CreateWindowAux::default()
         .background_pixel(screen.white_pixel)
         .event_mask(EventMask::KeyRelease | EventMask::ButtonPress | EventMask::Exposure);
@@ -2709,7 +2734,7 @@ fn example_create_default_gc<C: Connection>(
     let values = CreateGCAux::default()
         .foreground(screen.black_pixel)
         .background(screen.white_pixel);
-    let gc = conn.generate_id()?;
+    let gc = conn.generate_id()?.into();
     conn.create_gc(gc, screen.root, &values)?;
     Ok(gc)
 }
@@ -16,7 +16,7 @@ const EYE_SIZE: i16 = 50;
 // Draw the big background of the eyes
 fn draw_eyes<C: Connection>(
     conn: &C,
-    win_id: Window,
+    drawable: Drawable,
     black: Gcontext,
     white: Gcontext,
     window_size: (u16, u16),
@@ -32,7 +32,7 @@ fn draw_eyes<C: Connection>(
     };
     let mut arc2 = arc1;
     arc2.x = arc2.width as _;
-    conn.poly_fill_arc(win_id, black, &[arc1, arc2])?;
+    conn.poly_fill_arc(drawable, black, &[arc1, arc2])?;
 
     // Draw the white inner part
     for mut arc in [&mut arc1, &mut arc2].iter_mut() {
@@ -41,7 +41,7 @@ fn draw_eyes<C: Connection>(
         arc.width -= 2 * EYE_SIZE as u16;
         arc.height -= 2 * EYE_SIZE as u16;
     }
-    conn.poly_fill_arc(win_id, white, &[arc1, arc2])?;
+    conn.poly_fill_arc(drawable, white, &[arc1, arc2])?;
 
     Ok(())
 }
@@ -49,7 +49,7 @@ fn draw_eyes<C: Connection>(
 // Draw the pupils inside the eye
 fn draw_pupils<C: Connection>(
     conn: &C,
-    win_id: Window,
+    drawable: Drawable,
     gc: Gcontext,
     ((x1, y1), (x2, y2)): ((i16, i16), (i16, i16)),
 ) -> Result<(), ConnectionError> {
@@ -70,7 +70,7 @@ fn draw_pupils<C: Connection>(
     arc2.y = y2;
 
     // Do the drawing
-    conn.poly_fill_arc(win_id, gc, &[arc1, arc2])?;
+    conn.poly_fill_arc(drawable, gc, &[arc1, arc2])?;
     Ok(())
 }
 
@@ -172,7 +172,7 @@ fn create_pixmap_wrapper<C: Connection>(
     drawable: Drawable,
     size: (u16, u16),
 ) -> Result<FreePixmap<C>, ReplyOrIdError<C::Buf>> {
-    let pixmap = conn.generate_id()?;
+    let pixmap = conn.generate_id()?.into();
     conn.create_pixmap(depth, pixmap, drawable, size.0, size.1)?;
     Ok(FreePixmap(conn, pixmap))
 }
@@ -183,10 +183,10 @@ fn shape_window<C: Connection>(
     window_size: (u16, u16),
 ) -> Result<(), ReplyOrIdError<C::Buf>> {
     // Create a pixmap for the shape
-    let pixmap = create_pixmap_wrapper(conn, 1, win_id, window_size)?;
+    let pixmap = create_pixmap_wrapper(conn, 1, win_id.into(), window_size)?;
 
     // Fill the pixmap with what will indicate "transparent"
-    let gc = create_gc_with_foreground(conn, pixmap.1, 0)?;
+    let gc = create_gc_with_foreground(conn, pixmap.1.into(), 0)?;
     let _free_gc = FreeGC(conn, gc);
 
     let rect = Rectangle {
@@ -200,7 +200,7 @@ fn shape_window<C: Connection>(
     // Draw the eyes as "not transparent"
     let values = ChangeGCAux::new().foreground(1);
     conn.change_gc(gc, &values)?;
-    draw_eyes(conn, pixmap.1, gc, gc, window_size)?;
+    draw_eyes(conn, pixmap.1.into(), gc, gc, window_size)?;
 
     // Set the shape of the window
     conn.shape_mask(shape::SO::Set, shape::SK::Bounding, win_id, 0, 0, pixmap.1)?;
@@ -214,7 +214,7 @@ fn setup_window<C: Connection>(
     wm_protocols: Atom,
     wm_delete_window: Atom,
 ) -> Result<Window, ReplyOrIdError<C::Buf>> {
-    let win_id = conn.generate_id()?;
+    let win_id = conn.generate_id()?.into();
     let win_aux = CreateWindowAux::new()
         .event_mask(EventMask::Exposure | EventMask::StructureNotify | EventMask::PointerMotion)
         .background_pixel(screen.white_pixel);
@@ -247,7 +247,7 @@ fn setup_window<C: Connection>(
         win_id,
         wm_protocols,
         AtomEnum::ATOM,
-        &[wm_delete_window],
+        &[wm_delete_window.into()],
     )
     .unwrap();
 
@@ -258,14 +258,14 @@ fn setup_window<C: Connection>(
 
 fn create_gc_with_foreground<C: Connection>(
     conn: &C,
-    win_id: Window,
+    drawable: Drawable,
     foreground: u32,
 ) -> Result<Gcontext, ReplyOrIdError<C::Buf>> {
-    let gc = conn.generate_id()?;
+    let gc = conn.generate_id()?.into();
     let gc_aux = CreateGCAux::new()
         .graphics_exposures(0)
         .foreground(foreground);
-    conn.create_gc(gc, win_id, &gc_aux)?;
+    conn.create_gc(gc, drawable, &gc_aux)?;
     Ok(gc)
 }
 
@@ -291,10 +291,11 @@ fn main() {
         wm_delete_window.reply().unwrap().atom,
     );
     let win_id = setup_window(conn, screen, window_size, wm_protocols, wm_delete_window).unwrap();
-    let mut pixmap = create_pixmap_wrapper(conn, screen.root_depth, win_id, window_size).unwrap();
+    let mut pixmap =
+        create_pixmap_wrapper(conn, screen.root_depth, win_id.into(), window_size).unwrap();
 
-    let black_gc = create_gc_with_foreground(conn, win_id, screen.black_pixel).unwrap();
-    let white_gc = create_gc_with_foreground(conn, win_id, screen.white_pixel).unwrap();
+    let black_gc = create_gc_with_foreground(conn, win_id.into(), screen.black_pixel).unwrap();
+    let white_gc = create_gc_with_foreground(conn, win_id.into(), screen.white_pixel).unwrap();
 
     conn.flush().unwrap();
 
@@ -316,8 +317,9 @@ fn main() {
                 }
                 Event::ConfigureNotify(event) => {
                     window_size = (event.width, event.height);
-                    pixmap = create_pixmap_wrapper(conn, screen.root_depth, win_id, window_size)
-                        .unwrap();
+                    pixmap =
+                        create_pixmap_wrapper(conn, screen.root_depth, win_id.into(), window_size)
+                            .unwrap();
                     need_reshape = true;
                 }
                 Event::MotionNotify(event) => {
@@ -329,7 +331,10 @@ fn main() {
                 }
                 Event::ClientMessage(event) => {
                     let data = event.data.as_data32();
-                    if event.format == 32 && event.window == win_id && data[0] == wm_delete_window {
+                    if event.format == 32
+                        && event.window == win_id
+                        && data[0] == u32::from(wm_delete_window)
+                    {
                         println!("Window was asked to close");
                         return;
                     }
@@ -352,8 +357,8 @@ fn main() {
         if need_repaint {
             // Draw new pupils
             let pos = compute_pupils(window_size, mouse_position);
-            draw_eyes(conn, pixmap.1, black_gc, white_gc, window_size).unwrap();
-            draw_pupils(conn, pixmap.1, black_gc, pos).unwrap();
+            draw_eyes(conn, pixmap.1.into(), black_gc, white_gc, window_size).unwrap();
+            draw_pupils(conn, pixmap.1.into(), black_gc, pos).unwrap();
 
             // Copy drawing from pixmap to window
             conn.copy_area(
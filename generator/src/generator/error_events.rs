@@ -38,15 +38,14 @@ pub(super) fn generate(out: &mut Output, module: &xcbgen::defs::Module) {
     outln!(out, "/// Get the sequence number out of an X11 packet.");
     outln!(
         out,
-        "fn sequence_number(raw_bytes: &[u8]) -> Result<u16, ParseError> {{"
+        "fn sequence_number(raw_bytes: &[u8], order: ByteOrder) -> Result<u16, ParseError> {{"
     );
     out.indented(|out| {
-        outln!(out, "raw_bytes.get(2..4)");
         outln!(
-            out.indent(),
-            ".map(|b| u16::from_ne_bytes(b.try_into().unwrap()))"
+            out,
+            "let raw_bytes = raw_bytes.get(2..).ok_or(ParseError::ParseError)?;"
         );
-        outln!(out.indent(), ".ok_or(ParseError::ParseError)");
+        outln!(out, "Ok(u16::try_parse_with_order(raw_bytes, order)?.0)");
     });
     outln!(out, "}}");
 }
@@ -71,7 +70,7 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
                 outln!(
                     out,
                     "{}{}({}::{}Error),",
-                    get_ns_name_prefix(ns),
+                    super::get_ns_name_prefix(ns),
                     err_name,
                     ns.header,
                     err_name,
@@ -87,11 +86,31 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
             out,
             "/// Parse a generic X11 error into a concrete error type."
         );
-        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The error is expected to be encoded in the native byte order.",
+        );
         outln!(out, "pub fn parse(");
         outln!(out.indent(), "error: B,");
         outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
         outln!(out, ") -> Result<Self, ParseError> {{");
+        outln!(
+            out.indent(),
+            "Self::parse_with_order(error, ext_info_provider, ByteOrder::native())",
+        );
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "/// Parse a generic X11 error that is encoded in the given byte order.",
+        );
+        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        outln!(out, "pub fn parse_with_order(");
+        outln!(out.indent(), "error: B,");
+        outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out.indent(), "order: ByteOrder,");
+        outln!(out, ") -> Result<Self, ParseError> {{");
         out.indented(|out| {
             outln!(out, "let error_code = error_code(error.as_ref())?;");
             outln!(out, "");
@@ -103,9 +122,11 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
                 for err_name in error_defs.iter().map(|def| def.name()) {
                     outln!(
                         out,
-                        "xproto::{}_ERROR => return Ok(Self::{}(error.as_ref().try_into()?)),",
+                        "xproto::{}_ERROR => return Ok(Self::{}(\
+                        xproto::{}Error::try_parse_with_order(error.as_ref(), order)?.0)),",
                         super::camel_case_to_upper_snake(err_name),
                         err_name,
+                        err_name,
                     );
                 }
                 outln!(out, "_ => {{}}");
@@ -143,10 +164,13 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
                         for err_name in error_defs.iter().map(|def| def.name()) {
                             outln!(
                                 out.indent(),
-                                "{}::{}_ERROR => Ok(Self::{}{}(error.as_ref().try_into()?)),",
+                                "{}::{}_ERROR => Ok(Self::{}{}(\
+                                {}::{}Error::try_parse_with_order(error.as_ref(), order)?.0)),",
                                 ns.header,
                                 super::camel_case_to_upper_snake(err_name),
-                                get_ns_name_prefix(ns),
+                                super::get_ns_name_prefix(ns),
+                                err_name,
+                                ns.header,
                                 err_name,
                             );
                         }
@@ -165,12 +189,32 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
             out,
             "/// Get the sequence number contained in this X11 error",
         );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The sequence number of an unknown error is read in the native byte order.",
+        );
         outln!(out, "pub fn wire_sequence_number(&self) -> u16 {{");
+        outln!(
+            out.indent(),
+            "self.wire_sequence_number_with_order(ByteOrder::native())",
+        );
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "/// Get the sequence number contained in this X11 error, reading the sequence number of",
+        );
+        outln!(out, "/// an unknown error in the given byte order.");
+        outln!(
+            out,
+            "pub fn wire_sequence_number_with_order(&self, order: ByteOrder) -> u16 {{"
+        );
         out.indented(|out| {
             outln!(out, "match self {{");
             outln!(
                 out.indent(),
-                "Error::Unknown(value) => sequence_number(value.as_ref()).unwrap(),",
+                "Error::Unknown(value) => sequence_number(value.as_ref(), order).unwrap(),",
             );
             for ns in namespaces.iter() {
                 let has_feature = super::ext_has_feature(&ns.header);
@@ -183,7 +227,7 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
                     outln!(
                         out.indent(),
                         "Error::{}{}(value) => value.sequence,",
-                        get_ns_name_prefix(ns),
+                        super::get_ns_name_prefix(ns),
                         err_name,
                     );
                 }
@@ -210,7 +254,7 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
                     outln!(
                         out.indent(),
                         "Error::{}{}(value) => value.error_code,",
-                        get_ns_name_prefix(ns),
+                        super::get_ns_name_prefix(ns),
                         err_name,
                     );
                 }
@@ -242,7 +286,7 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
                     outln!(
                         out.indent(),
                         "Error::{}{}(value) => value.response_type,",
-                        get_ns_name_prefix(ns),
+                        super::get_ns_name_prefix(ns),
                         err_name,
                     );
                 }
@@ -274,7 +318,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                 outln!(
                     out,
                     "{}{}({}::{}Event),",
-                    get_ns_name_prefix(ns),
+                    super::get_ns_name_prefix(ns),
                     event_name,
                     ns.header,
                     event_name,
@@ -284,17 +328,67 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
     });
     outln!(out, "}}");
     outln!(out, "");
+    outln!(
+        out,
+        "/// The kind of an X11 event, without any of the event's data."
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// There is one variant for every variant of `Event`."
+    );
+    outln!(
+        out,
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]"
+    );
+    outln!(out, "pub enum EventKind {{");
+    out.indented(|out| {
+        outln!(out, "Unknown,");
+        outln!(out, "Error,");
+        for ns in namespaces.iter() {
+            let has_feature = super::ext_has_feature(&ns.header);
+            let event_defs = sorted_events(ns);
+            for event_name in event_defs.iter().map(|def| def.name()) {
+                if has_feature {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(out, "{}{},", super::get_ns_name_prefix(ns), event_name);
+            }
+        }
+    });
+    outln!(out, "}}");
+    outln!(out, "");
     outln!(out, "impl<B: std::fmt::Debug + AsRef<[u8]>> Event<B> {{");
     out.indented(|out| {
         outln!(
             out,
             "/// Parse a generic X11 event into a concrete event type."
         );
-        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The event is expected to be encoded in the native byte order.",
+        );
         outln!(out, "pub fn parse(");
         outln!(out.indent(), "event: B,");
         outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
         outln!(out, ") -> Result<Self, ParseError> {{");
+        outln!(
+            out.indent(),
+            "Self::parse_with_order(event, ext_info_provider, ByteOrder::native())",
+        );
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "/// Parse a generic X11 event that is encoded in the given byte order.",
+        );
+        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        outln!(out, "pub fn parse_with_order(");
+        outln!(out.indent(), "event: B,");
+        outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out.indent(), "order: ByteOrder,");
+        outln!(out, ") -> Result<Self, ParseError> {{");
         out.indented(|out| {
             outln!(out, "let event_code = response_type(event.as_ref())?;");
             outln!(out, "");
@@ -308,7 +402,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                 outln!(
                     out,
                     "0 => return Ok({}),",
-                    "Self::Error(Error::parse(event, ext_info_provider)?)",
+                    "Self::Error(Error::parse_with_order(event, ext_info_provider, order)?)",
                 );
                 let xproto_ns = module.namespace("xproto").unwrap();
                 let event_defs = sorted_events(&xproto_ns);
@@ -319,15 +413,17 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                     }
                     outln!(
                         out,
-                        "xproto::{}_EVENT => return Ok(Self::{}(event.as_ref().try_into()?)),",
+                        "xproto::{}_EVENT => return Ok(Self::{}(\
+                        xproto::{}Event::try_parse_with_order(event.as_ref(), order)?.0)),",
                         super::camel_case_to_upper_snake(event_name),
                         event_name,
+                        event_name,
                     );
                 }
                 outln!(
                     out,
                     "xproto::GE_GENERIC_EVENT => {},",
-                    "return Self::from_generic_event(event, ext_info_provider)",
+                    "return Self::from_generic_event(event, ext_info_provider, order)",
                 );
                 outln!(out, "_ => {{}}");
             });
@@ -377,10 +473,13 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                             }
                             outln!(
                                 out.indent(),
-                                "{}::{}_EVENT => Ok(Self::{}{}(event.as_ref().try_into()?)),",
+                                "{}::{}_EVENT => Ok(Self::{}{}(\
+                                {}::{}Event::try_parse_with_order(event.as_ref(), order)?.0)),",
                                 ns.header,
                                 super::camel_case_to_upper_snake(event_def.name()),
-                                get_ns_name_prefix(ns),
+                                super::get_ns_name_prefix(ns),
+                                event_def.name(),
+                                ns.header,
                                 event_def.name(),
                             );
                         }
@@ -399,11 +498,12 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
         outln!(out, "fn from_generic_event(");
         outln!(out.indent(), "event: B,");
         outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out.indent(), "order: ByteOrder,");
         outln!(out, ") -> Result<Self, ParseError> {{");
         out.indented(|out| {
             outln!(
                 out,
-                "let ge_event = xproto::GeGenericEvent::try_from(event.as_ref())?;"
+                "let ge_event = xproto::GeGenericEvent::try_parse_with_order(event.as_ref(), order)?.0;"
             );
             outln!(out, "let ext_name = ext_info_provider");
             outln!(out.indent(), ".get_from_major_opcode(ge_event.extension)");
@@ -433,10 +533,13 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                             }
                             outln!(
                                 out.indent(),
-                                "{}::{}_EVENT => Ok(Self::{}{}(event.as_ref().try_into()?)),",
+                                "{}::{}_EVENT => Ok(Self::{}{}(\
+                                {}::{}Event::try_parse_with_order(event.as_ref(), order)?.0)),",
                                 ns.header,
                                 super::camel_case_to_upper_snake(event_def.name()),
-                                get_ns_name_prefix(ns),
+                                super::get_ns_name_prefix(ns),
+                                event_def.name(),
+                                ns.header,
                                 event_def.name(),
                             );
                         }
@@ -456,16 +559,36 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
             out,
             "/// Get the sequence number contained in this X11 event",
         );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The sequence number of an unknown event is read in the native byte order.",
+        );
         outln!(out, "pub fn wire_sequence_number(&self) -> Option<u16> {{");
+        outln!(
+            out.indent(),
+            "self.wire_sequence_number_with_order(ByteOrder::native())",
+        );
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "/// Get the sequence number contained in this X11 event, reading the sequence number of",
+        );
+        outln!(out, "/// an unknown event in the given byte order.");
+        outln!(
+            out,
+            "pub fn wire_sequence_number_with_order(&self, order: ByteOrder) -> Option<u16> {{"
+        );
         out.indented(|out| {
             outln!(out, "match self {{");
             outln!(
                 out.indent(),
-                "Event::Unknown(value) => sequence_number(value.as_ref()).ok(),",
+                "Event::Unknown(value) => sequence_number(value.as_ref(), order).ok(),",
             );
             outln!(
                 out.indent(),
-                "Event::Error(value) => Some(value.wire_sequence_number()),",
+                "Event::Error(value) => Some(value.wire_sequence_number_with_order(order)),",
             );
             for ns in namespaces.iter() {
                 let event_defs = sorted_events(ns);
@@ -478,14 +601,14 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                         outln!(
                             out.indent(),
                             "Event::{}{}(_) => None,",
-                            get_ns_name_prefix(ns),
+                            super::get_ns_name_prefix(ns),
                             event_def.name(),
                         );
                     } else {
                         outln!(
                             out.indent(),
                             "Event::{}{}(value) => Some(value.sequence),",
-                            get_ns_name_prefix(ns),
+                            super::get_ns_name_prefix(ns),
                             event_def.name(),
                         );
                     }
@@ -533,7 +656,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                     outln!(
                         out.indent(),
                         "Event::{}{}(value) => value.response_type,",
-                        get_ns_name_prefix(ns),
+                        super::get_ns_name_prefix(ns),
                         event_def.name(),
                     );
                 }
@@ -566,22 +689,81 @@ pub fn sent_event(&self) -> bool {{
     self.raw_response_type() & 0x80 != 0
 }}"
         );
+        outln!(out, "");
+
+        outln!(out, "/// Get the kind of this X11 event");
+        outln!(out, "pub fn kind(&self) -> EventKind {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            outln!(out.indent(), "Event::Unknown(_) => EventKind::Unknown,");
+            outln!(out.indent(), "Event::Error(_) => EventKind::Error,");
+            for ns in namespaces.iter() {
+                let event_defs = sorted_events(ns);
+                let has_feature = super::ext_has_feature(&ns.header);
+                for event_def in event_defs.iter() {
+                    if has_feature {
+                        outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    outln!(
+                        out.indent(),
+                        "Event::{0}{1}(_) => EventKind::{0}{1},",
+                        super::get_ns_name_prefix(ns),
+                        event_def.name(),
+                    );
+                }
+            }
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(out, "/// Get the window that this X11 event refers to, if any");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// This is the value of the first field of type `WINDOW` that is called `window`,",
+        );
+        outln!(
+            out,
+            "/// `event`, `owner` or `requestor`, in this order of preference. For example, this is",
+        );
+        outln!(
+            out,
+            "/// the window that was configured for `ConfigureNotify` and the event window for",
+        );
+        outln!(out, "/// `ButtonPress`.");
+        outln!(out, "pub fn window(&self) -> Option<xproto::Window> {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            for ns in namespaces.iter() {
+                let event_defs = sorted_events(ns);
+                let has_feature = super::ext_has_feature(&ns.header);
+                for event_def in event_defs.iter() {
+                    let field = match event_window_field(&event_def.get_original_full_def()) {
+                        Some(field) => field,
+                        None => continue,
+                    };
+                    if has_feature {
+                        outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    outln!(
+                        out.indent(),
+                        "Event::{}{}(value) => Some(value.{}),",
+                        super::get_ns_name_prefix(ns),
+                        event_def.name(),
+                        field,
+                    );
+                }
+            }
+            outln!(out.indent(), "_ => None,");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
     });
     outln!(out, "}}");
 }
 
 /// Get the prefix that should be used for enum variants from this module.
-fn get_ns_name_prefix(ns: &xcbgen::defs::Namespace) -> String {
-    if ns.ext_info.is_some() {
-        let (first, remaining) = ns.header.split_at(1);
-        let mut r = first.to_ascii_uppercase();
-        r.push_str(remaining);
-        r
-    } else {
-        String::new()
-    }
-}
-
 fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
     let mut errors: Vec<_> = ns
         .error_defs
@@ -607,6 +789,32 @@ fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
     errors
 }
 
+/// Get the name of the field that contains the window that an event refers to.
+///
+/// See the documentation of the generated `Event::window()` for the rules.
+fn event_window_field(event_def: &xcbgen::defs::EventFullDef) -> Option<&'static str> {
+    let fields = event_def.fields.borrow();
+    let is_window_field = |name: &str| {
+        fields.iter().any(|field| match field {
+            xcbgen::defs::FieldDef::Normal(normal_field) if normal_field.name == name => {
+                match normal_field.type_.type_.def.get().unwrap() {
+                    xcbgen::defs::TypeRef::Xid(xid_def) => {
+                        let xid_def = xid_def.upgrade().unwrap();
+                        xid_def.name == "WINDOW"
+                            && xid_def.namespace.upgrade().unwrap().header == "xproto"
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        })
+    };
+    ["window", "event", "owner", "requestor"]
+        .iter()
+        .copied()
+        .find(|name| is_window_field(name))
+}
+
 fn sorted_events(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::EventDef> {
     let mut events: Vec<_> = ns.event_defs.borrow().values().cloned().collect();
     events.sort_by(|a, b| a.name().cmp(b.name()));
@@ -7,6 +7,7 @@ use fxhash::FxHashMap;
 mod output;
 mod error_events;
 mod namespace;
+mod requests;
 mod special_cases;
 
 use output::Output;
@@ -16,9 +17,13 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> FxHashMap<PathBuf, Stri
 
     let mut main_out = Output::new();
     write_code_header(&mut main_out);
-    outln!(main_out, "use std::convert::{{TryFrom, TryInto}};");
+    outln!(main_out, "use std::convert::TryFrom;");
     outln!(main_out, "use crate::errors::ParseError;");
-    outln!(main_out, "use crate::x11_utils::ExtInfoProvider;");
+    outln!(main_out, "use crate::utils::RawFdContainer;");
+    outln!(
+        main_out,
+        "use crate::x11_utils::{{ByteOrder, ExtInfoProvider, RequestHeader, TryParse}};"
+    );
     outln!(main_out, "");
 
     let caches = RefCell::new(namespace::Caches::default());
@@ -38,6 +43,8 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> FxHashMap<PathBuf, Stri
     outln!(main_out, "");
 
     error_events::generate(&mut main_out, module);
+    outln!(main_out, "");
+    requests::generate(&mut main_out, module);
 
     out_map.insert(PathBuf::from("mod.rs"), main_out.into_data());
     out_map
@@ -50,6 +57,22 @@ fn ext_has_feature(name: &str) -> bool {
     }
 }
 
+/// Get the prefix that is used for the items of namespace `ns` in the
+/// enumerations that cover all namespaces, e.g. `XcMisc` for `xc_misc`.
+fn get_ns_name_prefix(ns: &xcbgen::defs::Namespace) -> String {
+    if ns.ext_info.is_some() {
+        let mut r = String::new();
+        for part in ns.header.split('_') {
+            let (first, remaining) = part.split_at(1);
+            r.push_str(&first.to_ascii_uppercase());
+            r.push_str(remaining);
+        }
+        r
+    } else {
+        String::new()
+    }
+}
+
 /// Add a Rust-header to the output saying that this file is generated.
 fn write_code_header(out: &mut Output) {
     outln!(
@@ -19,7 +19,7 @@ pub(super) fn generate(ns: &xcbdefs::Namespace, caches: &RefCell<Caches>, out: &
 #[derive(Default)]
 pub(super) struct Caches {
     derives: FxHashMap<usize, Derives>,
-    enum_has_repeated_values: FxHashMap<usize, bool>,
+    enum_int_types: FxHashMap<usize, &'static str>,
     rust_type_names: FxHashMap<usize, String>,
 }
 
@@ -60,7 +60,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "#[allow(unused_imports)]");
         outln!(out, "use crate::utils::RawFdContainer;");
         outln!(out, "#[allow(unused_imports)]");
-        outln!(out, "use crate::x11_utils::{{Serialize, TryParse}};");
+        outln!(
+            out,
+            "use crate::x11_utils::{{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd}};"
+        );
         outln!(out, "use crate::connection::RequestConnection;");
         outln!(out, "#[allow(unused_imports)]");
         outln!(
@@ -233,6 +236,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             );
         }
 
+        self.emit_request_struct(request_def, &name, &deducible_fields, out);
+
         let gathered = self.gather_request_fields(request_def, &deducible_fields);
 
         self.emit_request_function(
@@ -243,6 +248,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             &gathered,
             out,
         );
+        self.emit_request_send_impl(request_def, &name, &function_name, &gathered, out);
         self.emit_request_trait_function(request_def, &name, &function_name, &gathered, trait_out);
 
         special_cases::handle_request(request_def, out);
@@ -263,10 +269,17 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 false,
                 true,
                 false,
-                false,
+                true,
                 reply.doc.as_ref(),
                 out,
             );
+            let deducible_fields = gather_deducible_fields(&*reply_fields);
+            self.emit_reply_or_xge_event_serialize(
+                &reply_struct_name,
+                &*reply_fields,
+                &deducible_fields,
+                out,
+            );
 
             outln!(out, "");
         }
@@ -287,7 +300,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 switch_field,
                 &aux_name,
                 deducible_fields,
-                false,
+                true,
                 true,
                 None,
                 out,
@@ -301,7 +314,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 switch_field,
                 &aux_name,
                 deducible_fields,
-                false,
+                true,
                 true,
                 Some(&doc),
                 out,
@@ -354,6 +367,304 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "");
     }
 
+    /// Emit a `*Request` struct holding the fields of a request and
+    /// a function for parsing it from its wire format.
+    fn emit_request_struct(
+        &self,
+        request_def: &xcbdefs::RequestDef,
+        name: &str,
+        deducible_fields: &FxHashMap<String, DeducibleField>,
+        out: &mut Output,
+    ) {
+        let ns = request_def.namespace.upgrade().unwrap();
+        let struct_name = format!("{}Request", name);
+        let fields = request_def.fields.borrow();
+
+        let is_header_field = |field: &xcbdefs::FieldDef| match field.name() {
+            Some("major_opcode") | Some("minor_opcode") | Some("length") => true,
+            _ => false,
+        };
+        let visible_fields = fields
+            .iter()
+            .filter(|field| self.field_is_visible(field, deducible_fields))
+            .filter(|field| !is_header_field(field))
+            .collect::<Vec<_>>();
+        let has_fds = fields.iter().any(|field| match field {
+            xcbdefs::FieldDef::Fd(_) | xcbdefs::FieldDef::FdList(_) => true,
+            _ => false,
+        });
+
+        let mut derives = Derives::all();
+        self.filter_derives_for_fields(&mut derives, &*fields);
+        let mut derives = derives.to_list();
+        if visible_fields
+            .iter()
+            .all(|field| self.field_implements_default(field))
+        {
+            derives.push("Default");
+        }
+
+        let struct_fields = visible_fields
+            .iter()
+            .map(|field| {
+                let field_type = match field {
+                    xcbdefs::FieldDef::Switch(_) => format!("{}Aux", name),
+                    _ => self.field_to_rust_type(field, name),
+                };
+                (to_rust_variable_name(field.name().unwrap()), field_type)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(ref doc) = request_def.doc {
+            self.emit_doc(doc, out);
+        }
+        if !derives.is_empty() {
+            outln!(out, "#[derive({})]", derives.join(", "));
+        }
+        outln!(out, "pub struct {} {{", struct_name);
+        for (field_name, field_type) in struct_fields.iter() {
+            outln!(out.indent(), "pub {}: {},", field_name, field_type);
+        }
+        outln!(out, "}}");
+
+        let opcode_name = format!("{}_REQUEST", super::camel_case_to_upper_snake(name));
+        outln!(out, "impl {} {{", struct_name);
+        out.indented(|out| {
+            if has_fds {
+                outln!(
+                    out,
+                    "/// Parse this request given its header, its body, any fds that go along with it, and the byte order of the client",
+                );
+                outln!(
+                    out,
+                    "pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<Self, ParseError> {{",
+                );
+            } else {
+                outln!(
+                    out,
+                    "/// Parse this request given its header, its body, and the byte order of the client",
+                );
+                outln!(
+                    out,
+                    "pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {{",
+                );
+            }
+            out.indented(|out| {
+                let mut odd_length_list = None;
+                for (field_i, field) in fields.iter().enumerate() {
+                    match field.name() {
+                        Some("major_opcode") => {
+                            if ns.ext_info.is_none() {
+                                outln!(out, "if header.major_opcode != {} {{", opcode_name);
+                                outln!(out.indent(), "return Err(ParseError::ParseError);");
+                                outln!(out, "}}");
+                            }
+                            continue;
+                        }
+                        Some("minor_opcode") => {
+                            outln!(out, "if header.minor_opcode != {} {{", opcode_name);
+                            outln!(out.indent(), "return Err(ParseError::ParseError);");
+                            outln!(out, "}}");
+                            continue;
+                        }
+                        Some("length") => {
+                            // The body follows the length field. Dynamic
+                            // alignment is relative to `value`, which is fine
+                            // since the header has a length of four bytes.
+                            outln!(out, "let remaining = value;");
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    if field_i == 1 {
+                        // In core requests, the second byte of the header
+                        // contains the first field (or padding).
+                        assert!(ns.ext_info.is_none());
+                        assert_eq!(field.size(), Some(1));
+                        if let xcbdefs::FieldDef::Pad(_) = field {
+                            continue;
+                        }
+                        outln!(out, "let remaining = &[header.minor_opcode];");
+                    }
+                    match field {
+                        xcbdefs::FieldDef::Switch(switch_field) => {
+                            let mut parse_params =
+                                vec![String::from("remaining"), String::from("order")];
+                            for ext_param in switch_field.external_params.borrow().iter() {
+                                parse_params.push(self.ext_param_from_raw(ext_param));
+                            }
+                            outln!(
+                                out,
+                                "let ({}, remaining) = {}Aux::try_parse_with_order({})?;",
+                                to_rust_variable_name(&switch_field.name),
+                                name,
+                                parse_params.join(", "),
+                            );
+                        }
+                        xcbdefs::FieldDef::Expr(expr_field) => {
+                            // The only case found in the XML definitions is
+                            // `odd_length`, which says that the last element
+                            // of the following list is padding.
+                            assert_eq!(expr_field.name, "odd_length");
+                            let list_name = fields
+                                .iter()
+                                .rev()
+                                .find_map(|field| match field {
+                                    xcbdefs::FieldDef::List(list_field) => {
+                                        assert!(list_field.length_expr.is_none());
+                                        Some(to_rust_variable_name(&list_field.name))
+                                    }
+                                    _ => None,
+                                })
+                                .unwrap();
+                            odd_length_list = Some(list_name);
+                            outln!(
+                                out,
+                                "let ({}, remaining) = {};",
+                                to_rust_variable_name(&expr_field.name),
+                                self.emit_value_parse(&expr_field.type_),
+                            );
+                        }
+                        _ => self.emit_field_parse(field, name, out),
+                    }
+                    if field_i == 1 {
+                        outln!(out, "let _ = remaining;");
+                    }
+                }
+                if let Some(list_name) = odd_length_list {
+                    outln!(out, "if odd_length && {}.pop().is_none() {{", list_name);
+                    outln!(out.indent(), "return Err(ParseError::ParseError);");
+                    outln!(out, "}}");
+                }
+                outln!(out, "let _ = remaining;");
+                outln!(out, "let _ = order;");
+                for field in fields.iter() {
+                    if !field
+                        .name()
+                        .map(|field_name| deducible_fields.contains_key(field_name))
+                        .unwrap_or(false)
+                    {
+                        self.emit_field_post_parse(field, out);
+                    }
+                }
+                let field_names = visible_fields
+                    .iter()
+                    .map(|field| to_rust_variable_name(field.name().unwrap()))
+                    .collect::<Vec<_>>();
+                outln!(out, "Ok({} {{", struct_name);
+                for field_name in field_names.iter() {
+                    outln!(out.indent(), "{},", field_name);
+                }
+                outln!(out, "}})");
+            });
+            outln!(out, "}}");
+            for (field_name, field_type) in struct_fields.iter() {
+                outln!(out, "/// Set the `{}` field of this structure.", field_name);
+                // Integer types implement `From` for several types, which
+                // would break type inference for integer literals.
+                if is_primitive_rust_type(field_type) {
+                    outln!(
+                        out,
+                        "pub fn {}(mut self, value: {}) -> Self {{",
+                        field_name,
+                        field_type,
+                    );
+                    outln!(out.indent(), "self.{} = value;", field_name);
+                } else {
+                    outln!(
+                        out,
+                        "pub fn {}<I>(mut self, value: I) -> Self where I: Into<{}> {{",
+                        field_name,
+                        field_type,
+                    );
+                    outln!(out.indent(), "self.{} = value.into();", field_name);
+                }
+                outln!(out.indent(), "self");
+                outln!(out, "}}");
+            }
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+    }
+
+    /// Emit the implementations of `Request` and `SendRequest` for the
+    /// `*Request` struct of a request. Sending calls the request function.
+    fn emit_request_send_impl(
+        &self,
+        request_def: &xcbdefs::RequestDef,
+        name: &str,
+        function_name: &str,
+        gathered: &GatheredRequestFields,
+        out: &mut Output,
+    ) {
+        let struct_name = format!("{}Request", name);
+        let reply_type = if request_def.reply.is_some() {
+            format!("{}Reply", name)
+        } else {
+            String::from("()")
+        };
+        outln!(out, "impl crate::x11_utils::Request for {} {{", struct_name);
+        outln!(out.indent(), "type Reply = {};", reply_type);
+        outln!(out, "}}");
+
+        let mut args = String::from("conn");
+        for (arg_name, arg_type) in gathered.args.iter() {
+            args.push_str(", ");
+            if arg_type.starts_with('&') {
+                args.push('&');
+            }
+            args.push_str("self.");
+            args.push_str(arg_name);
+        }
+        outln!(
+            out,
+            "impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for {}",
+            struct_name,
+        );
+        outln!(out, "where");
+        outln!(out.indent(), "Conn: RequestConnection + ?Sized + 'c,");
+        outln!(out, "{{");
+        out.indented(|out| {
+            outln!(
+                out,
+                "type Cookie = {};",
+                self.request_cookie_type(request_def, name, gathered, "'c"),
+            );
+            outln!(
+                out,
+                "fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {{",
+            );
+            outln!(out.indent(), "{}({})", function_name, args);
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
+    /// Returns the type of the cookie that is returned when sending a request.
+    fn request_cookie_type(
+        &self,
+        request_def: &xcbdefs::RequestDef,
+        name: &str,
+        gathered: &GatheredRequestFields,
+        lifetime: &str,
+    ) -> String {
+        let ns = request_def.namespace.upgrade().unwrap();
+        let is_list_fonts_with_info =
+            request_def.name == "ListFontsWithInfo" && ns.header == "xproto";
+
+        if is_list_fonts_with_info {
+            assert!(request_def.reply.is_some());
+            assert!(!gathered.reply_has_fds);
+            format!("ListFontsWithInfoCookie<{}, Conn>", lifetime)
+        } else {
+            match (request_def.reply.is_some(), gathered.reply_has_fds) {
+                (false, _) => format!("VoidCookie<{}, Conn>", lifetime),
+                (true, false) => format!("Cookie<{}, Conn, {}Reply>", lifetime, name),
+                (true, true) => format!("CookieWithFds<{}, Conn, {}Reply>", lifetime, name),
+            }
+        }
+    }
+
     fn emit_request_function(
         &self,
         request_def: &xcbdefs::RequestDef,
@@ -380,17 +691,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             request_def.name == "ListFontsWithInfo" && ns.header == "xproto";
 
         let ret_lifetime = if gathered.needs_lifetime { "'c" } else { "'_" };
-        let ret_type = if is_list_fonts_with_info {
-            assert!(request_def.reply.is_some());
-            assert!(!gathered.reply_has_fds);
-            format!("ListFontsWithInfoCookie<{}, Conn>", ret_lifetime)
-        } else {
-            match (request_def.reply.is_some(), gathered.reply_has_fds) {
-                (false, _) => format!("VoidCookie<{}, Conn>", ret_lifetime),
-                (true, false) => format!("Cookie<{}, Conn, {}Reply>", ret_lifetime, name),
-                (true, true) => format!("CookieWithFds<{}, Conn, {}Reply>", ret_lifetime, name),
-            }
-        };
+        let ret_type = self.request_cookie_type(request_def, name, gathered, ret_lifetime);
 
         let mut args = String::new();
         if gathered.needs_lifetime {
@@ -438,6 +739,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             for preamble in gathered.preamble.iter() {
                 outln!(out, "{}", preamble);
             }
+            outln!(out, "let order = conn.byte_order();");
 
             let fields = request_def.fields.borrow();
 
@@ -557,7 +859,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                             } else {
                                 outln!(
                                     tmp_out,
-                                    "let {} = {}.serialize();",
+                                    "let {} = {}.serialize_with_order(order);",
                                     bytes_name,
                                     rust_field_name
                                 );
@@ -605,7 +907,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                 let bytes_name = postfix_var_name(&rust_field_name, "bytes");
                                 outln!(
                                     tmp_out,
-                                    "let {} = {}.serialize();",
+                                    "let {} = {}.serialize_with_order(order);",
                                     bytes_name,
                                     rust_field_name,
                                 );
@@ -633,13 +935,12 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         let bytes_name = postfix_var_name(&rust_field_name, "bytes");
                         outln!(
                             tmp_out,
-                            "let {} = {}.serialize({});",
+                            "let {} = {}.serialize_with_order(order{});",
                             bytes_name,
                             rust_field_name,
-                            self.ext_params_to_call_args(
-                                false,
-                                to_rust_variable_name,
+                            self.ext_params_to_serialize_call_args(
                                 &*switch_field.external_params.borrow(),
+                                deducible_fields,
                             )
                         );
                         if let Some(field_size) = switch_field.size() {
@@ -775,7 +1076,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             );
             outln!(
                 out,
-                "request0[2..4].copy_from_slice(&length.to_ne_bytes());",
+                "request0[2..4].copy_from_slice(&length.serialize_with_order(order));",
             );
 
             let fds_arg = if gathered.fd_lists.is_empty() {
@@ -994,8 +1295,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             out,
         );
 
-        if !event_full_def.xge {
-            let deducible_fields = gather_deducible_fields(&*fields);
+        let deducible_fields = gather_deducible_fields(&*fields);
+        if event_full_def.xge {
+            self.emit_reply_or_xge_event_serialize(&full_name, &*fields, &deducible_fields, out);
+        } else {
             self.emit_event_or_error_serialize(&full_name, &*fields, &deducible_fields, out);
         }
 
@@ -1104,6 +1407,26 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "impl From<&{}> for [u8; 32] {{", name);
         out.indented(|out| {
             outln!(out, "fn from(input: &{}) -> Self {{", name);
+            outln!(out.indent(), "input.serialize()");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+
+        outln!(out, "impl From<{}> for [u8; 32] {{", name);
+        out.indented(|out| {
+            outln!(out, "fn from(input: {}) -> Self {{", name);
+            outln!(out.indent(), "Self::from(&input)");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+
+        outln!(out, "impl Serialize for {} {{", name);
+        out.indented(|out| {
+            outln!(out, "type Bytes = [u8; 32];");
+            outln!(
+                out,
+                "fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {{"
+            );
             out.indented(|out| {
                 // This gathers the bytes of the result
                 let mut result_bytes = Vec::new();
@@ -1111,7 +1434,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     self.emit_field_serialize(
                         field,
                         deducible_fields,
-                        "input.",
+                        "self.",
                         &mut result_bytes,
                         out,
                     );
@@ -1130,13 +1453,77 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 outln!(out, "]");
             });
             outln!(out, "}}");
+            outln!(
+                out,
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {{"
+            );
+            outln!(
+                out.indent(),
+                "bytes.extend_from_slice(&self.serialize_with_order(order));"
+            );
+            outln!(out, "}}");
         });
         outln!(out, "}}");
+    }
 
-        outln!(out, "impl From<{}> for [u8; 32] {{", name);
+    /// Emit a `Serialize` implementation for a reply or an event that
+    /// uses the generic event extension.
+    ///
+    /// These have a variable size: They are at least 32 bytes long, and
+    /// their `length` field says how many four byte units follow.
+    fn emit_reply_or_xge_event_serialize(
+        &self,
+        name: &str,
+        fields: &[xcbdefs::FieldDef],
+        deducible_fields: &FxHashMap<String, DeducibleField>,
+        out: &mut Output,
+    ) {
+        outln!(out, "impl Serialize for {} {{", name);
         out.indented(|out| {
-            outln!(out, "fn from(input: {}) -> Self {{", name);
-            outln!(out.indent(), "Self::from(&input)");
+            outln!(out, "type Bytes = Vec<u8>;");
+            outln!(
+                out,
+                "fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {{"
+            );
+            out.indented(|out| {
+                outln!(out, "let mut result = Vec::new();");
+                outln!(out, "self.serialize_into_with_order(&mut result, order);");
+                outln!(out, "result");
+            });
+            outln!(out, "}}");
+            outln!(
+                out,
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {{"
+            );
+            out.indented(|out| {
+                outln!(out, "let initial_length = bytes.len();");
+                outln!(out, "bytes.reserve(32);");
+                self.emit_switch_ext_params_bindings(fields, deducible_fields, out);
+                for field in fields.iter() {
+                    self.emit_field_serialize_into(field, deducible_fields, "self.", "bytes", out);
+                }
+                outln!(
+                    out,
+                    "// Pad to at least 32 bytes and to a multiple of four bytes"
+                );
+                outln!(
+                    out,
+                    "let packet_length = std::cmp::max(32, bytes.len() - initial_length);"
+                );
+                outln!(
+                    out,
+                    "bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);"
+                );
+                outln!(out, "// Fix up the length field");
+                outln!(
+                    out,
+                    "let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();"
+                );
+                outln!(
+                    out,
+                    "bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));"
+                );
+            });
             outln!(out, "}}");
         });
         outln!(out, "}}");
@@ -1194,17 +1581,18 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 out.indented(|out| {
                     outln!(
                         out,
-                        "fn do_the_parse(remaining: &[u8]) -> Result<{}, ParseError> {{",
+                        "fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<{}, ParseError> {{",
                         rust_field_type,
                     );
                     out.indented(|out| {
                         self.emit_field_parse(field, &rust_name, out);
                         self.emit_field_post_parse(field, out);
                         outln!(out, "let _ = remaining;");
+                        outln!(out, "let _ = order;");
                         outln!(out, "Ok({})", rust_field_name);
                     });
                     outln!(out, "}}");
-                    outln!(out, "do_the_parse(&self.0).unwrap()");
+                    outln!(out, "do_the_parse(&self.0, ByteOrder::native()).unwrap()");
                 });
                 outln!(out, "}}");
             }
@@ -1214,10 +1602,17 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "impl Serialize for {} {{", rust_name);
         out.indented(|out| {
             outln!(out, "type Bytes = [u8; {}];", union_size);
-            outln!(out, "fn serialize(&self) -> [u8; {}] {{", union_size);
+            outln!(
+                out,
+                "fn serialize_with_order(&self, _order: ByteOrder) -> [u8; {}] {{",
+                union_size,
+            );
             outln!(out.indent(), "self.0");
             outln!(out, "}}");
-            outln!(out, "fn serialize_into(&self, bytes: &mut Vec<u8>) {{");
+            outln!(
+                out,
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {{"
+            );
             outln!(out.indent(), "bytes.extend_from_slice(&self.0);");
             outln!(out, "}}");
         });
@@ -1226,7 +1621,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out.indented(|out| {
             outln!(
                 out,
-                "fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {{",
+                "fn try_parse_with_order(value: &[u8], _order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {{",
             );
             out.indented(|out| {
                 outln!(
@@ -1309,6 +1704,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 );
                 let mut result_bytes = Vec::new();
                 out.indented(|out| {
+                    outln!(out, "let order = ByteOrder::native();");
+                    outln!(out, "let _ = order;");
                     let bytes_name = self.emit_field_serialize(
                         field,
                         &FxHashMap::default(),
@@ -1390,10 +1787,16 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "impl Serialize for {} {{", rust_name);
         out.indented(|out| {
             outln!(out, "type Bytes = [u8; 32];");
-            outln!(out, "fn serialize(&self) -> [u8; 32] {{");
+            outln!(
+                out,
+                "fn serialize_with_order(&self, _order: ByteOrder) -> [u8; 32] {{"
+            );
             outln!(out.indent(), "self.0");
             outln!(out, "}}");
-            outln!(out, "fn serialize_into(&self, bytes: &mut Vec<u8>) {{");
+            outln!(
+                out,
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {{"
+            );
             outln!(out.indent(), "bytes.extend_from_slice(&self.0);");
             outln!(out, "}}");
         });
@@ -1403,7 +1806,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out.indented(|out| {
             outln!(
                 out,
-                "fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {{",
+                "fn try_parse_with_order(value: &[u8], _order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {{",
             );
             out.indented(|out| {
                 outln!(out, "let inner: [u8; 32] = value.get(..32)");
@@ -1451,152 +1854,194 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
 
     fn generate_xid_type_def(&self, xid_type_def: &xcbdefs::XidTypeDef, out: &mut Output) {
         let rust_name = self.get_xid_type_rust_name(xid_type_def);
-        outln!(out, "pub type {} = u32;", rust_name);
+        self.emit_xid_newtype(&rust_name, out);
         outln!(out, "");
     }
 
     fn generate_xid_union_def(&self, xid_union_def: &xcbdefs::XidUnionDef, out: &mut Output) {
         let rust_name = self.get_xid_union_rust_name(xid_union_def);
-        outln!(out, "pub type {} = u32;", rust_name);
+        self.emit_xid_newtype(&rust_name, out);
+        for type_ in xid_union_def.types.iter() {
+            let rust_type = self.type_to_rust_type(type_.def.get().unwrap());
+            outln!(out, "impl From<{}> for {} {{", rust_type, rust_name);
+            out.indented(|out| {
+                outln!(out, "fn from(value: {}) -> Self {{", rust_type);
+                outln!(out.indent(), "Self(u32::from(value))");
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+        }
         outln!(out, "");
     }
 
+    /// Emit a newtype wrapper around `u32` for an XID type.
+    fn emit_xid_newtype(&self, rust_name: &str, out: &mut Output) {
+        outln!(
+            out,
+            "#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]"
+        );
+        outln!(out, "pub struct {}(u32);", rust_name);
+        outln!(out, "impl {} {{", rust_name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "/// The XID with value zero, which usually means \"no resource\"."
+            );
+            outln!(out, "pub const NONE: Self = {}(0);", rust_name);
+        });
+        outln!(out, "}}");
+        outln!(out, "impl From<u32> for {} {{", rust_name);
+        out.indented(|out| {
+            outln!(out, "fn from(value: u32) -> Self {{");
+            outln!(out.indent(), "Self(value)");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "impl From<{}> for u32 {{", rust_name);
+        out.indented(|out| {
+            outln!(out, "fn from(value: {}) -> Self {{", rust_name);
+            outln!(out.indent(), "value.0");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "impl TryParse for {} {{", rust_name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {{",
+            );
+            out.indented(|out| {
+                outln!(
+                    out,
+                    "let (value, remaining) = u32::try_parse_with_order(value, order)?;"
+                );
+                outln!(out, "Ok((Self(value), remaining))");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "impl Serialize for {} {{", rust_name);
+        out.indented(|out| {
+            outln!(out, "type Bytes = [u8; 4];");
+            outln!(
+                out,
+                "fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {{"
+            );
+            outln!(out.indent(), "self.0.serialize_with_order(order)");
+            outln!(out, "}}");
+            outln!(
+                out,
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {{"
+            );
+            outln!(
+                out.indent(),
+                "self.0.serialize_into_with_order(bytes, order);"
+            );
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
+    /// Generate the type for an enum.
+    ///
+    /// Enums are newtypes around an integer with a constant for every item. Unlike a Rust enum,
+    /// this can represent values that are not known, e.g. because a newer server sends them.
+    /// Enums with `<bit>` items additionally work as a set of flags.
     fn generate_enum_def(&self, enum_def: &xcbdefs::EnumDef, out: &mut Output) {
         let rust_name = self.get_enum_rust_name(enum_def);
-        let assign_discriminators = !self.enum_has_repeated_values(enum_def);
-
-        // Guess which types this enum can be represented in. We do this based on the
-        // highest value that appears in any of the variants
-        let max_value = enum_def
-            .items
-            .iter()
-            .map(|enum_item| match enum_item.value {
-                xcbdefs::EnumValue::Value(value) => value,
-                xcbdefs::EnumValue::Bit(bit) => 1 << bit,
-            })
-            .max()
-            .unwrap();
-
-        let (to_type, larger_types): (&str, &[&str]) = if max_value <= 0xFF {
-            ("u8", &["u16", "u32"])
-        } else if max_value <= 0xFFFF {
-            ("u16", &["u32"])
-        } else {
-            ("u32", &[])
+        let int_type = self.get_enum_int_type(enum_def);
+        let is_bitmask = enum_has_bits(enum_def);
+        let larger_types: &[&str] = match int_type {
+            "u8" => &["u16", "u32"],
+            "u16" => &["u32"],
+            _ => &[],
         };
 
         if let Some(ref doc) = enum_def.doc {
             self.emit_doc(doc, out);
         }
 
-        outln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]");
-        let needs_lint_allow = enum_def.items.iter().any(|enum_item| {
-            enum_item
-                .name
-                .bytes()
-                .all(|c| !c.is_ascii_lowercase() && !c.is_ascii_digit())
-        });
-        if needs_lint_allow {
-            outln!(out, "#[allow(non_camel_case_types)]");
-        }
-        if assign_discriminators {
-            // Specify the representation if we are assigning discriminators, so we make sure that
-            // all the values fit. This prevents the 'enum_clike_unportable_variant' clippy warning.
-            outln!(out, "#[repr({})]", to_type);
+        if enum_has_default(enum_def) {
+            outln!(out, "#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]");
+        } else {
+            outln!(out, "#[derive(Clone, Copy, PartialEq, Eq, Hash)]");
         }
-        outln!(out, "pub enum {} {{", rust_name);
+        outln!(out, "pub struct {}({});", rust_name, int_type);
+        outln!(out, "#[allow(non_upper_case_globals)]");
+        outln!(out, "impl {} {{", rust_name);
         for enum_item in enum_def.items.iter() {
-            let rust_item_name = ename_to_rust(&enum_item.name);
-            if assign_discriminators {
-                match enum_item.value {
-                    xcbdefs::EnumValue::Value(value) => {
-                        outln!(
-                            out.indent(),
-                            "{} = {},",
-                            rust_item_name,
-                            format_literal_integer(value),
-                        );
-                    }
-                    xcbdefs::EnumValue::Bit(bit) => {
-                        outln!(out.indent(), "{} = 1 << {},", rust_item_name, bit);
-                    }
-                }
-            } else {
-                outln!(out.indent(), "{},", rust_item_name);
-            }
+            let value = match enum_item.value {
+                xcbdefs::EnumValue::Value(value) => format_literal_integer(value),
+                xcbdefs::EnumValue::Bit(bit) => format!("1 << {}", bit),
+            };
+            outln!(
+                out.indent(),
+                "pub const {}: Self = Self({});",
+                ename_to_rust(&enum_item.name),
+                value,
+            );
         }
         outln!(out, "}}");
 
-        if max_value == 1 && enum_def.items.len() == 2 {
+        if is_bitmask {
+            let flags = enum_def
+                .items
+                .iter()
+                .filter_map(|enum_item| match enum_item.value {
+                    xcbdefs::EnumValue::Value(_) => None,
+                    xcbdefs::EnumValue::Bit(_) => Some(ename_to_rust(&enum_item.name)),
+                })
+                .collect::<Vec<_>>();
+            outln!(
+                out,
+                "bitmask_set!({}, {}, [{}]);",
+                rust_name,
+                int_type,
+                flags.join(", "),
+            );
+        } else {
+            let items = enum_def
+                .items
+                .iter()
+                .map(|enum_item| ename_to_rust(&enum_item.name))
+                .collect::<Vec<_>>();
+            outln!(
+                out,
+                "enum_newtype!({}, {}, [{}]);",
+                rust_name,
+                int_type,
+                items.join(", "),
+            );
+        }
+
+        let is_bool_like = enum_def.items.len() == 2
+            && enum_def
+                .items
+                .iter()
+                .all(|enum_item| match enum_item.value {
+                    xcbdefs::EnumValue::Value(0) | xcbdefs::EnumValue::Value(1) => true,
+                    _ => false,
+                });
+        if is_bool_like {
             outln!(out, "impl From<{}> for bool {{", rust_name);
             out.indented(|out| {
                 outln!(out, "fn from(input: {}) -> Self {{", rust_name);
-                out.indented(|out| {
-                    outln!(out, "match input {{");
-                    for enum_item in enum_def.items.iter() {
-                        let bool_value = match enum_item.value {
-                            xcbdefs::EnumValue::Value(0) => "false",
-                            xcbdefs::EnumValue::Value(1) => "true",
-                            _ => unreachable!(),
-                        };
-                        outln!(
-                            out.indent(),
-                            "{}::{} => {},",
-                            rust_name,
-                            ename_to_rust(&enum_item.name),
-                            bool_value,
-                        );
-                    }
-                    outln!(out, "}}");
-                });
+                outln!(out.indent(), "input.0 != 0");
                 outln!(out, "}}");
             });
             outln!(out, "}}");
         }
 
-        outln!(out, "impl From<{}> for {} {{", rust_name, to_type);
-        out.indented(|out| {
-            outln!(out, "fn from(input: {}) -> Self {{", rust_name);
-            out.indented(|out| {
-                outln!(out, "match input {{");
-                for enum_item in enum_def.items.iter() {
-                    let rust_item_name = ename_to_rust(&enum_item.name);
-                    match enum_item.value {
-                        xcbdefs::EnumValue::Value(value) => {
-                            outln!(
-                                out.indent(),
-                                "{}::{} => {},",
-                                rust_name,
-                                rust_item_name,
-                                format_literal_integer(value),
-                            );
-                        }
-                        xcbdefs::EnumValue::Bit(bit) => {
-                            outln!(
-                                out.indent(),
-                                "{}::{} => 1 << {},",
-                                rust_name,
-                                rust_item_name,
-                                bit,
-                            );
-                        }
-                    }
-                }
-                outln!(out, "}}");
-            });
-            outln!(out, "}}");
-        });
-        outln!(out, "}}");
         outln!(
             out,
             "impl From<{}> for {}<{}> {{",
             rust_name,
             self.option_name,
-            to_type
+            int_type,
         );
         out.indented(|out| {
             outln!(out, "fn from(input: {}) -> Self {{", rust_name);
-            outln!(out.indent(), "Some({}::from(input))", to_type);
+            outln!(out.indent(), "Some(input.0)");
             outln!(out, "}}");
         });
         outln!(out, "}}");
@@ -1604,7 +2049,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "impl From<{}> for {} {{", rust_name, larger_type);
             out.indented(|out| {
                 outln!(out, "fn from(input: {}) -> Self {{", rust_name);
-                outln!(out.indent(), "Self::from({}::from(input))", to_type);
+                outln!(out.indent(), "Self::from(input.0)");
                 outln!(out, "}}");
             });
             outln!(out, "}}");
@@ -1617,119 +2062,46 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             );
             out.indented(|out| {
                 outln!(out, "fn from(input: {}) -> Self {{", rust_name);
-                outln!(out.indent(), "Some({}::from(input))", larger_type);
+                outln!(out.indent(), "Some({}::from(input.0))", larger_type);
                 outln!(out, "}}");
             });
             outln!(out, "}}");
         }
 
-        // Values can only be parsed if they are unique.
-        // As a special case, xproto's Gravity enum gets special API.
-        let is_xproto_gravity = is_xproto_gravity(enum_def);
-        if !self.enum_has_repeated_values(enum_def) || is_xproto_gravity {
-            if !is_xproto_gravity {
-                outln!(out, "impl TryFrom<{}> for {} {{", to_type, rust_name);
-            } else {
-                outln!(out, "impl {} {{", rust_name);
-            }
+        self.emit_enum_xid_conversions(enum_def, &rust_name, out);
+
+        outln!(out, "");
+    }
+
+    /// Emit the conversions from an enum into the XID types that it can be used in place of.
+    fn emit_enum_xid_conversions(
+        &self,
+        enum_def: &xcbdefs::EnumDef,
+        rust_name: &str,
+        out: &mut Output,
+    ) {
+        for xid_type in self.get_enum_xid_types(enum_def).iter() {
+            outln!(out, "impl From<{}> for {} {{", rust_name, xid_type);
             out.indented(|out| {
-                if !is_xproto_gravity {
-                    outln!(out, "type Error = ParseError;");
-                    outln!(
-                        out,
-                        "fn try_from(value: {}) -> Result<Self, Self::Error> {{",
-                        to_type,
-                    );
-                } else {
-                    let largest_type = larger_types.last().unwrap();
-                    outln!(
-                        out,
-                        "fn try_from(value: impl Into<{}>, value_for_zero: Self) -> Result<Self, ParseError> {{",
-                        largest_type,
-                    );
-                    outln!(out.indent(), "let value = value.into();");
-                }
-                out.indented(|out| {
-                    outln!(out, "match value {{");
-                    for enum_item in enum_def.items.iter() {
-                        let rust_item_name = ename_to_rust(&enum_item.name);
-                        match enum_item.value {
-                            xcbdefs::EnumValue::Value(0) if is_xproto_gravity => {
-                                if enum_item.name == "BitForget" {
-                                    outln!(out.indent(), "0 => Ok(value_for_zero),");
-                                }
-                            }
-                            xcbdefs::EnumValue::Value(value) => {
-                                outln!(
-                                    out.indent(),
-                                    "{} => Ok({}::{}),",
-                                    format_literal_integer(value),
-                                    rust_name,
-                                    rust_item_name,
-                                );
-                            }
-                            xcbdefs::EnumValue::Bit(bit) => {
-                                outln!(
-                                    out.indent(),
-                                    "{} => Ok({}::{}),",
-                                    format_literal_integer(1u32 << bit),
-                                    rust_name,
-                                    rust_item_name,
-                                );
-                            }
-                        }
-                    }
-                    outln!(out.indent(), "_ => Err(ParseError::ParseError),");
-                    outln!(out, "}}");
-                });
+                outln!(out, "fn from(input: {}) -> Self {{", rust_name);
+                outln!(out.indent(), "Self::from(u32::from(input))");
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+            outln!(
+                out,
+                "impl From<{}> for {}<{}> {{",
+                rust_name,
+                self.option_name,
+                xid_type,
+            );
+            out.indented(|out| {
+                outln!(out, "fn from(input: {}) -> Self {{", rust_name);
+                outln!(out.indent(), "Some({}::from(input))", xid_type);
                 outln!(out, "}}");
             });
             outln!(out, "}}");
-
-            if !is_xproto_gravity {
-                for larger_type in larger_types.iter() {
-                    outln!(out, "impl TryFrom<{}> for {} {{", larger_type, rust_name);
-                    out.indented(|out| {
-                        outln!(out, "type Error = ParseError;");
-                        outln!(
-                            out,
-                            "fn try_from(value: {}) -> Result<Self, Self::Error> {{",
-                            larger_type,
-                        );
-                        outln!(
-                            out.indent(),
-                            "Self::try_from({}::try_from(value).or(Err(ParseError::ParseError))?)",
-                            to_type,
-                        );
-                        outln!(out, "}}");
-                    });
-                    outln!(out, "}}");
-                }
-            }
-        }
-
-        // An enum is ok for bitmask if all its values are <bit>
-        // or have value zero (but not if all values are zero)
-        let ok_for_bitmask = enum_def
-            .items
-            .iter()
-            .all(|enum_item| match enum_item.value {
-                xcbdefs::EnumValue::Value(0) | xcbdefs::EnumValue::Bit(_) => true,
-                _ => false,
-            })
-            && enum_def
-                .items
-                .iter()
-                .any(|enum_item| match enum_item.value {
-                    xcbdefs::EnumValue::Value(_) => false,
-                    xcbdefs::EnumValue::Bit(_) => true,
-                });
-
-        if ok_for_bitmask {
-            outln!(out, "bitmask_binop!({}, {});", rust_name, to_type);
         }
-
-        outln!(out, "");
     }
 
     fn generate_type_alias_def(&self, type_alias_def: &xcbdefs::TypeAliasDef, out: &mut Output) {
@@ -1848,11 +2220,11 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         if generate_try_parse {
             if has_fds {
                 assert!(external_params.is_empty());
-                outln!(out, "impl {} {{", name);
+                outln!(out, "impl TryParseFd for {} {{", name);
                 outln!(
                     out.indent(),
-                    "fn try_parse_fd<'a>({}) -> Result<(Self, &'a [u8]), ParseError> {{",
-                    "remaining: &'a [u8], fds: &mut Vec<RawFdContainer>",
+                    "fn try_parse_fd_with_order<'a>({}) -> Result<(Self, &'a [u8]), ParseError> {{",
+                    "remaining: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder",
                 );
             } else if !external_params.is_empty() {
                 outln!(out, "impl {} {{", name);
@@ -1862,13 +2234,13 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         format!(
                             "{}: {}",
                             to_rust_variable_name(&ext_param.name),
-                            self.type_to_rust_type(&ext_param.type_),
+                            self.ext_param_to_rust_type(ext_param),
                         )
                     })
                     .collect::<Vec<_>>();
                 outln!(
                     out.indent(),
-                    "pub fn try_parse(remaining: &[u8], {}) \
+                    "pub fn try_parse_with_order(remaining: &[u8], order: ByteOrder, {}) \
                     -> Result<(Self, &[u8]), ParseError> {{",
                     p.join(", "),
                 );
@@ -1876,7 +2248,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 outln!(out, "impl TryParse for {} {{", name);
                 outln!(
                     out.indent(),
-                    "fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {{",
+                    "fn try_parse_with_order(remaining: &[u8], {}: ByteOrder) -> Result<(Self, &[u8]), ParseError> {{",
+                    self.byte_order_param_name(fields),
                 );
             }
 
@@ -1989,6 +2362,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out: &mut Output,
     ) {
         let ext_params_arg_defs = self.ext_params_to_arg_defs(true, external_params);
+        let order_name = self.byte_order_param_name(fields);
 
         if external_params.is_empty() {
             outln!(out, "impl Serialize for {} {{", name);
@@ -2002,7 +2376,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             }
             outln!(
                 out,
-                "fn serialize(&self{}) -> [u8; {}] {{",
+                "fn serialize_with_order(&self, {}: ByteOrder{}) -> [u8; {}] {{",
+                order_name,
                 ext_params_arg_defs,
                 size,
             );
@@ -2030,8 +2405,9 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
             outln!(
                 out,
-                "fn serialize_into(&self, bytes: &mut Vec<u8>{}) {{",
-                ext_params_arg_defs
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, {}: ByteOrder{}) {{",
+                order_name,
+                ext_params_arg_defs,
             );
             out.indented(|out| {
                 outln!(out, "bytes.reserve({});", size);
@@ -2072,14 +2448,14 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             }
             outln!(
                 out,
-                "fn serialize(&self{}) -> Vec<u8> {{",
+                "fn serialize_with_order(&self, order: ByteOrder{}) -> Vec<u8> {{",
                 ext_params_arg_defs,
             );
             out.indented(|out| {
                 outln!(out, "let mut result = Vec::new();");
                 outln!(
                     out,
-                    "self.serialize_into(&mut result{});",
+                    "self.serialize_into_with_order(&mut result, order{});",
                     ext_params_call_args,
                 );
                 outln!(out, "result");
@@ -2087,7 +2463,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
             outln!(
                 out,
-                "fn serialize_into(&self, bytes: &mut Vec<u8>{}) {{",
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder{}) {{",
                 ext_params_arg_defs
             );
             out.indented(|out| {
@@ -2109,6 +2485,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 if fixed_part_size != 0 && num_fixed_fields != 1 {
                     outln!(out, "bytes.reserve({});", fixed_part_size);
                 }
+                self.emit_switch_ext_params_bindings(fields, deducible_fields, out);
 
                 for field in fields.iter() {
                     if skip_length_field && field.name() == Some("length") {
@@ -2122,6 +2499,46 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "}}");
     }
 
+    /// Emit local variables for the fields of `self` that a `<switch>`
+    /// or a list element in `fields` needs as external parameters.
+    ///
+    /// `emit_field_serialize_into` passes external parameters by their
+    /// bare name, which only works for deducible fields otherwise.
+    fn emit_switch_ext_params_bindings(
+        &self,
+        fields: &[xcbdefs::FieldDef],
+        deducible_fields: &FxHashMap<String, DeducibleField>,
+        out: &mut Output,
+    ) {
+        let mut ext_param_names = Vec::new();
+        for field in fields.iter() {
+            match field {
+                xcbdefs::FieldDef::Switch(switch_field) => {
+                    for ext_param in switch_field.external_params.borrow().iter() {
+                        ext_param_names.push(ext_param.name.clone());
+                    }
+                }
+                xcbdefs::FieldDef::List(list_field) => {
+                    let element_type = list_field.element_type.type_.def.get().unwrap();
+                    ext_param_names.extend(self.get_type_ext_param_names(element_type));
+                }
+                _ => {}
+            }
+        }
+        let mut emitted = Vec::new();
+        for ext_param_name in ext_param_names {
+            let is_visible_field = fields.iter().any(|field| {
+                field.name() == Some(ext_param_name.as_str())
+                    && self.field_is_visible(field, deducible_fields)
+            });
+            if is_visible_field && !emitted.contains(&ext_param_name) {
+                let rust_name = to_rust_variable_name(&ext_param_name);
+                outln!(out, "let {} = self.{};", rust_name, rust_name);
+                emitted.push(ext_param_name);
+            }
+        }
+    }
+
     fn emit_switch_type(
         &self,
         switch: &xcbdefs::SwitchField,
@@ -2454,20 +2871,20 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     format!(
                         "{}: {}",
                         to_rust_variable_name(&ext_param.name),
-                        self.type_to_rust_type(&ext_param.type_),
+                        self.ext_param_to_rust_type(ext_param),
                     )
                 })
                 .collect::<Vec<_>>();
             outln!(
                 out.indent(),
-                "fn try_parse(value: &[u8], {}) -> Result<(Self, &[u8]), ParseError> {{",
+                "fn try_parse_with_order(value: &[u8], order: ByteOrder, {}) -> Result<(Self, &[u8]), ParseError> {{",
                 p.join(", "),
             );
         } else {
             outln!(out, "impl TryParse for {} {{", name);
             outln!(
                 out.indent(),
-                "fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {{",
+                "fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {{",
             );
         }
 
@@ -2534,12 +2951,13 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                 CaseInfo::MultiField(_, struct_name) => {
                                     let mut parse_params = Vec::new();
                                     parse_params.push(String::from("outer_remaining"));
+                                    parse_params.push(String::from("order"));
                                     for p in case.external_params.borrow().iter() {
                                         parse_params.push(to_rust_variable_name(&p.name));
                                     }
                                     outln!(
                                         out,
-                                        "let ({}, new_remaining) = {}::try_parse({})?;",
+                                        "let ({}, new_remaining) = {}::try_parse_with_order({})?;",
                                         rust_case_name,
                                         struct_name,
                                         parse_params.join(", "),
@@ -2620,12 +3038,13 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                 CaseInfo::MultiField(_, struct_name) => {
                                     let mut parse_params = Vec::new();
                                     parse_params.push(String::from("outer_remaining"));
+                                    parse_params.push(String::from("order"));
                                     for p in case.external_params.borrow().iter() {
                                         parse_params.push(to_rust_variable_name(&p.name));
                                     }
                                     outln!(
                                         out,
-                                        "let ({}, new_remaining) = {}::try_parse({})?;",
+                                        "let ({}, new_remaining) = {}::try_parse_with_order({})?;",
                                         rust_case_var_name,
                                         struct_name,
                                         parse_params.join(", "),
@@ -2688,7 +3107,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             }
             outln!(
                 out,
-                "fn serialize(&self{}) -> [u8; {}] {{",
+                "fn serialize_with_order(&self, order: ByteOrder{}) -> [u8; {}] {{",
                 ext_params_arg_defs,
                 size,
             );
@@ -2733,7 +3152,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                 let rust_field_name = to_rust_variable_name(field_name);
                                 outln!(
                                     out,
-                                    "{}::{}({}) => {}.serialize(),",
+                                    "{}::{}({}) => {}.serialize_with_order(order),",
                                     name,
                                     to_rust_type_name(field_name),
                                     rust_field_name,
@@ -2748,7 +3167,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
             outln!(
                 out,
-                "fn serialize_into(&self, bytes: &mut Vec<u8>{}) {{",
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder{}) {{",
                 ext_params_arg_defs
             );
             out.indented(|out| {
@@ -2790,7 +3209,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                             let rust_field_name = to_rust_variable_name(field_name);
                             outln!(
                                 out,
-                                "{}::{}(ref {}) => {}.serialize_into(bytes),",
+                                "{}::{}(ref {}) => {}.serialize_into_with_order(bytes, order),",
                                 name,
                                 to_rust_type_name(field_name),
                                 rust_field_name,
@@ -2831,14 +3250,14 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             }
             outln!(
                 out,
-                "fn serialize(&self{}) -> Vec<u8> {{",
+                "fn serialize_with_order(&self, order: ByteOrder{}) -> Vec<u8> {{",
                 ext_params_arg_defs,
             );
             out.indented(|out| {
                 outln!(out, "let mut result = Vec::new();");
                 outln!(
                     out,
-                    "self.serialize_into(&mut result{});",
+                    "self.serialize_into_with_order(&mut result, order{});",
                     ext_params_call_args,
                 );
                 outln!(out, "result");
@@ -2846,7 +3265,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
             outln!(
                 out,
-                "fn serialize_into(&self, bytes: &mut Vec<u8>{}) {{",
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder{}) {{",
                 ext_params_arg_defs
             );
             out.indented(|out| {
@@ -2918,7 +3337,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                 );
                                 outln!(
                                     out.indent(),
-                                    "{}.serialize_into(bytes{});",
+                                    "{}.serialize_into_with_order(bytes, order{});",
                                     rust_field_name,
                                     ext_params_call_args,
                                 );
@@ -2979,7 +3398,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                     let rust_field_name = to_rust_variable_name(field_name);
                                     outln!(
                                         out,
-                                        "{}::{}({}) => {}.serialize_into(bytes),",
+                                        "{}::{}({}) => {}.serialize_into_with_order(bytes, order),",
                                         name,
                                         to_rust_type_name(field_name),
                                         rust_field_name,
@@ -3101,8 +3520,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     outln!(
                         out,
                         "let ({}, remaining) = crate::x11_utils\
-                        ::parse_list::<{}>(remaining, \
-                        {}.try_into().or(Err(ParseError::ParseError))?)?;",
+                        ::parse_list_with_order::<{}>(remaining, \
+                        {}.try_into().or(Err(ParseError::ParseError))?, order)?;",
                         rust_field_name,
                         rust_element_type,
                         self.expr_to_str(
@@ -3175,12 +3594,14 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     format!("{}{}", switch_prefix, to_rust_type_name(&switch_field.name));
                 let mut parse_params = Vec::new();
                 parse_params.push(String::from("remaining"));
+                parse_params.push(String::from("order"));
+                // Fields are converted after parsing, so they still hold the raw value.
                 for ext_param in switch_field.external_params.borrow().iter() {
-                    parse_params.push(to_rust_variable_name(&ext_param.name));
+                    parse_params.push(self.ext_param_from_raw(ext_param));
                 }
                 outln!(
                     out,
-                    "let ({}, remaining) = {}::try_parse({})?;",
+                    "let ({}, remaining) = {}::try_parse_with_order({})?;",
                     rust_field_name,
                     switch_struct_name,
                     parse_params.join(", "),
@@ -3235,7 +3656,11 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         let type_type = type_.type_.def.get().unwrap();
         let rust_type = self.type_to_rust_type(type_type);
         let params = self.get_type_parse_params(type_type, "remaining");
-        format!("{}::try_parse({})?", rust_type, params.join(", "))
+        format!(
+            "{}::try_parse_with_order({})?",
+            rust_type,
+            params.join(", ")
+        )
     }
 
     fn emit_value_post_parse(
@@ -3244,48 +3669,24 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         var_name: &str,
         out: &mut Output,
     ) {
-        if let xcbdefs::FieldValueSet::Enum(ref enum_) = type_.value_set {
-            // Handle turning things into enum instances where necessary.
-            let enum_def = match enum_.def.get().unwrap() {
-                xcbdefs::TypeRef::Enum(enum_def) => enum_def.upgrade().unwrap(),
-                _ => unreachable!(),
-            };
-            if !self.enum_has_repeated_values(&enum_def) {
-                outln!(out, "let {} = {}.try_into()?;", var_name, var_name);
-            }
-            if is_xproto_gravity(&enum_def) {
-                let zero_type = match var_name {
-                    "bit_gravity" => "Gravity::BitForget",
-                    "win_gravity" => "Gravity::WinUnmap",
-                    _ => unreachable!(),
-                };
-                outln!(
-                    out,
-                    "let {} = Gravity::try_from({}, {})?;",
-                    var_name,
-                    var_name,
-                    zero_type,
-                );
-            }
+        // Handle turning things into enum instances where necessary.
+        if self.use_enum_type_in_field(type_).is_some() {
+            outln!(
+                out,
+                "let {} = {};",
+                var_name,
+                self.wire_type_to_enum(type_.type_.def.get().unwrap(), &type_.value_set, var_name),
+            );
         }
     }
 
     fn needs_post_parse(&self, type_: &xcbdefs::FieldValueType) -> bool {
-        if let xcbdefs::FieldValueSet::Enum(ref enum_) = type_.value_set {
-            let enum_def = match enum_.def.get().unwrap() {
-                xcbdefs::TypeRef::Enum(enum_def) => enum_def.upgrade().unwrap(),
-                _ => unreachable!(),
-            };
-            !self.enum_has_repeated_values(&enum_def)
-        } else {
-            false
-        }
+        self.use_enum_type_in_field(type_).is_some()
     }
 
     fn can_use_simple_list_parsing(&self, type_: &xcbdefs::FieldValueType) -> bool {
-        self.get_type_parse_params(&type_.type_.def.get().unwrap(), "")
-            .len()
-            == 1
+        self.get_type_ext_param_names(&type_.type_.def.get().unwrap())
+            .is_empty()
             && !self.needs_post_parse(type_)
     }
 
@@ -3383,7 +3784,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 let bytes_name = postfix_var_name(&rust_field_name, "bytes");
                 outln!(
                     out,
-                    "let {} = {}{}.serialize();",
+                    "let {} = {}{}.serialize_with_order(order);",
                     bytes_name,
                     obj_prefix,
                     rust_field_name,
@@ -3466,7 +3867,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 } else if self.can_use_simple_list_parsing(&list_field.element_type) {
                     outln!(
                         out,
-                        "{}{}.serialize_into({});",
+                        "{}{}.serialize_into_with_order({}, order);",
                         obj_name,
                         rust_field_name,
                         bytes_name
@@ -3479,36 +3880,62 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         rust_field_name
                     );
                     out.indented(|out| {
-                        self.emit_value_serialize_into(
-                            &list_field.element_type,
-                            "element",
-                            false,
-                            "bytes",
-                            out,
-                        );
+                        let element_type = &list_field.element_type;
+                        let ext_params =
+                            self.get_type_ext_param_names(element_type.type_.def.get().unwrap());
+                        if !ext_params.is_empty() {
+                            let args = ext_params
+                                .iter()
+                                .map(|name| to_rust_variable_name(name))
+                                .collect::<Vec<_>>();
+                            outln!(
+                                out,
+                                "element.serialize_into_with_order({}, order, {});",
+                                bytes_name,
+                                args.join(", "),
+                            );
+                        } else if self.use_enum_type_in_field(element_type).is_some() {
+                            self.emit_value_serialize_into(
+                                element_type,
+                                "*element",
+                                false,
+                                bytes_name,
+                                out,
+                            );
+                        } else {
+                            self.emit_value_serialize_into(
+                                element_type,
+                                "element",
+                                false,
+                                bytes_name,
+                                out,
+                            );
+                        }
                     });
                     outln!(out, "}}");
                 }
             }
             xcbdefs::FieldDef::Switch(switch_field) => {
                 let rust_field_name = to_rust_variable_name(&switch_field.name);
-                let ext_params_args = self.ext_params_to_call_args(
-                    true,
-                    to_rust_variable_name,
+                let ext_params_args = self.ext_params_to_serialize_call_args(
                     &*switch_field.external_params.borrow(),
+                    deducible_fields,
                 );
                 outln!(
                     out,
-                    "{}{}.serialize_into({}{});",
+                    "{}{}.serialize_into_with_order({}, order{});",
                     obj_name,
                     rust_field_name,
                     bytes_name,
                     ext_params_args,
                 );
             }
-            // the reamining field types are only used in request and replies,
-            // which do not implement serialize
-            _ => unreachable!(),
+            // FDs are not part of the serialized bytes
+            xcbdefs::FieldDef::Fd(_) | xcbdefs::FieldDef::FdList(_) => {}
+            xcbdefs::FieldDef::VirtualLen(_) => {}
+            // <exprfield>s are only used in requests, which do not implement
+            // serialize
+            xcbdefs::FieldDef::Expr(_) => unreachable!(),
         }
     }
 
@@ -3621,8 +4048,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     DeducibleField::CaseSwitchExpr(switch_name) => *switch_name == switch.name,
                     DeducibleField::BitCaseSwitchExpr(switch_name) => *switch_name == switch.name,
                 });
+        // `switch_expr()` only exists when every case has a single expression
+        let has_switch_expr_fn = switch.cases.iter().all(|case| case.exprs.len() == 1);
 
-        if needs_expr_assert {
+        if needs_expr_assert && has_switch_expr_fn {
             let rust_field_name = to_rust_variable_name(&switch.name);
             let switch_expr_str =
                 self.expr_to_str(&switch.expr, to_rust_variable_name, true, true, false);
@@ -3644,10 +4073,12 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
     ) -> String {
         // Deduced fields are not converter to their enum value
         if !was_deduced && self.use_enum_type_in_field(type_).is_some() {
-            let rust_wire_type = self.type_to_rust_type(type_.type_.def.get().unwrap());
-            format!("{}::from({}).serialize()", rust_wire_type, value)
+            format!(
+                "{}.serialize_with_order(order)",
+                self.enum_to_wire_type(type_.type_.def.get().unwrap(), &type_.value_set, value),
+            )
         } else {
-            format!("{}.serialize()", value)
+            format!("{}.serialize_with_order(order)", value)
         }
     }
 
@@ -3661,16 +4092,19 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
     ) {
         // Deduced fields are not converter to their enum value
         if !was_deduced && self.use_enum_type_in_field(type_).is_some() {
-            let rust_wire_type = self.type_to_rust_type(type_.type_.def.get().unwrap());
             outln!(
                 out,
-                "{}::from({}).serialize_into({});",
-                rust_wire_type,
-                value,
+                "{}.serialize_into_with_order({}, order);",
+                self.enum_to_wire_type(type_.type_.def.get().unwrap(), &type_.value_set, value),
                 bytes_var
             );
         } else {
-            outln!(out, "{}.serialize_into({});", value, bytes_var);
+            outln!(
+                out,
+                "{}.serialize_into_with_order({}, order);",
+                value,
+                bytes_var
+            );
         }
     }
 
@@ -3755,6 +4189,80 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
     }
 
+    /// Get the name for the byte order parameter of code that handles the given fields.
+    ///
+    /// Structs that only consist of padding and bytes do not depend on the byte order.
+    fn byte_order_param_name(&self, fields: &[xcbdefs::FieldDef]) -> &'static str {
+        let needs_order = fields.iter().any(|field| match field {
+            xcbdefs::FieldDef::Pad(_) => false,
+            xcbdefs::FieldDef::List(list_field) => {
+                !self.rust_value_type_is_u8(&list_field.element_type)
+            }
+            _ => true,
+        });
+        if needs_order {
+            "order"
+        } else {
+            "_order"
+        }
+    }
+
+    /// Get the XID types that the values of an enum can be used in place of.
+    ///
+    /// Requests accept the values of an `altenum` instead of a field's XID type, so
+    /// conversions from the enum into these types are needed. Only requests of the namespace
+    /// that defines the enum are considered.
+    fn get_enum_xid_types(&self, enum_def: &xcbdefs::EnumDef) -> Vec<String> {
+        fn gather(
+            generator: &NamespaceGenerator<'_, '_>,
+            enum_def: &xcbdefs::EnumDef,
+            fields: &[xcbdefs::FieldDef],
+            result: &mut Vec<String>,
+        ) {
+            for field in fields.iter() {
+                match field {
+                    xcbdefs::FieldDef::Normal(normal_field) => {
+                        let enum_ref = match normal_field.type_.value_set {
+                            xcbdefs::FieldValueSet::AltEnum(ref enum_ref) => enum_ref,
+                            _ => continue,
+                        };
+                        let is_this_enum = match enum_ref.def.get().unwrap() {
+                            xcbdefs::TypeRef::Enum(def) => {
+                                std::ptr::eq(&*def.upgrade().unwrap(), enum_def)
+                            }
+                            _ => false,
+                        };
+                        let type_ = normal_field.type_.type_.def.get().unwrap();
+                        let is_xid = match type_ {
+                            xcbdefs::TypeRef::Xid(_) | xcbdefs::TypeRef::XidUnion(_) => true,
+                            _ => false,
+                        };
+                        if is_this_enum && is_xid {
+                            let rust_type = generator.type_to_rust_type(type_);
+                            if !result.contains(&rust_type) {
+                                result.push(rust_type);
+                            }
+                        }
+                    }
+                    xcbdefs::FieldDef::Switch(switch_field) => {
+                        for case in switch_field.cases.iter() {
+                            gather(generator, enum_def, &case.fields.borrow(), result);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut result = Vec::new();
+        for def in self.ns.src_order_defs.borrow().iter() {
+            if let xcbdefs::Def::Request(request_def) = def {
+                gather(self, enum_def, &request_def.fields.borrow(), &mut result);
+            }
+        }
+        result
+    }
+
     fn rust_value_type_is_u8(&self, type_: &xcbdefs::FieldValueType) -> bool {
         if self.use_enum_type_in_field(type_).is_some() {
             false
@@ -3779,23 +4287,94 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         &self,
         type_: &xcbdefs::FieldValueType,
     ) -> Option<Rc<xcbdefs::EnumDef>> {
-        if let xcbdefs::FieldValueSet::Enum(ref enum_) = type_.value_set {
-            let enum_def = match enum_.def.get().unwrap() {
-                xcbdefs::TypeRef::Enum(enum_def) => enum_def.upgrade().unwrap(),
-                _ => unreachable!(),
-            };
-            if !self.enum_has_repeated_values(&enum_def) || is_xproto_gravity(&enum_def) {
-                // The field can only have the values from the enum,
-                // use its type.
-                Some(enum_def)
-            } else {
-                None
-            }
+        self.use_enum_type(type_.type_.def.get().unwrap(), &type_.value_set)
+    }
+
+    /// Check whether a value of type `type_` with the given `value_set` is represented with
+    /// the type of the enum of the value set and return the enum if so.
+    fn use_enum_type(
+        &self,
+        type_: &xcbdefs::TypeRef,
+        value_set: &xcbdefs::FieldValueSet,
+    ) -> Option<Rc<xcbdefs::EnumDef>> {
+        let (enum_, is_mask) = match value_set {
+            xcbdefs::FieldValueSet::Enum(enum_) => (enum_, false),
+            xcbdefs::FieldValueSet::Mask(enum_) => (enum_, true),
+            _ => return None,
+        };
+        let enum_def = match enum_.def.get().unwrap() {
+            xcbdefs::TypeRef::Enum(enum_def) => enum_def.upgrade().unwrap(),
+            _ => unreachable!(),
+        };
+        // Only masks of bits can be represented by the enum type. The enum type can hold
+        // any value of fields that are not larger than its integer type.
+        let wire_type = match get_wire_int_type(type_) {
+            Some(wire_type) => wire_type,
+            None => return None,
+        };
+        if (!is_mask || enum_has_bits(&enum_def))
+            && int_type_bits(wire_type) <= int_type_bits(self.get_enum_int_type(&enum_def))
+        {
+            Some(enum_def)
         } else {
             None
         }
     }
 
+    /// Returns an expression that converts `value`, which has the integer type that is sent on
+    /// the wire for `type_`, into the enum type that is used for `type_` with `value_set`.
+    fn wire_type_to_enum(
+        &self,
+        type_: &xcbdefs::TypeRef,
+        value_set: &xcbdefs::FieldValueSet,
+        value: &str,
+    ) -> String {
+        let enum_def = self.use_enum_type(type_, value_set).unwrap();
+        let ns = enum_def.namespace.upgrade().unwrap();
+        let rust_enum_type = self.type_name_to_rust_type(&self.get_enum_rust_name(&enum_def), &ns);
+        let int_type = self.get_enum_int_type(&enum_def);
+        if Some(int_type) == get_wire_int_type(type_) {
+            format!("{}::from({})", rust_enum_type, value)
+        } else {
+            format!("{}::from({}::from({}))", rust_enum_type, int_type, value)
+        }
+    }
+
+    /// Returns an expression that converts `value`, an instance of the enum type that is used
+    /// for `type_` with `value_set`, into the integer type that is sent on the wire.
+    ///
+    /// The enum type can be larger than the field. Values that do not fit into the field
+    /// cause a panic.
+    fn enum_to_wire_type(
+        &self,
+        type_: &xcbdefs::TypeRef,
+        value_set: &xcbdefs::FieldValueSet,
+        value: &str,
+    ) -> String {
+        let enum_def = self.use_enum_type(type_, value_set).unwrap();
+        let int_type = self.get_enum_int_type(&enum_def);
+        let wire_type = get_wire_int_type(type_).unwrap();
+        if int_type == wire_type {
+            format!("{}::from({})", wire_type, value)
+        } else {
+            format!(
+                "{}::try_from({}::from({})).unwrap()",
+                wire_type, int_type, value
+            )
+        }
+    }
+
+    /// Get the enum type that is used for a value of type `type_` with the given `value_set`.
+    fn get_enum_rust_type(
+        &self,
+        type_: &xcbdefs::TypeRef,
+        value_set: &xcbdefs::FieldValueSet,
+    ) -> Option<String> {
+        let enum_def = self.use_enum_type(type_, value_set)?;
+        let ns = enum_def.namespace.upgrade().unwrap();
+        Some(self.type_name_to_rust_type(&self.get_enum_rust_name(&enum_def), &ns))
+    }
+
     fn emit_doc(&self, doc: &xcbdefs::Doc, out: &mut Output) {
         let mut has_doc = false;
         if let Some(ref brief) = doc.brief {
@@ -4052,8 +4631,21 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card32) => true,
                     _ => false,
                 };
-                if cast_to_u32 && !field_is_card32 {
+                let field_is_enum = self
+                    .get_enum_rust_type(
+                        &resolved_field_ref.field_type,
+                        &resolved_field_ref.value_set,
+                    )
+                    .is_some();
+                if cast_to_u32 && (!field_is_card32 || field_is_enum) {
                     format!("u32::from({})", value)
+                } else if field_is_enum {
+                    // Turn the enum back into its raw value
+                    self.enum_to_wire_type(
+                        &resolved_field_ref.field_type,
+                        &resolved_field_ref.value_set,
+                        &value,
+                    )
                 } else {
                     value
                 }
@@ -4249,29 +4841,101 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         self.get_normal_type_name(id, &type_alias_def.new_name)
     }
 
-    /// Returns whether `enum_def` has repeated values.
-    fn enum_has_repeated_values(&self, enum_def: &xcbdefs::EnumDef) -> bool {
-        let id = enum_def as *const xcbdefs::EnumDef as usize;
-        match self.caches.borrow_mut().enum_has_repeated_values.entry(id) {
-            HashMapEntry::Occupied(entry) => *entry.get(),
-            HashMapEntry::Vacant(entry) => {
-                let mut value_set = FxHashSet::default();
-                let mut has_repeated = false;
-                for enum_item in enum_def.items.iter() {
-                    let value = match enum_item.value {
-                        xcbdefs::EnumValue::Value(v) => v,
-                        xcbdefs::EnumValue::Bit(bit) => 1 << bit,
-                    };
-                    if !value_set.insert(value) {
-                        // value already present
-                        has_repeated = true;
-                        break;
+    /// Get the integer type that the type of an enum wraps.
+    ///
+    /// The type is large enough for all values of the enum and for all fields
+    /// in the namespace of the enum that refer to it with `enum` or `mask`.
+    fn get_enum_int_type(&self, enum_def: &xcbdefs::EnumDef) -> &'static str {
+        fn gather(
+            generator: &NamespaceGenerator<'_, '_>,
+            enum_def: &xcbdefs::EnumDef,
+            fields: &[xcbdefs::FieldDef],
+            max_bits: &mut u32,
+        ) {
+            for field in fields.iter() {
+                let value_type = match field {
+                    xcbdefs::FieldDef::Normal(normal_field) => &normal_field.type_,
+                    xcbdefs::FieldDef::List(list_field) => &list_field.element_type,
+                    xcbdefs::FieldDef::Switch(switch_field) => {
+                        for case in switch_field.cases.iter() {
+                            gather(generator, enum_def, &case.fields.borrow(), max_bits);
+                        }
+                        continue;
                     }
+                    _ => continue,
+                };
+                let enum_ref = match value_type.value_set {
+                    xcbdefs::FieldValueSet::Enum(ref enum_ref)
+                    | xcbdefs::FieldValueSet::Mask(ref enum_ref) => enum_ref,
+                    _ => continue,
+                };
+                let is_this_enum = match enum_ref.def.get().unwrap() {
+                    xcbdefs::TypeRef::Enum(def) => std::ptr::eq(&*def.upgrade().unwrap(), enum_def),
+                    _ => false,
+                };
+                if is_this_enum {
+                    let bits = get_wire_int_type(value_type.type_.def.get().unwrap())
+                        .map_or(0, int_type_bits);
+                    *max_bits = std::cmp::max(*max_bits, bits);
                 }
-                entry.insert(has_repeated);
-                has_repeated
             }
         }
+
+        let id = enum_def as *const xcbdefs::EnumDef as usize;
+        if let Some(int_type) = self.caches.borrow().enum_int_types.get(&id) {
+            return int_type;
+        }
+
+        let int_type = {
+            let max_value = enum_def
+                .items
+                .iter()
+                .map(|enum_item| match enum_item.value {
+                    xcbdefs::EnumValue::Value(value) => value,
+                    xcbdefs::EnumValue::Bit(bit) => 1 << bit,
+                })
+                .max()
+                .unwrap();
+            let mut max_bits = if max_value <= 0xFF {
+                8
+            } else if max_value <= 0xFFFF {
+                16
+            } else {
+                32
+            };
+
+            let ns = enum_def.namespace.upgrade().unwrap();
+            for def in ns.src_order_defs.borrow().iter() {
+                match def {
+                    xcbdefs::Def::Request(request_def) => {
+                        gather(self, enum_def, &request_def.fields.borrow(), &mut max_bits);
+                        if let Some(ref reply_def) = request_def.reply {
+                            gather(self, enum_def, &reply_def.fields.borrow(), &mut max_bits);
+                        }
+                    }
+                    xcbdefs::Def::Event(xcbdefs::EventDef::Full(event_def)) => {
+                        gather(self, enum_def, &event_def.fields.borrow(), &mut max_bits);
+                    }
+                    xcbdefs::Def::Error(xcbdefs::ErrorDef::Full(error_def)) => {
+                        gather(self, enum_def, &error_def.fields.borrow(), &mut max_bits);
+                    }
+                    xcbdefs::Def::Type(xcbdefs::TypeDef::Struct(struct_def)) => {
+                        gather(self, enum_def, &struct_def.fields.borrow(), &mut max_bits);
+                    }
+                    xcbdefs::Def::Type(xcbdefs::TypeDef::Union(union_def)) => {
+                        gather(self, enum_def, &union_def.fields, &mut max_bits);
+                    }
+                    _ => {}
+                }
+            }
+            match max_bits {
+                8 => "u8",
+                16 => "u16",
+                _ => "u32",
+            }
+        };
+        let _ = self.caches.borrow_mut().enum_int_types.insert(id, int_type);
+        int_type
     }
 
     /// Gathers information about the fields of a request,
@@ -4309,18 +4973,21 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 xcbdefs::FieldDef::Normal(normal_field) => {
                     let rust_field_name = to_rust_variable_name(&normal_field.name);
                     let rust_field_type = self.field_value_type_to_rust_type(&normal_field.type_);
+                    let is_xid_union = match normal_field.type_.type_.def.get().unwrap() {
+                        xcbdefs::TypeRef::XidUnion(_) => true,
+                        _ => false,
+                    };
                     let use_into = if ((is_change_propery || is_get_propery)
                         && normal_field.name == "property")
                         || (is_change_propery && normal_field.name == "type")
+                        || is_xid_union
                     {
                         true
-                    } else if self.use_enum_type_in_field(&normal_field.type_).is_none() {
+                    } else {
                         match normal_field.type_.value_set {
                             xcbdefs::FieldValueSet::None => false,
                             _ => true,
                         }
-                    } else {
-                        false
                     };
 
                     if use_into {
@@ -4419,7 +5086,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             }
             s.push_str(&to_rust_variable_name(&ext_param.name));
             s.push_str(": ");
-            s.push_str(&self.type_to_rust_type(&ext_param.type_))
+            s.push_str(&self.ext_param_to_rust_type(ext_param))
         }
         s
     }
@@ -4440,6 +5107,47 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         s
     }
 
+    /// Like `ext_params_to_call_args`, but for the local variables of a
+    /// serialization function. Deduced fields are plain integers, so they
+    /// are converted to the set type of the parameter if it has one.
+    fn ext_params_to_serialize_call_args(
+        &self,
+        ext_params: &[xcbdefs::ExternalParam],
+        deducible_fields: &FxHashMap<String, DeducibleField>,
+    ) -> String {
+        let mut s = String::new();
+        for ext_param in ext_params.iter() {
+            s.push_str(", ");
+            if deducible_fields.contains_key(&ext_param.name) {
+                s.push_str(&self.ext_param_from_raw(ext_param));
+            } else {
+                s.push_str(&to_rust_variable_name(&ext_param.name));
+            }
+        }
+        s
+    }
+
+    /// Returns an expression that converts the local variable for
+    /// `ext_param`, which holds the raw wire value, into the type
+    /// returned by `ext_param_to_rust_type`.
+    fn ext_param_from_raw(&self, ext_param: &xcbdefs::ExternalParam) -> String {
+        let rust_name = to_rust_variable_name(&ext_param.name);
+        if self
+            .use_enum_type(&ext_param.type_, &ext_param.value_set)
+            .is_some()
+        {
+            self.wire_type_to_enum(&ext_param.type_, &ext_param.value_set, &rust_name)
+        } else {
+            rust_name
+        }
+    }
+
+    /// Returns the Rust type for `ext_param`.
+    fn ext_param_to_rust_type(&self, ext_param: &xcbdefs::ExternalParam) -> String {
+        self.get_enum_rust_type(&ext_param.type_, &ext_param.value_set)
+            .unwrap_or_else(|| self.type_to_rust_type(&ext_param.type_))
+    }
+
     /// Returns the Rust type for `field`.
     fn field_to_rust_type(&self, field: &xcbdefs::FieldDef, switch_prefix: &str) -> String {
         match field {
@@ -4574,7 +5282,15 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
     fn get_type_parse_params(&self, type_: &xcbdefs::TypeRef, remaining: &str) -> Vec<String> {
         let mut params = Vec::new();
         params.push(String::from(remaining));
+        params.push(String::from("order"));
+        for ext_param_name in self.get_type_ext_param_names(type_) {
+            params.push(to_rust_variable_name(&ext_param_name));
+        }
+        params
+    }
 
+    /// Returns the names of the external parameters of `type_`.
+    fn get_type_ext_param_names(&self, type_: &xcbdefs::TypeRef) -> Vec<String> {
         let original_type = if let xcbdefs::TypeRef::Alias(type_alias_def) = type_ {
             type_alias_def.upgrade().unwrap().get_original_type()
         } else {
@@ -4583,12 +5299,11 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
 
         if let xcbdefs::TypeRef::Struct(struct_def) = original_type {
             let struct_def = struct_def.upgrade().unwrap();
-            for ext_param in struct_def.external_params.borrow().iter() {
-                params.push(to_rust_variable_name(&ext_param.name));
-            }
+            let ext_params = struct_def.external_params.borrow();
+            ext_params.iter().map(|p| p.name.clone()).collect()
+        } else {
+            Vec::new()
         }
-
-        params
     }
 
     /// Gets the traits that can be implemented for `struct_def`.
@@ -4670,8 +5385,9 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 derives.eq = false;
             }
             xcbdefs::TypeRef::EventStruct(_) => {
-                // Only appears in requests
-                unreachable!();
+                // Only appears in requests, comparing makes no sense
+                derives.partial_eq = false;
+                derives.eq = false;
             }
             xcbdefs::TypeRef::Xid(_) => {}
             xcbdefs::TypeRef::XidUnion(_) => {}
@@ -4683,6 +5399,57 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
     }
 
+    /// Whether the Rust type of `field` in a `*Request` struct implements `Default`.
+    fn field_implements_default(&self, field: &xcbdefs::FieldDef) -> bool {
+        match field {
+            xcbdefs::FieldDef::Normal(normal_field) => {
+                self.field_value_type_implements_default(&normal_field.type_)
+            }
+            xcbdefs::FieldDef::List(list_field) => match list_field.length() {
+                // `Default` is only implemented for arrays with up to 32 elements
+                Some(list_len) => {
+                    list_len <= 32
+                        && self.field_value_type_implements_default(&list_field.element_type)
+                }
+                None => true,
+            },
+            xcbdefs::FieldDef::Switch(switch_field) => {
+                switch_field.kind == xcbdefs::SwitchKind::BitCase
+            }
+            xcbdefs::FieldDef::Fd(_) => false,
+            xcbdefs::FieldDef::FdList(_) => true,
+            xcbdefs::FieldDef::Pad(_)
+            | xcbdefs::FieldDef::Expr(_)
+            | xcbdefs::FieldDef::VirtualLen(_) => unreachable!(),
+        }
+    }
+
+    /// Whether the Rust type for `value_type` implements `Default`.
+    fn field_value_type_implements_default(&self, value_type: &xcbdefs::FieldValueType) -> bool {
+        if let Some(enum_def) = self.use_enum_type_in_field(value_type) {
+            enum_has_default(&enum_def)
+        } else {
+            self.type_implements_default(value_type.type_.def.get().unwrap())
+        }
+    }
+
+    /// Whether the Rust type for `type_` implements `Default`.
+    fn type_implements_default(&self, type_: &xcbdefs::TypeRef) -> bool {
+        match type_ {
+            xcbdefs::TypeRef::BuiltIn(_) => true,
+            xcbdefs::TypeRef::Xid(_) | xcbdefs::TypeRef::XidUnion(_) => true,
+            xcbdefs::TypeRef::Enum(enum_def) => enum_has_default(&enum_def.upgrade().unwrap()),
+            xcbdefs::TypeRef::Alias(type_alias_def) => {
+                let type_alias_def = type_alias_def.upgrade().unwrap();
+                self.type_implements_default(type_alias_def.old_name.def.get().unwrap())
+            }
+            // Structs and unions do not implement `Default`
+            xcbdefs::TypeRef::Struct(_)
+            | xcbdefs::TypeRef::Union(_)
+            | xcbdefs::TypeRef::EventStruct(_) => false,
+        }
+    }
+
     /// Whether the field is visible (i.e., appears in parsed rust structs)
     fn field_is_visible(
         &self,
@@ -4951,6 +5718,27 @@ struct GatheredRequestFields {
     fd_lists: Vec<String>,
 }
 
+/// Get the Rust type of the unsigned integer that a value of type `type_` is sent as, if it is one.
+fn get_wire_int_type(type_: &xcbdefs::TypeRef) -> Option<&'static str> {
+    match type_.get_original_type() {
+        xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card8)
+        | xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Byte) => Some("u8"),
+        xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card16) => Some("u16"),
+        xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card32) => Some("u32"),
+        _ => None,
+    }
+}
+
+/// Get the number of bits of an unsigned integer type as returned by `get_wire_int_type`.
+fn int_type_bits(int_type: &str) -> u32 {
+    match int_type {
+        "u8" => 8,
+        "u16" => 16,
+        "u32" => 32,
+        _ => unreachable!(),
+    }
+}
+
 /// Formats an integer such as clippy does not complain.
 ///
 /// `1234567` produces `"1_234_567"`
@@ -5003,7 +5791,7 @@ fn needs_match_by_value(field: &xcbdefs::FieldDef) -> bool {
 ///
 /// If the name is all uppercase, all but the first
 /// letter are converter to lowercase.
-fn to_rust_type_name(name: &str) -> String {
+pub(super) fn to_rust_type_name(name: &str) -> String {
     let mut name = String::from(name);
     if name.bytes().all(|c| !c.is_ascii_lowercase()) {
         name.make_ascii_lowercase();
@@ -5087,7 +5875,37 @@ fn ename_to_rust(name: &str) -> String {
     name
 }
 
-/// Check if an enum definition is xproto's Gravity enum.
-fn is_xproto_gravity(enum_def: &xcbdefs::EnumDef) -> bool {
-    enum_def.name == "Gravity" && enum_def.namespace.upgrade().unwrap().header == "xproto"
+/// Check if `rust_type` is a primitive integer, floating point or boolean type.
+fn is_primitive_rust_type(rust_type: &str) -> bool {
+    match rust_type {
+        "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "f32" | "f64" | "bool" => {
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Check if an enum definition has `<bit>` items.
+fn enum_has_bits(enum_def: &xcbdefs::EnumDef) -> bool {
+    enum_def
+        .items
+        .iter()
+        .any(|enum_item| match enum_item.value {
+            xcbdefs::EnumValue::Value(_) => false,
+            xcbdefs::EnumValue::Bit(_) => true,
+        })
+}
+
+/// Check if the type for an enum definition implements `Default`.
+///
+/// Bit masks default to the empty set and other enums to their item with value zero, if any.
+fn enum_has_default(enum_def: &xcbdefs::EnumDef) -> bool {
+    enum_has_bits(enum_def)
+        || enum_def
+            .items
+            .iter()
+            .any(|enum_item| match enum_item.value {
+                xcbdefs::EnumValue::Value(value) => value == 0,
+                xcbdefs::EnumValue::Bit(_) => false,
+            })
 }
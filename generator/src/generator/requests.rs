@@ -0,0 +1,535 @@
+use super::output::Output;
+
+pub(super) fn generate(out: &mut Output, module: &xcbgen::defs::Module) {
+    generate_request_name(out, module);
+    outln!(out, "");
+    generate_request_enum(out, module);
+    outln!(out, "");
+    generate_reply_enum(out, module);
+}
+
+fn generate_request_name(out: &mut Output, module: &xcbgen::defs::Module) {
+    let namespaces = module.sorted_namespaces();
+
+    outln!(
+        out,
+        "/// Get the name of a request from its major and minor opcode."
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// `ext_info_provider` is used to find the extension that a major opcode belongs to. For core"
+    );
+    outln!(
+        out,
+        "/// requests, `minor_opcode` is ignored. Returns `None` if the request is not known."
+    );
+    outln!(out, "pub fn request_name(");
+    outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+    outln!(out.indent(), "major_opcode: u8,");
+    outln!(out.indent(), "minor_opcode: u8,");
+    outln!(out, ") -> Option<&'static str> {{");
+    out.indented(|out| {
+        outln!(out, "// Check if this is a core protocol request");
+        outln!(out, "match major_opcode {{");
+        out.indented(|out| {
+            let xproto_ns = module.namespace("xproto").unwrap();
+            for name in sorted_request_names(&xproto_ns) {
+                outln!(
+                    out,
+                    "xproto::{}_REQUEST => return Some(\"{}\"),",
+                    super::camel_case_to_upper_snake(&name),
+                    name,
+                );
+            }
+            outln!(out, "_ => {{}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "// Find the extension that this request could belong to"
+        );
+        outln!(
+            out,
+            "let ext_info = ext_info_provider.get_from_major_opcode(major_opcode);"
+        );
+        outln!(out, "match ext_info {{");
+        out.indented(|out| {
+            for ns in namespaces.iter() {
+                // skip xproto
+                if ns.ext_info.is_none() {
+                    continue;
+                }
+
+                let request_names = sorted_request_names(ns);
+                if request_names.is_empty() {
+                    continue;
+                }
+                if super::ext_has_feature(&ns.header) {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(out, "Some(({}::X11_EXTENSION_NAME, _)) => {{", ns.header);
+                out.indented(|out| {
+                    outln!(out, "match minor_opcode {{");
+                    for name in request_names.iter() {
+                        outln!(
+                            out.indent(),
+                            "{}::{}_REQUEST => Some(\"{}\"),",
+                            ns.header,
+                            super::camel_case_to_upper_snake(name),
+                            name,
+                        );
+                    }
+                    outln!(out.indent(), "_ => None,");
+                    outln!(out, "}}");
+                });
+                outln!(out, "}}");
+            }
+            outln!(out, "_ => None,");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
+fn generate_request_enum(out: &mut Output, module: &xcbgen::defs::Module) {
+    let namespaces = module.sorted_namespaces();
+
+    outln!(out, "/// Enumeration of all possible X11 requests.");
+    outln!(out, "#[derive(Debug)]");
+    outln!(out, "#[allow(clippy::large_enum_variant)]");
+    outln!(out, "pub enum Request {{");
+    out.indented(|out| {
+        outln!(out, "Unknown(RequestHeader, Vec<u8>),");
+        for ns in namespaces.iter() {
+            let has_feature = super::ext_has_feature(&ns.header);
+            for (name, _) in sorted_requests(ns) {
+                if has_feature {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(
+                    out,
+                    "{}{}({}::{}Request),",
+                    super::get_ns_name_prefix(ns),
+                    name,
+                    ns.header,
+                    name,
+                );
+            }
+        }
+    });
+    outln!(out, "}}");
+    outln!(out, "");
+    outln!(out, "impl Request {{");
+    out.indented(|out| {
+        outln!(
+            out,
+            "/// Parse a generic X11 request into a concrete request type."
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// `header` and `body` can be obtained from the raw bytes of a request with"
+        );
+        outln!(
+            out,
+            "/// `x11rb::x11_utils::parse_request_header`. Data after the end of the request's body is"
+        );
+        outln!(
+            out,
+            "/// ignored. The FDs that were sent with the request are taken from the front of `fds`."
+        );
+        outln!(
+            out,
+            "/// `order` is the byte order that the client which sent the request uses."
+        );
+        outln!(
+            out,
+            "/// Requests that are not known are returned as `Request::Unknown`."
+        );
+        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        // `fds` is only used by requests of some extensions
+        let fd_features = namespaces
+            .iter()
+            .filter(|ns| sorted_requests(ns).iter().any(|(_, has_fds)| *has_fds))
+            .map(|ns| {
+                assert!(super::ext_has_feature(&ns.header));
+                format!("feature = \"{}\"", ns.header)
+            })
+            .collect::<Vec<_>>();
+        outln!(
+            out,
+            "#[cfg_attr(not(any({})), allow(unused_variables))]",
+            fd_features.join(", "),
+        );
+        outln!(out, "pub fn parse(");
+        outln!(out.indent(), "header: RequestHeader,");
+        outln!(out.indent(), "body: &[u8],");
+        outln!(out.indent(), "fds: &mut Vec<RawFdContainer>,");
+        outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out.indent(), "order: ByteOrder,");
+        outln!(out, ") -> Result<Self, ParseError> {{");
+        out.indented(|out| {
+            outln!(
+                out,
+                "let body_length = usize::try_from(header.remaining_length).unwrap() * 4;"
+            );
+            outln!(
+                out,
+                "let body = body.get(..body_length).ok_or(ParseError::ParseError)?;"
+            );
+            outln!(out, "");
+            outln!(out, "// Check if this is a core protocol request");
+            outln!(out, "match header.major_opcode {{");
+            out.indented(|out| {
+                let xproto_ns = module.namespace("xproto").unwrap();
+                for (name, has_fds) in sorted_requests(&xproto_ns) {
+                    outln!(
+                        out,
+                        "xproto::{}_REQUEST => return Ok(Request::{}({})),",
+                        super::camel_case_to_upper_snake(&name),
+                        name,
+                        parse_call("xproto", &name, has_fds),
+                    );
+                }
+                outln!(out, "_ => {{}}");
+            });
+            outln!(out, "}}");
+            outln!(out, "");
+            outln!(
+                out,
+                "// Find the extension that this request could belong to"
+            );
+            outln!(
+                out,
+                "let ext_info = ext_info_provider.get_from_major_opcode(header.major_opcode);"
+            );
+            outln!(out, "match ext_info {{");
+            out.indented(|out| {
+                for ns in namespaces.iter() {
+                    // skip xproto
+                    if ns.ext_info.is_none() {
+                        continue;
+                    }
+
+                    let requests = sorted_requests(ns);
+                    if requests.is_empty() {
+                        continue;
+                    }
+                    if super::ext_has_feature(&ns.header) {
+                        outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    outln!(out, "Some(({}::X11_EXTENSION_NAME, _)) => {{", ns.header);
+                    out.indented(|out| {
+                        outln!(out, "match header.minor_opcode {{");
+                        for (name, has_fds) in requests.iter() {
+                            outln!(
+                                out.indent(),
+                                "{}::{}_REQUEST => Ok(Request::{}{}({})),",
+                                ns.header,
+                                super::camel_case_to_upper_snake(name),
+                                super::get_ns_name_prefix(ns),
+                                name,
+                                parse_call(&ns.header, name, *has_fds),
+                            );
+                        }
+                        outln!(
+                            out.indent(),
+                            "_ => Ok(Request::Unknown(header, body.to_vec())),"
+                        );
+                        outln!(out, "}}");
+                    });
+                    outln!(out, "}}");
+                }
+                outln!(out, "_ => Ok(Request::Unknown(header, body.to_vec())),");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "/// Check whether the X11 server sends a reply to this request."
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Requests that are not known are assumed to have a reply."
+        );
+        outln!(out, "pub fn has_reply(&self) -> bool {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            out.indented(|out| {
+                outln!(out, "Request::Unknown(_, _) => true,");
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+                    for (name, _) in sorted_replies(ns) {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(
+                            out,
+                            "Request::{}{}(_) => true,",
+                            super::get_ns_name_prefix(ns),
+                            name,
+                        );
+                    }
+                }
+                outln!(out, "_ => false,");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
+fn generate_reply_enum(out: &mut Output, module: &xcbgen::defs::Module) {
+    let namespaces = module.sorted_namespaces();
+
+    outln!(out, "/// Enumeration of all possible X11 replies.");
+    outln!(out, "#[derive(Debug)]");
+    outln!(out, "#[allow(clippy::large_enum_variant)]");
+    outln!(out, "pub enum Reply {{");
+    out.indented(|out| {
+        outln!(out, "Unknown(Vec<u8>),");
+        for ns in namespaces.iter() {
+            let has_feature = super::ext_has_feature(&ns.header);
+            for (name, _) in sorted_replies(ns) {
+                if has_feature {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(
+                    out,
+                    "{}{}({}::{}Reply),",
+                    super::get_ns_name_prefix(ns),
+                    name,
+                    ns.header,
+                    name,
+                );
+            }
+        }
+    });
+    outln!(out, "}}");
+    outln!(out, "");
+    outln!(out, "impl Reply {{");
+    out.indented(|out| {
+        outln!(
+            out,
+            "/// Parse a generic X11 reply into a concrete reply type."
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// `major_opcode` and `minor_opcode` are the opcodes of the request that caused the reply."
+        );
+        outln!(
+            out,
+            "/// The reply is expected in the byte order of the machine that this code runs on. The FDs"
+        );
+        outln!(
+            out,
+            "/// that were received with the reply are taken from the front of `fds`. Replies to requests"
+        );
+        outln!(out, "/// that are not known are returned as `Reply::Unknown`.");
+        outln!(out, "pub fn parse(");
+        outln!(out.indent(), "major_opcode: u8,");
+        outln!(out.indent(), "minor_opcode: u8,");
+        outln!(out.indent(), "reply: &[u8],");
+        outln!(out.indent(), "fds: &mut Vec<RawFdContainer>,");
+        outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out, ") -> Result<Self, ParseError> {{");
+        outln!(
+            out.indent(),
+            "Self::parse_with_order(major_opcode, minor_opcode, reply, fds, ext_info_provider, ByteOrder::native())",
+        );
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "/// Parse a generic X11 reply that is encoded in the given byte order.",
+        );
+        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        // `fds` is only used by replies of some extensions
+        let fd_features = namespaces
+            .iter()
+            .filter(|ns| sorted_replies(ns).iter().any(|(_, has_fds)| *has_fds))
+            .map(|ns| {
+                assert!(super::ext_has_feature(&ns.header));
+                format!("feature = \"{}\"", ns.header)
+            })
+            .collect::<Vec<_>>();
+        outln!(
+            out,
+            "#[cfg_attr(not(any({})), allow(unused_variables))]",
+            fd_features.join(", "),
+        );
+        outln!(out, "pub fn parse_with_order(");
+        outln!(out.indent(), "major_opcode: u8,");
+        outln!(out.indent(), "minor_opcode: u8,");
+        outln!(out.indent(), "reply: &[u8],");
+        outln!(out.indent(), "fds: &mut Vec<RawFdContainer>,");
+        outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out.indent(), "order: ByteOrder,");
+        outln!(out, ") -> Result<Self, ParseError> {{");
+        out.indented(|out| {
+            outln!(out, "// Check if this is a reply to a core protocol request");
+            outln!(out, "match major_opcode {{");
+            out.indented(|out| {
+                let xproto_ns = module.namespace("xproto").unwrap();
+                for (name, has_fds) in sorted_replies(&xproto_ns) {
+                    outln!(
+                        out,
+                        "xproto::{}_REQUEST => return Ok(Reply::{}({})),",
+                        super::camel_case_to_upper_snake(&name),
+                        name,
+                        reply_parse_call("xproto", &name, has_fds),
+                    );
+                }
+                outln!(out, "_ => {{}}");
+            });
+            outln!(out, "}}");
+            outln!(out, "");
+            outln!(
+                out,
+                "// Find the extension that the request could belong to"
+            );
+            outln!(
+                out,
+                "let ext_info = ext_info_provider.get_from_major_opcode(major_opcode);"
+            );
+            outln!(out, "match ext_info {{");
+            out.indented(|out| {
+                for ns in namespaces.iter() {
+                    // skip xproto
+                    if ns.ext_info.is_none() {
+                        continue;
+                    }
+
+                    let replies = sorted_replies(ns);
+                    if replies.is_empty() {
+                        continue;
+                    }
+                    if super::ext_has_feature(&ns.header) {
+                        outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    outln!(out, "Some(({}::X11_EXTENSION_NAME, _)) => {{", ns.header);
+                    out.indented(|out| {
+                        outln!(out, "match minor_opcode {{");
+                        for (name, has_fds) in replies.iter() {
+                            outln!(
+                                out.indent(),
+                                "{}::{}_REQUEST => Ok(Reply::{}{}({})),",
+                                ns.header,
+                                super::camel_case_to_upper_snake(name),
+                                super::get_ns_name_prefix(ns),
+                                name,
+                                reply_parse_call(&ns.header, name, *has_fds),
+                            );
+                        }
+                        outln!(out.indent(), "_ => Ok(Reply::Unknown(reply.to_vec())),");
+                        outln!(out, "}}");
+                    });
+                    outln!(out, "}}");
+                }
+                outln!(out, "_ => Ok(Reply::Unknown(reply.to_vec())),");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
+/// Get the code for parsing the reply to request `name` from namespace `header`.
+fn reply_parse_call(header: &str, name: &str, has_fds: bool) -> String {
+    if has_fds {
+        format!(
+            "<{}::{}Reply as crate::x11_utils::TryParseFd>::try_parse_fd_with_order(reply, fds, order)?.0",
+            header, name,
+        )
+    } else {
+        format!(
+            "{}::{}Reply::try_parse_with_order(reply, order)?.0",
+            header, name
+        )
+    }
+}
+
+/// Get the code for parsing the request `name` from namespace `header`.
+fn parse_call(header: &str, name: &str, has_fds: bool) -> String {
+    if has_fds {
+        format!(
+            "{}::{}Request::try_parse_request_fd(header, body, fds, order)?",
+            header, name,
+        )
+    } else {
+        format!(
+            "{}::{}Request::try_parse_request(header, body, order)?",
+            header, name,
+        )
+    }
+}
+
+/// Get the Rust names of all requests in a namespace, sorted by their opcode.
+fn sorted_request_names(ns: &xcbgen::defs::Namespace) -> Vec<String> {
+    sorted_requests(ns)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Get the Rust names of all requests in a namespace and whether they carry
+/// FDs, sorted by their opcode.
+fn sorted_requests(ns: &xcbgen::defs::Namespace) -> Vec<(String, bool)> {
+    let mut requests: Vec<_> = ns
+        .request_defs
+        .borrow()
+        .values()
+        .map(|request_def| {
+            (
+                request_def.opcode,
+                super::namespace::to_rust_type_name(&request_def.name),
+                has_fds(&request_def.fields.borrow()),
+            )
+        })
+        .collect();
+    requests.sort();
+    requests
+        .into_iter()
+        .map(|(_, name, has_fds)| (name, has_fds))
+        .collect()
+}
+
+/// Get the Rust names of all requests with a reply in a namespace and whether
+/// the reply carries FDs, sorted by their opcode.
+fn sorted_replies(ns: &xcbgen::defs::Namespace) -> Vec<(String, bool)> {
+    let mut replies: Vec<_> = ns
+        .request_defs
+        .borrow()
+        .values()
+        .filter_map(|request_def| {
+            request_def.reply.as_ref().map(|reply_def| {
+                (
+                    request_def.opcode,
+                    super::namespace::to_rust_type_name(&request_def.name),
+                    has_fds(&reply_def.fields.borrow()),
+                )
+            })
+        })
+        .collect();
+    replies.sort();
+    replies
+        .into_iter()
+        .map(|(_, name, has_fds)| (name, has_fds))
+        .collect()
+}
+
+/// Check whether any of the given fields is an FD or a list of FDs.
+fn has_fds(fields: &[xcbgen::defs::FieldDef]) -> bool {
+    fields.iter().any(|field| match field {
+        xcbgen::defs::FieldDef::Fd(_) | xcbgen::defs::FieldDef::FdList(_) => true,
+        _ => false,
+    })
+}
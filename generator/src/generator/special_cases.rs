@@ -34,7 +34,7 @@ pub(super) fn handle_request(request_def: &xcbdefs::RequestDef, out: &mut Output
 ///     format: {},
 ///     sequence: 0,
 ///     length: 0, // This value is incorrect
-///     type_: 0, // This value is incorrect
+///     type_: 0.into(), // This value is incorrect
 ///     bytes_after: 0,
 ///     value_len: 4,
 ///     value: vec![{example_value}],
@@ -62,7 +62,7 @@ pub(super) fn handle_request(request_def: &xcbdefs::RequestDef, out: &mut Output
 ///     format: 42, // Not allowed in X11, but used for the example
 ///     sequence: 0,
 ///     length: 0, // This value is incorrect
-///     type_: 0, // This value is incorrect
+///     type_: 0.into(), // This value is incorrect
 ///     bytes_after: 0,
 ///     value_len: 4,
 ///     value: vec![1, 2, 3, 4],
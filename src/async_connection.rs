@@ -0,0 +1,481 @@
+//! Asynchronous variants of the connection traits.
+//!
+//! The traits in the [`connection`](crate::connection) module block the calling thread while
+//! waiting for the X11 server. This module contains extensions of these traits that allow to wait
+//! for replies, errors, and events with `async`/`await` instead.
+//!
+//! Sending requests never blocks and thus works exactly like it does for synchronous connections:
+//! The generated code in the [`protocol`](crate::protocol) module can be used directly. Only the
+//! cookies that are returned change their meaning: On an `AsyncRequestConnection`, a `Cookie` can
+//! be turned into a future via `Cookie::reply_async()`, and a `VoidCookie` can be checked via
+//! `VoidCookie::check_async()`.
+//!
+//! ```no_run
+//! use x11rb::async_connection::AsyncConnection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::ConnectionExt;
+//!
+//! async fn print_focus<C: AsyncConnection>(conn: &C) -> Result<(), ReplyError<C::Buf>> {
+//!     let focus = conn.get_input_focus()?.reply_async().await?;
+//!     println!("The input focus is on window {:?}", focus.focus);
+//!     let event = conn.wait_for_event_async().await?;
+//!     println!("Got an event: {:?}", event);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Note that the blocking methods of `RequestConnection` and `Connection` are still available on
+//! asynchronous connections, but they must not block the executor. Instead, they fail with
+//! `ConnectionError::WouldBlock` if their result is not yet available. Some of them, like
+//! `RequestConnection::extension_information()`, are called internally by the generated code.
+//! Use `extension_information_async()` to make sure that the information about an extension is
+//! available before such a function is called.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::connection::{
+    BufWithFds, Connection, RawEventAndSeqNumber, ReplyOrError, RequestConnection, SequenceNumber,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::{Error, Event};
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
+
+/// A connection to an X11 server that allows waiting for responses asynchronously.
+///
+/// The methods of this trait follow the conventions of `Future::poll`: If a result is not yet
+/// available, `Poll::Pending` is returned and the waker of the given `Context` is woken once it is
+/// worth to call the method again.
+///
+/// Users of this library will most likely not want to use the `poll_*` functions directly.
+/// Instead, the futures returned by e.g. `Cookie::reply_async()` should be used.
+pub trait AsyncRequestConnection: RequestConnection {
+    /// Poll for the reply to a request.
+    ///
+    /// This is the asynchronous version of `RequestConnection::wait_for_reply_or_raw_error()`.
+    fn poll_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<ReplyOrError<Self::Buf>, ConnectionError>>;
+
+    /// Poll for the reply to a request, handling errors as events.
+    ///
+    /// This is the asynchronous version of `RequestConnection::wait_for_reply()`.
+    fn poll_reply(
+        &self,
+        sequence: SequenceNumber,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Self::Buf>, ConnectionError>>;
+
+    /// Poll for the reply to a request that has FDs.
+    ///
+    /// This is the asynchronous version of `RequestConnection::wait_for_reply_with_fds_raw()`.
+    #[allow(clippy::type_complexity)]
+    fn poll_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<ReplyOrError<BufWithFds<Self::Buf>, Self::Buf>, ConnectionError>>;
+
+    /// Poll for an error to a request that does not have a reply.
+    ///
+    /// This is the asynchronous version of `RequestConnection::check_for_raw_error()`.
+    fn poll_check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Self::Buf>, ConnectionError>>;
+
+    /// Poll for information about an extension.
+    ///
+    /// This is the asynchronous version of `RequestConnection::extension_information()`. Once
+    /// this returned a result, calling `extension_information()` for the same extension does not
+    /// fail with `ConnectionError::WouldBlock` anymore.
+    fn poll_extension_information(
+        &self,
+        extension_name: &'static str,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<ExtensionInformation>, ConnectionError>>;
+
+    /// Asynchronously get information about an extension.
+    ///
+    /// This is the asynchronous version of `RequestConnection::extension_information()`.
+    fn extension_information_async(
+        &self,
+        extension_name: &'static str,
+    ) -> ExtensionInformationFuture<'_, Self> {
+        ExtensionInformationFuture {
+            connection: self,
+            extension_name,
+        }
+    }
+}
+
+/// A connection to an X11 server that allows waiting for events asynchronously.
+pub trait AsyncConnection: AsyncRequestConnection + Connection {
+    /// Poll for a new raw/unparsed event from the X11 server.
+    ///
+    /// This is the asynchronous version of `Connection::wait_for_raw_event_with_sequence()`.
+    fn poll_raw_event_with_sequence(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError>>;
+
+    /// Poll for all pending requests to be sent to the server.
+    ///
+    /// This is the asynchronous version of `Connection::flush()`.
+    fn poll_flush(&self, cx: &mut Context<'_>) -> Poll<Result<(), ConnectionError>>;
+
+    /// Asynchronously wait for a new event from the X11 server.
+    fn wait_for_event_async(&self) -> EventFuture<'_, Self> {
+        EventFuture { connection: self }
+    }
+
+    /// Asynchronously wait for a new raw/unparsed event from the X11 server.
+    fn wait_for_raw_event_with_sequence_async(&self) -> RawEventFuture<'_, Self> {
+        RawEventFuture { connection: self }
+    }
+
+    /// Asynchronously send all pending requests to the server.
+    fn flush_async(&self) -> FlushFuture<'_, Self> {
+        FlushFuture { connection: self }
+    }
+}
+
+/// A future for the reply to a request.
+///
+/// This future is returned by `Cookie::reply_async()`.
+#[derive(Debug)]
+pub struct ReplyFuture<'a, C, R>
+where
+    C: RequestConnection + ?Sized,
+{
+    cookie: Option<Cookie<'a, C, R>>,
+}
+
+impl<'a, C, R> ReplyFuture<'a, C, R>
+where
+    C: RequestConnection + ?Sized,
+{
+    pub(crate) fn new(cookie: Cookie<'a, C, R>) -> Self {
+        Self {
+            cookie: Some(cookie),
+        }
+    }
+}
+
+// The cookie does not need to be pinned, it is only moved out once the reply is ready.
+impl<C, R> Unpin for ReplyFuture<'_, C, R> where C: RequestConnection + ?Sized {}
+
+impl<C, R> Future for ReplyFuture<'_, C, R>
+where
+    R: TryParse,
+    C: AsyncRequestConnection + ?Sized,
+{
+    type Output = Result<R, ReplyError<C::Buf>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let cookie = self
+            .cookie
+            .as_ref()
+            .expect("future polled after completion");
+        let conn = cookie.connection();
+        let result = match conn.poll_reply_or_raw_error(cookie.sequence_number(), cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(result) => result,
+        };
+        let cookie = self.cookie.take().unwrap();
+        let conn = cookie.connection();
+        let result = match result {
+            Err(err) => Err(err.into()),
+            Ok(ReplyOrError::Reply(reply)) => {
+                R::try_parse_with_order(reply.as_ref(), conn.byte_order())
+                    .map(|r| r.0)
+                    .map_err(Into::into)
+            }
+            Ok(ReplyOrError::Error(error)) => match conn.parse_error(error) {
+                Ok(error) => Err(ReplyError::X11Error(error)),
+                Err(err) => Err(err.into()),
+            },
+        };
+        let _ = cookie.into_sequence_number();
+        Poll::Ready(result)
+    }
+}
+
+/// A future for the reply to a request, with errors being handled as events.
+///
+/// This future is returned by `Cookie::reply_unchecked_async()`.
+#[derive(Debug)]
+pub struct ReplyUncheckedFuture<'a, C, R>
+where
+    C: RequestConnection + ?Sized,
+{
+    cookie: Option<Cookie<'a, C, R>>,
+}
+
+impl<'a, C, R> ReplyUncheckedFuture<'a, C, R>
+where
+    C: RequestConnection + ?Sized,
+{
+    pub(crate) fn new(cookie: Cookie<'a, C, R>) -> Self {
+        Self {
+            cookie: Some(cookie),
+        }
+    }
+}
+
+// The cookie does not need to be pinned, it is only moved out once the reply is ready.
+impl<C, R> Unpin for ReplyUncheckedFuture<'_, C, R> where C: RequestConnection + ?Sized {}
+
+impl<C, R> Future for ReplyUncheckedFuture<'_, C, R>
+where
+    R: TryParse,
+    C: AsyncRequestConnection + ?Sized,
+{
+    type Output = Result<Option<R>, ConnectionError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let cookie = self
+            .cookie
+            .as_ref()
+            .expect("future polled after completion");
+        let result = match cookie.connection().poll_reply(cookie.sequence_number(), cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(result) => result,
+        };
+        let order = cookie.connection().byte_order();
+        let _ = self.cookie.take().unwrap().into_sequence_number();
+        Poll::Ready(result.and_then(|reply| {
+            reply
+                .map(|buf| R::try_parse_with_order(buf.as_ref(), order).map(|r| r.0))
+                .transpose()
+                .map_err(Into::into)
+        }))
+    }
+}
+
+/// A future for the reply to a request that contains file descriptors.
+///
+/// This future is returned by `CookieWithFds::reply_async()`.
+#[derive(Debug)]
+pub struct ReplyWithFdsFuture<'a, C, R>
+where
+    C: RequestConnection + ?Sized,
+{
+    cookie: Option<CookieWithFds<'a, C, R>>,
+}
+
+impl<'a, C, R> ReplyWithFdsFuture<'a, C, R>
+where
+    C: RequestConnection + ?Sized,
+{
+    pub(crate) fn new(cookie: CookieWithFds<'a, C, R>) -> Self {
+        Self {
+            cookie: Some(cookie),
+        }
+    }
+}
+
+// The cookie does not need to be pinned, it is only moved out once the reply is ready.
+impl<C, R> Unpin for ReplyWithFdsFuture<'_, C, R> where C: RequestConnection + ?Sized {}
+
+impl<C, R> Future for ReplyWithFdsFuture<'_, C, R>
+where
+    R: TryParseFd,
+    C: AsyncRequestConnection + ?Sized,
+{
+    type Output = Result<R, ReplyError<C::Buf>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let cookie = self
+            .cookie
+            .as_ref()
+            .expect("future polled after completion");
+        let conn = cookie.connection();
+        let result = match conn.poll_reply_with_fds_raw(cookie.sequence_number(), cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(result) => result,
+        };
+        let cookie = self.cookie.take().unwrap();
+        let conn = cookie.connection();
+        let result = match result {
+            Err(err) => Err(err.into()),
+            Ok(ReplyOrError::Reply((reply, mut fds))) => {
+                R::try_parse_fd_with_order(reply.as_ref(), &mut fds, conn.byte_order())
+                    .map(|r| r.0)
+                    .map_err(Into::into)
+            }
+            Ok(ReplyOrError::Error(error)) => match conn.parse_error(error) {
+                Ok(error) => Err(ReplyError::X11Error(error)),
+                Err(err) => Err(err.into()),
+            },
+        };
+        let _ = cookie.into_sequence_number();
+        Poll::Ready(result)
+    }
+}
+
+/// A future for checking whether a request without a reply caused an X11 error.
+///
+/// This future is returned by `VoidCookie::check_async()`.
+#[derive(Debug)]
+pub struct CheckFuture<'a, C>
+where
+    C: RequestConnection + ?Sized,
+{
+    cookie: Option<VoidCookie<'a, C>>,
+}
+
+impl<'a, C> CheckFuture<'a, C>
+where
+    C: RequestConnection + ?Sized,
+{
+    pub(crate) fn new(cookie: VoidCookie<'a, C>) -> Self {
+        Self {
+            cookie: Some(cookie),
+        }
+    }
+}
+
+impl<C> Future for CheckFuture<'_, C>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    type Output = Result<Option<Error<C::Buf>>, ConnectionError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let cookie = self
+            .cookie
+            .as_ref()
+            .expect("future polled after completion");
+        let result = match cookie
+            .connection()
+            .poll_check_for_raw_error(cookie.sequence_number(), cx)
+        {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(result) => result,
+        };
+        let (conn, _) = self.cookie.take().unwrap().consume();
+        Poll::Ready(result.and_then(|error| {
+            error
+                .map(|error| conn.parse_error(error))
+                .transpose()
+                .map_err(Into::into)
+        }))
+    }
+}
+
+/// A future for information about an X11 extension.
+///
+/// This future is returned by `AsyncRequestConnection::extension_information_async()`.
+#[derive(Debug)]
+pub struct ExtensionInformationFuture<'a, C: ?Sized> {
+    connection: &'a C,
+    extension_name: &'static str,
+}
+
+impl<C> Future for ExtensionInformationFuture<'_, C>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    type Output = Result<Option<ExtensionInformation>, ConnectionError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.connection
+            .poll_extension_information(self.extension_name, cx)
+    }
+}
+
+/// A future for the next event from the X11 server.
+///
+/// This future is returned by `AsyncConnection::wait_for_event_async()`.
+#[derive(Debug)]
+pub struct EventFuture<'a, C: ?Sized> {
+    connection: &'a C,
+}
+
+impl<C> Future for EventFuture<'_, C>
+where
+    C: AsyncConnection + ?Sized,
+{
+    type Output = Result<Event<C::Buf>, ConnectionError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.connection.poll_raw_event_with_sequence(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+            Poll::Ready(Ok((event, _seqno))) => {
+                Poll::Ready(self.connection.parse_event(event).map_err(Into::into))
+            }
+        }
+    }
+}
+
+/// A future for the next raw/unparsed event from the X11 server.
+///
+/// This future is returned by `AsyncConnection::wait_for_raw_event_with_sequence_async()`.
+#[derive(Debug)]
+pub struct RawEventFuture<'a, C: ?Sized> {
+    connection: &'a C,
+}
+
+impl<C> Future for RawEventFuture<'_, C>
+where
+    C: AsyncConnection + ?Sized,
+{
+    type Output = Result<RawEventAndSeqNumber<C::Buf>, ConnectionError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.connection.poll_raw_event_with_sequence(cx)
+    }
+}
+
+/// A future for sending all pending requests to the X11 server.
+///
+/// This future is returned by `AsyncConnection::flush_async()`.
+#[derive(Debug)]
+pub struct FlushFuture<'a, C: ?Sized> {
+    connection: &'a C,
+}
+
+impl<C> Future for FlushFuture<'_, C>
+where
+    C: AsyncConnection + ?Sized,
+{
+    type Output = Result<(), ConnectionError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.connection.poll_flush(cx)
+    }
+}
+
+/// A future that is implemented by a closure.
+///
+/// This is a copy of `std::future::poll_fn`, which was only stabilised in Rust 1.64 and is thus
+/// not available in the minimum Rust version 1.51 of the `async` feature.
+#[derive(Debug)]
+pub(crate) struct PollFn<F>(F);
+
+impl<F> Unpin for PollFn<F> {}
+
+pub(crate) fn poll_fn<T, F>(f: F) -> PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T>,
+{
+    PollFn(f)
+}
+
+impl<T, F> Future for PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T>,
+{
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        (self.0)(cx)
+    }
+}
@@ -42,13 +42,14 @@
 
 use std::convert::{TryFrom, TryInto};
 use std::io::IoSlice;
+use std::time::Duration;
 
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
 use crate::protocol::xproto::Setup;
 use crate::protocol::{Error, Event};
 use crate::utils::RawFdContainer;
-use crate::x11_utils::ExtensionInformation;
+use crate::x11_utils::{ByteOrder, ExtensionInformation, Request, Serialize, TryParse, TryParseFd};
 
 /// Number type used for referring to things that were sent to the server in responses from the
 /// server.
@@ -75,11 +76,65 @@ where
     Error(E),
 }
 
+/// A special event queue for events of the generic event extension.
+///
+/// See `Connection::register_for_special_xge()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpecialEventQueue {
+    major_opcode: u8,
+    eid: u32,
+}
+
+impl SpecialEventQueue {
+    /// Create a new special event queue identifier.
+    ///
+    /// This is only useful for implementing `Connection`. The queue receives all events of the
+    /// generic event extension that belong to the extension with the given major opcode and that
+    /// carry the given event context id (`eid`).
+    pub fn new(major_opcode: u8, eid: u32) -> Self {
+        Self { major_opcode, eid }
+    }
+
+    /// Get the major opcode of the extension whose events are received by this queue.
+    pub fn major_opcode(self) -> u8 {
+        self.major_opcode
+    }
+
+    /// Get the event context id of the events that are received by this queue.
+    pub fn eid(self) -> u32 {
+        self.eid
+    }
+
+    /// Get the queue that the given raw event would belong to.
+    ///
+    /// Returns `None` if the event is not an event of the generic event extension.
+    pub(crate) fn for_event(event: &[u8], order: ByteOrder) -> Option<Self> {
+        use crate::protocol::xproto::GE_GENERIC_EVENT;
+        if event.len() < 16 || event[0] & 0x7f != GE_GENERIC_EVENT {
+            return None;
+        }
+        let (eid, _) = u32::try_parse_with_order(&event[12..16], order).ok()?;
+        Some(Self::new(event[1], eid))
+    }
+}
+
+/// A request that can be sent on a connection of type `C`.
+///
+/// This is implemented by the generated `*Request` structs. The request is sent by calling the
+/// function for the request, e.g. `xproto::create_window()` for `xproto::CreateWindowRequest`.
+pub trait SendRequest<'c, C: RequestConnection + ?Sized>: Request {
+    /// The type of the cookie that is returned when sending this request.
+    type Cookie;
+
+    /// Send this request on the given connection.
+    fn send(self, conn: &'c C) -> Result<Self::Cookie, ConnectionError>;
+}
+
 /// A connection to an X11 server for sending requests.
 ///
-/// This trait only contains functions that are used by other parts of this library. This means
-/// that users of this library will most likely not need these functions, unless they want to
-/// implement their own X11 connection.
+/// Apart from `send()`, this trait only contains functions that are used by other parts of this
+/// library. This means that users of this library will most likely not need these functions,
+/// unless they want to implement their own X11 connection.
 pub trait RequestConnection {
     /// Type used as buffer to store raw replies or events before
     /// they are parsed.
@@ -110,7 +165,7 @@ pub trait RequestConnection {
         fds: Vec<RawFdContainer>,
     ) -> Result<Cookie<'_, Self, R>, ConnectionError>
     where
-        R: for<'a> TryFrom<&'a [u8], Error = ParseError>;
+        R: TryParse;
 
     /// Send a request with a reply containing file descriptors to the server.
     ///
@@ -137,7 +192,7 @@ pub trait RequestConnection {
         fds: Vec<RawFdContainer>,
     ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
     where
-        R: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error = ParseError>;
+        R: TryParseFd;
 
     /// Send a request without a reply to the server.
     ///
@@ -263,6 +318,71 @@ pub trait RequestConnection {
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<BufWithFds<Self::Buf>, Self::Buf>, ConnectionError>;
 
+    /// Wait for the reply to a request, but give up after the given timeout.
+    ///
+    /// This works like `wait_for_reply_or_error()`, but returns `Ok(None)` if neither a reply nor
+    /// an error was received within `timeout`. In this case, it is possible to wait for the reply
+    /// again later.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_or_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Self::Buf>, ReplyError<Self::Buf>> {
+        match self.wait_for_reply_or_raw_error_timeout(sequence, timeout)? {
+            None => Ok(None),
+            Some(ReplyOrError::Reply(reply)) => Ok(Some(reply)),
+            Some(ReplyOrError::Error(error)) => Err(ReplyError::X11Error(self.parse_error(error)?)),
+        }
+    }
+
+    /// Wait for the reply to a request, but give up after the given timeout.
+    ///
+    /// This works like `wait_for_reply_or_raw_error()`, but returns `Ok(None)` if neither a reply
+    /// nor an error was received within `timeout`. In this case, it is possible to wait for the
+    /// reply again later.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<ReplyOrError<Self::Buf>>, ConnectionError>;
+
+    /// Wait for the reply to a request that has FDs, but give up after the given timeout.
+    ///
+    /// This works like `wait_for_reply_with_fds()`, but returns `Ok(None)` if neither a reply nor
+    /// an error was received within `timeout`. In this case, it is possible to wait for the reply
+    /// again later.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_with_fds_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<BufWithFds<Self::Buf>>, ReplyError<Self::Buf>> {
+        match self.wait_for_reply_with_fds_raw_timeout(sequence, timeout)? {
+            None => Ok(None),
+            Some(ReplyOrError::Reply(reply)) => Ok(Some(reply)),
+            Some(ReplyOrError::Error(error)) => Err(ReplyError::X11Error(self.parse_error(error)?)),
+        }
+    }
+
+    /// Wait for the reply to a request that has FDs, but give up after the given timeout.
+    ///
+    /// This works like `wait_for_reply_with_fds_raw()`, but returns `Ok(None)` if neither a reply
+    /// nor an error was received within `timeout`. In this case, it is possible to wait for the
+    /// reply again later.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    #[allow(clippy::type_complexity)]
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<ReplyOrError<BufWithFds<Self::Buf>, Self::Buf>>, ConnectionError>;
+
     /// Check whether a request that does not have a reply caused an X11 error.
     ///
     /// The given sequence number identifies the request for which the check should be performed.
@@ -317,6 +437,14 @@ pub trait RequestConnection {
     /// The maximum number of bytes that the X11 server accepts in a request.
     fn maximum_request_bytes(&self) -> usize;
 
+    /// The byte order that is used on this connection.
+    ///
+    /// Requests must be encoded in this byte order and replies, events, and errors are encoded in
+    /// it. The default implementation returns the native byte order of this machine.
+    fn byte_order(&self) -> ByteOrder {
+        ByteOrder::native()
+    }
+
     /// Parse a generic error.
     fn parse_error<E>(&self, error: E) -> Result<Error<E>, ParseError>
     where
@@ -326,6 +454,30 @@ pub trait RequestConnection {
     fn parse_event<E>(&self, event: E) -> Result<Event<E>, ParseError>
     where
         E: std::fmt::Debug + AsRef<[u8]>;
+
+    /// Send a request that is represented as a value.
+    ///
+    /// This does the same as the function for the request, but the arguments of the request are
+    /// given as a struct. The returned cookie has the same type as the one returned by the
+    /// function.
+    ///
+    /// ```no_run
+    /// use x11rb::connection::RequestConnection;
+    /// use x11rb::errors::ReplyError;
+    /// use x11rb::protocol::xproto::{GetGeometryRequest, MapWindowRequest, Window};
+    /// # fn do_it<C: RequestConnection>(conn: C, window: Window) -> Result<(), ReplyError<C::Buf>> {
+    /// conn.send(MapWindowRequest::default().window(window))?;
+    /// let geometry = conn.send(GetGeometryRequest { drawable: window.into() })?.reply()?;
+    /// println!("The window is {}x{}", geometry.width, geometry.height);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn send<'c, R>(&'c self, request: R) -> Result<R::Cookie, ConnectionError>
+    where
+        R: SendRequest<'c, Self>,
+    {
+        request.send(self)
+    }
 }
 
 /// A connection to an X11 server.
@@ -354,6 +506,53 @@ pub trait Connection: RequestConnection {
         &self,
     ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError>;
 
+    /// Wait for a new event from the X11 server, but at most for the given duration.
+    ///
+    /// Returns `Ok(None)` if no event was received within `timeout`.
+    fn wait_for_event_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Event<Self::Buf>>, ConnectionError> {
+        Ok(self
+            .wait_for_event_with_sequence_timeout(timeout)?
+            .map(|r| r.0))
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server, but at most for the given duration.
+    ///
+    /// Returns `Ok(None)` if no event was received within `timeout`.
+    fn wait_for_raw_event_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        Ok(self
+            .wait_for_raw_event_with_sequence_timeout(timeout)?
+            .map(|r| r.0))
+    }
+
+    /// Wait for a new event from the X11 server, but at most for the given duration.
+    ///
+    /// Returns `Ok(None)` if no event was received within `timeout`.
+    fn wait_for_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<EventAndSeqNumber<Self::Buf>>, ConnectionError> {
+        Ok(
+            match self.wait_for_raw_event_with_sequence_timeout(timeout)? {
+                Some((event, seq)) => Some((self.parse_event(event)?, seq)),
+                None => None,
+            },
+        )
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server, but at most for the given duration.
+    ///
+    /// Returns `Ok(None)` if no event was received within `timeout`.
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<RawEventAndSeqNumber<Self::Buf>>, ConnectionError>;
+
     /// Poll for a new event from the X11 server.
     fn poll_for_event(&self) -> Result<Option<Event<Self::Buf>>, ConnectionError> {
         Ok(self.poll_for_event_with_sequence()?.map(|r| r.0))
@@ -379,6 +578,90 @@ pub trait Connection: RequestConnection {
         &self,
     ) -> Result<Option<RawEventAndSeqNumber<Self::Buf>>, ConnectionError>;
 
+    /// Register a special event queue for events of the generic event extension.
+    ///
+    /// Afterwards, all events of the extension with the given name that carry the event context
+    /// id `eid` are no longer returned by e.g. `wait_for_event()`. Instead, they are put into a
+    /// dedicated queue that can be read via `wait_for_special_event()` and
+    /// `poll_for_special_event()`. This is e.g. useful for the events of the Present extension,
+    /// where each swap chain has its own event context. This corresponds to libxcb's
+    /// `xcb_register_for_special_xge()`.
+    ///
+    /// If the extension is not supported by the X11 server, an error is returned.
+    fn register_for_special_xge(
+        &self,
+        extension_name: &'static str,
+        eid: u32,
+    ) -> Result<SpecialEventQueue, ConnectionError>;
+
+    /// Unregister a special event queue.
+    ///
+    /// Events that are still in the queue are discarded. Later events for this event context are
+    /// returned by e.g. `wait_for_event()` again.
+    fn unregister_for_special_xge(&self, queue: SpecialEventQueue);
+
+    /// Wait for a new event in the given special event queue.
+    ///
+    /// Fails with `ConnectionError::UnregisteredSpecialEventQueue` if the queue is not registered.
+    fn wait_for_special_event(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Event<Self::Buf>, ConnectionError> {
+        let event = self.wait_for_raw_special_event(queue)?;
+        Ok(self.parse_event(event)?)
+    }
+
+    /// Wait for a new raw/unparsed event in the given special event queue.
+    ///
+    /// Fails with `ConnectionError::UnregisteredSpecialEventQueue` if the queue is not registered.
+    fn wait_for_raw_special_event(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Self::Buf, ConnectionError> {
+        Ok(self.wait_for_raw_special_event_with_sequence(queue)?.0)
+    }
+
+    /// Wait for a new raw/unparsed event in the given special event queue.
+    ///
+    /// Fails with `ConnectionError::UnregisteredSpecialEventQueue` if the queue is not registered.
+    fn wait_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError>;
+
+    /// Poll for a new event in the given special event queue.
+    ///
+    /// Fails with `ConnectionError::UnregisteredSpecialEventQueue` if the queue is not registered.
+    fn poll_for_special_event(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Option<Event<Self::Buf>>, ConnectionError> {
+        Ok(match self.poll_for_raw_special_event(queue)? {
+            Some(event) => Some(self.parse_event(event)?),
+            None => None,
+        })
+    }
+
+    /// Poll for a new raw/unparsed event in the given special event queue.
+    ///
+    /// Fails with `ConnectionError::UnregisteredSpecialEventQueue` if the queue is not registered.
+    fn poll_for_raw_special_event(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        Ok(self
+            .poll_for_raw_special_event_with_sequence(queue)?
+            .map(|r| r.0))
+    }
+
+    /// Poll for a new raw/unparsed event in the given special event queue.
+    ///
+    /// Fails with `ConnectionError::UnregisteredSpecialEventQueue` if the queue is not registered.
+    fn poll_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Option<RawEventAndSeqNumber<Self::Buf>>, ConnectionError>;
+
     /// Send all pending requests to the server.
     ///
     /// Implementations of this trait may buffer requests for batched sending. When this method is
@@ -436,12 +719,11 @@ pub enum DiscardMode {
 /// Example usage:
 /// ```
 /// use std::io::IoSlice;
-/// use std::convert::TryFrom;
 /// use x11rb::connection::{BufWithFds, RequestConnection, SequenceNumber, compute_length_field};
 /// use x11rb::cookie::{Cookie, CookieWithFds, VoidCookie};
 /// use x11rb::errors::{ParseError, ConnectionError};
 /// use x11rb::utils::RawFdContainer;
-/// use x11rb::x11_utils::ExtensionInformation;
+/// use x11rb::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
 /// # use x11rb::connection::ReplyOrError;
 ///
 /// struct MyConnection();
@@ -477,6 +759,16 @@ pub enum DiscardMode {
 ///     # -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
 ///     #    unimplemented!()
 ///     # }
+///     # fn wait_for_reply_or_raw_error_timeout(&self, sequence: SequenceNumber,
+///     #                                        timeout: std::time::Duration)
+///     # -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+///     #    unimplemented!()
+///     # }
+///     # fn wait_for_reply_with_fds_raw_timeout(&self, sequence: SequenceNumber,
+///     #                                        timeout: std::time::Duration)
+///     # -> Result<Option<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>>, ConnectionError> {
+///     #    unimplemented!()
+///     # }
 ///     # fn check_for_raw_error(&self, sequence: SequenceNumber)
 ///     # ->Result<Option<Vec<u8>>, ConnectionError> {
 ///     #    unimplemented!()
@@ -500,13 +792,13 @@ pub enum DiscardMode {
 ///
 ///     fn send_request_with_reply<R>(&self, bufs: &[IoSlice], fds: Vec<RawFdContainer>)
 ///     -> Result<Cookie<Self, R>, ConnectionError>
-///     where R: for<'a> TryFrom<&'a [u8], Error=ParseError> {
+///     where R: TryParse {
 ///         Ok(Cookie::new(self, self.send_request(bufs, fds, true, false)?))
 ///     }
 ///
 ///     fn send_request_with_reply_with_fds<R>(&self, bufs: &[IoSlice], fds: Vec<RawFdContainer>)
 ///     -> Result<CookieWithFds<Self, R>, ConnectionError>
-///     where R: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error=ParseError> {
+///     where R: TryParseFd {
 ///         Ok(CookieWithFds::new(self, self.send_request(bufs, fds, true, true)?))
 ///     }
 ///
@@ -547,7 +839,8 @@ pub fn compute_length_field<'b>(
     // If the length fits into an u16, just return the request as-is
     if let Ok(wire_length) = u16::try_from(wire_length) {
         // Check that the request contains the correct length field
-        let length_field = u16::from_ne_bytes([first_buf[2], first_buf[3]]);
+        let (length_field, _) = u16::try_parse_with_order(&first_buf[2..4], conn.byte_order())
+            .expect("The request is too short");
         assert_eq!(
             wire_length, length_field,
             "Length field contains incorrect value"
@@ -564,7 +857,7 @@ pub fn compute_length_field<'b>(
     let wire_length: u32 = wire_length
         .try_into()
         .expect("X11 request larger than 2^34 bytes?!?");
-    let wire_length = wire_length.to_ne_bytes();
+    let wire_length = wire_length.serialize_with_order(conn.byte_order());
 
     // Now construct the new IoSlices
 
@@ -597,3 +890,68 @@ pub fn compute_length_field<'b>(
 
     Ok(&storage.0[..])
 }
+
+#[cfg(test)]
+mod test {
+    #[cfg(unix)]
+    #[test]
+    fn send_request_structs() {
+        use std::io::{Read, Write};
+
+        use super::{Connection, RequestConnection};
+        use crate::protocol::xproto::{
+            ConnectionExt as _, CreateWindowAux, CreateWindowRequest, EventMask,
+            GetGeometryRequest, Window, WindowClass,
+        };
+        use crate::rust_connection::test::connected_pair;
+        use crate::x11_utils::Serialize;
+
+        let (conn, mut server) = connected_pair();
+        let (window, root) = (Window::from(0x1234), Window::from(0x42));
+        let aux = CreateWindowAux::new().event_mask(EventMask::StructureNotify);
+
+        // Sending a request struct produces the same bytes as the function for the request
+        let request = CreateWindowRequest::default()
+            .wid(window)
+            .parent(root)
+            .x(10)
+            .width(200)
+            .height(100)
+            .class(WindowClass::InputOutput)
+            .value_list(aux);
+        assert_eq!(request.depth, 0);
+        let _ = conn.send(request).unwrap();
+        let class = WindowClass::InputOutput;
+        let _ = conn
+            .create_window(0, window, root, 10, 0, 200, 100, 0, class, 0, &aux)
+            .unwrap();
+        conn.flush().unwrap();
+        let mut requests = [0; 72];
+        server.read_exact(&mut requests).unwrap();
+        assert_eq!(requests[0], 1);
+        assert_eq!(&requests[..36], &requests[36..]);
+
+        // Requests with a reply return the same cookie
+        let cookie = conn
+            .send(GetGeometryRequest::default().drawable(window))
+            .unwrap();
+        assert_eq!(cookie.sequence_number(), 3);
+        let mut request = [0; 8];
+        server.read_exact(&mut request).unwrap();
+        let mut reply = vec![1, 24];
+        reply.extend_from_slice(&3u16.serialize());
+        reply.extend_from_slice(&0u32.serialize());
+        reply.extend_from_slice(&u32::from(root).serialize());
+        reply.extend_from_slice(&10i16.serialize());
+        reply.extend_from_slice(&0i16.serialize());
+        reply.extend_from_slice(&200u16.serialize());
+        reply.extend_from_slice(&100u16.serialize());
+        reply.resize(32, 0);
+        server.write_all(&reply).unwrap();
+        let geometry = cookie.reply().unwrap();
+        assert_eq!(
+            (geometry.root, geometry.x, geometry.width, geometry.height),
+            (root, 10, 200, 100)
+        );
+    }
+}
@@ -1,13 +1,17 @@
 //! Cookies are handles to future replies or errors from the X11 server.
 
-use std::convert::{TryFrom, TryInto};
 use std::marker::PhantomData;
+use std::time::Duration;
 
+#[cfg(feature = "async")]
+use crate::async_connection::{
+    AsyncRequestConnection, CheckFuture, ReplyFuture, ReplyUncheckedFuture, ReplyWithFdsFuture,
+};
 use crate::connection::{BufWithFds, DiscardMode, RequestConnection, RequestKind, SequenceNumber};
-use crate::errors::{ConnectionError, ParseError, ReplyError};
+use crate::errors::{ConnectionError, ReplyError};
 use crate::protocol::xproto::ListFontsWithInfoReply;
 use crate::protocol::Error;
-use crate::utils::RawFdContainer;
+use crate::x11_utils::{TryParse, TryParseFd};
 
 /// A handle to a possible error from the X11 server.
 ///
@@ -42,7 +46,13 @@ where
         self.sequence_number
     }
 
-    fn consume(self) -> (&'a C, SequenceNumber) {
+    /// Get the connection that this cookie belongs to.
+    #[cfg(feature = "async")]
+    pub(crate) fn connection(&self) -> &'a C {
+        self.connection
+    }
+
+    pub(crate) fn consume(self) -> (&'a C, SequenceNumber) {
         let result = (self.connection, self.sequence_number);
         std::mem::forget(self);
         result
@@ -68,6 +78,19 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+impl<'a, C> VoidCookie<'a, C>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    /// Asynchronously check if the original request caused an X11 error.
+    ///
+    /// This is the asynchronous version of `check()`.
+    pub fn check_async(self) -> CheckFuture<'a, C> {
+        CheckFuture::new(self)
+    }
+}
+
 impl<C> Drop for VoidCookie<'_, C>
 where
     C: RequestConnection + ?Sized,
@@ -143,7 +166,7 @@ where
 
 impl<C, R> Cookie<'_, C, R>
 where
-    R: for<'a> TryFrom<&'a [u8], Error = ParseError>,
+    R: TryParse,
     C: RequestConnection + ?Sized,
 {
     /// Construct a new cookie.
@@ -176,13 +199,50 @@ where
 
     /// Get the reply that the server sent.
     pub fn reply(self) -> Result<R, ReplyError<C::Buf>> {
-        Ok(self.raw_reply()?.as_ref().try_into()?)
+        let order = self.raw_cookie.connection.byte_order();
+        Ok(R::try_parse_with_order(self.raw_reply()?.as_ref(), order)?.0)
+    }
+
+    /// Get the raw reply that the server sent, but wait at most for the given duration.
+    ///
+    /// If no response arrived within `timeout`, the cookie is given back as `Ok(Err(cookie))`, so
+    /// that it can be used to wait for the response again.
+    pub fn raw_reply_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<Result<C::Buf, Self>, ReplyError<C::Buf>> {
+        let conn = self.raw_cookie.connection;
+        let sequence = self.raw_cookie.sequence_number;
+        match conn.wait_for_reply_or_error_timeout(sequence, timeout) {
+            Ok(None) => Ok(Err(self)),
+            Ok(Some(reply)) => {
+                let _ = self.raw_cookie.into_sequence_number();
+                Ok(Ok(reply))
+            }
+            Err(e) => {
+                let _ = self.raw_cookie.into_sequence_number();
+                Err(e)
+            }
+        }
+    }
+
+    /// Get the reply that the server sent, but wait at most for the given duration.
+    ///
+    /// If no response arrived within `timeout`, the cookie is given back as `Ok(Err(cookie))`, so
+    /// that it can be used to wait for the response again.
+    pub fn reply_timeout(self, timeout: Duration) -> Result<Result<R, Self>, ReplyError<C::Buf>> {
+        let order = self.raw_cookie.connection.byte_order();
+        match self.raw_reply_timeout(timeout)? {
+            Ok(buf) => Ok(Ok(R::try_parse_with_order(buf.as_ref(), order)?.0)),
+            Err(cookie) => Ok(Err(cookie)),
+        }
     }
 
     /// Get the reply that the server sent, but have errors handled as events.
     pub fn reply_unchecked(self) -> Result<Option<R>, ConnectionError> {
+        let order = self.raw_cookie.connection.byte_order();
         self.raw_reply_unchecked()?
-            .map(|buf| buf.as_ref().try_into())
+            .map(|buf| R::try_parse_with_order(buf.as_ref(), order).map(|r| r.0))
             .transpose()
             .map_err(Into::into)
     }
@@ -203,6 +263,32 @@ where
     pub(crate) fn into_sequence_number(self) -> SequenceNumber {
         self.raw_cookie.into_sequence_number()
     }
+
+    /// Get the connection that this cookie belongs to.
+    pub(crate) fn connection(&self) -> &C {
+        self.raw_cookie.connection
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, C, R> Cookie<'a, C, R>
+where
+    R: TryParse,
+    C: AsyncRequestConnection + ?Sized,
+{
+    /// Asynchronously get the reply that the server sent.
+    ///
+    /// This is the asynchronous version of `reply()`.
+    pub fn reply_async(self) -> ReplyFuture<'a, C, R> {
+        ReplyFuture::new(self)
+    }
+
+    /// Asynchronously get the reply that the server sent, but have errors handled as events.
+    ///
+    /// This is the asynchronous version of `reply_unchecked()`.
+    pub fn reply_unchecked_async(self) -> ReplyUncheckedFuture<'a, C, R> {
+        ReplyUncheckedFuture::new(self)
+    }
 }
 
 /// A handle to a response containing `RawFd` from the X11 server.
@@ -222,7 +308,7 @@ where
 
 impl<C, R> CookieWithFds<'_, C, R>
 where
-    R: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error = ParseError>,
+    R: TryParseFd,
     C: RequestConnection + ?Sized,
 {
     /// Construct a new cookie.
@@ -249,8 +335,75 @@ where
 
     /// Get the reply that the server sent.
     pub fn reply(self) -> Result<R, ReplyError<C::Buf>> {
-        let (buffer, fds) = self.raw_reply()?;
-        Ok(R::try_from((buffer.as_ref(), fds))?)
+        let order = self.raw_cookie.connection.byte_order();
+        let (buffer, mut fds) = self.raw_reply()?;
+        Ok(R::try_parse_fd_with_order(buffer.as_ref(), &mut fds, order)?.0)
+    }
+
+    /// Get the raw reply that the server sent, but wait at most for the given duration.
+    ///
+    /// If no response arrived within `timeout`, the cookie is given back as `Ok(Err(cookie))`, so
+    /// that it can be used to wait for the response again.
+    #[allow(clippy::type_complexity)]
+    pub fn raw_reply_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<Result<BufWithFds<C::Buf>, Self>, ReplyError<C::Buf>> {
+        let conn = self.raw_cookie.connection;
+        let sequence = self.raw_cookie.sequence_number;
+        match conn.wait_for_reply_with_fds_timeout(sequence, timeout) {
+            Ok(None) => Ok(Err(self)),
+            Ok(Some(reply)) => {
+                let _ = self.raw_cookie.into_sequence_number();
+                Ok(Ok(reply))
+            }
+            Err(e) => {
+                let _ = self.raw_cookie.into_sequence_number();
+                Err(e)
+            }
+        }
+    }
+
+    /// Get the reply that the server sent, but wait at most for the given duration.
+    ///
+    /// If no response arrived within `timeout`, the cookie is given back as `Ok(Err(cookie))`, so
+    /// that it can be used to wait for the response again.
+    pub fn reply_timeout(self, timeout: Duration) -> Result<Result<R, Self>, ReplyError<C::Buf>> {
+        let order = self.raw_cookie.connection.byte_order();
+        match self.raw_reply_timeout(timeout)? {
+            Ok((buffer, mut fds)) => Ok(Ok(R::try_parse_fd_with_order(
+                buffer.as_ref(),
+                &mut fds,
+                order,
+            )?
+            .0)),
+            Err(cookie) => Ok(Err(cookie)),
+        }
+    }
+
+    /// Consume this instance and get the contained sequence number out.
+    pub(crate) fn into_sequence_number(self) -> SequenceNumber {
+        self.raw_cookie.into_sequence_number()
+    }
+
+    /// Get the connection that this cookie belongs to.
+    #[cfg(feature = "async")]
+    pub(crate) fn connection(&self) -> &C {
+        self.raw_cookie.connection
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, C, R> CookieWithFds<'a, C, R>
+where
+    R: TryParseFd,
+    C: AsyncRequestConnection + ?Sized,
+{
+    /// Asynchronously get the reply that the server sent.
+    ///
+    /// This is the asynchronous version of `reply()`.
+    pub fn reply_async(self) -> ReplyWithFdsFuture<'a, C, R> {
+        ReplyWithFdsFuture::new(self)
     }
 }
 
@@ -295,7 +448,10 @@ where
             Err(e) => return Some(Err(e)),
             Ok(v) => v,
         };
-        let reply = ListFontsWithInfoReply::try_from(reply.as_ref()).map_err(ReplyError::from);
+        let order = cookie.connection.byte_order();
+        let reply = ListFontsWithInfoReply::try_parse_with_order(reply.as_ref(), order)
+            .map(|r| r.0)
+            .map_err(ReplyError::from);
         match reply {
             // Is this an indicator that no more replies follow?
             Ok(ref reply) if reply.name.is_empty() => None,
@@ -57,7 +57,7 @@ impl<C: Connection> Cookie<'_, C> {
             if let Some((version, formats)) = self.render_info {
                 let version = version.reply()?;
                 render_version = (version.major_version, version.minor_version);
-                picture_format = find_format(formats.reply()?);
+                picture_format = find_format(formats.reply()?).into();
             }
         }
         Ok(Self::from_replies(
@@ -82,7 +82,7 @@ impl<C: Connection> Cookie<'_, C> {
                 match (version.reply_unchecked()?, formats.reply_unchecked()?) {
                     (Some(version), Some(formats)) => {
                         render_version = (version.major_version, version.minor_version);
-                        picture_format = find_format(formats);
+                        picture_format = find_format(formats).into();
                     }
                     _ => return Ok(None),
                 }
@@ -117,13 +117,13 @@ impl<C: Connection> Cookie<'_, C> {
         };
         let (theme, cursor_size, xft_dpi) = parse_resource_manager(&resource_manager.value);
         let cursor_size = get_cursor_size(cursor_size, xft_dpi, screen);
-        let cursor_font = conn.generate_id()?;
+        let cursor_font = conn.generate_id()?.into();
         xproto::open_font(conn, cursor_font, b"cursor")?;
         Ok(Handle {
             root: screen.root,
             cursor_font,
             #[cfg(feature = "render")]
-            picture_format: _picture_format,
+            picture_format: _picture_format.into(),
             render_support,
             theme,
             cursor_size,
@@ -224,7 +224,7 @@ fn create_core_cursor<C: Connection>(
     cursor_font: Font,
     cursor: u16,
 ) -> Result<xproto::Cursor, ReplyOrIdError<C::Buf>> {
-    let result = conn.generate_id()?;
+    let result = conn.generate_id()?.into();
     xproto::create_glyph_cursor(
         conn,
         result,
@@ -251,7 +251,7 @@ fn create_render_cursor<C: Connection>(
     image: &parse_cursor::Image,
     storage: &mut Option<(xproto::Pixmap, xproto::Gcontext, u16, u16)>,
 ) -> Result<render::Animcursorelt, ReplyOrIdError<C::Buf>> {
-    let (cursor, picture) = (conn.generate_id()?, conn.generate_id()?);
+    let (cursor, picture) = (conn.generate_id()?.into(), conn.generate_id()?.into());
 
     // Get a pixmap of the right size and a gc for it
     let (pixmap, gc) = if storage.map(|(_, _, w, h)| (w, h)) == Some((image.width, image.height)) {
@@ -262,7 +262,7 @@ fn create_render_cursor<C: Connection>(
             xproto::free_pixmap(conn, *pixmap)?;
             (*pixmap, *gc)
         } else {
-            (conn.generate_id()?, conn.generate_id()?)
+            (conn.generate_id()?.into(), conn.generate_id()?.into())
         };
         xproto::create_pixmap(conn, 32, pixmap, handle.root, image.width, image.height)?;
         xproto::create_gc(conn, gc, pixmap, &Default::default())?;
@@ -271,8 +271,9 @@ fn create_render_cursor<C: Connection>(
         (pixmap, gc)
     };
 
-    // Sigh. We need the pixel data as a bunch of bytes.
-    let pixels = crate::x11_utils::Serialize::serialize(&image.pixels[..]);
+    // Sigh. We need the pixel data as a bunch of bytes in the server's image byte order.
+    let order = conn.setup().image_byte_order.into();
+    let pixels = crate::x11_utils::Serialize::serialize_with_order(&image.pixels[..], order);
     xproto::put_image(
         conn,
         xproto::ImageFormat::ZPixmap,
@@ -310,7 +311,7 @@ fn load_cursor<C: Connection>(
 ) -> Result<xproto::Cursor, ReplyOrIdError<C::Buf>> {
     // Find the right cursor, load it directly if it is a core cursor
     let cursor_file = match open_cursor(&handle.theme, name) {
-        None => return Ok(NONE),
+        None => return Ok(xproto::Cursor::NONE),
         Some(find_cursor::Cursor::CoreChar(c)) => {
             return create_core_cursor(conn, handle.cursor_font, c)
         }
@@ -319,14 +320,14 @@ fn load_cursor<C: Connection>(
 
     // We have to load a file and use RENDER to create a cursor
     if handle.render_support == RenderSupport::None {
-        return Ok(NONE);
+        return Ok(xproto::Cursor::NONE);
     }
 
     #[cfg(not(feature = "render"))]
     {
         // No render support, no render cursors
         drop(cursor_file);
-        Ok(NONE)
+        Ok(xproto::Cursor::NONE)
     }
     #[cfg(feature = "render")]
     {
@@ -356,7 +357,7 @@ fn load_cursor<C: Connection>(
         if cursors.len() == 1 {
             Ok(cursors[0].cursor)
         } else {
-            let result = conn.generate_id()?;
+            let result = conn.generate_id()?.into();
             render::create_anim_cursor(conn, result, &cursors)?;
             for elem in cursors {
                 xproto::free_cursor(conn, elem.cursor)?;
@@ -128,6 +128,24 @@ pub enum ConnectionError {
     /// This is `XCB_CONN_CLOSED_MEM_INSUFFICIENT`.
     InsufficientMemory,
 
+    /// The X11 server closed the connection.
+    ///
+    /// This is reported instead of an `IOError` when reading from the connection hit end of file
+    /// or when writing failed because the other end was closed.
+    ConnectionClosed,
+
+    /// A special event queue was used that is not registered.
+    ///
+    /// Unlike the other errors, this does not affect the connection.
+    UnregisteredSpecialEventQueue,
+
+    /// A blocking method was called on an asynchronous connection and its result is not yet
+    /// available.
+    ///
+    /// Asynchronous connections never block the calling thread. Use the corresponding `*_async`
+    /// method instead. Like `UnregisteredSpecialEventQueue`, this does not affect the connection.
+    WouldBlock,
+
     /// An I/O error occurred on the connection.
     IOError(std::io::Error),
 }
@@ -145,6 +163,11 @@ impl std::fmt::Display for ConnectionError {
             }
             ConnectionError::FDPassingFailed => write!(f, "FD passing failed"),
             ConnectionError::ParseError => write!(f, "Parsing error"),
+            ConnectionError::ConnectionClosed => write!(f, "The connection was closed"),
+            ConnectionError::UnregisteredSpecialEventQueue => {
+                write!(f, "The special event queue is not registered")
+            }
+            ConnectionError::WouldBlock => write!(f, "The operation would block"),
             ConnectionError::IOError(err) => err.fmt(f),
         }
     }
@@ -257,3 +280,35 @@ impl<B: AsRef<[u8]> + std::fmt::Debug> From<ReplyError<B>> for ReplyOrIdError<B>
         }
     }
 }
+
+/// An error that occurred while (re-)establishing a connection with `ReconnectingConnection`.
+#[derive(Debug)]
+pub enum ReconnectError<B: AsRef<[u8]> + std::fmt::Debug> {
+    /// Connecting to the X11 server failed.
+    ConnectError(ConnectError),
+    /// Some request failed, for example during initialisation of the new connection.
+    ReplyOrIdError(ReplyOrIdError<B>),
+}
+
+impl<B: AsRef<[u8]> + std::fmt::Debug> std::fmt::Display for ReconnectError<B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReconnectError::ConnectError(e) => write!(f, "{}", e),
+            ReconnectError::ReplyOrIdError(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<B: AsRef<[u8]> + std::fmt::Debug> std::error::Error for ReconnectError<B> {}
+
+impl<B: AsRef<[u8]> + std::fmt::Debug> From<ConnectError> for ReconnectError<B> {
+    fn from(err: ConnectError) -> Self {
+        ReconnectError::ConnectError(err)
+    }
+}
+
+impl<B: AsRef<[u8]> + std::fmt::Debug> From<ReplyOrIdError<B>> for ReconnectError<B> {
+    fn from(err: ReplyOrIdError<B>) -> Self {
+        ReconnectError::ReplyOrIdError(err)
+    }
+}
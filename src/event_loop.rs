@@ -0,0 +1,317 @@
+//! Dispatching X11 events to handlers.
+//!
+//! Instead of writing a big `match` over [`Event`], handlers can be registered with a
+//! [`Dispatcher`] for a specific kind of event, for a specific window, or for both. The window of
+//! an event is determined with [`Event::window()`].
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::event_loop::{Action, Dispatcher};
+//! use x11rb::protocol::{Event, EventKind};
+//! use x11rb::protocol::xproto::ConnectionExt;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (conn, _) = x11rb::connect(None)?;
+//! # let window = x11rb::protocol::xproto::Window::NONE;
+//! let mut dispatcher = Dispatcher::new();
+//! let _ = dispatcher.add_handler(EventKind::MapRequest, |event| {
+//!     if let Event::MapRequest(event) = event {
+//!         conn.map_window(event.window)?;
+//!         conn.flush()?;
+//!     }
+//!     Ok(Action::Continue)
+//! });
+//! let _ = dispatcher.add_window_handler(window, Some(EventKind::DestroyNotify), |_| {
+//!     Ok(Action::Quit)
+//! });
+//! dispatcher.run(&conn)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::connection::Connection;
+use crate::errors::ReplyOrIdError;
+use crate::protocol::xproto::Window;
+use crate::protocol::{Event, EventKind};
+
+/// What should happen after a handler handled an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Pass the event on to the next matching handler.
+    Continue,
+    /// Do not call any further handlers for this event.
+    Stop,
+    /// Do not call any further handlers for this event and leave the event loop.
+    Quit,
+}
+
+/// An identifier for a handler that was added to a [`Dispatcher`].
+///
+/// This can be used to remove the handler again via [`Dispatcher::remove_handler()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandlerId(u64);
+
+type Handler<'a, B> = Box<dyn FnMut(&Event<B>) -> Result<Action, ReplyOrIdError<B>> + 'a>;
+
+struct Entry<'a, B: std::fmt::Debug + AsRef<[u8]>> {
+    id: HandlerId,
+    kind: Option<EventKind>,
+    window: Option<Window>,
+    handler: Handler<'a, B>,
+}
+
+impl<B: std::fmt::Debug + AsRef<[u8]>> Entry<'_, B> {
+    fn matches(&self, kind: EventKind, window: Option<Window>) -> bool {
+        (self.kind.is_none() || self.kind == Some(kind))
+            && (self.window.is_none() || self.window == window)
+    }
+}
+
+/// Calls registered handlers for X11 events.
+///
+/// For each event, all matching handlers are called in the order in which they were added,
+/// until one of them returns [`Action::Stop`] or [`Action::Quit`], or fails. Handlers may borrow
+/// from their environment, e.g. the connection that the events come from.
+pub struct Dispatcher<'a, B: std::fmt::Debug + AsRef<[u8]>> {
+    handlers: Vec<Entry<'a, B>>,
+    next_id: u64,
+}
+
+impl<B: std::fmt::Debug + AsRef<[u8]>> std::fmt::Debug for Dispatcher<'_, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(
+                self.handlers
+                    .iter()
+                    .map(|entry| (entry.id, entry.kind, entry.window)),
+            )
+            .finish()
+    }
+}
+
+impl<B: std::fmt::Debug + AsRef<[u8]>> Default for Dispatcher<'_, B> {
+    fn default() -> Self {
+        Self {
+            handlers: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+impl<'a, B: std::fmt::Debug + AsRef<[u8]>> Dispatcher<'a, B> {
+    /// Create a new dispatcher without any handlers.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a handler that is called for every event of the given kind.
+    pub fn add_handler<F>(&mut self, kind: EventKind, handler: F) -> HandlerId
+    where
+        F: FnMut(&Event<B>) -> Result<Action, ReplyOrIdError<B>> + 'a,
+    {
+        self.add_entry(Some(kind), None, Box::new(handler))
+    }
+
+    /// Add a handler that is called for events that refer to the given window.
+    ///
+    /// If `kind` is `None`, the handler is called for all kinds of events. Otherwise, it is only
+    /// called for events of the given kind.
+    pub fn add_window_handler<F>(
+        &mut self,
+        window: Window,
+        kind: Option<EventKind>,
+        handler: F,
+    ) -> HandlerId
+    where
+        F: FnMut(&Event<B>) -> Result<Action, ReplyOrIdError<B>> + 'a,
+    {
+        self.add_entry(kind, Some(window), Box::new(handler))
+    }
+
+    /// Add a handler that is called for all events.
+    pub fn add_catch_all_handler<F>(&mut self, handler: F) -> HandlerId
+    where
+        F: FnMut(&Event<B>) -> Result<Action, ReplyOrIdError<B>> + 'a,
+    {
+        self.add_entry(None, None, Box::new(handler))
+    }
+
+    fn add_entry(
+        &mut self,
+        kind: Option<EventKind>,
+        window: Option<Window>,
+        handler: Handler<'a, B>,
+    ) -> HandlerId {
+        let id = HandlerId(self.next_id);
+        self.next_id += 1;
+        self.handlers.push(Entry {
+            id,
+            kind,
+            window,
+            handler,
+        });
+        id
+    }
+
+    /// Remove a handler.
+    ///
+    /// Returns `false` if there is no handler with the given id, e.g. because it was already
+    /// removed.
+    pub fn remove_handler(&mut self, id: HandlerId) -> bool {
+        match self.handlers.iter().position(|entry| entry.id == id) {
+            Some(index) => {
+                let _ = self.handlers.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove all handlers that were added for the given window.
+    ///
+    /// This is useful when a window is destroyed.
+    pub fn remove_window_handlers(&mut self, window: Window) {
+        self.handlers.retain(|entry| entry.window != Some(window));
+    }
+
+    /// Call all matching handlers for the given event.
+    ///
+    /// This returns `Action::Quit` if some handler asked to leave the event loop and
+    /// `Action::Continue` otherwise.
+    pub fn dispatch(&mut self, event: &Event<B>) -> Result<Action, ReplyOrIdError<B>> {
+        let (kind, window) = (event.kind(), event.window());
+        for entry in self.handlers.iter_mut() {
+            if !entry.matches(kind, window) {
+                continue;
+            }
+            match (entry.handler)(event)? {
+                Action::Continue => {}
+                Action::Stop => break,
+                Action::Quit => return Ok(Action::Quit),
+            }
+        }
+        Ok(Action::Continue)
+    }
+
+    /// Dispatch events from the connection until a handler returns `Action::Quit`.
+    ///
+    /// This blocks in `wait_for_event()` when no events are available. The connection is flushed
+    /// before waiting.
+    pub fn run<C>(&mut self, conn: &C) -> Result<(), ReplyOrIdError<B>>
+    where
+        C: Connection<Buf = B>,
+        B: Send + Sync + 'static,
+    {
+        loop {
+            conn.flush()?;
+            let event = conn.wait_for_event()?;
+            if self.dispatch(&event)? == Action::Quit {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Dispatch all events that are already available without blocking.
+    ///
+    /// This uses `poll_for_event()` and returns `true` if a handler returned `Action::Quit`.
+    /// Remaining events stay queued in the connection in that case.
+    pub fn run_pending<C>(&mut self, conn: &C) -> Result<bool, ReplyOrIdError<B>>
+    where
+        C: Connection<Buf = B>,
+        B: Send + Sync + 'static,
+    {
+        while let Some(event) = conn.poll_for_event()? {
+            if self.dispatch(&event)? == Action::Quit {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use super::{Action, Dispatcher};
+    use crate::protocol::xproto::{MapNotifyEvent, Window, MAP_NOTIFY_EVENT};
+    use crate::protocol::{Event, EventKind};
+
+    fn map_notify(window: u32) -> Event<Vec<u8>> {
+        Event::MapNotify(MapNotifyEvent {
+            response_type: MAP_NOTIFY_EVENT,
+            sequence: 0,
+            event: window.into(),
+            window: window.into(),
+            override_redirect: false,
+        })
+    }
+
+    #[test]
+    fn dispatch_order_and_filters() {
+        let calls = RefCell::new(Vec::new());
+        let mut dispatcher = Dispatcher::new();
+        let _ = dispatcher.add_catch_all_handler(|_| {
+            calls.borrow_mut().push("all");
+            Ok(Action::Continue)
+        });
+        let _ = dispatcher.add_handler(EventKind::UnmapNotify, |_| {
+            calls.borrow_mut().push("unmap");
+            Ok(Action::Continue)
+        });
+        let _ = dispatcher.add_window_handler(Window::from(1), Some(EventKind::MapNotify), |_| {
+            calls.borrow_mut().push("map 1");
+            Ok(Action::Stop)
+        });
+        let _ = dispatcher.add_window_handler(Window::from(2), None, |_| {
+            calls.borrow_mut().push("window 2");
+            Ok(Action::Continue)
+        });
+        let _ = dispatcher.add_handler(EventKind::MapNotify, |_| {
+            calls.borrow_mut().push("map");
+            Ok(Action::Continue)
+        });
+
+        assert_eq!(
+            dispatcher.dispatch(&map_notify(1)).unwrap(),
+            Action::Continue
+        );
+        assert_eq!(*calls.borrow(), ["all", "map 1"]);
+        calls.borrow_mut().clear();
+
+        assert_eq!(
+            dispatcher.dispatch(&map_notify(2)).unwrap(),
+            Action::Continue
+        );
+        assert_eq!(*calls.borrow(), ["all", "window 2", "map"]);
+    }
+
+    #[test]
+    fn remove_and_quit() {
+        let calls = RefCell::new(0);
+        let mut dispatcher = Dispatcher::new();
+        let first = dispatcher.add_catch_all_handler(|_| Ok(Action::Quit));
+        let _ = dispatcher.add_window_handler(Window::from(3), None, |_| {
+            *calls.borrow_mut() += 1;
+            Ok(Action::Continue)
+        });
+
+        assert_eq!(dispatcher.dispatch(&map_notify(3)).unwrap(), Action::Quit);
+        assert_eq!(*calls.borrow(), 0);
+
+        assert!(dispatcher.remove_handler(first));
+        assert!(!dispatcher.remove_handler(first));
+        assert_eq!(
+            dispatcher.dispatch(&map_notify(3)).unwrap(),
+            Action::Continue
+        );
+        assert_eq!(*calls.borrow(), 1);
+
+        dispatcher.remove_window_handlers(Window::from(3));
+        assert_eq!(
+            dispatcher.dispatch(&map_notify(3)).unwrap(),
+            Action::Continue
+        );
+        assert_eq!(*calls.borrow(), 1);
+    }
+}
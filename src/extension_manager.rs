@@ -53,6 +53,21 @@ impl ExtensionManager {
         Ok(())
     }
 
+    /// Get the sequence number of the `QueryExtension` request for the given extension.
+    ///
+    /// This returns `None` if the extension was not prefetched or if its reply was already
+    /// received and processed.
+    #[cfg(feature = "async")]
+    pub(crate) fn prefetched_sequence_number(
+        &self,
+        extension_name: &'static str,
+    ) -> Option<SequenceNumber> {
+        match self.0.get(extension_name) {
+            Some(CheckState::Prefetched(sequence_number)) => Some(*sequence_number),
+            _ => None,
+        }
+    }
+
     /// An implementation of `RequestConnection::extension_information()`.
     ///
     /// The given connection is used for sending a `QueryExtension` request if needed.
@@ -149,8 +164,8 @@ impl ExtInfoProvider for ExtensionManager {
 #[cfg(test)]
 mod test {
     use std::cell::RefCell;
-    use std::convert::TryFrom;
     use std::io::IoSlice;
+    use std::time::Duration;
 
     use crate::connection::{
         BufWithFds, DiscardMode, ReplyOrError, RequestConnection, RequestKind, SequenceNumber,
@@ -158,7 +173,7 @@ mod test {
     use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
     use crate::errors::{ConnectionError, ParseError};
     use crate::utils::RawFdContainer;
-    use crate::x11_utils::{ExtInfoProvider, ExtensionInformation};
+    use crate::x11_utils::{ExtInfoProvider, ExtensionInformation, TryParse, TryParseFd};
 
     use super::{CheckState, ExtensionManager};
 
@@ -173,7 +188,7 @@ mod test {
             _fds: Vec<RawFdContainer>,
         ) -> Result<Cookie<'_, Self, R>, ConnectionError>
         where
-            R: for<'a> TryFrom<&'a [u8], Error = ParseError>,
+            R: TryParse,
         {
             Ok(Cookie::new(self, 1))
         }
@@ -184,7 +199,7 @@ mod test {
             _fds: Vec<RawFdContainer>,
         ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
         where
-            R: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error = ParseError>,
+            R: TryParseFd,
         {
             unimplemented!()
         }
@@ -247,6 +262,22 @@ mod test {
             unimplemented!()
         }
 
+        fn wait_for_reply_or_raw_error_timeout(
+            &self,
+            _sequence: SequenceNumber,
+            _timeout: Duration,
+        ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+            unimplemented!()
+        }
+
+        fn wait_for_reply_with_fds_raw_timeout(
+            &self,
+            _sequence: SequenceNumber,
+            _timeout: Duration,
+        ) -> Result<Option<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>>, ConnectionError> {
+            unimplemented!()
+        }
+
         fn check_for_raw_error(
             &self,
             _sequence: SequenceNumber,
@@ -49,7 +49,7 @@
 //! fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     let (conn, screen_num) = x11rb::connect(None).unwrap();
 //!     let screen = &conn.setup().roots[screen_num];
-//!     let win_id = conn.generate_id()?;
+//!     let win_id = conn.generate_id()?.into();
 //!     conn.create_window(COPY_DEPTH_FROM_PARENT, win_id, screen.root, 0, 0, 100, 100, 0, WindowClass::InputOutput,
 //!                        0, &CreateWindowAux::new().background_pixel(screen.white_pixel))?;
 //!     conn.map_window(win_id)?;
@@ -80,10 +80,22 @@
 //! Additionally, the following flags are enabled by default:
 //! * `allow-unsafe-code`: Enable features that require `unsafe`. Without this flag,
 //!   `x11rb::xcb_ffi::XCBConnection` and some support code for it are unavailable.
+//!
+//! The following flags are disabled by default:
+//! * `async`: Provide `x11rb::async_connection` and `AsyncRustConnection` for using x11rb with
+//!   async/await. This needs Rust 1.51.
+//! * `async-tokio`: Additionally provide `AsyncRustConnection::connect()` and a tokio-based
+//!   stream for it. This implies `async`.
+//! * `log`: Provide `x11rb::trace::LogSink` for logging the protocol traffic via the `log` crate.
+//! * `testing`: Provide `x11rb::testing` with an in-process fake X11 server for tests. This is
+//!   only available on Unix.
 
 // This lint suggests a function that was added in Rust 1.40.0. Since our minimum supported version
 // is Rust 1.37.0, just disable the lint.
 #![allow(clippy::option_as_ref_deref)]
+// The same applies to these lints, which suggest `std::mem::take()` from Rust 1.40.0 and
+// `matches!` from Rust 1.42.0.
+#![allow(clippy::mem_replace_with_default, clippy::match_like_matches_macro)]
 #![deny(
     missing_copy_implementations,
     missing_debug_implementations,
@@ -105,13 +117,23 @@ pub mod utils;
 pub mod xcb_ffi;
 #[macro_use]
 pub mod x11_utils;
+#[cfg(feature = "async")]
+pub mod async_connection;
 pub mod connection;
 pub mod cookie;
 pub mod cursor;
 pub mod errors;
+pub mod event_loop;
 pub mod extension_manager;
 pub mod properties;
+#[cfg(unix)]
+pub mod proxy;
+pub mod reconnect;
 pub mod rust_connection;
+pub mod stats;
+#[cfg(all(unix, feature = "testing"))]
+pub mod testing;
+pub mod trace;
 pub mod wrapper;
 #[rustfmt::skip]
 pub mod protocol;
@@ -144,6 +166,8 @@ pub fn connect(
 }
 
 /// The universal null resource or null atom parameter value for many core X requests
+///
+/// The XID types like `Window` or `Atom` have their own `NONE` constant, e.g. `Window::NONE`.
 pub const NONE: u32 = 0;
 
 /// This constant can be used for many parameters in `create_window`
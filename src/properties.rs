@@ -6,7 +6,7 @@ use crate::connection::RequestConnection;
 use crate::cookie::{Cookie, VoidCookie};
 use crate::errors::{ConnectionError, ParseError, ReplyError};
 use crate::protocol::xproto::{self, Atom, AtomEnum, GetPropertyReply, Window};
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, Serialize, TryParse};
 
 // WM_CLASS
 
@@ -70,7 +70,7 @@ where
 ///     let class = std::str::from_utf8(wm_class.class());
 ///     let instance = std::str::from_utf8(wm_class.instance());
 ///     println!("For window {:x}, class is '{:?}' and instance is '{:?}'",
-///         window, class, instance);
+///         u32::from(window), class, instance);
 ///     Ok(true)
 /// }
 /// ```
@@ -163,14 +163,16 @@ where
 
     /// Get the reply that the server sent.
     pub fn reply(self) -> Result<WmSizeHints, ReplyError<Conn::Buf>> {
-        Ok(WmSizeHints::from_reply(self.0.reply()?)?)
+        let order = self.0.connection().byte_order();
+        Ok(WmSizeHints::from_reply_with_order(self.0.reply()?, order)?)
     }
 
     /// Get the reply that the server sent, but have errors handled as events.
     pub fn reply_unchecked(self) -> Result<Option<WmSizeHints>, ConnectionError> {
+        let order = self.0.connection().byte_order();
         self.0
             .reply_unchecked()?
-            .map(WmSizeHints::from_reply)
+            .map(|reply| WmSizeHints::from_reply_with_order(reply, order))
             .transpose()
             .map_err(Into::into)
     }
@@ -205,8 +207,8 @@ impl AspectRatio {
 }
 
 impl TryParse for AspectRatio {
-    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let ((numerator, denominator), remaining) = TryParse::try_parse(value)?;
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let ((numerator, denominator), remaining) = TryParse::try_parse_with_order(value, order)?;
         let result = AspectRatio::new(numerator, denominator);
         Ok((result, remaining))
     }
@@ -215,13 +217,13 @@ impl TryParse for AspectRatio {
 #[allow(clippy::many_single_char_names)]
 impl Serialize for AspectRatio {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> Self::Bytes {
-        let [a, b, c, d] = self.numerator.serialize();
-        let [e, f, g, h] = self.denominator.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+        let [a, b, c, d] = self.numerator.serialize_with_order(order);
+        let [e, f, g, h] = self.denominator.serialize_with_order(order);
         [a, b, c, d, e, f, g, h]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
-        (self.numerator, self.denominator).serialize_into(bytes);
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        (self.numerator, self.denominator).serialize_into_with_order(bytes, order);
     }
 }
 
@@ -267,10 +269,19 @@ impl WmSizeHints {
     /// The original `WmSizeHints` request must have been for a `WM_SIZE_HINTS` property for this
     /// function to return sensible results.
     pub fn from_reply(reply: GetPropertyReply) -> Result<Self, ParseError> {
+        Self::from_reply_with_order(reply, ByteOrder::native())
+    }
+
+    /// Construct a new `WmSizeHints` instance from a `GetPropertyReply` that was received on a
+    /// connection with the given byte order.
+    pub fn from_reply_with_order(
+        reply: GetPropertyReply,
+        order: ByteOrder,
+    ) -> Result<Self, ParseError> {
         if reply.type_ != AtomEnum::WM_SIZE_HINTS.into() || reply.format != 32 {
             return Err(ParseError::ParseError);
         }
-        Ok(Self::try_parse(&reply.value)?.0)
+        Ok(Self::try_parse_with_order(&reply.value, order)?.0)
     }
 
     /// Set these `WM_SIZE_HINTS` on some window as the `WM_NORMAL_HINTS` property.
@@ -289,7 +300,7 @@ impl WmSizeHints {
         window: Window,
         property: impl Into<Atom>,
     ) -> Result<VoidCookie<'a, C>, ConnectionError> {
-        let data = self.serialize();
+        let data = self.serialize_with_order(conn.byte_order());
         xproto::change_property(
             conn,
             xproto::PropMode::Replace,
@@ -304,28 +315,33 @@ impl WmSizeHints {
 }
 
 impl TryParse for WmSizeHints {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(
+        remaining: &[u8],
+        order: ByteOrder,
+    ) -> Result<(Self, &[u8]), ParseError> {
         // Implemented based on what xcb_icccm does. At least a bit. This stuff makes no sense...
 
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (x, remaining) = i32::try_parse(remaining)?;
-        let (y, remaining) = i32::try_parse(remaining)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
-        let (min_size, remaining) = parse_with_flag::<(i32, i32)>(remaining, flags, P_MIN_SIZE)?;
-        let (max_size, remaining) = parse_with_flag::<(i32, i32)>(remaining, flags, P_MAX_SIZE)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (min_size, remaining) =
+            parse_with_flag::<(i32, i32)>(remaining, flags, P_MIN_SIZE, order)?;
+        let (max_size, remaining) =
+            parse_with_flag::<(i32, i32)>(remaining, flags, P_MAX_SIZE, order)?;
         let (size_increment, remaining) =
-            parse_with_flag::<(i32, i32)>(remaining, flags, P_RESIZE_INCREMENT)?;
+            parse_with_flag::<(i32, i32)>(remaining, flags, P_RESIZE_INCREMENT, order)?;
         let (aspect, remaining) =
-            parse_with_flag::<(AspectRatio, AspectRatio)>(remaining, flags, P_ASPECT)?;
+            parse_with_flag::<(AspectRatio, AspectRatio)>(remaining, flags, P_ASPECT, order)?;
         // Apparently, some older version of ICCCM didn't have these...?
         let (base_size, wire_win_gravity, remaining) = if remaining.is_empty() {
             (min_size, Some(1), remaining)
         } else {
             let (base_size, remaining) =
-                parse_with_flag::<(i32, i32)>(remaining, flags, P_BASE_SIZE)?;
+                parse_with_flag::<(i32, i32)>(remaining, flags, P_BASE_SIZE, order)?;
             let (wire_win_gravity, remaining) =
-                parse_with_flag::<u32>(remaining, flags, P_WIN_GRAVITY)?;
+                parse_with_flag::<u32>(remaining, flags, P_WIN_GRAVITY, order)?;
             (base_size, wire_win_gravity, remaining)
         };
 
@@ -381,13 +397,13 @@ impl TryParse for WmSizeHints {
 
 impl Serialize for WmSizeHints {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Self::Bytes {
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
         // 18*4 surely fits into an usize, so this unwrap() cannot trigger
         let mut result = Vec::with_capacity((NUM_WM_SIZE_HINTS_ELEMENTS * 4).try_into().unwrap());
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let mut flags = 0;
         match self.position {
             Some((WmSizeHintsSpecification::UserSpecified, _, _)) => flags |= U_S_POSITION,
@@ -405,28 +421,38 @@ impl Serialize for WmSizeHints {
         flags |= self.aspect.map_or(0, |_| P_ASPECT);
         flags |= self.base_size.map_or(0, |_| P_BASE_SIZE);
         flags |= self.win_gravity.map_or(0, |_| P_WIN_GRAVITY);
-        flags.serialize_into(bytes);
+        flags.serialize_into_with_order(bytes, order);
 
         match self.position {
             Some((_, x, y)) => (x, y),
             None => (0, 0),
         }
-        .serialize_into(bytes);
+        .serialize_into_with_order(bytes, order);
 
         match self.size {
             Some((_, width, height)) => (width, height),
             None => (0, 0),
         }
-        .serialize_into(bytes);
-
-        self.min_size.unwrap_or((0, 0)).serialize_into(bytes);
-        self.max_size.unwrap_or((0, 0)).serialize_into(bytes);
-        self.size_increment.unwrap_or((0, 0)).serialize_into(bytes);
+        .serialize_into_with_order(bytes, order);
+
+        self.min_size
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
+        self.max_size
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
+        self.size_increment
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
         self.aspect
             .unwrap_or((AspectRatio::new(0, 0), AspectRatio::new(0, 0)))
-            .serialize_into(bytes);
-        self.base_size.unwrap_or((0, 0)).serialize_into(bytes);
-        self.win_gravity.map_or(0, u32::from).serialize_into(bytes);
+            .serialize_into_with_order(bytes, order);
+        self.base_size
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
+        self.win_gravity
+            .map_or(0, u32::from)
+            .serialize_into_with_order(bytes, order);
     }
 }
 
@@ -459,14 +485,16 @@ where
 
     /// Get the reply that the server sent.
     pub fn reply(self) -> Result<WmHints, ReplyError<Conn::Buf>> {
-        Ok(WmHints::from_reply(self.0.reply()?)?)
+        let order = self.0.connection().byte_order();
+        Ok(WmHints::from_reply_with_order(self.0.reply()?, order)?)
     }
 
     /// Get the reply that the server sent, but have errors handled as events.
     pub fn reply_unchecked(self) -> Result<Option<WmHints>, ConnectionError> {
+        let order = self.0.connection().byte_order();
         self.0
             .reply_unchecked()?
-            .map(WmHints::from_reply)
+            .map(|reply| WmHints::from_reply_with_order(reply, order))
             .transpose()
             .map_err(Into::into)
     }
@@ -523,11 +551,20 @@ impl WmHints {
     /// The original `WmHints` request must have been for a `WM_HINTS` property for this
     /// function to return sensible results.
     pub fn from_reply(reply: GetPropertyReply) -> Result<Self, ParseError> {
+        Self::from_reply_with_order(reply, ByteOrder::native())
+    }
+
+    /// Construct a new `WmHints` instance from a `GetPropertyReply` that was received on a
+    /// connection with the given byte order.
+    pub fn from_reply_with_order(
+        reply: GetPropertyReply,
+        order: ByteOrder,
+    ) -> Result<Self, ParseError> {
         if reply.type_ != AtomEnum::WM_HINTS.into() || reply.format != 32 {
             return Err(ParseError::ParseError);
         }
 
-        Ok(Self::try_parse(&reply.value)?.0)
+        Ok(Self::try_parse_with_order(&reply.value, order)?.0)
     }
 
     /// Set these `WM_HINTS` on some window.
@@ -536,7 +573,7 @@ impl WmHints {
         conn: &'a C,
         window: Window,
     ) -> Result<VoidCookie<'a, C>, ConnectionError> {
-        let data = self.serialize();
+        let data = self.serialize_with_order(conn.byte_order());
         xproto::change_property(
             conn,
             xproto::PropMode::Replace,
@@ -551,21 +588,28 @@ impl WmHints {
 }
 
 impl TryParse for WmHints {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (input, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_INPUT)?;
-        let (initial_state, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_STATE)?;
-        let (icon_pixmap, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_ICON_PIXMAP)?;
-        let (icon_window, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_ICON_WINDOW)?;
+    fn try_parse_with_order(
+        remaining: &[u8],
+        order: ByteOrder,
+    ) -> Result<(Self, &[u8]), ParseError> {
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (input, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_INPUT, order)?;
+        let (initial_state, remaining) =
+            parse_with_flag::<u32>(remaining, flags, HINT_STATE, order)?;
+        let (icon_pixmap, remaining) =
+            parse_with_flag::<xproto::Pixmap>(remaining, flags, HINT_ICON_PIXMAP, order)?;
+        let (icon_window, remaining) =
+            parse_with_flag::<Window>(remaining, flags, HINT_ICON_WINDOW, order)?;
         let (icon_position, remaining) =
-            parse_with_flag::<(i32, i32)>(remaining, flags, HINT_ICON_POSITION)?;
-        let (icon_mask, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_ICON_MASK)?;
+            parse_with_flag::<(i32, i32)>(remaining, flags, HINT_ICON_POSITION, order)?;
+        let (icon_mask, remaining) =
+            parse_with_flag::<xproto::Pixmap>(remaining, flags, HINT_ICON_MASK, order)?;
         // Apparently, some older version of ICCCM didn't have this...?
         let (window_group, remaining) = if remaining.is_empty() {
             (None, remaining)
         } else {
             let (window_group, remaining) =
-                parse_with_flag::<u32>(remaining, flags, HINT_WINDOW_GROUP)?;
+                parse_with_flag::<Window>(remaining, flags, HINT_WINDOW_GROUP, order)?;
             (window_group, remaining)
         };
 
@@ -598,13 +642,13 @@ impl TryParse for WmHints {
 
 impl Serialize for WmHints {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Self::Bytes {
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
         // 9*4 surely fits into an usize, so this unwrap() cannot trigger
         let mut result = Vec::with_capacity((NUM_WM_HINTS_ELEMENTS * 4).try_into().unwrap());
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let mut flags = 0;
         flags |= self.input.map_or(0, |_| HINT_INPUT);
         flags |= self.initial_state.map_or(0, |_| HINT_STATE);
@@ -617,19 +661,29 @@ impl Serialize for WmHints {
             flags |= HINT_URGENCY;
         }
 
-        flags.serialize_into(bytes);
-        u32::from(self.input.unwrap_or(false)).serialize_into(bytes);
+        flags.serialize_into_with_order(bytes, order);
+        u32::from(self.input.unwrap_or(false)).serialize_into_with_order(bytes, order);
         match self.initial_state {
             Some(WmHintsState::Normal) => 1,
             Some(WmHintsState::Iconic) => 3,
             None => 0,
         }
-        .serialize_into(bytes);
-        self.icon_pixmap.unwrap_or(0).serialize_into(bytes);
-        self.icon_window.unwrap_or(0).serialize_into(bytes);
-        self.icon_position.unwrap_or((0, 0)).serialize_into(bytes);
-        self.icon_mask.unwrap_or(0).serialize_into(bytes);
-        self.window_group.unwrap_or(0).serialize_into(bytes);
+        .serialize_into_with_order(bytes, order);
+        self.icon_pixmap
+            .unwrap_or(xproto::Pixmap::NONE)
+            .serialize_into_with_order(bytes, order);
+        self.icon_window
+            .unwrap_or(Window::NONE)
+            .serialize_into_with_order(bytes, order);
+        self.icon_position
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
+        self.icon_mask
+            .unwrap_or(xproto::Pixmap::NONE)
+            .serialize_into_with_order(bytes, order);
+        self.window_group
+            .unwrap_or(Window::NONE)
+            .serialize_into_with_order(bytes, order);
     }
 }
 
@@ -639,8 +693,9 @@ fn parse_with_flag<T: TryParse>(
     remaining: &[u8],
     flags: u32,
     bit: u32,
+    order: ByteOrder,
 ) -> Result<(Option<T>, &[u8]), ParseError> {
-    let (value, remaining) = T::try_parse(remaining)?;
+    let (value, remaining) = T::try_parse_with_order(remaining, order)?;
     if flags & bit != 0 {
         Ok((Some(value), remaining))
     } else {
@@ -653,7 +708,7 @@ mod test {
     use std::convert::TryInto;
 
     use super::{WmClass, WmHints, WmHintsState, WmSizeHints};
-    use crate::protocol::xproto::{Atom, AtomEnum, GetPropertyReply, Gravity};
+    use crate::protocol::xproto::{Atom, AtomEnum, GetPropertyReply, Gravity, Window};
     use crate::x11_utils::Serialize;
 
     fn get_property_reply(value: &[u8], format: u8, type_: impl Into<Atom>) -> GetPropertyReply {
@@ -767,7 +822,7 @@ mod test {
         assert_eq!(wm_hints.icon_window, None);
         assert_eq!(wm_hints.icon_position, None);
         assert_eq!(wm_hints.icon_mask, None);
-        assert_eq!(wm_hints.window_group, Some(0x0060_0009));
+        assert_eq!(wm_hints.window_group, Some(Window::from(0x0060_0009)));
         assert_eq!(wm_hints.urgent, false);
 
         assert_eq!(input, wm_hints.serialize());
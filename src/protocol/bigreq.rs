@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -32,12 +32,30 @@ pub const X11_XML_VERSION: (u32, u32) = (0, 0);
 
 /// Opcode for the Enable request
 pub const ENABLE_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnableRequest {
+}
+impl EnableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ENABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(EnableRequest {
+        })
+    }
+}
+
 pub fn enable<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, EnableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -48,9 +66,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for EnableRequest {
+    type Reply = EnableReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for EnableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, EnableReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        enable(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct EnableReply {
@@ -60,12 +90,12 @@ pub struct EnableReply {
     pub maximum_request_length: u32,
 }
 impl TryParse for EnableReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (maximum_request_length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (maximum_request_length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = EnableReply { response_type, sequence, length, maximum_request_length };
         Ok((result, remaining))
     }
@@ -76,6 +106,29 @@ impl TryFrom<&[u8]> for EnableReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for EnableReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.maximum_request_length.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
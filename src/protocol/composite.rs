@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -32,87 +32,90 @@ pub const X11_EXTENSION_NAME: &str = "Composite";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (0, 4);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Redirect {
-    Automatic = 0,
-    Manual = 1,
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Redirect(u8);
+#[allow(non_upper_case_globals)]
+impl Redirect {
+    pub const Automatic: Self = Self(0);
+    pub const Manual: Self = Self(1);
 }
+enum_newtype!(Redirect, u8, [Automatic, Manual]);
 impl From<Redirect> for bool {
     fn from(input: Redirect) -> Self {
-        match input {
-            Redirect::Automatic => false,
-            Redirect::Manual => true,
-        }
-    }
-}
-impl From<Redirect> for u8 {
-    fn from(input: Redirect) -> Self {
-        match input {
-            Redirect::Automatic => 0,
-            Redirect::Manual => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<Redirect> for Option<u8> {
     fn from(input: Redirect) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Redirect> for u16 {
     fn from(input: Redirect) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Redirect> for Option<u16> {
     fn from(input: Redirect) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Redirect> for u32 {
     fn from(input: Redirect) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Redirect> for Option<u32> {
     fn from(input: Redirect) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for Redirect {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Redirect::Automatic),
-            1 => Ok(Redirect::Manual),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
         }
+        let remaining = value;
+        let (client_major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
     }
-}
-impl TryFrom<u16> for Redirect {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `client_major_version` field of this structure.
+    pub fn client_major_version(mut self, value: u32) -> Self {
+        self.client_major_version = value;
+        self
     }
-}
-impl TryFrom<u32> for Redirect {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `client_minor_version` field of this structure.
+    pub fn client_minor_version(mut self, value: u32) -> Self {
+        self.client_minor_version = value;
+        self
     }
 }
 
-/// Opcode for the QueryVersion request
-pub const QUERY_VERSION_REQUEST: u8 = 0;
 pub fn query_version<Conn>(conn: &Conn, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let client_major_version_bytes = client_major_version.serialize();
-    let client_minor_version_bytes = client_minor_version.serialize();
+    let client_major_version_bytes = client_major_version.serialize_with_order(order);
+    let client_minor_version_bytes = client_minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -130,9 +133,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.client_major_version, self.client_minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -143,13 +158,13 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
@@ -161,18 +176,81 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the RedirectWindow request
 pub const REDIRECT_WINDOW_REQUEST: u8 = 1;
-pub fn redirect_window<Conn>(conn: &Conn, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RedirectWindowRequest {
+    pub window: xproto::Window,
+    pub update: Redirect,
+}
+impl RedirectWindowRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != REDIRECT_WINDOW_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (update, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let update = Redirect::from(update);
+        Ok(RedirectWindowRequest {
+            window,
+            update,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `update` field of this structure.
+    pub fn update<I>(mut self, value: I) -> Self where I: Into<Redirect> {
+        self.update = value.into();
+        self
+    }
+}
+
+pub fn redirect_window<Conn, A>(conn: &Conn, window: xproto::Window, update: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<Redirect>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let update: Redirect = update.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let update_bytes = u8::from(update).serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let update_bytes = u8::from(update).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         REDIRECT_WINDOW_REQUEST,
@@ -190,21 +268,71 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for RedirectWindowRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for RedirectWindowRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        redirect_window(conn, self.window, self.update)
+    }
+}
 
 /// Opcode for the RedirectSubwindows request
 pub const REDIRECT_SUBWINDOWS_REQUEST: u8 = 2;
-pub fn redirect_subwindows<Conn>(conn: &Conn, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RedirectSubwindowsRequest {
+    pub window: xproto::Window,
+    pub update: Redirect,
+}
+impl RedirectSubwindowsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != REDIRECT_SUBWINDOWS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (update, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let update = Redirect::from(update);
+        Ok(RedirectSubwindowsRequest {
+            window,
+            update,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `update` field of this structure.
+    pub fn update<I>(mut self, value: I) -> Self where I: Into<Redirect> {
+        self.update = value.into();
+        self
+    }
+}
+
+pub fn redirect_subwindows<Conn, A>(conn: &Conn, window: xproto::Window, update: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<Redirect>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let update: Redirect = update.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let update_bytes = u8::from(update).serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let update_bytes = u8::from(update).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         REDIRECT_SUBWINDOWS_REQUEST,
@@ -222,21 +350,71 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for RedirectSubwindowsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for RedirectSubwindowsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        redirect_subwindows(conn, self.window, self.update)
+    }
+}
 
 /// Opcode for the UnredirectWindow request
 pub const UNREDIRECT_WINDOW_REQUEST: u8 = 3;
-pub fn unredirect_window<Conn>(conn: &Conn, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UnredirectWindowRequest {
+    pub window: xproto::Window,
+    pub update: Redirect,
+}
+impl UnredirectWindowRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNREDIRECT_WINDOW_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (update, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let update = Redirect::from(update);
+        Ok(UnredirectWindowRequest {
+            window,
+            update,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `update` field of this structure.
+    pub fn update<I>(mut self, value: I) -> Self where I: Into<Redirect> {
+        self.update = value.into();
+        self
+    }
+}
+
+pub fn unredirect_window<Conn, A>(conn: &Conn, window: xproto::Window, update: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<Redirect>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let update: Redirect = update.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let update_bytes = u8::from(update).serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let update_bytes = u8::from(update).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         UNREDIRECT_WINDOW_REQUEST,
@@ -254,21 +432,71 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for UnredirectWindowRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UnredirectWindowRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        unredirect_window(conn, self.window, self.update)
+    }
+}
 
 /// Opcode for the UnredirectSubwindows request
 pub const UNREDIRECT_SUBWINDOWS_REQUEST: u8 = 4;
-pub fn unredirect_subwindows<Conn>(conn: &Conn, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UnredirectSubwindowsRequest {
+    pub window: xproto::Window,
+    pub update: Redirect,
+}
+impl UnredirectSubwindowsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNREDIRECT_SUBWINDOWS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (update, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let update = Redirect::from(update);
+        Ok(UnredirectSubwindowsRequest {
+            window,
+            update,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `update` field of this structure.
+    pub fn update<I>(mut self, value: I) -> Self where I: Into<Redirect> {
+        self.update = value.into();
+        self
+    }
+}
+
+pub fn unredirect_subwindows<Conn, A>(conn: &Conn, window: xproto::Window, update: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<Redirect>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let update: Redirect = update.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let update_bytes = u8::from(update).serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let update_bytes = u8::from(update).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         UNREDIRECT_SUBWINDOWS_REQUEST,
@@ -286,21 +514,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for UnredirectSubwindowsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UnredirectSubwindowsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        unredirect_subwindows(conn, self.window, self.update)
+    }
+}
 
 /// Opcode for the CreateRegionFromBorderClip request
 pub const CREATE_REGION_FROM_BORDER_CLIP_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateRegionFromBorderClipRequest {
+    pub region: xfixes::Region,
+    pub window: xproto::Window,
+}
+impl CreateRegionFromBorderClipRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_BORDER_CLIP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateRegionFromBorderClipRequest {
+            region,
+            window,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<xfixes::Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn create_region_from_border_clip<Conn>(conn: &Conn, region: xfixes::Region, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
-    let window_bytes = window.serialize();
+    let region_bytes = region.serialize_with_order(order);
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_REGION_FROM_BORDER_CLIP_REQUEST,
@@ -318,21 +592,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateRegionFromBorderClipRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateRegionFromBorderClipRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_region_from_border_clip(conn, self.region, self.window)
+    }
+}
 
 /// Opcode for the NameWindowPixmap request
 pub const NAME_WINDOW_PIXMAP_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NameWindowPixmapRequest {
+    pub window: xproto::Window,
+    pub pixmap: xproto::Pixmap,
+}
+impl NameWindowPixmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != NAME_WINDOW_PIXMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(NameWindowPixmapRequest {
+            window,
+            pixmap,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `pixmap` field of this structure.
+    pub fn pixmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.pixmap = value.into();
+        self
+    }
+}
+
 pub fn name_window_pixmap<Conn>(conn: &Conn, window: xproto::Window, pixmap: xproto::Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let pixmap_bytes = pixmap.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let pixmap_bytes = pixmap.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         NAME_WINDOW_PIXMAP_REQUEST,
@@ -350,20 +670,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for NameWindowPixmapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for NameWindowPixmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        name_window_pixmap(conn, self.window, self.pixmap)
+    }
+}
 
 /// Opcode for the GetOverlayWindow request
 pub const GET_OVERLAY_WINDOW_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetOverlayWindowRequest {
+    pub window: xproto::Window,
+}
+impl GetOverlayWindowRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_OVERLAY_WINDOW_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetOverlayWindowRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn get_overlay_window<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetOverlayWindowReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_OVERLAY_WINDOW_REQUEST,
@@ -377,9 +735,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetOverlayWindowRequest {
+    type Reply = GetOverlayWindowReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetOverlayWindowRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetOverlayWindowReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_overlay_window(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetOverlayWindowReply {
@@ -389,12 +759,12 @@ pub struct GetOverlayWindowReply {
     pub overlay_win: xproto::Window,
 }
 impl TryParse for GetOverlayWindowReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (overlay_win, remaining) = xproto::Window::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (overlay_win, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = GetOverlayWindowReply { response_type, sequence, length, overlay_win };
         Ok((result, remaining))
@@ -406,17 +776,67 @@ impl TryFrom<&[u8]> for GetOverlayWindowReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetOverlayWindowReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.overlay_win.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ReleaseOverlayWindow request
 pub const RELEASE_OVERLAY_WINDOW_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReleaseOverlayWindowRequest {
+    pub window: xproto::Window,
+}
+impl ReleaseOverlayWindowRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RELEASE_OVERLAY_WINDOW_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ReleaseOverlayWindowRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn release_overlay_window<Conn>(conn: &Conn, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         RELEASE_OVERLAY_WINDOW_REQUEST,
@@ -430,9 +850,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ReleaseOverlayWindowRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ReleaseOverlayWindowRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        release_overlay_window(conn, self.window)
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -440,19 +872,27 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_version(self, client_major_version, client_minor_version)
     }
-    fn composite_redirect_window(&self, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn composite_redirect_window<A>(&self, window: xproto::Window, update: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<Redirect>,
     {
         redirect_window(self, window, update)
     }
-    fn composite_redirect_subwindows(&self, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn composite_redirect_subwindows<A>(&self, window: xproto::Window, update: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<Redirect>,
     {
         redirect_subwindows(self, window, update)
     }
-    fn composite_unredirect_window(&self, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn composite_unredirect_window<A>(&self, window: xproto::Window, update: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<Redirect>,
     {
         unredirect_window(self, window, update)
     }
-    fn composite_unredirect_subwindows(&self, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn composite_unredirect_subwindows<A>(&self, window: xproto::Window, update: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<Redirect>,
     {
         unredirect_subwindows(self, window, update)
     }
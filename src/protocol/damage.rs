@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -32,73 +32,71 @@ pub const X11_EXTENSION_NAME: &str = "DAMAGE";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 1);
 
-pub type Damage = u32;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ReportLevel {
-    RawRectangles = 0,
-    DeltaRectangles = 1,
-    BoundingBox = 2,
-    NonEmpty = 3,
-}
-impl From<ReportLevel> for u8 {
-    fn from(input: ReportLevel) -> Self {
-        match input {
-            ReportLevel::RawRectangles => 0,
-            ReportLevel::DeltaRectangles => 1,
-            ReportLevel::BoundingBox => 2,
-            ReportLevel::NonEmpty => 3,
-        }
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Damage(u32);
+impl Damage {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Damage(0);
+}
+impl From<u32> for Damage {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Damage> for u32 {
+    fn from(value: Damage) -> Self {
+        value.0
     }
 }
+impl TryParse for Damage {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Damage {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ReportLevel(u8);
+#[allow(non_upper_case_globals)]
+impl ReportLevel {
+    pub const RawRectangles: Self = Self(0);
+    pub const DeltaRectangles: Self = Self(1);
+    pub const BoundingBox: Self = Self(2);
+    pub const NonEmpty: Self = Self(3);
+}
+enum_newtype!(ReportLevel, u8, [RawRectangles, DeltaRectangles, BoundingBox, NonEmpty]);
 impl From<ReportLevel> for Option<u8> {
     fn from(input: ReportLevel) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ReportLevel> for u16 {
     fn from(input: ReportLevel) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ReportLevel> for Option<u16> {
     fn from(input: ReportLevel) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ReportLevel> for u32 {
     fn from(input: ReportLevel) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ReportLevel> for Option<u32> {
     fn from(input: ReportLevel) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ReportLevel {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(ReportLevel::RawRectangles),
-            1 => Ok(ReportLevel::DeltaRectangles),
-            2 => Ok(ReportLevel::BoundingBox),
-            3 => Ok(ReportLevel::NonEmpty),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ReportLevel {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ReportLevel {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -111,10 +109,10 @@ pub struct BadDamageError {
     pub sequence: u16,
 }
 impl TryParse for BadDamageError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadDamageError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -127,9 +125,20 @@ impl TryFrom<&[u8]> for BadDamageError {
 }
 impl From<&BadDamageError> for [u8; 32] {
     fn from(input: &BadDamageError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadDamageError> for [u8; 32] {
+    fn from(input: BadDamageError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadDamageError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -166,24 +175,56 @@ impl From<&BadDamageError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadDamageError> for [u8; 32] {
-    fn from(input: BadDamageError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (client_major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
+    }
+    /// Set the `client_major_version` field of this structure.
+    pub fn client_major_version(mut self, value: u32) -> Self {
+        self.client_major_version = value;
+        self
+    }
+    /// Set the `client_minor_version` field of this structure.
+    pub fn client_minor_version(mut self, value: u32) -> Self {
+        self.client_minor_version = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let client_major_version_bytes = client_major_version.serialize();
-    let client_minor_version_bytes = client_minor_version.serialize();
+    let client_major_version_bytes = client_major_version.serialize_with_order(order);
+    let client_minor_version_bytes = client_minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -201,9 +242,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.client_major_version, self.client_minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -214,13 +267,13 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
@@ -232,19 +285,92 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Create request
 pub const CREATE_REQUEST: u8 = 1;
-pub fn create<Conn>(conn: &Conn, damage: Damage, drawable: xproto::Drawable, level: ReportLevel) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateRequest {
+    pub damage: Damage,
+    pub drawable: xproto::Drawable,
+    pub level: ReportLevel,
+}
+impl CreateRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (damage, remaining) = Damage::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let level = ReportLevel::from(level);
+        Ok(CreateRequest {
+            damage,
+            drawable,
+            level,
+        })
+    }
+    /// Set the `damage` field of this structure.
+    pub fn damage<I>(mut self, value: I) -> Self where I: Into<Damage> {
+        self.damage = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `level` field of this structure.
+    pub fn level<I>(mut self, value: I) -> Self where I: Into<ReportLevel> {
+        self.level = value.into();
+        self
+    }
+}
+
+pub fn create<Conn, A, B>(conn: &Conn, damage: Damage, drawable: A, level: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
+    B: Into<ReportLevel>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let level: ReportLevel = level.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let damage_bytes = damage.serialize();
-    let drawable_bytes = drawable.serialize();
-    let level_bytes = u8::from(level).serialize();
+    let damage_bytes = damage.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let level_bytes = u8::from(level).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_REQUEST,
@@ -266,20 +392,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create(conn, self.damage, self.drawable, self.level)
+    }
+}
 
 /// Opcode for the Destroy request
 pub const DESTROY_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyRequest {
+    pub damage: Damage,
+}
+impl DestroyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (damage, remaining) = Damage::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyRequest {
+            damage,
+        })
+    }
+    /// Set the `damage` field of this structure.
+    pub fn damage<I>(mut self, value: I) -> Self where I: Into<Damage> {
+        self.damage = value.into();
+        self
+    }
+}
+
 pub fn destroy<Conn>(conn: &Conn, damage: Damage) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let damage_bytes = damage.serialize();
+    let damage_bytes = damage.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_REQUEST,
@@ -293,12 +457,65 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy(conn, self.damage)
+    }
+}
 
 /// Opcode for the Subtract request
 pub const SUBTRACT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubtractRequest {
+    pub damage: Damage,
+    pub repair: xfixes::Region,
+    pub parts: xfixes::Region,
+}
+impl SubtractRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SUBTRACT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (damage, remaining) = Damage::try_parse_with_order(remaining, order)?;
+        let (repair, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (parts, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SubtractRequest {
+            damage,
+            repair,
+            parts,
+        })
+    }
+    /// Set the `damage` field of this structure.
+    pub fn damage<I>(mut self, value: I) -> Self where I: Into<Damage> {
+        self.damage = value.into();
+        self
+    }
+    /// Set the `repair` field of this structure.
+    pub fn repair<I>(mut self, value: I) -> Self where I: Into<xfixes::Region> {
+        self.repair = value.into();
+        self
+    }
+    /// Set the `parts` field of this structure.
+    pub fn parts<I>(mut self, value: I) -> Self where I: Into<xfixes::Region> {
+        self.parts = value.into();
+        self
+    }
+}
+
 pub fn subtract<Conn, A, B>(conn: &Conn, damage: Damage, repair: A, parts: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -309,10 +526,11 @@ where
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let repair: xfixes::Region = repair.into();
     let parts: xfixes::Region = parts.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let damage_bytes = damage.serialize();
-    let repair_bytes = repair.serialize();
-    let parts_bytes = parts.serialize();
+    let damage_bytes = damage.serialize_with_order(order);
+    let repair_bytes = repair.serialize_with_order(order);
+    let parts_bytes = parts.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SUBTRACT_REQUEST,
@@ -334,21 +552,69 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SubtractRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SubtractRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        subtract(conn, self.damage, self.repair, self.parts)
+    }
+}
 
 /// Opcode for the Add request
 pub const ADD_REQUEST: u8 = 4;
-pub fn add<Conn>(conn: &Conn, drawable: xproto::Drawable, region: xfixes::Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AddRequest {
+    pub drawable: xproto::Drawable,
+    pub region: xfixes::Region,
+}
+impl AddRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ADD_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (region, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(AddRequest {
+            drawable,
+            region,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<xfixes::Region> {
+        self.region = value.into();
+        self
+    }
+}
+
+pub fn add<Conn, A>(conn: &Conn, drawable: A, region: xfixes::Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let region_bytes = region.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let region_bytes = region.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ADD_REQUEST,
@@ -366,9 +632,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for AddRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AddRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        add(conn, self.drawable, self.region)
+    }
+}
 
 /// Opcode for the Notify event
 pub const NOTIFY_EVENT: u8 = 0;
@@ -384,16 +662,16 @@ pub struct NotifyEvent {
     pub geometry: xproto::Rectangle,
 }
 impl TryParse for NotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (level, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (damage, remaining) = Damage::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (area, remaining) = xproto::Rectangle::try_parse(remaining)?;
-        let (geometry, remaining) = xproto::Rectangle::try_parse(remaining)?;
-        let level = level.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (damage, remaining) = Damage::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (area, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let (geometry, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let level = ReportLevel::from(level);
         let result = NotifyEvent { response_type, level, sequence, drawable, damage, timestamp, area, geometry };
         Ok((result, remaining))
     }
@@ -406,14 +684,25 @@ impl TryFrom<&[u8]> for NotifyEvent {
 }
 impl From<&NotifyEvent> for [u8; 32] {
     fn from(input: &NotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let level_bytes = u8::from(input.level).serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let drawable_bytes = input.drawable.serialize();
-        let damage_bytes = input.damage.serialize();
-        let timestamp_bytes = input.timestamp.serialize();
-        let area_bytes = input.area.serialize();
-        let geometry_bytes = input.geometry.serialize();
+        input.serialize()
+    }
+}
+impl From<NotifyEvent> for [u8; 32] {
+    fn from(input: NotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for NotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let level_bytes = u8::from(self.level).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let damage_bytes = self.damage.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let area_bytes = self.area.serialize_with_order(order);
+        let geometry_bytes = self.geometry.serialize_with_order(order);
         [
             response_type_bytes[0],
             level_bytes[0],
@@ -449,10 +738,8 @@ impl From<&NotifyEvent> for [u8; 32] {
             geometry_bytes[7],
         ]
     }
-}
-impl From<NotifyEvent> for [u8; 32] {
-    fn from(input: NotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -462,7 +749,10 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_version(self, client_major_version, client_minor_version)
     }
-    fn damage_create(&self, damage: Damage, drawable: xproto::Drawable, level: ReportLevel) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn damage_create<A, B>(&self, damage: Damage, drawable: A, level: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
+        B: Into<ReportLevel>,
     {
         create(self, damage, drawable, level)
     }
@@ -477,7 +767,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         subtract(self, damage, repair, parts)
     }
-    fn damage_add(&self, drawable: xproto::Drawable, region: xfixes::Region) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn damage_add<A>(&self, drawable: A, region: xfixes::Region) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         add(self, drawable, region)
     }
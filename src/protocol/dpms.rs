@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -32,15 +32,49 @@ pub const X11_XML_VERSION: (u32, u32) = (0, 0);
 
 /// Opcode for the GetVersion request
 pub const GET_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetVersionRequest {
+    pub client_major_version: u16,
+    pub client_minor_version: u16,
+}
+impl GetVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (client_major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (client_minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
+    }
+    /// Set the `client_major_version` field of this structure.
+    pub fn client_major_version(mut self, value: u16) -> Self {
+        self.client_major_version = value;
+        self
+    }
+    /// Set the `client_minor_version` field of this structure.
+    pub fn client_minor_version(mut self, value: u16) -> Self {
+        self.client_minor_version = value;
+        self
+    }
+}
+
 pub fn get_version<Conn>(conn: &Conn, client_major_version: u16, client_minor_version: u16) -> Result<Cookie<'_, Conn, GetVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let client_major_version_bytes = client_major_version.serialize();
-    let client_minor_version_bytes = client_minor_version.serialize();
+    let client_major_version_bytes = client_major_version.serialize_with_order(order);
+    let client_minor_version_bytes = client_minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_VERSION_REQUEST,
@@ -54,9 +88,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetVersionRequest {
+    type Reply = GetVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_version(conn, self.client_major_version, self.client_minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetVersionReply {
@@ -67,13 +113,13 @@ pub struct GetVersionReply {
     pub server_minor_version: u16,
 }
 impl TryParse for GetVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major_version, remaining) = u16::try_parse(remaining)?;
-        let (server_minor_version, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = GetVersionReply { response_type, sequence, length, server_major_version, server_minor_version };
         Ok((result, remaining))
     }
@@ -84,15 +130,57 @@ impl TryFrom<&[u8]> for GetVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.server_major_version.serialize_into_with_order(bytes, order);
+        self.server_minor_version.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Capable request
 pub const CAPABLE_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CapableRequest {
+}
+impl CapableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CAPABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(CapableRequest {
+        })
+    }
+}
+
 pub fn capable<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, CapableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -103,9 +191,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CapableRequest {
+    type Reply = CapableReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CapableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, CapableReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        capable(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CapableReply {
@@ -115,12 +215,12 @@ pub struct CapableReply {
     pub capable: bool,
 }
 impl TryParse for CapableReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (capable, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (capable, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
         let result = CapableReply { response_type, sequence, length, capable };
         Ok((result, remaining))
@@ -132,15 +232,57 @@ impl TryFrom<&[u8]> for CapableReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CapableReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.capable.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTimeouts request
 pub const GET_TIMEOUTS_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTimeoutsRequest {
+}
+impl GetTimeoutsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TIMEOUTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTimeoutsRequest {
+        })
+    }
+}
+
 pub fn get_timeouts<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetTimeoutsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -151,9 +293,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTimeoutsRequest {
+    type Reply = GetTimeoutsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTimeoutsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTimeoutsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_timeouts(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTimeoutsReply {
@@ -165,14 +319,14 @@ pub struct GetTimeoutsReply {
     pub off_timeout: u16,
 }
 impl TryParse for GetTimeoutsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (standby_timeout, remaining) = u16::try_parse(remaining)?;
-        let (suspend_timeout, remaining) = u16::try_parse(remaining)?;
-        let (off_timeout, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (standby_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (suspend_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (off_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(18..).ok_or(ParseError::ParseError)?;
         let result = GetTimeoutsReply { response_type, sequence, length, standby_timeout, suspend_timeout, off_timeout };
         Ok((result, remaining))
@@ -184,19 +338,87 @@ impl TryFrom<&[u8]> for GetTimeoutsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTimeoutsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.standby_timeout.serialize_into_with_order(bytes, order);
+        self.suspend_timeout.serialize_into_with_order(bytes, order);
+        self.off_timeout.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 18]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetTimeouts request
 pub const SET_TIMEOUTS_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetTimeoutsRequest {
+    pub standby_timeout: u16,
+    pub suspend_timeout: u16,
+    pub off_timeout: u16,
+}
+impl SetTimeoutsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_TIMEOUTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (standby_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (suspend_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (off_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetTimeoutsRequest {
+            standby_timeout,
+            suspend_timeout,
+            off_timeout,
+        })
+    }
+    /// Set the `standby_timeout` field of this structure.
+    pub fn standby_timeout(mut self, value: u16) -> Self {
+        self.standby_timeout = value;
+        self
+    }
+    /// Set the `suspend_timeout` field of this structure.
+    pub fn suspend_timeout(mut self, value: u16) -> Self {
+        self.suspend_timeout = value;
+        self
+    }
+    /// Set the `off_timeout` field of this structure.
+    pub fn off_timeout(mut self, value: u16) -> Self {
+        self.off_timeout = value;
+        self
+    }
+}
+
 pub fn set_timeouts<Conn>(conn: &Conn, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let standby_timeout_bytes = standby_timeout.serialize();
-    let suspend_timeout_bytes = suspend_timeout.serialize();
-    let off_timeout_bytes = off_timeout.serialize();
+    let standby_timeout_bytes = standby_timeout.serialize_with_order(order);
+    let suspend_timeout_bytes = suspend_timeout.serialize_with_order(order);
+    let off_timeout_bytes = off_timeout.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_TIMEOUTS_REQUEST,
@@ -214,18 +436,48 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetTimeoutsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetTimeoutsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_timeouts(conn, self.standby_timeout, self.suspend_timeout, self.off_timeout)
+    }
+}
 
 /// Opcode for the Enable request
 pub const ENABLE_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnableRequest {
+}
+impl EnableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ENABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(EnableRequest {
+        })
+    }
+}
+
 pub fn enable<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -236,18 +488,48 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for EnableRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for EnableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        enable(conn)
+    }
+}
 
 /// Opcode for the Disable request
 pub const DISABLE_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DisableRequest {
+}
+impl DisableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DISABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(DisableRequest {
+        })
+    }
+}
+
 pub fn disable<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -258,88 +540,87 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum DPMSMode {
-    On = 0,
-    Standby = 1,
-    Suspend = 2,
-    Off = 3,
-}
-impl From<DPMSMode> for u8 {
-    fn from(input: DPMSMode) -> Self {
-        match input {
-            DPMSMode::On => 0,
-            DPMSMode::Standby => 1,
-            DPMSMode::Suspend => 2,
-            DPMSMode::Off => 3,
-        }
-    }
+impl crate::x11_utils::Request for DisableRequest {
+    type Reply = ();
 }
-impl From<DPMSMode> for Option<u8> {
-    fn from(input: DPMSMode) -> Self {
-        Some(u8::from(input))
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DisableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        disable(conn)
     }
 }
-impl From<DPMSMode> for u16 {
-    fn from(input: DPMSMode) -> Self {
-        Self::from(u8::from(input))
-    }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DPMSMode(u16);
+#[allow(non_upper_case_globals)]
+impl DPMSMode {
+    pub const On: Self = Self(0);
+    pub const Standby: Self = Self(1);
+    pub const Suspend: Self = Self(2);
+    pub const Off: Self = Self(3);
 }
+enum_newtype!(DPMSMode, u16, [On, Standby, Suspend, Off]);
 impl From<DPMSMode> for Option<u16> {
     fn from(input: DPMSMode) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<DPMSMode> for u32 {
     fn from(input: DPMSMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DPMSMode> for Option<u32> {
     fn from(input: DPMSMode) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for DPMSMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(DPMSMode::On),
-            1 => Ok(DPMSMode::Standby),
-            2 => Ok(DPMSMode::Suspend),
-            3 => Ok(DPMSMode::Off),
-            _ => Err(ParseError::ParseError),
-        }
-    }
+
+/// Opcode for the ForceLevel request
+pub const FORCE_LEVEL_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ForceLevelRequest {
+    pub power_level: DPMSMode,
 }
-impl TryFrom<u16> for DPMSMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl ForceLevelRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FORCE_LEVEL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (power_level, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let power_level = DPMSMode::from(power_level);
+        Ok(ForceLevelRequest {
+            power_level,
+        })
     }
-}
-impl TryFrom<u32> for DPMSMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `power_level` field of this structure.
+    pub fn power_level<I>(mut self, value: I) -> Self where I: Into<DPMSMode> {
+        self.power_level = value.into();
+        self
     }
 }
 
-/// Opcode for the ForceLevel request
-pub const FORCE_LEVEL_REQUEST: u8 = 6;
-pub fn force_level<Conn>(conn: &Conn, power_level: DPMSMode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+pub fn force_level<Conn, A>(conn: &Conn, power_level: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<DPMSMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let power_level: DPMSMode = power_level.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let power_level_bytes = u16::from(power_level).serialize();
+    let power_level_bytes = u16::from(power_level).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FORCE_LEVEL_REQUEST,
@@ -353,18 +634,48 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ForceLevelRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ForceLevelRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        force_level(conn, self.power_level)
+    }
+}
 
 /// Opcode for the Info request
 pub const INFO_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InfoRequest {
+}
+impl InfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(InfoRequest {
+        })
+    }
+}
+
 pub fn info<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, InfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -375,9 +686,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for InfoRequest {
+    type Reply = InfoReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for InfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, InfoReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        info(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InfoReply {
@@ -388,15 +711,15 @@ pub struct InfoReply {
     pub state: bool,
 }
 impl TryParse for InfoReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (power_level, remaining) = u16::try_parse(remaining)?;
-        let (state, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (power_level, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(21..).ok_or(ParseError::ParseError)?;
-        let power_level = power_level.try_into()?;
+        let power_level = DPMSMode::from(power_level);
         let result = InfoReply { response_type, sequence, length, power_level, state };
         Ok((result, remaining))
     }
@@ -407,6 +730,31 @@ impl TryFrom<&[u8]> for InfoReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for InfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u16::from(self.power_level).serialize_into_with_order(bytes, order);
+        self.state.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 21]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -434,7 +782,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         disable(self)
     }
-    fn dpms_force_level(&self, power_level: DPMSMode) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn dpms_force_level<A>(&self, power_level: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<DPMSMode>,
     {
         force_level(self, power_level)
     }
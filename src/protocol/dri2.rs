@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -31,228 +31,70 @@ pub const X11_EXTENSION_NAME: &str = "DRI2";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 4);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Attachment {
-    BufferFrontLeft = 0,
-    BufferBackLeft = 1,
-    BufferFrontRight = 2,
-    BufferBackRight = 3,
-    BufferDepth = 4,
-    BufferStencil = 5,
-    BufferAccum = 6,
-    BufferFakeFrontLeft = 7,
-    BufferFakeFrontRight = 8,
-    BufferDepthStencil = 9,
-    BufferHiz = 10,
-}
-impl From<Attachment> for u8 {
-    fn from(input: Attachment) -> Self {
-        match input {
-            Attachment::BufferFrontLeft => 0,
-            Attachment::BufferBackLeft => 1,
-            Attachment::BufferFrontRight => 2,
-            Attachment::BufferBackRight => 3,
-            Attachment::BufferDepth => 4,
-            Attachment::BufferStencil => 5,
-            Attachment::BufferAccum => 6,
-            Attachment::BufferFakeFrontLeft => 7,
-            Attachment::BufferFakeFrontRight => 8,
-            Attachment::BufferDepthStencil => 9,
-            Attachment::BufferHiz => 10,
-        }
-    }
-}
-impl From<Attachment> for Option<u8> {
-    fn from(input: Attachment) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<Attachment> for u16 {
-    fn from(input: Attachment) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<Attachment> for Option<u16> {
-    fn from(input: Attachment) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<Attachment> for u32 {
-    fn from(input: Attachment) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Attachment(u32);
+#[allow(non_upper_case_globals)]
+impl Attachment {
+    pub const BufferFrontLeft: Self = Self(0);
+    pub const BufferBackLeft: Self = Self(1);
+    pub const BufferFrontRight: Self = Self(2);
+    pub const BufferBackRight: Self = Self(3);
+    pub const BufferDepth: Self = Self(4);
+    pub const BufferStencil: Self = Self(5);
+    pub const BufferAccum: Self = Self(6);
+    pub const BufferFakeFrontLeft: Self = Self(7);
+    pub const BufferFakeFrontRight: Self = Self(8);
+    pub const BufferDepthStencil: Self = Self(9);
+    pub const BufferHiz: Self = Self(10);
+}
+enum_newtype!(Attachment, u32, [BufferFrontLeft, BufferBackLeft, BufferFrontRight, BufferBackRight, BufferDepth, BufferStencil, BufferAccum, BufferFakeFrontLeft, BufferFakeFrontRight, BufferDepthStencil, BufferHiz]);
 impl From<Attachment> for Option<u32> {
     fn from(input: Attachment) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Attachment {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Attachment::BufferFrontLeft),
-            1 => Ok(Attachment::BufferBackLeft),
-            2 => Ok(Attachment::BufferFrontRight),
-            3 => Ok(Attachment::BufferBackRight),
-            4 => Ok(Attachment::BufferDepth),
-            5 => Ok(Attachment::BufferStencil),
-            6 => Ok(Attachment::BufferAccum),
-            7 => Ok(Attachment::BufferFakeFrontLeft),
-            8 => Ok(Attachment::BufferFakeFrontRight),
-            9 => Ok(Attachment::BufferDepthStencil),
-            10 => Ok(Attachment::BufferHiz),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Attachment {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Attachment {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-pub enum DriverType {
-    DRI = 0,
-    VDPAU = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DriverType(u32);
+#[allow(non_upper_case_globals)]
+impl DriverType {
+    pub const DRI: Self = Self(0);
+    pub const VDPAU: Self = Self(1);
+}
+enum_newtype!(DriverType, u32, [DRI, VDPAU]);
 impl From<DriverType> for bool {
     fn from(input: DriverType) -> Self {
-        match input {
-            DriverType::DRI => false,
-            DriverType::VDPAU => true,
-        }
-    }
-}
-impl From<DriverType> for u8 {
-    fn from(input: DriverType) -> Self {
-        match input {
-            DriverType::DRI => 0,
-            DriverType::VDPAU => 1,
-        }
-    }
-}
-impl From<DriverType> for Option<u8> {
-    fn from(input: DriverType) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<DriverType> for u16 {
-    fn from(input: DriverType) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<DriverType> for Option<u16> {
-    fn from(input: DriverType) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<DriverType> for u32 {
-    fn from(input: DriverType) -> Self {
-        Self::from(u8::from(input))
+        input.0 != 0
     }
 }
 impl From<DriverType> for Option<u32> {
     fn from(input: DriverType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for DriverType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(DriverType::DRI),
-            1 => Ok(DriverType::VDPAU),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for DriverType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for DriverType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum EventType {
-    ExchangeComplete = 1,
-    BlitComplete = 2,
-    FlipComplete = 3,
-}
-impl From<EventType> for u8 {
-    fn from(input: EventType) -> Self {
-        match input {
-            EventType::ExchangeComplete => 1,
-            EventType::BlitComplete => 2,
-            EventType::FlipComplete => 3,
-        }
-    }
-}
-impl From<EventType> for Option<u8> {
-    fn from(input: EventType) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<EventType> for u16 {
-    fn from(input: EventType) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventType(u16);
+#[allow(non_upper_case_globals)]
+impl EventType {
+    pub const ExchangeComplete: Self = Self(1);
+    pub const BlitComplete: Self = Self(2);
+    pub const FlipComplete: Self = Self(3);
+}
+enum_newtype!(EventType, u16, [ExchangeComplete, BlitComplete, FlipComplete]);
 impl From<EventType> for Option<u16> {
     fn from(input: EventType) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<EventType> for u32 {
     fn from(input: EventType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<EventType> for Option<u32> {
     fn from(input: EventType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for EventType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(EventType::ExchangeComplete),
-            2 => Ok(EventType::BlitComplete),
-            3 => Ok(EventType::FlipComplete),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for EventType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for EventType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -265,13 +107,13 @@ pub struct DRI2Buffer {
     pub flags: u32,
 }
 impl TryParse for DRI2Buffer {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (attachment, remaining) = u32::try_parse(remaining)?;
-        let (name, remaining) = u32::try_parse(remaining)?;
-        let (pitch, remaining) = u32::try_parse(remaining)?;
-        let (cpp, remaining) = u32::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let attachment = attachment.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (attachment, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pitch, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (cpp, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let attachment = Attachment::from(attachment);
         let result = DRI2Buffer { attachment, name, pitch, cpp, flags };
         Ok((result, remaining))
     }
@@ -284,12 +126,12 @@ impl TryFrom<&[u8]> for DRI2Buffer {
 }
 impl Serialize for DRI2Buffer {
     type Bytes = [u8; 20];
-    fn serialize(&self) -> [u8; 20] {
-        let attachment_bytes = u32::from(self.attachment).serialize();
-        let name_bytes = self.name.serialize();
-        let pitch_bytes = self.pitch.serialize();
-        let cpp_bytes = self.cpp.serialize();
-        let flags_bytes = self.flags.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 20] {
+        let attachment_bytes = u32::from(self.attachment).serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let cpp_bytes = self.cpp.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
         [
             attachment_bytes[0],
             attachment_bytes[1],
@@ -313,13 +155,13 @@ impl Serialize for DRI2Buffer {
             flags_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(20);
-        u32::from(self.attachment).serialize_into(bytes);
-        self.name.serialize_into(bytes);
-        self.pitch.serialize_into(bytes);
-        self.cpp.serialize_into(bytes);
-        self.flags.serialize_into(bytes);
+        u32::from(self.attachment).serialize_into_with_order(bytes, order);
+        self.name.serialize_into_with_order(bytes, order);
+        self.pitch.serialize_into_with_order(bytes, order);
+        self.cpp.serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -329,10 +171,10 @@ pub struct AttachFormat {
     pub format: u32,
 }
 impl TryParse for AttachFormat {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (attachment, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u32::try_parse(remaining)?;
-        let attachment = attachment.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (attachment, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let attachment = Attachment::from(attachment);
         let result = AttachFormat { attachment, format };
         Ok((result, remaining))
     }
@@ -345,9 +187,9 @@ impl TryFrom<&[u8]> for AttachFormat {
 }
 impl Serialize for AttachFormat {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let attachment_bytes = u32::from(self.attachment).serialize();
-        let format_bytes = self.format.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let attachment_bytes = u32::from(self.attachment).serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
         [
             attachment_bytes[0],
             attachment_bytes[1],
@@ -359,24 +201,58 @@ impl Serialize for AttachFormat {
             format_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u32::from(self.attachment).serialize_into(bytes);
-        self.format.serialize_into(bytes);
+        u32::from(self.attachment).serialize_into_with_order(bytes, order);
+        self.format.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u32) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u32) -> Self {
+        self.minor_version = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -394,9 +270,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.major_version, self.minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -407,13 +295,13 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
     }
@@ -424,18 +312,79 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Connect request
 pub const CONNECT_REQUEST: u8 = 1;
-pub fn connect<Conn>(conn: &Conn, window: xproto::Window, driver_type: DriverType) -> Result<Cookie<'_, Conn, ConnectReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConnectRequest {
+    pub window: xproto::Window,
+    pub driver_type: DriverType,
+}
+impl ConnectRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CONNECT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (driver_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let driver_type = DriverType::from(driver_type);
+        Ok(ConnectRequest {
+            window,
+            driver_type,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `driver_type` field of this structure.
+    pub fn driver_type<I>(mut self, value: I) -> Self where I: Into<DriverType> {
+        self.driver_type = value.into();
+        self
+    }
+}
+
+pub fn connect<Conn, A>(conn: &Conn, window: xproto::Window, driver_type: A) -> Result<Cookie<'_, Conn, ConnectReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<DriverType>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let driver_type: DriverType = driver_type.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let driver_type_bytes = u32::from(driver_type).serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let driver_type_bytes = u32::from(driver_type).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CONNECT_REQUEST,
@@ -453,9 +402,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ConnectRequest {
+    type Reply = ConnectReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ConnectRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ConnectReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        connect(conn, self.window, self.driver_type)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ConnectReply {
@@ -467,13 +428,13 @@ pub struct ConnectReply {
     pub device_name: Vec<u8>,
 }
 impl TryParse for ConnectReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (driver_name_length, remaining) = u32::try_parse(remaining)?;
-        let (device_name_length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (driver_name_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (device_name_length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let (driver_name, remaining) = crate::x11_utils::parse_u8_list(remaining, driver_name_length.try_into().or(Err(ParseError::ParseError))?)?;
         let driver_name = driver_name.to_vec();
@@ -491,18 +452,83 @@ impl TryFrom<&[u8]> for ConnectReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ConnectReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let driver_name_length = u32::try_from(self.driver_name.len()).expect("`driver_name` has too many elements");
+        driver_name_length.serialize_into_with_order(bytes, order);
+        let device_name_length = u32::try_from(self.device_name.len()).expect("`device_name` has too many elements");
+        device_name_length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        bytes.extend_from_slice(&self.driver_name);
+        assert_eq!(self.alignment_pad.len(), usize::try_from((driver_name_length.checked_add(3u32).unwrap() & (!3u32)).checked_sub(driver_name_length).unwrap()).unwrap(), "`alignment_pad` has an incorrect length");
+        bytes.extend_from_slice(&self.alignment_pad);
+        bytes.extend_from_slice(&self.device_name);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Authenticate request
 pub const AUTHENTICATE_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AuthenticateRequest {
+    pub window: xproto::Window,
+    pub magic: u32,
+}
+impl AuthenticateRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != AUTHENTICATE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (magic, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(AuthenticateRequest {
+            window,
+            magic,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `magic` field of this structure.
+    pub fn magic(mut self, value: u32) -> Self {
+        self.magic = value;
+        self
+    }
+}
+
 pub fn authenticate<Conn>(conn: &Conn, window: xproto::Window, magic: u32) -> Result<Cookie<'_, Conn, AuthenticateReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let magic_bytes = magic.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let magic_bytes = magic.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         AUTHENTICATE_REQUEST,
@@ -520,9 +546,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for AuthenticateRequest {
+    type Reply = AuthenticateReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AuthenticateRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, AuthenticateReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        authenticate(conn, self.window, self.magic)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AuthenticateReply {
@@ -532,12 +570,12 @@ pub struct AuthenticateReply {
     pub authenticated: u32,
 }
 impl TryParse for AuthenticateReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (authenticated, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (authenticated, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = AuthenticateReply { response_type, sequence, length, authenticated };
         Ok((result, remaining))
     }
@@ -548,17 +586,68 @@ impl TryFrom<&[u8]> for AuthenticateReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for AuthenticateReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.authenticated.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreateDrawable request
 pub const CREATE_DRAWABLE_REQUEST: u8 = 3;
-pub fn create_drawable<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateDrawableRequest {
+    pub drawable: xproto::Drawable,
+}
+impl CreateDrawableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_DRAWABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateDrawableRequest {
+            drawable,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+}
+
+pub fn create_drawable<Conn, A>(conn: &Conn, drawable: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_DRAWABLE_REQUEST,
@@ -572,20 +661,60 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateDrawableRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateDrawableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_drawable(conn, self.drawable)
+    }
+}
 
 /// Opcode for the DestroyDrawable request
 pub const DESTROY_DRAWABLE_REQUEST: u8 = 4;
-pub fn destroy_drawable<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyDrawableRequest {
+    pub drawable: xproto::Drawable,
+}
+impl DestroyDrawableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_DRAWABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyDrawableRequest {
+            drawable,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+}
+
+pub fn destroy_drawable<Conn, A>(conn: &Conn, drawable: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_DRAWABLE_REQUEST,
@@ -599,21 +728,84 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyDrawableRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyDrawableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_drawable(conn, self.drawable)
+    }
+}
 
 /// Opcode for the GetBuffers request
 pub const GET_BUFFERS_REQUEST: u8 = 5;
-pub fn get_buffers<'c, Conn>(conn: &'c Conn, drawable: xproto::Drawable, count: u32, attachments: &[u32]) -> Result<Cookie<'c, Conn, GetBuffersReply>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GetBuffersRequest {
+    pub drawable: xproto::Drawable,
+    pub count: u32,
+    pub attachments: Vec<u32>,
+}
+impl GetBuffersRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_BUFFERS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut attachments = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = u32::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            attachments.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(GetBuffersRequest {
+            drawable,
+            count,
+            attachments,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `count` field of this structure.
+    pub fn count(mut self, value: u32) -> Self {
+        self.count = value;
+        self
+    }
+    /// Set the `attachments` field of this structure.
+    pub fn attachments<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.attachments = value.into();
+        self
+    }
+}
+
+pub fn get_buffers<'c, Conn, A>(conn: &'c Conn, drawable: A, count: u32, attachments: &[u32]) -> Result<Cookie<'c, Conn, GetBuffersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let count_bytes = count.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let count_bytes = count.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_BUFFERS_REQUEST,
@@ -629,15 +821,27 @@ where
         count_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let attachments_bytes = attachments.serialize();
+    let attachments_bytes = attachments.serialize_with_order(order);
     let length_so_far = length_so_far + attachments_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&attachments_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetBuffersRequest {
+    type Reply = GetBuffersReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetBuffersRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetBuffersReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_buffers(conn, self.drawable, self.count, &self.attachments)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetBuffersReply {
@@ -649,16 +853,16 @@ pub struct GetBuffersReply {
     pub buffers: Vec<DRI2Buffer>,
 }
 impl TryParse for GetBuffersReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u32::try_parse(remaining)?;
-        let (height, remaining) = u32::try_parse(remaining)?;
-        let (count, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (buffers, remaining) = crate::x11_utils::parse_list::<DRI2Buffer>(remaining, count.try_into().or(Err(ParseError::ParseError))?)?;
+        let (buffers, remaining) = crate::x11_utils::parse_list_with_order::<DRI2Buffer>(remaining, count.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetBuffersReply { response_type, sequence, length, width, height, buffers };
         Ok((result, remaining))
     }
@@ -669,20 +873,100 @@ impl TryFrom<&[u8]> for GetBuffersReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetBuffersReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        let count = u32::try_from(self.buffers.len()).expect("`buffers` has too many elements");
+        count.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.buffers.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CopyRegion request
 pub const COPY_REGION_REQUEST: u8 = 6;
-pub fn copy_region<Conn>(conn: &Conn, drawable: xproto::Drawable, region: u32, dest: u32, src: u32) -> Result<Cookie<'_, Conn, CopyRegionReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CopyRegionRequest {
+    pub drawable: xproto::Drawable,
+    pub region: u32,
+    pub dest: u32,
+    pub src: u32,
+}
+impl CopyRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COPY_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (region, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (dest, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (src, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CopyRegionRequest {
+            drawable,
+            region,
+            dest,
+            src,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `region` field of this structure.
+    pub fn region(mut self, value: u32) -> Self {
+        self.region = value;
+        self
+    }
+    /// Set the `dest` field of this structure.
+    pub fn dest(mut self, value: u32) -> Self {
+        self.dest = value;
+        self
+    }
+    /// Set the `src` field of this structure.
+    pub fn src(mut self, value: u32) -> Self {
+        self.src = value;
+        self
+    }
+}
+
+pub fn copy_region<Conn, A>(conn: &Conn, drawable: A, region: u32, dest: u32, src: u32) -> Result<Cookie<'_, Conn, CopyRegionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let region_bytes = region.serialize();
-    let dest_bytes = dest.serialize();
-    let src_bytes = src.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let region_bytes = region.serialize_with_order(order);
+    let dest_bytes = dest.serialize_with_order(order);
+    let src_bytes = src.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         COPY_REGION_REQUEST,
@@ -708,9 +992,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CopyRegionRequest {
+    type Reply = CopyRegionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CopyRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, CopyRegionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        copy_region(conn, self.drawable, self.region, self.dest, self.src)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CopyRegionReply {
@@ -719,11 +1015,11 @@ pub struct CopyRegionReply {
     pub length: u32,
 }
 impl TryParse for CopyRegionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = CopyRegionReply { response_type, sequence, length };
         Ok((result, remaining))
     }
@@ -734,18 +1030,91 @@ impl TryFrom<&[u8]> for CopyRegionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CopyRegionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetBuffersWithFormat request
 pub const GET_BUFFERS_WITH_FORMAT_REQUEST: u8 = 7;
-pub fn get_buffers_with_format<'c, Conn>(conn: &'c Conn, drawable: xproto::Drawable, count: u32, attachments: &[AttachFormat]) -> Result<Cookie<'c, Conn, GetBuffersWithFormatReply>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GetBuffersWithFormatRequest {
+    pub drawable: xproto::Drawable,
+    pub count: u32,
+    pub attachments: Vec<AttachFormat>,
+}
+impl GetBuffersWithFormatRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_BUFFERS_WITH_FORMAT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut attachments = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = AttachFormat::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            attachments.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(GetBuffersWithFormatRequest {
+            drawable,
+            count,
+            attachments,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `count` field of this structure.
+    pub fn count(mut self, value: u32) -> Self {
+        self.count = value;
+        self
+    }
+    /// Set the `attachments` field of this structure.
+    pub fn attachments<I>(mut self, value: I) -> Self where I: Into<Vec<AttachFormat>> {
+        self.attachments = value.into();
+        self
+    }
+}
+
+pub fn get_buffers_with_format<'c, Conn, A>(conn: &'c Conn, drawable: A, count: u32, attachments: &[AttachFormat]) -> Result<Cookie<'c, Conn, GetBuffersWithFormatReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let count_bytes = count.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let count_bytes = count.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_BUFFERS_WITH_FORMAT_REQUEST,
@@ -761,15 +1130,27 @@ where
         count_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let attachments_bytes = attachments.serialize();
+    let attachments_bytes = attachments.serialize_with_order(order);
     let length_so_far = length_so_far + attachments_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&attachments_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetBuffersWithFormatRequest {
+    type Reply = GetBuffersWithFormatReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetBuffersWithFormatRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetBuffersWithFormatReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_buffers_with_format(conn, self.drawable, self.count, &self.attachments)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetBuffersWithFormatReply {
@@ -781,16 +1162,16 @@ pub struct GetBuffersWithFormatReply {
     pub buffers: Vec<DRI2Buffer>,
 }
 impl TryParse for GetBuffersWithFormatReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u32::try_parse(remaining)?;
-        let (height, remaining) = u32::try_parse(remaining)?;
-        let (count, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (buffers, remaining) = crate::x11_utils::parse_list::<DRI2Buffer>(remaining, count.try_into().or(Err(ParseError::ParseError))?)?;
+        let (buffers, remaining) = crate::x11_utils::parse_list_with_order::<DRI2Buffer>(remaining, count.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetBuffersWithFormatReply { response_type, sequence, length, width, height, buffers };
         Ok((result, remaining))
     }
@@ -801,23 +1182,127 @@ impl TryFrom<&[u8]> for GetBuffersWithFormatReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetBuffersWithFormatReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        let count = u32::try_from(self.buffers.len()).expect("`buffers` has too many elements");
+        count.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.buffers.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SwapBuffers request
 pub const SWAP_BUFFERS_REQUEST: u8 = 8;
-pub fn swap_buffers<Conn>(conn: &Conn, drawable: xproto::Drawable, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Conn, SwapBuffersReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SwapBuffersRequest {
+    pub drawable: xproto::Drawable,
+    pub target_msc_hi: u32,
+    pub target_msc_lo: u32,
+    pub divisor_hi: u32,
+    pub divisor_lo: u32,
+    pub remainder_hi: u32,
+    pub remainder_lo: u32,
+}
+impl SwapBuffersRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWAP_BUFFERS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (target_msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (target_msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (divisor_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (divisor_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (remainder_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (remainder_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SwapBuffersRequest {
+            drawable,
+            target_msc_hi,
+            target_msc_lo,
+            divisor_hi,
+            divisor_lo,
+            remainder_hi,
+            remainder_lo,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `target_msc_hi` field of this structure.
+    pub fn target_msc_hi(mut self, value: u32) -> Self {
+        self.target_msc_hi = value;
+        self
+    }
+    /// Set the `target_msc_lo` field of this structure.
+    pub fn target_msc_lo(mut self, value: u32) -> Self {
+        self.target_msc_lo = value;
+        self
+    }
+    /// Set the `divisor_hi` field of this structure.
+    pub fn divisor_hi(mut self, value: u32) -> Self {
+        self.divisor_hi = value;
+        self
+    }
+    /// Set the `divisor_lo` field of this structure.
+    pub fn divisor_lo(mut self, value: u32) -> Self {
+        self.divisor_lo = value;
+        self
+    }
+    /// Set the `remainder_hi` field of this structure.
+    pub fn remainder_hi(mut self, value: u32) -> Self {
+        self.remainder_hi = value;
+        self
+    }
+    /// Set the `remainder_lo` field of this structure.
+    pub fn remainder_lo(mut self, value: u32) -> Self {
+        self.remainder_lo = value;
+        self
+    }
+}
+
+pub fn swap_buffers<Conn, A>(conn: &Conn, drawable: A, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Conn, SwapBuffersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let target_msc_hi_bytes = target_msc_hi.serialize();
-    let target_msc_lo_bytes = target_msc_lo.serialize();
-    let divisor_hi_bytes = divisor_hi.serialize();
-    let divisor_lo_bytes = divisor_lo.serialize();
-    let remainder_hi_bytes = remainder_hi.serialize();
-    let remainder_lo_bytes = remainder_lo.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let target_msc_hi_bytes = target_msc_hi.serialize_with_order(order);
+    let target_msc_lo_bytes = target_msc_lo.serialize_with_order(order);
+    let divisor_hi_bytes = divisor_hi.serialize_with_order(order);
+    let divisor_lo_bytes = divisor_lo.serialize_with_order(order);
+    let remainder_hi_bytes = remainder_hi.serialize_with_order(order);
+    let remainder_lo_bytes = remainder_lo.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SWAP_BUFFERS_REQUEST,
@@ -855,9 +1340,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SwapBuffersRequest {
+    type Reply = SwapBuffersReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SwapBuffersRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, SwapBuffersReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        swap_buffers(conn, self.drawable, self.target_msc_hi, self.target_msc_lo, self.divisor_hi, self.divisor_lo, self.remainder_hi, self.remainder_lo)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SwapBuffersReply {
@@ -868,13 +1365,13 @@ pub struct SwapBuffersReply {
     pub swap_lo: u32,
 }
 impl TryParse for SwapBuffersReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (swap_hi, remaining) = u32::try_parse(remaining)?;
-        let (swap_lo, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = SwapBuffersReply { response_type, sequence, length, swap_hi, swap_lo };
         Ok((result, remaining))
     }
@@ -885,17 +1382,69 @@ impl TryFrom<&[u8]> for SwapBuffersReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for SwapBuffersReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.swap_hi.serialize_into_with_order(bytes, order);
+        self.swap_lo.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetMSC request
 pub const GET_MSC_REQUEST: u8 = 9;
-pub fn get_msc<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<Cookie<'_, Conn, GetMSCReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMSCRequest {
+    pub drawable: xproto::Drawable,
+}
+impl GetMSCRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MSC_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMSCRequest {
+            drawable,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+}
+
+pub fn get_msc<Conn, A>(conn: &Conn, drawable: A) -> Result<Cookie<'_, Conn, GetMSCReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MSC_REQUEST,
@@ -909,9 +1458,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMSCRequest {
+    type Reply = GetMSCReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMSCRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMSCReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_msc(conn, self.drawable)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMSCReply {
@@ -926,17 +1487,17 @@ pub struct GetMSCReply {
     pub sbc_lo: u32,
 }
 impl TryParse for GetMSCReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc_hi, remaining) = u32::try_parse(remaining)?;
-        let (sbc_lo, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = GetMSCReply { response_type, sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
         Ok((result, remaining))
     }
@@ -947,23 +1508,127 @@ impl TryFrom<&[u8]> for GetMSCReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMSCReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ust_hi.serialize_into_with_order(bytes, order);
+        self.ust_lo.serialize_into_with_order(bytes, order);
+        self.msc_hi.serialize_into_with_order(bytes, order);
+        self.msc_lo.serialize_into_with_order(bytes, order);
+        self.sbc_hi.serialize_into_with_order(bytes, order);
+        self.sbc_lo.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the WaitMSC request
 pub const WAIT_MSC_REQUEST: u8 = 10;
-pub fn wait_msc<Conn>(conn: &Conn, drawable: xproto::Drawable, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Conn, WaitMSCReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WaitMSCRequest {
+    pub drawable: xproto::Drawable,
+    pub target_msc_hi: u32,
+    pub target_msc_lo: u32,
+    pub divisor_hi: u32,
+    pub divisor_lo: u32,
+    pub remainder_hi: u32,
+    pub remainder_lo: u32,
+}
+impl WaitMSCRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != WAIT_MSC_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (target_msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (target_msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (divisor_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (divisor_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (remainder_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (remainder_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(WaitMSCRequest {
+            drawable,
+            target_msc_hi,
+            target_msc_lo,
+            divisor_hi,
+            divisor_lo,
+            remainder_hi,
+            remainder_lo,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `target_msc_hi` field of this structure.
+    pub fn target_msc_hi(mut self, value: u32) -> Self {
+        self.target_msc_hi = value;
+        self
+    }
+    /// Set the `target_msc_lo` field of this structure.
+    pub fn target_msc_lo(mut self, value: u32) -> Self {
+        self.target_msc_lo = value;
+        self
+    }
+    /// Set the `divisor_hi` field of this structure.
+    pub fn divisor_hi(mut self, value: u32) -> Self {
+        self.divisor_hi = value;
+        self
+    }
+    /// Set the `divisor_lo` field of this structure.
+    pub fn divisor_lo(mut self, value: u32) -> Self {
+        self.divisor_lo = value;
+        self
+    }
+    /// Set the `remainder_hi` field of this structure.
+    pub fn remainder_hi(mut self, value: u32) -> Self {
+        self.remainder_hi = value;
+        self
+    }
+    /// Set the `remainder_lo` field of this structure.
+    pub fn remainder_lo(mut self, value: u32) -> Self {
+        self.remainder_lo = value;
+        self
+    }
+}
+
+pub fn wait_msc<Conn, A>(conn: &Conn, drawable: A, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Conn, WaitMSCReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let target_msc_hi_bytes = target_msc_hi.serialize();
-    let target_msc_lo_bytes = target_msc_lo.serialize();
-    let divisor_hi_bytes = divisor_hi.serialize();
-    let divisor_lo_bytes = divisor_lo.serialize();
-    let remainder_hi_bytes = remainder_hi.serialize();
-    let remainder_lo_bytes = remainder_lo.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let target_msc_hi_bytes = target_msc_hi.serialize_with_order(order);
+    let target_msc_lo_bytes = target_msc_lo.serialize_with_order(order);
+    let divisor_hi_bytes = divisor_hi.serialize_with_order(order);
+    let divisor_lo_bytes = divisor_lo.serialize_with_order(order);
+    let remainder_hi_bytes = remainder_hi.serialize_with_order(order);
+    let remainder_lo_bytes = remainder_lo.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         WAIT_MSC_REQUEST,
@@ -1001,9 +1666,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for WaitMSCRequest {
+    type Reply = WaitMSCReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for WaitMSCRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, WaitMSCReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        wait_msc(conn, self.drawable, self.target_msc_hi, self.target_msc_lo, self.divisor_hi, self.divisor_lo, self.remainder_hi, self.remainder_lo)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WaitMSCReply {
@@ -1018,17 +1695,17 @@ pub struct WaitMSCReply {
     pub sbc_lo: u32,
 }
 impl TryParse for WaitMSCReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc_hi, remaining) = u32::try_parse(remaining)?;
-        let (sbc_lo, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = WaitMSCReply { response_type, sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
         Ok((result, remaining))
     }
@@ -1039,19 +1716,91 @@ impl TryFrom<&[u8]> for WaitMSCReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for WaitMSCReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ust_hi.serialize_into_with_order(bytes, order);
+        self.ust_lo.serialize_into_with_order(bytes, order);
+        self.msc_hi.serialize_into_with_order(bytes, order);
+        self.msc_lo.serialize_into_with_order(bytes, order);
+        self.sbc_hi.serialize_into_with_order(bytes, order);
+        self.sbc_lo.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the WaitSBC request
 pub const WAIT_SBC_REQUEST: u8 = 11;
-pub fn wait_sbc<Conn>(conn: &Conn, drawable: xproto::Drawable, target_sbc_hi: u32, target_sbc_lo: u32) -> Result<Cookie<'_, Conn, WaitSBCReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WaitSBCRequest {
+    pub drawable: xproto::Drawable,
+    pub target_sbc_hi: u32,
+    pub target_sbc_lo: u32,
+}
+impl WaitSBCRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != WAIT_SBC_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (target_sbc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (target_sbc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(WaitSBCRequest {
+            drawable,
+            target_sbc_hi,
+            target_sbc_lo,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `target_sbc_hi` field of this structure.
+    pub fn target_sbc_hi(mut self, value: u32) -> Self {
+        self.target_sbc_hi = value;
+        self
+    }
+    /// Set the `target_sbc_lo` field of this structure.
+    pub fn target_sbc_lo(mut self, value: u32) -> Self {
+        self.target_sbc_lo = value;
+        self
+    }
+}
+
+pub fn wait_sbc<Conn, A>(conn: &Conn, drawable: A, target_sbc_hi: u32, target_sbc_lo: u32) -> Result<Cookie<'_, Conn, WaitSBCReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let target_sbc_hi_bytes = target_sbc_hi.serialize();
-    let target_sbc_lo_bytes = target_sbc_lo.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let target_sbc_hi_bytes = target_sbc_hi.serialize_with_order(order);
+    let target_sbc_lo_bytes = target_sbc_lo.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         WAIT_SBC_REQUEST,
@@ -1073,9 +1822,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for WaitSBCRequest {
+    type Reply = WaitSBCReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for WaitSBCRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, WaitSBCReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        wait_sbc(conn, self.drawable, self.target_sbc_hi, self.target_sbc_lo)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WaitSBCReply {
@@ -1090,17 +1851,17 @@ pub struct WaitSBCReply {
     pub sbc_lo: u32,
 }
 impl TryParse for WaitSBCReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc_hi, remaining) = u32::try_parse(remaining)?;
-        let (sbc_lo, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = WaitSBCReply { response_type, sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
         Ok((result, remaining))
     }
@@ -1111,18 +1872,82 @@ impl TryFrom<&[u8]> for WaitSBCReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for WaitSBCReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ust_hi.serialize_into_with_order(bytes, order);
+        self.ust_lo.serialize_into_with_order(bytes, order);
+        self.msc_hi.serialize_into_with_order(bytes, order);
+        self.msc_lo.serialize_into_with_order(bytes, order);
+        self.sbc_hi.serialize_into_with_order(bytes, order);
+        self.sbc_lo.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SwapInterval request
 pub const SWAP_INTERVAL_REQUEST: u8 = 12;
-pub fn swap_interval<Conn>(conn: &Conn, drawable: xproto::Drawable, interval: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SwapIntervalRequest {
+    pub drawable: xproto::Drawable,
+    pub interval: u32,
+}
+impl SwapIntervalRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWAP_INTERVAL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (interval, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SwapIntervalRequest {
+            drawable,
+            interval,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `interval` field of this structure.
+    pub fn interval(mut self, value: u32) -> Self {
+        self.interval = value;
+        self
+    }
+}
+
+pub fn swap_interval<Conn, A>(conn: &Conn, drawable: A, interval: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let interval_bytes = interval.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let interval_bytes = interval.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SWAP_INTERVAL_REQUEST,
@@ -1140,21 +1965,69 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SwapIntervalRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SwapIntervalRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        swap_interval(conn, self.drawable, self.interval)
+    }
+}
 
 /// Opcode for the GetParam request
 pub const GET_PARAM_REQUEST: u8 = 13;
-pub fn get_param<Conn>(conn: &Conn, drawable: xproto::Drawable, param: u32) -> Result<Cookie<'_, Conn, GetParamReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetParamRequest {
+    pub drawable: xproto::Drawable,
+    pub param: u32,
+}
+impl GetParamRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PARAM_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (param, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetParamRequest {
+            drawable,
+            param,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `param` field of this structure.
+    pub fn param(mut self, value: u32) -> Self {
+        self.param = value;
+        self
+    }
+}
+
+pub fn get_param<Conn, A>(conn: &Conn, drawable: A, param: u32) -> Result<Cookie<'_, Conn, GetParamReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let param_bytes = param.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let param_bytes = param.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PARAM_REQUEST,
@@ -1172,9 +2045,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetParamRequest {
+    type Reply = GetParamReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetParamRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetParamReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_param(conn, self.drawable, self.param)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetParamReply {
@@ -1186,13 +2071,13 @@ pub struct GetParamReply {
     pub value_lo: u32,
 }
 impl TryParse for GetParamReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (is_param_recognized, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (value_hi, remaining) = u32::try_parse(remaining)?;
-        let (value_lo, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (is_param_recognized, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = GetParamReply { response_type, is_param_recognized, sequence, length, value_hi, value_lo };
         Ok((result, remaining))
     }
@@ -1203,6 +2088,30 @@ impl TryFrom<&[u8]> for GetParamReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetParamReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.is_param_recognized.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.value_hi.serialize_into_with_order(bytes, order);
+        self.value_lo.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the BufferSwapComplete event
 pub const BUFFER_SWAP_COMPLETE_EVENT: u8 = 0;
@@ -1219,19 +2128,19 @@ pub struct BufferSwapCompleteEvent {
     pub sbc: u32,
 }
 impl TryParse for BufferSwapCompleteEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc, remaining) = u32::try_parse(remaining)?;
-        let event_type = event_type.try_into()?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let event_type = EventType::from(event_type);
         let result = BufferSwapCompleteEvent { response_type, sequence, event_type, drawable, ust_hi, ust_lo, msc_hi, msc_lo, sbc };
         Ok((result, remaining))
     }
@@ -1244,15 +2153,26 @@ impl TryFrom<&[u8]> for BufferSwapCompleteEvent {
 }
 impl From<&BufferSwapCompleteEvent> for [u8; 32] {
     fn from(input: &BufferSwapCompleteEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let event_type_bytes = u16::from(input.event_type).serialize();
-        let drawable_bytes = input.drawable.serialize();
-        let ust_hi_bytes = input.ust_hi.serialize();
-        let ust_lo_bytes = input.ust_lo.serialize();
-        let msc_hi_bytes = input.msc_hi.serialize();
-        let msc_lo_bytes = input.msc_lo.serialize();
-        let sbc_bytes = input.sbc.serialize();
+        input.serialize()
+    }
+}
+impl From<BufferSwapCompleteEvent> for [u8; 32] {
+    fn from(input: BufferSwapCompleteEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BufferSwapCompleteEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let event_type_bytes = u16::from(self.event_type).serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let ust_hi_bytes = self.ust_hi.serialize_with_order(order);
+        let ust_lo_bytes = self.ust_lo.serialize_with_order(order);
+        let msc_hi_bytes = self.msc_hi.serialize_with_order(order);
+        let msc_lo_bytes = self.msc_lo.serialize_with_order(order);
+        let sbc_bytes = self.sbc.serialize_with_order(order);
         [
             response_type_bytes[0],
             0,
@@ -1288,10 +2208,8 @@ impl From<&BufferSwapCompleteEvent> for [u8; 32] {
             sbc_bytes[3],
         ]
     }
-}
-impl From<BufferSwapCompleteEvent> for [u8; 32] {
-    fn from(input: BufferSwapCompleteEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1304,11 +2222,11 @@ pub struct InvalidateBuffersEvent {
     pub drawable: xproto::Drawable,
 }
 impl TryParse for InvalidateBuffersEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
         let result = InvalidateBuffersEvent { response_type, sequence, drawable };
         Ok((result, remaining))
     }
@@ -1321,9 +2239,20 @@ impl TryFrom<&[u8]> for InvalidateBuffersEvent {
 }
 impl From<&InvalidateBuffersEvent> for [u8; 32] {
     fn from(input: &InvalidateBuffersEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let drawable_bytes = input.drawable.serialize();
+        input.serialize()
+    }
+}
+impl From<InvalidateBuffersEvent> for [u8; 32] {
+    fn from(input: InvalidateBuffersEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for InvalidateBuffersEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
         [
             response_type_bytes[0],
             0,
@@ -1360,10 +2289,8 @@ impl From<&InvalidateBuffersEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<InvalidateBuffersEvent> for [u8; 32] {
-    fn from(input: InvalidateBuffersEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1373,7 +2300,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_version(self, major_version, minor_version)
     }
-    fn dri2_connect(&self, window: xproto::Window, driver_type: DriverType) -> Result<Cookie<'_, Self, ConnectReply>, ConnectionError>
+    fn dri2_connect<A>(&self, window: xproto::Window, driver_type: A) -> Result<Cookie<'_, Self, ConnectReply>, ConnectionError>
+    where
+        A: Into<DriverType>,
     {
         connect(self, window, driver_type)
     }
@@ -1381,47 +2310,69 @@ pub trait ConnectionExt: RequestConnection {
     {
         authenticate(self, window, magic)
     }
-    fn dri2_create_drawable(&self, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn dri2_create_drawable<A>(&self, drawable: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         create_drawable(self, drawable)
     }
-    fn dri2_destroy_drawable(&self, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn dri2_destroy_drawable<A>(&self, drawable: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         destroy_drawable(self, drawable)
     }
-    fn dri2_get_buffers<'c>(&'c self, drawable: xproto::Drawable, count: u32, attachments: &[u32]) -> Result<Cookie<'c, Self, GetBuffersReply>, ConnectionError>
+    fn dri2_get_buffers<'c, A>(&'c self, drawable: A, count: u32, attachments: &[u32]) -> Result<Cookie<'c, Self, GetBuffersReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         get_buffers(self, drawable, count, attachments)
     }
-    fn dri2_copy_region(&self, drawable: xproto::Drawable, region: u32, dest: u32, src: u32) -> Result<Cookie<'_, Self, CopyRegionReply>, ConnectionError>
+    fn dri2_copy_region<A>(&self, drawable: A, region: u32, dest: u32, src: u32) -> Result<Cookie<'_, Self, CopyRegionReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         copy_region(self, drawable, region, dest, src)
     }
-    fn dri2_get_buffers_with_format<'c>(&'c self, drawable: xproto::Drawable, count: u32, attachments: &[AttachFormat]) -> Result<Cookie<'c, Self, GetBuffersWithFormatReply>, ConnectionError>
+    fn dri2_get_buffers_with_format<'c, A>(&'c self, drawable: A, count: u32, attachments: &[AttachFormat]) -> Result<Cookie<'c, Self, GetBuffersWithFormatReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         get_buffers_with_format(self, drawable, count, attachments)
     }
-    fn dri2_swap_buffers(&self, drawable: xproto::Drawable, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Self, SwapBuffersReply>, ConnectionError>
+    fn dri2_swap_buffers<A>(&self, drawable: A, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Self, SwapBuffersReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         swap_buffers(self, drawable, target_msc_hi, target_msc_lo, divisor_hi, divisor_lo, remainder_hi, remainder_lo)
     }
-    fn dri2_get_msc(&self, drawable: xproto::Drawable) -> Result<Cookie<'_, Self, GetMSCReply>, ConnectionError>
+    fn dri2_get_msc<A>(&self, drawable: A) -> Result<Cookie<'_, Self, GetMSCReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         get_msc(self, drawable)
     }
-    fn dri2_wait_msc(&self, drawable: xproto::Drawable, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Self, WaitMSCReply>, ConnectionError>
+    fn dri2_wait_msc<A>(&self, drawable: A, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Self, WaitMSCReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         wait_msc(self, drawable, target_msc_hi, target_msc_lo, divisor_hi, divisor_lo, remainder_hi, remainder_lo)
     }
-    fn dri2_wait_sbc(&self, drawable: xproto::Drawable, target_sbc_hi: u32, target_sbc_lo: u32) -> Result<Cookie<'_, Self, WaitSBCReply>, ConnectionError>
+    fn dri2_wait_sbc<A>(&self, drawable: A, target_sbc_hi: u32, target_sbc_lo: u32) -> Result<Cookie<'_, Self, WaitSBCReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         wait_sbc(self, drawable, target_sbc_hi, target_sbc_lo)
     }
-    fn dri2_swap_interval(&self, drawable: xproto::Drawable, interval: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn dri2_swap_interval<A>(&self, drawable: A, interval: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         swap_interval(self, drawable, interval)
     }
-    fn dri2_get_param(&self, drawable: xproto::Drawable, param: u32) -> Result<Cookie<'_, Self, GetParamReply>, ConnectionError>
+    fn dri2_get_param<A>(&self, drawable: A, param: u32) -> Result<Cookie<'_, Self, GetParamReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         get_param(self, drawable, param)
     }
@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -33,15 +33,49 @@ pub const X11_XML_VERSION: (u32, u32) = (1, 2);
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u32) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u32) -> Self {
+        self.minor_version = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -59,9 +93,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.major_version, self.minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -72,13 +118,13 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
     }
@@ -89,18 +135,78 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Open request
 pub const OPEN_REQUEST: u8 = 1;
-pub fn open<Conn>(conn: &Conn, drawable: xproto::Drawable, provider: u32) -> Result<CookieWithFds<'_, Conn, OpenReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OpenRequest {
+    pub drawable: xproto::Drawable,
+    pub provider: u32,
+}
+impl OpenRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != OPEN_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (provider, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(OpenRequest {
+            drawable,
+            provider,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider(mut self, value: u32) -> Self {
+        self.provider = value;
+        self
+    }
+}
+
+pub fn open<Conn, A>(conn: &Conn, drawable: A, provider: u32) -> Result<CookieWithFds<'_, Conn, OpenReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let provider_bytes = provider.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let provider_bytes = provider.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         OPEN_REQUEST,
@@ -118,9 +224,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply_with_fds(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for OpenRequest {
+    type Reply = OpenReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for OpenRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = CookieWithFds<'c, Conn, OpenReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        open(conn, self.drawable, self.provider)
+    }
+}
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct OpenReply {
@@ -130,12 +248,12 @@ pub struct OpenReply {
     pub length: u32,
     pub device_fd: RawFdContainer,
 }
-impl OpenReply {
-    fn try_parse_fd<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+impl TryParseFd for OpenReply {
+    fn try_parse_fd_with_order<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         if fds.is_empty() { return Err(ParseError::ParseError) }
         let device_fd = fds.remove(0);
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
@@ -150,26 +268,142 @@ impl TryFrom<(&[u8], Vec<RawFdContainer>)> for OpenReply {
         Ok(Self::try_parse_fd(value, &mut fds)?.0)
     }
 }
+impl Serialize for OpenReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PixmapFromBuffer request
 pub const PIXMAP_FROM_BUFFER_REQUEST: u8 = 2;
-pub fn pixmap_from_buffer<Conn, A>(conn: &Conn, pixmap: xproto::Pixmap, drawable: xproto::Drawable, size: u32, width: u16, height: u16, stride: u16, depth: u8, bpp: u8, pixmap_fd: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, PartialEq, Eq)]
+pub struct PixmapFromBufferRequest {
+    pub pixmap: xproto::Pixmap,
+    pub drawable: xproto::Drawable,
+    pub size: u32,
+    pub width: u16,
+    pub height: u16,
+    pub stride: u16,
+    pub depth: u8,
+    pub bpp: u8,
+    pub pixmap_fd: RawFdContainer,
+}
+impl PixmapFromBufferRequest {
+    /// Parse this request given its header, its body, any fds that go along with it, and the byte order of the client
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXMAP_FROM_BUFFER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (stride, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        if fds.is_empty() { return Err(ParseError::ParseError) }
+        let pixmap_fd = fds.remove(0);
+        let _ = remaining;
+        let _ = order;
+        Ok(PixmapFromBufferRequest {
+            pixmap,
+            drawable,
+            size,
+            width,
+            height,
+            stride,
+            depth,
+            bpp,
+            pixmap_fd,
+        })
+    }
+    /// Set the `pixmap` field of this structure.
+    pub fn pixmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.pixmap = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `size` field of this structure.
+    pub fn size(mut self, value: u32) -> Self {
+        self.size = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `stride` field of this structure.
+    pub fn stride(mut self, value: u16) -> Self {
+        self.stride = value;
+        self
+    }
+    /// Set the `depth` field of this structure.
+    pub fn depth(mut self, value: u8) -> Self {
+        self.depth = value;
+        self
+    }
+    /// Set the `bpp` field of this structure.
+    pub fn bpp(mut self, value: u8) -> Self {
+        self.bpp = value;
+        self
+    }
+    /// Set the `pixmap_fd` field of this structure.
+    pub fn pixmap_fd<I>(mut self, value: I) -> Self where I: Into<RawFdContainer> {
+        self.pixmap_fd = value.into();
+        self
+    }
+}
+
+pub fn pixmap_from_buffer<Conn, A, B>(conn: &Conn, pixmap: xproto::Pixmap, drawable: A, size: u32, width: u16, height: u16, stride: u16, depth: u8, bpp: u8, pixmap_fd: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<RawFdContainer>,
+    A: Into<xproto::Drawable>,
+    B: Into<RawFdContainer>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
     let pixmap_fd: RawFdContainer = pixmap_fd.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let pixmap_bytes = pixmap.serialize();
-    let drawable_bytes = drawable.serialize();
-    let size_bytes = size.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let stride_bytes = stride.serialize();
-    let depth_bytes = depth.serialize();
-    let bpp_bytes = bpp.serialize();
+    let pixmap_bytes = pixmap.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let size_bytes = size.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let stride_bytes = stride.serialize_with_order(order);
+    let depth_bytes = depth.serialize_with_order(order);
+    let bpp_bytes = bpp.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PIXMAP_FROM_BUFFER_REQUEST,
@@ -199,20 +433,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![pixmap_fd])?)
 }
+impl crate::x11_utils::Request for PixmapFromBufferRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PixmapFromBufferRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        pixmap_from_buffer(conn, self.pixmap, self.drawable, self.size, self.width, self.height, self.stride, self.depth, self.bpp, self.pixmap_fd)
+    }
+}
 
 /// Opcode for the BufferFromPixmap request
 pub const BUFFER_FROM_PIXMAP_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BufferFromPixmapRequest {
+    pub pixmap: xproto::Pixmap,
+}
+impl BufferFromPixmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != BUFFER_FROM_PIXMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(BufferFromPixmapRequest {
+            pixmap,
+        })
+    }
+    /// Set the `pixmap` field of this structure.
+    pub fn pixmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.pixmap = value.into();
+        self
+    }
+}
+
 pub fn buffer_from_pixmap<Conn>(conn: &Conn, pixmap: xproto::Pixmap) -> Result<CookieWithFds<'_, Conn, BufferFromPixmapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let pixmap_bytes = pixmap.serialize();
+    let pixmap_bytes = pixmap.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         BUFFER_FROM_PIXMAP_REQUEST,
@@ -226,9 +498,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply_with_fds(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for BufferFromPixmapRequest {
+    type Reply = BufferFromPixmapReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for BufferFromPixmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = CookieWithFds<'c, Conn, BufferFromPixmapReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        buffer_from_pixmap(conn, self.pixmap)
+    }
+}
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct BufferFromPixmapReply {
@@ -244,18 +528,18 @@ pub struct BufferFromPixmapReply {
     pub bpp: u8,
     pub pixmap_fd: RawFdContainer,
 }
-impl BufferFromPixmapReply {
-    fn try_parse_fd<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (stride, remaining) = u16::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (bpp, remaining) = u8::try_parse(remaining)?;
+impl TryParseFd for BufferFromPixmapReply {
+    fn try_parse_fd_with_order<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (stride, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
         if fds.is_empty() { return Err(ParseError::ParseError) }
         let pixmap_fd = fds.remove(0);
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
@@ -270,21 +554,104 @@ impl TryFrom<(&[u8], Vec<RawFdContainer>)> for BufferFromPixmapReply {
         Ok(Self::try_parse_fd(value, &mut fds)?.0)
     }
 }
+impl Serialize for BufferFromPixmapReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.size.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.stride.serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
+        self.bpp.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the FenceFromFD request
 pub const FENCE_FROM_FD_REQUEST: u8 = 4;
-pub fn fence_from_fd<Conn, A>(conn: &Conn, drawable: xproto::Drawable, fence: u32, initially_triggered: bool, fence_fd: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, PartialEq, Eq)]
+pub struct FenceFromFDRequest {
+    pub drawable: xproto::Drawable,
+    pub fence: u32,
+    pub initially_triggered: bool,
+    pub fence_fd: RawFdContainer,
+}
+impl FenceFromFDRequest {
+    /// Parse this request given its header, its body, any fds that go along with it, and the byte order of the client
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FENCE_FROM_FD_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (fence, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (initially_triggered, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        if fds.is_empty() { return Err(ParseError::ParseError) }
+        let fence_fd = fds.remove(0);
+        let _ = remaining;
+        let _ = order;
+        Ok(FenceFromFDRequest {
+            drawable,
+            fence,
+            initially_triggered,
+            fence_fd,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `fence` field of this structure.
+    pub fn fence(mut self, value: u32) -> Self {
+        self.fence = value;
+        self
+    }
+    /// Set the `initially_triggered` field of this structure.
+    pub fn initially_triggered(mut self, value: bool) -> Self {
+        self.initially_triggered = value;
+        self
+    }
+    /// Set the `fence_fd` field of this structure.
+    pub fn fence_fd<I>(mut self, value: I) -> Self where I: Into<RawFdContainer> {
+        self.fence_fd = value.into();
+        self
+    }
+}
+
+pub fn fence_from_fd<Conn, A, B>(conn: &Conn, drawable: A, fence: u32, initially_triggered: bool, fence_fd: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<RawFdContainer>,
+    A: Into<xproto::Drawable>,
+    B: Into<RawFdContainer>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
     let fence_fd: RawFdContainer = fence_fd.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let fence_bytes = fence.serialize();
-    let initially_triggered_bytes = initially_triggered.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let fence_bytes = fence.serialize_with_order(order);
+    let initially_triggered_bytes = initially_triggered.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FENCE_FROM_FD_REQUEST,
@@ -306,21 +673,69 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![fence_fd])?)
 }
+impl crate::x11_utils::Request for FenceFromFDRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FenceFromFDRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        fence_from_fd(conn, self.drawable, self.fence, self.initially_triggered, self.fence_fd)
+    }
+}
 
 /// Opcode for the FDFromFence request
 pub const FD_FROM_FENCE_REQUEST: u8 = 5;
-pub fn fd_from_fence<Conn>(conn: &Conn, drawable: xproto::Drawable, fence: u32) -> Result<CookieWithFds<'_, Conn, FDFromFenceReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FDFromFenceRequest {
+    pub drawable: xproto::Drawable,
+    pub fence: u32,
+}
+impl FDFromFenceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FD_FROM_FENCE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (fence, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FDFromFenceRequest {
+            drawable,
+            fence,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `fence` field of this structure.
+    pub fn fence(mut self, value: u32) -> Self {
+        self.fence = value;
+        self
+    }
+}
+
+pub fn fd_from_fence<Conn, A>(conn: &Conn, drawable: A, fence: u32) -> Result<CookieWithFds<'_, Conn, FDFromFenceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let fence_bytes = fence.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let fence_bytes = fence.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FD_FROM_FENCE_REQUEST,
@@ -338,9 +753,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply_with_fds(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FDFromFenceRequest {
+    type Reply = FDFromFenceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FDFromFenceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = CookieWithFds<'c, Conn, FDFromFenceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        fd_from_fence(conn, self.drawable, self.fence)
+    }
+}
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct FDFromFenceReply {
@@ -350,12 +777,12 @@ pub struct FDFromFenceReply {
     pub length: u32,
     pub fence_fd: RawFdContainer,
 }
-impl FDFromFenceReply {
-    fn try_parse_fd<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+impl TryParseFd for FDFromFenceReply {
+    fn try_parse_fd_with_order<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         if fds.is_empty() { return Err(ParseError::ParseError) }
         let fence_fd = fds.remove(0);
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
@@ -370,19 +797,85 @@ impl TryFrom<(&[u8], Vec<RawFdContainer>)> for FDFromFenceReply {
         Ok(Self::try_parse_fd(value, &mut fds)?.0)
     }
 }
+impl Serialize for FDFromFenceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetSupportedModifiers request
 pub const GET_SUPPORTED_MODIFIERS_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetSupportedModifiersRequest {
+    pub window: u32,
+    pub depth: u8,
+    pub bpp: u8,
+}
+impl GetSupportedModifiersRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SUPPORTED_MODIFIERS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetSupportedModifiersRequest {
+            window,
+            depth,
+            bpp,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window(mut self, value: u32) -> Self {
+        self.window = value;
+        self
+    }
+    /// Set the `depth` field of this structure.
+    pub fn depth(mut self, value: u8) -> Self {
+        self.depth = value;
+        self
+    }
+    /// Set the `bpp` field of this structure.
+    pub fn bpp(mut self, value: u8) -> Self {
+        self.bpp = value;
+        self
+    }
+}
+
 pub fn get_supported_modifiers<Conn>(conn: &Conn, window: u32, depth: u8, bpp: u8) -> Result<Cookie<'_, Conn, GetSupportedModifiersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let depth_bytes = depth.serialize();
-    let bpp_bytes = bpp.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let depth_bytes = depth.serialize_with_order(order);
+    let bpp_bytes = bpp.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_SUPPORTED_MODIFIERS_REQUEST,
@@ -400,9 +893,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetSupportedModifiersRequest {
+    type Reply = GetSupportedModifiersReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetSupportedModifiersRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetSupportedModifiersReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_supported_modifiers(conn, self.window, self.depth, self.bpp)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetSupportedModifiersReply {
@@ -413,16 +918,16 @@ pub struct GetSupportedModifiersReply {
     pub screen_modifiers: Vec<u64>,
 }
 impl TryParse for GetSupportedModifiersReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_window_modifiers, remaining) = u32::try_parse(remaining)?;
-        let (num_screen_modifiers, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_window_modifiers, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_screen_modifiers, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (window_modifiers, remaining) = crate::x11_utils::parse_list::<u64>(remaining, num_window_modifiers.try_into().or(Err(ParseError::ParseError))?)?;
-        let (screen_modifiers, remaining) = crate::x11_utils::parse_list::<u64>(remaining, num_screen_modifiers.try_into().or(Err(ParseError::ParseError))?)?;
+        let (window_modifiers, remaining) = crate::x11_utils::parse_list_with_order::<u64>(remaining, num_window_modifiers.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (screen_modifiers, remaining) = crate::x11_utils::parse_list_with_order::<u64>(remaining, num_screen_modifiers.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetSupportedModifiersReply { response_type, sequence, length, window_modifiers, screen_modifiers };
         Ok((result, remaining))
     }
@@ -433,33 +938,214 @@ impl TryFrom<&[u8]> for GetSupportedModifiersReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetSupportedModifiersReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_window_modifiers = u32::try_from(self.window_modifiers.len()).expect("`window_modifiers` has too many elements");
+        num_window_modifiers.serialize_into_with_order(bytes, order);
+        let num_screen_modifiers = u32::try_from(self.screen_modifiers.len()).expect("`screen_modifiers` has too many elements");
+        num_screen_modifiers.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.window_modifiers.serialize_into_with_order(bytes, order);
+        self.screen_modifiers.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PixmapFromBuffers request
 pub const PIXMAP_FROM_BUFFERS_REQUEST: u8 = 7;
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct PixmapFromBuffersRequest {
+    pub pixmap: xproto::Pixmap,
+    pub window: xproto::Window,
+    pub width: u16,
+    pub height: u16,
+    pub stride0: u32,
+    pub offset0: u32,
+    pub stride1: u32,
+    pub offset1: u32,
+    pub stride2: u32,
+    pub offset2: u32,
+    pub stride3: u32,
+    pub offset3: u32,
+    pub depth: u8,
+    pub bpp: u8,
+    pub modifier: u64,
+    pub buffers: Vec<RawFdContainer>,
+}
+impl PixmapFromBuffersRequest {
+    /// Parse this request given its header, its body, any fds that go along with it, and the byte order of the client
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXMAP_FROM_BUFFERS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (num_buffers, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (stride0, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset0, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (stride1, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset1, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (stride2, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset2, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (stride3, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset3, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (modifier, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let fds_len = usize::try_from(num_buffers).or(Err(ParseError::ParseError))?;
+        if fds.len() < fds_len { return Err(ParseError::ParseError) }
+        let mut buffers = fds.split_off(fds_len);
+        std::mem::swap(fds, &mut buffers);
+        let _ = remaining;
+        let _ = order;
+        Ok(PixmapFromBuffersRequest {
+            pixmap,
+            window,
+            width,
+            height,
+            stride0,
+            offset0,
+            stride1,
+            offset1,
+            stride2,
+            offset2,
+            stride3,
+            offset3,
+            depth,
+            bpp,
+            modifier,
+            buffers,
+        })
+    }
+    /// Set the `pixmap` field of this structure.
+    pub fn pixmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.pixmap = value.into();
+        self
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `stride0` field of this structure.
+    pub fn stride0(mut self, value: u32) -> Self {
+        self.stride0 = value;
+        self
+    }
+    /// Set the `offset0` field of this structure.
+    pub fn offset0(mut self, value: u32) -> Self {
+        self.offset0 = value;
+        self
+    }
+    /// Set the `stride1` field of this structure.
+    pub fn stride1(mut self, value: u32) -> Self {
+        self.stride1 = value;
+        self
+    }
+    /// Set the `offset1` field of this structure.
+    pub fn offset1(mut self, value: u32) -> Self {
+        self.offset1 = value;
+        self
+    }
+    /// Set the `stride2` field of this structure.
+    pub fn stride2(mut self, value: u32) -> Self {
+        self.stride2 = value;
+        self
+    }
+    /// Set the `offset2` field of this structure.
+    pub fn offset2(mut self, value: u32) -> Self {
+        self.offset2 = value;
+        self
+    }
+    /// Set the `stride3` field of this structure.
+    pub fn stride3(mut self, value: u32) -> Self {
+        self.stride3 = value;
+        self
+    }
+    /// Set the `offset3` field of this structure.
+    pub fn offset3(mut self, value: u32) -> Self {
+        self.offset3 = value;
+        self
+    }
+    /// Set the `depth` field of this structure.
+    pub fn depth(mut self, value: u8) -> Self {
+        self.depth = value;
+        self
+    }
+    /// Set the `bpp` field of this structure.
+    pub fn bpp(mut self, value: u8) -> Self {
+        self.bpp = value;
+        self
+    }
+    /// Set the `modifier` field of this structure.
+    pub fn modifier(mut self, value: u64) -> Self {
+        self.modifier = value;
+        self
+    }
+    /// Set the `buffers` field of this structure.
+    pub fn buffers<I>(mut self, value: I) -> Self where I: Into<Vec<RawFdContainer>> {
+        self.buffers = value.into();
+        self
+    }
+}
+
 pub fn pixmap_from_buffers<Conn>(conn: &Conn, pixmap: xproto::Pixmap, window: xproto::Window, width: u16, height: u16, stride0: u32, offset0: u32, stride1: u32, offset1: u32, stride2: u32, offset2: u32, stride3: u32, offset3: u32, depth: u8, bpp: u8, modifier: u64, buffers: Vec<RawFdContainer>) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let pixmap_bytes = pixmap.serialize();
-    let window_bytes = window.serialize();
+    let pixmap_bytes = pixmap.serialize_with_order(order);
+    let window_bytes = window.serialize_with_order(order);
     let num_buffers = u8::try_from(buffers.len()).expect("`buffers` has too many elements");
-    let num_buffers_bytes = num_buffers.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let stride0_bytes = stride0.serialize();
-    let offset0_bytes = offset0.serialize();
-    let stride1_bytes = stride1.serialize();
-    let offset1_bytes = offset1.serialize();
-    let stride2_bytes = stride2.serialize();
-    let offset2_bytes = offset2.serialize();
-    let stride3_bytes = stride3.serialize();
-    let offset3_bytes = offset3.serialize();
-    let depth_bytes = depth.serialize();
-    let bpp_bytes = bpp.serialize();
-    let modifier_bytes = modifier.serialize();
+    let num_buffers_bytes = num_buffers.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let stride0_bytes = stride0.serialize_with_order(order);
+    let offset0_bytes = offset0.serialize_with_order(order);
+    let stride1_bytes = stride1.serialize_with_order(order);
+    let offset1_bytes = offset1.serialize_with_order(order);
+    let stride2_bytes = stride2.serialize_with_order(order);
+    let offset2_bytes = offset2.serialize_with_order(order);
+    let stride3_bytes = stride3.serialize_with_order(order);
+    let offset3_bytes = offset3.serialize_with_order(order);
+    let depth_bytes = depth.serialize_with_order(order);
+    let bpp_bytes = bpp.serialize_with_order(order);
+    let modifier_bytes = modifier.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PIXMAP_FROM_BUFFERS_REQUEST,
@@ -529,20 +1215,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], buffers)?)
 }
+impl crate::x11_utils::Request for PixmapFromBuffersRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PixmapFromBuffersRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        pixmap_from_buffers(conn, self.pixmap, self.window, self.width, self.height, self.stride0, self.offset0, self.stride1, self.offset1, self.stride2, self.offset2, self.stride3, self.offset3, self.depth, self.bpp, self.modifier, self.buffers)
+    }
+}
 
 /// Opcode for the BuffersFromPixmap request
 pub const BUFFERS_FROM_PIXMAP_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BuffersFromPixmapRequest {
+    pub pixmap: xproto::Pixmap,
+}
+impl BuffersFromPixmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != BUFFERS_FROM_PIXMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(BuffersFromPixmapRequest {
+            pixmap,
+        })
+    }
+    /// Set the `pixmap` field of this structure.
+    pub fn pixmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.pixmap = value.into();
+        self
+    }
+}
+
 pub fn buffers_from_pixmap<Conn>(conn: &Conn, pixmap: xproto::Pixmap) -> Result<CookieWithFds<'_, Conn, BuffersFromPixmapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let pixmap_bytes = pixmap.serialize();
+    let pixmap_bytes = pixmap.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         BUFFERS_FROM_PIXMAP_REQUEST,
@@ -556,9 +1280,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply_with_fds(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for BuffersFromPixmapRequest {
+    type Reply = BuffersFromPixmapReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for BuffersFromPixmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = CookieWithFds<'c, Conn, BuffersFromPixmapReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        buffers_from_pixmap(conn, self.pixmap)
+    }
+}
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct BuffersFromPixmapReply {
@@ -574,21 +1310,21 @@ pub struct BuffersFromPixmapReply {
     pub offsets: Vec<u32>,
     pub buffers: Vec<RawFdContainer>,
 }
-impl BuffersFromPixmapReply {
-    fn try_parse_fd<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+impl TryParseFd for BuffersFromPixmapReply {
+    fn try_parse_fd_with_order<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (modifier, remaining) = u64::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (bpp, remaining) = u8::try_parse(remaining)?;
+        let (modifier, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(6..).ok_or(ParseError::ParseError)?;
-        let (strides, remaining) = crate::x11_utils::parse_list::<u32>(remaining, nfd.try_into().or(Err(ParseError::ParseError))?)?;
-        let (offsets, remaining) = crate::x11_utils::parse_list::<u32>(remaining, nfd.try_into().or(Err(ParseError::ParseError))?)?;
+        let (strides, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, nfd.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (offsets, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, nfd.try_into().or(Err(ParseError::ParseError))?, order)?;
         let fds_len = usize::try_from(nfd).or(Err(ParseError::ParseError))?;
         if fds.len() < fds_len { return Err(ParseError::ParseError) }
         let mut buffers = fds.split_off(fds_len);
@@ -604,6 +1340,40 @@ impl TryFrom<(&[u8], Vec<RawFdContainer>)> for BuffersFromPixmapReply {
         Ok(Self::try_parse_fd(value, &mut fds)?.0)
     }
 }
+impl Serialize for BuffersFromPixmapReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        let nfd = u8::try_from(self.strides.len()).expect("`strides` has too many elements");
+        nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.modifier.serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
+        self.bpp.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 6]);
+        self.strides.serialize_into_with_order(bytes, order);
+        assert_eq!(self.offsets.len(), usize::try_from(nfd).unwrap(), "`offsets` has an incorrect length");
+        self.offsets.serialize_into_with_order(bytes, order);
+        assert_eq!(self.buffers.len(), usize::try_from(nfd).unwrap(), "`buffers` has an incorrect length");
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -611,13 +1381,16 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_version(self, major_version, minor_version)
     }
-    fn dri3_open(&self, drawable: xproto::Drawable, provider: u32) -> Result<CookieWithFds<'_, Self, OpenReply>, ConnectionError>
+    fn dri3_open<A>(&self, drawable: A, provider: u32) -> Result<CookieWithFds<'_, Self, OpenReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         open(self, drawable, provider)
     }
-    fn dri3_pixmap_from_buffer<A>(&self, pixmap: xproto::Pixmap, drawable: xproto::Drawable, size: u32, width: u16, height: u16, stride: u16, depth: u8, bpp: u8, pixmap_fd: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn dri3_pixmap_from_buffer<A, B>(&self, pixmap: xproto::Pixmap, drawable: A, size: u32, width: u16, height: u16, stride: u16, depth: u8, bpp: u8, pixmap_fd: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<RawFdContainer>,
+        A: Into<xproto::Drawable>,
+        B: Into<RawFdContainer>,
     {
         pixmap_from_buffer(self, pixmap, drawable, size, width, height, stride, depth, bpp, pixmap_fd)
     }
@@ -625,13 +1398,16 @@ pub trait ConnectionExt: RequestConnection {
     {
         buffer_from_pixmap(self, pixmap)
     }
-    fn dri3_fence_from_fd<A>(&self, drawable: xproto::Drawable, fence: u32, initially_triggered: bool, fence_fd: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn dri3_fence_from_fd<A, B>(&self, drawable: A, fence: u32, initially_triggered: bool, fence_fd: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<RawFdContainer>,
+        A: Into<xproto::Drawable>,
+        B: Into<RawFdContainer>,
     {
         fence_from_fd(self, drawable, fence, initially_triggered, fence_fd)
     }
-    fn dri3_fd_from_fence(&self, drawable: xproto::Drawable, fence: u32) -> Result<CookieWithFds<'_, Self, FDFromFenceReply>, ConnectionError>
+    fn dri3_fd_from_fence<A>(&self, drawable: A, fence: u32) -> Result<CookieWithFds<'_, Self, FDFromFenceReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         fd_from_fence(self, drawable, fence)
     }
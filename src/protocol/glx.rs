@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -31,17 +31,217 @@ pub const X11_EXTENSION_NAME: &str = "GLX";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 4);
 
-pub type Pixmap = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pixmap(u32);
+impl Pixmap {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Pixmap(0);
+}
+impl From<u32> for Pixmap {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Pixmap> for u32 {
+    fn from(value: Pixmap) -> Self {
+        value.0
+    }
+}
+impl TryParse for Pixmap {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Pixmap {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Context = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Context(u32);
+impl Context {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Context(0);
+}
+impl From<u32> for Context {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Context> for u32 {
+    fn from(value: Context) -> Self {
+        value.0
+    }
+}
+impl TryParse for Context {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Context {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Pbuffer = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pbuffer(u32);
+impl Pbuffer {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Pbuffer(0);
+}
+impl From<u32> for Pbuffer {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Pbuffer> for u32 {
+    fn from(value: Pbuffer) -> Self {
+        value.0
+    }
+}
+impl TryParse for Pbuffer {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Pbuffer {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Window = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Window(u32);
+impl Window {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Window(0);
+}
+impl From<u32> for Window {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Window> for u32 {
+    fn from(value: Window) -> Self {
+        value.0
+    }
+}
+impl TryParse for Window {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Window {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Fbconfig = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fbconfig(u32);
+impl Fbconfig {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Fbconfig(0);
+}
+impl From<u32> for Fbconfig {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Fbconfig> for u32 {
+    fn from(value: Fbconfig) -> Self {
+        value.0
+    }
+}
+impl TryParse for Fbconfig {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Fbconfig {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Drawable = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Drawable(u32);
+impl Drawable {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Drawable(0);
+}
+impl From<u32> for Drawable {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Drawable> for u32 {
+    fn from(value: Drawable) -> Self {
+        value.0
+    }
+}
+impl TryParse for Drawable {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Drawable {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
+impl From<xproto::Window> for Drawable {
+    fn from(value: xproto::Window) -> Self {
+        Self(u32::from(value))
+    }
+}
+impl From<Pbuffer> for Drawable {
+    fn from(value: Pbuffer) -> Self {
+        Self(u32::from(value))
+    }
+}
+impl From<Pixmap> for Drawable {
+    fn from(value: Pixmap) -> Self {
+        Self(u32::from(value))
+    }
+}
+impl From<Window> for Drawable {
+    fn from(value: Window) -> Self {
+        Self(u32::from(value))
+    }
+}
 
 pub type Float32 = f32;
 
@@ -61,13 +261,13 @@ pub struct GenericError {
     pub major_opcode: u8,
 }
 impl TryParse for GenericError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (bad_value, remaining) = u32::try_parse(remaining)?;
-        let (minor_opcode, remaining) = u16::try_parse(remaining)?;
-        let (major_opcode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (bad_value, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_opcode, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (major_opcode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(21..).ok_or(ParseError::ParseError)?;
         let result = GenericError { response_type, error_code, sequence, bad_value, minor_opcode, major_opcode };
         Ok((result, remaining))
@@ -81,12 +281,23 @@ impl TryFrom<&[u8]> for GenericError {
 }
 impl From<&GenericError> for [u8; 32] {
     fn from(input: &GenericError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let bad_value_bytes = input.bad_value.serialize();
-        let minor_opcode_bytes = input.minor_opcode.serialize();
-        let major_opcode_bytes = input.major_opcode.serialize();
+        input.serialize()
+    }
+}
+impl From<GenericError> for [u8; 32] {
+    fn from(input: GenericError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for GenericError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let bad_value_bytes = self.bad_value.serialize_with_order(order);
+        let minor_opcode_bytes = self.minor_opcode.serialize_with_order(order);
+        let major_opcode_bytes = self.major_opcode.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -122,10 +333,8 @@ impl From<&GenericError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<GenericError> for [u8; 32] {
-    fn from(input: GenericError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -203,20 +412,20 @@ pub struct PbufferClobberEvent {
     pub count: u16,
 }
 impl TryParse for PbufferClobberEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (draw_type, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (b_mask, remaining) = u32::try_parse(remaining)?;
-        let (aux_buffer, remaining) = u16::try_parse(remaining)?;
-        let (x, remaining) = u16::try_parse(remaining)?;
-        let (y, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (count, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (draw_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (b_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (aux_buffer, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
         let result = PbufferClobberEvent { response_type, sequence, event_type, draw_type, drawable, b_mask, aux_buffer, x, y, width, height, count };
         Ok((result, remaining))
@@ -230,18 +439,29 @@ impl TryFrom<&[u8]> for PbufferClobberEvent {
 }
 impl From<&PbufferClobberEvent> for [u8; 32] {
     fn from(input: &PbufferClobberEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let event_type_bytes = input.event_type.serialize();
-        let draw_type_bytes = input.draw_type.serialize();
-        let drawable_bytes = input.drawable.serialize();
-        let b_mask_bytes = input.b_mask.serialize();
-        let aux_buffer_bytes = input.aux_buffer.serialize();
-        let x_bytes = input.x.serialize();
-        let y_bytes = input.y.serialize();
-        let width_bytes = input.width.serialize();
-        let height_bytes = input.height.serialize();
-        let count_bytes = input.count.serialize();
+        input.serialize()
+    }
+}
+impl From<PbufferClobberEvent> for [u8; 32] {
+    fn from(input: PbufferClobberEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for PbufferClobberEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let event_type_bytes = self.event_type.serialize_with_order(order);
+        let draw_type_bytes = self.draw_type.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let b_mask_bytes = self.b_mask.serialize_with_order(order);
+        let aux_buffer_bytes = self.aux_buffer.serialize_with_order(order);
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
         [
             response_type_bytes[0],
             0,
@@ -277,10 +497,8 @@ impl From<&PbufferClobberEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<PbufferClobberEvent> for [u8; 32] {
-    fn from(input: PbufferClobberEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -299,18 +517,18 @@ pub struct BufferSwapCompleteEvent {
     pub sbc: u32,
 }
 impl TryParse for BufferSwapCompleteEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = BufferSwapCompleteEvent { response_type, sequence, event_type, drawable, ust_hi, ust_lo, msc_hi, msc_lo, sbc };
         Ok((result, remaining))
     }
@@ -323,15 +541,26 @@ impl TryFrom<&[u8]> for BufferSwapCompleteEvent {
 }
 impl From<&BufferSwapCompleteEvent> for [u8; 32] {
     fn from(input: &BufferSwapCompleteEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let event_type_bytes = input.event_type.serialize();
-        let drawable_bytes = input.drawable.serialize();
-        let ust_hi_bytes = input.ust_hi.serialize();
-        let ust_lo_bytes = input.ust_lo.serialize();
-        let msc_hi_bytes = input.msc_hi.serialize();
-        let msc_lo_bytes = input.msc_lo.serialize();
-        let sbc_bytes = input.sbc.serialize();
+        input.serialize()
+    }
+}
+impl From<BufferSwapCompleteEvent> for [u8; 32] {
+    fn from(input: BufferSwapCompleteEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BufferSwapCompleteEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let event_type_bytes = self.event_type.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let ust_hi_bytes = self.ust_hi.serialize_with_order(order);
+        let ust_lo_bytes = self.ust_lo.serialize_with_order(order);
+        let msc_hi_bytes = self.msc_hi.serialize_with_order(order);
+        let msc_lo_bytes = self.msc_lo.serialize_with_order(order);
+        let sbc_bytes = self.sbc.serialize_with_order(order);
         [
             response_type_bytes[0],
             0,
@@ -367,115 +596,104 @@ impl From<&BufferSwapCompleteEvent> for [u8; 32] {
             sbc_bytes[3],
         ]
     }
-}
-impl From<BufferSwapCompleteEvent> for [u8; 32] {
-    fn from(input: BufferSwapCompleteEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum PBCET {
-    Damaged = 32791,
-    Saved = 32792,
-}
-impl From<PBCET> for u16 {
-    fn from(input: PBCET) -> Self {
-        match input {
-            PBCET::Damaged => 32791,
-            PBCET::Saved => 32792,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PBCET(u16);
+#[allow(non_upper_case_globals)]
+impl PBCET {
+    pub const Damaged: Self = Self(32791);
+    pub const Saved: Self = Self(32792);
 }
+enum_newtype!(PBCET, u16, [Damaged, Saved]);
 impl From<PBCET> for Option<u16> {
     fn from(input: PBCET) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<PBCET> for u32 {
     fn from(input: PBCET) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PBCET> for Option<u32> {
     fn from(input: PBCET) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for PBCET {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            32791 => Ok(PBCET::Damaged),
-            32792 => Ok(PBCET::Saved),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for PBCET {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum PBCDT {
-    Window = 32793,
-    Pbuffer = 32794,
-}
-impl From<PBCDT> for u16 {
-    fn from(input: PBCDT) -> Self {
-        match input {
-            PBCDT::Window => 32793,
-            PBCDT::Pbuffer => 32794,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PBCDT(u16);
+#[allow(non_upper_case_globals)]
+impl PBCDT {
+    pub const Window: Self = Self(32793);
+    pub const Pbuffer: Self = Self(32794);
 }
+enum_newtype!(PBCDT, u16, [Window, Pbuffer]);
 impl From<PBCDT> for Option<u16> {
     fn from(input: PBCDT) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<PBCDT> for u32 {
     fn from(input: PBCDT) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PBCDT> for Option<u32> {
     fn from(input: PBCDT) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for PBCDT {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            32793 => Ok(PBCDT::Window),
-            32794 => Ok(PBCDT::Pbuffer),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the Render request
+pub const RENDER_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RenderRequest {
+    pub context_tag: ContextTag,
+    pub data: Vec<u8>,
+}
+impl RenderRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RENDER_REQUEST {
+            return Err(ParseError::ParseError);
         }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let data = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        Ok(RenderRequest {
+            context_tag,
+            data,
+        })
     }
-}
-impl TryFrom<u32> for PBCDT {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `data` field of this structure.
+    pub fn data<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.data = value.into();
+        self
     }
 }
 
-/// Opcode for the Render request
-pub const RENDER_REQUEST: u8 = 1;
 pub fn render<'c, Conn>(conn: &'c Conn, context_tag: ContextTag, data: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         RENDER_REQUEST,
@@ -492,24 +710,88 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(data), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for RenderRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for RenderRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        render(conn, self.context_tag, &self.data)
+    }
+}
 
 /// Opcode for the RenderLarge request
 pub const RENDER_LARGE_REQUEST: u8 = 2;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RenderLargeRequest {
+    pub context_tag: ContextTag,
+    pub request_num: u16,
+    pub request_total: u16,
+    pub data: Vec<u8>,
+}
+impl RenderLargeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RENDER_LARGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (request_num, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (request_total, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, data_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let data = data.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(RenderLargeRequest {
+            context_tag,
+            request_num,
+            request_total,
+            data,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `request_num` field of this structure.
+    pub fn request_num(mut self, value: u16) -> Self {
+        self.request_num = value;
+        self
+    }
+    /// Set the `request_total` field of this structure.
+    pub fn request_total(mut self, value: u16) -> Self {
+        self.request_total = value;
+        self
+    }
+    /// Set the `data` field of this structure.
+    pub fn data<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.data = value.into();
+        self
+    }
+}
+
 pub fn render_large<'c, Conn>(conn: &'c Conn, context_tag: ContextTag, request_num: u16, request_total: u16, data: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let request_num_bytes = request_num.serialize();
-    let request_total_bytes = request_total.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let request_num_bytes = request_num.serialize_with_order(order);
+    let request_total_bytes = request_total.serialize_with_order(order);
     let data_len = u32::try_from(data.len()).expect("`data` has too many elements");
-    let data_len_bytes = data_len.serialize();
+    let data_len_bytes = data_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         RENDER_LARGE_REQUEST,
@@ -534,24 +816,95 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(data), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for RenderLargeRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for RenderLargeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        render_large(conn, self.context_tag, self.request_num, self.request_total, &self.data)
+    }
+}
 
 /// Opcode for the CreateContext request
 pub const CREATE_CONTEXT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateContextRequest {
+    pub context: Context,
+    pub visual: xproto::Visualid,
+    pub screen: u32,
+    pub share_list: Context,
+    pub is_direct: bool,
+}
+impl CreateContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (share_list, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (is_direct, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateContextRequest {
+            context,
+            visual,
+            screen,
+            share_list,
+            is_direct,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `visual` field of this structure.
+    pub fn visual<I>(mut self, value: I) -> Self where I: Into<xproto::Visualid> {
+        self.visual = value.into();
+        self
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `share_list` field of this structure.
+    pub fn share_list<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.share_list = value.into();
+        self
+    }
+    /// Set the `is_direct` field of this structure.
+    pub fn is_direct(mut self, value: bool) -> Self {
+        self.is_direct = value;
+        self
+    }
+}
+
 pub fn create_context<Conn>(conn: &Conn, context: Context, visual: xproto::Visualid, screen: u32, share_list: Context, is_direct: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let visual_bytes = visual.serialize();
-    let screen_bytes = screen.serialize();
-    let share_list_bytes = share_list.serialize();
-    let is_direct_bytes = is_direct.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let visual_bytes = visual.serialize_with_order(order);
+    let screen_bytes = screen.serialize_with_order(order);
+    let share_list_bytes = share_list.serialize_with_order(order);
+    let is_direct_bytes = is_direct.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_CONTEXT_REQUEST,
@@ -581,20 +934,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_context(conn, self.context, self.visual, self.screen, self.share_list, self.is_direct)
+    }
+}
 
 /// Opcode for the DestroyContext request
 pub const DESTROY_CONTEXT_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyContextRequest {
+    pub context: Context,
+}
+impl DestroyContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyContextRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn destroy_context<Conn>(conn: &Conn, context: Context) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_CONTEXT_REQUEST,
@@ -608,22 +999,78 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_context(conn, self.context)
+    }
+}
 
 /// Opcode for the MakeCurrent request
 pub const MAKE_CURRENT_REQUEST: u8 = 5;
-pub fn make_current<Conn>(conn: &Conn, drawable: Drawable, context: Context, old_context_tag: ContextTag) -> Result<Cookie<'_, Conn, MakeCurrentReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MakeCurrentRequest {
+    pub drawable: Drawable,
+    pub context: Context,
+    pub old_context_tag: ContextTag,
+}
+impl MakeCurrentRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != MAKE_CURRENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (old_context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(MakeCurrentRequest {
+            drawable,
+            context,
+            old_context_tag,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `old_context_tag` field of this structure.
+    pub fn old_context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.old_context_tag = value.into();
+        self
+    }
+}
+
+pub fn make_current<Conn, A>(conn: &Conn, drawable: A, context: Context, old_context_tag: ContextTag) -> Result<Cookie<'_, Conn, MakeCurrentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let context_bytes = context.serialize();
-    let old_context_tag_bytes = old_context_tag.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let context_bytes = context.serialize_with_order(order);
+    let old_context_tag_bytes = old_context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         MAKE_CURRENT_REQUEST,
@@ -645,9 +1092,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for MakeCurrentRequest {
+    type Reply = MakeCurrentReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for MakeCurrentRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, MakeCurrentReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        make_current(conn, self.drawable, self.context, self.old_context_tag)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MakeCurrentReply {
@@ -657,12 +1116,12 @@ pub struct MakeCurrentReply {
     pub context_tag: ContextTag,
 }
 impl TryParse for MakeCurrentReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_tag, remaining) = ContextTag::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = MakeCurrentReply { response_type, sequence, length, context_tag };
         Ok((result, remaining))
@@ -674,17 +1133,67 @@ impl TryFrom<&[u8]> for MakeCurrentReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for MakeCurrentReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.context_tag.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the IsDirect request
 pub const IS_DIRECT_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IsDirectRequest {
+    pub context: Context,
+}
+impl IsDirectRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_DIRECT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(IsDirectRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn is_direct<Conn>(conn: &Conn, context: Context) -> Result<Cookie<'_, Conn, IsDirectReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         IS_DIRECT_REQUEST,
@@ -698,9 +1207,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for IsDirectRequest {
+    type Reply = IsDirectReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for IsDirectRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, IsDirectReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        is_direct(conn, self.context)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsDirectReply {
@@ -710,12 +1231,12 @@ pub struct IsDirectReply {
     pub is_direct: bool,
 }
 impl TryParse for IsDirectReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (is_direct, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (is_direct, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
         let result = IsDirectReply { response_type, sequence, length, is_direct };
         Ok((result, remaining))
@@ -727,18 +1248,76 @@ impl TryFrom<&[u8]> for IsDirectReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for IsDirectReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.is_direct.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u32) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u32) -> Self {
+        self.minor_version = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -756,9 +1335,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.major_version, self.minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -769,13 +1360,13 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
@@ -787,17 +1378,68 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the WaitGL request
 pub const WAIT_GL_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WaitGLRequest {
+    pub context_tag: ContextTag,
+}
+impl WaitGLRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != WAIT_GL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(WaitGLRequest {
+            context_tag,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+}
+
 pub fn wait_gl<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         WAIT_GL_REQUEST,
@@ -811,20 +1453,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for WaitGLRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for WaitGLRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        wait_gl(conn, self.context_tag)
+    }
+}
 
 /// Opcode for the WaitX request
 pub const WAIT_X_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WaitXRequest {
+    pub context_tag: ContextTag,
+}
+impl WaitXRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != WAIT_X_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(WaitXRequest {
+            context_tag,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+}
+
 pub fn wait_x<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         WAIT_X_REQUEST,
@@ -838,23 +1518,85 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for WaitXRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for WaitXRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        wait_x(conn, self.context_tag)
+    }
+}
 
 /// Opcode for the CopyContext request
 pub const COPY_CONTEXT_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CopyContextRequest {
+    pub src: Context,
+    pub dest: Context,
+    pub mask: u32,
+    pub src_context_tag: ContextTag,
+}
+impl CopyContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COPY_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (src, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (dest, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (src_context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CopyContextRequest {
+            src,
+            dest,
+            mask,
+            src_context_tag,
+        })
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `dest` field of this structure.
+    pub fn dest<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.dest = value.into();
+        self
+    }
+    /// Set the `mask` field of this structure.
+    pub fn mask(mut self, value: u32) -> Self {
+        self.mask = value;
+        self
+    }
+    /// Set the `src_context_tag` field of this structure.
+    pub fn src_context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.src_context_tag = value.into();
+        self
+    }
+}
+
 pub fn copy_context<Conn>(conn: &Conn, src: Context, dest: Context, mask: u32, src_context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let src_bytes = src.serialize();
-    let dest_bytes = dest.serialize();
-    let mask_bytes = mask.serialize();
-    let src_context_tag_bytes = src_context_tag.serialize();
+    let src_bytes = src.serialize_with_order(order);
+    let dest_bytes = dest.serialize_with_order(order);
+    let mask_bytes = mask.serialize_with_order(order);
+    let src_context_tag_bytes = src_context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         COPY_CONTEXT_REQUEST,
@@ -880,109 +1622,102 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)]
-#[repr(u32)]
-pub enum GC {
-    GL_CURRENT_BIT = 1 << 0,
-    GL_POINT_BIT = 1 << 1,
-    GL_LINE_BIT = 1 << 2,
-    GL_POLYGON_BIT = 1 << 3,
-    GL_POLYGON_STIPPLE_BIT = 1 << 4,
-    GL_PIXEL_MODE_BIT = 1 << 5,
-    GL_LIGHTING_BIT = 1 << 6,
-    GL_FOG_BIT = 1 << 7,
-    GL_DEPTH_BUFFER_BIT = 1 << 8,
-    GL_ACCUM_BUFFER_BIT = 1 << 9,
-    GL_STENCIL_BUFFER_BIT = 1 << 10,
-    GL_VIEWPORT_BIT = 1 << 11,
-    GL_TRANSFORM_BIT = 1 << 12,
-    GL_ENABLE_BIT = 1 << 13,
-    GL_COLOR_BUFFER_BIT = 1 << 14,
-    GL_HINT_BIT = 1 << 15,
-    GL_EVAL_BIT = 1 << 16,
-    GL_LIST_BIT = 1 << 17,
-    GL_TEXTURE_BIT = 1 << 18,
-    GL_SCISSOR_BIT = 1 << 19,
-    GL_ALL_ATTRIB_BITS = 16_777_215,
-}
-impl From<GC> for u32 {
-    fn from(input: GC) -> Self {
-        match input {
-            GC::GL_CURRENT_BIT => 1 << 0,
-            GC::GL_POINT_BIT => 1 << 1,
-            GC::GL_LINE_BIT => 1 << 2,
-            GC::GL_POLYGON_BIT => 1 << 3,
-            GC::GL_POLYGON_STIPPLE_BIT => 1 << 4,
-            GC::GL_PIXEL_MODE_BIT => 1 << 5,
-            GC::GL_LIGHTING_BIT => 1 << 6,
-            GC::GL_FOG_BIT => 1 << 7,
-            GC::GL_DEPTH_BUFFER_BIT => 1 << 8,
-            GC::GL_ACCUM_BUFFER_BIT => 1 << 9,
-            GC::GL_STENCIL_BUFFER_BIT => 1 << 10,
-            GC::GL_VIEWPORT_BIT => 1 << 11,
-            GC::GL_TRANSFORM_BIT => 1 << 12,
-            GC::GL_ENABLE_BIT => 1 << 13,
-            GC::GL_COLOR_BUFFER_BIT => 1 << 14,
-            GC::GL_HINT_BIT => 1 << 15,
-            GC::GL_EVAL_BIT => 1 << 16,
-            GC::GL_LIST_BIT => 1 << 17,
-            GC::GL_TEXTURE_BIT => 1 << 18,
-            GC::GL_SCISSOR_BIT => 1 << 19,
-            GC::GL_ALL_ATTRIB_BITS => 16_777_215,
-        }
-    }
+impl crate::x11_utils::Request for CopyContextRequest {
+    type Reply = ();
 }
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CopyContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        copy_context(conn, self.src, self.dest, self.mask, self.src_context_tag)
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GC(u32);
+#[allow(non_upper_case_globals)]
+impl GC {
+    pub const GL_CURRENT_BIT: Self = Self(1 << 0);
+    pub const GL_POINT_BIT: Self = Self(1 << 1);
+    pub const GL_LINE_BIT: Self = Self(1 << 2);
+    pub const GL_POLYGON_BIT: Self = Self(1 << 3);
+    pub const GL_POLYGON_STIPPLE_BIT: Self = Self(1 << 4);
+    pub const GL_PIXEL_MODE_BIT: Self = Self(1 << 5);
+    pub const GL_LIGHTING_BIT: Self = Self(1 << 6);
+    pub const GL_FOG_BIT: Self = Self(1 << 7);
+    pub const GL_DEPTH_BUFFER_BIT: Self = Self(1 << 8);
+    pub const GL_ACCUM_BUFFER_BIT: Self = Self(1 << 9);
+    pub const GL_STENCIL_BUFFER_BIT: Self = Self(1 << 10);
+    pub const GL_VIEWPORT_BIT: Self = Self(1 << 11);
+    pub const GL_TRANSFORM_BIT: Self = Self(1 << 12);
+    pub const GL_ENABLE_BIT: Self = Self(1 << 13);
+    pub const GL_COLOR_BUFFER_BIT: Self = Self(1 << 14);
+    pub const GL_HINT_BIT: Self = Self(1 << 15);
+    pub const GL_EVAL_BIT: Self = Self(1 << 16);
+    pub const GL_LIST_BIT: Self = Self(1 << 17);
+    pub const GL_TEXTURE_BIT: Self = Self(1 << 18);
+    pub const GL_SCISSOR_BIT: Self = Self(1 << 19);
+    pub const GL_ALL_ATTRIB_BITS: Self = Self(16_777_215);
+}
+bitmask_set!(GC, u32, [GL_CURRENT_BIT, GL_POINT_BIT, GL_LINE_BIT, GL_POLYGON_BIT, GL_POLYGON_STIPPLE_BIT, GL_PIXEL_MODE_BIT, GL_LIGHTING_BIT, GL_FOG_BIT, GL_DEPTH_BUFFER_BIT, GL_ACCUM_BUFFER_BIT, GL_STENCIL_BUFFER_BIT, GL_VIEWPORT_BIT, GL_TRANSFORM_BIT, GL_ENABLE_BIT, GL_COLOR_BUFFER_BIT, GL_HINT_BIT, GL_EVAL_BIT, GL_LIST_BIT, GL_TEXTURE_BIT, GL_SCISSOR_BIT]);
 impl From<GC> for Option<u32> {
     fn from(input: GC) -> Self {
-        Some(u32::from(input))
+        Some(input.0)
     }
 }
-impl TryFrom<u32> for GC {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(GC::GL_CURRENT_BIT),
-            2 => Ok(GC::GL_POINT_BIT),
-            4 => Ok(GC::GL_LINE_BIT),
-            8 => Ok(GC::GL_POLYGON_BIT),
-            16 => Ok(GC::GL_POLYGON_STIPPLE_BIT),
-            32 => Ok(GC::GL_PIXEL_MODE_BIT),
-            64 => Ok(GC::GL_LIGHTING_BIT),
-            128 => Ok(GC::GL_FOG_BIT),
-            256 => Ok(GC::GL_DEPTH_BUFFER_BIT),
-            512 => Ok(GC::GL_ACCUM_BUFFER_BIT),
-            1024 => Ok(GC::GL_STENCIL_BUFFER_BIT),
-            2048 => Ok(GC::GL_VIEWPORT_BIT),
-            4096 => Ok(GC::GL_TRANSFORM_BIT),
-            8192 => Ok(GC::GL_ENABLE_BIT),
-            16384 => Ok(GC::GL_COLOR_BUFFER_BIT),
-            32768 => Ok(GC::GL_HINT_BIT),
-            65536 => Ok(GC::GL_EVAL_BIT),
-            131_072 => Ok(GC::GL_LIST_BIT),
-            262_144 => Ok(GC::GL_TEXTURE_BIT),
-            524_288 => Ok(GC::GL_SCISSOR_BIT),
-            16_777_215 => Ok(GC::GL_ALL_ATTRIB_BITS),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the SwapBuffers request
+pub const SWAP_BUFFERS_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SwapBuffersRequest {
+    pub context_tag: ContextTag,
+    pub drawable: Drawable,
+}
+impl SwapBuffersRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWAP_BUFFERS_REQUEST {
+            return Err(ParseError::ParseError);
         }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SwapBuffersRequest {
+            context_tag,
+            drawable,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<Drawable> {
+        self.drawable = value.into();
+        self
     }
 }
 
-/// Opcode for the SwapBuffers request
-pub const SWAP_BUFFERS_REQUEST: u8 = 11;
-pub fn swap_buffers<Conn>(conn: &Conn, context_tag: ContextTag, drawable: Drawable) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+pub fn swap_buffers<Conn, A>(conn: &Conn, context_tag: ContextTag, drawable: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let drawable_bytes = drawable.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SWAP_BUFFERS_REQUEST,
@@ -1000,24 +1735,94 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SwapBuffersRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SwapBuffersRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        swap_buffers(conn, self.context_tag, self.drawable)
+    }
+}
 
 /// Opcode for the UseXFont request
 pub const USE_X_FONT_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UseXFontRequest {
+    pub context_tag: ContextTag,
+    pub font: xproto::Font,
+    pub first: u32,
+    pub count: u32,
+    pub list_base: u32,
+}
+impl UseXFontRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != USE_X_FONT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (font, remaining) = xproto::Font::try_parse_with_order(remaining, order)?;
+        let (first, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (list_base, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(UseXFontRequest {
+            context_tag,
+            font,
+            first,
+            count,
+            list_base,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `font` field of this structure.
+    pub fn font<I>(mut self, value: I) -> Self where I: Into<xproto::Font> {
+        self.font = value.into();
+        self
+    }
+    /// Set the `first` field of this structure.
+    pub fn first(mut self, value: u32) -> Self {
+        self.first = value;
+        self
+    }
+    /// Set the `count` field of this structure.
+    pub fn count(mut self, value: u32) -> Self {
+        self.count = value;
+        self
+    }
+    /// Set the `list_base` field of this structure.
+    pub fn list_base(mut self, value: u32) -> Self {
+        self.list_base = value;
+        self
+    }
+}
+
 pub fn use_x_font<Conn>(conn: &Conn, context_tag: ContextTag, font: xproto::Font, first: u32, count: u32, list_base: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let font_bytes = font.serialize();
-    let first_bytes = first.serialize();
-    let count_bytes = count.serialize();
-    let list_base_bytes = list_base.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let font_bytes = font.serialize_with_order(order);
+    let first_bytes = first.serialize_with_order(order);
+    let count_bytes = count.serialize_with_order(order);
+    let list_base_bytes = list_base.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         USE_X_FONT_REQUEST,
@@ -1047,23 +1852,85 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for UseXFontRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UseXFontRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        use_x_font(conn, self.context_tag, self.font, self.first, self.count, self.list_base)
+    }
+}
 
 /// Opcode for the CreateGLXPixmap request
 pub const CREATE_GLX_PIXMAP_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateGLXPixmapRequest {
+    pub screen: u32,
+    pub visual: xproto::Visualid,
+    pub pixmap: xproto::Pixmap,
+    pub glx_pixmap: Pixmap,
+}
+impl CreateGLXPixmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_GLX_PIXMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (glx_pixmap, remaining) = Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateGLXPixmapRequest {
+            screen,
+            visual,
+            pixmap,
+            glx_pixmap,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `visual` field of this structure.
+    pub fn visual<I>(mut self, value: I) -> Self where I: Into<xproto::Visualid> {
+        self.visual = value.into();
+        self
+    }
+    /// Set the `pixmap` field of this structure.
+    pub fn pixmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.pixmap = value.into();
+        self
+    }
+    /// Set the `glx_pixmap` field of this structure.
+    pub fn glx_pixmap<I>(mut self, value: I) -> Self where I: Into<Pixmap> {
+        self.glx_pixmap = value.into();
+        self
+    }
+}
+
 pub fn create_glx_pixmap<Conn>(conn: &Conn, screen: u32, visual: xproto::Visualid, pixmap: xproto::Pixmap, glx_pixmap: Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let visual_bytes = visual.serialize();
-    let pixmap_bytes = pixmap.serialize();
-    let glx_pixmap_bytes = glx_pixmap.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let visual_bytes = visual.serialize_with_order(order);
+    let pixmap_bytes = pixmap.serialize_with_order(order);
+    let glx_pixmap_bytes = glx_pixmap.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_GLX_PIXMAP_REQUEST,
@@ -1089,20 +1956,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateGLXPixmapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateGLXPixmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_glx_pixmap(conn, self.screen, self.visual, self.pixmap, self.glx_pixmap)
+    }
+}
 
 /// Opcode for the GetVisualConfigs request
 pub const GET_VISUAL_CONFIGS_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetVisualConfigsRequest {
+    pub screen: u32,
+}
+impl GetVisualConfigsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_VISUAL_CONFIGS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetVisualConfigsRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_visual_configs<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, GetVisualConfigsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_VISUAL_CONFIGS_REQUEST,
@@ -1116,9 +2021,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetVisualConfigsRequest {
+    type Reply = GetVisualConfigsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetVisualConfigsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetVisualConfigsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_visual_configs(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetVisualConfigsReply {
@@ -1129,15 +2046,15 @@ pub struct GetVisualConfigsReply {
     pub property_list: Vec<u32>,
 }
 impl TryParse for GetVisualConfigsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_visuals, remaining) = u32::try_parse(remaining)?;
-        let (num_properties, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_visuals, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_properties, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (property_list, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (property_list, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetVisualConfigsReply { response_type, sequence, num_visuals, num_properties, property_list };
         Ok((result, remaining))
     }
@@ -1148,17 +2065,70 @@ impl TryFrom<&[u8]> for GetVisualConfigsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetVisualConfigsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.property_list.len()).expect("`property_list` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.num_visuals.serialize_into_with_order(bytes, order);
+        self.num_properties.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.property_list.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DestroyGLXPixmap request
 pub const DESTROY_GLX_PIXMAP_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyGLXPixmapRequest {
+    pub glx_pixmap: Pixmap,
+}
+impl DestroyGLXPixmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_GLX_PIXMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (glx_pixmap, remaining) = Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyGLXPixmapRequest {
+            glx_pixmap,
+        })
+    }
+    /// Set the `glx_pixmap` field of this structure.
+    pub fn glx_pixmap<I>(mut self, value: I) -> Self where I: Into<Pixmap> {
+        self.glx_pixmap = value.into();
+        self
+    }
+}
+
 pub fn destroy_glx_pixmap<Conn>(conn: &Conn, glx_pixmap: Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let glx_pixmap_bytes = glx_pixmap.serialize();
+    let glx_pixmap_bytes = glx_pixmap.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_GLX_PIXMAP_REQUEST,
@@ -1172,21 +2142,76 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyGLXPixmapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyGLXPixmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_glx_pixmap(conn, self.glx_pixmap)
+    }
+}
 
 /// Opcode for the VendorPrivate request
 pub const VENDOR_PRIVATE_REQUEST: u8 = 16;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VendorPrivateRequest {
+    pub vendor_code: u32,
+    pub context_tag: ContextTag,
+    pub data: Vec<u8>,
+}
+impl VendorPrivateRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != VENDOR_PRIVATE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (vendor_code, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let data = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        Ok(VendorPrivateRequest {
+            vendor_code,
+            context_tag,
+            data,
+        })
+    }
+    /// Set the `vendor_code` field of this structure.
+    pub fn vendor_code(mut self, value: u32) -> Self {
+        self.vendor_code = value;
+        self
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `data` field of this structure.
+    pub fn data<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.data = value.into();
+        self
+    }
+}
+
 pub fn vendor_private<'c, Conn>(conn: &'c Conn, vendor_code: u32, context_tag: ContextTag, data: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let vendor_code_bytes = vendor_code.serialize();
-    let context_tag_bytes = context_tag.serialize();
+    let vendor_code_bytes = vendor_code.serialize_with_order(order);
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         VENDOR_PRIVATE_REQUEST,
@@ -1207,21 +2232,76 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(data), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for VendorPrivateRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for VendorPrivateRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        vendor_private(conn, self.vendor_code, self.context_tag, &self.data)
+    }
+}
 
 /// Opcode for the VendorPrivateWithReply request
 pub const VENDOR_PRIVATE_WITH_REPLY_REQUEST: u8 = 17;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VendorPrivateWithReplyRequest {
+    pub vendor_code: u32,
+    pub context_tag: ContextTag,
+    pub data: Vec<u8>,
+}
+impl VendorPrivateWithReplyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != VENDOR_PRIVATE_WITH_REPLY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (vendor_code, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let data = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        Ok(VendorPrivateWithReplyRequest {
+            vendor_code,
+            context_tag,
+            data,
+        })
+    }
+    /// Set the `vendor_code` field of this structure.
+    pub fn vendor_code(mut self, value: u32) -> Self {
+        self.vendor_code = value;
+        self
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `data` field of this structure.
+    pub fn data<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.data = value.into();
+        self
+    }
+}
+
 pub fn vendor_private_with_reply<'c, Conn>(conn: &'c Conn, vendor_code: u32, context_tag: ContextTag, data: &[u8]) -> Result<Cookie<'c, Conn, VendorPrivateWithReplyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let vendor_code_bytes = vendor_code.serialize();
-    let context_tag_bytes = context_tag.serialize();
+    let vendor_code_bytes = vendor_code.serialize_with_order(order);
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         VENDOR_PRIVATE_WITH_REPLY_REQUEST,
@@ -1242,9 +2322,21 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(data), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for VendorPrivateWithReplyRequest {
+    type Reply = VendorPrivateWithReplyReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for VendorPrivateWithReplyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, VendorPrivateWithReplyReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        vendor_private_with_reply(conn, self.vendor_code, self.context_tag, &self.data)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VendorPrivateWithReplyReply {
@@ -1255,12 +2347,12 @@ pub struct VendorPrivateWithReplyReply {
     pub data2: Vec<u8>,
 }
 impl TryParse for VendorPrivateWithReplyReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (retval, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (retval, remaining) = u32::try_parse_with_order(remaining, order)?;
         let (data1, remaining) = crate::x11_utils::parse_u8_list(remaining, 24)?;
         let data1 = <[u8; 24]>::try_from(data1).unwrap();
         let (data2, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
@@ -1275,17 +2367,70 @@ impl TryFrom<&[u8]> for VendorPrivateWithReplyReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for VendorPrivateWithReplyReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data2.len() % 4, 0, "`data2` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data2.len() / 4).expect("`data2` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.retval.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.data1);
+        bytes.extend_from_slice(&self.data2);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryExtensionsString request
 pub const QUERY_EXTENSIONS_STRING_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryExtensionsStringRequest {
+    pub screen: u32,
+}
+impl QueryExtensionsStringRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_EXTENSIONS_STRING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryExtensionsStringRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn query_extensions_string<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, QueryExtensionsStringReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_EXTENSIONS_STRING_REQUEST,
@@ -1299,9 +2444,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryExtensionsStringRequest {
+    type Reply = QueryExtensionsStringReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryExtensionsStringRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryExtensionsStringReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_extensions_string(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryExtensionsStringReply {
@@ -1311,13 +2468,13 @@ pub struct QueryExtensionsStringReply {
     pub n: u32,
 }
 impl TryParse for QueryExtensionsStringReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = QueryExtensionsStringReply { response_type, sequence, length, n };
         Ok((result, remaining))
@@ -1329,18 +2486,77 @@ impl TryFrom<&[u8]> for QueryExtensionsStringReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryExtensionsStringReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.n.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryServerString request
 pub const QUERY_SERVER_STRING_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryServerStringRequest {
+    pub screen: u32,
+    pub name: u32,
+}
+impl QueryServerStringRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_SERVER_STRING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryServerStringRequest {
+            screen,
+            name,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `name` field of this structure.
+    pub fn name(mut self, value: u32) -> Self {
+        self.name = value;
+        self
+    }
+}
+
 pub fn query_server_string<Conn>(conn: &Conn, screen: u32, name: u32) -> Result<Cookie<'_, Conn, QueryServerStringReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let name_bytes = name.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let name_bytes = name.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_SERVER_STRING_REQUEST,
@@ -1358,9 +2574,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryServerStringRequest {
+    type Reply = QueryServerStringReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryServerStringRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryServerStringReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_server_string(conn, self.screen, self.name)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryServerStringReply {
@@ -1370,13 +2598,13 @@ pub struct QueryServerStringReply {
     pub string: Vec<u8>,
 }
 impl TryParse for QueryServerStringReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (str_len, remaining) = u32::try_parse(remaining)?;
+        let (str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, str_len.try_into().or(Err(ParseError::ParseError))?)?;
         let string = string.to_vec();
@@ -1390,20 +2618,91 @@ impl TryFrom<&[u8]> for QueryServerStringReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryServerStringReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let str_len = u32::try_from(self.string.len()).expect("`string` has too many elements");
+        str_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        bytes.extend_from_slice(&self.string);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ClientInfo request
 pub const CLIENT_INFO_REQUEST: u8 = 20;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ClientInfoRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub string: Vec<u8>,
+}
+impl ClientInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CLIENT_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, str_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let string = string.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(ClientInfoRequest {
+            major_version,
+            minor_version,
+            string,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u32) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u32) -> Self {
+        self.minor_version = value;
+        self
+    }
+    /// Set the `string` field of this structure.
+    pub fn string<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.string = value.into();
+        self
+    }
+}
+
 pub fn client_info<'c, Conn>(conn: &'c Conn, major_version: u32, minor_version: u32, string: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     let str_len = u32::try_from(string.len()).expect("`string` has too many elements");
-    let str_len_bytes = str_len.serialize();
+    let str_len_bytes = str_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CLIENT_INFO_REQUEST,
@@ -1428,20 +2727,58 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(string), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ClientInfoRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ClientInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        client_info(conn, self.major_version, self.minor_version, &self.string)
+    }
+}
 
 /// Opcode for the GetFBConfigs request
 pub const GET_FB_CONFIGS_REQUEST: u8 = 21;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetFBConfigsRequest {
+    pub screen: u32,
+}
+impl GetFBConfigsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_FB_CONFIGS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetFBConfigsRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_fb_configs<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, GetFBConfigsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_FB_CONFIGS_REQUEST,
@@ -1455,9 +2792,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetFBConfigsRequest {
+    type Reply = GetFBConfigsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetFBConfigsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetFBConfigsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_fb_configs(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetFBConfigsReply {
@@ -1468,15 +2817,15 @@ pub struct GetFBConfigsReply {
     pub property_list: Vec<u32>,
 }
 impl TryParse for GetFBConfigsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_fb_configs, remaining) = u32::try_parse(remaining)?;
-        let (num_properties, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_fb_configs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_properties, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (property_list, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (property_list, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetFBConfigsReply { response_type, sequence, num_fb_configs, num_properties, property_list };
         Ok((result, remaining))
     }
@@ -1487,23 +2836,109 @@ impl TryFrom<&[u8]> for GetFBConfigsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetFBConfigsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.property_list.len()).expect("`property_list` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.num_fb_configs.serialize_into_with_order(bytes, order);
+        self.num_properties.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.property_list.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+/// Opcode for the CreatePixmap request
+pub const CREATE_PIXMAP_REQUEST: u8 = 22;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreatePixmapRequest {
+    pub screen: u32,
+    pub fbconfig: Fbconfig,
+    pub pixmap: xproto::Pixmap,
+    pub glx_pixmap: Pixmap,
+    pub attribs: Vec<u32>,
+}
+impl CreatePixmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_PIXMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (glx_pixmap, remaining) = Pixmap::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreatePixmapRequest {
+            screen,
+            fbconfig,
+            pixmap,
+            glx_pixmap,
+            attribs,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `fbconfig` field of this structure.
+    pub fn fbconfig<I>(mut self, value: I) -> Self where I: Into<Fbconfig> {
+        self.fbconfig = value.into();
+        self
+    }
+    /// Set the `pixmap` field of this structure.
+    pub fn pixmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.pixmap = value.into();
+        self
+    }
+    /// Set the `glx_pixmap` field of this structure.
+    pub fn glx_pixmap<I>(mut self, value: I) -> Self where I: Into<Pixmap> {
+        self.glx_pixmap = value.into();
+        self
+    }
+    /// Set the `attribs` field of this structure.
+    pub fn attribs<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.attribs = value.into();
+        self
+    }
+}
 
-/// Opcode for the CreatePixmap request
-pub const CREATE_PIXMAP_REQUEST: u8 = 22;
 pub fn create_pixmap<'c, Conn>(conn: &'c Conn, screen: u32, fbconfig: Fbconfig, pixmap: xproto::Pixmap, glx_pixmap: Pixmap, attribs: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let fbconfig_bytes = fbconfig.serialize();
-    let pixmap_bytes = pixmap.serialize();
-    let glx_pixmap_bytes = glx_pixmap.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let fbconfig_bytes = fbconfig.serialize_with_order(order);
+    let pixmap_bytes = pixmap.serialize_with_order(order);
+    let glx_pixmap_bytes = glx_pixmap.serialize_with_order(order);
     assert_eq!(attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
     let num_attribs = u32::try_from(attribs.len() / 2).expect("`attribs` has too many elements");
-    let num_attribs_bytes = num_attribs.serialize();
+    let num_attribs_bytes = num_attribs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_PIXMAP_REQUEST,
@@ -1531,26 +2966,64 @@ where
         num_attribs_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let attribs_bytes = attribs.serialize();
+    let attribs_bytes = attribs.serialize_with_order(order);
     let length_so_far = length_so_far + attribs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&attribs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreatePixmapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreatePixmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_pixmap(conn, self.screen, self.fbconfig, self.pixmap, self.glx_pixmap, &self.attribs)
+    }
+}
 
 /// Opcode for the DestroyPixmap request
 pub const DESTROY_PIXMAP_REQUEST: u8 = 23;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyPixmapRequest {
+    pub glx_pixmap: Pixmap,
+}
+impl DestroyPixmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_PIXMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (glx_pixmap, remaining) = Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyPixmapRequest {
+            glx_pixmap,
+        })
+    }
+    /// Set the `glx_pixmap` field of this structure.
+    pub fn glx_pixmap<I>(mut self, value: I) -> Self where I: Into<Pixmap> {
+        self.glx_pixmap = value.into();
+        self
+    }
+}
+
 pub fn destroy_pixmap<Conn>(conn: &Conn, glx_pixmap: Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let glx_pixmap_bytes = glx_pixmap.serialize();
+    let glx_pixmap_bytes = glx_pixmap.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_PIXMAP_REQUEST,
@@ -1564,25 +3037,104 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyPixmapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyPixmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_pixmap(conn, self.glx_pixmap)
+    }
+}
 
 /// Opcode for the CreateNewContext request
 pub const CREATE_NEW_CONTEXT_REQUEST: u8 = 24;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateNewContextRequest {
+    pub context: Context,
+    pub fbconfig: Fbconfig,
+    pub screen: u32,
+    pub render_type: u32,
+    pub share_list: Context,
+    pub is_direct: bool,
+}
+impl CreateNewContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_NEW_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (render_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (share_list, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (is_direct, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateNewContextRequest {
+            context,
+            fbconfig,
+            screen,
+            render_type,
+            share_list,
+            is_direct,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `fbconfig` field of this structure.
+    pub fn fbconfig<I>(mut self, value: I) -> Self where I: Into<Fbconfig> {
+        self.fbconfig = value.into();
+        self
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `render_type` field of this structure.
+    pub fn render_type(mut self, value: u32) -> Self {
+        self.render_type = value;
+        self
+    }
+    /// Set the `share_list` field of this structure.
+    pub fn share_list<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.share_list = value.into();
+        self
+    }
+    /// Set the `is_direct` field of this structure.
+    pub fn is_direct(mut self, value: bool) -> Self {
+        self.is_direct = value;
+        self
+    }
+}
+
 pub fn create_new_context<Conn>(conn: &Conn, context: Context, fbconfig: Fbconfig, screen: u32, render_type: u32, share_list: Context, is_direct: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let fbconfig_bytes = fbconfig.serialize();
-    let screen_bytes = screen.serialize();
-    let render_type_bytes = render_type.serialize();
-    let share_list_bytes = share_list.serialize();
-    let is_direct_bytes = is_direct.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let fbconfig_bytes = fbconfig.serialize_with_order(order);
+    let screen_bytes = screen.serialize_with_order(order);
+    let render_type_bytes = render_type.serialize_with_order(order);
+    let share_list_bytes = share_list.serialize_with_order(order);
+    let is_direct_bytes = is_direct.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_NEW_CONTEXT_REQUEST,
@@ -1616,20 +3168,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateNewContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateNewContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_new_context(conn, self.context, self.fbconfig, self.screen, self.render_type, self.share_list, self.is_direct)
+    }
+}
 
 /// Opcode for the QueryContext request
 pub const QUERY_CONTEXT_REQUEST: u8 = 25;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryContextRequest {
+    pub context: Context,
+}
+impl QueryContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryContextRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn query_context<Conn>(conn: &Conn, context: Context) -> Result<Cookie<'_, Conn, QueryContextReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_CONTEXT_REQUEST,
@@ -1643,9 +3233,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryContextRequest {
+    type Reply = QueryContextReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryContextReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_context(conn, self.context)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryContextReply {
@@ -1655,14 +3257,14 @@ pub struct QueryContextReply {
     pub attribs: Vec<u32>,
 }
 impl TryParse for QueryContextReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_attribs, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryContextReply { response_type, sequence, length, attribs };
         Ok((result, remaining))
     }
@@ -1673,20 +3275,101 @@ impl TryFrom<&[u8]> for QueryContextReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        num_attribs.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.attribs.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the MakeContextCurrent request
 pub const MAKE_CONTEXT_CURRENT_REQUEST: u8 = 26;
-pub fn make_context_current<Conn>(conn: &Conn, old_context_tag: ContextTag, drawable: Drawable, read_drawable: Drawable, context: Context) -> Result<Cookie<'_, Conn, MakeContextCurrentReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MakeContextCurrentRequest {
+    pub old_context_tag: ContextTag,
+    pub drawable: Drawable,
+    pub read_drawable: Drawable,
+    pub context: Context,
+}
+impl MakeContextCurrentRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != MAKE_CONTEXT_CURRENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (old_context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (read_drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(MakeContextCurrentRequest {
+            old_context_tag,
+            drawable,
+            read_drawable,
+            context,
+        })
+    }
+    /// Set the `old_context_tag` field of this structure.
+    pub fn old_context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.old_context_tag = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `read_drawable` field of this structure.
+    pub fn read_drawable<I>(mut self, value: I) -> Self where I: Into<Drawable> {
+        self.read_drawable = value.into();
+        self
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+}
+
+pub fn make_context_current<Conn, A, B>(conn: &Conn, old_context_tag: ContextTag, drawable: A, read_drawable: B, context: Context) -> Result<Cookie<'_, Conn, MakeContextCurrentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<Drawable>,
+    B: Into<Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: Drawable = drawable.into();
+    let read_drawable: Drawable = read_drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let old_context_tag_bytes = old_context_tag.serialize();
-    let drawable_bytes = drawable.serialize();
-    let read_drawable_bytes = read_drawable.serialize();
-    let context_bytes = context.serialize();
+    let old_context_tag_bytes = old_context_tag.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let read_drawable_bytes = read_drawable.serialize_with_order(order);
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         MAKE_CONTEXT_CURRENT_REQUEST,
@@ -1712,9 +3395,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for MakeContextCurrentRequest {
+    type Reply = MakeContextCurrentReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for MakeContextCurrentRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, MakeContextCurrentReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        make_context_current(conn, self.old_context_tag, self.drawable, self.read_drawable, self.context)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MakeContextCurrentReply {
@@ -1724,12 +3419,12 @@ pub struct MakeContextCurrentReply {
     pub context_tag: ContextTag,
 }
 impl TryParse for MakeContextCurrentReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_tag, remaining) = ContextTag::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = MakeContextCurrentReply { response_type, sequence, length, context_tag };
         Ok((result, remaining))
@@ -1741,22 +3436,97 @@ impl TryFrom<&[u8]> for MakeContextCurrentReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for MakeContextCurrentReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.context_tag.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreatePbuffer request
 pub const CREATE_PBUFFER_REQUEST: u8 = 27;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreatePbufferRequest {
+    pub screen: u32,
+    pub fbconfig: Fbconfig,
+    pub pbuffer: Pbuffer,
+    pub attribs: Vec<u32>,
+}
+impl CreatePbufferRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_PBUFFER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (pbuffer, remaining) = Pbuffer::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreatePbufferRequest {
+            screen,
+            fbconfig,
+            pbuffer,
+            attribs,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `fbconfig` field of this structure.
+    pub fn fbconfig<I>(mut self, value: I) -> Self where I: Into<Fbconfig> {
+        self.fbconfig = value.into();
+        self
+    }
+    /// Set the `pbuffer` field of this structure.
+    pub fn pbuffer<I>(mut self, value: I) -> Self where I: Into<Pbuffer> {
+        self.pbuffer = value.into();
+        self
+    }
+    /// Set the `attribs` field of this structure.
+    pub fn attribs<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.attribs = value.into();
+        self
+    }
+}
+
 pub fn create_pbuffer<'c, Conn>(conn: &'c Conn, screen: u32, fbconfig: Fbconfig, pbuffer: Pbuffer, attribs: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let fbconfig_bytes = fbconfig.serialize();
-    let pbuffer_bytes = pbuffer.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let fbconfig_bytes = fbconfig.serialize_with_order(order);
+    let pbuffer_bytes = pbuffer.serialize_with_order(order);
     assert_eq!(attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
     let num_attribs = u32::try_from(attribs.len() / 2).expect("`attribs` has too many elements");
-    let num_attribs_bytes = num_attribs.serialize();
+    let num_attribs_bytes = num_attribs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_PBUFFER_REQUEST,
@@ -1780,26 +3550,64 @@ where
         num_attribs_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let attribs_bytes = attribs.serialize();
+    let attribs_bytes = attribs.serialize_with_order(order);
     let length_so_far = length_so_far + attribs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&attribs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreatePbufferRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreatePbufferRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_pbuffer(conn, self.screen, self.fbconfig, self.pbuffer, &self.attribs)
+    }
+}
 
 /// Opcode for the DestroyPbuffer request
 pub const DESTROY_PBUFFER_REQUEST: u8 = 28;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyPbufferRequest {
+    pub pbuffer: Pbuffer,
+}
+impl DestroyPbufferRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_PBUFFER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (pbuffer, remaining) = Pbuffer::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyPbufferRequest {
+            pbuffer,
+        })
+    }
+    /// Set the `pbuffer` field of this structure.
+    pub fn pbuffer<I>(mut self, value: I) -> Self where I: Into<Pbuffer> {
+        self.pbuffer = value.into();
+        self
+    }
+}
+
 pub fn destroy_pbuffer<Conn>(conn: &Conn, pbuffer: Pbuffer) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let pbuffer_bytes = pbuffer.serialize();
+    let pbuffer_bytes = pbuffer.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_PBUFFER_REQUEST,
@@ -1813,20 +3621,60 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyPbufferRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyPbufferRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_pbuffer(conn, self.pbuffer)
+    }
+}
 
 /// Opcode for the GetDrawableAttributes request
 pub const GET_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 29;
-pub fn get_drawable_attributes<Conn>(conn: &Conn, drawable: Drawable) -> Result<Cookie<'_, Conn, GetDrawableAttributesReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDrawableAttributesRequest {
+    pub drawable: Drawable,
+}
+impl GetDrawableAttributesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DRAWABLE_ATTRIBUTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDrawableAttributesRequest {
+            drawable,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<Drawable> {
+        self.drawable = value.into();
+        self
+    }
+}
+
+pub fn get_drawable_attributes<Conn, A>(conn: &Conn, drawable: A) -> Result<Cookie<'_, Conn, GetDrawableAttributesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DRAWABLE_ATTRIBUTES_REQUEST,
@@ -1840,9 +3688,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDrawableAttributesRequest {
+    type Reply = GetDrawableAttributesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDrawableAttributesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDrawableAttributesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_drawable_attributes(conn, self.drawable)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDrawableAttributesReply {
@@ -1852,14 +3712,14 @@ pub struct GetDrawableAttributesReply {
     pub attribs: Vec<u32>,
 }
 impl TryParse for GetDrawableAttributesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_attribs, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetDrawableAttributesReply { response_type, sequence, length, attribs };
         Ok((result, remaining))
     }
@@ -1870,20 +3730,84 @@ impl TryFrom<&[u8]> for GetDrawableAttributesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDrawableAttributesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        num_attribs.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.attribs.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ChangeDrawableAttributes request
 pub const CHANGE_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 30;
-pub fn change_drawable_attributes<'c, Conn>(conn: &'c Conn, drawable: Drawable, attribs: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChangeDrawableAttributesRequest {
+    pub drawable: Drawable,
+    pub attribs: Vec<u32>,
+}
+impl ChangeDrawableAttributesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_DRAWABLE_ATTRIBUTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangeDrawableAttributesRequest {
+            drawable,
+            attribs,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `attribs` field of this structure.
+    pub fn attribs<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.attribs = value.into();
+        self
+    }
+}
+
+pub fn change_drawable_attributes<'c, Conn, A>(conn: &'c Conn, drawable: A, attribs: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
     assert_eq!(attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
     let num_attribs = u32::try_from(attribs.len() / 2).expect("`attribs` has too many elements");
-    let num_attribs_bytes = num_attribs.serialize();
+    let num_attribs_bytes = num_attribs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_DRAWABLE_ATTRIBUTES_REQUEST,
@@ -1899,32 +3823,103 @@ where
         num_attribs_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let attribs_bytes = attribs.serialize();
+    let attribs_bytes = attribs.serialize_with_order(order);
     let length_so_far = length_so_far + attribs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&attribs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeDrawableAttributesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeDrawableAttributesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_drawable_attributes(conn, self.drawable, &self.attribs)
+    }
+}
 
 /// Opcode for the CreateWindow request
 pub const CREATE_WINDOW_REQUEST: u8 = 31;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreateWindowRequest {
+    pub screen: u32,
+    pub fbconfig: Fbconfig,
+    pub window: xproto::Window,
+    pub glx_window: Window,
+    pub attribs: Vec<u32>,
+}
+impl CreateWindowRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_WINDOW_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (glx_window, remaining) = Window::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateWindowRequest {
+            screen,
+            fbconfig,
+            window,
+            glx_window,
+            attribs,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `fbconfig` field of this structure.
+    pub fn fbconfig<I>(mut self, value: I) -> Self where I: Into<Fbconfig> {
+        self.fbconfig = value.into();
+        self
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `glx_window` field of this structure.
+    pub fn glx_window<I>(mut self, value: I) -> Self where I: Into<Window> {
+        self.glx_window = value.into();
+        self
+    }
+    /// Set the `attribs` field of this structure.
+    pub fn attribs<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.attribs = value.into();
+        self
+    }
+}
+
 pub fn create_window<'c, Conn>(conn: &'c Conn, screen: u32, fbconfig: Fbconfig, window: xproto::Window, glx_window: Window, attribs: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let fbconfig_bytes = fbconfig.serialize();
-    let window_bytes = window.serialize();
-    let glx_window_bytes = glx_window.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let fbconfig_bytes = fbconfig.serialize_with_order(order);
+    let window_bytes = window.serialize_with_order(order);
+    let glx_window_bytes = glx_window.serialize_with_order(order);
     assert_eq!(attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
     let num_attribs = u32::try_from(attribs.len() / 2).expect("`attribs` has too many elements");
-    let num_attribs_bytes = num_attribs.serialize();
+    let num_attribs_bytes = num_attribs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_WINDOW_REQUEST,
@@ -1952,26 +3947,64 @@ where
         num_attribs_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let attribs_bytes = attribs.serialize();
+    let attribs_bytes = attribs.serialize_with_order(order);
     let length_so_far = length_so_far + attribs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&attribs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateWindowRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateWindowRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_window(conn, self.screen, self.fbconfig, self.window, self.glx_window, &self.attribs)
+    }
+}
 
 /// Opcode for the DeleteWindow request
 pub const DELETE_WINDOW_REQUEST: u8 = 32;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeleteWindowRequest {
+    pub glxwindow: Window,
+}
+impl DeleteWindowRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_WINDOW_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (glxwindow, remaining) = Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeleteWindowRequest {
+            glxwindow,
+        })
+    }
+    /// Set the `glxwindow` field of this structure.
+    pub fn glxwindow<I>(mut self, value: I) -> Self where I: Into<Window> {
+        self.glxwindow = value.into();
+        self
+    }
+}
+
 pub fn delete_window<Conn>(conn: &Conn, glxwindow: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let glxwindow_bytes = glxwindow.serialize();
+    let glxwindow_bytes = glxwindow.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_WINDOW_REQUEST,
@@ -1985,28 +4018,103 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteWindowRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteWindowRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_window(conn, self.glxwindow)
+    }
+}
 
 /// Opcode for the SetClientInfoARB request
 pub const SET_CLIENT_INFO_ARB_REQUEST: u8 = 33;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetClientInfoARBRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub gl_versions: Vec<u32>,
+    pub gl_extension_string: Vec<u8>,
+    pub glx_extension_string: Vec<u8>,
+}
+impl SetClientInfoARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CLIENT_INFO_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_versions, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (gl_str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (glx_str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (gl_versions, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_versions.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (gl_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, gl_str_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let gl_extension_string = gl_extension_string.to_vec();
+        let (glx_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, glx_str_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let glx_extension_string = glx_extension_string.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(SetClientInfoARBRequest {
+            major_version,
+            minor_version,
+            gl_versions,
+            gl_extension_string,
+            glx_extension_string,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u32) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u32) -> Self {
+        self.minor_version = value;
+        self
+    }
+    /// Set the `gl_versions` field of this structure.
+    pub fn gl_versions<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.gl_versions = value.into();
+        self
+    }
+    /// Set the `gl_extension_string` field of this structure.
+    pub fn gl_extension_string<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.gl_extension_string = value.into();
+        self
+    }
+    /// Set the `glx_extension_string` field of this structure.
+    pub fn glx_extension_string<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.glx_extension_string = value.into();
+        self
+    }
+}
+
 pub fn set_client_info_arb<'c, Conn>(conn: &'c Conn, major_version: u32, minor_version: u32, gl_versions: &[u32], gl_extension_string: &[u8], glx_extension_string: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     assert_eq!(gl_versions.len() % 2, 0, "`gl_versions` has an incorrect length, must be a multiple of 2");
     let num_versions = u32::try_from(gl_versions.len() / 2).expect("`gl_versions` has too many elements");
-    let num_versions_bytes = num_versions.serialize();
+    let num_versions_bytes = num_versions.serialize_with_order(order);
     let gl_str_len = u32::try_from(gl_extension_string.len()).expect("`gl_extension_string` has too many elements");
-    let gl_str_len_bytes = gl_str_len.serialize();
+    let gl_str_len_bytes = gl_str_len.serialize_with_order(order);
     let glx_str_len = u32::try_from(glx_extension_string.len()).expect("`glx_extension_string` has too many elements");
-    let glx_str_len_bytes = glx_str_len.serialize();
+    let glx_str_len_bytes = glx_str_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_CLIENT_INFO_ARB_REQUEST,
@@ -2034,7 +4142,7 @@ where
         glx_str_len_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let gl_versions_bytes = gl_versions.serialize();
+    let gl_versions_bytes = gl_versions.serialize_with_order(order);
     let length_so_far = length_so_far + gl_versions_bytes.len();
     let length_so_far = length_so_far + gl_extension_string.len();
     let length_so_far = length_so_far + glx_extension_string.len();
@@ -2042,27 +4150,107 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&gl_versions_bytes), IoSlice::new(gl_extension_string), IoSlice::new(glx_extension_string), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetClientInfoARBRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetClientInfoARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_client_info_arb(conn, self.major_version, self.minor_version, &self.gl_versions, &self.gl_extension_string, &self.glx_extension_string)
+    }
+}
 
 /// Opcode for the CreateContextAttribsARB request
 pub const CREATE_CONTEXT_ATTRIBS_ARB_REQUEST: u8 = 34;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreateContextAttribsARBRequest {
+    pub context: Context,
+    pub fbconfig: Fbconfig,
+    pub screen: u32,
+    pub share_list: Context,
+    pub is_direct: bool,
+    pub attribs: Vec<u32>,
+}
+impl CreateContextAttribsARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONTEXT_ATTRIBS_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (share_list, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (is_direct, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateContextAttribsARBRequest {
+            context,
+            fbconfig,
+            screen,
+            share_list,
+            is_direct,
+            attribs,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `fbconfig` field of this structure.
+    pub fn fbconfig<I>(mut self, value: I) -> Self where I: Into<Fbconfig> {
+        self.fbconfig = value.into();
+        self
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `share_list` field of this structure.
+    pub fn share_list<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.share_list = value.into();
+        self
+    }
+    /// Set the `is_direct` field of this structure.
+    pub fn is_direct(mut self, value: bool) -> Self {
+        self.is_direct = value;
+        self
+    }
+    /// Set the `attribs` field of this structure.
+    pub fn attribs<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.attribs = value.into();
+        self
+    }
+}
+
 pub fn create_context_attribs_arb<'c, Conn>(conn: &'c Conn, context: Context, fbconfig: Fbconfig, screen: u32, share_list: Context, is_direct: bool, attribs: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let fbconfig_bytes = fbconfig.serialize();
-    let screen_bytes = screen.serialize();
-    let share_list_bytes = share_list.serialize();
-    let is_direct_bytes = is_direct.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let fbconfig_bytes = fbconfig.serialize_with_order(order);
+    let screen_bytes = screen.serialize_with_order(order);
+    let share_list_bytes = share_list.serialize_with_order(order);
+    let is_direct_bytes = is_direct.serialize_with_order(order);
     assert_eq!(attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
     let num_attribs = u32::try_from(attribs.len() / 2).expect("`attribs` has too many elements");
-    let num_attribs_bytes = num_attribs.serialize();
+    let num_attribs_bytes = num_attribs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_CONTEXT_ATTRIBS_ARB_REQUEST,
@@ -2094,34 +4282,109 @@ where
         num_attribs_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let attribs_bytes = attribs.serialize();
+    let attribs_bytes = attribs.serialize_with_order(order);
     let length_so_far = length_so_far + attribs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&attribs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateContextAttribsARBRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateContextAttribsARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_context_attribs_arb(conn, self.context, self.fbconfig, self.screen, self.share_list, self.is_direct, &self.attribs)
+    }
+}
 
 /// Opcode for the SetClientInfo2ARB request
 pub const SET_CLIENT_INFO2_ARB_REQUEST: u8 = 35;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetClientInfo2ARBRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub gl_versions: Vec<u32>,
+    pub gl_extension_string: Vec<u8>,
+    pub glx_extension_string: Vec<u8>,
+}
+impl SetClientInfo2ARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CLIENT_INFO2_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_versions, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (gl_str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (glx_str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (gl_versions, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_versions.checked_mul(3u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (gl_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, gl_str_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let gl_extension_string = gl_extension_string.to_vec();
+        let (glx_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, glx_str_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let glx_extension_string = glx_extension_string.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(SetClientInfo2ARBRequest {
+            major_version,
+            minor_version,
+            gl_versions,
+            gl_extension_string,
+            glx_extension_string,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u32) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u32) -> Self {
+        self.minor_version = value;
+        self
+    }
+    /// Set the `gl_versions` field of this structure.
+    pub fn gl_versions<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.gl_versions = value.into();
+        self
+    }
+    /// Set the `gl_extension_string` field of this structure.
+    pub fn gl_extension_string<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.gl_extension_string = value.into();
+        self
+    }
+    /// Set the `glx_extension_string` field of this structure.
+    pub fn glx_extension_string<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.glx_extension_string = value.into();
+        self
+    }
+}
+
 pub fn set_client_info2_arb<'c, Conn>(conn: &'c Conn, major_version: u32, minor_version: u32, gl_versions: &[u32], gl_extension_string: &[u8], glx_extension_string: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     assert_eq!(gl_versions.len() % 3, 0, "`gl_versions` has an incorrect length, must be a multiple of 3");
     let num_versions = u32::try_from(gl_versions.len() / 3).expect("`gl_versions` has too many elements");
-    let num_versions_bytes = num_versions.serialize();
+    let num_versions_bytes = num_versions.serialize_with_order(order);
     let gl_str_len = u32::try_from(gl_extension_string.len()).expect("`gl_extension_string` has too many elements");
-    let gl_str_len_bytes = gl_str_len.serialize();
+    let gl_str_len_bytes = gl_str_len.serialize_with_order(order);
     let glx_str_len = u32::try_from(glx_extension_string.len()).expect("`glx_extension_string` has too many elements");
-    let glx_str_len_bytes = glx_str_len.serialize();
+    let glx_str_len_bytes = glx_str_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_CLIENT_INFO2_ARB_REQUEST,
@@ -2149,7 +4412,7 @@ where
         glx_str_len_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let gl_versions_bytes = gl_versions.serialize();
+    let gl_versions_bytes = gl_versions.serialize_with_order(order);
     let length_so_far = length_so_far + gl_versions_bytes.len();
     let length_so_far = length_so_far + gl_extension_string.len();
     let length_so_far = length_so_far + glx_extension_string.len();
@@ -2157,22 +4420,76 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&gl_versions_bytes), IoSlice::new(gl_extension_string), IoSlice::new(glx_extension_string), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetClientInfo2ARBRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetClientInfo2ARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_client_info2_arb(conn, self.major_version, self.minor_version, &self.gl_versions, &self.gl_extension_string, &self.glx_extension_string)
+    }
+}
 
 /// Opcode for the NewList request
 pub const NEW_LIST_REQUEST: u8 = 101;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NewListRequest {
+    pub context_tag: ContextTag,
+    pub list: u32,
+    pub mode: u32,
+}
+impl NewListRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != NEW_LIST_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (list, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(NewListRequest {
+            context_tag,
+            list,
+            mode,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `list` field of this structure.
+    pub fn list(mut self, value: u32) -> Self {
+        self.list = value;
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode(mut self, value: u32) -> Self {
+        self.mode = value;
+        self
+    }
+}
+
 pub fn new_list<Conn>(conn: &Conn, context_tag: ContextTag, list: u32, mode: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let list_bytes = list.serialize();
-    let mode_bytes = mode.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let list_bytes = list.serialize_with_order(order);
+    let mode_bytes = mode.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         NEW_LIST_REQUEST,
@@ -2194,20 +4511,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for NewListRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for NewListRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        new_list(conn, self.context_tag, self.list, self.mode)
+    }
+}
 
 /// Opcode for the EndList request
 pub const END_LIST_REQUEST: u8 = 102;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EndListRequest {
+    pub context_tag: ContextTag,
+}
+impl EndListRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != END_LIST_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(EndListRequest {
+            context_tag,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+}
+
 pub fn end_list<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         END_LIST_REQUEST,
@@ -2221,22 +4576,76 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for EndListRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for EndListRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        end_list(conn, self.context_tag)
+    }
+}
 
 /// Opcode for the DeleteLists request
 pub const DELETE_LISTS_REQUEST: u8 = 103;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeleteListsRequest {
+    pub context_tag: ContextTag,
+    pub list: u32,
+    pub range: i32,
+}
+impl DeleteListsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_LISTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (list, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeleteListsRequest {
+            context_tag,
+            list,
+            range,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `list` field of this structure.
+    pub fn list(mut self, value: u32) -> Self {
+        self.list = value;
+        self
+    }
+    /// Set the `range` field of this structure.
+    pub fn range(mut self, value: i32) -> Self {
+        self.range = value;
+        self
+    }
+}
+
 pub fn delete_lists<Conn>(conn: &Conn, context_tag: ContextTag, list: u32, range: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let list_bytes = list.serialize();
-    let range_bytes = range.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let list_bytes = list.serialize_with_order(order);
+    let range_bytes = range.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_LISTS_REQUEST,
@@ -2258,21 +4667,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteListsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteListsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_lists(conn, self.context_tag, self.list, self.range)
+    }
+}
 
 /// Opcode for the GenLists request
 pub const GEN_LISTS_REQUEST: u8 = 104;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GenListsRequest {
+    pub context_tag: ContextTag,
+    pub range: i32,
+}
+impl GenListsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GEN_LISTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GenListsRequest {
+            context_tag,
+            range,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `range` field of this structure.
+    pub fn range(mut self, value: i32) -> Self {
+        self.range = value;
+        self
+    }
+}
+
 pub fn gen_lists<Conn>(conn: &Conn, context_tag: ContextTag, range: i32) -> Result<Cookie<'_, Conn, GenListsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let range_bytes = range.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let range_bytes = range.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GEN_LISTS_REQUEST,
@@ -2290,9 +4745,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GenListsRequest {
+    type Reply = GenListsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GenListsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GenListsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        gen_lists(conn, self.context_tag, self.range)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GenListsReply {
@@ -2302,12 +4769,12 @@ pub struct GenListsReply {
     pub ret_val: u32,
 }
 impl TryParse for GenListsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = GenListsReply { response_type, sequence, length, ret_val };
         Ok((result, remaining))
     }
@@ -2318,19 +4785,84 @@ impl TryFrom<&[u8]> for GenListsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GenListsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the FeedbackBuffer request
 pub const FEEDBACK_BUFFER_REQUEST: u8 = 105;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FeedbackBufferRequest {
+    pub context_tag: ContextTag,
+    pub size: i32,
+    pub type_: i32,
+}
+impl FeedbackBufferRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FEEDBACK_BUFFER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FeedbackBufferRequest {
+            context_tag,
+            size,
+            type_,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `size` field of this structure.
+    pub fn size(mut self, value: i32) -> Self {
+        self.size = value;
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_(mut self, value: i32) -> Self {
+        self.type_ = value;
+        self
+    }
+}
+
 pub fn feedback_buffer<Conn>(conn: &Conn, context_tag: ContextTag, size: i32, type_: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let size_bytes = size.serialize();
-    let type_bytes = type_.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let size_bytes = size.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FEEDBACK_BUFFER_REQUEST,
@@ -2352,21 +4884,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FeedbackBufferRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FeedbackBufferRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        feedback_buffer(conn, self.context_tag, self.size, self.type_)
+    }
+}
 
 /// Opcode for the SelectBuffer request
 pub const SELECT_BUFFER_REQUEST: u8 = 106;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectBufferRequest {
+    pub context_tag: ContextTag,
+    pub size: i32,
+}
+impl SelectBufferRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_BUFFER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SelectBufferRequest {
+            context_tag,
+            size,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `size` field of this structure.
+    pub fn size(mut self, value: i32) -> Self {
+        self.size = value;
+        self
+    }
+}
+
 pub fn select_buffer<Conn>(conn: &Conn, context_tag: ContextTag, size: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let size_bytes = size.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let size_bytes = size.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_BUFFER_REQUEST,
@@ -2384,21 +4962,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectBufferRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectBufferRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_buffer(conn, self.context_tag, self.size)
+    }
+}
 
 /// Opcode for the RenderMode request
 pub const RENDER_MODE_REQUEST: u8 = 107;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderModeRequest {
+    pub context_tag: ContextTag,
+    pub mode: u32,
+}
+impl RenderModeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RENDER_MODE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(RenderModeRequest {
+            context_tag,
+            mode,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode(mut self, value: u32) -> Self {
+        self.mode = value;
+        self
+    }
+}
+
 pub fn render_mode<Conn>(conn: &Conn, context_tag: ContextTag, mode: u32) -> Result<Cookie<'_, Conn, RenderModeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let mode_bytes = mode.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let mode_bytes = mode.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         RENDER_MODE_REQUEST,
@@ -2416,9 +5040,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for RenderModeRequest {
+    type Reply = RenderModeReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for RenderModeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, RenderModeReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        render_mode(conn, self.context_tag, self.mode)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RenderModeReply {
@@ -2430,16 +5066,16 @@ pub struct RenderModeReply {
     pub data: Vec<u32>,
 }
 impl TryParse for RenderModeReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = u32::try_parse(remaining)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (new_mode, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (new_mode, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = RenderModeReply { response_type, sequence, length, ret_val, new_mode, data };
         Ok((result, remaining))
     }
@@ -2450,67 +5086,96 @@ impl TryFrom<&[u8]> for RenderModeReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)]
-#[repr(u16)]
-pub enum RM {
-    GL_RENDER = 7168,
-    GL_FEEDBACK = 7169,
-    GL_SELECT = 7170,
-}
-impl From<RM> for u16 {
-    fn from(input: RM) -> Self {
-        match input {
-            RM::GL_RENDER => 7168,
-            RM::GL_FEEDBACK => 7169,
-            RM::GL_SELECT => 7170,
-        }
-    }
-}
+impl Serialize for RenderModeReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.new_mode.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RM(u16);
+#[allow(non_upper_case_globals)]
+impl RM {
+    pub const GL_RENDER: Self = Self(7168);
+    pub const GL_FEEDBACK: Self = Self(7169);
+    pub const GL_SELECT: Self = Self(7170);
+}
+enum_newtype!(RM, u16, [GL_RENDER, GL_FEEDBACK, GL_SELECT]);
 impl From<RM> for Option<u16> {
     fn from(input: RM) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<RM> for u32 {
     fn from(input: RM) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<RM> for Option<u32> {
     fn from(input: RM) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for RM {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            7168 => Ok(RM::GL_RENDER),
-            7169 => Ok(RM::GL_FEEDBACK),
-            7170 => Ok(RM::GL_SELECT),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the Finish request
+pub const FINISH_REQUEST: u8 = 108;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FinishRequest {
+    pub context_tag: ContextTag,
+}
+impl FinishRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FINISH_REQUEST {
+            return Err(ParseError::ParseError);
         }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FinishRequest {
+            context_tag,
+        })
     }
-}
-impl TryFrom<u32> for RM {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
     }
 }
 
-/// Opcode for the Finish request
-pub const FINISH_REQUEST: u8 = 108;
 pub fn finish<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<Cookie<'_, Conn, FinishReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FINISH_REQUEST,
@@ -2524,9 +5189,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FinishRequest {
+    type Reply = FinishReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FinishRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, FinishReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        finish(conn, self.context_tag)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FinishReply {
@@ -2535,11 +5212,11 @@ pub struct FinishReply {
     pub length: u32,
 }
 impl TryParse for FinishReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = FinishReply { response_type, sequence, length };
         Ok((result, remaining))
     }
@@ -2550,19 +5227,83 @@ impl TryFrom<&[u8]> for FinishReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for FinishReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PixelStoref request
 pub const PIXEL_STOREF_REQUEST: u8 = 109;
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PixelStorefRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+    pub datum: Float32,
+}
+impl PixelStorefRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXEL_STOREF_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PixelStorefRequest {
+            context_tag,
+            pname,
+            datum,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+    /// Set the `datum` field of this structure.
+    pub fn datum<I>(mut self, value: I) -> Self where I: Into<Float32> {
+        self.datum = value.into();
+        self
+    }
+}
+
 pub fn pixel_storef<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32, datum: Float32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let pname_bytes = pname.serialize();
-    let datum_bytes = datum.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
+    let datum_bytes = datum.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PIXEL_STOREF_REQUEST,
@@ -2584,22 +5325,76 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PixelStorefRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PixelStorefRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        pixel_storef(conn, self.context_tag, self.pname, self.datum)
+    }
+}
 
 /// Opcode for the PixelStorei request
 pub const PIXEL_STOREI_REQUEST: u8 = 110;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PixelStoreiRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+    pub datum: i32,
+}
+impl PixelStoreiRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXEL_STOREI_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PixelStoreiRequest {
+            context_tag,
+            pname,
+            datum,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+    /// Set the `datum` field of this structure.
+    pub fn datum(mut self, value: i32) -> Self {
+        self.datum = value;
+        self
+    }
+}
+
 pub fn pixel_storei<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32, datum: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let pname_bytes = pname.serialize();
-    let datum_bytes = datum.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
+    let datum_bytes = datum.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PIXEL_STOREI_REQUEST,
@@ -2621,28 +5416,130 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PixelStoreiRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PixelStoreiRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        pixel_storei(conn, self.context_tag, self.pname, self.datum)
+    }
+}
 
 /// Opcode for the ReadPixels request
 pub const READ_PIXELS_REQUEST: u8 = 111;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReadPixelsRequest {
+    pub context_tag: ContextTag,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+    pub lsb_first: bool,
+}
+impl ReadPixelsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != READ_PIXELS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (lsb_first, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ReadPixelsRequest {
+            context_tag,
+            x,
+            y,
+            width,
+            height,
+            format,
+            type_,
+            swap_bytes,
+            lsb_first,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `x` field of this structure.
+    pub fn x(mut self, value: i32) -> Self {
+        self.x = value;
+        self
+    }
+    /// Set the `y` field of this structure.
+    pub fn y(mut self, value: i32) -> Self {
+        self.y = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: i32) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: i32) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u32) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_(mut self, value: u32) -> Self {
+        self.type_ = value;
+        self
+    }
+    /// Set the `swap_bytes` field of this structure.
+    pub fn swap_bytes(mut self, value: bool) -> Self {
+        self.swap_bytes = value;
+        self
+    }
+    /// Set the `lsb_first` field of this structure.
+    pub fn lsb_first(mut self, value: bool) -> Self {
+        self.lsb_first = value;
+        self
+    }
+}
+
 pub fn read_pixels<Conn>(conn: &Conn, context_tag: ContextTag, x: i32, y: i32, width: i32, height: i32, format: u32, type_: u32, swap_bytes: bool, lsb_first: bool) -> Result<Cookie<'_, Conn, ReadPixelsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let x_bytes = x.serialize();
-    let y_bytes = y.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let format_bytes = format.serialize();
-    let type_bytes = type_.serialize();
-    let swap_bytes_bytes = swap_bytes.serialize();
-    let lsb_first_bytes = lsb_first.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let x_bytes = x.serialize_with_order(order);
+    let y_bytes = y.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let swap_bytes_bytes = swap_bytes.serialize_with_order(order);
+    let lsb_first_bytes = lsb_first.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         READ_PIXELS_REQUEST,
@@ -2684,9 +5581,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ReadPixelsRequest {
+    type Reply = ReadPixelsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ReadPixelsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ReadPixelsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        read_pixels(conn, self.context_tag, self.x, self.y, self.width, self.height, self.format, self.type_, self.swap_bytes, self.lsb_first)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReadPixelsReply {
@@ -2695,11 +5604,11 @@ pub struct ReadPixelsReply {
     pub data: Vec<u8>,
 }
 impl TryParse for ReadPixelsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -2713,18 +5622,78 @@ impl TryFrom<&[u8]> for ReadPixelsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ReadPixelsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetBooleanv request
 pub const GET_BOOLEANV_REQUEST: u8 = 112;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetBooleanvRequest {
+    pub context_tag: ContextTag,
+    pub pname: i32,
+}
+impl GetBooleanvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_BOOLEANV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetBooleanvRequest {
+            context_tag,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: i32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_booleanv<Conn>(conn: &Conn, context_tag: ContextTag, pname: i32) -> Result<Cookie<'_, Conn, GetBooleanvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_BOOLEANV_REQUEST,
@@ -2742,9 +5711,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetBooleanvRequest {
+    type Reply = GetBooleanvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetBooleanvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetBooleanvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_booleanv(conn, self.context_tag, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetBooleanvReply {
@@ -2755,16 +5736,16 @@ pub struct GetBooleanvReply {
     pub data: Vec<bool>,
 }
 impl TryParse for GetBooleanvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = bool::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(15..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<bool>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<bool>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetBooleanvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -2775,18 +5756,80 @@ impl TryFrom<&[u8]> for GetBooleanvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetBooleanvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 15]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetClipPlane request
 pub const GET_CLIP_PLANE_REQUEST: u8 = 113;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetClipPlaneRequest {
+    pub context_tag: ContextTag,
+    pub plane: i32,
+}
+impl GetClipPlaneRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CLIP_PLANE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (plane, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetClipPlaneRequest {
+            context_tag,
+            plane,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `plane` field of this structure.
+    pub fn plane(mut self, value: i32) -> Self {
+        self.plane = value;
+        self
+    }
+}
+
 pub fn get_clip_plane<Conn>(conn: &Conn, context_tag: ContextTag, plane: i32) -> Result<Cookie<'_, Conn, GetClipPlaneReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let plane_bytes = plane.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let plane_bytes = plane.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CLIP_PLANE_REQUEST,
@@ -2804,9 +5847,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetClipPlaneRequest {
+    type Reply = GetClipPlaneReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetClipPlaneRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetClipPlaneReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_clip_plane(conn, self.context_tag, self.plane)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetClipPlaneReply {
@@ -2815,13 +5870,13 @@ pub struct GetClipPlaneReply {
     pub data: Vec<Float64>,
 }
 impl TryParse for GetClipPlaneReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, length.checked_div(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float64>(remaining, length.checked_div(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetClipPlaneReply { response_type, sequence, data };
         Ok((result, remaining))
     }
@@ -2832,18 +5887,77 @@ impl TryFrom<&[u8]> for GetClipPlaneReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetClipPlaneReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.data.len()).ok().and_then(|len| len.checked_mul(2)).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetDoublev request
 pub const GET_DOUBLEV_REQUEST: u8 = 114;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDoublevRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+}
+impl GetDoublevRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DOUBLEV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDoublevRequest {
+            context_tag,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_doublev<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32) -> Result<Cookie<'_, Conn, GetDoublevReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DOUBLEV_REQUEST,
@@ -2861,9 +5975,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDoublevRequest {
+    type Reply = GetDoublevReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDoublevRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDoublevReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_doublev(conn, self.context_tag, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetDoublevReply {
@@ -2874,16 +6000,16 @@ pub struct GetDoublevReply {
     pub data: Vec<Float64>,
 }
 impl TryParse for GetDoublevReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float64::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float64::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float64>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetDoublevReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -2894,17 +6020,71 @@ impl TryFrom<&[u8]> for GetDoublevReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDoublevReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetError request
 pub const GET_ERROR_REQUEST: u8 = 115;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetErrorRequest {
+    pub context_tag: ContextTag,
+}
+impl GetErrorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_ERROR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetErrorRequest {
+            context_tag,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+}
+
 pub fn get_error<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<Cookie<'_, Conn, GetErrorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_ERROR_REQUEST,
@@ -2918,9 +6098,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetErrorRequest {
+    type Reply = GetErrorReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetErrorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetErrorReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_error(conn, self.context_tag)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetErrorReply {
@@ -2930,12 +6122,12 @@ pub struct GetErrorReply {
     pub error: i32,
 }
 impl TryParse for GetErrorReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (error, remaining) = i32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (error, remaining) = i32::try_parse_with_order(remaining, order)?;
         let result = GetErrorReply { response_type, sequence, length, error };
         Ok((result, remaining))
     }
@@ -2946,18 +6138,75 @@ impl TryFrom<&[u8]> for GetErrorReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetErrorReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.error.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetFloatv request
 pub const GET_FLOATV_REQUEST: u8 = 116;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetFloatvRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+}
+impl GetFloatvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_FLOATV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetFloatvRequest {
+            context_tag,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_floatv<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32) -> Result<Cookie<'_, Conn, GetFloatvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_FLOATV_REQUEST,
@@ -2975,9 +6224,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetFloatvRequest {
+    type Reply = GetFloatvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetFloatvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetFloatvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_floatv(conn, self.context_tag, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetFloatvReply {
@@ -2988,16 +6249,16 @@ pub struct GetFloatvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetFloatvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetFloatvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3008,18 +6269,80 @@ impl TryFrom<&[u8]> for GetFloatvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetFloatvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetIntegerv request
 pub const GET_INTEGERV_REQUEST: u8 = 117;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetIntegervRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+}
+impl GetIntegervRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_INTEGERV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetIntegervRequest {
+            context_tag,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_integerv<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32) -> Result<Cookie<'_, Conn, GetIntegervReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_INTEGERV_REQUEST,
@@ -3037,9 +6360,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetIntegervRequest {
+    type Reply = GetIntegervReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetIntegervRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetIntegervReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_integerv(conn, self.context_tag, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetIntegervReply {
@@ -3050,16 +6385,16 @@ pub struct GetIntegervReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetIntegervReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetIntegervReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3070,19 +6405,89 @@ impl TryFrom<&[u8]> for GetIntegervReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetIntegervReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetLightfv request
 pub const GET_LIGHTFV_REQUEST: u8 = 118;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetLightfvRequest {
+    pub context_tag: ContextTag,
+    pub light: u32,
+    pub pname: u32,
+}
+impl GetLightfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_LIGHTFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (light, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetLightfvRequest {
+            context_tag,
+            light,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `light` field of this structure.
+    pub fn light(mut self, value: u32) -> Self {
+        self.light = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_lightfv<Conn>(conn: &Conn, context_tag: ContextTag, light: u32, pname: u32) -> Result<Cookie<'_, Conn, GetLightfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let light_bytes = light.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let light_bytes = light.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_LIGHTFV_REQUEST,
@@ -3104,9 +6509,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetLightfvRequest {
+    type Reply = GetLightfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetLightfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetLightfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_lightfv(conn, self.context_tag, self.light, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetLightfvReply {
@@ -3117,16 +6534,16 @@ pub struct GetLightfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetLightfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetLightfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3137,19 +6554,89 @@ impl TryFrom<&[u8]> for GetLightfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetLightfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetLightiv request
 pub const GET_LIGHTIV_REQUEST: u8 = 119;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetLightivRequest {
+    pub context_tag: ContextTag,
+    pub light: u32,
+    pub pname: u32,
+}
+impl GetLightivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_LIGHTIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (light, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetLightivRequest {
+            context_tag,
+            light,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `light` field of this structure.
+    pub fn light(mut self, value: u32) -> Self {
+        self.light = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_lightiv<Conn>(conn: &Conn, context_tag: ContextTag, light: u32, pname: u32) -> Result<Cookie<'_, Conn, GetLightivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let light_bytes = light.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let light_bytes = light.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_LIGHTIV_REQUEST,
@@ -3171,9 +6658,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetLightivRequest {
+    type Reply = GetLightivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetLightivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetLightivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_lightiv(conn, self.context_tag, self.light, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetLightivReply {
@@ -3184,16 +6683,16 @@ pub struct GetLightivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetLightivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetLightivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3204,19 +6703,89 @@ impl TryFrom<&[u8]> for GetLightivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetLightivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetMapdv request
 pub const GET_MAPDV_REQUEST: u8 = 120;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMapdvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub query: u32,
+}
+impl GetMapdvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MAPDV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (query, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMapdvRequest {
+            context_tag,
+            target,
+            query,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `query` field of this structure.
+    pub fn query(mut self, value: u32) -> Self {
+        self.query = value;
+        self
+    }
+}
+
 pub fn get_mapdv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, query: u32) -> Result<Cookie<'_, Conn, GetMapdvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let query_bytes = query.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let query_bytes = query.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MAPDV_REQUEST,
@@ -3238,9 +6807,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMapdvRequest {
+    type Reply = GetMapdvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMapdvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMapdvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_mapdv(conn, self.context_tag, self.target, self.query)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetMapdvReply {
@@ -3251,16 +6832,16 @@ pub struct GetMapdvReply {
     pub data: Vec<Float64>,
 }
 impl TryParse for GetMapdvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float64::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float64::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float64>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetMapdvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3271,19 +6852,89 @@ impl TryFrom<&[u8]> for GetMapdvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMapdvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetMapfv request
 pub const GET_MAPFV_REQUEST: u8 = 121;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMapfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub query: u32,
+}
+impl GetMapfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MAPFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (query, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMapfvRequest {
+            context_tag,
+            target,
+            query,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `query` field of this structure.
+    pub fn query(mut self, value: u32) -> Self {
+        self.query = value;
+        self
+    }
+}
+
 pub fn get_mapfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, query: u32) -> Result<Cookie<'_, Conn, GetMapfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let query_bytes = query.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let query_bytes = query.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MAPFV_REQUEST,
@@ -3305,9 +6956,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMapfvRequest {
+    type Reply = GetMapfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMapfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMapfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_mapfv(conn, self.context_tag, self.target, self.query)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetMapfvReply {
@@ -3318,16 +6981,16 @@ pub struct GetMapfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetMapfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetMapfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3338,19 +7001,89 @@ impl TryFrom<&[u8]> for GetMapfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMapfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetMapiv request
 pub const GET_MAPIV_REQUEST: u8 = 122;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMapivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub query: u32,
+}
+impl GetMapivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MAPIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (query, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMapivRequest {
+            context_tag,
+            target,
+            query,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `query` field of this structure.
+    pub fn query(mut self, value: u32) -> Self {
+        self.query = value;
+        self
+    }
+}
+
 pub fn get_mapiv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, query: u32) -> Result<Cookie<'_, Conn, GetMapivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let query_bytes = query.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let query_bytes = query.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MAPIV_REQUEST,
@@ -3372,9 +7105,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMapivRequest {
+    type Reply = GetMapivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMapivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMapivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_mapiv(conn, self.context_tag, self.target, self.query)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMapivReply {
@@ -3385,16 +7130,16 @@ pub struct GetMapivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetMapivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetMapivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3405,19 +7150,89 @@ impl TryFrom<&[u8]> for GetMapivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMapivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetMaterialfv request
 pub const GET_MATERIALFV_REQUEST: u8 = 123;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMaterialfvRequest {
+    pub context_tag: ContextTag,
+    pub face: u32,
+    pub pname: u32,
+}
+impl GetMaterialfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MATERIALFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (face, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMaterialfvRequest {
+            context_tag,
+            face,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `face` field of this structure.
+    pub fn face(mut self, value: u32) -> Self {
+        self.face = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_materialfv<Conn>(conn: &Conn, context_tag: ContextTag, face: u32, pname: u32) -> Result<Cookie<'_, Conn, GetMaterialfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let face_bytes = face.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let face_bytes = face.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MATERIALFV_REQUEST,
@@ -3439,9 +7254,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMaterialfvRequest {
+    type Reply = GetMaterialfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMaterialfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMaterialfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_materialfv(conn, self.context_tag, self.face, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetMaterialfvReply {
@@ -3452,16 +7279,16 @@ pub struct GetMaterialfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetMaterialfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetMaterialfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3472,19 +7299,89 @@ impl TryFrom<&[u8]> for GetMaterialfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMaterialfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetMaterialiv request
 pub const GET_MATERIALIV_REQUEST: u8 = 124;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMaterialivRequest {
+    pub context_tag: ContextTag,
+    pub face: u32,
+    pub pname: u32,
+}
+impl GetMaterialivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MATERIALIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (face, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMaterialivRequest {
+            context_tag,
+            face,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `face` field of this structure.
+    pub fn face(mut self, value: u32) -> Self {
+        self.face = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_materialiv<Conn>(conn: &Conn, context_tag: ContextTag, face: u32, pname: u32) -> Result<Cookie<'_, Conn, GetMaterialivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let face_bytes = face.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let face_bytes = face.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MATERIALIV_REQUEST,
@@ -3506,9 +7403,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMaterialivRequest {
+    type Reply = GetMaterialivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMaterialivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMaterialivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_materialiv(conn, self.context_tag, self.face, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMaterialivReply {
@@ -3519,16 +7428,16 @@ pub struct GetMaterialivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetMaterialivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetMaterialivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3539,18 +7448,80 @@ impl TryFrom<&[u8]> for GetMaterialivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMaterialivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetPixelMapfv request
 pub const GET_PIXEL_MAPFV_REQUEST: u8 = 125;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetPixelMapfvRequest {
+    pub context_tag: ContextTag,
+    pub map: u32,
+}
+impl GetPixelMapfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PIXEL_MAPFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetPixelMapfvRequest {
+            context_tag,
+            map,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `map` field of this structure.
+    pub fn map(mut self, value: u32) -> Self {
+        self.map = value;
+        self
+    }
+}
+
 pub fn get_pixel_mapfv<Conn>(conn: &Conn, context_tag: ContextTag, map: u32) -> Result<Cookie<'_, Conn, GetPixelMapfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let map_bytes = map.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let map_bytes = map.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PIXEL_MAPFV_REQUEST,
@@ -3568,9 +7539,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetPixelMapfvRequest {
+    type Reply = GetPixelMapfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetPixelMapfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetPixelMapfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_pixel_mapfv(conn, self.context_tag, self.map)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetPixelMapfvReply {
@@ -3581,16 +7564,16 @@ pub struct GetPixelMapfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetPixelMapfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetPixelMapfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3601,18 +7584,80 @@ impl TryFrom<&[u8]> for GetPixelMapfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetPixelMapfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetPixelMapuiv request
 pub const GET_PIXEL_MAPUIV_REQUEST: u8 = 126;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetPixelMapuivRequest {
+    pub context_tag: ContextTag,
+    pub map: u32,
+}
+impl GetPixelMapuivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PIXEL_MAPUIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetPixelMapuivRequest {
+            context_tag,
+            map,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `map` field of this structure.
+    pub fn map(mut self, value: u32) -> Self {
+        self.map = value;
+        self
+    }
+}
+
 pub fn get_pixel_mapuiv<Conn>(conn: &Conn, context_tag: ContextTag, map: u32) -> Result<Cookie<'_, Conn, GetPixelMapuivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let map_bytes = map.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let map_bytes = map.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PIXEL_MAPUIV_REQUEST,
@@ -3630,9 +7675,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetPixelMapuivRequest {
+    type Reply = GetPixelMapuivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetPixelMapuivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetPixelMapuivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_pixel_mapuiv(conn, self.context_tag, self.map)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetPixelMapuivReply {
@@ -3643,16 +7700,16 @@ pub struct GetPixelMapuivReply {
     pub data: Vec<u32>,
 }
 impl TryParse for GetPixelMapuivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = u32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetPixelMapuivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3663,18 +7720,80 @@ impl TryFrom<&[u8]> for GetPixelMapuivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetPixelMapuivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+/// Opcode for the GetPixelMapusv request
+pub const GET_PIXEL_MAPUSV_REQUEST: u8 = 127;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetPixelMapusvRequest {
+    pub context_tag: ContextTag,
+    pub map: u32,
+}
+impl GetPixelMapusvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PIXEL_MAPUSV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetPixelMapusvRequest {
+            context_tag,
+            map,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `map` field of this structure.
+    pub fn map(mut self, value: u32) -> Self {
+        self.map = value;
+        self
+    }
+}
 
-/// Opcode for the GetPixelMapusv request
-pub const GET_PIXEL_MAPUSV_REQUEST: u8 = 127;
 pub fn get_pixel_mapusv<Conn>(conn: &Conn, context_tag: ContextTag, map: u32) -> Result<Cookie<'_, Conn, GetPixelMapusvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let map_bytes = map.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let map_bytes = map.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PIXEL_MAPUSV_REQUEST,
@@ -3692,9 +7811,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetPixelMapusvRequest {
+    type Reply = GetPixelMapusvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetPixelMapusvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetPixelMapusvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_pixel_mapusv(conn, self.context_tag, self.map)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetPixelMapusvReply {
@@ -3705,16 +7836,16 @@ pub struct GetPixelMapusvReply {
     pub data: Vec<u16>,
 }
 impl TryParse for GetPixelMapusvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = u16::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u16>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetPixelMapusvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3725,18 +7856,80 @@ impl TryFrom<&[u8]> for GetPixelMapusvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetPixelMapusvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetPolygonStipple request
 pub const GET_POLYGON_STIPPLE_REQUEST: u8 = 128;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetPolygonStippleRequest {
+    pub context_tag: ContextTag,
+    pub lsb_first: bool,
+}
+impl GetPolygonStippleRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_POLYGON_STIPPLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (lsb_first, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetPolygonStippleRequest {
+            context_tag,
+            lsb_first,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `lsb_first` field of this structure.
+    pub fn lsb_first(mut self, value: bool) -> Self {
+        self.lsb_first = value;
+        self
+    }
+}
+
 pub fn get_polygon_stipple<Conn>(conn: &Conn, context_tag: ContextTag, lsb_first: bool) -> Result<Cookie<'_, Conn, GetPolygonStippleReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let lsb_first_bytes = lsb_first.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let lsb_first_bytes = lsb_first.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_POLYGON_STIPPLE_REQUEST,
@@ -3754,9 +7947,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetPolygonStippleRequest {
+    type Reply = GetPolygonStippleReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetPolygonStippleRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetPolygonStippleReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_polygon_stipple(conn, self.context_tag, self.lsb_first)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetPolygonStippleReply {
@@ -3765,11 +7970,11 @@ pub struct GetPolygonStippleReply {
     pub data: Vec<u8>,
 }
 impl TryParse for GetPolygonStippleReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -3783,18 +7988,78 @@ impl TryFrom<&[u8]> for GetPolygonStippleReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetPolygonStippleReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetString request
 pub const GET_STRING_REQUEST: u8 = 129;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetStringRequest {
+    pub context_tag: ContextTag,
+    pub name: u32,
+}
+impl GetStringRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_STRING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetStringRequest {
+            context_tag,
+            name,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `name` field of this structure.
+    pub fn name(mut self, value: u32) -> Self {
+        self.name = value;
+        self
+    }
+}
+
 pub fn get_string<Conn>(conn: &Conn, context_tag: ContextTag, name: u32) -> Result<Cookie<'_, Conn, GetStringReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let name_bytes = name.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let name_bytes = name.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_STRING_REQUEST,
@@ -3812,9 +8077,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetStringRequest {
+    type Reply = GetStringReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetStringRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetStringReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_string(conn, self.context_tag, self.name)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetStringReply {
@@ -3824,13 +8101,13 @@ pub struct GetStringReply {
     pub string: Vec<u8>,
 }
 impl TryParse for GetStringReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
         let string = string.to_vec();
@@ -3844,19 +8121,88 @@ impl TryFrom<&[u8]> for GetStringReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetStringReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.string.len()).expect("`string` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        bytes.extend_from_slice(&self.string);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexEnvfv request
 pub const GET_TEX_ENVFV_REQUEST: u8 = 130;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexEnvfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetTexEnvfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_ENVFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexEnvfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_tex_envfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexEnvfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_ENVFV_REQUEST,
@@ -3878,9 +8224,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexEnvfvRequest {
+    type Reply = GetTexEnvfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexEnvfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexEnvfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_envfv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexEnvfvReply {
@@ -3891,16 +8249,16 @@ pub struct GetTexEnvfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetTexEnvfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetTexEnvfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3911,19 +8269,89 @@ impl TryFrom<&[u8]> for GetTexEnvfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexEnvfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexEnviv request
 pub const GET_TEX_ENVIV_REQUEST: u8 = 131;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexEnvivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetTexEnvivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_ENVIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexEnvivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_tex_enviv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexEnvivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_ENVIV_REQUEST,
@@ -3945,9 +8373,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexEnvivRequest {
+    type Reply = GetTexEnvivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexEnvivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexEnvivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_enviv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexEnvivReply {
@@ -3958,16 +8398,16 @@ pub struct GetTexEnvivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetTexEnvivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetTexEnvivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -3978,19 +8418,89 @@ impl TryFrom<&[u8]> for GetTexEnvivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexEnvivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexGendv request
 pub const GET_TEX_GENDV_REQUEST: u8 = 132;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexGendvRequest {
+    pub context_tag: ContextTag,
+    pub coord: u32,
+    pub pname: u32,
+}
+impl GetTexGendvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_GENDV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (coord, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexGendvRequest {
+            context_tag,
+            coord,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `coord` field of this structure.
+    pub fn coord(mut self, value: u32) -> Self {
+        self.coord = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_tex_gendv<Conn>(conn: &Conn, context_tag: ContextTag, coord: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexGendvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let coord_bytes = coord.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let coord_bytes = coord.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_GENDV_REQUEST,
@@ -4012,9 +8522,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexGendvRequest {
+    type Reply = GetTexGendvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexGendvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexGendvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_gendv(conn, self.context_tag, self.coord, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexGendvReply {
@@ -4025,16 +8547,16 @@ pub struct GetTexGendvReply {
     pub data: Vec<Float64>,
 }
 impl TryParse for GetTexGendvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float64::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float64::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float64>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetTexGendvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -4045,19 +8567,89 @@ impl TryFrom<&[u8]> for GetTexGendvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexGendvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexGenfv request
 pub const GET_TEX_GENFV_REQUEST: u8 = 133;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexGenfvRequest {
+    pub context_tag: ContextTag,
+    pub coord: u32,
+    pub pname: u32,
+}
+impl GetTexGenfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_GENFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (coord, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexGenfvRequest {
+            context_tag,
+            coord,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `coord` field of this structure.
+    pub fn coord(mut self, value: u32) -> Self {
+        self.coord = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_tex_genfv<Conn>(conn: &Conn, context_tag: ContextTag, coord: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexGenfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let coord_bytes = coord.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let coord_bytes = coord.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_GENFV_REQUEST,
@@ -4079,9 +8671,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexGenfvRequest {
+    type Reply = GetTexGenfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexGenfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexGenfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_genfv(conn, self.context_tag, self.coord, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexGenfvReply {
@@ -4092,16 +8696,16 @@ pub struct GetTexGenfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetTexGenfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetTexGenfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -4112,19 +8716,89 @@ impl TryFrom<&[u8]> for GetTexGenfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexGenfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexGeniv request
 pub const GET_TEX_GENIV_REQUEST: u8 = 134;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexGenivRequest {
+    pub context_tag: ContextTag,
+    pub coord: u32,
+    pub pname: u32,
+}
+impl GetTexGenivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_GENIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (coord, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexGenivRequest {
+            context_tag,
+            coord,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `coord` field of this structure.
+    pub fn coord(mut self, value: u32) -> Self {
+        self.coord = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_tex_geniv<Conn>(conn: &Conn, context_tag: ContextTag, coord: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexGenivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let coord_bytes = coord.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let coord_bytes = coord.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_GENIV_REQUEST,
@@ -4146,9 +8820,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexGenivRequest {
+    type Reply = GetTexGenivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexGenivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexGenivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_geniv(conn, self.context_tag, self.coord, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexGenivReply {
@@ -4159,16 +8845,16 @@ pub struct GetTexGenivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetTexGenivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetTexGenivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -4179,22 +8865,116 @@ impl TryFrom<&[u8]> for GetTexGenivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexGenivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexImage request
 pub const GET_TEX_IMAGE_REQUEST: u8 = 135;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexImageRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub level: i32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+}
+impl GetTexImageRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_IMAGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexImageRequest {
+            context_tag,
+            target,
+            level,
+            format,
+            type_,
+            swap_bytes,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `level` field of this structure.
+    pub fn level(mut self, value: i32) -> Self {
+        self.level = value;
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u32) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_(mut self, value: u32) -> Self {
+        self.type_ = value;
+        self
+    }
+    /// Set the `swap_bytes` field of this structure.
+    pub fn swap_bytes(mut self, value: bool) -> Self {
+        self.swap_bytes = value;
+        self
+    }
+}
+
 pub fn get_tex_image<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, level: i32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Conn, GetTexImageReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let level_bytes = level.serialize();
-    let format_bytes = format.serialize();
-    let type_bytes = type_.serialize();
-    let swap_bytes_bytes = swap_bytes.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let level_bytes = level.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let swap_bytes_bytes = swap_bytes.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_IMAGE_REQUEST,
@@ -4228,9 +9008,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexImageRequest {
+    type Reply = GetTexImageReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexImageRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexImageReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_image(conn, self.context_tag, self.target, self.level, self.format, self.type_, self.swap_bytes)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexImageReply {
@@ -4242,15 +9034,15 @@ pub struct GetTexImageReply {
     pub data: Vec<u8>,
 }
 impl TryParse for GetTexImageReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
-        let (depth, remaining) = i32::try_parse(remaining)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -4264,19 +9056,91 @@ impl TryFrom<&[u8]> for GetTexImageReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexImageReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexParameterfv request
 pub const GET_TEX_PARAMETERFV_REQUEST: u8 = 136;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetTexParameterfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_PARAMETERFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_tex_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_PARAMETERFV_REQUEST,
@@ -4298,9 +9162,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexParameterfvRequest {
+    type Reply = GetTexParameterfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexParameterfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexParameterfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_parameterfv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexParameterfvReply {
@@ -4311,16 +9187,16 @@ pub struct GetTexParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetTexParameterfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetTexParameterfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -4331,19 +9207,89 @@ impl TryFrom<&[u8]> for GetTexParameterfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexParameteriv request
 pub const GET_TEX_PARAMETERIV_REQUEST: u8 = 137;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetTexParameterivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_PARAMETERIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_tex_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_PARAMETERIV_REQUEST,
@@ -4365,9 +9311,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexParameterivRequest {
+    type Reply = GetTexParameterivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexParameterivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexParameterivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_parameteriv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexParameterivReply {
@@ -4378,16 +9336,16 @@ pub struct GetTexParameterivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetTexParameterivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetTexParameterivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -4398,20 +9356,98 @@ impl TryFrom<&[u8]> for GetTexParameterivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexLevelParameterfv request
 pub const GET_TEX_LEVEL_PARAMETERFV_REQUEST: u8 = 138;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexLevelParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub level: i32,
+    pub pname: u32,
+}
+impl GetTexLevelParameterfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_LEVEL_PARAMETERFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexLevelParameterfvRequest {
+            context_tag,
+            target,
+            level,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `level` field of this structure.
+    pub fn level(mut self, value: i32) -> Self {
+        self.level = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_tex_level_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, level: i32, pname: u32) -> Result<Cookie<'_, Conn, GetTexLevelParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let level_bytes = level.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let level_bytes = level.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_LEVEL_PARAMETERFV_REQUEST,
@@ -4437,9 +9473,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexLevelParameterfvRequest {
+    type Reply = GetTexLevelParameterfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexLevelParameterfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexLevelParameterfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_level_parameterfv(conn, self.context_tag, self.target, self.level, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexLevelParameterfvReply {
@@ -4450,16 +9498,16 @@ pub struct GetTexLevelParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetTexLevelParameterfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetTexLevelParameterfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -4470,20 +9518,98 @@ impl TryFrom<&[u8]> for GetTexLevelParameterfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexLevelParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetTexLevelParameteriv request
 pub const GET_TEX_LEVEL_PARAMETERIV_REQUEST: u8 = 139;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetTexLevelParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub level: i32,
+    pub pname: u32,
+}
+impl GetTexLevelParameterivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_LEVEL_PARAMETERIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetTexLevelParameterivRequest {
+            context_tag,
+            target,
+            level,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `level` field of this structure.
+    pub fn level(mut self, value: i32) -> Self {
+        self.level = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_tex_level_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, level: i32, pname: u32) -> Result<Cookie<'_, Conn, GetTexLevelParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let level_bytes = level.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let level_bytes = level.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_TEX_LEVEL_PARAMETERIV_REQUEST,
@@ -4509,9 +9635,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetTexLevelParameterivRequest {
+    type Reply = GetTexLevelParameterivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetTexLevelParameterivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetTexLevelParameterivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_tex_level_parameteriv(conn, self.context_tag, self.target, self.level, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexLevelParameterivReply {
@@ -4522,16 +9660,16 @@ pub struct GetTexLevelParameterivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetTexLevelParameterivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetTexLevelParameterivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -4542,18 +9680,80 @@ impl TryFrom<&[u8]> for GetTexLevelParameterivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetTexLevelParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the IsEnabled request
 pub const IS_ENABLED_REQUEST: u8 = 140;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IsEnabledRequest {
+    pub context_tag: ContextTag,
+    pub capability: u32,
+}
+impl IsEnabledRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_ENABLED_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (capability, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(IsEnabledRequest {
+            context_tag,
+            capability,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `capability` field of this structure.
+    pub fn capability(mut self, value: u32) -> Self {
+        self.capability = value;
+        self
+    }
+}
+
 pub fn is_enabled<Conn>(conn: &Conn, context_tag: ContextTag, capability: u32) -> Result<Cookie<'_, Conn, IsEnabledReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let capability_bytes = capability.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let capability_bytes = capability.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         IS_ENABLED_REQUEST,
@@ -4571,9 +9771,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for IsEnabledRequest {
+    type Reply = IsEnabledReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for IsEnabledRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, IsEnabledReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        is_enabled(conn, self.context_tag, self.capability)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsEnabledReply {
@@ -4583,12 +9795,12 @@ pub struct IsEnabledReply {
     pub ret_val: Bool32,
 }
 impl TryParse for IsEnabledReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
         let result = IsEnabledReply { response_type, sequence, length, ret_val };
         Ok((result, remaining))
     }
@@ -4599,18 +9811,75 @@ impl TryFrom<&[u8]> for IsEnabledReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for IsEnabledReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the IsList request
 pub const IS_LIST_REQUEST: u8 = 141;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IsListRequest {
+    pub context_tag: ContextTag,
+    pub list: u32,
+}
+impl IsListRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_LIST_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (list, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(IsListRequest {
+            context_tag,
+            list,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `list` field of this structure.
+    pub fn list(mut self, value: u32) -> Self {
+        self.list = value;
+        self
+    }
+}
+
 pub fn is_list<Conn>(conn: &Conn, context_tag: ContextTag, list: u32) -> Result<Cookie<'_, Conn, IsListReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let list_bytes = list.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let list_bytes = list.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         IS_LIST_REQUEST,
@@ -4628,9 +9897,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for IsListRequest {
+    type Reply = IsListReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for IsListRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, IsListReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        is_list(conn, self.context_tag, self.list)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsListReply {
@@ -4640,12 +9921,12 @@ pub struct IsListReply {
     pub ret_val: Bool32,
 }
 impl TryParse for IsListReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
         let result = IsListReply { response_type, sequence, length, ret_val };
         Ok((result, remaining))
     }
@@ -4656,17 +9937,66 @@ impl TryFrom<&[u8]> for IsListReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for IsListReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Flush request
 pub const FLUSH_REQUEST: u8 = 142;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FlushRequest {
+    pub context_tag: ContextTag,
+}
+impl FlushRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FLUSH_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FlushRequest {
+            context_tag,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+}
+
 pub fn flush<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FLUSH_REQUEST,
@@ -4680,22 +10010,69 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FlushRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FlushRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        flush(conn, self.context_tag)
+    }
+}
 
 /// Opcode for the AreTexturesResident request
 pub const ARE_TEXTURES_RESIDENT_REQUEST: u8 = 143;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AreTexturesResidentRequest {
+    pub context_tag: ContextTag,
+    pub textures: Vec<u32>,
+}
+impl AreTexturesResidentRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ARE_TEXTURES_RESIDENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (textures, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(AreTexturesResidentRequest {
+            context_tag,
+            textures,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `textures` field of this structure.
+    pub fn textures<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.textures = value.into();
+        self
+    }
+}
+
 pub fn are_textures_resident<'c, Conn>(conn: &'c Conn, context_tag: ContextTag, textures: &[u32]) -> Result<Cookie<'c, Conn, AreTexturesResidentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let n = i32::try_from(textures.len()).expect("`textures` has too many elements");
-    let n_bytes = n.serialize();
+    let n_bytes = n.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ARE_TEXTURES_RESIDENT_REQUEST,
@@ -4711,15 +10088,27 @@ where
         n_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let textures_bytes = textures.serialize();
+    let textures_bytes = textures.serialize_with_order(order);
     let length_so_far = length_so_far + textures_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&textures_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for AreTexturesResidentRequest {
+    type Reply = AreTexturesResidentReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AreTexturesResidentRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, AreTexturesResidentReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        are_textures_resident(conn, self.context_tag, &self.textures)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AreTexturesResidentReply {
@@ -4729,14 +10118,14 @@ pub struct AreTexturesResidentReply {
     pub data: Vec<bool>,
 }
 impl TryParse for AreTexturesResidentReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<bool>(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<bool>(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = AreTexturesResidentReply { response_type, sequence, ret_val, data };
         Ok((result, remaining))
     }
@@ -4747,19 +10136,81 @@ impl TryFrom<&[u8]> for AreTexturesResidentReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for AreTexturesResidentReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DeleteTextures request
 pub const DELETE_TEXTURES_REQUEST: u8 = 144;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeleteTexturesRequest {
+    pub context_tag: ContextTag,
+    pub textures: Vec<u32>,
+}
+impl DeleteTexturesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_TEXTURES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (textures, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeleteTexturesRequest {
+            context_tag,
+            textures,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `textures` field of this structure.
+    pub fn textures<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.textures = value.into();
+        self
+    }
+}
+
 pub fn delete_textures<'c, Conn>(conn: &'c Conn, context_tag: ContextTag, textures: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let n = i32::try_from(textures.len()).expect("`textures` has too many elements");
-    let n_bytes = n.serialize();
+    let n_bytes = n.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_TEXTURES_REQUEST,
@@ -4775,27 +10226,73 @@ where
         n_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let textures_bytes = textures.serialize();
+    let textures_bytes = textures.serialize_with_order(order);
     let length_so_far = length_so_far + textures_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&textures_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteTexturesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteTexturesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_textures(conn, self.context_tag, &self.textures)
+    }
+}
 
 /// Opcode for the GenTextures request
 pub const GEN_TEXTURES_REQUEST: u8 = 145;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GenTexturesRequest {
+    pub context_tag: ContextTag,
+    pub n: i32,
+}
+impl GenTexturesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GEN_TEXTURES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GenTexturesRequest {
+            context_tag,
+            n,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `n` field of this structure.
+    pub fn n(mut self, value: i32) -> Self {
+        self.n = value;
+        self
+    }
+}
+
 pub fn gen_textures<Conn>(conn: &Conn, context_tag: ContextTag, n: i32) -> Result<Cookie<'_, Conn, GenTexturesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let n_bytes = n.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let n_bytes = n.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GEN_TEXTURES_REQUEST,
@@ -4813,9 +10310,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GenTexturesRequest {
+    type Reply = GenTexturesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GenTexturesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GenTexturesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        gen_textures(conn, self.context_tag, self.n)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenTexturesReply {
@@ -4824,13 +10333,13 @@ pub struct GenTexturesReply {
     pub data: Vec<u32>,
 }
 impl TryParse for GenTexturesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GenTexturesReply { response_type, sequence, data };
         Ok((result, remaining))
     }
@@ -4841,18 +10350,77 @@ impl TryFrom<&[u8]> for GenTexturesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GenTexturesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the IsTexture request
 pub const IS_TEXTURE_REQUEST: u8 = 146;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IsTextureRequest {
+    pub context_tag: ContextTag,
+    pub texture: u32,
+}
+impl IsTextureRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_TEXTURE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (texture, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(IsTextureRequest {
+            context_tag,
+            texture,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `texture` field of this structure.
+    pub fn texture(mut self, value: u32) -> Self {
+        self.texture = value;
+        self
+    }
+}
+
 pub fn is_texture<Conn>(conn: &Conn, context_tag: ContextTag, texture: u32) -> Result<Cookie<'_, Conn, IsTextureReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let texture_bytes = texture.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let texture_bytes = texture.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         IS_TEXTURE_REQUEST,
@@ -4870,9 +10438,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for IsTextureRequest {
+    type Reply = IsTextureReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for IsTextureRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, IsTextureReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        is_texture(conn, self.context_tag, self.texture)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsTextureReply {
@@ -4882,12 +10462,12 @@ pub struct IsTextureReply {
     pub ret_val: Bool32,
 }
 impl TryParse for IsTextureReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
         let result = IsTextureReply { response_type, sequence, length, ret_val };
         Ok((result, remaining))
     }
@@ -4898,21 +10478,102 @@ impl TryFrom<&[u8]> for IsTextureReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for IsTextureReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetColorTable request
 pub const GET_COLOR_TABLE_REQUEST: u8 = 147;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetColorTableRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+}
+impl GetColorTableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COLOR_TABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetColorTableRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u32) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_(mut self, value: u32) -> Self {
+        self.type_ = value;
+        self
+    }
+    /// Set the `swap_bytes` field of this structure.
+    pub fn swap_bytes(mut self, value: bool) -> Self {
+        self.swap_bytes = value;
+        self
+    }
+}
+
 pub fn get_color_table<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Conn, GetColorTableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let format_bytes = format.serialize();
-    let type_bytes = type_.serialize();
-    let swap_bytes_bytes = swap_bytes.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let swap_bytes_bytes = swap_bytes.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_COLOR_TABLE_REQUEST,
@@ -4942,9 +10603,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetColorTableRequest {
+    type Reply = GetColorTableReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetColorTableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetColorTableReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_color_table(conn, self.context_tag, self.target, self.format, self.type_, self.swap_bytes)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetColorTableReply {
@@ -4954,13 +10627,13 @@ pub struct GetColorTableReply {
     pub data: Vec<u8>,
 }
 impl TryParse for GetColorTableReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -4974,19 +10647,89 @@ impl TryFrom<&[u8]> for GetColorTableReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetColorTableReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.width.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetColorTableParameterfv request
 pub const GET_COLOR_TABLE_PARAMETERFV_REQUEST: u8 = 148;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetColorTableParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetColorTableParameterfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COLOR_TABLE_PARAMETERFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetColorTableParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_color_table_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetColorTableParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_COLOR_TABLE_PARAMETERFV_REQUEST,
@@ -5008,9 +10751,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetColorTableParameterfvRequest {
+    type Reply = GetColorTableParameterfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetColorTableParameterfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetColorTableParameterfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_color_table_parameterfv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetColorTableParameterfvReply {
@@ -5021,16 +10776,16 @@ pub struct GetColorTableParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetColorTableParameterfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetColorTableParameterfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -5041,19 +10796,89 @@ impl TryFrom<&[u8]> for GetColorTableParameterfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetColorTableParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetColorTableParameteriv request
 pub const GET_COLOR_TABLE_PARAMETERIV_REQUEST: u8 = 149;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetColorTableParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetColorTableParameterivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COLOR_TABLE_PARAMETERIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetColorTableParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_color_table_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetColorTableParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_COLOR_TABLE_PARAMETERIV_REQUEST,
@@ -5075,9 +10900,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetColorTableParameterivRequest {
+    type Reply = GetColorTableParameterivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetColorTableParameterivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetColorTableParameterivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_color_table_parameteriv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetColorTableParameterivReply {
@@ -5088,16 +10925,16 @@ pub struct GetColorTableParameterivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetColorTableParameterivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetColorTableParameterivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -5108,21 +10945,107 @@ impl TryFrom<&[u8]> for GetColorTableParameterivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetColorTableParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetConvolutionFilter request
 pub const GET_CONVOLUTION_FILTER_REQUEST: u8 = 150;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetConvolutionFilterRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+}
+impl GetConvolutionFilterRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONVOLUTION_FILTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetConvolutionFilterRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u32) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_(mut self, value: u32) -> Self {
+        self.type_ = value;
+        self
+    }
+    /// Set the `swap_bytes` field of this structure.
+    pub fn swap_bytes(mut self, value: bool) -> Self {
+        self.swap_bytes = value;
+        self
+    }
+}
+
 pub fn get_convolution_filter<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Conn, GetConvolutionFilterReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let format_bytes = format.serialize();
-    let type_bytes = type_.serialize();
-    let swap_bytes_bytes = swap_bytes.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let swap_bytes_bytes = swap_bytes.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CONVOLUTION_FILTER_REQUEST,
@@ -5152,9 +11075,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetConvolutionFilterRequest {
+    type Reply = GetConvolutionFilterReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetConvolutionFilterRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetConvolutionFilterReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_convolution_filter(conn, self.context_tag, self.target, self.format, self.type_, self.swap_bytes)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetConvolutionFilterReply {
@@ -5165,14 +11100,14 @@ pub struct GetConvolutionFilterReply {
     pub data: Vec<u8>,
 }
 impl TryParse for GetConvolutionFilterReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -5186,19 +11121,90 @@ impl TryFrom<&[u8]> for GetConvolutionFilterReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetConvolutionFilterReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetConvolutionParameterfv request
 pub const GET_CONVOLUTION_PARAMETERFV_REQUEST: u8 = 151;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetConvolutionParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetConvolutionParameterfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONVOLUTION_PARAMETERFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetConvolutionParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_convolution_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetConvolutionParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CONVOLUTION_PARAMETERFV_REQUEST,
@@ -5220,9 +11226,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetConvolutionParameterfvRequest {
+    type Reply = GetConvolutionParameterfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetConvolutionParameterfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetConvolutionParameterfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_convolution_parameterfv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetConvolutionParameterfvReply {
@@ -5233,16 +11251,16 @@ pub struct GetConvolutionParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetConvolutionParameterfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetConvolutionParameterfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -5253,19 +11271,89 @@ impl TryFrom<&[u8]> for GetConvolutionParameterfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetConvolutionParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetConvolutionParameteriv request
 pub const GET_CONVOLUTION_PARAMETERIV_REQUEST: u8 = 152;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetConvolutionParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetConvolutionParameterivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONVOLUTION_PARAMETERIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetConvolutionParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_convolution_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetConvolutionParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CONVOLUTION_PARAMETERIV_REQUEST,
@@ -5287,9 +11375,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetConvolutionParameterivRequest {
+    type Reply = GetConvolutionParameterivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetConvolutionParameterivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetConvolutionParameterivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_convolution_parameteriv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetConvolutionParameterivReply {
@@ -5300,16 +11400,16 @@ pub struct GetConvolutionParameterivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetConvolutionParameterivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetConvolutionParameterivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -5320,21 +11420,107 @@ impl TryFrom<&[u8]> for GetConvolutionParameterivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetConvolutionParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetSeparableFilter request
 pub const GET_SEPARABLE_FILTER_REQUEST: u8 = 153;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetSeparableFilterRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+}
+impl GetSeparableFilterRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SEPARABLE_FILTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetSeparableFilterRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u32) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_(mut self, value: u32) -> Self {
+        self.type_ = value;
+        self
+    }
+    /// Set the `swap_bytes` field of this structure.
+    pub fn swap_bytes(mut self, value: bool) -> Self {
+        self.swap_bytes = value;
+        self
+    }
+}
+
 pub fn get_separable_filter<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Conn, GetSeparableFilterReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let format_bytes = format.serialize();
-    let type_bytes = type_.serialize();
-    let swap_bytes_bytes = swap_bytes.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let swap_bytes_bytes = swap_bytes.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_SEPARABLE_FILTER_REQUEST,
@@ -5364,9 +11550,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetSeparableFilterRequest {
+    type Reply = GetSeparableFilterReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetSeparableFilterRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetSeparableFilterReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_separable_filter(conn, self.context_tag, self.target, self.format, self.type_, self.swap_bytes)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetSeparableFilterReply {
@@ -5377,14 +11575,14 @@ pub struct GetSeparableFilterReply {
     pub rows_and_cols: Vec<u8>,
 }
 impl TryParse for GetSeparableFilterReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (row_w, remaining) = i32::try_parse(remaining)?;
-        let (col_h, remaining) = i32::try_parse(remaining)?;
+        let (row_w, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (col_h, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
         let (rows_and_cols, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let rows_and_cols = rows_and_cols.to_vec();
@@ -5398,22 +11596,117 @@ impl TryFrom<&[u8]> for GetSeparableFilterReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetSeparableFilterReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.rows_and_cols.len() % 4, 0, "`rows_and_cols` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.rows_and_cols.len() / 4).expect("`rows_and_cols` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.row_w.serialize_into_with_order(bytes, order);
+        self.col_h.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&self.rows_and_cols);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetHistogram request
 pub const GET_HISTOGRAM_REQUEST: u8 = 154;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetHistogramRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+    pub reset: bool,
+}
+impl GetHistogramRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_HISTOGRAM_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (reset, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetHistogramRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+            reset,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u32) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_(mut self, value: u32) -> Self {
+        self.type_ = value;
+        self
+    }
+    /// Set the `swap_bytes` field of this structure.
+    pub fn swap_bytes(mut self, value: bool) -> Self {
+        self.swap_bytes = value;
+        self
+    }
+    /// Set the `reset` field of this structure.
+    pub fn reset(mut self, value: bool) -> Self {
+        self.reset = value;
+        self
+    }
+}
+
 pub fn get_histogram<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool, reset: bool) -> Result<Cookie<'_, Conn, GetHistogramReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let format_bytes = format.serialize();
-    let type_bytes = type_.serialize();
-    let swap_bytes_bytes = swap_bytes.serialize();
-    let reset_bytes = reset.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let swap_bytes_bytes = swap_bytes.serialize_with_order(order);
+    let reset_bytes = reset.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_HISTOGRAM_REQUEST,
@@ -5443,9 +11736,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetHistogramRequest {
+    type Reply = GetHistogramReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetHistogramRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetHistogramReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_histogram(conn, self.context_tag, self.target, self.format, self.type_, self.swap_bytes, self.reset)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetHistogramReply {
@@ -5455,13 +11760,13 @@ pub struct GetHistogramReply {
     pub data: Vec<u8>,
 }
 impl TryParse for GetHistogramReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -5475,19 +11780,89 @@ impl TryFrom<&[u8]> for GetHistogramReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetHistogramReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.width.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetHistogramParameterfv request
 pub const GET_HISTOGRAM_PARAMETERFV_REQUEST: u8 = 155;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetHistogramParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetHistogramParameterfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_HISTOGRAM_PARAMETERFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetHistogramParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_histogram_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetHistogramParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_HISTOGRAM_PARAMETERFV_REQUEST,
@@ -5509,9 +11884,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetHistogramParameterfvRequest {
+    type Reply = GetHistogramParameterfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetHistogramParameterfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetHistogramParameterfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_histogram_parameterfv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetHistogramParameterfvReply {
@@ -5522,16 +11909,16 @@ pub struct GetHistogramParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetHistogramParameterfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetHistogramParameterfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -5542,19 +11929,89 @@ impl TryFrom<&[u8]> for GetHistogramParameterfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetHistogramParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetHistogramParameteriv request
 pub const GET_HISTOGRAM_PARAMETERIV_REQUEST: u8 = 156;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetHistogramParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetHistogramParameterivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_HISTOGRAM_PARAMETERIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetHistogramParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_histogram_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetHistogramParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_HISTOGRAM_PARAMETERIV_REQUEST,
@@ -5576,9 +12033,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetHistogramParameterivRequest {
+    type Reply = GetHistogramParameterivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetHistogramParameterivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetHistogramParameterivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_histogram_parameteriv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetHistogramParameterivReply {
@@ -5589,16 +12058,16 @@ pub struct GetHistogramParameterivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetHistogramParameterivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetHistogramParameterivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -5609,22 +12078,116 @@ impl TryFrom<&[u8]> for GetHistogramParameterivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetHistogramParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetMinmax request
 pub const GET_MINMAX_REQUEST: u8 = 157;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMinmaxRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+    pub reset: bool,
+}
+impl GetMinmaxRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MINMAX_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (reset, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMinmaxRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+            reset,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u32) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_(mut self, value: u32) -> Self {
+        self.type_ = value;
+        self
+    }
+    /// Set the `swap_bytes` field of this structure.
+    pub fn swap_bytes(mut self, value: bool) -> Self {
+        self.swap_bytes = value;
+        self
+    }
+    /// Set the `reset` field of this structure.
+    pub fn reset(mut self, value: bool) -> Self {
+        self.reset = value;
+        self
+    }
+}
+
 pub fn get_minmax<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool, reset: bool) -> Result<Cookie<'_, Conn, GetMinmaxReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let format_bytes = format.serialize();
-    let type_bytes = type_.serialize();
-    let swap_bytes_bytes = swap_bytes.serialize();
-    let reset_bytes = reset.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let swap_bytes_bytes = swap_bytes.serialize_with_order(order);
+    let reset_bytes = reset.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MINMAX_REQUEST,
@@ -5654,9 +12217,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMinmaxRequest {
+    type Reply = GetMinmaxReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMinmaxRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMinmaxReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_minmax(conn, self.context_tag, self.target, self.format, self.type_, self.swap_bytes, self.reset)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMinmaxReply {
@@ -5665,11 +12240,11 @@ pub struct GetMinmaxReply {
     pub data: Vec<u8>,
 }
 impl TryParse for GetMinmaxReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -5683,19 +12258,87 @@ impl TryFrom<&[u8]> for GetMinmaxReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMinmaxReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetMinmaxParameterfv request
 pub const GET_MINMAX_PARAMETERFV_REQUEST: u8 = 158;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMinmaxParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetMinmaxParameterfvRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MINMAX_PARAMETERFV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMinmaxParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_minmax_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetMinmaxParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MINMAX_PARAMETERFV_REQUEST,
@@ -5717,9 +12360,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMinmaxParameterfvRequest {
+    type Reply = GetMinmaxParameterfvReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMinmaxParameterfvRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMinmaxParameterfvReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_minmax_parameterfv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetMinmaxParameterfvReply {
@@ -5730,16 +12385,16 @@ pub struct GetMinmaxParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl TryParse for GetMinmaxParameterfvReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetMinmaxParameterfvReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -5750,19 +12405,89 @@ impl TryFrom<&[u8]> for GetMinmaxParameterfvReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMinmaxParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetMinmaxParameteriv request
 pub const GET_MINMAX_PARAMETERIV_REQUEST: u8 = 159;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMinmaxParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetMinmaxParameterivRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MINMAX_PARAMETERIV_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMinmaxParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_minmax_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetMinmaxParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MINMAX_PARAMETERIV_REQUEST,
@@ -5784,9 +12509,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMinmaxParameterivRequest {
+    type Reply = GetMinmaxParameterivReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMinmaxParameterivRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMinmaxParameterivReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_minmax_parameteriv(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMinmaxParameterivReply {
@@ -5797,16 +12534,16 @@ pub struct GetMinmaxParameterivReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetMinmaxParameterivReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetMinmaxParameterivReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -5817,19 +12554,89 @@ impl TryFrom<&[u8]> for GetMinmaxParameterivReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMinmaxParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetCompressedTexImageARB request
 pub const GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST: u8 = 160;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetCompressedTexImageARBRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub level: i32,
+}
+impl GetCompressedTexImageARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetCompressedTexImageARBRequest {
+            context_tag,
+            target,
+            level,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `level` field of this structure.
+    pub fn level(mut self, value: i32) -> Self {
+        self.level = value;
+        self
+    }
+}
+
 pub fn get_compressed_tex_image_arb<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, level: i32) -> Result<Cookie<'_, Conn, GetCompressedTexImageARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let level_bytes = level.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let level_bytes = level.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST,
@@ -5851,9 +12658,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetCompressedTexImageARBRequest {
+    type Reply = GetCompressedTexImageARBReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetCompressedTexImageARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetCompressedTexImageARBReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_compressed_tex_image_arb(conn, self.context_tag, self.target, self.level)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCompressedTexImageARBReply {
@@ -5863,13 +12682,13 @@ pub struct GetCompressedTexImageARBReply {
     pub data: Vec<u8>,
 }
 impl TryParse for GetCompressedTexImageARBReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (size, remaining) = i32::try_parse(remaining)?;
+        let (size, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -5883,19 +12702,82 @@ impl TryFrom<&[u8]> for GetCompressedTexImageARBReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetCompressedTexImageARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DeleteQueriesARB request
 pub const DELETE_QUERIES_ARB_REQUEST: u8 = 161;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeleteQueriesARBRequest {
+    pub context_tag: ContextTag,
+    pub ids: Vec<u32>,
+}
+impl DeleteQueriesARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_QUERIES_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (ids, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeleteQueriesARBRequest {
+            context_tag,
+            ids,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `ids` field of this structure.
+    pub fn ids<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.ids = value.into();
+        self
+    }
+}
+
 pub fn delete_queries_arb<'c, Conn>(conn: &'c Conn, context_tag: ContextTag, ids: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
     let n = i32::try_from(ids.len()).expect("`ids` has too many elements");
-    let n_bytes = n.serialize();
+    let n_bytes = n.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_QUERIES_ARB_REQUEST,
@@ -5911,27 +12793,73 @@ where
         n_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let ids_bytes = ids.serialize();
+    let ids_bytes = ids.serialize_with_order(order);
     let length_so_far = length_so_far + ids_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&ids_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteQueriesARBRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteQueriesARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_queries_arb(conn, self.context_tag, &self.ids)
+    }
+}
 
 /// Opcode for the GenQueriesARB request
 pub const GEN_QUERIES_ARB_REQUEST: u8 = 162;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GenQueriesARBRequest {
+    pub context_tag: ContextTag,
+    pub n: i32,
+}
+impl GenQueriesARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GEN_QUERIES_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GenQueriesARBRequest {
+            context_tag,
+            n,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `n` field of this structure.
+    pub fn n(mut self, value: i32) -> Self {
+        self.n = value;
+        self
+    }
+}
+
 pub fn gen_queries_arb<Conn>(conn: &Conn, context_tag: ContextTag, n: i32) -> Result<Cookie<'_, Conn, GenQueriesARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let n_bytes = n.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let n_bytes = n.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GEN_QUERIES_ARB_REQUEST,
@@ -5949,9 +12877,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GenQueriesARBRequest {
+    type Reply = GenQueriesARBReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GenQueriesARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GenQueriesARBReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        gen_queries_arb(conn, self.context_tag, self.n)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenQueriesARBReply {
@@ -5960,13 +12900,13 @@ pub struct GenQueriesARBReply {
     pub data: Vec<u32>,
 }
 impl TryParse for GenQueriesARBReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GenQueriesARBReply { response_type, sequence, data };
         Ok((result, remaining))
     }
@@ -5977,18 +12917,77 @@ impl TryFrom<&[u8]> for GenQueriesARBReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GenQueriesARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the IsQueryARB request
 pub const IS_QUERY_ARB_REQUEST: u8 = 163;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IsQueryARBRequest {
+    pub context_tag: ContextTag,
+    pub id: u32,
+}
+impl IsQueryARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_QUERY_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(IsQueryARBRequest {
+            context_tag,
+            id,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `id` field of this structure.
+    pub fn id(mut self, value: u32) -> Self {
+        self.id = value;
+        self
+    }
+}
+
 pub fn is_query_arb<Conn>(conn: &Conn, context_tag: ContextTag, id: u32) -> Result<Cookie<'_, Conn, IsQueryARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let id_bytes = id.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let id_bytes = id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         IS_QUERY_ARB_REQUEST,
@@ -6006,9 +13005,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for IsQueryARBRequest {
+    type Reply = IsQueryARBReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for IsQueryARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, IsQueryARBReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        is_query_arb(conn, self.context_tag, self.id)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsQueryARBReply {
@@ -6018,12 +13029,12 @@ pub struct IsQueryARBReply {
     pub ret_val: Bool32,
 }
 impl TryParse for IsQueryARBReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
         let result = IsQueryARBReply { response_type, sequence, length, ret_val };
         Ok((result, remaining))
     }
@@ -6034,19 +13045,84 @@ impl TryFrom<&[u8]> for IsQueryARBReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for IsQueryARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetQueryivARB request
 pub const GET_QUERYIV_ARB_REQUEST: u8 = 164;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetQueryivARBRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetQueryivARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_QUERYIV_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetQueryivARBRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_queryiv_arb<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetQueryivARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let target_bytes = target.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let target_bytes = target.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_QUERYIV_ARB_REQUEST,
@@ -6068,9 +13144,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetQueryivARBRequest {
+    type Reply = GetQueryivARBReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetQueryivARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetQueryivARBReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_queryiv_arb(conn, self.context_tag, self.target, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetQueryivARBReply {
@@ -6081,16 +13169,16 @@ pub struct GetQueryivARBReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetQueryivARBReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetQueryivARBReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -6101,19 +13189,89 @@ impl TryFrom<&[u8]> for GetQueryivARBReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetQueryivARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetQueryObjectivARB request
 pub const GET_QUERY_OBJECTIV_ARB_REQUEST: u8 = 165;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetQueryObjectivARBRequest {
+    pub context_tag: ContextTag,
+    pub id: u32,
+    pub pname: u32,
+}
+impl GetQueryObjectivARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_QUERY_OBJECTIV_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetQueryObjectivARBRequest {
+            context_tag,
+            id,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `id` field of this structure.
+    pub fn id(mut self, value: u32) -> Self {
+        self.id = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_query_objectiv_arb<Conn>(conn: &Conn, context_tag: ContextTag, id: u32, pname: u32) -> Result<Cookie<'_, Conn, GetQueryObjectivARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let id_bytes = id.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let id_bytes = id.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_QUERY_OBJECTIV_ARB_REQUEST,
@@ -6135,9 +13293,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetQueryObjectivARBRequest {
+    type Reply = GetQueryObjectivARBReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetQueryObjectivARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetQueryObjectivARBReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_query_objectiv_arb(conn, self.context_tag, self.id, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetQueryObjectivARBReply {
@@ -6148,16 +13318,16 @@ pub struct GetQueryObjectivARBReply {
     pub data: Vec<i32>,
 }
 impl TryParse for GetQueryObjectivARBReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetQueryObjectivARBReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -6168,19 +13338,89 @@ impl TryFrom<&[u8]> for GetQueryObjectivARBReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetQueryObjectivARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetQueryObjectuivARB request
 pub const GET_QUERY_OBJECTUIV_ARB_REQUEST: u8 = 166;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetQueryObjectuivARBRequest {
+    pub context_tag: ContextTag,
+    pub id: u32,
+    pub pname: u32,
+}
+impl GetQueryObjectuivARBRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_QUERY_OBJECTUIV_ARB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetQueryObjectuivARBRequest {
+            context_tag,
+            id,
+            pname,
+        })
+    }
+    /// Set the `context_tag` field of this structure.
+    pub fn context_tag<I>(mut self, value: I) -> Self where I: Into<ContextTag> {
+        self.context_tag = value.into();
+        self
+    }
+    /// Set the `id` field of this structure.
+    pub fn id(mut self, value: u32) -> Self {
+        self.id = value;
+        self
+    }
+    /// Set the `pname` field of this structure.
+    pub fn pname(mut self, value: u32) -> Self {
+        self.pname = value;
+        self
+    }
+}
+
 pub fn get_query_objectuiv_arb<Conn>(conn: &Conn, context_tag: ContextTag, id: u32, pname: u32) -> Result<Cookie<'_, Conn, GetQueryObjectuivARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_tag_bytes = context_tag.serialize();
-    let id_bytes = id.serialize();
-    let pname_bytes = pname.serialize();
+    let context_tag_bytes = context_tag.serialize_with_order(order);
+    let id_bytes = id.serialize_with_order(order);
+    let pname_bytes = pname.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_QUERY_OBJECTUIV_ARB_REQUEST,
@@ -6202,9 +13442,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetQueryObjectuivARBRequest {
+    type Reply = GetQueryObjectuivARBReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetQueryObjectuivARBRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetQueryObjectuivARBReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_query_objectuiv_arb(conn, self.context_tag, self.id, self.pname)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetQueryObjectuivARBReply {
@@ -6215,16 +13467,16 @@ pub struct GetQueryObjectuivARBReply {
     pub data: Vec<u32>,
 }
 impl TryParse for GetQueryObjectuivARBReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = u32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_into().or(Err(ParseError::ParseError))?)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetQueryObjectuivARBReply { response_type, sequence, length, datum, data };
         Ok((result, remaining))
     }
@@ -6235,6 +13487,34 @@ impl TryFrom<&[u8]> for GetQueryObjectuivARBReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetQueryObjectuivARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -6254,7 +13534,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         destroy_context(self, context)
     }
-    fn glx_make_current(&self, drawable: Drawable, context: Context, old_context_tag: ContextTag) -> Result<Cookie<'_, Self, MakeCurrentReply>, ConnectionError>
+    fn glx_make_current<A>(&self, drawable: A, context: Context, old_context_tag: ContextTag) -> Result<Cookie<'_, Self, MakeCurrentReply>, ConnectionError>
+    where
+        A: Into<Drawable>,
     {
         make_current(self, drawable, context, old_context_tag)
     }
@@ -6278,7 +13560,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         copy_context(self, src, dest, mask, src_context_tag)
     }
-    fn glx_swap_buffers(&self, context_tag: ContextTag, drawable: Drawable) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn glx_swap_buffers<A>(&self, context_tag: ContextTag, drawable: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<Drawable>,
     {
         swap_buffers(self, context_tag, drawable)
     }
@@ -6338,7 +13622,10 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_context(self, context)
     }
-    fn glx_make_context_current(&self, old_context_tag: ContextTag, drawable: Drawable, read_drawable: Drawable, context: Context) -> Result<Cookie<'_, Self, MakeContextCurrentReply>, ConnectionError>
+    fn glx_make_context_current<A, B>(&self, old_context_tag: ContextTag, drawable: A, read_drawable: B, context: Context) -> Result<Cookie<'_, Self, MakeContextCurrentReply>, ConnectionError>
+    where
+        A: Into<Drawable>,
+        B: Into<Drawable>,
     {
         make_context_current(self, old_context_tag, drawable, read_drawable, context)
     }
@@ -6350,11 +13637,15 @@ pub trait ConnectionExt: RequestConnection {
     {
         destroy_pbuffer(self, pbuffer)
     }
-    fn glx_get_drawable_attributes(&self, drawable: Drawable) -> Result<Cookie<'_, Self, GetDrawableAttributesReply>, ConnectionError>
+    fn glx_get_drawable_attributes<A>(&self, drawable: A) -> Result<Cookie<'_, Self, GetDrawableAttributesReply>, ConnectionError>
+    where
+        A: Into<Drawable>,
     {
         get_drawable_attributes(self, drawable)
     }
-    fn glx_change_drawable_attributes<'c>(&'c self, drawable: Drawable, attribs: &[u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn glx_change_drawable_attributes<'c, A>(&'c self, drawable: A, attribs: &[u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<Drawable>,
     {
         change_drawable_attributes(self, drawable, attribs)
     }
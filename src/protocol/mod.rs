@@ -1,9 +1,10 @@
 // This file contains generated code. Do not edit directly.
 // To regenerate this, run 'make'.
 
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 use crate::errors::ParseError;
-use crate::x11_utils::ExtInfoProvider;
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ByteOrder, ExtInfoProvider, RequestHeader, TryParse};
 
 pub mod xproto;
 pub mod bigreq;
@@ -183,32 +184,43 @@ pub enum Error<B: std::fmt::Debug + AsRef<[u8]>> {
 
 impl<B: std::fmt::Debug + AsRef<[u8]>> Error<B> {
     /// Parse a generic X11 error into a concrete error type.
-    #[allow(clippy::cognitive_complexity)]
+    ///
+    /// The error is expected to be encoded in the native byte order.
     pub fn parse(
         error: B,
         ext_info_provider: &dyn ExtInfoProvider,
+    ) -> Result<Self, ParseError> {
+        Self::parse_with_order(error, ext_info_provider, ByteOrder::native())
+    }
+
+    /// Parse a generic X11 error that is encoded in the given byte order.
+    #[allow(clippy::cognitive_complexity)]
+    pub fn parse_with_order(
+        error: B,
+        ext_info_provider: &dyn ExtInfoProvider,
+        order: ByteOrder,
     ) -> Result<Self, ParseError> {
         let error_code = error_code(error.as_ref())?;
 
         // Check if this is a core protocol error
         match error_code {
-            xproto::ACCESS_ERROR => return Ok(Self::Access(error.as_ref().try_into()?)),
-            xproto::ALLOC_ERROR => return Ok(Self::Alloc(error.as_ref().try_into()?)),
-            xproto::ATOM_ERROR => return Ok(Self::Atom(error.as_ref().try_into()?)),
-            xproto::COLORMAP_ERROR => return Ok(Self::Colormap(error.as_ref().try_into()?)),
-            xproto::CURSOR_ERROR => return Ok(Self::Cursor(error.as_ref().try_into()?)),
-            xproto::DRAWABLE_ERROR => return Ok(Self::Drawable(error.as_ref().try_into()?)),
-            xproto::FONT_ERROR => return Ok(Self::Font(error.as_ref().try_into()?)),
-            xproto::G_CONTEXT_ERROR => return Ok(Self::GContext(error.as_ref().try_into()?)),
-            xproto::ID_CHOICE_ERROR => return Ok(Self::IDChoice(error.as_ref().try_into()?)),
-            xproto::IMPLEMENTATION_ERROR => return Ok(Self::Implementation(error.as_ref().try_into()?)),
-            xproto::LENGTH_ERROR => return Ok(Self::Length(error.as_ref().try_into()?)),
-            xproto::MATCH_ERROR => return Ok(Self::Match(error.as_ref().try_into()?)),
-            xproto::NAME_ERROR => return Ok(Self::Name(error.as_ref().try_into()?)),
-            xproto::PIXMAP_ERROR => return Ok(Self::Pixmap(error.as_ref().try_into()?)),
-            xproto::REQUEST_ERROR => return Ok(Self::Request(error.as_ref().try_into()?)),
-            xproto::VALUE_ERROR => return Ok(Self::Value(error.as_ref().try_into()?)),
-            xproto::WINDOW_ERROR => return Ok(Self::Window(error.as_ref().try_into()?)),
+            xproto::ACCESS_ERROR => return Ok(Self::Access(xproto::AccessError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::ALLOC_ERROR => return Ok(Self::Alloc(xproto::AllocError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::ATOM_ERROR => return Ok(Self::Atom(xproto::AtomError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::COLORMAP_ERROR => return Ok(Self::Colormap(xproto::ColormapError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::CURSOR_ERROR => return Ok(Self::Cursor(xproto::CursorError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::DRAWABLE_ERROR => return Ok(Self::Drawable(xproto::DrawableError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::FONT_ERROR => return Ok(Self::Font(xproto::FontError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::G_CONTEXT_ERROR => return Ok(Self::GContext(xproto::GContextError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::ID_CHOICE_ERROR => return Ok(Self::IDChoice(xproto::IDChoiceError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::IMPLEMENTATION_ERROR => return Ok(Self::Implementation(xproto::ImplementationError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::LENGTH_ERROR => return Ok(Self::Length(xproto::LengthError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::MATCH_ERROR => return Ok(Self::Match(xproto::MatchError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::NAME_ERROR => return Ok(Self::Name(xproto::NameError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::PIXMAP_ERROR => return Ok(Self::Pixmap(xproto::PixmapError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::REQUEST_ERROR => return Ok(Self::Request(xproto::RequestError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::VALUE_ERROR => return Ok(Self::Value(xproto::ValueError::try_parse_with_order(error.as_ref(), order)?.0)),
+            xproto::WINDOW_ERROR => return Ok(Self::Window(xproto::WindowError::try_parse_with_order(error.as_ref(), order)?.0)),
             _ => {}
         }
 
@@ -218,125 +230,125 @@ impl<B: std::fmt::Debug + AsRef<[u8]>> Error<B> {
             #[cfg(feature = "damage")]
             Some((damage::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    damage::BAD_DAMAGE_ERROR => Ok(Self::DamageBadDamage(error.as_ref().try_into()?)),
+                    damage::BAD_DAMAGE_ERROR => Ok(Self::DamageBadDamage(damage::BadDamageError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "glx")]
             Some((glx::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    glx::BAD_CONTEXT_ERROR => Ok(Self::GlxBadContext(error.as_ref().try_into()?)),
-                    glx::BAD_CONTEXT_STATE_ERROR => Ok(Self::GlxBadContextState(error.as_ref().try_into()?)),
-                    glx::BAD_CONTEXT_TAG_ERROR => Ok(Self::GlxBadContextTag(error.as_ref().try_into()?)),
-                    glx::BAD_CURRENT_DRAWABLE_ERROR => Ok(Self::GlxBadCurrentDrawable(error.as_ref().try_into()?)),
-                    glx::BAD_CURRENT_WINDOW_ERROR => Ok(Self::GlxBadCurrentWindow(error.as_ref().try_into()?)),
-                    glx::BAD_DRAWABLE_ERROR => Ok(Self::GlxBadDrawable(error.as_ref().try_into()?)),
-                    glx::BAD_FB_CONFIG_ERROR => Ok(Self::GlxBadFBConfig(error.as_ref().try_into()?)),
-                    glx::BAD_LARGE_REQUEST_ERROR => Ok(Self::GlxBadLargeRequest(error.as_ref().try_into()?)),
-                    glx::BAD_PBUFFER_ERROR => Ok(Self::GlxBadPbuffer(error.as_ref().try_into()?)),
-                    glx::BAD_PIXMAP_ERROR => Ok(Self::GlxBadPixmap(error.as_ref().try_into()?)),
-                    glx::BAD_RENDER_REQUEST_ERROR => Ok(Self::GlxBadRenderRequest(error.as_ref().try_into()?)),
-                    glx::BAD_WINDOW_ERROR => Ok(Self::GlxBadWindow(error.as_ref().try_into()?)),
-                    glx::GLX_BAD_PROFILE_ARB_ERROR => Ok(Self::GlxGLXBadProfileARB(error.as_ref().try_into()?)),
-                    glx::UNSUPPORTED_PRIVATE_REQUEST_ERROR => Ok(Self::GlxUnsupportedPrivateRequest(error.as_ref().try_into()?)),
+                    glx::BAD_CONTEXT_ERROR => Ok(Self::GlxBadContext(glx::BadContextError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_CONTEXT_STATE_ERROR => Ok(Self::GlxBadContextState(glx::BadContextStateError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_CONTEXT_TAG_ERROR => Ok(Self::GlxBadContextTag(glx::BadContextTagError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_CURRENT_DRAWABLE_ERROR => Ok(Self::GlxBadCurrentDrawable(glx::BadCurrentDrawableError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_CURRENT_WINDOW_ERROR => Ok(Self::GlxBadCurrentWindow(glx::BadCurrentWindowError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_DRAWABLE_ERROR => Ok(Self::GlxBadDrawable(glx::BadDrawableError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_FB_CONFIG_ERROR => Ok(Self::GlxBadFBConfig(glx::BadFBConfigError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_LARGE_REQUEST_ERROR => Ok(Self::GlxBadLargeRequest(glx::BadLargeRequestError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_PBUFFER_ERROR => Ok(Self::GlxBadPbuffer(glx::BadPbufferError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_PIXMAP_ERROR => Ok(Self::GlxBadPixmap(glx::BadPixmapError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_RENDER_REQUEST_ERROR => Ok(Self::GlxBadRenderRequest(glx::BadRenderRequestError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::BAD_WINDOW_ERROR => Ok(Self::GlxBadWindow(glx::BadWindowError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::GLX_BAD_PROFILE_ARB_ERROR => Ok(Self::GlxGLXBadProfileARB(glx::GLXBadProfileARBError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    glx::UNSUPPORTED_PRIVATE_REQUEST_ERROR => Ok(Self::GlxUnsupportedPrivateRequest(glx::UnsupportedPrivateRequestError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "randr")]
             Some((randr::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    randr::BAD_CRTC_ERROR => Ok(Self::RandrBadCrtc(error.as_ref().try_into()?)),
-                    randr::BAD_MODE_ERROR => Ok(Self::RandrBadMode(error.as_ref().try_into()?)),
-                    randr::BAD_OUTPUT_ERROR => Ok(Self::RandrBadOutput(error.as_ref().try_into()?)),
-                    randr::BAD_PROVIDER_ERROR => Ok(Self::RandrBadProvider(error.as_ref().try_into()?)),
+                    randr::BAD_CRTC_ERROR => Ok(Self::RandrBadCrtc(randr::BadCrtcError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    randr::BAD_MODE_ERROR => Ok(Self::RandrBadMode(randr::BadModeError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    randr::BAD_OUTPUT_ERROR => Ok(Self::RandrBadOutput(randr::BadOutputError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    randr::BAD_PROVIDER_ERROR => Ok(Self::RandrBadProvider(randr::BadProviderError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "record")]
             Some((record::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    record::BAD_CONTEXT_ERROR => Ok(Self::RecordBadContext(error.as_ref().try_into()?)),
+                    record::BAD_CONTEXT_ERROR => Ok(Self::RecordBadContext(record::BadContextError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "render")]
             Some((render::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    render::GLYPH_ERROR => Ok(Self::RenderGlyph(error.as_ref().try_into()?)),
-                    render::GLYPH_SET_ERROR => Ok(Self::RenderGlyphSet(error.as_ref().try_into()?)),
-                    render::PICT_FORMAT_ERROR => Ok(Self::RenderPictFormat(error.as_ref().try_into()?)),
-                    render::PICT_OP_ERROR => Ok(Self::RenderPictOp(error.as_ref().try_into()?)),
-                    render::PICTURE_ERROR => Ok(Self::RenderPicture(error.as_ref().try_into()?)),
+                    render::GLYPH_ERROR => Ok(Self::RenderGlyph(render::GlyphError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    render::GLYPH_SET_ERROR => Ok(Self::RenderGlyphSet(render::GlyphSetError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    render::PICT_FORMAT_ERROR => Ok(Self::RenderPictFormat(render::PictFormatError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    render::PICT_OP_ERROR => Ok(Self::RenderPictOp(render::PictOpError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    render::PICTURE_ERROR => Ok(Self::RenderPicture(render::PictureError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "shm")]
             Some((shm::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    shm::BAD_SEG_ERROR => Ok(Self::ShmBadSeg(error.as_ref().try_into()?)),
+                    shm::BAD_SEG_ERROR => Ok(Self::ShmBadSeg(shm::BadSegError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "sync")]
             Some((sync::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    sync::ALARM_ERROR => Ok(Self::SyncAlarm(error.as_ref().try_into()?)),
-                    sync::COUNTER_ERROR => Ok(Self::SyncCounter(error.as_ref().try_into()?)),
+                    sync::ALARM_ERROR => Ok(Self::SyncAlarm(sync::AlarmError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    sync::COUNTER_ERROR => Ok(Self::SyncCounter(sync::CounterError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "xf86vidmode")]
             Some((xf86vidmode::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    xf86vidmode::BAD_CLOCK_ERROR => Ok(Self::Xf86vidmodeBadClock(error.as_ref().try_into()?)),
-                    xf86vidmode::BAD_H_TIMINGS_ERROR => Ok(Self::Xf86vidmodeBadHTimings(error.as_ref().try_into()?)),
-                    xf86vidmode::BAD_V_TIMINGS_ERROR => Ok(Self::Xf86vidmodeBadVTimings(error.as_ref().try_into()?)),
-                    xf86vidmode::CLIENT_NOT_LOCAL_ERROR => Ok(Self::Xf86vidmodeClientNotLocal(error.as_ref().try_into()?)),
-                    xf86vidmode::EXTENSION_DISABLED_ERROR => Ok(Self::Xf86vidmodeExtensionDisabled(error.as_ref().try_into()?)),
-                    xf86vidmode::MODE_UNSUITABLE_ERROR => Ok(Self::Xf86vidmodeModeUnsuitable(error.as_ref().try_into()?)),
-                    xf86vidmode::ZOOM_LOCKED_ERROR => Ok(Self::Xf86vidmodeZoomLocked(error.as_ref().try_into()?)),
+                    xf86vidmode::BAD_CLOCK_ERROR => Ok(Self::Xf86vidmodeBadClock(xf86vidmode::BadClockError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xf86vidmode::BAD_H_TIMINGS_ERROR => Ok(Self::Xf86vidmodeBadHTimings(xf86vidmode::BadHTimingsError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xf86vidmode::BAD_V_TIMINGS_ERROR => Ok(Self::Xf86vidmodeBadVTimings(xf86vidmode::BadVTimingsError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xf86vidmode::CLIENT_NOT_LOCAL_ERROR => Ok(Self::Xf86vidmodeClientNotLocal(xf86vidmode::ClientNotLocalError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xf86vidmode::EXTENSION_DISABLED_ERROR => Ok(Self::Xf86vidmodeExtensionDisabled(xf86vidmode::ExtensionDisabledError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xf86vidmode::MODE_UNSUITABLE_ERROR => Ok(Self::Xf86vidmodeModeUnsuitable(xf86vidmode::ModeUnsuitableError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xf86vidmode::ZOOM_LOCKED_ERROR => Ok(Self::Xf86vidmodeZoomLocked(xf86vidmode::ZoomLockedError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "xfixes")]
             Some((xfixes::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    xfixes::BAD_REGION_ERROR => Ok(Self::XfixesBadRegion(error.as_ref().try_into()?)),
+                    xfixes::BAD_REGION_ERROR => Ok(Self::XfixesBadRegion(xfixes::BadRegionError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "xinput")]
             Some((xinput::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    xinput::CLASS_ERROR => Ok(Self::XinputClass(error.as_ref().try_into()?)),
-                    xinput::DEVICE_ERROR => Ok(Self::XinputDevice(error.as_ref().try_into()?)),
-                    xinput::DEVICE_BUSY_ERROR => Ok(Self::XinputDeviceBusy(error.as_ref().try_into()?)),
-                    xinput::EVENT_ERROR => Ok(Self::XinputEvent(error.as_ref().try_into()?)),
-                    xinput::MODE_ERROR => Ok(Self::XinputMode(error.as_ref().try_into()?)),
+                    xinput::CLASS_ERROR => Ok(Self::XinputClass(xinput::ClassError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xinput::DEVICE_ERROR => Ok(Self::XinputDevice(xinput::DeviceError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xinput::DEVICE_BUSY_ERROR => Ok(Self::XinputDeviceBusy(xinput::DeviceBusyError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xinput::EVENT_ERROR => Ok(Self::XinputEvent(xinput::EventError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xinput::MODE_ERROR => Ok(Self::XinputMode(xinput::ModeError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "xkb")]
             Some((xkb::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    xkb::KEYBOARD_ERROR => Ok(Self::XkbKeyboard(error.as_ref().try_into()?)),
+                    xkb::KEYBOARD_ERROR => Ok(Self::XkbKeyboard(xkb::KeyboardError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "xprint")]
             Some((xprint::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    xprint::BAD_CONTEXT_ERROR => Ok(Self::XprintBadContext(error.as_ref().try_into()?)),
-                    xprint::BAD_SEQUENCE_ERROR => Ok(Self::XprintBadSequence(error.as_ref().try_into()?)),
+                    xprint::BAD_CONTEXT_ERROR => Ok(Self::XprintBadContext(xprint::BadContextError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xprint::BAD_SEQUENCE_ERROR => Ok(Self::XprintBadSequence(xprint::BadSequenceError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
             #[cfg(feature = "xv")]
             Some((xv::X11_EXTENSION_NAME, ext_info)) => {
                 match error_code - ext_info.first_error {
-                    xv::BAD_CONTROL_ERROR => Ok(Self::XvBadControl(error.as_ref().try_into()?)),
-                    xv::BAD_ENCODING_ERROR => Ok(Self::XvBadEncoding(error.as_ref().try_into()?)),
-                    xv::BAD_PORT_ERROR => Ok(Self::XvBadPort(error.as_ref().try_into()?)),
+                    xv::BAD_CONTROL_ERROR => Ok(Self::XvBadControl(xv::BadControlError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xv::BAD_ENCODING_ERROR => Ok(Self::XvBadEncoding(xv::BadEncodingError::try_parse_with_order(error.as_ref(), order)?.0)),
+                    xv::BAD_PORT_ERROR => Ok(Self::XvBadPort(xv::BadPortError::try_parse_with_order(error.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(error)),
                 }
             }
@@ -345,9 +357,17 @@ impl<B: std::fmt::Debug + AsRef<[u8]>> Error<B> {
     }
 
     /// Get the sequence number contained in this X11 error
+    ///
+    /// The sequence number of an unknown error is read in the native byte order.
     pub fn wire_sequence_number(&self) -> u16 {
+        self.wire_sequence_number_with_order(ByteOrder::native())
+    }
+
+    /// Get the sequence number contained in this X11 error, reading the sequence number of
+    /// an unknown error in the given byte order.
+    pub fn wire_sequence_number_with_order(&self, order: ByteOrder) -> u16 {
         match self {
-            Error::Unknown(value) => sequence_number(value.as_ref()).unwrap(),
+            Error::Unknown(value) => sequence_number(value.as_ref(), order).unwrap(),
             Error::Access(value) => value.sequence,
             Error::Alloc(value) => value.sequence,
             Error::Atom(value) => value.sequence,
@@ -898,52 +918,262 @@ pub enum Event<B: std::fmt::Debug + AsRef<[u8]>> {
     XvVideoNotify(xv::VideoNotifyEvent),
 }
 
+/// The kind of an X11 event, without any of the event's data.
+///
+/// There is one variant for every variant of `Event`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EventKind {
+    Unknown,
+    Error,
+    ButtonPress,
+    ButtonRelease,
+    CirculateNotify,
+    CirculateRequest,
+    ClientMessage,
+    ColormapNotify,
+    ConfigureNotify,
+    ConfigureRequest,
+    CreateNotify,
+    DestroyNotify,
+    EnterNotify,
+    Expose,
+    FocusIn,
+    FocusOut,
+    GeGeneric,
+    GraphicsExposure,
+    GravityNotify,
+    KeyPress,
+    KeyRelease,
+    KeymapNotify,
+    LeaveNotify,
+    MapNotify,
+    MapRequest,
+    MappingNotify,
+    MotionNotify,
+    NoExposure,
+    PropertyNotify,
+    ReparentNotify,
+    ResizeRequest,
+    SelectionClear,
+    SelectionNotify,
+    SelectionRequest,
+    UnmapNotify,
+    VisibilityNotify,
+    #[cfg(feature = "damage")]
+    DamageNotify,
+    #[cfg(feature = "dri2")]
+    Dri2BufferSwapComplete,
+    #[cfg(feature = "dri2")]
+    Dri2InvalidateBuffers,
+    #[cfg(feature = "glx")]
+    GlxBufferSwapComplete,
+    #[cfg(feature = "glx")]
+    GlxPbufferClobber,
+    #[cfg(feature = "present")]
+    PresentCompleteNotify,
+    #[cfg(feature = "present")]
+    PresentConfigureNotify,
+    #[cfg(feature = "present")]
+    PresentGeneric,
+    #[cfg(feature = "present")]
+    PresentIdleNotify,
+    #[cfg(feature = "present")]
+    PresentRedirectNotify,
+    #[cfg(feature = "randr")]
+    RandrNotify,
+    #[cfg(feature = "randr")]
+    RandrScreenChangeNotify,
+    #[cfg(feature = "screensaver")]
+    ScreensaverNotify,
+    #[cfg(feature = "shape")]
+    ShapeNotify,
+    #[cfg(feature = "shm")]
+    ShmCompletion,
+    #[cfg(feature = "sync")]
+    SyncAlarmNotify,
+    #[cfg(feature = "sync")]
+    SyncCounterNotify,
+    #[cfg(feature = "xfixes")]
+    XfixesCursorNotify,
+    #[cfg(feature = "xfixes")]
+    XfixesSelectionNotify,
+    #[cfg(feature = "xinput")]
+    XinputBarrierHit,
+    #[cfg(feature = "xinput")]
+    XinputBarrierLeave,
+    #[cfg(feature = "xinput")]
+    XinputButtonPress,
+    #[cfg(feature = "xinput")]
+    XinputButtonRelease,
+    #[cfg(feature = "xinput")]
+    XinputChangeDeviceNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceButtonPress,
+    #[cfg(feature = "xinput")]
+    XinputDeviceButtonRelease,
+    #[cfg(feature = "xinput")]
+    XinputDeviceButtonStateNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceChanged,
+    #[cfg(feature = "xinput")]
+    XinputDeviceFocusIn,
+    #[cfg(feature = "xinput")]
+    XinputDeviceFocusOut,
+    #[cfg(feature = "xinput")]
+    XinputDeviceKeyPress,
+    #[cfg(feature = "xinput")]
+    XinputDeviceKeyRelease,
+    #[cfg(feature = "xinput")]
+    XinputDeviceKeyStateNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceMappingNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceMotionNotify,
+    #[cfg(feature = "xinput")]
+    XinputDevicePresenceNotify,
+    #[cfg(feature = "xinput")]
+    XinputDevicePropertyNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceStateNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceValuator,
+    #[cfg(feature = "xinput")]
+    XinputEnter,
+    #[cfg(feature = "xinput")]
+    XinputFocusIn,
+    #[cfg(feature = "xinput")]
+    XinputFocusOut,
+    #[cfg(feature = "xinput")]
+    XinputHierarchy,
+    #[cfg(feature = "xinput")]
+    XinputKeyPress,
+    #[cfg(feature = "xinput")]
+    XinputKeyRelease,
+    #[cfg(feature = "xinput")]
+    XinputLeave,
+    #[cfg(feature = "xinput")]
+    XinputMotion,
+    #[cfg(feature = "xinput")]
+    XinputProperty,
+    #[cfg(feature = "xinput")]
+    XinputProximityIn,
+    #[cfg(feature = "xinput")]
+    XinputProximityOut,
+    #[cfg(feature = "xinput")]
+    XinputRawButtonPress,
+    #[cfg(feature = "xinput")]
+    XinputRawButtonRelease,
+    #[cfg(feature = "xinput")]
+    XinputRawKeyPress,
+    #[cfg(feature = "xinput")]
+    XinputRawKeyRelease,
+    #[cfg(feature = "xinput")]
+    XinputRawMotion,
+    #[cfg(feature = "xinput")]
+    XinputRawTouchBegin,
+    #[cfg(feature = "xinput")]
+    XinputRawTouchEnd,
+    #[cfg(feature = "xinput")]
+    XinputRawTouchUpdate,
+    #[cfg(feature = "xinput")]
+    XinputTouchBegin,
+    #[cfg(feature = "xinput")]
+    XinputTouchEnd,
+    #[cfg(feature = "xinput")]
+    XinputTouchOwnership,
+    #[cfg(feature = "xinput")]
+    XinputTouchUpdate,
+    #[cfg(feature = "xkb")]
+    XkbAccessXNotify,
+    #[cfg(feature = "xkb")]
+    XkbActionMessage,
+    #[cfg(feature = "xkb")]
+    XkbBellNotify,
+    #[cfg(feature = "xkb")]
+    XkbCompatMapNotify,
+    #[cfg(feature = "xkb")]
+    XkbControlsNotify,
+    #[cfg(feature = "xkb")]
+    XkbExtensionDeviceNotify,
+    #[cfg(feature = "xkb")]
+    XkbIndicatorMapNotify,
+    #[cfg(feature = "xkb")]
+    XkbIndicatorStateNotify,
+    #[cfg(feature = "xkb")]
+    XkbMapNotify,
+    #[cfg(feature = "xkb")]
+    XkbNamesNotify,
+    #[cfg(feature = "xkb")]
+    XkbNewKeyboardNotify,
+    #[cfg(feature = "xkb")]
+    XkbStateNotify,
+    #[cfg(feature = "xprint")]
+    XprintAttributNotify,
+    #[cfg(feature = "xprint")]
+    XprintNotify,
+    #[cfg(feature = "xv")]
+    XvPortNotify,
+    #[cfg(feature = "xv")]
+    XvVideoNotify,
+}
+
 impl<B: std::fmt::Debug + AsRef<[u8]>> Event<B> {
     /// Parse a generic X11 event into a concrete event type.
-    #[allow(clippy::cognitive_complexity)]
+    ///
+    /// The event is expected to be encoded in the native byte order.
     pub fn parse(
         event: B,
         ext_info_provider: &dyn ExtInfoProvider,
+    ) -> Result<Self, ParseError> {
+        Self::parse_with_order(event, ext_info_provider, ByteOrder::native())
+    }
+
+    /// Parse a generic X11 event that is encoded in the given byte order.
+    #[allow(clippy::cognitive_complexity)]
+    pub fn parse_with_order(
+        event: B,
+        ext_info_provider: &dyn ExtInfoProvider,
+        order: ByteOrder,
     ) -> Result<Self, ParseError> {
         let event_code = response_type(event.as_ref())?;
 
         // Check if this is a core protocol event or error, or from the generic event extension
         match event_code {
-            0 => return Ok(Self::Error(Error::parse(event, ext_info_provider)?)),
-            xproto::BUTTON_PRESS_EVENT => return Ok(Self::ButtonPress(event.as_ref().try_into()?)),
-            xproto::BUTTON_RELEASE_EVENT => return Ok(Self::ButtonRelease(event.as_ref().try_into()?)),
-            xproto::CIRCULATE_NOTIFY_EVENT => return Ok(Self::CirculateNotify(event.as_ref().try_into()?)),
-            xproto::CIRCULATE_REQUEST_EVENT => return Ok(Self::CirculateRequest(event.as_ref().try_into()?)),
-            xproto::CLIENT_MESSAGE_EVENT => return Ok(Self::ClientMessage(event.as_ref().try_into()?)),
-            xproto::COLORMAP_NOTIFY_EVENT => return Ok(Self::ColormapNotify(event.as_ref().try_into()?)),
-            xproto::CONFIGURE_NOTIFY_EVENT => return Ok(Self::ConfigureNotify(event.as_ref().try_into()?)),
-            xproto::CONFIGURE_REQUEST_EVENT => return Ok(Self::ConfigureRequest(event.as_ref().try_into()?)),
-            xproto::CREATE_NOTIFY_EVENT => return Ok(Self::CreateNotify(event.as_ref().try_into()?)),
-            xproto::DESTROY_NOTIFY_EVENT => return Ok(Self::DestroyNotify(event.as_ref().try_into()?)),
-            xproto::ENTER_NOTIFY_EVENT => return Ok(Self::EnterNotify(event.as_ref().try_into()?)),
-            xproto::EXPOSE_EVENT => return Ok(Self::Expose(event.as_ref().try_into()?)),
-            xproto::FOCUS_IN_EVENT => return Ok(Self::FocusIn(event.as_ref().try_into()?)),
-            xproto::FOCUS_OUT_EVENT => return Ok(Self::FocusOut(event.as_ref().try_into()?)),
-            xproto::GRAPHICS_EXPOSURE_EVENT => return Ok(Self::GraphicsExposure(event.as_ref().try_into()?)),
-            xproto::GRAVITY_NOTIFY_EVENT => return Ok(Self::GravityNotify(event.as_ref().try_into()?)),
-            xproto::KEY_PRESS_EVENT => return Ok(Self::KeyPress(event.as_ref().try_into()?)),
-            xproto::KEY_RELEASE_EVENT => return Ok(Self::KeyRelease(event.as_ref().try_into()?)),
-            xproto::KEYMAP_NOTIFY_EVENT => return Ok(Self::KeymapNotify(event.as_ref().try_into()?)),
-            xproto::LEAVE_NOTIFY_EVENT => return Ok(Self::LeaveNotify(event.as_ref().try_into()?)),
-            xproto::MAP_NOTIFY_EVENT => return Ok(Self::MapNotify(event.as_ref().try_into()?)),
-            xproto::MAP_REQUEST_EVENT => return Ok(Self::MapRequest(event.as_ref().try_into()?)),
-            xproto::MAPPING_NOTIFY_EVENT => return Ok(Self::MappingNotify(event.as_ref().try_into()?)),
-            xproto::MOTION_NOTIFY_EVENT => return Ok(Self::MotionNotify(event.as_ref().try_into()?)),
-            xproto::NO_EXPOSURE_EVENT => return Ok(Self::NoExposure(event.as_ref().try_into()?)),
-            xproto::PROPERTY_NOTIFY_EVENT => return Ok(Self::PropertyNotify(event.as_ref().try_into()?)),
-            xproto::REPARENT_NOTIFY_EVENT => return Ok(Self::ReparentNotify(event.as_ref().try_into()?)),
-            xproto::RESIZE_REQUEST_EVENT => return Ok(Self::ResizeRequest(event.as_ref().try_into()?)),
-            xproto::SELECTION_CLEAR_EVENT => return Ok(Self::SelectionClear(event.as_ref().try_into()?)),
-            xproto::SELECTION_NOTIFY_EVENT => return Ok(Self::SelectionNotify(event.as_ref().try_into()?)),
-            xproto::SELECTION_REQUEST_EVENT => return Ok(Self::SelectionRequest(event.as_ref().try_into()?)),
-            xproto::UNMAP_NOTIFY_EVENT => return Ok(Self::UnmapNotify(event.as_ref().try_into()?)),
-            xproto::VISIBILITY_NOTIFY_EVENT => return Ok(Self::VisibilityNotify(event.as_ref().try_into()?)),
-            xproto::GE_GENERIC_EVENT => return Self::from_generic_event(event, ext_info_provider),
+            0 => return Ok(Self::Error(Error::parse_with_order(event, ext_info_provider, order)?)),
+            xproto::BUTTON_PRESS_EVENT => return Ok(Self::ButtonPress(xproto::ButtonPressEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::BUTTON_RELEASE_EVENT => return Ok(Self::ButtonRelease(xproto::ButtonReleaseEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::CIRCULATE_NOTIFY_EVENT => return Ok(Self::CirculateNotify(xproto::CirculateNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::CIRCULATE_REQUEST_EVENT => return Ok(Self::CirculateRequest(xproto::CirculateRequestEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::CLIENT_MESSAGE_EVENT => return Ok(Self::ClientMessage(xproto::ClientMessageEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::COLORMAP_NOTIFY_EVENT => return Ok(Self::ColormapNotify(xproto::ColormapNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::CONFIGURE_NOTIFY_EVENT => return Ok(Self::ConfigureNotify(xproto::ConfigureNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::CONFIGURE_REQUEST_EVENT => return Ok(Self::ConfigureRequest(xproto::ConfigureRequestEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::CREATE_NOTIFY_EVENT => return Ok(Self::CreateNotify(xproto::CreateNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::DESTROY_NOTIFY_EVENT => return Ok(Self::DestroyNotify(xproto::DestroyNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::ENTER_NOTIFY_EVENT => return Ok(Self::EnterNotify(xproto::EnterNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::EXPOSE_EVENT => return Ok(Self::Expose(xproto::ExposeEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::FOCUS_IN_EVENT => return Ok(Self::FocusIn(xproto::FocusInEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::FOCUS_OUT_EVENT => return Ok(Self::FocusOut(xproto::FocusOutEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::GRAPHICS_EXPOSURE_EVENT => return Ok(Self::GraphicsExposure(xproto::GraphicsExposureEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::GRAVITY_NOTIFY_EVENT => return Ok(Self::GravityNotify(xproto::GravityNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::KEY_PRESS_EVENT => return Ok(Self::KeyPress(xproto::KeyPressEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::KEY_RELEASE_EVENT => return Ok(Self::KeyRelease(xproto::KeyReleaseEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::KEYMAP_NOTIFY_EVENT => return Ok(Self::KeymapNotify(xproto::KeymapNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::LEAVE_NOTIFY_EVENT => return Ok(Self::LeaveNotify(xproto::LeaveNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::MAP_NOTIFY_EVENT => return Ok(Self::MapNotify(xproto::MapNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::MAP_REQUEST_EVENT => return Ok(Self::MapRequest(xproto::MapRequestEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::MAPPING_NOTIFY_EVENT => return Ok(Self::MappingNotify(xproto::MappingNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::MOTION_NOTIFY_EVENT => return Ok(Self::MotionNotify(xproto::MotionNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::NO_EXPOSURE_EVENT => return Ok(Self::NoExposure(xproto::NoExposureEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::PROPERTY_NOTIFY_EVENT => return Ok(Self::PropertyNotify(xproto::PropertyNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::REPARENT_NOTIFY_EVENT => return Ok(Self::ReparentNotify(xproto::ReparentNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::RESIZE_REQUEST_EVENT => return Ok(Self::ResizeRequest(xproto::ResizeRequestEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::SELECTION_CLEAR_EVENT => return Ok(Self::SelectionClear(xproto::SelectionClearEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::SELECTION_NOTIFY_EVENT => return Ok(Self::SelectionNotify(xproto::SelectionNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::SELECTION_REQUEST_EVENT => return Ok(Self::SelectionRequest(xproto::SelectionRequestEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::UNMAP_NOTIFY_EVENT => return Ok(Self::UnmapNotify(xproto::UnmapNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::VISIBILITY_NOTIFY_EVENT => return Ok(Self::VisibilityNotify(xproto::VisibilityNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+            xproto::GE_GENERIC_EVENT => return Self::from_generic_event(event, ext_info_provider, order),
             _ => {}
         }
         // Find the extension that this event could belong to
@@ -952,98 +1182,98 @@ impl<B: std::fmt::Debug + AsRef<[u8]>> Event<B> {
             #[cfg(feature = "damage")]
             Some((damage::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    damage::NOTIFY_EVENT => Ok(Self::DamageNotify(event.as_ref().try_into()?)),
+                    damage::NOTIFY_EVENT => Ok(Self::DamageNotify(damage::NotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "dri2")]
             Some((dri2::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    dri2::BUFFER_SWAP_COMPLETE_EVENT => Ok(Self::Dri2BufferSwapComplete(event.as_ref().try_into()?)),
-                    dri2::INVALIDATE_BUFFERS_EVENT => Ok(Self::Dri2InvalidateBuffers(event.as_ref().try_into()?)),
+                    dri2::BUFFER_SWAP_COMPLETE_EVENT => Ok(Self::Dri2BufferSwapComplete(dri2::BufferSwapCompleteEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    dri2::INVALIDATE_BUFFERS_EVENT => Ok(Self::Dri2InvalidateBuffers(dri2::InvalidateBuffersEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "glx")]
             Some((glx::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    glx::BUFFER_SWAP_COMPLETE_EVENT => Ok(Self::GlxBufferSwapComplete(event.as_ref().try_into()?)),
-                    glx::PBUFFER_CLOBBER_EVENT => Ok(Self::GlxPbufferClobber(event.as_ref().try_into()?)),
+                    glx::BUFFER_SWAP_COMPLETE_EVENT => Ok(Self::GlxBufferSwapComplete(glx::BufferSwapCompleteEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    glx::PBUFFER_CLOBBER_EVENT => Ok(Self::GlxPbufferClobber(glx::PbufferClobberEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "present")]
             Some((present::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    present::GENERIC_EVENT => Ok(Self::PresentGeneric(event.as_ref().try_into()?)),
+                    present::GENERIC_EVENT => Ok(Self::PresentGeneric(present::GenericEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "randr")]
             Some((randr::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    randr::NOTIFY_EVENT => Ok(Self::RandrNotify(event.as_ref().try_into()?)),
-                    randr::SCREEN_CHANGE_NOTIFY_EVENT => Ok(Self::RandrScreenChangeNotify(event.as_ref().try_into()?)),
+                    randr::NOTIFY_EVENT => Ok(Self::RandrNotify(randr::NotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    randr::SCREEN_CHANGE_NOTIFY_EVENT => Ok(Self::RandrScreenChangeNotify(randr::ScreenChangeNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "screensaver")]
             Some((screensaver::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    screensaver::NOTIFY_EVENT => Ok(Self::ScreensaverNotify(event.as_ref().try_into()?)),
+                    screensaver::NOTIFY_EVENT => Ok(Self::ScreensaverNotify(screensaver::NotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "shape")]
             Some((shape::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    shape::NOTIFY_EVENT => Ok(Self::ShapeNotify(event.as_ref().try_into()?)),
+                    shape::NOTIFY_EVENT => Ok(Self::ShapeNotify(shape::NotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "shm")]
             Some((shm::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    shm::COMPLETION_EVENT => Ok(Self::ShmCompletion(event.as_ref().try_into()?)),
+                    shm::COMPLETION_EVENT => Ok(Self::ShmCompletion(shm::CompletionEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "sync")]
             Some((sync::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    sync::ALARM_NOTIFY_EVENT => Ok(Self::SyncAlarmNotify(event.as_ref().try_into()?)),
-                    sync::COUNTER_NOTIFY_EVENT => Ok(Self::SyncCounterNotify(event.as_ref().try_into()?)),
+                    sync::ALARM_NOTIFY_EVENT => Ok(Self::SyncAlarmNotify(sync::AlarmNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    sync::COUNTER_NOTIFY_EVENT => Ok(Self::SyncCounterNotify(sync::CounterNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "xfixes")]
             Some((xfixes::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    xfixes::CURSOR_NOTIFY_EVENT => Ok(Self::XfixesCursorNotify(event.as_ref().try_into()?)),
-                    xfixes::SELECTION_NOTIFY_EVENT => Ok(Self::XfixesSelectionNotify(event.as_ref().try_into()?)),
+                    xfixes::CURSOR_NOTIFY_EVENT => Ok(Self::XfixesCursorNotify(xfixes::CursorNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xfixes::SELECTION_NOTIFY_EVENT => Ok(Self::XfixesSelectionNotify(xfixes::SelectionNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "xinput")]
             Some((xinput::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    xinput::CHANGE_DEVICE_NOTIFY_EVENT => Ok(Self::XinputChangeDeviceNotify(event.as_ref().try_into()?)),
-                    xinput::DEVICE_BUTTON_PRESS_EVENT => Ok(Self::XinputDeviceButtonPress(event.as_ref().try_into()?)),
-                    xinput::DEVICE_BUTTON_RELEASE_EVENT => Ok(Self::XinputDeviceButtonRelease(event.as_ref().try_into()?)),
-                    xinput::DEVICE_BUTTON_STATE_NOTIFY_EVENT => Ok(Self::XinputDeviceButtonStateNotify(event.as_ref().try_into()?)),
-                    xinput::DEVICE_FOCUS_IN_EVENT => Ok(Self::XinputDeviceFocusIn(event.as_ref().try_into()?)),
-                    xinput::DEVICE_FOCUS_OUT_EVENT => Ok(Self::XinputDeviceFocusOut(event.as_ref().try_into()?)),
-                    xinput::DEVICE_KEY_PRESS_EVENT => Ok(Self::XinputDeviceKeyPress(event.as_ref().try_into()?)),
-                    xinput::DEVICE_KEY_RELEASE_EVENT => Ok(Self::XinputDeviceKeyRelease(event.as_ref().try_into()?)),
-                    xinput::DEVICE_KEY_STATE_NOTIFY_EVENT => Ok(Self::XinputDeviceKeyStateNotify(event.as_ref().try_into()?)),
-                    xinput::DEVICE_MAPPING_NOTIFY_EVENT => Ok(Self::XinputDeviceMappingNotify(event.as_ref().try_into()?)),
-                    xinput::DEVICE_MOTION_NOTIFY_EVENT => Ok(Self::XinputDeviceMotionNotify(event.as_ref().try_into()?)),
-                    xinput::DEVICE_PRESENCE_NOTIFY_EVENT => Ok(Self::XinputDevicePresenceNotify(event.as_ref().try_into()?)),
-                    xinput::DEVICE_PROPERTY_NOTIFY_EVENT => Ok(Self::XinputDevicePropertyNotify(event.as_ref().try_into()?)),
-                    xinput::DEVICE_STATE_NOTIFY_EVENT => Ok(Self::XinputDeviceStateNotify(event.as_ref().try_into()?)),
-                    xinput::DEVICE_VALUATOR_EVENT => Ok(Self::XinputDeviceValuator(event.as_ref().try_into()?)),
-                    xinput::PROXIMITY_IN_EVENT => Ok(Self::XinputProximityIn(event.as_ref().try_into()?)),
-                    xinput::PROXIMITY_OUT_EVENT => Ok(Self::XinputProximityOut(event.as_ref().try_into()?)),
+                    xinput::CHANGE_DEVICE_NOTIFY_EVENT => Ok(Self::XinputChangeDeviceNotify(xinput::ChangeDeviceNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_BUTTON_PRESS_EVENT => Ok(Self::XinputDeviceButtonPress(xinput::DeviceButtonPressEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_BUTTON_RELEASE_EVENT => Ok(Self::XinputDeviceButtonRelease(xinput::DeviceButtonReleaseEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_BUTTON_STATE_NOTIFY_EVENT => Ok(Self::XinputDeviceButtonStateNotify(xinput::DeviceButtonStateNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_FOCUS_IN_EVENT => Ok(Self::XinputDeviceFocusIn(xinput::DeviceFocusInEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_FOCUS_OUT_EVENT => Ok(Self::XinputDeviceFocusOut(xinput::DeviceFocusOutEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_KEY_PRESS_EVENT => Ok(Self::XinputDeviceKeyPress(xinput::DeviceKeyPressEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_KEY_RELEASE_EVENT => Ok(Self::XinputDeviceKeyRelease(xinput::DeviceKeyReleaseEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_KEY_STATE_NOTIFY_EVENT => Ok(Self::XinputDeviceKeyStateNotify(xinput::DeviceKeyStateNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_MAPPING_NOTIFY_EVENT => Ok(Self::XinputDeviceMappingNotify(xinput::DeviceMappingNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_MOTION_NOTIFY_EVENT => Ok(Self::XinputDeviceMotionNotify(xinput::DeviceMotionNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_PRESENCE_NOTIFY_EVENT => Ok(Self::XinputDevicePresenceNotify(xinput::DevicePresenceNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_PROPERTY_NOTIFY_EVENT => Ok(Self::XinputDevicePropertyNotify(xinput::DevicePropertyNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_STATE_NOTIFY_EVENT => Ok(Self::XinputDeviceStateNotify(xinput::DeviceStateNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_VALUATOR_EVENT => Ok(Self::XinputDeviceValuator(xinput::DeviceValuatorEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::PROXIMITY_IN_EVENT => Ok(Self::XinputProximityIn(xinput::ProximityInEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::PROXIMITY_OUT_EVENT => Ok(Self::XinputProximityOut(xinput::ProximityOutEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
@@ -1053,34 +1283,34 @@ impl<B: std::fmt::Debug + AsRef<[u8]>> Event<B> {
                     return Ok(Self::Unknown(event));
                 }
                 match *event.as_ref().get(1).ok_or(ParseError::ParseError)? {
-                    xkb::ACCESS_X_NOTIFY_EVENT => Ok(Self::XkbAccessXNotify(event.as_ref().try_into()?)),
-                    xkb::ACTION_MESSAGE_EVENT => Ok(Self::XkbActionMessage(event.as_ref().try_into()?)),
-                    xkb::BELL_NOTIFY_EVENT => Ok(Self::XkbBellNotify(event.as_ref().try_into()?)),
-                    xkb::COMPAT_MAP_NOTIFY_EVENT => Ok(Self::XkbCompatMapNotify(event.as_ref().try_into()?)),
-                    xkb::CONTROLS_NOTIFY_EVENT => Ok(Self::XkbControlsNotify(event.as_ref().try_into()?)),
-                    xkb::EXTENSION_DEVICE_NOTIFY_EVENT => Ok(Self::XkbExtensionDeviceNotify(event.as_ref().try_into()?)),
-                    xkb::INDICATOR_MAP_NOTIFY_EVENT => Ok(Self::XkbIndicatorMapNotify(event.as_ref().try_into()?)),
-                    xkb::INDICATOR_STATE_NOTIFY_EVENT => Ok(Self::XkbIndicatorStateNotify(event.as_ref().try_into()?)),
-                    xkb::MAP_NOTIFY_EVENT => Ok(Self::XkbMapNotify(event.as_ref().try_into()?)),
-                    xkb::NAMES_NOTIFY_EVENT => Ok(Self::XkbNamesNotify(event.as_ref().try_into()?)),
-                    xkb::NEW_KEYBOARD_NOTIFY_EVENT => Ok(Self::XkbNewKeyboardNotify(event.as_ref().try_into()?)),
-                    xkb::STATE_NOTIFY_EVENT => Ok(Self::XkbStateNotify(event.as_ref().try_into()?)),
+                    xkb::ACCESS_X_NOTIFY_EVENT => Ok(Self::XkbAccessXNotify(xkb::AccessXNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::ACTION_MESSAGE_EVENT => Ok(Self::XkbActionMessage(xkb::ActionMessageEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::BELL_NOTIFY_EVENT => Ok(Self::XkbBellNotify(xkb::BellNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::COMPAT_MAP_NOTIFY_EVENT => Ok(Self::XkbCompatMapNotify(xkb::CompatMapNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::CONTROLS_NOTIFY_EVENT => Ok(Self::XkbControlsNotify(xkb::ControlsNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::EXTENSION_DEVICE_NOTIFY_EVENT => Ok(Self::XkbExtensionDeviceNotify(xkb::ExtensionDeviceNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::INDICATOR_MAP_NOTIFY_EVENT => Ok(Self::XkbIndicatorMapNotify(xkb::IndicatorMapNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::INDICATOR_STATE_NOTIFY_EVENT => Ok(Self::XkbIndicatorStateNotify(xkb::IndicatorStateNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::MAP_NOTIFY_EVENT => Ok(Self::XkbMapNotify(xkb::MapNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::NAMES_NOTIFY_EVENT => Ok(Self::XkbNamesNotify(xkb::NamesNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::NEW_KEYBOARD_NOTIFY_EVENT => Ok(Self::XkbNewKeyboardNotify(xkb::NewKeyboardNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xkb::STATE_NOTIFY_EVENT => Ok(Self::XkbStateNotify(xkb::StateNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "xprint")]
             Some((xprint::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    xprint::ATTRIBUT_NOTIFY_EVENT => Ok(Self::XprintAttributNotify(event.as_ref().try_into()?)),
-                    xprint::NOTIFY_EVENT => Ok(Self::XprintNotify(event.as_ref().try_into()?)),
+                    xprint::ATTRIBUT_NOTIFY_EVENT => Ok(Self::XprintAttributNotify(xprint::AttributNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xprint::NOTIFY_EVENT => Ok(Self::XprintNotify(xprint::NotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "xv")]
             Some((xv::X11_EXTENSION_NAME, ext_info)) => {
                 match event_code - ext_info.first_event {
-                    xv::PORT_NOTIFY_EVENT => Ok(Self::XvPortNotify(event.as_ref().try_into()?)),
-                    xv::VIDEO_NOTIFY_EVENT => Ok(Self::XvVideoNotify(event.as_ref().try_into()?)),
+                    xv::PORT_NOTIFY_EVENT => Ok(Self::XvPortNotify(xv::PortNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xv::VIDEO_NOTIFY_EVENT => Ok(Self::XvVideoNotify(xv::VideoNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
@@ -1091,8 +1321,9 @@ impl<B: std::fmt::Debug + AsRef<[u8]>> Event<B> {
     fn from_generic_event(
         event: B,
         ext_info_provider: &dyn ExtInfoProvider,
+        order: ByteOrder,
     ) -> Result<Self, ParseError> {
-        let ge_event = xproto::GeGenericEvent::try_from(event.as_ref())?;
+        let ge_event = xproto::GeGenericEvent::try_parse_with_order(event.as_ref(), order)?.0;
         let ext_name = ext_info_provider
             .get_from_major_opcode(ge_event.extension)
             .map(|(name, _)| name);
@@ -1100,42 +1331,42 @@ impl<B: std::fmt::Debug + AsRef<[u8]>> Event<B> {
             #[cfg(feature = "present")]
             Some(present::X11_EXTENSION_NAME) => {
                 match ge_event.event_type {
-                    present::COMPLETE_NOTIFY_EVENT => Ok(Self::PresentCompleteNotify(event.as_ref().try_into()?)),
-                    present::CONFIGURE_NOTIFY_EVENT => Ok(Self::PresentConfigureNotify(event.as_ref().try_into()?)),
-                    present::IDLE_NOTIFY_EVENT => Ok(Self::PresentIdleNotify(event.as_ref().try_into()?)),
-                    present::REDIRECT_NOTIFY_EVENT => Ok(Self::PresentRedirectNotify(event.as_ref().try_into()?)),
+                    present::COMPLETE_NOTIFY_EVENT => Ok(Self::PresentCompleteNotify(present::CompleteNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    present::CONFIGURE_NOTIFY_EVENT => Ok(Self::PresentConfigureNotify(present::ConfigureNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    present::IDLE_NOTIFY_EVENT => Ok(Self::PresentIdleNotify(present::IdleNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    present::REDIRECT_NOTIFY_EVENT => Ok(Self::PresentRedirectNotify(present::RedirectNotifyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
             #[cfg(feature = "xinput")]
             Some(xinput::X11_EXTENSION_NAME) => {
                 match ge_event.event_type {
-                    xinput::BARRIER_HIT_EVENT => Ok(Self::XinputBarrierHit(event.as_ref().try_into()?)),
-                    xinput::BARRIER_LEAVE_EVENT => Ok(Self::XinputBarrierLeave(event.as_ref().try_into()?)),
-                    xinput::BUTTON_PRESS_EVENT => Ok(Self::XinputButtonPress(event.as_ref().try_into()?)),
-                    xinput::BUTTON_RELEASE_EVENT => Ok(Self::XinputButtonRelease(event.as_ref().try_into()?)),
-                    xinput::DEVICE_CHANGED_EVENT => Ok(Self::XinputDeviceChanged(event.as_ref().try_into()?)),
-                    xinput::ENTER_EVENT => Ok(Self::XinputEnter(event.as_ref().try_into()?)),
-                    xinput::FOCUS_IN_EVENT => Ok(Self::XinputFocusIn(event.as_ref().try_into()?)),
-                    xinput::FOCUS_OUT_EVENT => Ok(Self::XinputFocusOut(event.as_ref().try_into()?)),
-                    xinput::HIERARCHY_EVENT => Ok(Self::XinputHierarchy(event.as_ref().try_into()?)),
-                    xinput::KEY_PRESS_EVENT => Ok(Self::XinputKeyPress(event.as_ref().try_into()?)),
-                    xinput::KEY_RELEASE_EVENT => Ok(Self::XinputKeyRelease(event.as_ref().try_into()?)),
-                    xinput::LEAVE_EVENT => Ok(Self::XinputLeave(event.as_ref().try_into()?)),
-                    xinput::MOTION_EVENT => Ok(Self::XinputMotion(event.as_ref().try_into()?)),
-                    xinput::PROPERTY_EVENT => Ok(Self::XinputProperty(event.as_ref().try_into()?)),
-                    xinput::RAW_BUTTON_PRESS_EVENT => Ok(Self::XinputRawButtonPress(event.as_ref().try_into()?)),
-                    xinput::RAW_BUTTON_RELEASE_EVENT => Ok(Self::XinputRawButtonRelease(event.as_ref().try_into()?)),
-                    xinput::RAW_KEY_PRESS_EVENT => Ok(Self::XinputRawKeyPress(event.as_ref().try_into()?)),
-                    xinput::RAW_KEY_RELEASE_EVENT => Ok(Self::XinputRawKeyRelease(event.as_ref().try_into()?)),
-                    xinput::RAW_MOTION_EVENT => Ok(Self::XinputRawMotion(event.as_ref().try_into()?)),
-                    xinput::RAW_TOUCH_BEGIN_EVENT => Ok(Self::XinputRawTouchBegin(event.as_ref().try_into()?)),
-                    xinput::RAW_TOUCH_END_EVENT => Ok(Self::XinputRawTouchEnd(event.as_ref().try_into()?)),
-                    xinput::RAW_TOUCH_UPDATE_EVENT => Ok(Self::XinputRawTouchUpdate(event.as_ref().try_into()?)),
-                    xinput::TOUCH_BEGIN_EVENT => Ok(Self::XinputTouchBegin(event.as_ref().try_into()?)),
-                    xinput::TOUCH_END_EVENT => Ok(Self::XinputTouchEnd(event.as_ref().try_into()?)),
-                    xinput::TOUCH_OWNERSHIP_EVENT => Ok(Self::XinputTouchOwnership(event.as_ref().try_into()?)),
-                    xinput::TOUCH_UPDATE_EVENT => Ok(Self::XinputTouchUpdate(event.as_ref().try_into()?)),
+                    xinput::BARRIER_HIT_EVENT => Ok(Self::XinputBarrierHit(xinput::BarrierHitEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::BARRIER_LEAVE_EVENT => Ok(Self::XinputBarrierLeave(xinput::BarrierLeaveEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::BUTTON_PRESS_EVENT => Ok(Self::XinputButtonPress(xinput::ButtonPressEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::BUTTON_RELEASE_EVENT => Ok(Self::XinputButtonRelease(xinput::ButtonReleaseEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::DEVICE_CHANGED_EVENT => Ok(Self::XinputDeviceChanged(xinput::DeviceChangedEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::ENTER_EVENT => Ok(Self::XinputEnter(xinput::EnterEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::FOCUS_IN_EVENT => Ok(Self::XinputFocusIn(xinput::FocusInEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::FOCUS_OUT_EVENT => Ok(Self::XinputFocusOut(xinput::FocusOutEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::HIERARCHY_EVENT => Ok(Self::XinputHierarchy(xinput::HierarchyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::KEY_PRESS_EVENT => Ok(Self::XinputKeyPress(xinput::KeyPressEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::KEY_RELEASE_EVENT => Ok(Self::XinputKeyRelease(xinput::KeyReleaseEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::LEAVE_EVENT => Ok(Self::XinputLeave(xinput::LeaveEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::MOTION_EVENT => Ok(Self::XinputMotion(xinput::MotionEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::PROPERTY_EVENT => Ok(Self::XinputProperty(xinput::PropertyEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::RAW_BUTTON_PRESS_EVENT => Ok(Self::XinputRawButtonPress(xinput::RawButtonPressEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::RAW_BUTTON_RELEASE_EVENT => Ok(Self::XinputRawButtonRelease(xinput::RawButtonReleaseEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::RAW_KEY_PRESS_EVENT => Ok(Self::XinputRawKeyPress(xinput::RawKeyPressEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::RAW_KEY_RELEASE_EVENT => Ok(Self::XinputRawKeyRelease(xinput::RawKeyReleaseEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::RAW_MOTION_EVENT => Ok(Self::XinputRawMotion(xinput::RawMotionEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::RAW_TOUCH_BEGIN_EVENT => Ok(Self::XinputRawTouchBegin(xinput::RawTouchBeginEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::RAW_TOUCH_END_EVENT => Ok(Self::XinputRawTouchEnd(xinput::RawTouchEndEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::RAW_TOUCH_UPDATE_EVENT => Ok(Self::XinputRawTouchUpdate(xinput::RawTouchUpdateEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::TOUCH_BEGIN_EVENT => Ok(Self::XinputTouchBegin(xinput::TouchBeginEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::TOUCH_END_EVENT => Ok(Self::XinputTouchEnd(xinput::TouchEndEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::TOUCH_OWNERSHIP_EVENT => Ok(Self::XinputTouchOwnership(xinput::TouchOwnershipEvent::try_parse_with_order(event.as_ref(), order)?.0)),
+                    xinput::TOUCH_UPDATE_EVENT => Ok(Self::XinputTouchUpdate(xinput::TouchUpdateEvent::try_parse_with_order(event.as_ref(), order)?.0)),
                     _ => Ok(Self::Unknown(event)),
                 }
             }
@@ -1144,10 +1375,18 @@ impl<B: std::fmt::Debug + AsRef<[u8]>> Event<B> {
     }
 
     /// Get the sequence number contained in this X11 event
+    ///
+    /// The sequence number of an unknown event is read in the native byte order.
     pub fn wire_sequence_number(&self) -> Option<u16> {
+        self.wire_sequence_number_with_order(ByteOrder::native())
+    }
+
+    /// Get the sequence number contained in this X11 event, reading the sequence number of
+    /// an unknown event in the given byte order.
+    pub fn wire_sequence_number_with_order(&self, order: ByteOrder) -> Option<u16> {
         match self {
-            Event::Unknown(value) => sequence_number(value.as_ref()).ok(),
-            Event::Error(value) => Some(value.wire_sequence_number()),
+            Event::Unknown(value) => sequence_number(value.as_ref(), order).ok(),
+            Event::Error(value) => Some(value.wire_sequence_number_with_order(order)),
             Event::ButtonPress(value) => Some(value.sequence),
             Event::ButtonRelease(value) => Some(value.sequence),
             Event::CirculateNotify(value) => Some(value.sequence),
@@ -1565,6 +1804,309 @@ impl<B: std::fmt::Debug + AsRef<[u8]>> Event<B> {
     pub fn sent_event(&self) -> bool {
         self.raw_response_type() & 0x80 != 0
     }
+
+    /// Get the kind of this X11 event
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Event::Unknown(_) => EventKind::Unknown,
+            Event::Error(_) => EventKind::Error,
+            Event::ButtonPress(_) => EventKind::ButtonPress,
+            Event::ButtonRelease(_) => EventKind::ButtonRelease,
+            Event::CirculateNotify(_) => EventKind::CirculateNotify,
+            Event::CirculateRequest(_) => EventKind::CirculateRequest,
+            Event::ClientMessage(_) => EventKind::ClientMessage,
+            Event::ColormapNotify(_) => EventKind::ColormapNotify,
+            Event::ConfigureNotify(_) => EventKind::ConfigureNotify,
+            Event::ConfigureRequest(_) => EventKind::ConfigureRequest,
+            Event::CreateNotify(_) => EventKind::CreateNotify,
+            Event::DestroyNotify(_) => EventKind::DestroyNotify,
+            Event::EnterNotify(_) => EventKind::EnterNotify,
+            Event::Expose(_) => EventKind::Expose,
+            Event::FocusIn(_) => EventKind::FocusIn,
+            Event::FocusOut(_) => EventKind::FocusOut,
+            Event::GeGeneric(_) => EventKind::GeGeneric,
+            Event::GraphicsExposure(_) => EventKind::GraphicsExposure,
+            Event::GravityNotify(_) => EventKind::GravityNotify,
+            Event::KeyPress(_) => EventKind::KeyPress,
+            Event::KeyRelease(_) => EventKind::KeyRelease,
+            Event::KeymapNotify(_) => EventKind::KeymapNotify,
+            Event::LeaveNotify(_) => EventKind::LeaveNotify,
+            Event::MapNotify(_) => EventKind::MapNotify,
+            Event::MapRequest(_) => EventKind::MapRequest,
+            Event::MappingNotify(_) => EventKind::MappingNotify,
+            Event::MotionNotify(_) => EventKind::MotionNotify,
+            Event::NoExposure(_) => EventKind::NoExposure,
+            Event::PropertyNotify(_) => EventKind::PropertyNotify,
+            Event::ReparentNotify(_) => EventKind::ReparentNotify,
+            Event::ResizeRequest(_) => EventKind::ResizeRequest,
+            Event::SelectionClear(_) => EventKind::SelectionClear,
+            Event::SelectionNotify(_) => EventKind::SelectionNotify,
+            Event::SelectionRequest(_) => EventKind::SelectionRequest,
+            Event::UnmapNotify(_) => EventKind::UnmapNotify,
+            Event::VisibilityNotify(_) => EventKind::VisibilityNotify,
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(_) => EventKind::DamageNotify,
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(_) => EventKind::Dri2BufferSwapComplete,
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(_) => EventKind::Dri2InvalidateBuffers,
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(_) => EventKind::GlxBufferSwapComplete,
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(_) => EventKind::GlxPbufferClobber,
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(_) => EventKind::PresentCompleteNotify,
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(_) => EventKind::PresentConfigureNotify,
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(_) => EventKind::PresentGeneric,
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(_) => EventKind::PresentIdleNotify,
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(_) => EventKind::PresentRedirectNotify,
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(_) => EventKind::RandrNotify,
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(_) => EventKind::RandrScreenChangeNotify,
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(_) => EventKind::ScreensaverNotify,
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(_) => EventKind::ShapeNotify,
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(_) => EventKind::ShmCompletion,
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(_) => EventKind::SyncAlarmNotify,
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(_) => EventKind::SyncCounterNotify,
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(_) => EventKind::XfixesCursorNotify,
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(_) => EventKind::XfixesSelectionNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(_) => EventKind::XinputBarrierHit,
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(_) => EventKind::XinputBarrierLeave,
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(_) => EventKind::XinputButtonPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(_) => EventKind::XinputButtonRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(_) => EventKind::XinputChangeDeviceNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(_) => EventKind::XinputDeviceButtonPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(_) => EventKind::XinputDeviceButtonRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(_) => EventKind::XinputDeviceButtonStateNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(_) => EventKind::XinputDeviceChanged,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(_) => EventKind::XinputDeviceFocusIn,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(_) => EventKind::XinputDeviceFocusOut,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(_) => EventKind::XinputDeviceKeyPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(_) => EventKind::XinputDeviceKeyRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(_) => EventKind::XinputDeviceKeyStateNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(_) => EventKind::XinputDeviceMappingNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(_) => EventKind::XinputDeviceMotionNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(_) => EventKind::XinputDevicePresenceNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(_) => EventKind::XinputDevicePropertyNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(_) => EventKind::XinputDeviceStateNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(_) => EventKind::XinputDeviceValuator,
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(_) => EventKind::XinputEnter,
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(_) => EventKind::XinputFocusIn,
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(_) => EventKind::XinputFocusOut,
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(_) => EventKind::XinputHierarchy,
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(_) => EventKind::XinputKeyPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(_) => EventKind::XinputKeyRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(_) => EventKind::XinputLeave,
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(_) => EventKind::XinputMotion,
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(_) => EventKind::XinputProperty,
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(_) => EventKind::XinputProximityIn,
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(_) => EventKind::XinputProximityOut,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(_) => EventKind::XinputRawButtonPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(_) => EventKind::XinputRawButtonRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(_) => EventKind::XinputRawKeyPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(_) => EventKind::XinputRawKeyRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(_) => EventKind::XinputRawMotion,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(_) => EventKind::XinputRawTouchBegin,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(_) => EventKind::XinputRawTouchEnd,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(_) => EventKind::XinputRawTouchUpdate,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(_) => EventKind::XinputTouchBegin,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(_) => EventKind::XinputTouchEnd,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(_) => EventKind::XinputTouchOwnership,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(_) => EventKind::XinputTouchUpdate,
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(_) => EventKind::XkbAccessXNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(_) => EventKind::XkbActionMessage,
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(_) => EventKind::XkbBellNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(_) => EventKind::XkbCompatMapNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(_) => EventKind::XkbControlsNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(_) => EventKind::XkbExtensionDeviceNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(_) => EventKind::XkbIndicatorMapNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(_) => EventKind::XkbIndicatorStateNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(_) => EventKind::XkbMapNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(_) => EventKind::XkbNamesNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(_) => EventKind::XkbNewKeyboardNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(_) => EventKind::XkbStateNotify,
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(_) => EventKind::XprintAttributNotify,
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(_) => EventKind::XprintNotify,
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(_) => EventKind::XvPortNotify,
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(_) => EventKind::XvVideoNotify,
+        }
+    }
+
+    /// Get the window that this X11 event refers to, if any
+    ///
+    /// This is the value of the first field of type `WINDOW` that is called `window`,
+    /// `event`, `owner` or `requestor`, in this order of preference. For example, this is
+    /// the window that was configured for `ConfigureNotify` and the event window for
+    /// `ButtonPress`.
+    pub fn window(&self) -> Option<xproto::Window> {
+        match self {
+            Event::ButtonPress(value) => Some(value.event),
+            Event::ButtonRelease(value) => Some(value.event),
+            Event::CirculateNotify(value) => Some(value.window),
+            Event::CirculateRequest(value) => Some(value.window),
+            Event::ClientMessage(value) => Some(value.window),
+            Event::ColormapNotify(value) => Some(value.window),
+            Event::ConfigureNotify(value) => Some(value.window),
+            Event::ConfigureRequest(value) => Some(value.window),
+            Event::CreateNotify(value) => Some(value.window),
+            Event::DestroyNotify(value) => Some(value.window),
+            Event::EnterNotify(value) => Some(value.event),
+            Event::Expose(value) => Some(value.window),
+            Event::FocusIn(value) => Some(value.event),
+            Event::FocusOut(value) => Some(value.event),
+            Event::GravityNotify(value) => Some(value.window),
+            Event::KeyPress(value) => Some(value.event),
+            Event::KeyRelease(value) => Some(value.event),
+            Event::LeaveNotify(value) => Some(value.event),
+            Event::MapNotify(value) => Some(value.window),
+            Event::MapRequest(value) => Some(value.window),
+            Event::MotionNotify(value) => Some(value.event),
+            Event::PropertyNotify(value) => Some(value.window),
+            Event::ReparentNotify(value) => Some(value.window),
+            Event::ResizeRequest(value) => Some(value.window),
+            Event::SelectionClear(value) => Some(value.owner),
+            Event::SelectionNotify(value) => Some(value.requestor),
+            Event::SelectionRequest(value) => Some(value.owner),
+            Event::UnmapNotify(value) => Some(value.window),
+            Event::VisibilityNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(value) => Some(value.window),
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(value) => Some(value.window),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(value) => Some(value.window),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(value) => Some(value.event),
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(value) => Some(value.window),
+            _ => None,
+        }
+    }
 }
 
 /// Get the response type out of the raw bytes of an X11 error or event.
@@ -1582,8 +2124,4650 @@ fn error_code(raw_bytes: &[u8]) -> Result<u8, ParseError> {
 }
 
 /// Get the sequence number out of an X11 packet.
-fn sequence_number(raw_bytes: &[u8]) -> Result<u16, ParseError> {
-    raw_bytes.get(2..4)
-        .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
-        .ok_or(ParseError::ParseError)
+fn sequence_number(raw_bytes: &[u8], order: ByteOrder) -> Result<u16, ParseError> {
+    let raw_bytes = raw_bytes.get(2..).ok_or(ParseError::ParseError)?;
+    Ok(u16::try_parse_with_order(raw_bytes, order)?.0)
+}
+
+/// Get the name of a request from its major and minor opcode.
+///
+/// `ext_info_provider` is used to find the extension that a major opcode belongs to. For core
+/// requests, `minor_opcode` is ignored. Returns `None` if the request is not known.
+pub fn request_name(
+    ext_info_provider: &dyn ExtInfoProvider,
+    major_opcode: u8,
+    minor_opcode: u8,
+) -> Option<&'static str> {
+    // Check if this is a core protocol request
+    match major_opcode {
+        xproto::CREATE_WINDOW_REQUEST => return Some("CreateWindow"),
+        xproto::CHANGE_WINDOW_ATTRIBUTES_REQUEST => return Some("ChangeWindowAttributes"),
+        xproto::GET_WINDOW_ATTRIBUTES_REQUEST => return Some("GetWindowAttributes"),
+        xproto::DESTROY_WINDOW_REQUEST => return Some("DestroyWindow"),
+        xproto::DESTROY_SUBWINDOWS_REQUEST => return Some("DestroySubwindows"),
+        xproto::CHANGE_SAVE_SET_REQUEST => return Some("ChangeSaveSet"),
+        xproto::REPARENT_WINDOW_REQUEST => return Some("ReparentWindow"),
+        xproto::MAP_WINDOW_REQUEST => return Some("MapWindow"),
+        xproto::MAP_SUBWINDOWS_REQUEST => return Some("MapSubwindows"),
+        xproto::UNMAP_WINDOW_REQUEST => return Some("UnmapWindow"),
+        xproto::UNMAP_SUBWINDOWS_REQUEST => return Some("UnmapSubwindows"),
+        xproto::CONFIGURE_WINDOW_REQUEST => return Some("ConfigureWindow"),
+        xproto::CIRCULATE_WINDOW_REQUEST => return Some("CirculateWindow"),
+        xproto::GET_GEOMETRY_REQUEST => return Some("GetGeometry"),
+        xproto::QUERY_TREE_REQUEST => return Some("QueryTree"),
+        xproto::INTERN_ATOM_REQUEST => return Some("InternAtom"),
+        xproto::GET_ATOM_NAME_REQUEST => return Some("GetAtomName"),
+        xproto::CHANGE_PROPERTY_REQUEST => return Some("ChangeProperty"),
+        xproto::DELETE_PROPERTY_REQUEST => return Some("DeleteProperty"),
+        xproto::GET_PROPERTY_REQUEST => return Some("GetProperty"),
+        xproto::LIST_PROPERTIES_REQUEST => return Some("ListProperties"),
+        xproto::SET_SELECTION_OWNER_REQUEST => return Some("SetSelectionOwner"),
+        xproto::GET_SELECTION_OWNER_REQUEST => return Some("GetSelectionOwner"),
+        xproto::CONVERT_SELECTION_REQUEST => return Some("ConvertSelection"),
+        xproto::SEND_EVENT_REQUEST => return Some("SendEvent"),
+        xproto::GRAB_POINTER_REQUEST => return Some("GrabPointer"),
+        xproto::UNGRAB_POINTER_REQUEST => return Some("UngrabPointer"),
+        xproto::GRAB_BUTTON_REQUEST => return Some("GrabButton"),
+        xproto::UNGRAB_BUTTON_REQUEST => return Some("UngrabButton"),
+        xproto::CHANGE_ACTIVE_POINTER_GRAB_REQUEST => return Some("ChangeActivePointerGrab"),
+        xproto::GRAB_KEYBOARD_REQUEST => return Some("GrabKeyboard"),
+        xproto::UNGRAB_KEYBOARD_REQUEST => return Some("UngrabKeyboard"),
+        xproto::GRAB_KEY_REQUEST => return Some("GrabKey"),
+        xproto::UNGRAB_KEY_REQUEST => return Some("UngrabKey"),
+        xproto::ALLOW_EVENTS_REQUEST => return Some("AllowEvents"),
+        xproto::GRAB_SERVER_REQUEST => return Some("GrabServer"),
+        xproto::UNGRAB_SERVER_REQUEST => return Some("UngrabServer"),
+        xproto::QUERY_POINTER_REQUEST => return Some("QueryPointer"),
+        xproto::GET_MOTION_EVENTS_REQUEST => return Some("GetMotionEvents"),
+        xproto::TRANSLATE_COORDINATES_REQUEST => return Some("TranslateCoordinates"),
+        xproto::WARP_POINTER_REQUEST => return Some("WarpPointer"),
+        xproto::SET_INPUT_FOCUS_REQUEST => return Some("SetInputFocus"),
+        xproto::GET_INPUT_FOCUS_REQUEST => return Some("GetInputFocus"),
+        xproto::QUERY_KEYMAP_REQUEST => return Some("QueryKeymap"),
+        xproto::OPEN_FONT_REQUEST => return Some("OpenFont"),
+        xproto::CLOSE_FONT_REQUEST => return Some("CloseFont"),
+        xproto::QUERY_FONT_REQUEST => return Some("QueryFont"),
+        xproto::QUERY_TEXT_EXTENTS_REQUEST => return Some("QueryTextExtents"),
+        xproto::LIST_FONTS_REQUEST => return Some("ListFonts"),
+        xproto::LIST_FONTS_WITH_INFO_REQUEST => return Some("ListFontsWithInfo"),
+        xproto::SET_FONT_PATH_REQUEST => return Some("SetFontPath"),
+        xproto::GET_FONT_PATH_REQUEST => return Some("GetFontPath"),
+        xproto::CREATE_PIXMAP_REQUEST => return Some("CreatePixmap"),
+        xproto::FREE_PIXMAP_REQUEST => return Some("FreePixmap"),
+        xproto::CREATE_GC_REQUEST => return Some("CreateGC"),
+        xproto::CHANGE_GC_REQUEST => return Some("ChangeGC"),
+        xproto::COPY_GC_REQUEST => return Some("CopyGC"),
+        xproto::SET_DASHES_REQUEST => return Some("SetDashes"),
+        xproto::SET_CLIP_RECTANGLES_REQUEST => return Some("SetClipRectangles"),
+        xproto::FREE_GC_REQUEST => return Some("FreeGC"),
+        xproto::CLEAR_AREA_REQUEST => return Some("ClearArea"),
+        xproto::COPY_AREA_REQUEST => return Some("CopyArea"),
+        xproto::COPY_PLANE_REQUEST => return Some("CopyPlane"),
+        xproto::POLY_POINT_REQUEST => return Some("PolyPoint"),
+        xproto::POLY_LINE_REQUEST => return Some("PolyLine"),
+        xproto::POLY_SEGMENT_REQUEST => return Some("PolySegment"),
+        xproto::POLY_RECTANGLE_REQUEST => return Some("PolyRectangle"),
+        xproto::POLY_ARC_REQUEST => return Some("PolyArc"),
+        xproto::FILL_POLY_REQUEST => return Some("FillPoly"),
+        xproto::POLY_FILL_RECTANGLE_REQUEST => return Some("PolyFillRectangle"),
+        xproto::POLY_FILL_ARC_REQUEST => return Some("PolyFillArc"),
+        xproto::PUT_IMAGE_REQUEST => return Some("PutImage"),
+        xproto::GET_IMAGE_REQUEST => return Some("GetImage"),
+        xproto::POLY_TEXT8_REQUEST => return Some("PolyText8"),
+        xproto::POLY_TEXT16_REQUEST => return Some("PolyText16"),
+        xproto::IMAGE_TEXT8_REQUEST => return Some("ImageText8"),
+        xproto::IMAGE_TEXT16_REQUEST => return Some("ImageText16"),
+        xproto::CREATE_COLORMAP_REQUEST => return Some("CreateColormap"),
+        xproto::FREE_COLORMAP_REQUEST => return Some("FreeColormap"),
+        xproto::COPY_COLORMAP_AND_FREE_REQUEST => return Some("CopyColormapAndFree"),
+        xproto::INSTALL_COLORMAP_REQUEST => return Some("InstallColormap"),
+        xproto::UNINSTALL_COLORMAP_REQUEST => return Some("UninstallColormap"),
+        xproto::LIST_INSTALLED_COLORMAPS_REQUEST => return Some("ListInstalledColormaps"),
+        xproto::ALLOC_COLOR_REQUEST => return Some("AllocColor"),
+        xproto::ALLOC_NAMED_COLOR_REQUEST => return Some("AllocNamedColor"),
+        xproto::ALLOC_COLOR_CELLS_REQUEST => return Some("AllocColorCells"),
+        xproto::ALLOC_COLOR_PLANES_REQUEST => return Some("AllocColorPlanes"),
+        xproto::FREE_COLORS_REQUEST => return Some("FreeColors"),
+        xproto::STORE_COLORS_REQUEST => return Some("StoreColors"),
+        xproto::STORE_NAMED_COLOR_REQUEST => return Some("StoreNamedColor"),
+        xproto::QUERY_COLORS_REQUEST => return Some("QueryColors"),
+        xproto::LOOKUP_COLOR_REQUEST => return Some("LookupColor"),
+        xproto::CREATE_CURSOR_REQUEST => return Some("CreateCursor"),
+        xproto::CREATE_GLYPH_CURSOR_REQUEST => return Some("CreateGlyphCursor"),
+        xproto::FREE_CURSOR_REQUEST => return Some("FreeCursor"),
+        xproto::RECOLOR_CURSOR_REQUEST => return Some("RecolorCursor"),
+        xproto::QUERY_BEST_SIZE_REQUEST => return Some("QueryBestSize"),
+        xproto::QUERY_EXTENSION_REQUEST => return Some("QueryExtension"),
+        xproto::LIST_EXTENSIONS_REQUEST => return Some("ListExtensions"),
+        xproto::CHANGE_KEYBOARD_MAPPING_REQUEST => return Some("ChangeKeyboardMapping"),
+        xproto::GET_KEYBOARD_MAPPING_REQUEST => return Some("GetKeyboardMapping"),
+        xproto::CHANGE_KEYBOARD_CONTROL_REQUEST => return Some("ChangeKeyboardControl"),
+        xproto::GET_KEYBOARD_CONTROL_REQUEST => return Some("GetKeyboardControl"),
+        xproto::BELL_REQUEST => return Some("Bell"),
+        xproto::CHANGE_POINTER_CONTROL_REQUEST => return Some("ChangePointerControl"),
+        xproto::GET_POINTER_CONTROL_REQUEST => return Some("GetPointerControl"),
+        xproto::SET_SCREEN_SAVER_REQUEST => return Some("SetScreenSaver"),
+        xproto::GET_SCREEN_SAVER_REQUEST => return Some("GetScreenSaver"),
+        xproto::CHANGE_HOSTS_REQUEST => return Some("ChangeHosts"),
+        xproto::LIST_HOSTS_REQUEST => return Some("ListHosts"),
+        xproto::SET_ACCESS_CONTROL_REQUEST => return Some("SetAccessControl"),
+        xproto::SET_CLOSE_DOWN_MODE_REQUEST => return Some("SetCloseDownMode"),
+        xproto::KILL_CLIENT_REQUEST => return Some("KillClient"),
+        xproto::ROTATE_PROPERTIES_REQUEST => return Some("RotateProperties"),
+        xproto::FORCE_SCREEN_SAVER_REQUEST => return Some("ForceScreenSaver"),
+        xproto::SET_POINTER_MAPPING_REQUEST => return Some("SetPointerMapping"),
+        xproto::GET_POINTER_MAPPING_REQUEST => return Some("GetPointerMapping"),
+        xproto::SET_MODIFIER_MAPPING_REQUEST => return Some("SetModifierMapping"),
+        xproto::GET_MODIFIER_MAPPING_REQUEST => return Some("GetModifierMapping"),
+        xproto::NO_OPERATION_REQUEST => return Some("NoOperation"),
+        _ => {}
+    }
+
+    // Find the extension that this request could belong to
+    let ext_info = ext_info_provider.get_from_major_opcode(major_opcode);
+    match ext_info {
+        Some((bigreq::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                bigreq::ENABLE_REQUEST => Some("Enable"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "composite")]
+        Some((composite::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                composite::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                composite::REDIRECT_WINDOW_REQUEST => Some("RedirectWindow"),
+                composite::REDIRECT_SUBWINDOWS_REQUEST => Some("RedirectSubwindows"),
+                composite::UNREDIRECT_WINDOW_REQUEST => Some("UnredirectWindow"),
+                composite::UNREDIRECT_SUBWINDOWS_REQUEST => Some("UnredirectSubwindows"),
+                composite::CREATE_REGION_FROM_BORDER_CLIP_REQUEST => Some("CreateRegionFromBorderClip"),
+                composite::NAME_WINDOW_PIXMAP_REQUEST => Some("NameWindowPixmap"),
+                composite::GET_OVERLAY_WINDOW_REQUEST => Some("GetOverlayWindow"),
+                composite::RELEASE_OVERLAY_WINDOW_REQUEST => Some("ReleaseOverlayWindow"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "damage")]
+        Some((damage::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                damage::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                damage::CREATE_REQUEST => Some("Create"),
+                damage::DESTROY_REQUEST => Some("Destroy"),
+                damage::SUBTRACT_REQUEST => Some("Subtract"),
+                damage::ADD_REQUEST => Some("Add"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "dpms")]
+        Some((dpms::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                dpms::GET_VERSION_REQUEST => Some("GetVersion"),
+                dpms::CAPABLE_REQUEST => Some("Capable"),
+                dpms::GET_TIMEOUTS_REQUEST => Some("GetTimeouts"),
+                dpms::SET_TIMEOUTS_REQUEST => Some("SetTimeouts"),
+                dpms::ENABLE_REQUEST => Some("Enable"),
+                dpms::DISABLE_REQUEST => Some("Disable"),
+                dpms::FORCE_LEVEL_REQUEST => Some("ForceLevel"),
+                dpms::INFO_REQUEST => Some("Info"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "dri2")]
+        Some((dri2::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                dri2::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                dri2::CONNECT_REQUEST => Some("Connect"),
+                dri2::AUTHENTICATE_REQUEST => Some("Authenticate"),
+                dri2::CREATE_DRAWABLE_REQUEST => Some("CreateDrawable"),
+                dri2::DESTROY_DRAWABLE_REQUEST => Some("DestroyDrawable"),
+                dri2::GET_BUFFERS_REQUEST => Some("GetBuffers"),
+                dri2::COPY_REGION_REQUEST => Some("CopyRegion"),
+                dri2::GET_BUFFERS_WITH_FORMAT_REQUEST => Some("GetBuffersWithFormat"),
+                dri2::SWAP_BUFFERS_REQUEST => Some("SwapBuffers"),
+                dri2::GET_MSC_REQUEST => Some("GetMSC"),
+                dri2::WAIT_MSC_REQUEST => Some("WaitMSC"),
+                dri2::WAIT_SBC_REQUEST => Some("WaitSBC"),
+                dri2::SWAP_INTERVAL_REQUEST => Some("SwapInterval"),
+                dri2::GET_PARAM_REQUEST => Some("GetParam"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "dri3")]
+        Some((dri3::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                dri3::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                dri3::OPEN_REQUEST => Some("Open"),
+                dri3::PIXMAP_FROM_BUFFER_REQUEST => Some("PixmapFromBuffer"),
+                dri3::BUFFER_FROM_PIXMAP_REQUEST => Some("BufferFromPixmap"),
+                dri3::FENCE_FROM_FD_REQUEST => Some("FenceFromFD"),
+                dri3::FD_FROM_FENCE_REQUEST => Some("FDFromFence"),
+                dri3::GET_SUPPORTED_MODIFIERS_REQUEST => Some("GetSupportedModifiers"),
+                dri3::PIXMAP_FROM_BUFFERS_REQUEST => Some("PixmapFromBuffers"),
+                dri3::BUFFERS_FROM_PIXMAP_REQUEST => Some("BuffersFromPixmap"),
+                _ => None,
+            }
+        }
+        Some((ge::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                ge::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "glx")]
+        Some((glx::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                glx::RENDER_REQUEST => Some("Render"),
+                glx::RENDER_LARGE_REQUEST => Some("RenderLarge"),
+                glx::CREATE_CONTEXT_REQUEST => Some("CreateContext"),
+                glx::DESTROY_CONTEXT_REQUEST => Some("DestroyContext"),
+                glx::MAKE_CURRENT_REQUEST => Some("MakeCurrent"),
+                glx::IS_DIRECT_REQUEST => Some("IsDirect"),
+                glx::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                glx::WAIT_GL_REQUEST => Some("WaitGL"),
+                glx::WAIT_X_REQUEST => Some("WaitX"),
+                glx::COPY_CONTEXT_REQUEST => Some("CopyContext"),
+                glx::SWAP_BUFFERS_REQUEST => Some("SwapBuffers"),
+                glx::USE_X_FONT_REQUEST => Some("UseXFont"),
+                glx::CREATE_GLX_PIXMAP_REQUEST => Some("CreateGLXPixmap"),
+                glx::GET_VISUAL_CONFIGS_REQUEST => Some("GetVisualConfigs"),
+                glx::DESTROY_GLX_PIXMAP_REQUEST => Some("DestroyGLXPixmap"),
+                glx::VENDOR_PRIVATE_REQUEST => Some("VendorPrivate"),
+                glx::VENDOR_PRIVATE_WITH_REPLY_REQUEST => Some("VendorPrivateWithReply"),
+                glx::QUERY_EXTENSIONS_STRING_REQUEST => Some("QueryExtensionsString"),
+                glx::QUERY_SERVER_STRING_REQUEST => Some("QueryServerString"),
+                glx::CLIENT_INFO_REQUEST => Some("ClientInfo"),
+                glx::GET_FB_CONFIGS_REQUEST => Some("GetFBConfigs"),
+                glx::CREATE_PIXMAP_REQUEST => Some("CreatePixmap"),
+                glx::DESTROY_PIXMAP_REQUEST => Some("DestroyPixmap"),
+                glx::CREATE_NEW_CONTEXT_REQUEST => Some("CreateNewContext"),
+                glx::QUERY_CONTEXT_REQUEST => Some("QueryContext"),
+                glx::MAKE_CONTEXT_CURRENT_REQUEST => Some("MakeContextCurrent"),
+                glx::CREATE_PBUFFER_REQUEST => Some("CreatePbuffer"),
+                glx::DESTROY_PBUFFER_REQUEST => Some("DestroyPbuffer"),
+                glx::GET_DRAWABLE_ATTRIBUTES_REQUEST => Some("GetDrawableAttributes"),
+                glx::CHANGE_DRAWABLE_ATTRIBUTES_REQUEST => Some("ChangeDrawableAttributes"),
+                glx::CREATE_WINDOW_REQUEST => Some("CreateWindow"),
+                glx::DELETE_WINDOW_REQUEST => Some("DeleteWindow"),
+                glx::SET_CLIENT_INFO_ARB_REQUEST => Some("SetClientInfoARB"),
+                glx::CREATE_CONTEXT_ATTRIBS_ARB_REQUEST => Some("CreateContextAttribsARB"),
+                glx::SET_CLIENT_INFO2_ARB_REQUEST => Some("SetClientInfo2ARB"),
+                glx::NEW_LIST_REQUEST => Some("NewList"),
+                glx::END_LIST_REQUEST => Some("EndList"),
+                glx::DELETE_LISTS_REQUEST => Some("DeleteLists"),
+                glx::GEN_LISTS_REQUEST => Some("GenLists"),
+                glx::FEEDBACK_BUFFER_REQUEST => Some("FeedbackBuffer"),
+                glx::SELECT_BUFFER_REQUEST => Some("SelectBuffer"),
+                glx::RENDER_MODE_REQUEST => Some("RenderMode"),
+                glx::FINISH_REQUEST => Some("Finish"),
+                glx::PIXEL_STOREF_REQUEST => Some("PixelStoref"),
+                glx::PIXEL_STOREI_REQUEST => Some("PixelStorei"),
+                glx::READ_PIXELS_REQUEST => Some("ReadPixels"),
+                glx::GET_BOOLEANV_REQUEST => Some("GetBooleanv"),
+                glx::GET_CLIP_PLANE_REQUEST => Some("GetClipPlane"),
+                glx::GET_DOUBLEV_REQUEST => Some("GetDoublev"),
+                glx::GET_ERROR_REQUEST => Some("GetError"),
+                glx::GET_FLOATV_REQUEST => Some("GetFloatv"),
+                glx::GET_INTEGERV_REQUEST => Some("GetIntegerv"),
+                glx::GET_LIGHTFV_REQUEST => Some("GetLightfv"),
+                glx::GET_LIGHTIV_REQUEST => Some("GetLightiv"),
+                glx::GET_MAPDV_REQUEST => Some("GetMapdv"),
+                glx::GET_MAPFV_REQUEST => Some("GetMapfv"),
+                glx::GET_MAPIV_REQUEST => Some("GetMapiv"),
+                glx::GET_MATERIALFV_REQUEST => Some("GetMaterialfv"),
+                glx::GET_MATERIALIV_REQUEST => Some("GetMaterialiv"),
+                glx::GET_PIXEL_MAPFV_REQUEST => Some("GetPixelMapfv"),
+                glx::GET_PIXEL_MAPUIV_REQUEST => Some("GetPixelMapuiv"),
+                glx::GET_PIXEL_MAPUSV_REQUEST => Some("GetPixelMapusv"),
+                glx::GET_POLYGON_STIPPLE_REQUEST => Some("GetPolygonStipple"),
+                glx::GET_STRING_REQUEST => Some("GetString"),
+                glx::GET_TEX_ENVFV_REQUEST => Some("GetTexEnvfv"),
+                glx::GET_TEX_ENVIV_REQUEST => Some("GetTexEnviv"),
+                glx::GET_TEX_GENDV_REQUEST => Some("GetTexGendv"),
+                glx::GET_TEX_GENFV_REQUEST => Some("GetTexGenfv"),
+                glx::GET_TEX_GENIV_REQUEST => Some("GetTexGeniv"),
+                glx::GET_TEX_IMAGE_REQUEST => Some("GetTexImage"),
+                glx::GET_TEX_PARAMETERFV_REQUEST => Some("GetTexParameterfv"),
+                glx::GET_TEX_PARAMETERIV_REQUEST => Some("GetTexParameteriv"),
+                glx::GET_TEX_LEVEL_PARAMETERFV_REQUEST => Some("GetTexLevelParameterfv"),
+                glx::GET_TEX_LEVEL_PARAMETERIV_REQUEST => Some("GetTexLevelParameteriv"),
+                glx::IS_ENABLED_REQUEST => Some("IsEnabled"),
+                glx::IS_LIST_REQUEST => Some("IsList"),
+                glx::FLUSH_REQUEST => Some("Flush"),
+                glx::ARE_TEXTURES_RESIDENT_REQUEST => Some("AreTexturesResident"),
+                glx::DELETE_TEXTURES_REQUEST => Some("DeleteTextures"),
+                glx::GEN_TEXTURES_REQUEST => Some("GenTextures"),
+                glx::IS_TEXTURE_REQUEST => Some("IsTexture"),
+                glx::GET_COLOR_TABLE_REQUEST => Some("GetColorTable"),
+                glx::GET_COLOR_TABLE_PARAMETERFV_REQUEST => Some("GetColorTableParameterfv"),
+                glx::GET_COLOR_TABLE_PARAMETERIV_REQUEST => Some("GetColorTableParameteriv"),
+                glx::GET_CONVOLUTION_FILTER_REQUEST => Some("GetConvolutionFilter"),
+                glx::GET_CONVOLUTION_PARAMETERFV_REQUEST => Some("GetConvolutionParameterfv"),
+                glx::GET_CONVOLUTION_PARAMETERIV_REQUEST => Some("GetConvolutionParameteriv"),
+                glx::GET_SEPARABLE_FILTER_REQUEST => Some("GetSeparableFilter"),
+                glx::GET_HISTOGRAM_REQUEST => Some("GetHistogram"),
+                glx::GET_HISTOGRAM_PARAMETERFV_REQUEST => Some("GetHistogramParameterfv"),
+                glx::GET_HISTOGRAM_PARAMETERIV_REQUEST => Some("GetHistogramParameteriv"),
+                glx::GET_MINMAX_REQUEST => Some("GetMinmax"),
+                glx::GET_MINMAX_PARAMETERFV_REQUEST => Some("GetMinmaxParameterfv"),
+                glx::GET_MINMAX_PARAMETERIV_REQUEST => Some("GetMinmaxParameteriv"),
+                glx::GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST => Some("GetCompressedTexImageARB"),
+                glx::DELETE_QUERIES_ARB_REQUEST => Some("DeleteQueriesARB"),
+                glx::GEN_QUERIES_ARB_REQUEST => Some("GenQueriesARB"),
+                glx::IS_QUERY_ARB_REQUEST => Some("IsQueryARB"),
+                glx::GET_QUERYIV_ARB_REQUEST => Some("GetQueryivARB"),
+                glx::GET_QUERY_OBJECTIV_ARB_REQUEST => Some("GetQueryObjectivARB"),
+                glx::GET_QUERY_OBJECTUIV_ARB_REQUEST => Some("GetQueryObjectuivARB"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "present")]
+        Some((present::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                present::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                present::PIXMAP_REQUEST => Some("Pixmap"),
+                present::NOTIFY_MSC_REQUEST => Some("NotifyMSC"),
+                present::SELECT_INPUT_REQUEST => Some("SelectInput"),
+                present::QUERY_CAPABILITIES_REQUEST => Some("QueryCapabilities"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "randr")]
+        Some((randr::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                randr::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                randr::SET_SCREEN_CONFIG_REQUEST => Some("SetScreenConfig"),
+                randr::SELECT_INPUT_REQUEST => Some("SelectInput"),
+                randr::GET_SCREEN_INFO_REQUEST => Some("GetScreenInfo"),
+                randr::GET_SCREEN_SIZE_RANGE_REQUEST => Some("GetScreenSizeRange"),
+                randr::SET_SCREEN_SIZE_REQUEST => Some("SetScreenSize"),
+                randr::GET_SCREEN_RESOURCES_REQUEST => Some("GetScreenResources"),
+                randr::GET_OUTPUT_INFO_REQUEST => Some("GetOutputInfo"),
+                randr::LIST_OUTPUT_PROPERTIES_REQUEST => Some("ListOutputProperties"),
+                randr::QUERY_OUTPUT_PROPERTY_REQUEST => Some("QueryOutputProperty"),
+                randr::CONFIGURE_OUTPUT_PROPERTY_REQUEST => Some("ConfigureOutputProperty"),
+                randr::CHANGE_OUTPUT_PROPERTY_REQUEST => Some("ChangeOutputProperty"),
+                randr::DELETE_OUTPUT_PROPERTY_REQUEST => Some("DeleteOutputProperty"),
+                randr::GET_OUTPUT_PROPERTY_REQUEST => Some("GetOutputProperty"),
+                randr::CREATE_MODE_REQUEST => Some("CreateMode"),
+                randr::DESTROY_MODE_REQUEST => Some("DestroyMode"),
+                randr::ADD_OUTPUT_MODE_REQUEST => Some("AddOutputMode"),
+                randr::DELETE_OUTPUT_MODE_REQUEST => Some("DeleteOutputMode"),
+                randr::GET_CRTC_INFO_REQUEST => Some("GetCrtcInfo"),
+                randr::SET_CRTC_CONFIG_REQUEST => Some("SetCrtcConfig"),
+                randr::GET_CRTC_GAMMA_SIZE_REQUEST => Some("GetCrtcGammaSize"),
+                randr::GET_CRTC_GAMMA_REQUEST => Some("GetCrtcGamma"),
+                randr::SET_CRTC_GAMMA_REQUEST => Some("SetCrtcGamma"),
+                randr::GET_SCREEN_RESOURCES_CURRENT_REQUEST => Some("GetScreenResourcesCurrent"),
+                randr::SET_CRTC_TRANSFORM_REQUEST => Some("SetCrtcTransform"),
+                randr::GET_CRTC_TRANSFORM_REQUEST => Some("GetCrtcTransform"),
+                randr::GET_PANNING_REQUEST => Some("GetPanning"),
+                randr::SET_PANNING_REQUEST => Some("SetPanning"),
+                randr::SET_OUTPUT_PRIMARY_REQUEST => Some("SetOutputPrimary"),
+                randr::GET_OUTPUT_PRIMARY_REQUEST => Some("GetOutputPrimary"),
+                randr::GET_PROVIDERS_REQUEST => Some("GetProviders"),
+                randr::GET_PROVIDER_INFO_REQUEST => Some("GetProviderInfo"),
+                randr::SET_PROVIDER_OFFLOAD_SINK_REQUEST => Some("SetProviderOffloadSink"),
+                randr::SET_PROVIDER_OUTPUT_SOURCE_REQUEST => Some("SetProviderOutputSource"),
+                randr::LIST_PROVIDER_PROPERTIES_REQUEST => Some("ListProviderProperties"),
+                randr::QUERY_PROVIDER_PROPERTY_REQUEST => Some("QueryProviderProperty"),
+                randr::CONFIGURE_PROVIDER_PROPERTY_REQUEST => Some("ConfigureProviderProperty"),
+                randr::CHANGE_PROVIDER_PROPERTY_REQUEST => Some("ChangeProviderProperty"),
+                randr::DELETE_PROVIDER_PROPERTY_REQUEST => Some("DeleteProviderProperty"),
+                randr::GET_PROVIDER_PROPERTY_REQUEST => Some("GetProviderProperty"),
+                randr::GET_MONITORS_REQUEST => Some("GetMonitors"),
+                randr::SET_MONITOR_REQUEST => Some("SetMonitor"),
+                randr::DELETE_MONITOR_REQUEST => Some("DeleteMonitor"),
+                randr::CREATE_LEASE_REQUEST => Some("CreateLease"),
+                randr::FREE_LEASE_REQUEST => Some("FreeLease"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "record")]
+        Some((record::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                record::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                record::CREATE_CONTEXT_REQUEST => Some("CreateContext"),
+                record::REGISTER_CLIENTS_REQUEST => Some("RegisterClients"),
+                record::UNREGISTER_CLIENTS_REQUEST => Some("UnregisterClients"),
+                record::GET_CONTEXT_REQUEST => Some("GetContext"),
+                record::ENABLE_CONTEXT_REQUEST => Some("EnableContext"),
+                record::DISABLE_CONTEXT_REQUEST => Some("DisableContext"),
+                record::FREE_CONTEXT_REQUEST => Some("FreeContext"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "render")]
+        Some((render::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                render::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                render::QUERY_PICT_FORMATS_REQUEST => Some("QueryPictFormats"),
+                render::QUERY_PICT_INDEX_VALUES_REQUEST => Some("QueryPictIndexValues"),
+                render::CREATE_PICTURE_REQUEST => Some("CreatePicture"),
+                render::CHANGE_PICTURE_REQUEST => Some("ChangePicture"),
+                render::SET_PICTURE_CLIP_RECTANGLES_REQUEST => Some("SetPictureClipRectangles"),
+                render::FREE_PICTURE_REQUEST => Some("FreePicture"),
+                render::COMPOSITE_REQUEST => Some("Composite"),
+                render::TRAPEZOIDS_REQUEST => Some("Trapezoids"),
+                render::TRIANGLES_REQUEST => Some("Triangles"),
+                render::TRI_STRIP_REQUEST => Some("TriStrip"),
+                render::TRI_FAN_REQUEST => Some("TriFan"),
+                render::CREATE_GLYPH_SET_REQUEST => Some("CreateGlyphSet"),
+                render::REFERENCE_GLYPH_SET_REQUEST => Some("ReferenceGlyphSet"),
+                render::FREE_GLYPH_SET_REQUEST => Some("FreeGlyphSet"),
+                render::ADD_GLYPHS_REQUEST => Some("AddGlyphs"),
+                render::FREE_GLYPHS_REQUEST => Some("FreeGlyphs"),
+                render::COMPOSITE_GLYPHS8_REQUEST => Some("CompositeGlyphs8"),
+                render::COMPOSITE_GLYPHS16_REQUEST => Some("CompositeGlyphs16"),
+                render::COMPOSITE_GLYPHS32_REQUEST => Some("CompositeGlyphs32"),
+                render::FILL_RECTANGLES_REQUEST => Some("FillRectangles"),
+                render::CREATE_CURSOR_REQUEST => Some("CreateCursor"),
+                render::SET_PICTURE_TRANSFORM_REQUEST => Some("SetPictureTransform"),
+                render::QUERY_FILTERS_REQUEST => Some("QueryFilters"),
+                render::SET_PICTURE_FILTER_REQUEST => Some("SetPictureFilter"),
+                render::CREATE_ANIM_CURSOR_REQUEST => Some("CreateAnimCursor"),
+                render::ADD_TRAPS_REQUEST => Some("AddTraps"),
+                render::CREATE_SOLID_FILL_REQUEST => Some("CreateSolidFill"),
+                render::CREATE_LINEAR_GRADIENT_REQUEST => Some("CreateLinearGradient"),
+                render::CREATE_RADIAL_GRADIENT_REQUEST => Some("CreateRadialGradient"),
+                render::CREATE_CONICAL_GRADIENT_REQUEST => Some("CreateConicalGradient"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "res")]
+        Some((res::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                res::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                res::QUERY_CLIENTS_REQUEST => Some("QueryClients"),
+                res::QUERY_CLIENT_RESOURCES_REQUEST => Some("QueryClientResources"),
+                res::QUERY_CLIENT_PIXMAP_BYTES_REQUEST => Some("QueryClientPixmapBytes"),
+                res::QUERY_CLIENT_IDS_REQUEST => Some("QueryClientIds"),
+                res::QUERY_RESOURCE_BYTES_REQUEST => Some("QueryResourceBytes"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "screensaver")]
+        Some((screensaver::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                screensaver::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                screensaver::QUERY_INFO_REQUEST => Some("QueryInfo"),
+                screensaver::SELECT_INPUT_REQUEST => Some("SelectInput"),
+                screensaver::SET_ATTRIBUTES_REQUEST => Some("SetAttributes"),
+                screensaver::UNSET_ATTRIBUTES_REQUEST => Some("UnsetAttributes"),
+                screensaver::SUSPEND_REQUEST => Some("Suspend"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "shape")]
+        Some((shape::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                shape::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                shape::RECTANGLES_REQUEST => Some("Rectangles"),
+                shape::MASK_REQUEST => Some("Mask"),
+                shape::COMBINE_REQUEST => Some("Combine"),
+                shape::OFFSET_REQUEST => Some("Offset"),
+                shape::QUERY_EXTENTS_REQUEST => Some("QueryExtents"),
+                shape::SELECT_INPUT_REQUEST => Some("SelectInput"),
+                shape::INPUT_SELECTED_REQUEST => Some("InputSelected"),
+                shape::GET_RECTANGLES_REQUEST => Some("GetRectangles"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "shm")]
+        Some((shm::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                shm::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                shm::ATTACH_REQUEST => Some("Attach"),
+                shm::DETACH_REQUEST => Some("Detach"),
+                shm::PUT_IMAGE_REQUEST => Some("PutImage"),
+                shm::GET_IMAGE_REQUEST => Some("GetImage"),
+                shm::CREATE_PIXMAP_REQUEST => Some("CreatePixmap"),
+                shm::ATTACH_FD_REQUEST => Some("AttachFd"),
+                shm::CREATE_SEGMENT_REQUEST => Some("CreateSegment"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "sync")]
+        Some((sync::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                sync::INITIALIZE_REQUEST => Some("Initialize"),
+                sync::LIST_SYSTEM_COUNTERS_REQUEST => Some("ListSystemCounters"),
+                sync::CREATE_COUNTER_REQUEST => Some("CreateCounter"),
+                sync::SET_COUNTER_REQUEST => Some("SetCounter"),
+                sync::CHANGE_COUNTER_REQUEST => Some("ChangeCounter"),
+                sync::QUERY_COUNTER_REQUEST => Some("QueryCounter"),
+                sync::DESTROY_COUNTER_REQUEST => Some("DestroyCounter"),
+                sync::AWAIT_REQUEST => Some("Await"),
+                sync::CREATE_ALARM_REQUEST => Some("CreateAlarm"),
+                sync::CHANGE_ALARM_REQUEST => Some("ChangeAlarm"),
+                sync::QUERY_ALARM_REQUEST => Some("QueryAlarm"),
+                sync::DESTROY_ALARM_REQUEST => Some("DestroyAlarm"),
+                sync::SET_PRIORITY_REQUEST => Some("SetPriority"),
+                sync::GET_PRIORITY_REQUEST => Some("GetPriority"),
+                sync::CREATE_FENCE_REQUEST => Some("CreateFence"),
+                sync::TRIGGER_FENCE_REQUEST => Some("TriggerFence"),
+                sync::RESET_FENCE_REQUEST => Some("ResetFence"),
+                sync::DESTROY_FENCE_REQUEST => Some("DestroyFence"),
+                sync::QUERY_FENCE_REQUEST => Some("QueryFence"),
+                sync::AWAIT_FENCE_REQUEST => Some("AwaitFence"),
+                _ => None,
+            }
+        }
+        Some((xc_misc::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xc_misc::GET_VERSION_REQUEST => Some("GetVersion"),
+                xc_misc::GET_XID_RANGE_REQUEST => Some("GetXIDRange"),
+                xc_misc::GET_XID_LIST_REQUEST => Some("GetXIDList"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xevie")]
+        Some((xevie::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xevie::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                xevie::START_REQUEST => Some("Start"),
+                xevie::END_REQUEST => Some("End"),
+                xevie::SEND_REQUEST => Some("Send"),
+                xevie::SELECT_INPUT_REQUEST => Some("SelectInput"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xf86dri")]
+        Some((xf86dri::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xf86dri::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                xf86dri::QUERY_DIRECT_RENDERING_CAPABLE_REQUEST => Some("QueryDirectRenderingCapable"),
+                xf86dri::OPEN_CONNECTION_REQUEST => Some("OpenConnection"),
+                xf86dri::CLOSE_CONNECTION_REQUEST => Some("CloseConnection"),
+                xf86dri::GET_CLIENT_DRIVER_NAME_REQUEST => Some("GetClientDriverName"),
+                xf86dri::CREATE_CONTEXT_REQUEST => Some("CreateContext"),
+                xf86dri::DESTROY_CONTEXT_REQUEST => Some("DestroyContext"),
+                xf86dri::CREATE_DRAWABLE_REQUEST => Some("CreateDrawable"),
+                xf86dri::DESTROY_DRAWABLE_REQUEST => Some("DestroyDrawable"),
+                xf86dri::GET_DRAWABLE_INFO_REQUEST => Some("GetDrawableInfo"),
+                xf86dri::GET_DEVICE_INFO_REQUEST => Some("GetDeviceInfo"),
+                xf86dri::AUTH_CONNECTION_REQUEST => Some("AuthConnection"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xf86vidmode")]
+        Some((xf86vidmode::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xf86vidmode::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                xf86vidmode::GET_MODE_LINE_REQUEST => Some("GetModeLine"),
+                xf86vidmode::MOD_MODE_LINE_REQUEST => Some("ModModeLine"),
+                xf86vidmode::SWITCH_MODE_REQUEST => Some("SwitchMode"),
+                xf86vidmode::GET_MONITOR_REQUEST => Some("GetMonitor"),
+                xf86vidmode::LOCK_MODE_SWITCH_REQUEST => Some("LockModeSwitch"),
+                xf86vidmode::GET_ALL_MODE_LINES_REQUEST => Some("GetAllModeLines"),
+                xf86vidmode::ADD_MODE_LINE_REQUEST => Some("AddModeLine"),
+                xf86vidmode::DELETE_MODE_LINE_REQUEST => Some("DeleteModeLine"),
+                xf86vidmode::VALIDATE_MODE_LINE_REQUEST => Some("ValidateModeLine"),
+                xf86vidmode::SWITCH_TO_MODE_REQUEST => Some("SwitchToMode"),
+                xf86vidmode::GET_VIEW_PORT_REQUEST => Some("GetViewPort"),
+                xf86vidmode::SET_VIEW_PORT_REQUEST => Some("SetViewPort"),
+                xf86vidmode::GET_DOT_CLOCKS_REQUEST => Some("GetDotClocks"),
+                xf86vidmode::SET_CLIENT_VERSION_REQUEST => Some("SetClientVersion"),
+                xf86vidmode::SET_GAMMA_REQUEST => Some("SetGamma"),
+                xf86vidmode::GET_GAMMA_REQUEST => Some("GetGamma"),
+                xf86vidmode::GET_GAMMA_RAMP_REQUEST => Some("GetGammaRamp"),
+                xf86vidmode::SET_GAMMA_RAMP_REQUEST => Some("SetGammaRamp"),
+                xf86vidmode::GET_GAMMA_RAMP_SIZE_REQUEST => Some("GetGammaRampSize"),
+                xf86vidmode::GET_PERMISSIONS_REQUEST => Some("GetPermissions"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xfixes")]
+        Some((xfixes::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xfixes::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                xfixes::CHANGE_SAVE_SET_REQUEST => Some("ChangeSaveSet"),
+                xfixes::SELECT_SELECTION_INPUT_REQUEST => Some("SelectSelectionInput"),
+                xfixes::SELECT_CURSOR_INPUT_REQUEST => Some("SelectCursorInput"),
+                xfixes::GET_CURSOR_IMAGE_REQUEST => Some("GetCursorImage"),
+                xfixes::CREATE_REGION_REQUEST => Some("CreateRegion"),
+                xfixes::CREATE_REGION_FROM_BITMAP_REQUEST => Some("CreateRegionFromBitmap"),
+                xfixes::CREATE_REGION_FROM_WINDOW_REQUEST => Some("CreateRegionFromWindow"),
+                xfixes::CREATE_REGION_FROM_GC_REQUEST => Some("CreateRegionFromGC"),
+                xfixes::CREATE_REGION_FROM_PICTURE_REQUEST => Some("CreateRegionFromPicture"),
+                xfixes::DESTROY_REGION_REQUEST => Some("DestroyRegion"),
+                xfixes::SET_REGION_REQUEST => Some("SetRegion"),
+                xfixes::COPY_REGION_REQUEST => Some("CopyRegion"),
+                xfixes::UNION_REGION_REQUEST => Some("UnionRegion"),
+                xfixes::INTERSECT_REGION_REQUEST => Some("IntersectRegion"),
+                xfixes::SUBTRACT_REGION_REQUEST => Some("SubtractRegion"),
+                xfixes::INVERT_REGION_REQUEST => Some("InvertRegion"),
+                xfixes::TRANSLATE_REGION_REQUEST => Some("TranslateRegion"),
+                xfixes::REGION_EXTENTS_REQUEST => Some("RegionExtents"),
+                xfixes::FETCH_REGION_REQUEST => Some("FetchRegion"),
+                xfixes::SET_GC_CLIP_REGION_REQUEST => Some("SetGCClipRegion"),
+                xfixes::SET_WINDOW_SHAPE_REGION_REQUEST => Some("SetWindowShapeRegion"),
+                xfixes::SET_PICTURE_CLIP_REGION_REQUEST => Some("SetPictureClipRegion"),
+                xfixes::SET_CURSOR_NAME_REQUEST => Some("SetCursorName"),
+                xfixes::GET_CURSOR_NAME_REQUEST => Some("GetCursorName"),
+                xfixes::GET_CURSOR_IMAGE_AND_NAME_REQUEST => Some("GetCursorImageAndName"),
+                xfixes::CHANGE_CURSOR_REQUEST => Some("ChangeCursor"),
+                xfixes::CHANGE_CURSOR_BY_NAME_REQUEST => Some("ChangeCursorByName"),
+                xfixes::EXPAND_REGION_REQUEST => Some("ExpandRegion"),
+                xfixes::HIDE_CURSOR_REQUEST => Some("HideCursor"),
+                xfixes::SHOW_CURSOR_REQUEST => Some("ShowCursor"),
+                xfixes::CREATE_POINTER_BARRIER_REQUEST => Some("CreatePointerBarrier"),
+                xfixes::DELETE_POINTER_BARRIER_REQUEST => Some("DeletePointerBarrier"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xinerama")]
+        Some((xinerama::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xinerama::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                xinerama::GET_STATE_REQUEST => Some("GetState"),
+                xinerama::GET_SCREEN_COUNT_REQUEST => Some("GetScreenCount"),
+                xinerama::GET_SCREEN_SIZE_REQUEST => Some("GetScreenSize"),
+                xinerama::IS_ACTIVE_REQUEST => Some("IsActive"),
+                xinerama::QUERY_SCREENS_REQUEST => Some("QueryScreens"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xinput")]
+        Some((xinput::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xinput::GET_EXTENSION_VERSION_REQUEST => Some("GetExtensionVersion"),
+                xinput::LIST_INPUT_DEVICES_REQUEST => Some("ListInputDevices"),
+                xinput::OPEN_DEVICE_REQUEST => Some("OpenDevice"),
+                xinput::CLOSE_DEVICE_REQUEST => Some("CloseDevice"),
+                xinput::SET_DEVICE_MODE_REQUEST => Some("SetDeviceMode"),
+                xinput::SELECT_EXTENSION_EVENT_REQUEST => Some("SelectExtensionEvent"),
+                xinput::GET_SELECTED_EXTENSION_EVENTS_REQUEST => Some("GetSelectedExtensionEvents"),
+                xinput::CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST => Some("ChangeDeviceDontPropagateList"),
+                xinput::GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST => Some("GetDeviceDontPropagateList"),
+                xinput::GET_DEVICE_MOTION_EVENTS_REQUEST => Some("GetDeviceMotionEvents"),
+                xinput::CHANGE_KEYBOARD_DEVICE_REQUEST => Some("ChangeKeyboardDevice"),
+                xinput::CHANGE_POINTER_DEVICE_REQUEST => Some("ChangePointerDevice"),
+                xinput::GRAB_DEVICE_REQUEST => Some("GrabDevice"),
+                xinput::UNGRAB_DEVICE_REQUEST => Some("UngrabDevice"),
+                xinput::GRAB_DEVICE_KEY_REQUEST => Some("GrabDeviceKey"),
+                xinput::UNGRAB_DEVICE_KEY_REQUEST => Some("UngrabDeviceKey"),
+                xinput::GRAB_DEVICE_BUTTON_REQUEST => Some("GrabDeviceButton"),
+                xinput::UNGRAB_DEVICE_BUTTON_REQUEST => Some("UngrabDeviceButton"),
+                xinput::ALLOW_DEVICE_EVENTS_REQUEST => Some("AllowDeviceEvents"),
+                xinput::GET_DEVICE_FOCUS_REQUEST => Some("GetDeviceFocus"),
+                xinput::SET_DEVICE_FOCUS_REQUEST => Some("SetDeviceFocus"),
+                xinput::GET_FEEDBACK_CONTROL_REQUEST => Some("GetFeedbackControl"),
+                xinput::CHANGE_FEEDBACK_CONTROL_REQUEST => Some("ChangeFeedbackControl"),
+                xinput::GET_DEVICE_KEY_MAPPING_REQUEST => Some("GetDeviceKeyMapping"),
+                xinput::CHANGE_DEVICE_KEY_MAPPING_REQUEST => Some("ChangeDeviceKeyMapping"),
+                xinput::GET_DEVICE_MODIFIER_MAPPING_REQUEST => Some("GetDeviceModifierMapping"),
+                xinput::SET_DEVICE_MODIFIER_MAPPING_REQUEST => Some("SetDeviceModifierMapping"),
+                xinput::GET_DEVICE_BUTTON_MAPPING_REQUEST => Some("GetDeviceButtonMapping"),
+                xinput::SET_DEVICE_BUTTON_MAPPING_REQUEST => Some("SetDeviceButtonMapping"),
+                xinput::QUERY_DEVICE_STATE_REQUEST => Some("QueryDeviceState"),
+                xinput::SEND_EXTENSION_EVENT_REQUEST => Some("SendExtensionEvent"),
+                xinput::DEVICE_BELL_REQUEST => Some("DeviceBell"),
+                xinput::SET_DEVICE_VALUATORS_REQUEST => Some("SetDeviceValuators"),
+                xinput::GET_DEVICE_CONTROL_REQUEST => Some("GetDeviceControl"),
+                xinput::CHANGE_DEVICE_CONTROL_REQUEST => Some("ChangeDeviceControl"),
+                xinput::LIST_DEVICE_PROPERTIES_REQUEST => Some("ListDeviceProperties"),
+                xinput::CHANGE_DEVICE_PROPERTY_REQUEST => Some("ChangeDeviceProperty"),
+                xinput::DELETE_DEVICE_PROPERTY_REQUEST => Some("DeleteDeviceProperty"),
+                xinput::GET_DEVICE_PROPERTY_REQUEST => Some("GetDeviceProperty"),
+                xinput::XI_QUERY_POINTER_REQUEST => Some("XIQueryPointer"),
+                xinput::XI_WARP_POINTER_REQUEST => Some("XIWarpPointer"),
+                xinput::XI_CHANGE_CURSOR_REQUEST => Some("XIChangeCursor"),
+                xinput::XI_CHANGE_HIERARCHY_REQUEST => Some("XIChangeHierarchy"),
+                xinput::XI_SET_CLIENT_POINTER_REQUEST => Some("XISetClientPointer"),
+                xinput::XI_GET_CLIENT_POINTER_REQUEST => Some("XIGetClientPointer"),
+                xinput::XI_SELECT_EVENTS_REQUEST => Some("XISelectEvents"),
+                xinput::XI_QUERY_VERSION_REQUEST => Some("XIQueryVersion"),
+                xinput::XI_QUERY_DEVICE_REQUEST => Some("XIQueryDevice"),
+                xinput::XI_SET_FOCUS_REQUEST => Some("XISetFocus"),
+                xinput::XI_GET_FOCUS_REQUEST => Some("XIGetFocus"),
+                xinput::XI_GRAB_DEVICE_REQUEST => Some("XIGrabDevice"),
+                xinput::XI_UNGRAB_DEVICE_REQUEST => Some("XIUngrabDevice"),
+                xinput::XI_ALLOW_EVENTS_REQUEST => Some("XIAllowEvents"),
+                xinput::XI_PASSIVE_GRAB_DEVICE_REQUEST => Some("XIPassiveGrabDevice"),
+                xinput::XI_PASSIVE_UNGRAB_DEVICE_REQUEST => Some("XIPassiveUngrabDevice"),
+                xinput::XI_LIST_PROPERTIES_REQUEST => Some("XIListProperties"),
+                xinput::XI_CHANGE_PROPERTY_REQUEST => Some("XIChangeProperty"),
+                xinput::XI_DELETE_PROPERTY_REQUEST => Some("XIDeleteProperty"),
+                xinput::XI_GET_PROPERTY_REQUEST => Some("XIGetProperty"),
+                xinput::XI_GET_SELECTED_EVENTS_REQUEST => Some("XIGetSelectedEvents"),
+                xinput::XI_BARRIER_RELEASE_POINTER_REQUEST => Some("XIBarrierReleasePointer"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xkb")]
+        Some((xkb::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xkb::USE_EXTENSION_REQUEST => Some("UseExtension"),
+                xkb::SELECT_EVENTS_REQUEST => Some("SelectEvents"),
+                xkb::BELL_REQUEST => Some("Bell"),
+                xkb::GET_STATE_REQUEST => Some("GetState"),
+                xkb::LATCH_LOCK_STATE_REQUEST => Some("LatchLockState"),
+                xkb::GET_CONTROLS_REQUEST => Some("GetControls"),
+                xkb::SET_CONTROLS_REQUEST => Some("SetControls"),
+                xkb::GET_MAP_REQUEST => Some("GetMap"),
+                xkb::SET_MAP_REQUEST => Some("SetMap"),
+                xkb::GET_COMPAT_MAP_REQUEST => Some("GetCompatMap"),
+                xkb::SET_COMPAT_MAP_REQUEST => Some("SetCompatMap"),
+                xkb::GET_INDICATOR_STATE_REQUEST => Some("GetIndicatorState"),
+                xkb::GET_INDICATOR_MAP_REQUEST => Some("GetIndicatorMap"),
+                xkb::SET_INDICATOR_MAP_REQUEST => Some("SetIndicatorMap"),
+                xkb::GET_NAMED_INDICATOR_REQUEST => Some("GetNamedIndicator"),
+                xkb::SET_NAMED_INDICATOR_REQUEST => Some("SetNamedIndicator"),
+                xkb::GET_NAMES_REQUEST => Some("GetNames"),
+                xkb::SET_NAMES_REQUEST => Some("SetNames"),
+                xkb::PER_CLIENT_FLAGS_REQUEST => Some("PerClientFlags"),
+                xkb::LIST_COMPONENTS_REQUEST => Some("ListComponents"),
+                xkb::GET_KBD_BY_NAME_REQUEST => Some("GetKbdByName"),
+                xkb::GET_DEVICE_INFO_REQUEST => Some("GetDeviceInfo"),
+                xkb::SET_DEVICE_INFO_REQUEST => Some("SetDeviceInfo"),
+                xkb::SET_DEBUGGING_FLAGS_REQUEST => Some("SetDebuggingFlags"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xprint")]
+        Some((xprint::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xprint::PRINT_QUERY_VERSION_REQUEST => Some("PrintQueryVersion"),
+                xprint::PRINT_GET_PRINTER_LIST_REQUEST => Some("PrintGetPrinterList"),
+                xprint::CREATE_CONTEXT_REQUEST => Some("CreateContext"),
+                xprint::PRINT_SET_CONTEXT_REQUEST => Some("PrintSetContext"),
+                xprint::PRINT_GET_CONTEXT_REQUEST => Some("PrintGetContext"),
+                xprint::PRINT_DESTROY_CONTEXT_REQUEST => Some("PrintDestroyContext"),
+                xprint::PRINT_GET_SCREEN_OF_CONTEXT_REQUEST => Some("PrintGetScreenOfContext"),
+                xprint::PRINT_START_JOB_REQUEST => Some("PrintStartJob"),
+                xprint::PRINT_END_JOB_REQUEST => Some("PrintEndJob"),
+                xprint::PRINT_START_DOC_REQUEST => Some("PrintStartDoc"),
+                xprint::PRINT_END_DOC_REQUEST => Some("PrintEndDoc"),
+                xprint::PRINT_PUT_DOCUMENT_DATA_REQUEST => Some("PrintPutDocumentData"),
+                xprint::PRINT_GET_DOCUMENT_DATA_REQUEST => Some("PrintGetDocumentData"),
+                xprint::PRINT_START_PAGE_REQUEST => Some("PrintStartPage"),
+                xprint::PRINT_END_PAGE_REQUEST => Some("PrintEndPage"),
+                xprint::PRINT_SELECT_INPUT_REQUEST => Some("PrintSelectInput"),
+                xprint::PRINT_INPUT_SELECTED_REQUEST => Some("PrintInputSelected"),
+                xprint::PRINT_GET_ATTRIBUTES_REQUEST => Some("PrintGetAttributes"),
+                xprint::PRINT_SET_ATTRIBUTES_REQUEST => Some("PrintSetAttributes"),
+                xprint::PRINT_GET_ONE_ATTRIBUTES_REQUEST => Some("PrintGetOneAttributes"),
+                xprint::PRINT_REHASH_PRINTER_LIST_REQUEST => Some("PrintRehashPrinterList"),
+                xprint::PRINT_GET_PAGE_DIMENSIONS_REQUEST => Some("PrintGetPageDimensions"),
+                xprint::PRINT_QUERY_SCREENS_REQUEST => Some("PrintQueryScreens"),
+                xprint::PRINT_SET_IMAGE_RESOLUTION_REQUEST => Some("PrintSetImageResolution"),
+                xprint::PRINT_GET_IMAGE_RESOLUTION_REQUEST => Some("PrintGetImageResolution"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xselinux")]
+        Some((xselinux::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xselinux::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                xselinux::SET_DEVICE_CREATE_CONTEXT_REQUEST => Some("SetDeviceCreateContext"),
+                xselinux::GET_DEVICE_CREATE_CONTEXT_REQUEST => Some("GetDeviceCreateContext"),
+                xselinux::SET_DEVICE_CONTEXT_REQUEST => Some("SetDeviceContext"),
+                xselinux::GET_DEVICE_CONTEXT_REQUEST => Some("GetDeviceContext"),
+                xselinux::SET_WINDOW_CREATE_CONTEXT_REQUEST => Some("SetWindowCreateContext"),
+                xselinux::GET_WINDOW_CREATE_CONTEXT_REQUEST => Some("GetWindowCreateContext"),
+                xselinux::GET_WINDOW_CONTEXT_REQUEST => Some("GetWindowContext"),
+                xselinux::SET_PROPERTY_CREATE_CONTEXT_REQUEST => Some("SetPropertyCreateContext"),
+                xselinux::GET_PROPERTY_CREATE_CONTEXT_REQUEST => Some("GetPropertyCreateContext"),
+                xselinux::SET_PROPERTY_USE_CONTEXT_REQUEST => Some("SetPropertyUseContext"),
+                xselinux::GET_PROPERTY_USE_CONTEXT_REQUEST => Some("GetPropertyUseContext"),
+                xselinux::GET_PROPERTY_CONTEXT_REQUEST => Some("GetPropertyContext"),
+                xselinux::GET_PROPERTY_DATA_CONTEXT_REQUEST => Some("GetPropertyDataContext"),
+                xselinux::LIST_PROPERTIES_REQUEST => Some("ListProperties"),
+                xselinux::SET_SELECTION_CREATE_CONTEXT_REQUEST => Some("SetSelectionCreateContext"),
+                xselinux::GET_SELECTION_CREATE_CONTEXT_REQUEST => Some("GetSelectionCreateContext"),
+                xselinux::SET_SELECTION_USE_CONTEXT_REQUEST => Some("SetSelectionUseContext"),
+                xselinux::GET_SELECTION_USE_CONTEXT_REQUEST => Some("GetSelectionUseContext"),
+                xselinux::GET_SELECTION_CONTEXT_REQUEST => Some("GetSelectionContext"),
+                xselinux::GET_SELECTION_DATA_CONTEXT_REQUEST => Some("GetSelectionDataContext"),
+                xselinux::LIST_SELECTIONS_REQUEST => Some("ListSelections"),
+                xselinux::GET_CLIENT_CONTEXT_REQUEST => Some("GetClientContext"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xtest")]
+        Some((xtest::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xtest::GET_VERSION_REQUEST => Some("GetVersion"),
+                xtest::COMPARE_CURSOR_REQUEST => Some("CompareCursor"),
+                xtest::FAKE_INPUT_REQUEST => Some("FakeInput"),
+                xtest::GRAB_CONTROL_REQUEST => Some("GrabControl"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xv")]
+        Some((xv::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xv::QUERY_EXTENSION_REQUEST => Some("QueryExtension"),
+                xv::QUERY_ADAPTORS_REQUEST => Some("QueryAdaptors"),
+                xv::QUERY_ENCODINGS_REQUEST => Some("QueryEncodings"),
+                xv::GRAB_PORT_REQUEST => Some("GrabPort"),
+                xv::UNGRAB_PORT_REQUEST => Some("UngrabPort"),
+                xv::PUT_VIDEO_REQUEST => Some("PutVideo"),
+                xv::PUT_STILL_REQUEST => Some("PutStill"),
+                xv::GET_VIDEO_REQUEST => Some("GetVideo"),
+                xv::GET_STILL_REQUEST => Some("GetStill"),
+                xv::STOP_VIDEO_REQUEST => Some("StopVideo"),
+                xv::SELECT_VIDEO_NOTIFY_REQUEST => Some("SelectVideoNotify"),
+                xv::SELECT_PORT_NOTIFY_REQUEST => Some("SelectPortNotify"),
+                xv::QUERY_BEST_SIZE_REQUEST => Some("QueryBestSize"),
+                xv::SET_PORT_ATTRIBUTE_REQUEST => Some("SetPortAttribute"),
+                xv::GET_PORT_ATTRIBUTE_REQUEST => Some("GetPortAttribute"),
+                xv::QUERY_PORT_ATTRIBUTES_REQUEST => Some("QueryPortAttributes"),
+                xv::LIST_IMAGE_FORMATS_REQUEST => Some("ListImageFormats"),
+                xv::QUERY_IMAGE_ATTRIBUTES_REQUEST => Some("QueryImageAttributes"),
+                xv::PUT_IMAGE_REQUEST => Some("PutImage"),
+                xv::SHM_PUT_IMAGE_REQUEST => Some("ShmPutImage"),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xvmc")]
+        Some((xvmc::X11_EXTENSION_NAME, _)) => {
+            match minor_opcode {
+                xvmc::QUERY_VERSION_REQUEST => Some("QueryVersion"),
+                xvmc::LIST_SURFACE_TYPES_REQUEST => Some("ListSurfaceTypes"),
+                xvmc::CREATE_CONTEXT_REQUEST => Some("CreateContext"),
+                xvmc::DESTROY_CONTEXT_REQUEST => Some("DestroyContext"),
+                xvmc::CREATE_SURFACE_REQUEST => Some("CreateSurface"),
+                xvmc::DESTROY_SURFACE_REQUEST => Some("DestroySurface"),
+                xvmc::CREATE_SUBPICTURE_REQUEST => Some("CreateSubpicture"),
+                xvmc::DESTROY_SUBPICTURE_REQUEST => Some("DestroySubpicture"),
+                xvmc::LIST_SUBPICTURE_TYPES_REQUEST => Some("ListSubpictureTypes"),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Enumeration of all possible X11 requests.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum Request {
+    Unknown(RequestHeader, Vec<u8>),
+    CreateWindow(xproto::CreateWindowRequest),
+    ChangeWindowAttributes(xproto::ChangeWindowAttributesRequest),
+    GetWindowAttributes(xproto::GetWindowAttributesRequest),
+    DestroyWindow(xproto::DestroyWindowRequest),
+    DestroySubwindows(xproto::DestroySubwindowsRequest),
+    ChangeSaveSet(xproto::ChangeSaveSetRequest),
+    ReparentWindow(xproto::ReparentWindowRequest),
+    MapWindow(xproto::MapWindowRequest),
+    MapSubwindows(xproto::MapSubwindowsRequest),
+    UnmapWindow(xproto::UnmapWindowRequest),
+    UnmapSubwindows(xproto::UnmapSubwindowsRequest),
+    ConfigureWindow(xproto::ConfigureWindowRequest),
+    CirculateWindow(xproto::CirculateWindowRequest),
+    GetGeometry(xproto::GetGeometryRequest),
+    QueryTree(xproto::QueryTreeRequest),
+    InternAtom(xproto::InternAtomRequest),
+    GetAtomName(xproto::GetAtomNameRequest),
+    ChangeProperty(xproto::ChangePropertyRequest),
+    DeleteProperty(xproto::DeletePropertyRequest),
+    GetProperty(xproto::GetPropertyRequest),
+    ListProperties(xproto::ListPropertiesRequest),
+    SetSelectionOwner(xproto::SetSelectionOwnerRequest),
+    GetSelectionOwner(xproto::GetSelectionOwnerRequest),
+    ConvertSelection(xproto::ConvertSelectionRequest),
+    SendEvent(xproto::SendEventRequest),
+    GrabPointer(xproto::GrabPointerRequest),
+    UngrabPointer(xproto::UngrabPointerRequest),
+    GrabButton(xproto::GrabButtonRequest),
+    UngrabButton(xproto::UngrabButtonRequest),
+    ChangeActivePointerGrab(xproto::ChangeActivePointerGrabRequest),
+    GrabKeyboard(xproto::GrabKeyboardRequest),
+    UngrabKeyboard(xproto::UngrabKeyboardRequest),
+    GrabKey(xproto::GrabKeyRequest),
+    UngrabKey(xproto::UngrabKeyRequest),
+    AllowEvents(xproto::AllowEventsRequest),
+    GrabServer(xproto::GrabServerRequest),
+    UngrabServer(xproto::UngrabServerRequest),
+    QueryPointer(xproto::QueryPointerRequest),
+    GetMotionEvents(xproto::GetMotionEventsRequest),
+    TranslateCoordinates(xproto::TranslateCoordinatesRequest),
+    WarpPointer(xproto::WarpPointerRequest),
+    SetInputFocus(xproto::SetInputFocusRequest),
+    GetInputFocus(xproto::GetInputFocusRequest),
+    QueryKeymap(xproto::QueryKeymapRequest),
+    OpenFont(xproto::OpenFontRequest),
+    CloseFont(xproto::CloseFontRequest),
+    QueryFont(xproto::QueryFontRequest),
+    QueryTextExtents(xproto::QueryTextExtentsRequest),
+    ListFonts(xproto::ListFontsRequest),
+    ListFontsWithInfo(xproto::ListFontsWithInfoRequest),
+    SetFontPath(xproto::SetFontPathRequest),
+    GetFontPath(xproto::GetFontPathRequest),
+    CreatePixmap(xproto::CreatePixmapRequest),
+    FreePixmap(xproto::FreePixmapRequest),
+    CreateGC(xproto::CreateGCRequest),
+    ChangeGC(xproto::ChangeGCRequest),
+    CopyGC(xproto::CopyGCRequest),
+    SetDashes(xproto::SetDashesRequest),
+    SetClipRectangles(xproto::SetClipRectanglesRequest),
+    FreeGC(xproto::FreeGCRequest),
+    ClearArea(xproto::ClearAreaRequest),
+    CopyArea(xproto::CopyAreaRequest),
+    CopyPlane(xproto::CopyPlaneRequest),
+    PolyPoint(xproto::PolyPointRequest),
+    PolyLine(xproto::PolyLineRequest),
+    PolySegment(xproto::PolySegmentRequest),
+    PolyRectangle(xproto::PolyRectangleRequest),
+    PolyArc(xproto::PolyArcRequest),
+    FillPoly(xproto::FillPolyRequest),
+    PolyFillRectangle(xproto::PolyFillRectangleRequest),
+    PolyFillArc(xproto::PolyFillArcRequest),
+    PutImage(xproto::PutImageRequest),
+    GetImage(xproto::GetImageRequest),
+    PolyText8(xproto::PolyText8Request),
+    PolyText16(xproto::PolyText16Request),
+    ImageText8(xproto::ImageText8Request),
+    ImageText16(xproto::ImageText16Request),
+    CreateColormap(xproto::CreateColormapRequest),
+    FreeColormap(xproto::FreeColormapRequest),
+    CopyColormapAndFree(xproto::CopyColormapAndFreeRequest),
+    InstallColormap(xproto::InstallColormapRequest),
+    UninstallColormap(xproto::UninstallColormapRequest),
+    ListInstalledColormaps(xproto::ListInstalledColormapsRequest),
+    AllocColor(xproto::AllocColorRequest),
+    AllocNamedColor(xproto::AllocNamedColorRequest),
+    AllocColorCells(xproto::AllocColorCellsRequest),
+    AllocColorPlanes(xproto::AllocColorPlanesRequest),
+    FreeColors(xproto::FreeColorsRequest),
+    StoreColors(xproto::StoreColorsRequest),
+    StoreNamedColor(xproto::StoreNamedColorRequest),
+    QueryColors(xproto::QueryColorsRequest),
+    LookupColor(xproto::LookupColorRequest),
+    CreateCursor(xproto::CreateCursorRequest),
+    CreateGlyphCursor(xproto::CreateGlyphCursorRequest),
+    FreeCursor(xproto::FreeCursorRequest),
+    RecolorCursor(xproto::RecolorCursorRequest),
+    QueryBestSize(xproto::QueryBestSizeRequest),
+    QueryExtension(xproto::QueryExtensionRequest),
+    ListExtensions(xproto::ListExtensionsRequest),
+    ChangeKeyboardMapping(xproto::ChangeKeyboardMappingRequest),
+    GetKeyboardMapping(xproto::GetKeyboardMappingRequest),
+    ChangeKeyboardControl(xproto::ChangeKeyboardControlRequest),
+    GetKeyboardControl(xproto::GetKeyboardControlRequest),
+    Bell(xproto::BellRequest),
+    ChangePointerControl(xproto::ChangePointerControlRequest),
+    GetPointerControl(xproto::GetPointerControlRequest),
+    SetScreenSaver(xproto::SetScreenSaverRequest),
+    GetScreenSaver(xproto::GetScreenSaverRequest),
+    ChangeHosts(xproto::ChangeHostsRequest),
+    ListHosts(xproto::ListHostsRequest),
+    SetAccessControl(xproto::SetAccessControlRequest),
+    SetCloseDownMode(xproto::SetCloseDownModeRequest),
+    KillClient(xproto::KillClientRequest),
+    RotateProperties(xproto::RotatePropertiesRequest),
+    ForceScreenSaver(xproto::ForceScreenSaverRequest),
+    SetPointerMapping(xproto::SetPointerMappingRequest),
+    GetPointerMapping(xproto::GetPointerMappingRequest),
+    SetModifierMapping(xproto::SetModifierMappingRequest),
+    GetModifierMapping(xproto::GetModifierMappingRequest),
+    NoOperation(xproto::NoOperationRequest),
+    BigreqEnable(bigreq::EnableRequest),
+    #[cfg(feature = "composite")]
+    CompositeQueryVersion(composite::QueryVersionRequest),
+    #[cfg(feature = "composite")]
+    CompositeRedirectWindow(composite::RedirectWindowRequest),
+    #[cfg(feature = "composite")]
+    CompositeRedirectSubwindows(composite::RedirectSubwindowsRequest),
+    #[cfg(feature = "composite")]
+    CompositeUnredirectWindow(composite::UnredirectWindowRequest),
+    #[cfg(feature = "composite")]
+    CompositeUnredirectSubwindows(composite::UnredirectSubwindowsRequest),
+    #[cfg(feature = "composite")]
+    CompositeCreateRegionFromBorderClip(composite::CreateRegionFromBorderClipRequest),
+    #[cfg(feature = "composite")]
+    CompositeNameWindowPixmap(composite::NameWindowPixmapRequest),
+    #[cfg(feature = "composite")]
+    CompositeGetOverlayWindow(composite::GetOverlayWindowRequest),
+    #[cfg(feature = "composite")]
+    CompositeReleaseOverlayWindow(composite::ReleaseOverlayWindowRequest),
+    #[cfg(feature = "damage")]
+    DamageQueryVersion(damage::QueryVersionRequest),
+    #[cfg(feature = "damage")]
+    DamageCreate(damage::CreateRequest),
+    #[cfg(feature = "damage")]
+    DamageDestroy(damage::DestroyRequest),
+    #[cfg(feature = "damage")]
+    DamageSubtract(damage::SubtractRequest),
+    #[cfg(feature = "damage")]
+    DamageAdd(damage::AddRequest),
+    #[cfg(feature = "dpms")]
+    DpmsGetVersion(dpms::GetVersionRequest),
+    #[cfg(feature = "dpms")]
+    DpmsCapable(dpms::CapableRequest),
+    #[cfg(feature = "dpms")]
+    DpmsGetTimeouts(dpms::GetTimeoutsRequest),
+    #[cfg(feature = "dpms")]
+    DpmsSetTimeouts(dpms::SetTimeoutsRequest),
+    #[cfg(feature = "dpms")]
+    DpmsEnable(dpms::EnableRequest),
+    #[cfg(feature = "dpms")]
+    DpmsDisable(dpms::DisableRequest),
+    #[cfg(feature = "dpms")]
+    DpmsForceLevel(dpms::ForceLevelRequest),
+    #[cfg(feature = "dpms")]
+    DpmsInfo(dpms::InfoRequest),
+    #[cfg(feature = "dri2")]
+    Dri2QueryVersion(dri2::QueryVersionRequest),
+    #[cfg(feature = "dri2")]
+    Dri2Connect(dri2::ConnectRequest),
+    #[cfg(feature = "dri2")]
+    Dri2Authenticate(dri2::AuthenticateRequest),
+    #[cfg(feature = "dri2")]
+    Dri2CreateDrawable(dri2::CreateDrawableRequest),
+    #[cfg(feature = "dri2")]
+    Dri2DestroyDrawable(dri2::DestroyDrawableRequest),
+    #[cfg(feature = "dri2")]
+    Dri2GetBuffers(dri2::GetBuffersRequest),
+    #[cfg(feature = "dri2")]
+    Dri2CopyRegion(dri2::CopyRegionRequest),
+    #[cfg(feature = "dri2")]
+    Dri2GetBuffersWithFormat(dri2::GetBuffersWithFormatRequest),
+    #[cfg(feature = "dri2")]
+    Dri2SwapBuffers(dri2::SwapBuffersRequest),
+    #[cfg(feature = "dri2")]
+    Dri2GetMSC(dri2::GetMSCRequest),
+    #[cfg(feature = "dri2")]
+    Dri2WaitMSC(dri2::WaitMSCRequest),
+    #[cfg(feature = "dri2")]
+    Dri2WaitSBC(dri2::WaitSBCRequest),
+    #[cfg(feature = "dri2")]
+    Dri2SwapInterval(dri2::SwapIntervalRequest),
+    #[cfg(feature = "dri2")]
+    Dri2GetParam(dri2::GetParamRequest),
+    #[cfg(feature = "dri3")]
+    Dri3QueryVersion(dri3::QueryVersionRequest),
+    #[cfg(feature = "dri3")]
+    Dri3Open(dri3::OpenRequest),
+    #[cfg(feature = "dri3")]
+    Dri3PixmapFromBuffer(dri3::PixmapFromBufferRequest),
+    #[cfg(feature = "dri3")]
+    Dri3BufferFromPixmap(dri3::BufferFromPixmapRequest),
+    #[cfg(feature = "dri3")]
+    Dri3FenceFromFD(dri3::FenceFromFDRequest),
+    #[cfg(feature = "dri3")]
+    Dri3FDFromFence(dri3::FDFromFenceRequest),
+    #[cfg(feature = "dri3")]
+    Dri3GetSupportedModifiers(dri3::GetSupportedModifiersRequest),
+    #[cfg(feature = "dri3")]
+    Dri3PixmapFromBuffers(dri3::PixmapFromBuffersRequest),
+    #[cfg(feature = "dri3")]
+    Dri3BuffersFromPixmap(dri3::BuffersFromPixmapRequest),
+    GeQueryVersion(ge::QueryVersionRequest),
+    #[cfg(feature = "glx")]
+    GlxRender(glx::RenderRequest),
+    #[cfg(feature = "glx")]
+    GlxRenderLarge(glx::RenderLargeRequest),
+    #[cfg(feature = "glx")]
+    GlxCreateContext(glx::CreateContextRequest),
+    #[cfg(feature = "glx")]
+    GlxDestroyContext(glx::DestroyContextRequest),
+    #[cfg(feature = "glx")]
+    GlxMakeCurrent(glx::MakeCurrentRequest),
+    #[cfg(feature = "glx")]
+    GlxIsDirect(glx::IsDirectRequest),
+    #[cfg(feature = "glx")]
+    GlxQueryVersion(glx::QueryVersionRequest),
+    #[cfg(feature = "glx")]
+    GlxWaitGL(glx::WaitGLRequest),
+    #[cfg(feature = "glx")]
+    GlxWaitX(glx::WaitXRequest),
+    #[cfg(feature = "glx")]
+    GlxCopyContext(glx::CopyContextRequest),
+    #[cfg(feature = "glx")]
+    GlxSwapBuffers(glx::SwapBuffersRequest),
+    #[cfg(feature = "glx")]
+    GlxUseXFont(glx::UseXFontRequest),
+    #[cfg(feature = "glx")]
+    GlxCreateGLXPixmap(glx::CreateGLXPixmapRequest),
+    #[cfg(feature = "glx")]
+    GlxGetVisualConfigs(glx::GetVisualConfigsRequest),
+    #[cfg(feature = "glx")]
+    GlxDestroyGLXPixmap(glx::DestroyGLXPixmapRequest),
+    #[cfg(feature = "glx")]
+    GlxVendorPrivate(glx::VendorPrivateRequest),
+    #[cfg(feature = "glx")]
+    GlxVendorPrivateWithReply(glx::VendorPrivateWithReplyRequest),
+    #[cfg(feature = "glx")]
+    GlxQueryExtensionsString(glx::QueryExtensionsStringRequest),
+    #[cfg(feature = "glx")]
+    GlxQueryServerString(glx::QueryServerStringRequest),
+    #[cfg(feature = "glx")]
+    GlxClientInfo(glx::ClientInfoRequest),
+    #[cfg(feature = "glx")]
+    GlxGetFBConfigs(glx::GetFBConfigsRequest),
+    #[cfg(feature = "glx")]
+    GlxCreatePixmap(glx::CreatePixmapRequest),
+    #[cfg(feature = "glx")]
+    GlxDestroyPixmap(glx::DestroyPixmapRequest),
+    #[cfg(feature = "glx")]
+    GlxCreateNewContext(glx::CreateNewContextRequest),
+    #[cfg(feature = "glx")]
+    GlxQueryContext(glx::QueryContextRequest),
+    #[cfg(feature = "glx")]
+    GlxMakeContextCurrent(glx::MakeContextCurrentRequest),
+    #[cfg(feature = "glx")]
+    GlxCreatePbuffer(glx::CreatePbufferRequest),
+    #[cfg(feature = "glx")]
+    GlxDestroyPbuffer(glx::DestroyPbufferRequest),
+    #[cfg(feature = "glx")]
+    GlxGetDrawableAttributes(glx::GetDrawableAttributesRequest),
+    #[cfg(feature = "glx")]
+    GlxChangeDrawableAttributes(glx::ChangeDrawableAttributesRequest),
+    #[cfg(feature = "glx")]
+    GlxCreateWindow(glx::CreateWindowRequest),
+    #[cfg(feature = "glx")]
+    GlxDeleteWindow(glx::DeleteWindowRequest),
+    #[cfg(feature = "glx")]
+    GlxSetClientInfoARB(glx::SetClientInfoARBRequest),
+    #[cfg(feature = "glx")]
+    GlxCreateContextAttribsARB(glx::CreateContextAttribsARBRequest),
+    #[cfg(feature = "glx")]
+    GlxSetClientInfo2ARB(glx::SetClientInfo2ARBRequest),
+    #[cfg(feature = "glx")]
+    GlxNewList(glx::NewListRequest),
+    #[cfg(feature = "glx")]
+    GlxEndList(glx::EndListRequest),
+    #[cfg(feature = "glx")]
+    GlxDeleteLists(glx::DeleteListsRequest),
+    #[cfg(feature = "glx")]
+    GlxGenLists(glx::GenListsRequest),
+    #[cfg(feature = "glx")]
+    GlxFeedbackBuffer(glx::FeedbackBufferRequest),
+    #[cfg(feature = "glx")]
+    GlxSelectBuffer(glx::SelectBufferRequest),
+    #[cfg(feature = "glx")]
+    GlxRenderMode(glx::RenderModeRequest),
+    #[cfg(feature = "glx")]
+    GlxFinish(glx::FinishRequest),
+    #[cfg(feature = "glx")]
+    GlxPixelStoref(glx::PixelStorefRequest),
+    #[cfg(feature = "glx")]
+    GlxPixelStorei(glx::PixelStoreiRequest),
+    #[cfg(feature = "glx")]
+    GlxReadPixels(glx::ReadPixelsRequest),
+    #[cfg(feature = "glx")]
+    GlxGetBooleanv(glx::GetBooleanvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetClipPlane(glx::GetClipPlaneRequest),
+    #[cfg(feature = "glx")]
+    GlxGetDoublev(glx::GetDoublevRequest),
+    #[cfg(feature = "glx")]
+    GlxGetError(glx::GetErrorRequest),
+    #[cfg(feature = "glx")]
+    GlxGetFloatv(glx::GetFloatvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetIntegerv(glx::GetIntegervRequest),
+    #[cfg(feature = "glx")]
+    GlxGetLightfv(glx::GetLightfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetLightiv(glx::GetLightivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetMapdv(glx::GetMapdvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetMapfv(glx::GetMapfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetMapiv(glx::GetMapivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetMaterialfv(glx::GetMaterialfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetMaterialiv(glx::GetMaterialivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetPixelMapfv(glx::GetPixelMapfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetPixelMapuiv(glx::GetPixelMapuivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetPixelMapusv(glx::GetPixelMapusvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetPolygonStipple(glx::GetPolygonStippleRequest),
+    #[cfg(feature = "glx")]
+    GlxGetString(glx::GetStringRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexEnvfv(glx::GetTexEnvfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexEnviv(glx::GetTexEnvivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexGendv(glx::GetTexGendvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexGenfv(glx::GetTexGenfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexGeniv(glx::GetTexGenivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexImage(glx::GetTexImageRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexParameterfv(glx::GetTexParameterfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexParameteriv(glx::GetTexParameterivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexLevelParameterfv(glx::GetTexLevelParameterfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetTexLevelParameteriv(glx::GetTexLevelParameterivRequest),
+    #[cfg(feature = "glx")]
+    GlxIsEnabled(glx::IsEnabledRequest),
+    #[cfg(feature = "glx")]
+    GlxIsList(glx::IsListRequest),
+    #[cfg(feature = "glx")]
+    GlxFlush(glx::FlushRequest),
+    #[cfg(feature = "glx")]
+    GlxAreTexturesResident(glx::AreTexturesResidentRequest),
+    #[cfg(feature = "glx")]
+    GlxDeleteTextures(glx::DeleteTexturesRequest),
+    #[cfg(feature = "glx")]
+    GlxGenTextures(glx::GenTexturesRequest),
+    #[cfg(feature = "glx")]
+    GlxIsTexture(glx::IsTextureRequest),
+    #[cfg(feature = "glx")]
+    GlxGetColorTable(glx::GetColorTableRequest),
+    #[cfg(feature = "glx")]
+    GlxGetColorTableParameterfv(glx::GetColorTableParameterfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetColorTableParameteriv(glx::GetColorTableParameterivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetConvolutionFilter(glx::GetConvolutionFilterRequest),
+    #[cfg(feature = "glx")]
+    GlxGetConvolutionParameterfv(glx::GetConvolutionParameterfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetConvolutionParameteriv(glx::GetConvolutionParameterivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetSeparableFilter(glx::GetSeparableFilterRequest),
+    #[cfg(feature = "glx")]
+    GlxGetHistogram(glx::GetHistogramRequest),
+    #[cfg(feature = "glx")]
+    GlxGetHistogramParameterfv(glx::GetHistogramParameterfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetHistogramParameteriv(glx::GetHistogramParameterivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetMinmax(glx::GetMinmaxRequest),
+    #[cfg(feature = "glx")]
+    GlxGetMinmaxParameterfv(glx::GetMinmaxParameterfvRequest),
+    #[cfg(feature = "glx")]
+    GlxGetMinmaxParameteriv(glx::GetMinmaxParameterivRequest),
+    #[cfg(feature = "glx")]
+    GlxGetCompressedTexImageARB(glx::GetCompressedTexImageARBRequest),
+    #[cfg(feature = "glx")]
+    GlxDeleteQueriesARB(glx::DeleteQueriesARBRequest),
+    #[cfg(feature = "glx")]
+    GlxGenQueriesARB(glx::GenQueriesARBRequest),
+    #[cfg(feature = "glx")]
+    GlxIsQueryARB(glx::IsQueryARBRequest),
+    #[cfg(feature = "glx")]
+    GlxGetQueryivARB(glx::GetQueryivARBRequest),
+    #[cfg(feature = "glx")]
+    GlxGetQueryObjectivARB(glx::GetQueryObjectivARBRequest),
+    #[cfg(feature = "glx")]
+    GlxGetQueryObjectuivARB(glx::GetQueryObjectuivARBRequest),
+    #[cfg(feature = "present")]
+    PresentQueryVersion(present::QueryVersionRequest),
+    #[cfg(feature = "present")]
+    PresentPixmap(present::PixmapRequest),
+    #[cfg(feature = "present")]
+    PresentNotifyMSC(present::NotifyMSCRequest),
+    #[cfg(feature = "present")]
+    PresentSelectInput(present::SelectInputRequest),
+    #[cfg(feature = "present")]
+    PresentQueryCapabilities(present::QueryCapabilitiesRequest),
+    #[cfg(feature = "randr")]
+    RandrQueryVersion(randr::QueryVersionRequest),
+    #[cfg(feature = "randr")]
+    RandrSetScreenConfig(randr::SetScreenConfigRequest),
+    #[cfg(feature = "randr")]
+    RandrSelectInput(randr::SelectInputRequest),
+    #[cfg(feature = "randr")]
+    RandrGetScreenInfo(randr::GetScreenInfoRequest),
+    #[cfg(feature = "randr")]
+    RandrGetScreenSizeRange(randr::GetScreenSizeRangeRequest),
+    #[cfg(feature = "randr")]
+    RandrSetScreenSize(randr::SetScreenSizeRequest),
+    #[cfg(feature = "randr")]
+    RandrGetScreenResources(randr::GetScreenResourcesRequest),
+    #[cfg(feature = "randr")]
+    RandrGetOutputInfo(randr::GetOutputInfoRequest),
+    #[cfg(feature = "randr")]
+    RandrListOutputProperties(randr::ListOutputPropertiesRequest),
+    #[cfg(feature = "randr")]
+    RandrQueryOutputProperty(randr::QueryOutputPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrConfigureOutputProperty(randr::ConfigureOutputPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrChangeOutputProperty(randr::ChangeOutputPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrDeleteOutputProperty(randr::DeleteOutputPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrGetOutputProperty(randr::GetOutputPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrCreateMode(randr::CreateModeRequest),
+    #[cfg(feature = "randr")]
+    RandrDestroyMode(randr::DestroyModeRequest),
+    #[cfg(feature = "randr")]
+    RandrAddOutputMode(randr::AddOutputModeRequest),
+    #[cfg(feature = "randr")]
+    RandrDeleteOutputMode(randr::DeleteOutputModeRequest),
+    #[cfg(feature = "randr")]
+    RandrGetCrtcInfo(randr::GetCrtcInfoRequest),
+    #[cfg(feature = "randr")]
+    RandrSetCrtcConfig(randr::SetCrtcConfigRequest),
+    #[cfg(feature = "randr")]
+    RandrGetCrtcGammaSize(randr::GetCrtcGammaSizeRequest),
+    #[cfg(feature = "randr")]
+    RandrGetCrtcGamma(randr::GetCrtcGammaRequest),
+    #[cfg(feature = "randr")]
+    RandrSetCrtcGamma(randr::SetCrtcGammaRequest),
+    #[cfg(feature = "randr")]
+    RandrGetScreenResourcesCurrent(randr::GetScreenResourcesCurrentRequest),
+    #[cfg(feature = "randr")]
+    RandrSetCrtcTransform(randr::SetCrtcTransformRequest),
+    #[cfg(feature = "randr")]
+    RandrGetCrtcTransform(randr::GetCrtcTransformRequest),
+    #[cfg(feature = "randr")]
+    RandrGetPanning(randr::GetPanningRequest),
+    #[cfg(feature = "randr")]
+    RandrSetPanning(randr::SetPanningRequest),
+    #[cfg(feature = "randr")]
+    RandrSetOutputPrimary(randr::SetOutputPrimaryRequest),
+    #[cfg(feature = "randr")]
+    RandrGetOutputPrimary(randr::GetOutputPrimaryRequest),
+    #[cfg(feature = "randr")]
+    RandrGetProviders(randr::GetProvidersRequest),
+    #[cfg(feature = "randr")]
+    RandrGetProviderInfo(randr::GetProviderInfoRequest),
+    #[cfg(feature = "randr")]
+    RandrSetProviderOffloadSink(randr::SetProviderOffloadSinkRequest),
+    #[cfg(feature = "randr")]
+    RandrSetProviderOutputSource(randr::SetProviderOutputSourceRequest),
+    #[cfg(feature = "randr")]
+    RandrListProviderProperties(randr::ListProviderPropertiesRequest),
+    #[cfg(feature = "randr")]
+    RandrQueryProviderProperty(randr::QueryProviderPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrConfigureProviderProperty(randr::ConfigureProviderPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrChangeProviderProperty(randr::ChangeProviderPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrDeleteProviderProperty(randr::DeleteProviderPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrGetProviderProperty(randr::GetProviderPropertyRequest),
+    #[cfg(feature = "randr")]
+    RandrGetMonitors(randr::GetMonitorsRequest),
+    #[cfg(feature = "randr")]
+    RandrSetMonitor(randr::SetMonitorRequest),
+    #[cfg(feature = "randr")]
+    RandrDeleteMonitor(randr::DeleteMonitorRequest),
+    #[cfg(feature = "randr")]
+    RandrCreateLease(randr::CreateLeaseRequest),
+    #[cfg(feature = "randr")]
+    RandrFreeLease(randr::FreeLeaseRequest),
+    #[cfg(feature = "record")]
+    RecordQueryVersion(record::QueryVersionRequest),
+    #[cfg(feature = "record")]
+    RecordCreateContext(record::CreateContextRequest),
+    #[cfg(feature = "record")]
+    RecordRegisterClients(record::RegisterClientsRequest),
+    #[cfg(feature = "record")]
+    RecordUnregisterClients(record::UnregisterClientsRequest),
+    #[cfg(feature = "record")]
+    RecordGetContext(record::GetContextRequest),
+    #[cfg(feature = "record")]
+    RecordEnableContext(record::EnableContextRequest),
+    #[cfg(feature = "record")]
+    RecordDisableContext(record::DisableContextRequest),
+    #[cfg(feature = "record")]
+    RecordFreeContext(record::FreeContextRequest),
+    #[cfg(feature = "render")]
+    RenderQueryVersion(render::QueryVersionRequest),
+    #[cfg(feature = "render")]
+    RenderQueryPictFormats(render::QueryPictFormatsRequest),
+    #[cfg(feature = "render")]
+    RenderQueryPictIndexValues(render::QueryPictIndexValuesRequest),
+    #[cfg(feature = "render")]
+    RenderCreatePicture(render::CreatePictureRequest),
+    #[cfg(feature = "render")]
+    RenderChangePicture(render::ChangePictureRequest),
+    #[cfg(feature = "render")]
+    RenderSetPictureClipRectangles(render::SetPictureClipRectanglesRequest),
+    #[cfg(feature = "render")]
+    RenderFreePicture(render::FreePictureRequest),
+    #[cfg(feature = "render")]
+    RenderComposite(render::CompositeRequest),
+    #[cfg(feature = "render")]
+    RenderTrapezoids(render::TrapezoidsRequest),
+    #[cfg(feature = "render")]
+    RenderTriangles(render::TrianglesRequest),
+    #[cfg(feature = "render")]
+    RenderTriStrip(render::TriStripRequest),
+    #[cfg(feature = "render")]
+    RenderTriFan(render::TriFanRequest),
+    #[cfg(feature = "render")]
+    RenderCreateGlyphSet(render::CreateGlyphSetRequest),
+    #[cfg(feature = "render")]
+    RenderReferenceGlyphSet(render::ReferenceGlyphSetRequest),
+    #[cfg(feature = "render")]
+    RenderFreeGlyphSet(render::FreeGlyphSetRequest),
+    #[cfg(feature = "render")]
+    RenderAddGlyphs(render::AddGlyphsRequest),
+    #[cfg(feature = "render")]
+    RenderFreeGlyphs(render::FreeGlyphsRequest),
+    #[cfg(feature = "render")]
+    RenderCompositeGlyphs8(render::CompositeGlyphs8Request),
+    #[cfg(feature = "render")]
+    RenderCompositeGlyphs16(render::CompositeGlyphs16Request),
+    #[cfg(feature = "render")]
+    RenderCompositeGlyphs32(render::CompositeGlyphs32Request),
+    #[cfg(feature = "render")]
+    RenderFillRectangles(render::FillRectanglesRequest),
+    #[cfg(feature = "render")]
+    RenderCreateCursor(render::CreateCursorRequest),
+    #[cfg(feature = "render")]
+    RenderSetPictureTransform(render::SetPictureTransformRequest),
+    #[cfg(feature = "render")]
+    RenderQueryFilters(render::QueryFiltersRequest),
+    #[cfg(feature = "render")]
+    RenderSetPictureFilter(render::SetPictureFilterRequest),
+    #[cfg(feature = "render")]
+    RenderCreateAnimCursor(render::CreateAnimCursorRequest),
+    #[cfg(feature = "render")]
+    RenderAddTraps(render::AddTrapsRequest),
+    #[cfg(feature = "render")]
+    RenderCreateSolidFill(render::CreateSolidFillRequest),
+    #[cfg(feature = "render")]
+    RenderCreateLinearGradient(render::CreateLinearGradientRequest),
+    #[cfg(feature = "render")]
+    RenderCreateRadialGradient(render::CreateRadialGradientRequest),
+    #[cfg(feature = "render")]
+    RenderCreateConicalGradient(render::CreateConicalGradientRequest),
+    #[cfg(feature = "res")]
+    ResQueryVersion(res::QueryVersionRequest),
+    #[cfg(feature = "res")]
+    ResQueryClients(res::QueryClientsRequest),
+    #[cfg(feature = "res")]
+    ResQueryClientResources(res::QueryClientResourcesRequest),
+    #[cfg(feature = "res")]
+    ResQueryClientPixmapBytes(res::QueryClientPixmapBytesRequest),
+    #[cfg(feature = "res")]
+    ResQueryClientIds(res::QueryClientIdsRequest),
+    #[cfg(feature = "res")]
+    ResQueryResourceBytes(res::QueryResourceBytesRequest),
+    #[cfg(feature = "screensaver")]
+    ScreensaverQueryVersion(screensaver::QueryVersionRequest),
+    #[cfg(feature = "screensaver")]
+    ScreensaverQueryInfo(screensaver::QueryInfoRequest),
+    #[cfg(feature = "screensaver")]
+    ScreensaverSelectInput(screensaver::SelectInputRequest),
+    #[cfg(feature = "screensaver")]
+    ScreensaverSetAttributes(screensaver::SetAttributesRequest),
+    #[cfg(feature = "screensaver")]
+    ScreensaverUnsetAttributes(screensaver::UnsetAttributesRequest),
+    #[cfg(feature = "screensaver")]
+    ScreensaverSuspend(screensaver::SuspendRequest),
+    #[cfg(feature = "shape")]
+    ShapeQueryVersion(shape::QueryVersionRequest),
+    #[cfg(feature = "shape")]
+    ShapeRectangles(shape::RectanglesRequest),
+    #[cfg(feature = "shape")]
+    ShapeMask(shape::MaskRequest),
+    #[cfg(feature = "shape")]
+    ShapeCombine(shape::CombineRequest),
+    #[cfg(feature = "shape")]
+    ShapeOffset(shape::OffsetRequest),
+    #[cfg(feature = "shape")]
+    ShapeQueryExtents(shape::QueryExtentsRequest),
+    #[cfg(feature = "shape")]
+    ShapeSelectInput(shape::SelectInputRequest),
+    #[cfg(feature = "shape")]
+    ShapeInputSelected(shape::InputSelectedRequest),
+    #[cfg(feature = "shape")]
+    ShapeGetRectangles(shape::GetRectanglesRequest),
+    #[cfg(feature = "shm")]
+    ShmQueryVersion(shm::QueryVersionRequest),
+    #[cfg(feature = "shm")]
+    ShmAttach(shm::AttachRequest),
+    #[cfg(feature = "shm")]
+    ShmDetach(shm::DetachRequest),
+    #[cfg(feature = "shm")]
+    ShmPutImage(shm::PutImageRequest),
+    #[cfg(feature = "shm")]
+    ShmGetImage(shm::GetImageRequest),
+    #[cfg(feature = "shm")]
+    ShmCreatePixmap(shm::CreatePixmapRequest),
+    #[cfg(feature = "shm")]
+    ShmAttachFd(shm::AttachFdRequest),
+    #[cfg(feature = "shm")]
+    ShmCreateSegment(shm::CreateSegmentRequest),
+    #[cfg(feature = "sync")]
+    SyncInitialize(sync::InitializeRequest),
+    #[cfg(feature = "sync")]
+    SyncListSystemCounters(sync::ListSystemCountersRequest),
+    #[cfg(feature = "sync")]
+    SyncCreateCounter(sync::CreateCounterRequest),
+    #[cfg(feature = "sync")]
+    SyncSetCounter(sync::SetCounterRequest),
+    #[cfg(feature = "sync")]
+    SyncChangeCounter(sync::ChangeCounterRequest),
+    #[cfg(feature = "sync")]
+    SyncQueryCounter(sync::QueryCounterRequest),
+    #[cfg(feature = "sync")]
+    SyncDestroyCounter(sync::DestroyCounterRequest),
+    #[cfg(feature = "sync")]
+    SyncAwait(sync::AwaitRequest),
+    #[cfg(feature = "sync")]
+    SyncCreateAlarm(sync::CreateAlarmRequest),
+    #[cfg(feature = "sync")]
+    SyncChangeAlarm(sync::ChangeAlarmRequest),
+    #[cfg(feature = "sync")]
+    SyncQueryAlarm(sync::QueryAlarmRequest),
+    #[cfg(feature = "sync")]
+    SyncDestroyAlarm(sync::DestroyAlarmRequest),
+    #[cfg(feature = "sync")]
+    SyncSetPriority(sync::SetPriorityRequest),
+    #[cfg(feature = "sync")]
+    SyncGetPriority(sync::GetPriorityRequest),
+    #[cfg(feature = "sync")]
+    SyncCreateFence(sync::CreateFenceRequest),
+    #[cfg(feature = "sync")]
+    SyncTriggerFence(sync::TriggerFenceRequest),
+    #[cfg(feature = "sync")]
+    SyncResetFence(sync::ResetFenceRequest),
+    #[cfg(feature = "sync")]
+    SyncDestroyFence(sync::DestroyFenceRequest),
+    #[cfg(feature = "sync")]
+    SyncQueryFence(sync::QueryFenceRequest),
+    #[cfg(feature = "sync")]
+    SyncAwaitFence(sync::AwaitFenceRequest),
+    XcMiscGetVersion(xc_misc::GetVersionRequest),
+    XcMiscGetXIDRange(xc_misc::GetXIDRangeRequest),
+    XcMiscGetXIDList(xc_misc::GetXIDListRequest),
+    #[cfg(feature = "xevie")]
+    XevieQueryVersion(xevie::QueryVersionRequest),
+    #[cfg(feature = "xevie")]
+    XevieStart(xevie::StartRequest),
+    #[cfg(feature = "xevie")]
+    XevieEnd(xevie::EndRequest),
+    #[cfg(feature = "xevie")]
+    XevieSend(xevie::SendRequest),
+    #[cfg(feature = "xevie")]
+    XevieSelectInput(xevie::SelectInputRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driQueryVersion(xf86dri::QueryVersionRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driQueryDirectRenderingCapable(xf86dri::QueryDirectRenderingCapableRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driOpenConnection(xf86dri::OpenConnectionRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driCloseConnection(xf86dri::CloseConnectionRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driGetClientDriverName(xf86dri::GetClientDriverNameRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driCreateContext(xf86dri::CreateContextRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driDestroyContext(xf86dri::DestroyContextRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driCreateDrawable(xf86dri::CreateDrawableRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driDestroyDrawable(xf86dri::DestroyDrawableRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driGetDrawableInfo(xf86dri::GetDrawableInfoRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driGetDeviceInfo(xf86dri::GetDeviceInfoRequest),
+    #[cfg(feature = "xf86dri")]
+    Xf86driAuthConnection(xf86dri::AuthConnectionRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeQueryVersion(xf86vidmode::QueryVersionRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetModeLine(xf86vidmode::GetModeLineRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeModModeLine(xf86vidmode::ModModeLineRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeSwitchMode(xf86vidmode::SwitchModeRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetMonitor(xf86vidmode::GetMonitorRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeLockModeSwitch(xf86vidmode::LockModeSwitchRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetAllModeLines(xf86vidmode::GetAllModeLinesRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeAddModeLine(xf86vidmode::AddModeLineRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeDeleteModeLine(xf86vidmode::DeleteModeLineRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeValidateModeLine(xf86vidmode::ValidateModeLineRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeSwitchToMode(xf86vidmode::SwitchToModeRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetViewPort(xf86vidmode::GetViewPortRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeSetViewPort(xf86vidmode::SetViewPortRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetDotClocks(xf86vidmode::GetDotClocksRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeSetClientVersion(xf86vidmode::SetClientVersionRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeSetGamma(xf86vidmode::SetGammaRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetGamma(xf86vidmode::GetGammaRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetGammaRamp(xf86vidmode::GetGammaRampRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeSetGammaRamp(xf86vidmode::SetGammaRampRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetGammaRampSize(xf86vidmode::GetGammaRampSizeRequest),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetPermissions(xf86vidmode::GetPermissionsRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesQueryVersion(xfixes::QueryVersionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesChangeSaveSet(xfixes::ChangeSaveSetRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesSelectSelectionInput(xfixes::SelectSelectionInputRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesSelectCursorInput(xfixes::SelectCursorInputRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesGetCursorImage(xfixes::GetCursorImageRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesCreateRegion(xfixes::CreateRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesCreateRegionFromBitmap(xfixes::CreateRegionFromBitmapRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesCreateRegionFromWindow(xfixes::CreateRegionFromWindowRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesCreateRegionFromGC(xfixes::CreateRegionFromGCRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesCreateRegionFromPicture(xfixes::CreateRegionFromPictureRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesDestroyRegion(xfixes::DestroyRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesSetRegion(xfixes::SetRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesCopyRegion(xfixes::CopyRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesUnionRegion(xfixes::UnionRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesIntersectRegion(xfixes::IntersectRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesSubtractRegion(xfixes::SubtractRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesInvertRegion(xfixes::InvertRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesTranslateRegion(xfixes::TranslateRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesRegionExtents(xfixes::RegionExtentsRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesFetchRegion(xfixes::FetchRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesSetGCClipRegion(xfixes::SetGCClipRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesSetWindowShapeRegion(xfixes::SetWindowShapeRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesSetPictureClipRegion(xfixes::SetPictureClipRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesSetCursorName(xfixes::SetCursorNameRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesGetCursorName(xfixes::GetCursorNameRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesGetCursorImageAndName(xfixes::GetCursorImageAndNameRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesChangeCursor(xfixes::ChangeCursorRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesChangeCursorByName(xfixes::ChangeCursorByNameRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesExpandRegion(xfixes::ExpandRegionRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesHideCursor(xfixes::HideCursorRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesShowCursor(xfixes::ShowCursorRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesCreatePointerBarrier(xfixes::CreatePointerBarrierRequest),
+    #[cfg(feature = "xfixes")]
+    XfixesDeletePointerBarrier(xfixes::DeletePointerBarrierRequest),
+    #[cfg(feature = "xinerama")]
+    XineramaQueryVersion(xinerama::QueryVersionRequest),
+    #[cfg(feature = "xinerama")]
+    XineramaGetState(xinerama::GetStateRequest),
+    #[cfg(feature = "xinerama")]
+    XineramaGetScreenCount(xinerama::GetScreenCountRequest),
+    #[cfg(feature = "xinerama")]
+    XineramaGetScreenSize(xinerama::GetScreenSizeRequest),
+    #[cfg(feature = "xinerama")]
+    XineramaIsActive(xinerama::IsActiveRequest),
+    #[cfg(feature = "xinerama")]
+    XineramaQueryScreens(xinerama::QueryScreensRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetExtensionVersion(xinput::GetExtensionVersionRequest),
+    #[cfg(feature = "xinput")]
+    XinputListInputDevices(xinput::ListInputDevicesRequest),
+    #[cfg(feature = "xinput")]
+    XinputOpenDevice(xinput::OpenDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputCloseDevice(xinput::CloseDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputSetDeviceMode(xinput::SetDeviceModeRequest),
+    #[cfg(feature = "xinput")]
+    XinputSelectExtensionEvent(xinput::SelectExtensionEventRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetSelectedExtensionEvents(xinput::GetSelectedExtensionEventsRequest),
+    #[cfg(feature = "xinput")]
+    XinputChangeDeviceDontPropagateList(xinput::ChangeDeviceDontPropagateListRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceDontPropagateList(xinput::GetDeviceDontPropagateListRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceMotionEvents(xinput::GetDeviceMotionEventsRequest),
+    #[cfg(feature = "xinput")]
+    XinputChangeKeyboardDevice(xinput::ChangeKeyboardDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputChangePointerDevice(xinput::ChangePointerDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputGrabDevice(xinput::GrabDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputUngrabDevice(xinput::UngrabDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputGrabDeviceKey(xinput::GrabDeviceKeyRequest),
+    #[cfg(feature = "xinput")]
+    XinputUngrabDeviceKey(xinput::UngrabDeviceKeyRequest),
+    #[cfg(feature = "xinput")]
+    XinputGrabDeviceButton(xinput::GrabDeviceButtonRequest),
+    #[cfg(feature = "xinput")]
+    XinputUngrabDeviceButton(xinput::UngrabDeviceButtonRequest),
+    #[cfg(feature = "xinput")]
+    XinputAllowDeviceEvents(xinput::AllowDeviceEventsRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceFocus(xinput::GetDeviceFocusRequest),
+    #[cfg(feature = "xinput")]
+    XinputSetDeviceFocus(xinput::SetDeviceFocusRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetFeedbackControl(xinput::GetFeedbackControlRequest),
+    #[cfg(feature = "xinput")]
+    XinputChangeFeedbackControl(xinput::ChangeFeedbackControlRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceKeyMapping(xinput::GetDeviceKeyMappingRequest),
+    #[cfg(feature = "xinput")]
+    XinputChangeDeviceKeyMapping(xinput::ChangeDeviceKeyMappingRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceModifierMapping(xinput::GetDeviceModifierMappingRequest),
+    #[cfg(feature = "xinput")]
+    XinputSetDeviceModifierMapping(xinput::SetDeviceModifierMappingRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceButtonMapping(xinput::GetDeviceButtonMappingRequest),
+    #[cfg(feature = "xinput")]
+    XinputSetDeviceButtonMapping(xinput::SetDeviceButtonMappingRequest),
+    #[cfg(feature = "xinput")]
+    XinputQueryDeviceState(xinput::QueryDeviceStateRequest),
+    #[cfg(feature = "xinput")]
+    XinputSendExtensionEvent(xinput::SendExtensionEventRequest),
+    #[cfg(feature = "xinput")]
+    XinputDeviceBell(xinput::DeviceBellRequest),
+    #[cfg(feature = "xinput")]
+    XinputSetDeviceValuators(xinput::SetDeviceValuatorsRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceControl(xinput::GetDeviceControlRequest),
+    #[cfg(feature = "xinput")]
+    XinputChangeDeviceControl(xinput::ChangeDeviceControlRequest),
+    #[cfg(feature = "xinput")]
+    XinputListDeviceProperties(xinput::ListDevicePropertiesRequest),
+    #[cfg(feature = "xinput")]
+    XinputChangeDeviceProperty(xinput::ChangeDevicePropertyRequest),
+    #[cfg(feature = "xinput")]
+    XinputDeleteDeviceProperty(xinput::DeleteDevicePropertyRequest),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceProperty(xinput::GetDevicePropertyRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIQueryPointer(xinput::XIQueryPointerRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIWarpPointer(xinput::XIWarpPointerRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIChangeCursor(xinput::XIChangeCursorRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIChangeHierarchy(xinput::XIChangeHierarchyRequest),
+    #[cfg(feature = "xinput")]
+    XinputXISetClientPointer(xinput::XISetClientPointerRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIGetClientPointer(xinput::XIGetClientPointerRequest),
+    #[cfg(feature = "xinput")]
+    XinputXISelectEvents(xinput::XISelectEventsRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIQueryVersion(xinput::XIQueryVersionRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIQueryDevice(xinput::XIQueryDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputXISetFocus(xinput::XISetFocusRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIGetFocus(xinput::XIGetFocusRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIGrabDevice(xinput::XIGrabDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIUngrabDevice(xinput::XIUngrabDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIAllowEvents(xinput::XIAllowEventsRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIPassiveGrabDevice(xinput::XIPassiveGrabDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIPassiveUngrabDevice(xinput::XIPassiveUngrabDeviceRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIListProperties(xinput::XIListPropertiesRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIChangeProperty(xinput::XIChangePropertyRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIDeleteProperty(xinput::XIDeletePropertyRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIGetProperty(xinput::XIGetPropertyRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIGetSelectedEvents(xinput::XIGetSelectedEventsRequest),
+    #[cfg(feature = "xinput")]
+    XinputXIBarrierReleasePointer(xinput::XIBarrierReleasePointerRequest),
+    #[cfg(feature = "xkb")]
+    XkbUseExtension(xkb::UseExtensionRequest),
+    #[cfg(feature = "xkb")]
+    XkbSelectEvents(xkb::SelectEventsRequest),
+    #[cfg(feature = "xkb")]
+    XkbBell(xkb::BellRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetState(xkb::GetStateRequest),
+    #[cfg(feature = "xkb")]
+    XkbLatchLockState(xkb::LatchLockStateRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetControls(xkb::GetControlsRequest),
+    #[cfg(feature = "xkb")]
+    XkbSetControls(xkb::SetControlsRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetMap(xkb::GetMapRequest),
+    #[cfg(feature = "xkb")]
+    XkbSetMap(xkb::SetMapRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetCompatMap(xkb::GetCompatMapRequest),
+    #[cfg(feature = "xkb")]
+    XkbSetCompatMap(xkb::SetCompatMapRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetIndicatorState(xkb::GetIndicatorStateRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetIndicatorMap(xkb::GetIndicatorMapRequest),
+    #[cfg(feature = "xkb")]
+    XkbSetIndicatorMap(xkb::SetIndicatorMapRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetNamedIndicator(xkb::GetNamedIndicatorRequest),
+    #[cfg(feature = "xkb")]
+    XkbSetNamedIndicator(xkb::SetNamedIndicatorRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetNames(xkb::GetNamesRequest),
+    #[cfg(feature = "xkb")]
+    XkbSetNames(xkb::SetNamesRequest),
+    #[cfg(feature = "xkb")]
+    XkbPerClientFlags(xkb::PerClientFlagsRequest),
+    #[cfg(feature = "xkb")]
+    XkbListComponents(xkb::ListComponentsRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetKbdByName(xkb::GetKbdByNameRequest),
+    #[cfg(feature = "xkb")]
+    XkbGetDeviceInfo(xkb::GetDeviceInfoRequest),
+    #[cfg(feature = "xkb")]
+    XkbSetDeviceInfo(xkb::SetDeviceInfoRequest),
+    #[cfg(feature = "xkb")]
+    XkbSetDebuggingFlags(xkb::SetDebuggingFlagsRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintQueryVersion(xprint::PrintQueryVersionRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetPrinterList(xprint::PrintGetPrinterListRequest),
+    #[cfg(feature = "xprint")]
+    XprintCreateContext(xprint::CreateContextRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintSetContext(xprint::PrintSetContextRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetContext(xprint::PrintGetContextRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintDestroyContext(xprint::PrintDestroyContextRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetScreenOfContext(xprint::PrintGetScreenOfContextRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintStartJob(xprint::PrintStartJobRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintEndJob(xprint::PrintEndJobRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintStartDoc(xprint::PrintStartDocRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintEndDoc(xprint::PrintEndDocRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintPutDocumentData(xprint::PrintPutDocumentDataRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetDocumentData(xprint::PrintGetDocumentDataRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintStartPage(xprint::PrintStartPageRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintEndPage(xprint::PrintEndPageRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintSelectInput(xprint::PrintSelectInputRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintInputSelected(xprint::PrintInputSelectedRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetAttributes(xprint::PrintGetAttributesRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintSetAttributes(xprint::PrintSetAttributesRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetOneAttributes(xprint::PrintGetOneAttributesRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintRehashPrinterList(xprint::PrintRehashPrinterListRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetPageDimensions(xprint::PrintGetPageDimensionsRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintQueryScreens(xprint::PrintQueryScreensRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintSetImageResolution(xprint::PrintSetImageResolutionRequest),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetImageResolution(xprint::PrintGetImageResolutionRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxQueryVersion(xselinux::QueryVersionRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxSetDeviceCreateContext(xselinux::SetDeviceCreateContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetDeviceCreateContext(xselinux::GetDeviceCreateContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxSetDeviceContext(xselinux::SetDeviceContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetDeviceContext(xselinux::GetDeviceContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxSetWindowCreateContext(xselinux::SetWindowCreateContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetWindowCreateContext(xselinux::GetWindowCreateContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetWindowContext(xselinux::GetWindowContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxSetPropertyCreateContext(xselinux::SetPropertyCreateContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetPropertyCreateContext(xselinux::GetPropertyCreateContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxSetPropertyUseContext(xselinux::SetPropertyUseContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetPropertyUseContext(xselinux::GetPropertyUseContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetPropertyContext(xselinux::GetPropertyContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetPropertyDataContext(xselinux::GetPropertyDataContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxListProperties(xselinux::ListPropertiesRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxSetSelectionCreateContext(xselinux::SetSelectionCreateContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetSelectionCreateContext(xselinux::GetSelectionCreateContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxSetSelectionUseContext(xselinux::SetSelectionUseContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetSelectionUseContext(xselinux::GetSelectionUseContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetSelectionContext(xselinux::GetSelectionContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetSelectionDataContext(xselinux::GetSelectionDataContextRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxListSelections(xselinux::ListSelectionsRequest),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetClientContext(xselinux::GetClientContextRequest),
+    #[cfg(feature = "xtest")]
+    XtestGetVersion(xtest::GetVersionRequest),
+    #[cfg(feature = "xtest")]
+    XtestCompareCursor(xtest::CompareCursorRequest),
+    #[cfg(feature = "xtest")]
+    XtestFakeInput(xtest::FakeInputRequest),
+    #[cfg(feature = "xtest")]
+    XtestGrabControl(xtest::GrabControlRequest),
+    #[cfg(feature = "xv")]
+    XvQueryExtension(xv::QueryExtensionRequest),
+    #[cfg(feature = "xv")]
+    XvQueryAdaptors(xv::QueryAdaptorsRequest),
+    #[cfg(feature = "xv")]
+    XvQueryEncodings(xv::QueryEncodingsRequest),
+    #[cfg(feature = "xv")]
+    XvGrabPort(xv::GrabPortRequest),
+    #[cfg(feature = "xv")]
+    XvUngrabPort(xv::UngrabPortRequest),
+    #[cfg(feature = "xv")]
+    XvPutVideo(xv::PutVideoRequest),
+    #[cfg(feature = "xv")]
+    XvPutStill(xv::PutStillRequest),
+    #[cfg(feature = "xv")]
+    XvGetVideo(xv::GetVideoRequest),
+    #[cfg(feature = "xv")]
+    XvGetStill(xv::GetStillRequest),
+    #[cfg(feature = "xv")]
+    XvStopVideo(xv::StopVideoRequest),
+    #[cfg(feature = "xv")]
+    XvSelectVideoNotify(xv::SelectVideoNotifyRequest),
+    #[cfg(feature = "xv")]
+    XvSelectPortNotify(xv::SelectPortNotifyRequest),
+    #[cfg(feature = "xv")]
+    XvQueryBestSize(xv::QueryBestSizeRequest),
+    #[cfg(feature = "xv")]
+    XvSetPortAttribute(xv::SetPortAttributeRequest),
+    #[cfg(feature = "xv")]
+    XvGetPortAttribute(xv::GetPortAttributeRequest),
+    #[cfg(feature = "xv")]
+    XvQueryPortAttributes(xv::QueryPortAttributesRequest),
+    #[cfg(feature = "xv")]
+    XvListImageFormats(xv::ListImageFormatsRequest),
+    #[cfg(feature = "xv")]
+    XvQueryImageAttributes(xv::QueryImageAttributesRequest),
+    #[cfg(feature = "xv")]
+    XvPutImage(xv::PutImageRequest),
+    #[cfg(feature = "xv")]
+    XvShmPutImage(xv::ShmPutImageRequest),
+    #[cfg(feature = "xvmc")]
+    XvmcQueryVersion(xvmc::QueryVersionRequest),
+    #[cfg(feature = "xvmc")]
+    XvmcListSurfaceTypes(xvmc::ListSurfaceTypesRequest),
+    #[cfg(feature = "xvmc")]
+    XvmcCreateContext(xvmc::CreateContextRequest),
+    #[cfg(feature = "xvmc")]
+    XvmcDestroyContext(xvmc::DestroyContextRequest),
+    #[cfg(feature = "xvmc")]
+    XvmcCreateSurface(xvmc::CreateSurfaceRequest),
+    #[cfg(feature = "xvmc")]
+    XvmcDestroySurface(xvmc::DestroySurfaceRequest),
+    #[cfg(feature = "xvmc")]
+    XvmcCreateSubpicture(xvmc::CreateSubpictureRequest),
+    #[cfg(feature = "xvmc")]
+    XvmcDestroySubpicture(xvmc::DestroySubpictureRequest),
+    #[cfg(feature = "xvmc")]
+    XvmcListSubpictureTypes(xvmc::ListSubpictureTypesRequest),
+}
+
+impl Request {
+    /// Parse a generic X11 request into a concrete request type.
+    ///
+    /// `header` and `body` can be obtained from the raw bytes of a request with
+    /// `x11rb::x11_utils::parse_request_header`. Data after the end of the request's body is
+    /// ignored. The FDs that were sent with the request are taken from the front of `fds`.
+    /// `order` is the byte order that the client which sent the request uses.
+    /// Requests that are not known are returned as `Request::Unknown`.
+    #[allow(clippy::cognitive_complexity)]
+    #[cfg_attr(not(any(feature = "dri3", feature = "shm")), allow(unused_variables))]
+    pub fn parse(
+        header: RequestHeader,
+        body: &[u8],
+        fds: &mut Vec<RawFdContainer>,
+        ext_info_provider: &dyn ExtInfoProvider,
+        order: ByteOrder,
+    ) -> Result<Self, ParseError> {
+        let body_length = usize::try_from(header.remaining_length).unwrap() * 4;
+        let body = body.get(..body_length).ok_or(ParseError::ParseError)?;
+
+        // Check if this is a core protocol request
+        match header.major_opcode {
+            xproto::CREATE_WINDOW_REQUEST => return Ok(Request::CreateWindow(xproto::CreateWindowRequest::try_parse_request(header, body, order)?)),
+            xproto::CHANGE_WINDOW_ATTRIBUTES_REQUEST => return Ok(Request::ChangeWindowAttributes(xproto::ChangeWindowAttributesRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_WINDOW_ATTRIBUTES_REQUEST => return Ok(Request::GetWindowAttributes(xproto::GetWindowAttributesRequest::try_parse_request(header, body, order)?)),
+            xproto::DESTROY_WINDOW_REQUEST => return Ok(Request::DestroyWindow(xproto::DestroyWindowRequest::try_parse_request(header, body, order)?)),
+            xproto::DESTROY_SUBWINDOWS_REQUEST => return Ok(Request::DestroySubwindows(xproto::DestroySubwindowsRequest::try_parse_request(header, body, order)?)),
+            xproto::CHANGE_SAVE_SET_REQUEST => return Ok(Request::ChangeSaveSet(xproto::ChangeSaveSetRequest::try_parse_request(header, body, order)?)),
+            xproto::REPARENT_WINDOW_REQUEST => return Ok(Request::ReparentWindow(xproto::ReparentWindowRequest::try_parse_request(header, body, order)?)),
+            xproto::MAP_WINDOW_REQUEST => return Ok(Request::MapWindow(xproto::MapWindowRequest::try_parse_request(header, body, order)?)),
+            xproto::MAP_SUBWINDOWS_REQUEST => return Ok(Request::MapSubwindows(xproto::MapSubwindowsRequest::try_parse_request(header, body, order)?)),
+            xproto::UNMAP_WINDOW_REQUEST => return Ok(Request::UnmapWindow(xproto::UnmapWindowRequest::try_parse_request(header, body, order)?)),
+            xproto::UNMAP_SUBWINDOWS_REQUEST => return Ok(Request::UnmapSubwindows(xproto::UnmapSubwindowsRequest::try_parse_request(header, body, order)?)),
+            xproto::CONFIGURE_WINDOW_REQUEST => return Ok(Request::ConfigureWindow(xproto::ConfigureWindowRequest::try_parse_request(header, body, order)?)),
+            xproto::CIRCULATE_WINDOW_REQUEST => return Ok(Request::CirculateWindow(xproto::CirculateWindowRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_GEOMETRY_REQUEST => return Ok(Request::GetGeometry(xproto::GetGeometryRequest::try_parse_request(header, body, order)?)),
+            xproto::QUERY_TREE_REQUEST => return Ok(Request::QueryTree(xproto::QueryTreeRequest::try_parse_request(header, body, order)?)),
+            xproto::INTERN_ATOM_REQUEST => return Ok(Request::InternAtom(xproto::InternAtomRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_ATOM_NAME_REQUEST => return Ok(Request::GetAtomName(xproto::GetAtomNameRequest::try_parse_request(header, body, order)?)),
+            xproto::CHANGE_PROPERTY_REQUEST => return Ok(Request::ChangeProperty(xproto::ChangePropertyRequest::try_parse_request(header, body, order)?)),
+            xproto::DELETE_PROPERTY_REQUEST => return Ok(Request::DeleteProperty(xproto::DeletePropertyRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_PROPERTY_REQUEST => return Ok(Request::GetProperty(xproto::GetPropertyRequest::try_parse_request(header, body, order)?)),
+            xproto::LIST_PROPERTIES_REQUEST => return Ok(Request::ListProperties(xproto::ListPropertiesRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_SELECTION_OWNER_REQUEST => return Ok(Request::SetSelectionOwner(xproto::SetSelectionOwnerRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_SELECTION_OWNER_REQUEST => return Ok(Request::GetSelectionOwner(xproto::GetSelectionOwnerRequest::try_parse_request(header, body, order)?)),
+            xproto::CONVERT_SELECTION_REQUEST => return Ok(Request::ConvertSelection(xproto::ConvertSelectionRequest::try_parse_request(header, body, order)?)),
+            xproto::SEND_EVENT_REQUEST => return Ok(Request::SendEvent(xproto::SendEventRequest::try_parse_request(header, body, order)?)),
+            xproto::GRAB_POINTER_REQUEST => return Ok(Request::GrabPointer(xproto::GrabPointerRequest::try_parse_request(header, body, order)?)),
+            xproto::UNGRAB_POINTER_REQUEST => return Ok(Request::UngrabPointer(xproto::UngrabPointerRequest::try_parse_request(header, body, order)?)),
+            xproto::GRAB_BUTTON_REQUEST => return Ok(Request::GrabButton(xproto::GrabButtonRequest::try_parse_request(header, body, order)?)),
+            xproto::UNGRAB_BUTTON_REQUEST => return Ok(Request::UngrabButton(xproto::UngrabButtonRequest::try_parse_request(header, body, order)?)),
+            xproto::CHANGE_ACTIVE_POINTER_GRAB_REQUEST => return Ok(Request::ChangeActivePointerGrab(xproto::ChangeActivePointerGrabRequest::try_parse_request(header, body, order)?)),
+            xproto::GRAB_KEYBOARD_REQUEST => return Ok(Request::GrabKeyboard(xproto::GrabKeyboardRequest::try_parse_request(header, body, order)?)),
+            xproto::UNGRAB_KEYBOARD_REQUEST => return Ok(Request::UngrabKeyboard(xproto::UngrabKeyboardRequest::try_parse_request(header, body, order)?)),
+            xproto::GRAB_KEY_REQUEST => return Ok(Request::GrabKey(xproto::GrabKeyRequest::try_parse_request(header, body, order)?)),
+            xproto::UNGRAB_KEY_REQUEST => return Ok(Request::UngrabKey(xproto::UngrabKeyRequest::try_parse_request(header, body, order)?)),
+            xproto::ALLOW_EVENTS_REQUEST => return Ok(Request::AllowEvents(xproto::AllowEventsRequest::try_parse_request(header, body, order)?)),
+            xproto::GRAB_SERVER_REQUEST => return Ok(Request::GrabServer(xproto::GrabServerRequest::try_parse_request(header, body, order)?)),
+            xproto::UNGRAB_SERVER_REQUEST => return Ok(Request::UngrabServer(xproto::UngrabServerRequest::try_parse_request(header, body, order)?)),
+            xproto::QUERY_POINTER_REQUEST => return Ok(Request::QueryPointer(xproto::QueryPointerRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_MOTION_EVENTS_REQUEST => return Ok(Request::GetMotionEvents(xproto::GetMotionEventsRequest::try_parse_request(header, body, order)?)),
+            xproto::TRANSLATE_COORDINATES_REQUEST => return Ok(Request::TranslateCoordinates(xproto::TranslateCoordinatesRequest::try_parse_request(header, body, order)?)),
+            xproto::WARP_POINTER_REQUEST => return Ok(Request::WarpPointer(xproto::WarpPointerRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_INPUT_FOCUS_REQUEST => return Ok(Request::SetInputFocus(xproto::SetInputFocusRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_INPUT_FOCUS_REQUEST => return Ok(Request::GetInputFocus(xproto::GetInputFocusRequest::try_parse_request(header, body, order)?)),
+            xproto::QUERY_KEYMAP_REQUEST => return Ok(Request::QueryKeymap(xproto::QueryKeymapRequest::try_parse_request(header, body, order)?)),
+            xproto::OPEN_FONT_REQUEST => return Ok(Request::OpenFont(xproto::OpenFontRequest::try_parse_request(header, body, order)?)),
+            xproto::CLOSE_FONT_REQUEST => return Ok(Request::CloseFont(xproto::CloseFontRequest::try_parse_request(header, body, order)?)),
+            xproto::QUERY_FONT_REQUEST => return Ok(Request::QueryFont(xproto::QueryFontRequest::try_parse_request(header, body, order)?)),
+            xproto::QUERY_TEXT_EXTENTS_REQUEST => return Ok(Request::QueryTextExtents(xproto::QueryTextExtentsRequest::try_parse_request(header, body, order)?)),
+            xproto::LIST_FONTS_REQUEST => return Ok(Request::ListFonts(xproto::ListFontsRequest::try_parse_request(header, body, order)?)),
+            xproto::LIST_FONTS_WITH_INFO_REQUEST => return Ok(Request::ListFontsWithInfo(xproto::ListFontsWithInfoRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_FONT_PATH_REQUEST => return Ok(Request::SetFontPath(xproto::SetFontPathRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_FONT_PATH_REQUEST => return Ok(Request::GetFontPath(xproto::GetFontPathRequest::try_parse_request(header, body, order)?)),
+            xproto::CREATE_PIXMAP_REQUEST => return Ok(Request::CreatePixmap(xproto::CreatePixmapRequest::try_parse_request(header, body, order)?)),
+            xproto::FREE_PIXMAP_REQUEST => return Ok(Request::FreePixmap(xproto::FreePixmapRequest::try_parse_request(header, body, order)?)),
+            xproto::CREATE_GC_REQUEST => return Ok(Request::CreateGC(xproto::CreateGCRequest::try_parse_request(header, body, order)?)),
+            xproto::CHANGE_GC_REQUEST => return Ok(Request::ChangeGC(xproto::ChangeGCRequest::try_parse_request(header, body, order)?)),
+            xproto::COPY_GC_REQUEST => return Ok(Request::CopyGC(xproto::CopyGCRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_DASHES_REQUEST => return Ok(Request::SetDashes(xproto::SetDashesRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_CLIP_RECTANGLES_REQUEST => return Ok(Request::SetClipRectangles(xproto::SetClipRectanglesRequest::try_parse_request(header, body, order)?)),
+            xproto::FREE_GC_REQUEST => return Ok(Request::FreeGC(xproto::FreeGCRequest::try_parse_request(header, body, order)?)),
+            xproto::CLEAR_AREA_REQUEST => return Ok(Request::ClearArea(xproto::ClearAreaRequest::try_parse_request(header, body, order)?)),
+            xproto::COPY_AREA_REQUEST => return Ok(Request::CopyArea(xproto::CopyAreaRequest::try_parse_request(header, body, order)?)),
+            xproto::COPY_PLANE_REQUEST => return Ok(Request::CopyPlane(xproto::CopyPlaneRequest::try_parse_request(header, body, order)?)),
+            xproto::POLY_POINT_REQUEST => return Ok(Request::PolyPoint(xproto::PolyPointRequest::try_parse_request(header, body, order)?)),
+            xproto::POLY_LINE_REQUEST => return Ok(Request::PolyLine(xproto::PolyLineRequest::try_parse_request(header, body, order)?)),
+            xproto::POLY_SEGMENT_REQUEST => return Ok(Request::PolySegment(xproto::PolySegmentRequest::try_parse_request(header, body, order)?)),
+            xproto::POLY_RECTANGLE_REQUEST => return Ok(Request::PolyRectangle(xproto::PolyRectangleRequest::try_parse_request(header, body, order)?)),
+            xproto::POLY_ARC_REQUEST => return Ok(Request::PolyArc(xproto::PolyArcRequest::try_parse_request(header, body, order)?)),
+            xproto::FILL_POLY_REQUEST => return Ok(Request::FillPoly(xproto::FillPolyRequest::try_parse_request(header, body, order)?)),
+            xproto::POLY_FILL_RECTANGLE_REQUEST => return Ok(Request::PolyFillRectangle(xproto::PolyFillRectangleRequest::try_parse_request(header, body, order)?)),
+            xproto::POLY_FILL_ARC_REQUEST => return Ok(Request::PolyFillArc(xproto::PolyFillArcRequest::try_parse_request(header, body, order)?)),
+            xproto::PUT_IMAGE_REQUEST => return Ok(Request::PutImage(xproto::PutImageRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_IMAGE_REQUEST => return Ok(Request::GetImage(xproto::GetImageRequest::try_parse_request(header, body, order)?)),
+            xproto::POLY_TEXT8_REQUEST => return Ok(Request::PolyText8(xproto::PolyText8Request::try_parse_request(header, body, order)?)),
+            xproto::POLY_TEXT16_REQUEST => return Ok(Request::PolyText16(xproto::PolyText16Request::try_parse_request(header, body, order)?)),
+            xproto::IMAGE_TEXT8_REQUEST => return Ok(Request::ImageText8(xproto::ImageText8Request::try_parse_request(header, body, order)?)),
+            xproto::IMAGE_TEXT16_REQUEST => return Ok(Request::ImageText16(xproto::ImageText16Request::try_parse_request(header, body, order)?)),
+            xproto::CREATE_COLORMAP_REQUEST => return Ok(Request::CreateColormap(xproto::CreateColormapRequest::try_parse_request(header, body, order)?)),
+            xproto::FREE_COLORMAP_REQUEST => return Ok(Request::FreeColormap(xproto::FreeColormapRequest::try_parse_request(header, body, order)?)),
+            xproto::COPY_COLORMAP_AND_FREE_REQUEST => return Ok(Request::CopyColormapAndFree(xproto::CopyColormapAndFreeRequest::try_parse_request(header, body, order)?)),
+            xproto::INSTALL_COLORMAP_REQUEST => return Ok(Request::InstallColormap(xproto::InstallColormapRequest::try_parse_request(header, body, order)?)),
+            xproto::UNINSTALL_COLORMAP_REQUEST => return Ok(Request::UninstallColormap(xproto::UninstallColormapRequest::try_parse_request(header, body, order)?)),
+            xproto::LIST_INSTALLED_COLORMAPS_REQUEST => return Ok(Request::ListInstalledColormaps(xproto::ListInstalledColormapsRequest::try_parse_request(header, body, order)?)),
+            xproto::ALLOC_COLOR_REQUEST => return Ok(Request::AllocColor(xproto::AllocColorRequest::try_parse_request(header, body, order)?)),
+            xproto::ALLOC_NAMED_COLOR_REQUEST => return Ok(Request::AllocNamedColor(xproto::AllocNamedColorRequest::try_parse_request(header, body, order)?)),
+            xproto::ALLOC_COLOR_CELLS_REQUEST => return Ok(Request::AllocColorCells(xproto::AllocColorCellsRequest::try_parse_request(header, body, order)?)),
+            xproto::ALLOC_COLOR_PLANES_REQUEST => return Ok(Request::AllocColorPlanes(xproto::AllocColorPlanesRequest::try_parse_request(header, body, order)?)),
+            xproto::FREE_COLORS_REQUEST => return Ok(Request::FreeColors(xproto::FreeColorsRequest::try_parse_request(header, body, order)?)),
+            xproto::STORE_COLORS_REQUEST => return Ok(Request::StoreColors(xproto::StoreColorsRequest::try_parse_request(header, body, order)?)),
+            xproto::STORE_NAMED_COLOR_REQUEST => return Ok(Request::StoreNamedColor(xproto::StoreNamedColorRequest::try_parse_request(header, body, order)?)),
+            xproto::QUERY_COLORS_REQUEST => return Ok(Request::QueryColors(xproto::QueryColorsRequest::try_parse_request(header, body, order)?)),
+            xproto::LOOKUP_COLOR_REQUEST => return Ok(Request::LookupColor(xproto::LookupColorRequest::try_parse_request(header, body, order)?)),
+            xproto::CREATE_CURSOR_REQUEST => return Ok(Request::CreateCursor(xproto::CreateCursorRequest::try_parse_request(header, body, order)?)),
+            xproto::CREATE_GLYPH_CURSOR_REQUEST => return Ok(Request::CreateGlyphCursor(xproto::CreateGlyphCursorRequest::try_parse_request(header, body, order)?)),
+            xproto::FREE_CURSOR_REQUEST => return Ok(Request::FreeCursor(xproto::FreeCursorRequest::try_parse_request(header, body, order)?)),
+            xproto::RECOLOR_CURSOR_REQUEST => return Ok(Request::RecolorCursor(xproto::RecolorCursorRequest::try_parse_request(header, body, order)?)),
+            xproto::QUERY_BEST_SIZE_REQUEST => return Ok(Request::QueryBestSize(xproto::QueryBestSizeRequest::try_parse_request(header, body, order)?)),
+            xproto::QUERY_EXTENSION_REQUEST => return Ok(Request::QueryExtension(xproto::QueryExtensionRequest::try_parse_request(header, body, order)?)),
+            xproto::LIST_EXTENSIONS_REQUEST => return Ok(Request::ListExtensions(xproto::ListExtensionsRequest::try_parse_request(header, body, order)?)),
+            xproto::CHANGE_KEYBOARD_MAPPING_REQUEST => return Ok(Request::ChangeKeyboardMapping(xproto::ChangeKeyboardMappingRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_KEYBOARD_MAPPING_REQUEST => return Ok(Request::GetKeyboardMapping(xproto::GetKeyboardMappingRequest::try_parse_request(header, body, order)?)),
+            xproto::CHANGE_KEYBOARD_CONTROL_REQUEST => return Ok(Request::ChangeKeyboardControl(xproto::ChangeKeyboardControlRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_KEYBOARD_CONTROL_REQUEST => return Ok(Request::GetKeyboardControl(xproto::GetKeyboardControlRequest::try_parse_request(header, body, order)?)),
+            xproto::BELL_REQUEST => return Ok(Request::Bell(xproto::BellRequest::try_parse_request(header, body, order)?)),
+            xproto::CHANGE_POINTER_CONTROL_REQUEST => return Ok(Request::ChangePointerControl(xproto::ChangePointerControlRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_POINTER_CONTROL_REQUEST => return Ok(Request::GetPointerControl(xproto::GetPointerControlRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_SCREEN_SAVER_REQUEST => return Ok(Request::SetScreenSaver(xproto::SetScreenSaverRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_SCREEN_SAVER_REQUEST => return Ok(Request::GetScreenSaver(xproto::GetScreenSaverRequest::try_parse_request(header, body, order)?)),
+            xproto::CHANGE_HOSTS_REQUEST => return Ok(Request::ChangeHosts(xproto::ChangeHostsRequest::try_parse_request(header, body, order)?)),
+            xproto::LIST_HOSTS_REQUEST => return Ok(Request::ListHosts(xproto::ListHostsRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_ACCESS_CONTROL_REQUEST => return Ok(Request::SetAccessControl(xproto::SetAccessControlRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_CLOSE_DOWN_MODE_REQUEST => return Ok(Request::SetCloseDownMode(xproto::SetCloseDownModeRequest::try_parse_request(header, body, order)?)),
+            xproto::KILL_CLIENT_REQUEST => return Ok(Request::KillClient(xproto::KillClientRequest::try_parse_request(header, body, order)?)),
+            xproto::ROTATE_PROPERTIES_REQUEST => return Ok(Request::RotateProperties(xproto::RotatePropertiesRequest::try_parse_request(header, body, order)?)),
+            xproto::FORCE_SCREEN_SAVER_REQUEST => return Ok(Request::ForceScreenSaver(xproto::ForceScreenSaverRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_POINTER_MAPPING_REQUEST => return Ok(Request::SetPointerMapping(xproto::SetPointerMappingRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_POINTER_MAPPING_REQUEST => return Ok(Request::GetPointerMapping(xproto::GetPointerMappingRequest::try_parse_request(header, body, order)?)),
+            xproto::SET_MODIFIER_MAPPING_REQUEST => return Ok(Request::SetModifierMapping(xproto::SetModifierMappingRequest::try_parse_request(header, body, order)?)),
+            xproto::GET_MODIFIER_MAPPING_REQUEST => return Ok(Request::GetModifierMapping(xproto::GetModifierMappingRequest::try_parse_request(header, body, order)?)),
+            xproto::NO_OPERATION_REQUEST => return Ok(Request::NoOperation(xproto::NoOperationRequest::try_parse_request(header, body, order)?)),
+            _ => {}
+        }
+
+        // Find the extension that this request could belong to
+        let ext_info = ext_info_provider.get_from_major_opcode(header.major_opcode);
+        match ext_info {
+            Some((bigreq::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    bigreq::ENABLE_REQUEST => Ok(Request::BigreqEnable(bigreq::EnableRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "composite")]
+            Some((composite::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    composite::QUERY_VERSION_REQUEST => Ok(Request::CompositeQueryVersion(composite::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    composite::REDIRECT_WINDOW_REQUEST => Ok(Request::CompositeRedirectWindow(composite::RedirectWindowRequest::try_parse_request(header, body, order)?)),
+                    composite::REDIRECT_SUBWINDOWS_REQUEST => Ok(Request::CompositeRedirectSubwindows(composite::RedirectSubwindowsRequest::try_parse_request(header, body, order)?)),
+                    composite::UNREDIRECT_WINDOW_REQUEST => Ok(Request::CompositeUnredirectWindow(composite::UnredirectWindowRequest::try_parse_request(header, body, order)?)),
+                    composite::UNREDIRECT_SUBWINDOWS_REQUEST => Ok(Request::CompositeUnredirectSubwindows(composite::UnredirectSubwindowsRequest::try_parse_request(header, body, order)?)),
+                    composite::CREATE_REGION_FROM_BORDER_CLIP_REQUEST => Ok(Request::CompositeCreateRegionFromBorderClip(composite::CreateRegionFromBorderClipRequest::try_parse_request(header, body, order)?)),
+                    composite::NAME_WINDOW_PIXMAP_REQUEST => Ok(Request::CompositeNameWindowPixmap(composite::NameWindowPixmapRequest::try_parse_request(header, body, order)?)),
+                    composite::GET_OVERLAY_WINDOW_REQUEST => Ok(Request::CompositeGetOverlayWindow(composite::GetOverlayWindowRequest::try_parse_request(header, body, order)?)),
+                    composite::RELEASE_OVERLAY_WINDOW_REQUEST => Ok(Request::CompositeReleaseOverlayWindow(composite::ReleaseOverlayWindowRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "damage")]
+            Some((damage::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    damage::QUERY_VERSION_REQUEST => Ok(Request::DamageQueryVersion(damage::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    damage::CREATE_REQUEST => Ok(Request::DamageCreate(damage::CreateRequest::try_parse_request(header, body, order)?)),
+                    damage::DESTROY_REQUEST => Ok(Request::DamageDestroy(damage::DestroyRequest::try_parse_request(header, body, order)?)),
+                    damage::SUBTRACT_REQUEST => Ok(Request::DamageSubtract(damage::SubtractRequest::try_parse_request(header, body, order)?)),
+                    damage::ADD_REQUEST => Ok(Request::DamageAdd(damage::AddRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "dpms")]
+            Some((dpms::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    dpms::GET_VERSION_REQUEST => Ok(Request::DpmsGetVersion(dpms::GetVersionRequest::try_parse_request(header, body, order)?)),
+                    dpms::CAPABLE_REQUEST => Ok(Request::DpmsCapable(dpms::CapableRequest::try_parse_request(header, body, order)?)),
+                    dpms::GET_TIMEOUTS_REQUEST => Ok(Request::DpmsGetTimeouts(dpms::GetTimeoutsRequest::try_parse_request(header, body, order)?)),
+                    dpms::SET_TIMEOUTS_REQUEST => Ok(Request::DpmsSetTimeouts(dpms::SetTimeoutsRequest::try_parse_request(header, body, order)?)),
+                    dpms::ENABLE_REQUEST => Ok(Request::DpmsEnable(dpms::EnableRequest::try_parse_request(header, body, order)?)),
+                    dpms::DISABLE_REQUEST => Ok(Request::DpmsDisable(dpms::DisableRequest::try_parse_request(header, body, order)?)),
+                    dpms::FORCE_LEVEL_REQUEST => Ok(Request::DpmsForceLevel(dpms::ForceLevelRequest::try_parse_request(header, body, order)?)),
+                    dpms::INFO_REQUEST => Ok(Request::DpmsInfo(dpms::InfoRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "dri2")]
+            Some((dri2::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    dri2::QUERY_VERSION_REQUEST => Ok(Request::Dri2QueryVersion(dri2::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    dri2::CONNECT_REQUEST => Ok(Request::Dri2Connect(dri2::ConnectRequest::try_parse_request(header, body, order)?)),
+                    dri2::AUTHENTICATE_REQUEST => Ok(Request::Dri2Authenticate(dri2::AuthenticateRequest::try_parse_request(header, body, order)?)),
+                    dri2::CREATE_DRAWABLE_REQUEST => Ok(Request::Dri2CreateDrawable(dri2::CreateDrawableRequest::try_parse_request(header, body, order)?)),
+                    dri2::DESTROY_DRAWABLE_REQUEST => Ok(Request::Dri2DestroyDrawable(dri2::DestroyDrawableRequest::try_parse_request(header, body, order)?)),
+                    dri2::GET_BUFFERS_REQUEST => Ok(Request::Dri2GetBuffers(dri2::GetBuffersRequest::try_parse_request(header, body, order)?)),
+                    dri2::COPY_REGION_REQUEST => Ok(Request::Dri2CopyRegion(dri2::CopyRegionRequest::try_parse_request(header, body, order)?)),
+                    dri2::GET_BUFFERS_WITH_FORMAT_REQUEST => Ok(Request::Dri2GetBuffersWithFormat(dri2::GetBuffersWithFormatRequest::try_parse_request(header, body, order)?)),
+                    dri2::SWAP_BUFFERS_REQUEST => Ok(Request::Dri2SwapBuffers(dri2::SwapBuffersRequest::try_parse_request(header, body, order)?)),
+                    dri2::GET_MSC_REQUEST => Ok(Request::Dri2GetMSC(dri2::GetMSCRequest::try_parse_request(header, body, order)?)),
+                    dri2::WAIT_MSC_REQUEST => Ok(Request::Dri2WaitMSC(dri2::WaitMSCRequest::try_parse_request(header, body, order)?)),
+                    dri2::WAIT_SBC_REQUEST => Ok(Request::Dri2WaitSBC(dri2::WaitSBCRequest::try_parse_request(header, body, order)?)),
+                    dri2::SWAP_INTERVAL_REQUEST => Ok(Request::Dri2SwapInterval(dri2::SwapIntervalRequest::try_parse_request(header, body, order)?)),
+                    dri2::GET_PARAM_REQUEST => Ok(Request::Dri2GetParam(dri2::GetParamRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "dri3")]
+            Some((dri3::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    dri3::QUERY_VERSION_REQUEST => Ok(Request::Dri3QueryVersion(dri3::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    dri3::OPEN_REQUEST => Ok(Request::Dri3Open(dri3::OpenRequest::try_parse_request(header, body, order)?)),
+                    dri3::PIXMAP_FROM_BUFFER_REQUEST => Ok(Request::Dri3PixmapFromBuffer(dri3::PixmapFromBufferRequest::try_parse_request_fd(header, body, fds, order)?)),
+                    dri3::BUFFER_FROM_PIXMAP_REQUEST => Ok(Request::Dri3BufferFromPixmap(dri3::BufferFromPixmapRequest::try_parse_request(header, body, order)?)),
+                    dri3::FENCE_FROM_FD_REQUEST => Ok(Request::Dri3FenceFromFD(dri3::FenceFromFDRequest::try_parse_request_fd(header, body, fds, order)?)),
+                    dri3::FD_FROM_FENCE_REQUEST => Ok(Request::Dri3FDFromFence(dri3::FDFromFenceRequest::try_parse_request(header, body, order)?)),
+                    dri3::GET_SUPPORTED_MODIFIERS_REQUEST => Ok(Request::Dri3GetSupportedModifiers(dri3::GetSupportedModifiersRequest::try_parse_request(header, body, order)?)),
+                    dri3::PIXMAP_FROM_BUFFERS_REQUEST => Ok(Request::Dri3PixmapFromBuffers(dri3::PixmapFromBuffersRequest::try_parse_request_fd(header, body, fds, order)?)),
+                    dri3::BUFFERS_FROM_PIXMAP_REQUEST => Ok(Request::Dri3BuffersFromPixmap(dri3::BuffersFromPixmapRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            Some((ge::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    ge::QUERY_VERSION_REQUEST => Ok(Request::GeQueryVersion(ge::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "glx")]
+            Some((glx::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    glx::RENDER_REQUEST => Ok(Request::GlxRender(glx::RenderRequest::try_parse_request(header, body, order)?)),
+                    glx::RENDER_LARGE_REQUEST => Ok(Request::GlxRenderLarge(glx::RenderLargeRequest::try_parse_request(header, body, order)?)),
+                    glx::CREATE_CONTEXT_REQUEST => Ok(Request::GlxCreateContext(glx::CreateContextRequest::try_parse_request(header, body, order)?)),
+                    glx::DESTROY_CONTEXT_REQUEST => Ok(Request::GlxDestroyContext(glx::DestroyContextRequest::try_parse_request(header, body, order)?)),
+                    glx::MAKE_CURRENT_REQUEST => Ok(Request::GlxMakeCurrent(glx::MakeCurrentRequest::try_parse_request(header, body, order)?)),
+                    glx::IS_DIRECT_REQUEST => Ok(Request::GlxIsDirect(glx::IsDirectRequest::try_parse_request(header, body, order)?)),
+                    glx::QUERY_VERSION_REQUEST => Ok(Request::GlxQueryVersion(glx::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    glx::WAIT_GL_REQUEST => Ok(Request::GlxWaitGL(glx::WaitGLRequest::try_parse_request(header, body, order)?)),
+                    glx::WAIT_X_REQUEST => Ok(Request::GlxWaitX(glx::WaitXRequest::try_parse_request(header, body, order)?)),
+                    glx::COPY_CONTEXT_REQUEST => Ok(Request::GlxCopyContext(glx::CopyContextRequest::try_parse_request(header, body, order)?)),
+                    glx::SWAP_BUFFERS_REQUEST => Ok(Request::GlxSwapBuffers(glx::SwapBuffersRequest::try_parse_request(header, body, order)?)),
+                    glx::USE_X_FONT_REQUEST => Ok(Request::GlxUseXFont(glx::UseXFontRequest::try_parse_request(header, body, order)?)),
+                    glx::CREATE_GLX_PIXMAP_REQUEST => Ok(Request::GlxCreateGLXPixmap(glx::CreateGLXPixmapRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_VISUAL_CONFIGS_REQUEST => Ok(Request::GlxGetVisualConfigs(glx::GetVisualConfigsRequest::try_parse_request(header, body, order)?)),
+                    glx::DESTROY_GLX_PIXMAP_REQUEST => Ok(Request::GlxDestroyGLXPixmap(glx::DestroyGLXPixmapRequest::try_parse_request(header, body, order)?)),
+                    glx::VENDOR_PRIVATE_REQUEST => Ok(Request::GlxVendorPrivate(glx::VendorPrivateRequest::try_parse_request(header, body, order)?)),
+                    glx::VENDOR_PRIVATE_WITH_REPLY_REQUEST => Ok(Request::GlxVendorPrivateWithReply(glx::VendorPrivateWithReplyRequest::try_parse_request(header, body, order)?)),
+                    glx::QUERY_EXTENSIONS_STRING_REQUEST => Ok(Request::GlxQueryExtensionsString(glx::QueryExtensionsStringRequest::try_parse_request(header, body, order)?)),
+                    glx::QUERY_SERVER_STRING_REQUEST => Ok(Request::GlxQueryServerString(glx::QueryServerStringRequest::try_parse_request(header, body, order)?)),
+                    glx::CLIENT_INFO_REQUEST => Ok(Request::GlxClientInfo(glx::ClientInfoRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_FB_CONFIGS_REQUEST => Ok(Request::GlxGetFBConfigs(glx::GetFBConfigsRequest::try_parse_request(header, body, order)?)),
+                    glx::CREATE_PIXMAP_REQUEST => Ok(Request::GlxCreatePixmap(glx::CreatePixmapRequest::try_parse_request(header, body, order)?)),
+                    glx::DESTROY_PIXMAP_REQUEST => Ok(Request::GlxDestroyPixmap(glx::DestroyPixmapRequest::try_parse_request(header, body, order)?)),
+                    glx::CREATE_NEW_CONTEXT_REQUEST => Ok(Request::GlxCreateNewContext(glx::CreateNewContextRequest::try_parse_request(header, body, order)?)),
+                    glx::QUERY_CONTEXT_REQUEST => Ok(Request::GlxQueryContext(glx::QueryContextRequest::try_parse_request(header, body, order)?)),
+                    glx::MAKE_CONTEXT_CURRENT_REQUEST => Ok(Request::GlxMakeContextCurrent(glx::MakeContextCurrentRequest::try_parse_request(header, body, order)?)),
+                    glx::CREATE_PBUFFER_REQUEST => Ok(Request::GlxCreatePbuffer(glx::CreatePbufferRequest::try_parse_request(header, body, order)?)),
+                    glx::DESTROY_PBUFFER_REQUEST => Ok(Request::GlxDestroyPbuffer(glx::DestroyPbufferRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_DRAWABLE_ATTRIBUTES_REQUEST => Ok(Request::GlxGetDrawableAttributes(glx::GetDrawableAttributesRequest::try_parse_request(header, body, order)?)),
+                    glx::CHANGE_DRAWABLE_ATTRIBUTES_REQUEST => Ok(Request::GlxChangeDrawableAttributes(glx::ChangeDrawableAttributesRequest::try_parse_request(header, body, order)?)),
+                    glx::CREATE_WINDOW_REQUEST => Ok(Request::GlxCreateWindow(glx::CreateWindowRequest::try_parse_request(header, body, order)?)),
+                    glx::DELETE_WINDOW_REQUEST => Ok(Request::GlxDeleteWindow(glx::DeleteWindowRequest::try_parse_request(header, body, order)?)),
+                    glx::SET_CLIENT_INFO_ARB_REQUEST => Ok(Request::GlxSetClientInfoARB(glx::SetClientInfoARBRequest::try_parse_request(header, body, order)?)),
+                    glx::CREATE_CONTEXT_ATTRIBS_ARB_REQUEST => Ok(Request::GlxCreateContextAttribsARB(glx::CreateContextAttribsARBRequest::try_parse_request(header, body, order)?)),
+                    glx::SET_CLIENT_INFO2_ARB_REQUEST => Ok(Request::GlxSetClientInfo2ARB(glx::SetClientInfo2ARBRequest::try_parse_request(header, body, order)?)),
+                    glx::NEW_LIST_REQUEST => Ok(Request::GlxNewList(glx::NewListRequest::try_parse_request(header, body, order)?)),
+                    glx::END_LIST_REQUEST => Ok(Request::GlxEndList(glx::EndListRequest::try_parse_request(header, body, order)?)),
+                    glx::DELETE_LISTS_REQUEST => Ok(Request::GlxDeleteLists(glx::DeleteListsRequest::try_parse_request(header, body, order)?)),
+                    glx::GEN_LISTS_REQUEST => Ok(Request::GlxGenLists(glx::GenListsRequest::try_parse_request(header, body, order)?)),
+                    glx::FEEDBACK_BUFFER_REQUEST => Ok(Request::GlxFeedbackBuffer(glx::FeedbackBufferRequest::try_parse_request(header, body, order)?)),
+                    glx::SELECT_BUFFER_REQUEST => Ok(Request::GlxSelectBuffer(glx::SelectBufferRequest::try_parse_request(header, body, order)?)),
+                    glx::RENDER_MODE_REQUEST => Ok(Request::GlxRenderMode(glx::RenderModeRequest::try_parse_request(header, body, order)?)),
+                    glx::FINISH_REQUEST => Ok(Request::GlxFinish(glx::FinishRequest::try_parse_request(header, body, order)?)),
+                    glx::PIXEL_STOREF_REQUEST => Ok(Request::GlxPixelStoref(glx::PixelStorefRequest::try_parse_request(header, body, order)?)),
+                    glx::PIXEL_STOREI_REQUEST => Ok(Request::GlxPixelStorei(glx::PixelStoreiRequest::try_parse_request(header, body, order)?)),
+                    glx::READ_PIXELS_REQUEST => Ok(Request::GlxReadPixels(glx::ReadPixelsRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_BOOLEANV_REQUEST => Ok(Request::GlxGetBooleanv(glx::GetBooleanvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_CLIP_PLANE_REQUEST => Ok(Request::GlxGetClipPlane(glx::GetClipPlaneRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_DOUBLEV_REQUEST => Ok(Request::GlxGetDoublev(glx::GetDoublevRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_ERROR_REQUEST => Ok(Request::GlxGetError(glx::GetErrorRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_FLOATV_REQUEST => Ok(Request::GlxGetFloatv(glx::GetFloatvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_INTEGERV_REQUEST => Ok(Request::GlxGetIntegerv(glx::GetIntegervRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_LIGHTFV_REQUEST => Ok(Request::GlxGetLightfv(glx::GetLightfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_LIGHTIV_REQUEST => Ok(Request::GlxGetLightiv(glx::GetLightivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_MAPDV_REQUEST => Ok(Request::GlxGetMapdv(glx::GetMapdvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_MAPFV_REQUEST => Ok(Request::GlxGetMapfv(glx::GetMapfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_MAPIV_REQUEST => Ok(Request::GlxGetMapiv(glx::GetMapivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_MATERIALFV_REQUEST => Ok(Request::GlxGetMaterialfv(glx::GetMaterialfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_MATERIALIV_REQUEST => Ok(Request::GlxGetMaterialiv(glx::GetMaterialivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_PIXEL_MAPFV_REQUEST => Ok(Request::GlxGetPixelMapfv(glx::GetPixelMapfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_PIXEL_MAPUIV_REQUEST => Ok(Request::GlxGetPixelMapuiv(glx::GetPixelMapuivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_PIXEL_MAPUSV_REQUEST => Ok(Request::GlxGetPixelMapusv(glx::GetPixelMapusvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_POLYGON_STIPPLE_REQUEST => Ok(Request::GlxGetPolygonStipple(glx::GetPolygonStippleRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_STRING_REQUEST => Ok(Request::GlxGetString(glx::GetStringRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_ENVFV_REQUEST => Ok(Request::GlxGetTexEnvfv(glx::GetTexEnvfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_ENVIV_REQUEST => Ok(Request::GlxGetTexEnviv(glx::GetTexEnvivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_GENDV_REQUEST => Ok(Request::GlxGetTexGendv(glx::GetTexGendvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_GENFV_REQUEST => Ok(Request::GlxGetTexGenfv(glx::GetTexGenfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_GENIV_REQUEST => Ok(Request::GlxGetTexGeniv(glx::GetTexGenivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_IMAGE_REQUEST => Ok(Request::GlxGetTexImage(glx::GetTexImageRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_PARAMETERFV_REQUEST => Ok(Request::GlxGetTexParameterfv(glx::GetTexParameterfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_PARAMETERIV_REQUEST => Ok(Request::GlxGetTexParameteriv(glx::GetTexParameterivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_LEVEL_PARAMETERFV_REQUEST => Ok(Request::GlxGetTexLevelParameterfv(glx::GetTexLevelParameterfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_TEX_LEVEL_PARAMETERIV_REQUEST => Ok(Request::GlxGetTexLevelParameteriv(glx::GetTexLevelParameterivRequest::try_parse_request(header, body, order)?)),
+                    glx::IS_ENABLED_REQUEST => Ok(Request::GlxIsEnabled(glx::IsEnabledRequest::try_parse_request(header, body, order)?)),
+                    glx::IS_LIST_REQUEST => Ok(Request::GlxIsList(glx::IsListRequest::try_parse_request(header, body, order)?)),
+                    glx::FLUSH_REQUEST => Ok(Request::GlxFlush(glx::FlushRequest::try_parse_request(header, body, order)?)),
+                    glx::ARE_TEXTURES_RESIDENT_REQUEST => Ok(Request::GlxAreTexturesResident(glx::AreTexturesResidentRequest::try_parse_request(header, body, order)?)),
+                    glx::DELETE_TEXTURES_REQUEST => Ok(Request::GlxDeleteTextures(glx::DeleteTexturesRequest::try_parse_request(header, body, order)?)),
+                    glx::GEN_TEXTURES_REQUEST => Ok(Request::GlxGenTextures(glx::GenTexturesRequest::try_parse_request(header, body, order)?)),
+                    glx::IS_TEXTURE_REQUEST => Ok(Request::GlxIsTexture(glx::IsTextureRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_COLOR_TABLE_REQUEST => Ok(Request::GlxGetColorTable(glx::GetColorTableRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_COLOR_TABLE_PARAMETERFV_REQUEST => Ok(Request::GlxGetColorTableParameterfv(glx::GetColorTableParameterfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_COLOR_TABLE_PARAMETERIV_REQUEST => Ok(Request::GlxGetColorTableParameteriv(glx::GetColorTableParameterivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_CONVOLUTION_FILTER_REQUEST => Ok(Request::GlxGetConvolutionFilter(glx::GetConvolutionFilterRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_CONVOLUTION_PARAMETERFV_REQUEST => Ok(Request::GlxGetConvolutionParameterfv(glx::GetConvolutionParameterfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_CONVOLUTION_PARAMETERIV_REQUEST => Ok(Request::GlxGetConvolutionParameteriv(glx::GetConvolutionParameterivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_SEPARABLE_FILTER_REQUEST => Ok(Request::GlxGetSeparableFilter(glx::GetSeparableFilterRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_HISTOGRAM_REQUEST => Ok(Request::GlxGetHistogram(glx::GetHistogramRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_HISTOGRAM_PARAMETERFV_REQUEST => Ok(Request::GlxGetHistogramParameterfv(glx::GetHistogramParameterfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_HISTOGRAM_PARAMETERIV_REQUEST => Ok(Request::GlxGetHistogramParameteriv(glx::GetHistogramParameterivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_MINMAX_REQUEST => Ok(Request::GlxGetMinmax(glx::GetMinmaxRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_MINMAX_PARAMETERFV_REQUEST => Ok(Request::GlxGetMinmaxParameterfv(glx::GetMinmaxParameterfvRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_MINMAX_PARAMETERIV_REQUEST => Ok(Request::GlxGetMinmaxParameteriv(glx::GetMinmaxParameterivRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST => Ok(Request::GlxGetCompressedTexImageARB(glx::GetCompressedTexImageARBRequest::try_parse_request(header, body, order)?)),
+                    glx::DELETE_QUERIES_ARB_REQUEST => Ok(Request::GlxDeleteQueriesARB(glx::DeleteQueriesARBRequest::try_parse_request(header, body, order)?)),
+                    glx::GEN_QUERIES_ARB_REQUEST => Ok(Request::GlxGenQueriesARB(glx::GenQueriesARBRequest::try_parse_request(header, body, order)?)),
+                    glx::IS_QUERY_ARB_REQUEST => Ok(Request::GlxIsQueryARB(glx::IsQueryARBRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_QUERYIV_ARB_REQUEST => Ok(Request::GlxGetQueryivARB(glx::GetQueryivARBRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_QUERY_OBJECTIV_ARB_REQUEST => Ok(Request::GlxGetQueryObjectivARB(glx::GetQueryObjectivARBRequest::try_parse_request(header, body, order)?)),
+                    glx::GET_QUERY_OBJECTUIV_ARB_REQUEST => Ok(Request::GlxGetQueryObjectuivARB(glx::GetQueryObjectuivARBRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "present")]
+            Some((present::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    present::QUERY_VERSION_REQUEST => Ok(Request::PresentQueryVersion(present::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    present::PIXMAP_REQUEST => Ok(Request::PresentPixmap(present::PixmapRequest::try_parse_request(header, body, order)?)),
+                    present::NOTIFY_MSC_REQUEST => Ok(Request::PresentNotifyMSC(present::NotifyMSCRequest::try_parse_request(header, body, order)?)),
+                    present::SELECT_INPUT_REQUEST => Ok(Request::PresentSelectInput(present::SelectInputRequest::try_parse_request(header, body, order)?)),
+                    present::QUERY_CAPABILITIES_REQUEST => Ok(Request::PresentQueryCapabilities(present::QueryCapabilitiesRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "randr")]
+            Some((randr::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    randr::QUERY_VERSION_REQUEST => Ok(Request::RandrQueryVersion(randr::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_SCREEN_CONFIG_REQUEST => Ok(Request::RandrSetScreenConfig(randr::SetScreenConfigRequest::try_parse_request(header, body, order)?)),
+                    randr::SELECT_INPUT_REQUEST => Ok(Request::RandrSelectInput(randr::SelectInputRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_SCREEN_INFO_REQUEST => Ok(Request::RandrGetScreenInfo(randr::GetScreenInfoRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_SCREEN_SIZE_RANGE_REQUEST => Ok(Request::RandrGetScreenSizeRange(randr::GetScreenSizeRangeRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_SCREEN_SIZE_REQUEST => Ok(Request::RandrSetScreenSize(randr::SetScreenSizeRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_SCREEN_RESOURCES_REQUEST => Ok(Request::RandrGetScreenResources(randr::GetScreenResourcesRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_OUTPUT_INFO_REQUEST => Ok(Request::RandrGetOutputInfo(randr::GetOutputInfoRequest::try_parse_request(header, body, order)?)),
+                    randr::LIST_OUTPUT_PROPERTIES_REQUEST => Ok(Request::RandrListOutputProperties(randr::ListOutputPropertiesRequest::try_parse_request(header, body, order)?)),
+                    randr::QUERY_OUTPUT_PROPERTY_REQUEST => Ok(Request::RandrQueryOutputProperty(randr::QueryOutputPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::CONFIGURE_OUTPUT_PROPERTY_REQUEST => Ok(Request::RandrConfigureOutputProperty(randr::ConfigureOutputPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::CHANGE_OUTPUT_PROPERTY_REQUEST => Ok(Request::RandrChangeOutputProperty(randr::ChangeOutputPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::DELETE_OUTPUT_PROPERTY_REQUEST => Ok(Request::RandrDeleteOutputProperty(randr::DeleteOutputPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_OUTPUT_PROPERTY_REQUEST => Ok(Request::RandrGetOutputProperty(randr::GetOutputPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::CREATE_MODE_REQUEST => Ok(Request::RandrCreateMode(randr::CreateModeRequest::try_parse_request(header, body, order)?)),
+                    randr::DESTROY_MODE_REQUEST => Ok(Request::RandrDestroyMode(randr::DestroyModeRequest::try_parse_request(header, body, order)?)),
+                    randr::ADD_OUTPUT_MODE_REQUEST => Ok(Request::RandrAddOutputMode(randr::AddOutputModeRequest::try_parse_request(header, body, order)?)),
+                    randr::DELETE_OUTPUT_MODE_REQUEST => Ok(Request::RandrDeleteOutputMode(randr::DeleteOutputModeRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_CRTC_INFO_REQUEST => Ok(Request::RandrGetCrtcInfo(randr::GetCrtcInfoRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_CRTC_CONFIG_REQUEST => Ok(Request::RandrSetCrtcConfig(randr::SetCrtcConfigRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_CRTC_GAMMA_SIZE_REQUEST => Ok(Request::RandrGetCrtcGammaSize(randr::GetCrtcGammaSizeRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_CRTC_GAMMA_REQUEST => Ok(Request::RandrGetCrtcGamma(randr::GetCrtcGammaRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_CRTC_GAMMA_REQUEST => Ok(Request::RandrSetCrtcGamma(randr::SetCrtcGammaRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_SCREEN_RESOURCES_CURRENT_REQUEST => Ok(Request::RandrGetScreenResourcesCurrent(randr::GetScreenResourcesCurrentRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_CRTC_TRANSFORM_REQUEST => Ok(Request::RandrSetCrtcTransform(randr::SetCrtcTransformRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_CRTC_TRANSFORM_REQUEST => Ok(Request::RandrGetCrtcTransform(randr::GetCrtcTransformRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_PANNING_REQUEST => Ok(Request::RandrGetPanning(randr::GetPanningRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_PANNING_REQUEST => Ok(Request::RandrSetPanning(randr::SetPanningRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_OUTPUT_PRIMARY_REQUEST => Ok(Request::RandrSetOutputPrimary(randr::SetOutputPrimaryRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_OUTPUT_PRIMARY_REQUEST => Ok(Request::RandrGetOutputPrimary(randr::GetOutputPrimaryRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_PROVIDERS_REQUEST => Ok(Request::RandrGetProviders(randr::GetProvidersRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_PROVIDER_INFO_REQUEST => Ok(Request::RandrGetProviderInfo(randr::GetProviderInfoRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_PROVIDER_OFFLOAD_SINK_REQUEST => Ok(Request::RandrSetProviderOffloadSink(randr::SetProviderOffloadSinkRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_PROVIDER_OUTPUT_SOURCE_REQUEST => Ok(Request::RandrSetProviderOutputSource(randr::SetProviderOutputSourceRequest::try_parse_request(header, body, order)?)),
+                    randr::LIST_PROVIDER_PROPERTIES_REQUEST => Ok(Request::RandrListProviderProperties(randr::ListProviderPropertiesRequest::try_parse_request(header, body, order)?)),
+                    randr::QUERY_PROVIDER_PROPERTY_REQUEST => Ok(Request::RandrQueryProviderProperty(randr::QueryProviderPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::CONFIGURE_PROVIDER_PROPERTY_REQUEST => Ok(Request::RandrConfigureProviderProperty(randr::ConfigureProviderPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::CHANGE_PROVIDER_PROPERTY_REQUEST => Ok(Request::RandrChangeProviderProperty(randr::ChangeProviderPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::DELETE_PROVIDER_PROPERTY_REQUEST => Ok(Request::RandrDeleteProviderProperty(randr::DeleteProviderPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_PROVIDER_PROPERTY_REQUEST => Ok(Request::RandrGetProviderProperty(randr::GetProviderPropertyRequest::try_parse_request(header, body, order)?)),
+                    randr::GET_MONITORS_REQUEST => Ok(Request::RandrGetMonitors(randr::GetMonitorsRequest::try_parse_request(header, body, order)?)),
+                    randr::SET_MONITOR_REQUEST => Ok(Request::RandrSetMonitor(randr::SetMonitorRequest::try_parse_request(header, body, order)?)),
+                    randr::DELETE_MONITOR_REQUEST => Ok(Request::RandrDeleteMonitor(randr::DeleteMonitorRequest::try_parse_request(header, body, order)?)),
+                    randr::CREATE_LEASE_REQUEST => Ok(Request::RandrCreateLease(randr::CreateLeaseRequest::try_parse_request(header, body, order)?)),
+                    randr::FREE_LEASE_REQUEST => Ok(Request::RandrFreeLease(randr::FreeLeaseRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "record")]
+            Some((record::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    record::QUERY_VERSION_REQUEST => Ok(Request::RecordQueryVersion(record::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    record::CREATE_CONTEXT_REQUEST => Ok(Request::RecordCreateContext(record::CreateContextRequest::try_parse_request(header, body, order)?)),
+                    record::REGISTER_CLIENTS_REQUEST => Ok(Request::RecordRegisterClients(record::RegisterClientsRequest::try_parse_request(header, body, order)?)),
+                    record::UNREGISTER_CLIENTS_REQUEST => Ok(Request::RecordUnregisterClients(record::UnregisterClientsRequest::try_parse_request(header, body, order)?)),
+                    record::GET_CONTEXT_REQUEST => Ok(Request::RecordGetContext(record::GetContextRequest::try_parse_request(header, body, order)?)),
+                    record::ENABLE_CONTEXT_REQUEST => Ok(Request::RecordEnableContext(record::EnableContextRequest::try_parse_request(header, body, order)?)),
+                    record::DISABLE_CONTEXT_REQUEST => Ok(Request::RecordDisableContext(record::DisableContextRequest::try_parse_request(header, body, order)?)),
+                    record::FREE_CONTEXT_REQUEST => Ok(Request::RecordFreeContext(record::FreeContextRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "render")]
+            Some((render::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    render::QUERY_VERSION_REQUEST => Ok(Request::RenderQueryVersion(render::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    render::QUERY_PICT_FORMATS_REQUEST => Ok(Request::RenderQueryPictFormats(render::QueryPictFormatsRequest::try_parse_request(header, body, order)?)),
+                    render::QUERY_PICT_INDEX_VALUES_REQUEST => Ok(Request::RenderQueryPictIndexValues(render::QueryPictIndexValuesRequest::try_parse_request(header, body, order)?)),
+                    render::CREATE_PICTURE_REQUEST => Ok(Request::RenderCreatePicture(render::CreatePictureRequest::try_parse_request(header, body, order)?)),
+                    render::CHANGE_PICTURE_REQUEST => Ok(Request::RenderChangePicture(render::ChangePictureRequest::try_parse_request(header, body, order)?)),
+                    render::SET_PICTURE_CLIP_RECTANGLES_REQUEST => Ok(Request::RenderSetPictureClipRectangles(render::SetPictureClipRectanglesRequest::try_parse_request(header, body, order)?)),
+                    render::FREE_PICTURE_REQUEST => Ok(Request::RenderFreePicture(render::FreePictureRequest::try_parse_request(header, body, order)?)),
+                    render::COMPOSITE_REQUEST => Ok(Request::RenderComposite(render::CompositeRequest::try_parse_request(header, body, order)?)),
+                    render::TRAPEZOIDS_REQUEST => Ok(Request::RenderTrapezoids(render::TrapezoidsRequest::try_parse_request(header, body, order)?)),
+                    render::TRIANGLES_REQUEST => Ok(Request::RenderTriangles(render::TrianglesRequest::try_parse_request(header, body, order)?)),
+                    render::TRI_STRIP_REQUEST => Ok(Request::RenderTriStrip(render::TriStripRequest::try_parse_request(header, body, order)?)),
+                    render::TRI_FAN_REQUEST => Ok(Request::RenderTriFan(render::TriFanRequest::try_parse_request(header, body, order)?)),
+                    render::CREATE_GLYPH_SET_REQUEST => Ok(Request::RenderCreateGlyphSet(render::CreateGlyphSetRequest::try_parse_request(header, body, order)?)),
+                    render::REFERENCE_GLYPH_SET_REQUEST => Ok(Request::RenderReferenceGlyphSet(render::ReferenceGlyphSetRequest::try_parse_request(header, body, order)?)),
+                    render::FREE_GLYPH_SET_REQUEST => Ok(Request::RenderFreeGlyphSet(render::FreeGlyphSetRequest::try_parse_request(header, body, order)?)),
+                    render::ADD_GLYPHS_REQUEST => Ok(Request::RenderAddGlyphs(render::AddGlyphsRequest::try_parse_request(header, body, order)?)),
+                    render::FREE_GLYPHS_REQUEST => Ok(Request::RenderFreeGlyphs(render::FreeGlyphsRequest::try_parse_request(header, body, order)?)),
+                    render::COMPOSITE_GLYPHS8_REQUEST => Ok(Request::RenderCompositeGlyphs8(render::CompositeGlyphs8Request::try_parse_request(header, body, order)?)),
+                    render::COMPOSITE_GLYPHS16_REQUEST => Ok(Request::RenderCompositeGlyphs16(render::CompositeGlyphs16Request::try_parse_request(header, body, order)?)),
+                    render::COMPOSITE_GLYPHS32_REQUEST => Ok(Request::RenderCompositeGlyphs32(render::CompositeGlyphs32Request::try_parse_request(header, body, order)?)),
+                    render::FILL_RECTANGLES_REQUEST => Ok(Request::RenderFillRectangles(render::FillRectanglesRequest::try_parse_request(header, body, order)?)),
+                    render::CREATE_CURSOR_REQUEST => Ok(Request::RenderCreateCursor(render::CreateCursorRequest::try_parse_request(header, body, order)?)),
+                    render::SET_PICTURE_TRANSFORM_REQUEST => Ok(Request::RenderSetPictureTransform(render::SetPictureTransformRequest::try_parse_request(header, body, order)?)),
+                    render::QUERY_FILTERS_REQUEST => Ok(Request::RenderQueryFilters(render::QueryFiltersRequest::try_parse_request(header, body, order)?)),
+                    render::SET_PICTURE_FILTER_REQUEST => Ok(Request::RenderSetPictureFilter(render::SetPictureFilterRequest::try_parse_request(header, body, order)?)),
+                    render::CREATE_ANIM_CURSOR_REQUEST => Ok(Request::RenderCreateAnimCursor(render::CreateAnimCursorRequest::try_parse_request(header, body, order)?)),
+                    render::ADD_TRAPS_REQUEST => Ok(Request::RenderAddTraps(render::AddTrapsRequest::try_parse_request(header, body, order)?)),
+                    render::CREATE_SOLID_FILL_REQUEST => Ok(Request::RenderCreateSolidFill(render::CreateSolidFillRequest::try_parse_request(header, body, order)?)),
+                    render::CREATE_LINEAR_GRADIENT_REQUEST => Ok(Request::RenderCreateLinearGradient(render::CreateLinearGradientRequest::try_parse_request(header, body, order)?)),
+                    render::CREATE_RADIAL_GRADIENT_REQUEST => Ok(Request::RenderCreateRadialGradient(render::CreateRadialGradientRequest::try_parse_request(header, body, order)?)),
+                    render::CREATE_CONICAL_GRADIENT_REQUEST => Ok(Request::RenderCreateConicalGradient(render::CreateConicalGradientRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "res")]
+            Some((res::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    res::QUERY_VERSION_REQUEST => Ok(Request::ResQueryVersion(res::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    res::QUERY_CLIENTS_REQUEST => Ok(Request::ResQueryClients(res::QueryClientsRequest::try_parse_request(header, body, order)?)),
+                    res::QUERY_CLIENT_RESOURCES_REQUEST => Ok(Request::ResQueryClientResources(res::QueryClientResourcesRequest::try_parse_request(header, body, order)?)),
+                    res::QUERY_CLIENT_PIXMAP_BYTES_REQUEST => Ok(Request::ResQueryClientPixmapBytes(res::QueryClientPixmapBytesRequest::try_parse_request(header, body, order)?)),
+                    res::QUERY_CLIENT_IDS_REQUEST => Ok(Request::ResQueryClientIds(res::QueryClientIdsRequest::try_parse_request(header, body, order)?)),
+                    res::QUERY_RESOURCE_BYTES_REQUEST => Ok(Request::ResQueryResourceBytes(res::QueryResourceBytesRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "screensaver")]
+            Some((screensaver::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    screensaver::QUERY_VERSION_REQUEST => Ok(Request::ScreensaverQueryVersion(screensaver::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    screensaver::QUERY_INFO_REQUEST => Ok(Request::ScreensaverQueryInfo(screensaver::QueryInfoRequest::try_parse_request(header, body, order)?)),
+                    screensaver::SELECT_INPUT_REQUEST => Ok(Request::ScreensaverSelectInput(screensaver::SelectInputRequest::try_parse_request(header, body, order)?)),
+                    screensaver::SET_ATTRIBUTES_REQUEST => Ok(Request::ScreensaverSetAttributes(screensaver::SetAttributesRequest::try_parse_request(header, body, order)?)),
+                    screensaver::UNSET_ATTRIBUTES_REQUEST => Ok(Request::ScreensaverUnsetAttributes(screensaver::UnsetAttributesRequest::try_parse_request(header, body, order)?)),
+                    screensaver::SUSPEND_REQUEST => Ok(Request::ScreensaverSuspend(screensaver::SuspendRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "shape")]
+            Some((shape::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    shape::QUERY_VERSION_REQUEST => Ok(Request::ShapeQueryVersion(shape::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    shape::RECTANGLES_REQUEST => Ok(Request::ShapeRectangles(shape::RectanglesRequest::try_parse_request(header, body, order)?)),
+                    shape::MASK_REQUEST => Ok(Request::ShapeMask(shape::MaskRequest::try_parse_request(header, body, order)?)),
+                    shape::COMBINE_REQUEST => Ok(Request::ShapeCombine(shape::CombineRequest::try_parse_request(header, body, order)?)),
+                    shape::OFFSET_REQUEST => Ok(Request::ShapeOffset(shape::OffsetRequest::try_parse_request(header, body, order)?)),
+                    shape::QUERY_EXTENTS_REQUEST => Ok(Request::ShapeQueryExtents(shape::QueryExtentsRequest::try_parse_request(header, body, order)?)),
+                    shape::SELECT_INPUT_REQUEST => Ok(Request::ShapeSelectInput(shape::SelectInputRequest::try_parse_request(header, body, order)?)),
+                    shape::INPUT_SELECTED_REQUEST => Ok(Request::ShapeInputSelected(shape::InputSelectedRequest::try_parse_request(header, body, order)?)),
+                    shape::GET_RECTANGLES_REQUEST => Ok(Request::ShapeGetRectangles(shape::GetRectanglesRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "shm")]
+            Some((shm::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    shm::QUERY_VERSION_REQUEST => Ok(Request::ShmQueryVersion(shm::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    shm::ATTACH_REQUEST => Ok(Request::ShmAttach(shm::AttachRequest::try_parse_request(header, body, order)?)),
+                    shm::DETACH_REQUEST => Ok(Request::ShmDetach(shm::DetachRequest::try_parse_request(header, body, order)?)),
+                    shm::PUT_IMAGE_REQUEST => Ok(Request::ShmPutImage(shm::PutImageRequest::try_parse_request(header, body, order)?)),
+                    shm::GET_IMAGE_REQUEST => Ok(Request::ShmGetImage(shm::GetImageRequest::try_parse_request(header, body, order)?)),
+                    shm::CREATE_PIXMAP_REQUEST => Ok(Request::ShmCreatePixmap(shm::CreatePixmapRequest::try_parse_request(header, body, order)?)),
+                    shm::ATTACH_FD_REQUEST => Ok(Request::ShmAttachFd(shm::AttachFdRequest::try_parse_request_fd(header, body, fds, order)?)),
+                    shm::CREATE_SEGMENT_REQUEST => Ok(Request::ShmCreateSegment(shm::CreateSegmentRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "sync")]
+            Some((sync::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    sync::INITIALIZE_REQUEST => Ok(Request::SyncInitialize(sync::InitializeRequest::try_parse_request(header, body, order)?)),
+                    sync::LIST_SYSTEM_COUNTERS_REQUEST => Ok(Request::SyncListSystemCounters(sync::ListSystemCountersRequest::try_parse_request(header, body, order)?)),
+                    sync::CREATE_COUNTER_REQUEST => Ok(Request::SyncCreateCounter(sync::CreateCounterRequest::try_parse_request(header, body, order)?)),
+                    sync::SET_COUNTER_REQUEST => Ok(Request::SyncSetCounter(sync::SetCounterRequest::try_parse_request(header, body, order)?)),
+                    sync::CHANGE_COUNTER_REQUEST => Ok(Request::SyncChangeCounter(sync::ChangeCounterRequest::try_parse_request(header, body, order)?)),
+                    sync::QUERY_COUNTER_REQUEST => Ok(Request::SyncQueryCounter(sync::QueryCounterRequest::try_parse_request(header, body, order)?)),
+                    sync::DESTROY_COUNTER_REQUEST => Ok(Request::SyncDestroyCounter(sync::DestroyCounterRequest::try_parse_request(header, body, order)?)),
+                    sync::AWAIT_REQUEST => Ok(Request::SyncAwait(sync::AwaitRequest::try_parse_request(header, body, order)?)),
+                    sync::CREATE_ALARM_REQUEST => Ok(Request::SyncCreateAlarm(sync::CreateAlarmRequest::try_parse_request(header, body, order)?)),
+                    sync::CHANGE_ALARM_REQUEST => Ok(Request::SyncChangeAlarm(sync::ChangeAlarmRequest::try_parse_request(header, body, order)?)),
+                    sync::QUERY_ALARM_REQUEST => Ok(Request::SyncQueryAlarm(sync::QueryAlarmRequest::try_parse_request(header, body, order)?)),
+                    sync::DESTROY_ALARM_REQUEST => Ok(Request::SyncDestroyAlarm(sync::DestroyAlarmRequest::try_parse_request(header, body, order)?)),
+                    sync::SET_PRIORITY_REQUEST => Ok(Request::SyncSetPriority(sync::SetPriorityRequest::try_parse_request(header, body, order)?)),
+                    sync::GET_PRIORITY_REQUEST => Ok(Request::SyncGetPriority(sync::GetPriorityRequest::try_parse_request(header, body, order)?)),
+                    sync::CREATE_FENCE_REQUEST => Ok(Request::SyncCreateFence(sync::CreateFenceRequest::try_parse_request(header, body, order)?)),
+                    sync::TRIGGER_FENCE_REQUEST => Ok(Request::SyncTriggerFence(sync::TriggerFenceRequest::try_parse_request(header, body, order)?)),
+                    sync::RESET_FENCE_REQUEST => Ok(Request::SyncResetFence(sync::ResetFenceRequest::try_parse_request(header, body, order)?)),
+                    sync::DESTROY_FENCE_REQUEST => Ok(Request::SyncDestroyFence(sync::DestroyFenceRequest::try_parse_request(header, body, order)?)),
+                    sync::QUERY_FENCE_REQUEST => Ok(Request::SyncQueryFence(sync::QueryFenceRequest::try_parse_request(header, body, order)?)),
+                    sync::AWAIT_FENCE_REQUEST => Ok(Request::SyncAwaitFence(sync::AwaitFenceRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            Some((xc_misc::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xc_misc::GET_VERSION_REQUEST => Ok(Request::XcMiscGetVersion(xc_misc::GetVersionRequest::try_parse_request(header, body, order)?)),
+                    xc_misc::GET_XID_RANGE_REQUEST => Ok(Request::XcMiscGetXIDRange(xc_misc::GetXIDRangeRequest::try_parse_request(header, body, order)?)),
+                    xc_misc::GET_XID_LIST_REQUEST => Ok(Request::XcMiscGetXIDList(xc_misc::GetXIDListRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xevie")]
+            Some((xevie::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xevie::QUERY_VERSION_REQUEST => Ok(Request::XevieQueryVersion(xevie::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    xevie::START_REQUEST => Ok(Request::XevieStart(xevie::StartRequest::try_parse_request(header, body, order)?)),
+                    xevie::END_REQUEST => Ok(Request::XevieEnd(xevie::EndRequest::try_parse_request(header, body, order)?)),
+                    xevie::SEND_REQUEST => Ok(Request::XevieSend(xevie::SendRequest::try_parse_request(header, body, order)?)),
+                    xevie::SELECT_INPUT_REQUEST => Ok(Request::XevieSelectInput(xevie::SelectInputRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xf86dri")]
+            Some((xf86dri::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xf86dri::QUERY_VERSION_REQUEST => Ok(Request::Xf86driQueryVersion(xf86dri::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::QUERY_DIRECT_RENDERING_CAPABLE_REQUEST => Ok(Request::Xf86driQueryDirectRenderingCapable(xf86dri::QueryDirectRenderingCapableRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::OPEN_CONNECTION_REQUEST => Ok(Request::Xf86driOpenConnection(xf86dri::OpenConnectionRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::CLOSE_CONNECTION_REQUEST => Ok(Request::Xf86driCloseConnection(xf86dri::CloseConnectionRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::GET_CLIENT_DRIVER_NAME_REQUEST => Ok(Request::Xf86driGetClientDriverName(xf86dri::GetClientDriverNameRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::CREATE_CONTEXT_REQUEST => Ok(Request::Xf86driCreateContext(xf86dri::CreateContextRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::DESTROY_CONTEXT_REQUEST => Ok(Request::Xf86driDestroyContext(xf86dri::DestroyContextRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::CREATE_DRAWABLE_REQUEST => Ok(Request::Xf86driCreateDrawable(xf86dri::CreateDrawableRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::DESTROY_DRAWABLE_REQUEST => Ok(Request::Xf86driDestroyDrawable(xf86dri::DestroyDrawableRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::GET_DRAWABLE_INFO_REQUEST => Ok(Request::Xf86driGetDrawableInfo(xf86dri::GetDrawableInfoRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::GET_DEVICE_INFO_REQUEST => Ok(Request::Xf86driGetDeviceInfo(xf86dri::GetDeviceInfoRequest::try_parse_request(header, body, order)?)),
+                    xf86dri::AUTH_CONNECTION_REQUEST => Ok(Request::Xf86driAuthConnection(xf86dri::AuthConnectionRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xf86vidmode")]
+            Some((xf86vidmode::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xf86vidmode::QUERY_VERSION_REQUEST => Ok(Request::Xf86vidmodeQueryVersion(xf86vidmode::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::GET_MODE_LINE_REQUEST => Ok(Request::Xf86vidmodeGetModeLine(xf86vidmode::GetModeLineRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::MOD_MODE_LINE_REQUEST => Ok(Request::Xf86vidmodeModModeLine(xf86vidmode::ModModeLineRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::SWITCH_MODE_REQUEST => Ok(Request::Xf86vidmodeSwitchMode(xf86vidmode::SwitchModeRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::GET_MONITOR_REQUEST => Ok(Request::Xf86vidmodeGetMonitor(xf86vidmode::GetMonitorRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::LOCK_MODE_SWITCH_REQUEST => Ok(Request::Xf86vidmodeLockModeSwitch(xf86vidmode::LockModeSwitchRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::GET_ALL_MODE_LINES_REQUEST => Ok(Request::Xf86vidmodeGetAllModeLines(xf86vidmode::GetAllModeLinesRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::ADD_MODE_LINE_REQUEST => Ok(Request::Xf86vidmodeAddModeLine(xf86vidmode::AddModeLineRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::DELETE_MODE_LINE_REQUEST => Ok(Request::Xf86vidmodeDeleteModeLine(xf86vidmode::DeleteModeLineRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::VALIDATE_MODE_LINE_REQUEST => Ok(Request::Xf86vidmodeValidateModeLine(xf86vidmode::ValidateModeLineRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::SWITCH_TO_MODE_REQUEST => Ok(Request::Xf86vidmodeSwitchToMode(xf86vidmode::SwitchToModeRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::GET_VIEW_PORT_REQUEST => Ok(Request::Xf86vidmodeGetViewPort(xf86vidmode::GetViewPortRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::SET_VIEW_PORT_REQUEST => Ok(Request::Xf86vidmodeSetViewPort(xf86vidmode::SetViewPortRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::GET_DOT_CLOCKS_REQUEST => Ok(Request::Xf86vidmodeGetDotClocks(xf86vidmode::GetDotClocksRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::SET_CLIENT_VERSION_REQUEST => Ok(Request::Xf86vidmodeSetClientVersion(xf86vidmode::SetClientVersionRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::SET_GAMMA_REQUEST => Ok(Request::Xf86vidmodeSetGamma(xf86vidmode::SetGammaRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::GET_GAMMA_REQUEST => Ok(Request::Xf86vidmodeGetGamma(xf86vidmode::GetGammaRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::GET_GAMMA_RAMP_REQUEST => Ok(Request::Xf86vidmodeGetGammaRamp(xf86vidmode::GetGammaRampRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::SET_GAMMA_RAMP_REQUEST => Ok(Request::Xf86vidmodeSetGammaRamp(xf86vidmode::SetGammaRampRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::GET_GAMMA_RAMP_SIZE_REQUEST => Ok(Request::Xf86vidmodeGetGammaRampSize(xf86vidmode::GetGammaRampSizeRequest::try_parse_request(header, body, order)?)),
+                    xf86vidmode::GET_PERMISSIONS_REQUEST => Ok(Request::Xf86vidmodeGetPermissions(xf86vidmode::GetPermissionsRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xfixes")]
+            Some((xfixes::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xfixes::QUERY_VERSION_REQUEST => Ok(Request::XfixesQueryVersion(xfixes::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::CHANGE_SAVE_SET_REQUEST => Ok(Request::XfixesChangeSaveSet(xfixes::ChangeSaveSetRequest::try_parse_request(header, body, order)?)),
+                    xfixes::SELECT_SELECTION_INPUT_REQUEST => Ok(Request::XfixesSelectSelectionInput(xfixes::SelectSelectionInputRequest::try_parse_request(header, body, order)?)),
+                    xfixes::SELECT_CURSOR_INPUT_REQUEST => Ok(Request::XfixesSelectCursorInput(xfixes::SelectCursorInputRequest::try_parse_request(header, body, order)?)),
+                    xfixes::GET_CURSOR_IMAGE_REQUEST => Ok(Request::XfixesGetCursorImage(xfixes::GetCursorImageRequest::try_parse_request(header, body, order)?)),
+                    xfixes::CREATE_REGION_REQUEST => Ok(Request::XfixesCreateRegion(xfixes::CreateRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::CREATE_REGION_FROM_BITMAP_REQUEST => Ok(Request::XfixesCreateRegionFromBitmap(xfixes::CreateRegionFromBitmapRequest::try_parse_request(header, body, order)?)),
+                    xfixes::CREATE_REGION_FROM_WINDOW_REQUEST => Ok(Request::XfixesCreateRegionFromWindow(xfixes::CreateRegionFromWindowRequest::try_parse_request(header, body, order)?)),
+                    xfixes::CREATE_REGION_FROM_GC_REQUEST => Ok(Request::XfixesCreateRegionFromGC(xfixes::CreateRegionFromGCRequest::try_parse_request(header, body, order)?)),
+                    xfixes::CREATE_REGION_FROM_PICTURE_REQUEST => Ok(Request::XfixesCreateRegionFromPicture(xfixes::CreateRegionFromPictureRequest::try_parse_request(header, body, order)?)),
+                    xfixes::DESTROY_REGION_REQUEST => Ok(Request::XfixesDestroyRegion(xfixes::DestroyRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::SET_REGION_REQUEST => Ok(Request::XfixesSetRegion(xfixes::SetRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::COPY_REGION_REQUEST => Ok(Request::XfixesCopyRegion(xfixes::CopyRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::UNION_REGION_REQUEST => Ok(Request::XfixesUnionRegion(xfixes::UnionRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::INTERSECT_REGION_REQUEST => Ok(Request::XfixesIntersectRegion(xfixes::IntersectRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::SUBTRACT_REGION_REQUEST => Ok(Request::XfixesSubtractRegion(xfixes::SubtractRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::INVERT_REGION_REQUEST => Ok(Request::XfixesInvertRegion(xfixes::InvertRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::TRANSLATE_REGION_REQUEST => Ok(Request::XfixesTranslateRegion(xfixes::TranslateRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::REGION_EXTENTS_REQUEST => Ok(Request::XfixesRegionExtents(xfixes::RegionExtentsRequest::try_parse_request(header, body, order)?)),
+                    xfixes::FETCH_REGION_REQUEST => Ok(Request::XfixesFetchRegion(xfixes::FetchRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::SET_GC_CLIP_REGION_REQUEST => Ok(Request::XfixesSetGCClipRegion(xfixes::SetGCClipRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::SET_WINDOW_SHAPE_REGION_REQUEST => Ok(Request::XfixesSetWindowShapeRegion(xfixes::SetWindowShapeRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::SET_PICTURE_CLIP_REGION_REQUEST => Ok(Request::XfixesSetPictureClipRegion(xfixes::SetPictureClipRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::SET_CURSOR_NAME_REQUEST => Ok(Request::XfixesSetCursorName(xfixes::SetCursorNameRequest::try_parse_request(header, body, order)?)),
+                    xfixes::GET_CURSOR_NAME_REQUEST => Ok(Request::XfixesGetCursorName(xfixes::GetCursorNameRequest::try_parse_request(header, body, order)?)),
+                    xfixes::GET_CURSOR_IMAGE_AND_NAME_REQUEST => Ok(Request::XfixesGetCursorImageAndName(xfixes::GetCursorImageAndNameRequest::try_parse_request(header, body, order)?)),
+                    xfixes::CHANGE_CURSOR_REQUEST => Ok(Request::XfixesChangeCursor(xfixes::ChangeCursorRequest::try_parse_request(header, body, order)?)),
+                    xfixes::CHANGE_CURSOR_BY_NAME_REQUEST => Ok(Request::XfixesChangeCursorByName(xfixes::ChangeCursorByNameRequest::try_parse_request(header, body, order)?)),
+                    xfixes::EXPAND_REGION_REQUEST => Ok(Request::XfixesExpandRegion(xfixes::ExpandRegionRequest::try_parse_request(header, body, order)?)),
+                    xfixes::HIDE_CURSOR_REQUEST => Ok(Request::XfixesHideCursor(xfixes::HideCursorRequest::try_parse_request(header, body, order)?)),
+                    xfixes::SHOW_CURSOR_REQUEST => Ok(Request::XfixesShowCursor(xfixes::ShowCursorRequest::try_parse_request(header, body, order)?)),
+                    xfixes::CREATE_POINTER_BARRIER_REQUEST => Ok(Request::XfixesCreatePointerBarrier(xfixes::CreatePointerBarrierRequest::try_parse_request(header, body, order)?)),
+                    xfixes::DELETE_POINTER_BARRIER_REQUEST => Ok(Request::XfixesDeletePointerBarrier(xfixes::DeletePointerBarrierRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xinerama")]
+            Some((xinerama::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xinerama::QUERY_VERSION_REQUEST => Ok(Request::XineramaQueryVersion(xinerama::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    xinerama::GET_STATE_REQUEST => Ok(Request::XineramaGetState(xinerama::GetStateRequest::try_parse_request(header, body, order)?)),
+                    xinerama::GET_SCREEN_COUNT_REQUEST => Ok(Request::XineramaGetScreenCount(xinerama::GetScreenCountRequest::try_parse_request(header, body, order)?)),
+                    xinerama::GET_SCREEN_SIZE_REQUEST => Ok(Request::XineramaGetScreenSize(xinerama::GetScreenSizeRequest::try_parse_request(header, body, order)?)),
+                    xinerama::IS_ACTIVE_REQUEST => Ok(Request::XineramaIsActive(xinerama::IsActiveRequest::try_parse_request(header, body, order)?)),
+                    xinerama::QUERY_SCREENS_REQUEST => Ok(Request::XineramaQueryScreens(xinerama::QueryScreensRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xinput")]
+            Some((xinput::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xinput::GET_EXTENSION_VERSION_REQUEST => Ok(Request::XinputGetExtensionVersion(xinput::GetExtensionVersionRequest::try_parse_request(header, body, order)?)),
+                    xinput::LIST_INPUT_DEVICES_REQUEST => Ok(Request::XinputListInputDevices(xinput::ListInputDevicesRequest::try_parse_request(header, body, order)?)),
+                    xinput::OPEN_DEVICE_REQUEST => Ok(Request::XinputOpenDevice(xinput::OpenDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::CLOSE_DEVICE_REQUEST => Ok(Request::XinputCloseDevice(xinput::CloseDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::SET_DEVICE_MODE_REQUEST => Ok(Request::XinputSetDeviceMode(xinput::SetDeviceModeRequest::try_parse_request(header, body, order)?)),
+                    xinput::SELECT_EXTENSION_EVENT_REQUEST => Ok(Request::XinputSelectExtensionEvent(xinput::SelectExtensionEventRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_SELECTED_EXTENSION_EVENTS_REQUEST => Ok(Request::XinputGetSelectedExtensionEvents(xinput::GetSelectedExtensionEventsRequest::try_parse_request(header, body, order)?)),
+                    xinput::CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST => Ok(Request::XinputChangeDeviceDontPropagateList(xinput::ChangeDeviceDontPropagateListRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST => Ok(Request::XinputGetDeviceDontPropagateList(xinput::GetDeviceDontPropagateListRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_DEVICE_MOTION_EVENTS_REQUEST => Ok(Request::XinputGetDeviceMotionEvents(xinput::GetDeviceMotionEventsRequest::try_parse_request(header, body, order)?)),
+                    xinput::CHANGE_KEYBOARD_DEVICE_REQUEST => Ok(Request::XinputChangeKeyboardDevice(xinput::ChangeKeyboardDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::CHANGE_POINTER_DEVICE_REQUEST => Ok(Request::XinputChangePointerDevice(xinput::ChangePointerDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::GRAB_DEVICE_REQUEST => Ok(Request::XinputGrabDevice(xinput::GrabDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::UNGRAB_DEVICE_REQUEST => Ok(Request::XinputUngrabDevice(xinput::UngrabDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::GRAB_DEVICE_KEY_REQUEST => Ok(Request::XinputGrabDeviceKey(xinput::GrabDeviceKeyRequest::try_parse_request(header, body, order)?)),
+                    xinput::UNGRAB_DEVICE_KEY_REQUEST => Ok(Request::XinputUngrabDeviceKey(xinput::UngrabDeviceKeyRequest::try_parse_request(header, body, order)?)),
+                    xinput::GRAB_DEVICE_BUTTON_REQUEST => Ok(Request::XinputGrabDeviceButton(xinput::GrabDeviceButtonRequest::try_parse_request(header, body, order)?)),
+                    xinput::UNGRAB_DEVICE_BUTTON_REQUEST => Ok(Request::XinputUngrabDeviceButton(xinput::UngrabDeviceButtonRequest::try_parse_request(header, body, order)?)),
+                    xinput::ALLOW_DEVICE_EVENTS_REQUEST => Ok(Request::XinputAllowDeviceEvents(xinput::AllowDeviceEventsRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_DEVICE_FOCUS_REQUEST => Ok(Request::XinputGetDeviceFocus(xinput::GetDeviceFocusRequest::try_parse_request(header, body, order)?)),
+                    xinput::SET_DEVICE_FOCUS_REQUEST => Ok(Request::XinputSetDeviceFocus(xinput::SetDeviceFocusRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_FEEDBACK_CONTROL_REQUEST => Ok(Request::XinputGetFeedbackControl(xinput::GetFeedbackControlRequest::try_parse_request(header, body, order)?)),
+                    xinput::CHANGE_FEEDBACK_CONTROL_REQUEST => Ok(Request::XinputChangeFeedbackControl(xinput::ChangeFeedbackControlRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_DEVICE_KEY_MAPPING_REQUEST => Ok(Request::XinputGetDeviceKeyMapping(xinput::GetDeviceKeyMappingRequest::try_parse_request(header, body, order)?)),
+                    xinput::CHANGE_DEVICE_KEY_MAPPING_REQUEST => Ok(Request::XinputChangeDeviceKeyMapping(xinput::ChangeDeviceKeyMappingRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_DEVICE_MODIFIER_MAPPING_REQUEST => Ok(Request::XinputGetDeviceModifierMapping(xinput::GetDeviceModifierMappingRequest::try_parse_request(header, body, order)?)),
+                    xinput::SET_DEVICE_MODIFIER_MAPPING_REQUEST => Ok(Request::XinputSetDeviceModifierMapping(xinput::SetDeviceModifierMappingRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_DEVICE_BUTTON_MAPPING_REQUEST => Ok(Request::XinputGetDeviceButtonMapping(xinput::GetDeviceButtonMappingRequest::try_parse_request(header, body, order)?)),
+                    xinput::SET_DEVICE_BUTTON_MAPPING_REQUEST => Ok(Request::XinputSetDeviceButtonMapping(xinput::SetDeviceButtonMappingRequest::try_parse_request(header, body, order)?)),
+                    xinput::QUERY_DEVICE_STATE_REQUEST => Ok(Request::XinputQueryDeviceState(xinput::QueryDeviceStateRequest::try_parse_request(header, body, order)?)),
+                    xinput::SEND_EXTENSION_EVENT_REQUEST => Ok(Request::XinputSendExtensionEvent(xinput::SendExtensionEventRequest::try_parse_request(header, body, order)?)),
+                    xinput::DEVICE_BELL_REQUEST => Ok(Request::XinputDeviceBell(xinput::DeviceBellRequest::try_parse_request(header, body, order)?)),
+                    xinput::SET_DEVICE_VALUATORS_REQUEST => Ok(Request::XinputSetDeviceValuators(xinput::SetDeviceValuatorsRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_DEVICE_CONTROL_REQUEST => Ok(Request::XinputGetDeviceControl(xinput::GetDeviceControlRequest::try_parse_request(header, body, order)?)),
+                    xinput::CHANGE_DEVICE_CONTROL_REQUEST => Ok(Request::XinputChangeDeviceControl(xinput::ChangeDeviceControlRequest::try_parse_request(header, body, order)?)),
+                    xinput::LIST_DEVICE_PROPERTIES_REQUEST => Ok(Request::XinputListDeviceProperties(xinput::ListDevicePropertiesRequest::try_parse_request(header, body, order)?)),
+                    xinput::CHANGE_DEVICE_PROPERTY_REQUEST => Ok(Request::XinputChangeDeviceProperty(xinput::ChangeDevicePropertyRequest::try_parse_request(header, body, order)?)),
+                    xinput::DELETE_DEVICE_PROPERTY_REQUEST => Ok(Request::XinputDeleteDeviceProperty(xinput::DeleteDevicePropertyRequest::try_parse_request(header, body, order)?)),
+                    xinput::GET_DEVICE_PROPERTY_REQUEST => Ok(Request::XinputGetDeviceProperty(xinput::GetDevicePropertyRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_QUERY_POINTER_REQUEST => Ok(Request::XinputXIQueryPointer(xinput::XIQueryPointerRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_WARP_POINTER_REQUEST => Ok(Request::XinputXIWarpPointer(xinput::XIWarpPointerRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_CHANGE_CURSOR_REQUEST => Ok(Request::XinputXIChangeCursor(xinput::XIChangeCursorRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_CHANGE_HIERARCHY_REQUEST => Ok(Request::XinputXIChangeHierarchy(xinput::XIChangeHierarchyRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_SET_CLIENT_POINTER_REQUEST => Ok(Request::XinputXISetClientPointer(xinput::XISetClientPointerRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_GET_CLIENT_POINTER_REQUEST => Ok(Request::XinputXIGetClientPointer(xinput::XIGetClientPointerRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_SELECT_EVENTS_REQUEST => Ok(Request::XinputXISelectEvents(xinput::XISelectEventsRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_QUERY_VERSION_REQUEST => Ok(Request::XinputXIQueryVersion(xinput::XIQueryVersionRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_QUERY_DEVICE_REQUEST => Ok(Request::XinputXIQueryDevice(xinput::XIQueryDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_SET_FOCUS_REQUEST => Ok(Request::XinputXISetFocus(xinput::XISetFocusRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_GET_FOCUS_REQUEST => Ok(Request::XinputXIGetFocus(xinput::XIGetFocusRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_GRAB_DEVICE_REQUEST => Ok(Request::XinputXIGrabDevice(xinput::XIGrabDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_UNGRAB_DEVICE_REQUEST => Ok(Request::XinputXIUngrabDevice(xinput::XIUngrabDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_ALLOW_EVENTS_REQUEST => Ok(Request::XinputXIAllowEvents(xinput::XIAllowEventsRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_PASSIVE_GRAB_DEVICE_REQUEST => Ok(Request::XinputXIPassiveGrabDevice(xinput::XIPassiveGrabDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_PASSIVE_UNGRAB_DEVICE_REQUEST => Ok(Request::XinputXIPassiveUngrabDevice(xinput::XIPassiveUngrabDeviceRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_LIST_PROPERTIES_REQUEST => Ok(Request::XinputXIListProperties(xinput::XIListPropertiesRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_CHANGE_PROPERTY_REQUEST => Ok(Request::XinputXIChangeProperty(xinput::XIChangePropertyRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_DELETE_PROPERTY_REQUEST => Ok(Request::XinputXIDeleteProperty(xinput::XIDeletePropertyRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_GET_PROPERTY_REQUEST => Ok(Request::XinputXIGetProperty(xinput::XIGetPropertyRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_GET_SELECTED_EVENTS_REQUEST => Ok(Request::XinputXIGetSelectedEvents(xinput::XIGetSelectedEventsRequest::try_parse_request(header, body, order)?)),
+                    xinput::XI_BARRIER_RELEASE_POINTER_REQUEST => Ok(Request::XinputXIBarrierReleasePointer(xinput::XIBarrierReleasePointerRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xkb")]
+            Some((xkb::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xkb::USE_EXTENSION_REQUEST => Ok(Request::XkbUseExtension(xkb::UseExtensionRequest::try_parse_request(header, body, order)?)),
+                    xkb::SELECT_EVENTS_REQUEST => Ok(Request::XkbSelectEvents(xkb::SelectEventsRequest::try_parse_request(header, body, order)?)),
+                    xkb::BELL_REQUEST => Ok(Request::XkbBell(xkb::BellRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_STATE_REQUEST => Ok(Request::XkbGetState(xkb::GetStateRequest::try_parse_request(header, body, order)?)),
+                    xkb::LATCH_LOCK_STATE_REQUEST => Ok(Request::XkbLatchLockState(xkb::LatchLockStateRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_CONTROLS_REQUEST => Ok(Request::XkbGetControls(xkb::GetControlsRequest::try_parse_request(header, body, order)?)),
+                    xkb::SET_CONTROLS_REQUEST => Ok(Request::XkbSetControls(xkb::SetControlsRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_MAP_REQUEST => Ok(Request::XkbGetMap(xkb::GetMapRequest::try_parse_request(header, body, order)?)),
+                    xkb::SET_MAP_REQUEST => Ok(Request::XkbSetMap(xkb::SetMapRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_COMPAT_MAP_REQUEST => Ok(Request::XkbGetCompatMap(xkb::GetCompatMapRequest::try_parse_request(header, body, order)?)),
+                    xkb::SET_COMPAT_MAP_REQUEST => Ok(Request::XkbSetCompatMap(xkb::SetCompatMapRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_INDICATOR_STATE_REQUEST => Ok(Request::XkbGetIndicatorState(xkb::GetIndicatorStateRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_INDICATOR_MAP_REQUEST => Ok(Request::XkbGetIndicatorMap(xkb::GetIndicatorMapRequest::try_parse_request(header, body, order)?)),
+                    xkb::SET_INDICATOR_MAP_REQUEST => Ok(Request::XkbSetIndicatorMap(xkb::SetIndicatorMapRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_NAMED_INDICATOR_REQUEST => Ok(Request::XkbGetNamedIndicator(xkb::GetNamedIndicatorRequest::try_parse_request(header, body, order)?)),
+                    xkb::SET_NAMED_INDICATOR_REQUEST => Ok(Request::XkbSetNamedIndicator(xkb::SetNamedIndicatorRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_NAMES_REQUEST => Ok(Request::XkbGetNames(xkb::GetNamesRequest::try_parse_request(header, body, order)?)),
+                    xkb::SET_NAMES_REQUEST => Ok(Request::XkbSetNames(xkb::SetNamesRequest::try_parse_request(header, body, order)?)),
+                    xkb::PER_CLIENT_FLAGS_REQUEST => Ok(Request::XkbPerClientFlags(xkb::PerClientFlagsRequest::try_parse_request(header, body, order)?)),
+                    xkb::LIST_COMPONENTS_REQUEST => Ok(Request::XkbListComponents(xkb::ListComponentsRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_KBD_BY_NAME_REQUEST => Ok(Request::XkbGetKbdByName(xkb::GetKbdByNameRequest::try_parse_request(header, body, order)?)),
+                    xkb::GET_DEVICE_INFO_REQUEST => Ok(Request::XkbGetDeviceInfo(xkb::GetDeviceInfoRequest::try_parse_request(header, body, order)?)),
+                    xkb::SET_DEVICE_INFO_REQUEST => Ok(Request::XkbSetDeviceInfo(xkb::SetDeviceInfoRequest::try_parse_request(header, body, order)?)),
+                    xkb::SET_DEBUGGING_FLAGS_REQUEST => Ok(Request::XkbSetDebuggingFlags(xkb::SetDebuggingFlagsRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xprint")]
+            Some((xprint::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xprint::PRINT_QUERY_VERSION_REQUEST => Ok(Request::XprintPrintQueryVersion(xprint::PrintQueryVersionRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_GET_PRINTER_LIST_REQUEST => Ok(Request::XprintPrintGetPrinterList(xprint::PrintGetPrinterListRequest::try_parse_request(header, body, order)?)),
+                    xprint::CREATE_CONTEXT_REQUEST => Ok(Request::XprintCreateContext(xprint::CreateContextRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_SET_CONTEXT_REQUEST => Ok(Request::XprintPrintSetContext(xprint::PrintSetContextRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_GET_CONTEXT_REQUEST => Ok(Request::XprintPrintGetContext(xprint::PrintGetContextRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_DESTROY_CONTEXT_REQUEST => Ok(Request::XprintPrintDestroyContext(xprint::PrintDestroyContextRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_GET_SCREEN_OF_CONTEXT_REQUEST => Ok(Request::XprintPrintGetScreenOfContext(xprint::PrintGetScreenOfContextRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_START_JOB_REQUEST => Ok(Request::XprintPrintStartJob(xprint::PrintStartJobRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_END_JOB_REQUEST => Ok(Request::XprintPrintEndJob(xprint::PrintEndJobRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_START_DOC_REQUEST => Ok(Request::XprintPrintStartDoc(xprint::PrintStartDocRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_END_DOC_REQUEST => Ok(Request::XprintPrintEndDoc(xprint::PrintEndDocRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_PUT_DOCUMENT_DATA_REQUEST => Ok(Request::XprintPrintPutDocumentData(xprint::PrintPutDocumentDataRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_GET_DOCUMENT_DATA_REQUEST => Ok(Request::XprintPrintGetDocumentData(xprint::PrintGetDocumentDataRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_START_PAGE_REQUEST => Ok(Request::XprintPrintStartPage(xprint::PrintStartPageRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_END_PAGE_REQUEST => Ok(Request::XprintPrintEndPage(xprint::PrintEndPageRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_SELECT_INPUT_REQUEST => Ok(Request::XprintPrintSelectInput(xprint::PrintSelectInputRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_INPUT_SELECTED_REQUEST => Ok(Request::XprintPrintInputSelected(xprint::PrintInputSelectedRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_GET_ATTRIBUTES_REQUEST => Ok(Request::XprintPrintGetAttributes(xprint::PrintGetAttributesRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_SET_ATTRIBUTES_REQUEST => Ok(Request::XprintPrintSetAttributes(xprint::PrintSetAttributesRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_GET_ONE_ATTRIBUTES_REQUEST => Ok(Request::XprintPrintGetOneAttributes(xprint::PrintGetOneAttributesRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_REHASH_PRINTER_LIST_REQUEST => Ok(Request::XprintPrintRehashPrinterList(xprint::PrintRehashPrinterListRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_GET_PAGE_DIMENSIONS_REQUEST => Ok(Request::XprintPrintGetPageDimensions(xprint::PrintGetPageDimensionsRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_QUERY_SCREENS_REQUEST => Ok(Request::XprintPrintQueryScreens(xprint::PrintQueryScreensRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_SET_IMAGE_RESOLUTION_REQUEST => Ok(Request::XprintPrintSetImageResolution(xprint::PrintSetImageResolutionRequest::try_parse_request(header, body, order)?)),
+                    xprint::PRINT_GET_IMAGE_RESOLUTION_REQUEST => Ok(Request::XprintPrintGetImageResolution(xprint::PrintGetImageResolutionRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xselinux")]
+            Some((xselinux::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xselinux::QUERY_VERSION_REQUEST => Ok(Request::XselinuxQueryVersion(xselinux::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    xselinux::SET_DEVICE_CREATE_CONTEXT_REQUEST => Ok(Request::XselinuxSetDeviceCreateContext(xselinux::SetDeviceCreateContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_DEVICE_CREATE_CONTEXT_REQUEST => Ok(Request::XselinuxGetDeviceCreateContext(xselinux::GetDeviceCreateContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::SET_DEVICE_CONTEXT_REQUEST => Ok(Request::XselinuxSetDeviceContext(xselinux::SetDeviceContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_DEVICE_CONTEXT_REQUEST => Ok(Request::XselinuxGetDeviceContext(xselinux::GetDeviceContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::SET_WINDOW_CREATE_CONTEXT_REQUEST => Ok(Request::XselinuxSetWindowCreateContext(xselinux::SetWindowCreateContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_WINDOW_CREATE_CONTEXT_REQUEST => Ok(Request::XselinuxGetWindowCreateContext(xselinux::GetWindowCreateContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_WINDOW_CONTEXT_REQUEST => Ok(Request::XselinuxGetWindowContext(xselinux::GetWindowContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::SET_PROPERTY_CREATE_CONTEXT_REQUEST => Ok(Request::XselinuxSetPropertyCreateContext(xselinux::SetPropertyCreateContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_PROPERTY_CREATE_CONTEXT_REQUEST => Ok(Request::XselinuxGetPropertyCreateContext(xselinux::GetPropertyCreateContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::SET_PROPERTY_USE_CONTEXT_REQUEST => Ok(Request::XselinuxSetPropertyUseContext(xselinux::SetPropertyUseContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_PROPERTY_USE_CONTEXT_REQUEST => Ok(Request::XselinuxGetPropertyUseContext(xselinux::GetPropertyUseContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_PROPERTY_CONTEXT_REQUEST => Ok(Request::XselinuxGetPropertyContext(xselinux::GetPropertyContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_PROPERTY_DATA_CONTEXT_REQUEST => Ok(Request::XselinuxGetPropertyDataContext(xselinux::GetPropertyDataContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::LIST_PROPERTIES_REQUEST => Ok(Request::XselinuxListProperties(xselinux::ListPropertiesRequest::try_parse_request(header, body, order)?)),
+                    xselinux::SET_SELECTION_CREATE_CONTEXT_REQUEST => Ok(Request::XselinuxSetSelectionCreateContext(xselinux::SetSelectionCreateContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_SELECTION_CREATE_CONTEXT_REQUEST => Ok(Request::XselinuxGetSelectionCreateContext(xselinux::GetSelectionCreateContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::SET_SELECTION_USE_CONTEXT_REQUEST => Ok(Request::XselinuxSetSelectionUseContext(xselinux::SetSelectionUseContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_SELECTION_USE_CONTEXT_REQUEST => Ok(Request::XselinuxGetSelectionUseContext(xselinux::GetSelectionUseContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_SELECTION_CONTEXT_REQUEST => Ok(Request::XselinuxGetSelectionContext(xselinux::GetSelectionContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_SELECTION_DATA_CONTEXT_REQUEST => Ok(Request::XselinuxGetSelectionDataContext(xselinux::GetSelectionDataContextRequest::try_parse_request(header, body, order)?)),
+                    xselinux::LIST_SELECTIONS_REQUEST => Ok(Request::XselinuxListSelections(xselinux::ListSelectionsRequest::try_parse_request(header, body, order)?)),
+                    xselinux::GET_CLIENT_CONTEXT_REQUEST => Ok(Request::XselinuxGetClientContext(xselinux::GetClientContextRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xtest")]
+            Some((xtest::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xtest::GET_VERSION_REQUEST => Ok(Request::XtestGetVersion(xtest::GetVersionRequest::try_parse_request(header, body, order)?)),
+                    xtest::COMPARE_CURSOR_REQUEST => Ok(Request::XtestCompareCursor(xtest::CompareCursorRequest::try_parse_request(header, body, order)?)),
+                    xtest::FAKE_INPUT_REQUEST => Ok(Request::XtestFakeInput(xtest::FakeInputRequest::try_parse_request(header, body, order)?)),
+                    xtest::GRAB_CONTROL_REQUEST => Ok(Request::XtestGrabControl(xtest::GrabControlRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xv")]
+            Some((xv::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xv::QUERY_EXTENSION_REQUEST => Ok(Request::XvQueryExtension(xv::QueryExtensionRequest::try_parse_request(header, body, order)?)),
+                    xv::QUERY_ADAPTORS_REQUEST => Ok(Request::XvQueryAdaptors(xv::QueryAdaptorsRequest::try_parse_request(header, body, order)?)),
+                    xv::QUERY_ENCODINGS_REQUEST => Ok(Request::XvQueryEncodings(xv::QueryEncodingsRequest::try_parse_request(header, body, order)?)),
+                    xv::GRAB_PORT_REQUEST => Ok(Request::XvGrabPort(xv::GrabPortRequest::try_parse_request(header, body, order)?)),
+                    xv::UNGRAB_PORT_REQUEST => Ok(Request::XvUngrabPort(xv::UngrabPortRequest::try_parse_request(header, body, order)?)),
+                    xv::PUT_VIDEO_REQUEST => Ok(Request::XvPutVideo(xv::PutVideoRequest::try_parse_request(header, body, order)?)),
+                    xv::PUT_STILL_REQUEST => Ok(Request::XvPutStill(xv::PutStillRequest::try_parse_request(header, body, order)?)),
+                    xv::GET_VIDEO_REQUEST => Ok(Request::XvGetVideo(xv::GetVideoRequest::try_parse_request(header, body, order)?)),
+                    xv::GET_STILL_REQUEST => Ok(Request::XvGetStill(xv::GetStillRequest::try_parse_request(header, body, order)?)),
+                    xv::STOP_VIDEO_REQUEST => Ok(Request::XvStopVideo(xv::StopVideoRequest::try_parse_request(header, body, order)?)),
+                    xv::SELECT_VIDEO_NOTIFY_REQUEST => Ok(Request::XvSelectVideoNotify(xv::SelectVideoNotifyRequest::try_parse_request(header, body, order)?)),
+                    xv::SELECT_PORT_NOTIFY_REQUEST => Ok(Request::XvSelectPortNotify(xv::SelectPortNotifyRequest::try_parse_request(header, body, order)?)),
+                    xv::QUERY_BEST_SIZE_REQUEST => Ok(Request::XvQueryBestSize(xv::QueryBestSizeRequest::try_parse_request(header, body, order)?)),
+                    xv::SET_PORT_ATTRIBUTE_REQUEST => Ok(Request::XvSetPortAttribute(xv::SetPortAttributeRequest::try_parse_request(header, body, order)?)),
+                    xv::GET_PORT_ATTRIBUTE_REQUEST => Ok(Request::XvGetPortAttribute(xv::GetPortAttributeRequest::try_parse_request(header, body, order)?)),
+                    xv::QUERY_PORT_ATTRIBUTES_REQUEST => Ok(Request::XvQueryPortAttributes(xv::QueryPortAttributesRequest::try_parse_request(header, body, order)?)),
+                    xv::LIST_IMAGE_FORMATS_REQUEST => Ok(Request::XvListImageFormats(xv::ListImageFormatsRequest::try_parse_request(header, body, order)?)),
+                    xv::QUERY_IMAGE_ATTRIBUTES_REQUEST => Ok(Request::XvQueryImageAttributes(xv::QueryImageAttributesRequest::try_parse_request(header, body, order)?)),
+                    xv::PUT_IMAGE_REQUEST => Ok(Request::XvPutImage(xv::PutImageRequest::try_parse_request(header, body, order)?)),
+                    xv::SHM_PUT_IMAGE_REQUEST => Ok(Request::XvShmPutImage(xv::ShmPutImageRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            #[cfg(feature = "xvmc")]
+            Some((xvmc::X11_EXTENSION_NAME, _)) => {
+                match header.minor_opcode {
+                    xvmc::QUERY_VERSION_REQUEST => Ok(Request::XvmcQueryVersion(xvmc::QueryVersionRequest::try_parse_request(header, body, order)?)),
+                    xvmc::LIST_SURFACE_TYPES_REQUEST => Ok(Request::XvmcListSurfaceTypes(xvmc::ListSurfaceTypesRequest::try_parse_request(header, body, order)?)),
+                    xvmc::CREATE_CONTEXT_REQUEST => Ok(Request::XvmcCreateContext(xvmc::CreateContextRequest::try_parse_request(header, body, order)?)),
+                    xvmc::DESTROY_CONTEXT_REQUEST => Ok(Request::XvmcDestroyContext(xvmc::DestroyContextRequest::try_parse_request(header, body, order)?)),
+                    xvmc::CREATE_SURFACE_REQUEST => Ok(Request::XvmcCreateSurface(xvmc::CreateSurfaceRequest::try_parse_request(header, body, order)?)),
+                    xvmc::DESTROY_SURFACE_REQUEST => Ok(Request::XvmcDestroySurface(xvmc::DestroySurfaceRequest::try_parse_request(header, body, order)?)),
+                    xvmc::CREATE_SUBPICTURE_REQUEST => Ok(Request::XvmcCreateSubpicture(xvmc::CreateSubpictureRequest::try_parse_request(header, body, order)?)),
+                    xvmc::DESTROY_SUBPICTURE_REQUEST => Ok(Request::XvmcDestroySubpicture(xvmc::DestroySubpictureRequest::try_parse_request(header, body, order)?)),
+                    xvmc::LIST_SUBPICTURE_TYPES_REQUEST => Ok(Request::XvmcListSubpictureTypes(xvmc::ListSubpictureTypesRequest::try_parse_request(header, body, order)?)),
+                    _ => Ok(Request::Unknown(header, body.to_vec())),
+                }
+            }
+            _ => Ok(Request::Unknown(header, body.to_vec())),
+        }
+    }
+
+    /// Check whether the X11 server sends a reply to this request.
+    ///
+    /// Requests that are not known are assumed to have a reply.
+    pub fn has_reply(&self) -> bool {
+        match self {
+            Request::Unknown(_, _) => true,
+            Request::GetWindowAttributes(_) => true,
+            Request::GetGeometry(_) => true,
+            Request::QueryTree(_) => true,
+            Request::InternAtom(_) => true,
+            Request::GetAtomName(_) => true,
+            Request::GetProperty(_) => true,
+            Request::ListProperties(_) => true,
+            Request::GetSelectionOwner(_) => true,
+            Request::GrabPointer(_) => true,
+            Request::GrabKeyboard(_) => true,
+            Request::QueryPointer(_) => true,
+            Request::GetMotionEvents(_) => true,
+            Request::TranslateCoordinates(_) => true,
+            Request::GetInputFocus(_) => true,
+            Request::QueryKeymap(_) => true,
+            Request::QueryFont(_) => true,
+            Request::QueryTextExtents(_) => true,
+            Request::ListFonts(_) => true,
+            Request::ListFontsWithInfo(_) => true,
+            Request::GetFontPath(_) => true,
+            Request::GetImage(_) => true,
+            Request::ListInstalledColormaps(_) => true,
+            Request::AllocColor(_) => true,
+            Request::AllocNamedColor(_) => true,
+            Request::AllocColorCells(_) => true,
+            Request::AllocColorPlanes(_) => true,
+            Request::QueryColors(_) => true,
+            Request::LookupColor(_) => true,
+            Request::QueryBestSize(_) => true,
+            Request::QueryExtension(_) => true,
+            Request::ListExtensions(_) => true,
+            Request::GetKeyboardMapping(_) => true,
+            Request::GetKeyboardControl(_) => true,
+            Request::GetPointerControl(_) => true,
+            Request::GetScreenSaver(_) => true,
+            Request::ListHosts(_) => true,
+            Request::SetPointerMapping(_) => true,
+            Request::GetPointerMapping(_) => true,
+            Request::SetModifierMapping(_) => true,
+            Request::GetModifierMapping(_) => true,
+            Request::BigreqEnable(_) => true,
+            #[cfg(feature = "composite")]
+            Request::CompositeQueryVersion(_) => true,
+            #[cfg(feature = "composite")]
+            Request::CompositeGetOverlayWindow(_) => true,
+            #[cfg(feature = "damage")]
+            Request::DamageQueryVersion(_) => true,
+            #[cfg(feature = "dpms")]
+            Request::DpmsGetVersion(_) => true,
+            #[cfg(feature = "dpms")]
+            Request::DpmsCapable(_) => true,
+            #[cfg(feature = "dpms")]
+            Request::DpmsGetTimeouts(_) => true,
+            #[cfg(feature = "dpms")]
+            Request::DpmsInfo(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2QueryVersion(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2Connect(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2Authenticate(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2GetBuffers(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2CopyRegion(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2GetBuffersWithFormat(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2SwapBuffers(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2GetMSC(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2WaitMSC(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2WaitSBC(_) => true,
+            #[cfg(feature = "dri2")]
+            Request::Dri2GetParam(_) => true,
+            #[cfg(feature = "dri3")]
+            Request::Dri3QueryVersion(_) => true,
+            #[cfg(feature = "dri3")]
+            Request::Dri3Open(_) => true,
+            #[cfg(feature = "dri3")]
+            Request::Dri3BufferFromPixmap(_) => true,
+            #[cfg(feature = "dri3")]
+            Request::Dri3FDFromFence(_) => true,
+            #[cfg(feature = "dri3")]
+            Request::Dri3GetSupportedModifiers(_) => true,
+            #[cfg(feature = "dri3")]
+            Request::Dri3BuffersFromPixmap(_) => true,
+            Request::GeQueryVersion(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxMakeCurrent(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxIsDirect(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxQueryVersion(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetVisualConfigs(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxVendorPrivateWithReply(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxQueryExtensionsString(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxQueryServerString(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetFBConfigs(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxQueryContext(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxMakeContextCurrent(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetDrawableAttributes(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGenLists(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxRenderMode(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxFinish(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxReadPixels(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetBooleanv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetClipPlane(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetDoublev(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetError(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetFloatv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetIntegerv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetLightfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetLightiv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetMapdv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetMapfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetMapiv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetMaterialfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetMaterialiv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetPixelMapfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetPixelMapuiv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetPixelMapusv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetPolygonStipple(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetString(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexEnvfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexEnviv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexGendv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexGenfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexGeniv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexImage(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexParameterfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexParameteriv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexLevelParameterfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexLevelParameteriv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxIsEnabled(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxIsList(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxAreTexturesResident(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGenTextures(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxIsTexture(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetColorTable(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetColorTableParameterfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetColorTableParameteriv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetConvolutionFilter(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetConvolutionParameterfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetConvolutionParameteriv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetSeparableFilter(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetHistogram(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetHistogramParameterfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetHistogramParameteriv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetMinmax(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetMinmaxParameterfv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetMinmaxParameteriv(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetCompressedTexImageARB(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGenQueriesARB(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxIsQueryARB(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetQueryivARB(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetQueryObjectivARB(_) => true,
+            #[cfg(feature = "glx")]
+            Request::GlxGetQueryObjectuivARB(_) => true,
+            #[cfg(feature = "present")]
+            Request::PresentQueryVersion(_) => true,
+            #[cfg(feature = "present")]
+            Request::PresentQueryCapabilities(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrQueryVersion(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrSetScreenConfig(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetScreenInfo(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetScreenSizeRange(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetScreenResources(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetOutputInfo(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrListOutputProperties(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrQueryOutputProperty(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetOutputProperty(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrCreateMode(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetCrtcInfo(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrSetCrtcConfig(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetCrtcGammaSize(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetCrtcGamma(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetScreenResourcesCurrent(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetCrtcTransform(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetPanning(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrSetPanning(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetOutputPrimary(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetProviders(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetProviderInfo(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrListProviderProperties(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrQueryProviderProperty(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetProviderProperty(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrGetMonitors(_) => true,
+            #[cfg(feature = "randr")]
+            Request::RandrCreateLease(_) => true,
+            #[cfg(feature = "record")]
+            Request::RecordQueryVersion(_) => true,
+            #[cfg(feature = "record")]
+            Request::RecordGetContext(_) => true,
+            #[cfg(feature = "record")]
+            Request::RecordEnableContext(_) => true,
+            #[cfg(feature = "render")]
+            Request::RenderQueryVersion(_) => true,
+            #[cfg(feature = "render")]
+            Request::RenderQueryPictFormats(_) => true,
+            #[cfg(feature = "render")]
+            Request::RenderQueryPictIndexValues(_) => true,
+            #[cfg(feature = "render")]
+            Request::RenderQueryFilters(_) => true,
+            #[cfg(feature = "res")]
+            Request::ResQueryVersion(_) => true,
+            #[cfg(feature = "res")]
+            Request::ResQueryClients(_) => true,
+            #[cfg(feature = "res")]
+            Request::ResQueryClientResources(_) => true,
+            #[cfg(feature = "res")]
+            Request::ResQueryClientPixmapBytes(_) => true,
+            #[cfg(feature = "res")]
+            Request::ResQueryClientIds(_) => true,
+            #[cfg(feature = "res")]
+            Request::ResQueryResourceBytes(_) => true,
+            #[cfg(feature = "screensaver")]
+            Request::ScreensaverQueryVersion(_) => true,
+            #[cfg(feature = "screensaver")]
+            Request::ScreensaverQueryInfo(_) => true,
+            #[cfg(feature = "shape")]
+            Request::ShapeQueryVersion(_) => true,
+            #[cfg(feature = "shape")]
+            Request::ShapeQueryExtents(_) => true,
+            #[cfg(feature = "shape")]
+            Request::ShapeInputSelected(_) => true,
+            #[cfg(feature = "shape")]
+            Request::ShapeGetRectangles(_) => true,
+            #[cfg(feature = "shm")]
+            Request::ShmQueryVersion(_) => true,
+            #[cfg(feature = "shm")]
+            Request::ShmGetImage(_) => true,
+            #[cfg(feature = "shm")]
+            Request::ShmCreateSegment(_) => true,
+            #[cfg(feature = "sync")]
+            Request::SyncInitialize(_) => true,
+            #[cfg(feature = "sync")]
+            Request::SyncListSystemCounters(_) => true,
+            #[cfg(feature = "sync")]
+            Request::SyncQueryCounter(_) => true,
+            #[cfg(feature = "sync")]
+            Request::SyncQueryAlarm(_) => true,
+            #[cfg(feature = "sync")]
+            Request::SyncGetPriority(_) => true,
+            #[cfg(feature = "sync")]
+            Request::SyncQueryFence(_) => true,
+            Request::XcMiscGetVersion(_) => true,
+            Request::XcMiscGetXIDRange(_) => true,
+            Request::XcMiscGetXIDList(_) => true,
+            #[cfg(feature = "xevie")]
+            Request::XevieQueryVersion(_) => true,
+            #[cfg(feature = "xevie")]
+            Request::XevieStart(_) => true,
+            #[cfg(feature = "xevie")]
+            Request::XevieEnd(_) => true,
+            #[cfg(feature = "xevie")]
+            Request::XevieSend(_) => true,
+            #[cfg(feature = "xevie")]
+            Request::XevieSelectInput(_) => true,
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driQueryVersion(_) => true,
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driQueryDirectRenderingCapable(_) => true,
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driOpenConnection(_) => true,
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driGetClientDriverName(_) => true,
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driCreateContext(_) => true,
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driCreateDrawable(_) => true,
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driGetDrawableInfo(_) => true,
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driGetDeviceInfo(_) => true,
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driAuthConnection(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeQueryVersion(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetModeLine(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetMonitor(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetAllModeLines(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeValidateModeLine(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetViewPort(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetDotClocks(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetGamma(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetGammaRamp(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetGammaRampSize(_) => true,
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetPermissions(_) => true,
+            #[cfg(feature = "xfixes")]
+            Request::XfixesQueryVersion(_) => true,
+            #[cfg(feature = "xfixes")]
+            Request::XfixesGetCursorImage(_) => true,
+            #[cfg(feature = "xfixes")]
+            Request::XfixesFetchRegion(_) => true,
+            #[cfg(feature = "xfixes")]
+            Request::XfixesGetCursorName(_) => true,
+            #[cfg(feature = "xfixes")]
+            Request::XfixesGetCursorImageAndName(_) => true,
+            #[cfg(feature = "xinerama")]
+            Request::XineramaQueryVersion(_) => true,
+            #[cfg(feature = "xinerama")]
+            Request::XineramaGetState(_) => true,
+            #[cfg(feature = "xinerama")]
+            Request::XineramaGetScreenCount(_) => true,
+            #[cfg(feature = "xinerama")]
+            Request::XineramaGetScreenSize(_) => true,
+            #[cfg(feature = "xinerama")]
+            Request::XineramaIsActive(_) => true,
+            #[cfg(feature = "xinerama")]
+            Request::XineramaQueryScreens(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetExtensionVersion(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputListInputDevices(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputOpenDevice(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputSetDeviceMode(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetSelectedExtensionEvents(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceDontPropagateList(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceMotionEvents(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputChangeKeyboardDevice(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputChangePointerDevice(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGrabDevice(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceFocus(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetFeedbackControl(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceKeyMapping(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceModifierMapping(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputSetDeviceModifierMapping(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceButtonMapping(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputSetDeviceButtonMapping(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputQueryDeviceState(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputSetDeviceValuators(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceControl(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputChangeDeviceControl(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputListDeviceProperties(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceProperty(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIQueryPointer(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGetClientPointer(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIQueryVersion(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIQueryDevice(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGetFocus(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGrabDevice(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIPassiveGrabDevice(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIListProperties(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGetProperty(_) => true,
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGetSelectedEvents(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbUseExtension(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetState(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetControls(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetMap(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetCompatMap(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetIndicatorState(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetIndicatorMap(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetNamedIndicator(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetNames(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbPerClientFlags(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbListComponents(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetKbdByName(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbGetDeviceInfo(_) => true,
+            #[cfg(feature = "xkb")]
+            Request::XkbSetDebuggingFlags(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintQueryVersion(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetPrinterList(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetContext(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetScreenOfContext(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetDocumentData(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintInputSelected(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetAttributes(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetOneAttributes(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetPageDimensions(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintQueryScreens(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintSetImageResolution(_) => true,
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetImageResolution(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxQueryVersion(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetDeviceCreateContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetDeviceContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetWindowCreateContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetWindowContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetPropertyCreateContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetPropertyUseContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetPropertyContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetPropertyDataContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxListProperties(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetSelectionCreateContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetSelectionUseContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetSelectionContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetSelectionDataContext(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxListSelections(_) => true,
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetClientContext(_) => true,
+            #[cfg(feature = "xtest")]
+            Request::XtestGetVersion(_) => true,
+            #[cfg(feature = "xtest")]
+            Request::XtestCompareCursor(_) => true,
+            #[cfg(feature = "xv")]
+            Request::XvQueryExtension(_) => true,
+            #[cfg(feature = "xv")]
+            Request::XvQueryAdaptors(_) => true,
+            #[cfg(feature = "xv")]
+            Request::XvQueryEncodings(_) => true,
+            #[cfg(feature = "xv")]
+            Request::XvGrabPort(_) => true,
+            #[cfg(feature = "xv")]
+            Request::XvQueryBestSize(_) => true,
+            #[cfg(feature = "xv")]
+            Request::XvGetPortAttribute(_) => true,
+            #[cfg(feature = "xv")]
+            Request::XvQueryPortAttributes(_) => true,
+            #[cfg(feature = "xv")]
+            Request::XvListImageFormats(_) => true,
+            #[cfg(feature = "xv")]
+            Request::XvQueryImageAttributes(_) => true,
+            #[cfg(feature = "xvmc")]
+            Request::XvmcQueryVersion(_) => true,
+            #[cfg(feature = "xvmc")]
+            Request::XvmcListSurfaceTypes(_) => true,
+            #[cfg(feature = "xvmc")]
+            Request::XvmcCreateContext(_) => true,
+            #[cfg(feature = "xvmc")]
+            Request::XvmcCreateSurface(_) => true,
+            #[cfg(feature = "xvmc")]
+            Request::XvmcCreateSubpicture(_) => true,
+            #[cfg(feature = "xvmc")]
+            Request::XvmcListSubpictureTypes(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Enumeration of all possible X11 replies.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum Reply {
+    Unknown(Vec<u8>),
+    GetWindowAttributes(xproto::GetWindowAttributesReply),
+    GetGeometry(xproto::GetGeometryReply),
+    QueryTree(xproto::QueryTreeReply),
+    InternAtom(xproto::InternAtomReply),
+    GetAtomName(xproto::GetAtomNameReply),
+    GetProperty(xproto::GetPropertyReply),
+    ListProperties(xproto::ListPropertiesReply),
+    GetSelectionOwner(xproto::GetSelectionOwnerReply),
+    GrabPointer(xproto::GrabPointerReply),
+    GrabKeyboard(xproto::GrabKeyboardReply),
+    QueryPointer(xproto::QueryPointerReply),
+    GetMotionEvents(xproto::GetMotionEventsReply),
+    TranslateCoordinates(xproto::TranslateCoordinatesReply),
+    GetInputFocus(xproto::GetInputFocusReply),
+    QueryKeymap(xproto::QueryKeymapReply),
+    QueryFont(xproto::QueryFontReply),
+    QueryTextExtents(xproto::QueryTextExtentsReply),
+    ListFonts(xproto::ListFontsReply),
+    ListFontsWithInfo(xproto::ListFontsWithInfoReply),
+    GetFontPath(xproto::GetFontPathReply),
+    GetImage(xproto::GetImageReply),
+    ListInstalledColormaps(xproto::ListInstalledColormapsReply),
+    AllocColor(xproto::AllocColorReply),
+    AllocNamedColor(xproto::AllocNamedColorReply),
+    AllocColorCells(xproto::AllocColorCellsReply),
+    AllocColorPlanes(xproto::AllocColorPlanesReply),
+    QueryColors(xproto::QueryColorsReply),
+    LookupColor(xproto::LookupColorReply),
+    QueryBestSize(xproto::QueryBestSizeReply),
+    QueryExtension(xproto::QueryExtensionReply),
+    ListExtensions(xproto::ListExtensionsReply),
+    GetKeyboardMapping(xproto::GetKeyboardMappingReply),
+    GetKeyboardControl(xproto::GetKeyboardControlReply),
+    GetPointerControl(xproto::GetPointerControlReply),
+    GetScreenSaver(xproto::GetScreenSaverReply),
+    ListHosts(xproto::ListHostsReply),
+    SetPointerMapping(xproto::SetPointerMappingReply),
+    GetPointerMapping(xproto::GetPointerMappingReply),
+    SetModifierMapping(xproto::SetModifierMappingReply),
+    GetModifierMapping(xproto::GetModifierMappingReply),
+    BigreqEnable(bigreq::EnableReply),
+    #[cfg(feature = "composite")]
+    CompositeQueryVersion(composite::QueryVersionReply),
+    #[cfg(feature = "composite")]
+    CompositeGetOverlayWindow(composite::GetOverlayWindowReply),
+    #[cfg(feature = "damage")]
+    DamageQueryVersion(damage::QueryVersionReply),
+    #[cfg(feature = "dpms")]
+    DpmsGetVersion(dpms::GetVersionReply),
+    #[cfg(feature = "dpms")]
+    DpmsCapable(dpms::CapableReply),
+    #[cfg(feature = "dpms")]
+    DpmsGetTimeouts(dpms::GetTimeoutsReply),
+    #[cfg(feature = "dpms")]
+    DpmsInfo(dpms::InfoReply),
+    #[cfg(feature = "dri2")]
+    Dri2QueryVersion(dri2::QueryVersionReply),
+    #[cfg(feature = "dri2")]
+    Dri2Connect(dri2::ConnectReply),
+    #[cfg(feature = "dri2")]
+    Dri2Authenticate(dri2::AuthenticateReply),
+    #[cfg(feature = "dri2")]
+    Dri2GetBuffers(dri2::GetBuffersReply),
+    #[cfg(feature = "dri2")]
+    Dri2CopyRegion(dri2::CopyRegionReply),
+    #[cfg(feature = "dri2")]
+    Dri2GetBuffersWithFormat(dri2::GetBuffersWithFormatReply),
+    #[cfg(feature = "dri2")]
+    Dri2SwapBuffers(dri2::SwapBuffersReply),
+    #[cfg(feature = "dri2")]
+    Dri2GetMSC(dri2::GetMSCReply),
+    #[cfg(feature = "dri2")]
+    Dri2WaitMSC(dri2::WaitMSCReply),
+    #[cfg(feature = "dri2")]
+    Dri2WaitSBC(dri2::WaitSBCReply),
+    #[cfg(feature = "dri2")]
+    Dri2GetParam(dri2::GetParamReply),
+    #[cfg(feature = "dri3")]
+    Dri3QueryVersion(dri3::QueryVersionReply),
+    #[cfg(feature = "dri3")]
+    Dri3Open(dri3::OpenReply),
+    #[cfg(feature = "dri3")]
+    Dri3BufferFromPixmap(dri3::BufferFromPixmapReply),
+    #[cfg(feature = "dri3")]
+    Dri3FDFromFence(dri3::FDFromFenceReply),
+    #[cfg(feature = "dri3")]
+    Dri3GetSupportedModifiers(dri3::GetSupportedModifiersReply),
+    #[cfg(feature = "dri3")]
+    Dri3BuffersFromPixmap(dri3::BuffersFromPixmapReply),
+    GeQueryVersion(ge::QueryVersionReply),
+    #[cfg(feature = "glx")]
+    GlxMakeCurrent(glx::MakeCurrentReply),
+    #[cfg(feature = "glx")]
+    GlxIsDirect(glx::IsDirectReply),
+    #[cfg(feature = "glx")]
+    GlxQueryVersion(glx::QueryVersionReply),
+    #[cfg(feature = "glx")]
+    GlxGetVisualConfigs(glx::GetVisualConfigsReply),
+    #[cfg(feature = "glx")]
+    GlxVendorPrivateWithReply(glx::VendorPrivateWithReplyReply),
+    #[cfg(feature = "glx")]
+    GlxQueryExtensionsString(glx::QueryExtensionsStringReply),
+    #[cfg(feature = "glx")]
+    GlxQueryServerString(glx::QueryServerStringReply),
+    #[cfg(feature = "glx")]
+    GlxGetFBConfigs(glx::GetFBConfigsReply),
+    #[cfg(feature = "glx")]
+    GlxQueryContext(glx::QueryContextReply),
+    #[cfg(feature = "glx")]
+    GlxMakeContextCurrent(glx::MakeContextCurrentReply),
+    #[cfg(feature = "glx")]
+    GlxGetDrawableAttributes(glx::GetDrawableAttributesReply),
+    #[cfg(feature = "glx")]
+    GlxGenLists(glx::GenListsReply),
+    #[cfg(feature = "glx")]
+    GlxRenderMode(glx::RenderModeReply),
+    #[cfg(feature = "glx")]
+    GlxFinish(glx::FinishReply),
+    #[cfg(feature = "glx")]
+    GlxReadPixels(glx::ReadPixelsReply),
+    #[cfg(feature = "glx")]
+    GlxGetBooleanv(glx::GetBooleanvReply),
+    #[cfg(feature = "glx")]
+    GlxGetClipPlane(glx::GetClipPlaneReply),
+    #[cfg(feature = "glx")]
+    GlxGetDoublev(glx::GetDoublevReply),
+    #[cfg(feature = "glx")]
+    GlxGetError(glx::GetErrorReply),
+    #[cfg(feature = "glx")]
+    GlxGetFloatv(glx::GetFloatvReply),
+    #[cfg(feature = "glx")]
+    GlxGetIntegerv(glx::GetIntegervReply),
+    #[cfg(feature = "glx")]
+    GlxGetLightfv(glx::GetLightfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetLightiv(glx::GetLightivReply),
+    #[cfg(feature = "glx")]
+    GlxGetMapdv(glx::GetMapdvReply),
+    #[cfg(feature = "glx")]
+    GlxGetMapfv(glx::GetMapfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetMapiv(glx::GetMapivReply),
+    #[cfg(feature = "glx")]
+    GlxGetMaterialfv(glx::GetMaterialfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetMaterialiv(glx::GetMaterialivReply),
+    #[cfg(feature = "glx")]
+    GlxGetPixelMapfv(glx::GetPixelMapfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetPixelMapuiv(glx::GetPixelMapuivReply),
+    #[cfg(feature = "glx")]
+    GlxGetPixelMapusv(glx::GetPixelMapusvReply),
+    #[cfg(feature = "glx")]
+    GlxGetPolygonStipple(glx::GetPolygonStippleReply),
+    #[cfg(feature = "glx")]
+    GlxGetString(glx::GetStringReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexEnvfv(glx::GetTexEnvfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexEnviv(glx::GetTexEnvivReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexGendv(glx::GetTexGendvReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexGenfv(glx::GetTexGenfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexGeniv(glx::GetTexGenivReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexImage(glx::GetTexImageReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexParameterfv(glx::GetTexParameterfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexParameteriv(glx::GetTexParameterivReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexLevelParameterfv(glx::GetTexLevelParameterfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetTexLevelParameteriv(glx::GetTexLevelParameterivReply),
+    #[cfg(feature = "glx")]
+    GlxIsEnabled(glx::IsEnabledReply),
+    #[cfg(feature = "glx")]
+    GlxIsList(glx::IsListReply),
+    #[cfg(feature = "glx")]
+    GlxAreTexturesResident(glx::AreTexturesResidentReply),
+    #[cfg(feature = "glx")]
+    GlxGenTextures(glx::GenTexturesReply),
+    #[cfg(feature = "glx")]
+    GlxIsTexture(glx::IsTextureReply),
+    #[cfg(feature = "glx")]
+    GlxGetColorTable(glx::GetColorTableReply),
+    #[cfg(feature = "glx")]
+    GlxGetColorTableParameterfv(glx::GetColorTableParameterfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetColorTableParameteriv(glx::GetColorTableParameterivReply),
+    #[cfg(feature = "glx")]
+    GlxGetConvolutionFilter(glx::GetConvolutionFilterReply),
+    #[cfg(feature = "glx")]
+    GlxGetConvolutionParameterfv(glx::GetConvolutionParameterfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetConvolutionParameteriv(glx::GetConvolutionParameterivReply),
+    #[cfg(feature = "glx")]
+    GlxGetSeparableFilter(glx::GetSeparableFilterReply),
+    #[cfg(feature = "glx")]
+    GlxGetHistogram(glx::GetHistogramReply),
+    #[cfg(feature = "glx")]
+    GlxGetHistogramParameterfv(glx::GetHistogramParameterfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetHistogramParameteriv(glx::GetHistogramParameterivReply),
+    #[cfg(feature = "glx")]
+    GlxGetMinmax(glx::GetMinmaxReply),
+    #[cfg(feature = "glx")]
+    GlxGetMinmaxParameterfv(glx::GetMinmaxParameterfvReply),
+    #[cfg(feature = "glx")]
+    GlxGetMinmaxParameteriv(glx::GetMinmaxParameterivReply),
+    #[cfg(feature = "glx")]
+    GlxGetCompressedTexImageARB(glx::GetCompressedTexImageARBReply),
+    #[cfg(feature = "glx")]
+    GlxGenQueriesARB(glx::GenQueriesARBReply),
+    #[cfg(feature = "glx")]
+    GlxIsQueryARB(glx::IsQueryARBReply),
+    #[cfg(feature = "glx")]
+    GlxGetQueryivARB(glx::GetQueryivARBReply),
+    #[cfg(feature = "glx")]
+    GlxGetQueryObjectivARB(glx::GetQueryObjectivARBReply),
+    #[cfg(feature = "glx")]
+    GlxGetQueryObjectuivARB(glx::GetQueryObjectuivARBReply),
+    #[cfg(feature = "present")]
+    PresentQueryVersion(present::QueryVersionReply),
+    #[cfg(feature = "present")]
+    PresentQueryCapabilities(present::QueryCapabilitiesReply),
+    #[cfg(feature = "randr")]
+    RandrQueryVersion(randr::QueryVersionReply),
+    #[cfg(feature = "randr")]
+    RandrSetScreenConfig(randr::SetScreenConfigReply),
+    #[cfg(feature = "randr")]
+    RandrGetScreenInfo(randr::GetScreenInfoReply),
+    #[cfg(feature = "randr")]
+    RandrGetScreenSizeRange(randr::GetScreenSizeRangeReply),
+    #[cfg(feature = "randr")]
+    RandrGetScreenResources(randr::GetScreenResourcesReply),
+    #[cfg(feature = "randr")]
+    RandrGetOutputInfo(randr::GetOutputInfoReply),
+    #[cfg(feature = "randr")]
+    RandrListOutputProperties(randr::ListOutputPropertiesReply),
+    #[cfg(feature = "randr")]
+    RandrQueryOutputProperty(randr::QueryOutputPropertyReply),
+    #[cfg(feature = "randr")]
+    RandrGetOutputProperty(randr::GetOutputPropertyReply),
+    #[cfg(feature = "randr")]
+    RandrCreateMode(randr::CreateModeReply),
+    #[cfg(feature = "randr")]
+    RandrGetCrtcInfo(randr::GetCrtcInfoReply),
+    #[cfg(feature = "randr")]
+    RandrSetCrtcConfig(randr::SetCrtcConfigReply),
+    #[cfg(feature = "randr")]
+    RandrGetCrtcGammaSize(randr::GetCrtcGammaSizeReply),
+    #[cfg(feature = "randr")]
+    RandrGetCrtcGamma(randr::GetCrtcGammaReply),
+    #[cfg(feature = "randr")]
+    RandrGetScreenResourcesCurrent(randr::GetScreenResourcesCurrentReply),
+    #[cfg(feature = "randr")]
+    RandrGetCrtcTransform(randr::GetCrtcTransformReply),
+    #[cfg(feature = "randr")]
+    RandrGetPanning(randr::GetPanningReply),
+    #[cfg(feature = "randr")]
+    RandrSetPanning(randr::SetPanningReply),
+    #[cfg(feature = "randr")]
+    RandrGetOutputPrimary(randr::GetOutputPrimaryReply),
+    #[cfg(feature = "randr")]
+    RandrGetProviders(randr::GetProvidersReply),
+    #[cfg(feature = "randr")]
+    RandrGetProviderInfo(randr::GetProviderInfoReply),
+    #[cfg(feature = "randr")]
+    RandrListProviderProperties(randr::ListProviderPropertiesReply),
+    #[cfg(feature = "randr")]
+    RandrQueryProviderProperty(randr::QueryProviderPropertyReply),
+    #[cfg(feature = "randr")]
+    RandrGetProviderProperty(randr::GetProviderPropertyReply),
+    #[cfg(feature = "randr")]
+    RandrGetMonitors(randr::GetMonitorsReply),
+    #[cfg(feature = "randr")]
+    RandrCreateLease(randr::CreateLeaseReply),
+    #[cfg(feature = "record")]
+    RecordQueryVersion(record::QueryVersionReply),
+    #[cfg(feature = "record")]
+    RecordGetContext(record::GetContextReply),
+    #[cfg(feature = "record")]
+    RecordEnableContext(record::EnableContextReply),
+    #[cfg(feature = "render")]
+    RenderQueryVersion(render::QueryVersionReply),
+    #[cfg(feature = "render")]
+    RenderQueryPictFormats(render::QueryPictFormatsReply),
+    #[cfg(feature = "render")]
+    RenderQueryPictIndexValues(render::QueryPictIndexValuesReply),
+    #[cfg(feature = "render")]
+    RenderQueryFilters(render::QueryFiltersReply),
+    #[cfg(feature = "res")]
+    ResQueryVersion(res::QueryVersionReply),
+    #[cfg(feature = "res")]
+    ResQueryClients(res::QueryClientsReply),
+    #[cfg(feature = "res")]
+    ResQueryClientResources(res::QueryClientResourcesReply),
+    #[cfg(feature = "res")]
+    ResQueryClientPixmapBytes(res::QueryClientPixmapBytesReply),
+    #[cfg(feature = "res")]
+    ResQueryClientIds(res::QueryClientIdsReply),
+    #[cfg(feature = "res")]
+    ResQueryResourceBytes(res::QueryResourceBytesReply),
+    #[cfg(feature = "screensaver")]
+    ScreensaverQueryVersion(screensaver::QueryVersionReply),
+    #[cfg(feature = "screensaver")]
+    ScreensaverQueryInfo(screensaver::QueryInfoReply),
+    #[cfg(feature = "shape")]
+    ShapeQueryVersion(shape::QueryVersionReply),
+    #[cfg(feature = "shape")]
+    ShapeQueryExtents(shape::QueryExtentsReply),
+    #[cfg(feature = "shape")]
+    ShapeInputSelected(shape::InputSelectedReply),
+    #[cfg(feature = "shape")]
+    ShapeGetRectangles(shape::GetRectanglesReply),
+    #[cfg(feature = "shm")]
+    ShmQueryVersion(shm::QueryVersionReply),
+    #[cfg(feature = "shm")]
+    ShmGetImage(shm::GetImageReply),
+    #[cfg(feature = "shm")]
+    ShmCreateSegment(shm::CreateSegmentReply),
+    #[cfg(feature = "sync")]
+    SyncInitialize(sync::InitializeReply),
+    #[cfg(feature = "sync")]
+    SyncListSystemCounters(sync::ListSystemCountersReply),
+    #[cfg(feature = "sync")]
+    SyncQueryCounter(sync::QueryCounterReply),
+    #[cfg(feature = "sync")]
+    SyncQueryAlarm(sync::QueryAlarmReply),
+    #[cfg(feature = "sync")]
+    SyncGetPriority(sync::GetPriorityReply),
+    #[cfg(feature = "sync")]
+    SyncQueryFence(sync::QueryFenceReply),
+    XcMiscGetVersion(xc_misc::GetVersionReply),
+    XcMiscGetXIDRange(xc_misc::GetXIDRangeReply),
+    XcMiscGetXIDList(xc_misc::GetXIDListReply),
+    #[cfg(feature = "xevie")]
+    XevieQueryVersion(xevie::QueryVersionReply),
+    #[cfg(feature = "xevie")]
+    XevieStart(xevie::StartReply),
+    #[cfg(feature = "xevie")]
+    XevieEnd(xevie::EndReply),
+    #[cfg(feature = "xevie")]
+    XevieSend(xevie::SendReply),
+    #[cfg(feature = "xevie")]
+    XevieSelectInput(xevie::SelectInputReply),
+    #[cfg(feature = "xf86dri")]
+    Xf86driQueryVersion(xf86dri::QueryVersionReply),
+    #[cfg(feature = "xf86dri")]
+    Xf86driQueryDirectRenderingCapable(xf86dri::QueryDirectRenderingCapableReply),
+    #[cfg(feature = "xf86dri")]
+    Xf86driOpenConnection(xf86dri::OpenConnectionReply),
+    #[cfg(feature = "xf86dri")]
+    Xf86driGetClientDriverName(xf86dri::GetClientDriverNameReply),
+    #[cfg(feature = "xf86dri")]
+    Xf86driCreateContext(xf86dri::CreateContextReply),
+    #[cfg(feature = "xf86dri")]
+    Xf86driCreateDrawable(xf86dri::CreateDrawableReply),
+    #[cfg(feature = "xf86dri")]
+    Xf86driGetDrawableInfo(xf86dri::GetDrawableInfoReply),
+    #[cfg(feature = "xf86dri")]
+    Xf86driGetDeviceInfo(xf86dri::GetDeviceInfoReply),
+    #[cfg(feature = "xf86dri")]
+    Xf86driAuthConnection(xf86dri::AuthConnectionReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeQueryVersion(xf86vidmode::QueryVersionReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetModeLine(xf86vidmode::GetModeLineReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetMonitor(xf86vidmode::GetMonitorReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetAllModeLines(xf86vidmode::GetAllModeLinesReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeValidateModeLine(xf86vidmode::ValidateModeLineReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetViewPort(xf86vidmode::GetViewPortReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetDotClocks(xf86vidmode::GetDotClocksReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetGamma(xf86vidmode::GetGammaReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetGammaRamp(xf86vidmode::GetGammaRampReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetGammaRampSize(xf86vidmode::GetGammaRampSizeReply),
+    #[cfg(feature = "xf86vidmode")]
+    Xf86vidmodeGetPermissions(xf86vidmode::GetPermissionsReply),
+    #[cfg(feature = "xfixes")]
+    XfixesQueryVersion(xfixes::QueryVersionReply),
+    #[cfg(feature = "xfixes")]
+    XfixesGetCursorImage(xfixes::GetCursorImageReply),
+    #[cfg(feature = "xfixes")]
+    XfixesFetchRegion(xfixes::FetchRegionReply),
+    #[cfg(feature = "xfixes")]
+    XfixesGetCursorName(xfixes::GetCursorNameReply),
+    #[cfg(feature = "xfixes")]
+    XfixesGetCursorImageAndName(xfixes::GetCursorImageAndNameReply),
+    #[cfg(feature = "xinerama")]
+    XineramaQueryVersion(xinerama::QueryVersionReply),
+    #[cfg(feature = "xinerama")]
+    XineramaGetState(xinerama::GetStateReply),
+    #[cfg(feature = "xinerama")]
+    XineramaGetScreenCount(xinerama::GetScreenCountReply),
+    #[cfg(feature = "xinerama")]
+    XineramaGetScreenSize(xinerama::GetScreenSizeReply),
+    #[cfg(feature = "xinerama")]
+    XineramaIsActive(xinerama::IsActiveReply),
+    #[cfg(feature = "xinerama")]
+    XineramaQueryScreens(xinerama::QueryScreensReply),
+    #[cfg(feature = "xinput")]
+    XinputGetExtensionVersion(xinput::GetExtensionVersionReply),
+    #[cfg(feature = "xinput")]
+    XinputListInputDevices(xinput::ListInputDevicesReply),
+    #[cfg(feature = "xinput")]
+    XinputOpenDevice(xinput::OpenDeviceReply),
+    #[cfg(feature = "xinput")]
+    XinputSetDeviceMode(xinput::SetDeviceModeReply),
+    #[cfg(feature = "xinput")]
+    XinputGetSelectedExtensionEvents(xinput::GetSelectedExtensionEventsReply),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceDontPropagateList(xinput::GetDeviceDontPropagateListReply),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceMotionEvents(xinput::GetDeviceMotionEventsReply),
+    #[cfg(feature = "xinput")]
+    XinputChangeKeyboardDevice(xinput::ChangeKeyboardDeviceReply),
+    #[cfg(feature = "xinput")]
+    XinputChangePointerDevice(xinput::ChangePointerDeviceReply),
+    #[cfg(feature = "xinput")]
+    XinputGrabDevice(xinput::GrabDeviceReply),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceFocus(xinput::GetDeviceFocusReply),
+    #[cfg(feature = "xinput")]
+    XinputGetFeedbackControl(xinput::GetFeedbackControlReply),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceKeyMapping(xinput::GetDeviceKeyMappingReply),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceModifierMapping(xinput::GetDeviceModifierMappingReply),
+    #[cfg(feature = "xinput")]
+    XinputSetDeviceModifierMapping(xinput::SetDeviceModifierMappingReply),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceButtonMapping(xinput::GetDeviceButtonMappingReply),
+    #[cfg(feature = "xinput")]
+    XinputSetDeviceButtonMapping(xinput::SetDeviceButtonMappingReply),
+    #[cfg(feature = "xinput")]
+    XinputQueryDeviceState(xinput::QueryDeviceStateReply),
+    #[cfg(feature = "xinput")]
+    XinputSetDeviceValuators(xinput::SetDeviceValuatorsReply),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceControl(xinput::GetDeviceControlReply),
+    #[cfg(feature = "xinput")]
+    XinputChangeDeviceControl(xinput::ChangeDeviceControlReply),
+    #[cfg(feature = "xinput")]
+    XinputListDeviceProperties(xinput::ListDevicePropertiesReply),
+    #[cfg(feature = "xinput")]
+    XinputGetDeviceProperty(xinput::GetDevicePropertyReply),
+    #[cfg(feature = "xinput")]
+    XinputXIQueryPointer(xinput::XIQueryPointerReply),
+    #[cfg(feature = "xinput")]
+    XinputXIGetClientPointer(xinput::XIGetClientPointerReply),
+    #[cfg(feature = "xinput")]
+    XinputXIQueryVersion(xinput::XIQueryVersionReply),
+    #[cfg(feature = "xinput")]
+    XinputXIQueryDevice(xinput::XIQueryDeviceReply),
+    #[cfg(feature = "xinput")]
+    XinputXIGetFocus(xinput::XIGetFocusReply),
+    #[cfg(feature = "xinput")]
+    XinputXIGrabDevice(xinput::XIGrabDeviceReply),
+    #[cfg(feature = "xinput")]
+    XinputXIPassiveGrabDevice(xinput::XIPassiveGrabDeviceReply),
+    #[cfg(feature = "xinput")]
+    XinputXIListProperties(xinput::XIListPropertiesReply),
+    #[cfg(feature = "xinput")]
+    XinputXIGetProperty(xinput::XIGetPropertyReply),
+    #[cfg(feature = "xinput")]
+    XinputXIGetSelectedEvents(xinput::XIGetSelectedEventsReply),
+    #[cfg(feature = "xkb")]
+    XkbUseExtension(xkb::UseExtensionReply),
+    #[cfg(feature = "xkb")]
+    XkbGetState(xkb::GetStateReply),
+    #[cfg(feature = "xkb")]
+    XkbGetControls(xkb::GetControlsReply),
+    #[cfg(feature = "xkb")]
+    XkbGetMap(xkb::GetMapReply),
+    #[cfg(feature = "xkb")]
+    XkbGetCompatMap(xkb::GetCompatMapReply),
+    #[cfg(feature = "xkb")]
+    XkbGetIndicatorState(xkb::GetIndicatorStateReply),
+    #[cfg(feature = "xkb")]
+    XkbGetIndicatorMap(xkb::GetIndicatorMapReply),
+    #[cfg(feature = "xkb")]
+    XkbGetNamedIndicator(xkb::GetNamedIndicatorReply),
+    #[cfg(feature = "xkb")]
+    XkbGetNames(xkb::GetNamesReply),
+    #[cfg(feature = "xkb")]
+    XkbPerClientFlags(xkb::PerClientFlagsReply),
+    #[cfg(feature = "xkb")]
+    XkbListComponents(xkb::ListComponentsReply),
+    #[cfg(feature = "xkb")]
+    XkbGetKbdByName(xkb::GetKbdByNameReply),
+    #[cfg(feature = "xkb")]
+    XkbGetDeviceInfo(xkb::GetDeviceInfoReply),
+    #[cfg(feature = "xkb")]
+    XkbSetDebuggingFlags(xkb::SetDebuggingFlagsReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintQueryVersion(xprint::PrintQueryVersionReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetPrinterList(xprint::PrintGetPrinterListReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetContext(xprint::PrintGetContextReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetScreenOfContext(xprint::PrintGetScreenOfContextReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetDocumentData(xprint::PrintGetDocumentDataReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintInputSelected(xprint::PrintInputSelectedReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetAttributes(xprint::PrintGetAttributesReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetOneAttributes(xprint::PrintGetOneAttributesReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetPageDimensions(xprint::PrintGetPageDimensionsReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintQueryScreens(xprint::PrintQueryScreensReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintSetImageResolution(xprint::PrintSetImageResolutionReply),
+    #[cfg(feature = "xprint")]
+    XprintPrintGetImageResolution(xprint::PrintGetImageResolutionReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxQueryVersion(xselinux::QueryVersionReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetDeviceCreateContext(xselinux::GetDeviceCreateContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetDeviceContext(xselinux::GetDeviceContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetWindowCreateContext(xselinux::GetWindowCreateContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetWindowContext(xselinux::GetWindowContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetPropertyCreateContext(xselinux::GetPropertyCreateContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetPropertyUseContext(xselinux::GetPropertyUseContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetPropertyContext(xselinux::GetPropertyContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetPropertyDataContext(xselinux::GetPropertyDataContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxListProperties(xselinux::ListPropertiesReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetSelectionCreateContext(xselinux::GetSelectionCreateContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetSelectionUseContext(xselinux::GetSelectionUseContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetSelectionContext(xselinux::GetSelectionContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetSelectionDataContext(xselinux::GetSelectionDataContextReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxListSelections(xselinux::ListSelectionsReply),
+    #[cfg(feature = "xselinux")]
+    XselinuxGetClientContext(xselinux::GetClientContextReply),
+    #[cfg(feature = "xtest")]
+    XtestGetVersion(xtest::GetVersionReply),
+    #[cfg(feature = "xtest")]
+    XtestCompareCursor(xtest::CompareCursorReply),
+    #[cfg(feature = "xv")]
+    XvQueryExtension(xv::QueryExtensionReply),
+    #[cfg(feature = "xv")]
+    XvQueryAdaptors(xv::QueryAdaptorsReply),
+    #[cfg(feature = "xv")]
+    XvQueryEncodings(xv::QueryEncodingsReply),
+    #[cfg(feature = "xv")]
+    XvGrabPort(xv::GrabPortReply),
+    #[cfg(feature = "xv")]
+    XvQueryBestSize(xv::QueryBestSizeReply),
+    #[cfg(feature = "xv")]
+    XvGetPortAttribute(xv::GetPortAttributeReply),
+    #[cfg(feature = "xv")]
+    XvQueryPortAttributes(xv::QueryPortAttributesReply),
+    #[cfg(feature = "xv")]
+    XvListImageFormats(xv::ListImageFormatsReply),
+    #[cfg(feature = "xv")]
+    XvQueryImageAttributes(xv::QueryImageAttributesReply),
+    #[cfg(feature = "xvmc")]
+    XvmcQueryVersion(xvmc::QueryVersionReply),
+    #[cfg(feature = "xvmc")]
+    XvmcListSurfaceTypes(xvmc::ListSurfaceTypesReply),
+    #[cfg(feature = "xvmc")]
+    XvmcCreateContext(xvmc::CreateContextReply),
+    #[cfg(feature = "xvmc")]
+    XvmcCreateSurface(xvmc::CreateSurfaceReply),
+    #[cfg(feature = "xvmc")]
+    XvmcCreateSubpicture(xvmc::CreateSubpictureReply),
+    #[cfg(feature = "xvmc")]
+    XvmcListSubpictureTypes(xvmc::ListSubpictureTypesReply),
+}
+
+impl Reply {
+    /// Parse a generic X11 reply into a concrete reply type.
+    ///
+    /// `major_opcode` and `minor_opcode` are the opcodes of the request that caused the reply.
+    /// The reply is expected in the byte order of the machine that this code runs on. The FDs
+    /// that were received with the reply are taken from the front of `fds`. Replies to requests
+    /// that are not known are returned as `Reply::Unknown`.
+    pub fn parse(
+        major_opcode: u8,
+        minor_opcode: u8,
+        reply: &[u8],
+        fds: &mut Vec<RawFdContainer>,
+        ext_info_provider: &dyn ExtInfoProvider,
+    ) -> Result<Self, ParseError> {
+        Self::parse_with_order(major_opcode, minor_opcode, reply, fds, ext_info_provider, ByteOrder::native())
+    }
+
+    /// Parse a generic X11 reply that is encoded in the given byte order.
+    #[allow(clippy::cognitive_complexity)]
+    #[cfg_attr(not(any(feature = "dri3", feature = "randr", feature = "shm")), allow(unused_variables))]
+    pub fn parse_with_order(
+        major_opcode: u8,
+        minor_opcode: u8,
+        reply: &[u8],
+        fds: &mut Vec<RawFdContainer>,
+        ext_info_provider: &dyn ExtInfoProvider,
+        order: ByteOrder,
+    ) -> Result<Self, ParseError> {
+        // Check if this is a reply to a core protocol request
+        match major_opcode {
+            xproto::GET_WINDOW_ATTRIBUTES_REQUEST => return Ok(Reply::GetWindowAttributes(xproto::GetWindowAttributesReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_GEOMETRY_REQUEST => return Ok(Reply::GetGeometry(xproto::GetGeometryReply::try_parse_with_order(reply, order)?.0)),
+            xproto::QUERY_TREE_REQUEST => return Ok(Reply::QueryTree(xproto::QueryTreeReply::try_parse_with_order(reply, order)?.0)),
+            xproto::INTERN_ATOM_REQUEST => return Ok(Reply::InternAtom(xproto::InternAtomReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_ATOM_NAME_REQUEST => return Ok(Reply::GetAtomName(xproto::GetAtomNameReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_PROPERTY_REQUEST => return Ok(Reply::GetProperty(xproto::GetPropertyReply::try_parse_with_order(reply, order)?.0)),
+            xproto::LIST_PROPERTIES_REQUEST => return Ok(Reply::ListProperties(xproto::ListPropertiesReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_SELECTION_OWNER_REQUEST => return Ok(Reply::GetSelectionOwner(xproto::GetSelectionOwnerReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GRAB_POINTER_REQUEST => return Ok(Reply::GrabPointer(xproto::GrabPointerReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GRAB_KEYBOARD_REQUEST => return Ok(Reply::GrabKeyboard(xproto::GrabKeyboardReply::try_parse_with_order(reply, order)?.0)),
+            xproto::QUERY_POINTER_REQUEST => return Ok(Reply::QueryPointer(xproto::QueryPointerReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_MOTION_EVENTS_REQUEST => return Ok(Reply::GetMotionEvents(xproto::GetMotionEventsReply::try_parse_with_order(reply, order)?.0)),
+            xproto::TRANSLATE_COORDINATES_REQUEST => return Ok(Reply::TranslateCoordinates(xproto::TranslateCoordinatesReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_INPUT_FOCUS_REQUEST => return Ok(Reply::GetInputFocus(xproto::GetInputFocusReply::try_parse_with_order(reply, order)?.0)),
+            xproto::QUERY_KEYMAP_REQUEST => return Ok(Reply::QueryKeymap(xproto::QueryKeymapReply::try_parse_with_order(reply, order)?.0)),
+            xproto::QUERY_FONT_REQUEST => return Ok(Reply::QueryFont(xproto::QueryFontReply::try_parse_with_order(reply, order)?.0)),
+            xproto::QUERY_TEXT_EXTENTS_REQUEST => return Ok(Reply::QueryTextExtents(xproto::QueryTextExtentsReply::try_parse_with_order(reply, order)?.0)),
+            xproto::LIST_FONTS_REQUEST => return Ok(Reply::ListFonts(xproto::ListFontsReply::try_parse_with_order(reply, order)?.0)),
+            xproto::LIST_FONTS_WITH_INFO_REQUEST => return Ok(Reply::ListFontsWithInfo(xproto::ListFontsWithInfoReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_FONT_PATH_REQUEST => return Ok(Reply::GetFontPath(xproto::GetFontPathReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_IMAGE_REQUEST => return Ok(Reply::GetImage(xproto::GetImageReply::try_parse_with_order(reply, order)?.0)),
+            xproto::LIST_INSTALLED_COLORMAPS_REQUEST => return Ok(Reply::ListInstalledColormaps(xproto::ListInstalledColormapsReply::try_parse_with_order(reply, order)?.0)),
+            xproto::ALLOC_COLOR_REQUEST => return Ok(Reply::AllocColor(xproto::AllocColorReply::try_parse_with_order(reply, order)?.0)),
+            xproto::ALLOC_NAMED_COLOR_REQUEST => return Ok(Reply::AllocNamedColor(xproto::AllocNamedColorReply::try_parse_with_order(reply, order)?.0)),
+            xproto::ALLOC_COLOR_CELLS_REQUEST => return Ok(Reply::AllocColorCells(xproto::AllocColorCellsReply::try_parse_with_order(reply, order)?.0)),
+            xproto::ALLOC_COLOR_PLANES_REQUEST => return Ok(Reply::AllocColorPlanes(xproto::AllocColorPlanesReply::try_parse_with_order(reply, order)?.0)),
+            xproto::QUERY_COLORS_REQUEST => return Ok(Reply::QueryColors(xproto::QueryColorsReply::try_parse_with_order(reply, order)?.0)),
+            xproto::LOOKUP_COLOR_REQUEST => return Ok(Reply::LookupColor(xproto::LookupColorReply::try_parse_with_order(reply, order)?.0)),
+            xproto::QUERY_BEST_SIZE_REQUEST => return Ok(Reply::QueryBestSize(xproto::QueryBestSizeReply::try_parse_with_order(reply, order)?.0)),
+            xproto::QUERY_EXTENSION_REQUEST => return Ok(Reply::QueryExtension(xproto::QueryExtensionReply::try_parse_with_order(reply, order)?.0)),
+            xproto::LIST_EXTENSIONS_REQUEST => return Ok(Reply::ListExtensions(xproto::ListExtensionsReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_KEYBOARD_MAPPING_REQUEST => return Ok(Reply::GetKeyboardMapping(xproto::GetKeyboardMappingReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_KEYBOARD_CONTROL_REQUEST => return Ok(Reply::GetKeyboardControl(xproto::GetKeyboardControlReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_POINTER_CONTROL_REQUEST => return Ok(Reply::GetPointerControl(xproto::GetPointerControlReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_SCREEN_SAVER_REQUEST => return Ok(Reply::GetScreenSaver(xproto::GetScreenSaverReply::try_parse_with_order(reply, order)?.0)),
+            xproto::LIST_HOSTS_REQUEST => return Ok(Reply::ListHosts(xproto::ListHostsReply::try_parse_with_order(reply, order)?.0)),
+            xproto::SET_POINTER_MAPPING_REQUEST => return Ok(Reply::SetPointerMapping(xproto::SetPointerMappingReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_POINTER_MAPPING_REQUEST => return Ok(Reply::GetPointerMapping(xproto::GetPointerMappingReply::try_parse_with_order(reply, order)?.0)),
+            xproto::SET_MODIFIER_MAPPING_REQUEST => return Ok(Reply::SetModifierMapping(xproto::SetModifierMappingReply::try_parse_with_order(reply, order)?.0)),
+            xproto::GET_MODIFIER_MAPPING_REQUEST => return Ok(Reply::GetModifierMapping(xproto::GetModifierMappingReply::try_parse_with_order(reply, order)?.0)),
+            _ => {}
+        }
+
+        // Find the extension that the request could belong to
+        let ext_info = ext_info_provider.get_from_major_opcode(major_opcode);
+        match ext_info {
+            Some((bigreq::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    bigreq::ENABLE_REQUEST => Ok(Reply::BigreqEnable(bigreq::EnableReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "composite")]
+            Some((composite::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    composite::QUERY_VERSION_REQUEST => Ok(Reply::CompositeQueryVersion(composite::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    composite::GET_OVERLAY_WINDOW_REQUEST => Ok(Reply::CompositeGetOverlayWindow(composite::GetOverlayWindowReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "damage")]
+            Some((damage::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    damage::QUERY_VERSION_REQUEST => Ok(Reply::DamageQueryVersion(damage::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "dpms")]
+            Some((dpms::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    dpms::GET_VERSION_REQUEST => Ok(Reply::DpmsGetVersion(dpms::GetVersionReply::try_parse_with_order(reply, order)?.0)),
+                    dpms::CAPABLE_REQUEST => Ok(Reply::DpmsCapable(dpms::CapableReply::try_parse_with_order(reply, order)?.0)),
+                    dpms::GET_TIMEOUTS_REQUEST => Ok(Reply::DpmsGetTimeouts(dpms::GetTimeoutsReply::try_parse_with_order(reply, order)?.0)),
+                    dpms::INFO_REQUEST => Ok(Reply::DpmsInfo(dpms::InfoReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "dri2")]
+            Some((dri2::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    dri2::QUERY_VERSION_REQUEST => Ok(Reply::Dri2QueryVersion(dri2::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::CONNECT_REQUEST => Ok(Reply::Dri2Connect(dri2::ConnectReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::AUTHENTICATE_REQUEST => Ok(Reply::Dri2Authenticate(dri2::AuthenticateReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::GET_BUFFERS_REQUEST => Ok(Reply::Dri2GetBuffers(dri2::GetBuffersReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::COPY_REGION_REQUEST => Ok(Reply::Dri2CopyRegion(dri2::CopyRegionReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::GET_BUFFERS_WITH_FORMAT_REQUEST => Ok(Reply::Dri2GetBuffersWithFormat(dri2::GetBuffersWithFormatReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::SWAP_BUFFERS_REQUEST => Ok(Reply::Dri2SwapBuffers(dri2::SwapBuffersReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::GET_MSC_REQUEST => Ok(Reply::Dri2GetMSC(dri2::GetMSCReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::WAIT_MSC_REQUEST => Ok(Reply::Dri2WaitMSC(dri2::WaitMSCReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::WAIT_SBC_REQUEST => Ok(Reply::Dri2WaitSBC(dri2::WaitSBCReply::try_parse_with_order(reply, order)?.0)),
+                    dri2::GET_PARAM_REQUEST => Ok(Reply::Dri2GetParam(dri2::GetParamReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "dri3")]
+            Some((dri3::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    dri3::QUERY_VERSION_REQUEST => Ok(Reply::Dri3QueryVersion(dri3::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    dri3::OPEN_REQUEST => Ok(Reply::Dri3Open(<dri3::OpenReply as crate::x11_utils::TryParseFd>::try_parse_fd_with_order(reply, fds, order)?.0)),
+                    dri3::BUFFER_FROM_PIXMAP_REQUEST => Ok(Reply::Dri3BufferFromPixmap(<dri3::BufferFromPixmapReply as crate::x11_utils::TryParseFd>::try_parse_fd_with_order(reply, fds, order)?.0)),
+                    dri3::FD_FROM_FENCE_REQUEST => Ok(Reply::Dri3FDFromFence(<dri3::FDFromFenceReply as crate::x11_utils::TryParseFd>::try_parse_fd_with_order(reply, fds, order)?.0)),
+                    dri3::GET_SUPPORTED_MODIFIERS_REQUEST => Ok(Reply::Dri3GetSupportedModifiers(dri3::GetSupportedModifiersReply::try_parse_with_order(reply, order)?.0)),
+                    dri3::BUFFERS_FROM_PIXMAP_REQUEST => Ok(Reply::Dri3BuffersFromPixmap(<dri3::BuffersFromPixmapReply as crate::x11_utils::TryParseFd>::try_parse_fd_with_order(reply, fds, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            Some((ge::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    ge::QUERY_VERSION_REQUEST => Ok(Reply::GeQueryVersion(ge::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "glx")]
+            Some((glx::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    glx::MAKE_CURRENT_REQUEST => Ok(Reply::GlxMakeCurrent(glx::MakeCurrentReply::try_parse_with_order(reply, order)?.0)),
+                    glx::IS_DIRECT_REQUEST => Ok(Reply::GlxIsDirect(glx::IsDirectReply::try_parse_with_order(reply, order)?.0)),
+                    glx::QUERY_VERSION_REQUEST => Ok(Reply::GlxQueryVersion(glx::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_VISUAL_CONFIGS_REQUEST => Ok(Reply::GlxGetVisualConfigs(glx::GetVisualConfigsReply::try_parse_with_order(reply, order)?.0)),
+                    glx::VENDOR_PRIVATE_WITH_REPLY_REQUEST => Ok(Reply::GlxVendorPrivateWithReply(glx::VendorPrivateWithReplyReply::try_parse_with_order(reply, order)?.0)),
+                    glx::QUERY_EXTENSIONS_STRING_REQUEST => Ok(Reply::GlxQueryExtensionsString(glx::QueryExtensionsStringReply::try_parse_with_order(reply, order)?.0)),
+                    glx::QUERY_SERVER_STRING_REQUEST => Ok(Reply::GlxQueryServerString(glx::QueryServerStringReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_FB_CONFIGS_REQUEST => Ok(Reply::GlxGetFBConfigs(glx::GetFBConfigsReply::try_parse_with_order(reply, order)?.0)),
+                    glx::QUERY_CONTEXT_REQUEST => Ok(Reply::GlxQueryContext(glx::QueryContextReply::try_parse_with_order(reply, order)?.0)),
+                    glx::MAKE_CONTEXT_CURRENT_REQUEST => Ok(Reply::GlxMakeContextCurrent(glx::MakeContextCurrentReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_DRAWABLE_ATTRIBUTES_REQUEST => Ok(Reply::GlxGetDrawableAttributes(glx::GetDrawableAttributesReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GEN_LISTS_REQUEST => Ok(Reply::GlxGenLists(glx::GenListsReply::try_parse_with_order(reply, order)?.0)),
+                    glx::RENDER_MODE_REQUEST => Ok(Reply::GlxRenderMode(glx::RenderModeReply::try_parse_with_order(reply, order)?.0)),
+                    glx::FINISH_REQUEST => Ok(Reply::GlxFinish(glx::FinishReply::try_parse_with_order(reply, order)?.0)),
+                    glx::READ_PIXELS_REQUEST => Ok(Reply::GlxReadPixels(glx::ReadPixelsReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_BOOLEANV_REQUEST => Ok(Reply::GlxGetBooleanv(glx::GetBooleanvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_CLIP_PLANE_REQUEST => Ok(Reply::GlxGetClipPlane(glx::GetClipPlaneReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_DOUBLEV_REQUEST => Ok(Reply::GlxGetDoublev(glx::GetDoublevReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_ERROR_REQUEST => Ok(Reply::GlxGetError(glx::GetErrorReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_FLOATV_REQUEST => Ok(Reply::GlxGetFloatv(glx::GetFloatvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_INTEGERV_REQUEST => Ok(Reply::GlxGetIntegerv(glx::GetIntegervReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_LIGHTFV_REQUEST => Ok(Reply::GlxGetLightfv(glx::GetLightfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_LIGHTIV_REQUEST => Ok(Reply::GlxGetLightiv(glx::GetLightivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_MAPDV_REQUEST => Ok(Reply::GlxGetMapdv(glx::GetMapdvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_MAPFV_REQUEST => Ok(Reply::GlxGetMapfv(glx::GetMapfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_MAPIV_REQUEST => Ok(Reply::GlxGetMapiv(glx::GetMapivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_MATERIALFV_REQUEST => Ok(Reply::GlxGetMaterialfv(glx::GetMaterialfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_MATERIALIV_REQUEST => Ok(Reply::GlxGetMaterialiv(glx::GetMaterialivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_PIXEL_MAPFV_REQUEST => Ok(Reply::GlxGetPixelMapfv(glx::GetPixelMapfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_PIXEL_MAPUIV_REQUEST => Ok(Reply::GlxGetPixelMapuiv(glx::GetPixelMapuivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_PIXEL_MAPUSV_REQUEST => Ok(Reply::GlxGetPixelMapusv(glx::GetPixelMapusvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_POLYGON_STIPPLE_REQUEST => Ok(Reply::GlxGetPolygonStipple(glx::GetPolygonStippleReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_STRING_REQUEST => Ok(Reply::GlxGetString(glx::GetStringReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_ENVFV_REQUEST => Ok(Reply::GlxGetTexEnvfv(glx::GetTexEnvfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_ENVIV_REQUEST => Ok(Reply::GlxGetTexEnviv(glx::GetTexEnvivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_GENDV_REQUEST => Ok(Reply::GlxGetTexGendv(glx::GetTexGendvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_GENFV_REQUEST => Ok(Reply::GlxGetTexGenfv(glx::GetTexGenfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_GENIV_REQUEST => Ok(Reply::GlxGetTexGeniv(glx::GetTexGenivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_IMAGE_REQUEST => Ok(Reply::GlxGetTexImage(glx::GetTexImageReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_PARAMETERFV_REQUEST => Ok(Reply::GlxGetTexParameterfv(glx::GetTexParameterfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_PARAMETERIV_REQUEST => Ok(Reply::GlxGetTexParameteriv(glx::GetTexParameterivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_LEVEL_PARAMETERFV_REQUEST => Ok(Reply::GlxGetTexLevelParameterfv(glx::GetTexLevelParameterfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_TEX_LEVEL_PARAMETERIV_REQUEST => Ok(Reply::GlxGetTexLevelParameteriv(glx::GetTexLevelParameterivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::IS_ENABLED_REQUEST => Ok(Reply::GlxIsEnabled(glx::IsEnabledReply::try_parse_with_order(reply, order)?.0)),
+                    glx::IS_LIST_REQUEST => Ok(Reply::GlxIsList(glx::IsListReply::try_parse_with_order(reply, order)?.0)),
+                    glx::ARE_TEXTURES_RESIDENT_REQUEST => Ok(Reply::GlxAreTexturesResident(glx::AreTexturesResidentReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GEN_TEXTURES_REQUEST => Ok(Reply::GlxGenTextures(glx::GenTexturesReply::try_parse_with_order(reply, order)?.0)),
+                    glx::IS_TEXTURE_REQUEST => Ok(Reply::GlxIsTexture(glx::IsTextureReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_COLOR_TABLE_REQUEST => Ok(Reply::GlxGetColorTable(glx::GetColorTableReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_COLOR_TABLE_PARAMETERFV_REQUEST => Ok(Reply::GlxGetColorTableParameterfv(glx::GetColorTableParameterfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_COLOR_TABLE_PARAMETERIV_REQUEST => Ok(Reply::GlxGetColorTableParameteriv(glx::GetColorTableParameterivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_CONVOLUTION_FILTER_REQUEST => Ok(Reply::GlxGetConvolutionFilter(glx::GetConvolutionFilterReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_CONVOLUTION_PARAMETERFV_REQUEST => Ok(Reply::GlxGetConvolutionParameterfv(glx::GetConvolutionParameterfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_CONVOLUTION_PARAMETERIV_REQUEST => Ok(Reply::GlxGetConvolutionParameteriv(glx::GetConvolutionParameterivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_SEPARABLE_FILTER_REQUEST => Ok(Reply::GlxGetSeparableFilter(glx::GetSeparableFilterReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_HISTOGRAM_REQUEST => Ok(Reply::GlxGetHistogram(glx::GetHistogramReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_HISTOGRAM_PARAMETERFV_REQUEST => Ok(Reply::GlxGetHistogramParameterfv(glx::GetHistogramParameterfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_HISTOGRAM_PARAMETERIV_REQUEST => Ok(Reply::GlxGetHistogramParameteriv(glx::GetHistogramParameterivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_MINMAX_REQUEST => Ok(Reply::GlxGetMinmax(glx::GetMinmaxReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_MINMAX_PARAMETERFV_REQUEST => Ok(Reply::GlxGetMinmaxParameterfv(glx::GetMinmaxParameterfvReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_MINMAX_PARAMETERIV_REQUEST => Ok(Reply::GlxGetMinmaxParameteriv(glx::GetMinmaxParameterivReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST => Ok(Reply::GlxGetCompressedTexImageARB(glx::GetCompressedTexImageARBReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GEN_QUERIES_ARB_REQUEST => Ok(Reply::GlxGenQueriesARB(glx::GenQueriesARBReply::try_parse_with_order(reply, order)?.0)),
+                    glx::IS_QUERY_ARB_REQUEST => Ok(Reply::GlxIsQueryARB(glx::IsQueryARBReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_QUERYIV_ARB_REQUEST => Ok(Reply::GlxGetQueryivARB(glx::GetQueryivARBReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_QUERY_OBJECTIV_ARB_REQUEST => Ok(Reply::GlxGetQueryObjectivARB(glx::GetQueryObjectivARBReply::try_parse_with_order(reply, order)?.0)),
+                    glx::GET_QUERY_OBJECTUIV_ARB_REQUEST => Ok(Reply::GlxGetQueryObjectuivARB(glx::GetQueryObjectuivARBReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "present")]
+            Some((present::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    present::QUERY_VERSION_REQUEST => Ok(Reply::PresentQueryVersion(present::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    present::QUERY_CAPABILITIES_REQUEST => Ok(Reply::PresentQueryCapabilities(present::QueryCapabilitiesReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "randr")]
+            Some((randr::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    randr::QUERY_VERSION_REQUEST => Ok(Reply::RandrQueryVersion(randr::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    randr::SET_SCREEN_CONFIG_REQUEST => Ok(Reply::RandrSetScreenConfig(randr::SetScreenConfigReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_SCREEN_INFO_REQUEST => Ok(Reply::RandrGetScreenInfo(randr::GetScreenInfoReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_SCREEN_SIZE_RANGE_REQUEST => Ok(Reply::RandrGetScreenSizeRange(randr::GetScreenSizeRangeReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_SCREEN_RESOURCES_REQUEST => Ok(Reply::RandrGetScreenResources(randr::GetScreenResourcesReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_OUTPUT_INFO_REQUEST => Ok(Reply::RandrGetOutputInfo(randr::GetOutputInfoReply::try_parse_with_order(reply, order)?.0)),
+                    randr::LIST_OUTPUT_PROPERTIES_REQUEST => Ok(Reply::RandrListOutputProperties(randr::ListOutputPropertiesReply::try_parse_with_order(reply, order)?.0)),
+                    randr::QUERY_OUTPUT_PROPERTY_REQUEST => Ok(Reply::RandrQueryOutputProperty(randr::QueryOutputPropertyReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_OUTPUT_PROPERTY_REQUEST => Ok(Reply::RandrGetOutputProperty(randr::GetOutputPropertyReply::try_parse_with_order(reply, order)?.0)),
+                    randr::CREATE_MODE_REQUEST => Ok(Reply::RandrCreateMode(randr::CreateModeReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_CRTC_INFO_REQUEST => Ok(Reply::RandrGetCrtcInfo(randr::GetCrtcInfoReply::try_parse_with_order(reply, order)?.0)),
+                    randr::SET_CRTC_CONFIG_REQUEST => Ok(Reply::RandrSetCrtcConfig(randr::SetCrtcConfigReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_CRTC_GAMMA_SIZE_REQUEST => Ok(Reply::RandrGetCrtcGammaSize(randr::GetCrtcGammaSizeReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_CRTC_GAMMA_REQUEST => Ok(Reply::RandrGetCrtcGamma(randr::GetCrtcGammaReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_SCREEN_RESOURCES_CURRENT_REQUEST => Ok(Reply::RandrGetScreenResourcesCurrent(randr::GetScreenResourcesCurrentReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_CRTC_TRANSFORM_REQUEST => Ok(Reply::RandrGetCrtcTransform(randr::GetCrtcTransformReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_PANNING_REQUEST => Ok(Reply::RandrGetPanning(randr::GetPanningReply::try_parse_with_order(reply, order)?.0)),
+                    randr::SET_PANNING_REQUEST => Ok(Reply::RandrSetPanning(randr::SetPanningReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_OUTPUT_PRIMARY_REQUEST => Ok(Reply::RandrGetOutputPrimary(randr::GetOutputPrimaryReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_PROVIDERS_REQUEST => Ok(Reply::RandrGetProviders(randr::GetProvidersReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_PROVIDER_INFO_REQUEST => Ok(Reply::RandrGetProviderInfo(randr::GetProviderInfoReply::try_parse_with_order(reply, order)?.0)),
+                    randr::LIST_PROVIDER_PROPERTIES_REQUEST => Ok(Reply::RandrListProviderProperties(randr::ListProviderPropertiesReply::try_parse_with_order(reply, order)?.0)),
+                    randr::QUERY_PROVIDER_PROPERTY_REQUEST => Ok(Reply::RandrQueryProviderProperty(randr::QueryProviderPropertyReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_PROVIDER_PROPERTY_REQUEST => Ok(Reply::RandrGetProviderProperty(randr::GetProviderPropertyReply::try_parse_with_order(reply, order)?.0)),
+                    randr::GET_MONITORS_REQUEST => Ok(Reply::RandrGetMonitors(randr::GetMonitorsReply::try_parse_with_order(reply, order)?.0)),
+                    randr::CREATE_LEASE_REQUEST => Ok(Reply::RandrCreateLease(<randr::CreateLeaseReply as crate::x11_utils::TryParseFd>::try_parse_fd_with_order(reply, fds, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "record")]
+            Some((record::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    record::QUERY_VERSION_REQUEST => Ok(Reply::RecordQueryVersion(record::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    record::GET_CONTEXT_REQUEST => Ok(Reply::RecordGetContext(record::GetContextReply::try_parse_with_order(reply, order)?.0)),
+                    record::ENABLE_CONTEXT_REQUEST => Ok(Reply::RecordEnableContext(record::EnableContextReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "render")]
+            Some((render::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    render::QUERY_VERSION_REQUEST => Ok(Reply::RenderQueryVersion(render::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    render::QUERY_PICT_FORMATS_REQUEST => Ok(Reply::RenderQueryPictFormats(render::QueryPictFormatsReply::try_parse_with_order(reply, order)?.0)),
+                    render::QUERY_PICT_INDEX_VALUES_REQUEST => Ok(Reply::RenderQueryPictIndexValues(render::QueryPictIndexValuesReply::try_parse_with_order(reply, order)?.0)),
+                    render::QUERY_FILTERS_REQUEST => Ok(Reply::RenderQueryFilters(render::QueryFiltersReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "res")]
+            Some((res::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    res::QUERY_VERSION_REQUEST => Ok(Reply::ResQueryVersion(res::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    res::QUERY_CLIENTS_REQUEST => Ok(Reply::ResQueryClients(res::QueryClientsReply::try_parse_with_order(reply, order)?.0)),
+                    res::QUERY_CLIENT_RESOURCES_REQUEST => Ok(Reply::ResQueryClientResources(res::QueryClientResourcesReply::try_parse_with_order(reply, order)?.0)),
+                    res::QUERY_CLIENT_PIXMAP_BYTES_REQUEST => Ok(Reply::ResQueryClientPixmapBytes(res::QueryClientPixmapBytesReply::try_parse_with_order(reply, order)?.0)),
+                    res::QUERY_CLIENT_IDS_REQUEST => Ok(Reply::ResQueryClientIds(res::QueryClientIdsReply::try_parse_with_order(reply, order)?.0)),
+                    res::QUERY_RESOURCE_BYTES_REQUEST => Ok(Reply::ResQueryResourceBytes(res::QueryResourceBytesReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "screensaver")]
+            Some((screensaver::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    screensaver::QUERY_VERSION_REQUEST => Ok(Reply::ScreensaverQueryVersion(screensaver::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    screensaver::QUERY_INFO_REQUEST => Ok(Reply::ScreensaverQueryInfo(screensaver::QueryInfoReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "shape")]
+            Some((shape::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    shape::QUERY_VERSION_REQUEST => Ok(Reply::ShapeQueryVersion(shape::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    shape::QUERY_EXTENTS_REQUEST => Ok(Reply::ShapeQueryExtents(shape::QueryExtentsReply::try_parse_with_order(reply, order)?.0)),
+                    shape::INPUT_SELECTED_REQUEST => Ok(Reply::ShapeInputSelected(shape::InputSelectedReply::try_parse_with_order(reply, order)?.0)),
+                    shape::GET_RECTANGLES_REQUEST => Ok(Reply::ShapeGetRectangles(shape::GetRectanglesReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "shm")]
+            Some((shm::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    shm::QUERY_VERSION_REQUEST => Ok(Reply::ShmQueryVersion(shm::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    shm::GET_IMAGE_REQUEST => Ok(Reply::ShmGetImage(shm::GetImageReply::try_parse_with_order(reply, order)?.0)),
+                    shm::CREATE_SEGMENT_REQUEST => Ok(Reply::ShmCreateSegment(<shm::CreateSegmentReply as crate::x11_utils::TryParseFd>::try_parse_fd_with_order(reply, fds, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "sync")]
+            Some((sync::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    sync::INITIALIZE_REQUEST => Ok(Reply::SyncInitialize(sync::InitializeReply::try_parse_with_order(reply, order)?.0)),
+                    sync::LIST_SYSTEM_COUNTERS_REQUEST => Ok(Reply::SyncListSystemCounters(sync::ListSystemCountersReply::try_parse_with_order(reply, order)?.0)),
+                    sync::QUERY_COUNTER_REQUEST => Ok(Reply::SyncQueryCounter(sync::QueryCounterReply::try_parse_with_order(reply, order)?.0)),
+                    sync::QUERY_ALARM_REQUEST => Ok(Reply::SyncQueryAlarm(sync::QueryAlarmReply::try_parse_with_order(reply, order)?.0)),
+                    sync::GET_PRIORITY_REQUEST => Ok(Reply::SyncGetPriority(sync::GetPriorityReply::try_parse_with_order(reply, order)?.0)),
+                    sync::QUERY_FENCE_REQUEST => Ok(Reply::SyncQueryFence(sync::QueryFenceReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            Some((xc_misc::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xc_misc::GET_VERSION_REQUEST => Ok(Reply::XcMiscGetVersion(xc_misc::GetVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xc_misc::GET_XID_RANGE_REQUEST => Ok(Reply::XcMiscGetXIDRange(xc_misc::GetXIDRangeReply::try_parse_with_order(reply, order)?.0)),
+                    xc_misc::GET_XID_LIST_REQUEST => Ok(Reply::XcMiscGetXIDList(xc_misc::GetXIDListReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xevie")]
+            Some((xevie::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xevie::QUERY_VERSION_REQUEST => Ok(Reply::XevieQueryVersion(xevie::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xevie::START_REQUEST => Ok(Reply::XevieStart(xevie::StartReply::try_parse_with_order(reply, order)?.0)),
+                    xevie::END_REQUEST => Ok(Reply::XevieEnd(xevie::EndReply::try_parse_with_order(reply, order)?.0)),
+                    xevie::SEND_REQUEST => Ok(Reply::XevieSend(xevie::SendReply::try_parse_with_order(reply, order)?.0)),
+                    xevie::SELECT_INPUT_REQUEST => Ok(Reply::XevieSelectInput(xevie::SelectInputReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xf86dri")]
+            Some((xf86dri::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xf86dri::QUERY_VERSION_REQUEST => Ok(Reply::Xf86driQueryVersion(xf86dri::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xf86dri::QUERY_DIRECT_RENDERING_CAPABLE_REQUEST => Ok(Reply::Xf86driQueryDirectRenderingCapable(xf86dri::QueryDirectRenderingCapableReply::try_parse_with_order(reply, order)?.0)),
+                    xf86dri::OPEN_CONNECTION_REQUEST => Ok(Reply::Xf86driOpenConnection(xf86dri::OpenConnectionReply::try_parse_with_order(reply, order)?.0)),
+                    xf86dri::GET_CLIENT_DRIVER_NAME_REQUEST => Ok(Reply::Xf86driGetClientDriverName(xf86dri::GetClientDriverNameReply::try_parse_with_order(reply, order)?.0)),
+                    xf86dri::CREATE_CONTEXT_REQUEST => Ok(Reply::Xf86driCreateContext(xf86dri::CreateContextReply::try_parse_with_order(reply, order)?.0)),
+                    xf86dri::CREATE_DRAWABLE_REQUEST => Ok(Reply::Xf86driCreateDrawable(xf86dri::CreateDrawableReply::try_parse_with_order(reply, order)?.0)),
+                    xf86dri::GET_DRAWABLE_INFO_REQUEST => Ok(Reply::Xf86driGetDrawableInfo(xf86dri::GetDrawableInfoReply::try_parse_with_order(reply, order)?.0)),
+                    xf86dri::GET_DEVICE_INFO_REQUEST => Ok(Reply::Xf86driGetDeviceInfo(xf86dri::GetDeviceInfoReply::try_parse_with_order(reply, order)?.0)),
+                    xf86dri::AUTH_CONNECTION_REQUEST => Ok(Reply::Xf86driAuthConnection(xf86dri::AuthConnectionReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xf86vidmode")]
+            Some((xf86vidmode::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xf86vidmode::QUERY_VERSION_REQUEST => Ok(Reply::Xf86vidmodeQueryVersion(xf86vidmode::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::GET_MODE_LINE_REQUEST => Ok(Reply::Xf86vidmodeGetModeLine(xf86vidmode::GetModeLineReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::GET_MONITOR_REQUEST => Ok(Reply::Xf86vidmodeGetMonitor(xf86vidmode::GetMonitorReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::GET_ALL_MODE_LINES_REQUEST => Ok(Reply::Xf86vidmodeGetAllModeLines(xf86vidmode::GetAllModeLinesReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::VALIDATE_MODE_LINE_REQUEST => Ok(Reply::Xf86vidmodeValidateModeLine(xf86vidmode::ValidateModeLineReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::GET_VIEW_PORT_REQUEST => Ok(Reply::Xf86vidmodeGetViewPort(xf86vidmode::GetViewPortReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::GET_DOT_CLOCKS_REQUEST => Ok(Reply::Xf86vidmodeGetDotClocks(xf86vidmode::GetDotClocksReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::GET_GAMMA_REQUEST => Ok(Reply::Xf86vidmodeGetGamma(xf86vidmode::GetGammaReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::GET_GAMMA_RAMP_REQUEST => Ok(Reply::Xf86vidmodeGetGammaRamp(xf86vidmode::GetGammaRampReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::GET_GAMMA_RAMP_SIZE_REQUEST => Ok(Reply::Xf86vidmodeGetGammaRampSize(xf86vidmode::GetGammaRampSizeReply::try_parse_with_order(reply, order)?.0)),
+                    xf86vidmode::GET_PERMISSIONS_REQUEST => Ok(Reply::Xf86vidmodeGetPermissions(xf86vidmode::GetPermissionsReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xfixes")]
+            Some((xfixes::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xfixes::QUERY_VERSION_REQUEST => Ok(Reply::XfixesQueryVersion(xfixes::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xfixes::GET_CURSOR_IMAGE_REQUEST => Ok(Reply::XfixesGetCursorImage(xfixes::GetCursorImageReply::try_parse_with_order(reply, order)?.0)),
+                    xfixes::FETCH_REGION_REQUEST => Ok(Reply::XfixesFetchRegion(xfixes::FetchRegionReply::try_parse_with_order(reply, order)?.0)),
+                    xfixes::GET_CURSOR_NAME_REQUEST => Ok(Reply::XfixesGetCursorName(xfixes::GetCursorNameReply::try_parse_with_order(reply, order)?.0)),
+                    xfixes::GET_CURSOR_IMAGE_AND_NAME_REQUEST => Ok(Reply::XfixesGetCursorImageAndName(xfixes::GetCursorImageAndNameReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xinerama")]
+            Some((xinerama::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xinerama::QUERY_VERSION_REQUEST => Ok(Reply::XineramaQueryVersion(xinerama::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xinerama::GET_STATE_REQUEST => Ok(Reply::XineramaGetState(xinerama::GetStateReply::try_parse_with_order(reply, order)?.0)),
+                    xinerama::GET_SCREEN_COUNT_REQUEST => Ok(Reply::XineramaGetScreenCount(xinerama::GetScreenCountReply::try_parse_with_order(reply, order)?.0)),
+                    xinerama::GET_SCREEN_SIZE_REQUEST => Ok(Reply::XineramaGetScreenSize(xinerama::GetScreenSizeReply::try_parse_with_order(reply, order)?.0)),
+                    xinerama::IS_ACTIVE_REQUEST => Ok(Reply::XineramaIsActive(xinerama::IsActiveReply::try_parse_with_order(reply, order)?.0)),
+                    xinerama::QUERY_SCREENS_REQUEST => Ok(Reply::XineramaQueryScreens(xinerama::QueryScreensReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xinput")]
+            Some((xinput::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xinput::GET_EXTENSION_VERSION_REQUEST => Ok(Reply::XinputGetExtensionVersion(xinput::GetExtensionVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::LIST_INPUT_DEVICES_REQUEST => Ok(Reply::XinputListInputDevices(xinput::ListInputDevicesReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::OPEN_DEVICE_REQUEST => Ok(Reply::XinputOpenDevice(xinput::OpenDeviceReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::SET_DEVICE_MODE_REQUEST => Ok(Reply::XinputSetDeviceMode(xinput::SetDeviceModeReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_SELECTED_EXTENSION_EVENTS_REQUEST => Ok(Reply::XinputGetSelectedExtensionEvents(xinput::GetSelectedExtensionEventsReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST => Ok(Reply::XinputGetDeviceDontPropagateList(xinput::GetDeviceDontPropagateListReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_DEVICE_MOTION_EVENTS_REQUEST => Ok(Reply::XinputGetDeviceMotionEvents(xinput::GetDeviceMotionEventsReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::CHANGE_KEYBOARD_DEVICE_REQUEST => Ok(Reply::XinputChangeKeyboardDevice(xinput::ChangeKeyboardDeviceReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::CHANGE_POINTER_DEVICE_REQUEST => Ok(Reply::XinputChangePointerDevice(xinput::ChangePointerDeviceReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GRAB_DEVICE_REQUEST => Ok(Reply::XinputGrabDevice(xinput::GrabDeviceReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_DEVICE_FOCUS_REQUEST => Ok(Reply::XinputGetDeviceFocus(xinput::GetDeviceFocusReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_FEEDBACK_CONTROL_REQUEST => Ok(Reply::XinputGetFeedbackControl(xinput::GetFeedbackControlReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_DEVICE_KEY_MAPPING_REQUEST => Ok(Reply::XinputGetDeviceKeyMapping(xinput::GetDeviceKeyMappingReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_DEVICE_MODIFIER_MAPPING_REQUEST => Ok(Reply::XinputGetDeviceModifierMapping(xinput::GetDeviceModifierMappingReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::SET_DEVICE_MODIFIER_MAPPING_REQUEST => Ok(Reply::XinputSetDeviceModifierMapping(xinput::SetDeviceModifierMappingReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_DEVICE_BUTTON_MAPPING_REQUEST => Ok(Reply::XinputGetDeviceButtonMapping(xinput::GetDeviceButtonMappingReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::SET_DEVICE_BUTTON_MAPPING_REQUEST => Ok(Reply::XinputSetDeviceButtonMapping(xinput::SetDeviceButtonMappingReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::QUERY_DEVICE_STATE_REQUEST => Ok(Reply::XinputQueryDeviceState(xinput::QueryDeviceStateReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::SET_DEVICE_VALUATORS_REQUEST => Ok(Reply::XinputSetDeviceValuators(xinput::SetDeviceValuatorsReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_DEVICE_CONTROL_REQUEST => Ok(Reply::XinputGetDeviceControl(xinput::GetDeviceControlReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::CHANGE_DEVICE_CONTROL_REQUEST => Ok(Reply::XinputChangeDeviceControl(xinput::ChangeDeviceControlReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::LIST_DEVICE_PROPERTIES_REQUEST => Ok(Reply::XinputListDeviceProperties(xinput::ListDevicePropertiesReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::GET_DEVICE_PROPERTY_REQUEST => Ok(Reply::XinputGetDeviceProperty(xinput::GetDevicePropertyReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_QUERY_POINTER_REQUEST => Ok(Reply::XinputXIQueryPointer(xinput::XIQueryPointerReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_GET_CLIENT_POINTER_REQUEST => Ok(Reply::XinputXIGetClientPointer(xinput::XIGetClientPointerReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_QUERY_VERSION_REQUEST => Ok(Reply::XinputXIQueryVersion(xinput::XIQueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_QUERY_DEVICE_REQUEST => Ok(Reply::XinputXIQueryDevice(xinput::XIQueryDeviceReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_GET_FOCUS_REQUEST => Ok(Reply::XinputXIGetFocus(xinput::XIGetFocusReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_GRAB_DEVICE_REQUEST => Ok(Reply::XinputXIGrabDevice(xinput::XIGrabDeviceReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_PASSIVE_GRAB_DEVICE_REQUEST => Ok(Reply::XinputXIPassiveGrabDevice(xinput::XIPassiveGrabDeviceReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_LIST_PROPERTIES_REQUEST => Ok(Reply::XinputXIListProperties(xinput::XIListPropertiesReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_GET_PROPERTY_REQUEST => Ok(Reply::XinputXIGetProperty(xinput::XIGetPropertyReply::try_parse_with_order(reply, order)?.0)),
+                    xinput::XI_GET_SELECTED_EVENTS_REQUEST => Ok(Reply::XinputXIGetSelectedEvents(xinput::XIGetSelectedEventsReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xkb")]
+            Some((xkb::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xkb::USE_EXTENSION_REQUEST => Ok(Reply::XkbUseExtension(xkb::UseExtensionReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_STATE_REQUEST => Ok(Reply::XkbGetState(xkb::GetStateReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_CONTROLS_REQUEST => Ok(Reply::XkbGetControls(xkb::GetControlsReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_MAP_REQUEST => Ok(Reply::XkbGetMap(xkb::GetMapReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_COMPAT_MAP_REQUEST => Ok(Reply::XkbGetCompatMap(xkb::GetCompatMapReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_INDICATOR_STATE_REQUEST => Ok(Reply::XkbGetIndicatorState(xkb::GetIndicatorStateReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_INDICATOR_MAP_REQUEST => Ok(Reply::XkbGetIndicatorMap(xkb::GetIndicatorMapReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_NAMED_INDICATOR_REQUEST => Ok(Reply::XkbGetNamedIndicator(xkb::GetNamedIndicatorReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_NAMES_REQUEST => Ok(Reply::XkbGetNames(xkb::GetNamesReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::PER_CLIENT_FLAGS_REQUEST => Ok(Reply::XkbPerClientFlags(xkb::PerClientFlagsReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::LIST_COMPONENTS_REQUEST => Ok(Reply::XkbListComponents(xkb::ListComponentsReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_KBD_BY_NAME_REQUEST => Ok(Reply::XkbGetKbdByName(xkb::GetKbdByNameReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::GET_DEVICE_INFO_REQUEST => Ok(Reply::XkbGetDeviceInfo(xkb::GetDeviceInfoReply::try_parse_with_order(reply, order)?.0)),
+                    xkb::SET_DEBUGGING_FLAGS_REQUEST => Ok(Reply::XkbSetDebuggingFlags(xkb::SetDebuggingFlagsReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xprint")]
+            Some((xprint::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xprint::PRINT_QUERY_VERSION_REQUEST => Ok(Reply::XprintPrintQueryVersion(xprint::PrintQueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_GET_PRINTER_LIST_REQUEST => Ok(Reply::XprintPrintGetPrinterList(xprint::PrintGetPrinterListReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_GET_CONTEXT_REQUEST => Ok(Reply::XprintPrintGetContext(xprint::PrintGetContextReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_GET_SCREEN_OF_CONTEXT_REQUEST => Ok(Reply::XprintPrintGetScreenOfContext(xprint::PrintGetScreenOfContextReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_GET_DOCUMENT_DATA_REQUEST => Ok(Reply::XprintPrintGetDocumentData(xprint::PrintGetDocumentDataReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_INPUT_SELECTED_REQUEST => Ok(Reply::XprintPrintInputSelected(xprint::PrintInputSelectedReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_GET_ATTRIBUTES_REQUEST => Ok(Reply::XprintPrintGetAttributes(xprint::PrintGetAttributesReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_GET_ONE_ATTRIBUTES_REQUEST => Ok(Reply::XprintPrintGetOneAttributes(xprint::PrintGetOneAttributesReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_GET_PAGE_DIMENSIONS_REQUEST => Ok(Reply::XprintPrintGetPageDimensions(xprint::PrintGetPageDimensionsReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_QUERY_SCREENS_REQUEST => Ok(Reply::XprintPrintQueryScreens(xprint::PrintQueryScreensReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_SET_IMAGE_RESOLUTION_REQUEST => Ok(Reply::XprintPrintSetImageResolution(xprint::PrintSetImageResolutionReply::try_parse_with_order(reply, order)?.0)),
+                    xprint::PRINT_GET_IMAGE_RESOLUTION_REQUEST => Ok(Reply::XprintPrintGetImageResolution(xprint::PrintGetImageResolutionReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xselinux")]
+            Some((xselinux::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xselinux::QUERY_VERSION_REQUEST => Ok(Reply::XselinuxQueryVersion(xselinux::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_DEVICE_CREATE_CONTEXT_REQUEST => Ok(Reply::XselinuxGetDeviceCreateContext(xselinux::GetDeviceCreateContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_DEVICE_CONTEXT_REQUEST => Ok(Reply::XselinuxGetDeviceContext(xselinux::GetDeviceContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_WINDOW_CREATE_CONTEXT_REQUEST => Ok(Reply::XselinuxGetWindowCreateContext(xselinux::GetWindowCreateContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_WINDOW_CONTEXT_REQUEST => Ok(Reply::XselinuxGetWindowContext(xselinux::GetWindowContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_PROPERTY_CREATE_CONTEXT_REQUEST => Ok(Reply::XselinuxGetPropertyCreateContext(xselinux::GetPropertyCreateContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_PROPERTY_USE_CONTEXT_REQUEST => Ok(Reply::XselinuxGetPropertyUseContext(xselinux::GetPropertyUseContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_PROPERTY_CONTEXT_REQUEST => Ok(Reply::XselinuxGetPropertyContext(xselinux::GetPropertyContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_PROPERTY_DATA_CONTEXT_REQUEST => Ok(Reply::XselinuxGetPropertyDataContext(xselinux::GetPropertyDataContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::LIST_PROPERTIES_REQUEST => Ok(Reply::XselinuxListProperties(xselinux::ListPropertiesReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_SELECTION_CREATE_CONTEXT_REQUEST => Ok(Reply::XselinuxGetSelectionCreateContext(xselinux::GetSelectionCreateContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_SELECTION_USE_CONTEXT_REQUEST => Ok(Reply::XselinuxGetSelectionUseContext(xselinux::GetSelectionUseContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_SELECTION_CONTEXT_REQUEST => Ok(Reply::XselinuxGetSelectionContext(xselinux::GetSelectionContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_SELECTION_DATA_CONTEXT_REQUEST => Ok(Reply::XselinuxGetSelectionDataContext(xselinux::GetSelectionDataContextReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::LIST_SELECTIONS_REQUEST => Ok(Reply::XselinuxListSelections(xselinux::ListSelectionsReply::try_parse_with_order(reply, order)?.0)),
+                    xselinux::GET_CLIENT_CONTEXT_REQUEST => Ok(Reply::XselinuxGetClientContext(xselinux::GetClientContextReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xtest")]
+            Some((xtest::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xtest::GET_VERSION_REQUEST => Ok(Reply::XtestGetVersion(xtest::GetVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xtest::COMPARE_CURSOR_REQUEST => Ok(Reply::XtestCompareCursor(xtest::CompareCursorReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xv")]
+            Some((xv::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xv::QUERY_EXTENSION_REQUEST => Ok(Reply::XvQueryExtension(xv::QueryExtensionReply::try_parse_with_order(reply, order)?.0)),
+                    xv::QUERY_ADAPTORS_REQUEST => Ok(Reply::XvQueryAdaptors(xv::QueryAdaptorsReply::try_parse_with_order(reply, order)?.0)),
+                    xv::QUERY_ENCODINGS_REQUEST => Ok(Reply::XvQueryEncodings(xv::QueryEncodingsReply::try_parse_with_order(reply, order)?.0)),
+                    xv::GRAB_PORT_REQUEST => Ok(Reply::XvGrabPort(xv::GrabPortReply::try_parse_with_order(reply, order)?.0)),
+                    xv::QUERY_BEST_SIZE_REQUEST => Ok(Reply::XvQueryBestSize(xv::QueryBestSizeReply::try_parse_with_order(reply, order)?.0)),
+                    xv::GET_PORT_ATTRIBUTE_REQUEST => Ok(Reply::XvGetPortAttribute(xv::GetPortAttributeReply::try_parse_with_order(reply, order)?.0)),
+                    xv::QUERY_PORT_ATTRIBUTES_REQUEST => Ok(Reply::XvQueryPortAttributes(xv::QueryPortAttributesReply::try_parse_with_order(reply, order)?.0)),
+                    xv::LIST_IMAGE_FORMATS_REQUEST => Ok(Reply::XvListImageFormats(xv::ListImageFormatsReply::try_parse_with_order(reply, order)?.0)),
+                    xv::QUERY_IMAGE_ATTRIBUTES_REQUEST => Ok(Reply::XvQueryImageAttributes(xv::QueryImageAttributesReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            #[cfg(feature = "xvmc")]
+            Some((xvmc::X11_EXTENSION_NAME, _)) => {
+                match minor_opcode {
+                    xvmc::QUERY_VERSION_REQUEST => Ok(Reply::XvmcQueryVersion(xvmc::QueryVersionReply::try_parse_with_order(reply, order)?.0)),
+                    xvmc::LIST_SURFACE_TYPES_REQUEST => Ok(Reply::XvmcListSurfaceTypes(xvmc::ListSurfaceTypesReply::try_parse_with_order(reply, order)?.0)),
+                    xvmc::CREATE_CONTEXT_REQUEST => Ok(Reply::XvmcCreateContext(xvmc::CreateContextReply::try_parse_with_order(reply, order)?.0)),
+                    xvmc::CREATE_SURFACE_REQUEST => Ok(Reply::XvmcCreateSurface(xvmc::CreateSurfaceReply::try_parse_with_order(reply, order)?.0)),
+                    xvmc::CREATE_SUBPICTURE_REQUEST => Ok(Reply::XvmcCreateSubpicture(xvmc::CreateSubpictureReply::try_parse_with_order(reply, order)?.0)),
+                    xvmc::LIST_SUBPICTURE_TYPES_REQUEST => Ok(Reply::XvmcListSubpictureTypes(xvmc::ListSubpictureTypesReply::try_parse_with_order(reply, order)?.0)),
+                    _ => Ok(Reply::Unknown(reply.to_vec())),
+                }
+            }
+            _ => Ok(Reply::Unknown(reply.to_vec())),
+        }
+    }
 }
@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -34,424 +34,204 @@ pub const X11_EXTENSION_NAME: &str = "Present";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 2);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum EventEnum {
-    ConfigureNotify = 0,
-    CompleteNotify = 1,
-    IdleNotify = 2,
-    RedirectNotify = 3,
-}
-impl From<EventEnum> for u8 {
-    fn from(input: EventEnum) -> Self {
-        match input {
-            EventEnum::ConfigureNotify => 0,
-            EventEnum::CompleteNotify => 1,
-            EventEnum::IdleNotify => 2,
-            EventEnum::RedirectNotify => 3,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EventEnum(u8);
+#[allow(non_upper_case_globals)]
+impl EventEnum {
+    pub const ConfigureNotify: Self = Self(0);
+    pub const CompleteNotify: Self = Self(1);
+    pub const IdleNotify: Self = Self(2);
+    pub const RedirectNotify: Self = Self(3);
+}
+enum_newtype!(EventEnum, u8, [ConfigureNotify, CompleteNotify, IdleNotify, RedirectNotify]);
 impl From<EventEnum> for std::option::Option<u8> {
     fn from(input: EventEnum) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<EventEnum> for u16 {
     fn from(input: EventEnum) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<EventEnum> for std::option::Option<u16> {
     fn from(input: EventEnum) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<EventEnum> for u32 {
     fn from(input: EventEnum) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<EventEnum> for std::option::Option<u32> {
     fn from(input: EventEnum) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for EventEnum {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(EventEnum::ConfigureNotify),
-            1 => Ok(EventEnum::CompleteNotify),
-            2 => Ok(EventEnum::IdleNotify),
-            3 => Ok(EventEnum::RedirectNotify),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for EventEnum {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for EventEnum {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum EventMask {
-    NoEvent = 0,
-    ConfigureNotify = 1 << 0,
-    CompleteNotify = 1 << 1,
-    IdleNotify = 1 << 2,
-    RedirectNotify = 1 << 3,
-}
-impl From<EventMask> for u8 {
-    fn from(input: EventMask) -> Self {
-        match input {
-            EventMask::NoEvent => 0,
-            EventMask::ConfigureNotify => 1 << 0,
-            EventMask::CompleteNotify => 1 << 1,
-            EventMask::IdleNotify => 1 << 2,
-            EventMask::RedirectNotify => 1 << 3,
-        }
-    }
-}
-impl From<EventMask> for std::option::Option<u8> {
-    fn from(input: EventMask) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<EventMask> for u16 {
-    fn from(input: EventMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<EventMask> for std::option::Option<u16> {
-    fn from(input: EventMask) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<EventMask> for u32 {
-    fn from(input: EventMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EventMask(u32);
+#[allow(non_upper_case_globals)]
+impl EventMask {
+    pub const NoEvent: Self = Self(0);
+    pub const ConfigureNotify: Self = Self(1 << 0);
+    pub const CompleteNotify: Self = Self(1 << 1);
+    pub const IdleNotify: Self = Self(1 << 2);
+    pub const RedirectNotify: Self = Self(1 << 3);
+}
+bitmask_set!(EventMask, u32, [ConfigureNotify, CompleteNotify, IdleNotify, RedirectNotify]);
 impl From<EventMask> for std::option::Option<u32> {
     fn from(input: EventMask) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for EventMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(EventMask::NoEvent),
-            1 => Ok(EventMask::ConfigureNotify),
-            2 => Ok(EventMask::CompleteNotify),
-            4 => Ok(EventMask::IdleNotify),
-            8 => Ok(EventMask::RedirectNotify),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for EventMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for EventMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-bitmask_binop!(EventMask, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-pub enum Option {
-    None = 0,
-    Async = 1 << 0,
-    Copy = 1 << 1,
-    UST = 1 << 2,
-    Suboptimal = 1 << 3,
-}
-impl From<Option> for u8 {
-    fn from(input: Option) -> Self {
-        match input {
-            Option::None => 0,
-            Option::Async => 1 << 0,
-            Option::Copy => 1 << 1,
-            Option::UST => 1 << 2,
-            Option::Suboptimal => 1 << 3,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Option(u8);
+#[allow(non_upper_case_globals)]
+impl Option {
+    pub const None: Self = Self(0);
+    pub const Async: Self = Self(1 << 0);
+    pub const Copy: Self = Self(1 << 1);
+    pub const UST: Self = Self(1 << 2);
+    pub const Suboptimal: Self = Self(1 << 3);
+}
+bitmask_set!(Option, u8, [Async, Copy, UST, Suboptimal]);
 impl From<Option> for std::option::Option<u8> {
     fn from(input: Option) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Option> for u16 {
     fn from(input: Option) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Option> for std::option::Option<u16> {
     fn from(input: Option) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Option> for u32 {
     fn from(input: Option) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Option> for std::option::Option<u32> {
     fn from(input: Option) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for Option {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Option::None),
-            1 => Ok(Option::Async),
-            2 => Ok(Option::Copy),
-            4 => Ok(Option::UST),
-            8 => Ok(Option::Suboptimal),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Option {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Option {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(Option, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-pub enum Capability {
-    None = 0,
-    Async = 1 << 0,
-    Fence = 1 << 1,
-    UST = 1 << 2,
-}
-impl From<Capability> for u8 {
-    fn from(input: Capability) -> Self {
-        match input {
-            Capability::None => 0,
-            Capability::Async => 1 << 0,
-            Capability::Fence => 1 << 1,
-            Capability::UST => 1 << 2,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Capability(u8);
+#[allow(non_upper_case_globals)]
+impl Capability {
+    pub const None: Self = Self(0);
+    pub const Async: Self = Self(1 << 0);
+    pub const Fence: Self = Self(1 << 1);
+    pub const UST: Self = Self(1 << 2);
+}
+bitmask_set!(Capability, u8, [Async, Fence, UST]);
 impl From<Capability> for std::option::Option<u8> {
     fn from(input: Capability) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Capability> for u16 {
     fn from(input: Capability) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Capability> for std::option::Option<u16> {
     fn from(input: Capability) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Capability> for u32 {
     fn from(input: Capability) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Capability> for std::option::Option<u32> {
     fn from(input: Capability) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Capability {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Capability::None),
-            1 => Ok(Capability::Async),
-            2 => Ok(Capability::Fence),
-            4 => Ok(Capability::UST),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Capability {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u32> for Capability {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(Capability, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum CompleteKind {
-    Pixmap = 0,
-    NotifyMSC = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CompleteKind(u8);
+#[allow(non_upper_case_globals)]
+impl CompleteKind {
+    pub const Pixmap: Self = Self(0);
+    pub const NotifyMSC: Self = Self(1);
+}
+enum_newtype!(CompleteKind, u8, [Pixmap, NotifyMSC]);
 impl From<CompleteKind> for bool {
     fn from(input: CompleteKind) -> Self {
-        match input {
-            CompleteKind::Pixmap => false,
-            CompleteKind::NotifyMSC => true,
-        }
-    }
-}
-impl From<CompleteKind> for u8 {
-    fn from(input: CompleteKind) -> Self {
-        match input {
-            CompleteKind::Pixmap => 0,
-            CompleteKind::NotifyMSC => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<CompleteKind> for std::option::Option<u8> {
     fn from(input: CompleteKind) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<CompleteKind> for u16 {
     fn from(input: CompleteKind) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CompleteKind> for std::option::Option<u16> {
     fn from(input: CompleteKind) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<CompleteKind> for u32 {
     fn from(input: CompleteKind) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CompleteKind> for std::option::Option<u32> {
     fn from(input: CompleteKind) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for CompleteKind {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(CompleteKind::Pixmap),
-            1 => Ok(CompleteKind::NotifyMSC),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for CompleteKind {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for CompleteKind {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum CompleteMode {
-    Copy = 0,
-    Flip = 1,
-    Skip = 2,
-    SuboptimalCopy = 3,
-}
-impl From<CompleteMode> for u8 {
-    fn from(input: CompleteMode) -> Self {
-        match input {
-            CompleteMode::Copy => 0,
-            CompleteMode::Flip => 1,
-            CompleteMode::Skip => 2,
-            CompleteMode::SuboptimalCopy => 3,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CompleteMode(u8);
+#[allow(non_upper_case_globals)]
+impl CompleteMode {
+    pub const Copy: Self = Self(0);
+    pub const Flip: Self = Self(1);
+    pub const Skip: Self = Self(2);
+    pub const SuboptimalCopy: Self = Self(3);
+}
+enum_newtype!(CompleteMode, u8, [Copy, Flip, Skip, SuboptimalCopy]);
 impl From<CompleteMode> for std::option::Option<u8> {
     fn from(input: CompleteMode) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<CompleteMode> for u16 {
     fn from(input: CompleteMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CompleteMode> for std::option::Option<u16> {
     fn from(input: CompleteMode) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<CompleteMode> for u32 {
     fn from(input: CompleteMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CompleteMode> for std::option::Option<u32> {
     fn from(input: CompleteMode) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for CompleteMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(CompleteMode::Copy),
-            1 => Ok(CompleteMode::Flip),
-            2 => Ok(CompleteMode::Skip),
-            3 => Ok(CompleteMode::SuboptimalCopy),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for CompleteMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for CompleteMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -461,9 +241,9 @@ pub struct Notify {
     pub serial: u32,
 }
 impl TryParse for Notify {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = Notify { window, serial };
         Ok((result, remaining))
     }
@@ -476,9 +256,9 @@ impl TryFrom<&[u8]> for Notify {
 }
 impl Serialize for Notify {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let window_bytes = self.window.serialize();
-        let serial_bytes = self.serial.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let window_bytes = self.window.serialize_with_order(order);
+        let serial_bytes = self.serial.serialize_with_order(order);
         [
             window_bytes[0],
             window_bytes[1],
@@ -490,24 +270,58 @@ impl Serialize for Notify {
             serial_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.window.serialize_into(bytes);
-        self.serial.serialize_into(bytes);
+        self.window.serialize_into_with_order(bytes, order);
+        self.serial.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u32) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u32) -> Self {
+        self.minor_version = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -525,9 +339,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.major_version, self.minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -538,13 +364,13 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
     }
@@ -555,30 +381,200 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Pixmap request
 pub const PIXMAP_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PixmapRequest {
+    pub window: xproto::Window,
+    pub pixmap: xproto::Pixmap,
+    pub serial: u32,
+    pub valid: xfixes::Region,
+    pub update: xfixes::Region,
+    pub x_off: i16,
+    pub y_off: i16,
+    pub target_crtc: randr::Crtc,
+    pub wait_fence: sync::Fence,
+    pub idle_fence: sync::Fence,
+    pub options: u32,
+    pub target_msc: u64,
+    pub divisor: u64,
+    pub remainder: u64,
+    pub notifies: Vec<Notify>,
+}
+impl PixmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (valid, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (update, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (x_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (target_crtc, remaining) = randr::Crtc::try_parse_with_order(remaining, order)?;
+        let (wait_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
+        let (idle_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
+        let (options, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
+        let (target_msc, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (divisor, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (remainder, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut notifies = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Notify::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            notifies.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(PixmapRequest {
+            window,
+            pixmap,
+            serial,
+            valid,
+            update,
+            x_off,
+            y_off,
+            target_crtc,
+            wait_fence,
+            idle_fence,
+            options,
+            target_msc,
+            divisor,
+            remainder,
+            notifies,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `pixmap` field of this structure.
+    pub fn pixmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.pixmap = value.into();
+        self
+    }
+    /// Set the `serial` field of this structure.
+    pub fn serial(mut self, value: u32) -> Self {
+        self.serial = value;
+        self
+    }
+    /// Set the `valid` field of this structure.
+    pub fn valid<I>(mut self, value: I) -> Self where I: Into<xfixes::Region> {
+        self.valid = value.into();
+        self
+    }
+    /// Set the `update` field of this structure.
+    pub fn update<I>(mut self, value: I) -> Self where I: Into<xfixes::Region> {
+        self.update = value.into();
+        self
+    }
+    /// Set the `x_off` field of this structure.
+    pub fn x_off(mut self, value: i16) -> Self {
+        self.x_off = value;
+        self
+    }
+    /// Set the `y_off` field of this structure.
+    pub fn y_off(mut self, value: i16) -> Self {
+        self.y_off = value;
+        self
+    }
+    /// Set the `target_crtc` field of this structure.
+    pub fn target_crtc<I>(mut self, value: I) -> Self where I: Into<randr::Crtc> {
+        self.target_crtc = value.into();
+        self
+    }
+    /// Set the `wait_fence` field of this structure.
+    pub fn wait_fence<I>(mut self, value: I) -> Self where I: Into<sync::Fence> {
+        self.wait_fence = value.into();
+        self
+    }
+    /// Set the `idle_fence` field of this structure.
+    pub fn idle_fence<I>(mut self, value: I) -> Self where I: Into<sync::Fence> {
+        self.idle_fence = value.into();
+        self
+    }
+    /// Set the `options` field of this structure.
+    pub fn options(mut self, value: u32) -> Self {
+        self.options = value;
+        self
+    }
+    /// Set the `target_msc` field of this structure.
+    pub fn target_msc(mut self, value: u64) -> Self {
+        self.target_msc = value;
+        self
+    }
+    /// Set the `divisor` field of this structure.
+    pub fn divisor(mut self, value: u64) -> Self {
+        self.divisor = value;
+        self
+    }
+    /// Set the `remainder` field of this structure.
+    pub fn remainder(mut self, value: u64) -> Self {
+        self.remainder = value;
+        self
+    }
+    /// Set the `notifies` field of this structure.
+    pub fn notifies<I>(mut self, value: I) -> Self where I: Into<Vec<Notify>> {
+        self.notifies = value.into();
+        self
+    }
+}
+
 pub fn pixmap<'c, Conn>(conn: &'c Conn, window: xproto::Window, pixmap: xproto::Pixmap, serial: u32, valid: xfixes::Region, update: xfixes::Region, x_off: i16, y_off: i16, target_crtc: randr::Crtc, wait_fence: sync::Fence, idle_fence: sync::Fence, options: u32, target_msc: u64, divisor: u64, remainder: u64, notifies: &[Notify]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let pixmap_bytes = pixmap.serialize();
-    let serial_bytes = serial.serialize();
-    let valid_bytes = valid.serialize();
-    let update_bytes = update.serialize();
-    let x_off_bytes = x_off.serialize();
-    let y_off_bytes = y_off.serialize();
-    let target_crtc_bytes = target_crtc.serialize();
-    let wait_fence_bytes = wait_fence.serialize();
-    let idle_fence_bytes = idle_fence.serialize();
-    let options_bytes = options.serialize();
-    let target_msc_bytes = target_msc.serialize();
-    let divisor_bytes = divisor.serialize();
-    let remainder_bytes = remainder.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let pixmap_bytes = pixmap.serialize_with_order(order);
+    let serial_bytes = serial.serialize_with_order(order);
+    let valid_bytes = valid.serialize_with_order(order);
+    let update_bytes = update.serialize_with_order(order);
+    let x_off_bytes = x_off.serialize_with_order(order);
+    let y_off_bytes = y_off.serialize_with_order(order);
+    let target_crtc_bytes = target_crtc.serialize_with_order(order);
+    let wait_fence_bytes = wait_fence.serialize_with_order(order);
+    let idle_fence_bytes = idle_fence.serialize_with_order(order);
+    let options_bytes = options.serialize_with_order(order);
+    let target_msc_bytes = target_msc.serialize_with_order(order);
+    let divisor_bytes = divisor.serialize_with_order(order);
+    let remainder_bytes = remainder.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PIXMAP_REQUEST,
@@ -654,30 +650,101 @@ where
         remainder_bytes[7],
     ];
     let length_so_far = length_so_far + request0.len();
-    let notifies_bytes = notifies.serialize();
+    let notifies_bytes = notifies.serialize_with_order(order);
     let length_so_far = length_so_far + notifies_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&notifies_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for PixmapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PixmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        pixmap(conn, self.window, self.pixmap, self.serial, self.valid, self.update, self.x_off, self.y_off, self.target_crtc, self.wait_fence, self.idle_fence, self.options, self.target_msc, self.divisor, self.remainder, &self.notifies)
+    }
+}
 
 /// Opcode for the NotifyMSC request
 pub const NOTIFY_MSC_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NotifyMSCRequest {
+    pub window: xproto::Window,
+    pub serial: u32,
+    pub target_msc: u64,
+    pub divisor: u64,
+    pub remainder: u64,
+}
+impl NotifyMSCRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != NOTIFY_MSC_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
+        let (target_msc, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (divisor, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (remainder, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(NotifyMSCRequest {
+            window,
+            serial,
+            target_msc,
+            divisor,
+            remainder,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `serial` field of this structure.
+    pub fn serial(mut self, value: u32) -> Self {
+        self.serial = value;
+        self
+    }
+    /// Set the `target_msc` field of this structure.
+    pub fn target_msc(mut self, value: u64) -> Self {
+        self.target_msc = value;
+        self
+    }
+    /// Set the `divisor` field of this structure.
+    pub fn divisor(mut self, value: u64) -> Self {
+        self.divisor = value;
+        self
+    }
+    /// Set the `remainder` field of this structure.
+    pub fn remainder(mut self, value: u64) -> Self {
+        self.remainder = value;
+        self
+    }
+}
+
 pub fn notify_msc<Conn>(conn: &Conn, window: xproto::Window, serial: u32, target_msc: u64, divisor: u64, remainder: u64) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let serial_bytes = serial.serialize();
-    let target_msc_bytes = target_msc.serialize();
-    let divisor_bytes = divisor.serialize();
-    let remainder_bytes = remainder.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let serial_bytes = serial.serialize_with_order(order);
+    let target_msc_bytes = target_msc.serialize_with_order(order);
+    let divisor_bytes = divisor.serialize_with_order(order);
+    let remainder_bytes = remainder.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         NOTIFY_MSC_REQUEST,
@@ -723,26 +790,111 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for NotifyMSCRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for NotifyMSCRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        notify_msc(conn, self.window, self.serial, self.target_msc, self.divisor, self.remainder)
+    }
+}
 
-pub type Event = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Event(u32);
+impl Event {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Event(0);
+}
+impl From<u32> for Event {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Event> for u32 {
+    fn from(value: Event) -> Self {
+        value.0
+    }
+}
+impl TryParse for Event {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Event {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
 /// Opcode for the SelectInput request
 pub const SELECT_INPUT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectInputRequest {
+    pub eid: Event,
+    pub window: xproto::Window,
+    pub event_mask: EventMask,
+}
+impl SelectInputRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_INPUT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (eid, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let event_mask = EventMask::from(event_mask);
+        Ok(SelectInputRequest {
+            eid,
+            window,
+            event_mask,
+        })
+    }
+    /// Set the `eid` field of this structure.
+    pub fn eid<I>(mut self, value: I) -> Self where I: Into<Event> {
+        self.eid = value.into();
+        self
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `event_mask` field of this structure.
+    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<EventMask> {
+        self.event_mask = value.into();
+        self
+    }
+}
+
 pub fn select_input<Conn, A>(conn: &Conn, eid: Event, window: xproto::Window, event_mask: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<EventMask>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let event_mask: u32 = event_mask.into();
+    let event_mask: EventMask = event_mask.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let eid_bytes = eid.serialize();
-    let window_bytes = window.serialize();
-    let event_mask_bytes = event_mask.serialize();
+    let eid_bytes = eid.serialize_with_order(order);
+    let window_bytes = window.serialize_with_order(order);
+    let event_mask_bytes = u32::from(event_mask).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_INPUT_REQUEST,
@@ -764,20 +916,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectInputRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectInputRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_input(conn, self.eid, self.window, self.event_mask)
+    }
+}
 
 /// Opcode for the QueryCapabilities request
 pub const QUERY_CAPABILITIES_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryCapabilitiesRequest {
+    pub target: u32,
+}
+impl QueryCapabilitiesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CAPABILITIES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryCapabilitiesRequest {
+            target,
+        })
+    }
+    /// Set the `target` field of this structure.
+    pub fn target(mut self, value: u32) -> Self {
+        self.target = value;
+        self
+    }
+}
+
 pub fn query_capabilities<Conn>(conn: &Conn, target: u32) -> Result<Cookie<'_, Conn, QueryCapabilitiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let target_bytes = target.serialize();
+    let target_bytes = target.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_CAPABILITIES_REQUEST,
@@ -791,9 +981,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryCapabilitiesRequest {
+    type Reply = QueryCapabilitiesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryCapabilitiesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryCapabilitiesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_capabilities(conn, self.target)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryCapabilitiesReply {
@@ -803,12 +1005,12 @@ pub struct QueryCapabilitiesReply {
     pub capabilities: u32,
 }
 impl TryParse for QueryCapabilitiesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (capabilities, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (capabilities, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = QueryCapabilitiesReply { response_type, sequence, length, capabilities };
         Ok((result, remaining))
     }
@@ -819,6 +1021,29 @@ impl TryFrom<&[u8]> for QueryCapabilitiesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryCapabilitiesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.capabilities.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Generic event
 pub const GENERIC_EVENT: u8 = 0;
@@ -832,14 +1057,14 @@ pub struct GenericEvent {
     pub event: Event,
 }
 impl TryParse for GenericEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (evtype, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (evtype, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
         let result = GenericEvent { response_type, extension, sequence, length, evtype, event };
         Ok((result, remaining))
     }
@@ -852,12 +1077,23 @@ impl TryFrom<&[u8]> for GenericEvent {
 }
 impl From<&GenericEvent> for [u8; 32] {
     fn from(input: &GenericEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let extension_bytes = input.extension.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let length_bytes = input.length.serialize();
-        let evtype_bytes = input.evtype.serialize();
-        let event_bytes = input.event.serialize();
+        input.serialize()
+    }
+}
+impl From<GenericEvent> for [u8; 32] {
+    fn from(input: GenericEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for GenericEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let extension_bytes = self.extension.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let length_bytes = self.length.serialize_with_order(order);
+        let evtype_bytes = self.evtype.serialize_with_order(order);
+        let event_bytes = self.event.serialize_with_order(order);
         [
             response_type_bytes[0],
             extension_bytes[0],
@@ -894,10 +1130,8 @@ impl From<&GenericEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<GenericEvent> for [u8; 32] {
-    fn from(input: GenericEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -923,24 +1157,24 @@ pub struct ConfigureNotifyEvent {
     pub pixmap_flags: u32,
 }
 impl TryParse for ConfigureNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (off_x, remaining) = i16::try_parse(remaining)?;
-        let (off_y, remaining) = i16::try_parse(remaining)?;
-        let (pixmap_width, remaining) = u16::try_parse(remaining)?;
-        let (pixmap_height, remaining) = u16::try_parse(remaining)?;
-        let (pixmap_flags, remaining) = u32::try_parse(remaining)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (off_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (off_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (pixmap_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pixmap_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pixmap_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = ConfigureNotifyEvent { response_type, extension, sequence, length, event_type, event, window, x, y, width, height, off_x, off_y, pixmap_width, pixmap_height, pixmap_flags };
         Ok((result, remaining))
     }
@@ -951,6 +1185,41 @@ impl TryFrom<&[u8]> for ConfigureNotifyEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ConfigureNotifyEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.event.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.off_x.serialize_into_with_order(bytes, order);
+        self.off_y.serialize_into_with_order(bytes, order);
+        self.pixmap_width.serialize_into_with_order(bytes, order);
+        self.pixmap_height.serialize_into_with_order(bytes, order);
+        self.pixmap_flags.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CompleteNotify event
 pub const COMPLETE_NOTIFY_EVENT: u16 = 1;
@@ -970,21 +1239,21 @@ pub struct CompleteNotifyEvent {
     pub msc: u64,
 }
 impl TryParse for CompleteNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
-        let (ust, remaining) = u64::try_parse(remaining)?;
-        let (msc, remaining) = u64::try_parse(remaining)?;
-        let kind = kind.try_into()?;
-        let mode = mode.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (msc, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let kind = CompleteKind::from(kind);
+        let mode = CompleteMode::from(mode);
         let result = CompleteNotifyEvent { response_type, extension, sequence, length, event_type, kind, mode, event, window, serial, ust, msc };
         Ok((result, remaining))
     }
@@ -995,6 +1264,36 @@ impl TryFrom<&[u8]> for CompleteNotifyEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CompleteNotifyEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        u8::from(self.kind).serialize_into_with_order(bytes, order);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
+        self.event.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.serial.serialize_into_with_order(bytes, order);
+        self.ust.serialize_into_with_order(bytes, order);
+        self.msc.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the IdleNotify event
 pub const IDLE_NOTIFY_EVENT: u16 = 2;
@@ -1012,18 +1311,18 @@ pub struct IdleNotifyEvent {
     pub idle_fence: sync::Fence,
 }
 impl TryParse for IdleNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
-        let (idle_fence, remaining) = sync::Fence::try_parse(remaining)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (idle_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
         let result = IdleNotifyEvent { response_type, extension, sequence, length, event_type, event, window, serial, pixmap, idle_fence };
         Ok((result, remaining))
     }
@@ -1034,6 +1333,35 @@ impl TryFrom<&[u8]> for IdleNotifyEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for IdleNotifyEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.event.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.serial.serialize_into_with_order(bytes, order);
+        self.pixmap.serialize_into_with_order(bytes, order);
+        self.idle_fence.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the RedirectNotify event
 pub const REDIRECT_NOTIFY_EVENT: u16 = 3;
@@ -1066,38 +1394,38 @@ pub struct RedirectNotifyEvent {
     pub notifies: Vec<Notify>,
 }
 impl TryParse for RedirectNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (update_window, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (update_window, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
-        let (event_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
-        let (valid_region, remaining) = xfixes::Region::try_parse(remaining)?;
-        let (update_region, remaining) = xfixes::Region::try_parse(remaining)?;
-        let (valid_rect, remaining) = xproto::Rectangle::try_parse(remaining)?;
-        let (update_rect, remaining) = xproto::Rectangle::try_parse(remaining)?;
-        let (x_off, remaining) = i16::try_parse(remaining)?;
-        let (y_off, remaining) = i16::try_parse(remaining)?;
-        let (target_crtc, remaining) = randr::Crtc::try_parse(remaining)?;
-        let (wait_fence, remaining) = sync::Fence::try_parse(remaining)?;
-        let (idle_fence, remaining) = sync::Fence::try_parse(remaining)?;
-        let (options, remaining) = u32::try_parse(remaining)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let (event_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (valid_region, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (update_region, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (valid_rect, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let (update_rect, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let (x_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (target_crtc, remaining) = randr::Crtc::try_parse_with_order(remaining, order)?;
+        let (wait_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
+        let (idle_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
+        let (options, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (target_msc, remaining) = u64::try_parse(remaining)?;
-        let (divisor, remaining) = u64::try_parse(remaining)?;
-        let (remainder, remaining) = u64::try_parse(remaining)?;
+        let (target_msc, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (divisor, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (remainder, remaining) = u64::try_parse_with_order(remaining, order)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut notifies = Vec::new();
         while !remaining.is_empty() {
-            let (v, new_remaining) = Notify::try_parse(remaining)?;
+            let (v, new_remaining) = Notify::try_parse_with_order(remaining, order)?;
             remaining = new_remaining;
             notifies.push(v);
         }
@@ -1111,6 +1439,51 @@ impl TryFrom<&[u8]> for RedirectNotifyEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for RedirectNotifyEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.update_window.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.event.serialize_into_with_order(bytes, order);
+        self.event_window.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.pixmap.serialize_into_with_order(bytes, order);
+        self.serial.serialize_into_with_order(bytes, order);
+        self.valid_region.serialize_into_with_order(bytes, order);
+        self.update_region.serialize_into_with_order(bytes, order);
+        self.valid_rect.serialize_into_with_order(bytes, order);
+        self.update_rect.serialize_into_with_order(bytes, order);
+        self.x_off.serialize_into_with_order(bytes, order);
+        self.y_off.serialize_into_with_order(bytes, order);
+        self.target_crtc.serialize_into_with_order(bytes, order);
+        self.wait_fence.serialize_into_with_order(bytes, order);
+        self.idle_fence.serialize_into_with_order(bytes, order);
+        self.options.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.target_msc.serialize_into_with_order(bytes, order);
+        self.divisor.serialize_into_with_order(bytes, order);
+        self.remainder.serialize_into_with_order(bytes, order);
+        self.notifies.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -1128,7 +1501,7 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn present_select_input<A>(&self, eid: Event, window: xproto::Window, event_mask: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<EventMask>,
     {
         select_input(self, eid, window, event_mask)
     }
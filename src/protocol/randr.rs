@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -32,15 +32,165 @@ pub const X11_EXTENSION_NAME: &str = "RANDR";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 6);
 
-pub type Mode = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mode(u32);
+impl Mode {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Mode(0);
+}
+impl From<u32> for Mode {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Mode> for u32 {
+    fn from(value: Mode) -> Self {
+        value.0
+    }
+}
+impl TryParse for Mode {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Mode {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Crtc = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Crtc(u32);
+impl Crtc {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Crtc(0);
+}
+impl From<u32> for Crtc {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Crtc> for u32 {
+    fn from(value: Crtc) -> Self {
+        value.0
+    }
+}
+impl TryParse for Crtc {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Crtc {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Output = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Output(u32);
+impl Output {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Output(0);
+}
+impl From<u32> for Output {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Output> for u32 {
+    fn from(value: Output) -> Self {
+        value.0
+    }
+}
+impl TryParse for Output {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Output {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Provider = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Provider(u32);
+impl Provider {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Provider(0);
+}
+impl From<u32> for Provider {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Provider> for u32 {
+    fn from(value: Provider) -> Self {
+        value.0
+    }
+}
+impl TryParse for Provider {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Provider {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Lease = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lease(u32);
+impl Lease {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Lease(0);
+}
+impl From<u32> for Lease {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Lease> for u32 {
+    fn from(value: Lease) -> Self {
+        value.0
+    }
+}
+impl TryParse for Lease {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Lease {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
 /// Opcode for the BadOutput error
 pub const BAD_OUTPUT_ERROR: u8 = 0;
@@ -51,10 +201,10 @@ pub struct BadOutputError {
     pub sequence: u16,
 }
 impl TryParse for BadOutputError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadOutputError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -67,9 +217,20 @@ impl TryFrom<&[u8]> for BadOutputError {
 }
 impl From<&BadOutputError> for [u8; 32] {
     fn from(input: &BadOutputError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadOutputError> for [u8; 32] {
+    fn from(input: BadOutputError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadOutputError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -106,10 +267,8 @@ impl From<&BadOutputError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadOutputError> for [u8; 32] {
-    fn from(input: BadOutputError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -122,10 +281,10 @@ pub struct BadCrtcError {
     pub sequence: u16,
 }
 impl TryParse for BadCrtcError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadCrtcError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -138,9 +297,20 @@ impl TryFrom<&[u8]> for BadCrtcError {
 }
 impl From<&BadCrtcError> for [u8; 32] {
     fn from(input: &BadCrtcError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadCrtcError> for [u8; 32] {
+    fn from(input: BadCrtcError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadCrtcError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -177,10 +347,8 @@ impl From<&BadCrtcError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadCrtcError> for [u8; 32] {
-    fn from(input: BadCrtcError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -193,10 +361,10 @@ pub struct BadModeError {
     pub sequence: u16,
 }
 impl TryParse for BadModeError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadModeError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -209,9 +377,20 @@ impl TryFrom<&[u8]> for BadModeError {
 }
 impl From<&BadModeError> for [u8; 32] {
     fn from(input: &BadModeError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadModeError> for [u8; 32] {
+    fn from(input: BadModeError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadModeError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -248,10 +427,8 @@ impl From<&BadModeError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadModeError> for [u8; 32] {
-    fn from(input: BadModeError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -264,10 +441,10 @@ pub struct BadProviderError {
     pub sequence: u16,
 }
 impl TryParse for BadProviderError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadProviderError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -280,9 +457,20 @@ impl TryFrom<&[u8]> for BadProviderError {
 }
 impl From<&BadProviderError> for [u8; 32] {
     fn from(input: &BadProviderError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadProviderError> for [u8; 32] {
+    fn from(input: BadProviderError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadProviderError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -319,87 +507,38 @@ impl From<&BadProviderError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadProviderError> for [u8; 32] {
-    fn from(input: BadProviderError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Rotation {
-    Rotate0 = 1 << 0,
-    Rotate90 = 1 << 1,
-    Rotate180 = 1 << 2,
-    Rotate270 = 1 << 3,
-    ReflectX = 1 << 4,
-    ReflectY = 1 << 5,
-}
-impl From<Rotation> for u8 {
-    fn from(input: Rotation) -> Self {
-        match input {
-            Rotation::Rotate0 => 1 << 0,
-            Rotation::Rotate90 => 1 << 1,
-            Rotation::Rotate180 => 1 << 2,
-            Rotation::Rotate270 => 1 << 3,
-            Rotation::ReflectX => 1 << 4,
-            Rotation::ReflectY => 1 << 5,
-        }
-    }
-}
-impl From<Rotation> for Option<u8> {
-    fn from(input: Rotation) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<Rotation> for u16 {
-    fn from(input: Rotation) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rotation(u16);
+#[allow(non_upper_case_globals)]
+impl Rotation {
+    pub const Rotate0: Self = Self(1 << 0);
+    pub const Rotate90: Self = Self(1 << 1);
+    pub const Rotate180: Self = Self(1 << 2);
+    pub const Rotate270: Self = Self(1 << 3);
+    pub const ReflectX: Self = Self(1 << 4);
+    pub const ReflectY: Self = Self(1 << 5);
+}
+bitmask_set!(Rotation, u16, [Rotate0, Rotate90, Rotate180, Rotate270, ReflectX, ReflectY]);
 impl From<Rotation> for Option<u16> {
     fn from(input: Rotation) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<Rotation> for u32 {
     fn from(input: Rotation) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Rotation> for Option<u32> {
     fn from(input: Rotation) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for Rotation {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(Rotation::Rotate0),
-            2 => Ok(Rotation::Rotate90),
-            4 => Ok(Rotation::Rotate180),
-            8 => Ok(Rotation::Rotate270),
-            16 => Ok(Rotation::ReflectX),
-            32 => Ok(Rotation::ReflectY),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Rotation {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Rotation {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(Rotation, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ScreenSize {
@@ -409,11 +548,11 @@ pub struct ScreenSize {
     pub mheight: u16,
 }
 impl TryParse for ScreenSize {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (mwidth, remaining) = u16::try_parse(remaining)?;
-        let (mheight, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mwidth, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mheight, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = ScreenSize { width, height, mwidth, mheight };
         Ok((result, remaining))
     }
@@ -426,11 +565,11 @@ impl TryFrom<&[u8]> for ScreenSize {
 }
 impl Serialize for ScreenSize {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let width_bytes = self.width.serialize();
-        let height_bytes = self.height.serialize();
-        let mwidth_bytes = self.mwidth.serialize();
-        let mheight_bytes = self.mheight.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let mwidth_bytes = self.mwidth.serialize_with_order(order);
+        let mheight_bytes = self.mheight.serialize_with_order(order);
         [
             width_bytes[0],
             width_bytes[1],
@@ -442,12 +581,12 @@ impl Serialize for ScreenSize {
             mheight_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
-        self.mwidth.serialize_into(bytes);
-        self.mheight.serialize_into(bytes);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.mwidth.serialize_into_with_order(bytes, order);
+        self.mheight.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -456,9 +595,9 @@ pub struct RefreshRates {
     pub rates: Vec<u16>,
 }
 impl TryParse for RefreshRates {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (n_rates, remaining) = u16::try_parse(remaining)?;
-        let (rates, remaining) = crate::x11_utils::parse_list::<u16>(remaining, n_rates.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (n_rates, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rates, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, n_rates.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = RefreshRates { rates };
         Ok((result, remaining))
     }
@@ -471,29 +610,63 @@ impl TryFrom<&[u8]> for RefreshRates {
 }
 impl Serialize for RefreshRates {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let n_rates = u16::try_from(self.rates.len()).expect("`rates` has too many elements");
-        n_rates.serialize_into(bytes);
-        self.rates.serialize_into(bytes);
+        n_rates.serialize_into_with_order(bytes, order);
+        self.rates.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u32) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u32) -> Self {
+        self.minor_version = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -511,9 +684,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.major_version, self.minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -524,13 +709,13 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
@@ -542,92 +727,153 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SetConfig {
-    Success = 0,
-    InvalidConfigTime = 1,
-    InvalidTime = 2,
-    Failed = 3,
-}
-impl From<SetConfig> for u8 {
-    fn from(input: SetConfig) -> Self {
-        match input {
-            SetConfig::Success => 0,
-            SetConfig::InvalidConfigTime => 1,
-            SetConfig::InvalidTime => 2,
-            SetConfig::Failed => 3,
-        }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SetConfig(u8);
+#[allow(non_upper_case_globals)]
+impl SetConfig {
+    pub const Success: Self = Self(0);
+    pub const InvalidConfigTime: Self = Self(1);
+    pub const InvalidTime: Self = Self(2);
+    pub const Failed: Self = Self(3);
+}
+enum_newtype!(SetConfig, u8, [Success, InvalidConfigTime, InvalidTime, Failed]);
 impl From<SetConfig> for Option<u8> {
     fn from(input: SetConfig) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SetConfig> for u16 {
     fn from(input: SetConfig) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SetConfig> for Option<u16> {
     fn from(input: SetConfig) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SetConfig> for u32 {
     fn from(input: SetConfig) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SetConfig> for Option<u32> {
     fn from(input: SetConfig) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SetConfig {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SetConfig::Success),
-            1 => Ok(SetConfig::InvalidConfigTime),
-            2 => Ok(SetConfig::InvalidTime),
-            3 => Ok(SetConfig::Failed),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for SetConfig {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
+
+/// Opcode for the SetScreenConfig request
+pub const SET_SCREEN_CONFIG_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetScreenConfigRequest {
+    pub window: xproto::Window,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub size_id: u16,
+    pub rotation: Rotation,
+    pub rate: u16,
 }
-impl TryFrom<u32> for SetConfig {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl SetScreenConfigRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_SCREEN_CONFIG_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (size_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rate, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let rotation = Rotation::from(rotation);
+        Ok(SetScreenConfigRequest {
+            window,
+            timestamp,
+            config_timestamp,
+            size_id,
+            rotation,
+            rate,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `timestamp` field of this structure.
+    pub fn timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.timestamp = value.into();
+        self
+    }
+    /// Set the `config_timestamp` field of this structure.
+    pub fn config_timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.config_timestamp = value.into();
+        self
+    }
+    /// Set the `size_id` field of this structure.
+    pub fn size_id(mut self, value: u16) -> Self {
+        self.size_id = value;
+        self
+    }
+    /// Set the `rotation` field of this structure.
+    pub fn rotation<I>(mut self, value: I) -> Self where I: Into<Rotation> {
+        self.rotation = value.into();
+        self
+    }
+    /// Set the `rate` field of this structure.
+    pub fn rate(mut self, value: u16) -> Self {
+        self.rate = value;
+        self
     }
 }
 
-/// Opcode for the SetScreenConfig request
-pub const SET_SCREEN_CONFIG_REQUEST: u8 = 2;
 pub fn set_screen_config<Conn, A>(conn: &Conn, window: xproto::Window, timestamp: xproto::Timestamp, config_timestamp: xproto::Timestamp, size_id: u16, rotation: A, rate: u16) -> Result<Cookie<'_, Conn, SetScreenConfigReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
+    A: Into<Rotation>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let rotation: u16 = rotation.into();
+    let rotation: Rotation = rotation.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let timestamp_bytes = timestamp.serialize();
-    let config_timestamp_bytes = config_timestamp.serialize();
-    let size_id_bytes = size_id.serialize();
-    let rotation_bytes = rotation.serialize();
-    let rate_bytes = rate.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let timestamp_bytes = timestamp.serialize_with_order(order);
+    let config_timestamp_bytes = config_timestamp.serialize_with_order(order);
+    let size_id_bytes = size_id.serialize_with_order(order);
+    let rotation_bytes = u16::from(rotation).serialize_with_order(order);
+    let rate_bytes = rate.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_SCREEN_CONFIG_REQUEST,
@@ -657,9 +903,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetScreenConfigRequest {
+    type Reply = SetScreenConfigReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetScreenConfigRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, SetScreenConfigReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_screen_config(conn, self.window, self.timestamp, self.config_timestamp, self.size_id, self.rotation, self.rate)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetScreenConfigReply {
@@ -673,18 +931,18 @@ pub struct SetScreenConfigReply {
     pub subpixel_order: render::SubPixel,
 }
 impl TryParse for SetScreenConfigReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (new_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (subpixel_order, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (new_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (subpixel_order, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(10..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
-        let subpixel_order = subpixel_order.try_into()?;
+        let status = SetConfig::from(status);
+        let subpixel_order = render::SubPixel::from(u32::from(subpixel_order));
         let result = SetScreenConfigReply { response_type, status, sequence, length, new_timestamp, config_timestamp, root, subpixel_order };
         Ok((result, remaining))
     }
@@ -695,101 +953,113 @@ impl TryFrom<&[u8]> for SetScreenConfigReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum NotifyMask {
-    ScreenChange = 1 << 0,
-    CrtcChange = 1 << 1,
-    OutputChange = 1 << 2,
-    OutputProperty = 1 << 3,
-    ProviderChange = 1 << 4,
-    ProviderProperty = 1 << 5,
-    ResourceChange = 1 << 6,
-    Lease = 1 << 7,
-}
-impl From<NotifyMask> for u8 {
-    fn from(input: NotifyMask) -> Self {
-        match input {
-            NotifyMask::ScreenChange => 1 << 0,
-            NotifyMask::CrtcChange => 1 << 1,
-            NotifyMask::OutputChange => 1 << 2,
-            NotifyMask::OutputProperty => 1 << 3,
-            NotifyMask::ProviderChange => 1 << 4,
-            NotifyMask::ProviderProperty => 1 << 5,
-            NotifyMask::ResourceChange => 1 << 6,
-            NotifyMask::Lease => 1 << 7,
-        }
-    }
-}
-impl From<NotifyMask> for Option<u8> {
-    fn from(input: NotifyMask) -> Self {
-        Some(u8::from(input))
+impl Serialize for SetScreenConfigReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
-impl From<NotifyMask> for u16 {
-    fn from(input: NotifyMask) -> Self {
-        Self::from(u8::from(input))
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.new_timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        u16::try_from(u32::from(self.subpixel_order)).unwrap().serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 10]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NotifyMask(u16);
+#[allow(non_upper_case_globals)]
+impl NotifyMask {
+    pub const ScreenChange: Self = Self(1 << 0);
+    pub const CrtcChange: Self = Self(1 << 1);
+    pub const OutputChange: Self = Self(1 << 2);
+    pub const OutputProperty: Self = Self(1 << 3);
+    pub const ProviderChange: Self = Self(1 << 4);
+    pub const ProviderProperty: Self = Self(1 << 5);
+    pub const ResourceChange: Self = Self(1 << 6);
+    pub const Lease: Self = Self(1 << 7);
+}
+bitmask_set!(NotifyMask, u16, [ScreenChange, CrtcChange, OutputChange, OutputProperty, ProviderChange, ProviderProperty, ResourceChange, Lease]);
 impl From<NotifyMask> for Option<u16> {
     fn from(input: NotifyMask) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<NotifyMask> for u32 {
     fn from(input: NotifyMask) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<NotifyMask> for Option<u32> {
     fn from(input: NotifyMask) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for NotifyMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(NotifyMask::ScreenChange),
-            2 => Ok(NotifyMask::CrtcChange),
-            4 => Ok(NotifyMask::OutputChange),
-            8 => Ok(NotifyMask::OutputProperty),
-            16 => Ok(NotifyMask::ProviderChange),
-            32 => Ok(NotifyMask::ProviderProperty),
-            64 => Ok(NotifyMask::ResourceChange),
-            128 => Ok(NotifyMask::Lease),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the SelectInput request
+pub const SELECT_INPUT_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectInputRequest {
+    pub window: xproto::Window,
+    pub enable: NotifyMask,
+}
+impl SelectInputRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_INPUT_REQUEST {
+            return Err(ParseError::ParseError);
         }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (enable, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let enable = NotifyMask::from(enable);
+        Ok(SelectInputRequest {
+            window,
+            enable,
+        })
     }
-}
-impl TryFrom<u16> for NotifyMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
     }
-}
-impl TryFrom<u32> for NotifyMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `enable` field of this structure.
+    pub fn enable<I>(mut self, value: I) -> Self where I: Into<NotifyMask> {
+        self.enable = value.into();
+        self
     }
 }
-bitmask_binop!(NotifyMask, u8);
 
-/// Opcode for the SelectInput request
-pub const SELECT_INPUT_REQUEST: u8 = 4;
 pub fn select_input<Conn, A>(conn: &Conn, window: xproto::Window, enable: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
+    A: Into<NotifyMask>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let enable: u16 = enable.into();
+    let enable: NotifyMask = enable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let enable_bytes = enable.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let enable_bytes = u16::from(enable).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_INPUT_REQUEST,
@@ -807,20 +1077,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectInputRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectInputRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_input(conn, self.window, self.enable)
+    }
+}
 
 /// Opcode for the GetScreenInfo request
 pub const GET_SCREEN_INFO_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetScreenInfoRequest {
+    pub window: xproto::Window,
+}
+impl GetScreenInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SCREEN_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetScreenInfoRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn get_screen_info<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetScreenInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_SCREEN_INFO_REQUEST,
@@ -834,43 +1142,57 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetScreenInfoRequest {
+    type Reply = GetScreenInfoReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetScreenInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetScreenInfoReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_screen_info(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetScreenInfoReply {
     pub response_type: u8,
-    pub rotations: u8,
+    pub rotations: Rotation,
     pub sequence: u16,
     pub length: u32,
     pub root: xproto::Window,
     pub timestamp: xproto::Timestamp,
     pub config_timestamp: xproto::Timestamp,
     pub size_id: u16,
-    pub rotation: u16,
+    pub rotation: Rotation,
     pub rate: u16,
     pub n_info: u16,
     pub sizes: Vec<ScreenSize>,
     pub rates: Vec<RefreshRates>,
 }
 impl TryParse for GetScreenInfoReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (rotations, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (n_sizes, remaining) = u16::try_parse(remaining)?;
-        let (size_id, remaining) = u16::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
-        let (rate, remaining) = u16::try_parse(remaining)?;
-        let (n_info, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (rotations, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (n_sizes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (size_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rate, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_info, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (sizes, remaining) = crate::x11_utils::parse_list::<ScreenSize>(remaining, n_sizes.try_into().or(Err(ParseError::ParseError))?)?;
-        let (rates, remaining) = crate::x11_utils::parse_list::<RefreshRates>(remaining, u32::from(n_info).checked_sub(u32::from(n_sizes)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (sizes, remaining) = crate::x11_utils::parse_list_with_order::<ScreenSize>(remaining, n_sizes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (rates, remaining) = crate::x11_utils::parse_list_with_order::<RefreshRates>(remaining, u32::from(n_info).checked_sub(u32::from(n_sizes)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let rotations = Rotation::from(u16::from(rotations));
+        let rotation = Rotation::from(rotation);
         let result = GetScreenInfoReply { response_type, rotations, sequence, length, root, timestamp, config_timestamp, size_id, rotation, rate, n_info, sizes, rates };
         Ok((result, remaining))
     }
@@ -881,17 +1203,78 @@ impl TryFrom<&[u8]> for GetScreenInfoReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetScreenInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.rotations)).unwrap().serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        let n_sizes = u16::try_from(self.sizes.len()).expect("`sizes` has too many elements");
+        n_sizes.serialize_into_with_order(bytes, order);
+        self.size_id.serialize_into_with_order(bytes, order);
+        u16::from(self.rotation).serialize_into_with_order(bytes, order);
+        self.rate.serialize_into_with_order(bytes, order);
+        self.n_info.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.sizes.serialize_into_with_order(bytes, order);
+        assert_eq!(self.rates.len(), usize::try_from(u32::from(self.n_info).checked_sub(u32::from(n_sizes)).unwrap()).unwrap(), "`rates` has an incorrect length");
+        self.rates.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetScreenSizeRange request
 pub const GET_SCREEN_SIZE_RANGE_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetScreenSizeRangeRequest {
+    pub window: xproto::Window,
+}
+impl GetScreenSizeRangeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SCREEN_SIZE_RANGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetScreenSizeRangeRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn get_screen_size_range<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetScreenSizeRangeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_SCREEN_SIZE_RANGE_REQUEST,
@@ -905,9 +1288,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetScreenSizeRangeRequest {
+    type Reply = GetScreenSizeRangeReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetScreenSizeRangeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetScreenSizeRangeReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_screen_size_range(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetScreenSizeRangeReply {
@@ -920,15 +1315,15 @@ pub struct GetScreenSizeRangeReply {
     pub max_height: u16,
 }
 impl TryParse for GetScreenSizeRangeReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (min_width, remaining) = u16::try_parse(remaining)?;
-        let (min_height, remaining) = u16::try_parse(remaining)?;
-        let (max_width, remaining) = u16::try_parse(remaining)?;
-        let (max_height, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (min_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (max_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (max_height, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = GetScreenSizeRangeReply { response_type, sequence, length, min_width, min_height, max_width, max_height };
         Ok((result, remaining))
@@ -940,21 +1335,106 @@ impl TryFrom<&[u8]> for GetScreenSizeRangeReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetScreenSizeRangeReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.min_width.serialize_into_with_order(bytes, order);
+        self.min_height.serialize_into_with_order(bytes, order);
+        self.max_width.serialize_into_with_order(bytes, order);
+        self.max_height.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetScreenSize request
 pub const SET_SCREEN_SIZE_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetScreenSizeRequest {
+    pub window: xproto::Window,
+    pub width: u16,
+    pub height: u16,
+    pub mm_width: u32,
+    pub mm_height: u32,
+}
+impl SetScreenSizeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_SCREEN_SIZE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mm_width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mm_height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetScreenSizeRequest {
+            window,
+            width,
+            height,
+            mm_width,
+            mm_height,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `mm_width` field of this structure.
+    pub fn mm_width(mut self, value: u32) -> Self {
+        self.mm_width = value;
+        self
+    }
+    /// Set the `mm_height` field of this structure.
+    pub fn mm_height(mut self, value: u32) -> Self {
+        self.mm_height = value;
+        self
+    }
+}
+
 pub fn set_screen_size<Conn>(conn: &Conn, window: xproto::Window, width: u16, height: u16, mm_width: u32, mm_height: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let mm_width_bytes = mm_width.serialize();
-    let mm_height_bytes = mm_height.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let mm_width_bytes = mm_width.serialize_with_order(order);
+    let mm_height_bytes = mm_height.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_SCREEN_SIZE_REQUEST,
@@ -980,92 +1460,47 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum ModeFlag {
-    HsyncPositive = 1 << 0,
-    HsyncNegative = 1 << 1,
-    VsyncPositive = 1 << 2,
-    VsyncNegative = 1 << 3,
-    Interlace = 1 << 4,
-    DoubleScan = 1 << 5,
-    Csync = 1 << 6,
-    CsyncPositive = 1 << 7,
-    CsyncNegative = 1 << 8,
-    HskewPresent = 1 << 9,
-    Bcast = 1 << 10,
-    PixelMultiplex = 1 << 11,
-    DoubleClock = 1 << 12,
-    HalveClock = 1 << 13,
-}
-impl From<ModeFlag> for u16 {
-    fn from(input: ModeFlag) -> Self {
-        match input {
-            ModeFlag::HsyncPositive => 1 << 0,
-            ModeFlag::HsyncNegative => 1 << 1,
-            ModeFlag::VsyncPositive => 1 << 2,
-            ModeFlag::VsyncNegative => 1 << 3,
-            ModeFlag::Interlace => 1 << 4,
-            ModeFlag::DoubleScan => 1 << 5,
-            ModeFlag::Csync => 1 << 6,
-            ModeFlag::CsyncPositive => 1 << 7,
-            ModeFlag::CsyncNegative => 1 << 8,
-            ModeFlag::HskewPresent => 1 << 9,
-            ModeFlag::Bcast => 1 << 10,
-            ModeFlag::PixelMultiplex => 1 << 11,
-            ModeFlag::DoubleClock => 1 << 12,
-            ModeFlag::HalveClock => 1 << 13,
-        }
-    }
-}
-impl From<ModeFlag> for Option<u16> {
-    fn from(input: ModeFlag) -> Self {
-        Some(u16::from(input))
-    }
+impl crate::x11_utils::Request for SetScreenSizeRequest {
+    type Reply = ();
 }
-impl From<ModeFlag> for u32 {
-    fn from(input: ModeFlag) -> Self {
-        Self::from(u16::from(input))
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetScreenSizeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_screen_size(conn, self.window, self.width, self.height, self.mm_width, self.mm_height)
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModeFlag(u32);
+#[allow(non_upper_case_globals)]
+impl ModeFlag {
+    pub const HsyncPositive: Self = Self(1 << 0);
+    pub const HsyncNegative: Self = Self(1 << 1);
+    pub const VsyncPositive: Self = Self(1 << 2);
+    pub const VsyncNegative: Self = Self(1 << 3);
+    pub const Interlace: Self = Self(1 << 4);
+    pub const DoubleScan: Self = Self(1 << 5);
+    pub const Csync: Self = Self(1 << 6);
+    pub const CsyncPositive: Self = Self(1 << 7);
+    pub const CsyncNegative: Self = Self(1 << 8);
+    pub const HskewPresent: Self = Self(1 << 9);
+    pub const Bcast: Self = Self(1 << 10);
+    pub const PixelMultiplex: Self = Self(1 << 11);
+    pub const DoubleClock: Self = Self(1 << 12);
+    pub const HalveClock: Self = Self(1 << 13);
+}
+bitmask_set!(ModeFlag, u32, [HsyncPositive, HsyncNegative, VsyncPositive, VsyncNegative, Interlace, DoubleScan, Csync, CsyncPositive, CsyncNegative, HskewPresent, Bcast, PixelMultiplex, DoubleClock, HalveClock]);
 impl From<ModeFlag> for Option<u32> {
     fn from(input: ModeFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for ModeFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ModeFlag::HsyncPositive),
-            2 => Ok(ModeFlag::HsyncNegative),
-            4 => Ok(ModeFlag::VsyncPositive),
-            8 => Ok(ModeFlag::VsyncNegative),
-            16 => Ok(ModeFlag::Interlace),
-            32 => Ok(ModeFlag::DoubleScan),
-            64 => Ok(ModeFlag::Csync),
-            128 => Ok(ModeFlag::CsyncPositive),
-            256 => Ok(ModeFlag::CsyncNegative),
-            512 => Ok(ModeFlag::HskewPresent),
-            1024 => Ok(ModeFlag::Bcast),
-            2048 => Ok(ModeFlag::PixelMultiplex),
-            4096 => Ok(ModeFlag::DoubleClock),
-            8192 => Ok(ModeFlag::HalveClock),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for ModeFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-bitmask_binop!(ModeFlag, u16);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ModeInfo {
@@ -1081,23 +1516,24 @@ pub struct ModeInfo {
     pub vsync_end: u16,
     pub vtotal: u16,
     pub name_len: u16,
-    pub mode_flags: u32,
+    pub mode_flags: ModeFlag,
 }
 impl TryParse for ModeInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (dot_clock, remaining) = u32::try_parse(remaining)?;
-        let (hsync_start, remaining) = u16::try_parse(remaining)?;
-        let (hsync_end, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vsync_start, remaining) = u16::try_parse(remaining)?;
-        let (vsync_end, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (mode_flags, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (dot_clock, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (hsync_start, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsync_end, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsync_start, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsync_end, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mode_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let mode_flags = ModeFlag::from(mode_flags);
         let result = ModeInfo { id, width, height, dot_clock, hsync_start, hsync_end, htotal, hskew, vsync_start, vsync_end, vtotal, name_len, mode_flags };
         Ok((result, remaining))
     }
@@ -1110,20 +1546,20 @@ impl TryFrom<&[u8]> for ModeInfo {
 }
 impl Serialize for ModeInfo {
     type Bytes = [u8; 32];
-    fn serialize(&self) -> [u8; 32] {
-        let id_bytes = self.id.serialize();
-        let width_bytes = self.width.serialize();
-        let height_bytes = self.height.serialize();
-        let dot_clock_bytes = self.dot_clock.serialize();
-        let hsync_start_bytes = self.hsync_start.serialize();
-        let hsync_end_bytes = self.hsync_end.serialize();
-        let htotal_bytes = self.htotal.serialize();
-        let hskew_bytes = self.hskew.serialize();
-        let vsync_start_bytes = self.vsync_start.serialize();
-        let vsync_end_bytes = self.vsync_end.serialize();
-        let vtotal_bytes = self.vtotal.serialize();
-        let name_len_bytes = self.name_len.serialize();
-        let mode_flags_bytes = self.mode_flags.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let id_bytes = self.id.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let dot_clock_bytes = self.dot_clock.serialize_with_order(order);
+        let hsync_start_bytes = self.hsync_start.serialize_with_order(order);
+        let hsync_end_bytes = self.hsync_end.serialize_with_order(order);
+        let htotal_bytes = self.htotal.serialize_with_order(order);
+        let hskew_bytes = self.hskew.serialize_with_order(order);
+        let vsync_start_bytes = self.vsync_start.serialize_with_order(order);
+        let vsync_end_bytes = self.vsync_end.serialize_with_order(order);
+        let vtotal_bytes = self.vtotal.serialize_with_order(order);
+        let name_len_bytes = self.name_len.serialize_with_order(order);
+        let mode_flags_bytes = u32::from(self.mode_flags).serialize_with_order(order);
         [
             id_bytes[0],
             id_bytes[1],
@@ -1159,34 +1595,60 @@ impl Serialize for ModeInfo {
             mode_flags_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(32);
-        self.id.serialize_into(bytes);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
-        self.dot_clock.serialize_into(bytes);
-        self.hsync_start.serialize_into(bytes);
-        self.hsync_end.serialize_into(bytes);
-        self.htotal.serialize_into(bytes);
-        self.hskew.serialize_into(bytes);
-        self.vsync_start.serialize_into(bytes);
-        self.vsync_end.serialize_into(bytes);
-        self.vtotal.serialize_into(bytes);
-        self.name_len.serialize_into(bytes);
-        self.mode_flags.serialize_into(bytes);
+        self.id.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.dot_clock.serialize_into_with_order(bytes, order);
+        self.hsync_start.serialize_into_with_order(bytes, order);
+        self.hsync_end.serialize_into_with_order(bytes, order);
+        self.htotal.serialize_into_with_order(bytes, order);
+        self.hskew.serialize_into_with_order(bytes, order);
+        self.vsync_start.serialize_into_with_order(bytes, order);
+        self.vsync_end.serialize_into_with_order(bytes, order);
+        self.vtotal.serialize_into_with_order(bytes, order);
+        self.name_len.serialize_into_with_order(bytes, order);
+        u32::from(self.mode_flags).serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the GetScreenResources request
 pub const GET_SCREEN_RESOURCES_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetScreenResourcesRequest {
+    pub window: xproto::Window,
+}
+impl GetScreenResourcesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SCREEN_RESOURCES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetScreenResourcesRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn get_screen_resources<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetScreenResourcesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_SCREEN_RESOURCES_REQUEST,
@@ -1200,9 +1662,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetScreenResourcesRequest {
+    type Reply = GetScreenResourcesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetScreenResourcesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetScreenResourcesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_screen_resources(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetScreenResourcesReply {
@@ -1217,21 +1691,21 @@ pub struct GetScreenResourcesReply {
     pub names: Vec<u8>,
 }
 impl TryParse for GetScreenResourcesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
-        let (num_outputs, remaining) = u16::try_parse(remaining)?;
-        let (num_modes, remaining) = u16::try_parse(remaining)?;
-        let (names_len, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_modes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (names_len, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ParseError))?)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ParseError))?)?;
-        let (modes, remaining) = crate::x11_utils::parse_list::<ModeInfo>(remaining, num_modes.try_into().or(Err(ParseError::ParseError))?)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (modes, remaining) = crate::x11_utils::parse_list_with_order::<ModeInfo>(remaining, num_modes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let (names, remaining) = crate::x11_utils::parse_u8_list(remaining, names_len.try_into().or(Err(ParseError::ParseError))?)?;
         let names = names.to_vec();
         let result = GetScreenResourcesReply { response_type, sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
@@ -1244,83 +1718,124 @@ impl TryFrom<&[u8]> for GetScreenResourcesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Connection {
-    Connected = 0,
-    Disconnected = 1,
-    Unknown = 2,
-}
-impl From<Connection> for u8 {
-    fn from(input: Connection) -> Self {
-        match input {
-            Connection::Connected => 0,
-            Connection::Disconnected => 1,
-            Connection::Unknown => 2,
-        }
+impl Serialize for GetScreenResourcesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        num_crtcs.serialize_into_with_order(bytes, order);
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        num_outputs.serialize_into_with_order(bytes, order);
+        let num_modes = u16::try_from(self.modes.len()).expect("`modes` has too many elements");
+        num_modes.serialize_into_with_order(bytes, order);
+        let names_len = u16::try_from(self.names.len()).expect("`names` has too many elements");
+        names_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.crtcs.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
+        self.modes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.names);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Connection(u8);
+#[allow(non_upper_case_globals)]
+impl Connection {
+    pub const Connected: Self = Self(0);
+    pub const Disconnected: Self = Self(1);
+    pub const Unknown: Self = Self(2);
+}
+enum_newtype!(Connection, u8, [Connected, Disconnected, Unknown]);
 impl From<Connection> for Option<u8> {
     fn from(input: Connection) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Connection> for u16 {
     fn from(input: Connection) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Connection> for Option<u16> {
     fn from(input: Connection) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Connection> for u32 {
     fn from(input: Connection) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Connection> for Option<u32> {
     fn from(input: Connection) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Connection {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Connection::Connected),
-            1 => Ok(Connection::Disconnected),
-            2 => Ok(Connection::Unknown),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for Connection {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
+
+/// Opcode for the GetOutputInfo request
+pub const GET_OUTPUT_INFO_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetOutputInfoRequest {
+    pub output: Output,
+    pub config_timestamp: xproto::Timestamp,
 }
-impl TryFrom<u32> for Connection {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl GetOutputInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_OUTPUT_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetOutputInfoRequest {
+            output,
+            config_timestamp,
+        })
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+    /// Set the `config_timestamp` field of this structure.
+    pub fn config_timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.config_timestamp = value.into();
+        self
     }
 }
 
-/// Opcode for the GetOutputInfo request
-pub const GET_OUTPUT_INFO_REQUEST: u8 = 9;
 pub fn get_output_info<Conn>(conn: &Conn, output: Output, config_timestamp: xproto::Timestamp) -> Result<Cookie<'_, Conn, GetOutputInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_bytes = output.serialize();
-    let config_timestamp_bytes = config_timestamp.serialize();
+    let output_bytes = output.serialize_with_order(order);
+    let config_timestamp_bytes = config_timestamp.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_OUTPUT_INFO_REQUEST,
@@ -1338,9 +1853,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetOutputInfoRequest {
+    type Reply = GetOutputInfoReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetOutputInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetOutputInfoReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_output_info(conn, self.output, self.config_timestamp)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetOutputInfoReply {
@@ -1361,30 +1888,30 @@ pub struct GetOutputInfoReply {
     pub name: Vec<u8>,
 }
 impl TryParse for GetOutputInfoReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (crtc, remaining) = Crtc::try_parse(remaining)?;
-        let (mm_width, remaining) = u32::try_parse(remaining)?;
-        let (mm_height, remaining) = u32::try_parse(remaining)?;
-        let (connection, remaining) = u8::try_parse(remaining)?;
-        let (subpixel_order, remaining) = u8::try_parse(remaining)?;
-        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
-        let (num_modes, remaining) = u16::try_parse(remaining)?;
-        let (num_preferred, remaining) = u16::try_parse(remaining)?;
-        let (num_clones, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ParseError))?)?;
-        let (modes, remaining) = crate::x11_utils::parse_list::<Mode>(remaining, num_modes.try_into().or(Err(ParseError::ParseError))?)?;
-        let (clones, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_clones.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (mm_width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mm_height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (connection, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (subpixel_order, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_modes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_preferred, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_clones, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (modes, remaining) = crate::x11_utils::parse_list_with_order::<Mode>(remaining, num_modes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (clones, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_clones.try_into().or(Err(ParseError::ParseError))?, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
-        let status = status.try_into()?;
-        let connection = connection.try_into()?;
-        let subpixel_order = subpixel_order.try_into()?;
+        let status = SetConfig::from(status);
+        let connection = Connection::from(connection);
+        let subpixel_order = render::SubPixel::from(u32::from(subpixel_order));
         let result = GetOutputInfoReply { response_type, status, sequence, length, timestamp, crtc, mm_width, mm_height, connection, subpixel_order, num_preferred, crtcs, modes, clones, name };
         Ok((result, remaining))
     }
@@ -1395,17 +1922,84 @@ impl TryFrom<&[u8]> for GetOutputInfoReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetOutputInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.crtc.serialize_into_with_order(bytes, order);
+        self.mm_width.serialize_into_with_order(bytes, order);
+        self.mm_height.serialize_into_with_order(bytes, order);
+        u8::from(self.connection).serialize_into_with_order(bytes, order);
+        u8::try_from(u32::from(self.subpixel_order)).unwrap().serialize_into_with_order(bytes, order);
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        num_crtcs.serialize_into_with_order(bytes, order);
+        let num_modes = u16::try_from(self.modes.len()).expect("`modes` has too many elements");
+        num_modes.serialize_into_with_order(bytes, order);
+        self.num_preferred.serialize_into_with_order(bytes, order);
+        let num_clones = u16::try_from(self.clones.len()).expect("`clones` has too many elements");
+        num_clones.serialize_into_with_order(bytes, order);
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        name_len.serialize_into_with_order(bytes, order);
+        self.crtcs.serialize_into_with_order(bytes, order);
+        self.modes.serialize_into_with_order(bytes, order);
+        self.clones.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ListOutputProperties request
 pub const LIST_OUTPUT_PROPERTIES_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListOutputPropertiesRequest {
+    pub output: Output,
+}
+impl ListOutputPropertiesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_OUTPUT_PROPERTIES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ListOutputPropertiesRequest {
+            output,
+        })
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+}
+
 pub fn list_output_properties<Conn>(conn: &Conn, output: Output) -> Result<Cookie<'_, Conn, ListOutputPropertiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_bytes = output.serialize();
+    let output_bytes = output.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         LIST_OUTPUT_PROPERTIES_REQUEST,
@@ -1419,9 +2013,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ListOutputPropertiesRequest {
+    type Reply = ListOutputPropertiesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ListOutputPropertiesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ListOutputPropertiesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        list_output_properties(conn, self.output)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListOutputPropertiesReply {
@@ -1431,14 +2037,14 @@ pub struct ListOutputPropertiesReply {
     pub atoms: Vec<xproto::Atom>,
 }
 impl TryParse for ListOutputPropertiesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_atoms, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_atoms, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (atoms, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_atoms.try_into().or(Err(ParseError::ParseError))?)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, num_atoms.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ListOutputPropertiesReply { response_type, sequence, length, atoms };
         Ok((result, remaining))
     }
@@ -1449,18 +2055,78 @@ impl TryFrom<&[u8]> for ListOutputPropertiesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ListOutputPropertiesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_atoms = u16::try_from(self.atoms.len()).expect("`atoms` has too many elements");
+        num_atoms.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.atoms.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryOutputProperty request
 pub const QUERY_OUTPUT_PROPERTY_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryOutputPropertyRequest {
+    pub output: Output,
+    pub property: xproto::Atom,
+}
+impl QueryOutputPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryOutputPropertyRequest {
+            output,
+            property,
+        })
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+}
+
 pub fn query_output_property<Conn>(conn: &Conn, output: Output, property: xproto::Atom) -> Result<Cookie<'_, Conn, QueryOutputPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_bytes = output.serialize();
-    let property_bytes = property.serialize();
+    let output_bytes = output.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_OUTPUT_PROPERTY_REQUEST,
@@ -1478,9 +2144,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryOutputPropertyRequest {
+    type Reply = QueryOutputPropertyReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryOutputPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryOutputPropertyReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_output_property(conn, self.output, self.property)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryOutputPropertyReply {
@@ -1492,16 +2170,16 @@ pub struct QueryOutputPropertyReply {
     pub valid_values: Vec<i32>,
 }
 impl TryParse for QueryOutputPropertyReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (pending, remaining) = bool::try_parse(remaining)?;
-        let (range, remaining) = bool::try_parse(remaining)?;
-        let (immutable, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (immutable, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(21..).ok_or(ParseError::ParseError)?;
-        let (valid_values, remaining) = crate::x11_utils::parse_list::<i32>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (valid_values, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryOutputPropertyReply { response_type, sequence, pending, range, immutable, valid_values };
         Ok((result, remaining))
     }
@@ -1512,20 +2190,114 @@ impl TryFrom<&[u8]> for QueryOutputPropertyReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryOutputPropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.valid_values.len()).expect("`valid_values` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.pending.serialize_into_with_order(bytes, order);
+        self.range.serialize_into_with_order(bytes, order);
+        self.immutable.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 21]);
+        self.valid_values.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ConfigureOutputProperty request
 pub const CONFIGURE_OUTPUT_PROPERTY_REQUEST: u8 = 12;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ConfigureOutputPropertyRequest {
+    pub output: Output,
+    pub property: xproto::Atom,
+    pub pending: bool,
+    pub range: bool,
+    pub values: Vec<i32>,
+}
+impl ConfigureOutputPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CONFIGURE_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut values = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = i32::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            values.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(ConfigureOutputPropertyRequest {
+            output,
+            property,
+            pending,
+            range,
+            values,
+        })
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `pending` field of this structure.
+    pub fn pending(mut self, value: bool) -> Self {
+        self.pending = value;
+        self
+    }
+    /// Set the `range` field of this structure.
+    pub fn range(mut self, value: bool) -> Self {
+        self.range = value;
+        self
+    }
+    /// Set the `values` field of this structure.
+    pub fn values<I>(mut self, value: I) -> Self where I: Into<Vec<i32>> {
+        self.values = value.into();
+        self
+    }
+}
+
 pub fn configure_output_property<'c, Conn>(conn: &'c Conn, output: Output, property: xproto::Atom, pending: bool, range: bool, values: &[i32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_bytes = output.serialize();
-    let property_bytes = property.serialize();
-    let pending_bytes = pending.serialize();
-    let range_bytes = range.serialize();
+    let output_bytes = output.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
+    let pending_bytes = pending.serialize_with_order(order);
+    let range_bytes = range.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CONFIGURE_OUTPUT_PROPERTY_REQUEST,
@@ -1545,31 +2317,122 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let values_bytes = values.serialize();
+    let values_bytes = values.serialize_with_order(order);
     let length_so_far = length_so_far + values_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&values_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ConfigureOutputPropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ConfigureOutputPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        configure_output_property(conn, self.output, self.property, self.pending, self.range, &self.values)
+    }
+}
 
 /// Opcode for the ChangeOutputProperty request
 pub const CHANGE_OUTPUT_PROPERTY_REQUEST: u8 = 13;
-pub fn change_output_property<'c, Conn>(conn: &'c Conn, output: Output, property: xproto::Atom, type_: xproto::Atom, format: u8, mode: xproto::PropMode, num_units: u32, data: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChangeOutputPropertyRequest {
+    pub output: Output,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub format: u8,
+    pub mode: xproto::PropMode,
+    pub num_units: u32,
+    pub data: Vec<u8>,
+}
+impl ChangeOutputPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (num_units, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_units.checked_mul(u32::from(format)).ok_or(ParseError::ParseError)?.checked_div(8u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let data = data.to_vec();
+        let _ = remaining;
+        let _ = order;
+        let mode = xproto::PropMode::from(mode);
+        Ok(ChangeOutputPropertyRequest {
+            output,
+            property,
+            type_,
+            format,
+            mode,
+            num_units,
+            data,
+        })
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.type_ = value.into();
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u8) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<xproto::PropMode> {
+        self.mode = value.into();
+        self
+    }
+    /// Set the `num_units` field of this structure.
+    pub fn num_units(mut self, value: u32) -> Self {
+        self.num_units = value;
+        self
+    }
+    /// Set the `data` field of this structure.
+    pub fn data<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.data = value.into();
+        self
+    }
+}
+
+pub fn change_output_property<'c, Conn, A>(conn: &'c Conn, output: Output, property: xproto::Atom, type_: xproto::Atom, format: u8, mode: A, num_units: u32, data: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::PropMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let mode: xproto::PropMode = mode.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_bytes = output.serialize();
-    let property_bytes = property.serialize();
-    let type_bytes = type_.serialize();
-    let format_bytes = format.serialize();
-    let mode_bytes = u8::from(mode).serialize();
-    let num_units_bytes = num_units.serialize();
+    let output_bytes = output.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let mode_bytes = u8::from(mode).serialize_with_order(order);
+    let num_units_bytes = num_units.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_OUTPUT_PROPERTY_REQUEST,
@@ -1603,21 +2466,67 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(data), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeOutputPropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeOutputPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_output_property(conn, self.output, self.property, self.type_, self.format, self.mode, self.num_units, &self.data)
+    }
+}
 
 /// Opcode for the DeleteOutputProperty request
 pub const DELETE_OUTPUT_PROPERTY_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeleteOutputPropertyRequest {
+    pub output: Output,
+    pub property: xproto::Atom,
+}
+impl DeleteOutputPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeleteOutputPropertyRequest {
+            output,
+            property,
+        })
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+}
+
 pub fn delete_output_property<Conn>(conn: &Conn, output: Output, property: xproto::Atom) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_bytes = output.serialize();
-    let property_bytes = property.serialize();
+    let output_bytes = output.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_OUTPUT_PROPERTY_REQUEST,
@@ -1635,12 +2544,98 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteOutputPropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteOutputPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_output_property(conn, self.output, self.property)
+    }
+}
 
 /// Opcode for the GetOutputProperty request
 pub const GET_OUTPUT_PROPERTY_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetOutputPropertyRequest {
+    pub output: Output,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub long_offset: u32,
+    pub long_length: u32,
+    pub delete: bool,
+    pub pending: bool,
+}
+impl GetOutputPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (long_offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (long_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (delete, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetOutputPropertyRequest {
+            output,
+            property,
+            type_,
+            long_offset,
+            long_length,
+            delete,
+            pending,
+        })
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.type_ = value.into();
+        self
+    }
+    /// Set the `long_offset` field of this structure.
+    pub fn long_offset(mut self, value: u32) -> Self {
+        self.long_offset = value;
+        self
+    }
+    /// Set the `long_length` field of this structure.
+    pub fn long_length(mut self, value: u32) -> Self {
+        self.long_length = value;
+        self
+    }
+    /// Set the `delete` field of this structure.
+    pub fn delete(mut self, value: bool) -> Self {
+        self.delete = value;
+        self
+    }
+    /// Set the `pending` field of this structure.
+    pub fn pending(mut self, value: bool) -> Self {
+        self.pending = value;
+        self
+    }
+}
+
 pub fn get_output_property<Conn, A>(conn: &Conn, output: Output, property: xproto::Atom, type_: A, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> Result<Cookie<'_, Conn, GetOutputPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1649,14 +2644,15 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let type_: xproto::Atom = type_.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_bytes = output.serialize();
-    let property_bytes = property.serialize();
-    let type_bytes = type_.serialize();
-    let long_offset_bytes = long_offset.serialize();
-    let long_length_bytes = long_length.serialize();
-    let delete_bytes = delete.serialize();
-    let pending_bytes = pending.serialize();
+    let output_bytes = output.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let long_offset_bytes = long_offset.serialize_with_order(order);
+    let long_length_bytes = long_length.serialize_with_order(order);
+    let delete_bytes = delete.serialize_with_order(order);
+    let pending_bytes = pending.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_OUTPUT_PROPERTY_REQUEST,
@@ -1690,9 +2686,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetOutputPropertyRequest {
+    type Reply = GetOutputPropertyReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetOutputPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetOutputPropertyReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_output_property(conn, self.output, self.property, self.type_, self.long_offset, self.long_length, self.delete, self.pending)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetOutputPropertyReply {
@@ -1706,14 +2714,14 @@ pub struct GetOutputPropertyReply {
     pub data: Vec<u8>,
 }
 impl TryParse for GetOutputPropertyReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (bytes_after, remaining) = u32::try_parse(remaining)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (bytes_after, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::ParseError)?).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -1727,18 +2735,89 @@ impl TryFrom<&[u8]> for GetOutputPropertyReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetOutputPropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.format.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.bytes_after.serialize_into_with_order(bytes, order);
+        self.num_items.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        assert_eq!(self.data.len(), usize::try_from(self.num_items.checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`data` has an incorrect length");
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreateMode request
 pub const CREATE_MODE_REQUEST: u8 = 16;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateModeRequest {
+    pub window: xproto::Window,
+    pub mode_info: ModeInfo,
+    pub name: Vec<u8>,
+}
+impl CreateModeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_MODE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (mode_info, remaining) = ModeInfo::try_parse_with_order(remaining, order)?;
+        let name = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateModeRequest {
+            window,
+            mode_info,
+            name,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `mode_info` field of this structure.
+    pub fn mode_info<I>(mut self, value: I) -> Self where I: Into<ModeInfo> {
+        self.mode_info = value.into();
+        self
+    }
+    /// Set the `name` field of this structure.
+    pub fn name<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.name = value.into();
+        self
+    }
+}
+
 pub fn create_mode<'c, Conn>(conn: &'c Conn, window: xproto::Window, mode_info: ModeInfo, name: &[u8]) -> Result<Cookie<'c, Conn, CreateModeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let mode_info_bytes = mode_info.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let mode_info_bytes = mode_info.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_MODE_REQUEST,
@@ -1787,9 +2866,21 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(name), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateModeRequest {
+    type Reply = CreateModeReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateModeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, CreateModeReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_mode(conn, self.window, self.mode_info, &self.name)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateModeReply {
@@ -1799,12 +2890,12 @@ pub struct CreateModeReply {
     pub mode: Mode,
 }
 impl TryParse for CreateModeReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = CreateModeReply { response_type, sequence, length, mode };
         Ok((result, remaining))
@@ -1816,17 +2907,67 @@ impl TryFrom<&[u8]> for CreateModeReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CreateModeReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.mode.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DestroyMode request
 pub const DESTROY_MODE_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyModeRequest {
+    pub mode: Mode,
+}
+impl DestroyModeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_MODE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyModeRequest {
+            mode,
+        })
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<Mode> {
+        self.mode = value.into();
+        self
+    }
+}
+
 pub fn destroy_mode<Conn>(conn: &Conn, mode: Mode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let mode_bytes = mode.serialize();
+    let mode_bytes = mode.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_MODE_REQUEST,
@@ -1840,21 +2981,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyModeRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyModeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_mode(conn, self.mode)
+    }
+}
 
 /// Opcode for the AddOutputMode request
 pub const ADD_OUTPUT_MODE_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AddOutputModeRequest {
+    pub output: Output,
+    pub mode: Mode,
+}
+impl AddOutputModeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ADD_OUTPUT_MODE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(AddOutputModeRequest {
+            output,
+            mode,
+        })
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<Mode> {
+        self.mode = value.into();
+        self
+    }
+}
+
 pub fn add_output_mode<Conn>(conn: &Conn, output: Output, mode: Mode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_bytes = output.serialize();
-    let mode_bytes = mode.serialize();
+    let output_bytes = output.serialize_with_order(order);
+    let mode_bytes = mode.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ADD_OUTPUT_MODE_REQUEST,
@@ -1872,21 +3059,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for AddOutputModeRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AddOutputModeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        add_output_mode(conn, self.output, self.mode)
+    }
+}
 
 /// Opcode for the DeleteOutputMode request
 pub const DELETE_OUTPUT_MODE_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeleteOutputModeRequest {
+    pub output: Output,
+    pub mode: Mode,
+}
+impl DeleteOutputModeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_OUTPUT_MODE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeleteOutputModeRequest {
+            output,
+            mode,
+        })
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<Mode> {
+        self.mode = value.into();
+        self
+    }
+}
+
 pub fn delete_output_mode<Conn>(conn: &Conn, output: Output, mode: Mode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_bytes = output.serialize();
-    let mode_bytes = mode.serialize();
+    let output_bytes = output.serialize_with_order(order);
+    let mode_bytes = mode.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_OUTPUT_MODE_REQUEST,
@@ -1904,21 +3137,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteOutputModeRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteOutputModeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_output_mode(conn, self.output, self.mode)
+    }
+}
 
 /// Opcode for the GetCrtcInfo request
 pub const GET_CRTC_INFO_REQUEST: u8 = 20;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetCrtcInfoRequest {
+    pub crtc: Crtc,
+    pub config_timestamp: xproto::Timestamp,
+}
+impl GetCrtcInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CRTC_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetCrtcInfoRequest {
+            crtc,
+            config_timestamp,
+        })
+    }
+    /// Set the `crtc` field of this structure.
+    pub fn crtc<I>(mut self, value: I) -> Self where I: Into<Crtc> {
+        self.crtc = value.into();
+        self
+    }
+    /// Set the `config_timestamp` field of this structure.
+    pub fn config_timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.config_timestamp = value.into();
+        self
+    }
+}
+
 pub fn get_crtc_info<Conn>(conn: &Conn, crtc: Crtc, config_timestamp: xproto::Timestamp) -> Result<Cookie<'_, Conn, GetCrtcInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let crtc_bytes = crtc.serialize();
-    let config_timestamp_bytes = config_timestamp.serialize();
+    let crtc_bytes = crtc.serialize_with_order(order);
+    let config_timestamp_bytes = config_timestamp.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CRTC_INFO_REQUEST,
@@ -1936,9 +3215,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetCrtcInfoRequest {
+    type Reply = GetCrtcInfoReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetCrtcInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetCrtcInfoReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_crtc_info(conn, self.crtc, self.config_timestamp)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCrtcInfoReply {
@@ -1952,30 +3243,32 @@ pub struct GetCrtcInfoReply {
     pub width: u16,
     pub height: u16,
     pub mode: Mode,
-    pub rotation: u16,
-    pub rotations: u16,
+    pub rotation: Rotation,
+    pub rotations: Rotation,
     pub outputs: Vec<Output>,
     pub possible: Vec<Output>,
 }
 impl TryParse for GetCrtcInfoReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
-        let (rotations, remaining) = u16::try_parse(remaining)?;
-        let (num_outputs, remaining) = u16::try_parse(remaining)?;
-        let (num_possible_outputs, remaining) = u16::try_parse(remaining)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ParseError))?)?;
-        let (possible, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_possible_outputs.try_into().or(Err(ParseError::ParseError))?)?;
-        let status = status.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rotations, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_possible_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (possible, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_possible_outputs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let status = SetConfig::from(status);
+        let rotation = Rotation::from(rotation);
+        let rotations = Rotation::from(rotations);
         let result = GetCrtcInfoReply { response_type, status, sequence, length, timestamp, x, y, width, height, mode, rotation, rotations, outputs, possible };
         Ok((result, remaining))
     }
@@ -1986,25 +3279,152 @@ impl TryFrom<&[u8]> for GetCrtcInfoReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetCrtcInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.mode.serialize_into_with_order(bytes, order);
+        u16::from(self.rotation).serialize_into_with_order(bytes, order);
+        u16::from(self.rotations).serialize_into_with_order(bytes, order);
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        num_outputs.serialize_into_with_order(bytes, order);
+        let num_possible_outputs = u16::try_from(self.possible.len()).expect("`possible` has too many elements");
+        num_possible_outputs.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
+        self.possible.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetCrtcConfig request
 pub const SET_CRTC_CONFIG_REQUEST: u8 = 21;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetCrtcConfigRequest {
+    pub crtc: Crtc,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub x: i16,
+    pub y: i16,
+    pub mode: Mode,
+    pub rotation: Rotation,
+    pub outputs: Vec<Output>,
+}
+impl SetCrtcConfigRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CRTC_CONFIG_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut outputs = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Output::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            outputs.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        let rotation = Rotation::from(rotation);
+        Ok(SetCrtcConfigRequest {
+            crtc,
+            timestamp,
+            config_timestamp,
+            x,
+            y,
+            mode,
+            rotation,
+            outputs,
+        })
+    }
+    /// Set the `crtc` field of this structure.
+    pub fn crtc<I>(mut self, value: I) -> Self where I: Into<Crtc> {
+        self.crtc = value.into();
+        self
+    }
+    /// Set the `timestamp` field of this structure.
+    pub fn timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.timestamp = value.into();
+        self
+    }
+    /// Set the `config_timestamp` field of this structure.
+    pub fn config_timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.config_timestamp = value.into();
+        self
+    }
+    /// Set the `x` field of this structure.
+    pub fn x(mut self, value: i16) -> Self {
+        self.x = value;
+        self
+    }
+    /// Set the `y` field of this structure.
+    pub fn y(mut self, value: i16) -> Self {
+        self.y = value;
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<Mode> {
+        self.mode = value.into();
+        self
+    }
+    /// Set the `rotation` field of this structure.
+    pub fn rotation<I>(mut self, value: I) -> Self where I: Into<Rotation> {
+        self.rotation = value.into();
+        self
+    }
+    /// Set the `outputs` field of this structure.
+    pub fn outputs<I>(mut self, value: I) -> Self where I: Into<Vec<Output>> {
+        self.outputs = value.into();
+        self
+    }
+}
+
 pub fn set_crtc_config<'c, Conn, A>(conn: &'c Conn, crtc: Crtc, timestamp: xproto::Timestamp, config_timestamp: xproto::Timestamp, x: i16, y: i16, mode: Mode, rotation: A, outputs: &[Output]) -> Result<Cookie<'c, Conn, SetCrtcConfigReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
+    A: Into<Rotation>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let rotation: u16 = rotation.into();
+    let rotation: Rotation = rotation.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let crtc_bytes = crtc.serialize();
-    let timestamp_bytes = timestamp.serialize();
-    let config_timestamp_bytes = config_timestamp.serialize();
-    let x_bytes = x.serialize();
-    let y_bytes = y.serialize();
-    let mode_bytes = mode.serialize();
-    let rotation_bytes = rotation.serialize();
+    let crtc_bytes = crtc.serialize_with_order(order);
+    let timestamp_bytes = timestamp.serialize_with_order(order);
+    let config_timestamp_bytes = config_timestamp.serialize_with_order(order);
+    let x_bytes = x.serialize_with_order(order);
+    let y_bytes = y.serialize_with_order(order);
+    let mode_bytes = mode.serialize_with_order(order);
+    let rotation_bytes = u16::from(rotation).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_CRTC_CONFIG_REQUEST,
@@ -2036,15 +3456,27 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let outputs_bytes = outputs.serialize();
+    let outputs_bytes = outputs.serialize_with_order(order);
     let length_so_far = length_so_far + outputs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&outputs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetCrtcConfigRequest {
+    type Reply = SetCrtcConfigReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetCrtcConfigRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, SetCrtcConfigReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_crtc_config(conn, self.crtc, self.timestamp, self.config_timestamp, self.x, self.y, self.mode, self.rotation, &self.outputs)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetCrtcConfigReply {
@@ -2055,14 +3487,14 @@ pub struct SetCrtcConfigReply {
     pub timestamp: xproto::Timestamp,
 }
 impl TryParse for SetCrtcConfigReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = SetConfig::from(status);
         let result = SetCrtcConfigReply { response_type, status, sequence, length, timestamp };
         Ok((result, remaining))
     }
@@ -2073,17 +3505,67 @@ impl TryFrom<&[u8]> for SetCrtcConfigReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for SetCrtcConfigReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetCrtcGammaSize request
 pub const GET_CRTC_GAMMA_SIZE_REQUEST: u8 = 22;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetCrtcGammaSizeRequest {
+    pub crtc: Crtc,
+}
+impl GetCrtcGammaSizeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CRTC_GAMMA_SIZE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetCrtcGammaSizeRequest {
+            crtc,
+        })
+    }
+    /// Set the `crtc` field of this structure.
+    pub fn crtc<I>(mut self, value: I) -> Self where I: Into<Crtc> {
+        self.crtc = value.into();
+        self
+    }
+}
+
 pub fn get_crtc_gamma_size<Conn>(conn: &Conn, crtc: Crtc) -> Result<Cookie<'_, Conn, GetCrtcGammaSizeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let crtc_bytes = crtc.serialize();
+    let crtc_bytes = crtc.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CRTC_GAMMA_SIZE_REQUEST,
@@ -2097,9 +3579,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetCrtcGammaSizeRequest {
+    type Reply = GetCrtcGammaSizeReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetCrtcGammaSizeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetCrtcGammaSizeReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_crtc_gamma_size(conn, self.crtc)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetCrtcGammaSizeReply {
@@ -2109,12 +3603,12 @@ pub struct GetCrtcGammaSizeReply {
     pub size: u16,
 }
 impl TryParse for GetCrtcGammaSizeReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
         let result = GetCrtcGammaSizeReply { response_type, sequence, length, size };
         Ok((result, remaining))
@@ -2126,17 +3620,67 @@ impl TryFrom<&[u8]> for GetCrtcGammaSizeReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetCrtcGammaSizeReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetCrtcGamma request
 pub const GET_CRTC_GAMMA_REQUEST: u8 = 23;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetCrtcGammaRequest {
+    pub crtc: Crtc,
+}
+impl GetCrtcGammaRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CRTC_GAMMA_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetCrtcGammaRequest {
+            crtc,
+        })
+    }
+    /// Set the `crtc` field of this structure.
+    pub fn crtc<I>(mut self, value: I) -> Self where I: Into<Crtc> {
+        self.crtc = value.into();
+        self
+    }
+}
+
 pub fn get_crtc_gamma<Conn>(conn: &Conn, crtc: Crtc) -> Result<Cookie<'_, Conn, GetCrtcGammaReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let crtc_bytes = crtc.serialize();
+    let crtc_bytes = crtc.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CRTC_GAMMA_REQUEST,
@@ -2150,9 +3694,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetCrtcGammaRequest {
+    type Reply = GetCrtcGammaReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetCrtcGammaRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetCrtcGammaReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_crtc_gamma(conn, self.crtc)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCrtcGammaReply {
@@ -2164,16 +3720,16 @@ pub struct GetCrtcGammaReply {
     pub blue: Vec<u16>,
 }
 impl TryParse for GetCrtcGammaReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_into().or(Err(ParseError::ParseError))?)?;
-        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_into().or(Err(ParseError::ParseError))?)?;
-        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_into().or(Err(ParseError::ParseError))?)?;
+        let (red, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (green, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (blue, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetCrtcGammaReply { response_type, sequence, length, red, green, blue };
         Ok((result, remaining))
     }
@@ -2184,19 +3740,101 @@ impl TryFrom<&[u8]> for GetCrtcGammaReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetCrtcGammaReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let size = u16::try_from(self.red.len()).expect("`red` has too many elements");
+        size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.red.serialize_into_with_order(bytes, order);
+        assert_eq!(self.green.len(), usize::try_from(size).unwrap(), "`green` has an incorrect length");
+        self.green.serialize_into_with_order(bytes, order);
+        assert_eq!(self.blue.len(), usize::try_from(size).unwrap(), "`blue` has an incorrect length");
+        self.blue.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetCrtcGamma request
 pub const SET_CRTC_GAMMA_REQUEST: u8 = 24;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetCrtcGammaRequest {
+    pub crtc: Crtc,
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
+}
+impl SetCrtcGammaRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CRTC_GAMMA_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (red, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (green, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (blue, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetCrtcGammaRequest {
+            crtc,
+            red,
+            green,
+            blue,
+        })
+    }
+    /// Set the `crtc` field of this structure.
+    pub fn crtc<I>(mut self, value: I) -> Self where I: Into<Crtc> {
+        self.crtc = value.into();
+        self
+    }
+    /// Set the `red` field of this structure.
+    pub fn red<I>(mut self, value: I) -> Self where I: Into<Vec<u16>> {
+        self.red = value.into();
+        self
+    }
+    /// Set the `green` field of this structure.
+    pub fn green<I>(mut self, value: I) -> Self where I: Into<Vec<u16>> {
+        self.green = value.into();
+        self
+    }
+    /// Set the `blue` field of this structure.
+    pub fn blue<I>(mut self, value: I) -> Self where I: Into<Vec<u16>> {
+        self.blue = value.into();
+        self
+    }
+}
+
 pub fn set_crtc_gamma<'c, Conn>(conn: &'c Conn, crtc: Crtc, red: &[u16], green: &[u16], blue: &[u16]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let crtc_bytes = crtc.serialize();
+    let crtc_bytes = crtc.serialize_with_order(order);
     let size = u16::try_from(red.len()).expect("`red` has too many elements");
-    let size_bytes = size.serialize();
+    let size_bytes = size.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_CRTC_GAMMA_REQUEST,
@@ -2212,32 +3850,70 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let red_bytes = red.serialize();
+    let red_bytes = red.serialize_with_order(order);
     let length_so_far = length_so_far + red_bytes.len();
     assert_eq!(green.len(), usize::try_from(size).unwrap(), "`green` has an incorrect length");
-    let green_bytes = green.serialize();
+    let green_bytes = green.serialize_with_order(order);
     let length_so_far = length_so_far + green_bytes.len();
     assert_eq!(blue.len(), usize::try_from(size).unwrap(), "`blue` has an incorrect length");
-    let blue_bytes = blue.serialize();
+    let blue_bytes = blue.serialize_with_order(order);
     let length_so_far = length_so_far + blue_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&red_bytes), IoSlice::new(&green_bytes), IoSlice::new(&blue_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetCrtcGammaRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetCrtcGammaRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_crtc_gamma(conn, self.crtc, &self.red, &self.green, &self.blue)
+    }
+}
 
 /// Opcode for the GetScreenResourcesCurrent request
 pub const GET_SCREEN_RESOURCES_CURRENT_REQUEST: u8 = 25;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetScreenResourcesCurrentRequest {
+    pub window: xproto::Window,
+}
+impl GetScreenResourcesCurrentRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SCREEN_RESOURCES_CURRENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetScreenResourcesCurrentRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn get_screen_resources_current<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetScreenResourcesCurrentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_SCREEN_RESOURCES_CURRENT_REQUEST,
@@ -2251,9 +3927,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetScreenResourcesCurrentRequest {
+    type Reply = GetScreenResourcesCurrentReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetScreenResourcesCurrentRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetScreenResourcesCurrentReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_screen_resources_current(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetScreenResourcesCurrentReply {
@@ -2268,21 +3956,21 @@ pub struct GetScreenResourcesCurrentReply {
     pub names: Vec<u8>,
 }
 impl TryParse for GetScreenResourcesCurrentReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
-        let (num_outputs, remaining) = u16::try_parse(remaining)?;
-        let (num_modes, remaining) = u16::try_parse(remaining)?;
-        let (names_len, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_modes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (names_len, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ParseError))?)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ParseError))?)?;
-        let (modes, remaining) = crate::x11_utils::parse_list::<ModeInfo>(remaining, num_modes.try_into().or(Err(ParseError::ParseError))?)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (modes, remaining) = crate::x11_utils::parse_list_with_order::<ModeInfo>(remaining, num_modes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let (names, remaining) = crate::x11_utils::parse_u8_list(remaining, names_len.try_into().or(Err(ParseError::ParseError))?)?;
         let names = names.to_vec();
         let result = GetScreenResourcesCurrentReply { response_type, sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
@@ -2295,89 +3983,157 @@ impl TryFrom<&[u8]> for GetScreenResourcesCurrentReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Transform {
-    Unit = 1 << 0,
-    ScaleUp = 1 << 1,
-    ScaleDown = 1 << 2,
-    Projective = 1 << 3,
-}
-impl From<Transform> for u8 {
-    fn from(input: Transform) -> Self {
-        match input {
-            Transform::Unit => 1 << 0,
-            Transform::ScaleUp => 1 << 1,
-            Transform::ScaleDown => 1 << 2,
-            Transform::Projective => 1 << 3,
-        }
+impl Serialize for GetScreenResourcesCurrentReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        num_crtcs.serialize_into_with_order(bytes, order);
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        num_outputs.serialize_into_with_order(bytes, order);
+        let num_modes = u16::try_from(self.modes.len()).expect("`modes` has too many elements");
+        num_modes.serialize_into_with_order(bytes, order);
+        let names_len = u16::try_from(self.names.len()).expect("`names` has too many elements");
+        names_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.crtcs.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
+        self.modes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.names);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Transform(u8);
+#[allow(non_upper_case_globals)]
+impl Transform {
+    pub const Unit: Self = Self(1 << 0);
+    pub const ScaleUp: Self = Self(1 << 1);
+    pub const ScaleDown: Self = Self(1 << 2);
+    pub const Projective: Self = Self(1 << 3);
+}
+bitmask_set!(Transform, u8, [Unit, ScaleUp, ScaleDown, Projective]);
 impl From<Transform> for Option<u8> {
     fn from(input: Transform) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Transform> for u16 {
     fn from(input: Transform) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Transform> for Option<u16> {
     fn from(input: Transform) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Transform> for u32 {
     fn from(input: Transform) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Transform> for Option<u32> {
     fn from(input: Transform) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for Transform {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(Transform::Unit),
-            2 => Ok(Transform::ScaleUp),
-            4 => Ok(Transform::ScaleDown),
-            8 => Ok(Transform::Projective),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the SetCrtcTransform request
+pub const SET_CRTC_TRANSFORM_REQUEST: u8 = 26;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetCrtcTransformRequest {
+    pub crtc: Crtc,
+    pub transform: render::Transform,
+    pub filter_name: Vec<u8>,
+    pub filter_params: Vec<render::Fixed>,
+}
+impl SetCrtcTransformRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CRTC_TRANSFORM_REQUEST {
+            return Err(ParseError::ParseError);
         }
+        let remaining = value;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (transform, remaining) = render::Transform::try_parse_with_order(remaining, order)?;
+        let (filter_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, filter_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let filter_name = filter_name.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut filter_params = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = render::Fixed::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            filter_params.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(SetCrtcTransformRequest {
+            crtc,
+            transform,
+            filter_name,
+            filter_params,
+        })
+    }
+    /// Set the `crtc` field of this structure.
+    pub fn crtc<I>(mut self, value: I) -> Self where I: Into<Crtc> {
+        self.crtc = value.into();
+        self
+    }
+    /// Set the `transform` field of this structure.
+    pub fn transform<I>(mut self, value: I) -> Self where I: Into<render::Transform> {
+        self.transform = value.into();
+        self
+    }
+    /// Set the `filter_name` field of this structure.
+    pub fn filter_name<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.filter_name = value.into();
+        self
+    }
+    /// Set the `filter_params` field of this structure.
+    pub fn filter_params<I>(mut self, value: I) -> Self where I: Into<Vec<render::Fixed>> {
+        self.filter_params = value.into();
+        self
     }
 }
-impl TryFrom<u16> for Transform {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Transform {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(Transform, u8);
 
-/// Opcode for the SetCrtcTransform request
-pub const SET_CRTC_TRANSFORM_REQUEST: u8 = 26;
 pub fn set_crtc_transform<'c, Conn>(conn: &'c Conn, crtc: Crtc, transform: render::Transform, filter_name: &[u8], filter_params: &[render::Fixed]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let crtc_bytes = crtc.serialize();
-    let transform_bytes = transform.serialize();
+    let crtc_bytes = crtc.serialize_with_order(order);
+    let transform_bytes = transform.serialize_with_order(order);
     let filter_len = u16::try_from(filter_name.len()).expect("`filter_name` has too many elements");
-    let filter_len_bytes = filter_len.serialize();
+    let filter_len_bytes = filter_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_CRTC_TRANSFORM_REQUEST,
@@ -2432,26 +4188,64 @@ where
     let length_so_far = length_so_far + filter_name.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
-    let filter_params_bytes = filter_params.serialize();
+    let filter_params_bytes = filter_params.serialize_with_order(order);
     let length_so_far = length_so_far + filter_params_bytes.len();
     let padding1 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding1.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(filter_name), IoSlice::new(&padding0), IoSlice::new(&filter_params_bytes), IoSlice::new(&padding1)], vec![])?)
 }
+impl crate::x11_utils::Request for SetCrtcTransformRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetCrtcTransformRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_crtc_transform(conn, self.crtc, self.transform, &self.filter_name, &self.filter_params)
+    }
+}
 
 /// Opcode for the GetCrtcTransform request
 pub const GET_CRTC_TRANSFORM_REQUEST: u8 = 27;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetCrtcTransformRequest {
+    pub crtc: Crtc,
+}
+impl GetCrtcTransformRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CRTC_TRANSFORM_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetCrtcTransformRequest {
+            crtc,
+        })
+    }
+    /// Set the `crtc` field of this structure.
+    pub fn crtc<I>(mut self, value: I) -> Self where I: Into<Crtc> {
+        self.crtc = value.into();
+        self
+    }
+}
+
 pub fn get_crtc_transform<Conn>(conn: &Conn, crtc: Crtc) -> Result<Cookie<'_, Conn, GetCrtcTransformReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let crtc_bytes = crtc.serialize();
+    let crtc_bytes = crtc.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CRTC_TRANSFORM_REQUEST,
@@ -2465,9 +4259,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetCrtcTransformRequest {
+    type Reply = GetCrtcTransformReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetCrtcTransformRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetCrtcTransformReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_crtc_transform(conn, self.crtc)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCrtcTransformReply {
@@ -2483,35 +4289,35 @@ pub struct GetCrtcTransformReply {
     pub current_params: Vec<render::Fixed>,
 }
 impl TryParse for GetCrtcTransformReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (pending_transform, remaining) = render::Transform::try_parse(remaining)?;
-        let (has_transforms, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pending_transform, remaining) = render::Transform::try_parse_with_order(remaining, order)?;
+        let (has_transforms, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (current_transform, remaining) = render::Transform::try_parse(remaining)?;
+        let (current_transform, remaining) = render::Transform::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (pending_len, remaining) = u16::try_parse(remaining)?;
-        let (pending_nparams, remaining) = u16::try_parse(remaining)?;
-        let (current_len, remaining) = u16::try_parse(remaining)?;
-        let (current_nparams, remaining) = u16::try_parse(remaining)?;
+        let (pending_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pending_nparams, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (current_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (current_nparams, remaining) = u16::try_parse_with_order(remaining, order)?;
         let (pending_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, pending_len.try_into().or(Err(ParseError::ParseError))?)?;
         let pending_filter_name = pending_filter_name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (pending_params, remaining) = crate::x11_utils::parse_list::<render::Fixed>(remaining, pending_nparams.try_into().or(Err(ParseError::ParseError))?)?;
+        let (pending_params, remaining) = crate::x11_utils::parse_list_with_order::<render::Fixed>(remaining, pending_nparams.try_into().or(Err(ParseError::ParseError))?, order)?;
         let (current_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, current_len.try_into().or(Err(ParseError::ParseError))?)?;
         let current_filter_name = current_filter_name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (current_params, remaining) = crate::x11_utils::parse_list::<render::Fixed>(remaining, current_nparams.try_into().or(Err(ParseError::ParseError))?)?;
+        let (current_params, remaining) = crate::x11_utils::parse_list_with_order::<render::Fixed>(remaining, current_nparams.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetCrtcTransformReply { response_type, sequence, length, pending_transform, has_transforms, current_transform, pending_filter_name, pending_params, current_filter_name, current_params };
         Ok((result, remaining))
     }
@@ -2522,17 +4328,84 @@ impl TryFrom<&[u8]> for GetCrtcTransformReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetCrtcTransformReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.pending_transform.serialize_into_with_order(bytes, order);
+        self.has_transforms.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        self.current_transform.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let pending_len = u16::try_from(self.pending_filter_name.len()).expect("`pending_filter_name` has too many elements");
+        pending_len.serialize_into_with_order(bytes, order);
+        let pending_nparams = u16::try_from(self.pending_params.len()).expect("`pending_params` has too many elements");
+        pending_nparams.serialize_into_with_order(bytes, order);
+        let current_len = u16::try_from(self.current_filter_name.len()).expect("`current_filter_name` has too many elements");
+        current_len.serialize_into_with_order(bytes, order);
+        let current_nparams = u16::try_from(self.current_params.len()).expect("`current_params` has too many elements");
+        current_nparams.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.pending_filter_name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        self.pending_params.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.current_filter_name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        self.current_params.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetPanning request
 pub const GET_PANNING_REQUEST: u8 = 28;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetPanningRequest {
+    pub crtc: Crtc,
+}
+impl GetPanningRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PANNING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetPanningRequest {
+            crtc,
+        })
+    }
+    /// Set the `crtc` field of this structure.
+    pub fn crtc<I>(mut self, value: I) -> Self where I: Into<Crtc> {
+        self.crtc = value.into();
+        self
+    }
+}
+
 pub fn get_panning<Conn>(conn: &Conn, crtc: Crtc) -> Result<Cookie<'_, Conn, GetPanningReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let crtc_bytes = crtc.serialize();
+    let crtc_bytes = crtc.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PANNING_REQUEST,
@@ -2546,9 +4419,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetPanningRequest {
+    type Reply = GetPanningReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetPanningRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetPanningReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_panning(conn, self.crtc)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPanningReply {
@@ -2571,25 +4456,25 @@ pub struct GetPanningReply {
     pub border_bottom: i16,
 }
 impl TryParse for GetPanningReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (left, remaining) = u16::try_parse(remaining)?;
-        let (top, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (track_left, remaining) = u16::try_parse(remaining)?;
-        let (track_top, remaining) = u16::try_parse(remaining)?;
-        let (track_width, remaining) = u16::try_parse(remaining)?;
-        let (track_height, remaining) = u16::try_parse(remaining)?;
-        let (border_left, remaining) = i16::try_parse(remaining)?;
-        let (border_top, remaining) = i16::try_parse(remaining)?;
-        let (border_right, remaining) = i16::try_parse(remaining)?;
-        let (border_bottom, remaining) = i16::try_parse(remaining)?;
-        let status = status.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (border_left, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_top, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_right, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_bottom, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let status = SetConfig::from(status);
         let result = GetPanningReply { response_type, status, sequence, length, timestamp, left, top, width, height, track_left, track_top, track_width, track_height, border_left, border_top, border_right, border_bottom };
         Ok((result, remaining))
     }
@@ -2600,30 +4485,195 @@ impl TryFrom<&[u8]> for GetPanningReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetPanningReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.left.serialize_into_with_order(bytes, order);
+        self.top.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.track_left.serialize_into_with_order(bytes, order);
+        self.track_top.serialize_into_with_order(bytes, order);
+        self.track_width.serialize_into_with_order(bytes, order);
+        self.track_height.serialize_into_with_order(bytes, order);
+        self.border_left.serialize_into_with_order(bytes, order);
+        self.border_top.serialize_into_with_order(bytes, order);
+        self.border_right.serialize_into_with_order(bytes, order);
+        self.border_bottom.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetPanning request
 pub const SET_PANNING_REQUEST: u8 = 29;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetPanningRequest {
+    pub crtc: Crtc,
+    pub timestamp: xproto::Timestamp,
+    pub left: u16,
+    pub top: u16,
+    pub width: u16,
+    pub height: u16,
+    pub track_left: u16,
+    pub track_top: u16,
+    pub track_width: u16,
+    pub track_height: u16,
+    pub border_left: i16,
+    pub border_top: i16,
+    pub border_right: i16,
+    pub border_bottom: i16,
+}
+impl SetPanningRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PANNING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (border_left, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_top, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_right, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_bottom, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetPanningRequest {
+            crtc,
+            timestamp,
+            left,
+            top,
+            width,
+            height,
+            track_left,
+            track_top,
+            track_width,
+            track_height,
+            border_left,
+            border_top,
+            border_right,
+            border_bottom,
+        })
+    }
+    /// Set the `crtc` field of this structure.
+    pub fn crtc<I>(mut self, value: I) -> Self where I: Into<Crtc> {
+        self.crtc = value.into();
+        self
+    }
+    /// Set the `timestamp` field of this structure.
+    pub fn timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.timestamp = value.into();
+        self
+    }
+    /// Set the `left` field of this structure.
+    pub fn left(mut self, value: u16) -> Self {
+        self.left = value;
+        self
+    }
+    /// Set the `top` field of this structure.
+    pub fn top(mut self, value: u16) -> Self {
+        self.top = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `track_left` field of this structure.
+    pub fn track_left(mut self, value: u16) -> Self {
+        self.track_left = value;
+        self
+    }
+    /// Set the `track_top` field of this structure.
+    pub fn track_top(mut self, value: u16) -> Self {
+        self.track_top = value;
+        self
+    }
+    /// Set the `track_width` field of this structure.
+    pub fn track_width(mut self, value: u16) -> Self {
+        self.track_width = value;
+        self
+    }
+    /// Set the `track_height` field of this structure.
+    pub fn track_height(mut self, value: u16) -> Self {
+        self.track_height = value;
+        self
+    }
+    /// Set the `border_left` field of this structure.
+    pub fn border_left(mut self, value: i16) -> Self {
+        self.border_left = value;
+        self
+    }
+    /// Set the `border_top` field of this structure.
+    pub fn border_top(mut self, value: i16) -> Self {
+        self.border_top = value;
+        self
+    }
+    /// Set the `border_right` field of this structure.
+    pub fn border_right(mut self, value: i16) -> Self {
+        self.border_right = value;
+        self
+    }
+    /// Set the `border_bottom` field of this structure.
+    pub fn border_bottom(mut self, value: i16) -> Self {
+        self.border_bottom = value;
+        self
+    }
+}
+
 pub fn set_panning<Conn>(conn: &Conn, crtc: Crtc, timestamp: xproto::Timestamp, left: u16, top: u16, width: u16, height: u16, track_left: u16, track_top: u16, track_width: u16, track_height: u16, border_left: i16, border_top: i16, border_right: i16, border_bottom: i16) -> Result<Cookie<'_, Conn, SetPanningReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let crtc_bytes = crtc.serialize();
-    let timestamp_bytes = timestamp.serialize();
-    let left_bytes = left.serialize();
-    let top_bytes = top.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let track_left_bytes = track_left.serialize();
-    let track_top_bytes = track_top.serialize();
-    let track_width_bytes = track_width.serialize();
-    let track_height_bytes = track_height.serialize();
-    let border_left_bytes = border_left.serialize();
-    let border_top_bytes = border_top.serialize();
-    let border_right_bytes = border_right.serialize();
-    let border_bottom_bytes = border_bottom.serialize();
+    let crtc_bytes = crtc.serialize_with_order(order);
+    let timestamp_bytes = timestamp.serialize_with_order(order);
+    let left_bytes = left.serialize_with_order(order);
+    let top_bytes = top.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let track_left_bytes = track_left.serialize_with_order(order);
+    let track_top_bytes = track_top.serialize_with_order(order);
+    let track_width_bytes = track_width.serialize_with_order(order);
+    let track_height_bytes = track_height.serialize_with_order(order);
+    let border_left_bytes = border_left.serialize_with_order(order);
+    let border_top_bytes = border_top.serialize_with_order(order);
+    let border_right_bytes = border_right.serialize_with_order(order);
+    let border_bottom_bytes = border_bottom.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_PANNING_REQUEST,
@@ -2665,9 +4715,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetPanningRequest {
+    type Reply = SetPanningReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetPanningRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, SetPanningReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_panning(conn, self.crtc, self.timestamp, self.left, self.top, self.width, self.height, self.track_left, self.track_top, self.track_width, self.track_height, self.border_left, self.border_top, self.border_right, self.border_bottom)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetPanningReply {
@@ -2678,13 +4740,13 @@ pub struct SetPanningReply {
     pub timestamp: xproto::Timestamp,
 }
 impl TryParse for SetPanningReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let status = status.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let status = SetConfig::from(status);
         let result = SetPanningReply { response_type, status, sequence, length, timestamp };
         Ok((result, remaining))
     }
@@ -2695,18 +4757,75 @@ impl TryFrom<&[u8]> for SetPanningReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for SetPanningReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetOutputPrimary request
 pub const SET_OUTPUT_PRIMARY_REQUEST: u8 = 30;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetOutputPrimaryRequest {
+    pub window: xproto::Window,
+    pub output: Output,
+}
+impl SetOutputPrimaryRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_OUTPUT_PRIMARY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetOutputPrimaryRequest {
+            window,
+            output,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `output` field of this structure.
+    pub fn output<I>(mut self, value: I) -> Self where I: Into<Output> {
+        self.output = value.into();
+        self
+    }
+}
+
 pub fn set_output_primary<Conn>(conn: &Conn, window: xproto::Window, output: Output) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let output_bytes = output.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let output_bytes = output.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_OUTPUT_PRIMARY_REQUEST,
@@ -2724,20 +4843,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetOutputPrimaryRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetOutputPrimaryRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_output_primary(conn, self.window, self.output)
+    }
+}
 
 /// Opcode for the GetOutputPrimary request
 pub const GET_OUTPUT_PRIMARY_REQUEST: u8 = 31;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetOutputPrimaryRequest {
+    pub window: xproto::Window,
+}
+impl GetOutputPrimaryRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_OUTPUT_PRIMARY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetOutputPrimaryRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn get_output_primary<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetOutputPrimaryReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_OUTPUT_PRIMARY_REQUEST,
@@ -2751,9 +4908,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetOutputPrimaryRequest {
+    type Reply = GetOutputPrimaryReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetOutputPrimaryRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetOutputPrimaryReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_output_primary(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetOutputPrimaryReply {
@@ -2763,12 +4932,12 @@ pub struct GetOutputPrimaryReply {
     pub output: Output,
 }
 impl TryParse for GetOutputPrimaryReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (output, remaining) = Output::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
         let result = GetOutputPrimaryReply { response_type, sequence, length, output };
         Ok((result, remaining))
     }
@@ -2779,17 +4948,66 @@ impl TryFrom<&[u8]> for GetOutputPrimaryReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetOutputPrimaryReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.output.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetProviders request
 pub const GET_PROVIDERS_REQUEST: u8 = 32;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetProvidersRequest {
+    pub window: xproto::Window,
+}
+impl GetProvidersRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PROVIDERS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetProvidersRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn get_providers<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetProvidersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PROVIDERS_REQUEST,
@@ -2803,9 +5021,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetProvidersRequest {
+    type Reply = GetProvidersReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetProvidersRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetProvidersReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_providers(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetProvidersReply {
@@ -2816,15 +5046,15 @@ pub struct GetProvidersReply {
     pub providers: Vec<Provider>,
 }
 impl TryParse for GetProvidersReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_providers, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (num_providers, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(18..).ok_or(ParseError::ParseError)?;
-        let (providers, remaining) = crate::x11_utils::parse_list::<Provider>(remaining, num_providers.try_into().or(Err(ParseError::ParseError))?)?;
+        let (providers, remaining) = crate::x11_utils::parse_list_with_order::<Provider>(remaining, num_providers.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetProvidersReply { response_type, sequence, length, timestamp, providers };
         Ok((result, remaining))
     }
@@ -2835,87 +5065,95 @@ impl TryFrom<&[u8]> for GetProvidersReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ProviderCapability {
-    SourceOutput = 1 << 0,
-    SinkOutput = 1 << 1,
-    SourceOffload = 1 << 2,
-    SinkOffload = 1 << 3,
-}
-impl From<ProviderCapability> for u8 {
-    fn from(input: ProviderCapability) -> Self {
-        match input {
-            ProviderCapability::SourceOutput => 1 << 0,
-            ProviderCapability::SinkOutput => 1 << 1,
-            ProviderCapability::SourceOffload => 1 << 2,
-            ProviderCapability::SinkOffload => 1 << 3,
-        }
-    }
-}
-impl From<ProviderCapability> for Option<u8> {
-    fn from(input: ProviderCapability) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<ProviderCapability> for u16 {
-    fn from(input: ProviderCapability) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<ProviderCapability> for Option<u16> {
-    fn from(input: ProviderCapability) -> Self {
-        Some(u16::from(input))
+impl Serialize for GetProvidersReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
-impl From<ProviderCapability> for u32 {
-    fn from(input: ProviderCapability) -> Self {
-        Self::from(u8::from(input))
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        let num_providers = u16::try_from(self.providers.len()).expect("`providers` has too many elements");
+        num_providers.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 18]);
+        self.providers.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ProviderCapability(u32);
+#[allow(non_upper_case_globals)]
+impl ProviderCapability {
+    pub const SourceOutput: Self = Self(1 << 0);
+    pub const SinkOutput: Self = Self(1 << 1);
+    pub const SourceOffload: Self = Self(1 << 2);
+    pub const SinkOffload: Self = Self(1 << 3);
+}
+bitmask_set!(ProviderCapability, u32, [SourceOutput, SinkOutput, SourceOffload, SinkOffload]);
 impl From<ProviderCapability> for Option<u32> {
     fn from(input: ProviderCapability) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ProviderCapability {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ProviderCapability::SourceOutput),
-            2 => Ok(ProviderCapability::SinkOutput),
-            4 => Ok(ProviderCapability::SourceOffload),
-            8 => Ok(ProviderCapability::SinkOffload),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ProviderCapability {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ProviderCapability {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-bitmask_binop!(ProviderCapability, u8);
 
 /// Opcode for the GetProviderInfo request
 pub const GET_PROVIDER_INFO_REQUEST: u8 = 33;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetProviderInfoRequest {
+    pub provider: Provider,
+    pub config_timestamp: xproto::Timestamp,
+}
+impl GetProviderInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PROVIDER_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetProviderInfoRequest {
+            provider,
+            config_timestamp,
+        })
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.provider = value.into();
+        self
+    }
+    /// Set the `config_timestamp` field of this structure.
+    pub fn config_timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.config_timestamp = value.into();
+        self
+    }
+}
+
 pub fn get_provider_info<Conn>(conn: &Conn, provider: Provider, config_timestamp: xproto::Timestamp) -> Result<Cookie<'_, Conn, GetProviderInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let provider_bytes = provider.serialize();
-    let config_timestamp_bytes = config_timestamp.serialize();
+    let provider_bytes = provider.serialize_with_order(order);
+    let config_timestamp_bytes = config_timestamp.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PROVIDER_INFO_REQUEST,
@@ -2933,9 +5171,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetProviderInfoRequest {
+    type Reply = GetProviderInfoReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetProviderInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetProviderInfoReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_provider_info(conn, self.provider, self.config_timestamp)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetProviderInfoReply {
@@ -2944,7 +5194,7 @@ pub struct GetProviderInfoReply {
     pub sequence: u16,
     pub length: u32,
     pub timestamp: xproto::Timestamp,
-    pub capabilities: u32,
+    pub capabilities: ProviderCapability,
     pub crtcs: Vec<Crtc>,
     pub outputs: Vec<Output>,
     pub associated_providers: Vec<Provider>,
@@ -2952,24 +5202,25 @@ pub struct GetProviderInfoReply {
     pub name: Vec<u8>,
 }
 impl TryParse for GetProviderInfoReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (capabilities, remaining) = u32::try_parse(remaining)?;
-        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
-        let (num_outputs, remaining) = u16::try_parse(remaining)?;
-        let (num_associated_providers, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (capabilities, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_associated_providers, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ParseError))?)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ParseError))?)?;
-        let (associated_providers, remaining) = crate::x11_utils::parse_list::<Provider>(remaining, num_associated_providers.try_into().or(Err(ParseError::ParseError))?)?;
-        let (associated_capability, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_associated_providers.try_into().or(Err(ParseError::ParseError))?)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (associated_providers, remaining) = crate::x11_utils::parse_list_with_order::<Provider>(remaining, num_associated_providers.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (associated_capability, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_associated_providers.try_into().or(Err(ParseError::ParseError))?, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
+        let capabilities = ProviderCapability::from(capabilities);
         let result = GetProviderInfoReply { response_type, status, sequence, length, timestamp, capabilities, crtcs, outputs, associated_providers, associated_capability, name };
         Ok((result, remaining))
     }
@@ -2980,19 +5231,100 @@ impl TryFrom<&[u8]> for GetProviderInfoReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetProviderInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.status.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        u32::from(self.capabilities).serialize_into_with_order(bytes, order);
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        num_crtcs.serialize_into_with_order(bytes, order);
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        num_outputs.serialize_into_with_order(bytes, order);
+        let num_associated_providers = u16::try_from(self.associated_providers.len()).expect("`associated_providers` has too many elements");
+        num_associated_providers.serialize_into_with_order(bytes, order);
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        name_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.crtcs.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
+        self.associated_providers.serialize_into_with_order(bytes, order);
+        assert_eq!(self.associated_capability.len(), usize::try_from(num_associated_providers).unwrap(), "`associated_capability` has an incorrect length");
+        self.associated_capability.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetProviderOffloadSink request
 pub const SET_PROVIDER_OFFLOAD_SINK_REQUEST: u8 = 34;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetProviderOffloadSinkRequest {
+    pub provider: Provider,
+    pub sink_provider: Provider,
+    pub config_timestamp: xproto::Timestamp,
+}
+impl SetProviderOffloadSinkRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PROVIDER_OFFLOAD_SINK_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (sink_provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetProviderOffloadSinkRequest {
+            provider,
+            sink_provider,
+            config_timestamp,
+        })
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.provider = value.into();
+        self
+    }
+    /// Set the `sink_provider` field of this structure.
+    pub fn sink_provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.sink_provider = value.into();
+        self
+    }
+    /// Set the `config_timestamp` field of this structure.
+    pub fn config_timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.config_timestamp = value.into();
+        self
+    }
+}
+
 pub fn set_provider_offload_sink<Conn>(conn: &Conn, provider: Provider, sink_provider: Provider, config_timestamp: xproto::Timestamp) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let provider_bytes = provider.serialize();
-    let sink_provider_bytes = sink_provider.serialize();
-    let config_timestamp_bytes = config_timestamp.serialize();
+    let provider_bytes = provider.serialize_with_order(order);
+    let sink_provider_bytes = sink_provider.serialize_with_order(order);
+    let config_timestamp_bytes = config_timestamp.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_PROVIDER_OFFLOAD_SINK_REQUEST,
@@ -3014,22 +5346,76 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetProviderOffloadSinkRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetProviderOffloadSinkRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_provider_offload_sink(conn, self.provider, self.sink_provider, self.config_timestamp)
+    }
+}
 
 /// Opcode for the SetProviderOutputSource request
 pub const SET_PROVIDER_OUTPUT_SOURCE_REQUEST: u8 = 35;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetProviderOutputSourceRequest {
+    pub provider: Provider,
+    pub source_provider: Provider,
+    pub config_timestamp: xproto::Timestamp,
+}
+impl SetProviderOutputSourceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PROVIDER_OUTPUT_SOURCE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (source_provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetProviderOutputSourceRequest {
+            provider,
+            source_provider,
+            config_timestamp,
+        })
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.provider = value.into();
+        self
+    }
+    /// Set the `source_provider` field of this structure.
+    pub fn source_provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.source_provider = value.into();
+        self
+    }
+    /// Set the `config_timestamp` field of this structure.
+    pub fn config_timestamp<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.config_timestamp = value.into();
+        self
+    }
+}
+
 pub fn set_provider_output_source<Conn>(conn: &Conn, provider: Provider, source_provider: Provider, config_timestamp: xproto::Timestamp) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let provider_bytes = provider.serialize();
-    let source_provider_bytes = source_provider.serialize();
-    let config_timestamp_bytes = config_timestamp.serialize();
+    let provider_bytes = provider.serialize_with_order(order);
+    let source_provider_bytes = source_provider.serialize_with_order(order);
+    let config_timestamp_bytes = config_timestamp.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_PROVIDER_OUTPUT_SOURCE_REQUEST,
@@ -3051,20 +5437,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetProviderOutputSourceRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetProviderOutputSourceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_provider_output_source(conn, self.provider, self.source_provider, self.config_timestamp)
+    }
+}
 
 /// Opcode for the ListProviderProperties request
 pub const LIST_PROVIDER_PROPERTIES_REQUEST: u8 = 36;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListProviderPropertiesRequest {
+    pub provider: Provider,
+}
+impl ListProviderPropertiesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_PROVIDER_PROPERTIES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ListProviderPropertiesRequest {
+            provider,
+        })
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.provider = value.into();
+        self
+    }
+}
+
 pub fn list_provider_properties<Conn>(conn: &Conn, provider: Provider) -> Result<Cookie<'_, Conn, ListProviderPropertiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let provider_bytes = provider.serialize();
+    let provider_bytes = provider.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         LIST_PROVIDER_PROPERTIES_REQUEST,
@@ -3078,9 +5502,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ListProviderPropertiesRequest {
+    type Reply = ListProviderPropertiesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ListProviderPropertiesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ListProviderPropertiesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        list_provider_properties(conn, self.provider)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListProviderPropertiesReply {
@@ -3090,14 +5526,14 @@ pub struct ListProviderPropertiesReply {
     pub atoms: Vec<xproto::Atom>,
 }
 impl TryParse for ListProviderPropertiesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_atoms, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_atoms, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (atoms, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_atoms.try_into().or(Err(ParseError::ParseError))?)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, num_atoms.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ListProviderPropertiesReply { response_type, sequence, length, atoms };
         Ok((result, remaining))
     }
@@ -3108,18 +5544,78 @@ impl TryFrom<&[u8]> for ListProviderPropertiesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ListProviderPropertiesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_atoms = u16::try_from(self.atoms.len()).expect("`atoms` has too many elements");
+        num_atoms.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.atoms.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryProviderProperty request
 pub const QUERY_PROVIDER_PROPERTY_REQUEST: u8 = 37;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryProviderPropertyRequest {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+}
+impl QueryProviderPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryProviderPropertyRequest {
+            provider,
+            property,
+        })
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.provider = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+}
+
 pub fn query_provider_property<Conn>(conn: &Conn, provider: Provider, property: xproto::Atom) -> Result<Cookie<'_, Conn, QueryProviderPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let provider_bytes = provider.serialize();
-    let property_bytes = property.serialize();
+    let provider_bytes = provider.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_PROVIDER_PROPERTY_REQUEST,
@@ -3137,9 +5633,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryProviderPropertyRequest {
+    type Reply = QueryProviderPropertyReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryProviderPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryProviderPropertyReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_provider_property(conn, self.provider, self.property)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryProviderPropertyReply {
@@ -3151,16 +5659,16 @@ pub struct QueryProviderPropertyReply {
     pub valid_values: Vec<i32>,
 }
 impl TryParse for QueryProviderPropertyReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (pending, remaining) = bool::try_parse(remaining)?;
-        let (range, remaining) = bool::try_parse(remaining)?;
-        let (immutable, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (immutable, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(21..).ok_or(ParseError::ParseError)?;
-        let (valid_values, remaining) = crate::x11_utils::parse_list::<i32>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (valid_values, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryProviderPropertyReply { response_type, sequence, pending, range, immutable, valid_values };
         Ok((result, remaining))
     }
@@ -3171,20 +5679,114 @@ impl TryFrom<&[u8]> for QueryProviderPropertyReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryProviderPropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.valid_values.len()).expect("`valid_values` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.pending.serialize_into_with_order(bytes, order);
+        self.range.serialize_into_with_order(bytes, order);
+        self.immutable.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 21]);
+        self.valid_values.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ConfigureProviderProperty request
 pub const CONFIGURE_PROVIDER_PROPERTY_REQUEST: u8 = 38;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ConfigureProviderPropertyRequest {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+    pub pending: bool,
+    pub range: bool,
+    pub values: Vec<i32>,
+}
+impl ConfigureProviderPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CONFIGURE_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut values = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = i32::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            values.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(ConfigureProviderPropertyRequest {
+            provider,
+            property,
+            pending,
+            range,
+            values,
+        })
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.provider = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `pending` field of this structure.
+    pub fn pending(mut self, value: bool) -> Self {
+        self.pending = value;
+        self
+    }
+    /// Set the `range` field of this structure.
+    pub fn range(mut self, value: bool) -> Self {
+        self.range = value;
+        self
+    }
+    /// Set the `values` field of this structure.
+    pub fn values<I>(mut self, value: I) -> Self where I: Into<Vec<i32>> {
+        self.values = value.into();
+        self
+    }
+}
+
 pub fn configure_provider_property<'c, Conn>(conn: &'c Conn, provider: Provider, property: xproto::Atom, pending: bool, range: bool, values: &[i32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let provider_bytes = provider.serialize();
-    let property_bytes = property.serialize();
-    let pending_bytes = pending.serialize();
-    let range_bytes = range.serialize();
+    let provider_bytes = provider.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
+    let pending_bytes = pending.serialize_with_order(order);
+    let range_bytes = range.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CONFIGURE_PROVIDER_PROPERTY_REQUEST,
@@ -3204,31 +5806,119 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let values_bytes = values.serialize();
+    let values_bytes = values.serialize_with_order(order);
     let length_so_far = length_so_far + values_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&values_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ConfigureProviderPropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ConfigureProviderPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        configure_provider_property(conn, self.provider, self.property, self.pending, self.range, &self.values)
+    }
+}
 
 /// Opcode for the ChangeProviderProperty request
 pub const CHANGE_PROVIDER_PROPERTY_REQUEST: u8 = 39;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChangeProviderPropertyRequest {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub format: u8,
+    pub mode: u8,
+    pub num_items: u32,
+    pub data: Vec<u8>,
+}
+impl ChangeProviderPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::ParseError)?).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let data = data.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangeProviderPropertyRequest {
+            provider,
+            property,
+            type_,
+            format,
+            mode,
+            num_items,
+            data,
+        })
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.provider = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.type_ = value.into();
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u8) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode(mut self, value: u8) -> Self {
+        self.mode = value;
+        self
+    }
+    /// Set the `num_items` field of this structure.
+    pub fn num_items(mut self, value: u32) -> Self {
+        self.num_items = value;
+        self
+    }
+    /// Set the `data` field of this structure.
+    pub fn data<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.data = value.into();
+        self
+    }
+}
+
 pub fn change_provider_property<'c, Conn>(conn: &'c Conn, provider: Provider, property: xproto::Atom, type_: xproto::Atom, format: u8, mode: u8, num_items: u32, data: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let provider_bytes = provider.serialize();
-    let property_bytes = property.serialize();
-    let type_bytes = type_.serialize();
-    let format_bytes = format.serialize();
-    let mode_bytes = mode.serialize();
-    let num_items_bytes = num_items.serialize();
+    let provider_bytes = provider.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let mode_bytes = mode.serialize_with_order(order);
+    let num_items_bytes = num_items.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_PROVIDER_PROPERTY_REQUEST,
@@ -3262,21 +5952,67 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(data), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeProviderPropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeProviderPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_provider_property(conn, self.provider, self.property, self.type_, self.format, self.mode, self.num_items, &self.data)
+    }
+}
 
 /// Opcode for the DeleteProviderProperty request
 pub const DELETE_PROVIDER_PROPERTY_REQUEST: u8 = 40;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeleteProviderPropertyRequest {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+}
+impl DeleteProviderPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeleteProviderPropertyRequest {
+            provider,
+            property,
+        })
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.provider = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+}
+
 pub fn delete_provider_property<Conn>(conn: &Conn, provider: Provider, property: xproto::Atom) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let provider_bytes = provider.serialize();
-    let property_bytes = property.serialize();
+    let provider_bytes = provider.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_PROVIDER_PROPERTY_REQUEST,
@@ -3294,26 +6030,113 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteProviderPropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteProviderPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_provider_property(conn, self.provider, self.property)
+    }
+}
 
 /// Opcode for the GetProviderProperty request
 pub const GET_PROVIDER_PROPERTY_REQUEST: u8 = 41;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetProviderPropertyRequest {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub long_offset: u32,
+    pub long_length: u32,
+    pub delete: bool,
+    pub pending: bool,
+}
+impl GetProviderPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (long_offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (long_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (delete, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetProviderPropertyRequest {
+            provider,
+            property,
+            type_,
+            long_offset,
+            long_length,
+            delete,
+            pending,
+        })
+    }
+    /// Set the `provider` field of this structure.
+    pub fn provider<I>(mut self, value: I) -> Self where I: Into<Provider> {
+        self.provider = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.type_ = value.into();
+        self
+    }
+    /// Set the `long_offset` field of this structure.
+    pub fn long_offset(mut self, value: u32) -> Self {
+        self.long_offset = value;
+        self
+    }
+    /// Set the `long_length` field of this structure.
+    pub fn long_length(mut self, value: u32) -> Self {
+        self.long_length = value;
+        self
+    }
+    /// Set the `delete` field of this structure.
+    pub fn delete(mut self, value: bool) -> Self {
+        self.delete = value;
+        self
+    }
+    /// Set the `pending` field of this structure.
+    pub fn pending(mut self, value: bool) -> Self {
+        self.pending = value;
+        self
+    }
+}
+
 pub fn get_provider_property<Conn>(conn: &Conn, provider: Provider, property: xproto::Atom, type_: xproto::Atom, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> Result<Cookie<'_, Conn, GetProviderPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let provider_bytes = provider.serialize();
-    let property_bytes = property.serialize();
-    let type_bytes = type_.serialize();
-    let long_offset_bytes = long_offset.serialize();
-    let long_length_bytes = long_length.serialize();
-    let delete_bytes = delete.serialize();
-    let pending_bytes = pending.serialize();
+    let provider_bytes = provider.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let long_offset_bytes = long_offset.serialize_with_order(order);
+    let long_length_bytes = long_length.serialize_with_order(order);
+    let delete_bytes = delete.serialize_with_order(order);
+    let pending_bytes = pending.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PROVIDER_PROPERTY_REQUEST,
@@ -3347,9 +6170,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetProviderPropertyRequest {
+    type Reply = GetProviderPropertyReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetProviderPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetProviderPropertyReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_provider_property(conn, self.provider, self.property, self.type_, self.long_offset, self.long_length, self.delete, self.pending)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetProviderPropertyReply {
@@ -3363,14 +6198,14 @@ pub struct GetProviderPropertyReply {
     pub data: Vec<u8>,
 }
 impl TryParse for GetProviderPropertyReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (bytes_after, remaining) = u32::try_parse(remaining)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (bytes_after, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::ParseError)?).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -3384,13 +6219,41 @@ impl TryFrom<&[u8]> for GetProviderPropertyReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetProviderPropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.format.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.bytes_after.serialize_into_with_order(bytes, order);
+        self.num_items.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        assert_eq!(self.data.len(), usize::try_from(self.num_items.checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`data` has an incorrect length");
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ScreenChangeNotify event
 pub const SCREEN_CHANGE_NOTIFY_EVENT: u8 = 0;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ScreenChangeNotifyEvent {
     pub response_type: u8,
-    pub rotation: u8,
+    pub rotation: Rotation,
     pub sequence: u16,
     pub timestamp: xproto::Timestamp,
     pub config_timestamp: xproto::Timestamp,
@@ -3404,21 +6267,22 @@ pub struct ScreenChangeNotifyEvent {
     pub mheight: u16,
 }
 impl TryParse for ScreenChangeNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (rotation, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (request_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (size_id, remaining) = u16::try_parse(remaining)?;
-        let (subpixel_order, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (mwidth, remaining) = u16::try_parse(remaining)?;
-        let (mheight, remaining) = u16::try_parse(remaining)?;
-        let subpixel_order = subpixel_order.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (request_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (size_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (subpixel_order, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mwidth, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mheight, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let rotation = Rotation::from(u16::from(rotation));
+        let subpixel_order = render::SubPixel::from(u32::from(subpixel_order));
         let result = ScreenChangeNotifyEvent { response_type, rotation, sequence, timestamp, config_timestamp, root, request_window, size_id, subpixel_order, width, height, mwidth, mheight };
         Ok((result, remaining))
     }
@@ -3431,19 +6295,30 @@ impl TryFrom<&[u8]> for ScreenChangeNotifyEvent {
 }
 impl From<&ScreenChangeNotifyEvent> for [u8; 32] {
     fn from(input: &ScreenChangeNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let rotation_bytes = input.rotation.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let timestamp_bytes = input.timestamp.serialize();
-        let config_timestamp_bytes = input.config_timestamp.serialize();
-        let root_bytes = input.root.serialize();
-        let request_window_bytes = input.request_window.serialize();
-        let size_id_bytes = input.size_id.serialize();
-        let subpixel_order_bytes = u16::from(input.subpixel_order).serialize();
-        let width_bytes = input.width.serialize();
-        let height_bytes = input.height.serialize();
-        let mwidth_bytes = input.mwidth.serialize();
-        let mheight_bytes = input.mheight.serialize();
+        input.serialize()
+    }
+}
+impl From<ScreenChangeNotifyEvent> for [u8; 32] {
+    fn from(input: ScreenChangeNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ScreenChangeNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let rotation_bytes = u8::try_from(u16::from(self.rotation)).unwrap().serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let root_bytes = self.root.serialize_with_order(order);
+        let request_window_bytes = self.request_window.serialize_with_order(order);
+        let size_id_bytes = self.size_id.serialize_with_order(order);
+        let subpixel_order_bytes = u16::try_from(u32::from(self.subpixel_order)).unwrap().serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let mwidth_bytes = self.mwidth.serialize_with_order(order);
+        let mheight_bytes = self.mheight.serialize_with_order(order);
         [
             response_type_bytes[0],
             rotation_bytes[0],
@@ -3479,87 +6354,47 @@ impl From<&ScreenChangeNotifyEvent> for [u8; 32] {
             mheight_bytes[1],
         ]
     }
-}
-impl From<ScreenChangeNotifyEvent> for [u8; 32] {
-    fn from(input: ScreenChangeNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Notify {
-    CrtcChange = 0,
-    OutputChange = 1,
-    OutputProperty = 2,
-    ProviderChange = 3,
-    ProviderProperty = 4,
-    ResourceChange = 5,
-    Lease = 6,
-}
-impl From<Notify> for u8 {
-    fn from(input: Notify) -> Self {
-        match input {
-            Notify::CrtcChange => 0,
-            Notify::OutputChange => 1,
-            Notify::OutputProperty => 2,
-            Notify::ProviderChange => 3,
-            Notify::ProviderProperty => 4,
-            Notify::ResourceChange => 5,
-            Notify::Lease => 6,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Notify(u8);
+#[allow(non_upper_case_globals)]
+impl Notify {
+    pub const CrtcChange: Self = Self(0);
+    pub const OutputChange: Self = Self(1);
+    pub const OutputProperty: Self = Self(2);
+    pub const ProviderChange: Self = Self(3);
+    pub const ProviderProperty: Self = Self(4);
+    pub const ResourceChange: Self = Self(5);
+    pub const Lease: Self = Self(6);
+}
+enum_newtype!(Notify, u8, [CrtcChange, OutputChange, OutputProperty, ProviderChange, ProviderProperty, ResourceChange, Lease]);
 impl From<Notify> for Option<u8> {
     fn from(input: Notify) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Notify> for u16 {
     fn from(input: Notify) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Notify> for Option<u16> {
     fn from(input: Notify) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Notify> for u32 {
     fn from(input: Notify) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Notify> for Option<u32> {
     fn from(input: Notify) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Notify {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Notify::CrtcChange),
-            1 => Ok(Notify::OutputChange),
-            2 => Ok(Notify::OutputProperty),
-            3 => Ok(Notify::ProviderChange),
-            4 => Ok(Notify::ProviderProperty),
-            5 => Ok(Notify::ResourceChange),
-            6 => Ok(Notify::Lease),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Notify {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Notify {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -3569,24 +6404,25 @@ pub struct CrtcChange {
     pub window: xproto::Window,
     pub crtc: Crtc,
     pub mode: Mode,
-    pub rotation: u16,
+    pub rotation: Rotation,
     pub x: i16,
     pub y: i16,
     pub width: u16,
     pub height: u16,
 }
 impl TryParse for CrtcChange {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (crtc, remaining) = Crtc::try_parse(remaining)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let rotation = Rotation::from(rotation);
         let result = CrtcChange { timestamp, window, crtc, mode, rotation, x, y, width, height };
         Ok((result, remaining))
     }
@@ -3599,16 +6435,16 @@ impl TryFrom<&[u8]> for CrtcChange {
 }
 impl Serialize for CrtcChange {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let timestamp_bytes = self.timestamp.serialize();
-        let window_bytes = self.window.serialize();
-        let crtc_bytes = self.crtc.serialize();
-        let mode_bytes = self.mode.serialize();
-        let rotation_bytes = self.rotation.serialize();
-        let x_bytes = self.x.serialize();
-        let y_bytes = self.y.serialize();
-        let width_bytes = self.width.serialize();
-        let height_bytes = self.height.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let rotation_bytes = u16::from(self.rotation).serialize_with_order(order);
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
         [
             timestamp_bytes[0],
             timestamp_bytes[1],
@@ -3640,18 +6476,18 @@ impl Serialize for CrtcChange {
             height_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        self.timestamp.serialize_into(bytes);
-        self.window.serialize_into(bytes);
-        self.crtc.serialize_into(bytes);
-        self.mode.serialize_into(bytes);
-        self.rotation.serialize_into(bytes);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.crtc.serialize_into_with_order(bytes, order);
+        self.mode.serialize_into_with_order(bytes, order);
+        u16::from(self.rotation).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.x.serialize_into(bytes);
-        self.y.serialize_into(bytes);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3663,23 +6499,24 @@ pub struct OutputChange {
     pub output: Output,
     pub crtc: Crtc,
     pub mode: Mode,
-    pub rotation: u16,
+    pub rotation: Rotation,
     pub connection: Connection,
     pub subpixel_order: render::SubPixel,
 }
 impl TryParse for OutputChange {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (output, remaining) = Output::try_parse(remaining)?;
-        let (crtc, remaining) = Crtc::try_parse(remaining)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
-        let (connection, remaining) = u8::try_parse(remaining)?;
-        let (subpixel_order, remaining) = u8::try_parse(remaining)?;
-        let connection = connection.try_into()?;
-        let subpixel_order = subpixel_order.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (connection, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (subpixel_order, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let rotation = Rotation::from(rotation);
+        let connection = Connection::from(connection);
+        let subpixel_order = render::SubPixel::from(u32::from(subpixel_order));
         let result = OutputChange { timestamp, config_timestamp, window, output, crtc, mode, rotation, connection, subpixel_order };
         Ok((result, remaining))
     }
@@ -3692,16 +6529,16 @@ impl TryFrom<&[u8]> for OutputChange {
 }
 impl Serialize for OutputChange {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let timestamp_bytes = self.timestamp.serialize();
-        let config_timestamp_bytes = self.config_timestamp.serialize();
-        let window_bytes = self.window.serialize();
-        let output_bytes = self.output.serialize();
-        let crtc_bytes = self.crtc.serialize();
-        let mode_bytes = self.mode.serialize();
-        let rotation_bytes = self.rotation.serialize();
-        let connection_bytes = u8::from(self.connection).serialize();
-        let subpixel_order_bytes = u8::from(self.subpixel_order).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let output_bytes = self.output.serialize_with_order(order);
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let rotation_bytes = u16::from(self.rotation).serialize_with_order(order);
+        let connection_bytes = u8::from(self.connection).serialize_with_order(order);
+        let subpixel_order_bytes = u8::try_from(u32::from(self.subpixel_order)).unwrap().serialize_with_order(order);
         [
             timestamp_bytes[0],
             timestamp_bytes[1],
@@ -3733,17 +6570,17 @@ impl Serialize for OutputChange {
             subpixel_order_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        self.timestamp.serialize_into(bytes);
-        self.config_timestamp.serialize_into(bytes);
-        self.window.serialize_into(bytes);
-        self.output.serialize_into(bytes);
-        self.crtc.serialize_into(bytes);
-        self.mode.serialize_into(bytes);
-        self.rotation.serialize_into(bytes);
-        u8::from(self.connection).serialize_into(bytes);
-        u8::from(self.subpixel_order).serialize_into(bytes);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.output.serialize_into_with_order(bytes, order);
+        self.crtc.serialize_into_with_order(bytes, order);
+        self.mode.serialize_into_with_order(bytes, order);
+        u16::from(self.rotation).serialize_into_with_order(bytes, order);
+        u8::from(self.connection).serialize_into_with_order(bytes, order);
+        u8::try_from(u32::from(self.subpixel_order)).unwrap().serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3756,14 +6593,14 @@ pub struct OutputProperty {
     pub status: xproto::Property,
 }
 impl TryParse for OutputProperty {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (output, remaining) = Output::try_parse(remaining)?;
-        let (atom, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (atom, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(11..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::Property::from(status);
         let result = OutputProperty { window, output, atom, timestamp, status };
         Ok((result, remaining))
     }
@@ -3776,12 +6613,12 @@ impl TryFrom<&[u8]> for OutputProperty {
 }
 impl Serialize for OutputProperty {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let window_bytes = self.window.serialize();
-        let output_bytes = self.output.serialize();
-        let atom_bytes = self.atom.serialize();
-        let timestamp_bytes = self.timestamp.serialize();
-        let status_bytes = u8::from(self.status).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let window_bytes = self.window.serialize_with_order(order);
+        let output_bytes = self.output.serialize_with_order(order);
+        let atom_bytes = self.atom.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let status_bytes = u8::from(self.status).serialize_with_order(order);
         [
             window_bytes[0],
             window_bytes[1],
@@ -3813,13 +6650,13 @@ impl Serialize for OutputProperty {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        self.window.serialize_into(bytes);
-        self.output.serialize_into(bytes);
-        self.atom.serialize_into(bytes);
-        self.timestamp.serialize_into(bytes);
-        u8::from(self.status).serialize_into(bytes);
+        self.window.serialize_into_with_order(bytes, order);
+        self.output.serialize_into_with_order(bytes, order);
+        self.atom.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 11]);
     }
 }
@@ -3831,10 +6668,10 @@ pub struct ProviderChange {
     pub provider: Provider,
 }
 impl TryParse for ProviderChange {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (provider, remaining) = Provider::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = ProviderChange { timestamp, window, provider };
         Ok((result, remaining))
@@ -3848,10 +6685,10 @@ impl TryFrom<&[u8]> for ProviderChange {
 }
 impl Serialize for ProviderChange {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let timestamp_bytes = self.timestamp.serialize();
-        let window_bytes = self.window.serialize();
-        let provider_bytes = self.provider.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let provider_bytes = self.provider.serialize_with_order(order);
         [
             timestamp_bytes[0],
             timestamp_bytes[1],
@@ -3883,11 +6720,11 @@ impl Serialize for ProviderChange {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        self.timestamp.serialize_into(bytes);
-        self.window.serialize_into(bytes);
-        self.provider.serialize_into(bytes);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.provider.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 16]);
     }
 }
@@ -3901,12 +6738,12 @@ pub struct ProviderProperty {
     pub state: u8,
 }
 impl TryParse for ProviderProperty {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (provider, remaining) = Provider::try_parse(remaining)?;
-        let (atom, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (atom, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(11..).ok_or(ParseError::ParseError)?;
         let result = ProviderProperty { window, provider, atom, timestamp, state };
         Ok((result, remaining))
@@ -3920,12 +6757,12 @@ impl TryFrom<&[u8]> for ProviderProperty {
 }
 impl Serialize for ProviderProperty {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let window_bytes = self.window.serialize();
-        let provider_bytes = self.provider.serialize();
-        let atom_bytes = self.atom.serialize();
-        let timestamp_bytes = self.timestamp.serialize();
-        let state_bytes = self.state.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let window_bytes = self.window.serialize_with_order(order);
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let atom_bytes = self.atom.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let state_bytes = self.state.serialize_with_order(order);
         [
             window_bytes[0],
             window_bytes[1],
@@ -3957,13 +6794,13 @@ impl Serialize for ProviderProperty {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        self.window.serialize_into(bytes);
-        self.provider.serialize_into(bytes);
-        self.atom.serialize_into(bytes);
-        self.timestamp.serialize_into(bytes);
-        self.state.serialize_into(bytes);
+        self.window.serialize_into_with_order(bytes, order);
+        self.provider.serialize_into_with_order(bytes, order);
+        self.atom.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.state.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 11]);
     }
 }
@@ -3974,9 +6811,9 @@ pub struct ResourceChange {
     pub window: xproto::Window,
 }
 impl TryParse for ResourceChange {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = ResourceChange { timestamp, window };
         Ok((result, remaining))
@@ -3990,9 +6827,9 @@ impl TryFrom<&[u8]> for ResourceChange {
 }
 impl Serialize for ResourceChange {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let timestamp_bytes = self.timestamp.serialize();
-        let window_bytes = self.window.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
         [
             timestamp_bytes[0],
             timestamp_bytes[1],
@@ -4024,10 +6861,10 @@ impl Serialize for ResourceChange {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        self.timestamp.serialize_into(bytes);
-        self.window.serialize_into(bytes);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 20]);
     }
 }
@@ -4046,18 +6883,18 @@ pub struct MonitorInfo {
     pub outputs: Vec<Output>,
 }
 impl TryParse for MonitorInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (primary, remaining) = bool::try_parse(remaining)?;
-        let (automatic, remaining) = bool::try_parse(remaining)?;
-        let (n_output, remaining) = u16::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (width_in_millimeters, remaining) = u32::try_parse(remaining)?;
-        let (height_in_millimeters, remaining) = u32::try_parse(remaining)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, n_output.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (primary, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (automatic, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (n_output, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width_in_millimeters, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (height_in_millimeters, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, n_output.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = MonitorInfo { name, primary, automatic, x, y, width, height, width_in_millimeters, height_in_millimeters, outputs };
         Ok((result, remaining))
     }
@@ -4070,39 +6907,73 @@ impl TryFrom<&[u8]> for MonitorInfo {
 }
 impl Serialize for MonitorInfo {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(24);
-        self.name.serialize_into(bytes);
-        self.primary.serialize_into(bytes);
-        self.automatic.serialize_into(bytes);
+        self.name.serialize_into_with_order(bytes, order);
+        self.primary.serialize_into_with_order(bytes, order);
+        self.automatic.serialize_into_with_order(bytes, order);
         let n_output = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
-        n_output.serialize_into(bytes);
-        self.x.serialize_into(bytes);
-        self.y.serialize_into(bytes);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
-        self.width_in_millimeters.serialize_into(bytes);
-        self.height_in_millimeters.serialize_into(bytes);
-        self.outputs.serialize_into(bytes);
+        n_output.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.width_in_millimeters.serialize_into_with_order(bytes, order);
+        self.height_in_millimeters.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the GetMonitors request
 pub const GET_MONITORS_REQUEST: u8 = 42;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMonitorsRequest {
+    pub window: xproto::Window,
+    pub get_active: bool,
+}
+impl GetMonitorsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MONITORS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (get_active, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMonitorsRequest {
+            window,
+            get_active,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `get_active` field of this structure.
+    pub fn get_active(mut self, value: bool) -> Self {
+        self.get_active = value;
+        self
+    }
+}
+
 pub fn get_monitors<Conn>(conn: &Conn, window: xproto::Window, get_active: bool) -> Result<Cookie<'_, Conn, GetMonitorsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let get_active_bytes = get_active.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let get_active_bytes = get_active.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MONITORS_REQUEST,
@@ -4120,9 +6991,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMonitorsRequest {
+    type Reply = GetMonitorsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMonitorsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMonitorsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_monitors(conn, self.window, self.get_active)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMonitorsReply {
@@ -4134,16 +7017,16 @@ pub struct GetMonitorsReply {
     pub monitors: Vec<MonitorInfo>,
 }
 impl TryParse for GetMonitorsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (n_monitors, remaining) = u32::try_parse(remaining)?;
-        let (n_outputs, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (n_monitors, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_outputs, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (monitors, remaining) = crate::x11_utils::parse_list::<MonitorInfo>(remaining, n_monitors.try_into().or(Err(ParseError::ParseError))?)?;
+        let (monitors, remaining) = crate::x11_utils::parse_list_with_order::<MonitorInfo>(remaining, n_monitors.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetMonitorsReply { response_type, sequence, length, timestamp, n_outputs, monitors };
         Ok((result, remaining))
     }
@@ -4154,17 +7037,79 @@ impl TryFrom<&[u8]> for GetMonitorsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMonitorsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        let n_monitors = u32::try_from(self.monitors.len()).expect("`monitors` has too many elements");
+        n_monitors.serialize_into_with_order(bytes, order);
+        self.n_outputs.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.monitors.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetMonitor request
 pub const SET_MONITOR_REQUEST: u8 = 43;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetMonitorRequest {
+    pub window: xproto::Window,
+    pub monitorinfo: MonitorInfo,
+}
+impl SetMonitorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_MONITOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (monitorinfo, remaining) = MonitorInfo::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetMonitorRequest {
+            window,
+            monitorinfo,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `monitorinfo` field of this structure.
+    pub fn monitorinfo<I>(mut self, value: I) -> Self where I: Into<MonitorInfo> {
+        self.monitorinfo = value.into();
+        self
+    }
+}
+
 pub fn set_monitor<Conn>(conn: &Conn, window: xproto::Window, monitorinfo: MonitorInfo) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_MONITOR_REQUEST,
@@ -4176,27 +7121,73 @@ where
         window_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let monitorinfo_bytes = monitorinfo.serialize();
+    let monitorinfo_bytes = monitorinfo.serialize_with_order(order);
     let length_so_far = length_so_far + monitorinfo_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&monitorinfo_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetMonitorRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetMonitorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_monitor(conn, self.window, self.monitorinfo)
+    }
+}
 
 /// Opcode for the DeleteMonitor request
 pub const DELETE_MONITOR_REQUEST: u8 = 44;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeleteMonitorRequest {
+    pub window: xproto::Window,
+    pub name: xproto::Atom,
+}
+impl DeleteMonitorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_MONITOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeleteMonitorRequest {
+            window,
+            name,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `name` field of this structure.
+    pub fn name<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.name = value.into();
+        self
+    }
+}
+
 pub fn delete_monitor<Conn>(conn: &Conn, window: xproto::Window, name: xproto::Atom) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let name_bytes = name.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let name_bytes = name.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_MONITOR_REQUEST,
@@ -4214,25 +7205,89 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteMonitorRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteMonitorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_monitor(conn, self.window, self.name)
+    }
+}
 
 /// Opcode for the CreateLease request
 pub const CREATE_LEASE_REQUEST: u8 = 45;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreateLeaseRequest {
+    pub window: xproto::Window,
+    pub lid: Lease,
+    pub crtcs: Vec<Crtc>,
+    pub outputs: Vec<Output>,
+}
+impl CreateLeaseRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_LEASE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (lid, remaining) = Lease::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateLeaseRequest {
+            window,
+            lid,
+            crtcs,
+            outputs,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `lid` field of this structure.
+    pub fn lid<I>(mut self, value: I) -> Self where I: Into<Lease> {
+        self.lid = value.into();
+        self
+    }
+    /// Set the `crtcs` field of this structure.
+    pub fn crtcs<I>(mut self, value: I) -> Self where I: Into<Vec<Crtc>> {
+        self.crtcs = value.into();
+        self
+    }
+    /// Set the `outputs` field of this structure.
+    pub fn outputs<I>(mut self, value: I) -> Self where I: Into<Vec<Output>> {
+        self.outputs = value.into();
+        self
+    }
+}
+
 pub fn create_lease<'c, Conn>(conn: &'c Conn, window: xproto::Window, lid: Lease, crtcs: &[Crtc], outputs: &[Output]) -> Result<CookieWithFds<'c, Conn, CreateLeaseReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let lid_bytes = lid.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let lid_bytes = lid.serialize_with_order(order);
     let num_crtcs = u16::try_from(crtcs.len()).expect("`crtcs` has too many elements");
-    let num_crtcs_bytes = num_crtcs.serialize();
+    let num_crtcs_bytes = num_crtcs.serialize_with_order(order);
     let num_outputs = u16::try_from(outputs.len()).expect("`outputs` has too many elements");
-    let num_outputs_bytes = num_outputs.serialize();
+    let num_outputs_bytes = num_outputs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_LEASE_REQUEST,
@@ -4252,17 +7307,29 @@ where
         num_outputs_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let crtcs_bytes = crtcs.serialize();
+    let crtcs_bytes = crtcs.serialize_with_order(order);
     let length_so_far = length_so_far + crtcs_bytes.len();
-    let outputs_bytes = outputs.serialize();
+    let outputs_bytes = outputs.serialize_with_order(order);
     let length_so_far = length_so_far + outputs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply_with_fds(&[IoSlice::new(&request0), IoSlice::new(&crtcs_bytes), IoSlice::new(&outputs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateLeaseRequest {
+    type Reply = CreateLeaseReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateLeaseRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = CookieWithFds<'c, Conn, CreateLeaseReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_lease(conn, self.window, self.lid, &self.crtcs, &self.outputs)
+    }
+}
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct CreateLeaseReply {
@@ -4272,12 +7339,12 @@ pub struct CreateLeaseReply {
     pub length: u32,
     pub master_fd: RawFdContainer,
 }
-impl CreateLeaseReply {
-    fn try_parse_fd<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+impl TryParseFd for CreateLeaseReply {
+    fn try_parse_fd_with_order<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         if fds.is_empty() { return Err(ParseError::ParseError) }
         let master_fd = fds.remove(0);
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
@@ -4292,18 +7359,75 @@ impl TryFrom<(&[u8], Vec<RawFdContainer>)> for CreateLeaseReply {
         Ok(Self::try_parse_fd(value, &mut fds)?.0)
     }
 }
+impl Serialize for CreateLeaseReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the FreeLease request
 pub const FREE_LEASE_REQUEST: u8 = 46;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FreeLeaseRequest {
+    pub lid: Lease,
+    pub terminate: u8,
+}
+impl FreeLeaseRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FREE_LEASE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (lid, remaining) = Lease::try_parse_with_order(remaining, order)?;
+        let (terminate, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FreeLeaseRequest {
+            lid,
+            terminate,
+        })
+    }
+    /// Set the `lid` field of this structure.
+    pub fn lid<I>(mut self, value: I) -> Self where I: Into<Lease> {
+        self.lid = value.into();
+        self
+    }
+    /// Set the `terminate` field of this structure.
+    pub fn terminate(mut self, value: u8) -> Self {
+        self.terminate = value;
+        self
+    }
+}
+
 pub fn free_lease<Conn>(conn: &Conn, lid: Lease, terminate: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let lid_bytes = lid.serialize();
-    let terminate_bytes = terminate.serialize();
+    let lid_bytes = lid.serialize_with_order(order);
+    let terminate_bytes = terminate.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FREE_LEASE_REQUEST,
@@ -4321,9 +7445,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FreeLeaseRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FreeLeaseRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        free_lease(conn, self.lid, self.terminate)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LeaseNotify {
@@ -4333,11 +7469,11 @@ pub struct LeaseNotify {
     pub created: u8,
 }
 impl TryParse for LeaseNotify {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (lease, remaining) = Lease::try_parse(remaining)?;
-        let (created, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (lease, remaining) = Lease::try_parse_with_order(remaining, order)?;
+        let (created, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(15..).ok_or(ParseError::ParseError)?;
         let result = LeaseNotify { timestamp, window, lease, created };
         Ok((result, remaining))
@@ -4351,11 +7487,11 @@ impl TryFrom<&[u8]> for LeaseNotify {
 }
 impl Serialize for LeaseNotify {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let timestamp_bytes = self.timestamp.serialize();
-        let window_bytes = self.window.serialize();
-        let lease_bytes = self.lease.serialize();
-        let created_bytes = self.created.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let lease_bytes = self.lease.serialize_with_order(order);
+        let created_bytes = self.created.serialize_with_order(order);
         [
             timestamp_bytes[0],
             timestamp_bytes[1],
@@ -4387,12 +7523,12 @@ impl Serialize for LeaseNotify {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        self.timestamp.serialize_into(bytes);
-        self.window.serialize_into(bytes);
-        self.lease.serialize_into(bytes);
-        self.created.serialize_into(bytes);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.lease.serialize_into_with_order(bytes, order);
+        self.created.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 15]);
     }
 }
@@ -4401,73 +7537,80 @@ impl Serialize for LeaseNotify {
 pub struct NotifyData([u8; 28]);
 impl NotifyData {
     pub fn as_cc(&self) -> CrtcChange {
-        fn do_the_parse(remaining: &[u8]) -> Result<CrtcChange, ParseError> {
-            let (cc, remaining) = CrtcChange::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<CrtcChange, ParseError> {
+            let (cc, remaining) = CrtcChange::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(cc)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_oc(&self) -> OutputChange {
-        fn do_the_parse(remaining: &[u8]) -> Result<OutputChange, ParseError> {
-            let (oc, remaining) = OutputChange::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<OutputChange, ParseError> {
+            let (oc, remaining) = OutputChange::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(oc)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_op(&self) -> OutputProperty {
-        fn do_the_parse(remaining: &[u8]) -> Result<OutputProperty, ParseError> {
-            let (op, remaining) = OutputProperty::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<OutputProperty, ParseError> {
+            let (op, remaining) = OutputProperty::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(op)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_pc(&self) -> ProviderChange {
-        fn do_the_parse(remaining: &[u8]) -> Result<ProviderChange, ParseError> {
-            let (pc, remaining) = ProviderChange::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<ProviderChange, ParseError> {
+            let (pc, remaining) = ProviderChange::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(pc)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_pp(&self) -> ProviderProperty {
-        fn do_the_parse(remaining: &[u8]) -> Result<ProviderProperty, ParseError> {
-            let (pp, remaining) = ProviderProperty::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<ProviderProperty, ParseError> {
+            let (pp, remaining) = ProviderProperty::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(pp)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_rc(&self) -> ResourceChange {
-        fn do_the_parse(remaining: &[u8]) -> Result<ResourceChange, ParseError> {
-            let (rc, remaining) = ResourceChange::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<ResourceChange, ParseError> {
+            let (rc, remaining) = ResourceChange::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(rc)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_lc(&self) -> LeaseNotify {
-        fn do_the_parse(remaining: &[u8]) -> Result<LeaseNotify, ParseError> {
-            let (lc, remaining) = LeaseNotify::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<LeaseNotify, ParseError> {
+            let (lc, remaining) = LeaseNotify::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(lc)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
 }
 impl Serialize for NotifyData {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
+    fn serialize_with_order(&self, _order: ByteOrder) -> [u8; 28] {
         self.0
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {
         bytes.extend_from_slice(&self.0);
     }
 }
 impl TryParse for NotifyData {
-    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], _order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let inner: [u8; 28] = value.get(..28)
             .ok_or(ParseError::ParseError)?
             .try_into()
@@ -4478,43 +7621,57 @@ impl TryParse for NotifyData {
 }
 impl From<CrtcChange> for NotifyData {
     fn from(cc: CrtcChange) -> Self {
-        let cc_bytes = cc.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let cc_bytes = cc.serialize_with_order(order);
         Self(cc_bytes)
     }
 }
 impl From<OutputChange> for NotifyData {
     fn from(oc: OutputChange) -> Self {
-        let oc_bytes = oc.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let oc_bytes = oc.serialize_with_order(order);
         Self(oc_bytes)
     }
 }
 impl From<OutputProperty> for NotifyData {
     fn from(op: OutputProperty) -> Self {
-        let op_bytes = op.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let op_bytes = op.serialize_with_order(order);
         Self(op_bytes)
     }
 }
 impl From<ProviderChange> for NotifyData {
     fn from(pc: ProviderChange) -> Self {
-        let pc_bytes = pc.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let pc_bytes = pc.serialize_with_order(order);
         Self(pc_bytes)
     }
 }
 impl From<ProviderProperty> for NotifyData {
     fn from(pp: ProviderProperty) -> Self {
-        let pp_bytes = pp.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let pp_bytes = pp.serialize_with_order(order);
         Self(pp_bytes)
     }
 }
 impl From<ResourceChange> for NotifyData {
     fn from(rc: ResourceChange) -> Self {
-        let rc_bytes = rc.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let rc_bytes = rc.serialize_with_order(order);
         Self(rc_bytes)
     }
 }
 impl From<LeaseNotify> for NotifyData {
     fn from(lc: LeaseNotify) -> Self {
-        let lc_bytes = lc.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let lc_bytes = lc.serialize_with_order(order);
         Self(lc_bytes)
     }
 }
@@ -4529,12 +7686,12 @@ pub struct NotifyEvent {
     pub u: NotifyData,
 }
 impl TryParse for NotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (sub_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (u, remaining) = NotifyData::try_parse(remaining)?;
-        let sub_code = sub_code.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sub_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (u, remaining) = NotifyData::try_parse_with_order(remaining, order)?;
+        let sub_code = Notify::from(sub_code);
         let result = NotifyEvent { response_type, sub_code, sequence, u };
         Ok((result, remaining))
     }
@@ -4547,10 +7704,21 @@ impl TryFrom<&[u8]> for NotifyEvent {
 }
 impl From<&NotifyEvent> for [u8; 32] {
     fn from(input: &NotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let sub_code_bytes = u8::from(input.sub_code).serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let u_bytes = input.u.serialize();
+        input.serialize()
+    }
+}
+impl From<NotifyEvent> for [u8; 32] {
+    fn from(input: NotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for NotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sub_code_bytes = u8::from(self.sub_code).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let u_bytes = self.u.serialize_with_order(order);
         [
             response_type_bytes[0],
             sub_code_bytes[0],
@@ -4586,10 +7754,8 @@ impl From<&NotifyEvent> for [u8; 32] {
             u_bytes[27],
         ]
     }
-}
-impl From<NotifyEvent> for [u8; 32] {
-    fn from(input: NotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -4601,13 +7767,13 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn randr_set_screen_config<A>(&self, window: xproto::Window, timestamp: xproto::Timestamp, config_timestamp: xproto::Timestamp, size_id: u16, rotation: A, rate: u16) -> Result<Cookie<'_, Self, SetScreenConfigReply>, ConnectionError>
     where
-        A: Into<u16>,
+        A: Into<Rotation>,
     {
         set_screen_config(self, window, timestamp, config_timestamp, size_id, rotation, rate)
     }
     fn randr_select_input<A>(&self, window: xproto::Window, enable: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<u16>,
+        A: Into<NotifyMask>,
     {
         select_input(self, window, enable)
     }
@@ -4643,7 +7809,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         configure_output_property(self, output, property, pending, range, values)
     }
-    fn randr_change_output_property<'c>(&'c self, output: Output, property: xproto::Atom, type_: xproto::Atom, format: u8, mode: xproto::PropMode, num_units: u32, data: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn randr_change_output_property<'c, A>(&'c self, output: Output, property: xproto::Atom, type_: xproto::Atom, format: u8, mode: A, num_units: u32, data: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<xproto::PropMode>,
     {
         change_output_property(self, output, property, type_, format, mode, num_units, data)
     }
@@ -4679,7 +7847,7 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn randr_set_crtc_config<'c, A>(&'c self, crtc: Crtc, timestamp: xproto::Timestamp, config_timestamp: xproto::Timestamp, x: i16, y: i16, mode: Mode, rotation: A, outputs: &[Output]) -> Result<Cookie<'c, Self, SetCrtcConfigReply>, ConnectionError>
     where
-        A: Into<u16>,
+        A: Into<Rotation>,
     {
         set_crtc_config(self, crtc, timestamp, config_timestamp, x, y, mode, rotation, outputs)
     }
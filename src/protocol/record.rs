@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -30,7 +30,37 @@ pub const X11_EXTENSION_NAME: &str = "RECORD";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 13);
 
-pub type Context = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Context(u32);
+impl Context {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Context(0);
+}
+impl From<u32> for Context {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Context> for u32 {
+    fn from(value: Context) -> Self {
+        value.0
+    }
+}
+impl TryParse for Context {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Context {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Range8 {
@@ -38,9 +68,9 @@ pub struct Range8 {
     pub last: u8,
 }
 impl TryParse for Range8 {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (first, remaining) = u8::try_parse(remaining)?;
-        let (last, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (first, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (last, remaining) = u8::try_parse_with_order(remaining, order)?;
         let result = Range8 { first, last };
         Ok((result, remaining))
     }
@@ -53,18 +83,18 @@ impl TryFrom<&[u8]> for Range8 {
 }
 impl Serialize for Range8 {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let first_bytes = self.first.serialize();
-        let last_bytes = self.last.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let first_bytes = self.first.serialize_with_order(order);
+        let last_bytes = self.last.serialize_with_order(order);
         [
             first_bytes[0],
             last_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.first.serialize_into(bytes);
-        self.last.serialize_into(bytes);
+        self.first.serialize_into_with_order(bytes, order);
+        self.last.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -74,9 +104,9 @@ pub struct Range16 {
     pub last: u16,
 }
 impl TryParse for Range16 {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (first, remaining) = u16::try_parse(remaining)?;
-        let (last, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (first, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (last, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = Range16 { first, last };
         Ok((result, remaining))
     }
@@ -89,9 +119,9 @@ impl TryFrom<&[u8]> for Range16 {
 }
 impl Serialize for Range16 {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let first_bytes = self.first.serialize();
-        let last_bytes = self.last.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let first_bytes = self.first.serialize_with_order(order);
+        let last_bytes = self.last.serialize_with_order(order);
         [
             first_bytes[0],
             first_bytes[1],
@@ -99,10 +129,10 @@ impl Serialize for Range16 {
             last_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.first.serialize_into(bytes);
-        self.last.serialize_into(bytes);
+        self.first.serialize_into_with_order(bytes, order);
+        self.last.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -112,9 +142,9 @@ pub struct ExtRange {
     pub minor: Range16,
 }
 impl TryParse for ExtRange {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (major, remaining) = Range8::try_parse(remaining)?;
-        let (minor, remaining) = Range16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (major, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (minor, remaining) = Range16::try_parse_with_order(remaining, order)?;
         let result = ExtRange { major, minor };
         Ok((result, remaining))
     }
@@ -127,9 +157,9 @@ impl TryFrom<&[u8]> for ExtRange {
 }
 impl Serialize for ExtRange {
     type Bytes = [u8; 6];
-    fn serialize(&self) -> [u8; 6] {
-        let major_bytes = self.major.serialize();
-        let minor_bytes = self.minor.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 6] {
+        let major_bytes = self.major.serialize_with_order(order);
+        let minor_bytes = self.minor.serialize_with_order(order);
         [
             major_bytes[0],
             major_bytes[1],
@@ -139,10 +169,10 @@ impl Serialize for ExtRange {
             minor_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(6);
-        self.major.serialize_into(bytes);
-        self.minor.serialize_into(bytes);
+        self.major.serialize_into_with_order(bytes, order);
+        self.minor.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -159,16 +189,16 @@ pub struct Range {
     pub client_died: bool,
 }
 impl TryParse for Range {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (core_requests, remaining) = Range8::try_parse(remaining)?;
-        let (core_replies, remaining) = Range8::try_parse(remaining)?;
-        let (ext_requests, remaining) = ExtRange::try_parse(remaining)?;
-        let (ext_replies, remaining) = ExtRange::try_parse(remaining)?;
-        let (delivered_events, remaining) = Range8::try_parse(remaining)?;
-        let (device_events, remaining) = Range8::try_parse(remaining)?;
-        let (errors, remaining) = Range8::try_parse(remaining)?;
-        let (client_started, remaining) = bool::try_parse(remaining)?;
-        let (client_died, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (core_requests, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (core_replies, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (ext_requests, remaining) = ExtRange::try_parse_with_order(remaining, order)?;
+        let (ext_replies, remaining) = ExtRange::try_parse_with_order(remaining, order)?;
+        let (delivered_events, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (device_events, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (errors, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (client_started, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (client_died, remaining) = bool::try_parse_with_order(remaining, order)?;
         let result = Range { core_requests, core_replies, ext_requests, ext_replies, delivered_events, device_events, errors, client_started, client_died };
         Ok((result, remaining))
     }
@@ -181,16 +211,16 @@ impl TryFrom<&[u8]> for Range {
 }
 impl Serialize for Range {
     type Bytes = [u8; 24];
-    fn serialize(&self) -> [u8; 24] {
-        let core_requests_bytes = self.core_requests.serialize();
-        let core_replies_bytes = self.core_replies.serialize();
-        let ext_requests_bytes = self.ext_requests.serialize();
-        let ext_replies_bytes = self.ext_replies.serialize();
-        let delivered_events_bytes = self.delivered_events.serialize();
-        let device_events_bytes = self.device_events.serialize();
-        let errors_bytes = self.errors.serialize();
-        let client_started_bytes = self.client_started.serialize();
-        let client_died_bytes = self.client_died.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 24] {
+        let core_requests_bytes = self.core_requests.serialize_with_order(order);
+        let core_replies_bytes = self.core_replies.serialize_with_order(order);
+        let ext_requests_bytes = self.ext_requests.serialize_with_order(order);
+        let ext_replies_bytes = self.ext_replies.serialize_with_order(order);
+        let delivered_events_bytes = self.delivered_events.serialize_with_order(order);
+        let device_events_bytes = self.device_events.serialize_with_order(order);
+        let errors_bytes = self.errors.serialize_with_order(order);
+        let client_started_bytes = self.client_started.serialize_with_order(order);
+        let client_died_bytes = self.client_died.serialize_with_order(order);
         [
             core_requests_bytes[0],
             core_requests_bytes[1],
@@ -218,152 +248,91 @@ impl Serialize for Range {
             client_died_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(24);
-        self.core_requests.serialize_into(bytes);
-        self.core_replies.serialize_into(bytes);
-        self.ext_requests.serialize_into(bytes);
-        self.ext_replies.serialize_into(bytes);
-        self.delivered_events.serialize_into(bytes);
-        self.device_events.serialize_into(bytes);
-        self.errors.serialize_into(bytes);
-        self.client_started.serialize_into(bytes);
-        self.client_died.serialize_into(bytes);
+        self.core_requests.serialize_into_with_order(bytes, order);
+        self.core_replies.serialize_into_with_order(bytes, order);
+        self.ext_requests.serialize_into_with_order(bytes, order);
+        self.ext_replies.serialize_into_with_order(bytes, order);
+        self.delivered_events.serialize_into_with_order(bytes, order);
+        self.device_events.serialize_into_with_order(bytes, order);
+        self.errors.serialize_into_with_order(bytes, order);
+        self.client_started.serialize_into_with_order(bytes, order);
+        self.client_died.serialize_into_with_order(bytes, order);
     }
 }
 
 pub type ElementHeader = u8;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum HType {
-    FromServerTime = 1 << 0,
-    FromClientTime = 1 << 1,
-    FromClientSequence = 1 << 2,
-}
-impl From<HType> for u8 {
-    fn from(input: HType) -> Self {
-        match input {
-            HType::FromServerTime => 1 << 0,
-            HType::FromClientTime => 1 << 1,
-            HType::FromClientSequence => 1 << 2,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HType(u8);
+#[allow(non_upper_case_globals)]
+impl HType {
+    pub const FromServerTime: Self = Self(1 << 0);
+    pub const FromClientTime: Self = Self(1 << 1);
+    pub const FromClientSequence: Self = Self(1 << 2);
 }
+bitmask_set!(HType, u8, [FromServerTime, FromClientTime, FromClientSequence]);
 impl From<HType> for Option<u8> {
     fn from(input: HType) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<HType> for u16 {
     fn from(input: HType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<HType> for Option<u16> {
     fn from(input: HType) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<HType> for u32 {
     fn from(input: HType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<HType> for Option<u32> {
     fn from(input: HType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for HType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(HType::FromServerTime),
-            2 => Ok(HType::FromClientTime),
-            4 => Ok(HType::FromClientSequence),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for HType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for HType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(HType, u8);
 
 pub type ClientSpec = u32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum CS {
-    CurrentClients = 1,
-    FutureClients = 2,
-    AllClients = 3,
-}
-impl From<CS> for u8 {
-    fn from(input: CS) -> Self {
-        match input {
-            CS::CurrentClients => 1,
-            CS::FutureClients => 2,
-            CS::AllClients => 3,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CS(u8);
+#[allow(non_upper_case_globals)]
+impl CS {
+    pub const CurrentClients: Self = Self(1);
+    pub const FutureClients: Self = Self(2);
+    pub const AllClients: Self = Self(3);
 }
+enum_newtype!(CS, u8, [CurrentClients, FutureClients, AllClients]);
 impl From<CS> for Option<u8> {
     fn from(input: CS) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<CS> for u16 {
     fn from(input: CS) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CS> for Option<u16> {
     fn from(input: CS) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<CS> for u32 {
     fn from(input: CS) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CS> for Option<u32> {
     fn from(input: CS) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for CS {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(CS::CurrentClients),
-            2 => Ok(CS::FutureClients),
-            3 => Ok(CS::AllClients),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for CS {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for CS {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -373,10 +342,10 @@ pub struct ClientInfo {
     pub ranges: Vec<Range>,
 }
 impl TryParse for ClientInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (client_resource, remaining) = ClientSpec::try_parse(remaining)?;
-        let (num_ranges, remaining) = u32::try_parse(remaining)?;
-        let (ranges, remaining) = crate::x11_utils::parse_list::<Range>(remaining, num_ranges.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (client_resource, remaining) = ClientSpec::try_parse_with_order(remaining, order)?;
+        let (num_ranges, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ranges, remaining) = crate::x11_utils::parse_list_with_order::<Range>(remaining, num_ranges.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ClientInfo { client_resource, ranges };
         Ok((result, remaining))
     }
@@ -389,17 +358,17 @@ impl TryFrom<&[u8]> for ClientInfo {
 }
 impl Serialize for ClientInfo {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.client_resource.serialize_into(bytes);
+        self.client_resource.serialize_into_with_order(bytes, order);
         let num_ranges = u32::try_from(self.ranges.len()).expect("`ranges` has too many elements");
-        num_ranges.serialize_into(bytes);
-        self.ranges.serialize_into(bytes);
+        num_ranges.serialize_into_with_order(bytes, order);
+        self.ranges.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -413,11 +382,11 @@ pub struct BadContextError {
     pub invalid_record: u32,
 }
 impl TryParse for BadContextError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (invalid_record, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (invalid_record, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = BadContextError { response_type, error_code, sequence, invalid_record };
         Ok((result, remaining))
     }
@@ -430,10 +399,21 @@ impl TryFrom<&[u8]> for BadContextError {
 }
 impl From<&BadContextError> for [u8; 32] {
     fn from(input: &BadContextError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let invalid_record_bytes = input.invalid_record.serialize();
+        input.serialize()
+    }
+}
+impl From<BadContextError> for [u8; 32] {
+    fn from(input: BadContextError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadContextError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let invalid_record_bytes = self.invalid_record.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -470,24 +450,56 @@ impl From<&BadContextError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadContextError> for [u8; 32] {
-    fn from(input: BadContextError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u16) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u16) -> Self {
+        self.minor_version = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, major_version: u16, minor_version: u16) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -501,9 +513,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.major_version, self.minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -514,13 +538,13 @@ pub struct QueryVersionReply {
     pub minor_version: u16,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
     }
@@ -531,22 +555,99 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreateContext request
 pub const CREATE_CONTEXT_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreateContextRequest {
+    pub context: Context,
+    pub element_header: ElementHeader,
+    pub client_specs: Vec<ClientSpec>,
+    pub ranges: Vec<Range>,
+}
+impl CreateContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (element_header, remaining) = ElementHeader::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (num_client_specs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_ranges, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_specs, remaining) = crate::x11_utils::parse_list_with_order::<ClientSpec>(remaining, num_client_specs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (ranges, remaining) = crate::x11_utils::parse_list_with_order::<Range>(remaining, num_ranges.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateContextRequest {
+            context,
+            element_header,
+            client_specs,
+            ranges,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `element_header` field of this structure.
+    pub fn element_header<I>(mut self, value: I) -> Self where I: Into<ElementHeader> {
+        self.element_header = value.into();
+        self
+    }
+    /// Set the `client_specs` field of this structure.
+    pub fn client_specs<I>(mut self, value: I) -> Self where I: Into<Vec<ClientSpec>> {
+        self.client_specs = value.into();
+        self
+    }
+    /// Set the `ranges` field of this structure.
+    pub fn ranges<I>(mut self, value: I) -> Self where I: Into<Vec<Range>> {
+        self.ranges = value.into();
+        self
+    }
+}
+
 pub fn create_context<'c, Conn>(conn: &'c Conn, context: Context, element_header: ElementHeader, client_specs: &[ClientSpec], ranges: &[Range]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let element_header_bytes = element_header.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let element_header_bytes = element_header.serialize_with_order(order);
     let num_client_specs = u32::try_from(client_specs.len()).expect("`client_specs` has too many elements");
-    let num_client_specs_bytes = num_client_specs.serialize();
+    let num_client_specs_bytes = num_client_specs.serialize_with_order(order);
     let num_ranges = u32::try_from(ranges.len()).expect("`ranges` has too many elements");
-    let num_ranges_bytes = num_ranges.serialize();
+    let num_ranges_bytes = num_ranges.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_CONTEXT_REQUEST,
@@ -570,33 +671,98 @@ where
         num_ranges_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let client_specs_bytes = client_specs.serialize();
+    let client_specs_bytes = client_specs.serialize_with_order(order);
     let length_so_far = length_so_far + client_specs_bytes.len();
-    let ranges_bytes = ranges.serialize();
+    let ranges_bytes = ranges.serialize_with_order(order);
     let length_so_far = length_so_far + ranges_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&client_specs_bytes), IoSlice::new(&ranges_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_context(conn, self.context, self.element_header, &self.client_specs, &self.ranges)
+    }
+}
 
 /// Opcode for the RegisterClients request
 pub const REGISTER_CLIENTS_REQUEST: u8 = 2;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RegisterClientsRequest {
+    pub context: Context,
+    pub element_header: ElementHeader,
+    pub client_specs: Vec<ClientSpec>,
+    pub ranges: Vec<Range>,
+}
+impl RegisterClientsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != REGISTER_CLIENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (element_header, remaining) = ElementHeader::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (num_client_specs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_ranges, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_specs, remaining) = crate::x11_utils::parse_list_with_order::<ClientSpec>(remaining, num_client_specs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (ranges, remaining) = crate::x11_utils::parse_list_with_order::<Range>(remaining, num_ranges.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(RegisterClientsRequest {
+            context,
+            element_header,
+            client_specs,
+            ranges,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `element_header` field of this structure.
+    pub fn element_header<I>(mut self, value: I) -> Self where I: Into<ElementHeader> {
+        self.element_header = value.into();
+        self
+    }
+    /// Set the `client_specs` field of this structure.
+    pub fn client_specs<I>(mut self, value: I) -> Self where I: Into<Vec<ClientSpec>> {
+        self.client_specs = value.into();
+        self
+    }
+    /// Set the `ranges` field of this structure.
+    pub fn ranges<I>(mut self, value: I) -> Self where I: Into<Vec<Range>> {
+        self.ranges = value.into();
+        self
+    }
+}
+
 pub fn register_clients<'c, Conn>(conn: &'c Conn, context: Context, element_header: ElementHeader, client_specs: &[ClientSpec], ranges: &[Range]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let element_header_bytes = element_header.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let element_header_bytes = element_header.serialize_with_order(order);
     let num_client_specs = u32::try_from(client_specs.len()).expect("`client_specs` has too many elements");
-    let num_client_specs_bytes = num_client_specs.serialize();
+    let num_client_specs_bytes = num_client_specs.serialize_with_order(order);
     let num_ranges = u32::try_from(ranges.len()).expect("`ranges` has too many elements");
-    let num_ranges_bytes = num_ranges.serialize();
+    let num_ranges_bytes = num_ranges.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         REGISTER_CLIENTS_REQUEST,
@@ -620,30 +786,77 @@ where
         num_ranges_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let client_specs_bytes = client_specs.serialize();
+    let client_specs_bytes = client_specs.serialize_with_order(order);
     let length_so_far = length_so_far + client_specs_bytes.len();
-    let ranges_bytes = ranges.serialize();
+    let ranges_bytes = ranges.serialize_with_order(order);
     let length_so_far = length_so_far + ranges_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&client_specs_bytes), IoSlice::new(&ranges_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for RegisterClientsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for RegisterClientsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        register_clients(conn, self.context, self.element_header, &self.client_specs, &self.ranges)
+    }
+}
 
 /// Opcode for the UnregisterClients request
 pub const UNREGISTER_CLIENTS_REQUEST: u8 = 3;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnregisterClientsRequest {
+    pub context: Context,
+    pub client_specs: Vec<ClientSpec>,
+}
+impl UnregisterClientsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNREGISTER_CLIENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (num_client_specs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_specs, remaining) = crate::x11_utils::parse_list_with_order::<ClientSpec>(remaining, num_client_specs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(UnregisterClientsRequest {
+            context,
+            client_specs,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `client_specs` field of this structure.
+    pub fn client_specs<I>(mut self, value: I) -> Self where I: Into<Vec<ClientSpec>> {
+        self.client_specs = value.into();
+        self
+    }
+}
+
 pub fn unregister_clients<'c, Conn>(conn: &'c Conn, context: Context, client_specs: &[ClientSpec]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let num_client_specs = u32::try_from(client_specs.len()).expect("`client_specs` has too many elements");
-    let num_client_specs_bytes = num_client_specs.serialize();
+    let num_client_specs_bytes = num_client_specs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         UNREGISTER_CLIENTS_REQUEST,
@@ -659,26 +872,64 @@ where
         num_client_specs_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let client_specs_bytes = client_specs.serialize();
+    let client_specs_bytes = client_specs.serialize_with_order(order);
     let length_so_far = length_so_far + client_specs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&client_specs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for UnregisterClientsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UnregisterClientsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        unregister_clients(conn, self.context, &self.client_specs)
+    }
+}
 
 /// Opcode for the GetContext request
 pub const GET_CONTEXT_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetContextRequest {
+    pub context: Context,
+}
+impl GetContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetContextRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn get_context<Conn>(conn: &Conn, context: Context) -> Result<Cookie<'_, Conn, GetContextReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CONTEXT_REQUEST,
@@ -692,9 +943,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetContextRequest {
+    type Reply = GetContextReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetContextReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_context(conn, self.context)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetContextReply {
@@ -706,16 +969,16 @@ pub struct GetContextReply {
     pub intercepted_clients: Vec<ClientInfo>,
 }
 impl TryParse for GetContextReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (enabled, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (element_header, remaining) = ElementHeader::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (enabled, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (element_header, remaining) = ElementHeader::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (num_intercepted_clients, remaining) = u32::try_parse(remaining)?;
+        let (num_intercepted_clients, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (intercepted_clients, remaining) = crate::x11_utils::parse_list::<ClientInfo>(remaining, num_intercepted_clients.try_into().or(Err(ParseError::ParseError))?)?;
+        let (intercepted_clients, remaining) = crate::x11_utils::parse_list_with_order::<ClientInfo>(remaining, num_intercepted_clients.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetContextReply { response_type, enabled, sequence, length, element_header, intercepted_clients };
         Ok((result, remaining))
     }
@@ -726,17 +989,71 @@ impl TryFrom<&[u8]> for GetContextReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.enabled.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.element_header.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        let num_intercepted_clients = u32::try_from(self.intercepted_clients.len()).expect("`intercepted_clients` has too many elements");
+        num_intercepted_clients.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.intercepted_clients.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the EnableContext request
 pub const ENABLE_CONTEXT_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnableContextRequest {
+    pub context: Context,
+}
+impl EnableContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ENABLE_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(EnableContextRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn enable_context<Conn>(conn: &Conn, context: Context) -> Result<Cookie<'_, Conn, EnableContextReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ENABLE_CONTEXT_REQUEST,
@@ -750,9 +1067,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for EnableContextRequest {
+    type Reply = EnableContextReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for EnableContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, EnableContextReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        enable_context(conn, self.context)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EnableContextReply {
@@ -767,17 +1096,17 @@ pub struct EnableContextReply {
     pub data: Vec<u8>,
 }
 impl TryParse for EnableContextReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (category, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (element_header, remaining) = ElementHeader::try_parse(remaining)?;
-        let (client_swapped, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (category, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (element_header, remaining) = ElementHeader::try_parse_with_order(remaining, order)?;
+        let (client_swapped, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (xid_base, remaining) = u32::try_parse(remaining)?;
-        let (server_time, remaining) = u32::try_parse(remaining)?;
-        let (rec_sequence_num, remaining) = u32::try_parse(remaining)?;
+        let (xid_base, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_time, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (rec_sequence_num, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -791,17 +1120,75 @@ impl TryFrom<&[u8]> for EnableContextReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for EnableContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.category.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.element_header.serialize_into_with_order(bytes, order);
+        self.client_swapped.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.xid_base.serialize_into_with_order(bytes, order);
+        self.server_time.serialize_into_with_order(bytes, order);
+        self.rec_sequence_num.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DisableContext request
 pub const DISABLE_CONTEXT_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DisableContextRequest {
+    pub context: Context,
+}
+impl DisableContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DISABLE_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DisableContextRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn disable_context<Conn>(conn: &Conn, context: Context) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DISABLE_CONTEXT_REQUEST,
@@ -815,20 +1202,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DisableContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DisableContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        disable_context(conn, self.context)
+    }
+}
 
 /// Opcode for the FreeContext request
 pub const FREE_CONTEXT_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FreeContextRequest {
+    pub context: Context,
+}
+impl FreeContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FREE_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FreeContextRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn free_context<Conn>(conn: &Conn, context: Context) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FREE_CONTEXT_REQUEST,
@@ -842,9 +1267,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FreeContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FreeContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        free_context(conn, self.context)
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -31,720 +31,368 @@ pub const X11_EXTENSION_NAME: &str = "RENDER";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (0, 11);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PictType {
-    Indexed = 0,
-    Direct = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PictType(u8);
+#[allow(non_upper_case_globals)]
+impl PictType {
+    pub const Indexed: Self = Self(0);
+    pub const Direct: Self = Self(1);
+}
+enum_newtype!(PictType, u8, [Indexed, Direct]);
 impl From<PictType> for bool {
     fn from(input: PictType) -> Self {
-        match input {
-            PictType::Indexed => false,
-            PictType::Direct => true,
-        }
-    }
-}
-impl From<PictType> for u8 {
-    fn from(input: PictType) -> Self {
-        match input {
-            PictType::Indexed => 0,
-            PictType::Direct => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<PictType> for Option<u8> {
     fn from(input: PictType) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<PictType> for u16 {
     fn from(input: PictType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PictType> for Option<u16> {
     fn from(input: PictType) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<PictType> for u32 {
     fn from(input: PictType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PictType> for Option<u32> {
     fn from(input: PictType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for PictType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(PictType::Indexed),
-            1 => Ok(PictType::Direct),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for PictType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for PictType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PictureEnum {
-    None = 0,
-}
-impl From<PictureEnum> for u8 {
-    fn from(input: PictureEnum) -> Self {
-        match input {
-            PictureEnum::None => 0,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PictureEnum(u8);
+#[allow(non_upper_case_globals)]
+impl PictureEnum {
+    pub const None: Self = Self(0);
 }
+enum_newtype!(PictureEnum, u8, [None]);
 impl From<PictureEnum> for Option<u8> {
     fn from(input: PictureEnum) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<PictureEnum> for u16 {
     fn from(input: PictureEnum) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PictureEnum> for Option<u16> {
     fn from(input: PictureEnum) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<PictureEnum> for u32 {
     fn from(input: PictureEnum) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PictureEnum> for Option<u32> {
     fn from(input: PictureEnum) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for PictureEnum {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(PictureEnum::None),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for PictureEnum {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<PictureEnum> for Picture {
+    fn from(input: PictureEnum) -> Self {
+        Self::from(u32::from(input))
     }
 }
-impl TryFrom<u32> for PictureEnum {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<PictureEnum> for Option<Picture> {
+    fn from(input: PictureEnum) -> Self {
+        Some(Picture::from(input))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PictOp {
-    Clear = 0,
-    Src = 1,
-    Dst = 2,
-    Over = 3,
-    OverReverse = 4,
-    In = 5,
-    InReverse = 6,
-    Out = 7,
-    OutReverse = 8,
-    Atop = 9,
-    AtopReverse = 10,
-    Xor = 11,
-    Add = 12,
-    Saturate = 13,
-    DisjointClear = 16,
-    DisjointSrc = 17,
-    DisjointDst = 18,
-    DisjointOver = 19,
-    DisjointOverReverse = 20,
-    DisjointIn = 21,
-    DisjointInReverse = 22,
-    DisjointOut = 23,
-    DisjointOutReverse = 24,
-    DisjointAtop = 25,
-    DisjointAtopReverse = 26,
-    DisjointXor = 27,
-    ConjointClear = 32,
-    ConjointSrc = 33,
-    ConjointDst = 34,
-    ConjointOver = 35,
-    ConjointOverReverse = 36,
-    ConjointIn = 37,
-    ConjointInReverse = 38,
-    ConjointOut = 39,
-    ConjointOutReverse = 40,
-    ConjointAtop = 41,
-    ConjointAtopReverse = 42,
-    ConjointXor = 43,
-    Multiply = 48,
-    Screen = 49,
-    Overlay = 50,
-    Darken = 51,
-    Lighten = 52,
-    ColorDodge = 53,
-    ColorBurn = 54,
-    HardLight = 55,
-    SoftLight = 56,
-    Difference = 57,
-    Exclusion = 58,
-    HSLHue = 59,
-    HSLSaturation = 60,
-    HSLColor = 61,
-    HSLLuminosity = 62,
-}
-impl From<PictOp> for u8 {
-    fn from(input: PictOp) -> Self {
-        match input {
-            PictOp::Clear => 0,
-            PictOp::Src => 1,
-            PictOp::Dst => 2,
-            PictOp::Over => 3,
-            PictOp::OverReverse => 4,
-            PictOp::In => 5,
-            PictOp::InReverse => 6,
-            PictOp::Out => 7,
-            PictOp::OutReverse => 8,
-            PictOp::Atop => 9,
-            PictOp::AtopReverse => 10,
-            PictOp::Xor => 11,
-            PictOp::Add => 12,
-            PictOp::Saturate => 13,
-            PictOp::DisjointClear => 16,
-            PictOp::DisjointSrc => 17,
-            PictOp::DisjointDst => 18,
-            PictOp::DisjointOver => 19,
-            PictOp::DisjointOverReverse => 20,
-            PictOp::DisjointIn => 21,
-            PictOp::DisjointInReverse => 22,
-            PictOp::DisjointOut => 23,
-            PictOp::DisjointOutReverse => 24,
-            PictOp::DisjointAtop => 25,
-            PictOp::DisjointAtopReverse => 26,
-            PictOp::DisjointXor => 27,
-            PictOp::ConjointClear => 32,
-            PictOp::ConjointSrc => 33,
-            PictOp::ConjointDst => 34,
-            PictOp::ConjointOver => 35,
-            PictOp::ConjointOverReverse => 36,
-            PictOp::ConjointIn => 37,
-            PictOp::ConjointInReverse => 38,
-            PictOp::ConjointOut => 39,
-            PictOp::ConjointOutReverse => 40,
-            PictOp::ConjointAtop => 41,
-            PictOp::ConjointAtopReverse => 42,
-            PictOp::ConjointXor => 43,
-            PictOp::Multiply => 48,
-            PictOp::Screen => 49,
-            PictOp::Overlay => 50,
-            PictOp::Darken => 51,
-            PictOp::Lighten => 52,
-            PictOp::ColorDodge => 53,
-            PictOp::ColorBurn => 54,
-            PictOp::HardLight => 55,
-            PictOp::SoftLight => 56,
-            PictOp::Difference => 57,
-            PictOp::Exclusion => 58,
-            PictOp::HSLHue => 59,
-            PictOp::HSLSaturation => 60,
-            PictOp::HSLColor => 61,
-            PictOp::HSLLuminosity => 62,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PictOp(u8);
+#[allow(non_upper_case_globals)]
+impl PictOp {
+    pub const Clear: Self = Self(0);
+    pub const Src: Self = Self(1);
+    pub const Dst: Self = Self(2);
+    pub const Over: Self = Self(3);
+    pub const OverReverse: Self = Self(4);
+    pub const In: Self = Self(5);
+    pub const InReverse: Self = Self(6);
+    pub const Out: Self = Self(7);
+    pub const OutReverse: Self = Self(8);
+    pub const Atop: Self = Self(9);
+    pub const AtopReverse: Self = Self(10);
+    pub const Xor: Self = Self(11);
+    pub const Add: Self = Self(12);
+    pub const Saturate: Self = Self(13);
+    pub const DisjointClear: Self = Self(16);
+    pub const DisjointSrc: Self = Self(17);
+    pub const DisjointDst: Self = Self(18);
+    pub const DisjointOver: Self = Self(19);
+    pub const DisjointOverReverse: Self = Self(20);
+    pub const DisjointIn: Self = Self(21);
+    pub const DisjointInReverse: Self = Self(22);
+    pub const DisjointOut: Self = Self(23);
+    pub const DisjointOutReverse: Self = Self(24);
+    pub const DisjointAtop: Self = Self(25);
+    pub const DisjointAtopReverse: Self = Self(26);
+    pub const DisjointXor: Self = Self(27);
+    pub const ConjointClear: Self = Self(32);
+    pub const ConjointSrc: Self = Self(33);
+    pub const ConjointDst: Self = Self(34);
+    pub const ConjointOver: Self = Self(35);
+    pub const ConjointOverReverse: Self = Self(36);
+    pub const ConjointIn: Self = Self(37);
+    pub const ConjointInReverse: Self = Self(38);
+    pub const ConjointOut: Self = Self(39);
+    pub const ConjointOutReverse: Self = Self(40);
+    pub const ConjointAtop: Self = Self(41);
+    pub const ConjointAtopReverse: Self = Self(42);
+    pub const ConjointXor: Self = Self(43);
+    pub const Multiply: Self = Self(48);
+    pub const Screen: Self = Self(49);
+    pub const Overlay: Self = Self(50);
+    pub const Darken: Self = Self(51);
+    pub const Lighten: Self = Self(52);
+    pub const ColorDodge: Self = Self(53);
+    pub const ColorBurn: Self = Self(54);
+    pub const HardLight: Self = Self(55);
+    pub const SoftLight: Self = Self(56);
+    pub const Difference: Self = Self(57);
+    pub const Exclusion: Self = Self(58);
+    pub const HSLHue: Self = Self(59);
+    pub const HSLSaturation: Self = Self(60);
+    pub const HSLColor: Self = Self(61);
+    pub const HSLLuminosity: Self = Self(62);
+}
+enum_newtype!(PictOp, u8, [Clear, Src, Dst, Over, OverReverse, In, InReverse, Out, OutReverse, Atop, AtopReverse, Xor, Add, Saturate, DisjointClear, DisjointSrc, DisjointDst, DisjointOver, DisjointOverReverse, DisjointIn, DisjointInReverse, DisjointOut, DisjointOutReverse, DisjointAtop, DisjointAtopReverse, DisjointXor, ConjointClear, ConjointSrc, ConjointDst, ConjointOver, ConjointOverReverse, ConjointIn, ConjointInReverse, ConjointOut, ConjointOutReverse, ConjointAtop, ConjointAtopReverse, ConjointXor, Multiply, Screen, Overlay, Darken, Lighten, ColorDodge, ColorBurn, HardLight, SoftLight, Difference, Exclusion, HSLHue, HSLSaturation, HSLColor, HSLLuminosity]);
 impl From<PictOp> for Option<u8> {
     fn from(input: PictOp) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<PictOp> for u16 {
     fn from(input: PictOp) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PictOp> for Option<u16> {
     fn from(input: PictOp) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<PictOp> for u32 {
     fn from(input: PictOp) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PictOp> for Option<u32> {
     fn from(input: PictOp) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for PictOp {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(PictOp::Clear),
-            1 => Ok(PictOp::Src),
-            2 => Ok(PictOp::Dst),
-            3 => Ok(PictOp::Over),
-            4 => Ok(PictOp::OverReverse),
-            5 => Ok(PictOp::In),
-            6 => Ok(PictOp::InReverse),
-            7 => Ok(PictOp::Out),
-            8 => Ok(PictOp::OutReverse),
-            9 => Ok(PictOp::Atop),
-            10 => Ok(PictOp::AtopReverse),
-            11 => Ok(PictOp::Xor),
-            12 => Ok(PictOp::Add),
-            13 => Ok(PictOp::Saturate),
-            16 => Ok(PictOp::DisjointClear),
-            17 => Ok(PictOp::DisjointSrc),
-            18 => Ok(PictOp::DisjointDst),
-            19 => Ok(PictOp::DisjointOver),
-            20 => Ok(PictOp::DisjointOverReverse),
-            21 => Ok(PictOp::DisjointIn),
-            22 => Ok(PictOp::DisjointInReverse),
-            23 => Ok(PictOp::DisjointOut),
-            24 => Ok(PictOp::DisjointOutReverse),
-            25 => Ok(PictOp::DisjointAtop),
-            26 => Ok(PictOp::DisjointAtopReverse),
-            27 => Ok(PictOp::DisjointXor),
-            32 => Ok(PictOp::ConjointClear),
-            33 => Ok(PictOp::ConjointSrc),
-            34 => Ok(PictOp::ConjointDst),
-            35 => Ok(PictOp::ConjointOver),
-            36 => Ok(PictOp::ConjointOverReverse),
-            37 => Ok(PictOp::ConjointIn),
-            38 => Ok(PictOp::ConjointInReverse),
-            39 => Ok(PictOp::ConjointOut),
-            40 => Ok(PictOp::ConjointOutReverse),
-            41 => Ok(PictOp::ConjointAtop),
-            42 => Ok(PictOp::ConjointAtopReverse),
-            43 => Ok(PictOp::ConjointXor),
-            48 => Ok(PictOp::Multiply),
-            49 => Ok(PictOp::Screen),
-            50 => Ok(PictOp::Overlay),
-            51 => Ok(PictOp::Darken),
-            52 => Ok(PictOp::Lighten),
-            53 => Ok(PictOp::ColorDodge),
-            54 => Ok(PictOp::ColorBurn),
-            55 => Ok(PictOp::HardLight),
-            56 => Ok(PictOp::SoftLight),
-            57 => Ok(PictOp::Difference),
-            58 => Ok(PictOp::Exclusion),
-            59 => Ok(PictOp::HSLHue),
-            60 => Ok(PictOp::HSLSaturation),
-            61 => Ok(PictOp::HSLColor),
-            62 => Ok(PictOp::HSLLuminosity),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for PictOp {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for PictOp {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PolyEdge {
-    Sharp = 0,
-    Smooth = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PolyEdge(u32);
+#[allow(non_upper_case_globals)]
+impl PolyEdge {
+    pub const Sharp: Self = Self(0);
+    pub const Smooth: Self = Self(1);
+}
+enum_newtype!(PolyEdge, u32, [Sharp, Smooth]);
 impl From<PolyEdge> for bool {
     fn from(input: PolyEdge) -> Self {
-        match input {
-            PolyEdge::Sharp => false,
-            PolyEdge::Smooth => true,
-        }
-    }
-}
-impl From<PolyEdge> for u8 {
-    fn from(input: PolyEdge) -> Self {
-        match input {
-            PolyEdge::Sharp => 0,
-            PolyEdge::Smooth => 1,
-        }
-    }
-}
-impl From<PolyEdge> for Option<u8> {
-    fn from(input: PolyEdge) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<PolyEdge> for u16 {
-    fn from(input: PolyEdge) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<PolyEdge> for Option<u16> {
-    fn from(input: PolyEdge) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<PolyEdge> for u32 {
-    fn from(input: PolyEdge) -> Self {
-        Self::from(u8::from(input))
+        input.0 != 0
     }
 }
 impl From<PolyEdge> for Option<u32> {
     fn from(input: PolyEdge) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for PolyEdge {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(PolyEdge::Sharp),
-            1 => Ok(PolyEdge::Smooth),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for PolyEdge {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for PolyEdge {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PolyMode {
-    Precise = 0,
-    Imprecise = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PolyMode(u32);
+#[allow(non_upper_case_globals)]
+impl PolyMode {
+    pub const Precise: Self = Self(0);
+    pub const Imprecise: Self = Self(1);
+}
+enum_newtype!(PolyMode, u32, [Precise, Imprecise]);
 impl From<PolyMode> for bool {
     fn from(input: PolyMode) -> Self {
-        match input {
-            PolyMode::Precise => false,
-            PolyMode::Imprecise => true,
-        }
-    }
-}
-impl From<PolyMode> for u8 {
-    fn from(input: PolyMode) -> Self {
-        match input {
-            PolyMode::Precise => 0,
-            PolyMode::Imprecise => 1,
-        }
-    }
-}
-impl From<PolyMode> for Option<u8> {
-    fn from(input: PolyMode) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<PolyMode> for u16 {
-    fn from(input: PolyMode) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<PolyMode> for Option<u16> {
-    fn from(input: PolyMode) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<PolyMode> for u32 {
-    fn from(input: PolyMode) -> Self {
-        Self::from(u8::from(input))
+        input.0 != 0
     }
 }
 impl From<PolyMode> for Option<u32> {
     fn from(input: PolyMode) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for PolyMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(PolyMode::Precise),
-            1 => Ok(PolyMode::Imprecise),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for PolyMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for PolyMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum CP {
-    Repeat = 1 << 0,
-    AlphaMap = 1 << 1,
-    AlphaXOrigin = 1 << 2,
-    AlphaYOrigin = 1 << 3,
-    ClipXOrigin = 1 << 4,
-    ClipYOrigin = 1 << 5,
-    ClipMask = 1 << 6,
-    GraphicsExposure = 1 << 7,
-    SubwindowMode = 1 << 8,
-    PolyEdge = 1 << 9,
-    PolyMode = 1 << 10,
-    Dither = 1 << 11,
-    ComponentAlpha = 1 << 12,
-}
-impl From<CP> for u16 {
-    fn from(input: CP) -> Self {
-        match input {
-            CP::Repeat => 1 << 0,
-            CP::AlphaMap => 1 << 1,
-            CP::AlphaXOrigin => 1 << 2,
-            CP::AlphaYOrigin => 1 << 3,
-            CP::ClipXOrigin => 1 << 4,
-            CP::ClipYOrigin => 1 << 5,
-            CP::ClipMask => 1 << 6,
-            CP::GraphicsExposure => 1 << 7,
-            CP::SubwindowMode => 1 << 8,
-            CP::PolyEdge => 1 << 9,
-            CP::PolyMode => 1 << 10,
-            CP::Dither => 1 << 11,
-            CP::ComponentAlpha => 1 << 12,
-        }
-    }
-}
-impl From<CP> for Option<u16> {
-    fn from(input: CP) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<CP> for u32 {
-    fn from(input: CP) -> Self {
-        Self::from(u16::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CP(u32);
+#[allow(non_upper_case_globals)]
+impl CP {
+    pub const Repeat: Self = Self(1 << 0);
+    pub const AlphaMap: Self = Self(1 << 1);
+    pub const AlphaXOrigin: Self = Self(1 << 2);
+    pub const AlphaYOrigin: Self = Self(1 << 3);
+    pub const ClipXOrigin: Self = Self(1 << 4);
+    pub const ClipYOrigin: Self = Self(1 << 5);
+    pub const ClipMask: Self = Self(1 << 6);
+    pub const GraphicsExposure: Self = Self(1 << 7);
+    pub const SubwindowMode: Self = Self(1 << 8);
+    pub const PolyEdge: Self = Self(1 << 9);
+    pub const PolyMode: Self = Self(1 << 10);
+    pub const Dither: Self = Self(1 << 11);
+    pub const ComponentAlpha: Self = Self(1 << 12);
+}
+bitmask_set!(CP, u32, [Repeat, AlphaMap, AlphaXOrigin, AlphaYOrigin, ClipXOrigin, ClipYOrigin, ClipMask, GraphicsExposure, SubwindowMode, PolyEdge, PolyMode, Dither, ComponentAlpha]);
 impl From<CP> for Option<u32> {
     fn from(input: CP) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for CP {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(CP::Repeat),
-            2 => Ok(CP::AlphaMap),
-            4 => Ok(CP::AlphaXOrigin),
-            8 => Ok(CP::AlphaYOrigin),
-            16 => Ok(CP::ClipXOrigin),
-            32 => Ok(CP::ClipYOrigin),
-            64 => Ok(CP::ClipMask),
-            128 => Ok(CP::GraphicsExposure),
-            256 => Ok(CP::SubwindowMode),
-            512 => Ok(CP::PolyEdge),
-            1024 => Ok(CP::PolyMode),
-            2048 => Ok(CP::Dither),
-            4096 => Ok(CP::ComponentAlpha),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for CP {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-bitmask_binop!(CP, u16);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SubPixel {
-    Unknown = 0,
-    HorizontalRGB = 1,
-    HorizontalBGR = 2,
-    VerticalRGB = 3,
-    VerticalBGR = 4,
-    None = 5,
-}
-impl From<SubPixel> for u8 {
-    fn from(input: SubPixel) -> Self {
-        match input {
-            SubPixel::Unknown => 0,
-            SubPixel::HorizontalRGB => 1,
-            SubPixel::HorizontalBGR => 2,
-            SubPixel::VerticalRGB => 3,
-            SubPixel::VerticalBGR => 4,
-            SubPixel::None => 5,
-        }
-    }
-}
-impl From<SubPixel> for Option<u8> {
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SubPixel(u32);
+#[allow(non_upper_case_globals)]
+impl SubPixel {
+    pub const Unknown: Self = Self(0);
+    pub const HorizontalRGB: Self = Self(1);
+    pub const HorizontalBGR: Self = Self(2);
+    pub const VerticalRGB: Self = Self(3);
+    pub const VerticalBGR: Self = Self(4);
+    pub const None: Self = Self(5);
+}
+enum_newtype!(SubPixel, u32, [Unknown, HorizontalRGB, HorizontalBGR, VerticalRGB, VerticalBGR, None]);
+impl From<SubPixel> for Option<u32> {
     fn from(input: SubPixel) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
-impl From<SubPixel> for u16 {
-    fn from(input: SubPixel) -> Self {
-        Self::from(u8::from(input))
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Repeat(u32);
+#[allow(non_upper_case_globals)]
+impl Repeat {
+    pub const None: Self = Self(0);
+    pub const Normal: Self = Self(1);
+    pub const Pad: Self = Self(2);
+    pub const Reflect: Self = Self(3);
+}
+enum_newtype!(Repeat, u32, [None, Normal, Pad, Reflect]);
+impl From<Repeat> for Option<u32> {
+    fn from(input: Repeat) -> Self {
+        Some(input.0)
     }
 }
-impl From<SubPixel> for Option<u16> {
-    fn from(input: SubPixel) -> Self {
-        Some(u16::from(input))
-    }
+
+pub type Glyph = u32;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Glyphset(u32);
+impl Glyphset {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Glyphset(0);
 }
-impl From<SubPixel> for u32 {
-    fn from(input: SubPixel) -> Self {
-        Self::from(u8::from(input))
+impl From<u32> for Glyphset {
+    fn from(value: u32) -> Self {
+        Self(value)
     }
 }
-impl From<SubPixel> for Option<u32> {
-    fn from(input: SubPixel) -> Self {
-        Some(u32::from(input))
+impl From<Glyphset> for u32 {
+    fn from(value: Glyphset) -> Self {
+        value.0
     }
 }
-impl TryFrom<u8> for SubPixel {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SubPixel::Unknown),
-            1 => Ok(SubPixel::HorizontalRGB),
-            2 => Ok(SubPixel::HorizontalBGR),
-            3 => Ok(SubPixel::VerticalRGB),
-            4 => Ok(SubPixel::VerticalBGR),
-            5 => Ok(SubPixel::None),
-            _ => Err(ParseError::ParseError),
-        }
+impl TryParse for Glyphset {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
     }
 }
-impl TryFrom<u16> for SubPixel {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl Serialize for Glyphset {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
     }
-}
-impl TryFrom<u32> for SubPixel {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Repeat {
-    None = 0,
-    Normal = 1,
-    Pad = 2,
-    Reflect = 3,
-}
-impl From<Repeat> for u8 {
-    fn from(input: Repeat) -> Self {
-        match input {
-            Repeat::None => 0,
-            Repeat::Normal => 1,
-            Repeat::Pad => 2,
-            Repeat::Reflect => 3,
-        }
-    }
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Picture(u32);
+impl Picture {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Picture(0);
 }
-impl From<Repeat> for Option<u8> {
-    fn from(input: Repeat) -> Self {
-        Some(u8::from(input))
+impl From<u32> for Picture {
+    fn from(value: u32) -> Self {
+        Self(value)
     }
 }
-impl From<Repeat> for u16 {
-    fn from(input: Repeat) -> Self {
-        Self::from(u8::from(input))
+impl From<Picture> for u32 {
+    fn from(value: Picture) -> Self {
+        value.0
     }
 }
-impl From<Repeat> for Option<u16> {
-    fn from(input: Repeat) -> Self {
-        Some(u16::from(input))
+impl TryParse for Picture {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
     }
 }
-impl From<Repeat> for u32 {
-    fn from(input: Repeat) -> Self {
-        Self::from(u8::from(input))
+impl Serialize for Picture {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
     }
 }
-impl From<Repeat> for Option<u32> {
-    fn from(input: Repeat) -> Self {
-        Some(u32::from(input))
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pictformat(u32);
+impl Pictformat {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Pictformat(0);
+}
+impl From<u32> for Pictformat {
+    fn from(value: u32) -> Self {
+        Self(value)
     }
 }
-impl TryFrom<u8> for Repeat {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Repeat::None),
-            1 => Ok(Repeat::Normal),
-            2 => Ok(Repeat::Pad),
-            3 => Ok(Repeat::Reflect),
-            _ => Err(ParseError::ParseError),
-        }
+impl From<Pictformat> for u32 {
+    fn from(value: Pictformat) -> Self {
+        value.0
     }
 }
-impl TryFrom<u16> for Repeat {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl TryParse for Pictformat {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
     }
 }
-impl TryFrom<u32> for Repeat {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl Serialize for Pictformat {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
     }
 }
 
-pub type Glyph = u32;
-
-pub type Glyphset = u32;
-
-pub type Picture = u32;
-
-pub type Pictformat = u32;
-
 pub type Fixed = i32;
 
 /// Opcode for the PictFormat error
@@ -756,10 +404,10 @@ pub struct PictFormatError {
     pub sequence: u16,
 }
 impl TryParse for PictFormatError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = PictFormatError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -772,9 +420,20 @@ impl TryFrom<&[u8]> for PictFormatError {
 }
 impl From<&PictFormatError> for [u8; 32] {
     fn from(input: &PictFormatError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<PictFormatError> for [u8; 32] {
+    fn from(input: PictFormatError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for PictFormatError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -811,10 +470,8 @@ impl From<&PictFormatError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<PictFormatError> for [u8; 32] {
-    fn from(input: PictFormatError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -827,10 +484,10 @@ pub struct PictureError {
     pub sequence: u16,
 }
 impl TryParse for PictureError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = PictureError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -843,9 +500,20 @@ impl TryFrom<&[u8]> for PictureError {
 }
 impl From<&PictureError> for [u8; 32] {
     fn from(input: &PictureError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<PictureError> for [u8; 32] {
+    fn from(input: PictureError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for PictureError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -882,10 +550,8 @@ impl From<&PictureError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<PictureError> for [u8; 32] {
-    fn from(input: PictureError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -898,10 +564,10 @@ pub struct PictOpError {
     pub sequence: u16,
 }
 impl TryParse for PictOpError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = PictOpError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -914,9 +580,20 @@ impl TryFrom<&[u8]> for PictOpError {
 }
 impl From<&PictOpError> for [u8; 32] {
     fn from(input: &PictOpError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<PictOpError> for [u8; 32] {
+    fn from(input: PictOpError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for PictOpError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -953,10 +630,8 @@ impl From<&PictOpError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<PictOpError> for [u8; 32] {
-    fn from(input: PictOpError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -969,10 +644,10 @@ pub struct GlyphSetError {
     pub sequence: u16,
 }
 impl TryParse for GlyphSetError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = GlyphSetError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -985,9 +660,20 @@ impl TryFrom<&[u8]> for GlyphSetError {
 }
 impl From<&GlyphSetError> for [u8; 32] {
     fn from(input: &GlyphSetError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<GlyphSetError> for [u8; 32] {
+    fn from(input: GlyphSetError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for GlyphSetError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1024,10 +710,8 @@ impl From<&GlyphSetError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<GlyphSetError> for [u8; 32] {
-    fn from(input: GlyphSetError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1040,10 +724,10 @@ pub struct GlyphError {
     pub sequence: u16,
 }
 impl TryParse for GlyphError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = GlyphError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1056,9 +740,20 @@ impl TryFrom<&[u8]> for GlyphError {
 }
 impl From<&GlyphError> for [u8; 32] {
     fn from(input: &GlyphError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<GlyphError> for [u8; 32] {
+    fn from(input: GlyphError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for GlyphError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1095,10 +790,8 @@ impl From<&GlyphError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<GlyphError> for [u8; 32] {
-    fn from(input: GlyphError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1114,15 +807,15 @@ pub struct Directformat {
     pub alpha_mask: u16,
 }
 impl TryParse for Directformat {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (red_shift, remaining) = u16::try_parse(remaining)?;
-        let (red_mask, remaining) = u16::try_parse(remaining)?;
-        let (green_shift, remaining) = u16::try_parse(remaining)?;
-        let (green_mask, remaining) = u16::try_parse(remaining)?;
-        let (blue_shift, remaining) = u16::try_parse(remaining)?;
-        let (blue_mask, remaining) = u16::try_parse(remaining)?;
-        let (alpha_shift, remaining) = u16::try_parse(remaining)?;
-        let (alpha_mask, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (red_shift, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (red_mask, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (green_shift, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (green_mask, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (blue_shift, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (blue_mask, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (alpha_shift, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (alpha_mask, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = Directformat { red_shift, red_mask, green_shift, green_mask, blue_shift, blue_mask, alpha_shift, alpha_mask };
         Ok((result, remaining))
     }
@@ -1135,15 +828,15 @@ impl TryFrom<&[u8]> for Directformat {
 }
 impl Serialize for Directformat {
     type Bytes = [u8; 16];
-    fn serialize(&self) -> [u8; 16] {
-        let red_shift_bytes = self.red_shift.serialize();
-        let red_mask_bytes = self.red_mask.serialize();
-        let green_shift_bytes = self.green_shift.serialize();
-        let green_mask_bytes = self.green_mask.serialize();
-        let blue_shift_bytes = self.blue_shift.serialize();
-        let blue_mask_bytes = self.blue_mask.serialize();
-        let alpha_shift_bytes = self.alpha_shift.serialize();
-        let alpha_mask_bytes = self.alpha_mask.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 16] {
+        let red_shift_bytes = self.red_shift.serialize_with_order(order);
+        let red_mask_bytes = self.red_mask.serialize_with_order(order);
+        let green_shift_bytes = self.green_shift.serialize_with_order(order);
+        let green_mask_bytes = self.green_mask.serialize_with_order(order);
+        let blue_shift_bytes = self.blue_shift.serialize_with_order(order);
+        let blue_mask_bytes = self.blue_mask.serialize_with_order(order);
+        let alpha_shift_bytes = self.alpha_shift.serialize_with_order(order);
+        let alpha_mask_bytes = self.alpha_mask.serialize_with_order(order);
         [
             red_shift_bytes[0],
             red_shift_bytes[1],
@@ -1163,16 +856,16 @@ impl Serialize for Directformat {
             alpha_mask_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(16);
-        self.red_shift.serialize_into(bytes);
-        self.red_mask.serialize_into(bytes);
-        self.green_shift.serialize_into(bytes);
-        self.green_mask.serialize_into(bytes);
-        self.blue_shift.serialize_into(bytes);
-        self.blue_mask.serialize_into(bytes);
-        self.alpha_shift.serialize_into(bytes);
-        self.alpha_mask.serialize_into(bytes);
+        self.red_shift.serialize_into_with_order(bytes, order);
+        self.red_mask.serialize_into_with_order(bytes, order);
+        self.green_shift.serialize_into_with_order(bytes, order);
+        self.green_mask.serialize_into_with_order(bytes, order);
+        self.blue_shift.serialize_into_with_order(bytes, order);
+        self.blue_mask.serialize_into_with_order(bytes, order);
+        self.alpha_shift.serialize_into_with_order(bytes, order);
+        self.alpha_mask.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1185,14 +878,14 @@ pub struct Pictforminfo {
     pub colormap: xproto::Colormap,
 }
 impl TryParse for Pictforminfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = Pictformat::try_parse(remaining)?;
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (id, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (direct, remaining) = Directformat::try_parse(remaining)?;
-        let (colormap, remaining) = xproto::Colormap::try_parse(remaining)?;
-        let type_ = type_.try_into()?;
+        let (direct, remaining) = Directformat::try_parse_with_order(remaining, order)?;
+        let (colormap, remaining) = xproto::Colormap::try_parse_with_order(remaining, order)?;
+        let type_ = PictType::from(type_);
         let result = Pictforminfo { id, type_, depth, direct, colormap };
         Ok((result, remaining))
     }
@@ -1205,12 +898,12 @@ impl TryFrom<&[u8]> for Pictforminfo {
 }
 impl Serialize for Pictforminfo {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let id_bytes = self.id.serialize();
-        let type_bytes = u8::from(self.type_).serialize();
-        let depth_bytes = self.depth.serialize();
-        let direct_bytes = self.direct.serialize();
-        let colormap_bytes = self.colormap.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let id_bytes = self.id.serialize_with_order(order);
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let depth_bytes = self.depth.serialize_with_order(order);
+        let direct_bytes = self.direct.serialize_with_order(order);
+        let colormap_bytes = self.colormap.serialize_with_order(order);
         [
             id_bytes[0],
             id_bytes[1],
@@ -1242,14 +935,14 @@ impl Serialize for Pictforminfo {
             colormap_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        self.id.serialize_into(bytes);
-        u8::from(self.type_).serialize_into(bytes);
-        self.depth.serialize_into(bytes);
+        self.id.serialize_into_with_order(bytes, order);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.direct.serialize_into(bytes);
-        self.colormap.serialize_into(bytes);
+        self.direct.serialize_into_with_order(bytes, order);
+        self.colormap.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1259,9 +952,9 @@ pub struct Pictvisual {
     pub format: Pictformat,
 }
 impl TryParse for Pictvisual {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (format, remaining) = Pictformat::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
         let result = Pictvisual { visual, format };
         Ok((result, remaining))
     }
@@ -1274,9 +967,9 @@ impl TryFrom<&[u8]> for Pictvisual {
 }
 impl Serialize for Pictvisual {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let visual_bytes = self.visual.serialize();
-        let format_bytes = self.format.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let visual_bytes = self.visual.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
         [
             visual_bytes[0],
             visual_bytes[1],
@@ -1288,10 +981,10 @@ impl Serialize for Pictvisual {
             format_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.visual.serialize_into(bytes);
-        self.format.serialize_into(bytes);
+        self.visual.serialize_into_with_order(bytes, order);
+        self.format.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1301,12 +994,12 @@ pub struct Pictdepth {
     pub visuals: Vec<Pictvisual>,
 }
 impl TryParse for Pictdepth {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (depth, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (num_visuals, remaining) = u16::try_parse(remaining)?;
+        let (num_visuals, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (visuals, remaining) = crate::x11_utils::parse_list::<Pictvisual>(remaining, num_visuals.try_into().or(Err(ParseError::ParseError))?)?;
+        let (visuals, remaining) = crate::x11_utils::parse_list_with_order::<Pictvisual>(remaining, num_visuals.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = Pictdepth { depth, visuals };
         Ok((result, remaining))
     }
@@ -1319,19 +1012,19 @@ impl TryFrom<&[u8]> for Pictdepth {
 }
 impl Serialize for Pictdepth {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.depth.serialize_into(bytes);
+        self.depth.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
         let num_visuals = u16::try_from(self.visuals.len()).expect("`visuals` has too many elements");
-        num_visuals.serialize_into(bytes);
+        num_visuals.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 4]);
-        self.visuals.serialize_into(bytes);
+        self.visuals.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1341,10 +1034,10 @@ pub struct Pictscreen {
     pub depths: Vec<Pictdepth>,
 }
 impl TryParse for Pictscreen {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_depths, remaining) = u32::try_parse(remaining)?;
-        let (fallback, remaining) = Pictformat::try_parse(remaining)?;
-        let (depths, remaining) = crate::x11_utils::parse_list::<Pictdepth>(remaining, num_depths.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (num_depths, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (fallback, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (depths, remaining) = crate::x11_utils::parse_list_with_order::<Pictdepth>(remaining, num_depths.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = Pictscreen { fallback, depths };
         Ok((result, remaining))
     }
@@ -1357,17 +1050,17 @@ impl TryFrom<&[u8]> for Pictscreen {
 }
 impl Serialize for Pictscreen {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
         let num_depths = u32::try_from(self.depths.len()).expect("`depths` has too many elements");
-        num_depths.serialize_into(bytes);
-        self.fallback.serialize_into(bytes);
-        self.depths.serialize_into(bytes);
+        num_depths.serialize_into_with_order(bytes, order);
+        self.fallback.serialize_into_with_order(bytes, order);
+        self.depths.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1380,12 +1073,12 @@ pub struct Indexvalue {
     pub alpha: u16,
 }
 impl TryParse for Indexvalue {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (pixel, remaining) = u32::try_parse(remaining)?;
-        let (red, remaining) = u16::try_parse(remaining)?;
-        let (green, remaining) = u16::try_parse(remaining)?;
-        let (blue, remaining) = u16::try_parse(remaining)?;
-        let (alpha, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (pixel, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (red, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (green, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (blue, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (alpha, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = Indexvalue { pixel, red, green, blue, alpha };
         Ok((result, remaining))
     }
@@ -1398,12 +1091,12 @@ impl TryFrom<&[u8]> for Indexvalue {
 }
 impl Serialize for Indexvalue {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let pixel_bytes = self.pixel.serialize();
-        let red_bytes = self.red.serialize();
-        let green_bytes = self.green.serialize();
-        let blue_bytes = self.blue.serialize();
-        let alpha_bytes = self.alpha.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let pixel_bytes = self.pixel.serialize_with_order(order);
+        let red_bytes = self.red.serialize_with_order(order);
+        let green_bytes = self.green.serialize_with_order(order);
+        let blue_bytes = self.blue.serialize_with_order(order);
+        let alpha_bytes = self.alpha.serialize_with_order(order);
         [
             pixel_bytes[0],
             pixel_bytes[1],
@@ -1419,13 +1112,13 @@ impl Serialize for Indexvalue {
             alpha_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.pixel.serialize_into(bytes);
-        self.red.serialize_into(bytes);
-        self.green.serialize_into(bytes);
-        self.blue.serialize_into(bytes);
-        self.alpha.serialize_into(bytes);
+        self.pixel.serialize_into_with_order(bytes, order);
+        self.red.serialize_into_with_order(bytes, order);
+        self.green.serialize_into_with_order(bytes, order);
+        self.blue.serialize_into_with_order(bytes, order);
+        self.alpha.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1437,11 +1130,11 @@ pub struct Color {
     pub alpha: u16,
 }
 impl TryParse for Color {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (red, remaining) = u16::try_parse(remaining)?;
-        let (green, remaining) = u16::try_parse(remaining)?;
-        let (blue, remaining) = u16::try_parse(remaining)?;
-        let (alpha, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (red, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (green, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (blue, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (alpha, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = Color { red, green, blue, alpha };
         Ok((result, remaining))
     }
@@ -1454,11 +1147,11 @@ impl TryFrom<&[u8]> for Color {
 }
 impl Serialize for Color {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let red_bytes = self.red.serialize();
-        let green_bytes = self.green.serialize();
-        let blue_bytes = self.blue.serialize();
-        let alpha_bytes = self.alpha.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let red_bytes = self.red.serialize_with_order(order);
+        let green_bytes = self.green.serialize_with_order(order);
+        let blue_bytes = self.blue.serialize_with_order(order);
+        let alpha_bytes = self.alpha.serialize_with_order(order);
         [
             red_bytes[0],
             red_bytes[1],
@@ -1470,12 +1163,12 @@ impl Serialize for Color {
             alpha_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.red.serialize_into(bytes);
-        self.green.serialize_into(bytes);
-        self.blue.serialize_into(bytes);
-        self.alpha.serialize_into(bytes);
+        self.red.serialize_into_with_order(bytes, order);
+        self.green.serialize_into_with_order(bytes, order);
+        self.blue.serialize_into_with_order(bytes, order);
+        self.alpha.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1485,9 +1178,9 @@ pub struct Pointfix {
     pub y: Fixed,
 }
 impl TryParse for Pointfix {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (x, remaining) = Fixed::try_parse(remaining)?;
-        let (y, remaining) = Fixed::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (x, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = Fixed::try_parse_with_order(remaining, order)?;
         let result = Pointfix { x, y };
         Ok((result, remaining))
     }
@@ -1500,9 +1193,9 @@ impl TryFrom<&[u8]> for Pointfix {
 }
 impl Serialize for Pointfix {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let x_bytes = self.x.serialize();
-        let y_bytes = self.y.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
         [
             x_bytes[0],
             x_bytes[1],
@@ -1514,10 +1207,10 @@ impl Serialize for Pointfix {
             y_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.x.serialize_into(bytes);
-        self.y.serialize_into(bytes);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1527,9 +1220,9 @@ pub struct Linefix {
     pub p2: Pointfix,
 }
 impl TryParse for Linefix {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (p1, remaining) = Pointfix::try_parse(remaining)?;
-        let (p2, remaining) = Pointfix::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (p1, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+        let (p2, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
         let result = Linefix { p1, p2 };
         Ok((result, remaining))
     }
@@ -1542,9 +1235,9 @@ impl TryFrom<&[u8]> for Linefix {
 }
 impl Serialize for Linefix {
     type Bytes = [u8; 16];
-    fn serialize(&self) -> [u8; 16] {
-        let p1_bytes = self.p1.serialize();
-        let p2_bytes = self.p2.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 16] {
+        let p1_bytes = self.p1.serialize_with_order(order);
+        let p2_bytes = self.p2.serialize_with_order(order);
         [
             p1_bytes[0],
             p1_bytes[1],
@@ -1564,10 +1257,10 @@ impl Serialize for Linefix {
             p2_bytes[7],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(16);
-        self.p1.serialize_into(bytes);
-        self.p2.serialize_into(bytes);
+        self.p1.serialize_into_with_order(bytes, order);
+        self.p2.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1578,10 +1271,10 @@ pub struct Triangle {
     pub p3: Pointfix,
 }
 impl TryParse for Triangle {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (p1, remaining) = Pointfix::try_parse(remaining)?;
-        let (p2, remaining) = Pointfix::try_parse(remaining)?;
-        let (p3, remaining) = Pointfix::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (p1, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+        let (p2, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+        let (p3, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
         let result = Triangle { p1, p2, p3 };
         Ok((result, remaining))
     }
@@ -1594,10 +1287,10 @@ impl TryFrom<&[u8]> for Triangle {
 }
 impl Serialize for Triangle {
     type Bytes = [u8; 24];
-    fn serialize(&self) -> [u8; 24] {
-        let p1_bytes = self.p1.serialize();
-        let p2_bytes = self.p2.serialize();
-        let p3_bytes = self.p3.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 24] {
+        let p1_bytes = self.p1.serialize_with_order(order);
+        let p2_bytes = self.p2.serialize_with_order(order);
+        let p3_bytes = self.p3.serialize_with_order(order);
         [
             p1_bytes[0],
             p1_bytes[1],
@@ -1625,11 +1318,11 @@ impl Serialize for Triangle {
             p3_bytes[7],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(24);
-        self.p1.serialize_into(bytes);
-        self.p2.serialize_into(bytes);
-        self.p3.serialize_into(bytes);
+        self.p1.serialize_into_with_order(bytes, order);
+        self.p2.serialize_into_with_order(bytes, order);
+        self.p3.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1641,11 +1334,11 @@ pub struct Trapezoid {
     pub right: Linefix,
 }
 impl TryParse for Trapezoid {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (top, remaining) = Fixed::try_parse(remaining)?;
-        let (bottom, remaining) = Fixed::try_parse(remaining)?;
-        let (left, remaining) = Linefix::try_parse(remaining)?;
-        let (right, remaining) = Linefix::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (top, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (bottom, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (left, remaining) = Linefix::try_parse_with_order(remaining, order)?;
+        let (right, remaining) = Linefix::try_parse_with_order(remaining, order)?;
         let result = Trapezoid { top, bottom, left, right };
         Ok((result, remaining))
     }
@@ -1658,11 +1351,11 @@ impl TryFrom<&[u8]> for Trapezoid {
 }
 impl Serialize for Trapezoid {
     type Bytes = [u8; 40];
-    fn serialize(&self) -> [u8; 40] {
-        let top_bytes = self.top.serialize();
-        let bottom_bytes = self.bottom.serialize();
-        let left_bytes = self.left.serialize();
-        let right_bytes = self.right.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 40] {
+        let top_bytes = self.top.serialize_with_order(order);
+        let bottom_bytes = self.bottom.serialize_with_order(order);
+        let left_bytes = self.left.serialize_with_order(order);
+        let right_bytes = self.right.serialize_with_order(order);
         [
             top_bytes[0],
             top_bytes[1],
@@ -1706,12 +1399,12 @@ impl Serialize for Trapezoid {
             right_bytes[15],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(40);
-        self.top.serialize_into(bytes);
-        self.bottom.serialize_into(bytes);
-        self.left.serialize_into(bytes);
-        self.right.serialize_into(bytes);
+        self.top.serialize_into_with_order(bytes, order);
+        self.bottom.serialize_into_with_order(bytes, order);
+        self.left.serialize_into_with_order(bytes, order);
+        self.right.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -1725,13 +1418,13 @@ pub struct Glyphinfo {
     pub y_off: i16,
 }
 impl TryParse for Glyphinfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (x_off, remaining) = i16::try_parse(remaining)?;
-        let (y_off, remaining) = i16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (x_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_off, remaining) = i16::try_parse_with_order(remaining, order)?;
         let result = Glyphinfo { width, height, x, y, x_off, y_off };
         Ok((result, remaining))
     }
@@ -1744,13 +1437,13 @@ impl TryFrom<&[u8]> for Glyphinfo {
 }
 impl Serialize for Glyphinfo {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let width_bytes = self.width.serialize();
-        let height_bytes = self.height.serialize();
-        let x_bytes = self.x.serialize();
-        let y_bytes = self.y.serialize();
-        let x_off_bytes = self.x_off.serialize();
-        let y_off_bytes = self.y_off.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
+        let x_off_bytes = self.x_off.serialize_with_order(order);
+        let y_off_bytes = self.y_off.serialize_with_order(order);
         [
             width_bytes[0],
             width_bytes[1],
@@ -1766,28 +1459,62 @@ impl Serialize for Glyphinfo {
             y_off_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
-        self.x.serialize_into(bytes);
-        self.y.serialize_into(bytes);
-        self.x_off.serialize_into(bytes);
-        self.y_off.serialize_into(bytes);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.x_off.serialize_into_with_order(bytes, order);
+        self.y_off.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (client_major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
+    }
+    /// Set the `client_major_version` field of this structure.
+    pub fn client_major_version(mut self, value: u32) -> Self {
+        self.client_major_version = value;
+        self
+    }
+    /// Set the `client_minor_version` field of this structure.
+    pub fn client_minor_version(mut self, value: u32) -> Self {
+        self.client_minor_version = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let client_major_version_bytes = client_major_version.serialize();
-    let client_minor_version_bytes = client_minor_version.serialize();
+    let client_major_version_bytes = client_major_version.serialize_with_order(order);
+    let client_minor_version_bytes = client_minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -1805,9 +1532,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.client_major_version, self.client_minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -1818,13 +1557,13 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
@@ -1836,15 +1575,58 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryPictFormats request
 pub const QUERY_PICT_FORMATS_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryPictFormatsRequest {
+}
+impl QueryPictFormatsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_PICT_FORMATS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryPictFormatsRequest {
+        })
+    }
+}
+
 pub fn query_pict_formats<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryPictFormatsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -1855,9 +1637,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryPictFormatsRequest {
+    type Reply = QueryPictFormatsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryPictFormatsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryPictFormatsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_pict_formats(conn)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryPictFormatsReply {
@@ -1871,25 +1665,25 @@ pub struct QueryPictFormatsReply {
     pub subpixels: Vec<SubPixel>,
 }
 impl TryParse for QueryPictFormatsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_formats, remaining) = u32::try_parse(remaining)?;
-        let (num_screens, remaining) = u32::try_parse(remaining)?;
-        let (num_depths, remaining) = u32::try_parse(remaining)?;
-        let (num_visuals, remaining) = u32::try_parse(remaining)?;
-        let (num_subpixel, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_formats, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_screens, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_depths, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_visuals, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_subpixel, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (formats, remaining) = crate::x11_utils::parse_list::<Pictforminfo>(remaining, num_formats.try_into().or(Err(ParseError::ParseError))?)?;
-        let (screens, remaining) = crate::x11_utils::parse_list::<Pictscreen>(remaining, num_screens.try_into().or(Err(ParseError::ParseError))?)?;
+        let (formats, remaining) = crate::x11_utils::parse_list_with_order::<Pictforminfo>(remaining, num_formats.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (screens, remaining) = crate::x11_utils::parse_list_with_order::<Pictscreen>(remaining, num_screens.try_into().or(Err(ParseError::ParseError))?, order)?;
         let mut remaining = remaining;
         let list_length = usize::try_from(num_subpixel).or(Err(ParseError::ParseError))?;
         let mut subpixels = Vec::with_capacity(list_length);
         for _ in 0..list_length {
-            let (v, new_remaining) = u32::try_parse(remaining)?;
-            let v = v.try_into()?;
+            let (v, new_remaining) = u32::try_parse_with_order(remaining, order)?;
+            let v = SubPixel::from(v);
             remaining = new_remaining;
             subpixels.push(v);
         }
@@ -1903,17 +1697,79 @@ impl TryFrom<&[u8]> for QueryPictFormatsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryPictFormatsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_formats = u32::try_from(self.formats.len()).expect("`formats` has too many elements");
+        num_formats.serialize_into_with_order(bytes, order);
+        let num_screens = u32::try_from(self.screens.len()).expect("`screens` has too many elements");
+        num_screens.serialize_into_with_order(bytes, order);
+        self.num_depths.serialize_into_with_order(bytes, order);
+        self.num_visuals.serialize_into_with_order(bytes, order);
+        let num_subpixel = u32::try_from(self.subpixels.len()).expect("`subpixels` has too many elements");
+        num_subpixel.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.formats.serialize_into_with_order(bytes, order);
+        self.screens.serialize_into_with_order(bytes, order);
+        for element in self.subpixels.iter() {
+            u32::from(*element).serialize_into_with_order(bytes, order);
+        }
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryPictIndexValues request
 pub const QUERY_PICT_INDEX_VALUES_REQUEST: u8 = 2;
-pub fn query_pict_index_values<Conn>(conn: &Conn, format: Pictformat) -> Result<Cookie<'_, Conn, QueryPictIndexValuesReply>, ConnectionError>
-where
-    Conn: RequestConnection + ?Sized,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryPictIndexValuesRequest {
+    pub format: Pictformat,
+}
+impl QueryPictIndexValuesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_PICT_INDEX_VALUES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryPictIndexValuesRequest {
+            format,
+        })
+    }
+    /// Set the `format` field of this structure.
+    pub fn format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.format = value.into();
+        self
+    }
+}
+
+pub fn query_pict_index_values<Conn>(conn: &Conn, format: Pictformat) -> Result<Cookie<'_, Conn, QueryPictIndexValuesReply>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let format_bytes = format.serialize();
+    let format_bytes = format.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_PICT_INDEX_VALUES_REQUEST,
@@ -1927,9 +1783,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryPictIndexValuesRequest {
+    type Reply = QueryPictIndexValuesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryPictIndexValuesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryPictIndexValuesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_pict_index_values(conn, self.format)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryPictIndexValuesReply {
@@ -1939,14 +1807,14 @@ pub struct QueryPictIndexValuesReply {
     pub values: Vec<Indexvalue>,
 }
 impl TryParse for QueryPictIndexValuesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_values, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_values, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (values, remaining) = crate::x11_utils::parse_list::<Indexvalue>(remaining, num_values.try_into().or(Err(ParseError::ParseError))?)?;
+        let (values, remaining) = crate::x11_utils::parse_list_with_order::<Indexvalue>(remaining, num_values.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryPictIndexValuesReply { response_type, sequence, length, values };
         Ok((result, remaining))
     }
@@ -1957,6 +1825,32 @@ impl TryFrom<&[u8]> for QueryPictIndexValuesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryPictIndexValuesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_values = u32::try_from(self.values.len()).expect("`values` has too many elements");
+        num_values.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.values.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreatePicture request
 pub const CREATE_PICTURE_REQUEST: u8 = 4;
@@ -1977,52 +1871,168 @@ pub struct CreatePictureAux {
     pub dither: Option<xproto::Atom>,
     pub componentalpha: Option<u32>,
 }
+impl CreatePictureAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, value_mask: CP) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(value_mask);
+        let mut outer_remaining = value;
+        let repeat = if switch_expr & u32::from(CP::Repeat) != 0 {
+            let remaining = outer_remaining;
+            let (repeat, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let repeat = Repeat::from(repeat);
+            outer_remaining = remaining;
+            Some(repeat)
+        } else {
+            None
+        };
+        let alphamap = if switch_expr & u32::from(CP::AlphaMap) != 0 {
+            let remaining = outer_remaining;
+            let (alphamap, remaining) = Picture::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(alphamap)
+        } else {
+            None
+        };
+        let alphaxorigin = if switch_expr & u32::from(CP::AlphaXOrigin) != 0 {
+            let remaining = outer_remaining;
+            let (alphaxorigin, remaining) = i32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(alphaxorigin)
+        } else {
+            None
+        };
+        let alphayorigin = if switch_expr & u32::from(CP::AlphaYOrigin) != 0 {
+            let remaining = outer_remaining;
+            let (alphayorigin, remaining) = i32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(alphayorigin)
+        } else {
+            None
+        };
+        let clipxorigin = if switch_expr & u32::from(CP::ClipXOrigin) != 0 {
+            let remaining = outer_remaining;
+            let (clipxorigin, remaining) = i32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(clipxorigin)
+        } else {
+            None
+        };
+        let clipyorigin = if switch_expr & u32::from(CP::ClipYOrigin) != 0 {
+            let remaining = outer_remaining;
+            let (clipyorigin, remaining) = i32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(clipyorigin)
+        } else {
+            None
+        };
+        let clipmask = if switch_expr & u32::from(CP::ClipMask) != 0 {
+            let remaining = outer_remaining;
+            let (clipmask, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(clipmask)
+        } else {
+            None
+        };
+        let graphicsexposure = if switch_expr & u32::from(CP::GraphicsExposure) != 0 {
+            let remaining = outer_remaining;
+            let (graphicsexposure, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(graphicsexposure)
+        } else {
+            None
+        };
+        let subwindowmode = if switch_expr & u32::from(CP::SubwindowMode) != 0 {
+            let remaining = outer_remaining;
+            let (subwindowmode, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let subwindowmode = xproto::SubwindowMode::from(subwindowmode);
+            outer_remaining = remaining;
+            Some(subwindowmode)
+        } else {
+            None
+        };
+        let polyedge = if switch_expr & u32::from(CP::PolyEdge) != 0 {
+            let remaining = outer_remaining;
+            let (polyedge, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let polyedge = PolyEdge::from(polyedge);
+            outer_remaining = remaining;
+            Some(polyedge)
+        } else {
+            None
+        };
+        let polymode = if switch_expr & u32::from(CP::PolyMode) != 0 {
+            let remaining = outer_remaining;
+            let (polymode, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let polymode = PolyMode::from(polymode);
+            outer_remaining = remaining;
+            Some(polymode)
+        } else {
+            None
+        };
+        let dither = if switch_expr & u32::from(CP::Dither) != 0 {
+            let remaining = outer_remaining;
+            let (dither, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(dither)
+        } else {
+            None
+        };
+        let componentalpha = if switch_expr & u32::from(CP::ComponentAlpha) != 0 {
+            let remaining = outer_remaining;
+            let (componentalpha, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(componentalpha)
+        } else {
+            None
+        };
+        let result = CreatePictureAux { repeat, alphamap, alphaxorigin, alphayorigin, clipxorigin, clipyorigin, clipmask, graphicsexposure, subwindowmode, polyedge, polymode, dither, componentalpha };
+        Ok((result, outer_remaining))
+    }
+}
 #[allow(dead_code, unused_variables)]
 impl CreatePictureAux {
-    fn serialize(&self, value_mask: u32) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, value_mask: CP) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, value_mask);
+        self.serialize_into_with_order(&mut result, order, value_mask);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, value_mask: u32) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, value_mask: CP) {
         if let Some(repeat) = self.repeat {
-            u32::from(repeat).serialize_into(bytes);
+            u32::from(repeat).serialize_into_with_order(bytes, order);
         }
         if let Some(alphamap) = self.alphamap {
-            alphamap.serialize_into(bytes);
+            alphamap.serialize_into_with_order(bytes, order);
         }
         if let Some(alphaxorigin) = self.alphaxorigin {
-            alphaxorigin.serialize_into(bytes);
+            alphaxorigin.serialize_into_with_order(bytes, order);
         }
         if let Some(alphayorigin) = self.alphayorigin {
-            alphayorigin.serialize_into(bytes);
+            alphayorigin.serialize_into_with_order(bytes, order);
         }
         if let Some(clipxorigin) = self.clipxorigin {
-            clipxorigin.serialize_into(bytes);
+            clipxorigin.serialize_into_with_order(bytes, order);
         }
         if let Some(clipyorigin) = self.clipyorigin {
-            clipyorigin.serialize_into(bytes);
+            clipyorigin.serialize_into_with_order(bytes, order);
         }
         if let Some(clipmask) = self.clipmask {
-            clipmask.serialize_into(bytes);
+            clipmask.serialize_into_with_order(bytes, order);
         }
         if let Some(graphicsexposure) = self.graphicsexposure {
-            graphicsexposure.serialize_into(bytes);
+            graphicsexposure.serialize_into_with_order(bytes, order);
         }
         if let Some(subwindowmode) = self.subwindowmode {
-            u32::from(subwindowmode).serialize_into(bytes);
+            u32::from(subwindowmode).serialize_into_with_order(bytes, order);
         }
         if let Some(polyedge) = self.polyedge {
-            u32::from(polyedge).serialize_into(bytes);
+            u32::from(polyedge).serialize_into_with_order(bytes, order);
         }
         if let Some(polymode) = self.polymode {
-            u32::from(polymode).serialize_into(bytes);
+            u32::from(polymode).serialize_into_with_order(bytes, order);
         }
         if let Some(dither) = self.dither {
-            dither.serialize_into(bytes);
+            dither.serialize_into_with_order(bytes, order);
         }
         if let Some(componentalpha) = self.componentalpha {
-            componentalpha.serialize_into(bytes);
+            componentalpha.serialize_into_with_order(bytes, order);
         }
     }
 }
@@ -2143,18 +2153,71 @@ impl CreatePictureAux {
     }
 }
 
-pub fn create_picture<'c, Conn>(conn: &'c Conn, pid: Picture, drawable: xproto::Drawable, format: Pictformat, value_list: &CreatePictureAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreatePictureRequest {
+    pub pid: Picture,
+    pub drawable: xproto::Drawable,
+    pub format: Pictformat,
+    pub value_list: CreatePictureAux,
+}
+impl CreatePictureRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_PICTURE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (pid, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (value_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_list, remaining) = CreatePictureAux::try_parse_with_order(remaining, order, CP::from(value_mask))?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreatePictureRequest {
+            pid,
+            drawable,
+            format,
+            value_list,
+        })
+    }
+    /// Set the `pid` field of this structure.
+    pub fn pid<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.pid = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.format = value.into();
+        self
+    }
+    /// Set the `value_list` field of this structure.
+    pub fn value_list<I>(mut self, value: I) -> Self where I: Into<CreatePictureAux> {
+        self.value_list = value.into();
+        self
+    }
+}
+
+pub fn create_picture<'c, Conn, A>(conn: &'c Conn, pid: Picture, drawable: A, format: Pictformat, value_list: &CreatePictureAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let pid_bytes = pid.serialize();
-    let drawable_bytes = drawable.serialize();
-    let format_bytes = format.serialize();
+    let pid_bytes = pid.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
     let value_mask = u32::try_from(value_list.switch_expr()).unwrap();
-    let value_mask_bytes = value_mask.serialize();
+    let value_mask_bytes = value_mask.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_PICTURE_REQUEST,
@@ -2178,15 +2241,27 @@ where
         value_mask_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let value_list_bytes = value_list.serialize(value_mask);
+    let value_list_bytes = value_list.serialize_with_order(order, CP::from(value_mask));
     let length_so_far = length_so_far + value_list_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&value_list_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreatePictureRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreatePictureRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_picture(conn, self.pid, self.drawable, self.format, &self.value_list)
+    }
+}
 
 /// Opcode for the ChangePicture request
 pub const CHANGE_PICTURE_REQUEST: u8 = 5;
@@ -2207,52 +2282,168 @@ pub struct ChangePictureAux {
     pub dither: Option<xproto::Atom>,
     pub componentalpha: Option<u32>,
 }
+impl ChangePictureAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, value_mask: CP) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(value_mask);
+        let mut outer_remaining = value;
+        let repeat = if switch_expr & u32::from(CP::Repeat) != 0 {
+            let remaining = outer_remaining;
+            let (repeat, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let repeat = Repeat::from(repeat);
+            outer_remaining = remaining;
+            Some(repeat)
+        } else {
+            None
+        };
+        let alphamap = if switch_expr & u32::from(CP::AlphaMap) != 0 {
+            let remaining = outer_remaining;
+            let (alphamap, remaining) = Picture::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(alphamap)
+        } else {
+            None
+        };
+        let alphaxorigin = if switch_expr & u32::from(CP::AlphaXOrigin) != 0 {
+            let remaining = outer_remaining;
+            let (alphaxorigin, remaining) = i32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(alphaxorigin)
+        } else {
+            None
+        };
+        let alphayorigin = if switch_expr & u32::from(CP::AlphaYOrigin) != 0 {
+            let remaining = outer_remaining;
+            let (alphayorigin, remaining) = i32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(alphayorigin)
+        } else {
+            None
+        };
+        let clipxorigin = if switch_expr & u32::from(CP::ClipXOrigin) != 0 {
+            let remaining = outer_remaining;
+            let (clipxorigin, remaining) = i32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(clipxorigin)
+        } else {
+            None
+        };
+        let clipyorigin = if switch_expr & u32::from(CP::ClipYOrigin) != 0 {
+            let remaining = outer_remaining;
+            let (clipyorigin, remaining) = i32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(clipyorigin)
+        } else {
+            None
+        };
+        let clipmask = if switch_expr & u32::from(CP::ClipMask) != 0 {
+            let remaining = outer_remaining;
+            let (clipmask, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(clipmask)
+        } else {
+            None
+        };
+        let graphicsexposure = if switch_expr & u32::from(CP::GraphicsExposure) != 0 {
+            let remaining = outer_remaining;
+            let (graphicsexposure, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(graphicsexposure)
+        } else {
+            None
+        };
+        let subwindowmode = if switch_expr & u32::from(CP::SubwindowMode) != 0 {
+            let remaining = outer_remaining;
+            let (subwindowmode, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let subwindowmode = xproto::SubwindowMode::from(subwindowmode);
+            outer_remaining = remaining;
+            Some(subwindowmode)
+        } else {
+            None
+        };
+        let polyedge = if switch_expr & u32::from(CP::PolyEdge) != 0 {
+            let remaining = outer_remaining;
+            let (polyedge, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let polyedge = PolyEdge::from(polyedge);
+            outer_remaining = remaining;
+            Some(polyedge)
+        } else {
+            None
+        };
+        let polymode = if switch_expr & u32::from(CP::PolyMode) != 0 {
+            let remaining = outer_remaining;
+            let (polymode, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let polymode = PolyMode::from(polymode);
+            outer_remaining = remaining;
+            Some(polymode)
+        } else {
+            None
+        };
+        let dither = if switch_expr & u32::from(CP::Dither) != 0 {
+            let remaining = outer_remaining;
+            let (dither, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(dither)
+        } else {
+            None
+        };
+        let componentalpha = if switch_expr & u32::from(CP::ComponentAlpha) != 0 {
+            let remaining = outer_remaining;
+            let (componentalpha, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(componentalpha)
+        } else {
+            None
+        };
+        let result = ChangePictureAux { repeat, alphamap, alphaxorigin, alphayorigin, clipxorigin, clipyorigin, clipmask, graphicsexposure, subwindowmode, polyedge, polymode, dither, componentalpha };
+        Ok((result, outer_remaining))
+    }
+}
 #[allow(dead_code, unused_variables)]
 impl ChangePictureAux {
-    fn serialize(&self, value_mask: u32) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, value_mask: CP) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, value_mask);
+        self.serialize_into_with_order(&mut result, order, value_mask);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, value_mask: u32) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, value_mask: CP) {
         if let Some(repeat) = self.repeat {
-            u32::from(repeat).serialize_into(bytes);
+            u32::from(repeat).serialize_into_with_order(bytes, order);
         }
         if let Some(alphamap) = self.alphamap {
-            alphamap.serialize_into(bytes);
+            alphamap.serialize_into_with_order(bytes, order);
         }
         if let Some(alphaxorigin) = self.alphaxorigin {
-            alphaxorigin.serialize_into(bytes);
+            alphaxorigin.serialize_into_with_order(bytes, order);
         }
         if let Some(alphayorigin) = self.alphayorigin {
-            alphayorigin.serialize_into(bytes);
+            alphayorigin.serialize_into_with_order(bytes, order);
         }
         if let Some(clipxorigin) = self.clipxorigin {
-            clipxorigin.serialize_into(bytes);
+            clipxorigin.serialize_into_with_order(bytes, order);
         }
         if let Some(clipyorigin) = self.clipyorigin {
-            clipyorigin.serialize_into(bytes);
+            clipyorigin.serialize_into_with_order(bytes, order);
         }
         if let Some(clipmask) = self.clipmask {
-            clipmask.serialize_into(bytes);
+            clipmask.serialize_into_with_order(bytes, order);
         }
         if let Some(graphicsexposure) = self.graphicsexposure {
-            graphicsexposure.serialize_into(bytes);
+            graphicsexposure.serialize_into_with_order(bytes, order);
         }
         if let Some(subwindowmode) = self.subwindowmode {
-            u32::from(subwindowmode).serialize_into(bytes);
+            u32::from(subwindowmode).serialize_into_with_order(bytes, order);
         }
         if let Some(polyedge) = self.polyedge {
-            u32::from(polyedge).serialize_into(bytes);
+            u32::from(polyedge).serialize_into_with_order(bytes, order);
         }
         if let Some(polymode) = self.polymode {
-            u32::from(polymode).serialize_into(bytes);
+            u32::from(polymode).serialize_into_with_order(bytes, order);
         }
         if let Some(dither) = self.dither {
-            dither.serialize_into(bytes);
+            dither.serialize_into_with_order(bytes, order);
         }
         if let Some(componentalpha) = self.componentalpha {
-            componentalpha.serialize_into(bytes);
+            componentalpha.serialize_into_with_order(bytes, order);
         }
     }
 }
@@ -2373,16 +2564,51 @@ impl ChangePictureAux {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChangePictureRequest {
+    pub picture: Picture,
+    pub value_list: ChangePictureAux,
+}
+impl ChangePictureRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_PICTURE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (value_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_list, remaining) = ChangePictureAux::try_parse_with_order(remaining, order, CP::from(value_mask))?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangePictureRequest {
+            picture,
+            value_list,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `value_list` field of this structure.
+    pub fn value_list<I>(mut self, value: I) -> Self where I: Into<ChangePictureAux> {
+        self.value_list = value.into();
+        self
+    }
+}
+
 pub fn change_picture<'c, Conn>(conn: &'c Conn, picture: Picture, value_list: &ChangePictureAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
     let value_mask = u32::try_from(value_list.switch_expr()).unwrap();
-    let value_mask_bytes = value_mask.serialize();
+    let value_mask_bytes = value_mask.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_PICTURE_REQUEST,
@@ -2398,28 +2624,97 @@ where
         value_mask_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let value_list_bytes = value_list.serialize(value_mask);
+    let value_list_bytes = value_list.serialize_with_order(order, CP::from(value_mask));
     let length_so_far = length_so_far + value_list_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&value_list_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangePictureRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangePictureRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_picture(conn, self.picture, &self.value_list)
+    }
+}
 
 /// Opcode for the SetPictureClipRectangles request
 pub const SET_PICTURE_CLIP_RECTANGLES_REQUEST: u8 = 6;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetPictureClipRectanglesRequest {
+    pub picture: Picture,
+    pub clip_x_origin: i16,
+    pub clip_y_origin: i16,
+    pub rectangles: Vec<xproto::Rectangle>,
+}
+impl SetPictureClipRectanglesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PICTURE_CLIP_RECTANGLES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (clip_x_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (clip_y_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut rectangles = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            rectangles.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(SetPictureClipRectanglesRequest {
+            picture,
+            clip_x_origin,
+            clip_y_origin,
+            rectangles,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `clip_x_origin` field of this structure.
+    pub fn clip_x_origin(mut self, value: i16) -> Self {
+        self.clip_x_origin = value;
+        self
+    }
+    /// Set the `clip_y_origin` field of this structure.
+    pub fn clip_y_origin(mut self, value: i16) -> Self {
+        self.clip_y_origin = value;
+        self
+    }
+    /// Set the `rectangles` field of this structure.
+    pub fn rectangles<I>(mut self, value: I) -> Self where I: Into<Vec<xproto::Rectangle>> {
+        self.rectangles = value.into();
+        self
+    }
+}
+
 pub fn set_picture_clip_rectangles<'c, Conn>(conn: &'c Conn, picture: Picture, clip_x_origin: i16, clip_y_origin: i16, rectangles: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
-    let clip_x_origin_bytes = clip_x_origin.serialize();
-    let clip_y_origin_bytes = clip_y_origin.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
+    let clip_x_origin_bytes = clip_x_origin.serialize_with_order(order);
+    let clip_y_origin_bytes = clip_y_origin.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_PICTURE_CLIP_RECTANGLES_REQUEST,
@@ -2435,26 +2730,64 @@ where
         clip_y_origin_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let rectangles_bytes = rectangles.serialize();
+    let rectangles_bytes = rectangles.serialize_with_order(order);
     let length_so_far = length_so_far + rectangles_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&rectangles_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetPictureClipRectanglesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetPictureClipRectanglesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_picture_clip_rectangles(conn, self.picture, self.clip_x_origin, self.clip_y_origin, &self.rectangles)
+    }
+}
 
 /// Opcode for the FreePicture request
 pub const FREE_PICTURE_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FreePictureRequest {
+    pub picture: Picture,
+}
+impl FreePictureRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FREE_PICTURE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FreePictureRequest {
+            picture,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+}
+
 pub fn free_picture<Conn>(conn: &Conn, picture: Picture) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FREE_PICTURE_REQUEST,
@@ -2468,33 +2801,163 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FreePictureRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FreePictureRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        free_picture(conn, self.picture)
+    }
+}
 
 /// Opcode for the Composite request
 pub const COMPOSITE_REQUEST: u8 = 8;
-pub fn composite<Conn, A>(conn: &Conn, op: PictOp, src: Picture, mask: A, dst: Picture, src_x: i16, src_y: i16, mask_x: i16, mask_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CompositeRequest {
+    pub op: PictOp,
+    pub src: Picture,
+    pub mask: Picture,
+    pub dst: Picture,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub mask_x: i16,
+    pub mask_y: i16,
+    pub dst_x: i16,
+    pub dst_y: i16,
+    pub width: u16,
+    pub height: u16,
+}
+impl CompositeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COMPOSITE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (op, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (src, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (dst, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (mask_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (mask_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (dst_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (dst_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let op = PictOp::from(op);
+        Ok(CompositeRequest {
+            op,
+            src,
+            mask,
+            dst,
+            src_x,
+            src_y,
+            mask_x,
+            mask_y,
+            dst_x,
+            dst_y,
+            width,
+            height,
+        })
+    }
+    /// Set the `op` field of this structure.
+    pub fn op<I>(mut self, value: I) -> Self where I: Into<PictOp> {
+        self.op = value.into();
+        self
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `mask` field of this structure.
+    pub fn mask<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.mask = value.into();
+        self
+    }
+    /// Set the `dst` field of this structure.
+    pub fn dst<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.dst = value.into();
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `mask_x` field of this structure.
+    pub fn mask_x(mut self, value: i16) -> Self {
+        self.mask_x = value;
+        self
+    }
+    /// Set the `mask_y` field of this structure.
+    pub fn mask_y(mut self, value: i16) -> Self {
+        self.mask_y = value;
+        self
+    }
+    /// Set the `dst_x` field of this structure.
+    pub fn dst_x(mut self, value: i16) -> Self {
+        self.dst_x = value;
+        self
+    }
+    /// Set the `dst_y` field of this structure.
+    pub fn dst_y(mut self, value: i16) -> Self {
+        self.dst_y = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+}
+
+pub fn composite<Conn, A, B>(conn: &Conn, op: A, src: Picture, mask: B, dst: Picture, src_x: i16, src_y: i16, mask_x: i16, mask_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<Picture>,
+    A: Into<PictOp>,
+    B: Into<Picture>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let op: PictOp = op.into();
     let mask: Picture = mask.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let op_bytes = u8::from(op).serialize();
-    let src_bytes = src.serialize();
-    let mask_bytes = mask.serialize();
-    let dst_bytes = dst.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
-    let mask_x_bytes = mask_x.serialize();
-    let mask_y_bytes = mask_y.serialize();
-    let dst_x_bytes = dst_x.serialize();
-    let dst_y_bytes = dst_y.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
+    let op_bytes = u8::from(op).serialize_with_order(order);
+    let src_bytes = src.serialize_with_order(order);
+    let mask_bytes = mask.serialize_with_order(order);
+    let dst_bytes = dst.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
+    let mask_x_bytes = mask_x.serialize_with_order(order);
+    let mask_y_bytes = mask_y.serialize_with_order(order);
+    let dst_x_bytes = dst_x.serialize_with_order(order);
+    let dst_y_bytes = dst_y.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         COMPOSITE_REQUEST,
@@ -2536,25 +2999,122 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CompositeRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CompositeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        composite(conn, self.op, self.src, self.mask, self.dst, self.src_x, self.src_y, self.mask_x, self.mask_y, self.dst_x, self.dst_y, self.width, self.height)
+    }
+}
 
 /// Opcode for the Trapezoids request
 pub const TRAPEZOIDS_REQUEST: u8 = 10;
-pub fn trapezoids<'c, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, traps: &[Trapezoid]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TrapezoidsRequest {
+    pub op: PictOp,
+    pub src: Picture,
+    pub dst: Picture,
+    pub mask_format: Pictformat,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub traps: Vec<Trapezoid>,
+}
+impl TrapezoidsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != TRAPEZOIDS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (op, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (src, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (dst, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (mask_format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut traps = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Trapezoid::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            traps.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        let op = PictOp::from(op);
+        Ok(TrapezoidsRequest {
+            op,
+            src,
+            dst,
+            mask_format,
+            src_x,
+            src_y,
+            traps,
+        })
+    }
+    /// Set the `op` field of this structure.
+    pub fn op<I>(mut self, value: I) -> Self where I: Into<PictOp> {
+        self.op = value.into();
+        self
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `dst` field of this structure.
+    pub fn dst<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.dst = value.into();
+        self
+    }
+    /// Set the `mask_format` field of this structure.
+    pub fn mask_format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.mask_format = value.into();
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `traps` field of this structure.
+    pub fn traps<I>(mut self, value: I) -> Self where I: Into<Vec<Trapezoid>> {
+        self.traps = value.into();
+        self
+    }
+}
+
+pub fn trapezoids<'c, Conn, A>(conn: &'c Conn, op: A, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, traps: &[Trapezoid]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<PictOp>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let op: PictOp = op.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let op_bytes = u8::from(op).serialize();
-    let src_bytes = src.serialize();
-    let dst_bytes = dst.serialize();
-    let mask_format_bytes = mask_format.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
+    let op_bytes = u8::from(op).serialize_with_order(order);
+    let src_bytes = src.serialize_with_order(order);
+    let dst_bytes = dst.serialize_with_order(order);
+    let mask_format_bytes = mask_format.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         TRAPEZOIDS_REQUEST,
@@ -2582,31 +3142,128 @@ where
         src_y_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let traps_bytes = traps.serialize();
+    let traps_bytes = traps.serialize_with_order(order);
     let length_so_far = length_so_far + traps_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&traps_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for TrapezoidsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for TrapezoidsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        trapezoids(conn, self.op, self.src, self.dst, self.mask_format, self.src_x, self.src_y, &self.traps)
+    }
+}
 
 /// Opcode for the Triangles request
 pub const TRIANGLES_REQUEST: u8 = 11;
-pub fn triangles<'c, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, triangles: &[Triangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TrianglesRequest {
+    pub op: PictOp,
+    pub src: Picture,
+    pub dst: Picture,
+    pub mask_format: Pictformat,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub triangles: Vec<Triangle>,
+}
+impl TrianglesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != TRIANGLES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (op, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (src, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (dst, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (mask_format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut triangles = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Triangle::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            triangles.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        let op = PictOp::from(op);
+        Ok(TrianglesRequest {
+            op,
+            src,
+            dst,
+            mask_format,
+            src_x,
+            src_y,
+            triangles,
+        })
+    }
+    /// Set the `op` field of this structure.
+    pub fn op<I>(mut self, value: I) -> Self where I: Into<PictOp> {
+        self.op = value.into();
+        self
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `dst` field of this structure.
+    pub fn dst<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.dst = value.into();
+        self
+    }
+    /// Set the `mask_format` field of this structure.
+    pub fn mask_format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.mask_format = value.into();
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `triangles` field of this structure.
+    pub fn triangles<I>(mut self, value: I) -> Self where I: Into<Vec<Triangle>> {
+        self.triangles = value.into();
+        self
+    }
+}
+
+pub fn triangles<'c, Conn, A>(conn: &'c Conn, op: A, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, triangles: &[Triangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<PictOp>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let op: PictOp = op.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let op_bytes = u8::from(op).serialize();
-    let src_bytes = src.serialize();
-    let dst_bytes = dst.serialize();
-    let mask_format_bytes = mask_format.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
+    let op_bytes = u8::from(op).serialize_with_order(order);
+    let src_bytes = src.serialize_with_order(order);
+    let dst_bytes = dst.serialize_with_order(order);
+    let mask_format_bytes = mask_format.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         TRIANGLES_REQUEST,
@@ -2634,31 +3291,128 @@ where
         src_y_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let triangles_bytes = triangles.serialize();
+    let triangles_bytes = triangles.serialize_with_order(order);
     let length_so_far = length_so_far + triangles_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&triangles_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for TrianglesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for TrianglesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        triangles(conn, self.op, self.src, self.dst, self.mask_format, self.src_x, self.src_y, &self.triangles)
+    }
+}
 
 /// Opcode for the TriStrip request
 pub const TRI_STRIP_REQUEST: u8 = 12;
-pub fn tri_strip<'c, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TriStripRequest {
+    pub op: PictOp,
+    pub src: Picture,
+    pub dst: Picture,
+    pub mask_format: Pictformat,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub points: Vec<Pointfix>,
+}
+impl TriStripRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != TRI_STRIP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (op, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (src, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (dst, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (mask_format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut points = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            points.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        let op = PictOp::from(op);
+        Ok(TriStripRequest {
+            op,
+            src,
+            dst,
+            mask_format,
+            src_x,
+            src_y,
+            points,
+        })
+    }
+    /// Set the `op` field of this structure.
+    pub fn op<I>(mut self, value: I) -> Self where I: Into<PictOp> {
+        self.op = value.into();
+        self
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `dst` field of this structure.
+    pub fn dst<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.dst = value.into();
+        self
+    }
+    /// Set the `mask_format` field of this structure.
+    pub fn mask_format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.mask_format = value.into();
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `points` field of this structure.
+    pub fn points<I>(mut self, value: I) -> Self where I: Into<Vec<Pointfix>> {
+        self.points = value.into();
+        self
+    }
+}
+
+pub fn tri_strip<'c, Conn, A>(conn: &'c Conn, op: A, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<PictOp>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let op: PictOp = op.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let op_bytes = u8::from(op).serialize();
-    let src_bytes = src.serialize();
-    let dst_bytes = dst.serialize();
-    let mask_format_bytes = mask_format.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
+    let op_bytes = u8::from(op).serialize_with_order(order);
+    let src_bytes = src.serialize_with_order(order);
+    let dst_bytes = dst.serialize_with_order(order);
+    let mask_format_bytes = mask_format.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         TRI_STRIP_REQUEST,
@@ -2686,31 +3440,128 @@ where
         src_y_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let points_bytes = points.serialize();
+    let points_bytes = points.serialize_with_order(order);
     let length_so_far = length_so_far + points_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&points_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for TriStripRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for TriStripRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        tri_strip(conn, self.op, self.src, self.dst, self.mask_format, self.src_x, self.src_y, &self.points)
+    }
+}
 
 /// Opcode for the TriFan request
 pub const TRI_FAN_REQUEST: u8 = 13;
-pub fn tri_fan<'c, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TriFanRequest {
+    pub op: PictOp,
+    pub src: Picture,
+    pub dst: Picture,
+    pub mask_format: Pictformat,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub points: Vec<Pointfix>,
+}
+impl TriFanRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != TRI_FAN_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (op, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (src, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (dst, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (mask_format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut points = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            points.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        let op = PictOp::from(op);
+        Ok(TriFanRequest {
+            op,
+            src,
+            dst,
+            mask_format,
+            src_x,
+            src_y,
+            points,
+        })
+    }
+    /// Set the `op` field of this structure.
+    pub fn op<I>(mut self, value: I) -> Self where I: Into<PictOp> {
+        self.op = value.into();
+        self
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `dst` field of this structure.
+    pub fn dst<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.dst = value.into();
+        self
+    }
+    /// Set the `mask_format` field of this structure.
+    pub fn mask_format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.mask_format = value.into();
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `points` field of this structure.
+    pub fn points<I>(mut self, value: I) -> Self where I: Into<Vec<Pointfix>> {
+        self.points = value.into();
+        self
+    }
+}
+
+pub fn tri_fan<'c, Conn, A>(conn: &'c Conn, op: A, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<PictOp>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let op: PictOp = op.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let op_bytes = u8::from(op).serialize();
-    let src_bytes = src.serialize();
-    let dst_bytes = dst.serialize();
-    let mask_format_bytes = mask_format.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
+    let op_bytes = u8::from(op).serialize_with_order(order);
+    let src_bytes = src.serialize_with_order(order);
+    let dst_bytes = dst.serialize_with_order(order);
+    let mask_format_bytes = mask_format.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         TRI_FAN_REQUEST,
@@ -2738,27 +3589,73 @@ where
         src_y_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let points_bytes = points.serialize();
+    let points_bytes = points.serialize_with_order(order);
     let length_so_far = length_so_far + points_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&points_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for TriFanRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for TriFanRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        tri_fan(conn, self.op, self.src, self.dst, self.mask_format, self.src_x, self.src_y, &self.points)
+    }
+}
 
 /// Opcode for the CreateGlyphSet request
 pub const CREATE_GLYPH_SET_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateGlyphSetRequest {
+    pub gsid: Glyphset,
+    pub format: Pictformat,
+}
+impl CreateGlyphSetRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_GLYPH_SET_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (gsid, remaining) = Glyphset::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateGlyphSetRequest {
+            gsid,
+            format,
+        })
+    }
+    /// Set the `gsid` field of this structure.
+    pub fn gsid<I>(mut self, value: I) -> Self where I: Into<Glyphset> {
+        self.gsid = value.into();
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.format = value.into();
+        self
+    }
+}
+
 pub fn create_glyph_set<Conn>(conn: &Conn, gsid: Glyphset, format: Pictformat) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let gsid_bytes = gsid.serialize();
-    let format_bytes = format.serialize();
+    let gsid_bytes = gsid.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_GLYPH_SET_REQUEST,
@@ -2776,21 +3673,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateGlyphSetRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateGlyphSetRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_glyph_set(conn, self.gsid, self.format)
+    }
+}
 
 /// Opcode for the ReferenceGlyphSet request
 pub const REFERENCE_GLYPH_SET_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReferenceGlyphSetRequest {
+    pub gsid: Glyphset,
+    pub existing: Glyphset,
+}
+impl ReferenceGlyphSetRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != REFERENCE_GLYPH_SET_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (gsid, remaining) = Glyphset::try_parse_with_order(remaining, order)?;
+        let (existing, remaining) = Glyphset::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ReferenceGlyphSetRequest {
+            gsid,
+            existing,
+        })
+    }
+    /// Set the `gsid` field of this structure.
+    pub fn gsid<I>(mut self, value: I) -> Self where I: Into<Glyphset> {
+        self.gsid = value.into();
+        self
+    }
+    /// Set the `existing` field of this structure.
+    pub fn existing<I>(mut self, value: I) -> Self where I: Into<Glyphset> {
+        self.existing = value.into();
+        self
+    }
+}
+
 pub fn reference_glyph_set<Conn>(conn: &Conn, gsid: Glyphset, existing: Glyphset) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let gsid_bytes = gsid.serialize();
-    let existing_bytes = existing.serialize();
+    let gsid_bytes = gsid.serialize_with_order(order);
+    let existing_bytes = existing.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         REFERENCE_GLYPH_SET_REQUEST,
@@ -2808,20 +3751,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ReferenceGlyphSetRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ReferenceGlyphSetRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        reference_glyph_set(conn, self.gsid, self.existing)
+    }
+}
 
 /// Opcode for the FreeGlyphSet request
 pub const FREE_GLYPH_SET_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FreeGlyphSetRequest {
+    pub glyphset: Glyphset,
+}
+impl FreeGlyphSetRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FREE_GLYPH_SET_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (glyphset, remaining) = Glyphset::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FreeGlyphSetRequest {
+            glyphset,
+        })
+    }
+    /// Set the `glyphset` field of this structure.
+    pub fn glyphset<I>(mut self, value: I) -> Self where I: Into<Glyphset> {
+        self.glyphset = value.into();
+        self
+    }
+}
+
 pub fn free_glyph_set<Conn>(conn: &Conn, glyphset: Glyphset) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let glyphset_bytes = glyphset.serialize();
+    let glyphset_bytes = glyphset.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FREE_GLYPH_SET_REQUEST,
@@ -2835,22 +3816,86 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FreeGlyphSetRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FreeGlyphSetRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        free_glyph_set(conn, self.glyphset)
+    }
+}
 
 /// Opcode for the AddGlyphs request
 pub const ADD_GLYPHS_REQUEST: u8 = 20;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AddGlyphsRequest {
+    pub glyphset: Glyphset,
+    pub glyphids: Vec<u32>,
+    pub glyphs: Vec<Glyphinfo>,
+    pub data: Vec<u8>,
+}
+impl AddGlyphsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ADD_GLYPHS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (glyphset, remaining) = Glyphset::try_parse_with_order(remaining, order)?;
+        let (glyphs_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (glyphids, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, glyphs_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (glyphs, remaining) = crate::x11_utils::parse_list_with_order::<Glyphinfo>(remaining, glyphs_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let data = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        Ok(AddGlyphsRequest {
+            glyphset,
+            glyphids,
+            glyphs,
+            data,
+        })
+    }
+    /// Set the `glyphset` field of this structure.
+    pub fn glyphset<I>(mut self, value: I) -> Self where I: Into<Glyphset> {
+        self.glyphset = value.into();
+        self
+    }
+    /// Set the `glyphids` field of this structure.
+    pub fn glyphids<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.glyphids = value.into();
+        self
+    }
+    /// Set the `glyphs` field of this structure.
+    pub fn glyphs<I>(mut self, value: I) -> Self where I: Into<Vec<Glyphinfo>> {
+        self.glyphs = value.into();
+        self
+    }
+    /// Set the `data` field of this structure.
+    pub fn data<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.data = value.into();
+        self
+    }
+}
+
 pub fn add_glyphs<'c, Conn>(conn: &'c Conn, glyphset: Glyphset, glyphids: &[u32], glyphs: &[Glyphinfo], data: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let glyphset_bytes = glyphset.serialize();
+    let glyphset_bytes = glyphset.serialize_with_order(order);
     let glyphs_len = u32::try_from(glyphids.len()).expect("`glyphids` has too many elements");
-    let glyphs_len_bytes = glyphs_len.serialize();
+    let glyphs_len_bytes = glyphs_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ADD_GLYPHS_REQUEST,
@@ -2866,30 +3911,83 @@ where
         glyphs_len_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let glyphids_bytes = glyphids.serialize();
+    let glyphids_bytes = glyphids.serialize_with_order(order);
     let length_so_far = length_so_far + glyphids_bytes.len();
     assert_eq!(glyphs.len(), usize::try_from(glyphs_len).unwrap(), "`glyphs` has an incorrect length");
-    let glyphs_bytes = glyphs.serialize();
+    let glyphs_bytes = glyphs.serialize_with_order(order);
     let length_so_far = length_so_far + glyphs_bytes.len();
     let length_so_far = length_so_far + data.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&glyphids_bytes), IoSlice::new(&glyphs_bytes), IoSlice::new(data), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for AddGlyphsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AddGlyphsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        add_glyphs(conn, self.glyphset, &self.glyphids, &self.glyphs, &self.data)
+    }
+}
 
 /// Opcode for the FreeGlyphs request
 pub const FREE_GLYPHS_REQUEST: u8 = 22;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FreeGlyphsRequest {
+    pub glyphset: Glyphset,
+    pub glyphs: Vec<Glyph>,
+}
+impl FreeGlyphsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FREE_GLYPHS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (glyphset, remaining) = Glyphset::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut glyphs = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Glyph::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            glyphs.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(FreeGlyphsRequest {
+            glyphset,
+            glyphs,
+        })
+    }
+    /// Set the `glyphset` field of this structure.
+    pub fn glyphset<I>(mut self, value: I) -> Self where I: Into<Glyphset> {
+        self.glyphset = value.into();
+        self
+    }
+    /// Set the `glyphs` field of this structure.
+    pub fn glyphs<I>(mut self, value: I) -> Self where I: Into<Vec<Glyph>> {
+        self.glyphs = value.into();
+        self
+    }
+}
+
 pub fn free_glyphs<'c, Conn>(conn: &'c Conn, glyphset: Glyphset, glyphs: &[Glyph]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let glyphset_bytes = glyphset.serialize();
+    let glyphset_bytes = glyphset.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FREE_GLYPHS_REQUEST,
@@ -2901,32 +3999,131 @@ where
         glyphset_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let glyphs_bytes = glyphs.serialize();
+    let glyphs_bytes = glyphs.serialize_with_order(order);
     let length_so_far = length_so_far + glyphs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&glyphs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for FreeGlyphsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FreeGlyphsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        free_glyphs(conn, self.glyphset, &self.glyphs)
+    }
+}
 
 /// Opcode for the CompositeGlyphs8 request
 pub const COMPOSITE_GLYPHS8_REQUEST: u8 = 23;
-pub fn composite_glyphs8<'c, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CompositeGlyphs8Request {
+    pub op: PictOp,
+    pub src: Picture,
+    pub dst: Picture,
+    pub mask_format: Pictformat,
+    pub glyphset: Glyphset,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub glyphcmds: Vec<u8>,
+}
+impl CompositeGlyphs8Request {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COMPOSITE_GLYPHS8_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (op, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (src, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (dst, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (mask_format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (glyphset, remaining) = Glyphset::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let glyphcmds = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        let op = PictOp::from(op);
+        Ok(CompositeGlyphs8Request {
+            op,
+            src,
+            dst,
+            mask_format,
+            glyphset,
+            src_x,
+            src_y,
+            glyphcmds,
+        })
+    }
+    /// Set the `op` field of this structure.
+    pub fn op<I>(mut self, value: I) -> Self where I: Into<PictOp> {
+        self.op = value.into();
+        self
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `dst` field of this structure.
+    pub fn dst<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.dst = value.into();
+        self
+    }
+    /// Set the `mask_format` field of this structure.
+    pub fn mask_format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.mask_format = value.into();
+        self
+    }
+    /// Set the `glyphset` field of this structure.
+    pub fn glyphset<I>(mut self, value: I) -> Self where I: Into<Glyphset> {
+        self.glyphset = value.into();
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `glyphcmds` field of this structure.
+    pub fn glyphcmds<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.glyphcmds = value.into();
+        self
+    }
+}
+
+pub fn composite_glyphs8<'c, Conn, A>(conn: &'c Conn, op: A, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<PictOp>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let op: PictOp = op.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let op_bytes = u8::from(op).serialize();
-    let src_bytes = src.serialize();
-    let dst_bytes = dst.serialize();
-    let mask_format_bytes = mask_format.serialize();
-    let glyphset_bytes = glyphset.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
+    let op_bytes = u8::from(op).serialize_with_order(order);
+    let src_bytes = src.serialize_with_order(order);
+    let dst_bytes = dst.serialize_with_order(order);
+    let mask_format_bytes = mask_format.serialize_with_order(order);
+    let glyphset_bytes = glyphset.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         COMPOSITE_GLYPHS8_REQUEST,
@@ -2963,26 +4160,125 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(glyphcmds), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CompositeGlyphs8Request {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CompositeGlyphs8Request
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        composite_glyphs8(conn, self.op, self.src, self.dst, self.mask_format, self.glyphset, self.src_x, self.src_y, &self.glyphcmds)
+    }
+}
+
+/// Opcode for the CompositeGlyphs16 request
+pub const COMPOSITE_GLYPHS16_REQUEST: u8 = 24;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CompositeGlyphs16Request {
+    pub op: PictOp,
+    pub src: Picture,
+    pub dst: Picture,
+    pub mask_format: Pictformat,
+    pub glyphset: Glyphset,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub glyphcmds: Vec<u8>,
+}
+impl CompositeGlyphs16Request {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COMPOSITE_GLYPHS16_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (op, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (src, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (dst, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (mask_format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (glyphset, remaining) = Glyphset::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let glyphcmds = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        let op = PictOp::from(op);
+        Ok(CompositeGlyphs16Request {
+            op,
+            src,
+            dst,
+            mask_format,
+            glyphset,
+            src_x,
+            src_y,
+            glyphcmds,
+        })
+    }
+    /// Set the `op` field of this structure.
+    pub fn op<I>(mut self, value: I) -> Self where I: Into<PictOp> {
+        self.op = value.into();
+        self
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `dst` field of this structure.
+    pub fn dst<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.dst = value.into();
+        self
+    }
+    /// Set the `mask_format` field of this structure.
+    pub fn mask_format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.mask_format = value.into();
+        self
+    }
+    /// Set the `glyphset` field of this structure.
+    pub fn glyphset<I>(mut self, value: I) -> Self where I: Into<Glyphset> {
+        self.glyphset = value.into();
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `glyphcmds` field of this structure.
+    pub fn glyphcmds<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.glyphcmds = value.into();
+        self
+    }
+}
 
-/// Opcode for the CompositeGlyphs16 request
-pub const COMPOSITE_GLYPHS16_REQUEST: u8 = 24;
-pub fn composite_glyphs16<'c, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+pub fn composite_glyphs16<'c, Conn, A>(conn: &'c Conn, op: A, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<PictOp>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let op: PictOp = op.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let op_bytes = u8::from(op).serialize();
-    let src_bytes = src.serialize();
-    let dst_bytes = dst.serialize();
-    let mask_format_bytes = mask_format.serialize();
-    let glyphset_bytes = glyphset.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
+    let op_bytes = u8::from(op).serialize_with_order(order);
+    let src_bytes = src.serialize_with_order(order);
+    let dst_bytes = dst.serialize_with_order(order);
+    let mask_format_bytes = mask_format.serialize_with_order(order);
+    let glyphset_bytes = glyphset.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         COMPOSITE_GLYPHS16_REQUEST,
@@ -3019,26 +4315,125 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(glyphcmds), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CompositeGlyphs16Request {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CompositeGlyphs16Request
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        composite_glyphs16(conn, self.op, self.src, self.dst, self.mask_format, self.glyphset, self.src_x, self.src_y, &self.glyphcmds)
+    }
+}
 
 /// Opcode for the CompositeGlyphs32 request
 pub const COMPOSITE_GLYPHS32_REQUEST: u8 = 25;
-pub fn composite_glyphs32<'c, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CompositeGlyphs32Request {
+    pub op: PictOp,
+    pub src: Picture,
+    pub dst: Picture,
+    pub mask_format: Pictformat,
+    pub glyphset: Glyphset,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub glyphcmds: Vec<u8>,
+}
+impl CompositeGlyphs32Request {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COMPOSITE_GLYPHS32_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (op, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (src, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (dst, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (mask_format, remaining) = Pictformat::try_parse_with_order(remaining, order)?;
+        let (glyphset, remaining) = Glyphset::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let glyphcmds = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        let op = PictOp::from(op);
+        Ok(CompositeGlyphs32Request {
+            op,
+            src,
+            dst,
+            mask_format,
+            glyphset,
+            src_x,
+            src_y,
+            glyphcmds,
+        })
+    }
+    /// Set the `op` field of this structure.
+    pub fn op<I>(mut self, value: I) -> Self where I: Into<PictOp> {
+        self.op = value.into();
+        self
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `dst` field of this structure.
+    pub fn dst<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.dst = value.into();
+        self
+    }
+    /// Set the `mask_format` field of this structure.
+    pub fn mask_format<I>(mut self, value: I) -> Self where I: Into<Pictformat> {
+        self.mask_format = value.into();
+        self
+    }
+    /// Set the `glyphset` field of this structure.
+    pub fn glyphset<I>(mut self, value: I) -> Self where I: Into<Glyphset> {
+        self.glyphset = value.into();
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `glyphcmds` field of this structure.
+    pub fn glyphcmds<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.glyphcmds = value.into();
+        self
+    }
+}
+
+pub fn composite_glyphs32<'c, Conn, A>(conn: &'c Conn, op: A, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<PictOp>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let op: PictOp = op.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let op_bytes = u8::from(op).serialize();
-    let src_bytes = src.serialize();
-    let dst_bytes = dst.serialize();
-    let mask_format_bytes = mask_format.serialize();
-    let glyphset_bytes = glyphset.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
+    let op_bytes = u8::from(op).serialize_with_order(order);
+    let src_bytes = src.serialize_with_order(order);
+    let dst_bytes = dst.serialize_with_order(order);
+    let mask_format_bytes = mask_format.serialize_with_order(order);
+    let glyphset_bytes = glyphset.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         COMPOSITE_GLYPHS32_REQUEST,
@@ -3075,22 +4470,95 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(glyphcmds), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CompositeGlyphs32Request {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CompositeGlyphs32Request
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        composite_glyphs32(conn, self.op, self.src, self.dst, self.mask_format, self.glyphset, self.src_x, self.src_y, &self.glyphcmds)
+    }
+}
 
 /// Opcode for the FillRectangles request
 pub const FILL_RECTANGLES_REQUEST: u8 = 26;
-pub fn fill_rectangles<'c, Conn>(conn: &'c Conn, op: PictOp, dst: Picture, color: Color, rects: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FillRectanglesRequest {
+    pub op: PictOp,
+    pub dst: Picture,
+    pub color: Color,
+    pub rects: Vec<xproto::Rectangle>,
+}
+impl FillRectanglesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FILL_RECTANGLES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (op, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (dst, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (color, remaining) = Color::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut rects = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            rects.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        let op = PictOp::from(op);
+        Ok(FillRectanglesRequest {
+            op,
+            dst,
+            color,
+            rects,
+        })
+    }
+    /// Set the `op` field of this structure.
+    pub fn op<I>(mut self, value: I) -> Self where I: Into<PictOp> {
+        self.op = value.into();
+        self
+    }
+    /// Set the `dst` field of this structure.
+    pub fn dst<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.dst = value.into();
+        self
+    }
+    /// Set the `color` field of this structure.
+    pub fn color<I>(mut self, value: I) -> Self where I: Into<Color> {
+        self.color = value.into();
+        self
+    }
+    /// Set the `rects` field of this structure.
+    pub fn rects<I>(mut self, value: I) -> Self where I: Into<Vec<xproto::Rectangle>> {
+        self.rects = value.into();
+        self
+    }
+}
+
+pub fn fill_rectangles<'c, Conn, A>(conn: &'c Conn, op: A, dst: Picture, color: Color, rects: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<PictOp>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let op: PictOp = op.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let op_bytes = u8::from(op).serialize();
-    let dst_bytes = dst.serialize();
-    let color_bytes = color.serialize();
+    let op_bytes = u8::from(op).serialize_with_order(order);
+    let dst_bytes = dst.serialize_with_order(order);
+    let color_bytes = color.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FILL_RECTANGLES_REQUEST,
@@ -3114,29 +4582,91 @@ where
         color_bytes[7],
     ];
     let length_so_far = length_so_far + request0.len();
-    let rects_bytes = rects.serialize();
+    let rects_bytes = rects.serialize_with_order(order);
     let length_so_far = length_so_far + rects_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&rects_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for FillRectanglesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FillRectanglesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        fill_rectangles(conn, self.op, self.dst, self.color, &self.rects)
+    }
+}
 
 /// Opcode for the CreateCursor request
 pub const CREATE_CURSOR_REQUEST: u8 = 27;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateCursorRequest {
+    pub cid: xproto::Cursor,
+    pub source: Picture,
+    pub x: u16,
+    pub y: u16,
+}
+impl CreateCursorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CURSOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (cid, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let (source, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateCursorRequest {
+            cid,
+            source,
+            x,
+            y,
+        })
+    }
+    /// Set the `cid` field of this structure.
+    pub fn cid<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.cid = value.into();
+        self
+    }
+    /// Set the `source` field of this structure.
+    pub fn source<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.source = value.into();
+        self
+    }
+    /// Set the `x` field of this structure.
+    pub fn x(mut self, value: u16) -> Self {
+        self.x = value;
+        self
+    }
+    /// Set the `y` field of this structure.
+    pub fn y(mut self, value: u16) -> Self {
+        self.y = value;
+        self
+    }
+}
+
 pub fn create_cursor<Conn>(conn: &Conn, cid: xproto::Cursor, source: Picture, x: u16, y: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let cid_bytes = cid.serialize();
-    let source_bytes = source.serialize();
-    let x_bytes = x.serialize();
-    let y_bytes = y.serialize();
+    let cid_bytes = cid.serialize_with_order(order);
+    let source_bytes = source.serialize_with_order(order);
+    let x_bytes = x.serialize_with_order(order);
+    let y_bytes = y.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_CURSOR_REQUEST,
@@ -3158,9 +4688,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateCursorRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateCursorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_cursor(conn, self.cid, self.source, self.x, self.y)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Transform {
@@ -3175,16 +4717,16 @@ pub struct Transform {
     pub matrix33: Fixed,
 }
 impl TryParse for Transform {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (matrix11, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix12, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix13, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix21, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix22, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix23, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix31, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix32, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix33, remaining) = Fixed::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (matrix11, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (matrix12, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (matrix13, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (matrix21, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (matrix22, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (matrix23, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (matrix31, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (matrix32, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (matrix33, remaining) = Fixed::try_parse_with_order(remaining, order)?;
         let result = Transform { matrix11, matrix12, matrix13, matrix21, matrix22, matrix23, matrix31, matrix32, matrix33 };
         Ok((result, remaining))
     }
@@ -3197,16 +4739,16 @@ impl TryFrom<&[u8]> for Transform {
 }
 impl Serialize for Transform {
     type Bytes = [u8; 36];
-    fn serialize(&self) -> [u8; 36] {
-        let matrix11_bytes = self.matrix11.serialize();
-        let matrix12_bytes = self.matrix12.serialize();
-        let matrix13_bytes = self.matrix13.serialize();
-        let matrix21_bytes = self.matrix21.serialize();
-        let matrix22_bytes = self.matrix22.serialize();
-        let matrix23_bytes = self.matrix23.serialize();
-        let matrix31_bytes = self.matrix31.serialize();
-        let matrix32_bytes = self.matrix32.serialize();
-        let matrix33_bytes = self.matrix33.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 36] {
+        let matrix11_bytes = self.matrix11.serialize_with_order(order);
+        let matrix12_bytes = self.matrix12.serialize_with_order(order);
+        let matrix13_bytes = self.matrix13.serialize_with_order(order);
+        let matrix21_bytes = self.matrix21.serialize_with_order(order);
+        let matrix22_bytes = self.matrix22.serialize_with_order(order);
+        let matrix23_bytes = self.matrix23.serialize_with_order(order);
+        let matrix31_bytes = self.matrix31.serialize_with_order(order);
+        let matrix32_bytes = self.matrix32.serialize_with_order(order);
+        let matrix33_bytes = self.matrix33.serialize_with_order(order);
         [
             matrix11_bytes[0],
             matrix11_bytes[1],
@@ -3246,31 +4788,65 @@ impl Serialize for Transform {
             matrix33_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(36);
-        self.matrix11.serialize_into(bytes);
-        self.matrix12.serialize_into(bytes);
-        self.matrix13.serialize_into(bytes);
-        self.matrix21.serialize_into(bytes);
-        self.matrix22.serialize_into(bytes);
-        self.matrix23.serialize_into(bytes);
-        self.matrix31.serialize_into(bytes);
-        self.matrix32.serialize_into(bytes);
-        self.matrix33.serialize_into(bytes);
+        self.matrix11.serialize_into_with_order(bytes, order);
+        self.matrix12.serialize_into_with_order(bytes, order);
+        self.matrix13.serialize_into_with_order(bytes, order);
+        self.matrix21.serialize_into_with_order(bytes, order);
+        self.matrix22.serialize_into_with_order(bytes, order);
+        self.matrix23.serialize_into_with_order(bytes, order);
+        self.matrix31.serialize_into_with_order(bytes, order);
+        self.matrix32.serialize_into_with_order(bytes, order);
+        self.matrix33.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the SetPictureTransform request
 pub const SET_PICTURE_TRANSFORM_REQUEST: u8 = 28;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetPictureTransformRequest {
+    pub picture: Picture,
+    pub transform: Transform,
+}
+impl SetPictureTransformRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PICTURE_TRANSFORM_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (transform, remaining) = Transform::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetPictureTransformRequest {
+            picture,
+            transform,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `transform` field of this structure.
+    pub fn transform<I>(mut self, value: I) -> Self where I: Into<Transform> {
+        self.transform = value.into();
+        self
+    }
+}
+
 pub fn set_picture_transform<Conn>(conn: &Conn, picture: Picture, transform: Transform) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
-    let transform_bytes = transform.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
+    let transform_bytes = transform.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_PICTURE_TRANSFORM_REQUEST,
@@ -3320,20 +4896,60 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetPictureTransformRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetPictureTransformRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_picture_transform(conn, self.picture, self.transform)
+    }
+}
 
 /// Opcode for the QueryFilters request
 pub const QUERY_FILTERS_REQUEST: u8 = 29;
-pub fn query_filters<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<Cookie<'_, Conn, QueryFiltersReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryFiltersRequest {
+    pub drawable: xproto::Drawable,
+}
+impl QueryFiltersRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_FILTERS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryFiltersRequest {
+            drawable,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+}
+
+pub fn query_filters<Conn, A>(conn: &Conn, drawable: A) -> Result<Cookie<'_, Conn, QueryFiltersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_FILTERS_REQUEST,
@@ -3347,9 +4963,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryFiltersRequest {
+    type Reply = QueryFiltersReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryFiltersRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryFiltersReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_filters(conn, self.drawable)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryFiltersReply {
@@ -3360,16 +4988,16 @@ pub struct QueryFiltersReply {
     pub filters: Vec<xproto::Str>,
 }
 impl TryParse for QueryFiltersReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_aliases, remaining) = u32::try_parse(remaining)?;
-        let (num_filters, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_aliases, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_filters, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (aliases, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_aliases.try_into().or(Err(ParseError::ParseError))?)?;
-        let (filters, remaining) = crate::x11_utils::parse_list::<xproto::Str>(remaining, num_filters.try_into().or(Err(ParseError::ParseError))?)?;
+        let (aliases, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, num_aliases.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (filters, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Str>(remaining, num_filters.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryFiltersReply { response_type, sequence, length, aliases, filters };
         Ok((result, remaining))
     }
@@ -3380,19 +5008,104 @@ impl TryFrom<&[u8]> for QueryFiltersReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryFiltersReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_aliases = u32::try_from(self.aliases.len()).expect("`aliases` has too many elements");
+        num_aliases.serialize_into_with_order(bytes, order);
+        let num_filters = u32::try_from(self.filters.len()).expect("`filters` has too many elements");
+        num_filters.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.aliases.serialize_into_with_order(bytes, order);
+        self.filters.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetPictureFilter request
 pub const SET_PICTURE_FILTER_REQUEST: u8 = 30;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetPictureFilterRequest {
+    pub picture: Picture,
+    pub filter: Vec<u8>,
+    pub values: Vec<Fixed>,
+}
+impl SetPictureFilterRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PICTURE_FILTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (filter_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (filter, remaining) = crate::x11_utils::parse_u8_list(remaining, filter_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let filter = filter.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut values = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Fixed::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            values.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(SetPictureFilterRequest {
+            picture,
+            filter,
+            values,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `filter` field of this structure.
+    pub fn filter<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.filter = value.into();
+        self
+    }
+    /// Set the `values` field of this structure.
+    pub fn values<I>(mut self, value: I) -> Self where I: Into<Vec<Fixed>> {
+        self.values = value.into();
+        self
+    }
+}
+
 pub fn set_picture_filter<'c, Conn>(conn: &'c Conn, picture: Picture, filter: &[u8], values: &[Fixed]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
     let filter_len = u16::try_from(filter.len()).expect("`filter` has too many elements");
-    let filter_len_bytes = filter_len.serialize();
+    let filter_len_bytes = filter_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_PICTURE_FILTER_REQUEST,
@@ -3411,15 +5124,27 @@ where
     let length_so_far = length_so_far + filter.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
-    let values_bytes = values.serialize();
+    let values_bytes = values.serialize_with_order(order);
     let length_so_far = length_so_far + values_bytes.len();
     let padding1 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding1.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(filter), IoSlice::new(&padding0), IoSlice::new(&values_bytes), IoSlice::new(&padding1)], vec![])?)
 }
+impl crate::x11_utils::Request for SetPictureFilterRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetPictureFilterRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_picture_filter(conn, self.picture, &self.filter, &self.values)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Animcursorelt {
@@ -3427,9 +5152,9 @@ pub struct Animcursorelt {
     pub delay: u32,
 }
 impl TryParse for Animcursorelt {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (cursor, remaining) = xproto::Cursor::try_parse(remaining)?;
-        let (delay, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (cursor, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let (delay, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = Animcursorelt { cursor, delay };
         Ok((result, remaining))
     }
@@ -3442,9 +5167,9 @@ impl TryFrom<&[u8]> for Animcursorelt {
 }
 impl Serialize for Animcursorelt {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let cursor_bytes = self.cursor.serialize();
-        let delay_bytes = self.delay.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let cursor_bytes = self.cursor.serialize_with_order(order);
+        let delay_bytes = self.delay.serialize_with_order(order);
         [
             cursor_bytes[0],
             cursor_bytes[1],
@@ -3456,23 +5181,64 @@ impl Serialize for Animcursorelt {
             delay_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.cursor.serialize_into(bytes);
-        self.delay.serialize_into(bytes);
+        self.cursor.serialize_into_with_order(bytes, order);
+        self.delay.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the CreateAnimCursor request
 pub const CREATE_ANIM_CURSOR_REQUEST: u8 = 31;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreateAnimCursorRequest {
+    pub cid: xproto::Cursor,
+    pub cursors: Vec<Animcursorelt>,
+}
+impl CreateAnimCursorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_ANIM_CURSOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (cid, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut cursors = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Animcursorelt::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            cursors.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateAnimCursorRequest {
+            cid,
+            cursors,
+        })
+    }
+    /// Set the `cid` field of this structure.
+    pub fn cid<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.cid = value.into();
+        self
+    }
+    /// Set the `cursors` field of this structure.
+    pub fn cursors<I>(mut self, value: I) -> Self where I: Into<Vec<Animcursorelt>> {
+        self.cursors = value.into();
+        self
+    }
+}
+
 pub fn create_anim_cursor<'c, Conn>(conn: &'c Conn, cid: xproto::Cursor, cursors: &[Animcursorelt]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let cid_bytes = cid.serialize();
+    let cid_bytes = cid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_ANIM_CURSOR_REQUEST,
@@ -3484,15 +5250,27 @@ where
         cid_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let cursors_bytes = cursors.serialize();
+    let cursors_bytes = cursors.serialize_with_order(order);
     let length_so_far = length_so_far + cursors_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&cursors_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateAnimCursorRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateAnimCursorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_anim_cursor(conn, self.cid, &self.cursors)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Spanfix {
@@ -3501,10 +5279,10 @@ pub struct Spanfix {
     pub y: Fixed,
 }
 impl TryParse for Spanfix {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (l, remaining) = Fixed::try_parse(remaining)?;
-        let (r, remaining) = Fixed::try_parse(remaining)?;
-        let (y, remaining) = Fixed::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (l, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (r, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = Fixed::try_parse_with_order(remaining, order)?;
         let result = Spanfix { l, r, y };
         Ok((result, remaining))
     }
@@ -3517,10 +5295,10 @@ impl TryFrom<&[u8]> for Spanfix {
 }
 impl Serialize for Spanfix {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let l_bytes = self.l.serialize();
-        let r_bytes = self.r.serialize();
-        let y_bytes = self.y.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let l_bytes = self.l.serialize_with_order(order);
+        let r_bytes = self.r.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
         [
             l_bytes[0],
             l_bytes[1],
@@ -3536,11 +5314,11 @@ impl Serialize for Spanfix {
             y_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.l.serialize_into(bytes);
-        self.r.serialize_into(bytes);
-        self.y.serialize_into(bytes);
+        self.l.serialize_into_with_order(bytes, order);
+        self.r.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3550,9 +5328,9 @@ pub struct Trap {
     pub bot: Spanfix,
 }
 impl TryParse for Trap {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (top, remaining) = Spanfix::try_parse(remaining)?;
-        let (bot, remaining) = Spanfix::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (top, remaining) = Spanfix::try_parse_with_order(remaining, order)?;
+        let (bot, remaining) = Spanfix::try_parse_with_order(remaining, order)?;
         let result = Trap { top, bot };
         Ok((result, remaining))
     }
@@ -3565,9 +5343,9 @@ impl TryFrom<&[u8]> for Trap {
 }
 impl Serialize for Trap {
     type Bytes = [u8; 24];
-    fn serialize(&self) -> [u8; 24] {
-        let top_bytes = self.top.serialize();
-        let bot_bytes = self.bot.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 24] {
+        let top_bytes = self.top.serialize_with_order(order);
+        let bot_bytes = self.bot.serialize_with_order(order);
         [
             top_bytes[0],
             top_bytes[1],
@@ -3595,25 +5373,82 @@ impl Serialize for Trap {
             bot_bytes[11],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(24);
-        self.top.serialize_into(bytes);
-        self.bot.serialize_into(bytes);
+        self.top.serialize_into_with_order(bytes, order);
+        self.bot.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the AddTraps request
 pub const ADD_TRAPS_REQUEST: u8 = 32;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AddTrapsRequest {
+    pub picture: Picture,
+    pub x_off: i16,
+    pub y_off: i16,
+    pub traps: Vec<Trap>,
+}
+impl AddTrapsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ADD_TRAPS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (x_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut traps = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Trap::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            traps.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(AddTrapsRequest {
+            picture,
+            x_off,
+            y_off,
+            traps,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `x_off` field of this structure.
+    pub fn x_off(mut self, value: i16) -> Self {
+        self.x_off = value;
+        self
+    }
+    /// Set the `y_off` field of this structure.
+    pub fn y_off(mut self, value: i16) -> Self {
+        self.y_off = value;
+        self
+    }
+    /// Set the `traps` field of this structure.
+    pub fn traps<I>(mut self, value: I) -> Self where I: Into<Vec<Trap>> {
+        self.traps = value.into();
+        self
+    }
+}
+
 pub fn add_traps<'c, Conn>(conn: &'c Conn, picture: Picture, x_off: i16, y_off: i16, traps: &[Trap]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
-    let x_off_bytes = x_off.serialize();
-    let y_off_bytes = y_off.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
+    let x_off_bytes = x_off.serialize_with_order(order);
+    let y_off_bytes = y_off.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ADD_TRAPS_REQUEST,
@@ -3629,27 +5464,73 @@ where
         y_off_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let traps_bytes = traps.serialize();
+    let traps_bytes = traps.serialize_with_order(order);
     let length_so_far = length_so_far + traps_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&traps_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for AddTrapsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AddTrapsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        add_traps(conn, self.picture, self.x_off, self.y_off, &self.traps)
+    }
+}
 
 /// Opcode for the CreateSolidFill request
 pub const CREATE_SOLID_FILL_REQUEST: u8 = 33;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateSolidFillRequest {
+    pub picture: Picture,
+    pub color: Color,
+}
+impl CreateSolidFillRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_SOLID_FILL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (color, remaining) = Color::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateSolidFillRequest {
+            picture,
+            color,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `color` field of this structure.
+    pub fn color<I>(mut self, value: I) -> Self where I: Into<Color> {
+        self.color = value.into();
+        self
+    }
+}
+
 pub fn create_solid_fill<Conn>(conn: &Conn, picture: Picture, color: Color) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
-    let color_bytes = color.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
+    let color_bytes = color.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_SOLID_FILL_REQUEST,
@@ -3671,24 +5552,95 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateSolidFillRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateSolidFillRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_solid_fill(conn, self.picture, self.color)
+    }
+}
 
 /// Opcode for the CreateLinearGradient request
 pub const CREATE_LINEAR_GRADIENT_REQUEST: u8 = 34;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateLinearGradientRequest {
+    pub picture: Picture,
+    pub p1: Pointfix,
+    pub p2: Pointfix,
+    pub stops: Vec<Fixed>,
+    pub colors: Vec<Color>,
+}
+impl CreateLinearGradientRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_LINEAR_GRADIENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (p1, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+        let (p2, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+        let (num_stops, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (stops, remaining) = crate::x11_utils::parse_list_with_order::<Fixed>(remaining, num_stops.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (colors, remaining) = crate::x11_utils::parse_list_with_order::<Color>(remaining, num_stops.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateLinearGradientRequest {
+            picture,
+            p1,
+            p2,
+            stops,
+            colors,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `p1` field of this structure.
+    pub fn p1<I>(mut self, value: I) -> Self where I: Into<Pointfix> {
+        self.p1 = value.into();
+        self
+    }
+    /// Set the `p2` field of this structure.
+    pub fn p2<I>(mut self, value: I) -> Self where I: Into<Pointfix> {
+        self.p2 = value.into();
+        self
+    }
+    /// Set the `stops` field of this structure.
+    pub fn stops<I>(mut self, value: I) -> Self where I: Into<Vec<Fixed>> {
+        self.stops = value.into();
+        self
+    }
+    /// Set the `colors` field of this structure.
+    pub fn colors<I>(mut self, value: I) -> Self where I: Into<Vec<Color>> {
+        self.colors = value.into();
+        self
+    }
+}
+
 pub fn create_linear_gradient<'c, Conn>(conn: &'c Conn, picture: Picture, p1: Pointfix, p2: Pointfix, stops: &[Fixed], colors: &[Color]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
-    let p1_bytes = p1.serialize();
-    let p2_bytes = p2.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
+    let p1_bytes = p1.serialize_with_order(order);
+    let p2_bytes = p2.serialize_with_order(order);
     let num_stops = u32::try_from(stops.len()).expect("`stops` has too many elements");
-    let num_stops_bytes = num_stops.serialize();
+    let num_stops_bytes = num_stops.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_LINEAR_GRADIENT_REQUEST,
@@ -3720,35 +5672,122 @@ where
         num_stops_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let stops_bytes = stops.serialize();
+    let stops_bytes = stops.serialize_with_order(order);
     let length_so_far = length_so_far + stops_bytes.len();
     assert_eq!(colors.len(), usize::try_from(num_stops).unwrap(), "`colors` has an incorrect length");
-    let colors_bytes = colors.serialize();
+    let colors_bytes = colors.serialize_with_order(order);
     let length_so_far = length_so_far + colors_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&stops_bytes), IoSlice::new(&colors_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateLinearGradientRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateLinearGradientRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_linear_gradient(conn, self.picture, self.p1, self.p2, &self.stops, &self.colors)
+    }
+}
 
 /// Opcode for the CreateRadialGradient request
 pub const CREATE_RADIAL_GRADIENT_REQUEST: u8 = 35;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateRadialGradientRequest {
+    pub picture: Picture,
+    pub inner: Pointfix,
+    pub outer: Pointfix,
+    pub inner_radius: Fixed,
+    pub outer_radius: Fixed,
+    pub stops: Vec<Fixed>,
+    pub colors: Vec<Color>,
+}
+impl CreateRadialGradientRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_RADIAL_GRADIENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (inner, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+        let (outer, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+        let (inner_radius, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (outer_radius, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (num_stops, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (stops, remaining) = crate::x11_utils::parse_list_with_order::<Fixed>(remaining, num_stops.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (colors, remaining) = crate::x11_utils::parse_list_with_order::<Color>(remaining, num_stops.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateRadialGradientRequest {
+            picture,
+            inner,
+            outer,
+            inner_radius,
+            outer_radius,
+            stops,
+            colors,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `inner` field of this structure.
+    pub fn inner<I>(mut self, value: I) -> Self where I: Into<Pointfix> {
+        self.inner = value.into();
+        self
+    }
+    /// Set the `outer` field of this structure.
+    pub fn outer<I>(mut self, value: I) -> Self where I: Into<Pointfix> {
+        self.outer = value.into();
+        self
+    }
+    /// Set the `inner_radius` field of this structure.
+    pub fn inner_radius<I>(mut self, value: I) -> Self where I: Into<Fixed> {
+        self.inner_radius = value.into();
+        self
+    }
+    /// Set the `outer_radius` field of this structure.
+    pub fn outer_radius<I>(mut self, value: I) -> Self where I: Into<Fixed> {
+        self.outer_radius = value.into();
+        self
+    }
+    /// Set the `stops` field of this structure.
+    pub fn stops<I>(mut self, value: I) -> Self where I: Into<Vec<Fixed>> {
+        self.stops = value.into();
+        self
+    }
+    /// Set the `colors` field of this structure.
+    pub fn colors<I>(mut self, value: I) -> Self where I: Into<Vec<Color>> {
+        self.colors = value.into();
+        self
+    }
+}
+
 pub fn create_radial_gradient<'c, Conn>(conn: &'c Conn, picture: Picture, inner: Pointfix, outer: Pointfix, inner_radius: Fixed, outer_radius: Fixed, stops: &[Fixed], colors: &[Color]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
-    let inner_bytes = inner.serialize();
-    let outer_bytes = outer.serialize();
-    let inner_radius_bytes = inner_radius.serialize();
-    let outer_radius_bytes = outer_radius.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
+    let inner_bytes = inner.serialize_with_order(order);
+    let outer_bytes = outer.serialize_with_order(order);
+    let inner_radius_bytes = inner_radius.serialize_with_order(order);
+    let outer_radius_bytes = outer_radius.serialize_with_order(order);
     let num_stops = u32::try_from(stops.len()).expect("`stops` has too many elements");
-    let num_stops_bytes = num_stops.serialize();
+    let num_stops_bytes = num_stops.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_RADIAL_GRADIENT_REQUEST,
@@ -3788,33 +5827,104 @@ where
         num_stops_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let stops_bytes = stops.serialize();
+    let stops_bytes = stops.serialize_with_order(order);
     let length_so_far = length_so_far + stops_bytes.len();
     assert_eq!(colors.len(), usize::try_from(num_stops).unwrap(), "`colors` has an incorrect length");
-    let colors_bytes = colors.serialize();
+    let colors_bytes = colors.serialize_with_order(order);
     let length_so_far = length_so_far + colors_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&stops_bytes), IoSlice::new(&colors_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateRadialGradientRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateRadialGradientRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_radial_gradient(conn, self.picture, self.inner, self.outer, self.inner_radius, self.outer_radius, &self.stops, &self.colors)
+    }
+}
 
 /// Opcode for the CreateConicalGradient request
 pub const CREATE_CONICAL_GRADIENT_REQUEST: u8 = 36;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateConicalGradientRequest {
+    pub picture: Picture,
+    pub center: Pointfix,
+    pub angle: Fixed,
+    pub stops: Vec<Fixed>,
+    pub colors: Vec<Color>,
+}
+impl CreateConicalGradientRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONICAL_GRADIENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = Picture::try_parse_with_order(remaining, order)?;
+        let (center, remaining) = Pointfix::try_parse_with_order(remaining, order)?;
+        let (angle, remaining) = Fixed::try_parse_with_order(remaining, order)?;
+        let (num_stops, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (stops, remaining) = crate::x11_utils::parse_list_with_order::<Fixed>(remaining, num_stops.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (colors, remaining) = crate::x11_utils::parse_list_with_order::<Color>(remaining, num_stops.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateConicalGradientRequest {
+            picture,
+            center,
+            angle,
+            stops,
+            colors,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `center` field of this structure.
+    pub fn center<I>(mut self, value: I) -> Self where I: Into<Pointfix> {
+        self.center = value.into();
+        self
+    }
+    /// Set the `angle` field of this structure.
+    pub fn angle<I>(mut self, value: I) -> Self where I: Into<Fixed> {
+        self.angle = value.into();
+        self
+    }
+    /// Set the `stops` field of this structure.
+    pub fn stops<I>(mut self, value: I) -> Self where I: Into<Vec<Fixed>> {
+        self.stops = value.into();
+        self
+    }
+    /// Set the `colors` field of this structure.
+    pub fn colors<I>(mut self, value: I) -> Self where I: Into<Vec<Color>> {
+        self.colors = value.into();
+        self
+    }
+}
+
 pub fn create_conical_gradient<'c, Conn>(conn: &'c Conn, picture: Picture, center: Pointfix, angle: Fixed, stops: &[Fixed], colors: &[Color]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
-    let center_bytes = center.serialize();
-    let angle_bytes = angle.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
+    let center_bytes = center.serialize_with_order(order);
+    let angle_bytes = angle.serialize_with_order(order);
     let num_stops = u32::try_from(stops.len()).expect("`stops` has too many elements");
-    let num_stops_bytes = num_stops.serialize();
+    let num_stops_bytes = num_stops.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_CONICAL_GRADIENT_REQUEST,
@@ -3842,18 +5952,30 @@ where
         num_stops_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let stops_bytes = stops.serialize();
+    let stops_bytes = stops.serialize_with_order(order);
     let length_so_far = length_so_far + stops_bytes.len();
     assert_eq!(colors.len(), usize::try_from(num_stops).unwrap(), "`colors` has an incorrect length");
-    let colors_bytes = colors.serialize();
+    let colors_bytes = colors.serialize_with_order(order);
     let length_so_far = length_so_far + colors_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&stops_bytes), IoSlice::new(&colors_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateConicalGradientRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateConicalGradientRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_conical_gradient(conn, self.picture, self.center, self.angle, &self.stops, &self.colors)
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -3869,7 +5991,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_pict_index_values(self, format)
     }
-    fn render_create_picture<'c>(&'c self, pid: Picture, drawable: xproto::Drawable, format: Pictformat, value_list: &CreatePictureAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn render_create_picture<'c, A>(&'c self, pid: Picture, drawable: A, format: Pictformat, value_list: &CreatePictureAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         create_picture(self, pid, drawable, format, value_list)
     }
@@ -3885,25 +6009,34 @@ pub trait ConnectionExt: RequestConnection {
     {
         free_picture(self, picture)
     }
-    fn render_composite<A>(&self, op: PictOp, src: Picture, mask: A, dst: Picture, src_x: i16, src_y: i16, mask_x: i16, mask_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn render_composite<A, B>(&self, op: A, src: Picture, mask: B, dst: Picture, src_x: i16, src_y: i16, mask_x: i16, mask_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<Picture>,
+        A: Into<PictOp>,
+        B: Into<Picture>,
     {
         composite(self, op, src, mask, dst, src_x, src_y, mask_x, mask_y, dst_x, dst_y, width, height)
     }
-    fn render_trapezoids<'c>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, traps: &[Trapezoid]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn render_trapezoids<'c, A>(&'c self, op: A, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, traps: &[Trapezoid]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<PictOp>,
     {
         trapezoids(self, op, src, dst, mask_format, src_x, src_y, traps)
     }
-    fn render_triangles<'c>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, triangles: &[Triangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn render_triangles<'c, A>(&'c self, op: A, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, triangles: &[Triangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<PictOp>,
     {
         self::triangles(self, op, src, dst, mask_format, src_x, src_y, triangles)
     }
-    fn render_tri_strip<'c>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn render_tri_strip<'c, A>(&'c self, op: A, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<PictOp>,
     {
         tri_strip(self, op, src, dst, mask_format, src_x, src_y, points)
     }
-    fn render_tri_fan<'c>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn render_tri_fan<'c, A>(&'c self, op: A, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<PictOp>,
     {
         tri_fan(self, op, src, dst, mask_format, src_x, src_y, points)
     }
@@ -3927,19 +6060,27 @@ pub trait ConnectionExt: RequestConnection {
     {
         free_glyphs(self, glyphset, glyphs)
     }
-    fn render_composite_glyphs8<'c>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn render_composite_glyphs8<'c, A>(&'c self, op: A, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<PictOp>,
     {
         composite_glyphs8(self, op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds)
     }
-    fn render_composite_glyphs16<'c>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn render_composite_glyphs16<'c, A>(&'c self, op: A, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<PictOp>,
     {
         composite_glyphs16(self, op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds)
     }
-    fn render_composite_glyphs32<'c>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn render_composite_glyphs32<'c, A>(&'c self, op: A, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<PictOp>,
     {
         composite_glyphs32(self, op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds)
     }
-    fn render_fill_rectangles<'c>(&'c self, op: PictOp, dst: Picture, color: Color, rects: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn render_fill_rectangles<'c, A>(&'c self, op: A, dst: Picture, color: Color, rects: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<PictOp>,
     {
         fill_rectangles(self, op, dst, color, rects)
     }
@@ -3951,7 +6092,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_picture_transform(self, picture, transform)
     }
-    fn render_query_filters(&self, drawable: xproto::Drawable) -> Result<Cookie<'_, Self, QueryFiltersReply>, ConnectionError>
+    fn render_query_filters<A>(&self, drawable: A) -> Result<Cookie<'_, Self, QueryFiltersReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         query_filters(self, drawable)
     }
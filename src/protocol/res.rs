@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -37,9 +37,9 @@ pub struct Client {
     pub resource_mask: u32,
 }
 impl TryParse for Client {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resource_base, remaining) = u32::try_parse(remaining)?;
-        let (resource_mask, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (resource_base, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (resource_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = Client { resource_base, resource_mask };
         Ok((result, remaining))
     }
@@ -52,9 +52,9 @@ impl TryFrom<&[u8]> for Client {
 }
 impl Serialize for Client {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let resource_base_bytes = self.resource_base.serialize();
-        let resource_mask_bytes = self.resource_mask.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let resource_base_bytes = self.resource_base.serialize_with_order(order);
+        let resource_mask_bytes = self.resource_mask.serialize_with_order(order);
         [
             resource_base_bytes[0],
             resource_base_bytes[1],
@@ -66,10 +66,10 @@ impl Serialize for Client {
             resource_mask_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.resource_base.serialize_into(bytes);
-        self.resource_mask.serialize_into(bytes);
+        self.resource_base.serialize_into_with_order(bytes, order);
+        self.resource_mask.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -79,9 +79,9 @@ pub struct Type {
     pub count: u32,
 }
 impl TryParse for Type {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resource_type, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (count, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (resource_type, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = Type { resource_type, count };
         Ok((result, remaining))
     }
@@ -94,9 +94,9 @@ impl TryFrom<&[u8]> for Type {
 }
 impl Serialize for Type {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let resource_type_bytes = self.resource_type.serialize();
-        let count_bytes = self.count.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let resource_type_bytes = self.resource_type.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
         [
             resource_type_bytes[0],
             resource_type_bytes[1],
@@ -108,85 +108,37 @@ impl Serialize for Type {
             count_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.resource_type.serialize_into(bytes);
-        self.count.serialize_into(bytes);
+        self.resource_type.serialize_into_with_order(bytes, order);
+        self.count.serialize_into_with_order(bytes, order);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ClientIdMask {
-    ClientXID = 1 << 0,
-    LocalClientPID = 1 << 1,
-}
-impl From<ClientIdMask> for u8 {
-    fn from(input: ClientIdMask) -> Self {
-        match input {
-            ClientIdMask::ClientXID => 1 << 0,
-            ClientIdMask::LocalClientPID => 1 << 1,
-        }
-    }
-}
-impl From<ClientIdMask> for Option<u8> {
-    fn from(input: ClientIdMask) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<ClientIdMask> for u16 {
-    fn from(input: ClientIdMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<ClientIdMask> for Option<u16> {
-    fn from(input: ClientIdMask) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<ClientIdMask> for u32 {
-    fn from(input: ClientIdMask) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ClientIdMask(u32);
+#[allow(non_upper_case_globals)]
+impl ClientIdMask {
+    pub const ClientXID: Self = Self(1 << 0);
+    pub const LocalClientPID: Self = Self(1 << 1);
 }
+bitmask_set!(ClientIdMask, u32, [ClientXID, LocalClientPID]);
 impl From<ClientIdMask> for Option<u32> {
     fn from(input: ClientIdMask) -> Self {
-        Some(u32::from(input))
+        Some(input.0)
     }
 }
-impl TryFrom<u8> for ClientIdMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ClientIdMask::ClientXID),
-            2 => Ok(ClientIdMask::LocalClientPID),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ClientIdMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ClientIdMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(ClientIdMask, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ClientIdSpec {
     pub client: u32,
-    pub mask: u32,
+    pub mask: ClientIdMask,
 }
 impl TryParse for ClientIdSpec {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (client, remaining) = u32::try_parse(remaining)?;
-        let (mask, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (client, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let mask = ClientIdMask::from(mask);
         let result = ClientIdSpec { client, mask };
         Ok((result, remaining))
     }
@@ -199,9 +151,9 @@ impl TryFrom<&[u8]> for ClientIdSpec {
 }
 impl Serialize for ClientIdSpec {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let client_bytes = self.client.serialize();
-        let mask_bytes = self.mask.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let client_bytes = self.client.serialize_with_order(order);
+        let mask_bytes = u32::from(self.mask).serialize_with_order(order);
         [
             client_bytes[0],
             client_bytes[1],
@@ -213,10 +165,10 @@ impl Serialize for ClientIdSpec {
             mask_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.client.serialize_into(bytes);
-        self.mask.serialize_into(bytes);
+        self.client.serialize_into_with_order(bytes, order);
+        u32::from(self.mask).serialize_into_with_order(bytes, order);
     }
 }
 
@@ -226,10 +178,10 @@ pub struct ClientIdValue {
     pub value: Vec<u32>,
 }
 impl TryParse for ClientIdValue {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (spec, remaining) = ClientIdSpec::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (value, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.checked_div(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (spec, remaining) = ClientIdSpec::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.checked_div(4u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ClientIdValue { spec, value };
         Ok((result, remaining))
     }
@@ -242,17 +194,17 @@ impl TryFrom<&[u8]> for ClientIdValue {
 }
 impl Serialize for ClientIdValue {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.spec.serialize_into(bytes);
+        self.spec.serialize_into_with_order(bytes, order);
         let length = u32::try_from(self.value.len()).ok().and_then(|len| len.checked_mul(4)).expect("`value` has too many elements");
-        length.serialize_into(bytes);
-        self.value.serialize_into(bytes);
+        length.serialize_into_with_order(bytes, order);
+        self.value.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -262,9 +214,9 @@ pub struct ResourceIdSpec {
     pub type_: u32,
 }
 impl TryParse for ResourceIdSpec {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resource, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (resource, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = ResourceIdSpec { resource, type_ };
         Ok((result, remaining))
     }
@@ -277,9 +229,9 @@ impl TryFrom<&[u8]> for ResourceIdSpec {
 }
 impl Serialize for ResourceIdSpec {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let resource_bytes = self.resource.serialize();
-        let type_bytes = self.type_.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let resource_bytes = self.resource.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
         [
             resource_bytes[0],
             resource_bytes[1],
@@ -291,10 +243,10 @@ impl Serialize for ResourceIdSpec {
             type_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.resource.serialize_into(bytes);
-        self.type_.serialize_into(bytes);
+        self.resource.serialize_into_with_order(bytes, order);
+        self.type_.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -306,11 +258,11 @@ pub struct ResourceSizeSpec {
     pub use_count: u32,
 }
 impl TryParse for ResourceSizeSpec {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (spec, remaining) = ResourceIdSpec::try_parse(remaining)?;
-        let (bytes, remaining) = u32::try_parse(remaining)?;
-        let (ref_count, remaining) = u32::try_parse(remaining)?;
-        let (use_count, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (spec, remaining) = ResourceIdSpec::try_parse_with_order(remaining, order)?;
+        let (bytes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ref_count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (use_count, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = ResourceSizeSpec { spec, bytes, ref_count, use_count };
         Ok((result, remaining))
     }
@@ -323,11 +275,11 @@ impl TryFrom<&[u8]> for ResourceSizeSpec {
 }
 impl Serialize for ResourceSizeSpec {
     type Bytes = [u8; 20];
-    fn serialize(&self) -> [u8; 20] {
-        let spec_bytes = self.spec.serialize();
-        let bytes_bytes = self.bytes.serialize();
-        let ref_count_bytes = self.ref_count.serialize();
-        let use_count_bytes = self.use_count.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 20] {
+        let spec_bytes = self.spec.serialize_with_order(order);
+        let bytes_bytes = self.bytes.serialize_with_order(order);
+        let ref_count_bytes = self.ref_count.serialize_with_order(order);
+        let use_count_bytes = self.use_count.serialize_with_order(order);
         [
             spec_bytes[0],
             spec_bytes[1],
@@ -351,12 +303,12 @@ impl Serialize for ResourceSizeSpec {
             use_count_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(20);
-        self.spec.serialize_into(bytes);
-        self.bytes.serialize_into(bytes);
-        self.ref_count.serialize_into(bytes);
-        self.use_count.serialize_into(bytes);
+        self.spec.serialize_into_with_order(bytes, order);
+        self.bytes.serialize_into_with_order(bytes, order);
+        self.ref_count.serialize_into_with_order(bytes, order);
+        self.use_count.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -366,10 +318,10 @@ pub struct ResourceSizeValue {
     pub cross_references: Vec<ResourceSizeSpec>,
 }
 impl TryParse for ResourceSizeValue {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (size, remaining) = ResourceSizeSpec::try_parse(remaining)?;
-        let (num_cross_references, remaining) = u32::try_parse(remaining)?;
-        let (cross_references, remaining) = crate::x11_utils::parse_list::<ResourceSizeSpec>(remaining, num_cross_references.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (size, remaining) = ResourceSizeSpec::try_parse_with_order(remaining, order)?;
+        let (num_cross_references, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (cross_references, remaining) = crate::x11_utils::parse_list_with_order::<ResourceSizeSpec>(remaining, num_cross_references.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ResourceSizeValue { size, cross_references };
         Ok((result, remaining))
     }
@@ -382,31 +334,65 @@ impl TryFrom<&[u8]> for ResourceSizeValue {
 }
 impl Serialize for ResourceSizeValue {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(24);
-        self.size.serialize_into(bytes);
+        self.size.serialize_into_with_order(bytes, order);
         let num_cross_references = u32::try_from(self.cross_references.len()).expect("`cross_references` has too many elements");
-        num_cross_references.serialize_into(bytes);
-        self.cross_references.serialize_into(bytes);
+        num_cross_references.serialize_into_with_order(bytes, order);
+        self.cross_references.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub client_major: u8,
+    pub client_minor: u8,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (client_major, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (client_minor, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            client_major,
+            client_minor,
+        })
+    }
+    /// Set the `client_major` field of this structure.
+    pub fn client_major(mut self, value: u8) -> Self {
+        self.client_major = value;
+        self
+    }
+    /// Set the `client_minor` field of this structure.
+    pub fn client_minor(mut self, value: u8) -> Self {
+        self.client_minor = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, client_major: u8, client_minor: u8) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let client_major_bytes = client_major.serialize();
-    let client_minor_bytes = client_minor.serialize();
+    let client_major_bytes = client_major.serialize_with_order(order);
+    let client_minor_bytes = client_minor.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -420,9 +406,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.client_major, self.client_minor)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -433,13 +431,13 @@ pub struct QueryVersionReply {
     pub server_minor: u16,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major, remaining) = u16::try_parse(remaining)?;
-        let (server_minor, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_major, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_minor, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = QueryVersionReply { response_type, sequence, length, server_major, server_minor };
         Ok((result, remaining))
     }
@@ -450,15 +448,57 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.server_major.serialize_into_with_order(bytes, order);
+        self.server_minor.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryClients request
 pub const QUERY_CLIENTS_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryClientsRequest {
+}
+impl QueryClientsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CLIENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryClientsRequest {
+        })
+    }
+}
+
 pub fn query_clients<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryClientsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -469,9 +509,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryClientsRequest {
+    type Reply = QueryClientsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryClientsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryClientsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_clients(conn)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryClientsReply {
@@ -481,14 +533,14 @@ pub struct QueryClientsReply {
     pub clients: Vec<Client>,
 }
 impl TryParse for QueryClientsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_clients, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_clients, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (clients, remaining) = crate::x11_utils::parse_list::<Client>(remaining, num_clients.try_into().or(Err(ParseError::ParseError))?)?;
+        let (clients, remaining) = crate::x11_utils::parse_list_with_order::<Client>(remaining, num_clients.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryClientsReply { response_type, sequence, length, clients };
         Ok((result, remaining))
     }
@@ -499,17 +551,69 @@ impl TryFrom<&[u8]> for QueryClientsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryClientsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_clients = u32::try_from(self.clients.len()).expect("`clients` has too many elements");
+        num_clients.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.clients.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryClientResources request
 pub const QUERY_CLIENT_RESOURCES_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryClientResourcesRequest {
+    pub xid: u32,
+}
+impl QueryClientResourcesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CLIENT_RESOURCES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (xid, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryClientResourcesRequest {
+            xid,
+        })
+    }
+    /// Set the `xid` field of this structure.
+    pub fn xid(mut self, value: u32) -> Self {
+        self.xid = value;
+        self
+    }
+}
+
 pub fn query_client_resources<Conn>(conn: &Conn, xid: u32) -> Result<Cookie<'_, Conn, QueryClientResourcesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let xid_bytes = xid.serialize();
+    let xid_bytes = xid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_CLIENT_RESOURCES_REQUEST,
@@ -523,9 +627,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryClientResourcesRequest {
+    type Reply = QueryClientResourcesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryClientResourcesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryClientResourcesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_client_resources(conn, self.xid)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryClientResourcesReply {
@@ -535,14 +651,14 @@ pub struct QueryClientResourcesReply {
     pub types: Vec<Type>,
 }
 impl TryParse for QueryClientResourcesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_types, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_types, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (types, remaining) = crate::x11_utils::parse_list::<Type>(remaining, num_types.try_into().or(Err(ParseError::ParseError))?)?;
+        let (types, remaining) = crate::x11_utils::parse_list_with_order::<Type>(remaining, num_types.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryClientResourcesReply { response_type, sequence, length, types };
         Ok((result, remaining))
     }
@@ -553,17 +669,69 @@ impl TryFrom<&[u8]> for QueryClientResourcesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryClientResourcesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_types = u32::try_from(self.types.len()).expect("`types` has too many elements");
+        num_types.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.types.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryClientPixmapBytes request
 pub const QUERY_CLIENT_PIXMAP_BYTES_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryClientPixmapBytesRequest {
+    pub xid: u32,
+}
+impl QueryClientPixmapBytesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CLIENT_PIXMAP_BYTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (xid, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryClientPixmapBytesRequest {
+            xid,
+        })
+    }
+    /// Set the `xid` field of this structure.
+    pub fn xid(mut self, value: u32) -> Self {
+        self.xid = value;
+        self
+    }
+}
+
 pub fn query_client_pixmap_bytes<Conn>(conn: &Conn, xid: u32) -> Result<Cookie<'_, Conn, QueryClientPixmapBytesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let xid_bytes = xid.serialize();
+    let xid_bytes = xid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_CLIENT_PIXMAP_BYTES_REQUEST,
@@ -577,9 +745,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryClientPixmapBytesRequest {
+    type Reply = QueryClientPixmapBytesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryClientPixmapBytesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryClientPixmapBytesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_client_pixmap_bytes(conn, self.xid)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryClientPixmapBytesReply {
@@ -590,13 +770,13 @@ pub struct QueryClientPixmapBytesReply {
     pub bytes_overflow: u32,
 }
 impl TryParse for QueryClientPixmapBytesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (bytes, remaining) = u32::try_parse(remaining)?;
-        let (bytes_overflow, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (bytes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (bytes_overflow, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = QueryClientPixmapBytesReply { response_type, sequence, length, bytes, bytes_overflow };
         Ok((result, remaining))
     }
@@ -607,18 +787,69 @@ impl TryFrom<&[u8]> for QueryClientPixmapBytesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryClientPixmapBytesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.bytes.serialize_into_with_order(bytes, order);
+        self.bytes_overflow.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryClientIds request
 pub const QUERY_CLIENT_IDS_REQUEST: u8 = 4;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct QueryClientIdsRequest {
+    pub specs: Vec<ClientIdSpec>,
+}
+impl QueryClientIdsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CLIENT_IDS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (num_specs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (specs, remaining) = crate::x11_utils::parse_list_with_order::<ClientIdSpec>(remaining, num_specs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryClientIdsRequest {
+            specs,
+        })
+    }
+    /// Set the `specs` field of this structure.
+    pub fn specs<I>(mut self, value: I) -> Self where I: Into<Vec<ClientIdSpec>> {
+        self.specs = value.into();
+        self
+    }
+}
+
 pub fn query_client_ids<'c, Conn>(conn: &'c Conn, specs: &[ClientIdSpec]) -> Result<Cookie<'c, Conn, QueryClientIdsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let num_specs = u32::try_from(specs.len()).expect("`specs` has too many elements");
-    let num_specs_bytes = num_specs.serialize();
+    let num_specs_bytes = num_specs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_CLIENT_IDS_REQUEST,
@@ -630,15 +861,27 @@ where
         num_specs_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let specs_bytes = specs.serialize();
+    let specs_bytes = specs.serialize_with_order(order);
     let length_so_far = length_so_far + specs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&specs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryClientIdsRequest {
+    type Reply = QueryClientIdsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryClientIdsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryClientIdsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_client_ids(conn, &self.specs)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryClientIdsReply {
@@ -648,14 +891,14 @@ pub struct QueryClientIdsReply {
     pub ids: Vec<ClientIdValue>,
 }
 impl TryParse for QueryClientIdsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_ids, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_ids, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (ids, remaining) = crate::x11_utils::parse_list::<ClientIdValue>(remaining, num_ids.try_into().or(Err(ParseError::ParseError))?)?;
+        let (ids, remaining) = crate::x11_utils::parse_list_with_order::<ClientIdValue>(remaining, num_ids.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryClientIdsReply { response_type, sequence, length, ids };
         Ok((result, remaining))
     }
@@ -666,19 +909,80 @@ impl TryFrom<&[u8]> for QueryClientIdsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryClientIdsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_ids = u32::try_from(self.ids.len()).expect("`ids` has too many elements");
+        num_ids.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.ids.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryResourceBytes request
 pub const QUERY_RESOURCE_BYTES_REQUEST: u8 = 5;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct QueryResourceBytesRequest {
+    pub client: u32,
+    pub specs: Vec<ResourceIdSpec>,
+}
+impl QueryResourceBytesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_RESOURCE_BYTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (client, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_specs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (specs, remaining) = crate::x11_utils::parse_list_with_order::<ResourceIdSpec>(remaining, num_specs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryResourceBytesRequest {
+            client,
+            specs,
+        })
+    }
+    /// Set the `client` field of this structure.
+    pub fn client(mut self, value: u32) -> Self {
+        self.client = value;
+        self
+    }
+    /// Set the `specs` field of this structure.
+    pub fn specs<I>(mut self, value: I) -> Self where I: Into<Vec<ResourceIdSpec>> {
+        self.specs = value.into();
+        self
+    }
+}
+
 pub fn query_resource_bytes<'c, Conn>(conn: &'c Conn, client: u32, specs: &[ResourceIdSpec]) -> Result<Cookie<'c, Conn, QueryResourceBytesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let client_bytes = client.serialize();
+    let client_bytes = client.serialize_with_order(order);
     let num_specs = u32::try_from(specs.len()).expect("`specs` has too many elements");
-    let num_specs_bytes = num_specs.serialize();
+    let num_specs_bytes = num_specs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_RESOURCE_BYTES_REQUEST,
@@ -694,15 +998,27 @@ where
         num_specs_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let specs_bytes = specs.serialize();
+    let specs_bytes = specs.serialize_with_order(order);
     let length_so_far = length_so_far + specs_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&specs_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryResourceBytesRequest {
+    type Reply = QueryResourceBytesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryResourceBytesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryResourceBytesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_resource_bytes(conn, self.client, &self.specs)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryResourceBytesReply {
@@ -712,14 +1028,14 @@ pub struct QueryResourceBytesReply {
     pub sizes: Vec<ResourceSizeValue>,
 }
 impl TryParse for QueryResourceBytesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_sizes, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_sizes, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (sizes, remaining) = crate::x11_utils::parse_list::<ResourceSizeValue>(remaining, num_sizes.try_into().or(Err(ParseError::ParseError))?)?;
+        let (sizes, remaining) = crate::x11_utils::parse_list_with_order::<ResourceSizeValue>(remaining, num_sizes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryResourceBytesReply { response_type, sequence, length, sizes };
         Ok((result, remaining))
     }
@@ -730,6 +1046,32 @@ impl TryFrom<&[u8]> for QueryResourceBytesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryResourceBytesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_sizes = u32::try_from(self.sizes.len()).expect("`sizes` has too many elements");
+        num_sizes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.sizes.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -31,213 +31,137 @@ pub const X11_EXTENSION_NAME: &str = "MIT-SCREEN-SAVER";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 1);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Kind {
-    Blanked = 0,
-    Internal = 1,
-    External = 2,
-}
-impl From<Kind> for u8 {
-    fn from(input: Kind) -> Self {
-        match input {
-            Kind::Blanked => 0,
-            Kind::Internal => 1,
-            Kind::External => 2,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Kind(u8);
+#[allow(non_upper_case_globals)]
+impl Kind {
+    pub const Blanked: Self = Self(0);
+    pub const Internal: Self = Self(1);
+    pub const External: Self = Self(2);
 }
+enum_newtype!(Kind, u8, [Blanked, Internal, External]);
 impl From<Kind> for Option<u8> {
     fn from(input: Kind) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Kind> for u16 {
     fn from(input: Kind) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Kind> for Option<u16> {
     fn from(input: Kind) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Kind> for u32 {
     fn from(input: Kind) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Kind> for Option<u32> {
     fn from(input: Kind) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Kind {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Kind::Blanked),
-            1 => Ok(Kind::Internal),
-            2 => Ok(Kind::External),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Kind {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Kind {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Event {
-    NotifyMask = 1 << 0,
-    CycleMask = 1 << 1,
-}
-impl From<Event> for u8 {
-    fn from(input: Event) -> Self {
-        match input {
-            Event::NotifyMask => 1 << 0,
-            Event::CycleMask => 1 << 1,
-        }
-    }
-}
-impl From<Event> for Option<u8> {
-    fn from(input: Event) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<Event> for u16 {
-    fn from(input: Event) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<Event> for Option<u16> {
-    fn from(input: Event) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<Event> for u32 {
-    fn from(input: Event) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Event(u32);
+#[allow(non_upper_case_globals)]
+impl Event {
+    pub const NotifyMask: Self = Self(1 << 0);
+    pub const CycleMask: Self = Self(1 << 1);
 }
+bitmask_set!(Event, u32, [NotifyMask, CycleMask]);
 impl From<Event> for Option<u32> {
     fn from(input: Event) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Event {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(Event::NotifyMask),
-            2 => Ok(Event::CycleMask),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Event {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-impl TryFrom<u32> for Event {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(Event, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum State {
-    Off = 0,
-    On = 1,
-    Cycle = 2,
-    Disabled = 3,
-}
-impl From<State> for u8 {
-    fn from(input: State) -> Self {
-        match input {
-            State::Off => 0,
-            State::On => 1,
-            State::Cycle => 2,
-            State::Disabled => 3,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct State(u8);
+#[allow(non_upper_case_globals)]
+impl State {
+    pub const Off: Self = Self(0);
+    pub const On: Self = Self(1);
+    pub const Cycle: Self = Self(2);
+    pub const Disabled: Self = Self(3);
 }
+enum_newtype!(State, u8, [Off, On, Cycle, Disabled]);
 impl From<State> for Option<u8> {
     fn from(input: State) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<State> for u16 {
     fn from(input: State) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<State> for Option<u16> {
     fn from(input: State) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<State> for u32 {
     fn from(input: State) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<State> for Option<u32> {
     fn from(input: State) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for State {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(State::Off),
-            1 => Ok(State::On),
-            2 => Ok(State::Cycle),
-            3 => Ok(State::Disabled),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub client_major_version: u8,
+    pub client_minor_version: u8,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
         }
+        let remaining = value;
+        let (client_major_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (client_minor_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
     }
-}
-impl TryFrom<u16> for State {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `client_major_version` field of this structure.
+    pub fn client_major_version(mut self, value: u8) -> Self {
+        self.client_major_version = value;
+        self
     }
-}
-impl TryFrom<u32> for State {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `client_minor_version` field of this structure.
+    pub fn client_minor_version(mut self, value: u8) -> Self {
+        self.client_minor_version = value;
+        self
     }
 }
 
-/// Opcode for the QueryVersion request
-pub const QUERY_VERSION_REQUEST: u8 = 0;
 pub fn query_version<Conn>(conn: &Conn, client_major_version: u8, client_minor_version: u8) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let client_major_version_bytes = client_major_version.serialize();
-    let client_minor_version_bytes = client_minor_version.serialize();
+    let client_major_version_bytes = client_major_version.serialize_with_order(order);
+    let client_minor_version_bytes = client_minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -251,9 +175,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.client_major_version, self.client_minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -264,13 +200,13 @@ pub struct QueryVersionReply {
     pub server_minor_version: u16,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major_version, remaining) = u16::try_parse(remaining)?;
-        let (server_minor_version, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = QueryVersionReply { response_type, sequence, length, server_major_version, server_minor_version };
         Ok((result, remaining))
@@ -282,17 +218,70 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.server_major_version.serialize_into_with_order(bytes, order);
+        self.server_minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryInfo request
 pub const QUERY_INFO_REQUEST: u8 = 1;
-pub fn query_info<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<Cookie<'_, Conn, QueryInfoReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryInfoRequest {
+    pub drawable: xproto::Drawable,
+}
+impl QueryInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryInfoRequest {
+            drawable,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+}
+
+pub fn query_info<Conn, A>(conn: &Conn, drawable: A) -> Result<Cookie<'_, Conn, QueryInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_INFO_REQUEST,
@@ -306,9 +295,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryInfoRequest {
+    type Reply = QueryInfoReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryInfoReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_info(conn, self.drawable)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryInfoReply {
@@ -323,18 +324,18 @@ pub struct QueryInfoReply {
     pub kind: Kind,
 }
 impl TryParse for QueryInfoReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (saver_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (ms_until_server, remaining) = u32::try_parse(remaining)?;
-        let (ms_since_user_input, remaining) = u32::try_parse(remaining)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (saver_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (ms_until_server, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ms_since_user_input, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(7..).ok_or(ParseError::ParseError)?;
-        let kind = kind.try_into()?;
+        let kind = Kind::from(kind);
         let result = QueryInfoReply { response_type, state, sequence, length, saver_window, ms_until_server, ms_since_user_input, event_mask, kind };
         Ok((result, remaining))
     }
@@ -345,20 +346,85 @@ impl TryFrom<&[u8]> for QueryInfoReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.state.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.saver_window.serialize_into_with_order(bytes, order);
+        self.ms_until_server.serialize_into_with_order(bytes, order);
+        self.ms_since_user_input.serialize_into_with_order(bytes, order);
+        self.event_mask.serialize_into_with_order(bytes, order);
+        u8::from(self.kind).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 7]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SelectInput request
 pub const SELECT_INPUT_REQUEST: u8 = 2;
-pub fn select_input<Conn, A>(conn: &Conn, drawable: xproto::Drawable, event_mask: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectInputRequest {
+    pub drawable: xproto::Drawable,
+    pub event_mask: Event,
+}
+impl SelectInputRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_INPUT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let event_mask = Event::from(event_mask);
+        Ok(SelectInputRequest {
+            drawable,
+            event_mask,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `event_mask` field of this structure.
+    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<Event> {
+        self.event_mask = value.into();
+        self
+    }
+}
+
+pub fn select_input<Conn, A, B>(conn: &Conn, drawable: A, event_mask: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<xproto::Drawable>,
+    B: Into<Event>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let event_mask: u32 = event_mask.into();
+    let drawable: xproto::Drawable = drawable.into();
+    let event_mask: Event = event_mask.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let event_mask_bytes = event_mask.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let event_mask_bytes = u32::from(event_mask).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_INPUT_REQUEST,
@@ -376,9 +442,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectInputRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectInputRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_input(conn, self.drawable, self.event_mask)
+    }
+}
 
 /// Opcode for the SetAttributes request
 pub const SET_ATTRIBUTES_REQUEST: u8 = 3;
@@ -396,63 +474,196 @@ pub struct SetAttributesAux {
     pub backing_pixel: Option<u32>,
     pub override_redirect: Option<xproto::Bool32>,
     pub save_under: Option<xproto::Bool32>,
-    pub event_mask: Option<u32>,
-    pub do_not_propogate_mask: Option<u32>,
+    pub event_mask: Option<xproto::EventMask>,
+    pub do_not_propogate_mask: Option<xproto::EventMask>,
     pub colormap: Option<xproto::Colormap>,
     pub cursor: Option<xproto::Cursor>,
 }
+impl SetAttributesAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, value_mask: xproto::CW) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(value_mask);
+        let mut outer_remaining = value;
+        let background_pixmap = if switch_expr & u32::from(xproto::CW::BackPixmap) != 0 {
+            let remaining = outer_remaining;
+            let (background_pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(background_pixmap)
+        } else {
+            None
+        };
+        let background_pixel = if switch_expr & u32::from(xproto::CW::BackPixel) != 0 {
+            let remaining = outer_remaining;
+            let (background_pixel, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(background_pixel)
+        } else {
+            None
+        };
+        let border_pixmap = if switch_expr & u32::from(xproto::CW::BorderPixmap) != 0 {
+            let remaining = outer_remaining;
+            let (border_pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(border_pixmap)
+        } else {
+            None
+        };
+        let border_pixel = if switch_expr & u32::from(xproto::CW::BorderPixel) != 0 {
+            let remaining = outer_remaining;
+            let (border_pixel, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(border_pixel)
+        } else {
+            None
+        };
+        let bit_gravity = if switch_expr & u32::from(xproto::CW::BitGravity) != 0 {
+            let remaining = outer_remaining;
+            let (bit_gravity, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let bit_gravity = xproto::Gravity::from(bit_gravity);
+            outer_remaining = remaining;
+            Some(bit_gravity)
+        } else {
+            None
+        };
+        let win_gravity = if switch_expr & u32::from(xproto::CW::WinGravity) != 0 {
+            let remaining = outer_remaining;
+            let (win_gravity, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let win_gravity = xproto::Gravity::from(win_gravity);
+            outer_remaining = remaining;
+            Some(win_gravity)
+        } else {
+            None
+        };
+        let backing_store = if switch_expr & u32::from(xproto::CW::BackingStore) != 0 {
+            let remaining = outer_remaining;
+            let (backing_store, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let backing_store = xproto::BackingStore::from(backing_store);
+            outer_remaining = remaining;
+            Some(backing_store)
+        } else {
+            None
+        };
+        let backing_planes = if switch_expr & u32::from(xproto::CW::BackingPlanes) != 0 {
+            let remaining = outer_remaining;
+            let (backing_planes, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(backing_planes)
+        } else {
+            None
+        };
+        let backing_pixel = if switch_expr & u32::from(xproto::CW::BackingPixel) != 0 {
+            let remaining = outer_remaining;
+            let (backing_pixel, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(backing_pixel)
+        } else {
+            None
+        };
+        let override_redirect = if switch_expr & u32::from(xproto::CW::OverrideRedirect) != 0 {
+            let remaining = outer_remaining;
+            let (override_redirect, remaining) = xproto::Bool32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(override_redirect)
+        } else {
+            None
+        };
+        let save_under = if switch_expr & u32::from(xproto::CW::SaveUnder) != 0 {
+            let remaining = outer_remaining;
+            let (save_under, remaining) = xproto::Bool32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(save_under)
+        } else {
+            None
+        };
+        let event_mask = if switch_expr & u32::from(xproto::CW::EventMask) != 0 {
+            let remaining = outer_remaining;
+            let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let event_mask = xproto::EventMask::from(event_mask);
+            outer_remaining = remaining;
+            Some(event_mask)
+        } else {
+            None
+        };
+        let do_not_propogate_mask = if switch_expr & u32::from(xproto::CW::DontPropagate) != 0 {
+            let remaining = outer_remaining;
+            let (do_not_propogate_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let do_not_propogate_mask = xproto::EventMask::from(do_not_propogate_mask);
+            outer_remaining = remaining;
+            Some(do_not_propogate_mask)
+        } else {
+            None
+        };
+        let colormap = if switch_expr & u32::from(xproto::CW::Colormap) != 0 {
+            let remaining = outer_remaining;
+            let (colormap, remaining) = xproto::Colormap::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(colormap)
+        } else {
+            None
+        };
+        let cursor = if switch_expr & u32::from(xproto::CW::Cursor) != 0 {
+            let remaining = outer_remaining;
+            let (cursor, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(cursor)
+        } else {
+            None
+        };
+        let result = SetAttributesAux { background_pixmap, background_pixel, border_pixmap, border_pixel, bit_gravity, win_gravity, backing_store, backing_planes, backing_pixel, override_redirect, save_under, event_mask, do_not_propogate_mask, colormap, cursor };
+        Ok((result, outer_remaining))
+    }
+}
 #[allow(dead_code, unused_variables)]
 impl SetAttributesAux {
-    fn serialize(&self, value_mask: u32) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, value_mask: xproto::CW) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, value_mask);
+        self.serialize_into_with_order(&mut result, order, value_mask);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, value_mask: u32) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, value_mask: xproto::CW) {
         if let Some(background_pixmap) = self.background_pixmap {
-            background_pixmap.serialize_into(bytes);
+            background_pixmap.serialize_into_with_order(bytes, order);
         }
         if let Some(background_pixel) = self.background_pixel {
-            background_pixel.serialize_into(bytes);
+            background_pixel.serialize_into_with_order(bytes, order);
         }
         if let Some(border_pixmap) = self.border_pixmap {
-            border_pixmap.serialize_into(bytes);
+            border_pixmap.serialize_into_with_order(bytes, order);
         }
         if let Some(border_pixel) = self.border_pixel {
-            border_pixel.serialize_into(bytes);
+            border_pixel.serialize_into_with_order(bytes, order);
         }
         if let Some(bit_gravity) = self.bit_gravity {
-            u32::from(bit_gravity).serialize_into(bytes);
+            u32::from(bit_gravity).serialize_into_with_order(bytes, order);
         }
         if let Some(win_gravity) = self.win_gravity {
-            u32::from(win_gravity).serialize_into(bytes);
+            u32::from(win_gravity).serialize_into_with_order(bytes, order);
         }
         if let Some(backing_store) = self.backing_store {
-            u32::from(backing_store).serialize_into(bytes);
+            u32::from(backing_store).serialize_into_with_order(bytes, order);
         }
         if let Some(backing_planes) = self.backing_planes {
-            backing_planes.serialize_into(bytes);
+            backing_planes.serialize_into_with_order(bytes, order);
         }
         if let Some(backing_pixel) = self.backing_pixel {
-            backing_pixel.serialize_into(bytes);
+            backing_pixel.serialize_into_with_order(bytes, order);
         }
         if let Some(override_redirect) = self.override_redirect {
-            override_redirect.serialize_into(bytes);
+            override_redirect.serialize_into_with_order(bytes, order);
         }
         if let Some(save_under) = self.save_under {
-            save_under.serialize_into(bytes);
+            save_under.serialize_into_with_order(bytes, order);
         }
         if let Some(event_mask) = self.event_mask {
-            event_mask.serialize_into(bytes);
+            u32::from(event_mask).serialize_into_with_order(bytes, order);
         }
         if let Some(do_not_propogate_mask) = self.do_not_propogate_mask {
-            do_not_propogate_mask.serialize_into(bytes);
+            u32::from(do_not_propogate_mask).serialize_into_with_order(bytes, order);
         }
         if let Some(colormap) = self.colormap {
-            colormap.serialize_into(bytes);
+            colormap.serialize_into_with_order(bytes, order);
         }
         if let Some(cursor) = self.cursor {
-            cursor.serialize_into(bytes);
+            cursor.serialize_into_with_order(bytes, order);
         }
     }
 }
@@ -568,12 +779,12 @@ impl SetAttributesAux {
         self
     }
     /// Set the `event_mask` field of this structure.
-    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<Option<xproto::EventMask>> {
         self.event_mask = value.into();
         self
     }
     /// Set the `do_not_propogate_mask` field of this structure.
-    pub fn do_not_propogate_mask<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+    pub fn do_not_propogate_mask<I>(mut self, value: I) -> Self where I: Into<Option<xproto::EventMask>> {
         self.do_not_propogate_mask = value.into();
         self
     }
@@ -589,24 +800,128 @@ impl SetAttributesAux {
     }
 }
 
-pub fn set_attributes<'c, Conn>(conn: &'c Conn, drawable: xproto::Drawable, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: xproto::WindowClass, depth: u8, visual: xproto::Visualid, value_list: &SetAttributesAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetAttributesRequest {
+    pub drawable: xproto::Drawable,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub class: xproto::WindowClass,
+    pub depth: u8,
+    pub visual: xproto::Visualid,
+    pub value_list: SetAttributesAux,
+}
+impl SetAttributesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_ATTRIBUTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (border_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (class, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (value_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_list, remaining) = SetAttributesAux::try_parse_with_order(remaining, order, xproto::CW::from(value_mask))?;
+        let _ = remaining;
+        let _ = order;
+        let class = xproto::WindowClass::from(u16::from(class));
+        Ok(SetAttributesRequest {
+            drawable,
+            x,
+            y,
+            width,
+            height,
+            border_width,
+            class,
+            depth,
+            visual,
+            value_list,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `x` field of this structure.
+    pub fn x(mut self, value: i16) -> Self {
+        self.x = value;
+        self
+    }
+    /// Set the `y` field of this structure.
+    pub fn y(mut self, value: i16) -> Self {
+        self.y = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `border_width` field of this structure.
+    pub fn border_width(mut self, value: u16) -> Self {
+        self.border_width = value;
+        self
+    }
+    /// Set the `class` field of this structure.
+    pub fn class<I>(mut self, value: I) -> Self where I: Into<xproto::WindowClass> {
+        self.class = value.into();
+        self
+    }
+    /// Set the `depth` field of this structure.
+    pub fn depth(mut self, value: u8) -> Self {
+        self.depth = value;
+        self
+    }
+    /// Set the `visual` field of this structure.
+    pub fn visual<I>(mut self, value: I) -> Self where I: Into<xproto::Visualid> {
+        self.visual = value.into();
+        self
+    }
+    /// Set the `value_list` field of this structure.
+    pub fn value_list<I>(mut self, value: I) -> Self where I: Into<SetAttributesAux> {
+        self.value_list = value.into();
+        self
+    }
+}
+
+pub fn set_attributes<'c, Conn, A, B>(conn: &'c Conn, drawable: A, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: B, depth: u8, visual: xproto::Visualid, value_list: &SetAttributesAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
+    B: Into<xproto::WindowClass>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let class: xproto::WindowClass = class.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let x_bytes = x.serialize();
-    let y_bytes = y.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let border_width_bytes = border_width.serialize();
-    let class_bytes = u8::from(class).serialize();
-    let depth_bytes = depth.serialize();
-    let visual_bytes = visual.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let x_bytes = x.serialize_with_order(order);
+    let y_bytes = y.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let border_width_bytes = border_width.serialize_with_order(order);
+    let class_bytes = u8::try_from(u16::from(class)).unwrap().serialize_with_order(order);
+    let depth_bytes = depth.serialize_with_order(order);
+    let visual_bytes = visual.serialize_with_order(order);
     let value_mask = u32::try_from(value_list.switch_expr()).unwrap();
-    let value_mask_bytes = value_mask.serialize();
+    let value_mask_bytes = value_mask.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_ATTRIBUTES_REQUEST,
@@ -638,26 +953,66 @@ where
         value_mask_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let value_list_bytes = value_list.serialize(value_mask);
+    let value_list_bytes = value_list.serialize_with_order(order, xproto::CW::from(value_mask));
     let length_so_far = length_so_far + value_list_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&value_list_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetAttributesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetAttributesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_attributes(conn, self.drawable, self.x, self.y, self.width, self.height, self.border_width, self.class, self.depth, self.visual, &self.value_list)
+    }
+}
 
 /// Opcode for the UnsetAttributes request
 pub const UNSET_ATTRIBUTES_REQUEST: u8 = 4;
-pub fn unset_attributes<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UnsetAttributesRequest {
+    pub drawable: xproto::Drawable,
+}
+impl UnsetAttributesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNSET_ATTRIBUTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(UnsetAttributesRequest {
+            drawable,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+}
+
+pub fn unset_attributes<Conn, A>(conn: &Conn, drawable: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         UNSET_ATTRIBUTES_REQUEST,
@@ -671,20 +1026,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for UnsetAttributesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UnsetAttributesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        unset_attributes(conn, self.drawable)
+    }
+}
 
 /// Opcode for the Suspend request
 pub const SUSPEND_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SuspendRequest {
+    pub suspend: u32,
+}
+impl SuspendRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SUSPEND_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (suspend, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SuspendRequest {
+            suspend,
+        })
+    }
+    /// Set the `suspend` field of this structure.
+    pub fn suspend(mut self, value: u32) -> Self {
+        self.suspend = value;
+        self
+    }
+}
+
 pub fn suspend<Conn>(conn: &Conn, suspend: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let suspend_bytes = suspend.serialize();
+    let suspend_bytes = suspend.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SUSPEND_REQUEST,
@@ -698,9 +1091,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SuspendRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SuspendRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        suspend(conn, self.suspend)
+    }
+}
 
 /// Opcode for the Notify event
 pub const NOTIFY_EVENT: u8 = 0;
@@ -716,18 +1121,18 @@ pub struct NotifyEvent {
     pub forced: bool,
 }
 impl TryParse for NotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
-        let (forced, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (forced, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(14..).ok_or(ParseError::ParseError)?;
-        let state = state.try_into()?;
-        let kind = kind.try_into()?;
+        let state = State::from(state);
+        let kind = Kind::from(kind);
         let result = NotifyEvent { response_type, state, sequence, time, root, window, kind, forced };
         Ok((result, remaining))
     }
@@ -740,14 +1145,25 @@ impl TryFrom<&[u8]> for NotifyEvent {
 }
 impl From<&NotifyEvent> for [u8; 32] {
     fn from(input: &NotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let state_bytes = u8::from(input.state).serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let root_bytes = input.root.serialize();
-        let window_bytes = input.window.serialize();
-        let kind_bytes = u8::from(input.kind).serialize();
-        let forced_bytes = input.forced.serialize();
+        input.serialize()
+    }
+}
+impl From<NotifyEvent> for [u8; 32] {
+    fn from(input: NotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for NotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let state_bytes = u8::from(self.state).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let root_bytes = self.root.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let kind_bytes = u8::from(self.kind).serialize_with_order(order);
+        let forced_bytes = self.forced.serialize_with_order(order);
         [
             response_type_bytes[0],
             state_bytes[0],
@@ -783,10 +1199,8 @@ impl From<&NotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<NotifyEvent> for [u8; 32] {
-    fn from(input: NotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -796,21 +1210,29 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_version(self, client_major_version, client_minor_version)
     }
-    fn screensaver_query_info(&self, drawable: xproto::Drawable) -> Result<Cookie<'_, Self, QueryInfoReply>, ConnectionError>
+    fn screensaver_query_info<A>(&self, drawable: A) -> Result<Cookie<'_, Self, QueryInfoReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         query_info(self, drawable)
     }
-    fn screensaver_select_input<A>(&self, drawable: xproto::Drawable, event_mask: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn screensaver_select_input<A, B>(&self, drawable: A, event_mask: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<xproto::Drawable>,
+        B: Into<Event>,
     {
         select_input(self, drawable, event_mask)
     }
-    fn screensaver_set_attributes<'c>(&'c self, drawable: xproto::Drawable, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: xproto::WindowClass, depth: u8, visual: xproto::Visualid, value_list: &SetAttributesAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn screensaver_set_attributes<'c, A, B>(&'c self, drawable: A, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: B, depth: u8, visual: xproto::Visualid, value_list: &SetAttributesAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
+        B: Into<xproto::WindowClass>,
     {
         set_attributes(self, drawable, x, y, width, height, border_width, class, depth, visual, value_list)
     }
-    fn screensaver_unset_attributes(&self, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn screensaver_unset_attributes<A>(&self, drawable: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         unset_attributes(self, drawable)
     }
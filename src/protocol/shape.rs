@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -35,139 +35,75 @@ pub type Op = u8;
 
 pub type Kind = u8;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SO {
-    Set = 0,
-    Union = 1,
-    Intersect = 2,
-    Subtract = 3,
-    Invert = 4,
-}
-impl From<SO> for u8 {
-    fn from(input: SO) -> Self {
-        match input {
-            SO::Set => 0,
-            SO::Union => 1,
-            SO::Intersect => 2,
-            SO::Subtract => 3,
-            SO::Invert => 4,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SO(u8);
+#[allow(non_upper_case_globals)]
+impl SO {
+    pub const Set: Self = Self(0);
+    pub const Union: Self = Self(1);
+    pub const Intersect: Self = Self(2);
+    pub const Subtract: Self = Self(3);
+    pub const Invert: Self = Self(4);
 }
+enum_newtype!(SO, u8, [Set, Union, Intersect, Subtract, Invert]);
 impl From<SO> for Option<u8> {
     fn from(input: SO) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SO> for u16 {
     fn from(input: SO) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SO> for Option<u16> {
     fn from(input: SO) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SO> for u32 {
     fn from(input: SO) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SO> for Option<u32> {
     fn from(input: SO) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SO {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SO::Set),
-            1 => Ok(SO::Union),
-            2 => Ok(SO::Intersect),
-            3 => Ok(SO::Subtract),
-            4 => Ok(SO::Invert),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SO {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SO {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SK {
-    Bounding = 0,
-    Clip = 1,
-    Input = 2,
-}
-impl From<SK> for u8 {
-    fn from(input: SK) -> Self {
-        match input {
-            SK::Bounding => 0,
-            SK::Clip => 1,
-            SK::Input => 2,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SK(u8);
+#[allow(non_upper_case_globals)]
+impl SK {
+    pub const Bounding: Self = Self(0);
+    pub const Clip: Self = Self(1);
+    pub const Input: Self = Self(2);
 }
+enum_newtype!(SK, u8, [Bounding, Clip, Input]);
 impl From<SK> for Option<u8> {
     fn from(input: SK) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SK> for u16 {
     fn from(input: SK) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SK> for Option<u16> {
     fn from(input: SK) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SK> for u32 {
     fn from(input: SK) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SK> for Option<u32> {
     fn from(input: SK) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SK {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SK::Bounding),
-            1 => Ok(SK::Clip),
-            2 => Ok(SK::Input),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SK {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SK {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -187,19 +123,19 @@ pub struct NotifyEvent {
     pub shaped: bool,
 }
 impl TryParse for NotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (shape_kind, remaining) = Kind::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (affected_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (extents_x, remaining) = i16::try_parse(remaining)?;
-        let (extents_y, remaining) = i16::try_parse(remaining)?;
-        let (extents_width, remaining) = u16::try_parse(remaining)?;
-        let (extents_height, remaining) = u16::try_parse(remaining)?;
-        let (server_time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (shaped, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (shape_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (affected_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (extents_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (extents_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (extents_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (extents_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (shaped, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(11..).ok_or(ParseError::ParseError)?;
-        let shape_kind = shape_kind.try_into()?;
+        let shape_kind = SK::from(shape_kind);
         let result = NotifyEvent { response_type, shape_kind, sequence, affected_window, extents_x, extents_y, extents_width, extents_height, server_time, shaped };
         Ok((result, remaining))
     }
@@ -212,16 +148,27 @@ impl TryFrom<&[u8]> for NotifyEvent {
 }
 impl From<&NotifyEvent> for [u8; 32] {
     fn from(input: &NotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let shape_kind_bytes = Kind::from(input.shape_kind).serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let affected_window_bytes = input.affected_window.serialize();
-        let extents_x_bytes = input.extents_x.serialize();
-        let extents_y_bytes = input.extents_y.serialize();
-        let extents_width_bytes = input.extents_width.serialize();
-        let extents_height_bytes = input.extents_height.serialize();
-        let server_time_bytes = input.server_time.serialize();
-        let shaped_bytes = input.shaped.serialize();
+        input.serialize()
+    }
+}
+impl From<NotifyEvent> for [u8; 32] {
+    fn from(input: NotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for NotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let shape_kind_bytes = u8::from(self.shape_kind).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let affected_window_bytes = self.affected_window.serialize_with_order(order);
+        let extents_x_bytes = self.extents_x.serialize_with_order(order);
+        let extents_y_bytes = self.extents_y.serialize_with_order(order);
+        let extents_width_bytes = self.extents_width.serialize_with_order(order);
+        let extents_height_bytes = self.extents_height.serialize_with_order(order);
+        let server_time_bytes = self.server_time.serialize_with_order(order);
+        let shaped_bytes = self.shaped.serialize_with_order(order);
         [
             response_type_bytes[0],
             shape_kind_bytes[0],
@@ -257,21 +204,37 @@ impl From<&NotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<NotifyEvent> for [u8; 32] {
-    fn from(input: NotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+        })
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -282,9 +245,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -295,13 +270,13 @@ pub struct QueryVersionReply {
     pub minor_version: u16,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
     }
@@ -312,22 +287,137 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Rectangles request
 pub const RECTANGLES_REQUEST: u8 = 1;
-pub fn rectangles<'c, Conn>(conn: &'c Conn, operation: SO, destination_kind: SK, ordering: xproto::ClipOrdering, destination_window: xproto::Window, x_offset: i16, y_offset: i16, rectangles: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RectanglesRequest {
+    pub operation: SO,
+    pub destination_kind: SK,
+    pub ordering: xproto::ClipOrdering,
+    pub destination_window: xproto::Window,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub rectangles: Vec<xproto::Rectangle>,
+}
+impl RectanglesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RECTANGLES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (operation, remaining) = Op::try_parse_with_order(remaining, order)?;
+        let (destination_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let (ordering, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut rectangles = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            rectangles.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        let operation = SO::from(operation);
+        let destination_kind = SK::from(destination_kind);
+        let ordering = xproto::ClipOrdering::from(ordering);
+        Ok(RectanglesRequest {
+            operation,
+            destination_kind,
+            ordering,
+            destination_window,
+            x_offset,
+            y_offset,
+            rectangles,
+        })
+    }
+    /// Set the `operation` field of this structure.
+    pub fn operation<I>(mut self, value: I) -> Self where I: Into<SO> {
+        self.operation = value.into();
+        self
+    }
+    /// Set the `destination_kind` field of this structure.
+    pub fn destination_kind<I>(mut self, value: I) -> Self where I: Into<SK> {
+        self.destination_kind = value.into();
+        self
+    }
+    /// Set the `ordering` field of this structure.
+    pub fn ordering<I>(mut self, value: I) -> Self where I: Into<xproto::ClipOrdering> {
+        self.ordering = value.into();
+        self
+    }
+    /// Set the `destination_window` field of this structure.
+    pub fn destination_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.destination_window = value.into();
+        self
+    }
+    /// Set the `x_offset` field of this structure.
+    pub fn x_offset(mut self, value: i16) -> Self {
+        self.x_offset = value;
+        self
+    }
+    /// Set the `y_offset` field of this structure.
+    pub fn y_offset(mut self, value: i16) -> Self {
+        self.y_offset = value;
+        self
+    }
+    /// Set the `rectangles` field of this structure.
+    pub fn rectangles<I>(mut self, value: I) -> Self where I: Into<Vec<xproto::Rectangle>> {
+        self.rectangles = value.into();
+        self
+    }
+}
+
+pub fn rectangles<'c, Conn, A, B, C>(conn: &'c Conn, operation: A, destination_kind: B, ordering: C, destination_window: xproto::Window, x_offset: i16, y_offset: i16, rectangles: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<SO>,
+    B: Into<SK>,
+    C: Into<xproto::ClipOrdering>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let operation: SO = operation.into();
+    let destination_kind: SK = destination_kind.into();
+    let ordering: xproto::ClipOrdering = ordering.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let operation_bytes = Op::from(operation).serialize();
-    let destination_kind_bytes = Kind::from(destination_kind).serialize();
-    let ordering_bytes = u8::from(ordering).serialize();
-    let destination_window_bytes = destination_window.serialize();
-    let x_offset_bytes = x_offset.serialize();
-    let y_offset_bytes = y_offset.serialize();
+    let operation_bytes = u8::from(operation).serialize_with_order(order);
+    let destination_kind_bytes = u8::from(destination_kind).serialize_with_order(order);
+    let ordering_bytes = u8::from(ordering).serialize_with_order(order);
+    let destination_window_bytes = destination_window.serialize_with_order(order);
+    let x_offset_bytes = x_offset.serialize_with_order(order);
+    let y_offset_bytes = y_offset.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         RECTANGLES_REQUEST,
@@ -347,33 +437,118 @@ where
         y_offset_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let rectangles_bytes = rectangles.serialize();
+    let rectangles_bytes = rectangles.serialize_with_order(order);
     let length_so_far = length_so_far + rectangles_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&rectangles_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for RectanglesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for RectanglesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        rectangles(conn, self.operation, self.destination_kind, self.ordering, self.destination_window, self.x_offset, self.y_offset, &self.rectangles)
+    }
+}
 
 /// Opcode for the Mask request
 pub const MASK_REQUEST: u8 = 2;
-pub fn mask<Conn, A>(conn: &Conn, operation: SO, destination_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_bitmap: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MaskRequest {
+    pub operation: SO,
+    pub destination_kind: SK,
+    pub destination_window: xproto::Window,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub source_bitmap: xproto::Pixmap,
+}
+impl MaskRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != MASK_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (operation, remaining) = Op::try_parse_with_order(remaining, order)?;
+        let (destination_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (source_bitmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let operation = SO::from(operation);
+        let destination_kind = SK::from(destination_kind);
+        Ok(MaskRequest {
+            operation,
+            destination_kind,
+            destination_window,
+            x_offset,
+            y_offset,
+            source_bitmap,
+        })
+    }
+    /// Set the `operation` field of this structure.
+    pub fn operation<I>(mut self, value: I) -> Self where I: Into<SO> {
+        self.operation = value.into();
+        self
+    }
+    /// Set the `destination_kind` field of this structure.
+    pub fn destination_kind<I>(mut self, value: I) -> Self where I: Into<SK> {
+        self.destination_kind = value.into();
+        self
+    }
+    /// Set the `destination_window` field of this structure.
+    pub fn destination_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.destination_window = value.into();
+        self
+    }
+    /// Set the `x_offset` field of this structure.
+    pub fn x_offset(mut self, value: i16) -> Self {
+        self.x_offset = value;
+        self
+    }
+    /// Set the `y_offset` field of this structure.
+    pub fn y_offset(mut self, value: i16) -> Self {
+        self.y_offset = value;
+        self
+    }
+    /// Set the `source_bitmap` field of this structure.
+    pub fn source_bitmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.source_bitmap = value.into();
+        self
+    }
+}
+
+pub fn mask<Conn, A, B, C>(conn: &Conn, operation: A, destination_kind: B, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_bitmap: C) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<xproto::Pixmap>,
+    A: Into<SO>,
+    B: Into<SK>,
+    C: Into<xproto::Pixmap>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let operation: SO = operation.into();
+    let destination_kind: SK = destination_kind.into();
     let source_bitmap: xproto::Pixmap = source_bitmap.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let operation_bytes = Op::from(operation).serialize();
-    let destination_kind_bytes = Kind::from(destination_kind).serialize();
-    let destination_window_bytes = destination_window.serialize();
-    let x_offset_bytes = x_offset.serialize();
-    let y_offset_bytes = y_offset.serialize();
-    let source_bitmap_bytes = source_bitmap.serialize();
+    let operation_bytes = u8::from(operation).serialize_with_order(order);
+    let destination_kind_bytes = u8::from(destination_kind).serialize_with_order(order);
+    let destination_window_bytes = destination_window.serialize_with_order(order);
+    let x_offset_bytes = x_offset.serialize_with_order(order);
+    let y_offset_bytes = y_offset.serialize_with_order(order);
+    let source_bitmap_bytes = source_bitmap.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         MASK_REQUEST,
@@ -399,26 +574,122 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for MaskRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for MaskRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        mask(conn, self.operation, self.destination_kind, self.destination_window, self.x_offset, self.y_offset, self.source_bitmap)
+    }
+}
 
 /// Opcode for the Combine request
 pub const COMBINE_REQUEST: u8 = 3;
-pub fn combine<Conn>(conn: &Conn, operation: SO, destination_kind: SK, source_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CombineRequest {
+    pub operation: SO,
+    pub destination_kind: SK,
+    pub source_kind: SK,
+    pub destination_window: xproto::Window,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub source_window: xproto::Window,
+}
+impl CombineRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COMBINE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (operation, remaining) = Op::try_parse_with_order(remaining, order)?;
+        let (destination_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let (source_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (source_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let operation = SO::from(operation);
+        let destination_kind = SK::from(destination_kind);
+        let source_kind = SK::from(source_kind);
+        Ok(CombineRequest {
+            operation,
+            destination_kind,
+            source_kind,
+            destination_window,
+            x_offset,
+            y_offset,
+            source_window,
+        })
+    }
+    /// Set the `operation` field of this structure.
+    pub fn operation<I>(mut self, value: I) -> Self where I: Into<SO> {
+        self.operation = value.into();
+        self
+    }
+    /// Set the `destination_kind` field of this structure.
+    pub fn destination_kind<I>(mut self, value: I) -> Self where I: Into<SK> {
+        self.destination_kind = value.into();
+        self
+    }
+    /// Set the `source_kind` field of this structure.
+    pub fn source_kind<I>(mut self, value: I) -> Self where I: Into<SK> {
+        self.source_kind = value.into();
+        self
+    }
+    /// Set the `destination_window` field of this structure.
+    pub fn destination_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.destination_window = value.into();
+        self
+    }
+    /// Set the `x_offset` field of this structure.
+    pub fn x_offset(mut self, value: i16) -> Self {
+        self.x_offset = value;
+        self
+    }
+    /// Set the `y_offset` field of this structure.
+    pub fn y_offset(mut self, value: i16) -> Self {
+        self.y_offset = value;
+        self
+    }
+    /// Set the `source_window` field of this structure.
+    pub fn source_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.source_window = value.into();
+        self
+    }
+}
+
+pub fn combine<Conn, A, B, C>(conn: &Conn, operation: A, destination_kind: B, source_kind: C, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<SO>,
+    B: Into<SK>,
+    C: Into<SK>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let operation: SO = operation.into();
+    let destination_kind: SK = destination_kind.into();
+    let source_kind: SK = source_kind.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let operation_bytes = Op::from(operation).serialize();
-    let destination_kind_bytes = Kind::from(destination_kind).serialize();
-    let source_kind_bytes = Kind::from(source_kind).serialize();
-    let destination_window_bytes = destination_window.serialize();
-    let x_offset_bytes = x_offset.serialize();
-    let y_offset_bytes = y_offset.serialize();
-    let source_window_bytes = source_window.serialize();
+    let operation_bytes = u8::from(operation).serialize_with_order(order);
+    let destination_kind_bytes = u8::from(destination_kind).serialize_with_order(order);
+    let source_kind_bytes = u8::from(source_kind).serialize_with_order(order);
+    let destination_window_bytes = destination_window.serialize_with_order(order);
+    let x_offset_bytes = x_offset.serialize_with_order(order);
+    let y_offset_bytes = y_offset.serialize_with_order(order);
+    let source_window_bytes = source_window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         COMBINE_REQUEST,
@@ -444,23 +715,89 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CombineRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CombineRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        combine(conn, self.operation, self.destination_kind, self.source_kind, self.destination_window, self.x_offset, self.y_offset, self.source_window)
+    }
+}
 
 /// Opcode for the Offset request
 pub const OFFSET_REQUEST: u8 = 4;
-pub fn offset<Conn>(conn: &Conn, destination_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OffsetRequest {
+    pub destination_kind: SK,
+    pub destination_window: xproto::Window,
+    pub x_offset: i16,
+    pub y_offset: i16,
+}
+impl OffsetRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != OFFSET_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (destination_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let destination_kind = SK::from(destination_kind);
+        Ok(OffsetRequest {
+            destination_kind,
+            destination_window,
+            x_offset,
+            y_offset,
+        })
+    }
+    /// Set the `destination_kind` field of this structure.
+    pub fn destination_kind<I>(mut self, value: I) -> Self where I: Into<SK> {
+        self.destination_kind = value.into();
+        self
+    }
+    /// Set the `destination_window` field of this structure.
+    pub fn destination_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.destination_window = value.into();
+        self
+    }
+    /// Set the `x_offset` field of this structure.
+    pub fn x_offset(mut self, value: i16) -> Self {
+        self.x_offset = value;
+        self
+    }
+    /// Set the `y_offset` field of this structure.
+    pub fn y_offset(mut self, value: i16) -> Self {
+        self.y_offset = value;
+        self
+    }
+}
+
+pub fn offset<Conn, A>(conn: &Conn, destination_kind: A, destination_window: xproto::Window, x_offset: i16, y_offset: i16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<SK>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let destination_kind: SK = destination_kind.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let destination_kind_bytes = Kind::from(destination_kind).serialize();
-    let destination_window_bytes = destination_window.serialize();
-    let x_offset_bytes = x_offset.serialize();
-    let y_offset_bytes = y_offset.serialize();
+    let destination_kind_bytes = u8::from(destination_kind).serialize_with_order(order);
+    let destination_window_bytes = destination_window.serialize_with_order(order);
+    let x_offset_bytes = x_offset.serialize_with_order(order);
+    let y_offset_bytes = y_offset.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         OFFSET_REQUEST,
@@ -482,20 +819,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for OffsetRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for OffsetRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        offset(conn, self.destination_kind, self.destination_window, self.x_offset, self.y_offset)
+    }
+}
 
 /// Opcode for the QueryExtents request
 pub const QUERY_EXTENTS_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryExtentsRequest {
+    pub destination_window: xproto::Window,
+}
+impl QueryExtentsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_EXTENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryExtentsRequest {
+            destination_window,
+        })
+    }
+    /// Set the `destination_window` field of this structure.
+    pub fn destination_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.destination_window = value.into();
+        self
+    }
+}
+
 pub fn query_extents<Conn>(conn: &Conn, destination_window: xproto::Window) -> Result<Cookie<'_, Conn, QueryExtentsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let destination_window_bytes = destination_window.serialize();
+    let destination_window_bytes = destination_window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_EXTENTS_REQUEST,
@@ -509,9 +884,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryExtentsRequest {
+    type Reply = QueryExtentsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryExtentsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryExtentsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_extents(conn, self.destination_window)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryExtentsReply {
@@ -530,22 +917,22 @@ pub struct QueryExtentsReply {
     pub clip_shape_extents_height: u16,
 }
 impl TryParse for QueryExtentsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (bounding_shaped, remaining) = bool::try_parse(remaining)?;
-        let (clip_shaped, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (bounding_shaped, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (clip_shaped, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (bounding_shape_extents_x, remaining) = i16::try_parse(remaining)?;
-        let (bounding_shape_extents_y, remaining) = i16::try_parse(remaining)?;
-        let (bounding_shape_extents_width, remaining) = u16::try_parse(remaining)?;
-        let (bounding_shape_extents_height, remaining) = u16::try_parse(remaining)?;
-        let (clip_shape_extents_x, remaining) = i16::try_parse(remaining)?;
-        let (clip_shape_extents_y, remaining) = i16::try_parse(remaining)?;
-        let (clip_shape_extents_width, remaining) = u16::try_parse(remaining)?;
-        let (clip_shape_extents_height, remaining) = u16::try_parse(remaining)?;
+        let (bounding_shape_extents_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (bounding_shape_extents_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (bounding_shape_extents_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (bounding_shape_extents_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (clip_shape_extents_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (clip_shape_extents_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (clip_shape_extents_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (clip_shape_extents_height, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = QueryExtentsReply { response_type, sequence, length, bounding_shaped, clip_shaped, bounding_shape_extents_x, bounding_shape_extents_y, bounding_shape_extents_width, bounding_shape_extents_height, clip_shape_extents_x, clip_shape_extents_y, clip_shape_extents_width, clip_shape_extents_height };
         Ok((result, remaining))
     }
@@ -556,18 +943,86 @@ impl TryFrom<&[u8]> for QueryExtentsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryExtentsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.bounding_shaped.serialize_into_with_order(bytes, order);
+        self.clip_shaped.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.bounding_shape_extents_x.serialize_into_with_order(bytes, order);
+        self.bounding_shape_extents_y.serialize_into_with_order(bytes, order);
+        self.bounding_shape_extents_width.serialize_into_with_order(bytes, order);
+        self.bounding_shape_extents_height.serialize_into_with_order(bytes, order);
+        self.clip_shape_extents_x.serialize_into_with_order(bytes, order);
+        self.clip_shape_extents_y.serialize_into_with_order(bytes, order);
+        self.clip_shape_extents_width.serialize_into_with_order(bytes, order);
+        self.clip_shape_extents_height.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SelectInput request
 pub const SELECT_INPUT_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectInputRequest {
+    pub destination_window: xproto::Window,
+    pub enable: bool,
+}
+impl SelectInputRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_INPUT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (enable, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SelectInputRequest {
+            destination_window,
+            enable,
+        })
+    }
+    /// Set the `destination_window` field of this structure.
+    pub fn destination_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.destination_window = value.into();
+        self
+    }
+    /// Set the `enable` field of this structure.
+    pub fn enable(mut self, value: bool) -> Self {
+        self.enable = value;
+        self
+    }
+}
+
 pub fn select_input<Conn>(conn: &Conn, destination_window: xproto::Window, enable: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let destination_window_bytes = destination_window.serialize();
-    let enable_bytes = enable.serialize();
+    let destination_window_bytes = destination_window.serialize_with_order(order);
+    let enable_bytes = enable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_INPUT_REQUEST,
@@ -585,20 +1040,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectInputRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectInputRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_input(conn, self.destination_window, self.enable)
+    }
+}
 
 /// Opcode for the InputSelected request
 pub const INPUT_SELECTED_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InputSelectedRequest {
+    pub destination_window: xproto::Window,
+}
+impl InputSelectedRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INPUT_SELECTED_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(InputSelectedRequest {
+            destination_window,
+        })
+    }
+    /// Set the `destination_window` field of this structure.
+    pub fn destination_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.destination_window = value.into();
+        self
+    }
+}
+
 pub fn input_selected<Conn>(conn: &Conn, destination_window: xproto::Window) -> Result<Cookie<'_, Conn, InputSelectedReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let destination_window_bytes = destination_window.serialize();
+    let destination_window_bytes = destination_window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         INPUT_SELECTED_REQUEST,
@@ -612,9 +1105,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for InputSelectedRequest {
+    type Reply = InputSelectedReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for InputSelectedRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, InputSelectedReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        input_selected(conn, self.destination_window)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InputSelectedReply {
@@ -624,11 +1129,11 @@ pub struct InputSelectedReply {
     pub length: u32,
 }
 impl TryParse for InputSelectedReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (enabled, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (enabled, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = InputSelectedReply { response_type, enabled, sequence, length };
         Ok((result, remaining))
     }
@@ -639,18 +1144,78 @@ impl TryFrom<&[u8]> for InputSelectedReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for InputSelectedReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.enabled.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetRectangles request
 pub const GET_RECTANGLES_REQUEST: u8 = 8;
-pub fn get_rectangles<Conn>(conn: &Conn, window: xproto::Window, source_kind: SK) -> Result<Cookie<'_, Conn, GetRectanglesReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetRectanglesRequest {
+    pub window: xproto::Window,
+    pub source_kind: SK,
+}
+impl GetRectanglesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_RECTANGLES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (source_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let source_kind = SK::from(source_kind);
+        Ok(GetRectanglesRequest {
+            window,
+            source_kind,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `source_kind` field of this structure.
+    pub fn source_kind<I>(mut self, value: I) -> Self where I: Into<SK> {
+        self.source_kind = value.into();
+        self
+    }
+}
+
+pub fn get_rectangles<Conn, A>(conn: &Conn, window: xproto::Window, source_kind: A) -> Result<Cookie<'_, Conn, GetRectanglesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<SK>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let source_kind: SK = source_kind.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let source_kind_bytes = Kind::from(source_kind).serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let source_kind_bytes = u8::from(source_kind).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_RECTANGLES_REQUEST,
@@ -668,9 +1233,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetRectanglesRequest {
+    type Reply = GetRectanglesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetRectanglesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetRectanglesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_rectangles(conn, self.window, self.source_kind)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetRectanglesReply {
@@ -681,15 +1258,15 @@ pub struct GetRectanglesReply {
     pub rectangles: Vec<xproto::Rectangle>,
 }
 impl TryParse for GetRectanglesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (ordering, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (rectangles_len, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ordering, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (rectangles_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (rectangles, remaining) = crate::x11_utils::parse_list::<xproto::Rectangle>(remaining, rectangles_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let ordering = ordering.try_into()?;
+        let (rectangles, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Rectangle>(remaining, rectangles_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let ordering = xproto::ClipOrdering::from(ordering);
         let result = GetRectanglesReply { response_type, ordering, sequence, length, rectangles };
         Ok((result, remaining))
     }
@@ -700,6 +1277,32 @@ impl TryFrom<&[u8]> for GetRectanglesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetRectanglesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        u8::from(self.ordering).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let rectangles_len = u32::try_from(self.rectangles.len()).expect("`rectangles` has too many elements");
+        rectangles_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.rectangles.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -707,21 +1310,33 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_version(self)
     }
-    fn shape_rectangles<'c>(&'c self, operation: SO, destination_kind: SK, ordering: xproto::ClipOrdering, destination_window: xproto::Window, x_offset: i16, y_offset: i16, rectangles: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn shape_rectangles<'c, A, B, C>(&'c self, operation: A, destination_kind: B, ordering: C, destination_window: xproto::Window, x_offset: i16, y_offset: i16, rectangles: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<SO>,
+        B: Into<SK>,
+        C: Into<xproto::ClipOrdering>,
     {
         self::rectangles(self, operation, destination_kind, ordering, destination_window, x_offset, y_offset, rectangles)
     }
-    fn shape_mask<A>(&self, operation: SO, destination_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_bitmap: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn shape_mask<A, B, C>(&self, operation: A, destination_kind: B, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_bitmap: C) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<xproto::Pixmap>,
+        A: Into<SO>,
+        B: Into<SK>,
+        C: Into<xproto::Pixmap>,
     {
         mask(self, operation, destination_kind, destination_window, x_offset, y_offset, source_bitmap)
     }
-    fn shape_combine(&self, operation: SO, destination_kind: SK, source_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn shape_combine<A, B, C>(&self, operation: A, destination_kind: B, source_kind: C, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<SO>,
+        B: Into<SK>,
+        C: Into<SK>,
     {
         combine(self, operation, destination_kind, source_kind, destination_window, x_offset, y_offset, source_window)
     }
-    fn shape_offset(&self, destination_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn shape_offset<A>(&self, destination_kind: A, destination_window: xproto::Window, x_offset: i16, y_offset: i16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<SK>,
     {
         offset(self, destination_kind, destination_window, x_offset, y_offset)
     }
@@ -737,7 +1352,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         input_selected(self, destination_window)
     }
-    fn shape_get_rectangles(&self, window: xproto::Window, source_kind: SK) -> Result<Cookie<'_, Self, GetRectanglesReply>, ConnectionError>
+    fn shape_get_rectangles<A>(&self, window: xproto::Window, source_kind: A) -> Result<Cookie<'_, Self, GetRectanglesReply>, ConnectionError>
+    where
+        A: Into<SK>,
     {
         get_rectangles(self, window, source_kind)
     }
@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -31,7 +31,37 @@ pub const X11_EXTENSION_NAME: &str = "MIT-SHM";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 2);
 
-pub type Seg = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Seg(u32);
+impl Seg {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Seg(0);
+}
+impl From<u32> for Seg {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Seg> for u32 {
+    fn from(value: Seg) -> Self {
+        value.0
+    }
+}
+impl TryParse for Seg {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Seg {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
 /// Opcode for the Completion event
 pub const COMPLETION_EVENT: u8 = 0;
@@ -46,16 +76,16 @@ pub struct CompletionEvent {
     pub offset: u32,
 }
 impl TryParse for CompletionEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (minor_event, remaining) = u16::try_parse(remaining)?;
-        let (major_event, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (minor_event, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (major_event, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (shmseg, remaining) = Seg::try_parse(remaining)?;
-        let (offset, remaining) = u32::try_parse(remaining)?;
+        let (shmseg, remaining) = Seg::try_parse_with_order(remaining, order)?;
+        let (offset, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = CompletionEvent { response_type, sequence, drawable, minor_event, major_event, shmseg, offset };
         Ok((result, remaining))
     }
@@ -68,13 +98,24 @@ impl TryFrom<&[u8]> for CompletionEvent {
 }
 impl From<&CompletionEvent> for [u8; 32] {
     fn from(input: &CompletionEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let drawable_bytes = input.drawable.serialize();
-        let minor_event_bytes = input.minor_event.serialize();
-        let major_event_bytes = input.major_event.serialize();
-        let shmseg_bytes = input.shmseg.serialize();
-        let offset_bytes = input.offset.serialize();
+        input.serialize()
+    }
+}
+impl From<CompletionEvent> for [u8; 32] {
+    fn from(input: CompletionEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for CompletionEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let minor_event_bytes = self.minor_event.serialize_with_order(order);
+        let major_event_bytes = self.major_event.serialize_with_order(order);
+        let shmseg_bytes = self.shmseg.serialize_with_order(order);
+        let offset_bytes = self.offset.serialize_with_order(order);
         [
             response_type_bytes[0],
             0,
@@ -111,10 +152,8 @@ impl From<&CompletionEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<CompletionEvent> for [u8; 32] {
-    fn from(input: CompletionEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -124,12 +163,30 @@ pub type BadSegError = xproto::ValueError;
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+        })
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -140,9 +197,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -157,16 +226,16 @@ pub struct QueryVersionReply {
     pub pixmap_format: u8,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (shared_pixmaps, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
-        let (uid, remaining) = u16::try_parse(remaining)?;
-        let (gid, remaining) = u16::try_parse(remaining)?;
-        let (pixmap_format, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (shared_pixmaps, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (uid, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (gid, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pixmap_format, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(15..).ok_or(ParseError::ParseError)?;
         let result = QueryVersionReply { response_type, shared_pixmaps, sequence, length, major_version, minor_version, uid, gid, pixmap_format };
         Ok((result, remaining))
@@ -178,19 +247,90 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.shared_pixmaps.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        self.uid.serialize_into_with_order(bytes, order);
+        self.gid.serialize_into_with_order(bytes, order);
+        self.pixmap_format.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 15]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Attach request
 pub const ATTACH_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AttachRequest {
+    pub shmseg: Seg,
+    pub shmid: u32,
+    pub read_only: bool,
+}
+impl AttachRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ATTACH_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (shmseg, remaining) = Seg::try_parse_with_order(remaining, order)?;
+        let (shmid, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (read_only, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(AttachRequest {
+            shmseg,
+            shmid,
+            read_only,
+        })
+    }
+    /// Set the `shmseg` field of this structure.
+    pub fn shmseg<I>(mut self, value: I) -> Self where I: Into<Seg> {
+        self.shmseg = value.into();
+        self
+    }
+    /// Set the `shmid` field of this structure.
+    pub fn shmid(mut self, value: u32) -> Self {
+        self.shmid = value;
+        self
+    }
+    /// Set the `read_only` field of this structure.
+    pub fn read_only(mut self, value: bool) -> Self {
+        self.read_only = value;
+        self
+    }
+}
+
 pub fn attach<Conn>(conn: &Conn, shmseg: Seg, shmid: u32, read_only: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let shmseg_bytes = shmseg.serialize();
-    let shmid_bytes = shmid.serialize();
-    let read_only_bytes = read_only.serialize();
+    let shmseg_bytes = shmseg.serialize_with_order(order);
+    let shmid_bytes = shmid.serialize_with_order(order);
+    let read_only_bytes = read_only.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ATTACH_REQUEST,
@@ -212,20 +352,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for AttachRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AttachRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        attach(conn, self.shmseg, self.shmid, self.read_only)
+    }
+}
 
 /// Opcode for the Detach request
 pub const DETACH_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DetachRequest {
+    pub shmseg: Seg,
+}
+impl DetachRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DETACH_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (shmseg, remaining) = Seg::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DetachRequest {
+            shmseg,
+        })
+    }
+    /// Set the `shmseg` field of this structure.
+    pub fn shmseg<I>(mut self, value: I) -> Self where I: Into<Seg> {
+        self.shmseg = value.into();
+        self
+    }
+}
+
 pub fn detach<Conn>(conn: &Conn, shmseg: Seg) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let shmseg_bytes = shmseg.serialize();
+    let shmseg_bytes = shmseg.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DETACH_REQUEST,
@@ -239,34 +417,187 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DetachRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DetachRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        detach(conn, self.shmseg)
+    }
+}
 
 /// Opcode for the PutImage request
 pub const PUT_IMAGE_REQUEST: u8 = 3;
-pub fn put_image<Conn>(conn: &Conn, drawable: xproto::Drawable, gc: xproto::Gcontext, total_width: u16, total_height: u16, src_x: u16, src_y: u16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16, depth: u8, format: u8, send_event: bool, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PutImageRequest {
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub total_width: u16,
+    pub total_height: u16,
+    pub src_x: u16,
+    pub src_y: u16,
+    pub src_width: u16,
+    pub src_height: u16,
+    pub dst_x: i16,
+    pub dst_y: i16,
+    pub depth: u8,
+    pub format: u8,
+    pub send_event: bool,
+    pub shmseg: Seg,
+    pub offset: u32,
+}
+impl PutImageRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PUT_IMAGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (total_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (total_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (src_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (src_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (dst_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (dst_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (send_event, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (shmseg, remaining) = Seg::try_parse_with_order(remaining, order)?;
+        let (offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PutImageRequest {
+            drawable,
+            gc,
+            total_width,
+            total_height,
+            src_x,
+            src_y,
+            src_width,
+            src_height,
+            dst_x,
+            dst_y,
+            depth,
+            format,
+            send_event,
+            shmseg,
+            offset,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `gc` field of this structure.
+    pub fn gc<I>(mut self, value: I) -> Self where I: Into<xproto::Gcontext> {
+        self.gc = value.into();
+        self
+    }
+    /// Set the `total_width` field of this structure.
+    pub fn total_width(mut self, value: u16) -> Self {
+        self.total_width = value;
+        self
+    }
+    /// Set the `total_height` field of this structure.
+    pub fn total_height(mut self, value: u16) -> Self {
+        self.total_height = value;
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: u16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: u16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `src_width` field of this structure.
+    pub fn src_width(mut self, value: u16) -> Self {
+        self.src_width = value;
+        self
+    }
+    /// Set the `src_height` field of this structure.
+    pub fn src_height(mut self, value: u16) -> Self {
+        self.src_height = value;
+        self
+    }
+    /// Set the `dst_x` field of this structure.
+    pub fn dst_x(mut self, value: i16) -> Self {
+        self.dst_x = value;
+        self
+    }
+    /// Set the `dst_y` field of this structure.
+    pub fn dst_y(mut self, value: i16) -> Self {
+        self.dst_y = value;
+        self
+    }
+    /// Set the `depth` field of this structure.
+    pub fn depth(mut self, value: u8) -> Self {
+        self.depth = value;
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u8) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `send_event` field of this structure.
+    pub fn send_event(mut self, value: bool) -> Self {
+        self.send_event = value;
+        self
+    }
+    /// Set the `shmseg` field of this structure.
+    pub fn shmseg<I>(mut self, value: I) -> Self where I: Into<Seg> {
+        self.shmseg = value.into();
+        self
+    }
+    /// Set the `offset` field of this structure.
+    pub fn offset(mut self, value: u32) -> Self {
+        self.offset = value;
+        self
+    }
+}
+
+pub fn put_image<Conn, A>(conn: &Conn, drawable: A, gc: xproto::Gcontext, total_width: u16, total_height: u16, src_x: u16, src_y: u16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16, depth: u8, format: u8, send_event: bool, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let gc_bytes = gc.serialize();
-    let total_width_bytes = total_width.serialize();
-    let total_height_bytes = total_height.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
-    let src_width_bytes = src_width.serialize();
-    let src_height_bytes = src_height.serialize();
-    let dst_x_bytes = dst_x.serialize();
-    let dst_y_bytes = dst_y.serialize();
-    let depth_bytes = depth.serialize();
-    let format_bytes = format.serialize();
-    let send_event_bytes = send_event.serialize();
-    let shmseg_bytes = shmseg.serialize();
-    let offset_bytes = offset.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let gc_bytes = gc.serialize_with_order(order);
+    let total_width_bytes = total_width.serialize_with_order(order);
+    let total_height_bytes = total_height.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
+    let src_width_bytes = src_width.serialize_with_order(order);
+    let src_height_bytes = src_height.serialize_with_order(order);
+    let dst_x_bytes = dst_x.serialize_with_order(order);
+    let dst_y_bytes = dst_y.serialize_with_order(order);
+    let depth_bytes = depth.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let send_event_bytes = send_event.serialize_with_order(order);
+    let shmseg_bytes = shmseg.serialize_with_order(order);
+    let offset_bytes = offset.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PUT_IMAGE_REQUEST,
@@ -312,28 +643,133 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PutImageRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PutImageRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        put_image(conn, self.drawable, self.gc, self.total_width, self.total_height, self.src_x, self.src_y, self.src_width, self.src_height, self.dst_x, self.dst_y, self.depth, self.format, self.send_event, self.shmseg, self.offset)
+    }
+}
 
 /// Opcode for the GetImage request
 pub const GET_IMAGE_REQUEST: u8 = 4;
-pub fn get_image<Conn>(conn: &Conn, drawable: xproto::Drawable, x: i16, y: i16, width: u16, height: u16, plane_mask: u32, format: u8, shmseg: Seg, offset: u32) -> Result<Cookie<'_, Conn, GetImageReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetImageRequest {
+    pub drawable: xproto::Drawable,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub plane_mask: u32,
+    pub format: u8,
+    pub shmseg: Seg,
+    pub offset: u32,
+}
+impl GetImageRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_IMAGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (plane_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (shmseg, remaining) = Seg::try_parse_with_order(remaining, order)?;
+        let (offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetImageRequest {
+            drawable,
+            x,
+            y,
+            width,
+            height,
+            plane_mask,
+            format,
+            shmseg,
+            offset,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `x` field of this structure.
+    pub fn x(mut self, value: i16) -> Self {
+        self.x = value;
+        self
+    }
+    /// Set the `y` field of this structure.
+    pub fn y(mut self, value: i16) -> Self {
+        self.y = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `plane_mask` field of this structure.
+    pub fn plane_mask(mut self, value: u32) -> Self {
+        self.plane_mask = value;
+        self
+    }
+    /// Set the `format` field of this structure.
+    pub fn format(mut self, value: u8) -> Self {
+        self.format = value;
+        self
+    }
+    /// Set the `shmseg` field of this structure.
+    pub fn shmseg<I>(mut self, value: I) -> Self where I: Into<Seg> {
+        self.shmseg = value.into();
+        self
+    }
+    /// Set the `offset` field of this structure.
+    pub fn offset(mut self, value: u32) -> Self {
+        self.offset = value;
+        self
+    }
+}
+
+pub fn get_image<Conn, A>(conn: &Conn, drawable: A, x: i16, y: i16, width: u16, height: u16, plane_mask: u32, format: u8, shmseg: Seg, offset: u32) -> Result<Cookie<'_, Conn, GetImageReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let x_bytes = x.serialize();
-    let y_bytes = y.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let plane_mask_bytes = plane_mask.serialize();
-    let format_bytes = format.serialize();
-    let shmseg_bytes = shmseg.serialize();
-    let offset_bytes = offset.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let x_bytes = x.serialize_with_order(order);
+    let y_bytes = y.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let plane_mask_bytes = plane_mask.serialize_with_order(order);
+    let format_bytes = format.serialize_with_order(order);
+    let shmseg_bytes = shmseg.serialize_with_order(order);
+    let offset_bytes = offset.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_IMAGE_REQUEST,
@@ -371,9 +807,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetImageRequest {
+    type Reply = GetImageReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetImageRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetImageReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_image(conn, self.drawable, self.x, self.y, self.width, self.height, self.plane_mask, self.format, self.shmseg, self.offset)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetImageReply {
@@ -385,13 +833,13 @@ pub struct GetImageReply {
     pub size: u32,
 }
 impl TryParse for GetImageReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (size, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = GetImageReply { response_type, depth, sequence, length, visual, size };
         Ok((result, remaining))
     }
@@ -402,23 +850,124 @@ impl TryFrom<&[u8]> for GetImageReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetImageReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.visual.serialize_into_with_order(bytes, order);
+        self.size.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreatePixmap request
 pub const CREATE_PIXMAP_REQUEST: u8 = 5;
-pub fn create_pixmap<Conn>(conn: &Conn, pid: xproto::Pixmap, drawable: xproto::Drawable, width: u16, height: u16, depth: u8, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreatePixmapRequest {
+    pub pid: xproto::Pixmap,
+    pub drawable: xproto::Drawable,
+    pub width: u16,
+    pub height: u16,
+    pub depth: u8,
+    pub shmseg: Seg,
+    pub offset: u32,
+}
+impl CreatePixmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_PIXMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (pid, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (shmseg, remaining) = Seg::try_parse_with_order(remaining, order)?;
+        let (offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreatePixmapRequest {
+            pid,
+            drawable,
+            width,
+            height,
+            depth,
+            shmseg,
+            offset,
+        })
+    }
+    /// Set the `pid` field of this structure.
+    pub fn pid<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.pid = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `depth` field of this structure.
+    pub fn depth(mut self, value: u8) -> Self {
+        self.depth = value;
+        self
+    }
+    /// Set the `shmseg` field of this structure.
+    pub fn shmseg<I>(mut self, value: I) -> Self where I: Into<Seg> {
+        self.shmseg = value.into();
+        self
+    }
+    /// Set the `offset` field of this structure.
+    pub fn offset(mut self, value: u32) -> Self {
+        self.offset = value;
+        self
+    }
+}
+
+pub fn create_pixmap<Conn, A>(conn: &Conn, pid: xproto::Pixmap, drawable: A, width: u16, height: u16, depth: u8, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let pid_bytes = pid.serialize();
-    let drawable_bytes = drawable.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let depth_bytes = depth.serialize();
-    let shmseg_bytes = shmseg.serialize();
-    let offset_bytes = offset.serialize();
+    let pid_bytes = pid.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let depth_bytes = depth.serialize_with_order(order);
+    let shmseg_bytes = shmseg.serialize_with_order(order);
+    let offset_bytes = offset.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_PIXMAP_REQUEST,
@@ -452,12 +1001,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreatePixmapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreatePixmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_pixmap(conn, self.pid, self.drawable, self.width, self.height, self.depth, self.shmseg, self.offset)
+    }
+}
 
 /// Opcode for the AttachFd request
 pub const ATTACH_FD_REQUEST: u8 = 6;
+#[derive(Debug, PartialEq, Eq)]
+pub struct AttachFdRequest {
+    pub shmseg: Seg,
+    pub shm_fd: RawFdContainer,
+    pub read_only: bool,
+}
+impl AttachFdRequest {
+    /// Parse this request given its header, its body, any fds that go along with it, and the byte order of the client
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ATTACH_FD_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (shmseg, remaining) = Seg::try_parse_with_order(remaining, order)?;
+        if fds.is_empty() { return Err(ParseError::ParseError) }
+        let shm_fd = fds.remove(0);
+        let (read_only, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(AttachFdRequest {
+            shmseg,
+            shm_fd,
+            read_only,
+        })
+    }
+    /// Set the `shmseg` field of this structure.
+    pub fn shmseg<I>(mut self, value: I) -> Self where I: Into<Seg> {
+        self.shmseg = value.into();
+        self
+    }
+    /// Set the `shm_fd` field of this structure.
+    pub fn shm_fd<I>(mut self, value: I) -> Self where I: Into<RawFdContainer> {
+        self.shm_fd = value.into();
+        self
+    }
+    /// Set the `read_only` field of this structure.
+    pub fn read_only(mut self, value: bool) -> Self {
+        self.read_only = value;
+        self
+    }
+}
+
 pub fn attach_fd<Conn, A>(conn: &Conn, shmseg: Seg, shm_fd: A, read_only: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -466,9 +1070,10 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let shm_fd: RawFdContainer = shm_fd.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let shmseg_bytes = shmseg.serialize();
-    let read_only_bytes = read_only.serialize();
+    let shmseg_bytes = shmseg.serialize_with_order(order);
+    let read_only_bytes = read_only.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ATTACH_FD_REQUEST,
@@ -486,22 +1091,77 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![shm_fd])?)
 }
+impl crate::x11_utils::Request for AttachFdRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AttachFdRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        attach_fd(conn, self.shmseg, self.shm_fd, self.read_only)
+    }
+}
 
 /// Opcode for the CreateSegment request
 pub const CREATE_SEGMENT_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateSegmentRequest {
+    pub shmseg: Seg,
+    pub size: u32,
+    pub read_only: bool,
+}
+impl CreateSegmentRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_SEGMENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (shmseg, remaining) = Seg::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (read_only, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateSegmentRequest {
+            shmseg,
+            size,
+            read_only,
+        })
+    }
+    /// Set the `shmseg` field of this structure.
+    pub fn shmseg<I>(mut self, value: I) -> Self where I: Into<Seg> {
+        self.shmseg = value.into();
+        self
+    }
+    /// Set the `size` field of this structure.
+    pub fn size(mut self, value: u32) -> Self {
+        self.size = value;
+        self
+    }
+    /// Set the `read_only` field of this structure.
+    pub fn read_only(mut self, value: bool) -> Self {
+        self.read_only = value;
+        self
+    }
+}
+
 pub fn create_segment<Conn>(conn: &Conn, shmseg: Seg, size: u32, read_only: bool) -> Result<CookieWithFds<'_, Conn, CreateSegmentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let shmseg_bytes = shmseg.serialize();
-    let size_bytes = size.serialize();
-    let read_only_bytes = read_only.serialize();
+    let shmseg_bytes = shmseg.serialize_with_order(order);
+    let size_bytes = size.serialize_with_order(order);
+    let read_only_bytes = read_only.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_SEGMENT_REQUEST,
@@ -523,9 +1183,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply_with_fds(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateSegmentRequest {
+    type Reply = CreateSegmentReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateSegmentRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = CookieWithFds<'c, Conn, CreateSegmentReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_segment(conn, self.shmseg, self.size, self.read_only)
+    }
+}
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct CreateSegmentReply {
@@ -535,12 +1207,12 @@ pub struct CreateSegmentReply {
     pub length: u32,
     pub shm_fd: RawFdContainer,
 }
-impl CreateSegmentReply {
-    fn try_parse_fd<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+impl TryParseFd for CreateSegmentReply {
+    fn try_parse_fd_with_order<'a>(remaining: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         if fds.is_empty() { return Err(ParseError::ParseError) }
         let shm_fd = fds.remove(0);
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
@@ -555,6 +1227,29 @@ impl TryFrom<(&[u8], Vec<RawFdContainer>)> for CreateSegmentReply {
         Ok(Self::try_parse_fd(value, &mut fds)?.0)
     }
 }
+impl Serialize for CreateSegmentReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -570,15 +1265,21 @@ pub trait ConnectionExt: RequestConnection {
     {
         detach(self, shmseg)
     }
-    fn shm_put_image(&self, drawable: xproto::Drawable, gc: xproto::Gcontext, total_width: u16, total_height: u16, src_x: u16, src_y: u16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16, depth: u8, format: u8, send_event: bool, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn shm_put_image<A>(&self, drawable: A, gc: xproto::Gcontext, total_width: u16, total_height: u16, src_x: u16, src_y: u16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16, depth: u8, format: u8, send_event: bool, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         put_image(self, drawable, gc, total_width, total_height, src_x, src_y, src_width, src_height, dst_x, dst_y, depth, format, send_event, shmseg, offset)
     }
-    fn shm_get_image(&self, drawable: xproto::Drawable, x: i16, y: i16, width: u16, height: u16, plane_mask: u32, format: u8, shmseg: Seg, offset: u32) -> Result<Cookie<'_, Self, GetImageReply>, ConnectionError>
+    fn shm_get_image<A>(&self, drawable: A, x: i16, y: i16, width: u16, height: u16, plane_mask: u32, format: u8, shmseg: Seg, offset: u32) -> Result<Cookie<'_, Self, GetImageReply>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         get_image(self, drawable, x, y, width, height, plane_mask, format, shmseg, offset)
     }
-    fn shm_create_pixmap(&self, pid: xproto::Pixmap, drawable: xproto::Drawable, width: u16, height: u16, depth: u8, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn shm_create_pixmap<A>(&self, pid: xproto::Pixmap, drawable: A, width: u16, height: u16, depth: u8, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         create_pixmap(self, pid, drawable, width, height, depth, shmseg, offset)
     }
@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -31,289 +31,189 @@ pub const X11_EXTENSION_NAME: &str = "SYNC";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (3, 1);
 
-pub type Alarm = u32;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ALARMSTATE {
-    Active = 0,
-    Inactive = 1,
-    Destroyed = 2,
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Alarm(u32);
+impl Alarm {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Alarm(0);
 }
-impl From<ALARMSTATE> for u8 {
-    fn from(input: ALARMSTATE) -> Self {
-        match input {
-            ALARMSTATE::Active => 0,
-            ALARMSTATE::Inactive => 1,
-            ALARMSTATE::Destroyed => 2,
-        }
+impl From<u32> for Alarm {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Alarm> for u32 {
+    fn from(value: Alarm) -> Self {
+        value.0
+    }
+}
+impl TryParse for Alarm {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
     }
 }
+impl Serialize for Alarm {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ALARMSTATE(u8);
+#[allow(non_upper_case_globals)]
+impl ALARMSTATE {
+    pub const Active: Self = Self(0);
+    pub const Inactive: Self = Self(1);
+    pub const Destroyed: Self = Self(2);
+}
+enum_newtype!(ALARMSTATE, u8, [Active, Inactive, Destroyed]);
 impl From<ALARMSTATE> for Option<u8> {
     fn from(input: ALARMSTATE) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ALARMSTATE> for u16 {
     fn from(input: ALARMSTATE) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ALARMSTATE> for Option<u16> {
     fn from(input: ALARMSTATE) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ALARMSTATE> for u32 {
     fn from(input: ALARMSTATE) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ALARMSTATE> for Option<u32> {
     fn from(input: ALARMSTATE) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ALARMSTATE {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(ALARMSTATE::Active),
-            1 => Ok(ALARMSTATE::Inactive),
-            2 => Ok(ALARMSTATE::Destroyed),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ALARMSTATE {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ALARMSTATE {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-pub type Counter = u32;
-
-pub type Fence = u32;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum TESTTYPE {
-    PositiveTransition = 0,
-    NegativeTransition = 1,
-    PositiveComparison = 2,
-    NegativeComparison = 3,
-}
-impl From<TESTTYPE> for u8 {
-    fn from(input: TESTTYPE) -> Self {
-        match input {
-            TESTTYPE::PositiveTransition => 0,
-            TESTTYPE::NegativeTransition => 1,
-            TESTTYPE::PositiveComparison => 2,
-            TESTTYPE::NegativeComparison => 3,
-        }
-    }
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Counter(u32);
+impl Counter {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Counter(0);
 }
-impl From<TESTTYPE> for Option<u8> {
-    fn from(input: TESTTYPE) -> Self {
-        Some(u8::from(input))
+impl From<u32> for Counter {
+    fn from(value: u32) -> Self {
+        Self(value)
     }
 }
-impl From<TESTTYPE> for u16 {
-    fn from(input: TESTTYPE) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<TESTTYPE> for Option<u16> {
-    fn from(input: TESTTYPE) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<TESTTYPE> for u32 {
-    fn from(input: TESTTYPE) -> Self {
-        Self::from(u8::from(input))
+impl From<Counter> for u32 {
+    fn from(value: Counter) -> Self {
+        value.0
     }
 }
-impl From<TESTTYPE> for Option<u32> {
-    fn from(input: TESTTYPE) -> Self {
-        Some(u32::from(input))
+impl TryParse for Counter {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
     }
 }
-impl TryFrom<u8> for TESTTYPE {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(TESTTYPE::PositiveTransition),
-            1 => Ok(TESTTYPE::NegativeTransition),
-            2 => Ok(TESTTYPE::PositiveComparison),
-            3 => Ok(TESTTYPE::NegativeComparison),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for TESTTYPE {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl Serialize for Counter {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
     }
-}
-impl TryFrom<u32> for TESTTYPE {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum VALUETYPE {
-    Absolute = 0,
-    Relative = 1,
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fence(u32);
+impl Fence {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Fence(0);
 }
-impl From<VALUETYPE> for bool {
-    fn from(input: VALUETYPE) -> Self {
-        match input {
-            VALUETYPE::Absolute => false,
-            VALUETYPE::Relative => true,
-        }
+impl From<u32> for Fence {
+    fn from(value: u32) -> Self {
+        Self(value)
     }
 }
-impl From<VALUETYPE> for u8 {
-    fn from(input: VALUETYPE) -> Self {
-        match input {
-            VALUETYPE::Absolute => 0,
-            VALUETYPE::Relative => 1,
-        }
+impl From<Fence> for u32 {
+    fn from(value: Fence) -> Self {
+        value.0
     }
 }
-impl From<VALUETYPE> for Option<u8> {
-    fn from(input: VALUETYPE) -> Self {
-        Some(u8::from(input))
+impl TryParse for Fence {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
     }
 }
-impl From<VALUETYPE> for u16 {
-    fn from(input: VALUETYPE) -> Self {
-        Self::from(u8::from(input))
+impl Serialize for Fence {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
     }
 }
-impl From<VALUETYPE> for Option<u16> {
-    fn from(input: VALUETYPE) -> Self {
-        Some(u16::from(input))
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TESTTYPE(u32);
+#[allow(non_upper_case_globals)]
+impl TESTTYPE {
+    pub const PositiveTransition: Self = Self(0);
+    pub const NegativeTransition: Self = Self(1);
+    pub const PositiveComparison: Self = Self(2);
+    pub const NegativeComparison: Self = Self(3);
+}
+enum_newtype!(TESTTYPE, u32, [PositiveTransition, NegativeTransition, PositiveComparison, NegativeComparison]);
+impl From<TESTTYPE> for Option<u32> {
+    fn from(input: TESTTYPE) -> Self {
+        Some(input.0)
     }
 }
-impl From<VALUETYPE> for u32 {
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct VALUETYPE(u32);
+#[allow(non_upper_case_globals)]
+impl VALUETYPE {
+    pub const Absolute: Self = Self(0);
+    pub const Relative: Self = Self(1);
+}
+enum_newtype!(VALUETYPE, u32, [Absolute, Relative]);
+impl From<VALUETYPE> for bool {
     fn from(input: VALUETYPE) -> Self {
-        Self::from(u8::from(input))
+        input.0 != 0
     }
 }
 impl From<VALUETYPE> for Option<u32> {
     fn from(input: VALUETYPE) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for VALUETYPE {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(VALUETYPE::Absolute),
-            1 => Ok(VALUETYPE::Relative),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for VALUETYPE {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for VALUETYPE {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum CA {
-    Counter = 1 << 0,
-    ValueType = 1 << 1,
-    Value = 1 << 2,
-    TestType = 1 << 3,
-    Delta = 1 << 4,
-    Events = 1 << 5,
-}
-impl From<CA> for u8 {
-    fn from(input: CA) -> Self {
-        match input {
-            CA::Counter => 1 << 0,
-            CA::ValueType => 1 << 1,
-            CA::Value => 1 << 2,
-            CA::TestType => 1 << 3,
-            CA::Delta => 1 << 4,
-            CA::Events => 1 << 5,
-        }
-    }
-}
-impl From<CA> for Option<u8> {
-    fn from(input: CA) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<CA> for u16 {
-    fn from(input: CA) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<CA> for Option<u16> {
-    fn from(input: CA) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<CA> for u32 {
-    fn from(input: CA) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CA(u32);
+#[allow(non_upper_case_globals)]
+impl CA {
+    pub const Counter: Self = Self(1 << 0);
+    pub const ValueType: Self = Self(1 << 1);
+    pub const Value: Self = Self(1 << 2);
+    pub const TestType: Self = Self(1 << 3);
+    pub const Delta: Self = Self(1 << 4);
+    pub const Events: Self = Self(1 << 5);
+}
+bitmask_set!(CA, u32, [Counter, ValueType, Value, TestType, Delta, Events]);
 impl From<CA> for Option<u32> {
     fn from(input: CA) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for CA {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(CA::Counter),
-            2 => Ok(CA::ValueType),
-            4 => Ok(CA::Value),
-            8 => Ok(CA::TestType),
-            16 => Ok(CA::Delta),
-            32 => Ok(CA::Events),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for CA {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-impl TryFrom<u32> for CA {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(CA, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Int64 {
@@ -321,9 +221,9 @@ pub struct Int64 {
     pub lo: u32,
 }
 impl TryParse for Int64 {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (hi, remaining) = i32::try_parse(remaining)?;
-        let (lo, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (hi, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (lo, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = Int64 { hi, lo };
         Ok((result, remaining))
     }
@@ -336,9 +236,9 @@ impl TryFrom<&[u8]> for Int64 {
 }
 impl Serialize for Int64 {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let hi_bytes = self.hi.serialize();
-        let lo_bytes = self.lo.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let hi_bytes = self.hi.serialize_with_order(order);
+        let lo_bytes = self.lo.serialize_with_order(order);
         [
             hi_bytes[0],
             hi_bytes[1],
@@ -350,10 +250,10 @@ impl Serialize for Int64 {
             lo_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.hi.serialize_into(bytes);
-        self.lo.serialize_into(bytes);
+        self.hi.serialize_into_with_order(bytes, order);
+        self.lo.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -364,11 +264,11 @@ pub struct Systemcounter {
     pub name: Vec<u8>,
 }
 impl TryParse for Systemcounter {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (counter, remaining) = Counter::try_parse(remaining)?;
-        let (resolution, remaining) = Int64::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let (resolution, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
         // Align offset to multiple of 4
@@ -387,17 +287,17 @@ impl TryFrom<&[u8]> for Systemcounter {
 }
 impl Serialize for Systemcounter {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(14);
-        self.counter.serialize_into(bytes);
-        self.resolution.serialize_into(bytes);
+        self.counter.serialize_into_with_order(bytes, order);
+        self.resolution.serialize_into_with_order(bytes, order);
         let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
-        name_len.serialize_into(bytes);
+        name_len.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.name);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
@@ -411,13 +311,13 @@ pub struct Trigger {
     pub test_type: TESTTYPE,
 }
 impl TryParse for Trigger {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (counter, remaining) = Counter::try_parse(remaining)?;
-        let (wait_type, remaining) = u32::try_parse(remaining)?;
-        let (wait_value, remaining) = Int64::try_parse(remaining)?;
-        let (test_type, remaining) = u32::try_parse(remaining)?;
-        let wait_type = wait_type.try_into()?;
-        let test_type = test_type.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let (wait_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (wait_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (test_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let wait_type = VALUETYPE::from(wait_type);
+        let test_type = TESTTYPE::from(test_type);
         let result = Trigger { counter, wait_type, wait_value, test_type };
         Ok((result, remaining))
     }
@@ -430,11 +330,11 @@ impl TryFrom<&[u8]> for Trigger {
 }
 impl Serialize for Trigger {
     type Bytes = [u8; 20];
-    fn serialize(&self) -> [u8; 20] {
-        let counter_bytes = self.counter.serialize();
-        let wait_type_bytes = u32::from(self.wait_type).serialize();
-        let wait_value_bytes = self.wait_value.serialize();
-        let test_type_bytes = u32::from(self.test_type).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 20] {
+        let counter_bytes = self.counter.serialize_with_order(order);
+        let wait_type_bytes = u32::from(self.wait_type).serialize_with_order(order);
+        let wait_value_bytes = self.wait_value.serialize_with_order(order);
+        let test_type_bytes = u32::from(self.test_type).serialize_with_order(order);
         [
             counter_bytes[0],
             counter_bytes[1],
@@ -458,12 +358,12 @@ impl Serialize for Trigger {
             test_type_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(20);
-        self.counter.serialize_into(bytes);
-        u32::from(self.wait_type).serialize_into(bytes);
-        self.wait_value.serialize_into(bytes);
-        u32::from(self.test_type).serialize_into(bytes);
+        self.counter.serialize_into_with_order(bytes, order);
+        u32::from(self.wait_type).serialize_into_with_order(bytes, order);
+        self.wait_value.serialize_into_with_order(bytes, order);
+        u32::from(self.test_type).serialize_into_with_order(bytes, order);
     }
 }
 
@@ -473,9 +373,9 @@ pub struct Waitcondition {
     pub event_threshold: Int64,
 }
 impl TryParse for Waitcondition {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (trigger, remaining) = Trigger::try_parse(remaining)?;
-        let (event_threshold, remaining) = Int64::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (trigger, remaining) = Trigger::try_parse_with_order(remaining, order)?;
+        let (event_threshold, remaining) = Int64::try_parse_with_order(remaining, order)?;
         let result = Waitcondition { trigger, event_threshold };
         Ok((result, remaining))
     }
@@ -488,9 +388,9 @@ impl TryFrom<&[u8]> for Waitcondition {
 }
 impl Serialize for Waitcondition {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let trigger_bytes = self.trigger.serialize();
-        let event_threshold_bytes = self.event_threshold.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let trigger_bytes = self.trigger.serialize_with_order(order);
+        let event_threshold_bytes = self.event_threshold.serialize_with_order(order);
         [
             trigger_bytes[0],
             trigger_bytes[1],
@@ -522,10 +422,10 @@ impl Serialize for Waitcondition {
             event_threshold_bytes[7],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        self.trigger.serialize_into(bytes);
-        self.event_threshold.serialize_into(bytes);
+        self.trigger.serialize_into_with_order(bytes, order);
+        self.event_threshold.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -541,13 +441,13 @@ pub struct CounterError {
     pub major_opcode: u8,
 }
 impl TryParse for CounterError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (bad_counter, remaining) = u32::try_parse(remaining)?;
-        let (minor_opcode, remaining) = u16::try_parse(remaining)?;
-        let (major_opcode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (bad_counter, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_opcode, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (major_opcode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let result = CounterError { response_type, error_code, sequence, bad_counter, minor_opcode, major_opcode };
         Ok((result, remaining))
     }
@@ -560,12 +460,23 @@ impl TryFrom<&[u8]> for CounterError {
 }
 impl From<&CounterError> for [u8; 32] {
     fn from(input: &CounterError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let bad_counter_bytes = input.bad_counter.serialize();
-        let minor_opcode_bytes = input.minor_opcode.serialize();
-        let major_opcode_bytes = input.major_opcode.serialize();
+        input.serialize()
+    }
+}
+impl From<CounterError> for [u8; 32] {
+    fn from(input: CounterError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for CounterError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let bad_counter_bytes = self.bad_counter.serialize_with_order(order);
+        let minor_opcode_bytes = self.minor_opcode.serialize_with_order(order);
+        let major_opcode_bytes = self.major_opcode.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -602,10 +513,8 @@ impl From<&CounterError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<CounterError> for [u8; 32] {
-    fn from(input: CounterError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -621,13 +530,13 @@ pub struct AlarmError {
     pub major_opcode: u8,
 }
 impl TryParse for AlarmError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (bad_alarm, remaining) = u32::try_parse(remaining)?;
-        let (minor_opcode, remaining) = u16::try_parse(remaining)?;
-        let (major_opcode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (bad_alarm, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_opcode, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (major_opcode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let result = AlarmError { response_type, error_code, sequence, bad_alarm, minor_opcode, major_opcode };
         Ok((result, remaining))
     }
@@ -640,12 +549,23 @@ impl TryFrom<&[u8]> for AlarmError {
 }
 impl From<&AlarmError> for [u8; 32] {
     fn from(input: &AlarmError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let bad_alarm_bytes = input.bad_alarm.serialize();
-        let minor_opcode_bytes = input.minor_opcode.serialize();
-        let major_opcode_bytes = input.major_opcode.serialize();
+        input.serialize()
+    }
+}
+impl From<AlarmError> for [u8; 32] {
+    fn from(input: AlarmError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for AlarmError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let bad_alarm_bytes = self.bad_alarm.serialize_with_order(order);
+        let minor_opcode_bytes = self.minor_opcode.serialize_with_order(order);
+        let major_opcode_bytes = self.major_opcode.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -682,24 +602,56 @@ impl From<&AlarmError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<AlarmError> for [u8; 32] {
-    fn from(input: AlarmError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
 /// Opcode for the Initialize request
 pub const INITIALIZE_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InitializeRequest {
+    pub desired_major_version: u8,
+    pub desired_minor_version: u8,
+}
+impl InitializeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INITIALIZE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (desired_major_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (desired_minor_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(InitializeRequest {
+            desired_major_version,
+            desired_minor_version,
+        })
+    }
+    /// Set the `desired_major_version` field of this structure.
+    pub fn desired_major_version(mut self, value: u8) -> Self {
+        self.desired_major_version = value;
+        self
+    }
+    /// Set the `desired_minor_version` field of this structure.
+    pub fn desired_minor_version(mut self, value: u8) -> Self {
+        self.desired_minor_version = value;
+        self
+    }
+}
+
 pub fn initialize<Conn>(conn: &Conn, desired_major_version: u8, desired_minor_version: u8) -> Result<Cookie<'_, Conn, InitializeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let desired_major_version_bytes = desired_major_version.serialize();
-    let desired_minor_version_bytes = desired_minor_version.serialize();
+    let desired_major_version_bytes = desired_major_version.serialize_with_order(order);
+    let desired_minor_version_bytes = desired_minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         INITIALIZE_REQUEST,
@@ -713,9 +665,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for InitializeRequest {
+    type Reply = InitializeReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for InitializeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, InitializeReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        initialize(conn, self.desired_major_version, self.desired_minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InitializeReply {
@@ -726,13 +690,13 @@ pub struct InitializeReply {
     pub minor_version: u8,
 }
 impl TryParse for InitializeReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u8::try_parse(remaining)?;
-        let (minor_version, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
         let result = InitializeReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
@@ -744,15 +708,58 @@ impl TryFrom<&[u8]> for InitializeReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for InitializeReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ListSystemCounters request
 pub const LIST_SYSTEM_COUNTERS_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListSystemCountersRequest {
+}
+impl ListSystemCountersRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_SYSTEM_COUNTERS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(ListSystemCountersRequest {
+        })
+    }
+}
+
 pub fn list_system_counters<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, ListSystemCountersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -763,9 +770,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ListSystemCountersRequest {
+    type Reply = ListSystemCountersReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ListSystemCountersRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ListSystemCountersReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        list_system_counters(conn)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListSystemCountersReply {
@@ -775,14 +794,14 @@ pub struct ListSystemCountersReply {
     pub counters: Vec<Systemcounter>,
 }
 impl TryParse for ListSystemCountersReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (counters_len, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (counters_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (counters, remaining) = crate::x11_utils::parse_list::<Systemcounter>(remaining, counters_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let (counters, remaining) = crate::x11_utils::parse_list_with_order::<Systemcounter>(remaining, counters_len.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ListSystemCountersReply { response_type, sequence, length, counters };
         Ok((result, remaining))
     }
@@ -793,18 +812,78 @@ impl TryFrom<&[u8]> for ListSystemCountersReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ListSystemCountersReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let counters_len = u32::try_from(self.counters.len()).expect("`counters` has too many elements");
+        counters_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.counters.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreateCounter request
 pub const CREATE_COUNTER_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateCounterRequest {
+    pub id: Counter,
+    pub initial_value: Int64,
+}
+impl CreateCounterRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_COUNTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (id, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let (initial_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateCounterRequest {
+            id,
+            initial_value,
+        })
+    }
+    /// Set the `id` field of this structure.
+    pub fn id<I>(mut self, value: I) -> Self where I: Into<Counter> {
+        self.id = value.into();
+        self
+    }
+    /// Set the `initial_value` field of this structure.
+    pub fn initial_value<I>(mut self, value: I) -> Self where I: Into<Int64> {
+        self.initial_value = value.into();
+        self
+    }
+}
+
 pub fn create_counter<Conn>(conn: &Conn, id: Counter, initial_value: Int64) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let id_bytes = id.serialize();
-    let initial_value_bytes = initial_value.serialize();
+    let id_bytes = id.serialize_with_order(order);
+    let initial_value_bytes = initial_value.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_COUNTER_REQUEST,
@@ -826,20 +905,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateCounterRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateCounterRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_counter(conn, self.id, self.initial_value)
+    }
+}
 
 /// Opcode for the DestroyCounter request
 pub const DESTROY_COUNTER_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyCounterRequest {
+    pub counter: Counter,
+}
+impl DestroyCounterRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_COUNTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyCounterRequest {
+            counter,
+        })
+    }
+    /// Set the `counter` field of this structure.
+    pub fn counter<I>(mut self, value: I) -> Self where I: Into<Counter> {
+        self.counter = value.into();
+        self
+    }
+}
+
 pub fn destroy_counter<Conn>(conn: &Conn, counter: Counter) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let counter_bytes = counter.serialize();
+    let counter_bytes = counter.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_COUNTER_REQUEST,
@@ -853,20 +970,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyCounterRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyCounterRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_counter(conn, self.counter)
+    }
+}
 
 /// Opcode for the QueryCounter request
 pub const QUERY_COUNTER_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryCounterRequest {
+    pub counter: Counter,
+}
+impl QueryCounterRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_COUNTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryCounterRequest {
+            counter,
+        })
+    }
+    /// Set the `counter` field of this structure.
+    pub fn counter<I>(mut self, value: I) -> Self where I: Into<Counter> {
+        self.counter = value.into();
+        self
+    }
+}
+
 pub fn query_counter<Conn>(conn: &Conn, counter: Counter) -> Result<Cookie<'_, Conn, QueryCounterReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let counter_bytes = counter.serialize();
+    let counter_bytes = counter.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_COUNTER_REQUEST,
@@ -880,9 +1035,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryCounterRequest {
+    type Reply = QueryCounterReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryCounterRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryCounterReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_counter(conn, self.counter)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryCounterReply {
@@ -892,12 +1059,12 @@ pub struct QueryCounterReply {
     pub counter_value: Int64,
 }
 impl TryParse for QueryCounterReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (counter_value, remaining) = Int64::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (counter_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
         let result = QueryCounterReply { response_type, sequence, length, counter_value };
         Ok((result, remaining))
     }
@@ -908,15 +1075,71 @@ impl TryFrom<&[u8]> for QueryCounterReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryCounterReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.counter_value.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Await request
 pub const AWAIT_REQUEST: u8 = 7;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AwaitRequest {
+    pub wait_list: Vec<Waitcondition>,
+}
+impl AwaitRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != AWAIT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut wait_list = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Waitcondition::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            wait_list.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(AwaitRequest {
+            wait_list,
+        })
+    }
+    /// Set the `wait_list` field of this structure.
+    pub fn wait_list<I>(mut self, value: I) -> Self where I: Into<Vec<Waitcondition>> {
+        self.wait_list = value.into();
+        self
+    }
+}
+
 pub fn await_<'c, Conn>(conn: &'c Conn, wait_list: &[Waitcondition]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -925,27 +1148,73 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let wait_list_bytes = wait_list.serialize();
+    let wait_list_bytes = wait_list.serialize_with_order(order);
     let length_so_far = length_so_far + wait_list_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&wait_list_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for AwaitRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AwaitRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        await_(conn, &self.wait_list)
+    }
+}
 
 /// Opcode for the ChangeCounter request
 pub const CHANGE_COUNTER_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeCounterRequest {
+    pub counter: Counter,
+    pub amount: Int64,
+}
+impl ChangeCounterRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_COUNTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let (amount, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangeCounterRequest {
+            counter,
+            amount,
+        })
+    }
+    /// Set the `counter` field of this structure.
+    pub fn counter<I>(mut self, value: I) -> Self where I: Into<Counter> {
+        self.counter = value.into();
+        self
+    }
+    /// Set the `amount` field of this structure.
+    pub fn amount<I>(mut self, value: I) -> Self where I: Into<Int64> {
+        self.amount = value.into();
+        self
+    }
+}
+
 pub fn change_counter<Conn>(conn: &Conn, counter: Counter, amount: Int64) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let counter_bytes = counter.serialize();
-    let amount_bytes = amount.serialize();
+    let counter_bytes = counter.serialize_with_order(order);
+    let amount_bytes = amount.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_COUNTER_REQUEST,
@@ -967,21 +1236,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeCounterRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeCounterRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_counter(conn, self.counter, self.amount)
+    }
+}
 
 /// Opcode for the SetCounter request
 pub const SET_COUNTER_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetCounterRequest {
+    pub counter: Counter,
+    pub value: Int64,
+}
+impl SetCounterRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_COUNTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetCounterRequest {
+            counter,
+            value,
+        })
+    }
+    /// Set the `counter` field of this structure.
+    pub fn counter<I>(mut self, value: I) -> Self where I: Into<Counter> {
+        self.counter = value.into();
+        self
+    }
+    /// Set the `value` field of this structure.
+    pub fn value<I>(mut self, value: I) -> Self where I: Into<Int64> {
+        self.value = value.into();
+        self
+    }
+}
+
 pub fn set_counter<Conn>(conn: &Conn, counter: Counter, value: Int64) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let counter_bytes = counter.serialize();
-    let value_bytes = value.serialize();
+    let counter_bytes = counter.serialize_with_order(order);
+    let value_bytes = value.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_COUNTER_REQUEST,
@@ -1003,9 +1318,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetCounterRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetCounterRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_counter(conn, self.counter, self.value)
+    }
+}
 
 /// Opcode for the CreateAlarm request
 pub const CREATE_ALARM_REQUEST: u8 = 8;
@@ -1019,31 +1346,89 @@ pub struct CreateAlarmAux {
     pub delta: Option<Int64>,
     pub events: Option<u32>,
 }
+impl CreateAlarmAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, value_mask: CA) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(value_mask);
+        let mut outer_remaining = value;
+        let counter = if switch_expr & u32::from(CA::Counter) != 0 {
+            let remaining = outer_remaining;
+            let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(counter)
+        } else {
+            None
+        };
+        let value_type = if switch_expr & u32::from(CA::ValueType) != 0 {
+            let remaining = outer_remaining;
+            let (value_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let value_type = VALUETYPE::from(value_type);
+            outer_remaining = remaining;
+            Some(value_type)
+        } else {
+            None
+        };
+        let value = if switch_expr & u32::from(CA::Value) != 0 {
+            let remaining = outer_remaining;
+            let (value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(value)
+        } else {
+            None
+        };
+        let test_type = if switch_expr & u32::from(CA::TestType) != 0 {
+            let remaining = outer_remaining;
+            let (test_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let test_type = TESTTYPE::from(test_type);
+            outer_remaining = remaining;
+            Some(test_type)
+        } else {
+            None
+        };
+        let delta = if switch_expr & u32::from(CA::Delta) != 0 {
+            let remaining = outer_remaining;
+            let (delta, remaining) = Int64::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(delta)
+        } else {
+            None
+        };
+        let events = if switch_expr & u32::from(CA::Events) != 0 {
+            let remaining = outer_remaining;
+            let (events, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(events)
+        } else {
+            None
+        };
+        let result = CreateAlarmAux { counter, value_type, value, test_type, delta, events };
+        Ok((result, outer_remaining))
+    }
+}
 #[allow(dead_code, unused_variables)]
 impl CreateAlarmAux {
-    fn serialize(&self, value_mask: u32) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, value_mask: CA) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, value_mask);
+        self.serialize_into_with_order(&mut result, order, value_mask);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, value_mask: u32) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, value_mask: CA) {
         if let Some(counter) = self.counter {
-            counter.serialize_into(bytes);
+            counter.serialize_into_with_order(bytes, order);
         }
         if let Some(value_type) = self.value_type {
-            u32::from(value_type).serialize_into(bytes);
+            u32::from(value_type).serialize_into_with_order(bytes, order);
         }
         if let Some(ref value) = self.value {
-            value.serialize_into(bytes);
+            value.serialize_into_with_order(bytes, order);
         }
         if let Some(test_type) = self.test_type {
-            u32::from(test_type).serialize_into(bytes);
+            u32::from(test_type).serialize_into_with_order(bytes, order);
         }
         if let Some(ref delta) = self.delta {
-            delta.serialize_into(bytes);
+            delta.serialize_into_with_order(bytes, order);
         }
         if let Some(events) = self.events {
-            events.serialize_into(bytes);
+            events.serialize_into_with_order(bytes, order);
         }
     }
 }
@@ -1108,16 +1493,51 @@ impl CreateAlarmAux {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateAlarmRequest {
+    pub id: Alarm,
+    pub value_list: CreateAlarmAux,
+}
+impl CreateAlarmRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_ALARM_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (id, remaining) = Alarm::try_parse_with_order(remaining, order)?;
+        let (value_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_list, remaining) = CreateAlarmAux::try_parse_with_order(remaining, order, CA::from(value_mask))?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateAlarmRequest {
+            id,
+            value_list,
+        })
+    }
+    /// Set the `id` field of this structure.
+    pub fn id<I>(mut self, value: I) -> Self where I: Into<Alarm> {
+        self.id = value.into();
+        self
+    }
+    /// Set the `value_list` field of this structure.
+    pub fn value_list<I>(mut self, value: I) -> Self where I: Into<CreateAlarmAux> {
+        self.value_list = value.into();
+        self
+    }
+}
+
 pub fn create_alarm<'c, Conn>(conn: &'c Conn, id: Alarm, value_list: &CreateAlarmAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let id_bytes = id.serialize();
+    let id_bytes = id.serialize_with_order(order);
     let value_mask = u32::try_from(value_list.switch_expr()).unwrap();
-    let value_mask_bytes = value_mask.serialize();
+    let value_mask_bytes = value_mask.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_ALARM_REQUEST,
@@ -1133,15 +1553,27 @@ where
         value_mask_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let value_list_bytes = value_list.serialize(value_mask);
+    let value_list_bytes = value_list.serialize_with_order(order, CA::from(value_mask));
     let length_so_far = length_so_far + value_list_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&value_list_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateAlarmRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateAlarmRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_alarm(conn, self.id, &self.value_list)
+    }
+}
 
 /// Opcode for the ChangeAlarm request
 pub const CHANGE_ALARM_REQUEST: u8 = 9;
@@ -1155,31 +1587,89 @@ pub struct ChangeAlarmAux {
     pub delta: Option<Int64>,
     pub events: Option<u32>,
 }
+impl ChangeAlarmAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, value_mask: CA) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(value_mask);
+        let mut outer_remaining = value;
+        let counter = if switch_expr & u32::from(CA::Counter) != 0 {
+            let remaining = outer_remaining;
+            let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(counter)
+        } else {
+            None
+        };
+        let value_type = if switch_expr & u32::from(CA::ValueType) != 0 {
+            let remaining = outer_remaining;
+            let (value_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let value_type = VALUETYPE::from(value_type);
+            outer_remaining = remaining;
+            Some(value_type)
+        } else {
+            None
+        };
+        let value = if switch_expr & u32::from(CA::Value) != 0 {
+            let remaining = outer_remaining;
+            let (value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(value)
+        } else {
+            None
+        };
+        let test_type = if switch_expr & u32::from(CA::TestType) != 0 {
+            let remaining = outer_remaining;
+            let (test_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let test_type = TESTTYPE::from(test_type);
+            outer_remaining = remaining;
+            Some(test_type)
+        } else {
+            None
+        };
+        let delta = if switch_expr & u32::from(CA::Delta) != 0 {
+            let remaining = outer_remaining;
+            let (delta, remaining) = Int64::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(delta)
+        } else {
+            None
+        };
+        let events = if switch_expr & u32::from(CA::Events) != 0 {
+            let remaining = outer_remaining;
+            let (events, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(events)
+        } else {
+            None
+        };
+        let result = ChangeAlarmAux { counter, value_type, value, test_type, delta, events };
+        Ok((result, outer_remaining))
+    }
+}
 #[allow(dead_code, unused_variables)]
 impl ChangeAlarmAux {
-    fn serialize(&self, value_mask: u32) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, value_mask: CA) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, value_mask);
+        self.serialize_into_with_order(&mut result, order, value_mask);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, value_mask: u32) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, value_mask: CA) {
         if let Some(counter) = self.counter {
-            counter.serialize_into(bytes);
+            counter.serialize_into_with_order(bytes, order);
         }
         if let Some(value_type) = self.value_type {
-            u32::from(value_type).serialize_into(bytes);
+            u32::from(value_type).serialize_into_with_order(bytes, order);
         }
         if let Some(ref value) = self.value {
-            value.serialize_into(bytes);
+            value.serialize_into_with_order(bytes, order);
         }
         if let Some(test_type) = self.test_type {
-            u32::from(test_type).serialize_into(bytes);
+            u32::from(test_type).serialize_into_with_order(bytes, order);
         }
         if let Some(ref delta) = self.delta {
-            delta.serialize_into(bytes);
+            delta.serialize_into_with_order(bytes, order);
         }
         if let Some(events) = self.events {
-            events.serialize_into(bytes);
+            events.serialize_into_with_order(bytes, order);
         }
     }
 }
@@ -1244,16 +1734,51 @@ impl ChangeAlarmAux {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChangeAlarmRequest {
+    pub id: Alarm,
+    pub value_list: ChangeAlarmAux,
+}
+impl ChangeAlarmRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_ALARM_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (id, remaining) = Alarm::try_parse_with_order(remaining, order)?;
+        let (value_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_list, remaining) = ChangeAlarmAux::try_parse_with_order(remaining, order, CA::from(value_mask))?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangeAlarmRequest {
+            id,
+            value_list,
+        })
+    }
+    /// Set the `id` field of this structure.
+    pub fn id<I>(mut self, value: I) -> Self where I: Into<Alarm> {
+        self.id = value.into();
+        self
+    }
+    /// Set the `value_list` field of this structure.
+    pub fn value_list<I>(mut self, value: I) -> Self where I: Into<ChangeAlarmAux> {
+        self.value_list = value.into();
+        self
+    }
+}
+
 pub fn change_alarm<'c, Conn>(conn: &'c Conn, id: Alarm, value_list: &ChangeAlarmAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let id_bytes = id.serialize();
+    let id_bytes = id.serialize_with_order(order);
     let value_mask = u32::try_from(value_list.switch_expr()).unwrap();
-    let value_mask_bytes = value_mask.serialize();
+    let value_mask_bytes = value_mask.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_ALARM_REQUEST,
@@ -1269,26 +1794,64 @@ where
         value_mask_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let value_list_bytes = value_list.serialize(value_mask);
+    let value_list_bytes = value_list.serialize_with_order(order, CA::from(value_mask));
     let length_so_far = length_so_far + value_list_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&value_list_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeAlarmRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeAlarmRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_alarm(conn, self.id, &self.value_list)
+    }
+}
 
 /// Opcode for the DestroyAlarm request
 pub const DESTROY_ALARM_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyAlarmRequest {
+    pub alarm: Alarm,
+}
+impl DestroyAlarmRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_ALARM_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (alarm, remaining) = Alarm::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyAlarmRequest {
+            alarm,
+        })
+    }
+    /// Set the `alarm` field of this structure.
+    pub fn alarm<I>(mut self, value: I) -> Self where I: Into<Alarm> {
+        self.alarm = value.into();
+        self
+    }
+}
+
 pub fn destroy_alarm<Conn>(conn: &Conn, alarm: Alarm) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let alarm_bytes = alarm.serialize();
+    let alarm_bytes = alarm.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_ALARM_REQUEST,
@@ -1302,20 +1865,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyAlarmRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyAlarmRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_alarm(conn, self.alarm)
+    }
+}
 
 /// Opcode for the QueryAlarm request
 pub const QUERY_ALARM_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryAlarmRequest {
+    pub alarm: Alarm,
+}
+impl QueryAlarmRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_ALARM_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (alarm, remaining) = Alarm::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryAlarmRequest {
+            alarm,
+        })
+    }
+    /// Set the `alarm` field of this structure.
+    pub fn alarm<I>(mut self, value: I) -> Self where I: Into<Alarm> {
+        self.alarm = value.into();
+        self
+    }
+}
+
 pub fn query_alarm<Conn>(conn: &Conn, alarm: Alarm) -> Result<Cookie<'_, Conn, QueryAlarmReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let alarm_bytes = alarm.serialize();
+    let alarm_bytes = alarm.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_ALARM_REQUEST,
@@ -1329,9 +1930,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryAlarmRequest {
+    type Reply = QueryAlarmReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryAlarmRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryAlarmReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_alarm(conn, self.alarm)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryAlarmReply {
@@ -1344,17 +1957,17 @@ pub struct QueryAlarmReply {
     pub state: ALARMSTATE,
 }
 impl TryParse for QueryAlarmReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (trigger, remaining) = Trigger::try_parse(remaining)?;
-        let (delta, remaining) = Int64::try_parse(remaining)?;
-        let (events, remaining) = bool::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (trigger, remaining) = Trigger::try_parse_with_order(remaining, order)?;
+        let (delta, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (events, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let state = state.try_into()?;
+        let state = ALARMSTATE::from(state);
         let result = QueryAlarmReply { response_type, sequence, length, trigger, delta, events, state };
         Ok((result, remaining))
     }
@@ -1365,18 +1978,79 @@ impl TryFrom<&[u8]> for QueryAlarmReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryAlarmReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.trigger.serialize_into_with_order(bytes, order);
+        self.delta.serialize_into_with_order(bytes, order);
+        self.events.serialize_into_with_order(bytes, order);
+        u8::from(self.state).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetPriority request
 pub const SET_PRIORITY_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetPriorityRequest {
+    pub id: u32,
+    pub priority: i32,
+}
+impl SetPriorityRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PRIORITY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (priority, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetPriorityRequest {
+            id,
+            priority,
+        })
+    }
+    /// Set the `id` field of this structure.
+    pub fn id(mut self, value: u32) -> Self {
+        self.id = value;
+        self
+    }
+    /// Set the `priority` field of this structure.
+    pub fn priority(mut self, value: i32) -> Self {
+        self.priority = value;
+        self
+    }
+}
+
 pub fn set_priority<Conn>(conn: &Conn, id: u32, priority: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let id_bytes = id.serialize();
-    let priority_bytes = priority.serialize();
+    let id_bytes = id.serialize_with_order(order);
+    let priority_bytes = priority.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_PRIORITY_REQUEST,
@@ -1394,20 +2068,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetPriorityRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetPriorityRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_priority(conn, self.id, self.priority)
+    }
+}
 
 /// Opcode for the GetPriority request
 pub const GET_PRIORITY_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetPriorityRequest {
+    pub id: u32,
+}
+impl GetPriorityRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PRIORITY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetPriorityRequest {
+            id,
+        })
+    }
+    /// Set the `id` field of this structure.
+    pub fn id(mut self, value: u32) -> Self {
+        self.id = value;
+        self
+    }
+}
+
 pub fn get_priority<Conn>(conn: &Conn, id: u32) -> Result<Cookie<'_, Conn, GetPriorityReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let id_bytes = id.serialize();
+    let id_bytes = id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PRIORITY_REQUEST,
@@ -1421,9 +2133,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetPriorityRequest {
+    type Reply = GetPriorityReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetPriorityRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetPriorityReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_priority(conn, self.id)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPriorityReply {
@@ -1433,12 +2157,12 @@ pub struct GetPriorityReply {
     pub priority: i32,
 }
 impl TryParse for GetPriorityReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (priority, remaining) = i32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (priority, remaining) = i32::try_parse_with_order(remaining, order)?;
         let result = GetPriorityReply { response_type, sequence, length, priority };
         Ok((result, remaining))
     }
@@ -1449,19 +2173,86 @@ impl TryFrom<&[u8]> for GetPriorityReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetPriorityReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.priority.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreateFence request
 pub const CREATE_FENCE_REQUEST: u8 = 14;
-pub fn create_fence<Conn>(conn: &Conn, drawable: xproto::Drawable, fence: Fence, initially_triggered: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateFenceRequest {
+    pub drawable: xproto::Drawable,
+    pub fence: Fence,
+    pub initially_triggered: bool,
+}
+impl CreateFenceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_FENCE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (fence, remaining) = Fence::try_parse_with_order(remaining, order)?;
+        let (initially_triggered, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateFenceRequest {
+            drawable,
+            fence,
+            initially_triggered,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `fence` field of this structure.
+    pub fn fence<I>(mut self, value: I) -> Self where I: Into<Fence> {
+        self.fence = value.into();
+        self
+    }
+    /// Set the `initially_triggered` field of this structure.
+    pub fn initially_triggered(mut self, value: bool) -> Self {
+        self.initially_triggered = value;
+        self
+    }
+}
+
+pub fn create_fence<Conn, A>(conn: &Conn, drawable: A, fence: Fence, initially_triggered: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let fence_bytes = fence.serialize();
-    let initially_triggered_bytes = initially_triggered.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let fence_bytes = fence.serialize_with_order(order);
+    let initially_triggered_bytes = initially_triggered.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_FENCE_REQUEST,
@@ -1483,20 +2274,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateFenceRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateFenceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_fence(conn, self.drawable, self.fence, self.initially_triggered)
+    }
+}
 
 /// Opcode for the TriggerFence request
 pub const TRIGGER_FENCE_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TriggerFenceRequest {
+    pub fence: Fence,
+}
+impl TriggerFenceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != TRIGGER_FENCE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (fence, remaining) = Fence::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(TriggerFenceRequest {
+            fence,
+        })
+    }
+    /// Set the `fence` field of this structure.
+    pub fn fence<I>(mut self, value: I) -> Self where I: Into<Fence> {
+        self.fence = value.into();
+        self
+    }
+}
+
 pub fn trigger_fence<Conn>(conn: &Conn, fence: Fence) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let fence_bytes = fence.serialize();
+    let fence_bytes = fence.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         TRIGGER_FENCE_REQUEST,
@@ -1510,20 +2339,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for TriggerFenceRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for TriggerFenceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        trigger_fence(conn, self.fence)
+    }
+}
 
 /// Opcode for the ResetFence request
 pub const RESET_FENCE_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResetFenceRequest {
+    pub fence: Fence,
+}
+impl ResetFenceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RESET_FENCE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (fence, remaining) = Fence::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ResetFenceRequest {
+            fence,
+        })
+    }
+    /// Set the `fence` field of this structure.
+    pub fn fence<I>(mut self, value: I) -> Self where I: Into<Fence> {
+        self.fence = value.into();
+        self
+    }
+}
+
 pub fn reset_fence<Conn>(conn: &Conn, fence: Fence) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let fence_bytes = fence.serialize();
+    let fence_bytes = fence.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         RESET_FENCE_REQUEST,
@@ -1537,20 +2404,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ResetFenceRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ResetFenceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        reset_fence(conn, self.fence)
+    }
+}
 
 /// Opcode for the DestroyFence request
 pub const DESTROY_FENCE_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyFenceRequest {
+    pub fence: Fence,
+}
+impl DestroyFenceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_FENCE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (fence, remaining) = Fence::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyFenceRequest {
+            fence,
+        })
+    }
+    /// Set the `fence` field of this structure.
+    pub fn fence<I>(mut self, value: I) -> Self where I: Into<Fence> {
+        self.fence = value.into();
+        self
+    }
+}
+
 pub fn destroy_fence<Conn>(conn: &Conn, fence: Fence) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let fence_bytes = fence.serialize();
+    let fence_bytes = fence.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_FENCE_REQUEST,
@@ -1564,20 +2469,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyFenceRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyFenceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_fence(conn, self.fence)
+    }
+}
 
 /// Opcode for the QueryFence request
 pub const QUERY_FENCE_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryFenceRequest {
+    pub fence: Fence,
+}
+impl QueryFenceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_FENCE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (fence, remaining) = Fence::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryFenceRequest {
+            fence,
+        })
+    }
+    /// Set the `fence` field of this structure.
+    pub fn fence<I>(mut self, value: I) -> Self where I: Into<Fence> {
+        self.fence = value.into();
+        self
+    }
+}
+
 pub fn query_fence<Conn>(conn: &Conn, fence: Fence) -> Result<Cookie<'_, Conn, QueryFenceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let fence_bytes = fence.serialize();
+    let fence_bytes = fence.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_FENCE_REQUEST,
@@ -1591,9 +2534,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryFenceRequest {
+    type Reply = QueryFenceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryFenceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryFenceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_fence(conn, self.fence)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryFenceReply {
@@ -1603,12 +2558,12 @@ pub struct QueryFenceReply {
     pub triggered: bool,
 }
 impl TryParse for QueryFenceReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (triggered, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (triggered, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
         let result = QueryFenceReply { response_type, sequence, length, triggered };
         Ok((result, remaining))
@@ -1620,15 +2575,72 @@ impl TryFrom<&[u8]> for QueryFenceReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryFenceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.triggered.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the AwaitFence request
 pub const AWAIT_FENCE_REQUEST: u8 = 19;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AwaitFenceRequest {
+    pub fence_list: Vec<Fence>,
+}
+impl AwaitFenceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != AWAIT_FENCE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut fence_list = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Fence::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            fence_list.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(AwaitFenceRequest {
+            fence_list,
+        })
+    }
+    /// Set the `fence_list` field of this structure.
+    pub fn fence_list<I>(mut self, value: I) -> Self where I: Into<Vec<Fence>> {
+        self.fence_list = value.into();
+        self
+    }
+}
+
 pub fn await_fence<'c, Conn>(conn: &'c Conn, fence_list: &[Fence]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -1637,15 +2649,27 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let fence_list_bytes = fence_list.serialize();
+    let fence_list_bytes = fence_list.serialize_with_order(order);
     let length_so_far = length_so_far + fence_list_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&fence_list_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for AwaitFenceRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AwaitFenceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        await_fence(conn, &self.fence_list)
+    }
+}
 
 /// Opcode for the CounterNotify event
 pub const COUNTER_NOTIFY_EVENT: u8 = 0;
@@ -1662,16 +2686,16 @@ pub struct CounterNotifyEvent {
     pub destroyed: bool,
 }
 impl TryParse for CounterNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (counter, remaining) = Counter::try_parse(remaining)?;
-        let (wait_value, remaining) = Int64::try_parse(remaining)?;
-        let (counter_value, remaining) = Int64::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (count, remaining) = u16::try_parse(remaining)?;
-        let (destroyed, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let (wait_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (counter_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (destroyed, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let result = CounterNotifyEvent { response_type, kind, sequence, counter, wait_value, counter_value, timestamp, count, destroyed };
         Ok((result, remaining))
@@ -1685,15 +2709,26 @@ impl TryFrom<&[u8]> for CounterNotifyEvent {
 }
 impl From<&CounterNotifyEvent> for [u8; 32] {
     fn from(input: &CounterNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let kind_bytes = input.kind.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let counter_bytes = input.counter.serialize();
-        let wait_value_bytes = input.wait_value.serialize();
-        let counter_value_bytes = input.counter_value.serialize();
-        let timestamp_bytes = input.timestamp.serialize();
-        let count_bytes = input.count.serialize();
-        let destroyed_bytes = input.destroyed.serialize();
+        input.serialize()
+    }
+}
+impl From<CounterNotifyEvent> for [u8; 32] {
+    fn from(input: CounterNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for CounterNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let kind_bytes = self.kind.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let counter_bytes = self.counter.serialize_with_order(order);
+        let wait_value_bytes = self.wait_value.serialize_with_order(order);
+        let counter_value_bytes = self.counter_value.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let destroyed_bytes = self.destroyed.serialize_with_order(order);
         [
             response_type_bytes[0],
             kind_bytes[0],
@@ -1729,10 +2764,8 @@ impl From<&CounterNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<CounterNotifyEvent> for [u8; 32] {
-    fn from(input: CounterNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1750,17 +2783,17 @@ pub struct AlarmNotifyEvent {
     pub state: ALARMSTATE,
 }
 impl TryParse for AlarmNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (alarm, remaining) = Alarm::try_parse(remaining)?;
-        let (counter_value, remaining) = Int64::try_parse(remaining)?;
-        let (alarm_value, remaining) = Int64::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (alarm, remaining) = Alarm::try_parse_with_order(remaining, order)?;
+        let (counter_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (alarm_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let state = state.try_into()?;
+        let state = ALARMSTATE::from(state);
         let result = AlarmNotifyEvent { response_type, kind, sequence, alarm, counter_value, alarm_value, timestamp, state };
         Ok((result, remaining))
     }
@@ -1773,14 +2806,25 @@ impl TryFrom<&[u8]> for AlarmNotifyEvent {
 }
 impl From<&AlarmNotifyEvent> for [u8; 32] {
     fn from(input: &AlarmNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let kind_bytes = input.kind.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let alarm_bytes = input.alarm.serialize();
-        let counter_value_bytes = input.counter_value.serialize();
-        let alarm_value_bytes = input.alarm_value.serialize();
-        let timestamp_bytes = input.timestamp.serialize();
-        let state_bytes = u8::from(input.state).serialize();
+        input.serialize()
+    }
+}
+impl From<AlarmNotifyEvent> for [u8; 32] {
+    fn from(input: AlarmNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for AlarmNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let kind_bytes = self.kind.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let alarm_bytes = self.alarm.serialize_with_order(order);
+        let counter_value_bytes = self.counter_value.serialize_with_order(order);
+        let alarm_value_bytes = self.alarm_value.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let state_bytes = u8::from(self.state).serialize_with_order(order);
         [
             response_type_bytes[0],
             kind_bytes[0],
@@ -1816,10 +2860,8 @@ impl From<&AlarmNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<AlarmNotifyEvent> for [u8; 32] {
-    fn from(input: AlarmNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1881,7 +2923,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         get_priority(self, id)
     }
-    fn sync_create_fence(&self, drawable: xproto::Drawable, fence: Fence, initially_triggered: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn sync_create_fence<A>(&self, drawable: A, fence: Fence, initially_triggered: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         create_fence(self, drawable, fence, initially_triggered)
     }
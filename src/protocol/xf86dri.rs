@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -38,11 +38,11 @@ pub struct DrmClipRect {
     pub x3: i16,
 }
 impl TryParse for DrmClipRect {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (x1, remaining) = i16::try_parse(remaining)?;
-        let (y1, remaining) = i16::try_parse(remaining)?;
-        let (x2, remaining) = i16::try_parse(remaining)?;
-        let (x3, remaining) = i16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (x1, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y1, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (x2, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (x3, remaining) = i16::try_parse_with_order(remaining, order)?;
         let result = DrmClipRect { x1, y1, x2, x3 };
         Ok((result, remaining))
     }
@@ -55,11 +55,11 @@ impl TryFrom<&[u8]> for DrmClipRect {
 }
 impl Serialize for DrmClipRect {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let x1_bytes = self.x1.serialize();
-        let y1_bytes = self.y1.serialize();
-        let x2_bytes = self.x2.serialize();
-        let x3_bytes = self.x3.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let x1_bytes = self.x1.serialize_with_order(order);
+        let y1_bytes = self.y1.serialize_with_order(order);
+        let x2_bytes = self.x2.serialize_with_order(order);
+        let x3_bytes = self.x3.serialize_with_order(order);
         [
             x1_bytes[0],
             x1_bytes[1],
@@ -71,23 +71,41 @@ impl Serialize for DrmClipRect {
             x3_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.x1.serialize_into(bytes);
-        self.y1.serialize_into(bytes);
-        self.x2.serialize_into(bytes);
-        self.x3.serialize_into(bytes);
+        self.x1.serialize_into_with_order(bytes, order);
+        self.y1.serialize_into_with_order(bytes, order);
+        self.x2.serialize_into_with_order(bytes, order);
+        self.x3.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+        })
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -98,9 +116,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -112,14 +142,14 @@ pub struct QueryVersionReply {
     pub dri_minor_patch: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (dri_major_version, remaining) = u16::try_parse(remaining)?;
-        let (dri_minor_version, remaining) = u16::try_parse(remaining)?;
-        let (dri_minor_patch, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (dri_major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (dri_minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (dri_minor_patch, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = QueryVersionReply { response_type, sequence, length, dri_major_version, dri_minor_version, dri_minor_patch };
         Ok((result, remaining))
     }
@@ -130,17 +160,68 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.dri_major_version.serialize_into_with_order(bytes, order);
+        self.dri_minor_version.serialize_into_with_order(bytes, order);
+        self.dri_minor_patch.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryDirectRenderingCapable request
 pub const QUERY_DIRECT_RENDERING_CAPABLE_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryDirectRenderingCapableRequest {
+    pub screen: u32,
+}
+impl QueryDirectRenderingCapableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_DIRECT_RENDERING_CAPABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryDirectRenderingCapableRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn query_direct_rendering_capable<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, QueryDirectRenderingCapableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_DIRECT_RENDERING_CAPABLE_REQUEST,
@@ -154,9 +235,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryDirectRenderingCapableRequest {
+    type Reply = QueryDirectRenderingCapableReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryDirectRenderingCapableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryDirectRenderingCapableReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_direct_rendering_capable(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryDirectRenderingCapableReply {
@@ -166,12 +259,12 @@ pub struct QueryDirectRenderingCapableReply {
     pub is_capable: bool,
 }
 impl TryParse for QueryDirectRenderingCapableReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (is_capable, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (is_capable, remaining) = bool::try_parse_with_order(remaining, order)?;
         let result = QueryDirectRenderingCapableReply { response_type, sequence, length, is_capable };
         Ok((result, remaining))
     }
@@ -182,17 +275,66 @@ impl TryFrom<&[u8]> for QueryDirectRenderingCapableReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryDirectRenderingCapableReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.is_capable.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the OpenConnection request
 pub const OPEN_CONNECTION_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OpenConnectionRequest {
+    pub screen: u32,
+}
+impl OpenConnectionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != OPEN_CONNECTION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(OpenConnectionRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn open_connection<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, OpenConnectionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         OPEN_CONNECTION_REQUEST,
@@ -206,9 +348,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for OpenConnectionRequest {
+    type Reply = OpenConnectionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for OpenConnectionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, OpenConnectionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        open_connection(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OpenConnectionReply {
@@ -220,14 +374,14 @@ pub struct OpenConnectionReply {
     pub bus_id: Vec<u8>,
 }
 impl TryParse for OpenConnectionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (sarea_handle_low, remaining) = u32::try_parse(remaining)?;
-        let (sarea_handle_high, remaining) = u32::try_parse(remaining)?;
-        let (bus_id_len, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sarea_handle_low, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sarea_handle_high, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (bus_id_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let (bus_id, remaining) = crate::x11_utils::parse_u8_list(remaining, bus_id_len.try_into().or(Err(ParseError::ParseError))?)?;
         let bus_id = bus_id.to_vec();
@@ -241,17 +395,71 @@ impl TryFrom<&[u8]> for OpenConnectionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for OpenConnectionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.sarea_handle_low.serialize_into_with_order(bytes, order);
+        self.sarea_handle_high.serialize_into_with_order(bytes, order);
+        let bus_id_len = u32::try_from(self.bus_id.len()).expect("`bus_id` has too many elements");
+        bus_id_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&self.bus_id);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CloseConnection request
 pub const CLOSE_CONNECTION_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CloseConnectionRequest {
+    pub screen: u32,
+}
+impl CloseConnectionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CLOSE_CONNECTION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CloseConnectionRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn close_connection<Conn>(conn: &Conn, screen: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CLOSE_CONNECTION_REQUEST,
@@ -265,20 +473,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CloseConnectionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CloseConnectionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        close_connection(conn, self.screen)
+    }
+}
 
 /// Opcode for the GetClientDriverName request
 pub const GET_CLIENT_DRIVER_NAME_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetClientDriverNameRequest {
+    pub screen: u32,
+}
+impl GetClientDriverNameRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CLIENT_DRIVER_NAME_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetClientDriverNameRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_client_driver_name<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, GetClientDriverNameReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CLIENT_DRIVER_NAME_REQUEST,
@@ -292,9 +538,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetClientDriverNameRequest {
+    type Reply = GetClientDriverNameReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetClientDriverNameRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetClientDriverNameReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_client_driver_name(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetClientDriverNameReply {
@@ -307,15 +565,15 @@ pub struct GetClientDriverNameReply {
     pub client_driver_name: Vec<u8>,
 }
 impl TryParse for GetClientDriverNameReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (client_driver_major_version, remaining) = u32::try_parse(remaining)?;
-        let (client_driver_minor_version, remaining) = u32::try_parse(remaining)?;
-        let (client_driver_patch_version, remaining) = u32::try_parse(remaining)?;
-        let (client_driver_name_len, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_driver_major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_driver_minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_driver_patch_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_driver_name_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
         let (client_driver_name, remaining) = crate::x11_utils::parse_u8_list(remaining, client_driver_name_len.try_into().or(Err(ParseError::ParseError))?)?;
         let client_driver_name = client_driver_name.to_vec();
@@ -329,19 +587,90 @@ impl TryFrom<&[u8]> for GetClientDriverNameReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetClientDriverNameReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.client_driver_major_version.serialize_into_with_order(bytes, order);
+        self.client_driver_minor_version.serialize_into_with_order(bytes, order);
+        self.client_driver_patch_version.serialize_into_with_order(bytes, order);
+        let client_driver_name_len = u32::try_from(self.client_driver_name.len()).expect("`client_driver_name` has too many elements");
+        client_driver_name_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&self.client_driver_name);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreateContext request
 pub const CREATE_CONTEXT_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateContextRequest {
+    pub screen: u32,
+    pub visual: u32,
+    pub context: u32,
+}
+impl CreateContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (visual, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (context, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateContextRequest {
+            screen,
+            visual,
+            context,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `visual` field of this structure.
+    pub fn visual(mut self, value: u32) -> Self {
+        self.visual = value;
+        self
+    }
+    /// Set the `context` field of this structure.
+    pub fn context(mut self, value: u32) -> Self {
+        self.context = value;
+        self
+    }
+}
+
 pub fn create_context<Conn>(conn: &Conn, screen: u32, visual: u32, context: u32) -> Result<Cookie<'_, Conn, CreateContextReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let visual_bytes = visual.serialize();
-    let context_bytes = context.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let visual_bytes = visual.serialize_with_order(order);
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_CONTEXT_REQUEST,
@@ -363,9 +692,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateContextRequest {
+    type Reply = CreateContextReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, CreateContextReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_context(conn, self.screen, self.visual, self.context)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateContextReply {
@@ -375,12 +716,12 @@ pub struct CreateContextReply {
     pub hw_context: u32,
 }
 impl TryParse for CreateContextReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (hw_context, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (hw_context, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = CreateContextReply { response_type, sequence, length, hw_context };
         Ok((result, remaining))
     }
@@ -391,18 +732,75 @@ impl TryFrom<&[u8]> for CreateContextReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CreateContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.hw_context.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DestroyContext request
 pub const DESTROY_CONTEXT_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyContextRequest {
+    pub screen: u32,
+    pub context: u32,
+}
+impl DestroyContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (context, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyContextRequest {
+            screen,
+            context,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `context` field of this structure.
+    pub fn context(mut self, value: u32) -> Self {
+        self.context = value;
+        self
+    }
+}
+
 pub fn destroy_context<Conn>(conn: &Conn, screen: u32, context: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let context_bytes = context.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_CONTEXT_REQUEST,
@@ -420,21 +818,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_context(conn, self.screen, self.context)
+    }
+}
 
 /// Opcode for the CreateDrawable request
 pub const CREATE_DRAWABLE_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateDrawableRequest {
+    pub screen: u32,
+    pub drawable: u32,
+}
+impl CreateDrawableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_DRAWABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateDrawableRequest {
+            screen,
+            drawable,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable(mut self, value: u32) -> Self {
+        self.drawable = value;
+        self
+    }
+}
+
 pub fn create_drawable<Conn>(conn: &Conn, screen: u32, drawable: u32) -> Result<Cookie<'_, Conn, CreateDrawableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let drawable_bytes = drawable.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_DRAWABLE_REQUEST,
@@ -452,9 +896,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateDrawableRequest {
+    type Reply = CreateDrawableReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateDrawableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, CreateDrawableReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_drawable(conn, self.screen, self.drawable)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateDrawableReply {
@@ -464,12 +920,12 @@ pub struct CreateDrawableReply {
     pub hw_drawable_handle: u32,
 }
 impl TryParse for CreateDrawableReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (hw_drawable_handle, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (hw_drawable_handle, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = CreateDrawableReply { response_type, sequence, length, hw_drawable_handle };
         Ok((result, remaining))
     }
@@ -480,18 +936,75 @@ impl TryFrom<&[u8]> for CreateDrawableReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CreateDrawableReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.hw_drawable_handle.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DestroyDrawable request
 pub const DESTROY_DRAWABLE_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyDrawableRequest {
+    pub screen: u32,
+    pub drawable: u32,
+}
+impl DestroyDrawableRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_DRAWABLE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyDrawableRequest {
+            screen,
+            drawable,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable(mut self, value: u32) -> Self {
+        self.drawable = value;
+        self
+    }
+}
+
 pub fn destroy_drawable<Conn>(conn: &Conn, screen: u32, drawable: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let drawable_bytes = drawable.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_DRAWABLE_REQUEST,
@@ -509,21 +1022,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyDrawableRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyDrawableRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_drawable(conn, self.screen, self.drawable)
+    }
+}
 
 /// Opcode for the GetDrawableInfo request
 pub const GET_DRAWABLE_INFO_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDrawableInfoRequest {
+    pub screen: u32,
+    pub drawable: u32,
+}
+impl GetDrawableInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DRAWABLE_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDrawableInfoRequest {
+            screen,
+            drawable,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable(mut self, value: u32) -> Self {
+        self.drawable = value;
+        self
+    }
+}
+
 pub fn get_drawable_info<Conn>(conn: &Conn, screen: u32, drawable: u32) -> Result<Cookie<'_, Conn, GetDrawableInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let drawable_bytes = drawable.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DRAWABLE_INFO_REQUEST,
@@ -541,9 +1100,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDrawableInfoRequest {
+    type Reply = GetDrawableInfoReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDrawableInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDrawableInfoReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_drawable_info(conn, self.screen, self.drawable)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDrawableInfoReply {
@@ -562,23 +1133,23 @@ pub struct GetDrawableInfoReply {
     pub back_clip_rects: Vec<DrmClipRect>,
 }
 impl TryParse for GetDrawableInfoReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (drawable_table_index, remaining) = u32::try_parse(remaining)?;
-        let (drawable_table_stamp, remaining) = u32::try_parse(remaining)?;
-        let (drawable_origin_x, remaining) = i16::try_parse(remaining)?;
-        let (drawable_origin_y, remaining) = i16::try_parse(remaining)?;
-        let (drawable_size_w, remaining) = i16::try_parse(remaining)?;
-        let (drawable_size_h, remaining) = i16::try_parse(remaining)?;
-        let (num_clip_rects, remaining) = u32::try_parse(remaining)?;
-        let (back_x, remaining) = i16::try_parse(remaining)?;
-        let (back_y, remaining) = i16::try_parse(remaining)?;
-        let (num_back_clip_rects, remaining) = u32::try_parse(remaining)?;
-        let (clip_rects, remaining) = crate::x11_utils::parse_list::<DrmClipRect>(remaining, num_clip_rects.try_into().or(Err(ParseError::ParseError))?)?;
-        let (back_clip_rects, remaining) = crate::x11_utils::parse_list::<DrmClipRect>(remaining, num_back_clip_rects.try_into().or(Err(ParseError::ParseError))?)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (drawable_table_index, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (drawable_table_stamp, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (drawable_origin_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drawable_origin_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drawable_size_w, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drawable_size_h, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (num_clip_rects, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (back_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (back_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (num_back_clip_rects, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (clip_rects, remaining) = crate::x11_utils::parse_list_with_order::<DrmClipRect>(remaining, num_clip_rects.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (back_clip_rects, remaining) = crate::x11_utils::parse_list_with_order::<DrmClipRect>(remaining, num_back_clip_rects.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetDrawableInfoReply { response_type, sequence, length, drawable_table_index, drawable_table_stamp, drawable_origin_x, drawable_origin_y, drawable_size_w, drawable_size_h, back_x, back_y, clip_rects, back_clip_rects };
         Ok((result, remaining))
     }
@@ -589,17 +1160,79 @@ impl TryFrom<&[u8]> for GetDrawableInfoReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDrawableInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.drawable_table_index.serialize_into_with_order(bytes, order);
+        self.drawable_table_stamp.serialize_into_with_order(bytes, order);
+        self.drawable_origin_x.serialize_into_with_order(bytes, order);
+        self.drawable_origin_y.serialize_into_with_order(bytes, order);
+        self.drawable_size_w.serialize_into_with_order(bytes, order);
+        self.drawable_size_h.serialize_into_with_order(bytes, order);
+        let num_clip_rects = u32::try_from(self.clip_rects.len()).expect("`clip_rects` has too many elements");
+        num_clip_rects.serialize_into_with_order(bytes, order);
+        self.back_x.serialize_into_with_order(bytes, order);
+        self.back_y.serialize_into_with_order(bytes, order);
+        let num_back_clip_rects = u32::try_from(self.back_clip_rects.len()).expect("`back_clip_rects` has too many elements");
+        num_back_clip_rects.serialize_into_with_order(bytes, order);
+        self.clip_rects.serialize_into_with_order(bytes, order);
+        self.back_clip_rects.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetDeviceInfo request
 pub const GET_DEVICE_INFO_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDeviceInfoRequest {
+    pub screen: u32,
+}
+impl GetDeviceInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDeviceInfoRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_device_info<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, GetDeviceInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_INFO_REQUEST,
@@ -613,9 +1246,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDeviceInfoRequest {
+    type Reply = GetDeviceInfoReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDeviceInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDeviceInfoReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_info(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceInfoReply {
@@ -630,18 +1275,18 @@ pub struct GetDeviceInfoReply {
     pub device_private: Vec<u32>,
 }
 impl TryParse for GetDeviceInfoReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_handle_low, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_handle_high, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_origin_offset, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_size, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_stride, remaining) = u32::try_parse(remaining)?;
-        let (device_private_size, remaining) = u32::try_parse(remaining)?;
-        let (device_private, remaining) = crate::x11_utils::parse_list::<u32>(remaining, device_private_size.try_into().or(Err(ParseError::ParseError))?)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (framebuffer_handle_low, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (framebuffer_handle_high, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (framebuffer_origin_offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (framebuffer_size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (framebuffer_stride, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (device_private_size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (device_private, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, device_private_size.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetDeviceInfoReply { response_type, sequence, length, framebuffer_handle_low, framebuffer_handle_high, framebuffer_origin_offset, framebuffer_size, framebuffer_stride, device_private };
         Ok((result, remaining))
     }
@@ -652,18 +1297,82 @@ impl TryFrom<&[u8]> for GetDeviceInfoReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDeviceInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.framebuffer_handle_low.serialize_into_with_order(bytes, order);
+        self.framebuffer_handle_high.serialize_into_with_order(bytes, order);
+        self.framebuffer_origin_offset.serialize_into_with_order(bytes, order);
+        self.framebuffer_size.serialize_into_with_order(bytes, order);
+        self.framebuffer_stride.serialize_into_with_order(bytes, order);
+        let device_private_size = u32::try_from(self.device_private.len()).expect("`device_private` has too many elements");
+        device_private_size.serialize_into_with_order(bytes, order);
+        self.device_private.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the AuthConnection request
 pub const AUTH_CONNECTION_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AuthConnectionRequest {
+    pub screen: u32,
+    pub magic: u32,
+}
+impl AuthConnectionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != AUTH_CONNECTION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (magic, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(AuthConnectionRequest {
+            screen,
+            magic,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `magic` field of this structure.
+    pub fn magic(mut self, value: u32) -> Self {
+        self.magic = value;
+        self
+    }
+}
+
 pub fn auth_connection<Conn>(conn: &Conn, screen: u32, magic: u32) -> Result<Cookie<'_, Conn, AuthConnectionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let magic_bytes = magic.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let magic_bytes = magic.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         AUTH_CONNECTION_REQUEST,
@@ -681,9 +1390,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for AuthConnectionRequest {
+    type Reply = AuthConnectionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AuthConnectionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, AuthConnectionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        auth_connection(conn, self.screen, self.magic)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AuthConnectionReply {
@@ -693,12 +1414,12 @@ pub struct AuthConnectionReply {
     pub authenticated: u32,
 }
 impl TryParse for AuthConnectionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (authenticated, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (authenticated, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = AuthConnectionReply { response_type, sequence, length, authenticated };
         Ok((result, remaining))
     }
@@ -709,6 +1430,29 @@ impl TryFrom<&[u8]> for AuthConnectionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for AuthConnectionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.authenticated.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
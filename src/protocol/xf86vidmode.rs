@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -34,208 +34,57 @@ pub type Syncrange = u32;
 
 pub type Dotclock = u32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum ModeFlag {
-    PositiveHSync = 1 << 0,
-    NegativeHSync = 1 << 1,
-    PositiveVSync = 1 << 2,
-    NegativeVSync = 1 << 3,
-    Interlace = 1 << 4,
-    CompositeSync = 1 << 5,
-    PositiveCSync = 1 << 6,
-    NegativeCSync = 1 << 7,
-    HSkew = 1 << 8,
-    Broadcast = 1 << 9,
-    Pixmux = 1 << 10,
-    DoubleClock = 1 << 11,
-    HalfClock = 1 << 12,
-}
-impl From<ModeFlag> for u16 {
-    fn from(input: ModeFlag) -> Self {
-        match input {
-            ModeFlag::PositiveHSync => 1 << 0,
-            ModeFlag::NegativeHSync => 1 << 1,
-            ModeFlag::PositiveVSync => 1 << 2,
-            ModeFlag::NegativeVSync => 1 << 3,
-            ModeFlag::Interlace => 1 << 4,
-            ModeFlag::CompositeSync => 1 << 5,
-            ModeFlag::PositiveCSync => 1 << 6,
-            ModeFlag::NegativeCSync => 1 << 7,
-            ModeFlag::HSkew => 1 << 8,
-            ModeFlag::Broadcast => 1 << 9,
-            ModeFlag::Pixmux => 1 << 10,
-            ModeFlag::DoubleClock => 1 << 11,
-            ModeFlag::HalfClock => 1 << 12,
-        }
-    }
-}
-impl From<ModeFlag> for Option<u16> {
-    fn from(input: ModeFlag) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<ModeFlag> for u32 {
-    fn from(input: ModeFlag) -> Self {
-        Self::from(u16::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModeFlag(u32);
+#[allow(non_upper_case_globals)]
+impl ModeFlag {
+    pub const PositiveHSync: Self = Self(1 << 0);
+    pub const NegativeHSync: Self = Self(1 << 1);
+    pub const PositiveVSync: Self = Self(1 << 2);
+    pub const NegativeVSync: Self = Self(1 << 3);
+    pub const Interlace: Self = Self(1 << 4);
+    pub const CompositeSync: Self = Self(1 << 5);
+    pub const PositiveCSync: Self = Self(1 << 6);
+    pub const NegativeCSync: Self = Self(1 << 7);
+    pub const HSkew: Self = Self(1 << 8);
+    pub const Broadcast: Self = Self(1 << 9);
+    pub const Pixmux: Self = Self(1 << 10);
+    pub const DoubleClock: Self = Self(1 << 11);
+    pub const HalfClock: Self = Self(1 << 12);
+}
+bitmask_set!(ModeFlag, u32, [PositiveHSync, NegativeHSync, PositiveVSync, NegativeVSync, Interlace, CompositeSync, PositiveCSync, NegativeCSync, HSkew, Broadcast, Pixmux, DoubleClock, HalfClock]);
 impl From<ModeFlag> for Option<u32> {
     fn from(input: ModeFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for ModeFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ModeFlag::PositiveHSync),
-            2 => Ok(ModeFlag::NegativeHSync),
-            4 => Ok(ModeFlag::PositiveVSync),
-            8 => Ok(ModeFlag::NegativeVSync),
-            16 => Ok(ModeFlag::Interlace),
-            32 => Ok(ModeFlag::CompositeSync),
-            64 => Ok(ModeFlag::PositiveCSync),
-            128 => Ok(ModeFlag::NegativeCSync),
-            256 => Ok(ModeFlag::HSkew),
-            512 => Ok(ModeFlag::Broadcast),
-            1024 => Ok(ModeFlag::Pixmux),
-            2048 => Ok(ModeFlag::DoubleClock),
-            4096 => Ok(ModeFlag::HalfClock),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(input.0)
     }
 }
-impl TryFrom<u32> for ModeFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(ModeFlag, u16);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ClockFlag {
-    Programable = 1 << 0,
-}
-impl From<ClockFlag> for u8 {
-    fn from(input: ClockFlag) -> Self {
-        match input {
-            ClockFlag::Programable => 1 << 0,
-        }
-    }
-}
-impl From<ClockFlag> for Option<u8> {
-    fn from(input: ClockFlag) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<ClockFlag> for u16 {
-    fn from(input: ClockFlag) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<ClockFlag> for Option<u16> {
-    fn from(input: ClockFlag) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<ClockFlag> for u32 {
-    fn from(input: ClockFlag) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ClockFlag(u32);
+#[allow(non_upper_case_globals)]
+impl ClockFlag {
+    pub const Programable: Self = Self(1 << 0);
 }
+bitmask_set!(ClockFlag, u32, [Programable]);
 impl From<ClockFlag> for Option<u32> {
     fn from(input: ClockFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ClockFlag {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ClockFlag::Programable),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ClockFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ClockFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-bitmask_binop!(ClockFlag, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Permission {
-    Read = 1 << 0,
-    Write = 1 << 1,
-}
-impl From<Permission> for u8 {
-    fn from(input: Permission) -> Self {
-        match input {
-            Permission::Read => 1 << 0,
-            Permission::Write => 1 << 1,
-        }
-    }
-}
-impl From<Permission> for Option<u8> {
-    fn from(input: Permission) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<Permission> for u16 {
-    fn from(input: Permission) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<Permission> for Option<u16> {
-    fn from(input: Permission) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<Permission> for u32 {
-    fn from(input: Permission) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Permission(u32);
+#[allow(non_upper_case_globals)]
+impl Permission {
+    pub const Read: Self = Self(1 << 0);
+    pub const Write: Self = Self(1 << 1);
+}
+bitmask_set!(Permission, u32, [Read, Write]);
 impl From<Permission> for Option<u32> {
     fn from(input: Permission) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Permission {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(Permission::Read),
-            2 => Ok(Permission::Write),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(input.0)
     }
 }
-impl TryFrom<u16> for Permission {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Permission {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(Permission, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ModeInfo {
@@ -249,25 +98,26 @@ pub struct ModeInfo {
     pub vsyncstart: u16,
     pub vsyncend: u16,
     pub vtotal: u16,
-    pub flags: u32,
+    pub flags: ModeFlag,
     pub privsize: u32,
 }
 impl TryParse for ModeInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u32::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let flags = ModeFlag::from(flags);
         let result = ModeInfo { dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, privsize };
         Ok((result, remaining))
     }
@@ -280,19 +130,19 @@ impl TryFrom<&[u8]> for ModeInfo {
 }
 impl Serialize for ModeInfo {
     type Bytes = [u8; 48];
-    fn serialize(&self) -> [u8; 48] {
-        let dotclock_bytes = self.dotclock.serialize();
-        let hdisplay_bytes = self.hdisplay.serialize();
-        let hsyncstart_bytes = self.hsyncstart.serialize();
-        let hsyncend_bytes = self.hsyncend.serialize();
-        let htotal_bytes = self.htotal.serialize();
-        let hskew_bytes = self.hskew.serialize();
-        let vdisplay_bytes = self.vdisplay.serialize();
-        let vsyncstart_bytes = self.vsyncstart.serialize();
-        let vsyncend_bytes = self.vsyncend.serialize();
-        let vtotal_bytes = self.vtotal.serialize();
-        let flags_bytes = self.flags.serialize();
-        let privsize_bytes = self.privsize.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 48] {
+        let dotclock_bytes = self.dotclock.serialize_with_order(order);
+        let hdisplay_bytes = self.hdisplay.serialize_with_order(order);
+        let hsyncstart_bytes = self.hsyncstart.serialize_with_order(order);
+        let hsyncend_bytes = self.hsyncend.serialize_with_order(order);
+        let htotal_bytes = self.htotal.serialize_with_order(order);
+        let hskew_bytes = self.hskew.serialize_with_order(order);
+        let vdisplay_bytes = self.vdisplay.serialize_with_order(order);
+        let vsyncstart_bytes = self.vsyncstart.serialize_with_order(order);
+        let vsyncend_bytes = self.vsyncend.serialize_with_order(order);
+        let vtotal_bytes = self.vtotal.serialize_with_order(order);
+        let flags_bytes = u32::from(self.flags).serialize_with_order(order);
+        let privsize_bytes = self.privsize.serialize_with_order(order);
         [
             dotclock_bytes[0],
             dotclock_bytes[1],
@@ -344,33 +194,51 @@ impl Serialize for ModeInfo {
             privsize_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(48);
-        self.dotclock.serialize_into(bytes);
-        self.hdisplay.serialize_into(bytes);
-        self.hsyncstart.serialize_into(bytes);
-        self.hsyncend.serialize_into(bytes);
-        self.htotal.serialize_into(bytes);
-        self.hskew.serialize_into(bytes);
-        self.vdisplay.serialize_into(bytes);
-        self.vsyncstart.serialize_into(bytes);
-        self.vsyncend.serialize_into(bytes);
-        self.vtotal.serialize_into(bytes);
+        self.dotclock.serialize_into_with_order(bytes, order);
+        self.hdisplay.serialize_into_with_order(bytes, order);
+        self.hsyncstart.serialize_into_with_order(bytes, order);
+        self.hsyncend.serialize_into_with_order(bytes, order);
+        self.htotal.serialize_into_with_order(bytes, order);
+        self.hskew.serialize_into_with_order(bytes, order);
+        self.vdisplay.serialize_into_with_order(bytes, order);
+        self.vsyncstart.serialize_into_with_order(bytes, order);
+        self.vsyncend.serialize_into_with_order(bytes, order);
+        self.vtotal.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 4]);
-        self.flags.serialize_into(bytes);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 12]);
-        self.privsize.serialize_into(bytes);
+        self.privsize.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+        })
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -381,9 +249,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -394,13 +274,13 @@ pub struct QueryVersionReply {
     pub minor_version: u16,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
     }
@@ -411,17 +291,68 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetModeLine request
 pub const GET_MODE_LINE_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetModeLineRequest {
+    pub screen: u16,
+}
+impl GetModeLineRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MODE_LINE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetModeLineRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_mode_line<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetModeLineReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MODE_LINE_REQUEST,
@@ -435,9 +366,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetModeLineRequest {
+    type Reply = GetModeLineReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetModeLineRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetModeLineReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_mode_line(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetModeLineReply {
@@ -454,31 +397,32 @@ pub struct GetModeLineReply {
     pub vsyncstart: u16,
     pub vsyncend: u16,
     pub vtotal: u16,
-    pub flags: u32,
+    pub flags: ModeFlag,
     pub private: Vec<u8>,
 }
 impl TryParse for GetModeLineReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
         let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ParseError))?)?;
         let private = private.to_vec();
+        let flags = ModeFlag::from(flags);
         let result = GetModeLineReply { response_type, sequence, length, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private };
         Ok((result, remaining))
     }
@@ -489,31 +433,188 @@ impl TryFrom<&[u8]> for GetModeLineReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetModeLineReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.dotclock.serialize_into_with_order(bytes, order);
+        self.hdisplay.serialize_into_with_order(bytes, order);
+        self.hsyncstart.serialize_into_with_order(bytes, order);
+        self.hsyncend.serialize_into_with_order(bytes, order);
+        self.htotal.serialize_into_with_order(bytes, order);
+        self.hskew.serialize_into_with_order(bytes, order);
+        self.vdisplay.serialize_into_with_order(bytes, order);
+        self.vsyncstart.serialize_into_with_order(bytes, order);
+        self.vsyncend.serialize_into_with_order(bytes, order);
+        self.vtotal.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        privsize.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.private);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ModModeLine request
 pub const MOD_MODE_LINE_REQUEST: u8 = 2;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ModModeLineRequest {
+    pub screen: u32,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: ModeFlag,
+    pub private: Vec<u8>,
+}
+impl ModModeLineRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != MOD_MODE_LINE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ParseError))?)?;
+        let private = private.to_vec();
+        let _ = remaining;
+        let _ = order;
+        let flags = ModeFlag::from(flags);
+        Ok(ModModeLineRequest {
+            screen,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            private,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `hdisplay` field of this structure.
+    pub fn hdisplay(mut self, value: u16) -> Self {
+        self.hdisplay = value;
+        self
+    }
+    /// Set the `hsyncstart` field of this structure.
+    pub fn hsyncstart(mut self, value: u16) -> Self {
+        self.hsyncstart = value;
+        self
+    }
+    /// Set the `hsyncend` field of this structure.
+    pub fn hsyncend(mut self, value: u16) -> Self {
+        self.hsyncend = value;
+        self
+    }
+    /// Set the `htotal` field of this structure.
+    pub fn htotal(mut self, value: u16) -> Self {
+        self.htotal = value;
+        self
+    }
+    /// Set the `hskew` field of this structure.
+    pub fn hskew(mut self, value: u16) -> Self {
+        self.hskew = value;
+        self
+    }
+    /// Set the `vdisplay` field of this structure.
+    pub fn vdisplay(mut self, value: u16) -> Self {
+        self.vdisplay = value;
+        self
+    }
+    /// Set the `vsyncstart` field of this structure.
+    pub fn vsyncstart(mut self, value: u16) -> Self {
+        self.vsyncstart = value;
+        self
+    }
+    /// Set the `vsyncend` field of this structure.
+    pub fn vsyncend(mut self, value: u16) -> Self {
+        self.vsyncend = value;
+        self
+    }
+    /// Set the `vtotal` field of this structure.
+    pub fn vtotal(mut self, value: u16) -> Self {
+        self.vtotal = value;
+        self
+    }
+    /// Set the `flags` field of this structure.
+    pub fn flags<I>(mut self, value: I) -> Self where I: Into<ModeFlag> {
+        self.flags = value.into();
+        self
+    }
+    /// Set the `private` field of this structure.
+    pub fn private<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.private = value.into();
+        self
+    }
+}
+
 pub fn mod_mode_line<'c, Conn, A>(conn: &'c Conn, screen: u32, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<ModeFlag>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let flags: u32 = flags.into();
+    let flags: ModeFlag = flags.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let hdisplay_bytes = hdisplay.serialize();
-    let hsyncstart_bytes = hsyncstart.serialize();
-    let hsyncend_bytes = hsyncend.serialize();
-    let htotal_bytes = htotal.serialize();
-    let hskew_bytes = hskew.serialize();
-    let vdisplay_bytes = vdisplay.serialize();
-    let vsyncstart_bytes = vsyncstart.serialize();
-    let vsyncend_bytes = vsyncend.serialize();
-    let vtotal_bytes = vtotal.serialize();
-    let flags_bytes = flags.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let hdisplay_bytes = hdisplay.serialize_with_order(order);
+    let hsyncstart_bytes = hsyncstart.serialize_with_order(order);
+    let hsyncend_bytes = hsyncend.serialize_with_order(order);
+    let htotal_bytes = htotal.serialize_with_order(order);
+    let hskew_bytes = hskew.serialize_with_order(order);
+    let vdisplay_bytes = vdisplay.serialize_with_order(order);
+    let vsyncstart_bytes = vsyncstart.serialize_with_order(order);
+    let vsyncend_bytes = vsyncend.serialize_with_order(order);
+    let vtotal_bytes = vtotal.serialize_with_order(order);
+    let flags_bytes = u32::from(flags).serialize_with_order(order);
     let privsize = u32::try_from(private.len()).expect("`private` has too many elements");
-    let privsize_bytes = privsize.serialize();
+    let privsize_bytes = privsize.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         MOD_MODE_LINE_REQUEST,
@@ -570,21 +671,67 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(private), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ModModeLineRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ModModeLineRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        mod_mode_line(conn, self.screen, self.hdisplay, self.hsyncstart, self.hsyncend, self.htotal, self.hskew, self.vdisplay, self.vsyncstart, self.vsyncend, self.vtotal, self.flags, &self.private)
+    }
+}
 
 /// Opcode for the SwitchMode request
 pub const SWITCH_MODE_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SwitchModeRequest {
+    pub screen: u16,
+    pub zoom: u16,
+}
+impl SwitchModeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWITCH_MODE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (zoom, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SwitchModeRequest {
+            screen,
+            zoom,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `zoom` field of this structure.
+    pub fn zoom(mut self, value: u16) -> Self {
+        self.zoom = value;
+        self
+    }
+}
+
 pub fn switch_mode<Conn>(conn: &Conn, screen: u16, zoom: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let zoom_bytes = zoom.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let zoom_bytes = zoom.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SWITCH_MODE_REQUEST,
@@ -598,20 +745,59 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SwitchModeRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SwitchModeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        switch_mode(conn, self.screen, self.zoom)
+    }
+}
 
 /// Opcode for the GetMonitor request
 pub const GET_MONITOR_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMonitorRequest {
+    pub screen: u16,
+}
+impl GetMonitorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MONITOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetMonitorRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_monitor<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetMonitorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MONITOR_REQUEST,
@@ -625,9 +811,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMonitorRequest {
+    type Reply = GetMonitorReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMonitorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMonitorReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_monitor(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMonitorReply {
@@ -641,18 +839,18 @@ pub struct GetMonitorReply {
     pub model: Vec<u8>,
 }
 impl TryParse for GetMonitorReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (vendor_length, remaining) = u8::try_parse(remaining)?;
-        let (model_length, remaining) = u8::try_parse(remaining)?;
-        let (num_hsync, remaining) = u8::try_parse(remaining)?;
-        let (num_vsync, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vendor_length, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (model_length, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_hsync, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_vsync, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (hsync, remaining) = crate::x11_utils::parse_list::<Syncrange>(remaining, num_hsync.try_into().or(Err(ParseError::ParseError))?)?;
-        let (vsync, remaining) = crate::x11_utils::parse_list::<Syncrange>(remaining, num_vsync.try_into().or(Err(ParseError::ParseError))?)?;
+        let (hsync, remaining) = crate::x11_utils::parse_list_with_order::<Syncrange>(remaining, num_hsync.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (vsync, remaining) = crate::x11_utils::parse_list_with_order::<Syncrange>(remaining, num_vsync.try_into().or(Err(ParseError::ParseError))?, order)?;
         let (vendor, remaining) = crate::x11_utils::parse_u8_list(remaining, vendor_length.try_into().or(Err(ParseError::ParseError))?)?;
         let vendor = vendor.to_vec();
         let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (u32::from(vendor_length).checked_add(3u32).ok_or(ParseError::ParseError)? & (!3u32)).checked_sub(u32::from(vendor_length)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
@@ -669,18 +867,89 @@ impl TryFrom<&[u8]> for GetMonitorReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMonitorReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let vendor_length = u8::try_from(self.vendor.len()).expect("`vendor` has too many elements");
+        vendor_length.serialize_into_with_order(bytes, order);
+        let model_length = u8::try_from(self.model.len()).expect("`model` has too many elements");
+        model_length.serialize_into_with_order(bytes, order);
+        let num_hsync = u8::try_from(self.hsync.len()).expect("`hsync` has too many elements");
+        num_hsync.serialize_into_with_order(bytes, order);
+        let num_vsync = u8::try_from(self.vsync.len()).expect("`vsync` has too many elements");
+        num_vsync.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.hsync.serialize_into_with_order(bytes, order);
+        self.vsync.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.vendor);
+        assert_eq!(self.alignment_pad.len(), usize::try_from((u32::from(vendor_length).checked_add(3u32).unwrap() & (!3u32)).checked_sub(u32::from(vendor_length)).unwrap()).unwrap(), "`alignment_pad` has an incorrect length");
+        bytes.extend_from_slice(&self.alignment_pad);
+        bytes.extend_from_slice(&self.model);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the LockModeSwitch request
 pub const LOCK_MODE_SWITCH_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LockModeSwitchRequest {
+    pub screen: u16,
+    pub lock: u16,
+}
+impl LockModeSwitchRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LOCK_MODE_SWITCH_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (lock, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(LockModeSwitchRequest {
+            screen,
+            lock,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `lock` field of this structure.
+    pub fn lock(mut self, value: u16) -> Self {
+        self.lock = value;
+        self
+    }
+}
+
 pub fn lock_mode_switch<Conn>(conn: &Conn, screen: u16, lock: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let lock_bytes = lock.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let lock_bytes = lock.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         LOCK_MODE_SWITCH_REQUEST,
@@ -694,20 +963,59 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for LockModeSwitchRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for LockModeSwitchRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        lock_mode_switch(conn, self.screen, self.lock)
+    }
+}
 
 /// Opcode for the GetAllModeLines request
 pub const GET_ALL_MODE_LINES_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetAllModeLinesRequest {
+    pub screen: u16,
+}
+impl GetAllModeLinesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_ALL_MODE_LINES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetAllModeLinesRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_all_mode_lines<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetAllModeLinesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_ALL_MODE_LINES_REQUEST,
@@ -721,9 +1029,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetAllModeLinesRequest {
+    type Reply = GetAllModeLinesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetAllModeLinesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetAllModeLinesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_all_mode_lines(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetAllModeLinesReply {
@@ -733,14 +1053,14 @@ pub struct GetAllModeLinesReply {
     pub modeinfo: Vec<ModeInfo>,
 }
 impl TryParse for GetAllModeLinesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (modecount, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (modecount, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (modeinfo, remaining) = crate::x11_utils::parse_list::<ModeInfo>(remaining, modecount.try_into().or(Err(ParseError::ParseError))?)?;
+        let (modeinfo, remaining) = crate::x11_utils::parse_list_with_order::<ModeInfo>(remaining, modecount.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetAllModeLinesReply { response_type, sequence, length, modeinfo };
         Ok((result, remaining))
     }
@@ -751,45 +1071,289 @@ impl TryFrom<&[u8]> for GetAllModeLinesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetAllModeLinesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let modecount = u32::try_from(self.modeinfo.len()).expect("`modeinfo` has too many elements");
+        modecount.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.modeinfo.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the AddModeLine request
 pub const ADD_MODE_LINE_REQUEST: u8 = 7;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AddModeLineRequest {
+    pub screen: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: ModeFlag,
+    pub after_dotclock: Dotclock,
+    pub after_hdisplay: u16,
+    pub after_hsyncstart: u16,
+    pub after_hsyncend: u16,
+    pub after_htotal: u16,
+    pub after_hskew: u16,
+    pub after_vdisplay: u16,
+    pub after_vsyncstart: u16,
+    pub after_vsyncend: u16,
+    pub after_vtotal: u16,
+    pub after_flags: ModeFlag,
+    pub private: Vec<u8>,
+}
+impl AddModeLineRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ADD_MODE_LINE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (after_dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (after_hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (after_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ParseError))?)?;
+        let private = private.to_vec();
+        let _ = remaining;
+        let _ = order;
+        let flags = ModeFlag::from(flags);
+        let after_flags = ModeFlag::from(after_flags);
+        Ok(AddModeLineRequest {
+            screen,
+            dotclock,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            after_dotclock,
+            after_hdisplay,
+            after_hsyncstart,
+            after_hsyncend,
+            after_htotal,
+            after_hskew,
+            after_vdisplay,
+            after_vsyncstart,
+            after_vsyncend,
+            after_vtotal,
+            after_flags,
+            private,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `dotclock` field of this structure.
+    pub fn dotclock<I>(mut self, value: I) -> Self where I: Into<Dotclock> {
+        self.dotclock = value.into();
+        self
+    }
+    /// Set the `hdisplay` field of this structure.
+    pub fn hdisplay(mut self, value: u16) -> Self {
+        self.hdisplay = value;
+        self
+    }
+    /// Set the `hsyncstart` field of this structure.
+    pub fn hsyncstart(mut self, value: u16) -> Self {
+        self.hsyncstart = value;
+        self
+    }
+    /// Set the `hsyncend` field of this structure.
+    pub fn hsyncend(mut self, value: u16) -> Self {
+        self.hsyncend = value;
+        self
+    }
+    /// Set the `htotal` field of this structure.
+    pub fn htotal(mut self, value: u16) -> Self {
+        self.htotal = value;
+        self
+    }
+    /// Set the `hskew` field of this structure.
+    pub fn hskew(mut self, value: u16) -> Self {
+        self.hskew = value;
+        self
+    }
+    /// Set the `vdisplay` field of this structure.
+    pub fn vdisplay(mut self, value: u16) -> Self {
+        self.vdisplay = value;
+        self
+    }
+    /// Set the `vsyncstart` field of this structure.
+    pub fn vsyncstart(mut self, value: u16) -> Self {
+        self.vsyncstart = value;
+        self
+    }
+    /// Set the `vsyncend` field of this structure.
+    pub fn vsyncend(mut self, value: u16) -> Self {
+        self.vsyncend = value;
+        self
+    }
+    /// Set the `vtotal` field of this structure.
+    pub fn vtotal(mut self, value: u16) -> Self {
+        self.vtotal = value;
+        self
+    }
+    /// Set the `flags` field of this structure.
+    pub fn flags<I>(mut self, value: I) -> Self where I: Into<ModeFlag> {
+        self.flags = value.into();
+        self
+    }
+    /// Set the `after_dotclock` field of this structure.
+    pub fn after_dotclock<I>(mut self, value: I) -> Self where I: Into<Dotclock> {
+        self.after_dotclock = value.into();
+        self
+    }
+    /// Set the `after_hdisplay` field of this structure.
+    pub fn after_hdisplay(mut self, value: u16) -> Self {
+        self.after_hdisplay = value;
+        self
+    }
+    /// Set the `after_hsyncstart` field of this structure.
+    pub fn after_hsyncstart(mut self, value: u16) -> Self {
+        self.after_hsyncstart = value;
+        self
+    }
+    /// Set the `after_hsyncend` field of this structure.
+    pub fn after_hsyncend(mut self, value: u16) -> Self {
+        self.after_hsyncend = value;
+        self
+    }
+    /// Set the `after_htotal` field of this structure.
+    pub fn after_htotal(mut self, value: u16) -> Self {
+        self.after_htotal = value;
+        self
+    }
+    /// Set the `after_hskew` field of this structure.
+    pub fn after_hskew(mut self, value: u16) -> Self {
+        self.after_hskew = value;
+        self
+    }
+    /// Set the `after_vdisplay` field of this structure.
+    pub fn after_vdisplay(mut self, value: u16) -> Self {
+        self.after_vdisplay = value;
+        self
+    }
+    /// Set the `after_vsyncstart` field of this structure.
+    pub fn after_vsyncstart(mut self, value: u16) -> Self {
+        self.after_vsyncstart = value;
+        self
+    }
+    /// Set the `after_vsyncend` field of this structure.
+    pub fn after_vsyncend(mut self, value: u16) -> Self {
+        self.after_vsyncend = value;
+        self
+    }
+    /// Set the `after_vtotal` field of this structure.
+    pub fn after_vtotal(mut self, value: u16) -> Self {
+        self.after_vtotal = value;
+        self
+    }
+    /// Set the `after_flags` field of this structure.
+    pub fn after_flags<I>(mut self, value: I) -> Self where I: Into<ModeFlag> {
+        self.after_flags = value.into();
+        self
+    }
+    /// Set the `private` field of this structure.
+    pub fn private<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.private = value.into();
+        self
+    }
+}
+
 pub fn add_mode_line<'c, Conn, A, B>(conn: &'c Conn, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, after_dotclock: Dotclock, after_hdisplay: u16, after_hsyncstart: u16, after_hsyncend: u16, after_htotal: u16, after_hskew: u16, after_vdisplay: u16, after_vsyncstart: u16, after_vsyncend: u16, after_vtotal: u16, after_flags: B, private: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
-    B: Into<u32>,
+    A: Into<ModeFlag>,
+    B: Into<ModeFlag>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let flags: u32 = flags.into();
-    let after_flags: u32 = after_flags.into();
+    let flags: ModeFlag = flags.into();
+    let after_flags: ModeFlag = after_flags.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let dotclock_bytes = dotclock.serialize();
-    let hdisplay_bytes = hdisplay.serialize();
-    let hsyncstart_bytes = hsyncstart.serialize();
-    let hsyncend_bytes = hsyncend.serialize();
-    let htotal_bytes = htotal.serialize();
-    let hskew_bytes = hskew.serialize();
-    let vdisplay_bytes = vdisplay.serialize();
-    let vsyncstart_bytes = vsyncstart.serialize();
-    let vsyncend_bytes = vsyncend.serialize();
-    let vtotal_bytes = vtotal.serialize();
-    let flags_bytes = flags.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let dotclock_bytes = dotclock.serialize_with_order(order);
+    let hdisplay_bytes = hdisplay.serialize_with_order(order);
+    let hsyncstart_bytes = hsyncstart.serialize_with_order(order);
+    let hsyncend_bytes = hsyncend.serialize_with_order(order);
+    let htotal_bytes = htotal.serialize_with_order(order);
+    let hskew_bytes = hskew.serialize_with_order(order);
+    let vdisplay_bytes = vdisplay.serialize_with_order(order);
+    let vsyncstart_bytes = vsyncstart.serialize_with_order(order);
+    let vsyncend_bytes = vsyncend.serialize_with_order(order);
+    let vtotal_bytes = vtotal.serialize_with_order(order);
+    let flags_bytes = u32::from(flags).serialize_with_order(order);
     let privsize = u32::try_from(private.len()).expect("`private` has too many elements");
-    let privsize_bytes = privsize.serialize();
-    let after_dotclock_bytes = after_dotclock.serialize();
-    let after_hdisplay_bytes = after_hdisplay.serialize();
-    let after_hsyncstart_bytes = after_hsyncstart.serialize();
-    let after_hsyncend_bytes = after_hsyncend.serialize();
-    let after_htotal_bytes = after_htotal.serialize();
-    let after_hskew_bytes = after_hskew.serialize();
-    let after_vdisplay_bytes = after_vdisplay.serialize();
-    let after_vsyncstart_bytes = after_vsyncstart.serialize();
-    let after_vsyncend_bytes = after_vsyncend.serialize();
-    let after_vtotal_bytes = after_vtotal.serialize();
-    let after_flags_bytes = after_flags.serialize();
+    let privsize_bytes = privsize.serialize_with_order(order);
+    let after_dotclock_bytes = after_dotclock.serialize_with_order(order);
+    let after_hdisplay_bytes = after_hdisplay.serialize_with_order(order);
+    let after_hsyncstart_bytes = after_hsyncstart.serialize_with_order(order);
+    let after_hsyncend_bytes = after_hsyncend.serialize_with_order(order);
+    let after_htotal_bytes = after_htotal.serialize_with_order(order);
+    let after_hskew_bytes = after_hskew.serialize_with_order(order);
+    let after_vdisplay_bytes = after_vdisplay.serialize_with_order(order);
+    let after_vsyncstart_bytes = after_vsyncstart.serialize_with_order(order);
+    let after_vsyncend_bytes = after_vsyncend.serialize_with_order(order);
+    let after_vtotal_bytes = after_vtotal.serialize_with_order(order);
+    let after_flags_bytes = u32::from(after_flags).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ADD_MODE_LINE_REQUEST,
@@ -890,35 +1454,174 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(private), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for AddModeLineRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AddModeLineRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        add_mode_line(conn, self.screen, self.dotclock, self.hdisplay, self.hsyncstart, self.hsyncend, self.htotal, self.hskew, self.vdisplay, self.vsyncstart, self.vsyncend, self.vtotal, self.flags, self.after_dotclock, self.after_hdisplay, self.after_hsyncstart, self.after_hsyncend, self.after_htotal, self.after_hskew, self.after_vdisplay, self.after_vsyncstart, self.after_vsyncend, self.after_vtotal, self.after_flags, &self.private)
+    }
+}
 
 /// Opcode for the DeleteModeLine request
 pub const DELETE_MODE_LINE_REQUEST: u8 = 8;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeleteModeLineRequest {
+    pub screen: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: ModeFlag,
+    pub private: Vec<u8>,
+}
+impl DeleteModeLineRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_MODE_LINE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ParseError))?)?;
+        let private = private.to_vec();
+        let _ = remaining;
+        let _ = order;
+        let flags = ModeFlag::from(flags);
+        Ok(DeleteModeLineRequest {
+            screen,
+            dotclock,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            private,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `dotclock` field of this structure.
+    pub fn dotclock<I>(mut self, value: I) -> Self where I: Into<Dotclock> {
+        self.dotclock = value.into();
+        self
+    }
+    /// Set the `hdisplay` field of this structure.
+    pub fn hdisplay(mut self, value: u16) -> Self {
+        self.hdisplay = value;
+        self
+    }
+    /// Set the `hsyncstart` field of this structure.
+    pub fn hsyncstart(mut self, value: u16) -> Self {
+        self.hsyncstart = value;
+        self
+    }
+    /// Set the `hsyncend` field of this structure.
+    pub fn hsyncend(mut self, value: u16) -> Self {
+        self.hsyncend = value;
+        self
+    }
+    /// Set the `htotal` field of this structure.
+    pub fn htotal(mut self, value: u16) -> Self {
+        self.htotal = value;
+        self
+    }
+    /// Set the `hskew` field of this structure.
+    pub fn hskew(mut self, value: u16) -> Self {
+        self.hskew = value;
+        self
+    }
+    /// Set the `vdisplay` field of this structure.
+    pub fn vdisplay(mut self, value: u16) -> Self {
+        self.vdisplay = value;
+        self
+    }
+    /// Set the `vsyncstart` field of this structure.
+    pub fn vsyncstart(mut self, value: u16) -> Self {
+        self.vsyncstart = value;
+        self
+    }
+    /// Set the `vsyncend` field of this structure.
+    pub fn vsyncend(mut self, value: u16) -> Self {
+        self.vsyncend = value;
+        self
+    }
+    /// Set the `vtotal` field of this structure.
+    pub fn vtotal(mut self, value: u16) -> Self {
+        self.vtotal = value;
+        self
+    }
+    /// Set the `flags` field of this structure.
+    pub fn flags<I>(mut self, value: I) -> Self where I: Into<ModeFlag> {
+        self.flags = value.into();
+        self
+    }
+    /// Set the `private` field of this structure.
+    pub fn private<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.private = value.into();
+        self
+    }
+}
+
 pub fn delete_mode_line<'c, Conn, A>(conn: &'c Conn, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<ModeFlag>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let flags: u32 = flags.into();
+    let flags: ModeFlag = flags.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let dotclock_bytes = dotclock.serialize();
-    let hdisplay_bytes = hdisplay.serialize();
-    let hsyncstart_bytes = hsyncstart.serialize();
-    let hsyncend_bytes = hsyncend.serialize();
-    let htotal_bytes = htotal.serialize();
-    let hskew_bytes = hskew.serialize();
-    let vdisplay_bytes = vdisplay.serialize();
-    let vsyncstart_bytes = vsyncstart.serialize();
-    let vsyncend_bytes = vsyncend.serialize();
-    let vtotal_bytes = vtotal.serialize();
-    let flags_bytes = flags.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let dotclock_bytes = dotclock.serialize_with_order(order);
+    let hdisplay_bytes = hdisplay.serialize_with_order(order);
+    let hsyncstart_bytes = hsyncstart.serialize_with_order(order);
+    let hsyncend_bytes = hsyncend.serialize_with_order(order);
+    let htotal_bytes = htotal.serialize_with_order(order);
+    let hskew_bytes = hskew.serialize_with_order(order);
+    let vdisplay_bytes = vdisplay.serialize_with_order(order);
+    let vsyncstart_bytes = vsyncstart.serialize_with_order(order);
+    let vsyncend_bytes = vsyncend.serialize_with_order(order);
+    let vtotal_bytes = vtotal.serialize_with_order(order);
+    let flags_bytes = u32::from(flags).serialize_with_order(order);
     let privsize = u32::try_from(private.len()).expect("`private` has too many elements");
-    let privsize_bytes = privsize.serialize();
+    let privsize_bytes = privsize.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_MODE_LINE_REQUEST,
@@ -979,35 +1682,174 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(private), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteModeLineRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteModeLineRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_mode_line(conn, self.screen, self.dotclock, self.hdisplay, self.hsyncstart, self.hsyncend, self.htotal, self.hskew, self.vdisplay, self.vsyncstart, self.vsyncend, self.vtotal, self.flags, &self.private)
+    }
+}
 
 /// Opcode for the ValidateModeLine request
 pub const VALIDATE_MODE_LINE_REQUEST: u8 = 9;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ValidateModeLineRequest {
+    pub screen: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: ModeFlag,
+    pub private: Vec<u8>,
+}
+impl ValidateModeLineRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != VALIDATE_MODE_LINE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ParseError))?)?;
+        let private = private.to_vec();
+        let _ = remaining;
+        let _ = order;
+        let flags = ModeFlag::from(flags);
+        Ok(ValidateModeLineRequest {
+            screen,
+            dotclock,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            private,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `dotclock` field of this structure.
+    pub fn dotclock<I>(mut self, value: I) -> Self where I: Into<Dotclock> {
+        self.dotclock = value.into();
+        self
+    }
+    /// Set the `hdisplay` field of this structure.
+    pub fn hdisplay(mut self, value: u16) -> Self {
+        self.hdisplay = value;
+        self
+    }
+    /// Set the `hsyncstart` field of this structure.
+    pub fn hsyncstart(mut self, value: u16) -> Self {
+        self.hsyncstart = value;
+        self
+    }
+    /// Set the `hsyncend` field of this structure.
+    pub fn hsyncend(mut self, value: u16) -> Self {
+        self.hsyncend = value;
+        self
+    }
+    /// Set the `htotal` field of this structure.
+    pub fn htotal(mut self, value: u16) -> Self {
+        self.htotal = value;
+        self
+    }
+    /// Set the `hskew` field of this structure.
+    pub fn hskew(mut self, value: u16) -> Self {
+        self.hskew = value;
+        self
+    }
+    /// Set the `vdisplay` field of this structure.
+    pub fn vdisplay(mut self, value: u16) -> Self {
+        self.vdisplay = value;
+        self
+    }
+    /// Set the `vsyncstart` field of this structure.
+    pub fn vsyncstart(mut self, value: u16) -> Self {
+        self.vsyncstart = value;
+        self
+    }
+    /// Set the `vsyncend` field of this structure.
+    pub fn vsyncend(mut self, value: u16) -> Self {
+        self.vsyncend = value;
+        self
+    }
+    /// Set the `vtotal` field of this structure.
+    pub fn vtotal(mut self, value: u16) -> Self {
+        self.vtotal = value;
+        self
+    }
+    /// Set the `flags` field of this structure.
+    pub fn flags<I>(mut self, value: I) -> Self where I: Into<ModeFlag> {
+        self.flags = value.into();
+        self
+    }
+    /// Set the `private` field of this structure.
+    pub fn private<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.private = value.into();
+        self
+    }
+}
+
 pub fn validate_mode_line<'c, Conn, A>(conn: &'c Conn, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &[u8]) -> Result<Cookie<'c, Conn, ValidateModeLineReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<ModeFlag>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let flags: u32 = flags.into();
+    let flags: ModeFlag = flags.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let dotclock_bytes = dotclock.serialize();
-    let hdisplay_bytes = hdisplay.serialize();
-    let hsyncstart_bytes = hsyncstart.serialize();
-    let hsyncend_bytes = hsyncend.serialize();
-    let htotal_bytes = htotal.serialize();
-    let hskew_bytes = hskew.serialize();
-    let vdisplay_bytes = vdisplay.serialize();
-    let vsyncstart_bytes = vsyncstart.serialize();
-    let vsyncend_bytes = vsyncend.serialize();
-    let vtotal_bytes = vtotal.serialize();
-    let flags_bytes = flags.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let dotclock_bytes = dotclock.serialize_with_order(order);
+    let hdisplay_bytes = hdisplay.serialize_with_order(order);
+    let hsyncstart_bytes = hsyncstart.serialize_with_order(order);
+    let hsyncend_bytes = hsyncend.serialize_with_order(order);
+    let htotal_bytes = htotal.serialize_with_order(order);
+    let hskew_bytes = hskew.serialize_with_order(order);
+    let vdisplay_bytes = vdisplay.serialize_with_order(order);
+    let vsyncstart_bytes = vsyncstart.serialize_with_order(order);
+    let vsyncend_bytes = vsyncend.serialize_with_order(order);
+    let vtotal_bytes = vtotal.serialize_with_order(order);
+    let flags_bytes = u32::from(flags).serialize_with_order(order);
     let privsize = u32::try_from(private.len()).expect("`private` has too many elements");
-    let privsize_bytes = privsize.serialize();
+    let privsize_bytes = privsize.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         VALIDATE_MODE_LINE_REQUEST,
@@ -1068,9 +1910,21 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(private), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ValidateModeLineRequest {
+    type Reply = ValidateModeLineReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ValidateModeLineRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ValidateModeLineReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        validate_mode_line(conn, self.screen, self.dotclock, self.hdisplay, self.hsyncstart, self.hsyncend, self.htotal, self.hskew, self.vdisplay, self.vsyncstart, self.vsyncend, self.vtotal, self.flags, &self.private)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ValidateModeLineReply {
@@ -1080,12 +1934,12 @@ pub struct ValidateModeLineReply {
     pub status: u32,
 }
 impl TryParse for ValidateModeLineReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = ValidateModeLineReply { response_type, sequence, length, status };
         Ok((result, remaining))
@@ -1097,32 +1951,183 @@ impl TryFrom<&[u8]> for ValidateModeLineReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ValidateModeLineReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.status.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SwitchToMode request
 pub const SWITCH_TO_MODE_REQUEST: u8 = 10;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SwitchToModeRequest {
+    pub screen: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: ModeFlag,
+    pub private: Vec<u8>,
+}
+impl SwitchToModeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWITCH_TO_MODE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ParseError))?)?;
+        let private = private.to_vec();
+        let _ = remaining;
+        let _ = order;
+        let flags = ModeFlag::from(flags);
+        Ok(SwitchToModeRequest {
+            screen,
+            dotclock,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            private,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u32) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `dotclock` field of this structure.
+    pub fn dotclock<I>(mut self, value: I) -> Self where I: Into<Dotclock> {
+        self.dotclock = value.into();
+        self
+    }
+    /// Set the `hdisplay` field of this structure.
+    pub fn hdisplay(mut self, value: u16) -> Self {
+        self.hdisplay = value;
+        self
+    }
+    /// Set the `hsyncstart` field of this structure.
+    pub fn hsyncstart(mut self, value: u16) -> Self {
+        self.hsyncstart = value;
+        self
+    }
+    /// Set the `hsyncend` field of this structure.
+    pub fn hsyncend(mut self, value: u16) -> Self {
+        self.hsyncend = value;
+        self
+    }
+    /// Set the `htotal` field of this structure.
+    pub fn htotal(mut self, value: u16) -> Self {
+        self.htotal = value;
+        self
+    }
+    /// Set the `hskew` field of this structure.
+    pub fn hskew(mut self, value: u16) -> Self {
+        self.hskew = value;
+        self
+    }
+    /// Set the `vdisplay` field of this structure.
+    pub fn vdisplay(mut self, value: u16) -> Self {
+        self.vdisplay = value;
+        self
+    }
+    /// Set the `vsyncstart` field of this structure.
+    pub fn vsyncstart(mut self, value: u16) -> Self {
+        self.vsyncstart = value;
+        self
+    }
+    /// Set the `vsyncend` field of this structure.
+    pub fn vsyncend(mut self, value: u16) -> Self {
+        self.vsyncend = value;
+        self
+    }
+    /// Set the `vtotal` field of this structure.
+    pub fn vtotal(mut self, value: u16) -> Self {
+        self.vtotal = value;
+        self
+    }
+    /// Set the `flags` field of this structure.
+    pub fn flags<I>(mut self, value: I) -> Self where I: Into<ModeFlag> {
+        self.flags = value.into();
+        self
+    }
+    /// Set the `private` field of this structure.
+    pub fn private<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.private = value.into();
+        self
+    }
+}
+
 pub fn switch_to_mode<'c, Conn, A>(conn: &'c Conn, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<ModeFlag>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let flags: u32 = flags.into();
+    let flags: ModeFlag = flags.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let dotclock_bytes = dotclock.serialize();
-    let hdisplay_bytes = hdisplay.serialize();
-    let hsyncstart_bytes = hsyncstart.serialize();
-    let hsyncend_bytes = hsyncend.serialize();
-    let htotal_bytes = htotal.serialize();
-    let hskew_bytes = hskew.serialize();
-    let vdisplay_bytes = vdisplay.serialize();
-    let vsyncstart_bytes = vsyncstart.serialize();
-    let vsyncend_bytes = vsyncend.serialize();
-    let vtotal_bytes = vtotal.serialize();
-    let flags_bytes = flags.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let dotclock_bytes = dotclock.serialize_with_order(order);
+    let hdisplay_bytes = hdisplay.serialize_with_order(order);
+    let hsyncstart_bytes = hsyncstart.serialize_with_order(order);
+    let hsyncend_bytes = hsyncend.serialize_with_order(order);
+    let htotal_bytes = htotal.serialize_with_order(order);
+    let hskew_bytes = hskew.serialize_with_order(order);
+    let vdisplay_bytes = vdisplay.serialize_with_order(order);
+    let vsyncstart_bytes = vsyncstart.serialize_with_order(order);
+    let vsyncend_bytes = vsyncend.serialize_with_order(order);
+    let vtotal_bytes = vtotal.serialize_with_order(order);
+    let flags_bytes = u32::from(flags).serialize_with_order(order);
     let privsize = u32::try_from(private.len()).expect("`private` has too many elements");
-    let privsize_bytes = privsize.serialize();
+    let privsize_bytes = privsize.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SWITCH_TO_MODE_REQUEST,
@@ -1183,20 +2188,59 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(private), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SwitchToModeRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SwitchToModeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        switch_to_mode(conn, self.screen, self.dotclock, self.hdisplay, self.hsyncstart, self.hsyncend, self.htotal, self.hskew, self.vdisplay, self.vsyncstart, self.vsyncend, self.vtotal, self.flags, &self.private)
+    }
+}
 
 /// Opcode for the GetViewPort request
 pub const GET_VIEW_PORT_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetViewPortRequest {
+    pub screen: u16,
+}
+impl GetViewPortRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_VIEW_PORT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetViewPortRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_view_port<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetViewPortReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_VIEW_PORT_REQUEST,
@@ -1210,9 +2254,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetViewPortRequest {
+    type Reply = GetViewPortReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetViewPortRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetViewPortReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_view_port(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetViewPortReply {
@@ -1223,13 +2279,13 @@ pub struct GetViewPortReply {
     pub y: u32,
 }
 impl TryParse for GetViewPortReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (x, remaining) = u32::try_parse(remaining)?;
-        let (y, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = GetViewPortReply { response_type, sequence, length, x, y };
         Ok((result, remaining))
@@ -1241,19 +2297,87 @@ impl TryFrom<&[u8]> for GetViewPortReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetViewPortReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetViewPort request
 pub const SET_VIEW_PORT_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetViewPortRequest {
+    pub screen: u16,
+    pub x: u32,
+    pub y: u32,
+}
+impl SetViewPortRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_VIEW_PORT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetViewPortRequest {
+            screen,
+            x,
+            y,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `x` field of this structure.
+    pub fn x(mut self, value: u32) -> Self {
+        self.x = value;
+        self
+    }
+    /// Set the `y` field of this structure.
+    pub fn y(mut self, value: u32) -> Self {
+        self.y = value;
+        self
+    }
+}
+
 pub fn set_view_port<Conn>(conn: &Conn, screen: u16, x: u32, y: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let x_bytes = x.serialize();
-    let y_bytes = y.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let x_bytes = x.serialize_with_order(order);
+    let y_bytes = y.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_VIEW_PORT_REQUEST,
@@ -1275,20 +2399,59 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetViewPortRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetViewPortRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_view_port(conn, self.screen, self.x, self.y)
+    }
+}
 
 /// Opcode for the GetDotClocks request
 pub const GET_DOT_CLOCKS_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDotClocksRequest {
+    pub screen: u16,
+}
+impl GetDotClocksRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DOT_CLOCKS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDotClocksRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_dot_clocks<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetDotClocksReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DOT_CLOCKS_REQUEST,
@@ -1302,31 +2465,44 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDotClocksRequest {
+    type Reply = GetDotClocksReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDotClocksRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDotClocksReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_dot_clocks(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDotClocksReply {
     pub response_type: u8,
     pub sequence: u16,
     pub length: u32,
-    pub flags: u32,
+    pub flags: ClockFlag,
     pub clocks: u32,
     pub maxclocks: u32,
     pub clock: Vec<u32>,
 }
 impl TryParse for GetDotClocksReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (clocks, remaining) = u32::try_parse(remaining)?;
-        let (maxclocks, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (clocks, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (maxclocks, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (clock, remaining) = crate::x11_utils::parse_list::<u32>(remaining, 1u32.checked_sub(flags & 1u32).ok_or(ParseError::ParseError)?.checked_mul(clocks).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (clock, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, 1u32.checked_sub(u32::from(flags) & 1u32).ok_or(ParseError::ParseError)?.checked_mul(clocks).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let flags = ClockFlag::from(flags);
         let result = GetDotClocksReply { response_type, sequence, length, flags, clocks, maxclocks, clock };
         Ok((result, remaining))
     }
@@ -1337,18 +2513,80 @@ impl TryFrom<&[u8]> for GetDotClocksReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDotClocksReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        self.clocks.serialize_into_with_order(bytes, order);
+        self.maxclocks.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        assert_eq!(self.clock.len(), usize::try_from(1u32.checked_sub(u32::from(self.flags) & 1u32).unwrap().checked_mul(self.clocks).unwrap()).unwrap(), "`clock` has an incorrect length");
+        self.clock.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetClientVersion request
 pub const SET_CLIENT_VERSION_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetClientVersionRequest {
+    pub major: u16,
+    pub minor: u16,
+}
+impl SetClientVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CLIENT_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetClientVersionRequest {
+            major,
+            minor,
+        })
+    }
+    /// Set the `major` field of this structure.
+    pub fn major(mut self, value: u16) -> Self {
+        self.major = value;
+        self
+    }
+    /// Set the `minor` field of this structure.
+    pub fn minor(mut self, value: u16) -> Self {
+        self.minor = value;
+        self
+    }
+}
+
 pub fn set_client_version<Conn>(conn: &Conn, major: u16, minor: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_bytes = major.serialize();
-    let minor_bytes = minor.serialize();
+    let major_bytes = major.serialize_with_order(order);
+    let minor_bytes = minor.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_CLIENT_VERSION_REQUEST,
@@ -1362,23 +2600,87 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetClientVersionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetClientVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_client_version(conn, self.major, self.minor)
+    }
+}
 
 /// Opcode for the SetGamma request
 pub const SET_GAMMA_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetGammaRequest {
+    pub screen: u16,
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+impl SetGammaRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_GAMMA_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (red, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (green, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (blue, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetGammaRequest {
+            screen,
+            red,
+            green,
+            blue,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `red` field of this structure.
+    pub fn red(mut self, value: u32) -> Self {
+        self.red = value;
+        self
+    }
+    /// Set the `green` field of this structure.
+    pub fn green(mut self, value: u32) -> Self {
+        self.green = value;
+        self
+    }
+    /// Set the `blue` field of this structure.
+    pub fn blue(mut self, value: u32) -> Self {
+        self.blue = value;
+        self
+    }
+}
+
 pub fn set_gamma<Conn>(conn: &Conn, screen: u16, red: u32, green: u32, blue: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let red_bytes = red.serialize();
-    let green_bytes = green.serialize();
-    let blue_bytes = blue.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let red_bytes = red.serialize_with_order(order);
+    let green_bytes = green.serialize_with_order(order);
+    let blue_bytes = blue.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_GAMMA_REQUEST,
@@ -1416,20 +2718,59 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetGammaRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetGammaRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_gamma(conn, self.screen, self.red, self.green, self.blue)
+    }
+}
 
 /// Opcode for the GetGamma request
 pub const GET_GAMMA_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetGammaRequest {
+    pub screen: u16,
+}
+impl GetGammaRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_GAMMA_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(26..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetGammaRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_gamma<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetGammaReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_GAMMA_REQUEST,
@@ -1467,9 +2808,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetGammaRequest {
+    type Reply = GetGammaReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetGammaRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetGammaReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_gamma(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetGammaReply {
@@ -1481,14 +2834,14 @@ pub struct GetGammaReply {
     pub blue: u32,
 }
 impl TryParse for GetGammaReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (red, remaining) = u32::try_parse(remaining)?;
-        let (green, remaining) = u32::try_parse(remaining)?;
-        let (blue, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (red, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (green, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (blue, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let result = GetGammaReply { response_type, sequence, length, red, green, blue };
         Ok((result, remaining))
@@ -1500,18 +2853,78 @@ impl TryFrom<&[u8]> for GetGammaReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetGammaReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.red.serialize_into_with_order(bytes, order);
+        self.green.serialize_into_with_order(bytes, order);
+        self.blue.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetGammaRamp request
 pub const GET_GAMMA_RAMP_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetGammaRampRequest {
+    pub screen: u16,
+    pub size: u16,
+}
+impl GetGammaRampRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_GAMMA_RAMP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetGammaRampRequest {
+            screen,
+            size,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `size` field of this structure.
+    pub fn size(mut self, value: u16) -> Self {
+        self.size = value;
+        self
+    }
+}
+
 pub fn get_gamma_ramp<Conn>(conn: &Conn, screen: u16, size: u16) -> Result<Cookie<'_, Conn, GetGammaRampReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let size_bytes = size.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let size_bytes = size.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_GAMMA_RAMP_REQUEST,
@@ -1525,9 +2938,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetGammaRampRequest {
+    type Reply = GetGammaRampReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetGammaRampRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetGammaRampReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_gamma_ramp(conn, self.screen, self.size)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetGammaRampReply {
@@ -1540,16 +2965,16 @@ pub struct GetGammaRampReply {
     pub blue: Vec<u16>,
 }
 impl TryParse for GetGammaRampReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::ParseError)? & (!1u32)).try_into().or(Err(ParseError::ParseError))?)?;
-        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::ParseError)? & (!1u32)).try_into().or(Err(ParseError::ParseError))?)?;
-        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::ParseError)? & (!1u32)).try_into().or(Err(ParseError::ParseError))?)?;
+        let (red, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::ParseError)? & (!1u32)).try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (green, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::ParseError)? & (!1u32)).try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (blue, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::ParseError)? & (!1u32)).try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetGammaRampReply { response_type, sequence, length, size, red, green, blue };
         Ok((result, remaining))
     }
@@ -1560,18 +2985,106 @@ impl TryFrom<&[u8]> for GetGammaRampReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetGammaRampReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        assert_eq!(self.red.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`red` has an incorrect length");
+        self.red.serialize_into_with_order(bytes, order);
+        assert_eq!(self.green.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`green` has an incorrect length");
+        self.green.serialize_into_with_order(bytes, order);
+        assert_eq!(self.blue.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`blue` has an incorrect length");
+        self.blue.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetGammaRamp request
 pub const SET_GAMMA_RAMP_REQUEST: u8 = 18;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetGammaRampRequest {
+    pub screen: u16,
+    pub size: u16,
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
+}
+impl SetGammaRampRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_GAMMA_RAMP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (red, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::ParseError)? & (!1u32)).try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (green, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::ParseError)? & (!1u32)).try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (blue, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::ParseError)? & (!1u32)).try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetGammaRampRequest {
+            screen,
+            size,
+            red,
+            green,
+            blue,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+    /// Set the `size` field of this structure.
+    pub fn size(mut self, value: u16) -> Self {
+        self.size = value;
+        self
+    }
+    /// Set the `red` field of this structure.
+    pub fn red<I>(mut self, value: I) -> Self where I: Into<Vec<u16>> {
+        self.red = value.into();
+        self
+    }
+    /// Set the `green` field of this structure.
+    pub fn green<I>(mut self, value: I) -> Self where I: Into<Vec<u16>> {
+        self.green = value.into();
+        self
+    }
+    /// Set the `blue` field of this structure.
+    pub fn blue<I>(mut self, value: I) -> Self where I: Into<Vec<u16>> {
+        self.blue = value.into();
+        self
+    }
+}
+
 pub fn set_gamma_ramp<'c, Conn>(conn: &'c Conn, screen: u16, size: u16, red: &[u16], green: &[u16], blue: &[u16]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
-    let size_bytes = size.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
+    let size_bytes = size.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_GAMMA_RAMP_REQUEST,
@@ -1584,32 +3097,71 @@ where
     ];
     let length_so_far = length_so_far + request0.len();
     assert_eq!(red.len(), usize::try_from(u32::from(size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`red` has an incorrect length");
-    let red_bytes = red.serialize();
+    let red_bytes = red.serialize_with_order(order);
     let length_so_far = length_so_far + red_bytes.len();
     assert_eq!(green.len(), usize::try_from(u32::from(size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`green` has an incorrect length");
-    let green_bytes = green.serialize();
+    let green_bytes = green.serialize_with_order(order);
     let length_so_far = length_so_far + green_bytes.len();
     assert_eq!(blue.len(), usize::try_from(u32::from(size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`blue` has an incorrect length");
-    let blue_bytes = blue.serialize();
+    let blue_bytes = blue.serialize_with_order(order);
     let length_so_far = length_so_far + blue_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&red_bytes), IoSlice::new(&green_bytes), IoSlice::new(&blue_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetGammaRampRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetGammaRampRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_gamma_ramp(conn, self.screen, self.size, &self.red, &self.green, &self.blue)
+    }
+}
 
 /// Opcode for the GetGammaRampSize request
 pub const GET_GAMMA_RAMP_SIZE_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetGammaRampSizeRequest {
+    pub screen: u16,
+}
+impl GetGammaRampSizeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_GAMMA_RAMP_SIZE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetGammaRampSizeRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_gamma_ramp_size<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetGammaRampSizeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_GAMMA_RAMP_SIZE_REQUEST,
@@ -1623,9 +3175,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetGammaRampSizeRequest {
+    type Reply = GetGammaRampSizeReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetGammaRampSizeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetGammaRampSizeReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_gamma_ramp_size(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetGammaRampSizeReply {
@@ -1635,12 +3199,12 @@ pub struct GetGammaRampSizeReply {
     pub size: u16,
 }
 impl TryParse for GetGammaRampSizeReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
         let result = GetGammaRampSizeReply { response_type, sequence, length, size };
         Ok((result, remaining))
@@ -1652,17 +3216,68 @@ impl TryFrom<&[u8]> for GetGammaRampSizeReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetGammaRampSizeReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetPermissions request
 pub const GET_PERMISSIONS_REQUEST: u8 = 20;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetPermissionsRequest {
+    pub screen: u16,
+}
+impl GetPermissionsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PERMISSIONS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (screen, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetPermissionsRequest {
+            screen,
+        })
+    }
+    /// Set the `screen` field of this structure.
+    pub fn screen(mut self, value: u16) -> Self {
+        self.screen = value;
+        self
+    }
+}
+
 pub fn get_permissions<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetPermissionsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let screen_bytes = screen.serialize();
+    let screen_bytes = screen.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PERMISSIONS_REQUEST,
@@ -1676,25 +3291,38 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetPermissionsRequest {
+    type Reply = GetPermissionsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetPermissionsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetPermissionsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_permissions(conn, self.screen)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPermissionsReply {
     pub response_type: u8,
     pub sequence: u16,
     pub length: u32,
-    pub permissions: u32,
+    pub permissions: Permission,
 }
 impl TryParse for GetPermissionsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (permissions, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (permissions, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
+        let permissions = Permission::from(permissions);
         let result = GetPermissionsReply { response_type, sequence, length, permissions };
         Ok((result, remaining))
     }
@@ -1705,6 +3333,30 @@ impl TryFrom<&[u8]> for GetPermissionsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetPermissionsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u32::from(self.permissions).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the BadClock error
 pub const BAD_CLOCK_ERROR: u8 = 0;
@@ -1715,10 +3367,10 @@ pub struct BadClockError {
     pub sequence: u16,
 }
 impl TryParse for BadClockError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadClockError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1731,9 +3383,20 @@ impl TryFrom<&[u8]> for BadClockError {
 }
 impl From<&BadClockError> for [u8; 32] {
     fn from(input: &BadClockError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadClockError> for [u8; 32] {
+    fn from(input: BadClockError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadClockError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1770,10 +3433,8 @@ impl From<&BadClockError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadClockError> for [u8; 32] {
-    fn from(input: BadClockError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1786,10 +3447,10 @@ pub struct BadHTimingsError {
     pub sequence: u16,
 }
 impl TryParse for BadHTimingsError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadHTimingsError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1802,9 +3463,20 @@ impl TryFrom<&[u8]> for BadHTimingsError {
 }
 impl From<&BadHTimingsError> for [u8; 32] {
     fn from(input: &BadHTimingsError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadHTimingsError> for [u8; 32] {
+    fn from(input: BadHTimingsError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadHTimingsError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1841,10 +3513,8 @@ impl From<&BadHTimingsError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadHTimingsError> for [u8; 32] {
-    fn from(input: BadHTimingsError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1857,10 +3527,10 @@ pub struct BadVTimingsError {
     pub sequence: u16,
 }
 impl TryParse for BadVTimingsError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadVTimingsError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1873,9 +3543,20 @@ impl TryFrom<&[u8]> for BadVTimingsError {
 }
 impl From<&BadVTimingsError> for [u8; 32] {
     fn from(input: &BadVTimingsError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadVTimingsError> for [u8; 32] {
+    fn from(input: BadVTimingsError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadVTimingsError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1912,10 +3593,8 @@ impl From<&BadVTimingsError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadVTimingsError> for [u8; 32] {
-    fn from(input: BadVTimingsError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1928,10 +3607,10 @@ pub struct ModeUnsuitableError {
     pub sequence: u16,
 }
 impl TryParse for ModeUnsuitableError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = ModeUnsuitableError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1944,9 +3623,20 @@ impl TryFrom<&[u8]> for ModeUnsuitableError {
 }
 impl From<&ModeUnsuitableError> for [u8; 32] {
     fn from(input: &ModeUnsuitableError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<ModeUnsuitableError> for [u8; 32] {
+    fn from(input: ModeUnsuitableError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ModeUnsuitableError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1983,10 +3673,8 @@ impl From<&ModeUnsuitableError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ModeUnsuitableError> for [u8; 32] {
-    fn from(input: ModeUnsuitableError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1999,10 +3687,10 @@ pub struct ExtensionDisabledError {
     pub sequence: u16,
 }
 impl TryParse for ExtensionDisabledError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = ExtensionDisabledError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -2015,9 +3703,20 @@ impl TryFrom<&[u8]> for ExtensionDisabledError {
 }
 impl From<&ExtensionDisabledError> for [u8; 32] {
     fn from(input: &ExtensionDisabledError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<ExtensionDisabledError> for [u8; 32] {
+    fn from(input: ExtensionDisabledError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ExtensionDisabledError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -2054,10 +3753,8 @@ impl From<&ExtensionDisabledError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ExtensionDisabledError> for [u8; 32] {
-    fn from(input: ExtensionDisabledError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -2070,10 +3767,10 @@ pub struct ClientNotLocalError {
     pub sequence: u16,
 }
 impl TryParse for ClientNotLocalError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = ClientNotLocalError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -2086,9 +3783,20 @@ impl TryFrom<&[u8]> for ClientNotLocalError {
 }
 impl From<&ClientNotLocalError> for [u8; 32] {
     fn from(input: &ClientNotLocalError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<ClientNotLocalError> for [u8; 32] {
+    fn from(input: ClientNotLocalError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ClientNotLocalError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -2125,10 +3833,8 @@ impl From<&ClientNotLocalError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ClientNotLocalError> for [u8; 32] {
-    fn from(input: ClientNotLocalError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -2141,10 +3847,10 @@ pub struct ZoomLockedError {
     pub sequence: u16,
 }
 impl TryParse for ZoomLockedError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = ZoomLockedError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -2157,9 +3863,20 @@ impl TryFrom<&[u8]> for ZoomLockedError {
 }
 impl From<&ZoomLockedError> for [u8; 32] {
     fn from(input: &ZoomLockedError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<ZoomLockedError> for [u8; 32] {
+    fn from(input: ZoomLockedError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ZoomLockedError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -2196,10 +3913,8 @@ impl From<&ZoomLockedError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ZoomLockedError> for [u8; 32] {
-    fn from(input: ZoomLockedError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -2215,7 +3930,7 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn xf86vidmode_mod_mode_line<'c, A>(&'c self, screen: u32, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<ModeFlag>,
     {
         mod_mode_line(self, screen, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private)
     }
@@ -2237,26 +3952,26 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn xf86vidmode_add_mode_line<'c, A, B>(&'c self, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, after_dotclock: Dotclock, after_hdisplay: u16, after_hsyncstart: u16, after_hsyncend: u16, after_htotal: u16, after_hskew: u16, after_vdisplay: u16, after_vsyncstart: u16, after_vsyncend: u16, after_vtotal: u16, after_flags: B, private: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u32>,
-        B: Into<u32>,
+        A: Into<ModeFlag>,
+        B: Into<ModeFlag>,
     {
         add_mode_line(self, screen, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, after_dotclock, after_hdisplay, after_hsyncstart, after_hsyncend, after_htotal, after_hskew, after_vdisplay, after_vsyncstart, after_vsyncend, after_vtotal, after_flags, private)
     }
     fn xf86vidmode_delete_mode_line<'c, A>(&'c self, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<ModeFlag>,
     {
         delete_mode_line(self, screen, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private)
     }
     fn xf86vidmode_validate_mode_line<'c, A>(&'c self, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &[u8]) -> Result<Cookie<'c, Self, ValidateModeLineReply>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<ModeFlag>,
     {
         validate_mode_line(self, screen, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private)
     }
     fn xf86vidmode_switch_to_mode<'c, A>(&'c self, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<ModeFlag>,
     {
         switch_to_mode(self, screen, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private)
     }
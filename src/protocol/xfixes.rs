@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -35,15 +35,49 @@ pub const X11_XML_VERSION: (u32, u32) = (5, 0);
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (client_major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
+    }
+    /// Set the `client_major_version` field of this structure.
+    pub fn client_major_version(mut self, value: u32) -> Self {
+        self.client_major_version = value;
+        self
+    }
+    /// Set the `client_minor_version` field of this structure.
+    pub fn client_minor_version(mut self, value: u32) -> Self {
+        self.client_minor_version = value;
+        self
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let client_major_version_bytes = client_major_version.serialize();
-    let client_minor_version_bytes = client_minor_version.serialize();
+    let client_major_version_bytes = client_major_version.serialize_with_order(order);
+    let client_minor_version_bytes = client_minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_VERSION_REQUEST,
@@ -61,9 +95,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn, self.client_major_version, self.client_minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -74,13 +120,13 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
         let result = QueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
@@ -92,230 +138,222 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SaveSetMode {
-    Insert = 0,
-    Delete = 1,
-}
-impl From<SaveSetMode> for bool {
-    fn from(input: SaveSetMode) -> Self {
-        match input {
-            SaveSetMode::Insert => false,
-            SaveSetMode::Delete => true,
-        }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
     }
 }
-impl From<SaveSetMode> for u8 {
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SaveSetMode(u8);
+#[allow(non_upper_case_globals)]
+impl SaveSetMode {
+    pub const Insert: Self = Self(0);
+    pub const Delete: Self = Self(1);
+}
+enum_newtype!(SaveSetMode, u8, [Insert, Delete]);
+impl From<SaveSetMode> for bool {
     fn from(input: SaveSetMode) -> Self {
-        match input {
-            SaveSetMode::Insert => 0,
-            SaveSetMode::Delete => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<SaveSetMode> for Option<u8> {
     fn from(input: SaveSetMode) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SaveSetMode> for u16 {
     fn from(input: SaveSetMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SaveSetMode> for Option<u16> {
     fn from(input: SaveSetMode) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SaveSetMode> for u32 {
     fn from(input: SaveSetMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SaveSetMode> for Option<u32> {
     fn from(input: SaveSetMode) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SaveSetMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SaveSetMode::Insert),
-            1 => Ok(SaveSetMode::Delete),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SaveSetMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SaveSetMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SaveSetTarget {
-    Nearest = 0,
-    Root = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SaveSetTarget(u8);
+#[allow(non_upper_case_globals)]
+impl SaveSetTarget {
+    pub const Nearest: Self = Self(0);
+    pub const Root: Self = Self(1);
+}
+enum_newtype!(SaveSetTarget, u8, [Nearest, Root]);
 impl From<SaveSetTarget> for bool {
     fn from(input: SaveSetTarget) -> Self {
-        match input {
-            SaveSetTarget::Nearest => false,
-            SaveSetTarget::Root => true,
-        }
-    }
-}
-impl From<SaveSetTarget> for u8 {
-    fn from(input: SaveSetTarget) -> Self {
-        match input {
-            SaveSetTarget::Nearest => 0,
-            SaveSetTarget::Root => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<SaveSetTarget> for Option<u8> {
     fn from(input: SaveSetTarget) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SaveSetTarget> for u16 {
     fn from(input: SaveSetTarget) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SaveSetTarget> for Option<u16> {
     fn from(input: SaveSetTarget) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SaveSetTarget> for u32 {
     fn from(input: SaveSetTarget) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SaveSetTarget> for Option<u32> {
     fn from(input: SaveSetTarget) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SaveSetTarget {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SaveSetTarget::Nearest),
-            1 => Ok(SaveSetTarget::Root),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SaveSetTarget {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SaveSetTarget {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SaveSetMapping {
-    Map = 0,
-    Unmap = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SaveSetMapping(u8);
+#[allow(non_upper_case_globals)]
+impl SaveSetMapping {
+    pub const Map: Self = Self(0);
+    pub const Unmap: Self = Self(1);
+}
+enum_newtype!(SaveSetMapping, u8, [Map, Unmap]);
 impl From<SaveSetMapping> for bool {
     fn from(input: SaveSetMapping) -> Self {
-        match input {
-            SaveSetMapping::Map => false,
-            SaveSetMapping::Unmap => true,
-        }
-    }
-}
-impl From<SaveSetMapping> for u8 {
-    fn from(input: SaveSetMapping) -> Self {
-        match input {
-            SaveSetMapping::Map => 0,
-            SaveSetMapping::Unmap => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<SaveSetMapping> for Option<u8> {
     fn from(input: SaveSetMapping) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SaveSetMapping> for u16 {
     fn from(input: SaveSetMapping) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SaveSetMapping> for Option<u16> {
     fn from(input: SaveSetMapping) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SaveSetMapping> for u32 {
     fn from(input: SaveSetMapping) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SaveSetMapping> for Option<u32> {
     fn from(input: SaveSetMapping) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SaveSetMapping {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SaveSetMapping::Map),
-            1 => Ok(SaveSetMapping::Unmap),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for SaveSetMapping {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
+
+/// Opcode for the ChangeSaveSet request
+pub const CHANGE_SAVE_SET_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChangeSaveSetRequest {
+    pub mode: SaveSetMode,
+    pub target: SaveSetTarget,
+    pub map: SaveSetMapping,
+    pub window: xproto::Window,
 }
-impl TryFrom<u32> for SaveSetMapping {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl ChangeSaveSetRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_SAVE_SET_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (target, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let mode = SaveSetMode::from(mode);
+        let target = SaveSetTarget::from(target);
+        let map = SaveSetMapping::from(map);
+        Ok(ChangeSaveSetRequest {
+            mode,
+            target,
+            map,
+            window,
+        })
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<SaveSetMode> {
+        self.mode = value.into();
+        self
+    }
+    /// Set the `target` field of this structure.
+    pub fn target<I>(mut self, value: I) -> Self where I: Into<SaveSetTarget> {
+        self.target = value.into();
+        self
+    }
+    /// Set the `map` field of this structure.
+    pub fn map<I>(mut self, value: I) -> Self where I: Into<SaveSetMapping> {
+        self.map = value.into();
+        self
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
     }
 }
 
-/// Opcode for the ChangeSaveSet request
-pub const CHANGE_SAVE_SET_REQUEST: u8 = 1;
-pub fn change_save_set<Conn>(conn: &Conn, mode: SaveSetMode, target: SaveSetTarget, map: SaveSetMapping, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+pub fn change_save_set<Conn, A, B, C>(conn: &Conn, mode: A, target: B, map: C, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<SaveSetMode>,
+    B: Into<SaveSetTarget>,
+    C: Into<SaveSetMapping>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let mode: SaveSetMode = mode.into();
+    let target: SaveSetTarget = target.into();
+    let map: SaveSetMapping = map.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let mode_bytes = u8::from(mode).serialize();
-    let target_bytes = u8::from(target).serialize();
-    let map_bytes = u8::from(map).serialize();
-    let window_bytes = window.serialize();
+    let mode_bytes = u8::from(mode).serialize_with_order(order);
+    let target_bytes = u8::from(target).serialize_with_order(order);
+    let map_bytes = u8::from(map).serialize_with_order(order);
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_SAVE_SET_REQUEST,
@@ -333,140 +371,71 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SelectionEvent {
-    SetSelectionOwner = 0,
-    SelectionWindowDestroy = 1,
-    SelectionClientClose = 2,
+impl crate::x11_utils::Request for ChangeSaveSetRequest {
+    type Reply = ();
 }
-impl From<SelectionEvent> for u8 {
-    fn from(input: SelectionEvent) -> Self {
-        match input {
-            SelectionEvent::SetSelectionOwner => 0,
-            SelectionEvent::SelectionWindowDestroy => 1,
-            SelectionEvent::SelectionClientClose => 2,
-        }
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeSaveSetRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_save_set(conn, self.mode, self.target, self.map, self.window)
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SelectionEvent(u8);
+#[allow(non_upper_case_globals)]
+impl SelectionEvent {
+    pub const SetSelectionOwner: Self = Self(0);
+    pub const SelectionWindowDestroy: Self = Self(1);
+    pub const SelectionClientClose: Self = Self(2);
+}
+enum_newtype!(SelectionEvent, u8, [SetSelectionOwner, SelectionWindowDestroy, SelectionClientClose]);
 impl From<SelectionEvent> for Option<u8> {
     fn from(input: SelectionEvent) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SelectionEvent> for u16 {
     fn from(input: SelectionEvent) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SelectionEvent> for Option<u16> {
     fn from(input: SelectionEvent) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SelectionEvent> for u32 {
     fn from(input: SelectionEvent) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SelectionEvent> for Option<u32> {
     fn from(input: SelectionEvent) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SelectionEvent {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SelectionEvent::SetSelectionOwner),
-            1 => Ok(SelectionEvent::SelectionWindowDestroy),
-            2 => Ok(SelectionEvent::SelectionClientClose),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SelectionEvent {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SelectionEvent {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SelectionEventMask {
-    SetSelectionOwner = 1 << 0,
-    SelectionWindowDestroy = 1 << 1,
-    SelectionClientClose = 1 << 2,
-}
-impl From<SelectionEventMask> for u8 {
-    fn from(input: SelectionEventMask) -> Self {
-        match input {
-            SelectionEventMask::SetSelectionOwner => 1 << 0,
-            SelectionEventMask::SelectionWindowDestroy => 1 << 1,
-            SelectionEventMask::SelectionClientClose => 1 << 2,
-        }
-    }
-}
-impl From<SelectionEventMask> for Option<u8> {
-    fn from(input: SelectionEventMask) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<SelectionEventMask> for u16 {
-    fn from(input: SelectionEventMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<SelectionEventMask> for Option<u16> {
-    fn from(input: SelectionEventMask) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<SelectionEventMask> for u32 {
-    fn from(input: SelectionEventMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SelectionEventMask(u32);
+#[allow(non_upper_case_globals)]
+impl SelectionEventMask {
+    pub const SetSelectionOwner: Self = Self(1 << 0);
+    pub const SelectionWindowDestroy: Self = Self(1 << 1);
+    pub const SelectionClientClose: Self = Self(1 << 2);
+}
+bitmask_set!(SelectionEventMask, u32, [SetSelectionOwner, SelectionWindowDestroy, SelectionClientClose]);
 impl From<SelectionEventMask> for Option<u32> {
     fn from(input: SelectionEventMask) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SelectionEventMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(SelectionEventMask::SetSelectionOwner),
-            2 => Ok(SelectionEventMask::SelectionWindowDestroy),
-            4 => Ok(SelectionEventMask::SelectionClientClose),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(input.0)
     }
 }
-impl TryFrom<u16> for SelectionEventMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SelectionEventMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(SelectionEventMask, u8);
 
 /// Opcode for the SelectionNotify event
 pub const SELECTION_NOTIFY_EVENT: u8 = 0;
@@ -482,17 +451,17 @@ pub struct SelectionNotifyEvent {
     pub selection_timestamp: xproto::Timestamp,
 }
 impl TryParse for SelectionNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (subtype, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (owner, remaining) = xproto::Window::try_parse(remaining)?;
-        let (selection, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (selection_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (subtype, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (owner, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (selection, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (selection_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let subtype = subtype.try_into()?;
+        let subtype = SelectionEvent::from(subtype);
         let result = SelectionNotifyEvent { response_type, subtype, sequence, window, owner, selection, timestamp, selection_timestamp };
         Ok((result, remaining))
     }
@@ -505,14 +474,25 @@ impl TryFrom<&[u8]> for SelectionNotifyEvent {
 }
 impl From<&SelectionNotifyEvent> for [u8; 32] {
     fn from(input: &SelectionNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let subtype_bytes = u8::from(input.subtype).serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let window_bytes = input.window.serialize();
-        let owner_bytes = input.owner.serialize();
-        let selection_bytes = input.selection.serialize();
-        let timestamp_bytes = input.timestamp.serialize();
-        let selection_timestamp_bytes = input.selection_timestamp.serialize();
+        input.serialize()
+    }
+}
+impl From<SelectionNotifyEvent> for [u8; 32] {
+    fn from(input: SelectionNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for SelectionNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let subtype_bytes = u8::from(self.subtype).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let owner_bytes = self.owner.serialize_with_order(order);
+        let selection_bytes = self.selection.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let selection_timestamp_bytes = self.selection_timestamp.serialize_with_order(order);
         [
             response_type_bytes[0],
             subtype_bytes[0],
@@ -548,27 +528,68 @@ impl From<&SelectionNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<SelectionNotifyEvent> for [u8; 32] {
-    fn from(input: SelectionNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
 /// Opcode for the SelectSelectionInput request
 pub const SELECT_SELECTION_INPUT_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectSelectionInputRequest {
+    pub window: xproto::Window,
+    pub selection: xproto::Atom,
+    pub event_mask: SelectionEventMask,
+}
+impl SelectSelectionInputRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_SELECTION_INPUT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (selection, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let event_mask = SelectionEventMask::from(event_mask);
+        Ok(SelectSelectionInputRequest {
+            window,
+            selection,
+            event_mask,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `selection` field of this structure.
+    pub fn selection<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.selection = value.into();
+        self
+    }
+    /// Set the `event_mask` field of this structure.
+    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<SelectionEventMask> {
+        self.event_mask = value.into();
+        self
+    }
+}
+
 pub fn select_selection_input<Conn, A>(conn: &Conn, window: xproto::Window, selection: xproto::Atom, event_mask: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<SelectionEventMask>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let event_mask: u32 = event_mask.into();
+    let event_mask: SelectionEventMask = event_mask.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let selection_bytes = selection.serialize();
-    let event_mask_bytes = event_mask.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let selection_bytes = selection.serialize_with_order(order);
+    let event_mask_bytes = u32::from(event_mask).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_SELECTION_INPUT_REQUEST,
@@ -590,128 +611,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum CursorNotify {
-    DisplayCursor = 0,
+impl crate::x11_utils::Request for SelectSelectionInputRequest {
+    type Reply = ();
 }
-impl From<CursorNotify> for u8 {
-    fn from(input: CursorNotify) -> Self {
-        match input {
-            CursorNotify::DisplayCursor => 0,
-        }
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectSelectionInputRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_selection_input(conn, self.window, self.selection, self.event_mask)
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CursorNotify(u8);
+#[allow(non_upper_case_globals)]
+impl CursorNotify {
+    pub const DisplayCursor: Self = Self(0);
+}
+enum_newtype!(CursorNotify, u8, [DisplayCursor]);
 impl From<CursorNotify> for Option<u8> {
     fn from(input: CursorNotify) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<CursorNotify> for u16 {
     fn from(input: CursorNotify) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CursorNotify> for Option<u16> {
     fn from(input: CursorNotify) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<CursorNotify> for u32 {
     fn from(input: CursorNotify) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CursorNotify> for Option<u32> {
     fn from(input: CursorNotify) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for CursorNotify {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(CursorNotify::DisplayCursor),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for CursorNotify {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for CursorNotify {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum CursorNotifyMask {
-    DisplayCursor = 1 << 0,
-}
-impl From<CursorNotifyMask> for u8 {
-    fn from(input: CursorNotifyMask) -> Self {
-        match input {
-            CursorNotifyMask::DisplayCursor => 1 << 0,
-        }
-    }
-}
-impl From<CursorNotifyMask> for Option<u8> {
-    fn from(input: CursorNotifyMask) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<CursorNotifyMask> for u16 {
-    fn from(input: CursorNotifyMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<CursorNotifyMask> for Option<u16> {
-    fn from(input: CursorNotifyMask) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<CursorNotifyMask> for u32 {
-    fn from(input: CursorNotifyMask) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CursorNotifyMask(u32);
+#[allow(non_upper_case_globals)]
+impl CursorNotifyMask {
+    pub const DisplayCursor: Self = Self(1 << 0);
 }
+bitmask_set!(CursorNotifyMask, u32, [DisplayCursor]);
 impl From<CursorNotifyMask> for Option<u32> {
     fn from(input: CursorNotifyMask) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for CursorNotifyMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(CursorNotifyMask::DisplayCursor),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for CursorNotifyMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for CursorNotifyMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-bitmask_binop!(CursorNotifyMask, u8);
 
 /// Opcode for the CursorNotify event
 pub const CURSOR_NOTIFY_EVENT: u8 = 1;
@@ -726,16 +686,16 @@ pub struct CursorNotifyEvent {
     pub name: xproto::Atom,
 }
 impl TryParse for CursorNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (subtype, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (cursor_serial, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (subtype, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (cursor_serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let subtype = subtype.try_into()?;
+        let subtype = CursorNotify::from(subtype);
         let result = CursorNotifyEvent { response_type, subtype, sequence, window, cursor_serial, timestamp, name };
         Ok((result, remaining))
     }
@@ -748,13 +708,24 @@ impl TryFrom<&[u8]> for CursorNotifyEvent {
 }
 impl From<&CursorNotifyEvent> for [u8; 32] {
     fn from(input: &CursorNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let subtype_bytes = u8::from(input.subtype).serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let window_bytes = input.window.serialize();
-        let cursor_serial_bytes = input.cursor_serial.serialize();
-        let timestamp_bytes = input.timestamp.serialize();
-        let name_bytes = input.name.serialize();
+        input.serialize()
+    }
+}
+impl From<CursorNotifyEvent> for [u8; 32] {
+    fn from(input: CursorNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for CursorNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let subtype_bytes = u8::from(self.subtype).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let cursor_serial_bytes = self.cursor_serial.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
         [
             response_type_bytes[0],
             subtype_bytes[0],
@@ -790,26 +761,59 @@ impl From<&CursorNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<CursorNotifyEvent> for [u8; 32] {
-    fn from(input: CursorNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
 /// Opcode for the SelectCursorInput request
 pub const SELECT_CURSOR_INPUT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectCursorInputRequest {
+    pub window: xproto::Window,
+    pub event_mask: CursorNotifyMask,
+}
+impl SelectCursorInputRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_CURSOR_INPUT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let event_mask = CursorNotifyMask::from(event_mask);
+        Ok(SelectCursorInputRequest {
+            window,
+            event_mask,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `event_mask` field of this structure.
+    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<CursorNotifyMask> {
+        self.event_mask = value.into();
+        self
+    }
+}
+
 pub fn select_cursor_input<Conn, A>(conn: &Conn, window: xproto::Window, event_mask: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<CursorNotifyMask>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let event_mask: u32 = event_mask.into();
+    let event_mask: CursorNotifyMask = event_mask.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let event_mask_bytes = event_mask.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let event_mask_bytes = u32::from(event_mask).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_CURSOR_INPUT_REQUEST,
@@ -827,18 +831,48 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectCursorInputRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectCursorInputRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_cursor_input(conn, self.window, self.event_mask)
+    }
+}
 
 /// Opcode for the GetCursorImage request
 pub const GET_CURSOR_IMAGE_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetCursorImageRequest {
+}
+impl GetCursorImageRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CURSOR_IMAGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetCursorImageRequest {
+        })
+    }
+}
+
 pub fn get_cursor_image<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetCursorImageReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -849,9 +883,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetCursorImageRequest {
+    type Reply = GetCursorImageReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetCursorImageRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetCursorImageReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_cursor_image(conn)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCursorImageReply {
@@ -868,20 +914,20 @@ pub struct GetCursorImageReply {
     pub cursor_image: Vec<u32>,
 }
 impl TryParse for GetCursorImageReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (xhot, remaining) = u16::try_parse(remaining)?;
-        let (yhot, remaining) = u16::try_parse(remaining)?;
-        let (cursor_serial, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (xhot, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (yhot, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (cursor_serial, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let (cursor_image, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (cursor_image, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetCursorImageReply { response_type, sequence, length, x, y, width, height, xhot, yhot, cursor_serial, cursor_image };
         Ok((result, remaining))
     }
@@ -892,8 +938,70 @@ impl TryFrom<&[u8]> for GetCursorImageReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetCursorImageReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.xhot.serialize_into_with_order(bytes, order);
+        self.yhot.serialize_into_with_order(bytes, order);
+        self.cursor_serial.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        assert_eq!(self.cursor_image.len(), usize::try_from(u32::from(self.width).checked_mul(u32::from(self.height)).unwrap()).unwrap(), "`cursor_image` has an incorrect length");
+        self.cursor_image.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
-pub type Region = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Region(u32);
+impl Region {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Region(0);
+}
+impl From<u32> for Region {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Region> for u32 {
+    fn from(value: Region) -> Self {
+        value.0
+    }
+}
+impl TryParse for Region {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Region {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
 /// Opcode for the BadRegion error
 pub const BAD_REGION_ERROR: u8 = 0;
@@ -904,10 +1012,10 @@ pub struct BadRegionError {
     pub sequence: u16,
 }
 impl TryParse for BadRegionError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadRegionError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -920,9 +1028,20 @@ impl TryFrom<&[u8]> for BadRegionError {
 }
 impl From<&BadRegionError> for [u8; 32] {
     fn from(input: &BadRegionError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadRegionError> for [u8; 32] {
+    fn from(input: BadRegionError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadRegionError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -959,82 +1078,105 @@ impl From<&BadRegionError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadRegionError> for [u8; 32] {
-    fn from(input: BadRegionError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum RegionEnum {
-    None = 0,
-}
-impl From<RegionEnum> for u8 {
-    fn from(input: RegionEnum) -> Self {
-        match input {
-            RegionEnum::None => 0,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RegionEnum(u8);
+#[allow(non_upper_case_globals)]
+impl RegionEnum {
+    pub const None: Self = Self(0);
 }
+enum_newtype!(RegionEnum, u8, [None]);
 impl From<RegionEnum> for Option<u8> {
     fn from(input: RegionEnum) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<RegionEnum> for u16 {
     fn from(input: RegionEnum) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<RegionEnum> for Option<u16> {
     fn from(input: RegionEnum) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<RegionEnum> for u32 {
     fn from(input: RegionEnum) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<RegionEnum> for Option<u32> {
     fn from(input: RegionEnum) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for RegionEnum {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(RegionEnum::None),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for RegionEnum {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<RegionEnum> for Region {
+    fn from(input: RegionEnum) -> Self {
+        Self::from(u32::from(input))
     }
 }
-impl TryFrom<u32> for RegionEnum {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<RegionEnum> for Option<Region> {
+    fn from(input: RegionEnum) -> Self {
+        Some(Region::from(input))
     }
 }
 
 /// Opcode for the CreateRegion request
 pub const CREATE_REGION_REQUEST: u8 = 5;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreateRegionRequest {
+    pub region: Region,
+    pub rectangles: Vec<xproto::Rectangle>,
+}
+impl CreateRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut rectangles = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            rectangles.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateRegionRequest {
+            region,
+            rectangles,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `rectangles` field of this structure.
+    pub fn rectangles<I>(mut self, value: I) -> Self where I: Into<Vec<xproto::Rectangle>> {
+        self.rectangles = value.into();
+        self
+    }
+}
+
 pub fn create_region<'c, Conn>(conn: &'c Conn, region: Region, rectangles: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
+    let region_bytes = region.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_REGION_REQUEST,
@@ -1046,27 +1188,73 @@ where
         region_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let rectangles_bytes = rectangles.serialize();
+    let rectangles_bytes = rectangles.serialize_with_order(order);
     let length_so_far = length_so_far + rectangles_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&rectangles_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_region(conn, self.region, &self.rectangles)
+    }
+}
 
 /// Opcode for the CreateRegionFromBitmap request
 pub const CREATE_REGION_FROM_BITMAP_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateRegionFromBitmapRequest {
+    pub region: Region,
+    pub bitmap: xproto::Pixmap,
+}
+impl CreateRegionFromBitmapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_BITMAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (bitmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateRegionFromBitmapRequest {
+            region,
+            bitmap,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `bitmap` field of this structure.
+    pub fn bitmap<I>(mut self, value: I) -> Self where I: Into<xproto::Pixmap> {
+        self.bitmap = value.into();
+        self
+    }
+}
+
 pub fn create_region_from_bitmap<Conn>(conn: &Conn, region: Region, bitmap: xproto::Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
-    let bitmap_bytes = bitmap.serialize();
+    let region_bytes = region.serialize_with_order(order);
+    let bitmap_bytes = bitmap.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_REGION_FROM_BITMAP_REQUEST,
@@ -1084,22 +1272,80 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateRegionFromBitmapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateRegionFromBitmapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_region_from_bitmap(conn, self.region, self.bitmap)
+    }
+}
 
 /// Opcode for the CreateRegionFromWindow request
 pub const CREATE_REGION_FROM_WINDOW_REQUEST: u8 = 7;
-pub fn create_region_from_window<Conn>(conn: &Conn, region: Region, window: xproto::Window, kind: shape::SK) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateRegionFromWindowRequest {
+    pub region: Region,
+    pub window: xproto::Window,
+    pub kind: shape::SK,
+}
+impl CreateRegionFromWindowRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_WINDOW_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = shape::Kind::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let kind = shape::SK::from(kind);
+        Ok(CreateRegionFromWindowRequest {
+            region,
+            window,
+            kind,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `kind` field of this structure.
+    pub fn kind<I>(mut self, value: I) -> Self where I: Into<shape::SK> {
+        self.kind = value.into();
+        self
+    }
+}
+
+pub fn create_region_from_window<Conn, A>(conn: &Conn, region: Region, window: xproto::Window, kind: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<shape::SK>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let kind: shape::SK = kind.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
-    let window_bytes = window.serialize();
-    let kind_bytes = shape::Kind::from(kind).serialize();
+    let region_bytes = region.serialize_with_order(order);
+    let window_bytes = window.serialize_with_order(order);
+    let kind_bytes = u8::from(kind).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_REGION_FROM_WINDOW_REQUEST,
@@ -1121,21 +1367,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateRegionFromWindowRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateRegionFromWindowRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_region_from_window(conn, self.region, self.window, self.kind)
+    }
+}
 
 /// Opcode for the CreateRegionFromGC request
 pub const CREATE_REGION_FROM_GC_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateRegionFromGCRequest {
+    pub region: Region,
+    pub gc: xproto::Gcontext,
+}
+impl CreateRegionFromGCRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_GC_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateRegionFromGCRequest {
+            region,
+            gc,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `gc` field of this structure.
+    pub fn gc<I>(mut self, value: I) -> Self where I: Into<xproto::Gcontext> {
+        self.gc = value.into();
+        self
+    }
+}
+
 pub fn create_region_from_gc<Conn>(conn: &Conn, region: Region, gc: xproto::Gcontext) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
-    let gc_bytes = gc.serialize();
+    let region_bytes = region.serialize_with_order(order);
+    let gc_bytes = gc.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_REGION_FROM_GC_REQUEST,
@@ -1153,21 +1445,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateRegionFromGCRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateRegionFromGCRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_region_from_gc(conn, self.region, self.gc)
+    }
+}
 
 /// Opcode for the CreateRegionFromPicture request
 pub const CREATE_REGION_FROM_PICTURE_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateRegionFromPictureRequest {
+    pub region: Region,
+    pub picture: render::Picture,
+}
+impl CreateRegionFromPictureRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_PICTURE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (picture, remaining) = render::Picture::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateRegionFromPictureRequest {
+            region,
+            picture,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<render::Picture> {
+        self.picture = value.into();
+        self
+    }
+}
+
 pub fn create_region_from_picture<Conn>(conn: &Conn, region: Region, picture: render::Picture) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
-    let picture_bytes = picture.serialize();
+    let region_bytes = region.serialize_with_order(order);
+    let picture_bytes = picture.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_REGION_FROM_PICTURE_REQUEST,
@@ -1185,20 +1523,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateRegionFromPictureRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateRegionFromPictureRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_region_from_picture(conn, self.region, self.picture)
+    }
+}
 
 /// Opcode for the DestroyRegion request
 pub const DESTROY_REGION_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyRegionRequest {
+    pub region: Region,
+}
+impl DestroyRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyRegionRequest {
+            region,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+}
+
 pub fn destroy_region<Conn>(conn: &Conn, region: Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
+    let region_bytes = region.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_REGION_REQUEST,
@@ -1212,20 +1588,73 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_region(conn, self.region)
+    }
+}
+
+/// Opcode for the SetRegion request
+pub const SET_REGION_REQUEST: u8 = 11;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetRegionRequest {
+    pub region: Region,
+    pub rectangles: Vec<xproto::Rectangle>,
+}
+impl SetRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut rectangles = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            rectangles.push(v);
+        }
+        let _ = remaining;
+        let _ = order;
+        Ok(SetRegionRequest {
+            region,
+            rectangles,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `rectangles` field of this structure.
+    pub fn rectangles<I>(mut self, value: I) -> Self where I: Into<Vec<xproto::Rectangle>> {
+        self.rectangles = value.into();
+        self
+    }
+}
 
-/// Opcode for the SetRegion request
-pub const SET_REGION_REQUEST: u8 = 11;
 pub fn set_region<'c, Conn>(conn: &'c Conn, region: Region, rectangles: &[xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
+    let region_bytes = region.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_REGION_REQUEST,
@@ -1237,27 +1666,73 @@ where
         region_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let rectangles_bytes = rectangles.serialize();
+    let rectangles_bytes = rectangles.serialize_with_order(order);
     let length_so_far = length_so_far + rectangles_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&rectangles_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_region(conn, self.region, &self.rectangles)
+    }
+}
 
 /// Opcode for the CopyRegion request
 pub const COPY_REGION_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CopyRegionRequest {
+    pub source: Region,
+    pub destination: Region,
+}
+impl CopyRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COPY_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (source, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CopyRegionRequest {
+            source,
+            destination,
+        })
+    }
+    /// Set the `source` field of this structure.
+    pub fn source<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source = value.into();
+        self
+    }
+    /// Set the `destination` field of this structure.
+    pub fn destination<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.destination = value.into();
+        self
+    }
+}
+
 pub fn copy_region<Conn>(conn: &Conn, source: Region, destination: Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let source_bytes = source.serialize();
-    let destination_bytes = destination.serialize();
+    let source_bytes = source.serialize_with_order(order);
+    let destination_bytes = destination.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         COPY_REGION_REQUEST,
@@ -1275,22 +1750,76 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CopyRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CopyRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        copy_region(conn, self.source, self.destination)
+    }
+}
 
 /// Opcode for the UnionRegion request
 pub const UNION_REGION_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UnionRegionRequest {
+    pub source1: Region,
+    pub source2: Region,
+    pub destination: Region,
+}
+impl UnionRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNION_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (source1, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (source2, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(UnionRegionRequest {
+            source1,
+            source2,
+            destination,
+        })
+    }
+    /// Set the `source1` field of this structure.
+    pub fn source1<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source1 = value.into();
+        self
+    }
+    /// Set the `source2` field of this structure.
+    pub fn source2<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source2 = value.into();
+        self
+    }
+    /// Set the `destination` field of this structure.
+    pub fn destination<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.destination = value.into();
+        self
+    }
+}
+
 pub fn union_region<Conn>(conn: &Conn, source1: Region, source2: Region, destination: Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let source1_bytes = source1.serialize();
-    let source2_bytes = source2.serialize();
-    let destination_bytes = destination.serialize();
+    let source1_bytes = source1.serialize_with_order(order);
+    let source2_bytes = source2.serialize_with_order(order);
+    let destination_bytes = destination.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         UNION_REGION_REQUEST,
@@ -1312,22 +1841,76 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for UnionRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UnionRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        union_region(conn, self.source1, self.source2, self.destination)
+    }
+}
 
 /// Opcode for the IntersectRegion request
 pub const INTERSECT_REGION_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IntersectRegionRequest {
+    pub source1: Region,
+    pub source2: Region,
+    pub destination: Region,
+}
+impl IntersectRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INTERSECT_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (source1, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (source2, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(IntersectRegionRequest {
+            source1,
+            source2,
+            destination,
+        })
+    }
+    /// Set the `source1` field of this structure.
+    pub fn source1<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source1 = value.into();
+        self
+    }
+    /// Set the `source2` field of this structure.
+    pub fn source2<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source2 = value.into();
+        self
+    }
+    /// Set the `destination` field of this structure.
+    pub fn destination<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.destination = value.into();
+        self
+    }
+}
+
 pub fn intersect_region<Conn>(conn: &Conn, source1: Region, source2: Region, destination: Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let source1_bytes = source1.serialize();
-    let source2_bytes = source2.serialize();
-    let destination_bytes = destination.serialize();
+    let source1_bytes = source1.serialize_with_order(order);
+    let source2_bytes = source2.serialize_with_order(order);
+    let destination_bytes = destination.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         INTERSECT_REGION_REQUEST,
@@ -1349,22 +1932,76 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for IntersectRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for IntersectRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        intersect_region(conn, self.source1, self.source2, self.destination)
+    }
+}
 
 /// Opcode for the SubtractRegion request
 pub const SUBTRACT_REGION_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubtractRegionRequest {
+    pub source1: Region,
+    pub source2: Region,
+    pub destination: Region,
+}
+impl SubtractRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SUBTRACT_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (source1, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (source2, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SubtractRegionRequest {
+            source1,
+            source2,
+            destination,
+        })
+    }
+    /// Set the `source1` field of this structure.
+    pub fn source1<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source1 = value.into();
+        self
+    }
+    /// Set the `source2` field of this structure.
+    pub fn source2<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source2 = value.into();
+        self
+    }
+    /// Set the `destination` field of this structure.
+    pub fn destination<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.destination = value.into();
+        self
+    }
+}
+
 pub fn subtract_region<Conn>(conn: &Conn, source1: Region, source2: Region, destination: Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let source1_bytes = source1.serialize();
-    let source2_bytes = source2.serialize();
-    let destination_bytes = destination.serialize();
+    let source1_bytes = source1.serialize_with_order(order);
+    let source2_bytes = source2.serialize_with_order(order);
+    let destination_bytes = destination.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SUBTRACT_REGION_REQUEST,
@@ -1386,22 +2023,76 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SubtractRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SubtractRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        subtract_region(conn, self.source1, self.source2, self.destination)
+    }
+}
 
 /// Opcode for the InvertRegion request
 pub const INVERT_REGION_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvertRegionRequest {
+    pub source: Region,
+    pub bounds: xproto::Rectangle,
+    pub destination: Region,
+}
+impl InvertRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INVERT_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (source, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (bounds, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(InvertRegionRequest {
+            source,
+            bounds,
+            destination,
+        })
+    }
+    /// Set the `source` field of this structure.
+    pub fn source<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source = value.into();
+        self
+    }
+    /// Set the `bounds` field of this structure.
+    pub fn bounds<I>(mut self, value: I) -> Self where I: Into<xproto::Rectangle> {
+        self.bounds = value.into();
+        self
+    }
+    /// Set the `destination` field of this structure.
+    pub fn destination<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.destination = value.into();
+        self
+    }
+}
+
 pub fn invert_region<Conn>(conn: &Conn, source: Region, bounds: xproto::Rectangle, destination: Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let source_bytes = source.serialize();
-    let bounds_bytes = bounds.serialize();
-    let destination_bytes = destination.serialize();
+    let source_bytes = source.serialize_with_order(order);
+    let bounds_bytes = bounds.serialize_with_order(order);
+    let destination_bytes = destination.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         INVERT_REGION_REQUEST,
@@ -1427,22 +2118,76 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for InvertRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for InvertRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        invert_region(conn, self.source, self.bounds, self.destination)
+    }
+}
 
 /// Opcode for the TranslateRegion request
 pub const TRANSLATE_REGION_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TranslateRegionRequest {
+    pub region: Region,
+    pub dx: i16,
+    pub dy: i16,
+}
+impl TranslateRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != TRANSLATE_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (dx, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (dy, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(TranslateRegionRequest {
+            region,
+            dx,
+            dy,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `dx` field of this structure.
+    pub fn dx(mut self, value: i16) -> Self {
+        self.dx = value;
+        self
+    }
+    /// Set the `dy` field of this structure.
+    pub fn dy(mut self, value: i16) -> Self {
+        self.dy = value;
+        self
+    }
+}
+
 pub fn translate_region<Conn>(conn: &Conn, region: Region, dx: i16, dy: i16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
-    let dx_bytes = dx.serialize();
-    let dy_bytes = dy.serialize();
+    let region_bytes = region.serialize_with_order(order);
+    let dx_bytes = dx.serialize_with_order(order);
+    let dy_bytes = dy.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         TRANSLATE_REGION_REQUEST,
@@ -1460,21 +2205,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for TranslateRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for TranslateRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        translate_region(conn, self.region, self.dx, self.dy)
+    }
+}
 
 /// Opcode for the RegionExtents request
 pub const REGION_EXTENTS_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RegionExtentsRequest {
+    pub source: Region,
+    pub destination: Region,
+}
+impl RegionExtentsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != REGION_EXTENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (source, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(RegionExtentsRequest {
+            source,
+            destination,
+        })
+    }
+    /// Set the `source` field of this structure.
+    pub fn source<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source = value.into();
+        self
+    }
+    /// Set the `destination` field of this structure.
+    pub fn destination<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.destination = value.into();
+        self
+    }
+}
+
 pub fn region_extents<Conn>(conn: &Conn, source: Region, destination: Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let source_bytes = source.serialize();
-    let destination_bytes = destination.serialize();
+    let source_bytes = source.serialize_with_order(order);
+    let destination_bytes = destination.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         REGION_EXTENTS_REQUEST,
@@ -1492,20 +2283,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for RegionExtentsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for RegionExtentsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        region_extents(conn, self.source, self.destination)
+    }
+}
 
 /// Opcode for the FetchRegion request
 pub const FETCH_REGION_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FetchRegionRequest {
+    pub region: Region,
+}
+impl FetchRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FETCH_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FetchRegionRequest {
+            region,
+        })
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+}
+
 pub fn fetch_region<Conn>(conn: &Conn, region: Region) -> Result<Cookie<'_, Conn, FetchRegionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let region_bytes = region.serialize();
+    let region_bytes = region.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FETCH_REGION_REQUEST,
@@ -1519,9 +2348,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FetchRegionRequest {
+    type Reply = FetchRegionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FetchRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, FetchRegionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        fetch_region(conn, self.region)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FetchRegionReply {
@@ -1531,14 +2372,14 @@ pub struct FetchRegionReply {
     pub rectangles: Vec<xproto::Rectangle>,
 }
 impl TryParse for FetchRegionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (extents, remaining) = xproto::Rectangle::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (extents, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (rectangles, remaining) = crate::x11_utils::parse_list::<xproto::Rectangle>(remaining, length.checked_div(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (rectangles, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Rectangle>(remaining, length.checked_div(2u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = FetchRegionReply { response_type, sequence, extents, rectangles };
         Ok((result, remaining))
     }
@@ -1549,9 +2390,84 @@ impl TryFrom<&[u8]> for FetchRegionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for FetchRegionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.rectangles.len()).ok().and_then(|len| len.checked_mul(2)).expect("`rectangles` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.extents.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.rectangles.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetGCClipRegion request
 pub const SET_GC_CLIP_REGION_REQUEST: u8 = 20;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetGCClipRegionRequest {
+    pub gc: xproto::Gcontext,
+    pub region: Region,
+    pub x_origin: i16,
+    pub y_origin: i16,
+}
+impl SetGCClipRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_GC_CLIP_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (x_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetGCClipRegionRequest {
+            gc,
+            region,
+            x_origin,
+            y_origin,
+        })
+    }
+    /// Set the `gc` field of this structure.
+    pub fn gc<I>(mut self, value: I) -> Self where I: Into<xproto::Gcontext> {
+        self.gc = value.into();
+        self
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `x_origin` field of this structure.
+    pub fn x_origin(mut self, value: i16) -> Self {
+        self.x_origin = value;
+        self
+    }
+    /// Set the `y_origin` field of this structure.
+    pub fn y_origin(mut self, value: i16) -> Self {
+        self.y_origin = value;
+        self
+    }
+}
+
 pub fn set_gc_clip_region<Conn, A>(conn: &Conn, gc: xproto::Gcontext, region: A, x_origin: i16, y_origin: i16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1560,11 +2476,12 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let region: Region = region.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let gc_bytes = gc.serialize();
-    let region_bytes = region.serialize();
-    let x_origin_bytes = x_origin.serialize();
-    let y_origin_bytes = y_origin.serialize();
+    let gc_bytes = gc.serialize_with_order(order);
+    let region_bytes = region.serialize_with_order(order);
+    let x_origin_bytes = x_origin.serialize_with_order(order);
+    let y_origin_bytes = y_origin.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_GC_CLIP_REGION_REQUEST,
@@ -1586,26 +2503,100 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetGCClipRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetGCClipRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_gc_clip_region(conn, self.gc, self.region, self.x_origin, self.y_origin)
+    }
+}
 
 /// Opcode for the SetWindowShapeRegion request
 pub const SET_WINDOW_SHAPE_REGION_REQUEST: u8 = 21;
-pub fn set_window_shape_region<Conn, A>(conn: &Conn, dest: xproto::Window, dest_kind: shape::SK, x_offset: i16, y_offset: i16, region: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetWindowShapeRegionRequest {
+    pub dest: xproto::Window,
+    pub dest_kind: shape::SK,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub region: Region,
+}
+impl SetWindowShapeRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_WINDOW_SHAPE_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (dest, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (dest_kind, remaining) = shape::Kind::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let dest_kind = shape::SK::from(dest_kind);
+        Ok(SetWindowShapeRegionRequest {
+            dest,
+            dest_kind,
+            x_offset,
+            y_offset,
+            region,
+        })
+    }
+    /// Set the `dest` field of this structure.
+    pub fn dest<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.dest = value.into();
+        self
+    }
+    /// Set the `dest_kind` field of this structure.
+    pub fn dest_kind<I>(mut self, value: I) -> Self where I: Into<shape::SK> {
+        self.dest_kind = value.into();
+        self
+    }
+    /// Set the `x_offset` field of this structure.
+    pub fn x_offset(mut self, value: i16) -> Self {
+        self.x_offset = value;
+        self
+    }
+    /// Set the `y_offset` field of this structure.
+    pub fn y_offset(mut self, value: i16) -> Self {
+        self.y_offset = value;
+        self
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+}
+
+pub fn set_window_shape_region<Conn, A, B>(conn: &Conn, dest: xproto::Window, dest_kind: A, x_offset: i16, y_offset: i16, region: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<Region>,
+    A: Into<shape::SK>,
+    B: Into<Region>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let dest_kind: shape::SK = dest_kind.into();
     let region: Region = region.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let dest_bytes = dest.serialize();
-    let dest_kind_bytes = shape::Kind::from(dest_kind).serialize();
-    let x_offset_bytes = x_offset.serialize();
-    let y_offset_bytes = y_offset.serialize();
-    let region_bytes = region.serialize();
+    let dest_bytes = dest.serialize_with_order(order);
+    let dest_kind_bytes = u8::from(dest_kind).serialize_with_order(order);
+    let x_offset_bytes = x_offset.serialize_with_order(order);
+    let y_offset_bytes = y_offset.serialize_with_order(order);
+    let region_bytes = region.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_WINDOW_SHAPE_REGION_REQUEST,
@@ -1631,12 +2622,73 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetWindowShapeRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetWindowShapeRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_window_shape_region(conn, self.dest, self.dest_kind, self.x_offset, self.y_offset, self.region)
+    }
+}
 
 /// Opcode for the SetPictureClipRegion request
 pub const SET_PICTURE_CLIP_REGION_REQUEST: u8 = 22;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetPictureClipRegionRequest {
+    pub picture: render::Picture,
+    pub region: Region,
+    pub x_origin: i16,
+    pub y_origin: i16,
+}
+impl SetPictureClipRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PICTURE_CLIP_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (picture, remaining) = render::Picture::try_parse_with_order(remaining, order)?;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (x_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetPictureClipRegionRequest {
+            picture,
+            region,
+            x_origin,
+            y_origin,
+        })
+    }
+    /// Set the `picture` field of this structure.
+    pub fn picture<I>(mut self, value: I) -> Self where I: Into<render::Picture> {
+        self.picture = value.into();
+        self
+    }
+    /// Set the `region` field of this structure.
+    pub fn region<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.region = value.into();
+        self
+    }
+    /// Set the `x_origin` field of this structure.
+    pub fn x_origin(mut self, value: i16) -> Self {
+        self.x_origin = value;
+        self
+    }
+    /// Set the `y_origin` field of this structure.
+    pub fn y_origin(mut self, value: i16) -> Self {
+        self.y_origin = value;
+        self
+    }
+}
+
 pub fn set_picture_clip_region<Conn, A>(conn: &Conn, picture: render::Picture, region: A, x_origin: i16, y_origin: i16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1645,11 +2697,12 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let region: Region = region.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let picture_bytes = picture.serialize();
-    let region_bytes = region.serialize();
-    let x_origin_bytes = x_origin.serialize();
-    let y_origin_bytes = y_origin.serialize();
+    let picture_bytes = picture.serialize_with_order(order);
+    let region_bytes = region.serialize_with_order(order);
+    let x_origin_bytes = x_origin.serialize_with_order(order);
+    let y_origin_bytes = y_origin.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_PICTURE_CLIP_REGION_REQUEST,
@@ -1671,22 +2724,71 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetPictureClipRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetPictureClipRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_picture_clip_region(conn, self.picture, self.region, self.x_origin, self.y_origin)
+    }
+}
 
 /// Opcode for the SetCursorName request
 pub const SET_CURSOR_NAME_REQUEST: u8 = 23;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetCursorNameRequest {
+    pub cursor: xproto::Cursor,
+    pub name: Vec<u8>,
+}
+impl SetCursorNameRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CURSOR_NAME_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (cursor, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let (nbytes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_into().or(Err(ParseError::ParseError))?)?;
+        let name = name.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(SetCursorNameRequest {
+            cursor,
+            name,
+        })
+    }
+    /// Set the `cursor` field of this structure.
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.cursor = value.into();
+        self
+    }
+    /// Set the `name` field of this structure.
+    pub fn name<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.name = value.into();
+        self
+    }
+}
+
 pub fn set_cursor_name<'c, Conn>(conn: &'c Conn, cursor: xproto::Cursor, name: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let cursor_bytes = cursor.serialize();
+    let cursor_bytes = cursor.serialize_with_order(order);
     let nbytes = u16::try_from(name.len()).expect("`name` has too many elements");
-    let nbytes_bytes = nbytes.serialize();
+    let nbytes_bytes = nbytes.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_CURSOR_NAME_REQUEST,
@@ -1707,20 +2809,58 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(name), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetCursorNameRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetCursorNameRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_cursor_name(conn, self.cursor, &self.name)
+    }
+}
 
 /// Opcode for the GetCursorName request
 pub const GET_CURSOR_NAME_REQUEST: u8 = 24;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetCursorNameRequest {
+    pub cursor: xproto::Cursor,
+}
+impl GetCursorNameRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CURSOR_NAME_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (cursor, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetCursorNameRequest {
+            cursor,
+        })
+    }
+    /// Set the `cursor` field of this structure.
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.cursor = value.into();
+        self
+    }
+}
+
 pub fn get_cursor_name<Conn>(conn: &Conn, cursor: xproto::Cursor) -> Result<Cookie<'_, Conn, GetCursorNameReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let cursor_bytes = cursor.serialize();
+    let cursor_bytes = cursor.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CURSOR_NAME_REQUEST,
@@ -1734,9 +2874,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetCursorNameRequest {
+    type Reply = GetCursorNameReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetCursorNameRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetCursorNameReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_cursor_name(conn, self.cursor)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCursorNameReply {
@@ -1747,13 +2899,13 @@ pub struct GetCursorNameReply {
     pub name: Vec<u8>,
 }
 impl TryParse for GetCursorNameReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (atom, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (nbytes, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (atom, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (nbytes, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(18..).ok_or(ParseError::ParseError)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
@@ -1767,15 +2919,60 @@ impl TryFrom<&[u8]> for GetCursorNameReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetCursorNameReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.atom.serialize_into_with_order(bytes, order);
+        let nbytes = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        nbytes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 18]);
+        bytes.extend_from_slice(&self.name);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetCursorImageAndName request
 pub const GET_CURSOR_IMAGE_AND_NAME_REQUEST: u8 = 25;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetCursorImageAndNameRequest {
+}
+impl GetCursorImageAndNameRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CURSOR_IMAGE_AND_NAME_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetCursorImageAndNameRequest {
+        })
+    }
+}
+
 pub fn get_cursor_image_and_name<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetCursorImageAndNameReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -1786,9 +2983,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetCursorImageAndNameRequest {
+    type Reply = GetCursorImageAndNameReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetCursorImageAndNameRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetCursorImageAndNameReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_cursor_image_and_name(conn)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCursorImageAndNameReply {
@@ -1807,22 +3016,22 @@ pub struct GetCursorImageAndNameReply {
     pub name: Vec<u8>,
 }
 impl TryParse for GetCursorImageAndNameReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (xhot, remaining) = u16::try_parse(remaining)?;
-        let (yhot, remaining) = u16::try_parse(remaining)?;
-        let (cursor_serial, remaining) = u32::try_parse(remaining)?;
-        let (cursor_atom, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (nbytes, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (xhot, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (yhot, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (cursor_serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (cursor_atom, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (nbytes, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (cursor_image, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (cursor_image, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
         let result = GetCursorImageAndNameReply { response_type, sequence, length, x, y, width, height, xhot, yhot, cursor_serial, cursor_atom, cursor_image, name };
@@ -1835,18 +3044,88 @@ impl TryFrom<&[u8]> for GetCursorImageAndNameReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetCursorImageAndNameReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.xhot.serialize_into_with_order(bytes, order);
+        self.yhot.serialize_into_with_order(bytes, order);
+        self.cursor_serial.serialize_into_with_order(bytes, order);
+        self.cursor_atom.serialize_into_with_order(bytes, order);
+        let nbytes = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        nbytes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        assert_eq!(self.cursor_image.len(), usize::try_from(u32::from(self.width).checked_mul(u32::from(self.height)).unwrap()).unwrap(), "`cursor_image` has an incorrect length");
+        self.cursor_image.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ChangeCursor request
 pub const CHANGE_CURSOR_REQUEST: u8 = 26;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChangeCursorRequest {
+    pub source: xproto::Cursor,
+    pub destination: xproto::Cursor,
+}
+impl ChangeCursorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_CURSOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (source, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangeCursorRequest {
+            source,
+            destination,
+        })
+    }
+    /// Set the `source` field of this structure.
+    pub fn source<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.source = value.into();
+        self
+    }
+    /// Set the `destination` field of this structure.
+    pub fn destination<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.destination = value.into();
+        self
+    }
+}
+
 pub fn change_cursor<Conn>(conn: &Conn, source: xproto::Cursor, destination: xproto::Cursor) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let source_bytes = source.serialize();
-    let destination_bytes = destination.serialize();
+    let source_bytes = source.serialize_with_order(order);
+    let destination_bytes = destination.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_CURSOR_REQUEST,
@@ -1864,22 +3143,71 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeCursorRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeCursorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_cursor(conn, self.source, self.destination)
+    }
+}
 
 /// Opcode for the ChangeCursorByName request
 pub const CHANGE_CURSOR_BY_NAME_REQUEST: u8 = 27;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChangeCursorByNameRequest {
+    pub src: xproto::Cursor,
+    pub name: Vec<u8>,
+}
+impl ChangeCursorByNameRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_CURSOR_BY_NAME_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (src, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let (nbytes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_into().or(Err(ParseError::ParseError))?)?;
+        let name = name.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangeCursorByNameRequest {
+            src,
+            name,
+        })
+    }
+    /// Set the `src` field of this structure.
+    pub fn src<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.src = value.into();
+        self
+    }
+    /// Set the `name` field of this structure.
+    pub fn name<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.name = value.into();
+        self
+    }
+}
+
 pub fn change_cursor_by_name<'c, Conn>(conn: &'c Conn, src: xproto::Cursor, name: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let src_bytes = src.serialize();
+    let src_bytes = src.serialize_with_order(order);
     let nbytes = u16::try_from(name.len()).expect("`name` has too many elements");
-    let nbytes_bytes = nbytes.serialize();
+    let nbytes_bytes = nbytes.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_CURSOR_BY_NAME_REQUEST,
@@ -1900,25 +3228,103 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(name), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeCursorByNameRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeCursorByNameRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_cursor_by_name(conn, self.src, &self.name)
+    }
+}
 
 /// Opcode for the ExpandRegion request
 pub const EXPAND_REGION_REQUEST: u8 = 28;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExpandRegionRequest {
+    pub source: Region,
+    pub destination: Region,
+    pub left: u16,
+    pub right: u16,
+    pub top: u16,
+    pub bottom: u16,
+}
+impl ExpandRegionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != EXPAND_REGION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (source, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (right, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (bottom, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ExpandRegionRequest {
+            source,
+            destination,
+            left,
+            right,
+            top,
+            bottom,
+        })
+    }
+    /// Set the `source` field of this structure.
+    pub fn source<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.source = value.into();
+        self
+    }
+    /// Set the `destination` field of this structure.
+    pub fn destination<I>(mut self, value: I) -> Self where I: Into<Region> {
+        self.destination = value.into();
+        self
+    }
+    /// Set the `left` field of this structure.
+    pub fn left(mut self, value: u16) -> Self {
+        self.left = value;
+        self
+    }
+    /// Set the `right` field of this structure.
+    pub fn right(mut self, value: u16) -> Self {
+        self.right = value;
+        self
+    }
+    /// Set the `top` field of this structure.
+    pub fn top(mut self, value: u16) -> Self {
+        self.top = value;
+        self
+    }
+    /// Set the `bottom` field of this structure.
+    pub fn bottom(mut self, value: u16) -> Self {
+        self.bottom = value;
+        self
+    }
+}
+
 pub fn expand_region<Conn>(conn: &Conn, source: Region, destination: Region, left: u16, right: u16, top: u16, bottom: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let source_bytes = source.serialize();
-    let destination_bytes = destination.serialize();
-    let left_bytes = left.serialize();
-    let right_bytes = right.serialize();
-    let top_bytes = top.serialize();
-    let bottom_bytes = bottom.serialize();
+    let source_bytes = source.serialize_with_order(order);
+    let destination_bytes = destination.serialize_with_order(order);
+    let left_bytes = left.serialize_with_order(order);
+    let right_bytes = right.serialize_with_order(order);
+    let top_bytes = top.serialize_with_order(order);
+    let bottom_bytes = bottom.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         EXPAND_REGION_REQUEST,
@@ -1944,20 +3350,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ExpandRegionRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ExpandRegionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        expand_region(conn, self.source, self.destination, self.left, self.right, self.top, self.bottom)
+    }
+}
 
 /// Opcode for the HideCursor request
 pub const HIDE_CURSOR_REQUEST: u8 = 29;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HideCursorRequest {
+    pub window: xproto::Window,
+}
+impl HideCursorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != HIDE_CURSOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(HideCursorRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn hide_cursor<Conn>(conn: &Conn, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         HIDE_CURSOR_REQUEST,
@@ -1971,20 +3415,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for HideCursorRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for HideCursorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        hide_cursor(conn, self.window)
+    }
+}
 
 /// Opcode for the ShowCursor request
 pub const SHOW_CURSOR_REQUEST: u8 = 30;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ShowCursorRequest {
+    pub window: xproto::Window,
+}
+impl ShowCursorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SHOW_CURSOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ShowCursorRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn show_cursor<Conn>(conn: &Conn, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SHOW_CURSOR_REQUEST,
@@ -1998,101 +3480,175 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-pub type Barrier = u32;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum BarrierDirections {
-    PositiveX = 1 << 0,
-    PositiveY = 1 << 1,
-    NegativeX = 1 << 2,
-    NegativeY = 1 << 3,
-}
-impl From<BarrierDirections> for u8 {
-    fn from(input: BarrierDirections) -> Self {
-        match input {
-            BarrierDirections::PositiveX => 1 << 0,
-            BarrierDirections::PositiveY => 1 << 1,
-            BarrierDirections::NegativeX => 1 << 2,
-            BarrierDirections::NegativeY => 1 << 3,
-        }
-    }
+impl crate::x11_utils::Request for ShowCursorRequest {
+    type Reply = ();
 }
-impl From<BarrierDirections> for Option<u8> {
-    fn from(input: BarrierDirections) -> Self {
-        Some(u8::from(input))
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ShowCursorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        show_cursor(conn, self.window)
     }
 }
-impl From<BarrierDirections> for u16 {
-    fn from(input: BarrierDirections) -> Self {
-        Self::from(u8::from(input))
-    }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Barrier(u32);
+impl Barrier {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Barrier(0);
 }
-impl From<BarrierDirections> for Option<u16> {
-    fn from(input: BarrierDirections) -> Self {
-        Some(u16::from(input))
+impl From<u32> for Barrier {
+    fn from(value: u32) -> Self {
+        Self(value)
     }
 }
-impl From<BarrierDirections> for u32 {
-    fn from(input: BarrierDirections) -> Self {
-        Self::from(u8::from(input))
+impl From<Barrier> for u32 {
+    fn from(value: Barrier) -> Self {
+        value.0
     }
 }
-impl From<BarrierDirections> for Option<u32> {
-    fn from(input: BarrierDirections) -> Self {
-        Some(u32::from(input))
+impl TryParse for Barrier {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
     }
 }
-impl TryFrom<u8> for BarrierDirections {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(BarrierDirections::PositiveX),
-            2 => Ok(BarrierDirections::PositiveY),
-            4 => Ok(BarrierDirections::NegativeX),
-            8 => Ok(BarrierDirections::NegativeY),
-            _ => Err(ParseError::ParseError),
-        }
+impl Serialize for Barrier {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
     }
-}
-impl TryFrom<u16> for BarrierDirections {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
     }
 }
-impl TryFrom<u32> for BarrierDirections {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BarrierDirections(u32);
+#[allow(non_upper_case_globals)]
+impl BarrierDirections {
+    pub const PositiveX: Self = Self(1 << 0);
+    pub const PositiveY: Self = Self(1 << 1);
+    pub const NegativeX: Self = Self(1 << 2);
+    pub const NegativeY: Self = Self(1 << 3);
+}
+bitmask_set!(BarrierDirections, u32, [PositiveX, PositiveY, NegativeX, NegativeY]);
+impl From<BarrierDirections> for Option<u32> {
+    fn from(input: BarrierDirections) -> Self {
+        Some(input.0)
     }
 }
-bitmask_binop!(BarrierDirections, u8);
 
 /// Opcode for the CreatePointerBarrier request
 pub const CREATE_POINTER_BARRIER_REQUEST: u8 = 31;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreatePointerBarrierRequest {
+    pub barrier: Barrier,
+    pub window: xproto::Window,
+    pub x1: u16,
+    pub y1: u16,
+    pub x2: u16,
+    pub y2: u16,
+    pub directions: BarrierDirections,
+    pub devices: Vec<u16>,
+}
+impl CreatePointerBarrierRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_POINTER_BARRIER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (barrier, remaining) = Barrier::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x1, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (y1, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (x2, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (y2, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (directions, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (num_devices, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (devices, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, num_devices.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let directions = BarrierDirections::from(directions);
+        Ok(CreatePointerBarrierRequest {
+            barrier,
+            window,
+            x1,
+            y1,
+            x2,
+            y2,
+            directions,
+            devices,
+        })
+    }
+    /// Set the `barrier` field of this structure.
+    pub fn barrier<I>(mut self, value: I) -> Self where I: Into<Barrier> {
+        self.barrier = value.into();
+        self
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `x1` field of this structure.
+    pub fn x1(mut self, value: u16) -> Self {
+        self.x1 = value;
+        self
+    }
+    /// Set the `y1` field of this structure.
+    pub fn y1(mut self, value: u16) -> Self {
+        self.y1 = value;
+        self
+    }
+    /// Set the `x2` field of this structure.
+    pub fn x2(mut self, value: u16) -> Self {
+        self.x2 = value;
+        self
+    }
+    /// Set the `y2` field of this structure.
+    pub fn y2(mut self, value: u16) -> Self {
+        self.y2 = value;
+        self
+    }
+    /// Set the `directions` field of this structure.
+    pub fn directions<I>(mut self, value: I) -> Self where I: Into<BarrierDirections> {
+        self.directions = value.into();
+        self
+    }
+    /// Set the `devices` field of this structure.
+    pub fn devices<I>(mut self, value: I) -> Self where I: Into<Vec<u16>> {
+        self.devices = value.into();
+        self
+    }
+}
+
 pub fn create_pointer_barrier<'c, Conn, A>(conn: &'c Conn, barrier: Barrier, window: xproto::Window, x1: u16, y1: u16, x2: u16, y2: u16, directions: A, devices: &[u16]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<BarrierDirections>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let directions: u32 = directions.into();
+    let directions: BarrierDirections = directions.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let barrier_bytes = barrier.serialize();
-    let window_bytes = window.serialize();
-    let x1_bytes = x1.serialize();
-    let y1_bytes = y1.serialize();
-    let x2_bytes = x2.serialize();
-    let y2_bytes = y2.serialize();
-    let directions_bytes = directions.serialize();
+    let barrier_bytes = barrier.serialize_with_order(order);
+    let window_bytes = window.serialize_with_order(order);
+    let x1_bytes = x1.serialize_with_order(order);
+    let y1_bytes = y1.serialize_with_order(order);
+    let x2_bytes = x2.serialize_with_order(order);
+    let y2_bytes = y2.serialize_with_order(order);
+    let directions_bytes = u32::from(directions).serialize_with_order(order);
     let num_devices = u16::try_from(devices.len()).expect("`devices` has too many elements");
-    let num_devices_bytes = num_devices.serialize();
+    let num_devices_bytes = num_devices.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_POINTER_BARRIER_REQUEST,
@@ -2124,26 +3680,64 @@ where
         num_devices_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let devices_bytes = devices.serialize();
+    let devices_bytes = devices.serialize_with_order(order);
     let length_so_far = length_so_far + devices_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&devices_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreatePointerBarrierRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreatePointerBarrierRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_pointer_barrier(conn, self.barrier, self.window, self.x1, self.y1, self.x2, self.y2, self.directions, &self.devices)
+    }
+}
 
 /// Opcode for the DeletePointerBarrier request
 pub const DELETE_POINTER_BARRIER_REQUEST: u8 = 32;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeletePointerBarrierRequest {
+    pub barrier: Barrier,
+}
+impl DeletePointerBarrierRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_POINTER_BARRIER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (barrier, remaining) = Barrier::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeletePointerBarrierRequest {
+            barrier,
+        })
+    }
+    /// Set the `barrier` field of this structure.
+    pub fn barrier<I>(mut self, value: I) -> Self where I: Into<Barrier> {
+        self.barrier = value.into();
+        self
+    }
+}
+
 pub fn delete_pointer_barrier<Conn>(conn: &Conn, barrier: Barrier) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let barrier_bytes = barrier.serialize();
+    let barrier_bytes = barrier.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_POINTER_BARRIER_REQUEST,
@@ -2157,9 +3751,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeletePointerBarrierRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeletePointerBarrierRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_pointer_barrier(conn, self.barrier)
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -2167,19 +3773,23 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_version(self, client_major_version, client_minor_version)
     }
-    fn xfixes_change_save_set(&self, mode: SaveSetMode, target: SaveSetTarget, map: SaveSetMapping, window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xfixes_change_save_set<A, B, C>(&self, mode: A, target: B, map: C, window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<SaveSetMode>,
+        B: Into<SaveSetTarget>,
+        C: Into<SaveSetMapping>,
     {
         change_save_set(self, mode, target, map, window)
     }
     fn xfixes_select_selection_input<A>(&self, window: xproto::Window, selection: xproto::Atom, event_mask: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<SelectionEventMask>,
     {
         select_selection_input(self, window, selection, event_mask)
     }
     fn xfixes_select_cursor_input<A>(&self, window: xproto::Window, event_mask: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<CursorNotifyMask>,
     {
         select_cursor_input(self, window, event_mask)
     }
@@ -2195,7 +3805,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         create_region_from_bitmap(self, region, bitmap)
     }
-    fn xfixes_create_region_from_window(&self, region: Region, window: xproto::Window, kind: shape::SK) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xfixes_create_region_from_window<A>(&self, region: Region, window: xproto::Window, kind: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<shape::SK>,
     {
         create_region_from_window(self, region, window, kind)
     }
@@ -2253,9 +3865,10 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_gc_clip_region(self, gc, region, x_origin, y_origin)
     }
-    fn xfixes_set_window_shape_region<A>(&self, dest: xproto::Window, dest_kind: shape::SK, x_offset: i16, y_offset: i16, region: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xfixes_set_window_shape_region<A, B>(&self, dest: xproto::Window, dest_kind: A, x_offset: i16, y_offset: i16, region: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<Region>,
+        A: Into<shape::SK>,
+        B: Into<Region>,
     {
         set_window_shape_region(self, dest, dest_kind, x_offset, y_offset, region)
     }
@@ -2299,7 +3912,7 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn xfixes_create_pointer_barrier<'c, A>(&'c self, barrier: Barrier, window: xproto::Window, x1: u16, y1: u16, x2: u16, y2: u16, directions: A, devices: &[u16]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<BarrierDirections>,
     {
         create_pointer_barrier(self, barrier, window, x1, y1, x2, y2, directions, devices)
     }
@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -46,9 +46,9 @@ pub struct Fp3232 {
     pub frac: u32,
 }
 impl TryParse for Fp3232 {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (integral, remaining) = i32::try_parse(remaining)?;
-        let (frac, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (integral, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (frac, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = Fp3232 { integral, frac };
         Ok((result, remaining))
     }
@@ -61,9 +61,9 @@ impl TryFrom<&[u8]> for Fp3232 {
 }
 impl Serialize for Fp3232 {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let integral_bytes = self.integral.serialize();
-        let frac_bytes = self.frac.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let integral_bytes = self.integral.serialize_with_order(order);
+        let frac_bytes = self.frac.serialize_with_order(order);
         [
             integral_bytes[0],
             integral_bytes[1],
@@ -75,24 +75,53 @@ impl Serialize for Fp3232 {
             frac_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.integral.serialize_into(bytes);
-        self.frac.serialize_into(bytes);
+        self.integral.serialize_into_with_order(bytes, order);
+        self.frac.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the GetExtensionVersion request
 pub const GET_EXTENSION_VERSION_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GetExtensionVersionRequest {
+    pub name: Vec<u8>,
+}
+impl GetExtensionVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_EXTENSION_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let name = name.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(GetExtensionVersionRequest {
+            name,
+        })
+    }
+    /// Set the `name` field of this structure.
+    pub fn name<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.name = value.into();
+        self
+    }
+}
+
 pub fn get_extension_version<'c, Conn>(conn: &'c Conn, name: &[u8]) -> Result<Cookie<'c, Conn, GetExtensionVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let name_len = u16::try_from(name.len()).expect("`name` has too many elements");
-    let name_len_bytes = name_len.serialize();
+    let name_len_bytes = name_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_EXTENSION_VERSION_REQUEST,
@@ -109,9 +138,21 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(name), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetExtensionVersionRequest {
+    type Reply = GetExtensionVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetExtensionVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetExtensionVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_extension_version(conn, &self.name)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetExtensionVersionReply {
@@ -124,14 +165,14 @@ pub struct GetExtensionVersionReply {
     pub present: bool,
 }
 impl TryParse for GetExtensionVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major, remaining) = u16::try_parse(remaining)?;
-        let (server_minor, remaining) = u16::try_parse(remaining)?;
-        let (present, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_major, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_minor, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (present, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(19..).ok_or(ParseError::ParseError)?;
         let result = GetExtensionVersionReply { response_type, xi_reply_type, sequence, length, server_major, server_minor, present };
         Ok((result, remaining))
@@ -143,222 +184,145 @@ impl TryFrom<&[u8]> for GetExtensionVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum DeviceUse {
-    IsXPointer = 0,
-    IsXKeyboard = 1,
-    IsXExtensionDevice = 2,
-    IsXExtensionKeyboard = 3,
-    IsXExtensionPointer = 4,
-}
-impl From<DeviceUse> for u8 {
-    fn from(input: DeviceUse) -> Self {
-        match input {
-            DeviceUse::IsXPointer => 0,
-            DeviceUse::IsXKeyboard => 1,
-            DeviceUse::IsXExtensionDevice => 2,
-            DeviceUse::IsXExtensionKeyboard => 3,
-            DeviceUse::IsXExtensionPointer => 4,
-        }
+impl Serialize for GetExtensionVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.server_major.serialize_into_with_order(bytes, order);
+        self.server_minor.serialize_into_with_order(bytes, order);
+        self.present.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 19]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DeviceUse(u8);
+#[allow(non_upper_case_globals)]
+impl DeviceUse {
+    pub const IsXPointer: Self = Self(0);
+    pub const IsXKeyboard: Self = Self(1);
+    pub const IsXExtensionDevice: Self = Self(2);
+    pub const IsXExtensionKeyboard: Self = Self(3);
+    pub const IsXExtensionPointer: Self = Self(4);
+}
+enum_newtype!(DeviceUse, u8, [IsXPointer, IsXKeyboard, IsXExtensionDevice, IsXExtensionKeyboard, IsXExtensionPointer]);
 impl From<DeviceUse> for Option<u8> {
     fn from(input: DeviceUse) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<DeviceUse> for u16 {
     fn from(input: DeviceUse) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DeviceUse> for Option<u16> {
     fn from(input: DeviceUse) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<DeviceUse> for u32 {
     fn from(input: DeviceUse) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DeviceUse> for Option<u32> {
     fn from(input: DeviceUse) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for DeviceUse {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(DeviceUse::IsXPointer),
-            1 => Ok(DeviceUse::IsXKeyboard),
-            2 => Ok(DeviceUse::IsXExtensionDevice),
-            3 => Ok(DeviceUse::IsXExtensionKeyboard),
-            4 => Ok(DeviceUse::IsXExtensionPointer),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for DeviceUse {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for DeviceUse {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum InputClass {
-    Key = 0,
-    Button = 1,
-    Valuator = 2,
-    Feedback = 3,
-    Proximity = 4,
-    Focus = 5,
-    Other = 6,
-}
-impl From<InputClass> for u8 {
-    fn from(input: InputClass) -> Self {
-        match input {
-            InputClass::Key => 0,
-            InputClass::Button => 1,
-            InputClass::Valuator => 2,
-            InputClass::Feedback => 3,
-            InputClass::Proximity => 4,
-            InputClass::Focus => 5,
-            InputClass::Other => 6,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct InputClass(u8);
+#[allow(non_upper_case_globals)]
+impl InputClass {
+    pub const Key: Self = Self(0);
+    pub const Button: Self = Self(1);
+    pub const Valuator: Self = Self(2);
+    pub const Feedback: Self = Self(3);
+    pub const Proximity: Self = Self(4);
+    pub const Focus: Self = Self(5);
+    pub const Other: Self = Self(6);
 }
+enum_newtype!(InputClass, u8, [Key, Button, Valuator, Feedback, Proximity, Focus, Other]);
 impl From<InputClass> for Option<u8> {
     fn from(input: InputClass) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<InputClass> for u16 {
     fn from(input: InputClass) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<InputClass> for Option<u16> {
     fn from(input: InputClass) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<InputClass> for u32 {
     fn from(input: InputClass) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<InputClass> for Option<u32> {
     fn from(input: InputClass) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for InputClass {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(InputClass::Key),
-            1 => Ok(InputClass::Button),
-            2 => Ok(InputClass::Valuator),
-            3 => Ok(InputClass::Feedback),
-            4 => Ok(InputClass::Proximity),
-            5 => Ok(InputClass::Focus),
-            6 => Ok(InputClass::Other),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for InputClass {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for InputClass {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ValuatorMode {
-    Relative = 0,
-    Absolute = 1,
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ValuatorMode(u8);
+#[allow(non_upper_case_globals)]
+impl ValuatorMode {
+    pub const Relative: Self = Self(0);
+    pub const Absolute: Self = Self(1);
 }
+enum_newtype!(ValuatorMode, u8, [Relative, Absolute]);
 impl From<ValuatorMode> for bool {
     fn from(input: ValuatorMode) -> Self {
-        match input {
-            ValuatorMode::Relative => false,
-            ValuatorMode::Absolute => true,
-        }
-    }
-}
-impl From<ValuatorMode> for u8 {
-    fn from(input: ValuatorMode) -> Self {
-        match input {
-            ValuatorMode::Relative => 0,
-            ValuatorMode::Absolute => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<ValuatorMode> for Option<u8> {
     fn from(input: ValuatorMode) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ValuatorMode> for u16 {
     fn from(input: ValuatorMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ValuatorMode> for Option<u16> {
     fn from(input: ValuatorMode) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ValuatorMode> for u32 {
     fn from(input: ValuatorMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ValuatorMode> for Option<u32> {
     fn from(input: ValuatorMode) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ValuatorMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(ValuatorMode::Relative),
-            1 => Ok(ValuatorMode::Absolute),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ValuatorMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ValuatorMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -370,13 +334,13 @@ pub struct DeviceInfo {
     pub device_use: DeviceUse,
 }
 impl TryParse for DeviceInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (device_type, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (num_class_info, remaining) = u8::try_parse(remaining)?;
-        let (device_use, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (device_type, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_class_info, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_use, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let device_use = device_use.try_into()?;
+        let device_use = DeviceUse::from(device_use);
         let result = DeviceInfo { device_type, device_id, num_class_info, device_use };
         Ok((result, remaining))
     }
@@ -389,11 +353,11 @@ impl TryFrom<&[u8]> for DeviceInfo {
 }
 impl Serialize for DeviceInfo {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let device_type_bytes = self.device_type.serialize();
-        let device_id_bytes = self.device_id.serialize();
-        let num_class_info_bytes = self.num_class_info.serialize();
-        let device_use_bytes = u8::from(self.device_use).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let device_type_bytes = self.device_type.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let num_class_info_bytes = self.num_class_info.serialize_with_order(order);
+        let device_use_bytes = u8::from(self.device_use).serialize_with_order(order);
         [
             device_type_bytes[0],
             device_type_bytes[1],
@@ -405,12 +369,12 @@ impl Serialize for DeviceInfo {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.device_type.serialize_into(bytes);
-        self.device_id.serialize_into(bytes);
-        self.num_class_info.serialize_into(bytes);
-        u8::from(self.device_use).serialize_into(bytes);
+        self.device_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.num_class_info.serialize_into_with_order(bytes, order);
+        u8::from(self.device_use).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
     }
 }
@@ -424,14 +388,14 @@ pub struct KeyInfo {
     pub num_keys: u16,
 }
 impl TryParse for KeyInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (min_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (max_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (num_keys, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (min_keycode, remaining) = KeyCode::try_parse_with_order(remaining, order)?;
+        let (max_keycode, remaining) = KeyCode::try_parse_with_order(remaining, order)?;
+        let (num_keys, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let class_id = class_id.try_into()?;
+        let class_id = InputClass::from(class_id);
         let result = KeyInfo { class_id, len, min_keycode, max_keycode, num_keys };
         Ok((result, remaining))
     }
@@ -444,12 +408,12 @@ impl TryFrom<&[u8]> for KeyInfo {
 }
 impl Serialize for KeyInfo {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let len_bytes = self.len.serialize();
-        let min_keycode_bytes = self.min_keycode.serialize();
-        let max_keycode_bytes = self.max_keycode.serialize();
-        let num_keys_bytes = self.num_keys.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let min_keycode_bytes = self.min_keycode.serialize_with_order(order);
+        let max_keycode_bytes = self.max_keycode.serialize_with_order(order);
+        let num_keys_bytes = self.num_keys.serialize_with_order(order);
         [
             class_id_bytes[0],
             len_bytes[0],
@@ -461,13 +425,13 @@ impl Serialize for KeyInfo {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.min_keycode.serialize_into(bytes);
-        self.max_keycode.serialize_into(bytes);
-        self.num_keys.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.min_keycode.serialize_into_with_order(bytes, order);
+        self.max_keycode.serialize_into_with_order(bytes, order);
+        self.num_keys.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
@@ -479,11 +443,11 @@ pub struct ButtonInfo {
     pub num_buttons: u16,
 }
 impl TryParse for ButtonInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (num_buttons, remaining) = u16::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_buttons, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let class_id = InputClass::from(class_id);
         let result = ButtonInfo { class_id, len, num_buttons };
         Ok((result, remaining))
     }
@@ -496,10 +460,10 @@ impl TryFrom<&[u8]> for ButtonInfo {
 }
 impl Serialize for ButtonInfo {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let len_bytes = self.len.serialize();
-        let num_buttons_bytes = self.num_buttons.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let num_buttons_bytes = self.num_buttons.serialize_with_order(order);
         [
             class_id_bytes[0],
             len_bytes[0],
@@ -507,11 +471,11 @@ impl Serialize for ButtonInfo {
             num_buttons_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.num_buttons.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.num_buttons.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -522,10 +486,10 @@ pub struct AxisInfo {
     pub maximum: i32,
 }
 impl TryParse for AxisInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (minimum, remaining) = i32::try_parse(remaining)?;
-        let (maximum, remaining) = i32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (resolution, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minimum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (maximum, remaining) = i32::try_parse_with_order(remaining, order)?;
         let result = AxisInfo { resolution, minimum, maximum };
         Ok((result, remaining))
     }
@@ -538,10 +502,10 @@ impl TryFrom<&[u8]> for AxisInfo {
 }
 impl Serialize for AxisInfo {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let resolution_bytes = self.resolution.serialize();
-        let minimum_bytes = self.minimum.serialize();
-        let maximum_bytes = self.maximum.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let resolution_bytes = self.resolution.serialize_with_order(order);
+        let minimum_bytes = self.minimum.serialize_with_order(order);
+        let maximum_bytes = self.maximum.serialize_with_order(order);
         [
             resolution_bytes[0],
             resolution_bytes[1],
@@ -557,11 +521,11 @@ impl Serialize for AxisInfo {
             maximum_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.resolution.serialize_into(bytes);
-        self.minimum.serialize_into(bytes);
-        self.maximum.serialize_into(bytes);
+        self.resolution.serialize_into_with_order(bytes, order);
+        self.minimum.serialize_into_with_order(bytes, order);
+        self.maximum.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -574,15 +538,15 @@ pub struct ValuatorInfo {
     pub axes: Vec<AxisInfo>,
 }
 impl TryParse for ValuatorInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (axes_len, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (motion_size, remaining) = u32::try_parse(remaining)?;
-        let (axes, remaining) = crate::x11_utils::parse_list::<AxisInfo>(remaining, axes_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let class_id = class_id.try_into()?;
-        let mode = mode.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (axes_len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (motion_size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (axes, remaining) = crate::x11_utils::parse_list_with_order::<AxisInfo>(remaining, axes_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let class_id = InputClass::from(class_id);
+        let mode = ValuatorMode::from(mode);
         let result = ValuatorInfo { class_id, len, mode, motion_size, axes };
         Ok((result, remaining))
     }
@@ -595,20 +559,20 @@ impl TryFrom<&[u8]> for ValuatorInfo {
 }
 impl Serialize for ValuatorInfo {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
         let axes_len = u8::try_from(self.axes.len()).expect("`axes` has too many elements");
-        axes_len.serialize_into(bytes);
-        u8::from(self.mode).serialize_into(bytes);
-        self.motion_size.serialize_into(bytes);
-        self.axes.serialize_into(bytes);
+        axes_len.serialize_into_with_order(bytes, order);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
+        self.motion_size.serialize_into_with_order(bytes, order);
+        self.axes.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -619,10 +583,10 @@ pub struct InputInfoInfoKey {
     pub num_keys: u16,
 }
 impl TryParse for InputInfoInfoKey {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (min_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (max_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (num_keys, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (min_keycode, remaining) = KeyCode::try_parse_with_order(remaining, order)?;
+        let (max_keycode, remaining) = KeyCode::try_parse_with_order(remaining, order)?;
+        let (num_keys, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
         let result = InputInfoInfoKey { min_keycode, max_keycode, num_keys };
         Ok((result, remaining))
@@ -636,10 +600,10 @@ impl TryFrom<&[u8]> for InputInfoInfoKey {
 }
 impl Serialize for InputInfoInfoKey {
     type Bytes = [u8; 6];
-    fn serialize(&self) -> [u8; 6] {
-        let min_keycode_bytes = self.min_keycode.serialize();
-        let max_keycode_bytes = self.max_keycode.serialize();
-        let num_keys_bytes = self.num_keys.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 6] {
+        let min_keycode_bytes = self.min_keycode.serialize_with_order(order);
+        let max_keycode_bytes = self.max_keycode.serialize_with_order(order);
+        let num_keys_bytes = self.num_keys.serialize_with_order(order);
         [
             min_keycode_bytes[0],
             max_keycode_bytes[0],
@@ -649,11 +613,11 @@ impl Serialize for InputInfoInfoKey {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(6);
-        self.min_keycode.serialize_into(bytes);
-        self.max_keycode.serialize_into(bytes);
-        self.num_keys.serialize_into(bytes);
+        self.min_keycode.serialize_into_with_order(bytes, order);
+        self.max_keycode.serialize_into_with_order(bytes, order);
+        self.num_keys.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
@@ -662,8 +626,8 @@ pub struct InputInfoInfoButton {
     pub num_buttons: u16,
 }
 impl TryParse for InputInfoInfoButton {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_buttons, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (num_buttons, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = InputInfoInfoButton { num_buttons };
         Ok((result, remaining))
     }
@@ -676,16 +640,16 @@ impl TryFrom<&[u8]> for InputInfoInfoButton {
 }
 impl Serialize for InputInfoInfoButton {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let num_buttons_bytes = self.num_buttons.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let num_buttons_bytes = self.num_buttons.serialize_with_order(order);
         [
             num_buttons_bytes[0],
             num_buttons_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.num_buttons.serialize_into(bytes);
+        self.num_buttons.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -695,12 +659,12 @@ pub struct InputInfoInfoValuator {
     pub axes: Vec<AxisInfo>,
 }
 impl TryParse for InputInfoInfoValuator {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (axes_len, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (motion_size, remaining) = u32::try_parse(remaining)?;
-        let (axes, remaining) = crate::x11_utils::parse_list::<AxisInfo>(remaining, axes_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let mode = mode.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (axes_len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (motion_size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (axes, remaining) = crate::x11_utils::parse_list_with_order::<AxisInfo>(remaining, axes_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let mode = ValuatorMode::from(mode);
         let result = InputInfoInfoValuator { mode, motion_size, axes };
         Ok((result, remaining))
     }
@@ -713,18 +677,18 @@ impl TryFrom<&[u8]> for InputInfoInfoValuator {
 }
 impl Serialize for InputInfoInfoValuator {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(6);
         let axes_len = u8::try_from(self.axes.len()).expect("`axes` has too many elements");
-        axes_len.serialize_into(bytes);
-        u8::from(self.mode).serialize_into(bytes);
-        self.motion_size.serialize_into(bytes);
-        self.axes.serialize_into(bytes);
+        axes_len.serialize_into_with_order(bytes, order);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
+        self.motion_size.serialize_into_with_order(bytes, order);
+        self.axes.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -734,24 +698,24 @@ pub enum InputInfoInfo {
     Valuator(InputInfoInfoValuator),
 }
 impl InputInfoInfo {
-    fn try_parse(value: &[u8], class_id: u8) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, class_id: InputClass) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(class_id);
         let mut outer_remaining = value;
         let mut parse_result = None;
         if switch_expr == u32::from(InputClass::Key) {
-            let (key, new_remaining) = InputInfoInfoKey::try_parse(outer_remaining)?;
+            let (key, new_remaining) = InputInfoInfoKey::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(InputInfoInfo::Key(key));
         }
         if switch_expr == u32::from(InputClass::Button) {
-            let (button, new_remaining) = InputInfoInfoButton::try_parse(outer_remaining)?;
+            let (button, new_remaining) = InputInfoInfoButton::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(InputInfoInfo::Button(button));
         }
         if switch_expr == u32::from(InputClass::Valuator) {
-            let (valuator, new_remaining) = InputInfoInfoValuator::try_parse(outer_remaining)?;
+            let (valuator, new_remaining) = InputInfoInfoValuator::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(InputInfoInfo::Valuator(valuator));
@@ -784,16 +748,16 @@ impl InputInfoInfo {
 }
 #[allow(dead_code, unused_variables)]
 impl InputInfoInfo {
-    fn serialize(&self, class_id: u8) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, class_id: InputClass) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, class_id);
+        self.serialize_into_with_order(&mut result, order, class_id);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, class_id: u8) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, class_id: InputClass) {
         match self {
-            InputInfoInfo::Key(key) => key.serialize_into(bytes),
-            InputInfoInfo::Button(button) => button.serialize_into(bytes),
-            InputInfoInfo::Valuator(valuator) => valuator.serialize_into(bytes),
+            InputInfoInfo::Key(key) => key.serialize_into_with_order(bytes, order),
+            InputInfoInfo::Button(button) => button.serialize_into_with_order(bytes, order),
+            InputInfoInfo::Valuator(valuator) => valuator.serialize_into_with_order(bytes, order),
         }
     }
 }
@@ -813,10 +777,10 @@ pub struct InputInfo {
     pub info: InputInfoInfo,
 }
 impl TryParse for InputInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (info, remaining) = InputInfoInfo::try_parse(remaining, class_id)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (info, remaining) = InputInfoInfo::try_parse_with_order(remaining, order, InputClass::from(class_id))?;
         let result = InputInfo { len, info };
         Ok((result, remaining))
     }
@@ -829,17 +793,17 @@ impl TryFrom<&[u8]> for InputInfo {
 }
 impl Serialize for InputInfo {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
         let class_id = u8::try_from(self.info.switch_expr()).unwrap();
-        class_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.info.serialize_into(bytes, class_id);
+        class_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.info.serialize_into_with_order(bytes, order, InputClass::from(class_id));
     }
 }
 
@@ -848,8 +812,8 @@ pub struct DeviceName {
     pub string: Vec<u8>,
 }
 impl TryParse for DeviceName {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (len, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (len, remaining) = u8::try_parse_with_order(remaining, order)?;
         let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, len.try_into().or(Err(ParseError::ParseError))?)?;
         let string = string.to_vec();
         let result = DeviceName { string };
@@ -864,26 +828,44 @@ impl TryFrom<&[u8]> for DeviceName {
 }
 impl Serialize for DeviceName {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let len = u8::try_from(self.string.len()).expect("`string` has too many elements");
-        len.serialize_into(bytes);
+        len.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.string);
     }
 }
 
 /// Opcode for the ListInputDevices request
 pub const LIST_INPUT_DEVICES_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListInputDevicesRequest {
+}
+impl ListInputDevicesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_INPUT_DEVICES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(ListInputDevicesRequest {
+        })
+    }
+}
+
 pub fn list_input_devices<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, ListInputDevicesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -894,9 +876,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ListInputDevicesRequest {
+    type Reply = ListInputDevicesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ListInputDevicesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ListInputDevicesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        list_input_devices(conn)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListInputDevicesReply {
@@ -909,17 +903,17 @@ pub struct ListInputDevicesReply {
     pub names: Vec<xproto::Str>,
 }
 impl TryParse for ListInputDevicesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (devices_len, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (devices_len, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let (devices, remaining) = crate::x11_utils::parse_list::<DeviceInfo>(remaining, devices_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (infos, remaining) = crate::x11_utils::parse_list::<InputInfo>(remaining, devices.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(x.num_class_info)).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
-        let (names, remaining) = crate::x11_utils::parse_list::<xproto::Str>(remaining, devices_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let (devices, remaining) = crate::x11_utils::parse_list_with_order::<DeviceInfo>(remaining, devices_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (infos, remaining) = crate::x11_utils::parse_list_with_order::<InputInfo>(remaining, devices.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(x.num_class_info)).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Str>(remaining, devices_len.try_into().or(Err(ParseError::ParseError))?, order)?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
@@ -934,6 +928,37 @@ impl TryFrom<&[u8]> for ListInputDevicesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ListInputDevicesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let devices_len = u8::try_from(self.devices.len()).expect("`devices` has too many elements");
+        devices_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        self.devices.serialize_into_with_order(bytes, order);
+        assert_eq!(self.infos.len(), usize::try_from(self.devices.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(x.num_class_info)).unwrap())).unwrap(), "`infos` has an incorrect length");
+        self.infos.serialize_into_with_order(bytes, order);
+        assert_eq!(self.names.len(), usize::try_from(devices_len).unwrap(), "`names` has an incorrect length");
+        self.names.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 pub type EventTypeBase = u8;
 
@@ -943,10 +968,10 @@ pub struct InputClassInfo {
     pub event_type_base: EventTypeBase,
 }
 impl TryParse for InputClassInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (event_type_base, remaining) = EventTypeBase::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (event_type_base, remaining) = EventTypeBase::try_parse_with_order(remaining, order)?;
+        let class_id = InputClass::from(class_id);
         let result = InputClassInfo { class_id, event_type_base };
         Ok((result, remaining))
     }
@@ -959,31 +984,58 @@ impl TryFrom<&[u8]> for InputClassInfo {
 }
 impl Serialize for InputClassInfo {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let event_type_base_bytes = self.event_type_base.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let event_type_base_bytes = self.event_type_base.serialize_with_order(order);
         [
             class_id_bytes[0],
             event_type_base_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.event_type_base.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.event_type_base.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the OpenDevice request
 pub const OPEN_DEVICE_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OpenDeviceRequest {
+    pub device_id: u8,
+}
+impl OpenDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != OPEN_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(OpenDeviceRequest {
+            device_id,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn open_device<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, OpenDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         OPEN_DEVICE_REQUEST,
@@ -997,9 +1049,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for OpenDeviceRequest {
+    type Reply = OpenDeviceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for OpenDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, OpenDeviceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        open_device(conn, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OpenDeviceReply {
@@ -1010,15 +1074,15 @@ pub struct OpenDeviceReply {
     pub class_info: Vec<InputClassInfo>,
 }
 impl TryParse for OpenDeviceReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_classes, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let (class_info, remaining) = crate::x11_utils::parse_list::<InputClassInfo>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?)?;
+        let (class_info, remaining) = crate::x11_utils::parse_list_with_order::<InputClassInfo>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
@@ -1033,17 +1097,71 @@ impl TryFrom<&[u8]> for OpenDeviceReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for OpenDeviceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_classes = u8::try_from(self.class_info.len()).expect("`class_info` has too many elements");
+        num_classes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        self.class_info.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CloseDevice request
 pub const CLOSE_DEVICE_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CloseDeviceRequest {
+    pub device_id: u8,
+}
+impl CloseDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CLOSE_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CloseDeviceRequest {
+            device_id,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn close_device<Conn>(conn: &Conn, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CLOSE_DEVICE_REQUEST,
@@ -1057,21 +1175,71 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CloseDeviceRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CloseDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        close_device(conn, self.device_id)
+    }
+}
 
 /// Opcode for the SetDeviceMode request
 pub const SET_DEVICE_MODE_REQUEST: u8 = 5;
-pub fn set_device_mode<Conn>(conn: &Conn, device_id: u8, mode: ValuatorMode) -> Result<Cookie<'_, Conn, SetDeviceModeReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetDeviceModeRequest {
+    pub device_id: u8,
+    pub mode: ValuatorMode,
+}
+impl SetDeviceModeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_DEVICE_MODE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let mode = ValuatorMode::from(mode);
+        Ok(SetDeviceModeRequest {
+            device_id,
+            mode,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<ValuatorMode> {
+        self.mode = value.into();
+        self
+    }
+}
+
+pub fn set_device_mode<Conn, A>(conn: &Conn, device_id: u8, mode: A) -> Result<Cookie<'_, Conn, SetDeviceModeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<ValuatorMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let mode: ValuatorMode = mode.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
-    let mode_bytes = u8::from(mode).serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
+    let mode_bytes = u8::from(mode).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_DEVICE_MODE_REQUEST,
@@ -1085,9 +1253,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetDeviceModeRequest {
+    type Reply = SetDeviceModeReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetDeviceModeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, SetDeviceModeReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_device_mode(conn, self.device_id, self.mode)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceModeReply {
@@ -1098,14 +1278,14 @@ pub struct SetDeviceModeReply {
     pub status: xproto::GrabStatus,
 }
 impl TryParse for SetDeviceModeReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::GrabStatus::from(status);
         let result = SetDeviceModeReply { response_type, xi_reply_type, sequence, length, status };
         Ok((result, remaining))
     }
@@ -1116,19 +1296,79 @@ impl TryFrom<&[u8]> for SetDeviceModeReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for SetDeviceModeReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SelectExtensionEvent request
 pub const SELECT_EXTENSION_EVENT_REQUEST: u8 = 6;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SelectExtensionEventRequest {
+    pub window: xproto::Window,
+    pub classes: Vec<EventClass>,
+}
+impl SelectExtensionEventRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_EXTENSION_EVENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<EventClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SelectExtensionEventRequest {
+            window,
+            classes,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `classes` field of this structure.
+    pub fn classes<I>(mut self, value: I) -> Self where I: Into<Vec<EventClass>> {
+        self.classes = value.into();
+        self
+    }
+}
+
 pub fn select_extension_event<'c, Conn>(conn: &'c Conn, window: xproto::Window, classes: &[EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let num_classes = u16::try_from(classes.len()).expect("`classes` has too many elements");
-    let num_classes_bytes = num_classes.serialize();
+    let num_classes_bytes = num_classes.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_EXTENSION_EVENT_REQUEST,
@@ -1144,26 +1384,64 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let classes_bytes = classes.serialize();
+    let classes_bytes = classes.serialize_with_order(order);
     let length_so_far = length_so_far + classes_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&classes_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectExtensionEventRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectExtensionEventRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_extension_event(conn, self.window, &self.classes)
+    }
+}
 
 /// Opcode for the GetSelectedExtensionEvents request
 pub const GET_SELECTED_EXTENSION_EVENTS_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetSelectedExtensionEventsRequest {
+    pub window: xproto::Window,
+}
+impl GetSelectedExtensionEventsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SELECTED_EXTENSION_EVENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetSelectedExtensionEventsRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn get_selected_extension_events<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetSelectedExtensionEventsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_SELECTED_EXTENSION_EVENTS_REQUEST,
@@ -1177,9 +1455,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetSelectedExtensionEventsRequest {
+    type Reply = GetSelectedExtensionEventsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetSelectedExtensionEventsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetSelectedExtensionEventsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_selected_extension_events(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetSelectedExtensionEventsReply {
@@ -1191,16 +1481,16 @@ pub struct GetSelectedExtensionEventsReply {
     pub all_classes: Vec<EventClass>,
 }
 impl TryParse for GetSelectedExtensionEventsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_this_classes, remaining) = u16::try_parse(remaining)?;
-        let (num_all_classes, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_this_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_all_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (this_classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_this_classes.try_into().or(Err(ParseError::ParseError))?)?;
-        let (all_classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_all_classes.try_into().or(Err(ParseError::ParseError))?)?;
+        let (this_classes, remaining) = crate::x11_utils::parse_list_with_order::<EventClass>(remaining, num_this_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (all_classes, remaining) = crate::x11_utils::parse_list_with_order::<EventClass>(remaining, num_all_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetSelectedExtensionEventsReply { response_type, xi_reply_type, sequence, length, this_classes, all_classes };
         Ok((result, remaining))
     }
@@ -1211,90 +1501,135 @@ impl TryFrom<&[u8]> for GetSelectedExtensionEventsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PropagateMode {
-    AddToList = 0,
-    DeleteFromList = 1,
-}
-impl From<PropagateMode> for bool {
-    fn from(input: PropagateMode) -> Self {
-        match input {
-            PropagateMode::AddToList => false,
-            PropagateMode::DeleteFromList => true,
-        }
+impl Serialize for GetSelectedExtensionEventsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
-impl From<PropagateMode> for u8 {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_this_classes = u16::try_from(self.this_classes.len()).expect("`this_classes` has too many elements");
+        num_this_classes.serialize_into_with_order(bytes, order);
+        let num_all_classes = u16::try_from(self.all_classes.len()).expect("`all_classes` has too many elements");
+        num_all_classes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.this_classes.serialize_into_with_order(bytes, order);
+        self.all_classes.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PropagateMode(u8);
+#[allow(non_upper_case_globals)]
+impl PropagateMode {
+    pub const AddToList: Self = Self(0);
+    pub const DeleteFromList: Self = Self(1);
+}
+enum_newtype!(PropagateMode, u8, [AddToList, DeleteFromList]);
+impl From<PropagateMode> for bool {
     fn from(input: PropagateMode) -> Self {
-        match input {
-            PropagateMode::AddToList => 0,
-            PropagateMode::DeleteFromList => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<PropagateMode> for Option<u8> {
     fn from(input: PropagateMode) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<PropagateMode> for u16 {
     fn from(input: PropagateMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PropagateMode> for Option<u16> {
     fn from(input: PropagateMode) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<PropagateMode> for u32 {
     fn from(input: PropagateMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PropagateMode> for Option<u32> {
     fn from(input: PropagateMode) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for PropagateMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(PropagateMode::AddToList),
-            1 => Ok(PropagateMode::DeleteFromList),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for PropagateMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for PropagateMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
 /// Opcode for the ChangeDeviceDontPropagateList request
 pub const CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST: u8 = 8;
-pub fn change_device_dont_propagate_list<'c, Conn>(conn: &'c Conn, window: xproto::Window, mode: PropagateMode, classes: &[EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChangeDeviceDontPropagateListRequest {
+    pub window: xproto::Window,
+    pub mode: PropagateMode,
+    pub classes: Vec<EventClass>,
+}
+impl ChangeDeviceDontPropagateListRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<EventClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let mode = PropagateMode::from(mode);
+        Ok(ChangeDeviceDontPropagateListRequest {
+            window,
+            mode,
+            classes,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<PropagateMode> {
+        self.mode = value.into();
+        self
+    }
+    /// Set the `classes` field of this structure.
+    pub fn classes<I>(mut self, value: I) -> Self where I: Into<Vec<EventClass>> {
+        self.classes = value.into();
+        self
+    }
+}
+
+pub fn change_device_dont_propagate_list<'c, Conn, A>(conn: &'c Conn, window: xproto::Window, mode: A, classes: &[EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<PropagateMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let mode: PropagateMode = mode.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let num_classes = u16::try_from(classes.len()).expect("`classes` has too many elements");
-    let num_classes_bytes = num_classes.serialize();
-    let mode_bytes = u8::from(mode).serialize();
+    let num_classes_bytes = num_classes.serialize_with_order(order);
+    let mode_bytes = u8::from(mode).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST,
@@ -1310,26 +1645,64 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let classes_bytes = classes.serialize();
+    let classes_bytes = classes.serialize_with_order(order);
     let length_so_far = length_so_far + classes_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&classes_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeDeviceDontPropagateListRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeDeviceDontPropagateListRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_device_dont_propagate_list(conn, self.window, self.mode, &self.classes)
+    }
+}
 
 /// Opcode for the GetDeviceDontPropagateList request
 pub const GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDeviceDontPropagateListRequest {
+    pub window: xproto::Window,
+}
+impl GetDeviceDontPropagateListRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDeviceDontPropagateListRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn get_device_dont_propagate_list<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetDeviceDontPropagateListReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST,
@@ -1343,9 +1716,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDeviceDontPropagateListRequest {
+    type Reply = GetDeviceDontPropagateListReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDeviceDontPropagateListRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDeviceDontPropagateListReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_dont_propagate_list(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceDontPropagateListReply {
@@ -1356,14 +1741,14 @@ pub struct GetDeviceDontPropagateListReply {
     pub classes: Vec<EventClass>,
 }
 impl TryParse for GetDeviceDontPropagateListReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?)?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<EventClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetDeviceDontPropagateListReply { response_type, xi_reply_type, sequence, length, classes };
         Ok((result, remaining))
     }
@@ -1374,6 +1759,32 @@ impl TryFrom<&[u8]> for GetDeviceDontPropagateListReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDeviceDontPropagateListReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        num_classes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.classes.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeviceTimeCoord {
@@ -1381,9 +1792,9 @@ pub struct DeviceTimeCoord {
     pub axisvalues: Vec<i32>,
 }
 impl DeviceTimeCoord {
-    pub fn try_parse(remaining: &[u8], num_axes: u8) -> Result<(Self, &[u8]), ParseError> {
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_axes.try_into().or(Err(ParseError::ParseError))?)?;
+    pub fn try_parse_with_order(remaining: &[u8], order: ByteOrder, num_axes: u8) -> Result<(Self, &[u8]), ParseError> {
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, num_axes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = DeviceTimeCoord { time, axisvalues };
         Ok((result, remaining))
     }
@@ -1391,20 +1802,62 @@ impl DeviceTimeCoord {
 // Skipping TryFrom implementations because of unresolved members
 #[allow(dead_code, unused_variables)]
 impl DeviceTimeCoord {
-    fn serialize(&self, num_axes: u8) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, num_axes: u8) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, num_axes);
+        self.serialize_into_with_order(&mut result, order, num_axes);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, num_axes: u8) {
-        self.time.serialize_into(bytes);
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, num_axes: u8) {
+        self.time.serialize_into_with_order(bytes, order);
         assert_eq!(self.axisvalues.len(), usize::try_from(num_axes).unwrap(), "`axisvalues` has an incorrect length");
-        self.axisvalues.serialize_into(bytes);
+        self.axisvalues.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the GetDeviceMotionEvents request
 pub const GET_DEVICE_MOTION_EVENTS_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDeviceMotionEventsRequest {
+    pub start: xproto::Timestamp,
+    pub stop: xproto::Timestamp,
+    pub device_id: u8,
+}
+impl GetDeviceMotionEventsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_MOTION_EVENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (start, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (stop, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDeviceMotionEventsRequest {
+            start,
+            stop,
+            device_id,
+        })
+    }
+    /// Set the `start` field of this structure.
+    pub fn start<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.start = value.into();
+        self
+    }
+    /// Set the `stop` field of this structure.
+    pub fn stop<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.stop = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn get_device_motion_events<Conn, A>(conn: &Conn, start: xproto::Timestamp, stop: A, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceMotionEventsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1413,10 +1866,11 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let stop: xproto::Timestamp = stop.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let start_bytes = start.serialize();
-    let stop_bytes = stop.serialize();
-    let device_id_bytes = device_id.serialize();
+    let start_bytes = start.serialize_with_order(order);
+    let stop_bytes = stop.serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_MOTION_EVENTS_REQUEST,
@@ -1438,9 +1892,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDeviceMotionEventsRequest {
+    type Reply = GetDeviceMotionEventsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDeviceMotionEventsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDeviceMotionEventsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_motion_events(conn, self.start, self.stop, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceMotionEventsReply {
@@ -1453,24 +1919,24 @@ pub struct GetDeviceMotionEventsReply {
     pub events: Vec<DeviceTimeCoord>,
 }
 impl TryParse for GetDeviceMotionEventsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_events, remaining) = u32::try_parse(remaining)?;
-        let (num_axes, remaining) = u8::try_parse(remaining)?;
-        let (device_mode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_events, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_axes, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(18..).ok_or(ParseError::ParseError)?;
         let mut remaining = remaining;
         let list_length = usize::try_from(num_events).or(Err(ParseError::ParseError))?;
         let mut events = Vec::with_capacity(list_length);
         for _ in 0..list_length {
-            let (v, new_remaining) = DeviceTimeCoord::try_parse(remaining, num_axes)?;
+            let (v, new_remaining) = DeviceTimeCoord::try_parse_with_order(remaining, order, num_axes)?;
             remaining = new_remaining;
             events.push(v);
         }
-        let device_mode = device_mode.try_into()?;
+        let device_mode = ValuatorMode::from(device_mode);
         let result = GetDeviceMotionEventsReply { response_type, xi_reply_type, sequence, length, num_axes, device_mode, events };
         Ok((result, remaining))
     }
@@ -1481,17 +1947,75 @@ impl TryFrom<&[u8]> for GetDeviceMotionEventsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDeviceMotionEventsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        let num_axes = self.num_axes;
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_events = u32::try_from(self.events.len()).expect("`events` has too many elements");
+        num_events.serialize_into_with_order(bytes, order);
+        self.num_axes.serialize_into_with_order(bytes, order);
+        u8::from(self.device_mode).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 18]);
+        for element in self.events.iter() {
+            element.serialize_into_with_order(bytes, order, num_axes);
+        }
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ChangeKeyboardDevice request
 pub const CHANGE_KEYBOARD_DEVICE_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChangeKeyboardDeviceRequest {
+    pub device_id: u8,
+}
+impl ChangeKeyboardDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_KEYBOARD_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangeKeyboardDeviceRequest {
+            device_id,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn change_keyboard_device<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, ChangeKeyboardDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_KEYBOARD_DEVICE_REQUEST,
@@ -1505,9 +2029,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeKeyboardDeviceRequest {
+    type Reply = ChangeKeyboardDeviceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeKeyboardDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ChangeKeyboardDeviceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_keyboard_device(conn, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangeKeyboardDeviceReply {
@@ -1518,14 +2054,14 @@ pub struct ChangeKeyboardDeviceReply {
     pub status: xproto::GrabStatus,
 }
 impl TryParse for ChangeKeyboardDeviceReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::GrabStatus::from(status);
         let result = ChangeKeyboardDeviceReply { response_type, xi_reply_type, sequence, length, status };
         Ok((result, remaining))
     }
@@ -1536,19 +2072,86 @@ impl TryFrom<&[u8]> for ChangeKeyboardDeviceReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-/// Opcode for the ChangePointerDevice request
-pub const CHANGE_POINTER_DEVICE_REQUEST: u8 = 12;
+impl Serialize for ChangeKeyboardDeviceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+/// Opcode for the ChangePointerDevice request
+pub const CHANGE_POINTER_DEVICE_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChangePointerDeviceRequest {
+    pub x_axis: u8,
+    pub y_axis: u8,
+    pub device_id: u8,
+}
+impl ChangePointerDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_POINTER_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (x_axis, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (y_axis, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangePointerDeviceRequest {
+            x_axis,
+            y_axis,
+            device_id,
+        })
+    }
+    /// Set the `x_axis` field of this structure.
+    pub fn x_axis(mut self, value: u8) -> Self {
+        self.x_axis = value;
+        self
+    }
+    /// Set the `y_axis` field of this structure.
+    pub fn y_axis(mut self, value: u8) -> Self {
+        self.y_axis = value;
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn change_pointer_device<Conn>(conn: &Conn, x_axis: u8, y_axis: u8, device_id: u8) -> Result<Cookie<'_, Conn, ChangePointerDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let x_axis_bytes = x_axis.serialize();
-    let y_axis_bytes = y_axis.serialize();
-    let device_id_bytes = device_id.serialize();
+    let x_axis_bytes = x_axis.serialize_with_order(order);
+    let y_axis_bytes = y_axis.serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_POINTER_DEVICE_REQUEST,
@@ -1562,9 +2165,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangePointerDeviceRequest {
+    type Reply = ChangePointerDeviceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangePointerDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ChangePointerDeviceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_pointer_device(conn, self.x_axis, self.y_axis, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangePointerDeviceReply {
@@ -1575,14 +2190,14 @@ pub struct ChangePointerDeviceReply {
     pub status: xproto::GrabStatus,
 }
 impl TryParse for ChangePointerDeviceReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::GrabStatus::from(status);
         let result = ChangePointerDeviceReply { response_type, xi_reply_type, sequence, length, status };
         Ok((result, remaining))
     }
@@ -1593,26 +2208,132 @@ impl TryFrom<&[u8]> for ChangePointerDeviceReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ChangePointerDeviceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GrabDevice request
 pub const GRAB_DEVICE_REQUEST: u8 = 13;
-pub fn grab_device<'c, Conn, A>(conn: &'c Conn, grab_window: xproto::Window, time: A, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, owner_events: bool, device_id: u8, classes: &[EventClass]) -> Result<Cookie<'c, Conn, GrabDeviceReply>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GrabDeviceRequest {
+    pub grab_window: xproto::Window,
+    pub time: xproto::Timestamp,
+    pub this_device_mode: xproto::GrabMode,
+    pub other_device_mode: xproto::GrabMode,
+    pub owner_events: bool,
+    pub device_id: u8,
+    pub classes: Vec<EventClass>,
+}
+impl GrabDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GRAB_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (grab_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (this_device_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (other_device_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (owner_events, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<EventClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let this_device_mode = xproto::GrabMode::from(this_device_mode);
+        let other_device_mode = xproto::GrabMode::from(other_device_mode);
+        Ok(GrabDeviceRequest {
+            grab_window,
+            time,
+            this_device_mode,
+            other_device_mode,
+            owner_events,
+            device_id,
+            classes,
+        })
+    }
+    /// Set the `grab_window` field of this structure.
+    pub fn grab_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.grab_window = value.into();
+        self
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+    /// Set the `this_device_mode` field of this structure.
+    pub fn this_device_mode<I>(mut self, value: I) -> Self where I: Into<xproto::GrabMode> {
+        self.this_device_mode = value.into();
+        self
+    }
+    /// Set the `other_device_mode` field of this structure.
+    pub fn other_device_mode<I>(mut self, value: I) -> Self where I: Into<xproto::GrabMode> {
+        self.other_device_mode = value.into();
+        self
+    }
+    /// Set the `owner_events` field of this structure.
+    pub fn owner_events(mut self, value: bool) -> Self {
+        self.owner_events = value;
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `classes` field of this structure.
+    pub fn classes<I>(mut self, value: I) -> Self where I: Into<Vec<EventClass>> {
+        self.classes = value.into();
+        self
+    }
+}
+
+pub fn grab_device<'c, Conn, A, B, C>(conn: &'c Conn, grab_window: xproto::Window, time: A, this_device_mode: B, other_device_mode: C, owner_events: bool, device_id: u8, classes: &[EventClass]) -> Result<Cookie<'c, Conn, GrabDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
     A: Into<xproto::Timestamp>,
+    B: Into<xproto::GrabMode>,
+    C: Into<xproto::GrabMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
+    let this_device_mode: xproto::GrabMode = this_device_mode.into();
+    let other_device_mode: xproto::GrabMode = other_device_mode.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let grab_window_bytes = grab_window.serialize();
-    let time_bytes = time.serialize();
+    let grab_window_bytes = grab_window.serialize_with_order(order);
+    let time_bytes = time.serialize_with_order(order);
     let num_classes = u16::try_from(classes.len()).expect("`classes` has too many elements");
-    let num_classes_bytes = num_classes.serialize();
-    let this_device_mode_bytes = u8::from(this_device_mode).serialize();
-    let other_device_mode_bytes = u8::from(other_device_mode).serialize();
-    let owner_events_bytes = owner_events.serialize();
-    let device_id_bytes = device_id.serialize();
+    let num_classes_bytes = num_classes.serialize_with_order(order);
+    let this_device_mode_bytes = u8::from(this_device_mode).serialize_with_order(order);
+    let other_device_mode_bytes = u8::from(other_device_mode).serialize_with_order(order);
+    let owner_events_bytes = owner_events.serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GRAB_DEVICE_REQUEST,
@@ -1636,15 +2357,27 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let classes_bytes = classes.serialize();
+    let classes_bytes = classes.serialize_with_order(order);
     let length_so_far = length_so_far + classes_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&classes_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for GrabDeviceRequest {
+    type Reply = GrabDeviceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GrabDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GrabDeviceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        grab_device(conn, self.grab_window, self.time, self.this_device_mode, self.other_device_mode, self.owner_events, self.device_id, &self.classes)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GrabDeviceReply {
@@ -1655,14 +2388,14 @@ pub struct GrabDeviceReply {
     pub status: xproto::GrabStatus,
 }
 impl TryParse for GrabDeviceReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::GrabStatus::from(status);
         let result = GrabDeviceReply { response_type, xi_reply_type, sequence, length, status };
         Ok((result, remaining))
     }
@@ -1673,9 +2406,67 @@ impl TryFrom<&[u8]> for GrabDeviceReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GrabDeviceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the UngrabDevice request
 pub const UNGRAB_DEVICE_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UngrabDeviceRequest {
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+}
+impl UngrabDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNGRAB_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(UngrabDeviceRequest {
+            time,
+            device_id,
+        })
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn ungrab_device<Conn, A>(conn: &Conn, time: A, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1684,9 +2475,10 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let time_bytes = time.serialize();
-    let device_id_bytes = device_id.serialize();
+    let time_bytes = time.serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         UNGRAB_DEVICE_REQUEST,
@@ -1704,94 +2496,179 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ModifierDevice {
-    UseXKeyboard = 255,
+impl crate::x11_utils::Request for UngrabDeviceRequest {
+    type Reply = ();
 }
-impl From<ModifierDevice> for u8 {
-    fn from(input: ModifierDevice) -> Self {
-        match input {
-            ModifierDevice::UseXKeyboard => 255,
-        }
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UngrabDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        ungrab_device(conn, self.time, self.device_id)
     }
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModifierDevice(u8);
+#[allow(non_upper_case_globals)]
+impl ModifierDevice {
+    pub const UseXKeyboard: Self = Self(255);
+}
+enum_newtype!(ModifierDevice, u8, [UseXKeyboard]);
 impl From<ModifierDevice> for Option<u8> {
     fn from(input: ModifierDevice) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ModifierDevice> for u16 {
     fn from(input: ModifierDevice) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ModifierDevice> for Option<u16> {
     fn from(input: ModifierDevice) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ModifierDevice> for u32 {
     fn from(input: ModifierDevice) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ModifierDevice> for Option<u32> {
     fn from(input: ModifierDevice) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ModifierDevice {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            255 => Ok(ModifierDevice::UseXKeyboard),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ModifierDevice {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ModifierDevice {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
 /// Opcode for the GrabDeviceKey request
 pub const GRAB_DEVICE_KEY_REQUEST: u8 = 15;
-pub fn grab_device_key<'c, Conn, A, B, C>(conn: &'c Conn, grab_window: xproto::Window, modifiers: A, modifier_device: B, grabbed_device: u8, key: C, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, owner_events: bool, classes: &[EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GrabDeviceKeyRequest {
+    pub grab_window: xproto::Window,
+    pub modifiers: xproto::ModMask,
+    pub modifier_device: u8,
+    pub grabbed_device: u8,
+    pub key: u8,
+    pub this_device_mode: xproto::GrabMode,
+    pub other_device_mode: xproto::GrabMode,
+    pub owner_events: bool,
+    pub classes: Vec<EventClass>,
+}
+impl GrabDeviceKeyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GRAB_DEVICE_KEY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (grab_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (modifiers, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (modifier_device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (grabbed_device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (key, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (this_device_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (other_device_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (owner_events, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<EventClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let modifiers = xproto::ModMask::from(modifiers);
+        let this_device_mode = xproto::GrabMode::from(this_device_mode);
+        let other_device_mode = xproto::GrabMode::from(other_device_mode);
+        Ok(GrabDeviceKeyRequest {
+            grab_window,
+            modifiers,
+            modifier_device,
+            grabbed_device,
+            key,
+            this_device_mode,
+            other_device_mode,
+            owner_events,
+            classes,
+        })
+    }
+    /// Set the `grab_window` field of this structure.
+    pub fn grab_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.grab_window = value.into();
+        self
+    }
+    /// Set the `modifiers` field of this structure.
+    pub fn modifiers<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.modifiers = value.into();
+        self
+    }
+    /// Set the `modifier_device` field of this structure.
+    pub fn modifier_device(mut self, value: u8) -> Self {
+        self.modifier_device = value;
+        self
+    }
+    /// Set the `grabbed_device` field of this structure.
+    pub fn grabbed_device(mut self, value: u8) -> Self {
+        self.grabbed_device = value;
+        self
+    }
+    /// Set the `key` field of this structure.
+    pub fn key(mut self, value: u8) -> Self {
+        self.key = value;
+        self
+    }
+    /// Set the `this_device_mode` field of this structure.
+    pub fn this_device_mode<I>(mut self, value: I) -> Self where I: Into<xproto::GrabMode> {
+        self.this_device_mode = value.into();
+        self
+    }
+    /// Set the `other_device_mode` field of this structure.
+    pub fn other_device_mode<I>(mut self, value: I) -> Self where I: Into<xproto::GrabMode> {
+        self.other_device_mode = value.into();
+        self
+    }
+    /// Set the `owner_events` field of this structure.
+    pub fn owner_events(mut self, value: bool) -> Self {
+        self.owner_events = value;
+        self
+    }
+    /// Set the `classes` field of this structure.
+    pub fn classes<I>(mut self, value: I) -> Self where I: Into<Vec<EventClass>> {
+        self.classes = value.into();
+        self
+    }
+}
+
+pub fn grab_device_key<'c, Conn, A, B, C, D, E>(conn: &'c Conn, grab_window: xproto::Window, modifiers: A, modifier_device: B, grabbed_device: u8, key: C, this_device_mode: D, other_device_mode: E, owner_events: bool, classes: &[EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
+    A: Into<xproto::ModMask>,
     B: Into<u8>,
     C: Into<u8>,
+    D: Into<xproto::GrabMode>,
+    E: Into<xproto::GrabMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let modifiers: u16 = modifiers.into();
+    let modifiers: xproto::ModMask = modifiers.into();
     let modifier_device: u8 = modifier_device.into();
     let key: u8 = key.into();
+    let this_device_mode: xproto::GrabMode = this_device_mode.into();
+    let other_device_mode: xproto::GrabMode = other_device_mode.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let grab_window_bytes = grab_window.serialize();
+    let grab_window_bytes = grab_window.serialize_with_order(order);
     let num_classes = u16::try_from(classes.len()).expect("`classes` has too many elements");
-    let num_classes_bytes = num_classes.serialize();
-    let modifiers_bytes = modifiers.serialize();
-    let modifier_device_bytes = modifier_device.serialize();
-    let grabbed_device_bytes = grabbed_device.serialize();
-    let key_bytes = key.serialize();
-    let this_device_mode_bytes = u8::from(this_device_mode).serialize();
-    let other_device_mode_bytes = u8::from(other_device_mode).serialize();
-    let owner_events_bytes = owner_events.serialize();
+    let num_classes_bytes = num_classes.serialize_with_order(order);
+    let modifiers_bytes = u16::from(modifiers).serialize_with_order(order);
+    let modifier_device_bytes = modifier_device.serialize_with_order(order);
+    let grabbed_device_bytes = grabbed_device.serialize_with_order(order);
+    let key_bytes = key.serialize_with_order(order);
+    let this_device_mode_bytes = u8::from(this_device_mode).serialize_with_order(order);
+    let other_device_mode_bytes = u8::from(other_device_mode).serialize_with_order(order);
+    let owner_events_bytes = owner_events.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GRAB_DEVICE_KEY_REQUEST,
@@ -1815,36 +2692,107 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let classes_bytes = classes.serialize();
+    let classes_bytes = classes.serialize_with_order(order);
     let length_so_far = length_so_far + classes_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&classes_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for GrabDeviceKeyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GrabDeviceKeyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        grab_device_key(conn, self.grab_window, self.modifiers, self.modifier_device, self.grabbed_device, self.key, self.this_device_mode, self.other_device_mode, self.owner_events, &self.classes)
+    }
+}
 
 /// Opcode for the UngrabDeviceKey request
 pub const UNGRAB_DEVICE_KEY_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UngrabDeviceKeyRequest {
+    pub grab_window: xproto::Window,
+    pub modifiers: xproto::ModMask,
+    pub modifier_device: u8,
+    pub key: u8,
+    pub grabbed_device: u8,
+}
+impl UngrabDeviceKeyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNGRAB_DEVICE_KEY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (grab_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (modifiers, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (modifier_device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (key, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (grabbed_device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let modifiers = xproto::ModMask::from(modifiers);
+        Ok(UngrabDeviceKeyRequest {
+            grab_window,
+            modifiers,
+            modifier_device,
+            key,
+            grabbed_device,
+        })
+    }
+    /// Set the `grab_window` field of this structure.
+    pub fn grab_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.grab_window = value.into();
+        self
+    }
+    /// Set the `modifiers` field of this structure.
+    pub fn modifiers<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.modifiers = value.into();
+        self
+    }
+    /// Set the `modifier_device` field of this structure.
+    pub fn modifier_device(mut self, value: u8) -> Self {
+        self.modifier_device = value;
+        self
+    }
+    /// Set the `key` field of this structure.
+    pub fn key(mut self, value: u8) -> Self {
+        self.key = value;
+        self
+    }
+    /// Set the `grabbed_device` field of this structure.
+    pub fn grabbed_device(mut self, value: u8) -> Self {
+        self.grabbed_device = value;
+        self
+    }
+}
+
 pub fn ungrab_device_key<Conn, A, B, C>(conn: &Conn, grab_window: xproto::Window, modifiers: A, modifier_device: B, key: C, grabbed_device: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
+    A: Into<xproto::ModMask>,
     B: Into<u8>,
     C: Into<u8>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let modifiers: u16 = modifiers.into();
+    let modifiers: xproto::ModMask = modifiers.into();
     let modifier_device: u8 = modifier_device.into();
     let key: u8 = key.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let grab_window_bytes = grab_window.serialize();
-    let modifiers_bytes = modifiers.serialize();
-    let modifier_device_bytes = modifier_device.serialize();
-    let key_bytes = key.serialize();
-    let grabbed_device_bytes = grabbed_device.serialize();
+    let grab_window_bytes = grab_window.serialize_with_order(order);
+    let modifiers_bytes = u16::from(modifiers).serialize_with_order(order);
+    let modifier_device_bytes = modifier_device.serialize_with_order(order);
+    let key_bytes = key.serialize_with_order(order);
+    let grabbed_device_bytes = grabbed_device.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         UNGRAB_DEVICE_KEY_REQUEST,
@@ -1866,35 +2814,146 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for UngrabDeviceKeyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UngrabDeviceKeyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        ungrab_device_key(conn, self.grab_window, self.modifiers, self.modifier_device, self.key, self.grabbed_device)
+    }
+}
 
 /// Opcode for the GrabDeviceButton request
 pub const GRAB_DEVICE_BUTTON_REQUEST: u8 = 17;
-pub fn grab_device_button<'c, Conn, A, B, C>(conn: &'c Conn, grab_window: xproto::Window, grabbed_device: u8, modifier_device: A, modifiers: B, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, button: C, owner_events: bool, classes: &[EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GrabDeviceButtonRequest {
+    pub grab_window: xproto::Window,
+    pub grabbed_device: u8,
+    pub modifier_device: u8,
+    pub modifiers: xproto::ModMask,
+    pub this_device_mode: xproto::GrabMode,
+    pub other_device_mode: xproto::GrabMode,
+    pub button: u8,
+    pub owner_events: bool,
+    pub classes: Vec<EventClass>,
+}
+impl GrabDeviceButtonRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GRAB_DEVICE_BUTTON_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (grab_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (grabbed_device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (modifier_device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (modifiers, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (this_device_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (other_device_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (owner_events, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<EventClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let modifiers = xproto::ModMask::from(modifiers);
+        let this_device_mode = xproto::GrabMode::from(this_device_mode);
+        let other_device_mode = xproto::GrabMode::from(other_device_mode);
+        Ok(GrabDeviceButtonRequest {
+            grab_window,
+            grabbed_device,
+            modifier_device,
+            modifiers,
+            this_device_mode,
+            other_device_mode,
+            button,
+            owner_events,
+            classes,
+        })
+    }
+    /// Set the `grab_window` field of this structure.
+    pub fn grab_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.grab_window = value.into();
+        self
+    }
+    /// Set the `grabbed_device` field of this structure.
+    pub fn grabbed_device(mut self, value: u8) -> Self {
+        self.grabbed_device = value;
+        self
+    }
+    /// Set the `modifier_device` field of this structure.
+    pub fn modifier_device(mut self, value: u8) -> Self {
+        self.modifier_device = value;
+        self
+    }
+    /// Set the `modifiers` field of this structure.
+    pub fn modifiers<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.modifiers = value.into();
+        self
+    }
+    /// Set the `this_device_mode` field of this structure.
+    pub fn this_device_mode<I>(mut self, value: I) -> Self where I: Into<xproto::GrabMode> {
+        self.this_device_mode = value.into();
+        self
+    }
+    /// Set the `other_device_mode` field of this structure.
+    pub fn other_device_mode<I>(mut self, value: I) -> Self where I: Into<xproto::GrabMode> {
+        self.other_device_mode = value.into();
+        self
+    }
+    /// Set the `button` field of this structure.
+    pub fn button(mut self, value: u8) -> Self {
+        self.button = value;
+        self
+    }
+    /// Set the `owner_events` field of this structure.
+    pub fn owner_events(mut self, value: bool) -> Self {
+        self.owner_events = value;
+        self
+    }
+    /// Set the `classes` field of this structure.
+    pub fn classes<I>(mut self, value: I) -> Self where I: Into<Vec<EventClass>> {
+        self.classes = value.into();
+        self
+    }
+}
+
+pub fn grab_device_button<'c, Conn, A, B, C, D, E>(conn: &'c Conn, grab_window: xproto::Window, grabbed_device: u8, modifier_device: A, modifiers: B, this_device_mode: C, other_device_mode: D, button: E, owner_events: bool, classes: &[EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
     A: Into<u8>,
-    B: Into<u16>,
-    C: Into<u8>,
+    B: Into<xproto::ModMask>,
+    C: Into<xproto::GrabMode>,
+    D: Into<xproto::GrabMode>,
+    E: Into<u8>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let modifier_device: u8 = modifier_device.into();
-    let modifiers: u16 = modifiers.into();
+    let modifiers: xproto::ModMask = modifiers.into();
+    let this_device_mode: xproto::GrabMode = this_device_mode.into();
+    let other_device_mode: xproto::GrabMode = other_device_mode.into();
     let button: u8 = button.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let grab_window_bytes = grab_window.serialize();
-    let grabbed_device_bytes = grabbed_device.serialize();
-    let modifier_device_bytes = modifier_device.serialize();
+    let grab_window_bytes = grab_window.serialize_with_order(order);
+    let grabbed_device_bytes = grabbed_device.serialize_with_order(order);
+    let modifier_device_bytes = modifier_device.serialize_with_order(order);
     let num_classes = u16::try_from(classes.len()).expect("`classes` has too many elements");
-    let num_classes_bytes = num_classes.serialize();
-    let modifiers_bytes = modifiers.serialize();
-    let this_device_mode_bytes = u8::from(this_device_mode).serialize();
-    let other_device_mode_bytes = u8::from(other_device_mode).serialize();
-    let button_bytes = button.serialize();
-    let owner_events_bytes = owner_events.serialize();
+    let num_classes_bytes = num_classes.serialize_with_order(order);
+    let modifiers_bytes = u16::from(modifiers).serialize_with_order(order);
+    let this_device_mode_bytes = u8::from(this_device_mode).serialize_with_order(order);
+    let other_device_mode_bytes = u8::from(other_device_mode).serialize_with_order(order);
+    let button_bytes = button.serialize_with_order(order);
+    let owner_events_bytes = owner_events.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GRAB_DEVICE_BUTTON_REQUEST,
@@ -1918,36 +2977,108 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let classes_bytes = classes.serialize();
+    let classes_bytes = classes.serialize_with_order(order);
     let length_so_far = length_so_far + classes_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&classes_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for GrabDeviceButtonRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GrabDeviceButtonRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        grab_device_button(conn, self.grab_window, self.grabbed_device, self.modifier_device, self.modifiers, self.this_device_mode, self.other_device_mode, self.button, self.owner_events, &self.classes)
+    }
+}
 
 /// Opcode for the UngrabDeviceButton request
 pub const UNGRAB_DEVICE_BUTTON_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UngrabDeviceButtonRequest {
+    pub grab_window: xproto::Window,
+    pub modifiers: xproto::ModMask,
+    pub modifier_device: u8,
+    pub button: u8,
+    pub grabbed_device: u8,
+}
+impl UngrabDeviceButtonRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNGRAB_DEVICE_BUTTON_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (grab_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (modifiers, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (modifier_device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (grabbed_device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let modifiers = xproto::ModMask::from(modifiers);
+        Ok(UngrabDeviceButtonRequest {
+            grab_window,
+            modifiers,
+            modifier_device,
+            button,
+            grabbed_device,
+        })
+    }
+    /// Set the `grab_window` field of this structure.
+    pub fn grab_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.grab_window = value.into();
+        self
+    }
+    /// Set the `modifiers` field of this structure.
+    pub fn modifiers<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.modifiers = value.into();
+        self
+    }
+    /// Set the `modifier_device` field of this structure.
+    pub fn modifier_device(mut self, value: u8) -> Self {
+        self.modifier_device = value;
+        self
+    }
+    /// Set the `button` field of this structure.
+    pub fn button(mut self, value: u8) -> Self {
+        self.button = value;
+        self
+    }
+    /// Set the `grabbed_device` field of this structure.
+    pub fn grabbed_device(mut self, value: u8) -> Self {
+        self.grabbed_device = value;
+        self
+    }
+}
+
 pub fn ungrab_device_button<Conn, A, B, C>(conn: &Conn, grab_window: xproto::Window, modifiers: A, modifier_device: B, button: C, grabbed_device: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
+    A: Into<xproto::ModMask>,
     B: Into<u8>,
     C: Into<u8>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let modifiers: u16 = modifiers.into();
+    let modifiers: xproto::ModMask = modifiers.into();
     let modifier_device: u8 = modifier_device.into();
     let button: u8 = button.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let grab_window_bytes = grab_window.serialize();
-    let modifiers_bytes = modifiers.serialize();
-    let modifier_device_bytes = modifier_device.serialize();
-    let button_bytes = button.serialize();
-    let grabbed_device_bytes = grabbed_device.serialize();
+    let grab_window_bytes = grab_window.serialize_with_order(order);
+    let modifiers_bytes = u16::from(modifiers).serialize_with_order(order);
+    let modifier_device_bytes = modifier_device.serialize_with_order(order);
+    let button_bytes = button.serialize_with_order(order);
+    let grabbed_device_bytes = grabbed_device.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         UNGRAB_DEVICE_BUTTON_REQUEST,
@@ -1969,98 +3100,120 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum DeviceInputMode {
-    AsyncThisDevice = 0,
-    SyncThisDevice = 1,
-    ReplayThisDevice = 2,
-    AsyncOtherDevices = 3,
-    AsyncAll = 4,
-    SyncAll = 5,
-}
-impl From<DeviceInputMode> for u8 {
-    fn from(input: DeviceInputMode) -> Self {
-        match input {
-            DeviceInputMode::AsyncThisDevice => 0,
-            DeviceInputMode::SyncThisDevice => 1,
-            DeviceInputMode::ReplayThisDevice => 2,
-            DeviceInputMode::AsyncOtherDevices => 3,
-            DeviceInputMode::AsyncAll => 4,
-            DeviceInputMode::SyncAll => 5,
-        }
+impl crate::x11_utils::Request for UngrabDeviceButtonRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UngrabDeviceButtonRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        ungrab_device_button(conn, self.grab_window, self.modifiers, self.modifier_device, self.button, self.grabbed_device)
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DeviceInputMode(u8);
+#[allow(non_upper_case_globals)]
+impl DeviceInputMode {
+    pub const AsyncThisDevice: Self = Self(0);
+    pub const SyncThisDevice: Self = Self(1);
+    pub const ReplayThisDevice: Self = Self(2);
+    pub const AsyncOtherDevices: Self = Self(3);
+    pub const AsyncAll: Self = Self(4);
+    pub const SyncAll: Self = Self(5);
+}
+enum_newtype!(DeviceInputMode, u8, [AsyncThisDevice, SyncThisDevice, ReplayThisDevice, AsyncOtherDevices, AsyncAll, SyncAll]);
 impl From<DeviceInputMode> for Option<u8> {
     fn from(input: DeviceInputMode) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<DeviceInputMode> for u16 {
     fn from(input: DeviceInputMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DeviceInputMode> for Option<u16> {
     fn from(input: DeviceInputMode) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<DeviceInputMode> for u32 {
     fn from(input: DeviceInputMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DeviceInputMode> for Option<u32> {
     fn from(input: DeviceInputMode) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for DeviceInputMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(DeviceInputMode::AsyncThisDevice),
-            1 => Ok(DeviceInputMode::SyncThisDevice),
-            2 => Ok(DeviceInputMode::ReplayThisDevice),
-            3 => Ok(DeviceInputMode::AsyncOtherDevices),
-            4 => Ok(DeviceInputMode::AsyncAll),
-            5 => Ok(DeviceInputMode::SyncAll),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the AllowDeviceEvents request
+pub const ALLOW_DEVICE_EVENTS_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllowDeviceEventsRequest {
+    pub time: xproto::Timestamp,
+    pub mode: DeviceInputMode,
+    pub device_id: u8,
+}
+impl AllowDeviceEventsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ALLOW_DEVICE_EVENTS_REQUEST {
+            return Err(ParseError::ParseError);
         }
+        let remaining = value;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let mode = DeviceInputMode::from(mode);
+        Ok(AllowDeviceEventsRequest {
+            time,
+            mode,
+            device_id,
+        })
     }
-}
-impl TryFrom<u16> for DeviceInputMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
     }
-}
-impl TryFrom<u32> for DeviceInputMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<DeviceInputMode> {
+        self.mode = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
     }
 }
 
-/// Opcode for the AllowDeviceEvents request
-pub const ALLOW_DEVICE_EVENTS_REQUEST: u8 = 19;
-pub fn allow_device_events<Conn, A>(conn: &Conn, time: A, mode: DeviceInputMode, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+pub fn allow_device_events<Conn, A, B>(conn: &Conn, time: A, mode: B, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
     A: Into<xproto::Timestamp>,
+    B: Into<DeviceInputMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
+    let mode: DeviceInputMode = mode.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let time_bytes = time.serialize();
-    let mode_bytes = u8::from(mode).serialize();
-    let device_id_bytes = device_id.serialize();
+    let time_bytes = time.serialize_with_order(order);
+    let mode_bytes = u8::from(mode).serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         ALLOW_DEVICE_EVENTS_REQUEST,
@@ -2078,20 +3231,59 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for AllowDeviceEventsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for AllowDeviceEventsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        allow_device_events(conn, self.time, self.mode, self.device_id)
+    }
+}
 
 /// Opcode for the GetDeviceFocus request
 pub const GET_DEVICE_FOCUS_REQUEST: u8 = 20;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDeviceFocusRequest {
+    pub device_id: u8,
+}
+impl GetDeviceFocusRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_FOCUS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDeviceFocusRequest {
+            device_id,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn get_device_focus<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceFocusReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_FOCUS_REQUEST,
@@ -2105,9 +3297,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDeviceFocusRequest {
+    type Reply = GetDeviceFocusReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDeviceFocusRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDeviceFocusReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_focus(conn, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceFocusReply {
@@ -2120,16 +3324,16 @@ pub struct GetDeviceFocusReply {
     pub revert_to: xproto::InputFocus,
 }
 impl TryParse for GetDeviceFocusReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (focus, remaining) = xproto::Window::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (revert_to, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (focus, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (revert_to, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(15..).ok_or(ParseError::ParseError)?;
-        let revert_to = revert_to.try_into()?;
+        let revert_to = xproto::InputFocus::from(revert_to);
         let result = GetDeviceFocusReply { response_type, xi_reply_type, sequence, length, focus, time, revert_to };
         Ok((result, remaining))
     }
@@ -2140,24 +3344,104 @@ impl TryFrom<&[u8]> for GetDeviceFocusReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDeviceFocusReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.focus.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        u8::from(self.revert_to).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 15]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetDeviceFocus request
 pub const SET_DEVICE_FOCUS_REQUEST: u8 = 21;
-pub fn set_device_focus<Conn, A, B>(conn: &Conn, focus: A, time: B, revert_to: xproto::InputFocus, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetDeviceFocusRequest {
+    pub focus: xproto::Window,
+    pub time: xproto::Timestamp,
+    pub revert_to: xproto::InputFocus,
+    pub device_id: u8,
+}
+impl SetDeviceFocusRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_DEVICE_FOCUS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (focus, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (revert_to, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let revert_to = xproto::InputFocus::from(revert_to);
+        Ok(SetDeviceFocusRequest {
+            focus,
+            time,
+            revert_to,
+            device_id,
+        })
+    }
+    /// Set the `focus` field of this structure.
+    pub fn focus<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.focus = value.into();
+        self
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+    /// Set the `revert_to` field of this structure.
+    pub fn revert_to<I>(mut self, value: I) -> Self where I: Into<xproto::InputFocus> {
+        self.revert_to = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
+pub fn set_device_focus<Conn, A, B, C>(conn: &Conn, focus: A, time: B, revert_to: C, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
     A: Into<xproto::Window>,
     B: Into<xproto::Timestamp>,
+    C: Into<xproto::InputFocus>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let focus: xproto::Window = focus.into();
     let time: xproto::Timestamp = time.into();
+    let revert_to: xproto::InputFocus = revert_to.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let focus_bytes = focus.serialize();
-    let time_bytes = time.serialize();
-    let revert_to_bytes = u8::from(revert_to).serialize();
-    let device_id_bytes = device_id.serialize();
+    let focus_bytes = focus.serialize_with_order(order);
+    let time_bytes = time.serialize_with_order(order);
+    let revert_to_bytes = u8::from(revert_to).serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_DEVICE_FOCUS_REQUEST,
@@ -2179,81 +3463,57 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum FeedbackClass {
-    Keyboard = 0,
-    Pointer = 1,
-    String = 2,
-    Integer = 3,
-    Led = 4,
-    Bell = 5,
-}
-impl From<FeedbackClass> for u8 {
-    fn from(input: FeedbackClass) -> Self {
-        match input {
-            FeedbackClass::Keyboard => 0,
-            FeedbackClass::Pointer => 1,
-            FeedbackClass::String => 2,
-            FeedbackClass::Integer => 3,
-            FeedbackClass::Led => 4,
-            FeedbackClass::Bell => 5,
-        }
+impl crate::x11_utils::Request for SetDeviceFocusRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetDeviceFocusRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_device_focus(conn, self.focus, self.time, self.revert_to, self.device_id)
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FeedbackClass(u8);
+#[allow(non_upper_case_globals)]
+impl FeedbackClass {
+    pub const Keyboard: Self = Self(0);
+    pub const Pointer: Self = Self(1);
+    pub const String: Self = Self(2);
+    pub const Integer: Self = Self(3);
+    pub const Led: Self = Self(4);
+    pub const Bell: Self = Self(5);
+}
+enum_newtype!(FeedbackClass, u8, [Keyboard, Pointer, String, Integer, Led, Bell]);
 impl From<FeedbackClass> for Option<u8> {
     fn from(input: FeedbackClass) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<FeedbackClass> for u16 {
     fn from(input: FeedbackClass) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<FeedbackClass> for Option<u16> {
     fn from(input: FeedbackClass) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<FeedbackClass> for u32 {
     fn from(input: FeedbackClass) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<FeedbackClass> for Option<u32> {
     fn from(input: FeedbackClass) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for FeedbackClass {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(FeedbackClass::Keyboard),
-            1 => Ok(FeedbackClass::Pointer),
-            2 => Ok(FeedbackClass::String),
-            3 => Ok(FeedbackClass::Integer),
-            4 => Ok(FeedbackClass::Led),
-            5 => Ok(FeedbackClass::Bell),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for FeedbackClass {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for FeedbackClass {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -2272,21 +3532,21 @@ pub struct KbdFeedbackState {
     pub auto_repeats: [u8; 32],
 }
 impl TryParse for KbdFeedbackState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
-        let (global_auto_repeat, remaining) = bool::try_parse(remaining)?;
-        let (click, remaining) = u8::try_parse(remaining)?;
-        let (percent, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pitch, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (led_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (global_auto_repeat, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (click, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (percent, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let (auto_repeats, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let auto_repeats = <[u8; 32]>::try_from(auto_repeats).unwrap();
-        let class_id = class_id.try_into()?;
+        let class_id = FeedbackClass::from(class_id);
         let result = KbdFeedbackState { class_id, feedback_id, len, pitch, duration, led_mask, led_values, global_auto_repeat, click, percent, auto_repeats };
         Ok((result, remaining))
     }
@@ -2299,17 +3559,17 @@ impl TryFrom<&[u8]> for KbdFeedbackState {
 }
 impl Serialize for KbdFeedbackState {
     type Bytes = [u8; 52];
-    fn serialize(&self) -> [u8; 52] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let pitch_bytes = self.pitch.serialize();
-        let duration_bytes = self.duration.serialize();
-        let led_mask_bytes = self.led_mask.serialize();
-        let led_values_bytes = self.led_values.serialize();
-        let global_auto_repeat_bytes = self.global_auto_repeat.serialize();
-        let click_bytes = self.click.serialize();
-        let percent_bytes = self.percent.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 52] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let duration_bytes = self.duration.serialize_with_order(order);
+        let led_mask_bytes = self.led_mask.serialize_with_order(order);
+        let led_values_bytes = self.led_values.serialize_with_order(order);
+        let global_auto_repeat_bytes = self.global_auto_repeat.serialize_with_order(order);
+        let click_bytes = self.click.serialize_with_order(order);
+        let percent_bytes = self.percent.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -2365,18 +3625,18 @@ impl Serialize for KbdFeedbackState {
             self.auto_repeats[31],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(52);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.pitch.serialize_into(bytes);
-        self.duration.serialize_into(bytes);
-        self.led_mask.serialize_into(bytes);
-        self.led_values.serialize_into(bytes);
-        self.global_auto_repeat.serialize_into(bytes);
-        self.click.serialize_into(bytes);
-        self.percent.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.pitch.serialize_into_with_order(bytes, order);
+        self.duration.serialize_into_with_order(bytes, order);
+        self.led_mask.serialize_into_with_order(bytes, order);
+        self.led_values.serialize_into_with_order(bytes, order);
+        self.global_auto_repeat.serialize_into_with_order(bytes, order);
+        self.click.serialize_into_with_order(bytes, order);
+        self.percent.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
         bytes.extend_from_slice(&self.auto_repeats);
     }
@@ -2392,15 +3652,15 @@ pub struct PtrFeedbackState {
     pub threshold: u16,
 }
 impl TryParse for PtrFeedbackState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (accel_num, remaining) = u16::try_parse(remaining)?;
-        let (accel_denom, remaining) = u16::try_parse(remaining)?;
-        let (threshold, remaining) = u16::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+        let (accel_num, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (accel_denom, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (threshold, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = PtrFeedbackState { class_id, feedback_id, len, accel_num, accel_denom, threshold };
         Ok((result, remaining))
     }
@@ -2413,13 +3673,13 @@ impl TryFrom<&[u8]> for PtrFeedbackState {
 }
 impl Serialize for PtrFeedbackState {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let accel_num_bytes = self.accel_num.serialize();
-        let accel_denom_bytes = self.accel_denom.serialize();
-        let threshold_bytes = self.threshold.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let accel_num_bytes = self.accel_num.serialize_with_order(order);
+        let accel_denom_bytes = self.accel_denom.serialize_with_order(order);
+        let threshold_bytes = self.threshold.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -2435,15 +3695,15 @@ impl Serialize for PtrFeedbackState {
             threshold_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.accel_num.serialize_into(bytes);
-        self.accel_denom.serialize_into(bytes);
-        self.threshold.serialize_into(bytes);
+        self.accel_num.serialize_into_with_order(bytes, order);
+        self.accel_denom.serialize_into_with_order(bytes, order);
+        self.threshold.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2457,14 +3717,14 @@ pub struct IntegerFeedbackState {
     pub max_value: i32,
 }
 impl TryParse for IntegerFeedbackState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (min_value, remaining) = i32::try_parse(remaining)?;
-        let (max_value, remaining) = i32::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (resolution, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min_value, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_value, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = IntegerFeedbackState { class_id, feedback_id, len, resolution, min_value, max_value };
         Ok((result, remaining))
     }
@@ -2477,13 +3737,13 @@ impl TryFrom<&[u8]> for IntegerFeedbackState {
 }
 impl Serialize for IntegerFeedbackState {
     type Bytes = [u8; 16];
-    fn serialize(&self) -> [u8; 16] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let resolution_bytes = self.resolution.serialize();
-        let min_value_bytes = self.min_value.serialize();
-        let max_value_bytes = self.max_value.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 16] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let resolution_bytes = self.resolution.serialize_with_order(order);
+        let min_value_bytes = self.min_value.serialize_with_order(order);
+        let max_value_bytes = self.max_value.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -2503,14 +3763,14 @@ impl Serialize for IntegerFeedbackState {
             max_value_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(16);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.resolution.serialize_into(bytes);
-        self.min_value.serialize_into(bytes);
-        self.max_value.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.resolution.serialize_into_with_order(bytes, order);
+        self.min_value.serialize_into_with_order(bytes, order);
+        self.max_value.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2523,14 +3783,14 @@ pub struct StringFeedbackState {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl TryParse for StringFeedbackState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (max_symbols, remaining) = u16::try_parse(remaining)?;
-        let (num_keysyms, remaining) = u16::try_parse(remaining)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_into().or(Err(ParseError::ParseError))?)?;
-        let class_id = class_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (max_symbols, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_keysyms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Keysym>(remaining, num_keysyms.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = StringFeedbackState { class_id, feedback_id, len, max_symbols, keysyms };
         Ok((result, remaining))
     }
@@ -2543,20 +3803,20 @@ impl TryFrom<&[u8]> for StringFeedbackState {
 }
 impl Serialize for StringFeedbackState {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.max_symbols.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.max_symbols.serialize_into_with_order(bytes, order);
         let num_keysyms = u16::try_from(self.keysyms.len()).expect("`keysyms` has too many elements");
-        num_keysyms.serialize_into(bytes);
-        self.keysyms.serialize_into(bytes);
+        num_keysyms.serialize_into_with_order(bytes, order);
+        self.keysyms.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2570,15 +3830,15 @@ pub struct BellFeedbackState {
     pub duration: u16,
 }
 impl TryParse for BellFeedbackState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (percent, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (percent, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+        let (pitch, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = BellFeedbackState { class_id, feedback_id, len, percent, pitch, duration };
         Ok((result, remaining))
     }
@@ -2591,13 +3851,13 @@ impl TryFrom<&[u8]> for BellFeedbackState {
 }
 impl Serialize for BellFeedbackState {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let percent_bytes = self.percent.serialize();
-        let pitch_bytes = self.pitch.serialize();
-        let duration_bytes = self.duration.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let percent_bytes = self.percent.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let duration_bytes = self.duration.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -2613,15 +3873,15 @@ impl Serialize for BellFeedbackState {
             duration_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.percent.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.percent.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
-        self.pitch.serialize_into(bytes);
-        self.duration.serialize_into(bytes);
+        self.pitch.serialize_into_with_order(bytes, order);
+        self.duration.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2634,13 +3894,13 @@ pub struct LedFeedbackState {
     pub led_values: u32,
 }
 impl TryParse for LedFeedbackState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (led_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = LedFeedbackState { class_id, feedback_id, len, led_mask, led_values };
         Ok((result, remaining))
     }
@@ -2653,12 +3913,12 @@ impl TryFrom<&[u8]> for LedFeedbackState {
 }
 impl Serialize for LedFeedbackState {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let led_mask_bytes = self.led_mask.serialize();
-        let led_values_bytes = self.led_values.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let led_mask_bytes = self.led_mask.serialize_with_order(order);
+        let led_values_bytes = self.led_values.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -2674,13 +3934,13 @@ impl Serialize for LedFeedbackState {
             led_values_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.led_mask.serialize_into(bytes);
-        self.led_values.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.led_mask.serialize_into_with_order(bytes, order);
+        self.led_values.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2696,14 +3956,14 @@ pub struct FeedbackStateDataKeyboard {
     pub auto_repeats: [u8; 32],
 }
 impl TryParse for FeedbackStateDataKeyboard {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
-        let (global_auto_repeat, remaining) = bool::try_parse(remaining)?;
-        let (click, remaining) = u8::try_parse(remaining)?;
-        let (percent, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (pitch, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (led_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (global_auto_repeat, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (click, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (percent, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let (auto_repeats, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let auto_repeats = <[u8; 32]>::try_from(auto_repeats).unwrap();
@@ -2719,14 +3979,14 @@ impl TryFrom<&[u8]> for FeedbackStateDataKeyboard {
 }
 impl Serialize for FeedbackStateDataKeyboard {
     type Bytes = [u8; 48];
-    fn serialize(&self) -> [u8; 48] {
-        let pitch_bytes = self.pitch.serialize();
-        let duration_bytes = self.duration.serialize();
-        let led_mask_bytes = self.led_mask.serialize();
-        let led_values_bytes = self.led_values.serialize();
-        let global_auto_repeat_bytes = self.global_auto_repeat.serialize();
-        let click_bytes = self.click.serialize();
-        let percent_bytes = self.percent.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 48] {
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let duration_bytes = self.duration.serialize_with_order(order);
+        let led_mask_bytes = self.led_mask.serialize_with_order(order);
+        let led_values_bytes = self.led_values.serialize_with_order(order);
+        let global_auto_repeat_bytes = self.global_auto_repeat.serialize_with_order(order);
+        let click_bytes = self.click.serialize_with_order(order);
+        let percent_bytes = self.percent.serialize_with_order(order);
         [
             pitch_bytes[0],
             pitch_bytes[1],
@@ -2778,15 +4038,15 @@ impl Serialize for FeedbackStateDataKeyboard {
             self.auto_repeats[31],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(48);
-        self.pitch.serialize_into(bytes);
-        self.duration.serialize_into(bytes);
-        self.led_mask.serialize_into(bytes);
-        self.led_values.serialize_into(bytes);
-        self.global_auto_repeat.serialize_into(bytes);
-        self.click.serialize_into(bytes);
-        self.percent.serialize_into(bytes);
+        self.pitch.serialize_into_with_order(bytes, order);
+        self.duration.serialize_into_with_order(bytes, order);
+        self.led_mask.serialize_into_with_order(bytes, order);
+        self.led_values.serialize_into_with_order(bytes, order);
+        self.global_auto_repeat.serialize_into_with_order(bytes, order);
+        self.click.serialize_into_with_order(bytes, order);
+        self.percent.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
         bytes.extend_from_slice(&self.auto_repeats);
     }
@@ -2798,11 +4058,11 @@ pub struct FeedbackStateDataPointer {
     pub threshold: u16,
 }
 impl TryParse for FeedbackStateDataPointer {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (accel_num, remaining) = u16::try_parse(remaining)?;
-        let (accel_denom, remaining) = u16::try_parse(remaining)?;
-        let (threshold, remaining) = u16::try_parse(remaining)?;
+        let (accel_num, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (accel_denom, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (threshold, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = FeedbackStateDataPointer { accel_num, accel_denom, threshold };
         Ok((result, remaining))
     }
@@ -2815,10 +4075,10 @@ impl TryFrom<&[u8]> for FeedbackStateDataPointer {
 }
 impl Serialize for FeedbackStateDataPointer {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let accel_num_bytes = self.accel_num.serialize();
-        let accel_denom_bytes = self.accel_denom.serialize();
-        let threshold_bytes = self.threshold.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let accel_num_bytes = self.accel_num.serialize_with_order(order);
+        let accel_denom_bytes = self.accel_denom.serialize_with_order(order);
+        let threshold_bytes = self.threshold.serialize_with_order(order);
         [
             0,
             0,
@@ -2830,12 +4090,12 @@ impl Serialize for FeedbackStateDataPointer {
             threshold_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
         bytes.extend_from_slice(&[0; 2]);
-        self.accel_num.serialize_into(bytes);
-        self.accel_denom.serialize_into(bytes);
-        self.threshold.serialize_into(bytes);
+        self.accel_num.serialize_into_with_order(bytes, order);
+        self.accel_denom.serialize_into_with_order(bytes, order);
+        self.threshold.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -2844,10 +4104,10 @@ pub struct FeedbackStateDataString {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl TryParse for FeedbackStateDataString {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (max_symbols, remaining) = u16::try_parse(remaining)?;
-        let (num_keysyms, remaining) = u16::try_parse(remaining)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (max_symbols, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_keysyms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Keysym>(remaining, num_keysyms.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = FeedbackStateDataString { max_symbols, keysyms };
         Ok((result, remaining))
     }
@@ -2860,17 +4120,17 @@ impl TryFrom<&[u8]> for FeedbackStateDataString {
 }
 impl Serialize for FeedbackStateDataString {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.max_symbols.serialize_into(bytes);
+        self.max_symbols.serialize_into_with_order(bytes, order);
         let num_keysyms = u16::try_from(self.keysyms.len()).expect("`keysyms` has too many elements");
-        num_keysyms.serialize_into(bytes);
-        self.keysyms.serialize_into(bytes);
+        num_keysyms.serialize_into_with_order(bytes, order);
+        self.keysyms.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -2880,10 +4140,10 @@ pub struct FeedbackStateDataInteger {
     pub max_value: i32,
 }
 impl TryParse for FeedbackStateDataInteger {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (min_value, remaining) = i32::try_parse(remaining)?;
-        let (max_value, remaining) = i32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (resolution, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min_value, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_value, remaining) = i32::try_parse_with_order(remaining, order)?;
         let result = FeedbackStateDataInteger { resolution, min_value, max_value };
         Ok((result, remaining))
     }
@@ -2896,10 +4156,10 @@ impl TryFrom<&[u8]> for FeedbackStateDataInteger {
 }
 impl Serialize for FeedbackStateDataInteger {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let resolution_bytes = self.resolution.serialize();
-        let min_value_bytes = self.min_value.serialize();
-        let max_value_bytes = self.max_value.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let resolution_bytes = self.resolution.serialize_with_order(order);
+        let min_value_bytes = self.min_value.serialize_with_order(order);
+        let max_value_bytes = self.max_value.serialize_with_order(order);
         [
             resolution_bytes[0],
             resolution_bytes[1],
@@ -2915,11 +4175,11 @@ impl Serialize for FeedbackStateDataInteger {
             max_value_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.resolution.serialize_into(bytes);
-        self.min_value.serialize_into(bytes);
-        self.max_value.serialize_into(bytes);
+        self.resolution.serialize_into_with_order(bytes, order);
+        self.min_value.serialize_into_with_order(bytes, order);
+        self.max_value.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -2928,9 +4188,9 @@ pub struct FeedbackStateDataLed {
     pub led_values: u32,
 }
 impl TryParse for FeedbackStateDataLed {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (led_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_values, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = FeedbackStateDataLed { led_mask, led_values };
         Ok((result, remaining))
     }
@@ -2943,9 +4203,9 @@ impl TryFrom<&[u8]> for FeedbackStateDataLed {
 }
 impl Serialize for FeedbackStateDataLed {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let led_mask_bytes = self.led_mask.serialize();
-        let led_values_bytes = self.led_values.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let led_mask_bytes = self.led_mask.serialize_with_order(order);
+        let led_values_bytes = self.led_values.serialize_with_order(order);
         [
             led_mask_bytes[0],
             led_mask_bytes[1],
@@ -2957,10 +4217,10 @@ impl Serialize for FeedbackStateDataLed {
             led_values_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.led_mask.serialize_into(bytes);
-        self.led_values.serialize_into(bytes);
+        self.led_mask.serialize_into_with_order(bytes, order);
+        self.led_values.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -2970,11 +4230,11 @@ pub struct FeedbackStateDataBell {
     pub duration: u16,
 }
 impl TryParse for FeedbackStateDataBell {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (percent, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (percent, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
+        let (pitch, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = FeedbackStateDataBell { percent, pitch, duration };
         Ok((result, remaining))
     }
@@ -2987,10 +4247,10 @@ impl TryFrom<&[u8]> for FeedbackStateDataBell {
 }
 impl Serialize for FeedbackStateDataBell {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let percent_bytes = self.percent.serialize();
-        let pitch_bytes = self.pitch.serialize();
-        let duration_bytes = self.duration.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let percent_bytes = self.percent.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let duration_bytes = self.duration.serialize_with_order(order);
         [
             percent_bytes[0],
             0,
@@ -3002,12 +4262,12 @@ impl Serialize for FeedbackStateDataBell {
             duration_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.percent.serialize_into(bytes);
+        self.percent.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
-        self.pitch.serialize_into(bytes);
-        self.duration.serialize_into(bytes);
+        self.pitch.serialize_into_with_order(bytes, order);
+        self.duration.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -3020,42 +4280,42 @@ pub enum FeedbackStateData {
     Bell(FeedbackStateDataBell),
 }
 impl FeedbackStateData {
-    fn try_parse(value: &[u8], class_id: u8) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, class_id: FeedbackClass) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(class_id);
         let mut outer_remaining = value;
         let mut parse_result = None;
         if switch_expr == u32::from(FeedbackClass::Keyboard) {
-            let (keyboard, new_remaining) = FeedbackStateDataKeyboard::try_parse(outer_remaining)?;
+            let (keyboard, new_remaining) = FeedbackStateDataKeyboard::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackStateData::Keyboard(keyboard));
         }
         if switch_expr == u32::from(FeedbackClass::Pointer) {
-            let (pointer, new_remaining) = FeedbackStateDataPointer::try_parse(outer_remaining)?;
+            let (pointer, new_remaining) = FeedbackStateDataPointer::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackStateData::Pointer(pointer));
         }
         if switch_expr == u32::from(FeedbackClass::String) {
-            let (string, new_remaining) = FeedbackStateDataString::try_parse(outer_remaining)?;
+            let (string, new_remaining) = FeedbackStateDataString::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackStateData::String(string));
         }
         if switch_expr == u32::from(FeedbackClass::Integer) {
-            let (integer, new_remaining) = FeedbackStateDataInteger::try_parse(outer_remaining)?;
+            let (integer, new_remaining) = FeedbackStateDataInteger::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackStateData::Integer(integer));
         }
         if switch_expr == u32::from(FeedbackClass::Led) {
-            let (led, new_remaining) = FeedbackStateDataLed::try_parse(outer_remaining)?;
+            let (led, new_remaining) = FeedbackStateDataLed::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackStateData::Led(led));
         }
         if switch_expr == u32::from(FeedbackClass::Bell) {
-            let (bell, new_remaining) = FeedbackStateDataBell::try_parse(outer_remaining)?;
+            let (bell, new_remaining) = FeedbackStateDataBell::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackStateData::Bell(bell));
@@ -3106,19 +4366,19 @@ impl FeedbackStateData {
 }
 #[allow(dead_code, unused_variables)]
 impl FeedbackStateData {
-    fn serialize(&self, class_id: u8) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, class_id: FeedbackClass) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, class_id);
+        self.serialize_into_with_order(&mut result, order, class_id);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, class_id: u8) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, class_id: FeedbackClass) {
         match self {
-            FeedbackStateData::Keyboard(keyboard) => keyboard.serialize_into(bytes),
-            FeedbackStateData::Pointer(pointer) => pointer.serialize_into(bytes),
-            FeedbackStateData::String(string) => string.serialize_into(bytes),
-            FeedbackStateData::Integer(integer) => integer.serialize_into(bytes),
-            FeedbackStateData::Led(led) => led.serialize_into(bytes),
-            FeedbackStateData::Bell(bell) => bell.serialize_into(bytes),
+            FeedbackStateData::Keyboard(keyboard) => keyboard.serialize_into_with_order(bytes, order),
+            FeedbackStateData::Pointer(pointer) => pointer.serialize_into_with_order(bytes, order),
+            FeedbackStateData::String(string) => string.serialize_into_with_order(bytes, order),
+            FeedbackStateData::Integer(integer) => integer.serialize_into_with_order(bytes, order),
+            FeedbackStateData::Led(led) => led.serialize_into_with_order(bytes, order),
+            FeedbackStateData::Bell(bell) => bell.serialize_into_with_order(bytes, order),
         }
     }
 }
@@ -3142,11 +4402,11 @@ pub struct FeedbackState {
     pub data: FeedbackStateData,
 }
 impl TryParse for FeedbackState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (data, remaining) = FeedbackStateData::try_parse(remaining, class_id)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = FeedbackStateData::try_parse_with_order(remaining, order, FeedbackClass::from(class_id))?;
         let result = FeedbackState { feedback_id, len, data };
         Ok((result, remaining))
     }
@@ -3159,31 +4419,58 @@ impl TryFrom<&[u8]> for FeedbackState {
 }
 impl Serialize for FeedbackState {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
         let class_id = u8::try_from(self.data.switch_expr()).unwrap();
-        class_id.serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.data.serialize_into(bytes, class_id);
+        class_id.serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.data.serialize_into_with_order(bytes, order, FeedbackClass::from(class_id));
     }
 }
 
 /// Opcode for the GetFeedbackControl request
 pub const GET_FEEDBACK_CONTROL_REQUEST: u8 = 22;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetFeedbackControlRequest {
+    pub device_id: u8,
+}
+impl GetFeedbackControlRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_FEEDBACK_CONTROL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetFeedbackControlRequest {
+            device_id,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn get_feedback_control<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, GetFeedbackControlReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_FEEDBACK_CONTROL_REQUEST,
@@ -3197,9 +4484,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetFeedbackControlRequest {
+    type Reply = GetFeedbackControlReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetFeedbackControlRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetFeedbackControlReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_feedback_control(conn, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetFeedbackControlReply {
@@ -3210,14 +4509,14 @@ pub struct GetFeedbackControlReply {
     pub feedbacks: Vec<FeedbackState>,
 }
 impl TryParse for GetFeedbackControlReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_feedbacks, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_feedbacks, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (feedbacks, remaining) = crate::x11_utils::parse_list::<FeedbackState>(remaining, num_feedbacks.try_into().or(Err(ParseError::ParseError))?)?;
+        let (feedbacks, remaining) = crate::x11_utils::parse_list_with_order::<FeedbackState>(remaining, num_feedbacks.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetFeedbackControlReply { response_type, xi_reply_type, sequence, length, feedbacks };
         Ok((result, remaining))
     }
@@ -3228,6 +4527,32 @@ impl TryFrom<&[u8]> for GetFeedbackControlReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetFeedbackControlReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_feedbacks = u16::try_from(self.feedbacks.len()).expect("`feedbacks` has too many elements");
+        num_feedbacks.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.feedbacks.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KbdFeedbackCtl {
@@ -3244,19 +4569,19 @@ pub struct KbdFeedbackCtl {
     pub led_values: u32,
 }
 impl TryParse for KbdFeedbackCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (key, remaining) = KeyCode::try_parse(remaining)?;
-        let (auto_repeat_mode, remaining) = u8::try_parse(remaining)?;
-        let (key_click_percent, remaining) = i8::try_parse(remaining)?;
-        let (bell_percent, remaining) = i8::try_parse(remaining)?;
-        let (bell_pitch, remaining) = i16::try_parse(remaining)?;
-        let (bell_duration, remaining) = i16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (key, remaining) = KeyCode::try_parse_with_order(remaining, order)?;
+        let (auto_repeat_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (key_click_percent, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (bell_percent, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (bell_pitch, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (bell_duration, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (led_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = KbdFeedbackCtl { class_id, feedback_id, len, key, auto_repeat_mode, key_click_percent, bell_percent, bell_pitch, bell_duration, led_mask, led_values };
         Ok((result, remaining))
     }
@@ -3269,18 +4594,18 @@ impl TryFrom<&[u8]> for KbdFeedbackCtl {
 }
 impl Serialize for KbdFeedbackCtl {
     type Bytes = [u8; 20];
-    fn serialize(&self) -> [u8; 20] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let key_bytes = self.key.serialize();
-        let auto_repeat_mode_bytes = self.auto_repeat_mode.serialize();
-        let key_click_percent_bytes = self.key_click_percent.serialize();
-        let bell_percent_bytes = self.bell_percent.serialize();
-        let bell_pitch_bytes = self.bell_pitch.serialize();
-        let bell_duration_bytes = self.bell_duration.serialize();
-        let led_mask_bytes = self.led_mask.serialize();
-        let led_values_bytes = self.led_values.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 20] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let key_bytes = self.key.serialize_with_order(order);
+        let auto_repeat_mode_bytes = self.auto_repeat_mode.serialize_with_order(order);
+        let key_click_percent_bytes = self.key_click_percent.serialize_with_order(order);
+        let bell_percent_bytes = self.bell_percent.serialize_with_order(order);
+        let bell_pitch_bytes = self.bell_pitch.serialize_with_order(order);
+        let bell_duration_bytes = self.bell_duration.serialize_with_order(order);
+        let led_mask_bytes = self.led_mask.serialize_with_order(order);
+        let led_values_bytes = self.led_values.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -3304,19 +4629,19 @@ impl Serialize for KbdFeedbackCtl {
             led_values_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(20);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.key.serialize_into(bytes);
-        self.auto_repeat_mode.serialize_into(bytes);
-        self.key_click_percent.serialize_into(bytes);
-        self.bell_percent.serialize_into(bytes);
-        self.bell_pitch.serialize_into(bytes);
-        self.bell_duration.serialize_into(bytes);
-        self.led_mask.serialize_into(bytes);
-        self.led_values.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.key.serialize_into_with_order(bytes, order);
+        self.auto_repeat_mode.serialize_into_with_order(bytes, order);
+        self.key_click_percent.serialize_into_with_order(bytes, order);
+        self.bell_percent.serialize_into_with_order(bytes, order);
+        self.bell_pitch.serialize_into_with_order(bytes, order);
+        self.bell_duration.serialize_into_with_order(bytes, order);
+        self.led_mask.serialize_into_with_order(bytes, order);
+        self.led_values.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3330,15 +4655,15 @@ pub struct PtrFeedbackCtl {
     pub threshold: i16,
 }
 impl TryParse for PtrFeedbackCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (num, remaining) = i16::try_parse(remaining)?;
-        let (denom, remaining) = i16::try_parse(remaining)?;
-        let (threshold, remaining) = i16::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+        let (num, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (denom, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (threshold, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = PtrFeedbackCtl { class_id, feedback_id, len, num, denom, threshold };
         Ok((result, remaining))
     }
@@ -3351,13 +4676,13 @@ impl TryFrom<&[u8]> for PtrFeedbackCtl {
 }
 impl Serialize for PtrFeedbackCtl {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let num_bytes = self.num.serialize();
-        let denom_bytes = self.denom.serialize();
-        let threshold_bytes = self.threshold.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let num_bytes = self.num.serialize_with_order(order);
+        let denom_bytes = self.denom.serialize_with_order(order);
+        let threshold_bytes = self.threshold.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -3373,15 +4698,15 @@ impl Serialize for PtrFeedbackCtl {
             threshold_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.num.serialize_into(bytes);
-        self.denom.serialize_into(bytes);
-        self.threshold.serialize_into(bytes);
+        self.num.serialize_into_with_order(bytes, order);
+        self.denom.serialize_into_with_order(bytes, order);
+        self.threshold.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3393,12 +4718,12 @@ pub struct IntegerFeedbackCtl {
     pub int_to_display: i32,
 }
 impl TryParse for IntegerFeedbackCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (int_to_display, remaining) = i32::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (int_to_display, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = IntegerFeedbackCtl { class_id, feedback_id, len, int_to_display };
         Ok((result, remaining))
     }
@@ -3411,11 +4736,11 @@ impl TryFrom<&[u8]> for IntegerFeedbackCtl {
 }
 impl Serialize for IntegerFeedbackCtl {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let int_to_display_bytes = self.int_to_display.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let int_to_display_bytes = self.int_to_display.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -3427,12 +4752,12 @@ impl Serialize for IntegerFeedbackCtl {
             int_to_display_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.int_to_display.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.int_to_display.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3444,14 +4769,14 @@ pub struct StringFeedbackCtl {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl TryParse for StringFeedbackCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (num_keysyms, remaining) = u16::try_parse(remaining)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_into().or(Err(ParseError::ParseError))?)?;
-        let class_id = class_id.try_into()?;
+        let (num_keysyms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Keysym>(remaining, num_keysyms.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = StringFeedbackCtl { class_id, feedback_id, len, keysyms };
         Ok((result, remaining))
     }
@@ -3464,20 +4789,20 @@ impl TryFrom<&[u8]> for StringFeedbackCtl {
 }
 impl Serialize for StringFeedbackCtl {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
         let num_keysyms = u16::try_from(self.keysyms.len()).expect("`keysyms` has too many elements");
-        num_keysyms.serialize_into(bytes);
-        self.keysyms.serialize_into(bytes);
+        num_keysyms.serialize_into_with_order(bytes, order);
+        self.keysyms.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3491,15 +4816,15 @@ pub struct BellFeedbackCtl {
     pub duration: i16,
 }
 impl TryParse for BellFeedbackCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (percent, remaining) = i8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (percent, remaining) = i8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (pitch, remaining) = i16::try_parse(remaining)?;
-        let (duration, remaining) = i16::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+        let (pitch, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = BellFeedbackCtl { class_id, feedback_id, len, percent, pitch, duration };
         Ok((result, remaining))
     }
@@ -3512,13 +4837,13 @@ impl TryFrom<&[u8]> for BellFeedbackCtl {
 }
 impl Serialize for BellFeedbackCtl {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let percent_bytes = self.percent.serialize();
-        let pitch_bytes = self.pitch.serialize();
-        let duration_bytes = self.duration.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let percent_bytes = self.percent.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let duration_bytes = self.duration.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -3534,15 +4859,15 @@ impl Serialize for BellFeedbackCtl {
             duration_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.percent.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.percent.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
-        self.pitch.serialize_into(bytes);
-        self.duration.serialize_into(bytes);
+        self.pitch.serialize_into_with_order(bytes, order);
+        self.duration.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3555,13 +4880,13 @@ pub struct LedFeedbackCtl {
     pub led_values: u32,
 }
 impl TryParse for LedFeedbackCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
-        let class_id = class_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (led_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let class_id = FeedbackClass::from(class_id);
         let result = LedFeedbackCtl { class_id, feedback_id, len, led_mask, led_values };
         Ok((result, remaining))
     }
@@ -3574,12 +4899,12 @@ impl TryFrom<&[u8]> for LedFeedbackCtl {
 }
 impl Serialize for LedFeedbackCtl {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let feedback_id_bytes = self.feedback_id.serialize();
-        let len_bytes = self.len.serialize();
-        let led_mask_bytes = self.led_mask.serialize();
-        let led_values_bytes = self.led_values.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let feedback_id_bytes = self.feedback_id.serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let led_mask_bytes = self.led_mask.serialize_with_order(order);
+        let led_values_bytes = self.led_values.serialize_with_order(order);
         [
             class_id_bytes[0],
             feedback_id_bytes[0],
@@ -3595,13 +4920,13 @@ impl Serialize for LedFeedbackCtl {
             led_values_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.led_mask.serialize_into(bytes);
-        self.led_values.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.led_mask.serialize_into_with_order(bytes, order);
+        self.led_values.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3617,15 +4942,15 @@ pub struct FeedbackCtlDataKeyboard {
     pub led_values: u32,
 }
 impl TryParse for FeedbackCtlDataKeyboard {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (key, remaining) = KeyCode::try_parse(remaining)?;
-        let (auto_repeat_mode, remaining) = u8::try_parse(remaining)?;
-        let (key_click_percent, remaining) = i8::try_parse(remaining)?;
-        let (bell_percent, remaining) = i8::try_parse(remaining)?;
-        let (bell_pitch, remaining) = i16::try_parse(remaining)?;
-        let (bell_duration, remaining) = i16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (key, remaining) = KeyCode::try_parse_with_order(remaining, order)?;
+        let (auto_repeat_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (key_click_percent, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (bell_percent, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (bell_pitch, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (bell_duration, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (led_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_values, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = FeedbackCtlDataKeyboard { key, auto_repeat_mode, key_click_percent, bell_percent, bell_pitch, bell_duration, led_mask, led_values };
         Ok((result, remaining))
     }
@@ -3638,15 +4963,15 @@ impl TryFrom<&[u8]> for FeedbackCtlDataKeyboard {
 }
 impl Serialize for FeedbackCtlDataKeyboard {
     type Bytes = [u8; 16];
-    fn serialize(&self) -> [u8; 16] {
-        let key_bytes = self.key.serialize();
-        let auto_repeat_mode_bytes = self.auto_repeat_mode.serialize();
-        let key_click_percent_bytes = self.key_click_percent.serialize();
-        let bell_percent_bytes = self.bell_percent.serialize();
-        let bell_pitch_bytes = self.bell_pitch.serialize();
-        let bell_duration_bytes = self.bell_duration.serialize();
-        let led_mask_bytes = self.led_mask.serialize();
-        let led_values_bytes = self.led_values.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 16] {
+        let key_bytes = self.key.serialize_with_order(order);
+        let auto_repeat_mode_bytes = self.auto_repeat_mode.serialize_with_order(order);
+        let key_click_percent_bytes = self.key_click_percent.serialize_with_order(order);
+        let bell_percent_bytes = self.bell_percent.serialize_with_order(order);
+        let bell_pitch_bytes = self.bell_pitch.serialize_with_order(order);
+        let bell_duration_bytes = self.bell_duration.serialize_with_order(order);
+        let led_mask_bytes = self.led_mask.serialize_with_order(order);
+        let led_values_bytes = self.led_values.serialize_with_order(order);
         [
             key_bytes[0],
             auto_repeat_mode_bytes[0],
@@ -3666,16 +4991,16 @@ impl Serialize for FeedbackCtlDataKeyboard {
             led_values_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(16);
-        self.key.serialize_into(bytes);
-        self.auto_repeat_mode.serialize_into(bytes);
-        self.key_click_percent.serialize_into(bytes);
-        self.bell_percent.serialize_into(bytes);
-        self.bell_pitch.serialize_into(bytes);
-        self.bell_duration.serialize_into(bytes);
-        self.led_mask.serialize_into(bytes);
-        self.led_values.serialize_into(bytes);
+        self.key.serialize_into_with_order(bytes, order);
+        self.auto_repeat_mode.serialize_into_with_order(bytes, order);
+        self.key_click_percent.serialize_into_with_order(bytes, order);
+        self.bell_percent.serialize_into_with_order(bytes, order);
+        self.bell_pitch.serialize_into_with_order(bytes, order);
+        self.bell_duration.serialize_into_with_order(bytes, order);
+        self.led_mask.serialize_into_with_order(bytes, order);
+        self.led_values.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -3685,11 +5010,11 @@ pub struct FeedbackCtlDataPointer {
     pub threshold: i16,
 }
 impl TryParse for FeedbackCtlDataPointer {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (num, remaining) = i16::try_parse(remaining)?;
-        let (denom, remaining) = i16::try_parse(remaining)?;
-        let (threshold, remaining) = i16::try_parse(remaining)?;
+        let (num, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (denom, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (threshold, remaining) = i16::try_parse_with_order(remaining, order)?;
         let result = FeedbackCtlDataPointer { num, denom, threshold };
         Ok((result, remaining))
     }
@@ -3702,10 +5027,10 @@ impl TryFrom<&[u8]> for FeedbackCtlDataPointer {
 }
 impl Serialize for FeedbackCtlDataPointer {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let num_bytes = self.num.serialize();
-        let denom_bytes = self.denom.serialize();
-        let threshold_bytes = self.threshold.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let num_bytes = self.num.serialize_with_order(order);
+        let denom_bytes = self.denom.serialize_with_order(order);
+        let threshold_bytes = self.threshold.serialize_with_order(order);
         [
             0,
             0,
@@ -3717,12 +5042,12 @@ impl Serialize for FeedbackCtlDataPointer {
             threshold_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
         bytes.extend_from_slice(&[0; 2]);
-        self.num.serialize_into(bytes);
-        self.denom.serialize_into(bytes);
-        self.threshold.serialize_into(bytes);
+        self.num.serialize_into_with_order(bytes, order);
+        self.denom.serialize_into_with_order(bytes, order);
+        self.threshold.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -3730,10 +5055,10 @@ pub struct FeedbackCtlDataString {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl TryParse for FeedbackCtlDataString {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (num_keysyms, remaining) = u16::try_parse(remaining)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_into().or(Err(ParseError::ParseError))?)?;
+        let (num_keysyms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Keysym>(remaining, num_keysyms.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = FeedbackCtlDataString { keysyms };
         Ok((result, remaining))
     }
@@ -3746,17 +5071,17 @@ impl TryFrom<&[u8]> for FeedbackCtlDataString {
 }
 impl Serialize for FeedbackCtlDataString {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
         bytes.extend_from_slice(&[0; 2]);
         let num_keysyms = u16::try_from(self.keysyms.len()).expect("`keysyms` has too many elements");
-        num_keysyms.serialize_into(bytes);
-        self.keysyms.serialize_into(bytes);
+        num_keysyms.serialize_into_with_order(bytes, order);
+        self.keysyms.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -3764,8 +5089,8 @@ pub struct FeedbackCtlDataInteger {
     pub int_to_display: i32,
 }
 impl TryParse for FeedbackCtlDataInteger {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (int_to_display, remaining) = i32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (int_to_display, remaining) = i32::try_parse_with_order(remaining, order)?;
         let result = FeedbackCtlDataInteger { int_to_display };
         Ok((result, remaining))
     }
@@ -3778,8 +5103,8 @@ impl TryFrom<&[u8]> for FeedbackCtlDataInteger {
 }
 impl Serialize for FeedbackCtlDataInteger {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let int_to_display_bytes = self.int_to_display.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let int_to_display_bytes = self.int_to_display.serialize_with_order(order);
         [
             int_to_display_bytes[0],
             int_to_display_bytes[1],
@@ -3787,9 +5112,9 @@ impl Serialize for FeedbackCtlDataInteger {
             int_to_display_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.int_to_display.serialize_into(bytes);
+        self.int_to_display.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -3798,9 +5123,9 @@ pub struct FeedbackCtlDataLed {
     pub led_values: u32,
 }
 impl TryParse for FeedbackCtlDataLed {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (led_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_values, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = FeedbackCtlDataLed { led_mask, led_values };
         Ok((result, remaining))
     }
@@ -3813,9 +5138,9 @@ impl TryFrom<&[u8]> for FeedbackCtlDataLed {
 }
 impl Serialize for FeedbackCtlDataLed {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let led_mask_bytes = self.led_mask.serialize();
-        let led_values_bytes = self.led_values.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let led_mask_bytes = self.led_mask.serialize_with_order(order);
+        let led_values_bytes = self.led_values.serialize_with_order(order);
         [
             led_mask_bytes[0],
             led_mask_bytes[1],
@@ -3827,10 +5152,10 @@ impl Serialize for FeedbackCtlDataLed {
             led_values_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.led_mask.serialize_into(bytes);
-        self.led_values.serialize_into(bytes);
+        self.led_mask.serialize_into_with_order(bytes, order);
+        self.led_values.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -3840,11 +5165,11 @@ pub struct FeedbackCtlDataBell {
     pub duration: i16,
 }
 impl TryParse for FeedbackCtlDataBell {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (percent, remaining) = i8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (percent, remaining) = i8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (pitch, remaining) = i16::try_parse(remaining)?;
-        let (duration, remaining) = i16::try_parse(remaining)?;
+        let (pitch, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = i16::try_parse_with_order(remaining, order)?;
         let result = FeedbackCtlDataBell { percent, pitch, duration };
         Ok((result, remaining))
     }
@@ -3857,10 +5182,10 @@ impl TryFrom<&[u8]> for FeedbackCtlDataBell {
 }
 impl Serialize for FeedbackCtlDataBell {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let percent_bytes = self.percent.serialize();
-        let pitch_bytes = self.pitch.serialize();
-        let duration_bytes = self.duration.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let percent_bytes = self.percent.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let duration_bytes = self.duration.serialize_with_order(order);
         [
             percent_bytes[0],
             0,
@@ -3872,12 +5197,12 @@ impl Serialize for FeedbackCtlDataBell {
             duration_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.percent.serialize_into(bytes);
+        self.percent.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
-        self.pitch.serialize_into(bytes);
-        self.duration.serialize_into(bytes);
+        self.pitch.serialize_into_with_order(bytes, order);
+        self.duration.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -3890,42 +5215,42 @@ pub enum FeedbackCtlData {
     Bell(FeedbackCtlDataBell),
 }
 impl FeedbackCtlData {
-    fn try_parse(value: &[u8], class_id: u8) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, class_id: FeedbackClass) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(class_id);
         let mut outer_remaining = value;
         let mut parse_result = None;
         if switch_expr == u32::from(FeedbackClass::Keyboard) {
-            let (keyboard, new_remaining) = FeedbackCtlDataKeyboard::try_parse(outer_remaining)?;
+            let (keyboard, new_remaining) = FeedbackCtlDataKeyboard::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackCtlData::Keyboard(keyboard));
         }
         if switch_expr == u32::from(FeedbackClass::Pointer) {
-            let (pointer, new_remaining) = FeedbackCtlDataPointer::try_parse(outer_remaining)?;
+            let (pointer, new_remaining) = FeedbackCtlDataPointer::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackCtlData::Pointer(pointer));
         }
         if switch_expr == u32::from(FeedbackClass::String) {
-            let (string, new_remaining) = FeedbackCtlDataString::try_parse(outer_remaining)?;
+            let (string, new_remaining) = FeedbackCtlDataString::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackCtlData::String(string));
         }
         if switch_expr == u32::from(FeedbackClass::Integer) {
-            let (integer, new_remaining) = FeedbackCtlDataInteger::try_parse(outer_remaining)?;
+            let (integer, new_remaining) = FeedbackCtlDataInteger::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackCtlData::Integer(integer));
         }
         if switch_expr == u32::from(FeedbackClass::Led) {
-            let (led, new_remaining) = FeedbackCtlDataLed::try_parse(outer_remaining)?;
+            let (led, new_remaining) = FeedbackCtlDataLed::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackCtlData::Led(led));
         }
         if switch_expr == u32::from(FeedbackClass::Bell) {
-            let (bell, new_remaining) = FeedbackCtlDataBell::try_parse(outer_remaining)?;
+            let (bell, new_remaining) = FeedbackCtlDataBell::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(FeedbackCtlData::Bell(bell));
@@ -3976,19 +5301,19 @@ impl FeedbackCtlData {
 }
 #[allow(dead_code, unused_variables)]
 impl FeedbackCtlData {
-    fn serialize(&self, class_id: u8) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, class_id: FeedbackClass) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, class_id);
+        self.serialize_into_with_order(&mut result, order, class_id);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, class_id: u8) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, class_id: FeedbackClass) {
         match self {
-            FeedbackCtlData::Keyboard(keyboard) => keyboard.serialize_into(bytes),
-            FeedbackCtlData::Pointer(pointer) => pointer.serialize_into(bytes),
-            FeedbackCtlData::String(string) => string.serialize_into(bytes),
-            FeedbackCtlData::Integer(integer) => integer.serialize_into(bytes),
-            FeedbackCtlData::Led(led) => led.serialize_into(bytes),
-            FeedbackCtlData::Bell(bell) => bell.serialize_into(bytes),
+            FeedbackCtlData::Keyboard(keyboard) => keyboard.serialize_into_with_order(bytes, order),
+            FeedbackCtlData::Pointer(pointer) => pointer.serialize_into_with_order(bytes, order),
+            FeedbackCtlData::String(string) => string.serialize_into_with_order(bytes, order),
+            FeedbackCtlData::Integer(integer) => integer.serialize_into_with_order(bytes, order),
+            FeedbackCtlData::Led(led) => led.serialize_into_with_order(bytes, order),
+            FeedbackCtlData::Bell(bell) => bell.serialize_into_with_order(bytes, order),
         }
     }
 }
@@ -4012,11 +5337,11 @@ pub struct FeedbackCtl {
     pub data: FeedbackCtlData,
 }
 impl TryParse for FeedbackCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (data, remaining) = FeedbackCtlData::try_parse(remaining, class_id)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = FeedbackCtlData::try_parse_with_order(remaining, order, FeedbackClass::from(class_id))?;
         let result = FeedbackCtl { feedback_id, len, data };
         Ok((result, remaining))
     }
@@ -4029,97 +5354,112 @@ impl TryFrom<&[u8]> for FeedbackCtl {
 }
 impl Serialize for FeedbackCtl {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
         let class_id = u8::try_from(self.data.switch_expr()).unwrap();
-        class_id.serialize_into(bytes);
-        self.feedback_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.data.serialize_into(bytes, class_id);
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ChangeFeedbackControlMask {
-    KeyClickPercent,
-    Percent,
-    Pitch,
-    Duration,
-    Led,
-    LedMode,
-    Key,
-    AutoRepeatMode,
-    String,
-    Integer,
-    AccelNum,
-    AccelDenom,
-    Threshold,
-}
-impl From<ChangeFeedbackControlMask> for u8 {
-    fn from(input: ChangeFeedbackControlMask) -> Self {
-        match input {
-            ChangeFeedbackControlMask::KeyClickPercent => 1 << 0,
-            ChangeFeedbackControlMask::Percent => 1 << 1,
-            ChangeFeedbackControlMask::Pitch => 1 << 2,
-            ChangeFeedbackControlMask::Duration => 1 << 3,
-            ChangeFeedbackControlMask::Led => 1 << 4,
-            ChangeFeedbackControlMask::LedMode => 1 << 5,
-            ChangeFeedbackControlMask::Key => 1 << 6,
-            ChangeFeedbackControlMask::AutoRepeatMode => 1 << 7,
-            ChangeFeedbackControlMask::String => 1 << 0,
-            ChangeFeedbackControlMask::Integer => 1 << 0,
-            ChangeFeedbackControlMask::AccelNum => 1 << 0,
-            ChangeFeedbackControlMask::AccelDenom => 1 << 1,
-            ChangeFeedbackControlMask::Threshold => 1 << 2,
-        }
-    }
-}
-impl From<ChangeFeedbackControlMask> for Option<u8> {
-    fn from(input: ChangeFeedbackControlMask) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<ChangeFeedbackControlMask> for u16 {
-    fn from(input: ChangeFeedbackControlMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<ChangeFeedbackControlMask> for Option<u16> {
-    fn from(input: ChangeFeedbackControlMask) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<ChangeFeedbackControlMask> for u32 {
-    fn from(input: ChangeFeedbackControlMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+        class_id.serialize_into_with_order(bytes, order);
+        self.feedback_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.data.serialize_into_with_order(bytes, order, FeedbackClass::from(class_id));
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ChangeFeedbackControlMask(u32);
+#[allow(non_upper_case_globals)]
+impl ChangeFeedbackControlMask {
+    pub const KeyClickPercent: Self = Self(1 << 0);
+    pub const Percent: Self = Self(1 << 1);
+    pub const Pitch: Self = Self(1 << 2);
+    pub const Duration: Self = Self(1 << 3);
+    pub const Led: Self = Self(1 << 4);
+    pub const LedMode: Self = Self(1 << 5);
+    pub const Key: Self = Self(1 << 6);
+    pub const AutoRepeatMode: Self = Self(1 << 7);
+    pub const String: Self = Self(1 << 0);
+    pub const Integer: Self = Self(1 << 0);
+    pub const AccelNum: Self = Self(1 << 0);
+    pub const AccelDenom: Self = Self(1 << 1);
+    pub const Threshold: Self = Self(1 << 2);
+}
+bitmask_set!(ChangeFeedbackControlMask, u32, [KeyClickPercent, Percent, Pitch, Duration, Led, LedMode, Key, AutoRepeatMode, String, Integer, AccelNum, AccelDenom, Threshold]);
 impl From<ChangeFeedbackControlMask> for Option<u32> {
     fn from(input: ChangeFeedbackControlMask) -> Self {
-        Some(u32::from(input))
+        Some(input.0)
     }
 }
-bitmask_binop!(ChangeFeedbackControlMask, u8);
 
 /// Opcode for the ChangeFeedbackControl request
 pub const CHANGE_FEEDBACK_CONTROL_REQUEST: u8 = 23;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeFeedbackControlRequest {
+    pub mask: ChangeFeedbackControlMask,
+    pub device_id: u8,
+    pub feedback_id: u8,
+    pub feedback: FeedbackCtl,
+}
+impl ChangeFeedbackControlRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_FEEDBACK_CONTROL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (feedback, remaining) = FeedbackCtl::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let mask = ChangeFeedbackControlMask::from(mask);
+        Ok(ChangeFeedbackControlRequest {
+            mask,
+            device_id,
+            feedback_id,
+            feedback,
+        })
+    }
+    /// Set the `mask` field of this structure.
+    pub fn mask<I>(mut self, value: I) -> Self where I: Into<ChangeFeedbackControlMask> {
+        self.mask = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `feedback_id` field of this structure.
+    pub fn feedback_id(mut self, value: u8) -> Self {
+        self.feedback_id = value;
+        self
+    }
+    /// Set the `feedback` field of this structure.
+    pub fn feedback<I>(mut self, value: I) -> Self where I: Into<FeedbackCtl> {
+        self.feedback = value.into();
+        self
+    }
+}
+
 pub fn change_feedback_control<Conn, A>(conn: &Conn, mask: A, device_id: u8, feedback_id: u8, feedback: FeedbackCtl) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<ChangeFeedbackControlMask>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let mask: u32 = mask.into();
+    let mask: ChangeFeedbackControlMask = mask.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let mask_bytes = mask.serialize();
-    let device_id_bytes = device_id.serialize();
-    let feedback_id_bytes = feedback_id.serialize();
+    let mask_bytes = u32::from(mask).serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
+    let feedback_id_bytes = feedback_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_FEEDBACK_CONTROL_REQUEST,
@@ -4135,28 +5475,83 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let feedback_bytes = feedback.serialize();
+    let feedback_bytes = feedback.serialize_with_order(order);
     let length_so_far = length_so_far + feedback_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&feedback_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeFeedbackControlRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeFeedbackControlRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_feedback_control(conn, self.mask, self.device_id, self.feedback_id, self.feedback)
+    }
+}
 
 /// Opcode for the GetDeviceKeyMapping request
 pub const GET_DEVICE_KEY_MAPPING_REQUEST: u8 = 24;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDeviceKeyMappingRequest {
+    pub device_id: u8,
+    pub first_keycode: KeyCode,
+    pub count: u8,
+}
+impl GetDeviceKeyMappingRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_KEY_MAPPING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_keycode, remaining) = KeyCode::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDeviceKeyMappingRequest {
+            device_id,
+            first_keycode,
+            count,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `first_keycode` field of this structure.
+    pub fn first_keycode<I>(mut self, value: I) -> Self where I: Into<KeyCode> {
+        self.first_keycode = value.into();
+        self
+    }
+    /// Set the `count` field of this structure.
+    pub fn count(mut self, value: u8) -> Self {
+        self.count = value;
+        self
+    }
+}
+
 pub fn get_device_key_mapping<Conn>(conn: &Conn, device_id: u8, first_keycode: KeyCode, count: u8) -> Result<Cookie<'_, Conn, GetDeviceKeyMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
-    let first_keycode_bytes = first_keycode.serialize();
-    let count_bytes = count.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
+    let first_keycode_bytes = first_keycode.serialize_with_order(order);
+    let count_bytes = count.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_KEY_MAPPING_REQUEST,
@@ -4170,9 +5565,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDeviceKeyMappingRequest {
+    type Reply = GetDeviceKeyMappingReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDeviceKeyMappingRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDeviceKeyMappingReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_key_mapping(conn, self.device_id, self.first_keycode, self.count)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceKeyMappingReply {
@@ -4183,14 +5590,14 @@ pub struct GetDeviceKeyMappingReply {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl TryParse for GetDeviceKeyMappingReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (keysyms_per_keycode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (keysyms_per_keycode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Keysym>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetDeviceKeyMappingReply { response_type, xi_reply_type, sequence, keysyms_per_keycode, keysyms };
         Ok((result, remaining))
     }
@@ -4201,20 +5608,104 @@ impl TryFrom<&[u8]> for GetDeviceKeyMappingReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-/// Opcode for the ChangeDeviceKeyMapping request
+impl Serialize for GetDeviceKeyMappingReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.keysyms.len()).expect("`keysyms` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.keysyms_per_keycode.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        self.keysyms.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+/// Opcode for the ChangeDeviceKeyMapping request
 pub const CHANGE_DEVICE_KEY_MAPPING_REQUEST: u8 = 25;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChangeDeviceKeyMappingRequest {
+    pub device_id: u8,
+    pub first_keycode: KeyCode,
+    pub keysyms_per_keycode: u8,
+    pub keycode_count: u8,
+    pub keysyms: Vec<xproto::Keysym>,
+}
+impl ChangeDeviceKeyMappingRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_DEVICE_KEY_MAPPING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_keycode, remaining) = KeyCode::try_parse_with_order(remaining, order)?;
+        let (keysyms_per_keycode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (keycode_count, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Keysym>(remaining, u32::from(keycode_count).checked_mul(u32::from(keysyms_per_keycode)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ChangeDeviceKeyMappingRequest {
+            device_id,
+            first_keycode,
+            keysyms_per_keycode,
+            keycode_count,
+            keysyms,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `first_keycode` field of this structure.
+    pub fn first_keycode<I>(mut self, value: I) -> Self where I: Into<KeyCode> {
+        self.first_keycode = value.into();
+        self
+    }
+    /// Set the `keysyms_per_keycode` field of this structure.
+    pub fn keysyms_per_keycode(mut self, value: u8) -> Self {
+        self.keysyms_per_keycode = value;
+        self
+    }
+    /// Set the `keycode_count` field of this structure.
+    pub fn keycode_count(mut self, value: u8) -> Self {
+        self.keycode_count = value;
+        self
+    }
+    /// Set the `keysyms` field of this structure.
+    pub fn keysyms<I>(mut self, value: I) -> Self where I: Into<Vec<xproto::Keysym>> {
+        self.keysyms = value.into();
+        self
+    }
+}
+
 pub fn change_device_key_mapping<'c, Conn>(conn: &'c Conn, device_id: u8, first_keycode: KeyCode, keysyms_per_keycode: u8, keycode_count: u8, keysyms: &[xproto::Keysym]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
-    let first_keycode_bytes = first_keycode.serialize();
-    let keysyms_per_keycode_bytes = keysyms_per_keycode.serialize();
-    let keycode_count_bytes = keycode_count.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
+    let first_keycode_bytes = first_keycode.serialize_with_order(order);
+    let keysyms_per_keycode_bytes = keysyms_per_keycode.serialize_with_order(order);
+    let keycode_count_bytes = keycode_count.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_DEVICE_KEY_MAPPING_REQUEST,
@@ -4227,26 +5718,65 @@ where
     ];
     let length_so_far = length_so_far + request0.len();
     assert_eq!(keysyms.len(), usize::try_from(u32::from(keycode_count).checked_mul(u32::from(keysyms_per_keycode)).unwrap()).unwrap(), "`keysyms` has an incorrect length");
-    let keysyms_bytes = keysyms.serialize();
+    let keysyms_bytes = keysyms.serialize_with_order(order);
     let length_so_far = length_so_far + keysyms_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&keysyms_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeDeviceKeyMappingRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeDeviceKeyMappingRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_device_key_mapping(conn, self.device_id, self.first_keycode, self.keysyms_per_keycode, self.keycode_count, &self.keysyms)
+    }
+}
 
 /// Opcode for the GetDeviceModifierMapping request
 pub const GET_DEVICE_MODIFIER_MAPPING_REQUEST: u8 = 26;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDeviceModifierMappingRequest {
+    pub device_id: u8,
+}
+impl GetDeviceModifierMappingRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_MODIFIER_MAPPING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDeviceModifierMappingRequest {
+            device_id,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn get_device_modifier_mapping<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceModifierMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_MODIFIER_MAPPING_REQUEST,
@@ -4260,9 +5790,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDeviceModifierMappingRequest {
+    type Reply = GetDeviceModifierMappingReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDeviceModifierMappingRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDeviceModifierMappingReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_modifier_mapping(conn, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceModifierMappingReply {
@@ -4273,12 +5815,12 @@ pub struct GetDeviceModifierMappingReply {
     pub keymaps: Vec<u8>,
 }
 impl TryParse for GetDeviceModifierMappingReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (keycodes_per_modifier, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (keycodes_per_modifier, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
         let (keymaps, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
         let keymaps = keymaps.to_vec();
@@ -4292,20 +5834,84 @@ impl TryFrom<&[u8]> for GetDeviceModifierMappingReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDeviceModifierMappingReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        assert_eq!(self.keymaps.len() % 8, 0, "`keymaps` has an incorrect length, must be a multiple of 8");
+        let keycodes_per_modifier = u8::try_from(self.keymaps.len() / 8).expect("`keymaps` has too many elements");
+        keycodes_per_modifier.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        bytes.extend_from_slice(&self.keymaps);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetDeviceModifierMapping request
 pub const SET_DEVICE_MODIFIER_MAPPING_REQUEST: u8 = 27;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetDeviceModifierMappingRequest {
+    pub device_id: u8,
+    pub keymaps: Vec<u8>,
+}
+impl SetDeviceModifierMappingRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_DEVICE_MODIFIER_MAPPING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (keycodes_per_modifier, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (keymaps, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let keymaps = keymaps.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(SetDeviceModifierMappingRequest {
+            device_id,
+            keymaps,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `keymaps` field of this structure.
+    pub fn keymaps<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.keymaps = value.into();
+        self
+    }
+}
+
 pub fn set_device_modifier_mapping<'c, Conn>(conn: &'c Conn, device_id: u8, keymaps: &[u8]) -> Result<Cookie<'c, Conn, SetDeviceModifierMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     assert_eq!(keymaps.len() % 8, 0, "`keymaps` has an incorrect length, must be a multiple of 8");
     let keycodes_per_modifier = u8::try_from(keymaps.len() / 8).expect("`keymaps` has too many elements");
-    let keycodes_per_modifier_bytes = keycodes_per_modifier.serialize();
+    let keycodes_per_modifier_bytes = keycodes_per_modifier.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_DEVICE_MODIFIER_MAPPING_REQUEST,
@@ -4322,9 +5928,21 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(keymaps), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetDeviceModifierMappingRequest {
+    type Reply = SetDeviceModifierMappingReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetDeviceModifierMappingRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, SetDeviceModifierMappingReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_device_modifier_mapping(conn, self.device_id, &self.keymaps)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceModifierMappingReply {
@@ -4335,14 +5953,14 @@ pub struct SetDeviceModifierMappingReply {
     pub status: xproto::MappingStatus,
 }
 impl TryParse for SetDeviceModifierMappingReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::MappingStatus::from(status);
         let result = SetDeviceModifierMappingReply { response_type, xi_reply_type, sequence, length, status };
         Ok((result, remaining))
     }
@@ -4353,17 +5971,68 @@ impl TryFrom<&[u8]> for SetDeviceModifierMappingReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for SetDeviceModifierMappingReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetDeviceButtonMapping request
 pub const GET_DEVICE_BUTTON_MAPPING_REQUEST: u8 = 28;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDeviceButtonMappingRequest {
+    pub device_id: u8,
+}
+impl GetDeviceButtonMappingRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_BUTTON_MAPPING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDeviceButtonMappingRequest {
+            device_id,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn get_device_button_mapping<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceButtonMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_BUTTON_MAPPING_REQUEST,
@@ -4377,9 +6046,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDeviceButtonMappingRequest {
+    type Reply = GetDeviceButtonMappingReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDeviceButtonMappingRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDeviceButtonMappingReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_button_mapping(conn, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceButtonMappingReply {
@@ -4390,13 +6071,13 @@ pub struct GetDeviceButtonMappingReply {
     pub map: Vec<u8>,
 }
 impl TryParse for GetDeviceButtonMappingReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (map_size, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (map_size, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
         let (map, remaining) = crate::x11_utils::parse_u8_list(remaining, map_size.try_into().or(Err(ParseError::ParseError))?)?;
         let map = map.to_vec();
@@ -4414,19 +6095,83 @@ impl TryFrom<&[u8]> for GetDeviceButtonMappingReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDeviceButtonMappingReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let map_size = u8::try_from(self.map.len()).expect("`map` has too many elements");
+        map_size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        bytes.extend_from_slice(&self.map);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetDeviceButtonMapping request
 pub const SET_DEVICE_BUTTON_MAPPING_REQUEST: u8 = 29;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetDeviceButtonMappingRequest {
+    pub device_id: u8,
+    pub map: Vec<u8>,
+}
+impl SetDeviceButtonMappingRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_DEVICE_BUTTON_MAPPING_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_size, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (map, remaining) = crate::x11_utils::parse_u8_list(remaining, map_size.try_into().or(Err(ParseError::ParseError))?)?;
+        let map = map.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(SetDeviceButtonMappingRequest {
+            device_id,
+            map,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `map` field of this structure.
+    pub fn map<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.map = value.into();
+        self
+    }
+}
+
 pub fn set_device_button_mapping<'c, Conn>(conn: &'c Conn, device_id: u8, map: &[u8]) -> Result<Cookie<'c, Conn, SetDeviceButtonMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let map_size = u8::try_from(map.len()).expect("`map` has too many elements");
-    let map_size_bytes = map_size.serialize();
+    let map_size_bytes = map_size.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_DEVICE_BUTTON_MAPPING_REQUEST,
@@ -4443,9 +6188,21 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(map), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetDeviceButtonMappingRequest {
+    type Reply = SetDeviceButtonMappingReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetDeviceButtonMappingRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, SetDeviceButtonMappingReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_device_button_mapping(conn, self.device_id, &self.map)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceButtonMappingReply {
@@ -4456,14 +6213,14 @@ pub struct SetDeviceButtonMappingReply {
     pub status: xproto::MappingStatus,
 }
 impl TryParse for SetDeviceButtonMappingReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::MappingStatus::from(status);
         let result = SetDeviceButtonMappingReply { response_type, xi_reply_type, sequence, length, status };
         Ok((result, remaining))
     }
@@ -4474,6 +6231,30 @@ impl TryFrom<&[u8]> for SetDeviceButtonMappingReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for SetDeviceButtonMappingReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KeyState {
@@ -4483,14 +6264,14 @@ pub struct KeyState {
     pub keys: [u8; 32],
 }
 impl TryParse for KeyState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (num_keys, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let (keys, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let keys = <[u8; 32]>::try_from(keys).unwrap();
-        let class_id = class_id.try_into()?;
+        let class_id = InputClass::from(class_id);
         let result = KeyState { class_id, len, num_keys, keys };
         Ok((result, remaining))
     }
@@ -4503,10 +6284,10 @@ impl TryFrom<&[u8]> for KeyState {
 }
 impl Serialize for KeyState {
     type Bytes = [u8; 36];
-    fn serialize(&self) -> [u8; 36] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let len_bytes = self.len.serialize();
-        let num_keys_bytes = self.num_keys.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 36] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let num_keys_bytes = self.num_keys.serialize_with_order(order);
         [
             class_id_bytes[0],
             len_bytes[0],
@@ -4546,11 +6327,11 @@ impl Serialize for KeyState {
             self.keys[31],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(36);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.num_keys.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.num_keys.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
         bytes.extend_from_slice(&self.keys);
     }
@@ -4564,14 +6345,14 @@ pub struct ButtonState {
     pub buttons: [u8; 32],
 }
 impl TryParse for ButtonState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (num_buttons, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_buttons, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let (buttons, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let buttons = <[u8; 32]>::try_from(buttons).unwrap();
-        let class_id = class_id.try_into()?;
+        let class_id = InputClass::from(class_id);
         let result = ButtonState { class_id, len, num_buttons, buttons };
         Ok((result, remaining))
     }
@@ -4584,10 +6365,10 @@ impl TryFrom<&[u8]> for ButtonState {
 }
 impl Serialize for ButtonState {
     type Bytes = [u8; 36];
-    fn serialize(&self) -> [u8; 36] {
-        let class_id_bytes = u8::from(self.class_id).serialize();
-        let len_bytes = self.len.serialize();
-        let num_buttons_bytes = self.num_buttons.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 36] {
+        let class_id_bytes = u8::from(self.class_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let num_buttons_bytes = self.num_buttons.serialize_with_order(order);
         [
             class_id_bytes[0],
             len_bytes[0],
@@ -4627,94 +6408,66 @@ impl Serialize for ButtonState {
             self.buttons[31],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(36);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.num_buttons.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.num_buttons.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
         bytes.extend_from_slice(&self.buttons);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ValuatorStateModeMask {
-    DeviceModeAbsolute = 1 << 0,
-    OutOfProximity = 1 << 1,
-}
-impl From<ValuatorStateModeMask> for u8 {
-    fn from(input: ValuatorStateModeMask) -> Self {
-        match input {
-            ValuatorStateModeMask::DeviceModeAbsolute => 1 << 0,
-            ValuatorStateModeMask::OutOfProximity => 1 << 1,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ValuatorStateModeMask(u8);
+#[allow(non_upper_case_globals)]
+impl ValuatorStateModeMask {
+    pub const DeviceModeAbsolute: Self = Self(1 << 0);
+    pub const OutOfProximity: Self = Self(1 << 1);
 }
+bitmask_set!(ValuatorStateModeMask, u8, [DeviceModeAbsolute, OutOfProximity]);
 impl From<ValuatorStateModeMask> for Option<u8> {
     fn from(input: ValuatorStateModeMask) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ValuatorStateModeMask> for u16 {
     fn from(input: ValuatorStateModeMask) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ValuatorStateModeMask> for Option<u16> {
     fn from(input: ValuatorStateModeMask) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ValuatorStateModeMask> for u32 {
     fn from(input: ValuatorStateModeMask) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ValuatorStateModeMask> for Option<u32> {
     fn from(input: ValuatorStateModeMask) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ValuatorStateModeMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ValuatorStateModeMask::DeviceModeAbsolute),
-            2 => Ok(ValuatorStateModeMask::OutOfProximity),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ValuatorStateModeMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u32> for ValuatorStateModeMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(ValuatorStateModeMask, u8);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ValuatorState {
     pub class_id: InputClass,
     pub len: u8,
-    pub mode: u8,
+    pub mode: ValuatorStateModeMask,
     pub valuators: Vec<i32>,
 }
 impl TryParse for ValuatorState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (valuators, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
-        let class_id = class_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_valuators, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (valuators, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let class_id = InputClass::from(class_id);
+        let mode = ValuatorStateModeMask::from(mode);
         let result = ValuatorState { class_id, len, mode, valuators };
         Ok((result, remaining))
     }
@@ -4727,19 +6480,19 @@ impl TryFrom<&[u8]> for ValuatorState {
 }
 impl Serialize for ValuatorState {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        u8::from(self.class_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
+        u8::from(self.class_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
         let num_valuators = u8::try_from(self.valuators.len()).expect("`valuators` has too many elements");
-        num_valuators.serialize_into(bytes);
-        self.mode.serialize_into(bytes);
-        self.valuators.serialize_into(bytes);
+        num_valuators.serialize_into_with_order(bytes, order);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
+        self.valuators.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -4749,8 +6502,8 @@ pub struct InputStateDataKey {
     pub keys: [u8; 32],
 }
 impl TryParse for InputStateDataKey {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_keys, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (num_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let (keys, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let keys = <[u8; 32]>::try_from(keys).unwrap();
@@ -4766,8 +6519,8 @@ impl TryFrom<&[u8]> for InputStateDataKey {
 }
 impl Serialize for InputStateDataKey {
     type Bytes = [u8; 34];
-    fn serialize(&self) -> [u8; 34] {
-        let num_keys_bytes = self.num_keys.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 34] {
+        let num_keys_bytes = self.num_keys.serialize_with_order(order);
         [
             num_keys_bytes[0],
             0,
@@ -4805,9 +6558,9 @@ impl Serialize for InputStateDataKey {
             self.keys[31],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(34);
-        self.num_keys.serialize_into(bytes);
+        self.num_keys.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
         bytes.extend_from_slice(&self.keys);
     }
@@ -4818,8 +6571,8 @@ pub struct InputStateDataButton {
     pub buttons: [u8; 32],
 }
 impl TryParse for InputStateDataButton {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_buttons, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (num_buttons, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let (buttons, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let buttons = <[u8; 32]>::try_from(buttons).unwrap();
@@ -4835,8 +6588,8 @@ impl TryFrom<&[u8]> for InputStateDataButton {
 }
 impl Serialize for InputStateDataButton {
     type Bytes = [u8; 34];
-    fn serialize(&self) -> [u8; 34] {
-        let num_buttons_bytes = self.num_buttons.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 34] {
+        let num_buttons_bytes = self.num_buttons.serialize_with_order(order);
         [
             num_buttons_bytes[0],
             0,
@@ -4874,23 +6627,24 @@ impl Serialize for InputStateDataButton {
             self.buttons[31],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(34);
-        self.num_buttons.serialize_into(bytes);
+        self.num_buttons.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
         bytes.extend_from_slice(&self.buttons);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InputStateDataValuator {
-    pub mode: u8,
+    pub mode: ValuatorStateModeMask,
     pub valuators: Vec<i32>,
 }
 impl TryParse for InputStateDataValuator {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (valuators, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (num_valuators, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (valuators, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let mode = ValuatorStateModeMask::from(mode);
         let result = InputStateDataValuator { mode, valuators };
         Ok((result, remaining))
     }
@@ -4903,17 +6657,17 @@ impl TryFrom<&[u8]> for InputStateDataValuator {
 }
 impl Serialize for InputStateDataValuator {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
         let num_valuators = u8::try_from(self.valuators.len()).expect("`valuators` has too many elements");
-        num_valuators.serialize_into(bytes);
-        self.mode.serialize_into(bytes);
-        self.valuators.serialize_into(bytes);
+        num_valuators.serialize_into_with_order(bytes, order);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
+        self.valuators.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -4923,24 +6677,24 @@ pub enum InputStateData {
     Valuator(InputStateDataValuator),
 }
 impl InputStateData {
-    fn try_parse(value: &[u8], class_id: u8) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, class_id: InputClass) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(class_id);
         let mut outer_remaining = value;
         let mut parse_result = None;
         if switch_expr == u32::from(InputClass::Key) {
-            let (key, new_remaining) = InputStateDataKey::try_parse(outer_remaining)?;
+            let (key, new_remaining) = InputStateDataKey::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(InputStateData::Key(key));
         }
         if switch_expr == u32::from(InputClass::Button) {
-            let (button, new_remaining) = InputStateDataButton::try_parse(outer_remaining)?;
+            let (button, new_remaining) = InputStateDataButton::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(InputStateData::Button(button));
         }
         if switch_expr == u32::from(InputClass::Valuator) {
-            let (valuator, new_remaining) = InputStateDataValuator::try_parse(outer_remaining)?;
+            let (valuator, new_remaining) = InputStateDataValuator::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(InputStateData::Valuator(valuator));
@@ -4973,16 +6727,16 @@ impl InputStateData {
 }
 #[allow(dead_code, unused_variables)]
 impl InputStateData {
-    fn serialize(&self, class_id: u8) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, class_id: InputClass) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, class_id);
+        self.serialize_into_with_order(&mut result, order, class_id);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, class_id: u8) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, class_id: InputClass) {
         match self {
-            InputStateData::Key(key) => key.serialize_into(bytes),
-            InputStateData::Button(button) => button.serialize_into(bytes),
-            InputStateData::Valuator(valuator) => valuator.serialize_into(bytes),
+            InputStateData::Key(key) => key.serialize_into_with_order(bytes, order),
+            InputStateData::Button(button) => button.serialize_into_with_order(bytes, order),
+            InputStateData::Valuator(valuator) => valuator.serialize_into_with_order(bytes, order),
         }
     }
 }
@@ -5002,10 +6756,10 @@ pub struct InputState {
     pub data: InputStateData,
 }
 impl TryParse for InputState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (data, remaining) = InputStateData::try_parse(remaining, class_id)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (class_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = InputStateData::try_parse_with_order(remaining, order, InputClass::from(class_id))?;
         let result = InputState { len, data };
         Ok((result, remaining))
     }
@@ -5018,30 +6772,57 @@ impl TryFrom<&[u8]> for InputState {
 }
 impl Serialize for InputState {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
         let class_id = u8::try_from(self.data.switch_expr()).unwrap();
-        class_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.data.serialize_into(bytes, class_id);
+        class_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.data.serialize_into_with_order(bytes, order, InputClass::from(class_id));
     }
 }
 
 /// Opcode for the QueryDeviceState request
 pub const QUERY_DEVICE_STATE_REQUEST: u8 = 30;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryDeviceStateRequest {
+    pub device_id: u8,
+}
+impl QueryDeviceStateRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_DEVICE_STATE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryDeviceStateRequest {
+            device_id,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn query_device_state<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, QueryDeviceStateReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_DEVICE_STATE_REQUEST,
@@ -5055,9 +6836,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryDeviceStateRequest {
+    type Reply = QueryDeviceStateReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryDeviceStateRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryDeviceStateReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_device_state(conn, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryDeviceStateReply {
@@ -5068,14 +6861,14 @@ pub struct QueryDeviceStateReply {
     pub classes: Vec<InputState>,
 }
 impl TryParse for QueryDeviceStateReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_classes, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<InputState>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?)?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<InputState>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryDeviceStateReply { response_type, xi_reply_type, sequence, length, classes };
         Ok((result, remaining))
     }
@@ -5086,20 +6879,96 @@ impl TryFrom<&[u8]> for QueryDeviceStateReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryDeviceStateReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_classes = u8::try_from(self.classes.len()).expect("`classes` has too many elements");
+        num_classes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        self.classes.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DeviceBell request
 pub const DEVICE_BELL_REQUEST: u8 = 32;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeviceBellRequest {
+    pub device_id: u8,
+    pub feedback_id: u8,
+    pub feedback_class: u8,
+    pub percent: i8,
+}
+impl DeviceBellRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DEVICE_BELL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (feedback_class, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (percent, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeviceBellRequest {
+            device_id,
+            feedback_id,
+            feedback_class,
+            percent,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `feedback_id` field of this structure.
+    pub fn feedback_id(mut self, value: u8) -> Self {
+        self.feedback_id = value;
+        self
+    }
+    /// Set the `feedback_class` field of this structure.
+    pub fn feedback_class(mut self, value: u8) -> Self {
+        self.feedback_class = value;
+        self
+    }
+    /// Set the `percent` field of this structure.
+    pub fn percent(mut self, value: i8) -> Self {
+        self.percent = value;
+        self
+    }
+}
+
 pub fn device_bell<Conn>(conn: &Conn, device_id: u8, feedback_id: u8, feedback_class: u8, percent: i8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
-    let feedback_id_bytes = feedback_id.serialize();
-    let feedback_class_bytes = feedback_class.serialize();
-    let percent_bytes = percent.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
+    let feedback_id_bytes = feedback_id.serialize_with_order(order);
+    let feedback_class_bytes = feedback_class.serialize_with_order(order);
+    let percent_bytes = percent.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DEVICE_BELL_REQUEST,
@@ -5113,23 +6982,79 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeviceBellRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeviceBellRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        device_bell(conn, self.device_id, self.feedback_id, self.feedback_class, self.percent)
+    }
+}
 
 /// Opcode for the SetDeviceValuators request
 pub const SET_DEVICE_VALUATORS_REQUEST: u8 = 33;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetDeviceValuatorsRequest {
+    pub device_id: u8,
+    pub first_valuator: u8,
+    pub valuators: Vec<i32>,
+}
+impl SetDeviceValuatorsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_DEVICE_VALUATORS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_valuator, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_valuators, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (valuators, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetDeviceValuatorsRequest {
+            device_id,
+            first_valuator,
+            valuators,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `first_valuator` field of this structure.
+    pub fn first_valuator(mut self, value: u8) -> Self {
+        self.first_valuator = value;
+        self
+    }
+    /// Set the `valuators` field of this structure.
+    pub fn valuators<I>(mut self, value: I) -> Self where I: Into<Vec<i32>> {
+        self.valuators = value.into();
+        self
+    }
+}
+
 pub fn set_device_valuators<'c, Conn>(conn: &'c Conn, device_id: u8, first_valuator: u8, valuators: &[i32]) -> Result<Cookie<'c, Conn, SetDeviceValuatorsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
-    let first_valuator_bytes = first_valuator.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
+    let first_valuator_bytes = first_valuator.serialize_with_order(order);
     let num_valuators = u8::try_from(valuators.len()).expect("`valuators` has too many elements");
-    let num_valuators_bytes = num_valuators.serialize();
+    let num_valuators_bytes = num_valuators.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_DEVICE_VALUATORS_REQUEST,
@@ -5141,15 +7066,27 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let valuators_bytes = valuators.serialize();
+    let valuators_bytes = valuators.serialize_with_order(order);
     let length_so_far = length_so_far + valuators_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&valuators_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetDeviceValuatorsRequest {
+    type Reply = SetDeviceValuatorsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetDeviceValuatorsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, SetDeviceValuatorsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_device_valuators(conn, self.device_id, self.first_valuator, &self.valuators)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceValuatorsReply {
@@ -5160,14 +7097,14 @@ pub struct SetDeviceValuatorsReply {
     pub status: xproto::GrabStatus,
 }
 impl TryParse for SetDeviceValuatorsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::GrabStatus::from(status);
         let result = SetDeviceValuatorsReply { response_type, xi_reply_type, sequence, length, status };
         Ok((result, remaining))
     }
@@ -5178,75 +7115,55 @@ impl TryFrom<&[u8]> for SetDeviceValuatorsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum DeviceControl {
-    Resolution = 1,
-    Abscalib = 2,
-    Core = 3,
-    Enable = 4,
-    Absarea = 5,
-}
-impl From<DeviceControl> for u8 {
-    fn from(input: DeviceControl) -> Self {
-        match input {
-            DeviceControl::Resolution => 1,
-            DeviceControl::Abscalib => 2,
-            DeviceControl::Core => 3,
-            DeviceControl::Enable => 4,
-            DeviceControl::Absarea => 5,
-        }
-    }
-}
-impl From<DeviceControl> for Option<u8> {
-    fn from(input: DeviceControl) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<DeviceControl> for u16 {
-    fn from(input: DeviceControl) -> Self {
-        Self::from(u8::from(input))
+impl Serialize for SetDeviceValuatorsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeviceControl(u16);
+#[allow(non_upper_case_globals)]
+impl DeviceControl {
+    pub const Resolution: Self = Self(1);
+    pub const Abscalib: Self = Self(2);
+    pub const Core: Self = Self(3);
+    pub const Enable: Self = Self(4);
+    pub const Absarea: Self = Self(5);
+}
+enum_newtype!(DeviceControl, u16, [Resolution, Abscalib, Core, Enable, Absarea]);
 impl From<DeviceControl> for Option<u16> {
     fn from(input: DeviceControl) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<DeviceControl> for u32 {
     fn from(input: DeviceControl) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DeviceControl> for Option<u32> {
     fn from(input: DeviceControl) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for DeviceControl {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(DeviceControl::Resolution),
-            2 => Ok(DeviceControl::Abscalib),
-            3 => Ok(DeviceControl::Core),
-            4 => Ok(DeviceControl::Enable),
-            5 => Ok(DeviceControl::Absarea),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for DeviceControl {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for DeviceControl {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -5259,14 +7176,14 @@ pub struct DeviceResolutionState {
     pub resolution_max: Vec<u32>,
 }
 impl TryParse for DeviceResolutionState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (num_valuators, remaining) = u32::try_parse(remaining)?;
-        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
-        let (resolution_min, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
-        let (resolution_max, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
-        let control_id = control_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_valuators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (resolution_values, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (resolution_min, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (resolution_max, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceResolutionState { control_id, len, resolution_values, resolution_min, resolution_max };
         Ok((result, remaining))
     }
@@ -5279,22 +7196,22 @@ impl TryFrom<&[u8]> for DeviceResolutionState {
 }
 impl Serialize for DeviceResolutionState {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
         let num_valuators = u32::try_from(self.resolution_values.len()).expect("`resolution_values` has too many elements");
-        num_valuators.serialize_into(bytes);
-        self.resolution_values.serialize_into(bytes);
+        num_valuators.serialize_into_with_order(bytes, order);
+        self.resolution_values.serialize_into_with_order(bytes, order);
         assert_eq!(self.resolution_min.len(), usize::try_from(num_valuators).unwrap(), "`resolution_min` has an incorrect length");
-        self.resolution_min.serialize_into(bytes);
+        self.resolution_min.serialize_into_with_order(bytes, order);
         assert_eq!(self.resolution_max.len(), usize::try_from(num_valuators).unwrap(), "`resolution_max` has an incorrect length");
-        self.resolution_max.serialize_into(bytes);
+        self.resolution_max.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -5312,18 +7229,18 @@ pub struct DeviceAbsCalibState {
     pub button_threshold: u32,
 }
 impl TryParse for DeviceAbsCalibState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (min_x, remaining) = i32::try_parse(remaining)?;
-        let (max_x, remaining) = i32::try_parse(remaining)?;
-        let (min_y, remaining) = i32::try_parse(remaining)?;
-        let (max_y, remaining) = i32::try_parse(remaining)?;
-        let (flip_x, remaining) = u32::try_parse(remaining)?;
-        let (flip_y, remaining) = u32::try_parse(remaining)?;
-        let (rotation, remaining) = u32::try_parse(remaining)?;
-        let (button_threshold, remaining) = u32::try_parse(remaining)?;
-        let control_id = control_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (min_x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (min_y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (flip_x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flip_y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (button_threshold, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceAbsCalibState { control_id, len, min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
     }
@@ -5336,17 +7253,17 @@ impl TryFrom<&[u8]> for DeviceAbsCalibState {
 }
 impl Serialize for DeviceAbsCalibState {
     type Bytes = [u8; 36];
-    fn serialize(&self) -> [u8; 36] {
-        let control_id_bytes = u16::from(self.control_id).serialize();
-        let len_bytes = self.len.serialize();
-        let min_x_bytes = self.min_x.serialize();
-        let max_x_bytes = self.max_x.serialize();
-        let min_y_bytes = self.min_y.serialize();
-        let max_y_bytes = self.max_y.serialize();
-        let flip_x_bytes = self.flip_x.serialize();
-        let flip_y_bytes = self.flip_y.serialize();
-        let rotation_bytes = self.rotation.serialize();
-        let button_threshold_bytes = self.button_threshold.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 36] {
+        let control_id_bytes = u16::from(self.control_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let min_x_bytes = self.min_x.serialize_with_order(order);
+        let max_x_bytes = self.max_x.serialize_with_order(order);
+        let min_y_bytes = self.min_y.serialize_with_order(order);
+        let max_y_bytes = self.max_y.serialize_with_order(order);
+        let flip_x_bytes = self.flip_x.serialize_with_order(order);
+        let flip_y_bytes = self.flip_y.serialize_with_order(order);
+        let rotation_bytes = self.rotation.serialize_with_order(order);
+        let button_threshold_bytes = self.button_threshold.serialize_with_order(order);
         [
             control_id_bytes[0],
             control_id_bytes[1],
@@ -5386,18 +7303,18 @@ impl Serialize for DeviceAbsCalibState {
             button_threshold_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(36);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.min_x.serialize_into(bytes);
-        self.max_x.serialize_into(bytes);
-        self.min_y.serialize_into(bytes);
-        self.max_y.serialize_into(bytes);
-        self.flip_x.serialize_into(bytes);
-        self.flip_y.serialize_into(bytes);
-        self.rotation.serialize_into(bytes);
-        self.button_threshold.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.min_x.serialize_into_with_order(bytes, order);
+        self.max_x.serialize_into_with_order(bytes, order);
+        self.min_y.serialize_into_with_order(bytes, order);
+        self.max_y.serialize_into_with_order(bytes, order);
+        self.flip_x.serialize_into_with_order(bytes, order);
+        self.flip_y.serialize_into_with_order(bytes, order);
+        self.rotation.serialize_into_with_order(bytes, order);
+        self.button_threshold.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -5413,16 +7330,16 @@ pub struct DeviceAbsAreaState {
     pub following: u32,
 }
 impl TryParse for DeviceAbsAreaState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (offset_x, remaining) = u32::try_parse(remaining)?;
-        let (offset_y, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u32::try_parse(remaining)?;
-        let (height, remaining) = u32::try_parse(remaining)?;
-        let (screen, remaining) = u32::try_parse(remaining)?;
-        let (following, remaining) = u32::try_parse(remaining)?;
-        let control_id = control_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (offset_x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset_y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (following, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceAbsAreaState { control_id, len, offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
     }
@@ -5435,15 +7352,15 @@ impl TryFrom<&[u8]> for DeviceAbsAreaState {
 }
 impl Serialize for DeviceAbsAreaState {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let control_id_bytes = u16::from(self.control_id).serialize();
-        let len_bytes = self.len.serialize();
-        let offset_x_bytes = self.offset_x.serialize();
-        let offset_y_bytes = self.offset_y.serialize();
-        let width_bytes = self.width.serialize();
-        let height_bytes = self.height.serialize();
-        let screen_bytes = self.screen.serialize();
-        let following_bytes = self.following.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let control_id_bytes = u16::from(self.control_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let offset_x_bytes = self.offset_x.serialize_with_order(order);
+        let offset_y_bytes = self.offset_y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let following_bytes = self.following.serialize_with_order(order);
         [
             control_id_bytes[0],
             control_id_bytes[1],
@@ -5475,16 +7392,16 @@ impl Serialize for DeviceAbsAreaState {
             following_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.offset_x.serialize_into(bytes);
-        self.offset_y.serialize_into(bytes);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
-        self.screen.serialize_into(bytes);
-        self.following.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.offset_x.serialize_into_with_order(bytes, order);
+        self.offset_y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.screen.serialize_into_with_order(bytes, order);
+        self.following.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -5496,13 +7413,13 @@ pub struct DeviceCoreState {
     pub iscore: u8,
 }
 impl TryParse for DeviceCoreState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (iscore, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (iscore, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let control_id = control_id.try_into()?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceCoreState { control_id, len, status, iscore };
         Ok((result, remaining))
     }
@@ -5515,11 +7432,11 @@ impl TryFrom<&[u8]> for DeviceCoreState {
 }
 impl Serialize for DeviceCoreState {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let control_id_bytes = u16::from(self.control_id).serialize();
-        let len_bytes = self.len.serialize();
-        let status_bytes = self.status.serialize();
-        let iscore_bytes = self.iscore.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let control_id_bytes = u16::from(self.control_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let status_bytes = self.status.serialize_with_order(order);
+        let iscore_bytes = self.iscore.serialize_with_order(order);
         [
             control_id_bytes[0],
             control_id_bytes[1],
@@ -5531,12 +7448,12 @@ impl Serialize for DeviceCoreState {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.status.serialize_into(bytes);
-        self.iscore.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.status.serialize_into_with_order(bytes, order);
+        self.iscore.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
@@ -5548,12 +7465,12 @@ pub struct DeviceEnableState {
     pub enable: u8,
 }
 impl TryParse for DeviceEnableState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (enable, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (enable, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let control_id = control_id.try_into()?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceEnableState { control_id, len, enable };
         Ok((result, remaining))
     }
@@ -5566,10 +7483,10 @@ impl TryFrom<&[u8]> for DeviceEnableState {
 }
 impl Serialize for DeviceEnableState {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let control_id_bytes = u16::from(self.control_id).serialize();
-        let len_bytes = self.len.serialize();
-        let enable_bytes = self.enable.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let control_id_bytes = u16::from(self.control_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let enable_bytes = self.enable.serialize_with_order(order);
         [
             control_id_bytes[0],
             control_id_bytes[1],
@@ -5581,11 +7498,11 @@ impl Serialize for DeviceEnableState {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.enable.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.enable.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
@@ -5597,11 +7514,11 @@ pub struct DeviceStateDataResolution {
     pub resolution_max: Vec<u32>,
 }
 impl TryParse for DeviceStateDataResolution {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_valuators, remaining) = u32::try_parse(remaining)?;
-        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
-        let (resolution_min, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
-        let (resolution_max, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (num_valuators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (resolution_values, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (resolution_min, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (resolution_max, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = DeviceStateDataResolution { resolution_values, resolution_min, resolution_max };
         Ok((result, remaining))
     }
@@ -5614,19 +7531,19 @@ impl TryFrom<&[u8]> for DeviceStateDataResolution {
 }
 impl Serialize for DeviceStateDataResolution {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let num_valuators = u32::try_from(self.resolution_values.len()).expect("`resolution_values` has too many elements");
-        num_valuators.serialize_into(bytes);
-        self.resolution_values.serialize_into(bytes);
+        num_valuators.serialize_into_with_order(bytes, order);
+        self.resolution_values.serialize_into_with_order(bytes, order);
         assert_eq!(self.resolution_min.len(), usize::try_from(num_valuators).unwrap(), "`resolution_min` has an incorrect length");
-        self.resolution_min.serialize_into(bytes);
+        self.resolution_min.serialize_into_with_order(bytes, order);
         assert_eq!(self.resolution_max.len(), usize::try_from(num_valuators).unwrap(), "`resolution_max` has an incorrect length");
-        self.resolution_max.serialize_into(bytes);
+        self.resolution_max.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -5641,15 +7558,15 @@ pub struct DeviceStateDataAbsCalib {
     pub button_threshold: u32,
 }
 impl TryParse for DeviceStateDataAbsCalib {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (min_x, remaining) = i32::try_parse(remaining)?;
-        let (max_x, remaining) = i32::try_parse(remaining)?;
-        let (min_y, remaining) = i32::try_parse(remaining)?;
-        let (max_y, remaining) = i32::try_parse(remaining)?;
-        let (flip_x, remaining) = u32::try_parse(remaining)?;
-        let (flip_y, remaining) = u32::try_parse(remaining)?;
-        let (rotation, remaining) = u32::try_parse(remaining)?;
-        let (button_threshold, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (min_x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (min_y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (flip_x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flip_y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (button_threshold, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = DeviceStateDataAbsCalib { min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
     }
@@ -5662,15 +7579,15 @@ impl TryFrom<&[u8]> for DeviceStateDataAbsCalib {
 }
 impl Serialize for DeviceStateDataAbsCalib {
     type Bytes = [u8; 32];
-    fn serialize(&self) -> [u8; 32] {
-        let min_x_bytes = self.min_x.serialize();
-        let max_x_bytes = self.max_x.serialize();
-        let min_y_bytes = self.min_y.serialize();
-        let max_y_bytes = self.max_y.serialize();
-        let flip_x_bytes = self.flip_x.serialize();
-        let flip_y_bytes = self.flip_y.serialize();
-        let rotation_bytes = self.rotation.serialize();
-        let button_threshold_bytes = self.button_threshold.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let min_x_bytes = self.min_x.serialize_with_order(order);
+        let max_x_bytes = self.max_x.serialize_with_order(order);
+        let min_y_bytes = self.min_y.serialize_with_order(order);
+        let max_y_bytes = self.max_y.serialize_with_order(order);
+        let flip_x_bytes = self.flip_x.serialize_with_order(order);
+        let flip_y_bytes = self.flip_y.serialize_with_order(order);
+        let rotation_bytes = self.rotation.serialize_with_order(order);
+        let button_threshold_bytes = self.button_threshold.serialize_with_order(order);
         [
             min_x_bytes[0],
             min_x_bytes[1],
@@ -5706,16 +7623,16 @@ impl Serialize for DeviceStateDataAbsCalib {
             button_threshold_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(32);
-        self.min_x.serialize_into(bytes);
-        self.max_x.serialize_into(bytes);
-        self.min_y.serialize_into(bytes);
-        self.max_y.serialize_into(bytes);
-        self.flip_x.serialize_into(bytes);
-        self.flip_y.serialize_into(bytes);
-        self.rotation.serialize_into(bytes);
-        self.button_threshold.serialize_into(bytes);
+        self.min_x.serialize_into_with_order(bytes, order);
+        self.max_x.serialize_into_with_order(bytes, order);
+        self.min_y.serialize_into_with_order(bytes, order);
+        self.max_y.serialize_into_with_order(bytes, order);
+        self.flip_x.serialize_into_with_order(bytes, order);
+        self.flip_y.serialize_into_with_order(bytes, order);
+        self.rotation.serialize_into_with_order(bytes, order);
+        self.button_threshold.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -5724,9 +7641,9 @@ pub struct DeviceStateDataCore {
     pub iscore: u8,
 }
 impl TryParse for DeviceStateDataCore {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (iscore, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (iscore, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
         let result = DeviceStateDataCore { status, iscore };
         Ok((result, remaining))
@@ -5740,9 +7657,9 @@ impl TryFrom<&[u8]> for DeviceStateDataCore {
 }
 impl Serialize for DeviceStateDataCore {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let status_bytes = self.status.serialize();
-        let iscore_bytes = self.iscore.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let status_bytes = self.status.serialize_with_order(order);
+        let iscore_bytes = self.iscore.serialize_with_order(order);
         [
             status_bytes[0],
             iscore_bytes[0],
@@ -5750,10 +7667,10 @@ impl Serialize for DeviceStateDataCore {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.status.serialize_into(bytes);
-        self.iscore.serialize_into(bytes);
+        self.status.serialize_into_with_order(bytes, order);
+        self.iscore.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
@@ -5767,13 +7684,13 @@ pub struct DeviceStateDataAbsArea {
     pub following: u32,
 }
 impl TryParse for DeviceStateDataAbsArea {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (offset_x, remaining) = u32::try_parse(remaining)?;
-        let (offset_y, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u32::try_parse(remaining)?;
-        let (height, remaining) = u32::try_parse(remaining)?;
-        let (screen, remaining) = u32::try_parse(remaining)?;
-        let (following, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (offset_x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset_y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (following, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = DeviceStateDataAbsArea { offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
     }
@@ -5786,13 +7703,13 @@ impl TryFrom<&[u8]> for DeviceStateDataAbsArea {
 }
 impl Serialize for DeviceStateDataAbsArea {
     type Bytes = [u8; 24];
-    fn serialize(&self) -> [u8; 24] {
-        let offset_x_bytes = self.offset_x.serialize();
-        let offset_y_bytes = self.offset_y.serialize();
-        let width_bytes = self.width.serialize();
-        let height_bytes = self.height.serialize();
-        let screen_bytes = self.screen.serialize();
-        let following_bytes = self.following.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 24] {
+        let offset_x_bytes = self.offset_x.serialize_with_order(order);
+        let offset_y_bytes = self.offset_y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let following_bytes = self.following.serialize_with_order(order);
         [
             offset_x_bytes[0],
             offset_x_bytes[1],
@@ -5820,14 +7737,14 @@ impl Serialize for DeviceStateDataAbsArea {
             following_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(24);
-        self.offset_x.serialize_into(bytes);
-        self.offset_y.serialize_into(bytes);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
-        self.screen.serialize_into(bytes);
-        self.following.serialize_into(bytes);
+        self.offset_x.serialize_into_with_order(bytes, order);
+        self.offset_y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.screen.serialize_into_with_order(bytes, order);
+        self.following.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -5839,38 +7756,38 @@ pub enum DeviceStateData {
     AbsArea(DeviceStateDataAbsArea),
 }
 impl DeviceStateData {
-    fn try_parse(value: &[u8], control_id: u16) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, control_id: DeviceControl) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(control_id);
         let mut outer_remaining = value;
         let mut parse_result = None;
         if switch_expr == u32::from(DeviceControl::Resolution) {
-            let (resolution, new_remaining) = DeviceStateDataResolution::try_parse(outer_remaining)?;
+            let (resolution, new_remaining) = DeviceStateDataResolution::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceStateData::Resolution(resolution));
         }
         if switch_expr == u32::from(DeviceControl::Abscalib) {
-            let (abs_calib, new_remaining) = DeviceStateDataAbsCalib::try_parse(outer_remaining)?;
+            let (abs_calib, new_remaining) = DeviceStateDataAbsCalib::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceStateData::AbsCalib(abs_calib));
         }
         if switch_expr == u32::from(DeviceControl::Core) {
-            let (core, new_remaining) = DeviceStateDataCore::try_parse(outer_remaining)?;
+            let (core, new_remaining) = DeviceStateDataCore::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceStateData::Core(core));
         }
         if switch_expr == u32::from(DeviceControl::Enable) {
             let remaining = outer_remaining;
-            let (enable, remaining) = u8::try_parse(remaining)?;
+            let (enable, remaining) = u8::try_parse_with_order(remaining, order)?;
             let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceStateData::Enable(enable));
         }
         if switch_expr == u32::from(DeviceControl::Absarea) {
-            let (abs_area, new_remaining) = DeviceStateDataAbsArea::try_parse(outer_remaining)?;
+            let (abs_area, new_remaining) = DeviceStateDataAbsArea::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceStateData::AbsArea(abs_area));
@@ -5915,22 +7832,22 @@ impl DeviceStateData {
 }
 #[allow(dead_code, unused_variables)]
 impl DeviceStateData {
-    fn serialize(&self, control_id: u16) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, control_id: DeviceControl) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, control_id);
+        self.serialize_into_with_order(&mut result, order, control_id);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, control_id: u16) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, control_id: DeviceControl) {
         match self {
-            DeviceStateData::Resolution(resolution) => resolution.serialize_into(bytes),
-            DeviceStateData::AbsCalib(abs_calib) => abs_calib.serialize_into(bytes),
-            DeviceStateData::Core(core) => core.serialize_into(bytes),
+            DeviceStateData::Resolution(resolution) => resolution.serialize_into_with_order(bytes, order),
+            DeviceStateData::AbsCalib(abs_calib) => abs_calib.serialize_into_with_order(bytes, order),
+            DeviceStateData::Core(core) => core.serialize_into_with_order(bytes, order),
             DeviceStateData::Enable(enable) => {
                 bytes.reserve(4);
-                enable.serialize_into(bytes);
+                enable.serialize_into_with_order(bytes, order);
                 bytes.extend_from_slice(&[0; 3]);
             }
-            DeviceStateData::AbsArea(abs_area) => abs_area.serialize_into(bytes),
+            DeviceStateData::AbsArea(abs_area) => abs_area.serialize_into_with_order(bytes, order),
         }
     }
 }
@@ -5952,10 +7869,10 @@ pub struct DeviceState {
     pub data: DeviceStateData,
 }
 impl TryParse for DeviceState {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (data, remaining) = DeviceStateData::try_parse(remaining, control_id)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = DeviceStateData::try_parse_with_order(remaining, order, DeviceControl::from(control_id))?;
         let result = DeviceState { len, data };
         Ok((result, remaining))
     }
@@ -5968,31 +7885,69 @@ impl TryFrom<&[u8]> for DeviceState {
 }
 impl Serialize for DeviceState {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
         let control_id = u16::try_from(self.data.switch_expr()).unwrap();
-        control_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.data.serialize_into(bytes, control_id);
+        control_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.data.serialize_into_with_order(bytes, order, DeviceControl::from(control_id));
     }
 }
 
 /// Opcode for the GetDeviceControl request
 pub const GET_DEVICE_CONTROL_REQUEST: u8 = 34;
-pub fn get_device_control<Conn>(conn: &Conn, control_id: DeviceControl, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceControlReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetDeviceControlRequest {
+    pub control_id: DeviceControl,
+    pub device_id: u8,
+}
+impl GetDeviceControlRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_CONTROL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let control_id = DeviceControl::from(control_id);
+        Ok(GetDeviceControlRequest {
+            control_id,
+            device_id,
+        })
+    }
+    /// Set the `control_id` field of this structure.
+    pub fn control_id<I>(mut self, value: I) -> Self where I: Into<DeviceControl> {
+        self.control_id = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
+pub fn get_device_control<Conn, A>(conn: &Conn, control_id: A, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceControlReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<DeviceControl>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let control_id: DeviceControl = control_id.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let control_id_bytes = u16::from(control_id).serialize();
-    let device_id_bytes = device_id.serialize();
+    let control_id_bytes = u16::from(control_id).serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_CONTROL_REQUEST,
@@ -6006,9 +7961,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDeviceControlRequest {
+    type Reply = GetDeviceControlReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDeviceControlRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDeviceControlReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_control(conn, self.control_id, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceControlReply {
@@ -6020,14 +7987,14 @@ pub struct GetDeviceControlReply {
     pub control: DeviceState,
 }
 impl TryParse for GetDeviceControlReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let (control, remaining) = DeviceState::try_parse(remaining)?;
+        let (control, remaining) = DeviceState::try_parse_with_order(remaining, order)?;
         let result = GetDeviceControlReply { response_type, xi_reply_type, sequence, length, status, control };
         Ok((result, remaining))
     }
@@ -6038,6 +8005,31 @@ impl TryFrom<&[u8]> for GetDeviceControlReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDeviceControlReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.status.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        self.control.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeviceResolutionCtl {
@@ -6047,14 +8039,14 @@ pub struct DeviceResolutionCtl {
     pub resolution_values: Vec<u32>,
 }
 impl TryParse for DeviceResolutionCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (first_valuator, remaining) = u8::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_valuator, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_valuators, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
-        let control_id = control_id.try_into()?;
+        let (resolution_values, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceResolutionCtl { control_id, len, first_valuator, resolution_values };
         Ok((result, remaining))
     }
@@ -6067,20 +8059,20 @@ impl TryFrom<&[u8]> for DeviceResolutionCtl {
 }
 impl Serialize for DeviceResolutionCtl {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.first_valuator.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.first_valuator.serialize_into_with_order(bytes, order);
         let num_valuators = u8::try_from(self.resolution_values.len()).expect("`resolution_values` has too many elements");
-        num_valuators.serialize_into(bytes);
+        num_valuators.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.resolution_values.serialize_into(bytes);
+        self.resolution_values.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -6098,18 +8090,18 @@ pub struct DeviceAbsCalibCtl {
     pub button_threshold: u32,
 }
 impl TryParse for DeviceAbsCalibCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (min_x, remaining) = i32::try_parse(remaining)?;
-        let (max_x, remaining) = i32::try_parse(remaining)?;
-        let (min_y, remaining) = i32::try_parse(remaining)?;
-        let (max_y, remaining) = i32::try_parse(remaining)?;
-        let (flip_x, remaining) = u32::try_parse(remaining)?;
-        let (flip_y, remaining) = u32::try_parse(remaining)?;
-        let (rotation, remaining) = u32::try_parse(remaining)?;
-        let (button_threshold, remaining) = u32::try_parse(remaining)?;
-        let control_id = control_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (min_x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (min_y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (flip_x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flip_y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (button_threshold, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceAbsCalibCtl { control_id, len, min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
     }
@@ -6122,17 +8114,17 @@ impl TryFrom<&[u8]> for DeviceAbsCalibCtl {
 }
 impl Serialize for DeviceAbsCalibCtl {
     type Bytes = [u8; 36];
-    fn serialize(&self) -> [u8; 36] {
-        let control_id_bytes = u16::from(self.control_id).serialize();
-        let len_bytes = self.len.serialize();
-        let min_x_bytes = self.min_x.serialize();
-        let max_x_bytes = self.max_x.serialize();
-        let min_y_bytes = self.min_y.serialize();
-        let max_y_bytes = self.max_y.serialize();
-        let flip_x_bytes = self.flip_x.serialize();
-        let flip_y_bytes = self.flip_y.serialize();
-        let rotation_bytes = self.rotation.serialize();
-        let button_threshold_bytes = self.button_threshold.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 36] {
+        let control_id_bytes = u16::from(self.control_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let min_x_bytes = self.min_x.serialize_with_order(order);
+        let max_x_bytes = self.max_x.serialize_with_order(order);
+        let min_y_bytes = self.min_y.serialize_with_order(order);
+        let max_y_bytes = self.max_y.serialize_with_order(order);
+        let flip_x_bytes = self.flip_x.serialize_with_order(order);
+        let flip_y_bytes = self.flip_y.serialize_with_order(order);
+        let rotation_bytes = self.rotation.serialize_with_order(order);
+        let button_threshold_bytes = self.button_threshold.serialize_with_order(order);
         [
             control_id_bytes[0],
             control_id_bytes[1],
@@ -6172,18 +8164,18 @@ impl Serialize for DeviceAbsCalibCtl {
             button_threshold_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(36);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.min_x.serialize_into(bytes);
-        self.max_x.serialize_into(bytes);
-        self.min_y.serialize_into(bytes);
-        self.max_y.serialize_into(bytes);
-        self.flip_x.serialize_into(bytes);
-        self.flip_y.serialize_into(bytes);
-        self.rotation.serialize_into(bytes);
-        self.button_threshold.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.min_x.serialize_into_with_order(bytes, order);
+        self.max_x.serialize_into_with_order(bytes, order);
+        self.min_y.serialize_into_with_order(bytes, order);
+        self.max_y.serialize_into_with_order(bytes, order);
+        self.flip_x.serialize_into_with_order(bytes, order);
+        self.flip_y.serialize_into_with_order(bytes, order);
+        self.rotation.serialize_into_with_order(bytes, order);
+        self.button_threshold.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -6199,16 +8191,16 @@ pub struct DeviceAbsAreaCtrl {
     pub following: u32,
 }
 impl TryParse for DeviceAbsAreaCtrl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (offset_x, remaining) = u32::try_parse(remaining)?;
-        let (offset_y, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
-        let (screen, remaining) = i32::try_parse(remaining)?;
-        let (following, remaining) = u32::try_parse(remaining)?;
-        let control_id = control_id.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (offset_x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset_y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (following, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceAbsAreaCtrl { control_id, len, offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
     }
@@ -6221,15 +8213,15 @@ impl TryFrom<&[u8]> for DeviceAbsAreaCtrl {
 }
 impl Serialize for DeviceAbsAreaCtrl {
     type Bytes = [u8; 28];
-    fn serialize(&self) -> [u8; 28] {
-        let control_id_bytes = u16::from(self.control_id).serialize();
-        let len_bytes = self.len.serialize();
-        let offset_x_bytes = self.offset_x.serialize();
-        let offset_y_bytes = self.offset_y.serialize();
-        let width_bytes = self.width.serialize();
-        let height_bytes = self.height.serialize();
-        let screen_bytes = self.screen.serialize();
-        let following_bytes = self.following.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let control_id_bytes = u16::from(self.control_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let offset_x_bytes = self.offset_x.serialize_with_order(order);
+        let offset_y_bytes = self.offset_y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let following_bytes = self.following.serialize_with_order(order);
         [
             control_id_bytes[0],
             control_id_bytes[1],
@@ -6261,16 +8253,16 @@ impl Serialize for DeviceAbsAreaCtrl {
             following_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(28);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.offset_x.serialize_into(bytes);
-        self.offset_y.serialize_into(bytes);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
-        self.screen.serialize_into(bytes);
-        self.following.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.offset_x.serialize_into_with_order(bytes, order);
+        self.offset_y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.screen.serialize_into_with_order(bytes, order);
+        self.following.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -6281,12 +8273,12 @@ pub struct DeviceCoreCtrl {
     pub status: u8,
 }
 impl TryParse for DeviceCoreCtrl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let control_id = control_id.try_into()?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceCoreCtrl { control_id, len, status };
         Ok((result, remaining))
     }
@@ -6299,10 +8291,10 @@ impl TryFrom<&[u8]> for DeviceCoreCtrl {
 }
 impl Serialize for DeviceCoreCtrl {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let control_id_bytes = u16::from(self.control_id).serialize();
-        let len_bytes = self.len.serialize();
-        let status_bytes = self.status.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let control_id_bytes = u16::from(self.control_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let status_bytes = self.status.serialize_with_order(order);
         [
             control_id_bytes[0],
             control_id_bytes[1],
@@ -6314,11 +8306,11 @@ impl Serialize for DeviceCoreCtrl {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.status.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.status.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
@@ -6330,12 +8322,12 @@ pub struct DeviceEnableCtrl {
     pub enable: u8,
 }
 impl TryParse for DeviceEnableCtrl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (enable, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (enable, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let control_id = control_id.try_into()?;
+        let control_id = DeviceControl::from(control_id);
         let result = DeviceEnableCtrl { control_id, len, enable };
         Ok((result, remaining))
     }
@@ -6348,10 +8340,10 @@ impl TryFrom<&[u8]> for DeviceEnableCtrl {
 }
 impl Serialize for DeviceEnableCtrl {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let control_id_bytes = u16::from(self.control_id).serialize();
-        let len_bytes = self.len.serialize();
-        let enable_bytes = self.enable.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let control_id_bytes = u16::from(self.control_id).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let enable_bytes = self.enable.serialize_with_order(order);
         [
             control_id_bytes[0],
             control_id_bytes[1],
@@ -6363,11 +8355,11 @@ impl Serialize for DeviceEnableCtrl {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.control_id).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.enable.serialize_into(bytes);
+        u16::from(self.control_id).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.enable.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
@@ -6378,11 +8370,11 @@ pub struct DeviceCtlDataResolution {
     pub resolution_values: Vec<u32>,
 }
 impl TryParse for DeviceCtlDataResolution {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (first_valuator, remaining) = u8::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (first_valuator, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_valuators, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?)?;
+        let (resolution_values, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_valuators.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = DeviceCtlDataResolution { first_valuator, resolution_values };
         Ok((result, remaining))
     }
@@ -6395,18 +8387,18 @@ impl TryFrom<&[u8]> for DeviceCtlDataResolution {
 }
 impl Serialize for DeviceCtlDataResolution {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.first_valuator.serialize_into(bytes);
+        self.first_valuator.serialize_into_with_order(bytes, order);
         let num_valuators = u8::try_from(self.resolution_values.len()).expect("`resolution_values` has too many elements");
-        num_valuators.serialize_into(bytes);
+        num_valuators.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.resolution_values.serialize_into(bytes);
+        self.resolution_values.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -6421,15 +8413,15 @@ pub struct DeviceCtlDataAbsCalib {
     pub button_threshold: u32,
 }
 impl TryParse for DeviceCtlDataAbsCalib {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (min_x, remaining) = i32::try_parse(remaining)?;
-        let (max_x, remaining) = i32::try_parse(remaining)?;
-        let (min_y, remaining) = i32::try_parse(remaining)?;
-        let (max_y, remaining) = i32::try_parse(remaining)?;
-        let (flip_x, remaining) = u32::try_parse(remaining)?;
-        let (flip_y, remaining) = u32::try_parse(remaining)?;
-        let (rotation, remaining) = u32::try_parse(remaining)?;
-        let (button_threshold, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (min_x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (min_y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max_y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (flip_x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flip_y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (button_threshold, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = DeviceCtlDataAbsCalib { min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
     }
@@ -6442,15 +8434,15 @@ impl TryFrom<&[u8]> for DeviceCtlDataAbsCalib {
 }
 impl Serialize for DeviceCtlDataAbsCalib {
     type Bytes = [u8; 32];
-    fn serialize(&self) -> [u8; 32] {
-        let min_x_bytes = self.min_x.serialize();
-        let max_x_bytes = self.max_x.serialize();
-        let min_y_bytes = self.min_y.serialize();
-        let max_y_bytes = self.max_y.serialize();
-        let flip_x_bytes = self.flip_x.serialize();
-        let flip_y_bytes = self.flip_y.serialize();
-        let rotation_bytes = self.rotation.serialize();
-        let button_threshold_bytes = self.button_threshold.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let min_x_bytes = self.min_x.serialize_with_order(order);
+        let max_x_bytes = self.max_x.serialize_with_order(order);
+        let min_y_bytes = self.min_y.serialize_with_order(order);
+        let max_y_bytes = self.max_y.serialize_with_order(order);
+        let flip_x_bytes = self.flip_x.serialize_with_order(order);
+        let flip_y_bytes = self.flip_y.serialize_with_order(order);
+        let rotation_bytes = self.rotation.serialize_with_order(order);
+        let button_threshold_bytes = self.button_threshold.serialize_with_order(order);
         [
             min_x_bytes[0],
             min_x_bytes[1],
@@ -6486,16 +8478,16 @@ impl Serialize for DeviceCtlDataAbsCalib {
             button_threshold_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(32);
-        self.min_x.serialize_into(bytes);
-        self.max_x.serialize_into(bytes);
-        self.min_y.serialize_into(bytes);
-        self.max_y.serialize_into(bytes);
-        self.flip_x.serialize_into(bytes);
-        self.flip_y.serialize_into(bytes);
-        self.rotation.serialize_into(bytes);
-        self.button_threshold.serialize_into(bytes);
+        self.min_x.serialize_into_with_order(bytes, order);
+        self.max_x.serialize_into_with_order(bytes, order);
+        self.min_y.serialize_into_with_order(bytes, order);
+        self.max_y.serialize_into_with_order(bytes, order);
+        self.flip_x.serialize_into_with_order(bytes, order);
+        self.flip_y.serialize_into_with_order(bytes, order);
+        self.rotation.serialize_into_with_order(bytes, order);
+        self.button_threshold.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -6503,8 +8495,8 @@ pub struct DeviceCtlDataCore {
     pub status: u8,
 }
 impl TryParse for DeviceCtlDataCore {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
         let result = DeviceCtlDataCore { status };
         Ok((result, remaining))
@@ -6518,8 +8510,8 @@ impl TryFrom<&[u8]> for DeviceCtlDataCore {
 }
 impl Serialize for DeviceCtlDataCore {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let status_bytes = self.status.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let status_bytes = self.status.serialize_with_order(order);
         [
             status_bytes[0],
             0,
@@ -6527,9 +8519,9 @@ impl Serialize for DeviceCtlDataCore {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.status.serialize_into(bytes);
+        self.status.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
@@ -6543,13 +8535,13 @@ pub struct DeviceCtlDataAbsArea {
     pub following: u32,
 }
 impl TryParse for DeviceCtlDataAbsArea {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (offset_x, remaining) = u32::try_parse(remaining)?;
-        let (offset_y, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
-        let (screen, remaining) = i32::try_parse(remaining)?;
-        let (following, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (offset_x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset_y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (following, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = DeviceCtlDataAbsArea { offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
     }
@@ -6562,13 +8554,13 @@ impl TryFrom<&[u8]> for DeviceCtlDataAbsArea {
 }
 impl Serialize for DeviceCtlDataAbsArea {
     type Bytes = [u8; 24];
-    fn serialize(&self) -> [u8; 24] {
-        let offset_x_bytes = self.offset_x.serialize();
-        let offset_y_bytes = self.offset_y.serialize();
-        let width_bytes = self.width.serialize();
-        let height_bytes = self.height.serialize();
-        let screen_bytes = self.screen.serialize();
-        let following_bytes = self.following.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 24] {
+        let offset_x_bytes = self.offset_x.serialize_with_order(order);
+        let offset_y_bytes = self.offset_y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let following_bytes = self.following.serialize_with_order(order);
         [
             offset_x_bytes[0],
             offset_x_bytes[1],
@@ -6596,14 +8588,14 @@ impl Serialize for DeviceCtlDataAbsArea {
             following_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(24);
-        self.offset_x.serialize_into(bytes);
-        self.offset_y.serialize_into(bytes);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
-        self.screen.serialize_into(bytes);
-        self.following.serialize_into(bytes);
+        self.offset_x.serialize_into_with_order(bytes, order);
+        self.offset_y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.screen.serialize_into_with_order(bytes, order);
+        self.following.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -6615,38 +8607,38 @@ pub enum DeviceCtlData {
     AbsArea(DeviceCtlDataAbsArea),
 }
 impl DeviceCtlData {
-    fn try_parse(value: &[u8], control_id: u16) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, control_id: DeviceControl) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(control_id);
         let mut outer_remaining = value;
         let mut parse_result = None;
         if switch_expr == u32::from(DeviceControl::Resolution) {
-            let (resolution, new_remaining) = DeviceCtlDataResolution::try_parse(outer_remaining)?;
+            let (resolution, new_remaining) = DeviceCtlDataResolution::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceCtlData::Resolution(resolution));
         }
         if switch_expr == u32::from(DeviceControl::Abscalib) {
-            let (abs_calib, new_remaining) = DeviceCtlDataAbsCalib::try_parse(outer_remaining)?;
+            let (abs_calib, new_remaining) = DeviceCtlDataAbsCalib::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceCtlData::AbsCalib(abs_calib));
         }
         if switch_expr == u32::from(DeviceControl::Core) {
-            let (core, new_remaining) = DeviceCtlDataCore::try_parse(outer_remaining)?;
+            let (core, new_remaining) = DeviceCtlDataCore::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceCtlData::Core(core));
         }
         if switch_expr == u32::from(DeviceControl::Enable) {
             let remaining = outer_remaining;
-            let (enable, remaining) = u8::try_parse(remaining)?;
+            let (enable, remaining) = u8::try_parse_with_order(remaining, order)?;
             let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceCtlData::Enable(enable));
         }
         if switch_expr == u32::from(DeviceControl::Absarea) {
-            let (abs_area, new_remaining) = DeviceCtlDataAbsArea::try_parse(outer_remaining)?;
+            let (abs_area, new_remaining) = DeviceCtlDataAbsArea::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceCtlData::AbsArea(abs_area));
@@ -6691,22 +8683,22 @@ impl DeviceCtlData {
 }
 #[allow(dead_code, unused_variables)]
 impl DeviceCtlData {
-    fn serialize(&self, control_id: u16) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, control_id: DeviceControl) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, control_id);
+        self.serialize_into_with_order(&mut result, order, control_id);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, control_id: u16) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, control_id: DeviceControl) {
         match self {
-            DeviceCtlData::Resolution(resolution) => resolution.serialize_into(bytes),
-            DeviceCtlData::AbsCalib(abs_calib) => abs_calib.serialize_into(bytes),
-            DeviceCtlData::Core(core) => core.serialize_into(bytes),
+            DeviceCtlData::Resolution(resolution) => resolution.serialize_into_with_order(bytes, order),
+            DeviceCtlData::AbsCalib(abs_calib) => abs_calib.serialize_into_with_order(bytes, order),
+            DeviceCtlData::Core(core) => core.serialize_into_with_order(bytes, order),
             DeviceCtlData::Enable(enable) => {
                 bytes.reserve(4);
-                enable.serialize_into(bytes);
+                enable.serialize_into_with_order(bytes, order);
                 bytes.extend_from_slice(&[0; 3]);
             }
-            DeviceCtlData::AbsArea(abs_area) => abs_area.serialize_into(bytes),
+            DeviceCtlData::AbsArea(abs_area) => abs_area.serialize_into_with_order(bytes, order),
         }
     }
 }
@@ -6728,10 +8720,10 @@ pub struct DeviceCtl {
     pub data: DeviceCtlData,
 }
 impl TryParse for DeviceCtl {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (data, remaining) = DeviceCtlData::try_parse(remaining, control_id)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = DeviceCtlData::try_parse_with_order(remaining, order, DeviceControl::from(control_id))?;
         let result = DeviceCtl { len, data };
         Ok((result, remaining))
     }
@@ -6744,31 +8736,77 @@ impl TryFrom<&[u8]> for DeviceCtl {
 }
 impl Serialize for DeviceCtl {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
         let control_id = u16::try_from(self.data.switch_expr()).unwrap();
-        control_id.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.data.serialize_into(bytes, control_id);
+        control_id.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.data.serialize_into_with_order(bytes, order, DeviceControl::from(control_id));
     }
 }
 
 /// Opcode for the ChangeDeviceControl request
 pub const CHANGE_DEVICE_CONTROL_REQUEST: u8 = 35;
-pub fn change_device_control<Conn>(conn: &Conn, control_id: DeviceControl, device_id: u8, control: DeviceCtl) -> Result<Cookie<'_, Conn, ChangeDeviceControlReply>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeDeviceControlRequest {
+    pub control_id: DeviceControl,
+    pub device_id: u8,
+    pub control: DeviceCtl,
+}
+impl ChangeDeviceControlRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_DEVICE_CONTROL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (control_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (control, remaining) = DeviceCtl::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let control_id = DeviceControl::from(control_id);
+        Ok(ChangeDeviceControlRequest {
+            control_id,
+            device_id,
+            control,
+        })
+    }
+    /// Set the `control_id` field of this structure.
+    pub fn control_id<I>(mut self, value: I) -> Self where I: Into<DeviceControl> {
+        self.control_id = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `control` field of this structure.
+    pub fn control<I>(mut self, value: I) -> Self where I: Into<DeviceCtl> {
+        self.control = value.into();
+        self
+    }
+}
+
+pub fn change_device_control<Conn, A>(conn: &Conn, control_id: A, device_id: u8, control: DeviceCtl) -> Result<Cookie<'_, Conn, ChangeDeviceControlReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<DeviceControl>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let control_id: DeviceControl = control_id.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let control_id_bytes = u16::from(control_id).serialize();
-    let device_id_bytes = device_id.serialize();
+    let control_id_bytes = u16::from(control_id).serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_DEVICE_CONTROL_REQUEST,
@@ -6780,15 +8818,27 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let control_bytes = control.serialize();
+    let control_bytes = control.serialize_with_order(order);
     let length_so_far = length_so_far + control_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&control_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeDeviceControlRequest {
+    type Reply = ChangeDeviceControlReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeDeviceControlRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ChangeDeviceControlReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_device_control(conn, self.control_id, self.device_id, self.control)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangeDeviceControlReply {
@@ -6799,12 +8849,12 @@ pub struct ChangeDeviceControlReply {
     pub status: u8,
 }
 impl TryParse for ChangeDeviceControlReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
         let result = ChangeDeviceControlReply { response_type, xi_reply_type, sequence, length, status };
         Ok((result, remaining))
@@ -6816,17 +8866,68 @@ impl TryFrom<&[u8]> for ChangeDeviceControlReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ChangeDeviceControlReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.status.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ListDeviceProperties request
 pub const LIST_DEVICE_PROPERTIES_REQUEST: u8 = 36;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListDevicePropertiesRequest {
+    pub device_id: u8,
+}
+impl ListDevicePropertiesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_DEVICE_PROPERTIES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ListDevicePropertiesRequest {
+            device_id,
+        })
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn list_device_properties<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, ListDevicePropertiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_id_bytes = device_id.serialize();
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         LIST_DEVICE_PROPERTIES_REQUEST,
@@ -6840,9 +8941,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ListDevicePropertiesRequest {
+    type Reply = ListDevicePropertiesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ListDevicePropertiesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ListDevicePropertiesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        list_device_properties(conn, self.device_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListDevicePropertiesReply {
@@ -6853,14 +8966,14 @@ pub struct ListDevicePropertiesReply {
     pub atoms: Vec<xproto::Atom>,
 }
 impl TryParse for ListDevicePropertiesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_atoms, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_atoms, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (atoms, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_atoms.try_into().or(Err(ParseError::ParseError))?)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, num_atoms.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ListDevicePropertiesReply { response_type, xi_reply_type, sequence, length, atoms };
         Ok((result, remaining))
     }
@@ -6871,69 +8984,65 @@ impl TryFrom<&[u8]> for ListDevicePropertiesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PropertyFormat {
-    M8Bits = 8,
-    M16Bits = 16,
-    M32Bits = 32,
-}
-impl From<PropertyFormat> for u8 {
-    fn from(input: PropertyFormat) -> Self {
-        match input {
-            PropertyFormat::M8Bits => 8,
-            PropertyFormat::M16Bits => 16,
-            PropertyFormat::M32Bits => 32,
-        }
+impl Serialize for ListDevicePropertiesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_atoms = u16::try_from(self.atoms.len()).expect("`atoms` has too many elements");
+        num_atoms.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.atoms.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PropertyFormat(u8);
+#[allow(non_upper_case_globals)]
+impl PropertyFormat {
+    pub const M8Bits: Self = Self(8);
+    pub const M16Bits: Self = Self(16);
+    pub const M32Bits: Self = Self(32);
+}
+enum_newtype!(PropertyFormat, u8, [M8Bits, M16Bits, M32Bits]);
 impl From<PropertyFormat> for Option<u8> {
     fn from(input: PropertyFormat) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<PropertyFormat> for u16 {
     fn from(input: PropertyFormat) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PropertyFormat> for Option<u16> {
     fn from(input: PropertyFormat) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<PropertyFormat> for u32 {
     fn from(input: PropertyFormat) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PropertyFormat> for Option<u32> {
     fn from(input: PropertyFormat) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for PropertyFormat {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            8 => Ok(PropertyFormat::M8Bits),
-            16 => Ok(PropertyFormat::M16Bits),
-            32 => Ok(PropertyFormat::M32Bits),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for PropertyFormat {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for PropertyFormat {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -6945,6 +9054,49 @@ pub enum ChangeDevicePropertyAux {
     Data16(Vec<u16>),
     Data32(Vec<u32>),
 }
+impl ChangeDevicePropertyAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, format: PropertyFormat, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(format);
+        let mut outer_remaining = value;
+        let mut parse_result = None;
+        if switch_expr == u32::from(PropertyFormat::M8Bits) {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_into().or(Err(ParseError::ParseError))?)?;
+            let data8 = data8.to_vec();
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+            outer_remaining = remaining;
+            assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
+            parse_result = Some(ChangeDevicePropertyAux::Data8(data8));
+        }
+        if switch_expr == u32::from(PropertyFormat::M16Bits) {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (data16, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?, order)?;
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+            outer_remaining = remaining;
+            assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
+            parse_result = Some(ChangeDevicePropertyAux::Data16(data16));
+        }
+        if switch_expr == u32::from(PropertyFormat::M32Bits) {
+            let remaining = outer_remaining;
+            let (data32, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
+            parse_result = Some(ChangeDevicePropertyAux::Data32(data32));
+        }
+        match parse_result {
+            None => Err(ParseError::ParseError),
+            Some(result) => Ok((result, outer_remaining)),
+        }
+    }
+}
 impl ChangeDevicePropertyAux {
     pub fn as_data8(&self) -> Option<&Vec<u8>> {
         match self {
@@ -6967,12 +9119,12 @@ impl ChangeDevicePropertyAux {
 }
 #[allow(dead_code, unused_variables)]
 impl ChangeDevicePropertyAux {
-    fn serialize(&self, format: u8, num_items: u32) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, format: PropertyFormat, num_items: u32) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, format, num_items);
+        self.serialize_into_with_order(&mut result, order, format, num_items);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, format: u8, num_items: u32) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, format: PropertyFormat, num_items: u32) {
         match self {
             ChangeDevicePropertyAux::Data8(data8) => {
                 assert_eq!(data8.len(), usize::try_from(num_items).unwrap(), "`data8` has an incorrect length");
@@ -6981,12 +9133,12 @@ impl ChangeDevicePropertyAux {
             }
             ChangeDevicePropertyAux::Data16(data16) => {
                 assert_eq!(data16.len(), usize::try_from(num_items).unwrap(), "`data16` has an incorrect length");
-                data16.serialize_into(bytes);
+                data16.serialize_into_with_order(bytes, order);
                 bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
             }
             ChangeDevicePropertyAux::Data32(data32) => {
                 assert_eq!(data32.len(), usize::try_from(num_items).unwrap(), "`data32` has an incorrect length");
-                data32.serialize_into(bytes);
+                data32.serialize_into_with_order(bytes, order);
             }
         }
     }
@@ -7001,20 +9153,91 @@ impl ChangeDevicePropertyAux {
     }
 }
 
-pub fn change_device_property<'c, Conn>(conn: &'c Conn, property: xproto::Atom, type_: xproto::Atom, device_id: u8, mode: xproto::PropMode, num_items: u32, items: &ChangeDevicePropertyAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeDevicePropertyRequest {
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub device_id: u8,
+    pub mode: xproto::PropMode,
+    pub num_items: u32,
+    pub items: ChangeDevicePropertyAux,
+}
+impl ChangeDevicePropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_DEVICE_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (items, remaining) = ChangeDevicePropertyAux::try_parse_with_order(remaining, order, PropertyFormat::from(format), num_items)?;
+        let _ = remaining;
+        let _ = order;
+        let mode = xproto::PropMode::from(mode);
+        Ok(ChangeDevicePropertyRequest {
+            property,
+            type_,
+            device_id,
+            mode,
+            num_items,
+            items,
+        })
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.type_ = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<xproto::PropMode> {
+        self.mode = value.into();
+        self
+    }
+    /// Set the `num_items` field of this structure.
+    pub fn num_items(mut self, value: u32) -> Self {
+        self.num_items = value;
+        self
+    }
+    /// Set the `items` field of this structure.
+    pub fn items<I>(mut self, value: I) -> Self where I: Into<ChangeDevicePropertyAux> {
+        self.items = value.into();
+        self
+    }
+}
+
+pub fn change_device_property<'c, Conn, A>(conn: &'c Conn, property: xproto::Atom, type_: xproto::Atom, device_id: u8, mode: A, num_items: u32, items: &ChangeDevicePropertyAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::PropMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let mode: xproto::PropMode = mode.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let property_bytes = property.serialize();
-    let type_bytes = type_.serialize();
-    let device_id_bytes = device_id.serialize();
+    let property_bytes = property.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let format = u8::try_from(items.switch_expr()).unwrap();
-    let format_bytes = format.serialize();
-    let mode_bytes = u8::from(mode).serialize();
-    let num_items_bytes = num_items.serialize();
+    let format_bytes = format.serialize_with_order(order);
+    let mode_bytes = u8::from(mode).serialize_with_order(order);
+    let num_items_bytes = num_items.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CHANGE_DEVICE_PROPERTY_REQUEST,
@@ -7038,27 +9261,74 @@ where
         num_items_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let items_bytes = items.serialize(format, num_items);
+    let items_bytes = items.serialize_with_order(order, PropertyFormat::from(format), num_items);
     let length_so_far = length_so_far + items_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&items_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for ChangeDevicePropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ChangeDevicePropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        change_device_property(conn, self.property, self.type_, self.device_id, self.mode, self.num_items, &self.items)
+    }
+}
 
 /// Opcode for the DeleteDeviceProperty request
 pub const DELETE_DEVICE_PROPERTY_REQUEST: u8 = 38;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeleteDevicePropertyRequest {
+    pub property: xproto::Atom,
+    pub device_id: u8,
+}
+impl DeleteDevicePropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_DEVICE_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DeleteDevicePropertyRequest {
+            property,
+            device_id,
+        })
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+}
+
 pub fn delete_device_property<Conn>(conn: &Conn, property: xproto::Atom, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let property_bytes = property.serialize();
-    let device_id_bytes = device_id.serialize();
+    let property_bytes = property.serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DELETE_DEVICE_PROPERTY_REQUEST,
@@ -7076,25 +9346,104 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DeleteDevicePropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DeleteDevicePropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        delete_device_property(conn, self.property, self.device_id)
+    }
+}
 
 /// Opcode for the GetDeviceProperty request
 pub const GET_DEVICE_PROPERTY_REQUEST: u8 = 39;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDevicePropertyRequest {
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub offset: u32,
+    pub len: u32,
+    pub device_id: u8,
+    pub delete: bool,
+}
+impl GetDevicePropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (delete, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetDevicePropertyRequest {
+            property,
+            type_,
+            offset,
+            len,
+            device_id,
+            delete,
+        })
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.type_ = value.into();
+        self
+    }
+    /// Set the `offset` field of this structure.
+    pub fn offset(mut self, value: u32) -> Self {
+        self.offset = value;
+        self
+    }
+    /// Set the `len` field of this structure.
+    pub fn len(mut self, value: u32) -> Self {
+        self.len = value;
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `delete` field of this structure.
+    pub fn delete(mut self, value: bool) -> Self {
+        self.delete = value;
+        self
+    }
+}
+
 pub fn get_device_property<Conn>(conn: &Conn, property: xproto::Atom, type_: xproto::Atom, offset: u32, len: u32, device_id: u8, delete: bool) -> Result<Cookie<'_, Conn, GetDevicePropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let property_bytes = property.serialize();
-    let type_bytes = type_.serialize();
-    let offset_bytes = offset.serialize();
-    let len_bytes = len.serialize();
-    let device_id_bytes = device_id.serialize();
-    let delete_bytes = delete.serialize();
+    let property_bytes = property.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let offset_bytes = offset.serialize_with_order(order);
+    let len_bytes = len.serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
+    let delete_bytes = delete.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_PROPERTY_REQUEST,
@@ -7124,9 +9473,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDevicePropertyRequest {
+    type Reply = GetDevicePropertyReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDevicePropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDevicePropertyReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_property(conn, self.property, self.type_, self.offset, self.len, self.device_id, self.delete)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GetDevicePropertyItems {
@@ -7135,7 +9496,7 @@ pub enum GetDevicePropertyItems {
     Data32(Vec<u32>),
 }
 impl GetDevicePropertyItems {
-    fn try_parse(value: &[u8], format: u8, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, format: PropertyFormat, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(format);
         let mut outer_remaining = value;
         let mut parse_result = None;
@@ -7155,7 +9516,7 @@ impl GetDevicePropertyItems {
         if switch_expr == u32::from(PropertyFormat::M16Bits) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?)?;
+            let (data16, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?, order)?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
@@ -7166,7 +9527,7 @@ impl GetDevicePropertyItems {
         }
         if switch_expr == u32::from(PropertyFormat::M32Bits) {
             let remaining = outer_remaining;
-            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?)?;
+            let (data32, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(GetDevicePropertyItems::Data32(data32));
@@ -7197,6 +9558,41 @@ impl GetDevicePropertyItems {
         }
     }
 }
+#[allow(dead_code, unused_variables)]
+impl GetDevicePropertyItems {
+    fn serialize_with_order(&self, order: ByteOrder, format: PropertyFormat, num_items: u32) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, format, num_items);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, format: PropertyFormat, num_items: u32) {
+        match self {
+            GetDevicePropertyItems::Data8(data8) => {
+                assert_eq!(data8.len(), usize::try_from(num_items).unwrap(), "`data8` has an incorrect length");
+                bytes.extend_from_slice(&data8);
+                bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+            }
+            GetDevicePropertyItems::Data16(data16) => {
+                assert_eq!(data16.len(), usize::try_from(num_items).unwrap(), "`data16` has an incorrect length");
+                data16.serialize_into_with_order(bytes, order);
+                bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+            }
+            GetDevicePropertyItems::Data32(data32) => {
+                assert_eq!(data32.len(), usize::try_from(num_items).unwrap(), "`data32` has an incorrect length");
+                data32.serialize_into_with_order(bytes, order);
+            }
+        }
+    }
+}
+impl GetDevicePropertyItems {
+    fn switch_expr(&self) -> u32 {
+        match self {
+            GetDevicePropertyItems::Data8(_) => u32::from(PropertyFormat::M8Bits),
+            GetDevicePropertyItems::Data16(_) => u32::from(PropertyFormat::M16Bits),
+            GetDevicePropertyItems::Data32(_) => u32::from(PropertyFormat::M32Bits),
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDevicePropertyReply {
@@ -7211,18 +9607,18 @@ pub struct GetDevicePropertyReply {
     pub items: GetDevicePropertyItems,
 }
 impl TryParse for GetDevicePropertyReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (bytes_after, remaining) = u32::try_parse(remaining)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xi_reply_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (bytes_after, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(10..).ok_or(ParseError::ParseError)?;
-        let (items, remaining) = GetDevicePropertyItems::try_parse(remaining, format, num_items)?;
+        let (items, remaining) = GetDevicePropertyItems::try_parse_with_order(remaining, order, PropertyFormat::from(format), num_items)?;
         let result = GetDevicePropertyReply { response_type, xi_reply_type, sequence, length, type_, bytes_after, num_items, device_id, items };
         Ok((result, remaining))
     }
@@ -7233,74 +9629,74 @@ impl TryFrom<&[u8]> for GetDevicePropertyReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Device {
-    All = 0,
-    AllMaster = 1,
-}
-impl From<Device> for bool {
-    fn from(input: Device) -> Self {
-        match input {
-            Device::All => false,
-            Device::AllMaster => true,
-        }
+impl Serialize for GetDevicePropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
-impl From<Device> for u8 {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        let num_items = self.num_items;
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.xi_reply_type.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.bytes_after.serialize_into_with_order(bytes, order);
+        self.num_items.serialize_into_with_order(bytes, order);
+        let format = u8::try_from(self.items.switch_expr()).unwrap();
+        format.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 10]);
+        self.items.serialize_into_with_order(bytes, order, PropertyFormat::from(format), num_items);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Device(u8);
+#[allow(non_upper_case_globals)]
+impl Device {
+    pub const All: Self = Self(0);
+    pub const AllMaster: Self = Self(1);
+}
+enum_newtype!(Device, u8, [All, AllMaster]);
+impl From<Device> for bool {
     fn from(input: Device) -> Self {
-        match input {
-            Device::All => 0,
-            Device::AllMaster => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<Device> for Option<u8> {
     fn from(input: Device) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Device> for u16 {
     fn from(input: Device) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Device> for Option<u16> {
     fn from(input: Device) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Device> for u32 {
     fn from(input: Device) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Device> for Option<u32> {
     fn from(input: Device) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Device {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Device::All),
-            1 => Ok(Device::AllMaster),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Device {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Device {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -7312,11 +9708,11 @@ pub struct GroupInfo {
     pub effective: u8,
 }
 impl TryParse for GroupInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (base, remaining) = u8::try_parse(remaining)?;
-        let (latched, remaining) = u8::try_parse(remaining)?;
-        let (locked, remaining) = u8::try_parse(remaining)?;
-        let (effective, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (base, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (latched, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (locked, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (effective, remaining) = u8::try_parse_with_order(remaining, order)?;
         let result = GroupInfo { base, latched, locked, effective };
         Ok((result, remaining))
     }
@@ -7329,11 +9725,11 @@ impl TryFrom<&[u8]> for GroupInfo {
 }
 impl Serialize for GroupInfo {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let base_bytes = self.base.serialize();
-        let latched_bytes = self.latched.serialize();
-        let locked_bytes = self.locked.serialize();
-        let effective_bytes = self.effective.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let base_bytes = self.base.serialize_with_order(order);
+        let latched_bytes = self.latched.serialize_with_order(order);
+        let locked_bytes = self.locked.serialize_with_order(order);
+        let effective_bytes = self.effective.serialize_with_order(order);
         [
             base_bytes[0],
             latched_bytes[0],
@@ -7341,12 +9737,12 @@ impl Serialize for GroupInfo {
             effective_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.base.serialize_into(bytes);
-        self.latched.serialize_into(bytes);
-        self.locked.serialize_into(bytes);
-        self.effective.serialize_into(bytes);
+        self.base.serialize_into_with_order(bytes, order);
+        self.latched.serialize_into_with_order(bytes, order);
+        self.locked.serialize_into_with_order(bytes, order);
+        self.effective.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -7358,11 +9754,11 @@ pub struct ModifierInfo {
     pub effective: u32,
 }
 impl TryParse for ModifierInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (base, remaining) = u32::try_parse(remaining)?;
-        let (latched, remaining) = u32::try_parse(remaining)?;
-        let (locked, remaining) = u32::try_parse(remaining)?;
-        let (effective, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (base, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (latched, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (locked, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (effective, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = ModifierInfo { base, latched, locked, effective };
         Ok((result, remaining))
     }
@@ -7375,11 +9771,11 @@ impl TryFrom<&[u8]> for ModifierInfo {
 }
 impl Serialize for ModifierInfo {
     type Bytes = [u8; 16];
-    fn serialize(&self) -> [u8; 16] {
-        let base_bytes = self.base.serialize();
-        let latched_bytes = self.latched.serialize();
-        let locked_bytes = self.locked.serialize();
-        let effective_bytes = self.effective.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 16] {
+        let base_bytes = self.base.serialize_with_order(order);
+        let latched_bytes = self.latched.serialize_with_order(order);
+        let locked_bytes = self.locked.serialize_with_order(order);
+        let effective_bytes = self.effective.serialize_with_order(order);
         [
             base_bytes[0],
             base_bytes[1],
@@ -7399,17 +9795,51 @@ impl Serialize for ModifierInfo {
             effective_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(16);
-        self.base.serialize_into(bytes);
-        self.latched.serialize_into(bytes);
-        self.locked.serialize_into(bytes);
-        self.effective.serialize_into(bytes);
+        self.base.serialize_into_with_order(bytes, order);
+        self.latched.serialize_into_with_order(bytes, order);
+        self.locked.serialize_into_with_order(bytes, order);
+        self.effective.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the XIQueryPointer request
 pub const XI_QUERY_POINTER_REQUEST: u8 = 40;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIQueryPointerRequest {
+    pub window: xproto::Window,
+    pub deviceid: DeviceId,
+}
+impl XIQueryPointerRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_QUERY_POINTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIQueryPointerRequest {
+            window,
+            deviceid,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+}
+
 pub fn xi_query_pointer<Conn, A>(conn: &Conn, window: xproto::Window, deviceid: A) -> Result<Cookie<'_, Conn, XIQueryPointerReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7418,9 +9848,10 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let deviceid_bytes = deviceid.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_QUERY_POINTER_REQUEST,
@@ -7438,9 +9869,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIQueryPointerRequest {
+    type Reply = XIQueryPointerReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIQueryPointerRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIQueryPointerReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_query_pointer(conn, self.window, self.deviceid)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIQueryPointerReply {
@@ -7459,23 +9902,23 @@ pub struct XIQueryPointerReply {
     pub buttons: Vec<u32>,
 }
 impl TryParse for XIQueryPointerReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (win_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (win_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (child, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (root_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (root_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (win_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (win_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (same_screen, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (buttons, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let (buttons_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = ModifierInfo::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = GroupInfo::try_parse_with_order(remaining, order)?;
+        let (buttons, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = XIQueryPointerReply { response_type, sequence, length, root, child, root_x, root_y, win_x, win_y, same_screen, mods, group, buttons };
         Ok((result, remaining))
     }
@@ -7486,9 +9929,134 @@ impl TryFrom<&[u8]> for XIQueryPointerReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for XIQueryPointerReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        self.child.serialize_into_with_order(bytes, order);
+        self.root_x.serialize_into_with_order(bytes, order);
+        self.root_y.serialize_into_with_order(bytes, order);
+        self.win_x.serialize_into_with_order(bytes, order);
+        self.win_y.serialize_into_with_order(bytes, order);
+        self.same_screen.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        let buttons_len = u16::try_from(self.buttons.len()).expect("`buttons` has too many elements");
+        buttons_len.serialize_into_with_order(bytes, order);
+        self.mods.serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
+        self.buttons.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the XIWarpPointer request
 pub const XI_WARP_POINTER_REQUEST: u8 = 41;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIWarpPointerRequest {
+    pub src_win: xproto::Window,
+    pub dst_win: xproto::Window,
+    pub src_x: Fp1616,
+    pub src_y: Fp1616,
+    pub src_width: u16,
+    pub src_height: u16,
+    pub dst_x: Fp1616,
+    pub dst_y: Fp1616,
+    pub deviceid: DeviceId,
+}
+impl XIWarpPointerRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_WARP_POINTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (src_win, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (dst_win, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (src_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (src_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (dst_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (dst_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIWarpPointerRequest {
+            src_win,
+            dst_win,
+            src_x,
+            src_y,
+            src_width,
+            src_height,
+            dst_x,
+            dst_y,
+            deviceid,
+        })
+    }
+    /// Set the `src_win` field of this structure.
+    pub fn src_win<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.src_win = value.into();
+        self
+    }
+    /// Set the `dst_win` field of this structure.
+    pub fn dst_win<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.dst_win = value.into();
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x<I>(mut self, value: I) -> Self where I: Into<Fp1616> {
+        self.src_x = value.into();
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y<I>(mut self, value: I) -> Self where I: Into<Fp1616> {
+        self.src_y = value.into();
+        self
+    }
+    /// Set the `src_width` field of this structure.
+    pub fn src_width(mut self, value: u16) -> Self {
+        self.src_width = value;
+        self
+    }
+    /// Set the `src_height` field of this structure.
+    pub fn src_height(mut self, value: u16) -> Self {
+        self.src_height = value;
+        self
+    }
+    /// Set the `dst_x` field of this structure.
+    pub fn dst_x<I>(mut self, value: I) -> Self where I: Into<Fp1616> {
+        self.dst_x = value.into();
+        self
+    }
+    /// Set the `dst_y` field of this structure.
+    pub fn dst_y<I>(mut self, value: I) -> Self where I: Into<Fp1616> {
+        self.dst_y = value.into();
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+}
+
 pub fn xi_warp_pointer<Conn, A>(conn: &Conn, src_win: xproto::Window, dst_win: xproto::Window, src_x: Fp1616, src_y: Fp1616, src_width: u16, src_height: u16, dst_x: Fp1616, dst_y: Fp1616, deviceid: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7497,16 +10065,17 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let src_win_bytes = src_win.serialize();
-    let dst_win_bytes = dst_win.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
-    let src_width_bytes = src_width.serialize();
-    let src_height_bytes = src_height.serialize();
-    let dst_x_bytes = dst_x.serialize();
-    let dst_y_bytes = dst_y.serialize();
-    let deviceid_bytes = deviceid.serialize();
+    let src_win_bytes = src_win.serialize_with_order(order);
+    let dst_win_bytes = dst_win.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
+    let src_width_bytes = src_width.serialize_with_order(order);
+    let src_height_bytes = src_height.serialize_with_order(order);
+    let dst_x_bytes = dst_x.serialize_with_order(order);
+    let dst_y_bytes = dst_y.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_WARP_POINTER_REQUEST,
@@ -7548,12 +10117,66 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIWarpPointerRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIWarpPointerRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_warp_pointer(conn, self.src_win, self.dst_win, self.src_x, self.src_y, self.src_width, self.src_height, self.dst_x, self.dst_y, self.deviceid)
+    }
+}
 
 /// Opcode for the XIChangeCursor request
 pub const XI_CHANGE_CURSOR_REQUEST: u8 = 42;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIChangeCursorRequest {
+    pub window: xproto::Window,
+    pub cursor: xproto::Cursor,
+    pub deviceid: DeviceId,
+}
+impl XIChangeCursorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_CHANGE_CURSOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (cursor, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIChangeCursorRequest {
+            window,
+            cursor,
+            deviceid,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `cursor` field of this structure.
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.cursor = value.into();
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+}
+
 pub fn xi_change_cursor<Conn, A>(conn: &Conn, window: xproto::Window, cursor: xproto::Cursor, deviceid: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7562,10 +10185,11 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let cursor_bytes = cursor.serialize();
-    let deviceid_bytes = deviceid.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let cursor_bytes = cursor.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_CHANGE_CURSOR_REQUEST,
@@ -7587,137 +10211,79 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum HierarchyChangeType {
-    AddMaster = 1,
-    RemoveMaster = 2,
-    AttachSlave = 3,
-    DetachSlave = 4,
-}
-impl From<HierarchyChangeType> for u8 {
-    fn from(input: HierarchyChangeType) -> Self {
-        match input {
-            HierarchyChangeType::AddMaster => 1,
-            HierarchyChangeType::RemoveMaster => 2,
-            HierarchyChangeType::AttachSlave => 3,
-            HierarchyChangeType::DetachSlave => 4,
-        }
-    }
+impl crate::x11_utils::Request for XIChangeCursorRequest {
+    type Reply = ();
 }
-impl From<HierarchyChangeType> for Option<u8> {
-    fn from(input: HierarchyChangeType) -> Self {
-        Some(u8::from(input))
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIChangeCursorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_change_cursor(conn, self.window, self.cursor, self.deviceid)
     }
 }
-impl From<HierarchyChangeType> for u16 {
-    fn from(input: HierarchyChangeType) -> Self {
-        Self::from(u8::from(input))
-    }
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HierarchyChangeType(u16);
+#[allow(non_upper_case_globals)]
+impl HierarchyChangeType {
+    pub const AddMaster: Self = Self(1);
+    pub const RemoveMaster: Self = Self(2);
+    pub const AttachSlave: Self = Self(3);
+    pub const DetachSlave: Self = Self(4);
 }
+enum_newtype!(HierarchyChangeType, u16, [AddMaster, RemoveMaster, AttachSlave, DetachSlave]);
 impl From<HierarchyChangeType> for Option<u16> {
     fn from(input: HierarchyChangeType) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<HierarchyChangeType> for u32 {
     fn from(input: HierarchyChangeType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<HierarchyChangeType> for Option<u32> {
     fn from(input: HierarchyChangeType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for HierarchyChangeType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(HierarchyChangeType::AddMaster),
-            2 => Ok(HierarchyChangeType::RemoveMaster),
-            3 => Ok(HierarchyChangeType::AttachSlave),
-            4 => Ok(HierarchyChangeType::DetachSlave),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for HierarchyChangeType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for HierarchyChangeType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ChangeMode {
-    Attach = 1,
-    Float = 2,
-}
-impl From<ChangeMode> for u8 {
-    fn from(input: ChangeMode) -> Self {
-        match input {
-            ChangeMode::Attach => 1,
-            ChangeMode::Float => 2,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChangeMode(u8);
+#[allow(non_upper_case_globals)]
+impl ChangeMode {
+    pub const Attach: Self = Self(1);
+    pub const Float: Self = Self(2);
 }
+enum_newtype!(ChangeMode, u8, [Attach, Float]);
 impl From<ChangeMode> for Option<u8> {
     fn from(input: ChangeMode) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ChangeMode> for u16 {
     fn from(input: ChangeMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ChangeMode> for Option<u16> {
     fn from(input: ChangeMode) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ChangeMode> for u32 {
     fn from(input: ChangeMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ChangeMode> for Option<u32> {
     fn from(input: ChangeMode) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ChangeMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ChangeMode::Attach),
-            2 => Ok(ChangeMode::Float),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ChangeMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ChangeMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -7730,20 +10296,20 @@ pub struct AddMaster {
     pub name: Vec<u8>,
 }
 impl TryParse for AddMaster {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (send_core, remaining) = bool::try_parse(remaining)?;
-        let (enable, remaining) = bool::try_parse(remaining)?;
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (send_core, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (enable, remaining) = bool::try_parse_with_order(remaining, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = HierarchyChangeType::from(type_);
         let result = AddMaster { type_, len, send_core, enable, name };
         Ok((result, remaining))
     }
@@ -7756,19 +10322,19 @@ impl TryFrom<&[u8]> for AddMaster {
 }
 impl Serialize for AddMaster {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.type_).serialize_into(bytes);
-        self.len.serialize_into(bytes);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
         let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
-        name_len.serialize_into(bytes);
-        self.send_core.serialize_into(bytes);
-        self.enable.serialize_into(bytes);
+        name_len.serialize_into_with_order(bytes, order);
+        self.send_core.serialize_into_with_order(bytes, order);
+        self.enable.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.name);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
@@ -7784,16 +10350,16 @@ pub struct RemoveMaster {
     pub return_keyboard: DeviceId,
 }
 impl TryParse for RemoveMaster {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (return_mode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (return_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (return_pointer, remaining) = DeviceId::try_parse(remaining)?;
-        let (return_keyboard, remaining) = DeviceId::try_parse(remaining)?;
-        let type_ = type_.try_into()?;
-        let return_mode = return_mode.try_into()?;
+        let (return_pointer, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (return_keyboard, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let type_ = HierarchyChangeType::from(type_);
+        let return_mode = ChangeMode::from(return_mode);
         let result = RemoveMaster { type_, len, deviceid, return_mode, return_pointer, return_keyboard };
         Ok((result, remaining))
     }
@@ -7806,13 +10372,13 @@ impl TryFrom<&[u8]> for RemoveMaster {
 }
 impl Serialize for RemoveMaster {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let type_bytes = u16::from(self.type_).serialize();
-        let len_bytes = self.len.serialize();
-        let deviceid_bytes = self.deviceid.serialize();
-        let return_mode_bytes = u8::from(self.return_mode).serialize();
-        let return_pointer_bytes = self.return_pointer.serialize();
-        let return_keyboard_bytes = self.return_keyboard.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let type_bytes = u16::from(self.type_).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let deviceid_bytes = self.deviceid.serialize_with_order(order);
+        let return_mode_bytes = u8::from(self.return_mode).serialize_with_order(order);
+        let return_pointer_bytes = self.return_pointer.serialize_with_order(order);
+        let return_keyboard_bytes = self.return_keyboard.serialize_with_order(order);
         [
             type_bytes[0],
             type_bytes[1],
@@ -7828,15 +10394,15 @@ impl Serialize for RemoveMaster {
             return_keyboard_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        u16::from(self.type_).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.deviceid.serialize_into(bytes);
-        u8::from(self.return_mode).serialize_into(bytes);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        u8::from(self.return_mode).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
-        self.return_pointer.serialize_into(bytes);
-        self.return_keyboard.serialize_into(bytes);
+        self.return_pointer.serialize_into_with_order(bytes, order);
+        self.return_keyboard.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -7848,12 +10414,12 @@ pub struct AttachSlave {
     pub master: DeviceId,
 }
 impl TryParse for AttachSlave {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (master, remaining) = DeviceId::try_parse(remaining)?;
-        let type_ = type_.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (master, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let type_ = HierarchyChangeType::from(type_);
         let result = AttachSlave { type_, len, deviceid, master };
         Ok((result, remaining))
     }
@@ -7866,11 +10432,11 @@ impl TryFrom<&[u8]> for AttachSlave {
 }
 impl Serialize for AttachSlave {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u16::from(self.type_).serialize();
-        let len_bytes = self.len.serialize();
-        let deviceid_bytes = self.deviceid.serialize();
-        let master_bytes = self.master.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u16::from(self.type_).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let deviceid_bytes = self.deviceid.serialize_with_order(order);
+        let master_bytes = self.master.serialize_with_order(order);
         [
             type_bytes[0],
             type_bytes[1],
@@ -7882,12 +10448,12 @@ impl Serialize for AttachSlave {
             master_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.type_).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.deviceid.serialize_into(bytes);
-        self.master.serialize_into(bytes);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.master.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -7898,12 +10464,12 @@ pub struct DetachSlave {
     pub deviceid: DeviceId,
 }
 impl TryParse for DetachSlave {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = HierarchyChangeType::from(type_);
         let result = DetachSlave { type_, len, deviceid };
         Ok((result, remaining))
     }
@@ -7916,10 +10482,10 @@ impl TryFrom<&[u8]> for DetachSlave {
 }
 impl Serialize for DetachSlave {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u16::from(self.type_).serialize();
-        let len_bytes = self.len.serialize();
-        let deviceid_bytes = self.deviceid.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u16::from(self.type_).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let deviceid_bytes = self.deviceid.serialize_with_order(order);
         [
             type_bytes[0],
             type_bytes[1],
@@ -7931,11 +10497,11 @@ impl Serialize for DetachSlave {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.type_).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.deviceid.serialize_into(bytes);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
@@ -7947,11 +10513,11 @@ pub struct HierarchyChangeDataAddMaster {
     pub name: Vec<u8>,
 }
 impl TryParse for HierarchyChangeDataAddMaster {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (send_core, remaining) = bool::try_parse(remaining)?;
-        let (enable, remaining) = bool::try_parse(remaining)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (send_core, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (enable, remaining) = bool::try_parse_with_order(remaining, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
         // Align offset to multiple of 4
@@ -7970,17 +10536,17 @@ impl TryFrom<&[u8]> for HierarchyChangeDataAddMaster {
 }
 impl Serialize for HierarchyChangeDataAddMaster {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
         let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
-        name_len.serialize_into(bytes);
-        self.send_core.serialize_into(bytes);
-        self.enable.serialize_into(bytes);
+        name_len.serialize_into_with_order(bytes, order);
+        self.send_core.serialize_into_with_order(bytes, order);
+        self.enable.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.name);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
@@ -7993,13 +10559,13 @@ pub struct HierarchyChangeDataRemoveMaster {
     pub return_keyboard: DeviceId,
 }
 impl TryParse for HierarchyChangeDataRemoveMaster {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (return_mode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (return_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (return_pointer, remaining) = DeviceId::try_parse(remaining)?;
-        let (return_keyboard, remaining) = DeviceId::try_parse(remaining)?;
-        let return_mode = return_mode.try_into()?;
+        let (return_pointer, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (return_keyboard, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let return_mode = ChangeMode::from(return_mode);
         let result = HierarchyChangeDataRemoveMaster { deviceid, return_mode, return_pointer, return_keyboard };
         Ok((result, remaining))
     }
@@ -8012,11 +10578,11 @@ impl TryFrom<&[u8]> for HierarchyChangeDataRemoveMaster {
 }
 impl Serialize for HierarchyChangeDataRemoveMaster {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let deviceid_bytes = self.deviceid.serialize();
-        let return_mode_bytes = u8::from(self.return_mode).serialize();
-        let return_pointer_bytes = self.return_pointer.serialize();
-        let return_keyboard_bytes = self.return_keyboard.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let deviceid_bytes = self.deviceid.serialize_with_order(order);
+        let return_mode_bytes = u8::from(self.return_mode).serialize_with_order(order);
+        let return_pointer_bytes = self.return_pointer.serialize_with_order(order);
+        let return_keyboard_bytes = self.return_keyboard.serialize_with_order(order);
         [
             deviceid_bytes[0],
             deviceid_bytes[1],
@@ -8028,13 +10594,13 @@ impl Serialize for HierarchyChangeDataRemoveMaster {
             return_keyboard_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.deviceid.serialize_into(bytes);
-        u8::from(self.return_mode).serialize_into(bytes);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        u8::from(self.return_mode).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
-        self.return_pointer.serialize_into(bytes);
-        self.return_keyboard.serialize_into(bytes);
+        self.return_pointer.serialize_into_with_order(bytes, order);
+        self.return_keyboard.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8043,9 +10609,9 @@ pub struct HierarchyChangeDataAttachSlave {
     pub master: DeviceId,
 }
 impl TryParse for HierarchyChangeDataAttachSlave {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (master, remaining) = DeviceId::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (master, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let result = HierarchyChangeDataAttachSlave { deviceid, master };
         Ok((result, remaining))
     }
@@ -8058,9 +10624,9 @@ impl TryFrom<&[u8]> for HierarchyChangeDataAttachSlave {
 }
 impl Serialize for HierarchyChangeDataAttachSlave {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let deviceid_bytes = self.deviceid.serialize();
-        let master_bytes = self.master.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let deviceid_bytes = self.deviceid.serialize_with_order(order);
+        let master_bytes = self.master.serialize_with_order(order);
         [
             deviceid_bytes[0],
             deviceid_bytes[1],
@@ -8068,10 +10634,10 @@ impl Serialize for HierarchyChangeDataAttachSlave {
             master_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.deviceid.serialize_into(bytes);
-        self.master.serialize_into(bytes);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.master.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8079,8 +10645,8 @@ pub struct HierarchyChangeDataDetachSlave {
     pub deviceid: DeviceId,
 }
 impl TryParse for HierarchyChangeDataDetachSlave {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
         let result = HierarchyChangeDataDetachSlave { deviceid };
         Ok((result, remaining))
@@ -8094,8 +10660,8 @@ impl TryFrom<&[u8]> for HierarchyChangeDataDetachSlave {
 }
 impl Serialize for HierarchyChangeDataDetachSlave {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let deviceid_bytes = self.deviceid.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let deviceid_bytes = self.deviceid.serialize_with_order(order);
         [
             deviceid_bytes[0],
             deviceid_bytes[1],
@@ -8103,9 +10669,9 @@ impl Serialize for HierarchyChangeDataDetachSlave {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.deviceid.serialize_into(bytes);
+        self.deviceid.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
@@ -8117,30 +10683,30 @@ pub enum HierarchyChangeData {
     DetachSlave(HierarchyChangeDataDetachSlave),
 }
 impl HierarchyChangeData {
-    fn try_parse(value: &[u8], type_: u16) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, type_: HierarchyChangeType) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(type_);
         let mut outer_remaining = value;
         let mut parse_result = None;
         if switch_expr == u32::from(HierarchyChangeType::AddMaster) {
-            let (add_master, new_remaining) = HierarchyChangeDataAddMaster::try_parse(outer_remaining)?;
+            let (add_master, new_remaining) = HierarchyChangeDataAddMaster::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(HierarchyChangeData::AddMaster(add_master));
         }
         if switch_expr == u32::from(HierarchyChangeType::RemoveMaster) {
-            let (remove_master, new_remaining) = HierarchyChangeDataRemoveMaster::try_parse(outer_remaining)?;
+            let (remove_master, new_remaining) = HierarchyChangeDataRemoveMaster::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(HierarchyChangeData::RemoveMaster(remove_master));
         }
         if switch_expr == u32::from(HierarchyChangeType::AttachSlave) {
-            let (attach_slave, new_remaining) = HierarchyChangeDataAttachSlave::try_parse(outer_remaining)?;
+            let (attach_slave, new_remaining) = HierarchyChangeDataAttachSlave::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(HierarchyChangeData::AttachSlave(attach_slave));
         }
         if switch_expr == u32::from(HierarchyChangeType::DetachSlave) {
-            let (detach_slave, new_remaining) = HierarchyChangeDataDetachSlave::try_parse(outer_remaining)?;
+            let (detach_slave, new_remaining) = HierarchyChangeDataDetachSlave::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(HierarchyChangeData::DetachSlave(detach_slave));
@@ -8179,17 +10745,17 @@ impl HierarchyChangeData {
 }
 #[allow(dead_code, unused_variables)]
 impl HierarchyChangeData {
-    fn serialize(&self, type_: u16) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, type_: HierarchyChangeType) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, type_);
+        self.serialize_into_with_order(&mut result, order, type_);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, type_: u16) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, type_: HierarchyChangeType) {
         match self {
-            HierarchyChangeData::AddMaster(add_master) => add_master.serialize_into(bytes),
-            HierarchyChangeData::RemoveMaster(remove_master) => remove_master.serialize_into(bytes),
-            HierarchyChangeData::AttachSlave(attach_slave) => attach_slave.serialize_into(bytes),
-            HierarchyChangeData::DetachSlave(detach_slave) => detach_slave.serialize_into(bytes),
+            HierarchyChangeData::AddMaster(add_master) => add_master.serialize_into_with_order(bytes, order),
+            HierarchyChangeData::RemoveMaster(remove_master) => remove_master.serialize_into_with_order(bytes, order),
+            HierarchyChangeData::AttachSlave(attach_slave) => attach_slave.serialize_into_with_order(bytes, order),
+            HierarchyChangeData::DetachSlave(detach_slave) => detach_slave.serialize_into_with_order(bytes, order),
         }
     }
 }
@@ -8210,10 +10776,10 @@ pub struct HierarchyChange {
     pub data: HierarchyChangeData,
 }
 impl TryParse for HierarchyChange {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (data, remaining) = HierarchyChangeData::try_parse(remaining, type_)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = HierarchyChangeData::try_parse_with_order(remaining, order, HierarchyChangeType::from(type_))?;
         let result = HierarchyChange { len, data };
         Ok((result, remaining))
     }
@@ -8226,31 +10792,59 @@ impl TryFrom<&[u8]> for HierarchyChange {
 }
 impl Serialize for HierarchyChange {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
         let type_ = u16::try_from(self.data.switch_expr()).unwrap();
-        type_.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.data.serialize_into(bytes, type_);
+        type_.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.data.serialize_into_with_order(bytes, order, HierarchyChangeType::from(type_));
     }
 }
 
 /// Opcode for the XIChangeHierarchy request
 pub const XI_CHANGE_HIERARCHY_REQUEST: u8 = 43;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct XIChangeHierarchyRequest {
+    pub changes: Vec<HierarchyChange>,
+}
+impl XIChangeHierarchyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_CHANGE_HIERARCHY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (num_changes, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (changes, remaining) = crate::x11_utils::parse_list_with_order::<HierarchyChange>(remaining, num_changes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIChangeHierarchyRequest {
+            changes,
+        })
+    }
+    /// Set the `changes` field of this structure.
+    pub fn changes<I>(mut self, value: I) -> Self where I: Into<Vec<HierarchyChange>> {
+        self.changes = value.into();
+        self
+    }
+}
+
 pub fn xi_change_hierarchy<'c, Conn>(conn: &'c Conn, changes: &[HierarchyChange]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let num_changes = u8::try_from(changes.len()).expect("`changes` has too many elements");
-    let num_changes_bytes = num_changes.serialize();
+    let num_changes_bytes = num_changes.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_CHANGE_HIERARCHY_REQUEST,
@@ -8262,18 +10856,64 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let changes_bytes = changes.serialize();
+    let changes_bytes = changes.serialize_with_order(order);
     let length_so_far = length_so_far + changes_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&changes_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIChangeHierarchyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIChangeHierarchyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_change_hierarchy(conn, &self.changes)
+    }
+}
 
 /// Opcode for the XISetClientPointer request
 pub const XI_SET_CLIENT_POINTER_REQUEST: u8 = 44;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XISetClientPointerRequest {
+    pub window: xproto::Window,
+    pub deviceid: DeviceId,
+}
+impl XISetClientPointerRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_SET_CLIENT_POINTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XISetClientPointerRequest {
+            window,
+            deviceid,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+}
+
 pub fn xi_set_client_pointer<Conn, A>(conn: &Conn, window: xproto::Window, deviceid: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8282,9 +10922,10 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let deviceid_bytes = deviceid.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_SET_CLIENT_POINTER_REQUEST,
@@ -8302,20 +10943,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XISetClientPointerRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XISetClientPointerRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_set_client_pointer(conn, self.window, self.deviceid)
+    }
+}
 
 /// Opcode for the XIGetClientPointer request
 pub const XI_GET_CLIENT_POINTER_REQUEST: u8 = 45;
-pub fn xi_get_client_pointer<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, XIGetClientPointerReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIGetClientPointerRequest {
+    pub window: xproto::Window,
+}
+impl XIGetClientPointerRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_GET_CLIENT_POINTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIGetClientPointerRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
+pub fn xi_get_client_pointer<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, XIGetClientPointerReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_GET_CLIENT_POINTER_REQUEST,
@@ -8329,9 +11008,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIGetClientPointerRequest {
+    type Reply = XIGetClientPointerReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIGetClientPointerRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIGetClientPointerReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_get_client_pointer(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIGetClientPointerReply {
@@ -8342,14 +11033,14 @@ pub struct XIGetClientPointerReply {
     pub deviceid: DeviceId,
 }
 impl TryParse for XIGetClientPointerReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (set, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (set, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = XIGetClientPointerReply { response_type, sequence, length, set, deviceid };
         Ok((result, remaining))
@@ -8361,120 +11052,89 @@ impl TryFrom<&[u8]> for XIGetClientPointerReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
-pub enum XIEventMask {
-    DeviceChanged = 1 << 1,
-    KeyPress = 1 << 2,
-    KeyRelease = 1 << 3,
-    ButtonPress = 1 << 4,
-    ButtonRelease = 1 << 5,
-    Motion = 1 << 6,
-    Enter = 1 << 7,
-    Leave = 1 << 8,
-    FocusIn = 1 << 9,
-    FocusOut = 1 << 10,
-    Hierarchy = 1 << 11,
-    Property = 1 << 12,
-    RawKeyPress = 1 << 13,
-    RawKeyRelease = 1 << 14,
-    RawButtonPress = 1 << 15,
-    RawButtonRelease = 1 << 16,
-    RawMotion = 1 << 17,
-    TouchBegin = 1 << 18,
-    TouchUpdate = 1 << 19,
-    TouchEnd = 1 << 20,
-    TouchOwnership = 1 << 21,
-    RawTouchBegin = 1 << 22,
-    RawTouchUpdate = 1 << 23,
-    RawTouchEnd = 1 << 24,
-    BarrierHit = 1 << 25,
-    BarrierLeave = 1 << 26,
-}
-impl From<XIEventMask> for u32 {
-    fn from(input: XIEventMask) -> Self {
-        match input {
-            XIEventMask::DeviceChanged => 1 << 1,
-            XIEventMask::KeyPress => 1 << 2,
-            XIEventMask::KeyRelease => 1 << 3,
-            XIEventMask::ButtonPress => 1 << 4,
-            XIEventMask::ButtonRelease => 1 << 5,
-            XIEventMask::Motion => 1 << 6,
-            XIEventMask::Enter => 1 << 7,
-            XIEventMask::Leave => 1 << 8,
-            XIEventMask::FocusIn => 1 << 9,
-            XIEventMask::FocusOut => 1 << 10,
-            XIEventMask::Hierarchy => 1 << 11,
-            XIEventMask::Property => 1 << 12,
-            XIEventMask::RawKeyPress => 1 << 13,
-            XIEventMask::RawKeyRelease => 1 << 14,
-            XIEventMask::RawButtonPress => 1 << 15,
-            XIEventMask::RawButtonRelease => 1 << 16,
-            XIEventMask::RawMotion => 1 << 17,
-            XIEventMask::TouchBegin => 1 << 18,
-            XIEventMask::TouchUpdate => 1 << 19,
-            XIEventMask::TouchEnd => 1 << 20,
-            XIEventMask::TouchOwnership => 1 << 21,
-            XIEventMask::RawTouchBegin => 1 << 22,
-            XIEventMask::RawTouchUpdate => 1 << 23,
-            XIEventMask::RawTouchEnd => 1 << 24,
-            XIEventMask::BarrierHit => 1 << 25,
-            XIEventMask::BarrierLeave => 1 << 26,
-        }
+impl Serialize for XIGetClientPointerReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.set.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct XIEventMask(u32);
+#[allow(non_upper_case_globals)]
+impl XIEventMask {
+    pub const DeviceChanged: Self = Self(1 << 1);
+    pub const KeyPress: Self = Self(1 << 2);
+    pub const KeyRelease: Self = Self(1 << 3);
+    pub const ButtonPress: Self = Self(1 << 4);
+    pub const ButtonRelease: Self = Self(1 << 5);
+    pub const Motion: Self = Self(1 << 6);
+    pub const Enter: Self = Self(1 << 7);
+    pub const Leave: Self = Self(1 << 8);
+    pub const FocusIn: Self = Self(1 << 9);
+    pub const FocusOut: Self = Self(1 << 10);
+    pub const Hierarchy: Self = Self(1 << 11);
+    pub const Property: Self = Self(1 << 12);
+    pub const RawKeyPress: Self = Self(1 << 13);
+    pub const RawKeyRelease: Self = Self(1 << 14);
+    pub const RawButtonPress: Self = Self(1 << 15);
+    pub const RawButtonRelease: Self = Self(1 << 16);
+    pub const RawMotion: Self = Self(1 << 17);
+    pub const TouchBegin: Self = Self(1 << 18);
+    pub const TouchUpdate: Self = Self(1 << 19);
+    pub const TouchEnd: Self = Self(1 << 20);
+    pub const TouchOwnership: Self = Self(1 << 21);
+    pub const RawTouchBegin: Self = Self(1 << 22);
+    pub const RawTouchUpdate: Self = Self(1 << 23);
+    pub const RawTouchEnd: Self = Self(1 << 24);
+    pub const BarrierHit: Self = Self(1 << 25);
+    pub const BarrierLeave: Self = Self(1 << 26);
+}
+bitmask_set!(XIEventMask, u32, [DeviceChanged, KeyPress, KeyRelease, ButtonPress, ButtonRelease, Motion, Enter, Leave, FocusIn, FocusOut, Hierarchy, Property, RawKeyPress, RawKeyRelease, RawButtonPress, RawButtonRelease, RawMotion, TouchBegin, TouchUpdate, TouchEnd, TouchOwnership, RawTouchBegin, RawTouchUpdate, RawTouchEnd, BarrierHit, BarrierLeave]);
 impl From<XIEventMask> for Option<u32> {
     fn from(input: XIEventMask) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u32> for XIEventMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            2 => Ok(XIEventMask::DeviceChanged),
-            4 => Ok(XIEventMask::KeyPress),
-            8 => Ok(XIEventMask::KeyRelease),
-            16 => Ok(XIEventMask::ButtonPress),
-            32 => Ok(XIEventMask::ButtonRelease),
-            64 => Ok(XIEventMask::Motion),
-            128 => Ok(XIEventMask::Enter),
-            256 => Ok(XIEventMask::Leave),
-            512 => Ok(XIEventMask::FocusIn),
-            1024 => Ok(XIEventMask::FocusOut),
-            2048 => Ok(XIEventMask::Hierarchy),
-            4096 => Ok(XIEventMask::Property),
-            8192 => Ok(XIEventMask::RawKeyPress),
-            16384 => Ok(XIEventMask::RawKeyRelease),
-            32768 => Ok(XIEventMask::RawButtonPress),
-            65536 => Ok(XIEventMask::RawButtonRelease),
-            131_072 => Ok(XIEventMask::RawMotion),
-            262_144 => Ok(XIEventMask::TouchBegin),
-            524_288 => Ok(XIEventMask::TouchUpdate),
-            1_048_576 => Ok(XIEventMask::TouchEnd),
-            2_097_152 => Ok(XIEventMask::TouchOwnership),
-            4_194_304 => Ok(XIEventMask::RawTouchBegin),
-            8_388_608 => Ok(XIEventMask::RawTouchUpdate),
-            16_777_216 => Ok(XIEventMask::RawTouchEnd),
-            33_554_432 => Ok(XIEventMask::BarrierHit),
-            67_108_864 => Ok(XIEventMask::BarrierLeave),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-bitmask_binop!(XIEventMask, u32);
+        Some(input.0)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EventMask {
     pub deviceid: DeviceId,
-    pub mask: Vec<u32>,
+    pub mask: Vec<XIEventMask>,
 }
 impl TryParse for EventMask {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (mask_len, remaining) = u16::try_parse(remaining)?;
-        let (mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, mask_len.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (mask_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        let list_length = usize::try_from(mask_len).or(Err(ParseError::ParseError))?;
+        let mut mask = Vec::with_capacity(list_length);
+        for _ in 0..list_length {
+            let (v, new_remaining) = u32::try_parse_with_order(remaining, order)?;
+            let v = XIEventMask::from(v);
+            remaining = new_remaining;
+            mask.push(v);
+        }
         let result = EventMask { deviceid, mask };
         Ok((result, remaining))
     }
@@ -8487,32 +11147,70 @@ impl TryFrom<&[u8]> for EventMask {
 }
 impl Serialize for EventMask {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.deviceid.serialize_into(bytes);
+        self.deviceid.serialize_into_with_order(bytes, order);
         let mask_len = u16::try_from(self.mask.len()).expect("`mask` has too many elements");
-        mask_len.serialize_into(bytes);
-        self.mask.serialize_into(bytes);
+        mask_len.serialize_into_with_order(bytes, order);
+        for element in self.mask.iter() {
+            u32::from(*element).serialize_into_with_order(bytes, order);
+        }
     }
 }
 
 /// Opcode for the XISelectEvents request
 pub const XI_SELECT_EVENTS_REQUEST: u8 = 46;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct XISelectEventsRequest {
+    pub window: xproto::Window,
+    pub masks: Vec<EventMask>,
+}
+impl XISelectEventsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_SELECT_EVENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (num_mask, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (masks, remaining) = crate::x11_utils::parse_list_with_order::<EventMask>(remaining, num_mask.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XISelectEventsRequest {
+            window,
+            masks,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `masks` field of this structure.
+    pub fn masks<I>(mut self, value: I) -> Self where I: Into<Vec<EventMask>> {
+        self.masks = value.into();
+        self
+    }
+}
+
 pub fn xi_select_events<'c, Conn>(conn: &'c Conn, window: xproto::Window, masks: &[EventMask]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let num_mask = u16::try_from(masks.len()).expect("`masks` has too many elements");
-    let num_mask_bytes = num_mask.serialize();
+    let num_mask_bytes = num_mask.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_SELECT_EVENTS_REQUEST,
@@ -8528,27 +11226,73 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let masks_bytes = masks.serialize();
+    let masks_bytes = masks.serialize_with_order(order);
     let length_so_far = length_so_far + masks_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&masks_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for XISelectEventsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XISelectEventsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_select_events(conn, self.window, &self.masks)
+    }
+}
 
 /// Opcode for the XIQueryVersion request
 pub const XI_QUERY_VERSION_REQUEST: u8 = 47;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIQueryVersionRequest {
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+impl XIQueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIQueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u16) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u16) -> Self {
+        self.minor_version = value;
+        self
+    }
+}
+
 pub fn xi_query_version<Conn>(conn: &Conn, major_version: u16, minor_version: u16) -> Result<Cookie<'_, Conn, XIQueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_QUERY_VERSION_REQUEST,
@@ -8562,9 +11306,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIQueryVersionRequest {
+    type Reply = XIQueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIQueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIQueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_query_version(conn, self.major_version, self.minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIQueryVersionReply {
@@ -8575,13 +11331,13 @@ pub struct XIQueryVersionReply {
     pub minor_version: u16,
 }
 impl TryParse for XIQueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = XIQueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
@@ -8593,333 +11349,155 @@ impl TryFrom<&[u8]> for XIQueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum DeviceClassType {
-    Key = 0,
-    Button = 1,
-    Valuator = 2,
-    Scroll = 3,
-    Touch = 8,
-}
-impl From<DeviceClassType> for u8 {
-    fn from(input: DeviceClassType) -> Self {
-        match input {
-            DeviceClassType::Key => 0,
-            DeviceClassType::Button => 1,
-            DeviceClassType::Valuator => 2,
-            DeviceClassType::Scroll => 3,
-            DeviceClassType::Touch => 8,
-        }
-    }
-}
-impl From<DeviceClassType> for Option<u8> {
-    fn from(input: DeviceClassType) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<DeviceClassType> for u16 {
-    fn from(input: DeviceClassType) -> Self {
-        Self::from(u8::from(input))
+impl Serialize for XIQueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DeviceClassType(u16);
+#[allow(non_upper_case_globals)]
+impl DeviceClassType {
+    pub const Key: Self = Self(0);
+    pub const Button: Self = Self(1);
+    pub const Valuator: Self = Self(2);
+    pub const Scroll: Self = Self(3);
+    pub const Touch: Self = Self(8);
+}
+enum_newtype!(DeviceClassType, u16, [Key, Button, Valuator, Scroll, Touch]);
 impl From<DeviceClassType> for Option<u16> {
     fn from(input: DeviceClassType) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<DeviceClassType> for u32 {
     fn from(input: DeviceClassType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DeviceClassType> for Option<u32> {
     fn from(input: DeviceClassType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for DeviceClassType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(DeviceClassType::Key),
-            1 => Ok(DeviceClassType::Button),
-            2 => Ok(DeviceClassType::Valuator),
-            3 => Ok(DeviceClassType::Scroll),
-            8 => Ok(DeviceClassType::Touch),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for DeviceClassType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for DeviceClassType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum DeviceType {
-    MasterPointer = 1,
-    MasterKeyboard = 2,
-    SlavePointer = 3,
-    SlaveKeyboard = 4,
-    FloatingSlave = 5,
-}
-impl From<DeviceType> for u8 {
-    fn from(input: DeviceType) -> Self {
-        match input {
-            DeviceType::MasterPointer => 1,
-            DeviceType::MasterKeyboard => 2,
-            DeviceType::SlavePointer => 3,
-            DeviceType::SlaveKeyboard => 4,
-            DeviceType::FloatingSlave => 5,
-        }
-    }
-}
-impl From<DeviceType> for Option<u8> {
-    fn from(input: DeviceType) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<DeviceType> for u16 {
-    fn from(input: DeviceType) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeviceType(u16);
+#[allow(non_upper_case_globals)]
+impl DeviceType {
+    pub const MasterPointer: Self = Self(1);
+    pub const MasterKeyboard: Self = Self(2);
+    pub const SlavePointer: Self = Self(3);
+    pub const SlaveKeyboard: Self = Self(4);
+    pub const FloatingSlave: Self = Self(5);
 }
+enum_newtype!(DeviceType, u16, [MasterPointer, MasterKeyboard, SlavePointer, SlaveKeyboard, FloatingSlave]);
 impl From<DeviceType> for Option<u16> {
     fn from(input: DeviceType) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<DeviceType> for u32 {
     fn from(input: DeviceType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DeviceType> for Option<u32> {
     fn from(input: DeviceType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for DeviceType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(DeviceType::MasterPointer),
-            2 => Ok(DeviceType::MasterKeyboard),
-            3 => Ok(DeviceType::SlavePointer),
-            4 => Ok(DeviceType::SlaveKeyboard),
-            5 => Ok(DeviceType::FloatingSlave),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for DeviceType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for DeviceType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ScrollFlags {
-    NoEmulation = 1 << 0,
-    Preferred = 1 << 1,
-}
-impl From<ScrollFlags> for u8 {
-    fn from(input: ScrollFlags) -> Self {
-        match input {
-            ScrollFlags::NoEmulation => 1 << 0,
-            ScrollFlags::Preferred => 1 << 1,
-        }
-    }
-}
-impl From<ScrollFlags> for Option<u8> {
-    fn from(input: ScrollFlags) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<ScrollFlags> for u16 {
-    fn from(input: ScrollFlags) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<ScrollFlags> for Option<u16> {
-    fn from(input: ScrollFlags) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<ScrollFlags> for u32 {
-    fn from(input: ScrollFlags) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ScrollFlags(u32);
+#[allow(non_upper_case_globals)]
+impl ScrollFlags {
+    pub const NoEmulation: Self = Self(1 << 0);
+    pub const Preferred: Self = Self(1 << 1);
 }
+bitmask_set!(ScrollFlags, u32, [NoEmulation, Preferred]);
 impl From<ScrollFlags> for Option<u32> {
     fn from(input: ScrollFlags) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ScrollFlags {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ScrollFlags::NoEmulation),
-            2 => Ok(ScrollFlags::Preferred),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(input.0)
     }
 }
-impl TryFrom<u16> for ScrollFlags {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ScrollFlags {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(ScrollFlags, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ScrollType {
-    Vertical = 1,
-    Horizontal = 2,
-}
-impl From<ScrollType> for u8 {
-    fn from(input: ScrollType) -> Self {
-        match input {
-            ScrollType::Vertical => 1,
-            ScrollType::Horizontal => 2,
-        }
-    }
-}
-impl From<ScrollType> for Option<u8> {
-    fn from(input: ScrollType) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<ScrollType> for u16 {
-    fn from(input: ScrollType) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScrollType(u16);
+#[allow(non_upper_case_globals)]
+impl ScrollType {
+    pub const Vertical: Self = Self(1);
+    pub const Horizontal: Self = Self(2);
 }
+enum_newtype!(ScrollType, u16, [Vertical, Horizontal]);
 impl From<ScrollType> for Option<u16> {
     fn from(input: ScrollType) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<ScrollType> for u32 {
     fn from(input: ScrollType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ScrollType> for Option<u32> {
     fn from(input: ScrollType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ScrollType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ScrollType::Vertical),
-            2 => Ok(ScrollType::Horizontal),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ScrollType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ScrollType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum TouchMode {
-    Direct = 1,
-    Dependent = 2,
-}
-impl From<TouchMode> for u8 {
-    fn from(input: TouchMode) -> Self {
-        match input {
-            TouchMode::Direct => 1,
-            TouchMode::Dependent => 2,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TouchMode(u8);
+#[allow(non_upper_case_globals)]
+impl TouchMode {
+    pub const Direct: Self = Self(1);
+    pub const Dependent: Self = Self(2);
 }
+enum_newtype!(TouchMode, u8, [Direct, Dependent]);
 impl From<TouchMode> for Option<u8> {
     fn from(input: TouchMode) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<TouchMode> for u16 {
     fn from(input: TouchMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<TouchMode> for Option<u16> {
     fn from(input: TouchMode) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<TouchMode> for u32 {
     fn from(input: TouchMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<TouchMode> for Option<u32> {
     fn from(input: TouchMode) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for TouchMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(TouchMode::Direct),
-            2 => Ok(TouchMode::Dependent),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for TouchMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for TouchMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -8932,14 +11510,14 @@ pub struct ButtonClass {
     pub labels: Vec<xproto::Atom>,
 }
 impl TryParse for ButtonClass {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (num_buttons, remaining) = u16::try_parse(remaining)?;
-        let (state, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_buttons).checked_add(31u32).ok_or(ParseError::ParseError)?.checked_div(32u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
-        let (labels, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_buttons.try_into().or(Err(ParseError::ParseError))?)?;
-        let type_ = type_.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (num_buttons, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, u32::from(num_buttons).checked_add(31u32).ok_or(ParseError::ParseError)?.checked_div(32u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (labels, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, num_buttons.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let type_ = DeviceClassType::from(type_);
         let result = ButtonClass { type_, len, sourceid, state, labels };
         Ok((result, remaining))
     }
@@ -8952,21 +11530,21 @@ impl TryFrom<&[u8]> for ButtonClass {
 }
 impl Serialize for ButtonClass {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.type_).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.sourceid.serialize_into(bytes);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
         let num_buttons = u16::try_from(self.labels.len()).expect("`labels` has too many elements");
-        num_buttons.serialize_into(bytes);
+        num_buttons.serialize_into_with_order(bytes, order);
         assert_eq!(self.state.len(), usize::try_from(u32::from(num_buttons).checked_add(31u32).unwrap().checked_div(32u32).unwrap()).unwrap(), "`state` has an incorrect length");
-        self.state.serialize_into(bytes);
-        self.labels.serialize_into(bytes);
+        self.state.serialize_into_with_order(bytes, order);
+        self.labels.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -8978,13 +11556,13 @@ pub struct KeyClass {
     pub keys: Vec<u32>,
 }
 impl TryParse for KeyClass {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (num_keys, remaining) = u16::try_parse(remaining)?;
-        let (keys, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_keys.try_into().or(Err(ParseError::ParseError))?)?;
-        let type_ = type_.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (num_keys, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keys, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let type_ = DeviceClassType::from(type_);
         let result = KeyClass { type_, len, sourceid, keys };
         Ok((result, remaining))
     }
@@ -8997,19 +11575,19 @@ impl TryFrom<&[u8]> for KeyClass {
 }
 impl Serialize for KeyClass {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.type_).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.sourceid.serialize_into(bytes);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
         let num_keys = u16::try_from(self.keys.len()).expect("`keys` has too many elements");
-        num_keys.serialize_into(bytes);
-        self.keys.serialize_into(bytes);
+        num_keys.serialize_into_with_order(bytes, order);
+        self.keys.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -9020,21 +11598,22 @@ pub struct ScrollClass {
     pub sourceid: DeviceId,
     pub number: u16,
     pub scroll_type: ScrollType,
-    pub flags: u32,
+    pub flags: ScrollFlags,
     pub increment: Fp3232,
 }
 impl TryParse for ScrollClass {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (number, remaining) = u16::try_parse(remaining)?;
-        let (scroll_type, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (number, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (scroll_type, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (increment, remaining) = Fp3232::try_parse(remaining)?;
-        let type_ = type_.try_into()?;
-        let scroll_type = scroll_type.try_into()?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (increment, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let type_ = DeviceClassType::from(type_);
+        let scroll_type = ScrollType::from(scroll_type);
+        let flags = ScrollFlags::from(flags);
         let result = ScrollClass { type_, len, sourceid, number, scroll_type, flags, increment };
         Ok((result, remaining))
     }
@@ -9047,14 +11626,14 @@ impl TryFrom<&[u8]> for ScrollClass {
 }
 impl Serialize for ScrollClass {
     type Bytes = [u8; 24];
-    fn serialize(&self) -> [u8; 24] {
-        let type_bytes = u16::from(self.type_).serialize();
-        let len_bytes = self.len.serialize();
-        let sourceid_bytes = self.sourceid.serialize();
-        let number_bytes = self.number.serialize();
-        let scroll_type_bytes = u16::from(self.scroll_type).serialize();
-        let flags_bytes = self.flags.serialize();
-        let increment_bytes = self.increment.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 24] {
+        let type_bytes = u16::from(self.type_).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let sourceid_bytes = self.sourceid.serialize_with_order(order);
+        let number_bytes = self.number.serialize_with_order(order);
+        let scroll_type_bytes = u16::from(self.scroll_type).serialize_with_order(order);
+        let flags_bytes = u32::from(self.flags).serialize_with_order(order);
+        let increment_bytes = self.increment.serialize_with_order(order);
         [
             type_bytes[0],
             type_bytes[1],
@@ -9082,16 +11661,16 @@ impl Serialize for ScrollClass {
             increment_bytes[7],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(24);
-        u16::from(self.type_).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.sourceid.serialize_into(bytes);
-        self.number.serialize_into(bytes);
-        u16::from(self.scroll_type).serialize_into(bytes);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        self.number.serialize_into_with_order(bytes, order);
+        u16::from(self.scroll_type).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.flags.serialize_into(bytes);
-        self.increment.serialize_into(bytes);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        self.increment.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -9104,14 +11683,14 @@ pub struct TouchClass {
     pub num_touches: u8,
 }
 impl TryParse for TouchClass {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (num_touches, remaining) = u8::try_parse(remaining)?;
-        let type_ = type_.try_into()?;
-        let mode = mode.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_touches, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let type_ = DeviceClassType::from(type_);
+        let mode = TouchMode::from(mode);
         let result = TouchClass { type_, len, sourceid, mode, num_touches };
         Ok((result, remaining))
     }
@@ -9124,12 +11703,12 @@ impl TryFrom<&[u8]> for TouchClass {
 }
 impl Serialize for TouchClass {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u16::from(self.type_).serialize();
-        let len_bytes = self.len.serialize();
-        let sourceid_bytes = self.sourceid.serialize();
-        let mode_bytes = u8::from(self.mode).serialize();
-        let num_touches_bytes = self.num_touches.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u16::from(self.type_).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let sourceid_bytes = self.sourceid.serialize_with_order(order);
+        let mode_bytes = u8::from(self.mode).serialize_with_order(order);
+        let num_touches_bytes = self.num_touches.serialize_with_order(order);
         [
             type_bytes[0],
             type_bytes[1],
@@ -9141,13 +11720,13 @@ impl Serialize for TouchClass {
             num_touches_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u16::from(self.type_).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.sourceid.serialize_into(bytes);
-        u8::from(self.mode).serialize_into(bytes);
-        self.num_touches.serialize_into(bytes);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
+        self.num_touches.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -9165,20 +11744,20 @@ pub struct ValuatorClass {
     pub mode: ValuatorMode,
 }
 impl TryParse for ValuatorClass {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (number, remaining) = u16::try_parse(remaining)?;
-        let (label, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (min, remaining) = Fp3232::try_parse(remaining)?;
-        let (max, remaining) = Fp3232::try_parse(remaining)?;
-        let (value, remaining) = Fp3232::try_parse(remaining)?;
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (number, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (label, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (min, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let (max, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let (resolution, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
-        let mode = mode.try_into()?;
+        let type_ = DeviceClassType::from(type_);
+        let mode = ValuatorMode::from(mode);
         let result = ValuatorClass { type_, len, sourceid, number, label, min, max, value, resolution, mode };
         Ok((result, remaining))
     }
@@ -9191,17 +11770,17 @@ impl TryFrom<&[u8]> for ValuatorClass {
 }
 impl Serialize for ValuatorClass {
     type Bytes = [u8; 44];
-    fn serialize(&self) -> [u8; 44] {
-        let type_bytes = u16::from(self.type_).serialize();
-        let len_bytes = self.len.serialize();
-        let sourceid_bytes = self.sourceid.serialize();
-        let number_bytes = self.number.serialize();
-        let label_bytes = self.label.serialize();
-        let min_bytes = self.min.serialize();
-        let max_bytes = self.max.serialize();
-        let value_bytes = self.value.serialize();
-        let resolution_bytes = self.resolution.serialize();
-        let mode_bytes = u8::from(self.mode).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 44] {
+        let type_bytes = u16::from(self.type_).serialize_with_order(order);
+        let len_bytes = self.len.serialize_with_order(order);
+        let sourceid_bytes = self.sourceid.serialize_with_order(order);
+        let number_bytes = self.number.serialize_with_order(order);
+        let label_bytes = self.label.serialize_with_order(order);
+        let min_bytes = self.min.serialize_with_order(order);
+        let max_bytes = self.max.serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
+        let resolution_bytes = self.resolution.serialize_with_order(order);
+        let mode_bytes = u8::from(self.mode).serialize_with_order(order);
         [
             type_bytes[0],
             type_bytes[1],
@@ -9249,18 +11828,18 @@ impl Serialize for ValuatorClass {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(44);
-        u16::from(self.type_).serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.sourceid.serialize_into(bytes);
-        self.number.serialize_into(bytes);
-        self.label.serialize_into(bytes);
-        self.min.serialize_into(bytes);
-        self.max.serialize_into(bytes);
-        self.value.serialize_into(bytes);
-        self.resolution.serialize_into(bytes);
-        u8::from(self.mode).serialize_into(bytes);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        self.number.serialize_into_with_order(bytes, order);
+        self.label.serialize_into_with_order(bytes, order);
+        self.min.serialize_into_with_order(bytes, order);
+        self.max.serialize_into_with_order(bytes, order);
+        self.value.serialize_into_with_order(bytes, order);
+        self.resolution.serialize_into_with_order(bytes, order);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
@@ -9270,9 +11849,9 @@ pub struct DeviceClassDataKey {
     pub keys: Vec<u32>,
 }
 impl TryParse for DeviceClassDataKey {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_keys, remaining) = u16::try_parse(remaining)?;
-        let (keys, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_keys.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (num_keys, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keys, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = DeviceClassDataKey { keys };
         Ok((result, remaining))
     }
@@ -9285,15 +11864,15 @@ impl TryFrom<&[u8]> for DeviceClassDataKey {
 }
 impl Serialize for DeviceClassDataKey {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let num_keys = u16::try_from(self.keys.len()).expect("`keys` has too many elements");
-        num_keys.serialize_into(bytes);
-        self.keys.serialize_into(bytes);
+        num_keys.serialize_into_with_order(bytes, order);
+        self.keys.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -9302,10 +11881,10 @@ pub struct DeviceClassDataButton {
     pub labels: Vec<xproto::Atom>,
 }
 impl TryParse for DeviceClassDataButton {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_buttons, remaining) = u16::try_parse(remaining)?;
-        let (state, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_buttons).checked_add(31u32).ok_or(ParseError::ParseError)?.checked_div(32u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
-        let (labels, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_buttons.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (num_buttons, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, u32::from(num_buttons).checked_add(31u32).ok_or(ParseError::ParseError)?.checked_div(32u32).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (labels, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, num_buttons.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = DeviceClassDataButton { state, labels };
         Ok((result, remaining))
     }
@@ -9318,17 +11897,17 @@ impl TryFrom<&[u8]> for DeviceClassDataButton {
 }
 impl Serialize for DeviceClassDataButton {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let num_buttons = u16::try_from(self.labels.len()).expect("`labels` has too many elements");
-        num_buttons.serialize_into(bytes);
+        num_buttons.serialize_into_with_order(bytes, order);
         assert_eq!(self.state.len(), usize::try_from(u32::from(num_buttons).checked_add(31u32).unwrap().checked_div(32u32).unwrap()).unwrap(), "`state` has an incorrect length");
-        self.state.serialize_into(bytes);
-        self.labels.serialize_into(bytes);
+        self.state.serialize_into_with_order(bytes, order);
+        self.labels.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9342,16 +11921,16 @@ pub struct DeviceClassDataValuator {
     pub mode: ValuatorMode,
 }
 impl TryParse for DeviceClassDataValuator {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (number, remaining) = u16::try_parse(remaining)?;
-        let (label, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (min, remaining) = Fp3232::try_parse(remaining)?;
-        let (max, remaining) = Fp3232::try_parse(remaining)?;
-        let (value, remaining) = Fp3232::try_parse(remaining)?;
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (number, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (label, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (min, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let (max, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let (resolution, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let mode = mode.try_into()?;
+        let mode = ValuatorMode::from(mode);
         let result = DeviceClassDataValuator { number, label, min, max, value, resolution, mode };
         Ok((result, remaining))
     }
@@ -9364,14 +11943,14 @@ impl TryFrom<&[u8]> for DeviceClassDataValuator {
 }
 impl Serialize for DeviceClassDataValuator {
     type Bytes = [u8; 38];
-    fn serialize(&self) -> [u8; 38] {
-        let number_bytes = self.number.serialize();
-        let label_bytes = self.label.serialize();
-        let min_bytes = self.min.serialize();
-        let max_bytes = self.max.serialize();
-        let value_bytes = self.value.serialize();
-        let resolution_bytes = self.resolution.serialize();
-        let mode_bytes = u8::from(self.mode).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 38] {
+        let number_bytes = self.number.serialize_with_order(order);
+        let label_bytes = self.label.serialize_with_order(order);
+        let min_bytes = self.min.serialize_with_order(order);
+        let max_bytes = self.max.serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
+        let resolution_bytes = self.resolution.serialize_with_order(order);
+        let mode_bytes = u8::from(self.mode).serialize_with_order(order);
         [
             number_bytes[0],
             number_bytes[1],
@@ -9413,15 +11992,15 @@ impl Serialize for DeviceClassDataValuator {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(38);
-        self.number.serialize_into(bytes);
-        self.label.serialize_into(bytes);
-        self.min.serialize_into(bytes);
-        self.max.serialize_into(bytes);
-        self.value.serialize_into(bytes);
-        self.resolution.serialize_into(bytes);
-        u8::from(self.mode).serialize_into(bytes);
+        self.number.serialize_into_with_order(bytes, order);
+        self.label.serialize_into_with_order(bytes, order);
+        self.min.serialize_into_with_order(bytes, order);
+        self.max.serialize_into_with_order(bytes, order);
+        self.value.serialize_into_with_order(bytes, order);
+        self.resolution.serialize_into_with_order(bytes, order);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
@@ -9429,17 +12008,18 @@ impl Serialize for DeviceClassDataValuator {
 pub struct DeviceClassDataScroll {
     pub number: u16,
     pub scroll_type: ScrollType,
-    pub flags: u32,
+    pub flags: ScrollFlags,
     pub increment: Fp3232,
 }
 impl TryParse for DeviceClassDataScroll {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (number, remaining) = u16::try_parse(remaining)?;
-        let (scroll_type, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (number, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (scroll_type, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (increment, remaining) = Fp3232::try_parse(remaining)?;
-        let scroll_type = scroll_type.try_into()?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (increment, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let scroll_type = ScrollType::from(scroll_type);
+        let flags = ScrollFlags::from(flags);
         let result = DeviceClassDataScroll { number, scroll_type, flags, increment };
         Ok((result, remaining))
     }
@@ -9452,11 +12032,11 @@ impl TryFrom<&[u8]> for DeviceClassDataScroll {
 }
 impl Serialize for DeviceClassDataScroll {
     type Bytes = [u8; 18];
-    fn serialize(&self) -> [u8; 18] {
-        let number_bytes = self.number.serialize();
-        let scroll_type_bytes = u16::from(self.scroll_type).serialize();
-        let flags_bytes = self.flags.serialize();
-        let increment_bytes = self.increment.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 18] {
+        let number_bytes = self.number.serialize_with_order(order);
+        let scroll_type_bytes = u16::from(self.scroll_type).serialize_with_order(order);
+        let flags_bytes = u32::from(self.flags).serialize_with_order(order);
+        let increment_bytes = self.increment.serialize_with_order(order);
         [
             number_bytes[0],
             number_bytes[1],
@@ -9478,13 +12058,13 @@ impl Serialize for DeviceClassDataScroll {
             increment_bytes[7],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(18);
-        self.number.serialize_into(bytes);
-        u16::from(self.scroll_type).serialize_into(bytes);
+        self.number.serialize_into_with_order(bytes, order);
+        u16::from(self.scroll_type).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.flags.serialize_into(bytes);
-        self.increment.serialize_into(bytes);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        self.increment.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9493,10 +12073,10 @@ pub struct DeviceClassDataTouch {
     pub num_touches: u8,
 }
 impl TryParse for DeviceClassDataTouch {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (num_touches, remaining) = u8::try_parse(remaining)?;
-        let mode = mode.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_touches, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let mode = TouchMode::from(mode);
         let result = DeviceClassDataTouch { mode, num_touches };
         Ok((result, remaining))
     }
@@ -9509,18 +12089,18 @@ impl TryFrom<&[u8]> for DeviceClassDataTouch {
 }
 impl Serialize for DeviceClassDataTouch {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let mode_bytes = u8::from(self.mode).serialize();
-        let num_touches_bytes = self.num_touches.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let mode_bytes = u8::from(self.mode).serialize_with_order(order);
+        let num_touches_bytes = self.num_touches.serialize_with_order(order);
         [
             mode_bytes[0],
             num_touches_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        u8::from(self.mode).serialize_into(bytes);
-        self.num_touches.serialize_into(bytes);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
+        self.num_touches.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -9532,36 +12112,36 @@ pub enum DeviceClassData {
     Touch(DeviceClassDataTouch),
 }
 impl DeviceClassData {
-    fn try_parse(value: &[u8], type_: u16) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, type_: DeviceClassType) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(type_);
         let mut outer_remaining = value;
         let mut parse_result = None;
         if switch_expr == u32::from(DeviceClassType::Key) {
-            let (key, new_remaining) = DeviceClassDataKey::try_parse(outer_remaining)?;
+            let (key, new_remaining) = DeviceClassDataKey::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceClassData::Key(key));
         }
         if switch_expr == u32::from(DeviceClassType::Button) {
-            let (button, new_remaining) = DeviceClassDataButton::try_parse(outer_remaining)?;
+            let (button, new_remaining) = DeviceClassDataButton::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceClassData::Button(button));
         }
         if switch_expr == u32::from(DeviceClassType::Valuator) {
-            let (valuator, new_remaining) = DeviceClassDataValuator::try_parse(outer_remaining)?;
+            let (valuator, new_remaining) = DeviceClassDataValuator::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceClassData::Valuator(valuator));
         }
         if switch_expr == u32::from(DeviceClassType::Scroll) {
-            let (scroll, new_remaining) = DeviceClassDataScroll::try_parse(outer_remaining)?;
+            let (scroll, new_remaining) = DeviceClassDataScroll::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceClassData::Scroll(scroll));
         }
         if switch_expr == u32::from(DeviceClassType::Touch) {
-            let (touch, new_remaining) = DeviceClassDataTouch::try_parse(outer_remaining)?;
+            let (touch, new_remaining) = DeviceClassDataTouch::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceClassData::Touch(touch));
@@ -9606,18 +12186,18 @@ impl DeviceClassData {
 }
 #[allow(dead_code, unused_variables)]
 impl DeviceClassData {
-    fn serialize(&self, type_: u16) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, type_: DeviceClassType) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, type_);
+        self.serialize_into_with_order(&mut result, order, type_);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, type_: u16) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, type_: DeviceClassType) {
         match self {
-            DeviceClassData::Key(key) => key.serialize_into(bytes),
-            DeviceClassData::Button(button) => button.serialize_into(bytes),
-            DeviceClassData::Valuator(valuator) => valuator.serialize_into(bytes),
-            DeviceClassData::Scroll(scroll) => scroll.serialize_into(bytes),
-            DeviceClassData::Touch(touch) => touch.serialize_into(bytes),
+            DeviceClassData::Key(key) => key.serialize_into_with_order(bytes, order),
+            DeviceClassData::Button(button) => button.serialize_into_with_order(bytes, order),
+            DeviceClassData::Valuator(valuator) => valuator.serialize_into_with_order(bytes, order),
+            DeviceClassData::Scroll(scroll) => scroll.serialize_into_with_order(bytes, order),
+            DeviceClassData::Touch(touch) => touch.serialize_into_with_order(bytes, order),
         }
     }
 }
@@ -9640,11 +12220,11 @@ pub struct DeviceClass {
     pub data: DeviceClassData,
 }
 impl TryParse for DeviceClass {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (data, remaining) = DeviceClassData::try_parse(remaining, type_)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = DeviceClassData::try_parse_with_order(remaining, order, DeviceClassType::from(type_))?;
         let result = DeviceClass { len, sourceid, data };
         Ok((result, remaining))
     }
@@ -9657,18 +12237,18 @@ impl TryFrom<&[u8]> for DeviceClass {
 }
 impl Serialize for DeviceClass {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(6);
         let type_ = u16::try_from(self.data.switch_expr()).unwrap();
-        type_.serialize_into(bytes);
-        self.len.serialize_into(bytes);
-        self.sourceid.serialize_into(bytes);
-        self.data.serialize_into(bytes, type_);
+        type_.serialize_into_with_order(bytes, order);
+        self.len.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        self.data.serialize_into_with_order(bytes, order, DeviceClassType::from(type_));
     }
 }
 
@@ -9682,14 +12262,14 @@ pub struct XIDeviceInfo {
     pub classes: Vec<DeviceClass>,
 }
 impl TryParse for XIDeviceInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (attachment, remaining) = DeviceId::try_parse(remaining)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (enabled, remaining) = bool::try_parse(remaining)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (attachment, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (enabled, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
@@ -9697,8 +12277,8 @@ impl TryParse for XIDeviceInfo {
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<DeviceClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?)?;
-        let type_ = type_.try_into()?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<DeviceClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let type_ = DeviceType::from(type_);
         let result = XIDeviceInfo { deviceid, type_, attachment, enabled, name, classes };
         Ok((result, remaining))
     }
@@ -9711,30 +12291,56 @@ impl TryFrom<&[u8]> for XIDeviceInfo {
 }
 impl Serialize for XIDeviceInfo {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.deviceid.serialize_into(bytes);
-        u16::from(self.type_).serialize_into(bytes);
-        self.attachment.serialize_into(bytes);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        u16::from(self.type_).serialize_into_with_order(bytes, order);
+        self.attachment.serialize_into_with_order(bytes, order);
         let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
-        num_classes.serialize_into(bytes);
+        num_classes.serialize_into_with_order(bytes, order);
         let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
-        name_len.serialize_into(bytes);
-        self.enabled.serialize_into(bytes);
+        name_len.serialize_into_with_order(bytes, order);
+        self.enabled.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
         bytes.extend_from_slice(&self.name);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
-        self.classes.serialize_into(bytes);
+        self.classes.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the XIQueryDevice request
 pub const XI_QUERY_DEVICE_REQUEST: u8 = 48;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIQueryDeviceRequest {
+    pub deviceid: DeviceId,
+}
+impl XIQueryDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_QUERY_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIQueryDeviceRequest {
+            deviceid,
+        })
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+}
+
 pub fn xi_query_device<Conn, A>(conn: &Conn, deviceid: A) -> Result<Cookie<'_, Conn, XIQueryDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9743,8 +12349,9 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let deviceid_bytes = deviceid.serialize();
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_QUERY_DEVICE_REQUEST,
@@ -9758,9 +12365,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIQueryDeviceRequest {
+    type Reply = XIQueryDeviceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIQueryDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIQueryDeviceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_query_device(conn, self.deviceid)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIQueryDeviceReply {
@@ -9770,14 +12389,14 @@ pub struct XIQueryDeviceReply {
     pub infos: Vec<XIDeviceInfo>,
 }
 impl TryParse for XIQueryDeviceReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_infos, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_infos, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (infos, remaining) = crate::x11_utils::parse_list::<XIDeviceInfo>(remaining, num_infos.try_into().or(Err(ParseError::ParseError))?)?;
+        let (infos, remaining) = crate::x11_utils::parse_list_with_order::<XIDeviceInfo>(remaining, num_infos.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = XIQueryDeviceReply { response_type, sequence, length, infos };
         Ok((result, remaining))
     }
@@ -9788,9 +12407,77 @@ impl TryFrom<&[u8]> for XIQueryDeviceReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for XIQueryDeviceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_infos = u16::try_from(self.infos.len()).expect("`infos` has too many elements");
+        num_infos.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.infos.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the XISetFocus request
 pub const XI_SET_FOCUS_REQUEST: u8 = 49;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XISetFocusRequest {
+    pub window: xproto::Window,
+    pub time: xproto::Timestamp,
+    pub deviceid: DeviceId,
+}
+impl XISetFocusRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_SET_FOCUS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XISetFocusRequest {
+            window,
+            time,
+            deviceid,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+}
+
 pub fn xi_set_focus<Conn, A, B>(conn: &Conn, window: xproto::Window, time: A, deviceid: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9801,10 +12488,11 @@ where
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let time_bytes = time.serialize();
-    let deviceid_bytes = deviceid.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let time_bytes = time.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_SET_FOCUS_REQUEST,
@@ -9826,12 +12514,50 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XISetFocusRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XISetFocusRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_set_focus(conn, self.window, self.time, self.deviceid)
+    }
+}
 
 /// Opcode for the XIGetFocus request
 pub const XI_GET_FOCUS_REQUEST: u8 = 50;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIGetFocusRequest {
+    pub deviceid: DeviceId,
+}
+impl XIGetFocusRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_GET_FOCUS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIGetFocusRequest {
+            deviceid,
+        })
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+}
+
 pub fn xi_get_focus<Conn, A>(conn: &Conn, deviceid: A) -> Result<Cookie<'_, Conn, XIGetFocusReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9840,8 +12566,9 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let deviceid_bytes = deviceid.serialize();
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_GET_FOCUS_REQUEST,
@@ -9855,9 +12582,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIGetFocusRequest {
+    type Reply = XIGetFocusReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIGetFocusRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIGetFocusReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_get_focus(conn, self.deviceid)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIGetFocusReply {
@@ -9867,12 +12606,12 @@ pub struct XIGetFocusReply {
     pub focus: xproto::Window,
 }
 impl TryParse for XIGetFocusReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (focus, remaining) = xproto::Window::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (focus, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = XIGetFocusReply { response_type, sequence, length, focus };
         Ok((result, remaining))
@@ -9884,99 +12623,184 @@ impl TryFrom<&[u8]> for XIGetFocusReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GrabOwner {
-    NoOwner = 0,
-    Owner = 1,
-}
-impl From<GrabOwner> for bool {
-    fn from(input: GrabOwner) -> Self {
-        match input {
-            GrabOwner::NoOwner => false,
-            GrabOwner::Owner => true,
-        }
+impl Serialize for XIGetFocusReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
-impl From<GrabOwner> for u8 {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.focus.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GrabOwner(u8);
+#[allow(non_upper_case_globals)]
+impl GrabOwner {
+    pub const NoOwner: Self = Self(0);
+    pub const Owner: Self = Self(1);
+}
+enum_newtype!(GrabOwner, u8, [NoOwner, Owner]);
+impl From<GrabOwner> for bool {
     fn from(input: GrabOwner) -> Self {
-        match input {
-            GrabOwner::NoOwner => 0,
-            GrabOwner::Owner => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<GrabOwner> for Option<u8> {
     fn from(input: GrabOwner) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<GrabOwner> for u16 {
     fn from(input: GrabOwner) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GrabOwner> for Option<u16> {
     fn from(input: GrabOwner) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<GrabOwner> for u32 {
     fn from(input: GrabOwner) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GrabOwner> for Option<u32> {
     fn from(input: GrabOwner) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for GrabOwner {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(GrabOwner::NoOwner),
-            1 => Ok(GrabOwner::Owner),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for GrabOwner {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for GrabOwner {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
 /// Opcode for the XIGrabDevice request
 pub const XI_GRAB_DEVICE_REQUEST: u8 = 51;
-pub fn xi_grab_device<'c, Conn, A, B>(conn: &'c Conn, window: xproto::Window, time: A, cursor: xproto::Cursor, deviceid: B, mode: xproto::GrabMode, paired_device_mode: xproto::GrabMode, owner_events: GrabOwner, mask: &[u32]) -> Result<Cookie<'c, Conn, XIGrabDeviceReply>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct XIGrabDeviceRequest {
+    pub window: xproto::Window,
+    pub time: xproto::Timestamp,
+    pub cursor: xproto::Cursor,
+    pub deviceid: DeviceId,
+    pub mode: xproto::GrabMode,
+    pub paired_device_mode: xproto::GrabMode,
+    pub owner_events: bool,
+    pub mask: Vec<u32>,
+}
+impl XIGrabDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_GRAB_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (cursor, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (paired_device_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (owner_events, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (mask_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, mask_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let mode = xproto::GrabMode::from(mode);
+        let paired_device_mode = xproto::GrabMode::from(paired_device_mode);
+        Ok(XIGrabDeviceRequest {
+            window,
+            time,
+            cursor,
+            deviceid,
+            mode,
+            paired_device_mode,
+            owner_events,
+            mask,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+    /// Set the `cursor` field of this structure.
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.cursor = value.into();
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<xproto::GrabMode> {
+        self.mode = value.into();
+        self
+    }
+    /// Set the `paired_device_mode` field of this structure.
+    pub fn paired_device_mode<I>(mut self, value: I) -> Self where I: Into<xproto::GrabMode> {
+        self.paired_device_mode = value.into();
+        self
+    }
+    /// Set the `owner_events` field of this structure.
+    pub fn owner_events(mut self, value: bool) -> Self {
+        self.owner_events = value;
+        self
+    }
+    /// Set the `mask` field of this structure.
+    pub fn mask<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.mask = value.into();
+        self
+    }
+}
+
+pub fn xi_grab_device<'c, Conn, A, B, C, D, E>(conn: &'c Conn, window: xproto::Window, time: A, cursor: xproto::Cursor, deviceid: B, mode: C, paired_device_mode: D, owner_events: E, mask: &[u32]) -> Result<Cookie<'c, Conn, XIGrabDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
     A: Into<xproto::Timestamp>,
     B: Into<DeviceId>,
+    C: Into<xproto::GrabMode>,
+    D: Into<xproto::GrabMode>,
+    E: Into<bool>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
     let deviceid: DeviceId = deviceid.into();
+    let mode: xproto::GrabMode = mode.into();
+    let paired_device_mode: xproto::GrabMode = paired_device_mode.into();
+    let owner_events: bool = owner_events.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let time_bytes = time.serialize();
-    let cursor_bytes = cursor.serialize();
-    let deviceid_bytes = deviceid.serialize();
-    let mode_bytes = u8::from(mode).serialize();
-    let paired_device_mode_bytes = u8::from(paired_device_mode).serialize();
-    let owner_events_bytes = bool::from(owner_events).serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let time_bytes = time.serialize_with_order(order);
+    let cursor_bytes = cursor.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
+    let mode_bytes = u8::from(mode).serialize_with_order(order);
+    let paired_device_mode_bytes = u8::from(paired_device_mode).serialize_with_order(order);
+    let owner_events_bytes = owner_events.serialize_with_order(order);
     let mask_len = u16::try_from(mask.len()).expect("`mask` has too many elements");
-    let mask_len_bytes = mask_len.serialize();
+    let mask_len_bytes = mask_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_GRAB_DEVICE_REQUEST,
@@ -10004,32 +12828,44 @@ where
         mask_len_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let mask_bytes = mask.serialize();
+    let mask_bytes = mask.serialize_with_order(order);
     let length_so_far = length_so_far + mask_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&mask_bytes), IoSlice::new(&padding0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct XIGrabDeviceReply {
-    pub response_type: u8,
+impl crate::x11_utils::Request for XIGrabDeviceRequest {
+    type Reply = XIGrabDeviceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIGrabDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIGrabDeviceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_grab_device(conn, self.window, self.time, self.cursor, self.deviceid, self.mode, self.paired_device_mode, self.owner_events, &self.mask)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XIGrabDeviceReply {
+    pub response_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub status: xproto::GrabStatus,
 }
 impl TryParse for XIGrabDeviceReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::GrabStatus::from(status);
         let result = XIGrabDeviceReply { response_type, sequence, length, status };
         Ok((result, remaining))
     }
@@ -10040,9 +12876,67 @@ impl TryFrom<&[u8]> for XIGrabDeviceReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for XIGrabDeviceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the XIUngrabDevice request
 pub const XI_UNGRAB_DEVICE_REQUEST: u8 = 52;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIUngrabDeviceRequest {
+    pub time: xproto::Timestamp,
+    pub deviceid: DeviceId,
+}
+impl XIUngrabDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_UNGRAB_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIUngrabDeviceRequest {
+            time,
+            deviceid,
+        })
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+}
+
 pub fn xi_ungrab_device<Conn, A, B>(conn: &Conn, time: A, deviceid: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10053,9 +12947,10 @@ where
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let time_bytes = time.serialize();
-    let deviceid_bytes = deviceid.serialize();
+    let time_bytes = time.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_UNGRAB_DEVICE_REQUEST,
@@ -10073,108 +12968,142 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum EventMode {
-    AsyncDevice = 0,
-    SyncDevice = 1,
-    ReplayDevice = 2,
-    AsyncPairedDevice = 3,
-    AsyncPair = 4,
-    SyncPair = 5,
-    AcceptTouch = 6,
-    RejectTouch = 7,
-}
-impl From<EventMode> for u8 {
-    fn from(input: EventMode) -> Self {
-        match input {
-            EventMode::AsyncDevice => 0,
-            EventMode::SyncDevice => 1,
-            EventMode::ReplayDevice => 2,
-            EventMode::AsyncPairedDevice => 3,
-            EventMode::AsyncPair => 4,
-            EventMode::SyncPair => 5,
-            EventMode::AcceptTouch => 6,
-            EventMode::RejectTouch => 7,
-        }
-    }
+impl crate::x11_utils::Request for XIUngrabDeviceRequest {
+    type Reply = ();
 }
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIUngrabDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_ungrab_device(conn, self.time, self.deviceid)
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EventMode(u8);
+#[allow(non_upper_case_globals)]
+impl EventMode {
+    pub const AsyncDevice: Self = Self(0);
+    pub const SyncDevice: Self = Self(1);
+    pub const ReplayDevice: Self = Self(2);
+    pub const AsyncPairedDevice: Self = Self(3);
+    pub const AsyncPair: Self = Self(4);
+    pub const SyncPair: Self = Self(5);
+    pub const AcceptTouch: Self = Self(6);
+    pub const RejectTouch: Self = Self(7);
+}
+enum_newtype!(EventMode, u8, [AsyncDevice, SyncDevice, ReplayDevice, AsyncPairedDevice, AsyncPair, SyncPair, AcceptTouch, RejectTouch]);
 impl From<EventMode> for Option<u8> {
     fn from(input: EventMode) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<EventMode> for u16 {
     fn from(input: EventMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<EventMode> for Option<u16> {
     fn from(input: EventMode) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<EventMode> for u32 {
     fn from(input: EventMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<EventMode> for Option<u32> {
     fn from(input: EventMode) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for EventMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(EventMode::AsyncDevice),
-            1 => Ok(EventMode::SyncDevice),
-            2 => Ok(EventMode::ReplayDevice),
-            3 => Ok(EventMode::AsyncPairedDevice),
-            4 => Ok(EventMode::AsyncPair),
-            5 => Ok(EventMode::SyncPair),
-            6 => Ok(EventMode::AcceptTouch),
-            7 => Ok(EventMode::RejectTouch),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for EventMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for EventMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
 /// Opcode for the XIAllowEvents request
 pub const XI_ALLOW_EVENTS_REQUEST: u8 = 53;
-pub fn xi_allow_events<Conn, A, B>(conn: &Conn, time: A, deviceid: B, event_mode: EventMode, touchid: u32, grab_window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIAllowEventsRequest {
+    pub time: xproto::Timestamp,
+    pub deviceid: DeviceId,
+    pub event_mode: EventMode,
+    pub touchid: u32,
+    pub grab_window: xproto::Window,
+}
+impl XIAllowEventsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_ALLOW_EVENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (event_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (touchid, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (grab_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let event_mode = EventMode::from(event_mode);
+        Ok(XIAllowEventsRequest {
+            time,
+            deviceid,
+            event_mode,
+            touchid,
+            grab_window,
+        })
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+    /// Set the `event_mode` field of this structure.
+    pub fn event_mode<I>(mut self, value: I) -> Self where I: Into<EventMode> {
+        self.event_mode = value.into();
+        self
+    }
+    /// Set the `touchid` field of this structure.
+    pub fn touchid(mut self, value: u32) -> Self {
+        self.touchid = value;
+        self
+    }
+    /// Set the `grab_window` field of this structure.
+    pub fn grab_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.grab_window = value.into();
+        self
+    }
+}
+
+pub fn xi_allow_events<Conn, A, B, C>(conn: &Conn, time: A, deviceid: B, event_mode: C, touchid: u32, grab_window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
     A: Into<xproto::Timestamp>,
     B: Into<DeviceId>,
+    C: Into<EventMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
     let deviceid: DeviceId = deviceid.into();
+    let event_mode: EventMode = event_mode.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let time_bytes = time.serialize();
-    let deviceid_bytes = deviceid.serialize();
-    let event_mode_bytes = u8::from(event_mode).serialize();
-    let touchid_bytes = touchid.serialize();
-    let grab_window_bytes = grab_window.serialize();
+    let time_bytes = time.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
+    let event_mode_bytes = u8::from(event_mode).serialize_with_order(order);
+    let touchid_bytes = touchid.serialize_with_order(order);
+    let grab_window_bytes = grab_window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_ALLOW_EVENTS_REQUEST,
@@ -10200,173 +13129,106 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GrabMode22 {
-    Sync = 0,
-    Async = 1,
-    Touch = 2,
+impl crate::x11_utils::Request for XIAllowEventsRequest {
+    type Reply = ();
 }
-impl From<GrabMode22> for u8 {
-    fn from(input: GrabMode22) -> Self {
-        match input {
-            GrabMode22::Sync => 0,
-            GrabMode22::Async => 1,
-            GrabMode22::Touch => 2,
-        }
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIAllowEventsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_allow_events(conn, self.time, self.deviceid, self.event_mode, self.touchid, self.grab_window)
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GrabMode22(u8);
+#[allow(non_upper_case_globals)]
+impl GrabMode22 {
+    pub const Sync: Self = Self(0);
+    pub const Async: Self = Self(1);
+    pub const Touch: Self = Self(2);
+}
+enum_newtype!(GrabMode22, u8, [Sync, Async, Touch]);
 impl From<GrabMode22> for Option<u8> {
     fn from(input: GrabMode22) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<GrabMode22> for u16 {
     fn from(input: GrabMode22) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GrabMode22> for Option<u16> {
     fn from(input: GrabMode22) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<GrabMode22> for u32 {
     fn from(input: GrabMode22) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GrabMode22> for Option<u32> {
     fn from(input: GrabMode22) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for GrabMode22 {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(GrabMode22::Sync),
-            1 => Ok(GrabMode22::Async),
-            2 => Ok(GrabMode22::Touch),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for GrabMode22 {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for GrabMode22 {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GrabType {
-    Button = 0,
-    Keycode = 1,
-    Enter = 2,
-    FocusIn = 3,
-    TouchBegin = 4,
-}
-impl From<GrabType> for u8 {
-    fn from(input: GrabType) -> Self {
-        match input {
-            GrabType::Button => 0,
-            GrabType::Keycode => 1,
-            GrabType::Enter => 2,
-            GrabType::FocusIn => 3,
-            GrabType::TouchBegin => 4,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GrabType(u8);
+#[allow(non_upper_case_globals)]
+impl GrabType {
+    pub const Button: Self = Self(0);
+    pub const Keycode: Self = Self(1);
+    pub const Enter: Self = Self(2);
+    pub const FocusIn: Self = Self(3);
+    pub const TouchBegin: Self = Self(4);
 }
+enum_newtype!(GrabType, u8, [Button, Keycode, Enter, FocusIn, TouchBegin]);
 impl From<GrabType> for Option<u8> {
     fn from(input: GrabType) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<GrabType> for u16 {
     fn from(input: GrabType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GrabType> for Option<u16> {
     fn from(input: GrabType) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<GrabType> for u32 {
     fn from(input: GrabType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GrabType> for Option<u32> {
     fn from(input: GrabType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for GrabType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(GrabType::Button),
-            1 => Ok(GrabType::Keycode),
-            2 => Ok(GrabType::Enter),
-            3 => Ok(GrabType::FocusIn),
-            4 => Ok(GrabType::TouchBegin),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for GrabType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for GrabType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
-pub enum ModifierMask {
-    Any = 1 << 31,
-}
-impl From<ModifierMask> for u32 {
-    fn from(input: ModifierMask) -> Self {
-        match input {
-            ModifierMask::Any => 1 << 31,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModifierMask(u32);
+#[allow(non_upper_case_globals)]
+impl ModifierMask {
+    pub const Any: Self = Self(1 << 31);
 }
+bitmask_set!(ModifierMask, u32, [Any]);
 impl From<ModifierMask> for Option<u32> {
     fn from(input: ModifierMask) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u32> for ModifierMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            2_147_483_648 => Ok(ModifierMask::Any),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(input.0)
     }
 }
-bitmask_binop!(ModifierMask, u32);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GrabModifierInfo {
@@ -10374,11 +13236,11 @@ pub struct GrabModifierInfo {
     pub status: xproto::GrabStatus,
 }
 impl TryParse for GrabModifierInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (modifiers, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (modifiers, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let status = status.try_into()?;
+        let status = xproto::GrabStatus::from(status);
         let result = GrabModifierInfo { modifiers, status };
         Ok((result, remaining))
     }
@@ -10391,9 +13253,9 @@ impl TryFrom<&[u8]> for GrabModifierInfo {
 }
 impl Serialize for GrabModifierInfo {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let modifiers_bytes = self.modifiers.serialize();
-        let status_bytes = u8::from(self.status).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let modifiers_bytes = self.modifiers.serialize_with_order(order);
+        let status_bytes = u8::from(self.status).serialize_with_order(order);
         [
             modifiers_bytes[0],
             modifiers_bytes[1],
@@ -10405,40 +13267,160 @@ impl Serialize for GrabModifierInfo {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.modifiers.serialize_into(bytes);
-        u8::from(self.status).serialize_into(bytes);
+        self.modifiers.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
 
 /// Opcode for the XIPassiveGrabDevice request
 pub const XI_PASSIVE_GRAB_DEVICE_REQUEST: u8 = 54;
-pub fn xi_passive_grab_device<'c, Conn, A, B>(conn: &'c Conn, time: A, grab_window: xproto::Window, cursor: xproto::Cursor, detail: u32, deviceid: B, grab_type: GrabType, grab_mode: GrabMode22, paired_device_mode: xproto::GrabMode, owner_events: GrabOwner, mask: &[u32], modifiers: &[u32]) -> Result<Cookie<'c, Conn, XIPassiveGrabDeviceReply>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct XIPassiveGrabDeviceRequest {
+    pub time: xproto::Timestamp,
+    pub grab_window: xproto::Window,
+    pub cursor: xproto::Cursor,
+    pub detail: u32,
+    pub deviceid: DeviceId,
+    pub grab_type: GrabType,
+    pub grab_mode: GrabMode22,
+    pub paired_device_mode: xproto::GrabMode,
+    pub owner_events: bool,
+    pub mask: Vec<u32>,
+    pub modifiers: Vec<u32>,
+}
+impl XIPassiveGrabDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_PASSIVE_GRAB_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (grab_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (cursor, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (num_modifiers, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mask_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (grab_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (grab_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (paired_device_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (owner_events, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, mask_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (modifiers, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_modifiers.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let grab_type = GrabType::from(grab_type);
+        let grab_mode = GrabMode22::from(grab_mode);
+        let paired_device_mode = xproto::GrabMode::from(paired_device_mode);
+        Ok(XIPassiveGrabDeviceRequest {
+            time,
+            grab_window,
+            cursor,
+            detail,
+            deviceid,
+            grab_type,
+            grab_mode,
+            paired_device_mode,
+            owner_events,
+            mask,
+            modifiers,
+        })
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+    /// Set the `grab_window` field of this structure.
+    pub fn grab_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.grab_window = value.into();
+        self
+    }
+    /// Set the `cursor` field of this structure.
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.cursor = value.into();
+        self
+    }
+    /// Set the `detail` field of this structure.
+    pub fn detail(mut self, value: u32) -> Self {
+        self.detail = value;
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+    /// Set the `grab_type` field of this structure.
+    pub fn grab_type<I>(mut self, value: I) -> Self where I: Into<GrabType> {
+        self.grab_type = value.into();
+        self
+    }
+    /// Set the `grab_mode` field of this structure.
+    pub fn grab_mode<I>(mut self, value: I) -> Self where I: Into<GrabMode22> {
+        self.grab_mode = value.into();
+        self
+    }
+    /// Set the `paired_device_mode` field of this structure.
+    pub fn paired_device_mode<I>(mut self, value: I) -> Self where I: Into<xproto::GrabMode> {
+        self.paired_device_mode = value.into();
+        self
+    }
+    /// Set the `owner_events` field of this structure.
+    pub fn owner_events(mut self, value: bool) -> Self {
+        self.owner_events = value;
+        self
+    }
+    /// Set the `mask` field of this structure.
+    pub fn mask<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.mask = value.into();
+        self
+    }
+    /// Set the `modifiers` field of this structure.
+    pub fn modifiers<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.modifiers = value.into();
+        self
+    }
+}
+
+pub fn xi_passive_grab_device<'c, Conn, A, B, C, D, E, F>(conn: &'c Conn, time: A, grab_window: xproto::Window, cursor: xproto::Cursor, detail: u32, deviceid: B, grab_type: C, grab_mode: D, paired_device_mode: E, owner_events: F, mask: &[u32], modifiers: &[u32]) -> Result<Cookie<'c, Conn, XIPassiveGrabDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
     A: Into<xproto::Timestamp>,
     B: Into<DeviceId>,
+    C: Into<GrabType>,
+    D: Into<GrabMode22>,
+    E: Into<xproto::GrabMode>,
+    F: Into<bool>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
     let deviceid: DeviceId = deviceid.into();
+    let grab_type: GrabType = grab_type.into();
+    let grab_mode: GrabMode22 = grab_mode.into();
+    let paired_device_mode: xproto::GrabMode = paired_device_mode.into();
+    let owner_events: bool = owner_events.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let time_bytes = time.serialize();
-    let grab_window_bytes = grab_window.serialize();
-    let cursor_bytes = cursor.serialize();
-    let detail_bytes = detail.serialize();
-    let deviceid_bytes = deviceid.serialize();
+    let time_bytes = time.serialize_with_order(order);
+    let grab_window_bytes = grab_window.serialize_with_order(order);
+    let cursor_bytes = cursor.serialize_with_order(order);
+    let detail_bytes = detail.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let num_modifiers = u16::try_from(modifiers.len()).expect("`modifiers` has too many elements");
-    let num_modifiers_bytes = num_modifiers.serialize();
+    let num_modifiers_bytes = num_modifiers.serialize_with_order(order);
     let mask_len = u16::try_from(mask.len()).expect("`mask` has too many elements");
-    let mask_len_bytes = mask_len.serialize();
-    let grab_type_bytes = u8::from(grab_type).serialize();
-    let grab_mode_bytes = u8::from(grab_mode).serialize();
-    let paired_device_mode_bytes = u8::from(paired_device_mode).serialize();
-    let owner_events_bytes = bool::from(owner_events).serialize();
+    let mask_len_bytes = mask_len.serialize_with_order(order);
+    let grab_type_bytes = u8::from(grab_type).serialize_with_order(order);
+    let grab_mode_bytes = u8::from(grab_mode).serialize_with_order(order);
+    let paired_device_mode_bytes = u8::from(paired_device_mode).serialize_with_order(order);
+    let owner_events_bytes = owner_events.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_PASSIVE_GRAB_DEVICE_REQUEST,
@@ -10474,17 +13456,29 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let mask_bytes = mask.serialize();
+    let mask_bytes = mask.serialize_with_order(order);
     let length_so_far = length_so_far + mask_bytes.len();
-    let modifiers_bytes = modifiers.serialize();
+    let modifiers_bytes = modifiers.serialize_with_order(order);
     let length_so_far = length_so_far + modifiers_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(&mask_bytes), IoSlice::new(&modifiers_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIPassiveGrabDeviceRequest {
+    type Reply = XIPassiveGrabDeviceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIPassiveGrabDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIPassiveGrabDeviceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_passive_grab_device(conn, self.time, self.grab_window, self.cursor, self.detail, self.deviceid, self.grab_type, self.grab_mode, self.paired_device_mode, self.owner_events, &self.mask, &self.modifiers)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIPassiveGrabDeviceReply {
@@ -10494,14 +13488,14 @@ pub struct XIPassiveGrabDeviceReply {
     pub modifiers: Vec<GrabModifierInfo>,
 }
 impl TryParse for XIPassiveGrabDeviceReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_modifiers, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_modifiers, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (modifiers, remaining) = crate::x11_utils::parse_list::<GrabModifierInfo>(remaining, num_modifiers.try_into().or(Err(ParseError::ParseError))?)?;
+        let (modifiers, remaining) = crate::x11_utils::parse_list_with_order::<GrabModifierInfo>(remaining, num_modifiers.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = XIPassiveGrabDeviceReply { response_type, sequence, length, modifiers };
         Ok((result, remaining))
     }
@@ -10512,24 +13506,113 @@ impl TryFrom<&[u8]> for XIPassiveGrabDeviceReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for XIPassiveGrabDeviceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_modifiers = u16::try_from(self.modifiers.len()).expect("`modifiers` has too many elements");
+        num_modifiers.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.modifiers.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the XIPassiveUngrabDevice request
 pub const XI_PASSIVE_UNGRAB_DEVICE_REQUEST: u8 = 55;
-pub fn xi_passive_ungrab_device<'c, Conn, A>(conn: &'c Conn, grab_window: xproto::Window, detail: u32, deviceid: A, grab_type: GrabType, modifiers: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct XIPassiveUngrabDeviceRequest {
+    pub grab_window: xproto::Window,
+    pub detail: u32,
+    pub deviceid: DeviceId,
+    pub grab_type: GrabType,
+    pub modifiers: Vec<u32>,
+}
+impl XIPassiveUngrabDeviceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_PASSIVE_UNGRAB_DEVICE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (grab_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (num_modifiers, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (grab_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (modifiers, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_modifiers.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let grab_type = GrabType::from(grab_type);
+        Ok(XIPassiveUngrabDeviceRequest {
+            grab_window,
+            detail,
+            deviceid,
+            grab_type,
+            modifiers,
+        })
+    }
+    /// Set the `grab_window` field of this structure.
+    pub fn grab_window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.grab_window = value.into();
+        self
+    }
+    /// Set the `detail` field of this structure.
+    pub fn detail(mut self, value: u32) -> Self {
+        self.detail = value;
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+    /// Set the `grab_type` field of this structure.
+    pub fn grab_type<I>(mut self, value: I) -> Self where I: Into<GrabType> {
+        self.grab_type = value.into();
+        self
+    }
+    /// Set the `modifiers` field of this structure.
+    pub fn modifiers<I>(mut self, value: I) -> Self where I: Into<Vec<u32>> {
+        self.modifiers = value.into();
+        self
+    }
+}
+
+pub fn xi_passive_ungrab_device<'c, Conn, A, B>(conn: &'c Conn, grab_window: xproto::Window, detail: u32, deviceid: A, grab_type: B, modifiers: &[u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
     A: Into<DeviceId>,
+    B: Into<GrabType>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let grab_type: GrabType = grab_type.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let grab_window_bytes = grab_window.serialize();
-    let detail_bytes = detail.serialize();
-    let deviceid_bytes = deviceid.serialize();
+    let grab_window_bytes = grab_window.serialize_with_order(order);
+    let detail_bytes = detail.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let num_modifiers = u16::try_from(modifiers.len()).expect("`modifiers` has too many elements");
-    let num_modifiers_bytes = num_modifiers.serialize();
-    let grab_type_bytes = u8::from(grab_type).serialize();
+    let num_modifiers_bytes = num_modifiers.serialize_with_order(order);
+    let grab_type_bytes = u8::from(grab_type).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_PASSIVE_UNGRAB_DEVICE_REQUEST,
@@ -10553,18 +13636,56 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let modifiers_bytes = modifiers.serialize();
+    let modifiers_bytes = modifiers.serialize_with_order(order);
     let length_so_far = length_so_far + modifiers_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&modifiers_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIPassiveUngrabDeviceRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIPassiveUngrabDeviceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_passive_ungrab_device(conn, self.grab_window, self.detail, self.deviceid, self.grab_type, &self.modifiers)
+    }
+}
 
 /// Opcode for the XIListProperties request
 pub const XI_LIST_PROPERTIES_REQUEST: u8 = 56;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIListPropertiesRequest {
+    pub deviceid: DeviceId,
+}
+impl XIListPropertiesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_LIST_PROPERTIES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIListPropertiesRequest {
+            deviceid,
+        })
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+}
+
 pub fn xi_list_properties<Conn, A>(conn: &Conn, deviceid: A) -> Result<Cookie<'_, Conn, XIListPropertiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10573,8 +13694,9 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let deviceid_bytes = deviceid.serialize();
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_LIST_PROPERTIES_REQUEST,
@@ -10588,9 +13710,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIListPropertiesRequest {
+    type Reply = XIListPropertiesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIListPropertiesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIListPropertiesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_list_properties(conn, self.deviceid)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIListPropertiesReply {
@@ -10600,14 +13734,14 @@ pub struct XIListPropertiesReply {
     pub properties: Vec<xproto::Atom>,
 }
 impl TryParse for XIListPropertiesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_properties, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_properties, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (properties, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_properties.try_into().or(Err(ParseError::ParseError))?)?;
+        let (properties, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, num_properties.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = XIListPropertiesReply { response_type, sequence, length, properties };
         Ok((result, remaining))
     }
@@ -10618,6 +13752,32 @@ impl TryFrom<&[u8]> for XIListPropertiesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for XIListPropertiesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_properties = u16::try_from(self.properties.len()).expect("`properties` has too many elements");
+        num_properties.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.properties.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the XIChangeProperty request
 pub const XI_CHANGE_PROPERTY_REQUEST: u8 = 57;
@@ -10627,6 +13787,49 @@ pub enum XIChangePropertyAux {
     Data16(Vec<u16>),
     Data32(Vec<u32>),
 }
+impl XIChangePropertyAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, format: PropertyFormat, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(format);
+        let mut outer_remaining = value;
+        let mut parse_result = None;
+        if switch_expr == u32::from(PropertyFormat::M8Bits) {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_into().or(Err(ParseError::ParseError))?)?;
+            let data8 = data8.to_vec();
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+            outer_remaining = remaining;
+            assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
+            parse_result = Some(XIChangePropertyAux::Data8(data8));
+        }
+        if switch_expr == u32::from(PropertyFormat::M16Bits) {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (data16, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?, order)?;
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+            outer_remaining = remaining;
+            assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
+            parse_result = Some(XIChangePropertyAux::Data16(data16));
+        }
+        if switch_expr == u32::from(PropertyFormat::M32Bits) {
+            let remaining = outer_remaining;
+            let (data32, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
+            parse_result = Some(XIChangePropertyAux::Data32(data32));
+        }
+        match parse_result {
+            None => Err(ParseError::ParseError),
+            Some(result) => Ok((result, outer_remaining)),
+        }
+    }
+}
 impl XIChangePropertyAux {
     pub fn as_data8(&self) -> Option<&Vec<u8>> {
         match self {
@@ -10649,12 +13852,12 @@ impl XIChangePropertyAux {
 }
 #[allow(dead_code, unused_variables)]
 impl XIChangePropertyAux {
-    fn serialize(&self, format: u8, num_items: u32) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, format: PropertyFormat, num_items: u32) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, format, num_items);
+        self.serialize_into_with_order(&mut result, order, format, num_items);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, format: u8, num_items: u32) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, format: PropertyFormat, num_items: u32) {
         match self {
             XIChangePropertyAux::Data8(data8) => {
                 assert_eq!(data8.len(), usize::try_from(num_items).unwrap(), "`data8` has an incorrect length");
@@ -10663,12 +13866,12 @@ impl XIChangePropertyAux {
             }
             XIChangePropertyAux::Data16(data16) => {
                 assert_eq!(data16.len(), usize::try_from(num_items).unwrap(), "`data16` has an incorrect length");
-                data16.serialize_into(bytes);
+                data16.serialize_into_with_order(bytes, order);
                 bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
             }
             XIChangePropertyAux::Data32(data32) => {
                 assert_eq!(data32.len(), usize::try_from(num_items).unwrap(), "`data32` has an incorrect length");
-                data32.serialize_into(bytes);
+                data32.serialize_into_with_order(bytes, order);
             }
         }
     }
@@ -10683,22 +13886,92 @@ impl XIChangePropertyAux {
     }
 }
 
-pub fn xi_change_property<'c, Conn, A>(conn: &'c Conn, deviceid: A, mode: xproto::PropMode, property: xproto::Atom, type_: xproto::Atom, num_items: u32, items: &XIChangePropertyAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XIChangePropertyRequest {
+    pub deviceid: DeviceId,
+    pub mode: xproto::PropMode,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub num_items: u32,
+    pub items: XIChangePropertyAux,
+}
+impl XIChangePropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_CHANGE_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (items, remaining) = XIChangePropertyAux::try_parse_with_order(remaining, order, PropertyFormat::from(format), num_items)?;
+        let _ = remaining;
+        let _ = order;
+        let mode = xproto::PropMode::from(mode);
+        Ok(XIChangePropertyRequest {
+            deviceid,
+            mode,
+            property,
+            type_,
+            num_items,
+            items,
+        })
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+    /// Set the `mode` field of this structure.
+    pub fn mode<I>(mut self, value: I) -> Self where I: Into<xproto::PropMode> {
+        self.mode = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.type_ = value.into();
+        self
+    }
+    /// Set the `num_items` field of this structure.
+    pub fn num_items(mut self, value: u32) -> Self {
+        self.num_items = value;
+        self
+    }
+    /// Set the `items` field of this structure.
+    pub fn items<I>(mut self, value: I) -> Self where I: Into<XIChangePropertyAux> {
+        self.items = value.into();
+        self
+    }
+}
+
+pub fn xi_change_property<'c, Conn, A, B>(conn: &'c Conn, deviceid: A, mode: B, property: xproto::Atom, type_: xproto::Atom, num_items: u32, items: &XIChangePropertyAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
     A: Into<DeviceId>,
+    B: Into<xproto::PropMode>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let mode: xproto::PropMode = mode.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let deviceid_bytes = deviceid.serialize();
-    let mode_bytes = u8::from(mode).serialize();
+    let deviceid_bytes = deviceid.serialize_with_order(order);
+    let mode_bytes = u8::from(mode).serialize_with_order(order);
     let format = u8::try_from(items.switch_expr()).unwrap();
-    let format_bytes = format.serialize();
-    let property_bytes = property.serialize();
-    let type_bytes = type_.serialize();
-    let num_items_bytes = num_items.serialize();
+    let format_bytes = format.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let num_items_bytes = num_items.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_CHANGE_PROPERTY_REQUEST,
@@ -10722,18 +13995,64 @@ where
         num_items_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let items_bytes = items.serialize(format, num_items);
+    let items_bytes = items.serialize_with_order(order, PropertyFormat::from(format), num_items);
     let length_so_far = length_so_far + items_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&items_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIChangePropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIChangePropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_change_property(conn, self.deviceid, self.mode, self.property, self.type_, self.num_items, &self.items)
+    }
+}
 
 /// Opcode for the XIDeleteProperty request
 pub const XI_DELETE_PROPERTY_REQUEST: u8 = 58;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIDeletePropertyRequest {
+    pub deviceid: DeviceId,
+    pub property: xproto::Atom,
+}
+impl XIDeletePropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_DELETE_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIDeletePropertyRequest {
+            deviceid,
+            property,
+        })
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+}
+
 pub fn xi_delete_property<Conn, A>(conn: &Conn, deviceid: A, property: xproto::Atom) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10742,9 +14061,10 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let deviceid_bytes = deviceid.serialize();
-    let property_bytes = property.serialize();
+    let deviceid_bytes = deviceid.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_DELETE_PROPERTY_REQUEST,
@@ -10762,12 +14082,90 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIDeletePropertyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIDeletePropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_delete_property(conn, self.deviceid, self.property)
+    }
+}
 
 /// Opcode for the XIGetProperty request
 pub const XI_GET_PROPERTY_REQUEST: u8 = 59;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIGetPropertyRequest {
+    pub deviceid: DeviceId,
+    pub delete: bool,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub offset: u32,
+    pub len: u32,
+}
+impl XIGetPropertyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_GET_PROPERTY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (delete, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIGetPropertyRequest {
+            deviceid,
+            delete,
+            property,
+            type_,
+            offset,
+            len,
+        })
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid<I>(mut self, value: I) -> Self where I: Into<DeviceId> {
+        self.deviceid = value.into();
+        self
+    }
+    /// Set the `delete` field of this structure.
+    pub fn delete(mut self, value: bool) -> Self {
+        self.delete = value;
+        self
+    }
+    /// Set the `property` field of this structure.
+    pub fn property<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.property = value.into();
+        self
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.type_ = value.into();
+        self
+    }
+    /// Set the `offset` field of this structure.
+    pub fn offset(mut self, value: u32) -> Self {
+        self.offset = value;
+        self
+    }
+    /// Set the `len` field of this structure.
+    pub fn len(mut self, value: u32) -> Self {
+        self.len = value;
+        self
+    }
+}
+
 pub fn xi_get_property<Conn, A>(conn: &Conn, deviceid: A, delete: bool, property: xproto::Atom, type_: xproto::Atom, offset: u32, len: u32) -> Result<Cookie<'_, Conn, XIGetPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10776,13 +14174,14 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let deviceid: DeviceId = deviceid.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let deviceid_bytes = deviceid.serialize();
-    let delete_bytes = delete.serialize();
-    let property_bytes = property.serialize();
-    let type_bytes = type_.serialize();
-    let offset_bytes = offset.serialize();
-    let len_bytes = len.serialize();
+    let deviceid_bytes = deviceid.serialize_with_order(order);
+    let delete_bytes = delete.serialize_with_order(order);
+    let property_bytes = property.serialize_with_order(order);
+    let type_bytes = type_.serialize_with_order(order);
+    let offset_bytes = offset.serialize_with_order(order);
+    let len_bytes = len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_GET_PROPERTY_REQUEST,
@@ -10812,9 +14211,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIGetPropertyRequest {
+    type Reply = XIGetPropertyReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIGetPropertyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIGetPropertyReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_get_property(conn, self.deviceid, self.delete, self.property, self.type_, self.offset, self.len)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum XIGetPropertyItems {
@@ -10823,7 +14234,7 @@ pub enum XIGetPropertyItems {
     Data32(Vec<u32>),
 }
 impl XIGetPropertyItems {
-    fn try_parse(value: &[u8], format: u8, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, format: PropertyFormat, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(format);
         let mut outer_remaining = value;
         let mut parse_result = None;
@@ -10843,7 +14254,7 @@ impl XIGetPropertyItems {
         if switch_expr == u32::from(PropertyFormat::M16Bits) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?)?;
+            let (data16, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?, order)?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
@@ -10854,7 +14265,7 @@ impl XIGetPropertyItems {
         }
         if switch_expr == u32::from(PropertyFormat::M32Bits) {
             let remaining = outer_remaining;
-            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?)?;
+            let (data32, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_items.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIGetPropertyItems::Data32(data32));
@@ -10885,6 +14296,41 @@ impl XIGetPropertyItems {
         }
     }
 }
+#[allow(dead_code, unused_variables)]
+impl XIGetPropertyItems {
+    fn serialize_with_order(&self, order: ByteOrder, format: PropertyFormat, num_items: u32) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, format, num_items);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, format: PropertyFormat, num_items: u32) {
+        match self {
+            XIGetPropertyItems::Data8(data8) => {
+                assert_eq!(data8.len(), usize::try_from(num_items).unwrap(), "`data8` has an incorrect length");
+                bytes.extend_from_slice(&data8);
+                bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+            }
+            XIGetPropertyItems::Data16(data16) => {
+                assert_eq!(data16.len(), usize::try_from(num_items).unwrap(), "`data16` has an incorrect length");
+                data16.serialize_into_with_order(bytes, order);
+                bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+            }
+            XIGetPropertyItems::Data32(data32) => {
+                assert_eq!(data32.len(), usize::try_from(num_items).unwrap(), "`data32` has an incorrect length");
+                data32.serialize_into_with_order(bytes, order);
+            }
+        }
+    }
+}
+impl XIGetPropertyItems {
+    fn switch_expr(&self) -> u32 {
+        match self {
+            XIGetPropertyItems::Data8(_) => u32::from(PropertyFormat::M8Bits),
+            XIGetPropertyItems::Data16(_) => u32::from(PropertyFormat::M16Bits),
+            XIGetPropertyItems::Data32(_) => u32::from(PropertyFormat::M32Bits),
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIGetPropertyReply {
@@ -10897,17 +14343,17 @@ pub struct XIGetPropertyReply {
     pub items: XIGetPropertyItems,
 }
 impl TryParse for XIGetPropertyReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (bytes_after, remaining) = u32::try_parse(remaining)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (bytes_after, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(11..).ok_or(ParseError::ParseError)?;
-        let (items, remaining) = XIGetPropertyItems::try_parse(remaining, format, num_items)?;
+        let (items, remaining) = XIGetPropertyItems::try_parse_with_order(remaining, order, PropertyFormat::from(format), num_items)?;
         let result = XIGetPropertyReply { response_type, sequence, length, type_, bytes_after, num_items, items };
         Ok((result, remaining))
     }
@@ -10918,17 +14364,73 @@ impl TryFrom<&[u8]> for XIGetPropertyReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for XIGetPropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        let num_items = self.num_items;
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.bytes_after.serialize_into_with_order(bytes, order);
+        self.num_items.serialize_into_with_order(bytes, order);
+        let format = u8::try_from(self.items.switch_expr()).unwrap();
+        format.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 11]);
+        self.items.serialize_into_with_order(bytes, order, PropertyFormat::from(format), num_items);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the XIGetSelectedEvents request
 pub const XI_GET_SELECTED_EVENTS_REQUEST: u8 = 60;
-pub fn xi_get_selected_events<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, XIGetSelectedEventsReply>, ConnectionError>
-where
-    Conn: RequestConnection + ?Sized,
-{
-    let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-        .ok_or(ConnectionError::UnsupportedExtension)?;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XIGetSelectedEventsRequest {
+    pub window: xproto::Window,
+}
+impl XIGetSelectedEventsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_GET_SELECTED_EVENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIGetSelectedEventsRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
+pub fn xi_get_selected_events<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, XIGetSelectedEventsReply>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+        .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_GET_SELECTED_EVENTS_REQUEST,
@@ -10942,9 +14444,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIGetSelectedEventsRequest {
+    type Reply = XIGetSelectedEventsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIGetSelectedEventsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, XIGetSelectedEventsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_get_selected_events(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIGetSelectedEventsReply {
@@ -10954,14 +14468,14 @@ pub struct XIGetSelectedEventsReply {
     pub masks: Vec<EventMask>,
 }
 impl TryParse for XIGetSelectedEventsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_masks, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_masks, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (masks, remaining) = crate::x11_utils::parse_list::<EventMask>(remaining, num_masks.try_into().or(Err(ParseError::ParseError))?)?;
+        let (masks, remaining) = crate::x11_utils::parse_list_with_order::<EventMask>(remaining, num_masks.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = XIGetSelectedEventsReply { response_type, sequence, length, masks };
         Ok((result, remaining))
     }
@@ -10972,6 +14486,32 @@ impl TryFrom<&[u8]> for XIGetSelectedEventsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for XIGetSelectedEventsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_masks = u16::try_from(self.masks.len()).expect("`masks` has too many elements");
+        num_masks.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.masks.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BarrierReleasePointerInfo {
@@ -10980,11 +14520,11 @@ pub struct BarrierReleasePointerInfo {
     pub eventid: u32,
 }
 impl TryParse for BarrierReleasePointerInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (barrier, remaining) = xfixes::Barrier::try_parse(remaining)?;
-        let (eventid, remaining) = u32::try_parse(remaining)?;
+        let (barrier, remaining) = xfixes::Barrier::try_parse_with_order(remaining, order)?;
+        let (eventid, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = BarrierReleasePointerInfo { deviceid, barrier, eventid };
         Ok((result, remaining))
     }
@@ -10997,10 +14537,10 @@ impl TryFrom<&[u8]> for BarrierReleasePointerInfo {
 }
 impl Serialize for BarrierReleasePointerInfo {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let deviceid_bytes = self.deviceid.serialize();
-        let barrier_bytes = self.barrier.serialize();
-        let eventid_bytes = self.eventid.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let deviceid_bytes = self.deviceid.serialize_with_order(order);
+        let barrier_bytes = self.barrier.serialize_with_order(order);
+        let eventid_bytes = self.eventid.serialize_with_order(order);
         [
             deviceid_bytes[0],
             deviceid_bytes[1],
@@ -11016,26 +14556,53 @@ impl Serialize for BarrierReleasePointerInfo {
             eventid_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.deviceid.serialize_into(bytes);
+        self.deviceid.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.barrier.serialize_into(bytes);
-        self.eventid.serialize_into(bytes);
+        self.barrier.serialize_into_with_order(bytes, order);
+        self.eventid.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the XIBarrierReleasePointer request
 pub const XI_BARRIER_RELEASE_POINTER_REQUEST: u8 = 61;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct XIBarrierReleasePointerRequest {
+    pub barriers: Vec<BarrierReleasePointerInfo>,
+}
+impl XIBarrierReleasePointerRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != XI_BARRIER_RELEASE_POINTER_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (num_barriers, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (barriers, remaining) = crate::x11_utils::parse_list_with_order::<BarrierReleasePointerInfo>(remaining, num_barriers.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(XIBarrierReleasePointerRequest {
+            barriers,
+        })
+    }
+    /// Set the `barriers` field of this structure.
+    pub fn barriers<I>(mut self, value: I) -> Self where I: Into<Vec<BarrierReleasePointerInfo>> {
+        self.barriers = value.into();
+        self
+    }
+}
+
 pub fn xi_barrier_release_pointer<'c, Conn>(conn: &'c Conn, barriers: &[BarrierReleasePointerInfo]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let num_barriers = u32::try_from(barriers.len()).expect("`barriers` has too many elements");
-    let num_barriers_bytes = num_barriers.serialize();
+    let num_barriers_bytes = num_barriers.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         XI_BARRIER_RELEASE_POINTER_REQUEST,
@@ -11047,15 +14614,27 @@ where
         num_barriers_bytes[3],
     ];
     let length_so_far = length_so_far + request0.len();
-    let barriers_bytes = barriers.serialize();
+    let barriers_bytes = barriers.serialize_with_order(order);
     let length_so_far = length_so_far + barriers_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&barriers_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for XIBarrierReleasePointerRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for XIBarrierReleasePointerRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        xi_barrier_release_pointer(conn, &self.barriers)
+    }
+}
 
 /// Opcode for the DeviceValuator event
 pub const DEVICE_VALUATOR_EVENT: u8 = 0;
@@ -11070,19 +14649,19 @@ pub struct DeviceValuatorEvent {
     pub valuators: [i32; 6],
 }
 impl TryParse for DeviceValuatorEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (device_state, remaining) = u16::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let (first_valuator, remaining) = u8::try_parse(remaining)?;
-        let (valuators_0, remaining) = i32::try_parse(remaining)?;
-        let (valuators_1, remaining) = i32::try_parse(remaining)?;
-        let (valuators_2, remaining) = i32::try_parse(remaining)?;
-        let (valuators_3, remaining) = i32::try_parse(remaining)?;
-        let (valuators_4, remaining) = i32::try_parse(remaining)?;
-        let (valuators_5, remaining) = i32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (device_state, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_valuators, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_valuator, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (valuators_0, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (valuators_1, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (valuators_2, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (valuators_3, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (valuators_4, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (valuators_5, remaining) = i32::try_parse_with_order(remaining, order)?;
         let valuators = [
             valuators_0,
             valuators_1,
@@ -11103,18 +14682,29 @@ impl TryFrom<&[u8]> for DeviceValuatorEvent {
 }
 impl From<&DeviceValuatorEvent> for [u8; 32] {
     fn from(input: &DeviceValuatorEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let device_state_bytes = input.device_state.serialize();
-        let num_valuators_bytes = input.num_valuators.serialize();
-        let first_valuator_bytes = input.first_valuator.serialize();
-        let valuators_0_bytes = input.valuators[0].serialize();
-        let valuators_1_bytes = input.valuators[1].serialize();
-        let valuators_2_bytes = input.valuators[2].serialize();
-        let valuators_3_bytes = input.valuators[3].serialize();
-        let valuators_4_bytes = input.valuators[4].serialize();
-        let valuators_5_bytes = input.valuators[5].serialize();
+        input.serialize()
+    }
+}
+impl From<DeviceValuatorEvent> for [u8; 32] {
+    fn from(input: DeviceValuatorEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DeviceValuatorEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let device_state_bytes = self.device_state.serialize_with_order(order);
+        let num_valuators_bytes = self.num_valuators.serialize_with_order(order);
+        let first_valuator_bytes = self.first_valuator.serialize_with_order(order);
+        let valuators_0_bytes = self.valuators[0].serialize_with_order(order);
+        let valuators_1_bytes = self.valuators[1].serialize_with_order(order);
+        let valuators_2_bytes = self.valuators[2].serialize_with_order(order);
+        let valuators_3_bytes = self.valuators[3].serialize_with_order(order);
+        let valuators_4_bytes = self.valuators[4].serialize_with_order(order);
+        let valuators_5_bytes = self.valuators[5].serialize_with_order(order);
         [
             response_type_bytes[0],
             device_id_bytes[0],
@@ -11150,72 +14740,43 @@ impl From<&DeviceValuatorEvent> for [u8; 32] {
             valuators_5_bytes[3],
         ]
     }
-}
-impl From<DeviceValuatorEvent> for [u8; 32] {
-    fn from(input: DeviceValuatorEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum MoreEventsMask {
-    MoreEvents = 1 << 7,
-}
-impl From<MoreEventsMask> for u8 {
-    fn from(input: MoreEventsMask) -> Self {
-        match input {
-            MoreEventsMask::MoreEvents => 1 << 7,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MoreEventsMask(u8);
+#[allow(non_upper_case_globals)]
+impl MoreEventsMask {
+    pub const MoreEvents: Self = Self(1 << 7);
 }
+bitmask_set!(MoreEventsMask, u8, [MoreEvents]);
 impl From<MoreEventsMask> for Option<u8> {
     fn from(input: MoreEventsMask) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<MoreEventsMask> for u16 {
     fn from(input: MoreEventsMask) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<MoreEventsMask> for Option<u16> {
     fn from(input: MoreEventsMask) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<MoreEventsMask> for u32 {
     fn from(input: MoreEventsMask) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<MoreEventsMask> for Option<u32> {
     fn from(input: MoreEventsMask) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for MoreEventsMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            128 => Ok(MoreEventsMask::MoreEvents),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for MoreEventsMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for MoreEventsMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(MoreEventsMask, u8);
 
 /// Opcode for the DeviceKeyPress event
 pub const DEVICE_KEY_PRESS_EVENT: u8 = 1;
@@ -11232,26 +14793,27 @@ pub struct DeviceKeyPressEvent {
     pub root_y: i16,
     pub event_x: i16,
     pub event_y: i16,
-    pub state: u16,
+    pub state: xproto::KeyButMask,
     pub same_screen: bool,
     pub device_id: u8,
 }
 impl TryParse for DeviceKeyPressEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = i16::try_parse(remaining)?;
-        let (root_y, remaining) = i16::try_parse(remaining)?;
-        let (event_x, remaining) = i16::try_parse(remaining)?;
-        let (event_y, remaining) = i16::try_parse(remaining)?;
-        let (state, remaining) = u16::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (child, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (root_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (root_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (event_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (event_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (same_screen, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let state = xproto::KeyButMask::from(state);
         let result = DeviceKeyPressEvent { response_type, detail, sequence, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen, device_id };
         Ok((result, remaining))
     }
@@ -11264,20 +14826,31 @@ impl TryFrom<&[u8]> for DeviceKeyPressEvent {
 }
 impl From<&DeviceKeyPressEvent> for [u8; 32] {
     fn from(input: &DeviceKeyPressEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let detail_bytes = input.detail.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let root_bytes = input.root.serialize();
-        let event_bytes = input.event.serialize();
-        let child_bytes = input.child.serialize();
-        let root_x_bytes = input.root_x.serialize();
-        let root_y_bytes = input.root_y.serialize();
-        let event_x_bytes = input.event_x.serialize();
-        let event_y_bytes = input.event_y.serialize();
-        let state_bytes = input.state.serialize();
-        let same_screen_bytes = input.same_screen.serialize();
-        let device_id_bytes = input.device_id.serialize();
+        input.serialize()
+    }
+}
+impl From<DeviceKeyPressEvent> for [u8; 32] {
+    fn from(input: DeviceKeyPressEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DeviceKeyPressEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let detail_bytes = self.detail.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let root_bytes = self.root.serialize_with_order(order);
+        let event_bytes = self.event.serialize_with_order(order);
+        let child_bytes = self.child.serialize_with_order(order);
+        let root_x_bytes = self.root_x.serialize_with_order(order);
+        let root_y_bytes = self.root_y.serialize_with_order(order);
+        let event_x_bytes = self.event_x.serialize_with_order(order);
+        let event_y_bytes = self.event_y.serialize_with_order(order);
+        let state_bytes = u16::from(self.state).serialize_with_order(order);
+        let same_screen_bytes = self.same_screen.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
         [
             response_type_bytes[0],
             detail_bytes[0],
@@ -11313,10 +14886,8 @@ impl From<&DeviceKeyPressEvent> for [u8; 32] {
             device_id_bytes[0],
         ]
     }
-}
-impl From<DeviceKeyPressEvent> for [u8; 32] {
-    fn from(input: DeviceKeyPressEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -11349,17 +14920,17 @@ pub struct DeviceFocusInEvent {
     pub device_id: u8,
 }
 impl TryParse for DeviceFocusInEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(18..).ok_or(ParseError::ParseError)?;
-        let detail = detail.try_into()?;
-        let mode = mode.try_into()?;
+        let detail = xproto::NotifyDetail::from(detail);
+        let mode = xproto::NotifyMode::from(mode);
         let result = DeviceFocusInEvent { response_type, detail, sequence, time, window, mode, device_id };
         Ok((result, remaining))
     }
@@ -11372,13 +14943,24 @@ impl TryFrom<&[u8]> for DeviceFocusInEvent {
 }
 impl From<&DeviceFocusInEvent> for [u8; 32] {
     fn from(input: &DeviceFocusInEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let detail_bytes = u8::from(input.detail).serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let window_bytes = input.window.serialize();
-        let mode_bytes = u8::from(input.mode).serialize();
-        let device_id_bytes = input.device_id.serialize();
+        input.serialize()
+    }
+}
+impl From<DeviceFocusInEvent> for [u8; 32] {
+    fn from(input: DeviceFocusInEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DeviceFocusInEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let detail_bytes = u8::from(self.detail).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let mode_bytes = u8::from(self.mode).serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
         [
             response_type_bytes[0],
             detail_bytes[0],
@@ -11414,10 +14996,8 @@ impl From<&DeviceFocusInEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<DeviceFocusInEvent> for [u8; 32] {
-    fn from(input: DeviceFocusInEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -11433,77 +15013,42 @@ pub type ProximityInEvent = DeviceKeyPressEvent;
 pub const PROXIMITY_OUT_EVENT: u8 = 9;
 pub type ProximityOutEvent = DeviceKeyPressEvent;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ClassesReportedMask {
-    OutOfProximity = 1 << 7,
-    DeviceModeAbsolute = 1 << 6,
-    ReportingValuators = 1 << 2,
-    ReportingButtons = 1 << 1,
-    ReportingKeys = 1 << 0,
-}
-impl From<ClassesReportedMask> for u8 {
-    fn from(input: ClassesReportedMask) -> Self {
-        match input {
-            ClassesReportedMask::OutOfProximity => 1 << 7,
-            ClassesReportedMask::DeviceModeAbsolute => 1 << 6,
-            ClassesReportedMask::ReportingValuators => 1 << 2,
-            ClassesReportedMask::ReportingButtons => 1 << 1,
-            ClassesReportedMask::ReportingKeys => 1 << 0,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ClassesReportedMask(u8);
+#[allow(non_upper_case_globals)]
+impl ClassesReportedMask {
+    pub const OutOfProximity: Self = Self(1 << 7);
+    pub const DeviceModeAbsolute: Self = Self(1 << 6);
+    pub const ReportingValuators: Self = Self(1 << 2);
+    pub const ReportingButtons: Self = Self(1 << 1);
+    pub const ReportingKeys: Self = Self(1 << 0);
+}
+bitmask_set!(ClassesReportedMask, u8, [OutOfProximity, DeviceModeAbsolute, ReportingValuators, ReportingButtons, ReportingKeys]);
 impl From<ClassesReportedMask> for Option<u8> {
     fn from(input: ClassesReportedMask) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ClassesReportedMask> for u16 {
     fn from(input: ClassesReportedMask) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ClassesReportedMask> for Option<u16> {
     fn from(input: ClassesReportedMask) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ClassesReportedMask> for u32 {
     fn from(input: ClassesReportedMask) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ClassesReportedMask> for Option<u32> {
     fn from(input: ClassesReportedMask) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for ClassesReportedMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            128 => Ok(ClassesReportedMask::OutOfProximity),
-            64 => Ok(ClassesReportedMask::DeviceModeAbsolute),
-            4 => Ok(ClassesReportedMask::ReportingValuators),
-            2 => Ok(ClassesReportedMask::ReportingButtons),
-            1 => Ok(ClassesReportedMask::ReportingKeys),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ClassesReportedMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ClassesReportedMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(ClassesReportedMask, u8);
 
 /// Opcode for the DeviceStateNotify event
 pub const DEVICE_STATE_NOTIFY_EVENT: u8 = 10;
@@ -11516,33 +15061,34 @@ pub struct DeviceStateNotifyEvent {
     pub num_keys: u8,
     pub num_buttons: u8,
     pub num_valuators: u8,
-    pub classes_reported: u8,
+    pub classes_reported: ClassesReportedMask,
     pub buttons: [u8; 4],
     pub keys: [u8; 4],
     pub valuators: [u32; 3],
 }
 impl TryParse for DeviceStateNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_keys, remaining) = u8::try_parse(remaining)?;
-        let (num_buttons, remaining) = u8::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let (classes_reported, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (num_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_buttons, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_valuators, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (classes_reported, remaining) = u8::try_parse_with_order(remaining, order)?;
         let (buttons, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
         let buttons = <[u8; 4]>::try_from(buttons).unwrap();
         let (keys, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
         let keys = <[u8; 4]>::try_from(keys).unwrap();
-        let (valuators_0, remaining) = u32::try_parse(remaining)?;
-        let (valuators_1, remaining) = u32::try_parse(remaining)?;
-        let (valuators_2, remaining) = u32::try_parse(remaining)?;
+        let (valuators_0, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (valuators_1, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (valuators_2, remaining) = u32::try_parse_with_order(remaining, order)?;
         let valuators = [
             valuators_0,
             valuators_1,
             valuators_2,
         ];
+        let classes_reported = ClassesReportedMask::from(classes_reported);
         let result = DeviceStateNotifyEvent { response_type, device_id, sequence, time, num_keys, num_buttons, num_valuators, classes_reported, buttons, keys, valuators };
         Ok((result, remaining))
     }
@@ -11555,17 +15101,28 @@ impl TryFrom<&[u8]> for DeviceStateNotifyEvent {
 }
 impl From<&DeviceStateNotifyEvent> for [u8; 32] {
     fn from(input: &DeviceStateNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let num_keys_bytes = input.num_keys.serialize();
-        let num_buttons_bytes = input.num_buttons.serialize();
-        let num_valuators_bytes = input.num_valuators.serialize();
-        let classes_reported_bytes = input.classes_reported.serialize();
-        let valuators_0_bytes = input.valuators[0].serialize();
-        let valuators_1_bytes = input.valuators[1].serialize();
-        let valuators_2_bytes = input.valuators[2].serialize();
+        input.serialize()
+    }
+}
+impl From<DeviceStateNotifyEvent> for [u8; 32] {
+    fn from(input: DeviceStateNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DeviceStateNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let num_keys_bytes = self.num_keys.serialize_with_order(order);
+        let num_buttons_bytes = self.num_buttons.serialize_with_order(order);
+        let num_valuators_bytes = self.num_valuators.serialize_with_order(order);
+        let classes_reported_bytes = u8::from(self.classes_reported).serialize_with_order(order);
+        let valuators_0_bytes = self.valuators[0].serialize_with_order(order);
+        let valuators_1_bytes = self.valuators[1].serialize_with_order(order);
+        let valuators_2_bytes = self.valuators[2].serialize_with_order(order);
         [
             response_type_bytes[0],
             device_id_bytes[0],
@@ -11579,14 +15136,14 @@ impl From<&DeviceStateNotifyEvent> for [u8; 32] {
             num_buttons_bytes[0],
             num_valuators_bytes[0],
             classes_reported_bytes[0],
-            input.buttons[0],
-            input.buttons[1],
-            input.buttons[2],
-            input.buttons[3],
-            input.keys[0],
-            input.keys[1],
-            input.keys[2],
-            input.keys[3],
+            self.buttons[0],
+            self.buttons[1],
+            self.buttons[2],
+            self.buttons[3],
+            self.keys[0],
+            self.keys[1],
+            self.keys[2],
+            self.keys[3],
             valuators_0_bytes[0],
             valuators_0_bytes[1],
             valuators_0_bytes[2],
@@ -11601,10 +15158,8 @@ impl From<&DeviceStateNotifyEvent> for [u8; 32] {
             valuators_2_bytes[3],
         ]
     }
-}
-impl From<DeviceStateNotifyEvent> for [u8; 32] {
-    fn from(input: DeviceStateNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -11621,17 +15176,17 @@ pub struct DeviceMappingNotifyEvent {
     pub time: xproto::Timestamp,
 }
 impl TryParse for DeviceMappingNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (request, remaining) = u8::try_parse(remaining)?;
-        let (first_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (count, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (request, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_keycode, remaining) = KeyCode::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let request = request.try_into()?;
+        let request = xproto::Mapping::from(request);
         let result = DeviceMappingNotifyEvent { response_type, device_id, sequence, request, first_keycode, count, time };
         Ok((result, remaining))
     }
@@ -11644,13 +15199,24 @@ impl TryFrom<&[u8]> for DeviceMappingNotifyEvent {
 }
 impl From<&DeviceMappingNotifyEvent> for [u8; 32] {
     fn from(input: &DeviceMappingNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let request_bytes = u8::from(input.request).serialize();
-        let first_keycode_bytes = input.first_keycode.serialize();
-        let count_bytes = input.count.serialize();
-        let time_bytes = input.time.serialize();
+        input.serialize()
+    }
+}
+impl From<DeviceMappingNotifyEvent> for [u8; 32] {
+    fn from(input: DeviceMappingNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DeviceMappingNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let request_bytes = u8::from(self.request).serialize_with_order(order);
+        let first_keycode_bytes = self.first_keycode.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
         [
             response_type_bytes[0],
             device_id_bytes[0],
@@ -11686,80 +15252,47 @@ impl From<&DeviceMappingNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<DeviceMappingNotifyEvent> for [u8; 32] {
-    fn from(input: DeviceMappingNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ChangeDevice {
-    NewPointer = 0,
-    NewKeyboard = 1,
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ChangeDevice(u8);
+#[allow(non_upper_case_globals)]
+impl ChangeDevice {
+    pub const NewPointer: Self = Self(0);
+    pub const NewKeyboard: Self = Self(1);
 }
+enum_newtype!(ChangeDevice, u8, [NewPointer, NewKeyboard]);
 impl From<ChangeDevice> for bool {
     fn from(input: ChangeDevice) -> Self {
-        match input {
-            ChangeDevice::NewPointer => false,
-            ChangeDevice::NewKeyboard => true,
-        }
-    }
-}
-impl From<ChangeDevice> for u8 {
-    fn from(input: ChangeDevice) -> Self {
-        match input {
-            ChangeDevice::NewPointer => 0,
-            ChangeDevice::NewKeyboard => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<ChangeDevice> for Option<u8> {
     fn from(input: ChangeDevice) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ChangeDevice> for u16 {
     fn from(input: ChangeDevice) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ChangeDevice> for Option<u16> {
     fn from(input: ChangeDevice) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ChangeDevice> for u32 {
     fn from(input: ChangeDevice) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ChangeDevice> for Option<u32> {
     fn from(input: ChangeDevice) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ChangeDevice {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(ChangeDevice::NewPointer),
-            1 => Ok(ChangeDevice::NewKeyboard),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ChangeDevice {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ChangeDevice {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -11774,14 +15307,14 @@ pub struct ChangeDeviceNotifyEvent {
     pub request: ChangeDevice,
 }
 impl TryParse for ChangeDeviceNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (request, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (request, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(23..).ok_or(ParseError::ParseError)?;
-        let request = request.try_into()?;
+        let request = ChangeDevice::from(request);
         let result = ChangeDeviceNotifyEvent { response_type, device_id, sequence, time, request };
         Ok((result, remaining))
     }
@@ -11794,11 +15327,22 @@ impl TryFrom<&[u8]> for ChangeDeviceNotifyEvent {
 }
 impl From<&ChangeDeviceNotifyEvent> for [u8; 32] {
     fn from(input: &ChangeDeviceNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let request_bytes = u8::from(input.request).serialize();
+        input.serialize()
+    }
+}
+impl From<ChangeDeviceNotifyEvent> for [u8; 32] {
+    fn from(input: ChangeDeviceNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ChangeDeviceNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let request_bytes = u8::from(self.request).serialize_with_order(order);
         [
             response_type_bytes[0],
             device_id_bytes[0],
@@ -11834,10 +15378,8 @@ impl From<&ChangeDeviceNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ChangeDeviceNotifyEvent> for [u8; 32] {
-    fn from(input: ChangeDeviceNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -11851,10 +15393,10 @@ pub struct DeviceKeyStateNotifyEvent {
     pub keys: [u8; 28],
 }
 impl TryParse for DeviceKeyStateNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let (keys, remaining) = crate::x11_utils::parse_u8_list(remaining, 28)?;
         let keys = <[u8; 28]>::try_from(keys).unwrap();
         let result = DeviceKeyStateNotifyEvent { response_type, device_id, sequence, keys };
@@ -11869,48 +15411,57 @@ impl TryFrom<&[u8]> for DeviceKeyStateNotifyEvent {
 }
 impl From<&DeviceKeyStateNotifyEvent> for [u8; 32] {
     fn from(input: &DeviceKeyStateNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<DeviceKeyStateNotifyEvent> for [u8; 32] {
+    fn from(input: DeviceKeyStateNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DeviceKeyStateNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             device_id_bytes[0],
             sequence_bytes[0],
             sequence_bytes[1],
-            input.keys[0],
-            input.keys[1],
-            input.keys[2],
-            input.keys[3],
-            input.keys[4],
-            input.keys[5],
-            input.keys[6],
-            input.keys[7],
-            input.keys[8],
-            input.keys[9],
-            input.keys[10],
-            input.keys[11],
-            input.keys[12],
-            input.keys[13],
-            input.keys[14],
-            input.keys[15],
-            input.keys[16],
-            input.keys[17],
-            input.keys[18],
-            input.keys[19],
-            input.keys[20],
-            input.keys[21],
-            input.keys[22],
-            input.keys[23],
-            input.keys[24],
-            input.keys[25],
-            input.keys[26],
-            input.keys[27],
+            self.keys[0],
+            self.keys[1],
+            self.keys[2],
+            self.keys[3],
+            self.keys[4],
+            self.keys[5],
+            self.keys[6],
+            self.keys[7],
+            self.keys[8],
+            self.keys[9],
+            self.keys[10],
+            self.keys[11],
+            self.keys[12],
+            self.keys[13],
+            self.keys[14],
+            self.keys[15],
+            self.keys[16],
+            self.keys[17],
+            self.keys[18],
+            self.keys[19],
+            self.keys[20],
+            self.keys[21],
+            self.keys[22],
+            self.keys[23],
+            self.keys[24],
+            self.keys[25],
+            self.keys[26],
+            self.keys[27],
         ]
     }
-}
-impl From<DeviceKeyStateNotifyEvent> for [u8; 32] {
-    fn from(input: DeviceKeyStateNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -11924,10 +15475,10 @@ pub struct DeviceButtonStateNotifyEvent {
     pub buttons: [u8; 28],
 }
 impl TryParse for DeviceButtonStateNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let (buttons, remaining) = crate::x11_utils::parse_u8_list(remaining, 28)?;
         let buttons = <[u8; 28]>::try_from(buttons).unwrap();
         let result = DeviceButtonStateNotifyEvent { response_type, device_id, sequence, buttons };
@@ -11942,122 +15493,95 @@ impl TryFrom<&[u8]> for DeviceButtonStateNotifyEvent {
 }
 impl From<&DeviceButtonStateNotifyEvent> for [u8; 32] {
     fn from(input: &DeviceButtonStateNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<DeviceButtonStateNotifyEvent> for [u8; 32] {
+    fn from(input: DeviceButtonStateNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DeviceButtonStateNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             device_id_bytes[0],
             sequence_bytes[0],
             sequence_bytes[1],
-            input.buttons[0],
-            input.buttons[1],
-            input.buttons[2],
-            input.buttons[3],
-            input.buttons[4],
-            input.buttons[5],
-            input.buttons[6],
-            input.buttons[7],
-            input.buttons[8],
-            input.buttons[9],
-            input.buttons[10],
-            input.buttons[11],
-            input.buttons[12],
-            input.buttons[13],
-            input.buttons[14],
-            input.buttons[15],
-            input.buttons[16],
-            input.buttons[17],
-            input.buttons[18],
-            input.buttons[19],
-            input.buttons[20],
-            input.buttons[21],
-            input.buttons[22],
-            input.buttons[23],
-            input.buttons[24],
-            input.buttons[25],
-            input.buttons[26],
-            input.buttons[27],
+            self.buttons[0],
+            self.buttons[1],
+            self.buttons[2],
+            self.buttons[3],
+            self.buttons[4],
+            self.buttons[5],
+            self.buttons[6],
+            self.buttons[7],
+            self.buttons[8],
+            self.buttons[9],
+            self.buttons[10],
+            self.buttons[11],
+            self.buttons[12],
+            self.buttons[13],
+            self.buttons[14],
+            self.buttons[15],
+            self.buttons[16],
+            self.buttons[17],
+            self.buttons[18],
+            self.buttons[19],
+            self.buttons[20],
+            self.buttons[21],
+            self.buttons[22],
+            self.buttons[23],
+            self.buttons[24],
+            self.buttons[25],
+            self.buttons[26],
+            self.buttons[27],
         ]
     }
-}
-impl From<DeviceButtonStateNotifyEvent> for [u8; 32] {
-    fn from(input: DeviceButtonStateNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum DeviceChange {
-    Added = 0,
-    Removed = 1,
-    Enabled = 2,
-    Disabled = 3,
-    Unrecoverable = 4,
-    ControlChanged = 5,
-}
-impl From<DeviceChange> for u8 {
-    fn from(input: DeviceChange) -> Self {
-        match input {
-            DeviceChange::Added => 0,
-            DeviceChange::Removed => 1,
-            DeviceChange::Enabled => 2,
-            DeviceChange::Disabled => 3,
-            DeviceChange::Unrecoverable => 4,
-            DeviceChange::ControlChanged => 5,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DeviceChange(u8);
+#[allow(non_upper_case_globals)]
+impl DeviceChange {
+    pub const Added: Self = Self(0);
+    pub const Removed: Self = Self(1);
+    pub const Enabled: Self = Self(2);
+    pub const Disabled: Self = Self(3);
+    pub const Unrecoverable: Self = Self(4);
+    pub const ControlChanged: Self = Self(5);
 }
+enum_newtype!(DeviceChange, u8, [Added, Removed, Enabled, Disabled, Unrecoverable, ControlChanged]);
 impl From<DeviceChange> for Option<u8> {
     fn from(input: DeviceChange) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<DeviceChange> for u16 {
     fn from(input: DeviceChange) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DeviceChange> for Option<u16> {
     fn from(input: DeviceChange) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<DeviceChange> for u32 {
     fn from(input: DeviceChange) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DeviceChange> for Option<u32> {
     fn from(input: DeviceChange) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for DeviceChange {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(DeviceChange::Added),
-            1 => Ok(DeviceChange::Removed),
-            2 => Ok(DeviceChange::Enabled),
-            3 => Ok(DeviceChange::Disabled),
-            4 => Ok(DeviceChange::Unrecoverable),
-            5 => Ok(DeviceChange::ControlChanged),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for DeviceChange {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for DeviceChange {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -12073,16 +15597,16 @@ pub struct DevicePresenceNotifyEvent {
     pub control: u16,
 }
 impl TryParse for DevicePresenceNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (devchange, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (control, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (devchange, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (control, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let devchange = devchange.try_into()?;
+        let devchange = DeviceChange::from(devchange);
         let result = DevicePresenceNotifyEvent { response_type, sequence, time, devchange, device_id, control };
         Ok((result, remaining))
     }
@@ -12095,12 +15619,23 @@ impl TryFrom<&[u8]> for DevicePresenceNotifyEvent {
 }
 impl From<&DevicePresenceNotifyEvent> for [u8; 32] {
     fn from(input: &DevicePresenceNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let devchange_bytes = u8::from(input.devchange).serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let control_bytes = input.control.serialize();
+        input.serialize()
+    }
+}
+impl From<DevicePresenceNotifyEvent> for [u8; 32] {
+    fn from(input: DevicePresenceNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DevicePresenceNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let devchange_bytes = u8::from(self.devchange).serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let control_bytes = self.control.serialize_with_order(order);
         [
             response_type_bytes[0],
             0,
@@ -12136,10 +15671,8 @@ impl From<&DevicePresenceNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<DevicePresenceNotifyEvent> for [u8; 32] {
-    fn from(input: DevicePresenceNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -12155,15 +15688,15 @@ pub struct DevicePropertyNotifyEvent {
     pub device_id: u8,
 }
 impl TryParse for DevicePropertyNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(19..).ok_or(ParseError::ParseError)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let state = state.try_into()?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let state = xproto::Property::from(state);
         let result = DevicePropertyNotifyEvent { response_type, state, sequence, time, property, device_id };
         Ok((result, remaining))
     }
@@ -12176,12 +15709,23 @@ impl TryFrom<&[u8]> for DevicePropertyNotifyEvent {
 }
 impl From<&DevicePropertyNotifyEvent> for [u8; 32] {
     fn from(input: &DevicePropertyNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let state_bytes = u8::from(input.state).serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let property_bytes = input.property.serialize();
-        let device_id_bytes = input.device_id.serialize();
+        input.serialize()
+    }
+}
+impl From<DevicePropertyNotifyEvent> for [u8; 32] {
+    fn from(input: DevicePropertyNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DevicePropertyNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let state_bytes = u8::from(self.state).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
         [
             response_type_bytes[0],
             state_bytes[0],
@@ -12217,72 +15761,42 @@ impl From<&DevicePropertyNotifyEvent> for [u8; 32] {
             device_id_bytes[0],
         ]
     }
-}
-impl From<DevicePropertyNotifyEvent> for [u8; 32] {
-    fn from(input: DevicePropertyNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ChangeReason {
-    SlaveSwitch = 1,
-    DeviceChange = 2,
-}
-impl From<ChangeReason> for u8 {
-    fn from(input: ChangeReason) -> Self {
-        match input {
-            ChangeReason::SlaveSwitch => 1,
-            ChangeReason::DeviceChange => 2,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChangeReason(u8);
+#[allow(non_upper_case_globals)]
+impl ChangeReason {
+    pub const SlaveSwitch: Self = Self(1);
+    pub const DeviceChange: Self = Self(2);
 }
+enum_newtype!(ChangeReason, u8, [SlaveSwitch, DeviceChange]);
 impl From<ChangeReason> for Option<u8> {
     fn from(input: ChangeReason) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ChangeReason> for u16 {
     fn from(input: ChangeReason) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ChangeReason> for Option<u16> {
     fn from(input: ChangeReason) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ChangeReason> for u32 {
     fn from(input: ChangeReason) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ChangeReason> for Option<u32> {
     fn from(input: ChangeReason) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ChangeReason {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ChangeReason::SlaveSwitch),
-            2 => Ok(ChangeReason::DeviceChange),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ChangeReason {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ChangeReason {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -12302,20 +15816,20 @@ pub struct DeviceChangedEvent {
     pub classes: Vec<DeviceClass>,
 }
 impl TryParse for DeviceChangedEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (reason, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (reason, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(11..).ok_or(ParseError::ParseError)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<DeviceClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?)?;
-        let reason = reason.try_into()?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<DeviceClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let reason = ChangeReason::from(reason);
         let result = DeviceChangedEvent { response_type, extension, sequence, length, event_type, deviceid, time, sourceid, reason, classes };
         Ok((result, remaining))
     }
@@ -12326,34 +15840,50 @@ impl TryFrom<&[u8]> for DeviceChangedEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
-pub enum KeyEventFlags {
-    KeyRepeat = 1 << 16,
-}
-impl From<KeyEventFlags> for u32 {
-    fn from(input: KeyEventFlags) -> Self {
-        match input {
-            KeyEventFlags::KeyRepeat => 1 << 16,
-        }
+impl Serialize for DeviceChangedEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        num_classes.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        u8::from(self.reason).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 11]);
+        self.classes.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KeyEventFlags(u32);
+#[allow(non_upper_case_globals)]
+impl KeyEventFlags {
+    pub const KeyRepeat: Self = Self(1 << 16);
+}
+bitmask_set!(KeyEventFlags, u32, [KeyRepeat]);
 impl From<KeyEventFlags> for Option<u32> {
     fn from(input: KeyEventFlags) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u32> for KeyEventFlags {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            65536 => Ok(KeyEventFlags::KeyRepeat),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(input.0)
     }
 }
-bitmask_binop!(KeyEventFlags, u32);
 
 /// Opcode for the KeyPress event
 pub const KEY_PRESS_EVENT: u16 = 2;
@@ -12375,7 +15905,7 @@ pub struct KeyPressEvent {
     pub event_x: Fp1616,
     pub event_y: Fp1616,
     pub sourceid: DeviceId,
-    pub flags: u32,
+    pub flags: KeyEventFlags,
     pub mods: ModifierInfo,
     pub group: GroupInfo,
     pub button_mask: Vec<u32>,
@@ -12383,32 +15913,33 @@ pub struct KeyPressEvent {
     pub axisvalues: Vec<Fp3232>,
 }
 impl TryParse for KeyPressEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (child, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (root_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (root_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (event_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (event_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (buttons_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (valuators_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (button_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = ModifierInfo::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = GroupInfo::try_parse_with_order(remaining, order)?;
+        let (button_mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list_with_order::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let flags = KeyEventFlags::from(flags);
         let result = KeyPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues };
         Ok((result, remaining))
     }
@@ -12419,38 +15950,69 @@ impl TryFrom<&[u8]> for KeyPressEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for KeyPressEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.detail.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        self.event.serialize_into_with_order(bytes, order);
+        self.child.serialize_into_with_order(bytes, order);
+        self.root_x.serialize_into_with_order(bytes, order);
+        self.root_y.serialize_into_with_order(bytes, order);
+        self.event_x.serialize_into_with_order(bytes, order);
+        self.event_y.serialize_into_with_order(bytes, order);
+        let buttons_len = u16::try_from(self.button_mask.len()).expect("`button_mask` has too many elements");
+        buttons_len.serialize_into_with_order(bytes, order);
+        let valuators_len = u16::try_from(self.valuator_mask.len()).expect("`valuator_mask` has too many elements");
+        valuators_len.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        self.mods.serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
+        self.button_mask.serialize_into_with_order(bytes, order);
+        self.valuator_mask.serialize_into_with_order(bytes, order);
+        assert_eq!(self.axisvalues.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).unwrap())).unwrap(), "`axisvalues` has an incorrect length");
+        self.axisvalues.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the KeyRelease event
 pub const KEY_RELEASE_EVENT: u16 = 3;
 pub type KeyReleaseEvent = KeyPressEvent;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
-pub enum PointerEventFlags {
-    PointerEmulated = 1 << 16,
-}
-impl From<PointerEventFlags> for u32 {
-    fn from(input: PointerEventFlags) -> Self {
-        match input {
-            PointerEventFlags::PointerEmulated => 1 << 16,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PointerEventFlags(u32);
+#[allow(non_upper_case_globals)]
+impl PointerEventFlags {
+    pub const PointerEmulated: Self = Self(1 << 16);
 }
+bitmask_set!(PointerEventFlags, u32, [PointerEmulated]);
 impl From<PointerEventFlags> for Option<u32> {
     fn from(input: PointerEventFlags) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u32> for PointerEventFlags {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            65536 => Ok(PointerEventFlags::PointerEmulated),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(input.0)
     }
 }
-bitmask_binop!(PointerEventFlags, u32);
 
 /// Opcode for the ButtonPress event
 pub const BUTTON_PRESS_EVENT: u16 = 4;
@@ -12472,7 +16034,7 @@ pub struct ButtonPressEvent {
     pub event_x: Fp1616,
     pub event_y: Fp1616,
     pub sourceid: DeviceId,
-    pub flags: u32,
+    pub flags: PointerEventFlags,
     pub mods: ModifierInfo,
     pub group: GroupInfo,
     pub button_mask: Vec<u32>,
@@ -12480,32 +16042,33 @@ pub struct ButtonPressEvent {
     pub axisvalues: Vec<Fp3232>,
 }
 impl TryParse for ButtonPressEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (child, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (root_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (root_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (event_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (event_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (buttons_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (valuators_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (button_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = ModifierInfo::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = GroupInfo::try_parse_with_order(remaining, order)?;
+        let (button_mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list_with_order::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let flags = PointerEventFlags::from(flags);
         let result = ButtonPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues };
         Ok((result, remaining))
     }
@@ -12516,6 +16079,52 @@ impl TryFrom<&[u8]> for ButtonPressEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ButtonPressEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.detail.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        self.event.serialize_into_with_order(bytes, order);
+        self.child.serialize_into_with_order(bytes, order);
+        self.root_x.serialize_into_with_order(bytes, order);
+        self.root_y.serialize_into_with_order(bytes, order);
+        self.event_x.serialize_into_with_order(bytes, order);
+        self.event_y.serialize_into_with_order(bytes, order);
+        let buttons_len = u16::try_from(self.button_mask.len()).expect("`button_mask` has too many elements");
+        buttons_len.serialize_into_with_order(bytes, order);
+        let valuators_len = u16::try_from(self.valuator_mask.len()).expect("`valuator_mask` has too many elements");
+        valuators_len.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        self.mods.serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
+        self.button_mask.serialize_into_with_order(bytes, order);
+        self.valuator_mask.serialize_into_with_order(bytes, order);
+        assert_eq!(self.axisvalues.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).unwrap())).unwrap(), "`axisvalues` has an incorrect length");
+        self.axisvalues.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ButtonRelease event
 pub const BUTTON_RELEASE_EVENT: u16 = 5;
@@ -12525,157 +16134,81 @@ pub type ButtonReleaseEvent = ButtonPressEvent;
 pub const MOTION_EVENT: u16 = 6;
 pub type MotionEvent = ButtonPressEvent;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum NotifyMode {
-    Normal = 0,
-    Grab = 1,
-    Ungrab = 2,
-    WhileGrabbed = 3,
-    PassiveGrab = 4,
-    PassiveUngrab = 5,
-}
-impl From<NotifyMode> for u8 {
-    fn from(input: NotifyMode) -> Self {
-        match input {
-            NotifyMode::Normal => 0,
-            NotifyMode::Grab => 1,
-            NotifyMode::Ungrab => 2,
-            NotifyMode::WhileGrabbed => 3,
-            NotifyMode::PassiveGrab => 4,
-            NotifyMode::PassiveUngrab => 5,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NotifyMode(u8);
+#[allow(non_upper_case_globals)]
+impl NotifyMode {
+    pub const Normal: Self = Self(0);
+    pub const Grab: Self = Self(1);
+    pub const Ungrab: Self = Self(2);
+    pub const WhileGrabbed: Self = Self(3);
+    pub const PassiveGrab: Self = Self(4);
+    pub const PassiveUngrab: Self = Self(5);
+}
+enum_newtype!(NotifyMode, u8, [Normal, Grab, Ungrab, WhileGrabbed, PassiveGrab, PassiveUngrab]);
 impl From<NotifyMode> for Option<u8> {
     fn from(input: NotifyMode) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<NotifyMode> for u16 {
     fn from(input: NotifyMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<NotifyMode> for Option<u16> {
     fn from(input: NotifyMode) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<NotifyMode> for u32 {
     fn from(input: NotifyMode) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<NotifyMode> for Option<u32> {
     fn from(input: NotifyMode) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for NotifyMode {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(NotifyMode::Normal),
-            1 => Ok(NotifyMode::Grab),
-            2 => Ok(NotifyMode::Ungrab),
-            3 => Ok(NotifyMode::WhileGrabbed),
-            4 => Ok(NotifyMode::PassiveGrab),
-            5 => Ok(NotifyMode::PassiveUngrab),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for NotifyMode {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for NotifyMode {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum NotifyDetail {
-    Ancestor = 0,
-    Virtual = 1,
-    Inferior = 2,
-    Nonlinear = 3,
-    NonlinearVirtual = 4,
-    Pointer = 5,
-    PointerRoot = 6,
-    None = 7,
-}
-impl From<NotifyDetail> for u8 {
-    fn from(input: NotifyDetail) -> Self {
-        match input {
-            NotifyDetail::Ancestor => 0,
-            NotifyDetail::Virtual => 1,
-            NotifyDetail::Inferior => 2,
-            NotifyDetail::Nonlinear => 3,
-            NotifyDetail::NonlinearVirtual => 4,
-            NotifyDetail::Pointer => 5,
-            NotifyDetail::PointerRoot => 6,
-            NotifyDetail::None => 7,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NotifyDetail(u8);
+#[allow(non_upper_case_globals)]
+impl NotifyDetail {
+    pub const Ancestor: Self = Self(0);
+    pub const Virtual: Self = Self(1);
+    pub const Inferior: Self = Self(2);
+    pub const Nonlinear: Self = Self(3);
+    pub const NonlinearVirtual: Self = Self(4);
+    pub const Pointer: Self = Self(5);
+    pub const PointerRoot: Self = Self(6);
+    pub const None: Self = Self(7);
 }
+enum_newtype!(NotifyDetail, u8, [Ancestor, Virtual, Inferior, Nonlinear, NonlinearVirtual, Pointer, PointerRoot, None]);
 impl From<NotifyDetail> for Option<u8> {
     fn from(input: NotifyDetail) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<NotifyDetail> for u16 {
     fn from(input: NotifyDetail) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
-impl From<NotifyDetail> for Option<u16> {
-    fn from(input: NotifyDetail) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<NotifyDetail> for u32 {
-    fn from(input: NotifyDetail) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<NotifyDetail> for Option<u32> {
-    fn from(input: NotifyDetail) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for NotifyDetail {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(NotifyDetail::Ancestor),
-            1 => Ok(NotifyDetail::Virtual),
-            2 => Ok(NotifyDetail::Inferior),
-            3 => Ok(NotifyDetail::Nonlinear),
-            4 => Ok(NotifyDetail::NonlinearVirtual),
-            5 => Ok(NotifyDetail::Pointer),
-            6 => Ok(NotifyDetail::PointerRoot),
-            7 => Ok(NotifyDetail::None),
-            _ => Err(ParseError::ParseError),
-        }
+impl From<NotifyDetail> for Option<u16> {
+    fn from(input: NotifyDetail) -> Self {
+        Some(u16::from(input.0))
     }
 }
-impl TryFrom<u16> for NotifyDetail {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<NotifyDetail> for u32 {
+    fn from(input: NotifyDetail) -> Self {
+        Self::from(input.0)
     }
 }
-impl TryFrom<u32> for NotifyDetail {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<NotifyDetail> for Option<u32> {
+    fn from(input: NotifyDetail) -> Self {
+        Some(u32::from(input.0))
     }
 }
 
@@ -12707,32 +16240,32 @@ pub struct EnterEvent {
     pub buttons: Vec<u32>,
 }
 impl TryParse for EnterEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let (focus, remaining) = bool::try_parse(remaining)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (buttons, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let mode = mode.try_into()?;
-        let detail = detail.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (child, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (root_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (root_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (event_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (event_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (same_screen, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (focus, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (buttons_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = ModifierInfo::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = GroupInfo::try_parse_with_order(remaining, order)?;
+        let (buttons, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let mode = NotifyMode::from(mode);
+        let detail = NotifyDetail::from(detail);
         let result = EnterEvent { response_type, extension, sequence, length, event_type, deviceid, time, sourceid, mode, detail, root, event, child, root_x, root_y, event_x, event_y, same_screen, focus, mods, group, buttons };
         Ok((result, remaining))
     }
@@ -12743,6 +16276,48 @@ impl TryFrom<&[u8]> for EnterEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for EnterEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        u8::from(self.mode).serialize_into_with_order(bytes, order);
+        u8::from(self.detail).serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        self.event.serialize_into_with_order(bytes, order);
+        self.child.serialize_into_with_order(bytes, order);
+        self.root_x.serialize_into_with_order(bytes, order);
+        self.root_y.serialize_into_with_order(bytes, order);
+        self.event_x.serialize_into_with_order(bytes, order);
+        self.event_y.serialize_into_with_order(bytes, order);
+        self.same_screen.serialize_into_with_order(bytes, order);
+        self.focus.serialize_into_with_order(bytes, order);
+        let buttons_len = u16::try_from(self.buttons.len()).expect("`buttons` has too many elements");
+        buttons_len.serialize_into_with_order(bytes, order);
+        self.mods.serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
+        self.buttons.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Leave event
 pub const LEAVE_EVENT: u16 = 8;
@@ -12756,86 +16331,25 @@ pub type FocusInEvent = EnterEvent;
 pub const FOCUS_OUT_EVENT: u16 = 10;
 pub type FocusOutEvent = EnterEvent;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum HierarchyMask {
-    MasterAdded = 1 << 0,
-    MasterRemoved = 1 << 1,
-    SlaveAdded = 1 << 2,
-    SlaveRemoved = 1 << 3,
-    SlaveAttached = 1 << 4,
-    SlaveDetached = 1 << 5,
-    DeviceEnabled = 1 << 6,
-    DeviceDisabled = 1 << 7,
-}
-impl From<HierarchyMask> for u8 {
-    fn from(input: HierarchyMask) -> Self {
-        match input {
-            HierarchyMask::MasterAdded => 1 << 0,
-            HierarchyMask::MasterRemoved => 1 << 1,
-            HierarchyMask::SlaveAdded => 1 << 2,
-            HierarchyMask::SlaveRemoved => 1 << 3,
-            HierarchyMask::SlaveAttached => 1 << 4,
-            HierarchyMask::SlaveDetached => 1 << 5,
-            HierarchyMask::DeviceEnabled => 1 << 6,
-            HierarchyMask::DeviceDisabled => 1 << 7,
-        }
-    }
-}
-impl From<HierarchyMask> for Option<u8> {
-    fn from(input: HierarchyMask) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<HierarchyMask> for u16 {
-    fn from(input: HierarchyMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<HierarchyMask> for Option<u16> {
-    fn from(input: HierarchyMask) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<HierarchyMask> for u32 {
-    fn from(input: HierarchyMask) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HierarchyMask(u32);
+#[allow(non_upper_case_globals)]
+impl HierarchyMask {
+    pub const MasterAdded: Self = Self(1 << 0);
+    pub const MasterRemoved: Self = Self(1 << 1);
+    pub const SlaveAdded: Self = Self(1 << 2);
+    pub const SlaveRemoved: Self = Self(1 << 3);
+    pub const SlaveAttached: Self = Self(1 << 4);
+    pub const SlaveDetached: Self = Self(1 << 5);
+    pub const DeviceEnabled: Self = Self(1 << 6);
+    pub const DeviceDisabled: Self = Self(1 << 7);
+}
+bitmask_set!(HierarchyMask, u32, [MasterAdded, MasterRemoved, SlaveAdded, SlaveRemoved, SlaveAttached, SlaveDetached, DeviceEnabled, DeviceDisabled]);
 impl From<HierarchyMask> for Option<u32> {
     fn from(input: HierarchyMask) -> Self {
-        Some(u32::from(input))
+        Some(input.0)
     }
 }
-impl TryFrom<u8> for HierarchyMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(HierarchyMask::MasterAdded),
-            2 => Ok(HierarchyMask::MasterRemoved),
-            4 => Ok(HierarchyMask::SlaveAdded),
-            8 => Ok(HierarchyMask::SlaveRemoved),
-            16 => Ok(HierarchyMask::SlaveAttached),
-            32 => Ok(HierarchyMask::SlaveDetached),
-            64 => Ok(HierarchyMask::DeviceEnabled),
-            128 => Ok(HierarchyMask::DeviceDisabled),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for HierarchyMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for HierarchyMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(HierarchyMask, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct HierarchyInfo {
@@ -12843,17 +16357,18 @@ pub struct HierarchyInfo {
     pub attachment: DeviceId,
     pub type_: DeviceType,
     pub enabled: bool,
-    pub flags: u32,
+    pub flags: HierarchyMask,
 }
 impl TryParse for HierarchyInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (attachment, remaining) = DeviceId::try_parse(remaining)?;
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (enabled, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (attachment, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (enabled, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let type_ = type_.try_into()?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let type_ = DeviceType::from(u16::from(type_));
+        let flags = HierarchyMask::from(flags);
         let result = HierarchyInfo { deviceid, attachment, type_, enabled, flags };
         Ok((result, remaining))
     }
@@ -12866,12 +16381,12 @@ impl TryFrom<&[u8]> for HierarchyInfo {
 }
 impl Serialize for HierarchyInfo {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let deviceid_bytes = self.deviceid.serialize();
-        let attachment_bytes = self.attachment.serialize();
-        let type_bytes = u8::from(self.type_).serialize();
-        let enabled_bytes = self.enabled.serialize();
-        let flags_bytes = self.flags.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let deviceid_bytes = self.deviceid.serialize_with_order(order);
+        let attachment_bytes = self.attachment.serialize_with_order(order);
+        let type_bytes = u8::try_from(u16::from(self.type_)).unwrap().serialize_with_order(order);
+        let enabled_bytes = self.enabled.serialize_with_order(order);
+        let flags_bytes = u32::from(self.flags).serialize_with_order(order);
         [
             deviceid_bytes[0],
             deviceid_bytes[1],
@@ -12887,14 +16402,14 @@ impl Serialize for HierarchyInfo {
             flags_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.deviceid.serialize_into(bytes);
-        self.attachment.serialize_into(bytes);
-        u8::from(self.type_).serialize_into(bytes);
-        self.enabled.serialize_into(bytes);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.attachment.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.type_)).unwrap().serialize_into_with_order(bytes, order);
+        self.enabled.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.flags.serialize_into(bytes);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
     }
 }
 
@@ -12909,22 +16424,23 @@ pub struct HierarchyEvent {
     pub event_type: u16,
     pub deviceid: DeviceId,
     pub time: xproto::Timestamp,
-    pub flags: u32,
+    pub flags: HierarchyMask,
     pub infos: Vec<HierarchyInfo>,
 }
 impl TryParse for HierarchyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (num_infos, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_infos, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(10..).ok_or(ParseError::ParseError)?;
-        let (infos, remaining) = crate::x11_utils::parse_list::<HierarchyInfo>(remaining, num_infos.try_into().or(Err(ParseError::ParseError))?)?;
+        let (infos, remaining) = crate::x11_utils::parse_list_with_order::<HierarchyInfo>(remaining, num_infos.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let flags = HierarchyMask::from(flags);
         let result = HierarchyEvent { response_type, extension, sequence, length, event_type, deviceid, time, flags, infos };
         Ok((result, remaining))
     }
@@ -12935,69 +16451,69 @@ impl TryFrom<&[u8]> for HierarchyEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PropertyFlag {
-    Deleted = 0,
-    Created = 1,
-    Modified = 2,
-}
-impl From<PropertyFlag> for u8 {
-    fn from(input: PropertyFlag) -> Self {
-        match input {
-            PropertyFlag::Deleted => 0,
-            PropertyFlag::Created => 1,
-            PropertyFlag::Modified => 2,
-        }
+impl Serialize for HierarchyEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
     }
-}
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        let num_infos = u16::try_from(self.infos.len()).expect("`infos` has too many elements");
+        num_infos.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 10]);
+        self.infos.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PropertyFlag(u8);
+#[allow(non_upper_case_globals)]
+impl PropertyFlag {
+    pub const Deleted: Self = Self(0);
+    pub const Created: Self = Self(1);
+    pub const Modified: Self = Self(2);
+}
+enum_newtype!(PropertyFlag, u8, [Deleted, Created, Modified]);
 impl From<PropertyFlag> for Option<u8> {
     fn from(input: PropertyFlag) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<PropertyFlag> for u16 {
     fn from(input: PropertyFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PropertyFlag> for Option<u16> {
     fn from(input: PropertyFlag) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<PropertyFlag> for u32 {
     fn from(input: PropertyFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<PropertyFlag> for Option<u32> {
     fn from(input: PropertyFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for PropertyFlag {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(PropertyFlag::Deleted),
-            1 => Ok(PropertyFlag::Created),
-            2 => Ok(PropertyFlag::Modified),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for PropertyFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for PropertyFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -13016,18 +16532,18 @@ pub struct PropertyEvent {
     pub what: PropertyFlag,
 }
 impl TryParse for PropertyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (what, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (what, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(11..).ok_or(ParseError::ParseError)?;
-        let what = what.try_into()?;
+        let what = PropertyFlag::from(what);
         let result = PropertyEvent { response_type, extension, sequence, length, event_type, deviceid, time, property, what };
         Ok((result, remaining))
     }
@@ -13038,6 +16554,34 @@ impl TryFrom<&[u8]> for PropertyEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PropertyEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.property.serialize_into_with_order(bytes, order);
+        u8::from(self.what).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 11]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the RawKeyPress event
 pub const RAW_KEY_PRESS_EVENT: u16 = 13;
@@ -13052,28 +16596,29 @@ pub struct RawKeyPressEvent {
     pub time: xproto::Timestamp,
     pub detail: u32,
     pub sourceid: DeviceId,
-    pub flags: u32,
+    pub flags: KeyEventFlags,
     pub valuator_mask: Vec<u32>,
     pub axisvalues: Vec<Fp3232>,
     pub axisvalues_raw: Vec<Fp3232>,
 }
 impl TryParse for RawKeyPressEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (valuators_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
-        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list_with_order::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list_with_order::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let flags = KeyEventFlags::from(flags);
         let result = RawKeyPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw };
         Ok((result, remaining))
     }
@@ -13084,6 +16629,42 @@ impl TryFrom<&[u8]> for RawKeyPressEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for RawKeyPressEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.detail.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        let valuators_len = u16::try_from(self.valuator_mask.len()).expect("`valuator_mask` has too many elements");
+        valuators_len.serialize_into_with_order(bytes, order);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.valuator_mask.serialize_into_with_order(bytes, order);
+        assert_eq!(self.axisvalues.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).unwrap())).unwrap(), "`axisvalues` has an incorrect length");
+        self.axisvalues.serialize_into_with_order(bytes, order);
+        assert_eq!(self.axisvalues_raw.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).unwrap())).unwrap(), "`axisvalues_raw` has an incorrect length");
+        self.axisvalues_raw.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the RawKeyRelease event
 pub const RAW_KEY_RELEASE_EVENT: u16 = 14;
@@ -13102,28 +16683,29 @@ pub struct RawButtonPressEvent {
     pub time: xproto::Timestamp,
     pub detail: u32,
     pub sourceid: DeviceId,
-    pub flags: u32,
+    pub flags: PointerEventFlags,
     pub valuator_mask: Vec<u32>,
     pub axisvalues: Vec<Fp3232>,
     pub axisvalues_raw: Vec<Fp3232>,
 }
 impl TryParse for RawButtonPressEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (valuators_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
-        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list_with_order::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list_with_order::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let flags = PointerEventFlags::from(flags);
         let result = RawButtonPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw };
         Ok((result, remaining))
     }
@@ -13134,6 +16716,42 @@ impl TryFrom<&[u8]> for RawButtonPressEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for RawButtonPressEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.detail.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        let valuators_len = u16::try_from(self.valuator_mask.len()).expect("`valuator_mask` has too many elements");
+        valuators_len.serialize_into_with_order(bytes, order);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.valuator_mask.serialize_into_with_order(bytes, order);
+        assert_eq!(self.axisvalues.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).unwrap())).unwrap(), "`axisvalues` has an incorrect length");
+        self.axisvalues.serialize_into_with_order(bytes, order);
+        assert_eq!(self.axisvalues_raw.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).unwrap())).unwrap(), "`axisvalues_raw` has an incorrect length");
+        self.axisvalues_raw.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the RawButtonRelease event
 pub const RAW_BUTTON_RELEASE_EVENT: u16 = 16;
@@ -13143,36 +16761,19 @@ pub type RawButtonReleaseEvent = RawButtonPressEvent;
 pub const RAW_MOTION_EVENT: u16 = 17;
 pub type RawMotionEvent = RawButtonPressEvent;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
-pub enum TouchEventFlags {
-    TouchPendingEnd = 1 << 16,
-    TouchEmulatingPointer = 1 << 17,
-}
-impl From<TouchEventFlags> for u32 {
-    fn from(input: TouchEventFlags) -> Self {
-        match input {
-            TouchEventFlags::TouchPendingEnd => 1 << 16,
-            TouchEventFlags::TouchEmulatingPointer => 1 << 17,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TouchEventFlags(u32);
+#[allow(non_upper_case_globals)]
+impl TouchEventFlags {
+    pub const TouchPendingEnd: Self = Self(1 << 16);
+    pub const TouchEmulatingPointer: Self = Self(1 << 17);
 }
+bitmask_set!(TouchEventFlags, u32, [TouchPendingEnd, TouchEmulatingPointer]);
 impl From<TouchEventFlags> for Option<u32> {
     fn from(input: TouchEventFlags) -> Self {
-        Some(u32::from(input))
+        Some(input.0)
     }
 }
-impl TryFrom<u32> for TouchEventFlags {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            65536 => Ok(TouchEventFlags::TouchPendingEnd),
-            131_072 => Ok(TouchEventFlags::TouchEmulatingPointer),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-bitmask_binop!(TouchEventFlags, u32);
 
 /// Opcode for the TouchBegin event
 pub const TOUCH_BEGIN_EVENT: u16 = 18;
@@ -13194,7 +16795,7 @@ pub struct TouchBeginEvent {
     pub event_x: Fp1616,
     pub event_y: Fp1616,
     pub sourceid: DeviceId,
-    pub flags: u32,
+    pub flags: TouchEventFlags,
     pub mods: ModifierInfo,
     pub group: GroupInfo,
     pub button_mask: Vec<u32>,
@@ -13202,32 +16803,33 @@ pub struct TouchBeginEvent {
     pub axisvalues: Vec<Fp3232>,
 }
 impl TryParse for TouchBeginEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (child, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (root_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (root_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (event_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (event_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (buttons_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (valuators_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (button_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = ModifierInfo::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = GroupInfo::try_parse_with_order(remaining, order)?;
+        let (button_mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, buttons_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list_with_order::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let flags = TouchEventFlags::from(flags);
         let result = TouchBeginEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues };
         Ok((result, remaining))
     }
@@ -13238,6 +16840,52 @@ impl TryFrom<&[u8]> for TouchBeginEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for TouchBeginEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.detail.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        self.event.serialize_into_with_order(bytes, order);
+        self.child.serialize_into_with_order(bytes, order);
+        self.root_x.serialize_into_with_order(bytes, order);
+        self.root_y.serialize_into_with_order(bytes, order);
+        self.event_x.serialize_into_with_order(bytes, order);
+        self.event_y.serialize_into_with_order(bytes, order);
+        let buttons_len = u16::try_from(self.button_mask.len()).expect("`button_mask` has too many elements");
+        buttons_len.serialize_into_with_order(bytes, order);
+        let valuators_len = u16::try_from(self.valuator_mask.len()).expect("`valuator_mask` has too many elements");
+        valuators_len.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        self.mods.serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
+        self.button_mask.serialize_into_with_order(bytes, order);
+        self.valuator_mask.serialize_into_with_order(bytes, order);
+        assert_eq!(self.axisvalues.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).unwrap())).unwrap(), "`axisvalues` has an incorrect length");
+        self.axisvalues.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the TouchUpdate event
 pub const TOUCH_UPDATE_EVENT: u16 = 19;
@@ -13247,62 +16895,16 @@ pub type TouchUpdateEvent = TouchBeginEvent;
 pub const TOUCH_END_EVENT: u16 = 20;
 pub type TouchEndEvent = TouchBeginEvent;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum TouchOwnershipFlags {
-    None = 0,
-}
-impl From<TouchOwnershipFlags> for u8 {
-    fn from(input: TouchOwnershipFlags) -> Self {
-        match input {
-            TouchOwnershipFlags::None => 0,
-        }
-    }
-}
-impl From<TouchOwnershipFlags> for Option<u8> {
-    fn from(input: TouchOwnershipFlags) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<TouchOwnershipFlags> for u16 {
-    fn from(input: TouchOwnershipFlags) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<TouchOwnershipFlags> for Option<u16> {
-    fn from(input: TouchOwnershipFlags) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<TouchOwnershipFlags> for u32 {
-    fn from(input: TouchOwnershipFlags) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TouchOwnershipFlags(u32);
+#[allow(non_upper_case_globals)]
+impl TouchOwnershipFlags {
+    pub const None: Self = Self(0);
 }
+enum_newtype!(TouchOwnershipFlags, u32, [None]);
 impl From<TouchOwnershipFlags> for Option<u32> {
     fn from(input: TouchOwnershipFlags) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for TouchOwnershipFlags {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(TouchOwnershipFlags::None),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for TouchOwnershipFlags {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for TouchOwnershipFlags {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
 
@@ -13325,23 +16927,23 @@ pub struct TouchOwnershipEvent {
     pub flags: TouchOwnershipFlags,
 }
 impl TryParse for TouchOwnershipEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (touchid, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (touchid, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (child, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
-        let flags = flags.try_into()?;
+        let flags = TouchOwnershipFlags::from(flags);
         let result = TouchOwnershipEvent { response_type, extension, sequence, length, event_type, deviceid, time, touchid, root, event, child, sourceid, flags };
         Ok((result, remaining))
     }
@@ -13352,6 +16954,39 @@ impl TryFrom<&[u8]> for TouchOwnershipEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for TouchOwnershipEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.touchid.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        self.event.serialize_into_with_order(bytes, order);
+        self.child.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the RawTouchBegin event
 pub const RAW_TOUCH_BEGIN_EVENT: u16 = 22;
@@ -13366,28 +17001,29 @@ pub struct RawTouchBeginEvent {
     pub time: xproto::Timestamp,
     pub detail: u32,
     pub sourceid: DeviceId,
-    pub flags: u32,
+    pub flags: TouchEventFlags,
     pub valuator_mask: Vec<u32>,
     pub axisvalues: Vec<Fp3232>,
     pub axisvalues_raw: Vec<Fp3232>,
 }
 impl TryParse for RawTouchBeginEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (valuators_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
-        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, valuators_len.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list_with_order::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list_with_order::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let flags = TouchEventFlags::from(flags);
         let result = RawTouchBeginEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw };
         Ok((result, remaining))
     }
@@ -13398,6 +17034,42 @@ impl TryFrom<&[u8]> for RawTouchBeginEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for RawTouchBeginEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.detail.serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        let valuators_len = u16::try_from(self.valuator_mask.len()).expect("`valuator_mask` has too many elements");
+        valuators_len.serialize_into_with_order(bytes, order);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.valuator_mask.serialize_into_with_order(bytes, order);
+        assert_eq!(self.axisvalues.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).unwrap())).unwrap(), "`axisvalues` has an incorrect length");
+        self.axisvalues.serialize_into_with_order(bytes, order);
+        assert_eq!(self.axisvalues_raw.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).unwrap())).unwrap(), "`axisvalues_raw` has an incorrect length");
+        self.axisvalues_raw.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the RawTouchUpdate event
 pub const RAW_TOUCH_UPDATE_EVENT: u16 = 23;
@@ -13407,68 +17079,19 @@ pub type RawTouchUpdateEvent = RawTouchBeginEvent;
 pub const RAW_TOUCH_END_EVENT: u16 = 24;
 pub type RawTouchEndEvent = RawTouchBeginEvent;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum BarrierFlags {
-    PointerReleased = 1 << 0,
-    DeviceIsGrabbed = 1 << 1,
-}
-impl From<BarrierFlags> for u8 {
-    fn from(input: BarrierFlags) -> Self {
-        match input {
-            BarrierFlags::PointerReleased => 1 << 0,
-            BarrierFlags::DeviceIsGrabbed => 1 << 1,
-        }
-    }
-}
-impl From<BarrierFlags> for Option<u8> {
-    fn from(input: BarrierFlags) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<BarrierFlags> for u16 {
-    fn from(input: BarrierFlags) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<BarrierFlags> for Option<u16> {
-    fn from(input: BarrierFlags) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<BarrierFlags> for u32 {
-    fn from(input: BarrierFlags) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BarrierFlags(u32);
+#[allow(non_upper_case_globals)]
+impl BarrierFlags {
+    pub const PointerReleased: Self = Self(1 << 0);
+    pub const DeviceIsGrabbed: Self = Self(1 << 1);
 }
+bitmask_set!(BarrierFlags, u32, [PointerReleased, DeviceIsGrabbed]);
 impl From<BarrierFlags> for Option<u32> {
     fn from(input: BarrierFlags) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for BarrierFlags {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(BarrierFlags::PointerReleased),
-            2 => Ok(BarrierFlags::DeviceIsGrabbed),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for BarrierFlags {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for BarrierFlags {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-bitmask_binop!(BarrierFlags, u8);
 
 /// Opcode for the BarrierHit event
 pub const BARRIER_HIT_EVENT: u16 = 25;
@@ -13486,7 +17109,7 @@ pub struct BarrierHitEvent {
     pub event: xproto::Window,
     pub barrier: xfixes::Barrier,
     pub dtime: u32,
-    pub flags: u32,
+    pub flags: BarrierFlags,
     pub sourceid: DeviceId,
     pub root_x: Fp1616,
     pub root_y: Fp1616,
@@ -13494,26 +17117,27 @@ pub struct BarrierHitEvent {
     pub dy: Fp3232,
 }
 impl TryParse for BarrierHitEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (eventid, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (barrier, remaining) = xfixes::Barrier::try_parse(remaining)?;
-        let (dtime, remaining) = u32::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (deviceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (eventid, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (barrier, remaining) = xfixes::Barrier::try_parse_with_order(remaining, order)?;
+        let (dtime, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sourceid, remaining) = DeviceId::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (dx, remaining) = Fp3232::try_parse(remaining)?;
-        let (dy, remaining) = Fp3232::try_parse(remaining)?;
+        let (root_x, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (root_y, remaining) = Fp1616::try_parse_with_order(remaining, order)?;
+        let (dx, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let (dy, remaining) = Fp3232::try_parse_with_order(remaining, order)?;
+        let flags = BarrierFlags::from(flags);
         let result = BarrierHitEvent { response_type, extension, sequence, length, event_type, deviceid, time, eventid, root, event, barrier, dtime, flags, sourceid, root_x, root_y, dx, dy };
         Ok((result, remaining))
     }
@@ -13524,6 +17148,43 @@ impl TryFrom<&[u8]> for BarrierHitEvent {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for BarrierHitEvent {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.extension.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_type.serialize_into_with_order(bytes, order);
+        self.deviceid.serialize_into_with_order(bytes, order);
+        self.time.serialize_into_with_order(bytes, order);
+        self.eventid.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        self.event.serialize_into_with_order(bytes, order);
+        self.barrier.serialize_into_with_order(bytes, order);
+        self.dtime.serialize_into_with_order(bytes, order);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        self.sourceid.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.root_x.serialize_into_with_order(bytes, order);
+        self.root_y.serialize_into_with_order(bytes, order);
+        self.dx.serialize_into_with_order(bytes, order);
+        self.dy.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the BarrierLeave event
 pub const BARRIER_LEAVE_EVENT: u16 = 26;
@@ -13620,15 +17281,15 @@ impl EventForSend {
 }
 impl Serialize for EventForSend {
     type Bytes = [u8; 32];
-    fn serialize(&self) -> [u8; 32] {
+    fn serialize_with_order(&self, _order: ByteOrder) -> [u8; 32] {
         self.0
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {
         bytes.extend_from_slice(&self.0);
     }
 }
 impl TryParse for EventForSend {
-    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], _order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let inner: [u8; 32] = value.get(..32)
             .ok_or(ParseError::ParseError)?
             .try_into()
@@ -13740,20 +17401,81 @@ impl From<&DevicePropertyNotifyEvent> for EventForSend {
 
 /// Opcode for the SendExtensionEvent request
 pub const SEND_EXTENSION_EVENT_REQUEST: u8 = 31;
+#[derive(Debug, Clone, Default)]
+pub struct SendExtensionEventRequest {
+    pub destination: xproto::Window,
+    pub device_id: u8,
+    pub propagate: bool,
+    pub events: Vec<EventForSend>,
+    pub classes: Vec<EventClass>,
+}
+impl SendExtensionEventRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SEND_EXTENSION_EVENT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (destination, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (propagate, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (num_classes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_events, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (events, remaining) = crate::x11_utils::parse_list_with_order::<EventForSend>(remaining, num_events.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (classes, remaining) = crate::x11_utils::parse_list_with_order::<EventClass>(remaining, num_classes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SendExtensionEventRequest {
+            destination,
+            device_id,
+            propagate,
+            events,
+            classes,
+        })
+    }
+    /// Set the `destination` field of this structure.
+    pub fn destination<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.destination = value.into();
+        self
+    }
+    /// Set the `device_id` field of this structure.
+    pub fn device_id(mut self, value: u8) -> Self {
+        self.device_id = value;
+        self
+    }
+    /// Set the `propagate` field of this structure.
+    pub fn propagate(mut self, value: bool) -> Self {
+        self.propagate = value;
+        self
+    }
+    /// Set the `events` field of this structure.
+    pub fn events<I>(mut self, value: I) -> Self where I: Into<Vec<EventForSend>> {
+        self.events = value.into();
+        self
+    }
+    /// Set the `classes` field of this structure.
+    pub fn classes<I>(mut self, value: I) -> Self where I: Into<Vec<EventClass>> {
+        self.classes = value.into();
+        self
+    }
+}
+
 pub fn send_extension_event<'c, Conn>(conn: &'c Conn, destination: xproto::Window, device_id: u8, propagate: bool, events: &[EventForSend], classes: &[EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let destination_bytes = destination.serialize();
-    let device_id_bytes = device_id.serialize();
-    let propagate_bytes = propagate.serialize();
+    let destination_bytes = destination.serialize_with_order(order);
+    let device_id_bytes = device_id.serialize_with_order(order);
+    let propagate_bytes = propagate.serialize_with_order(order);
     let num_classes = u16::try_from(classes.len()).expect("`classes` has too many elements");
-    let num_classes_bytes = num_classes.serialize();
+    let num_classes_bytes = num_classes.serialize_with_order(order);
     let num_events = u8::try_from(events.len()).expect("`events` has too many elements");
-    let num_events_bytes = num_events.serialize();
+    let num_events_bytes = num_events.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SEND_EXTENSION_EVENT_REQUEST,
@@ -13773,17 +17495,29 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let events_bytes = events.serialize();
+    let events_bytes = events.serialize_with_order(order);
     let length_so_far = length_so_far + events_bytes.len();
-    let classes_bytes = classes.serialize();
+    let classes_bytes = classes.serialize_with_order(order);
     let length_so_far = length_so_far + classes_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&events_bytes), IoSlice::new(&classes_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SendExtensionEventRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SendExtensionEventRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        send_extension_event(conn, self.destination, self.device_id, self.propagate, &self.events, &self.classes)
+    }
+}
 
 /// Opcode for the Device error
 pub const DEVICE_ERROR: u8 = 0;
@@ -13794,10 +17528,10 @@ pub struct DeviceError {
     pub sequence: u16,
 }
 impl TryParse for DeviceError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = DeviceError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -13810,9 +17544,20 @@ impl TryFrom<&[u8]> for DeviceError {
 }
 impl From<&DeviceError> for [u8; 32] {
     fn from(input: &DeviceError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<DeviceError> for [u8; 32] {
+    fn from(input: DeviceError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DeviceError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -13849,10 +17594,8 @@ impl From<&DeviceError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<DeviceError> for [u8; 32] {
-    fn from(input: DeviceError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -13865,10 +17608,10 @@ pub struct EventError {
     pub sequence: u16,
 }
 impl TryParse for EventError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = EventError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -13881,9 +17624,20 @@ impl TryFrom<&[u8]> for EventError {
 }
 impl From<&EventError> for [u8; 32] {
     fn from(input: &EventError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<EventError> for [u8; 32] {
+    fn from(input: EventError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for EventError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -13920,10 +17674,8 @@ impl From<&EventError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<EventError> for [u8; 32] {
-    fn from(input: EventError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -13936,10 +17688,10 @@ pub struct ModeError {
     pub sequence: u16,
 }
 impl TryParse for ModeError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = ModeError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -13952,9 +17704,20 @@ impl TryFrom<&[u8]> for ModeError {
 }
 impl From<&ModeError> for [u8; 32] {
     fn from(input: &ModeError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<ModeError> for [u8; 32] {
+    fn from(input: ModeError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ModeError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -13991,10 +17754,8 @@ impl From<&ModeError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ModeError> for [u8; 32] {
-    fn from(input: ModeError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -14007,10 +17768,10 @@ pub struct DeviceBusyError {
     pub sequence: u16,
 }
 impl TryParse for DeviceBusyError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = DeviceBusyError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -14023,9 +17784,20 @@ impl TryFrom<&[u8]> for DeviceBusyError {
 }
 impl From<&DeviceBusyError> for [u8; 32] {
     fn from(input: &DeviceBusyError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<DeviceBusyError> for [u8; 32] {
+    fn from(input: DeviceBusyError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for DeviceBusyError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -14062,10 +17834,8 @@ impl From<&DeviceBusyError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<DeviceBusyError> for [u8; 32] {
-    fn from(input: DeviceBusyError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -14078,10 +17848,10 @@ pub struct ClassError {
     pub sequence: u16,
 }
 impl TryParse for ClassError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = ClassError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -14094,9 +17864,20 @@ impl TryFrom<&[u8]> for ClassError {
 }
 impl From<&ClassError> for [u8; 32] {
     fn from(input: &ClassError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<ClassError> for [u8; 32] {
+    fn from(input: ClassError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ClassError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -14133,10 +17914,8 @@ impl From<&ClassError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ClassError> for [u8; 32] {
-    fn from(input: ClassError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -14158,7 +17937,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         close_device(self, device_id)
     }
-    fn xinput_set_device_mode(&self, device_id: u8, mode: ValuatorMode) -> Result<Cookie<'_, Self, SetDeviceModeReply>, ConnectionError>
+    fn xinput_set_device_mode<A>(&self, device_id: u8, mode: A) -> Result<Cookie<'_, Self, SetDeviceModeReply>, ConnectionError>
+    where
+        A: Into<ValuatorMode>,
     {
         set_device_mode(self, device_id, mode)
     }
@@ -14170,7 +17951,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         get_selected_extension_events(self, window)
     }
-    fn xinput_change_device_dont_propagate_list<'c>(&'c self, window: xproto::Window, mode: PropagateMode, classes: &[EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn xinput_change_device_dont_propagate_list<'c, A>(&'c self, window: xproto::Window, mode: A, classes: &[EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<PropagateMode>,
     {
         change_device_dont_propagate_list(self, window, mode, classes)
     }
@@ -14192,9 +17975,11 @@ pub trait ConnectionExt: RequestConnection {
     {
         change_pointer_device(self, x_axis, y_axis, device_id)
     }
-    fn xinput_grab_device<'c, A>(&'c self, grab_window: xproto::Window, time: A, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, owner_events: bool, device_id: u8, classes: &[EventClass]) -> Result<Cookie<'c, Self, GrabDeviceReply>, ConnectionError>
+    fn xinput_grab_device<'c, A, B, C>(&'c self, grab_window: xproto::Window, time: A, this_device_mode: B, other_device_mode: C, owner_events: bool, device_id: u8, classes: &[EventClass]) -> Result<Cookie<'c, Self, GrabDeviceReply>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
+        B: Into<xproto::GrabMode>,
+        C: Into<xproto::GrabMode>,
     {
         grab_device(self, grab_window, time, this_device_mode, other_device_mode, owner_events, device_id, classes)
     }
@@ -14204,41 +17989,46 @@ pub trait ConnectionExt: RequestConnection {
     {
         ungrab_device(self, time, device_id)
     }
-    fn xinput_grab_device_key<'c, A, B, C>(&'c self, grab_window: xproto::Window, modifiers: A, modifier_device: B, grabbed_device: u8, key: C, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, owner_events: bool, classes: &[EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn xinput_grab_device_key<'c, A, B, C, D, E>(&'c self, grab_window: xproto::Window, modifiers: A, modifier_device: B, grabbed_device: u8, key: C, this_device_mode: D, other_device_mode: E, owner_events: bool, classes: &[EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u16>,
+        A: Into<xproto::ModMask>,
         B: Into<u8>,
         C: Into<u8>,
+        D: Into<xproto::GrabMode>,
+        E: Into<xproto::GrabMode>,
     {
         grab_device_key(self, grab_window, modifiers, modifier_device, grabbed_device, key, this_device_mode, other_device_mode, owner_events, classes)
     }
     fn xinput_ungrab_device_key<A, B, C>(&self, grab_window: xproto::Window, modifiers: A, modifier_device: B, key: C, grabbed_device: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<u16>,
+        A: Into<xproto::ModMask>,
         B: Into<u8>,
         C: Into<u8>,
     {
         ungrab_device_key(self, grab_window, modifiers, modifier_device, key, grabbed_device)
     }
-    fn xinput_grab_device_button<'c, A, B, C>(&'c self, grab_window: xproto::Window, grabbed_device: u8, modifier_device: A, modifiers: B, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, button: C, owner_events: bool, classes: &[EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn xinput_grab_device_button<'c, A, B, C, D, E>(&'c self, grab_window: xproto::Window, grabbed_device: u8, modifier_device: A, modifiers: B, this_device_mode: C, other_device_mode: D, button: E, owner_events: bool, classes: &[EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u8>,
-        B: Into<u16>,
-        C: Into<u8>,
+        B: Into<xproto::ModMask>,
+        C: Into<xproto::GrabMode>,
+        D: Into<xproto::GrabMode>,
+        E: Into<u8>,
     {
         grab_device_button(self, grab_window, grabbed_device, modifier_device, modifiers, this_device_mode, other_device_mode, button, owner_events, classes)
     }
     fn xinput_ungrab_device_button<A, B, C>(&self, grab_window: xproto::Window, modifiers: A, modifier_device: B, button: C, grabbed_device: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<u16>,
+        A: Into<xproto::ModMask>,
         B: Into<u8>,
         C: Into<u8>,
     {
         ungrab_device_button(self, grab_window, modifiers, modifier_device, button, grabbed_device)
     }
-    fn xinput_allow_device_events<A>(&self, time: A, mode: DeviceInputMode, device_id: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xinput_allow_device_events<A, B>(&self, time: A, mode: B, device_id: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
+        B: Into<DeviceInputMode>,
     {
         allow_device_events(self, time, mode, device_id)
     }
@@ -14246,10 +18036,11 @@ pub trait ConnectionExt: RequestConnection {
     {
         get_device_focus(self, device_id)
     }
-    fn xinput_set_device_focus<A, B>(&self, focus: A, time: B, revert_to: xproto::InputFocus, device_id: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xinput_set_device_focus<A, B, C>(&self, focus: A, time: B, revert_to: C, device_id: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Window>,
         B: Into<xproto::Timestamp>,
+        C: Into<xproto::InputFocus>,
     {
         set_device_focus(self, focus, time, revert_to, device_id)
     }
@@ -14259,7 +18050,7 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn xinput_change_feedback_control<A>(&self, mask: A, device_id: u8, feedback_id: u8, feedback: FeedbackCtl) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<ChangeFeedbackControlMask>,
     {
         change_feedback_control(self, mask, device_id, feedback_id, feedback)
     }
@@ -14299,11 +18090,15 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_device_valuators(self, device_id, first_valuator, valuators)
     }
-    fn xinput_get_device_control(&self, control_id: DeviceControl, device_id: u8) -> Result<Cookie<'_, Self, GetDeviceControlReply>, ConnectionError>
+    fn xinput_get_device_control<A>(&self, control_id: A, device_id: u8) -> Result<Cookie<'_, Self, GetDeviceControlReply>, ConnectionError>
+    where
+        A: Into<DeviceControl>,
     {
         get_device_control(self, control_id, device_id)
     }
-    fn xinput_change_device_control(&self, control_id: DeviceControl, device_id: u8, control: DeviceCtl) -> Result<Cookie<'_, Self, ChangeDeviceControlReply>, ConnectionError>
+    fn xinput_change_device_control<A>(&self, control_id: A, device_id: u8, control: DeviceCtl) -> Result<Cookie<'_, Self, ChangeDeviceControlReply>, ConnectionError>
+    where
+        A: Into<DeviceControl>,
     {
         change_device_control(self, control_id, device_id, control)
     }
@@ -14311,7 +18106,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         list_device_properties(self, device_id)
     }
-    fn xinput_change_device_property<'c>(&'c self, property: xproto::Atom, type_: xproto::Atom, device_id: u8, mode: xproto::PropMode, num_items: u32, items: &ChangeDevicePropertyAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn xinput_change_device_property<'c, A>(&'c self, property: xproto::Atom, type_: xproto::Atom, device_id: u8, mode: A, num_items: u32, items: &ChangeDevicePropertyAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<xproto::PropMode>,
     {
         change_device_property(self, property, type_, device_id, mode, num_items, items)
     }
@@ -14382,10 +18179,13 @@ pub trait ConnectionExt: RequestConnection {
     {
         xi_get_focus(self, deviceid)
     }
-    fn xinput_xi_grab_device<'c, A, B>(&'c self, window: xproto::Window, time: A, cursor: xproto::Cursor, deviceid: B, mode: xproto::GrabMode, paired_device_mode: xproto::GrabMode, owner_events: GrabOwner, mask: &[u32]) -> Result<Cookie<'c, Self, XIGrabDeviceReply>, ConnectionError>
+    fn xinput_xi_grab_device<'c, A, B, C, D, E>(&'c self, window: xproto::Window, time: A, cursor: xproto::Cursor, deviceid: B, mode: C, paired_device_mode: D, owner_events: E, mask: &[u32]) -> Result<Cookie<'c, Self, XIGrabDeviceReply>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
         B: Into<DeviceId>,
+        C: Into<xproto::GrabMode>,
+        D: Into<xproto::GrabMode>,
+        E: Into<bool>,
     {
         xi_grab_device(self, window, time, cursor, deviceid, mode, paired_device_mode, owner_events, mask)
     }
@@ -14396,23 +18196,29 @@ pub trait ConnectionExt: RequestConnection {
     {
         xi_ungrab_device(self, time, deviceid)
     }
-    fn xinput_xi_allow_events<A, B>(&self, time: A, deviceid: B, event_mode: EventMode, touchid: u32, grab_window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xinput_xi_allow_events<A, B, C>(&self, time: A, deviceid: B, event_mode: C, touchid: u32, grab_window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
         B: Into<DeviceId>,
+        C: Into<EventMode>,
     {
         xi_allow_events(self, time, deviceid, event_mode, touchid, grab_window)
     }
-    fn xinput_xi_passive_grab_device<'c, A, B>(&'c self, time: A, grab_window: xproto::Window, cursor: xproto::Cursor, detail: u32, deviceid: B, grab_type: GrabType, grab_mode: GrabMode22, paired_device_mode: xproto::GrabMode, owner_events: GrabOwner, mask: &[u32], modifiers: &[u32]) -> Result<Cookie<'c, Self, XIPassiveGrabDeviceReply>, ConnectionError>
+    fn xinput_xi_passive_grab_device<'c, A, B, C, D, E, F>(&'c self, time: A, grab_window: xproto::Window, cursor: xproto::Cursor, detail: u32, deviceid: B, grab_type: C, grab_mode: D, paired_device_mode: E, owner_events: F, mask: &[u32], modifiers: &[u32]) -> Result<Cookie<'c, Self, XIPassiveGrabDeviceReply>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
         B: Into<DeviceId>,
+        C: Into<GrabType>,
+        D: Into<GrabMode22>,
+        E: Into<xproto::GrabMode>,
+        F: Into<bool>,
     {
         xi_passive_grab_device(self, time, grab_window, cursor, detail, deviceid, grab_type, grab_mode, paired_device_mode, owner_events, mask, modifiers)
     }
-    fn xinput_xi_passive_ungrab_device<'c, A>(&'c self, grab_window: xproto::Window, detail: u32, deviceid: A, grab_type: GrabType, modifiers: &[u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn xinput_xi_passive_ungrab_device<'c, A, B>(&'c self, grab_window: xproto::Window, detail: u32, deviceid: A, grab_type: B, modifiers: &[u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<DeviceId>,
+        B: Into<GrabType>,
     {
         xi_passive_ungrab_device(self, grab_window, detail, deviceid, grab_type, modifiers)
     }
@@ -14422,9 +18228,10 @@ pub trait ConnectionExt: RequestConnection {
     {
         xi_list_properties(self, deviceid)
     }
-    fn xinput_xi_change_property<'c, A>(&'c self, deviceid: A, mode: xproto::PropMode, property: xproto::Atom, type_: xproto::Atom, num_items: u32, items: &XIChangePropertyAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn xinput_xi_change_property<'c, A, B>(&'c self, deviceid: A, mode: B, property: xproto::Atom, type_: xproto::Atom, num_items: u32, items: &XIChangePropertyAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<DeviceId>,
+        B: Into<xproto::PropMode>,
     {
         xi_change_property(self, deviceid, mode, property, type_, num_items, items)
     }
@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -31,2009 +31,952 @@ pub const X11_EXTENSION_NAME: &str = "XKEYBOARD";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 0);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Const {
-    MaxLegalKeyCode = 255,
-    PerKeyBitArraySize = 32,
-    KeyNameLength = 4,
-}
-impl From<Const> for u8 {
-    fn from(input: Const) -> Self {
-        match input {
-            Const::MaxLegalKeyCode => 255,
-            Const::PerKeyBitArraySize => 32,
-            Const::KeyNameLength => 4,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Const(u8);
+#[allow(non_upper_case_globals)]
+impl Const {
+    pub const MaxLegalKeyCode: Self = Self(255);
+    pub const PerKeyBitArraySize: Self = Self(32);
+    pub const KeyNameLength: Self = Self(4);
 }
+enum_newtype!(Const, u8, [MaxLegalKeyCode, PerKeyBitArraySize, KeyNameLength]);
 impl From<Const> for Option<u8> {
     fn from(input: Const) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Const> for u16 {
     fn from(input: Const) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Const> for Option<u16> {
     fn from(input: Const) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Const> for u32 {
     fn from(input: Const) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Const> for Option<u32> {
     fn from(input: Const) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Const {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            255 => Ok(Const::MaxLegalKeyCode),
-            32 => Ok(Const::PerKeyBitArraySize),
-            4 => Ok(Const::KeyNameLength),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Const {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Const {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum EventType {
-    NewKeyboardNotify = 1 << 0,
-    MapNotify = 1 << 1,
-    StateNotify = 1 << 2,
-    ControlsNotify = 1 << 3,
-    IndicatorStateNotify = 1 << 4,
-    IndicatorMapNotify = 1 << 5,
-    NamesNotify = 1 << 6,
-    CompatMapNotify = 1 << 7,
-    BellNotify = 1 << 8,
-    ActionMessage = 1 << 9,
-    AccessXNotify = 1 << 10,
-    ExtensionDeviceNotify = 1 << 11,
-}
-impl From<EventType> for u16 {
-    fn from(input: EventType) -> Self {
-        match input {
-            EventType::NewKeyboardNotify => 1 << 0,
-            EventType::MapNotify => 1 << 1,
-            EventType::StateNotify => 1 << 2,
-            EventType::ControlsNotify => 1 << 3,
-            EventType::IndicatorStateNotify => 1 << 4,
-            EventType::IndicatorMapNotify => 1 << 5,
-            EventType::NamesNotify => 1 << 6,
-            EventType::CompatMapNotify => 1 << 7,
-            EventType::BellNotify => 1 << 8,
-            EventType::ActionMessage => 1 << 9,
-            EventType::AccessXNotify => 1 << 10,
-            EventType::ExtensionDeviceNotify => 1 << 11,
-        }
-    }
-}
+        Some(u32::from(input.0))
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EventType(u16);
+#[allow(non_upper_case_globals)]
+impl EventType {
+    pub const NewKeyboardNotify: Self = Self(1 << 0);
+    pub const MapNotify: Self = Self(1 << 1);
+    pub const StateNotify: Self = Self(1 << 2);
+    pub const ControlsNotify: Self = Self(1 << 3);
+    pub const IndicatorStateNotify: Self = Self(1 << 4);
+    pub const IndicatorMapNotify: Self = Self(1 << 5);
+    pub const NamesNotify: Self = Self(1 << 6);
+    pub const CompatMapNotify: Self = Self(1 << 7);
+    pub const BellNotify: Self = Self(1 << 8);
+    pub const ActionMessage: Self = Self(1 << 9);
+    pub const AccessXNotify: Self = Self(1 << 10);
+    pub const ExtensionDeviceNotify: Self = Self(1 << 11);
+}
+bitmask_set!(EventType, u16, [NewKeyboardNotify, MapNotify, StateNotify, ControlsNotify, IndicatorStateNotify, IndicatorMapNotify, NamesNotify, CompatMapNotify, BellNotify, ActionMessage, AccessXNotify, ExtensionDeviceNotify]);
 impl From<EventType> for Option<u16> {
     fn from(input: EventType) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<EventType> for u32 {
     fn from(input: EventType) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<EventType> for Option<u32> {
     fn from(input: EventType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for EventType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(EventType::NewKeyboardNotify),
-            2 => Ok(EventType::MapNotify),
-            4 => Ok(EventType::StateNotify),
-            8 => Ok(EventType::ControlsNotify),
-            16 => Ok(EventType::IndicatorStateNotify),
-            32 => Ok(EventType::IndicatorMapNotify),
-            64 => Ok(EventType::NamesNotify),
-            128 => Ok(EventType::CompatMapNotify),
-            256 => Ok(EventType::BellNotify),
-            512 => Ok(EventType::ActionMessage),
-            1024 => Ok(EventType::AccessXNotify),
-            2048 => Ok(EventType::ExtensionDeviceNotify),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for EventType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(EventType, u16);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum NKNDetail {
-    Keycodes = 1 << 0,
-    Geometry = 1 << 1,
-    DeviceID = 1 << 2,
-}
-impl From<NKNDetail> for u8 {
-    fn from(input: NKNDetail) -> Self {
-        match input {
-            NKNDetail::Keycodes => 1 << 0,
-            NKNDetail::Geometry => 1 << 1,
-            NKNDetail::DeviceID => 1 << 2,
-        }
-    }
-}
-impl From<NKNDetail> for Option<u8> {
-    fn from(input: NKNDetail) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<NKNDetail> for u16 {
-    fn from(input: NKNDetail) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NKNDetail(u16);
+#[allow(non_upper_case_globals)]
+impl NKNDetail {
+    pub const Keycodes: Self = Self(1 << 0);
+    pub const Geometry: Self = Self(1 << 1);
+    pub const DeviceID: Self = Self(1 << 2);
 }
+bitmask_set!(NKNDetail, u16, [Keycodes, Geometry, DeviceID]);
 impl From<NKNDetail> for Option<u16> {
     fn from(input: NKNDetail) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<NKNDetail> for u32 {
     fn from(input: NKNDetail) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<NKNDetail> for Option<u32> {
     fn from(input: NKNDetail) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for NKNDetail {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(NKNDetail::Keycodes),
-            2 => Ok(NKNDetail::Geometry),
-            4 => Ok(NKNDetail::DeviceID),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for NKNDetail {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for NKNDetail {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(NKNDetail, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum AXNDetail {
-    SKPress = 1 << 0,
-    SKAccept = 1 << 1,
-    SKReject = 1 << 2,
-    SKRelease = 1 << 3,
-    BKAccept = 1 << 4,
-    BKReject = 1 << 5,
-    AXKWarning = 1 << 6,
-}
-impl From<AXNDetail> for u8 {
-    fn from(input: AXNDetail) -> Self {
-        match input {
-            AXNDetail::SKPress => 1 << 0,
-            AXNDetail::SKAccept => 1 << 1,
-            AXNDetail::SKReject => 1 << 2,
-            AXNDetail::SKRelease => 1 << 3,
-            AXNDetail::BKAccept => 1 << 4,
-            AXNDetail::BKReject => 1 << 5,
-            AXNDetail::AXKWarning => 1 << 6,
-        }
-    }
-}
-impl From<AXNDetail> for Option<u8> {
-    fn from(input: AXNDetail) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<AXNDetail> for u16 {
-    fn from(input: AXNDetail) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AXNDetail(u16);
+#[allow(non_upper_case_globals)]
+impl AXNDetail {
+    pub const SKPress: Self = Self(1 << 0);
+    pub const SKAccept: Self = Self(1 << 1);
+    pub const SKReject: Self = Self(1 << 2);
+    pub const SKRelease: Self = Self(1 << 3);
+    pub const BKAccept: Self = Self(1 << 4);
+    pub const BKReject: Self = Self(1 << 5);
+    pub const AXKWarning: Self = Self(1 << 6);
 }
+bitmask_set!(AXNDetail, u16, [SKPress, SKAccept, SKReject, SKRelease, BKAccept, BKReject, AXKWarning]);
 impl From<AXNDetail> for Option<u16> {
     fn from(input: AXNDetail) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<AXNDetail> for u32 {
     fn from(input: AXNDetail) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<AXNDetail> for Option<u32> {
     fn from(input: AXNDetail) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for AXNDetail {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(AXNDetail::SKPress),
-            2 => Ok(AXNDetail::SKAccept),
-            4 => Ok(AXNDetail::SKReject),
-            8 => Ok(AXNDetail::SKRelease),
-            16 => Ok(AXNDetail::BKAccept),
-            32 => Ok(AXNDetail::BKReject),
-            64 => Ok(AXNDetail::AXKWarning),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for AXNDetail {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for AXNDetail {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(AXNDetail, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum MapPart {
-    KeyTypes = 1 << 0,
-    KeySyms = 1 << 1,
-    ModifierMap = 1 << 2,
-    ExplicitComponents = 1 << 3,
-    KeyActions = 1 << 4,
-    KeyBehaviors = 1 << 5,
-    VirtualMods = 1 << 6,
-    VirtualModMap = 1 << 7,
-}
-impl From<MapPart> for u8 {
-    fn from(input: MapPart) -> Self {
-        match input {
-            MapPart::KeyTypes => 1 << 0,
-            MapPart::KeySyms => 1 << 1,
-            MapPart::ModifierMap => 1 << 2,
-            MapPart::ExplicitComponents => 1 << 3,
-            MapPart::KeyActions => 1 << 4,
-            MapPart::KeyBehaviors => 1 << 5,
-            MapPart::VirtualMods => 1 << 6,
-            MapPart::VirtualModMap => 1 << 7,
-        }
-    }
-}
-impl From<MapPart> for Option<u8> {
-    fn from(input: MapPart) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<MapPart> for u16 {
-    fn from(input: MapPart) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MapPart(u16);
+#[allow(non_upper_case_globals)]
+impl MapPart {
+    pub const KeyTypes: Self = Self(1 << 0);
+    pub const KeySyms: Self = Self(1 << 1);
+    pub const ModifierMap: Self = Self(1 << 2);
+    pub const ExplicitComponents: Self = Self(1 << 3);
+    pub const KeyActions: Self = Self(1 << 4);
+    pub const KeyBehaviors: Self = Self(1 << 5);
+    pub const VirtualMods: Self = Self(1 << 6);
+    pub const VirtualModMap: Self = Self(1 << 7);
 }
+bitmask_set!(MapPart, u16, [KeyTypes, KeySyms, ModifierMap, ExplicitComponents, KeyActions, KeyBehaviors, VirtualMods, VirtualModMap]);
 impl From<MapPart> for Option<u16> {
     fn from(input: MapPart) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<MapPart> for u32 {
     fn from(input: MapPart) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<MapPart> for Option<u32> {
     fn from(input: MapPart) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for MapPart {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(MapPart::KeyTypes),
-            2 => Ok(MapPart::KeySyms),
-            4 => Ok(MapPart::ModifierMap),
-            8 => Ok(MapPart::ExplicitComponents),
-            16 => Ok(MapPart::KeyActions),
-            32 => Ok(MapPart::KeyBehaviors),
-            64 => Ok(MapPart::VirtualMods),
-            128 => Ok(MapPart::VirtualModMap),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for MapPart {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for MapPart {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(MapPart, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SetMapFlags {
-    ResizeTypes = 1 << 0,
-    RecomputeActions = 1 << 1,
-}
-impl From<SetMapFlags> for u8 {
-    fn from(input: SetMapFlags) -> Self {
-        match input {
-            SetMapFlags::ResizeTypes => 1 << 0,
-            SetMapFlags::RecomputeActions => 1 << 1,
-        }
-    }
-}
-impl From<SetMapFlags> for Option<u8> {
-    fn from(input: SetMapFlags) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<SetMapFlags> for u16 {
-    fn from(input: SetMapFlags) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SetMapFlags(u16);
+#[allow(non_upper_case_globals)]
+impl SetMapFlags {
+    pub const ResizeTypes: Self = Self(1 << 0);
+    pub const RecomputeActions: Self = Self(1 << 1);
 }
+bitmask_set!(SetMapFlags, u16, [ResizeTypes, RecomputeActions]);
 impl From<SetMapFlags> for Option<u16> {
     fn from(input: SetMapFlags) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<SetMapFlags> for u32 {
     fn from(input: SetMapFlags) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SetMapFlags> for Option<u32> {
     fn from(input: SetMapFlags) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SetMapFlags {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(SetMapFlags::ResizeTypes),
-            2 => Ok(SetMapFlags::RecomputeActions),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SetMapFlags {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SetMapFlags {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(SetMapFlags, u8);
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum StatePart {
-    ModifierState = 1 << 0,
-    ModifierBase = 1 << 1,
-    ModifierLatch = 1 << 2,
-    ModifierLock = 1 << 3,
-    GroupState = 1 << 4,
-    GroupBase = 1 << 5,
-    GroupLatch = 1 << 6,
-    GroupLock = 1 << 7,
-    CompatState = 1 << 8,
-    GrabMods = 1 << 9,
-    CompatGrabMods = 1 << 10,
-    LookupMods = 1 << 11,
-    CompatLookupMods = 1 << 12,
-    PointerButtons = 1 << 13,
-}
-impl From<StatePart> for u16 {
-    fn from(input: StatePart) -> Self {
-        match input {
-            StatePart::ModifierState => 1 << 0,
-            StatePart::ModifierBase => 1 << 1,
-            StatePart::ModifierLatch => 1 << 2,
-            StatePart::ModifierLock => 1 << 3,
-            StatePart::GroupState => 1 << 4,
-            StatePart::GroupBase => 1 << 5,
-            StatePart::GroupLatch => 1 << 6,
-            StatePart::GroupLock => 1 << 7,
-            StatePart::CompatState => 1 << 8,
-            StatePart::GrabMods => 1 << 9,
-            StatePart::CompatGrabMods => 1 << 10,
-            StatePart::LookupMods => 1 << 11,
-            StatePart::CompatLookupMods => 1 << 12,
-            StatePart::PointerButtons => 1 << 13,
-        }
-    }
-}
+        Some(u32::from(input.0))
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StatePart(u16);
+#[allow(non_upper_case_globals)]
+impl StatePart {
+    pub const ModifierState: Self = Self(1 << 0);
+    pub const ModifierBase: Self = Self(1 << 1);
+    pub const ModifierLatch: Self = Self(1 << 2);
+    pub const ModifierLock: Self = Self(1 << 3);
+    pub const GroupState: Self = Self(1 << 4);
+    pub const GroupBase: Self = Self(1 << 5);
+    pub const GroupLatch: Self = Self(1 << 6);
+    pub const GroupLock: Self = Self(1 << 7);
+    pub const CompatState: Self = Self(1 << 8);
+    pub const GrabMods: Self = Self(1 << 9);
+    pub const CompatGrabMods: Self = Self(1 << 10);
+    pub const LookupMods: Self = Self(1 << 11);
+    pub const CompatLookupMods: Self = Self(1 << 12);
+    pub const PointerButtons: Self = Self(1 << 13);
+}
+bitmask_set!(StatePart, u16, [ModifierState, ModifierBase, ModifierLatch, ModifierLock, GroupState, GroupBase, GroupLatch, GroupLock, CompatState, GrabMods, CompatGrabMods, LookupMods, CompatLookupMods, PointerButtons]);
 impl From<StatePart> for Option<u16> {
     fn from(input: StatePart) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<StatePart> for u32 {
     fn from(input: StatePart) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<StatePart> for Option<u32> {
     fn from(input: StatePart) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for StatePart {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(StatePart::ModifierState),
-            2 => Ok(StatePart::ModifierBase),
-            4 => Ok(StatePart::ModifierLatch),
-            8 => Ok(StatePart::ModifierLock),
-            16 => Ok(StatePart::GroupState),
-            32 => Ok(StatePart::GroupBase),
-            64 => Ok(StatePart::GroupLatch),
-            128 => Ok(StatePart::GroupLock),
-            256 => Ok(StatePart::CompatState),
-            512 => Ok(StatePart::GrabMods),
-            1024 => Ok(StatePart::CompatGrabMods),
-            2048 => Ok(StatePart::LookupMods),
-            4096 => Ok(StatePart::CompatLookupMods),
-            8192 => Ok(StatePart::PointerButtons),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for StatePart {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(StatePart, u16);
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum BoolCtrl {
-    RepeatKeys = 1 << 0,
-    SlowKeys = 1 << 1,
-    BounceKeys = 1 << 2,
-    StickyKeys = 1 << 3,
-    MouseKeys = 1 << 4,
-    MouseKeysAccel = 1 << 5,
-    AccessXKeys = 1 << 6,
-    AccessXTimeoutMask = 1 << 7,
-    AccessXFeedbackMask = 1 << 8,
-    AudibleBellMask = 1 << 9,
-    Overlay1Mask = 1 << 10,
-    Overlay2Mask = 1 << 11,
-    IgnoreGroupLockMask = 1 << 12,
-}
-impl From<BoolCtrl> for u16 {
-    fn from(input: BoolCtrl) -> Self {
-        match input {
-            BoolCtrl::RepeatKeys => 1 << 0,
-            BoolCtrl::SlowKeys => 1 << 1,
-            BoolCtrl::BounceKeys => 1 << 2,
-            BoolCtrl::StickyKeys => 1 << 3,
-            BoolCtrl::MouseKeys => 1 << 4,
-            BoolCtrl::MouseKeysAccel => 1 << 5,
-            BoolCtrl::AccessXKeys => 1 << 6,
-            BoolCtrl::AccessXTimeoutMask => 1 << 7,
-            BoolCtrl::AccessXFeedbackMask => 1 << 8,
-            BoolCtrl::AudibleBellMask => 1 << 9,
-            BoolCtrl::Overlay1Mask => 1 << 10,
-            BoolCtrl::Overlay2Mask => 1 << 11,
-            BoolCtrl::IgnoreGroupLockMask => 1 << 12,
-        }
-    }
-}
-impl From<BoolCtrl> for Option<u16> {
-    fn from(input: BoolCtrl) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<BoolCtrl> for u32 {
-    fn from(input: BoolCtrl) -> Self {
-        Self::from(u16::from(input))
-    }
-}
+        Some(u32::from(input.0))
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BoolCtrl(u32);
+#[allow(non_upper_case_globals)]
+impl BoolCtrl {
+    pub const RepeatKeys: Self = Self(1 << 0);
+    pub const SlowKeys: Self = Self(1 << 1);
+    pub const BounceKeys: Self = Self(1 << 2);
+    pub const StickyKeys: Self = Self(1 << 3);
+    pub const MouseKeys: Self = Self(1 << 4);
+    pub const MouseKeysAccel: Self = Self(1 << 5);
+    pub const AccessXKeys: Self = Self(1 << 6);
+    pub const AccessXTimeoutMask: Self = Self(1 << 7);
+    pub const AccessXFeedbackMask: Self = Self(1 << 8);
+    pub const AudibleBellMask: Self = Self(1 << 9);
+    pub const Overlay1Mask: Self = Self(1 << 10);
+    pub const Overlay2Mask: Self = Self(1 << 11);
+    pub const IgnoreGroupLockMask: Self = Self(1 << 12);
+}
+bitmask_set!(BoolCtrl, u32, [RepeatKeys, SlowKeys, BounceKeys, StickyKeys, MouseKeys, MouseKeysAccel, AccessXKeys, AccessXTimeoutMask, AccessXFeedbackMask, AudibleBellMask, Overlay1Mask, Overlay2Mask, IgnoreGroupLockMask]);
 impl From<BoolCtrl> for Option<u32> {
     fn from(input: BoolCtrl) -> Self {
-        Some(u32::from(input))
+        Some(input.0)
     }
 }
-impl TryFrom<u16> for BoolCtrl {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(BoolCtrl::RepeatKeys),
-            2 => Ok(BoolCtrl::SlowKeys),
-            4 => Ok(BoolCtrl::BounceKeys),
-            8 => Ok(BoolCtrl::StickyKeys),
-            16 => Ok(BoolCtrl::MouseKeys),
-            32 => Ok(BoolCtrl::MouseKeysAccel),
-            64 => Ok(BoolCtrl::AccessXKeys),
-            128 => Ok(BoolCtrl::AccessXTimeoutMask),
-            256 => Ok(BoolCtrl::AccessXFeedbackMask),
-            512 => Ok(BoolCtrl::AudibleBellMask),
-            1024 => Ok(BoolCtrl::Overlay1Mask),
-            2048 => Ok(BoolCtrl::Overlay2Mask),
-            4096 => Ok(BoolCtrl::IgnoreGroupLockMask),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for BoolCtrl {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(BoolCtrl, u16);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
-pub enum Control {
-    GroupsWrap = 1 << 27,
-    InternalMods = 1 << 28,
-    IgnoreLockMods = 1 << 29,
-    PerKeyRepeat = 1 << 30,
-    ControlsEnabled = 1 << 31,
-}
-impl From<Control> for u32 {
-    fn from(input: Control) -> Self {
-        match input {
-            Control::GroupsWrap => 1 << 27,
-            Control::InternalMods => 1 << 28,
-            Control::IgnoreLockMods => 1 << 29,
-            Control::PerKeyRepeat => 1 << 30,
-            Control::ControlsEnabled => 1 << 31,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Control(u32);
+#[allow(non_upper_case_globals)]
+impl Control {
+    pub const GroupsWrap: Self = Self(1 << 27);
+    pub const InternalMods: Self = Self(1 << 28);
+    pub const IgnoreLockMods: Self = Self(1 << 29);
+    pub const PerKeyRepeat: Self = Self(1 << 30);
+    pub const ControlsEnabled: Self = Self(1 << 31);
 }
+bitmask_set!(Control, u32, [GroupsWrap, InternalMods, IgnoreLockMods, PerKeyRepeat, ControlsEnabled]);
 impl From<Control> for Option<u32> {
     fn from(input: Control) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u32> for Control {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            134_217_728 => Ok(Control::GroupsWrap),
-            268_435_456 => Ok(Control::InternalMods),
-            536_870_912 => Ok(Control::IgnoreLockMods),
-            1_073_741_824 => Ok(Control::PerKeyRepeat),
-            2_147_483_648 => Ok(Control::ControlsEnabled),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-bitmask_binop!(Control, u32);
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum AXOption {
-    SKPressFB = 1 << 0,
-    SKAcceptFB = 1 << 1,
-    FeatureFB = 1 << 2,
-    SlowWarnFB = 1 << 3,
-    IndicatorFB = 1 << 4,
-    StickyKeysFB = 1 << 5,
-    TwoKeys = 1 << 6,
-    LatchToLock = 1 << 7,
-    SKReleaseFB = 1 << 8,
-    SKRejectFB = 1 << 9,
-    BKRejectFB = 1 << 10,
-    DumbBell = 1 << 11,
-}
-impl From<AXOption> for u16 {
-    fn from(input: AXOption) -> Self {
-        match input {
-            AXOption::SKPressFB => 1 << 0,
-            AXOption::SKAcceptFB => 1 << 1,
-            AXOption::FeatureFB => 1 << 2,
-            AXOption::SlowWarnFB => 1 << 3,
-            AXOption::IndicatorFB => 1 << 4,
-            AXOption::StickyKeysFB => 1 << 5,
-            AXOption::TwoKeys => 1 << 6,
-            AXOption::LatchToLock => 1 << 7,
-            AXOption::SKReleaseFB => 1 << 8,
-            AXOption::SKRejectFB => 1 << 9,
-            AXOption::BKRejectFB => 1 << 10,
-            AXOption::DumbBell => 1 << 11,
-        }
-    }
-}
+        Some(input.0)
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AXOption(u16);
+#[allow(non_upper_case_globals)]
+impl AXOption {
+    pub const SKPressFB: Self = Self(1 << 0);
+    pub const SKAcceptFB: Self = Self(1 << 1);
+    pub const FeatureFB: Self = Self(1 << 2);
+    pub const SlowWarnFB: Self = Self(1 << 3);
+    pub const IndicatorFB: Self = Self(1 << 4);
+    pub const StickyKeysFB: Self = Self(1 << 5);
+    pub const TwoKeys: Self = Self(1 << 6);
+    pub const LatchToLock: Self = Self(1 << 7);
+    pub const SKReleaseFB: Self = Self(1 << 8);
+    pub const SKRejectFB: Self = Self(1 << 9);
+    pub const BKRejectFB: Self = Self(1 << 10);
+    pub const DumbBell: Self = Self(1 << 11);
+}
+bitmask_set!(AXOption, u16, [SKPressFB, SKAcceptFB, FeatureFB, SlowWarnFB, IndicatorFB, StickyKeysFB, TwoKeys, LatchToLock, SKReleaseFB, SKRejectFB, BKRejectFB, DumbBell]);
 impl From<AXOption> for Option<u16> {
     fn from(input: AXOption) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<AXOption> for u32 {
     fn from(input: AXOption) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<AXOption> for Option<u32> {
     fn from(input: AXOption) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for AXOption {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(AXOption::SKPressFB),
-            2 => Ok(AXOption::SKAcceptFB),
-            4 => Ok(AXOption::FeatureFB),
-            8 => Ok(AXOption::SlowWarnFB),
-            16 => Ok(AXOption::IndicatorFB),
-            32 => Ok(AXOption::StickyKeysFB),
-            64 => Ok(AXOption::TwoKeys),
-            128 => Ok(AXOption::LatchToLock),
-            256 => Ok(AXOption::SKReleaseFB),
-            512 => Ok(AXOption::SKRejectFB),
-            1024 => Ok(AXOption::BKRejectFB),
-            2048 => Ok(AXOption::DumbBell),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for AXOption {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(AXOption, u16);
 
 pub type DeviceSpec = u16;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum LedClassResult {
-    KbdFeedbackClass = 0,
-    LedFeedbackClass = 4,
-}
-impl From<LedClassResult> for u8 {
-    fn from(input: LedClassResult) -> Self {
-        match input {
-            LedClassResult::KbdFeedbackClass => 0,
-            LedClassResult::LedFeedbackClass => 4,
-        }
-    }
-}
-impl From<LedClassResult> for Option<u8> {
-    fn from(input: LedClassResult) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<LedClassResult> for u16 {
-    fn from(input: LedClassResult) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LedClassResult(u16);
+#[allow(non_upper_case_globals)]
+impl LedClassResult {
+    pub const KbdFeedbackClass: Self = Self(0);
+    pub const LedFeedbackClass: Self = Self(4);
 }
+enum_newtype!(LedClassResult, u16, [KbdFeedbackClass, LedFeedbackClass]);
 impl From<LedClassResult> for Option<u16> {
     fn from(input: LedClassResult) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<LedClassResult> for u32 {
     fn from(input: LedClassResult) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<LedClassResult> for Option<u32> {
     fn from(input: LedClassResult) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for LedClassResult {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(LedClassResult::KbdFeedbackClass),
-            4 => Ok(LedClassResult::LedFeedbackClass),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for LedClassResult {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for LedClassResult {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum LedClass {
-    KbdFeedbackClass = 0,
-    LedFeedbackClass = 4,
-    DfltXIClass = 768,
-    AllXIClasses = 1280,
-}
-impl From<LedClass> for u16 {
-    fn from(input: LedClass) -> Self {
-        match input {
-            LedClass::KbdFeedbackClass => 0,
-            LedClass::LedFeedbackClass => 4,
-            LedClass::DfltXIClass => 768,
-            LedClass::AllXIClasses => 1280,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LedClass(u16);
+#[allow(non_upper_case_globals)]
+impl LedClass {
+    pub const KbdFeedbackClass: Self = Self(0);
+    pub const LedFeedbackClass: Self = Self(4);
+    pub const DfltXIClass: Self = Self(768);
+    pub const AllXIClasses: Self = Self(1280);
 }
+enum_newtype!(LedClass, u16, [KbdFeedbackClass, LedFeedbackClass, DfltXIClass, AllXIClasses]);
 impl From<LedClass> for Option<u16> {
     fn from(input: LedClass) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<LedClass> for u32 {
     fn from(input: LedClass) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<LedClass> for Option<u32> {
     fn from(input: LedClass) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for LedClass {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(LedClass::KbdFeedbackClass),
-            4 => Ok(LedClass::LedFeedbackClass),
-            768 => Ok(LedClass::DfltXIClass),
-            1280 => Ok(LedClass::AllXIClasses),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for LedClass {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
 pub type LedClassSpec = u16;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum BellClassResult {
-    KbdFeedbackClass = 0,
-    BellFeedbackClass = 5,
-}
-impl From<BellClassResult> for u8 {
-    fn from(input: BellClassResult) -> Self {
-        match input {
-            BellClassResult::KbdFeedbackClass => 0,
-            BellClassResult::BellFeedbackClass => 5,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BellClassResult(u8);
+#[allow(non_upper_case_globals)]
+impl BellClassResult {
+    pub const KbdFeedbackClass: Self = Self(0);
+    pub const BellFeedbackClass: Self = Self(5);
 }
+enum_newtype!(BellClassResult, u8, [KbdFeedbackClass, BellFeedbackClass]);
 impl From<BellClassResult> for Option<u8> {
     fn from(input: BellClassResult) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<BellClassResult> for u16 {
     fn from(input: BellClassResult) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<BellClassResult> for Option<u16> {
     fn from(input: BellClassResult) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<BellClassResult> for u32 {
     fn from(input: BellClassResult) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<BellClassResult> for Option<u32> {
     fn from(input: BellClassResult) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for BellClassResult {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(BellClassResult::KbdFeedbackClass),
-            5 => Ok(BellClassResult::BellFeedbackClass),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for BellClassResult {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for BellClassResult {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum BellClass {
-    KbdFeedbackClass = 0,
-    BellFeedbackClass = 5,
-    DfltXIClass = 768,
-}
-impl From<BellClass> for u16 {
-    fn from(input: BellClass) -> Self {
-        match input {
-            BellClass::KbdFeedbackClass => 0,
-            BellClass::BellFeedbackClass => 5,
-            BellClass::DfltXIClass => 768,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BellClass(u16);
+#[allow(non_upper_case_globals)]
+impl BellClass {
+    pub const KbdFeedbackClass: Self = Self(0);
+    pub const BellFeedbackClass: Self = Self(5);
+    pub const DfltXIClass: Self = Self(768);
 }
+enum_newtype!(BellClass, u16, [KbdFeedbackClass, BellFeedbackClass, DfltXIClass]);
 impl From<BellClass> for Option<u16> {
     fn from(input: BellClass) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<BellClass> for u32 {
     fn from(input: BellClass) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<BellClass> for Option<u32> {
     fn from(input: BellClass) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for BellClass {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(BellClass::KbdFeedbackClass),
-            5 => Ok(BellClass::BellFeedbackClass),
-            768 => Ok(BellClass::DfltXIClass),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for BellClass {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
 pub type BellClassSpec = u16;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum ID {
-    UseCoreKbd = 256,
-    UseCorePtr = 512,
-    DfltXIClass = 768,
-    DfltXIId = 1024,
-    AllXIClass = 1280,
-    AllXIId = 1536,
-    XINone = 65280,
-}
-impl From<ID> for u16 {
-    fn from(input: ID) -> Self {
-        match input {
-            ID::UseCoreKbd => 256,
-            ID::UseCorePtr => 512,
-            ID::DfltXIClass => 768,
-            ID::DfltXIId => 1024,
-            ID::AllXIClass => 1280,
-            ID::AllXIId => 1536,
-            ID::XINone => 65280,
-        }
-    }
-}
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ID(u16);
+#[allow(non_upper_case_globals)]
+impl ID {
+    pub const UseCoreKbd: Self = Self(256);
+    pub const UseCorePtr: Self = Self(512);
+    pub const DfltXIClass: Self = Self(768);
+    pub const DfltXIId: Self = Self(1024);
+    pub const AllXIClass: Self = Self(1280);
+    pub const AllXIId: Self = Self(1536);
+    pub const XINone: Self = Self(65280);
+}
+enum_newtype!(ID, u16, [UseCoreKbd, UseCorePtr, DfltXIClass, DfltXIId, AllXIClass, AllXIId, XINone]);
 impl From<ID> for Option<u16> {
     fn from(input: ID) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<ID> for u32 {
     fn from(input: ID) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ID> for Option<u32> {
     fn from(input: ID) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for ID {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            256 => Ok(ID::UseCoreKbd),
-            512 => Ok(ID::UseCorePtr),
-            768 => Ok(ID::DfltXIClass),
-            1024 => Ok(ID::DfltXIId),
-            1280 => Ok(ID::AllXIClass),
-            1536 => Ok(ID::AllXIId),
-            65280 => Ok(ID::XINone),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for ID {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
 pub type IDSpec = u16;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Group {
-    M1 = 0,
-    M2 = 1,
-    M3 = 2,
-    M4 = 3,
-}
-impl From<Group> for u8 {
-    fn from(input: Group) -> Self {
-        match input {
-            Group::M1 => 0,
-            Group::M2 => 1,
-            Group::M3 => 2,
-            Group::M4 => 3,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Group(u8);
+#[allow(non_upper_case_globals)]
+impl Group {
+    pub const M1: Self = Self(0);
+    pub const M2: Self = Self(1);
+    pub const M3: Self = Self(2);
+    pub const M4: Self = Self(3);
 }
+enum_newtype!(Group, u8, [M1, M2, M3, M4]);
 impl From<Group> for Option<u8> {
     fn from(input: Group) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Group> for u16 {
     fn from(input: Group) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Group> for Option<u16> {
     fn from(input: Group) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Group> for u32 {
     fn from(input: Group) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Group> for Option<u32> {
     fn from(input: Group) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Group {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Group::M1),
-            1 => Ok(Group::M2),
-            2 => Ok(Group::M3),
-            3 => Ok(Group::M4),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Group {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Group {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Groups {
-    Any = 254,
-    All = 255,
-}
-impl From<Groups> for u8 {
-    fn from(input: Groups) -> Self {
-        match input {
-            Groups::Any => 254,
-            Groups::All => 255,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Groups(u8);
+#[allow(non_upper_case_globals)]
+impl Groups {
+    pub const Any: Self = Self(254);
+    pub const All: Self = Self(255);
 }
+enum_newtype!(Groups, u8, [Any, All]);
 impl From<Groups> for Option<u8> {
     fn from(input: Groups) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Groups> for u16 {
     fn from(input: Groups) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Groups> for Option<u16> {
     fn from(input: Groups) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Groups> for u32 {
     fn from(input: Groups) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Groups> for Option<u32> {
     fn from(input: Groups) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Groups {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            254 => Ok(Groups::Any),
-            255 => Ok(Groups::All),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Groups {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Groups {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SetOfGroup {
-    Group1 = 1 << 0,
-    Group2 = 1 << 1,
-    Group3 = 1 << 2,
-    Group4 = 1 << 3,
-}
-impl From<SetOfGroup> for u8 {
-    fn from(input: SetOfGroup) -> Self {
-        match input {
-            SetOfGroup::Group1 => 1 << 0,
-            SetOfGroup::Group2 => 1 << 1,
-            SetOfGroup::Group3 => 1 << 2,
-            SetOfGroup::Group4 => 1 << 3,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SetOfGroup(u8);
+#[allow(non_upper_case_globals)]
+impl SetOfGroup {
+    pub const Group1: Self = Self(1 << 0);
+    pub const Group2: Self = Self(1 << 1);
+    pub const Group3: Self = Self(1 << 2);
+    pub const Group4: Self = Self(1 << 3);
 }
+bitmask_set!(SetOfGroup, u8, [Group1, Group2, Group3, Group4]);
 impl From<SetOfGroup> for Option<u8> {
     fn from(input: SetOfGroup) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SetOfGroup> for u16 {
     fn from(input: SetOfGroup) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SetOfGroup> for Option<u16> {
     fn from(input: SetOfGroup) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SetOfGroup> for u32 {
     fn from(input: SetOfGroup) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SetOfGroup> for Option<u32> {
     fn from(input: SetOfGroup) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for SetOfGroup {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(SetOfGroup::Group1),
-            2 => Ok(SetOfGroup::Group2),
-            4 => Ok(SetOfGroup::Group3),
-            8 => Ok(SetOfGroup::Group4),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SetOfGroup {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SetOfGroup {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(SetOfGroup, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SetOfGroups {
-    Any = 1 << 7,
-}
-impl From<SetOfGroups> for u8 {
-    fn from(input: SetOfGroups) -> Self {
-        match input {
-            SetOfGroups::Any => 1 << 7,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SetOfGroups(u8);
+#[allow(non_upper_case_globals)]
+impl SetOfGroups {
+    pub const Any: Self = Self(1 << 7);
 }
+bitmask_set!(SetOfGroups, u8, [Any]);
 impl From<SetOfGroups> for Option<u8> {
     fn from(input: SetOfGroups) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SetOfGroups> for u16 {
     fn from(input: SetOfGroups) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SetOfGroups> for Option<u16> {
     fn from(input: SetOfGroups) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SetOfGroups> for u32 {
     fn from(input: SetOfGroups) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SetOfGroups> for Option<u32> {
     fn from(input: SetOfGroups) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for SetOfGroups {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            128 => Ok(SetOfGroups::Any),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SetOfGroups {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SetOfGroups {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(SetOfGroups, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GroupsWrap {
-    WrapIntoRange = 0,
-    ClampIntoRange = 1 << 6,
-    RedirectIntoRange = 1 << 7,
-}
-impl From<GroupsWrap> for u8 {
-    fn from(input: GroupsWrap) -> Self {
-        match input {
-            GroupsWrap::WrapIntoRange => 0,
-            GroupsWrap::ClampIntoRange => 1 << 6,
-            GroupsWrap::RedirectIntoRange => 1 << 7,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GroupsWrap(u8);
+#[allow(non_upper_case_globals)]
+impl GroupsWrap {
+    pub const WrapIntoRange: Self = Self(0);
+    pub const ClampIntoRange: Self = Self(1 << 6);
+    pub const RedirectIntoRange: Self = Self(1 << 7);
 }
+bitmask_set!(GroupsWrap, u8, [ClampIntoRange, RedirectIntoRange]);
 impl From<GroupsWrap> for Option<u8> {
     fn from(input: GroupsWrap) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<GroupsWrap> for u16 {
     fn from(input: GroupsWrap) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GroupsWrap> for Option<u16> {
     fn from(input: GroupsWrap) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<GroupsWrap> for u32 {
     fn from(input: GroupsWrap) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GroupsWrap> for Option<u32> {
     fn from(input: GroupsWrap) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for GroupsWrap {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(GroupsWrap::WrapIntoRange),
-            64 => Ok(GroupsWrap::ClampIntoRange),
-            128 => Ok(GroupsWrap::RedirectIntoRange),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for GroupsWrap {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for GroupsWrap {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(GroupsWrap, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum VModsHigh {
-    M15 = 1 << 7,
-    M14 = 1 << 6,
-    M13 = 1 << 5,
-    M12 = 1 << 4,
-    M11 = 1 << 3,
-    M10 = 1 << 2,
-    M9 = 1 << 1,
-    M8 = 1 << 0,
-}
-impl From<VModsHigh> for u8 {
-    fn from(input: VModsHigh) -> Self {
-        match input {
-            VModsHigh::M15 => 1 << 7,
-            VModsHigh::M14 => 1 << 6,
-            VModsHigh::M13 => 1 << 5,
-            VModsHigh::M12 => 1 << 4,
-            VModsHigh::M11 => 1 << 3,
-            VModsHigh::M10 => 1 << 2,
-            VModsHigh::M9 => 1 << 1,
-            VModsHigh::M8 => 1 << 0,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct VModsHigh(u8);
+#[allow(non_upper_case_globals)]
+impl VModsHigh {
+    pub const M15: Self = Self(1 << 7);
+    pub const M14: Self = Self(1 << 6);
+    pub const M13: Self = Self(1 << 5);
+    pub const M12: Self = Self(1 << 4);
+    pub const M11: Self = Self(1 << 3);
+    pub const M10: Self = Self(1 << 2);
+    pub const M9: Self = Self(1 << 1);
+    pub const M8: Self = Self(1 << 0);
 }
+bitmask_set!(VModsHigh, u8, [M15, M14, M13, M12, M11, M10, M9, M8]);
 impl From<VModsHigh> for Option<u8> {
     fn from(input: VModsHigh) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<VModsHigh> for u16 {
     fn from(input: VModsHigh) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<VModsHigh> for Option<u16> {
     fn from(input: VModsHigh) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<VModsHigh> for u32 {
     fn from(input: VModsHigh) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<VModsHigh> for Option<u32> {
     fn from(input: VModsHigh) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for VModsHigh {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            128 => Ok(VModsHigh::M15),
-            64 => Ok(VModsHigh::M14),
-            32 => Ok(VModsHigh::M13),
-            16 => Ok(VModsHigh::M12),
-            8 => Ok(VModsHigh::M11),
-            4 => Ok(VModsHigh::M10),
-            2 => Ok(VModsHigh::M9),
-            1 => Ok(VModsHigh::M8),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for VModsHigh {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for VModsHigh {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(VModsHigh, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum VModsLow {
-    M7 = 1 << 7,
-    M6 = 1 << 6,
-    M5 = 1 << 5,
-    M4 = 1 << 4,
-    M3 = 1 << 3,
-    M2 = 1 << 2,
-    M1 = 1 << 1,
-    M0 = 1 << 0,
-}
-impl From<VModsLow> for u8 {
-    fn from(input: VModsLow) -> Self {
-        match input {
-            VModsLow::M7 => 1 << 7,
-            VModsLow::M6 => 1 << 6,
-            VModsLow::M5 => 1 << 5,
-            VModsLow::M4 => 1 << 4,
-            VModsLow::M3 => 1 << 3,
-            VModsLow::M2 => 1 << 2,
-            VModsLow::M1 => 1 << 1,
-            VModsLow::M0 => 1 << 0,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct VModsLow(u8);
+#[allow(non_upper_case_globals)]
+impl VModsLow {
+    pub const M7: Self = Self(1 << 7);
+    pub const M6: Self = Self(1 << 6);
+    pub const M5: Self = Self(1 << 5);
+    pub const M4: Self = Self(1 << 4);
+    pub const M3: Self = Self(1 << 3);
+    pub const M2: Self = Self(1 << 2);
+    pub const M1: Self = Self(1 << 1);
+    pub const M0: Self = Self(1 << 0);
 }
+bitmask_set!(VModsLow, u8, [M7, M6, M5, M4, M3, M2, M1, M0]);
 impl From<VModsLow> for Option<u8> {
     fn from(input: VModsLow) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<VModsLow> for u16 {
     fn from(input: VModsLow) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<VModsLow> for Option<u16> {
     fn from(input: VModsLow) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<VModsLow> for u32 {
     fn from(input: VModsLow) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<VModsLow> for Option<u32> {
     fn from(input: VModsLow) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for VModsLow {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            128 => Ok(VModsLow::M7),
-            64 => Ok(VModsLow::M6),
-            32 => Ok(VModsLow::M5),
-            16 => Ok(VModsLow::M4),
-            8 => Ok(VModsLow::M3),
-            4 => Ok(VModsLow::M2),
-            2 => Ok(VModsLow::M1),
-            1 => Ok(VModsLow::M0),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for VModsLow {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for VModsLow {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(VModsLow, u8);
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum VMod {
-    M15 = 1 << 15,
-    M14 = 1 << 14,
-    M13 = 1 << 13,
-    M12 = 1 << 12,
-    M11 = 1 << 11,
-    M10 = 1 << 10,
-    M9 = 1 << 9,
-    M8 = 1 << 8,
-    M7 = 1 << 7,
-    M6 = 1 << 6,
-    M5 = 1 << 5,
-    M4 = 1 << 4,
-    M3 = 1 << 3,
-    M2 = 1 << 2,
-    M1 = 1 << 1,
-    M0 = 1 << 0,
-}
-impl From<VMod> for u16 {
-    fn from(input: VMod) -> Self {
-        match input {
-            VMod::M15 => 1 << 15,
-            VMod::M14 => 1 << 14,
-            VMod::M13 => 1 << 13,
-            VMod::M12 => 1 << 12,
-            VMod::M11 => 1 << 11,
-            VMod::M10 => 1 << 10,
-            VMod::M9 => 1 << 9,
-            VMod::M8 => 1 << 8,
-            VMod::M7 => 1 << 7,
-            VMod::M6 => 1 << 6,
-            VMod::M5 => 1 << 5,
-            VMod::M4 => 1 << 4,
-            VMod::M3 => 1 << 3,
-            VMod::M2 => 1 << 2,
-            VMod::M1 => 1 << 1,
-            VMod::M0 => 1 << 0,
-        }
-    }
-}
+        Some(u32::from(input.0))
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct VMod(u16);
+#[allow(non_upper_case_globals)]
+impl VMod {
+    pub const M15: Self = Self(1 << 15);
+    pub const M14: Self = Self(1 << 14);
+    pub const M13: Self = Self(1 << 13);
+    pub const M12: Self = Self(1 << 12);
+    pub const M11: Self = Self(1 << 11);
+    pub const M10: Self = Self(1 << 10);
+    pub const M9: Self = Self(1 << 9);
+    pub const M8: Self = Self(1 << 8);
+    pub const M7: Self = Self(1 << 7);
+    pub const M6: Self = Self(1 << 6);
+    pub const M5: Self = Self(1 << 5);
+    pub const M4: Self = Self(1 << 4);
+    pub const M3: Self = Self(1 << 3);
+    pub const M2: Self = Self(1 << 2);
+    pub const M1: Self = Self(1 << 1);
+    pub const M0: Self = Self(1 << 0);
+}
+bitmask_set!(VMod, u16, [M15, M14, M13, M12, M11, M10, M9, M8, M7, M6, M5, M4, M3, M2, M1, M0]);
 impl From<VMod> for Option<u16> {
     fn from(input: VMod) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<VMod> for u32 {
     fn from(input: VMod) -> Self {
-        Self::from(u16::from(input))
+        Self::from(input.0)
     }
 }
 impl From<VMod> for Option<u32> {
     fn from(input: VMod) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for VMod {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            32768 => Ok(VMod::M15),
-            16384 => Ok(VMod::M14),
-            8192 => Ok(VMod::M13),
-            4096 => Ok(VMod::M12),
-            2048 => Ok(VMod::M11),
-            1024 => Ok(VMod::M10),
-            512 => Ok(VMod::M9),
-            256 => Ok(VMod::M8),
-            128 => Ok(VMod::M7),
-            64 => Ok(VMod::M6),
-            32 => Ok(VMod::M5),
-            16 => Ok(VMod::M4),
-            8 => Ok(VMod::M3),
-            4 => Ok(VMod::M2),
-            2 => Ok(VMod::M1),
-            1 => Ok(VMod::M0),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for VMod {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(VMod, u16);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Explicit {
-    VModMap = 1 << 7,
-    Behavior = 1 << 6,
-    AutoRepeat = 1 << 5,
-    Interpret = 1 << 4,
-    KeyType4 = 1 << 3,
-    KeyType3 = 1 << 2,
-    KeyType2 = 1 << 1,
-    KeyType1 = 1 << 0,
-}
-impl From<Explicit> for u8 {
-    fn from(input: Explicit) -> Self {
-        match input {
-            Explicit::VModMap => 1 << 7,
-            Explicit::Behavior => 1 << 6,
-            Explicit::AutoRepeat => 1 << 5,
-            Explicit::Interpret => 1 << 4,
-            Explicit::KeyType4 => 1 << 3,
-            Explicit::KeyType3 => 1 << 2,
-            Explicit::KeyType2 => 1 << 1,
-            Explicit::KeyType1 => 1 << 0,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Explicit(u8);
+#[allow(non_upper_case_globals)]
+impl Explicit {
+    pub const VModMap: Self = Self(1 << 7);
+    pub const Behavior: Self = Self(1 << 6);
+    pub const AutoRepeat: Self = Self(1 << 5);
+    pub const Interpret: Self = Self(1 << 4);
+    pub const KeyType4: Self = Self(1 << 3);
+    pub const KeyType3: Self = Self(1 << 2);
+    pub const KeyType2: Self = Self(1 << 1);
+    pub const KeyType1: Self = Self(1 << 0);
 }
+bitmask_set!(Explicit, u8, [VModMap, Behavior, AutoRepeat, Interpret, KeyType4, KeyType3, KeyType2, KeyType1]);
 impl From<Explicit> for Option<u8> {
     fn from(input: Explicit) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Explicit> for u16 {
     fn from(input: Explicit) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Explicit> for Option<u16> {
     fn from(input: Explicit) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Explicit> for u32 {
     fn from(input: Explicit) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Explicit> for Option<u32> {
     fn from(input: Explicit) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Explicit {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            128 => Ok(Explicit::VModMap),
-            64 => Ok(Explicit::Behavior),
-            32 => Ok(Explicit::AutoRepeat),
-            16 => Ok(Explicit::Interpret),
-            8 => Ok(Explicit::KeyType4),
-            4 => Ok(Explicit::KeyType3),
-            2 => Ok(Explicit::KeyType2),
-            1 => Ok(Explicit::KeyType1),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Explicit {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Explicit {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(Explicit, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SymInterpretMatch {
-    NoneOf = 0,
-    AnyOfOrNone = 1,
-    AnyOf = 2,
-    AllOf = 3,
-    Exactly = 4,
-}
-impl From<SymInterpretMatch> for u8 {
-    fn from(input: SymInterpretMatch) -> Self {
-        match input {
-            SymInterpretMatch::NoneOf => 0,
-            SymInterpretMatch::AnyOfOrNone => 1,
-            SymInterpretMatch::AnyOf => 2,
-            SymInterpretMatch::AllOf => 3,
-            SymInterpretMatch::Exactly => 4,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SymInterpretMatch(u8);
+#[allow(non_upper_case_globals)]
+impl SymInterpretMatch {
+    pub const NoneOf: Self = Self(0);
+    pub const AnyOfOrNone: Self = Self(1);
+    pub const AnyOf: Self = Self(2);
+    pub const AllOf: Self = Self(3);
+    pub const Exactly: Self = Self(4);
 }
+enum_newtype!(SymInterpretMatch, u8, [NoneOf, AnyOfOrNone, AnyOf, AllOf, Exactly]);
 impl From<SymInterpretMatch> for Option<u8> {
     fn from(input: SymInterpretMatch) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SymInterpretMatch> for u16 {
     fn from(input: SymInterpretMatch) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SymInterpretMatch> for Option<u16> {
     fn from(input: SymInterpretMatch) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SymInterpretMatch> for u32 {
     fn from(input: SymInterpretMatch) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SymInterpretMatch> for Option<u32> {
     fn from(input: SymInterpretMatch) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SymInterpretMatch {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SymInterpretMatch::NoneOf),
-            1 => Ok(SymInterpretMatch::AnyOfOrNone),
-            2 => Ok(SymInterpretMatch::AnyOf),
-            3 => Ok(SymInterpretMatch::AllOf),
-            4 => Ok(SymInterpretMatch::Exactly),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SymInterpretMatch {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SymInterpretMatch {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SymInterpMatch {
-    LevelOneOnly = 1 << 7,
-    OpMask = 127,
-}
-impl From<SymInterpMatch> for u8 {
-    fn from(input: SymInterpMatch) -> Self {
-        match input {
-            SymInterpMatch::LevelOneOnly => 1 << 7,
-            SymInterpMatch::OpMask => 127,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SymInterpMatch(u8);
+#[allow(non_upper_case_globals)]
+impl SymInterpMatch {
+    pub const LevelOneOnly: Self = Self(1 << 7);
+    pub const OpMask: Self = Self(127);
 }
+bitmask_set!(SymInterpMatch, u8, [LevelOneOnly]);
 impl From<SymInterpMatch> for Option<u8> {
     fn from(input: SymInterpMatch) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SymInterpMatch> for u16 {
     fn from(input: SymInterpMatch) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SymInterpMatch> for Option<u16> {
     fn from(input: SymInterpMatch) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SymInterpMatch> for u32 {
     fn from(input: SymInterpMatch) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SymInterpMatch> for Option<u32> {
     fn from(input: SymInterpMatch) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SymInterpMatch {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            128 => Ok(SymInterpMatch::LevelOneOnly),
-            127 => Ok(SymInterpMatch::OpMask),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SymInterpMatch {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SymInterpMatch {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum IMFlag {
-    NoExplicit = 1 << 7,
-    NoAutomatic = 1 << 6,
-    LEDDrivesKB = 1 << 5,
-}
-impl From<IMFlag> for u8 {
-    fn from(input: IMFlag) -> Self {
-        match input {
-            IMFlag::NoExplicit => 1 << 7,
-            IMFlag::NoAutomatic => 1 << 6,
-            IMFlag::LEDDrivesKB => 1 << 5,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IMFlag(u8);
+#[allow(non_upper_case_globals)]
+impl IMFlag {
+    pub const NoExplicit: Self = Self(1 << 7);
+    pub const NoAutomatic: Self = Self(1 << 6);
+    pub const LEDDrivesKB: Self = Self(1 << 5);
 }
+bitmask_set!(IMFlag, u8, [NoExplicit, NoAutomatic, LEDDrivesKB]);
 impl From<IMFlag> for Option<u8> {
     fn from(input: IMFlag) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<IMFlag> for u16 {
     fn from(input: IMFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<IMFlag> for Option<u16> {
     fn from(input: IMFlag) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<IMFlag> for u32 {
-    fn from(input: IMFlag) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<IMFlag> for Option<u32> {
-    fn from(input: IMFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for IMFlag {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            128 => Ok(IMFlag::NoExplicit),
-            64 => Ok(IMFlag::NoAutomatic),
-            32 => Ok(IMFlag::LEDDrivesKB),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u16::from(input.0))
     }
 }
-impl TryFrom<u16> for IMFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<IMFlag> for u32 {
+    fn from(input: IMFlag) -> Self {
+        Self::from(input.0)
     }
 }
-impl TryFrom<u32> for IMFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<IMFlag> for Option<u32> {
+    fn from(input: IMFlag) -> Self {
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(IMFlag, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum IMModsWhich {
-    UseCompat = 1 << 4,
-    UseEffective = 1 << 3,
-    UseLocked = 1 << 2,
-    UseLatched = 1 << 1,
-    UseBase = 1 << 0,
-}
-impl From<IMModsWhich> for u8 {
-    fn from(input: IMModsWhich) -> Self {
-        match input {
-            IMModsWhich::UseCompat => 1 << 4,
-            IMModsWhich::UseEffective => 1 << 3,
-            IMModsWhich::UseLocked => 1 << 2,
-            IMModsWhich::UseLatched => 1 << 1,
-            IMModsWhich::UseBase => 1 << 0,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IMModsWhich(u8);
+#[allow(non_upper_case_globals)]
+impl IMModsWhich {
+    pub const UseCompat: Self = Self(1 << 4);
+    pub const UseEffective: Self = Self(1 << 3);
+    pub const UseLocked: Self = Self(1 << 2);
+    pub const UseLatched: Self = Self(1 << 1);
+    pub const UseBase: Self = Self(1 << 0);
 }
+bitmask_set!(IMModsWhich, u8, [UseCompat, UseEffective, UseLocked, UseLatched, UseBase]);
 impl From<IMModsWhich> for Option<u8> {
     fn from(input: IMModsWhich) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<IMModsWhich> for u16 {
     fn from(input: IMModsWhich) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<IMModsWhich> for Option<u16> {
     fn from(input: IMModsWhich) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<IMModsWhich> for u32 {
     fn from(input: IMModsWhich) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<IMModsWhich> for Option<u32> {
     fn from(input: IMModsWhich) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for IMModsWhich {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            16 => Ok(IMModsWhich::UseCompat),
-            8 => Ok(IMModsWhich::UseEffective),
-            4 => Ok(IMModsWhich::UseLocked),
-            2 => Ok(IMModsWhich::UseLatched),
-            1 => Ok(IMModsWhich::UseBase),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for IMModsWhich {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for IMModsWhich {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(IMModsWhich, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum IMGroupsWhich {
-    UseCompat = 1 << 4,
-    UseEffective = 1 << 3,
-    UseLocked = 1 << 2,
-    UseLatched = 1 << 1,
-    UseBase = 1 << 0,
-}
-impl From<IMGroupsWhich> for u8 {
-    fn from(input: IMGroupsWhich) -> Self {
-        match input {
-            IMGroupsWhich::UseCompat => 1 << 4,
-            IMGroupsWhich::UseEffective => 1 << 3,
-            IMGroupsWhich::UseLocked => 1 << 2,
-            IMGroupsWhich::UseLatched => 1 << 1,
-            IMGroupsWhich::UseBase => 1 << 0,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IMGroupsWhich(u8);
+#[allow(non_upper_case_globals)]
+impl IMGroupsWhich {
+    pub const UseCompat: Self = Self(1 << 4);
+    pub const UseEffective: Self = Self(1 << 3);
+    pub const UseLocked: Self = Self(1 << 2);
+    pub const UseLatched: Self = Self(1 << 1);
+    pub const UseBase: Self = Self(1 << 0);
 }
+bitmask_set!(IMGroupsWhich, u8, [UseCompat, UseEffective, UseLocked, UseLatched, UseBase]);
 impl From<IMGroupsWhich> for Option<u8> {
     fn from(input: IMGroupsWhich) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<IMGroupsWhich> for u16 {
     fn from(input: IMGroupsWhich) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<IMGroupsWhich> for Option<u16> {
     fn from(input: IMGroupsWhich) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<IMGroupsWhich> for u32 {
     fn from(input: IMGroupsWhich) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<IMGroupsWhich> for Option<u32> {
     fn from(input: IMGroupsWhich) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for IMGroupsWhich {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            16 => Ok(IMGroupsWhich::UseCompat),
-            8 => Ok(IMGroupsWhich::UseEffective),
-            4 => Ok(IMGroupsWhich::UseLocked),
-            2 => Ok(IMGroupsWhich::UseLatched),
-            1 => Ok(IMGroupsWhich::UseBase),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for IMGroupsWhich {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for IMGroupsWhich {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(IMGroupsWhich, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IndicatorMap {
@@ -2041,25 +984,29 @@ pub struct IndicatorMap {
     pub which_groups: IMGroupsWhich,
     pub groups: SetOfGroup,
     pub which_mods: IMModsWhich,
-    pub mods: u8,
-    pub real_mods: u8,
-    pub vmods: u16,
-    pub ctrls: u32,
+    pub mods: xproto::ModMask,
+    pub real_mods: xproto::ModMask,
+    pub vmods: VMod,
+    pub ctrls: BoolCtrl,
 }
 impl TryParse for IndicatorMap {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (which_groups, remaining) = u8::try_parse(remaining)?;
-        let (groups, remaining) = u8::try_parse(remaining)?;
-        let (which_mods, remaining) = u8::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (vmods, remaining) = u16::try_parse(remaining)?;
-        let (ctrls, remaining) = u32::try_parse(remaining)?;
-        let flags = flags.try_into()?;
-        let which_groups = which_groups.try_into()?;
-        let groups = groups.try_into()?;
-        let which_mods = which_mods.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (which_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (which_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let flags = IMFlag::from(flags);
+        let which_groups = IMGroupsWhich::from(which_groups);
+        let groups = SetOfGroup::from(groups);
+        let which_mods = IMModsWhich::from(which_mods);
+        let mods = xproto::ModMask::from(u16::from(mods));
+        let real_mods = xproto::ModMask::from(u16::from(real_mods));
+        let vmods = VMod::from(vmods);
+        let ctrls = BoolCtrl::from(ctrls);
         let result = IndicatorMap { flags, which_groups, groups, which_mods, mods, real_mods, vmods, ctrls };
         Ok((result, remaining))
     }
@@ -2072,15 +1019,15 @@ impl TryFrom<&[u8]> for IndicatorMap {
 }
 impl Serialize for IndicatorMap {
     type Bytes = [u8; 12];
-    fn serialize(&self) -> [u8; 12] {
-        let flags_bytes = u8::from(self.flags).serialize();
-        let which_groups_bytes = u8::from(self.which_groups).serialize();
-        let groups_bytes = u8::from(self.groups).serialize();
-        let which_mods_bytes = u8::from(self.which_mods).serialize();
-        let mods_bytes = self.mods.serialize();
-        let real_mods_bytes = self.real_mods.serialize();
-        let vmods_bytes = self.vmods.serialize();
-        let ctrls_bytes = self.ctrls.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let flags_bytes = u8::from(self.flags).serialize_with_order(order);
+        let which_groups_bytes = u8::from(self.which_groups).serialize_with_order(order);
+        let groups_bytes = u8::from(self.groups).serialize_with_order(order);
+        let which_mods_bytes = u8::from(self.which_mods).serialize_with_order(order);
+        let mods_bytes = u8::try_from(u16::from(self.mods)).unwrap().serialize_with_order(order);
+        let real_mods_bytes = u8::try_from(u16::from(self.real_mods)).unwrap().serialize_with_order(order);
+        let vmods_bytes = u16::from(self.vmods).serialize_with_order(order);
+        let ctrls_bytes = u32::from(self.ctrls).serialize_with_order(order);
         [
             flags_bytes[0],
             which_groups_bytes[0],
@@ -2096,401 +1043,167 @@ impl Serialize for IndicatorMap {
             ctrls_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        u8::from(self.flags).serialize_into(bytes);
-        u8::from(self.which_groups).serialize_into(bytes);
-        u8::from(self.groups).serialize_into(bytes);
-        u8::from(self.which_mods).serialize_into(bytes);
-        self.mods.serialize_into(bytes);
-        self.real_mods.serialize_into(bytes);
-        self.vmods.serialize_into(bytes);
-        self.ctrls.serialize_into(bytes);
+        u8::from(self.flags).serialize_into_with_order(bytes, order);
+        u8::from(self.which_groups).serialize_into_with_order(bytes, order);
+        u8::from(self.groups).serialize_into_with_order(bytes, order);
+        u8::from(self.which_mods).serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.real_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u16::from(self.vmods).serialize_into_with_order(bytes, order);
+        u32::from(self.ctrls).serialize_into_with_order(bytes, order);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum CMDetail {
-    SymInterp = 1 << 0,
-    GroupCompat = 1 << 1,
-}
-impl From<CMDetail> for u8 {
-    fn from(input: CMDetail) -> Self {
-        match input {
-            CMDetail::SymInterp => 1 << 0,
-            CMDetail::GroupCompat => 1 << 1,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CMDetail(u8);
+#[allow(non_upper_case_globals)]
+impl CMDetail {
+    pub const SymInterp: Self = Self(1 << 0);
+    pub const GroupCompat: Self = Self(1 << 1);
 }
+bitmask_set!(CMDetail, u8, [SymInterp, GroupCompat]);
 impl From<CMDetail> for Option<u8> {
     fn from(input: CMDetail) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<CMDetail> for u16 {
     fn from(input: CMDetail) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CMDetail> for Option<u16> {
     fn from(input: CMDetail) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<CMDetail> for u32 {
     fn from(input: CMDetail) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<CMDetail> for Option<u32> {
     fn from(input: CMDetail) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for CMDetail {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(CMDetail::SymInterp),
-            2 => Ok(CMDetail::GroupCompat),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for CMDetail {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for CMDetail {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(CMDetail, u8);
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-pub enum NameDetail {
-    Keycodes = 1 << 0,
-    Geometry = 1 << 1,
-    Symbols = 1 << 2,
-    PhysSymbols = 1 << 3,
-    Types = 1 << 4,
-    Compat = 1 << 5,
-    KeyTypeNames = 1 << 6,
-    KTLevelNames = 1 << 7,
-    IndicatorNames = 1 << 8,
-    KeyNames = 1 << 9,
-    KeyAliases = 1 << 10,
-    VirtualModNames = 1 << 11,
-    GroupNames = 1 << 12,
-    RGNames = 1 << 13,
-}
-impl From<NameDetail> for u16 {
-    fn from(input: NameDetail) -> Self {
-        match input {
-            NameDetail::Keycodes => 1 << 0,
-            NameDetail::Geometry => 1 << 1,
-            NameDetail::Symbols => 1 << 2,
-            NameDetail::PhysSymbols => 1 << 3,
-            NameDetail::Types => 1 << 4,
-            NameDetail::Compat => 1 << 5,
-            NameDetail::KeyTypeNames => 1 << 6,
-            NameDetail::KTLevelNames => 1 << 7,
-            NameDetail::IndicatorNames => 1 << 8,
-            NameDetail::KeyNames => 1 << 9,
-            NameDetail::KeyAliases => 1 << 10,
-            NameDetail::VirtualModNames => 1 << 11,
-            NameDetail::GroupNames => 1 << 12,
-            NameDetail::RGNames => 1 << 13,
-        }
-    }
-}
-impl From<NameDetail> for Option<u16> {
-    fn from(input: NameDetail) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<NameDetail> for u32 {
-    fn from(input: NameDetail) -> Self {
-        Self::from(u16::from(input))
-    }
-}
+        Some(u32::from(input.0))
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NameDetail(u32);
+#[allow(non_upper_case_globals)]
+impl NameDetail {
+    pub const Keycodes: Self = Self(1 << 0);
+    pub const Geometry: Self = Self(1 << 1);
+    pub const Symbols: Self = Self(1 << 2);
+    pub const PhysSymbols: Self = Self(1 << 3);
+    pub const Types: Self = Self(1 << 4);
+    pub const Compat: Self = Self(1 << 5);
+    pub const KeyTypeNames: Self = Self(1 << 6);
+    pub const KTLevelNames: Self = Self(1 << 7);
+    pub const IndicatorNames: Self = Self(1 << 8);
+    pub const KeyNames: Self = Self(1 << 9);
+    pub const KeyAliases: Self = Self(1 << 10);
+    pub const VirtualModNames: Self = Self(1 << 11);
+    pub const GroupNames: Self = Self(1 << 12);
+    pub const RGNames: Self = Self(1 << 13);
+}
+bitmask_set!(NameDetail, u32, [Keycodes, Geometry, Symbols, PhysSymbols, Types, Compat, KeyTypeNames, KTLevelNames, IndicatorNames, KeyNames, KeyAliases, VirtualModNames, GroupNames, RGNames]);
 impl From<NameDetail> for Option<u32> {
     fn from(input: NameDetail) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u16> for NameDetail {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(NameDetail::Keycodes),
-            2 => Ok(NameDetail::Geometry),
-            4 => Ok(NameDetail::Symbols),
-            8 => Ok(NameDetail::PhysSymbols),
-            16 => Ok(NameDetail::Types),
-            32 => Ok(NameDetail::Compat),
-            64 => Ok(NameDetail::KeyTypeNames),
-            128 => Ok(NameDetail::KTLevelNames),
-            256 => Ok(NameDetail::IndicatorNames),
-            512 => Ok(NameDetail::KeyNames),
-            1024 => Ok(NameDetail::KeyAliases),
-            2048 => Ok(NameDetail::VirtualModNames),
-            4096 => Ok(NameDetail::GroupNames),
-            8192 => Ok(NameDetail::RGNames),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u32> for NameDetail {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u16::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-bitmask_binop!(NameDetail, u16);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GBNDetail {
-    Types = 1 << 0,
-    CompatMap = 1 << 1,
-    ClientSymbols = 1 << 2,
-    ServerSymbols = 1 << 3,
-    IndicatorMaps = 1 << 4,
-    KeyNames = 1 << 5,
-    Geometry = 1 << 6,
-    OtherNames = 1 << 7,
-}
-impl From<GBNDetail> for u8 {
-    fn from(input: GBNDetail) -> Self {
-        match input {
-            GBNDetail::Types => 1 << 0,
-            GBNDetail::CompatMap => 1 << 1,
-            GBNDetail::ClientSymbols => 1 << 2,
-            GBNDetail::ServerSymbols => 1 << 3,
-            GBNDetail::IndicatorMaps => 1 << 4,
-            GBNDetail::KeyNames => 1 << 5,
-            GBNDetail::Geometry => 1 << 6,
-            GBNDetail::OtherNames => 1 << 7,
-        }
-    }
-}
-impl From<GBNDetail> for Option<u8> {
-    fn from(input: GBNDetail) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<GBNDetail> for u16 {
-    fn from(input: GBNDetail) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GBNDetail(u16);
+#[allow(non_upper_case_globals)]
+impl GBNDetail {
+    pub const Types: Self = Self(1 << 0);
+    pub const CompatMap: Self = Self(1 << 1);
+    pub const ClientSymbols: Self = Self(1 << 2);
+    pub const ServerSymbols: Self = Self(1 << 3);
+    pub const IndicatorMaps: Self = Self(1 << 4);
+    pub const KeyNames: Self = Self(1 << 5);
+    pub const Geometry: Self = Self(1 << 6);
+    pub const OtherNames: Self = Self(1 << 7);
 }
+bitmask_set!(GBNDetail, u16, [Types, CompatMap, ClientSymbols, ServerSymbols, IndicatorMaps, KeyNames, Geometry, OtherNames]);
 impl From<GBNDetail> for Option<u16> {
     fn from(input: GBNDetail) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<GBNDetail> for u32 {
     fn from(input: GBNDetail) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GBNDetail> for Option<u32> {
     fn from(input: GBNDetail) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for GBNDetail {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(GBNDetail::Types),
-            2 => Ok(GBNDetail::CompatMap),
-            4 => Ok(GBNDetail::ClientSymbols),
-            8 => Ok(GBNDetail::ServerSymbols),
-            16 => Ok(GBNDetail::IndicatorMaps),
-            32 => Ok(GBNDetail::KeyNames),
-            64 => Ok(GBNDetail::Geometry),
-            128 => Ok(GBNDetail::OtherNames),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for GBNDetail {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for GBNDetail {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(GBNDetail, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum XIFeature {
-    Keyboards = 1 << 0,
-    ButtonActions = 1 << 1,
-    IndicatorNames = 1 << 2,
-    IndicatorMaps = 1 << 3,
-    IndicatorState = 1 << 4,
-}
-impl From<XIFeature> for u8 {
-    fn from(input: XIFeature) -> Self {
-        match input {
-            XIFeature::Keyboards => 1 << 0,
-            XIFeature::ButtonActions => 1 << 1,
-            XIFeature::IndicatorNames => 1 << 2,
-            XIFeature::IndicatorMaps => 1 << 3,
-            XIFeature::IndicatorState => 1 << 4,
-        }
-    }
-}
-impl From<XIFeature> for Option<u8> {
-    fn from(input: XIFeature) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<XIFeature> for u16 {
-    fn from(input: XIFeature) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct XIFeature(u16);
+#[allow(non_upper_case_globals)]
+impl XIFeature {
+    pub const Keyboards: Self = Self(1 << 0);
+    pub const ButtonActions: Self = Self(1 << 1);
+    pub const IndicatorNames: Self = Self(1 << 2);
+    pub const IndicatorMaps: Self = Self(1 << 3);
+    pub const IndicatorState: Self = Self(1 << 4);
 }
+bitmask_set!(XIFeature, u16, [Keyboards, ButtonActions, IndicatorNames, IndicatorMaps, IndicatorState]);
 impl From<XIFeature> for Option<u16> {
     fn from(input: XIFeature) -> Self {
-        Some(u16::from(input))
+        Some(input.0)
     }
 }
 impl From<XIFeature> for u32 {
     fn from(input: XIFeature) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<XIFeature> for Option<u32> {
     fn from(input: XIFeature) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for XIFeature {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(XIFeature::Keyboards),
-            2 => Ok(XIFeature::ButtonActions),
-            4 => Ok(XIFeature::IndicatorNames),
-            8 => Ok(XIFeature::IndicatorMaps),
-            16 => Ok(XIFeature::IndicatorState),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for XIFeature {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for XIFeature {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(XIFeature, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PerClientFlag {
-    DetectableAutoRepeat = 1 << 0,
-    GrabsUseXKBState = 1 << 1,
-    AutoResetControls = 1 << 2,
-    LookupStateWhenGrabbed = 1 << 3,
-    SendEventUsesXKBState = 1 << 4,
-}
-impl From<PerClientFlag> for u8 {
-    fn from(input: PerClientFlag) -> Self {
-        match input {
-            PerClientFlag::DetectableAutoRepeat => 1 << 0,
-            PerClientFlag::GrabsUseXKBState => 1 << 1,
-            PerClientFlag::AutoResetControls => 1 << 2,
-            PerClientFlag::LookupStateWhenGrabbed => 1 << 3,
-            PerClientFlag::SendEventUsesXKBState => 1 << 4,
-        }
-    }
-}
-impl From<PerClientFlag> for Option<u8> {
-    fn from(input: PerClientFlag) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<PerClientFlag> for u16 {
-    fn from(input: PerClientFlag) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<PerClientFlag> for Option<u16> {
-    fn from(input: PerClientFlag) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<PerClientFlag> for u32 {
-    fn from(input: PerClientFlag) -> Self {
-        Self::from(u8::from(input))
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PerClientFlag(u32);
+#[allow(non_upper_case_globals)]
+impl PerClientFlag {
+    pub const DetectableAutoRepeat: Self = Self(1 << 0);
+    pub const GrabsUseXKBState: Self = Self(1 << 1);
+    pub const AutoResetControls: Self = Self(1 << 2);
+    pub const LookupStateWhenGrabbed: Self = Self(1 << 3);
+    pub const SendEventUsesXKBState: Self = Self(1 << 4);
 }
+bitmask_set!(PerClientFlag, u32, [DetectableAutoRepeat, GrabsUseXKBState, AutoResetControls, LookupStateWhenGrabbed, SendEventUsesXKBState]);
 impl From<PerClientFlag> for Option<u32> {
     fn from(input: PerClientFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for PerClientFlag {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(PerClientFlag::DetectableAutoRepeat),
-            2 => Ok(PerClientFlag::GrabsUseXKBState),
-            4 => Ok(PerClientFlag::AutoResetControls),
-            8 => Ok(PerClientFlag::LookupStateWhenGrabbed),
-            16 => Ok(PerClientFlag::SendEventUsesXKBState),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for PerClientFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-impl TryFrom<u32> for PerClientFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(PerClientFlag, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ModDef {
-    pub mask: u8,
-    pub real_mods: u8,
-    pub vmods: u16,
+    pub mask: xproto::ModMask,
+    pub real_mods: xproto::ModMask,
+    pub vmods: VMod,
 }
 impl TryParse for ModDef {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (vmods, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let mask = xproto::ModMask::from(u16::from(mask));
+        let real_mods = xproto::ModMask::from(u16::from(real_mods));
+        let vmods = VMod::from(vmods);
         let result = ModDef { mask, real_mods, vmods };
         Ok((result, remaining))
     }
@@ -2503,10 +1216,10 @@ impl TryFrom<&[u8]> for ModDef {
 }
 impl Serialize for ModDef {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let mask_bytes = self.mask.serialize();
-        let real_mods_bytes = self.real_mods.serialize();
-        let vmods_bytes = self.vmods.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let mask_bytes = u8::try_from(u16::from(self.mask)).unwrap().serialize_with_order(order);
+        let real_mods_bytes = u8::try_from(u16::from(self.real_mods)).unwrap().serialize_with_order(order);
+        let vmods_bytes = u16::from(self.vmods).serialize_with_order(order);
         [
             mask_bytes[0],
             real_mods_bytes[0],
@@ -2514,11 +1227,11 @@ impl Serialize for ModDef {
             vmods_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.mask.serialize_into(bytes);
-        self.real_mods.serialize_into(bytes);
-        self.vmods.serialize_into(bytes);
+        u8::try_from(u16::from(self.mask)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.real_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u16::from(self.vmods).serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2527,7 +1240,7 @@ pub struct KeyName {
     pub name: [u8; 4],
 }
 impl TryParse for KeyName {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], _order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
         let name = <[u8; 4]>::try_from(name).unwrap();
         let result = KeyName { name };
@@ -2542,7 +1255,7 @@ impl TryFrom<&[u8]> for KeyName {
 }
 impl Serialize for KeyName {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
+    fn serialize_with_order(&self, _order: ByteOrder) -> [u8; 4] {
         [
             self.name[0],
             self.name[1],
@@ -2550,7 +1263,7 @@ impl Serialize for KeyName {
             self.name[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {
         bytes.reserve(4);
         bytes.extend_from_slice(&self.name);
     }
@@ -2562,7 +1275,7 @@ pub struct KeyAlias {
     pub alias: [u8; 4],
 }
 impl TryParse for KeyAlias {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], _order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let (real, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
         let real = <[u8; 4]>::try_from(real).unwrap();
         let (alias, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
@@ -2579,7 +1292,7 @@ impl TryFrom<&[u8]> for KeyAlias {
 }
 impl Serialize for KeyAlias {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
+    fn serialize_with_order(&self, _order: ByteOrder) -> [u8; 8] {
         [
             self.real[0],
             self.real[1],
@@ -2591,7 +1304,7 @@ impl Serialize for KeyAlias {
             self.alias[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {
         bytes.reserve(8);
         bytes.extend_from_slice(&self.real);
         bytes.extend_from_slice(&self.alias);
@@ -2604,8 +1317,8 @@ pub struct CountedString16 {
     pub alignment_pad: Vec<u8>,
 }
 impl TryParse for CountedString16 {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (length, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (length, remaining) = u16::try_parse_with_order(remaining, order)?;
         let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
         let string = string.to_vec();
         let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (u32::from(length).checked_add(5u32).ok_or(ParseError::ParseError)? & (!3u32)).checked_sub(u32::from(length).checked_add(2u32).ok_or(ParseError::ParseError)?).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
@@ -2622,14 +1335,14 @@ impl TryFrom<&[u8]> for CountedString16 {
 }
 impl Serialize for CountedString16 {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let length = u16::try_from(self.string.len()).expect("`string` has too many elements");
-        length.serialize_into(bytes);
+        length.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.string);
         assert_eq!(self.alignment_pad.len(), usize::try_from((u32::from(length).checked_add(5u32).unwrap() & (!3u32)).checked_sub(u32::from(length).checked_add(2u32).unwrap()).unwrap()).unwrap(), "`alignment_pad` has an incorrect length");
         bytes.extend_from_slice(&self.alignment_pad);
@@ -2639,19 +1352,22 @@ impl Serialize for CountedString16 {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KTMapEntry {
     pub active: bool,
-    pub mods_mask: u8,
+    pub mods_mask: xproto::ModMask,
     pub level: u8,
-    pub mods_mods: u8,
-    pub mods_vmods: u16,
+    pub mods_mods: xproto::ModMask,
+    pub mods_vmods: VMod,
 }
 impl TryParse for KTMapEntry {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (active, remaining) = bool::try_parse(remaining)?;
-        let (mods_mask, remaining) = u8::try_parse(remaining)?;
-        let (level, remaining) = u8::try_parse(remaining)?;
-        let (mods_mods, remaining) = u8::try_parse(remaining)?;
-        let (mods_vmods, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (active, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (mods_mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let mods_mask = xproto::ModMask::from(u16::from(mods_mask));
+        let mods_mods = xproto::ModMask::from(u16::from(mods_mods));
+        let mods_vmods = VMod::from(mods_vmods);
         let result = KTMapEntry { active, mods_mask, level, mods_mods, mods_vmods };
         Ok((result, remaining))
     }
@@ -2664,12 +1380,12 @@ impl TryFrom<&[u8]> for KTMapEntry {
 }
 impl Serialize for KTMapEntry {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let active_bytes = self.active.serialize();
-        let mods_mask_bytes = self.mods_mask.serialize();
-        let level_bytes = self.level.serialize();
-        let mods_mods_bytes = self.mods_mods.serialize();
-        let mods_vmods_bytes = self.mods_vmods.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let active_bytes = self.active.serialize_with_order(order);
+        let mods_mask_bytes = u8::try_from(u16::from(self.mods_mask)).unwrap().serialize_with_order(order);
+        let level_bytes = self.level.serialize_with_order(order);
+        let mods_mods_bytes = u8::try_from(u16::from(self.mods_mods)).unwrap().serialize_with_order(order);
+        let mods_vmods_bytes = u16::from(self.mods_vmods).serialize_with_order(order);
         [
             active_bytes[0],
             mods_mask_bytes[0],
@@ -2681,38 +1397,41 @@ impl Serialize for KTMapEntry {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.active.serialize_into(bytes);
-        self.mods_mask.serialize_into(bytes);
-        self.level.serialize_into(bytes);
-        self.mods_mods.serialize_into(bytes);
-        self.mods_vmods.serialize_into(bytes);
+        self.active.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mods_mask)).unwrap().serialize_into_with_order(bytes, order);
+        self.level.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mods_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u16::from(self.mods_vmods).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct KeyType {
-    pub mods_mask: u8,
-    pub mods_mods: u8,
-    pub mods_vmods: u16,
+    pub mods_mask: xproto::ModMask,
+    pub mods_mods: xproto::ModMask,
+    pub mods_vmods: VMod,
     pub num_levels: u8,
     pub has_preserve: bool,
     pub map: Vec<KTMapEntry>,
     pub preserve: Vec<ModDef>,
 }
 impl TryParse for KeyType {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (mods_mask, remaining) = u8::try_parse(remaining)?;
-        let (mods_mods, remaining) = u8::try_parse(remaining)?;
-        let (mods_vmods, remaining) = u16::try_parse(remaining)?;
-        let (num_levels, remaining) = u8::try_parse(remaining)?;
-        let (n_map_entries, remaining) = u8::try_parse(remaining)?;
-        let (has_preserve, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (mods_mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_levels, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_map_entries, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (has_preserve, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (map, remaining) = crate::x11_utils::parse_list::<KTMapEntry>(remaining, n_map_entries.try_into().or(Err(ParseError::ParseError))?)?;
-        let (preserve, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, u32::from(has_preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (map, remaining) = crate::x11_utils::parse_list_with_order::<KTMapEntry>(remaining, n_map_entries.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (preserve, remaining) = crate::x11_utils::parse_list_with_order::<ModDef>(remaining, u32::from(has_preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let mods_mask = xproto::ModMask::from(u16::from(mods_mask));
+        let mods_mods = xproto::ModMask::from(u16::from(mods_mods));
+        let mods_vmods = VMod::from(mods_vmods);
         let result = KeyType { mods_mask, mods_mods, mods_vmods, num_levels, has_preserve, map, preserve };
         Ok((result, remaining))
     }
@@ -2725,24 +1444,24 @@ impl TryFrom<&[u8]> for KeyType {
 }
 impl Serialize for KeyType {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.mods_mask.serialize_into(bytes);
-        self.mods_mods.serialize_into(bytes);
-        self.mods_vmods.serialize_into(bytes);
-        self.num_levels.serialize_into(bytes);
+        u8::try_from(u16::from(self.mods_mask)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mods_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u16::from(self.mods_vmods).serialize_into_with_order(bytes, order);
+        self.num_levels.serialize_into_with_order(bytes, order);
         let n_map_entries = u8::try_from(self.map.len()).expect("`map` has too many elements");
-        n_map_entries.serialize_into(bytes);
-        self.has_preserve.serialize_into(bytes);
+        n_map_entries.serialize_into_with_order(bytes, order);
+        self.has_preserve.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
-        self.map.serialize_into(bytes);
+        self.map.serialize_into_with_order(bytes, order);
         assert_eq!(self.preserve.len(), usize::try_from(u32::from(self.has_preserve).checked_mul(u32::from(n_map_entries)).unwrap()).unwrap(), "`preserve` has an incorrect length");
-        self.preserve.serialize_into(bytes);
+        self.preserve.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2754,13 +1473,13 @@ pub struct KeySymMap {
     pub syms: Vec<xproto::Keysym>,
 }
 impl TryParse for KeySymMap {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let (kt_index, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
         let kt_index = <[u8; 4]>::try_from(kt_index).unwrap();
-        let (group_info, remaining) = u8::try_parse(remaining)?;
-        let (width, remaining) = u8::try_parse(remaining)?;
-        let (n_syms, remaining) = u16::try_parse(remaining)?;
-        let (syms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, n_syms.try_into().or(Err(ParseError::ParseError))?)?;
+        let (group_info, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_syms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (syms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Keysym>(remaining, n_syms.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = KeySymMap { kt_index, group_info, width, syms };
         Ok((result, remaining))
     }
@@ -2773,19 +1492,19 @@ impl TryFrom<&[u8]> for KeySymMap {
 }
 impl Serialize for KeySymMap {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
         bytes.extend_from_slice(&self.kt_index);
-        self.group_info.serialize_into(bytes);
-        self.width.serialize_into(bytes);
+        self.group_info.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
         let n_syms = u16::try_from(self.syms.len()).expect("`syms` has too many elements");
-        n_syms.serialize_into(bytes);
-        self.syms.serialize_into(bytes);
+        n_syms.serialize_into_with_order(bytes, order);
+        self.syms.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2795,9 +1514,9 @@ pub struct CommonBehavior {
     pub data: u8,
 }
 impl TryParse for CommonBehavior {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (data, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = u8::try_parse_with_order(remaining, order)?;
         let result = CommonBehavior { type_, data };
         Ok((result, remaining))
     }
@@ -2810,18 +1529,18 @@ impl TryFrom<&[u8]> for CommonBehavior {
 }
 impl Serialize for CommonBehavior {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let type_bytes = self.type_.serialize();
-        let data_bytes = self.data.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let type_bytes = self.type_.serialize_with_order(order);
+        let data_bytes = self.data.serialize_with_order(order);
         [
             type_bytes[0],
             data_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.type_.serialize_into(bytes);
-        self.data.serialize_into(bytes);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.data.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2830,8 +1549,8 @@ pub struct DefaultBehavior {
     pub type_: u8,
 }
 impl TryParse for DefaultBehavior {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let result = DefaultBehavior { type_ };
         Ok((result, remaining))
@@ -2845,16 +1564,16 @@ impl TryFrom<&[u8]> for DefaultBehavior {
 }
 impl Serialize for DefaultBehavior {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let type_bytes = self.type_.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let type_bytes = self.type_.serialize_with_order(order);
         [
             type_bytes[0],
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.type_.serialize_into(bytes);
+        self.type_.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
     }
 }
@@ -2867,9 +1586,9 @@ pub struct RadioGroupBehavior {
     pub group: u8,
 }
 impl TryParse for RadioGroupBehavior {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = u8::try_parse_with_order(remaining, order)?;
         let result = RadioGroupBehavior { type_, group };
         Ok((result, remaining))
     }
@@ -2882,18 +1601,18 @@ impl TryFrom<&[u8]> for RadioGroupBehavior {
 }
 impl Serialize for RadioGroupBehavior {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let type_bytes = self.type_.serialize();
-        let group_bytes = self.group.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let type_bytes = self.type_.serialize_with_order(order);
+        let group_bytes = self.group.serialize_with_order(order);
         [
             type_bytes[0],
             group_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.type_.serialize_into(bytes);
-        self.group.serialize_into(bytes);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2903,9 +1622,9 @@ pub struct OverlayBehavior {
     pub key: xproto::Keycode,
 }
 impl TryParse for OverlayBehavior {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (key, remaining) = xproto::Keycode::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
         let result = OverlayBehavior { type_, key };
         Ok((result, remaining))
     }
@@ -2918,18 +1637,18 @@ impl TryFrom<&[u8]> for OverlayBehavior {
 }
 impl Serialize for OverlayBehavior {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let type_bytes = self.type_.serialize();
-        let key_bytes = self.key.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let type_bytes = self.type_.serialize_with_order(order);
+        let key_bytes = self.key.serialize_with_order(order);
         [
             type_bytes[0],
             key_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.type_.serialize_into(bytes);
-        self.key.serialize_into(bytes);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.key.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -2943,105 +1662,116 @@ pub type PermamentOverlayBehavior = OverlayBehavior;
 pub struct Behavior([u8; 2]);
 impl Behavior {
     pub fn as_common(&self) -> CommonBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<CommonBehavior, ParseError> {
-            let (common, remaining) = CommonBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<CommonBehavior, ParseError> {
+            let (common, remaining) = CommonBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(common)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_default(&self) -> DefaultBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<DefaultBehavior, ParseError> {
-            let (default, remaining) = DefaultBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<DefaultBehavior, ParseError> {
+            let (default, remaining) = DefaultBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(default)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_lock(&self) -> LockBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<LockBehavior, ParseError> {
-            let (lock, remaining) = LockBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<LockBehavior, ParseError> {
+            let (lock, remaining) = LockBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(lock)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_radio_group(&self) -> RadioGroupBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<RadioGroupBehavior, ParseError> {
-            let (radio_group, remaining) = RadioGroupBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<RadioGroupBehavior, ParseError> {
+            let (radio_group, remaining) = RadioGroupBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(radio_group)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_overlay1(&self) -> OverlayBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<OverlayBehavior, ParseError> {
-            let (overlay1, remaining) = OverlayBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<OverlayBehavior, ParseError> {
+            let (overlay1, remaining) = OverlayBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(overlay1)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_overlay2(&self) -> OverlayBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<OverlayBehavior, ParseError> {
-            let (overlay2, remaining) = OverlayBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<OverlayBehavior, ParseError> {
+            let (overlay2, remaining) = OverlayBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(overlay2)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_permament_lock(&self) -> PermamentLockBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<PermamentLockBehavior, ParseError> {
-            let (permament_lock, remaining) = PermamentLockBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<PermamentLockBehavior, ParseError> {
+            let (permament_lock, remaining) = PermamentLockBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(permament_lock)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_permament_radio_group(&self) -> PermamentRadioGroupBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<PermamentRadioGroupBehavior, ParseError> {
-            let (permament_radio_group, remaining) = PermamentRadioGroupBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<PermamentRadioGroupBehavior, ParseError> {
+            let (permament_radio_group, remaining) = PermamentRadioGroupBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(permament_radio_group)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_permament_overlay1(&self) -> PermamentOverlayBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<PermamentOverlayBehavior, ParseError> {
-            let (permament_overlay1, remaining) = PermamentOverlayBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<PermamentOverlayBehavior, ParseError> {
+            let (permament_overlay1, remaining) = PermamentOverlayBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(permament_overlay1)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_permament_overlay2(&self) -> PermamentOverlayBehavior {
-        fn do_the_parse(remaining: &[u8]) -> Result<PermamentOverlayBehavior, ParseError> {
-            let (permament_overlay2, remaining) = PermamentOverlayBehavior::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<PermamentOverlayBehavior, ParseError> {
+            let (permament_overlay2, remaining) = PermamentOverlayBehavior::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(permament_overlay2)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_type(&self) -> u8 {
-        fn do_the_parse(remaining: &[u8]) -> Result<u8, ParseError> {
-            let (type_, remaining) = u8::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<u8, ParseError> {
+            let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(type_)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
 }
 impl Serialize for Behavior {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
+    fn serialize_with_order(&self, _order: ByteOrder) -> [u8; 2] {
         self.0
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {
         bytes.extend_from_slice(&self.0);
     }
 }
 impl TryParse for Behavior {
-    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], _order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let inner: [u8; 2] = value.get(..2)
             .ok_or(ParseError::ParseError)?
             .try_into()
@@ -3052,31 +1782,41 @@ impl TryParse for Behavior {
 }
 impl From<CommonBehavior> for Behavior {
     fn from(common: CommonBehavior) -> Self {
-        let common_bytes = common.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let common_bytes = common.serialize_with_order(order);
         Self(common_bytes)
     }
 }
 impl From<DefaultBehavior> for Behavior {
     fn from(default: DefaultBehavior) -> Self {
-        let default_bytes = default.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let default_bytes = default.serialize_with_order(order);
         Self(default_bytes)
     }
 }
 impl From<RadioGroupBehavior> for Behavior {
     fn from(radio_group: RadioGroupBehavior) -> Self {
-        let radio_group_bytes = radio_group.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let radio_group_bytes = radio_group.serialize_with_order(order);
         Self(radio_group_bytes)
     }
 }
 impl From<OverlayBehavior> for Behavior {
     fn from(overlay1: OverlayBehavior) -> Self {
-        let overlay1_bytes = overlay1.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let overlay1_bytes = overlay1.serialize_with_order(order);
         Self(overlay1_bytes)
     }
 }
 impl From<u8> for Behavior {
     fn from(type_: u8) -> Self {
-        let type_bytes = type_.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let type_bytes = type_.serialize_with_order(order);
         let value = [
             type_bytes[0],
             0,
@@ -3085,86 +1825,44 @@ impl From<u8> for Behavior {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum BehaviorType {
-    Default = 0,
-    Lock = 1,
-    RadioGroup = 2,
-    Overlay1 = 3,
-    Overlay2 = 4,
-    PermamentLock = 129,
-    PermamentRadioGroup = 130,
-    PermamentOverlay1 = 131,
-    PermamentOverlay2 = 132,
-}
-impl From<BehaviorType> for u8 {
-    fn from(input: BehaviorType) -> Self {
-        match input {
-            BehaviorType::Default => 0,
-            BehaviorType::Lock => 1,
-            BehaviorType::RadioGroup => 2,
-            BehaviorType::Overlay1 => 3,
-            BehaviorType::Overlay2 => 4,
-            BehaviorType::PermamentLock => 129,
-            BehaviorType::PermamentRadioGroup => 130,
-            BehaviorType::PermamentOverlay1 => 131,
-            BehaviorType::PermamentOverlay2 => 132,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BehaviorType(u8);
+#[allow(non_upper_case_globals)]
+impl BehaviorType {
+    pub const Default: Self = Self(0);
+    pub const Lock: Self = Self(1);
+    pub const RadioGroup: Self = Self(2);
+    pub const Overlay1: Self = Self(3);
+    pub const Overlay2: Self = Self(4);
+    pub const PermamentLock: Self = Self(129);
+    pub const PermamentRadioGroup: Self = Self(130);
+    pub const PermamentOverlay1: Self = Self(131);
+    pub const PermamentOverlay2: Self = Self(132);
 }
+enum_newtype!(BehaviorType, u8, [Default, Lock, RadioGroup, Overlay1, Overlay2, PermamentLock, PermamentRadioGroup, PermamentOverlay1, PermamentOverlay2]);
 impl From<BehaviorType> for Option<u8> {
     fn from(input: BehaviorType) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<BehaviorType> for u16 {
     fn from(input: BehaviorType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<BehaviorType> for Option<u16> {
     fn from(input: BehaviorType) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<BehaviorType> for u32 {
     fn from(input: BehaviorType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<BehaviorType> for Option<u32> {
     fn from(input: BehaviorType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for BehaviorType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(BehaviorType::Default),
-            1 => Ok(BehaviorType::Lock),
-            2 => Ok(BehaviorType::RadioGroup),
-            3 => Ok(BehaviorType::Overlay1),
-            4 => Ok(BehaviorType::Overlay2),
-            129 => Ok(BehaviorType::PermamentLock),
-            130 => Ok(BehaviorType::PermamentRadioGroup),
-            131 => Ok(BehaviorType::PermamentOverlay1),
-            132 => Ok(BehaviorType::PermamentOverlay2),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for BehaviorType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for BehaviorType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -3174,9 +1872,9 @@ pub struct SetBehavior {
     pub behavior: Behavior,
 }
 impl TryParse for SetBehavior {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (behavior, remaining) = Behavior::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (behavior, remaining) = Behavior::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let result = SetBehavior { keycode, behavior };
         Ok((result, remaining))
@@ -3190,9 +1888,9 @@ impl TryFrom<&[u8]> for SetBehavior {
 }
 impl Serialize for SetBehavior {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let keycode_bytes = self.keycode.serialize();
-        let behavior_bytes = self.behavior.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let behavior_bytes = self.behavior.serialize_with_order(order);
         [
             keycode_bytes[0],
             behavior_bytes[0],
@@ -3200,10 +1898,10 @@ impl Serialize for SetBehavior {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.keycode.serialize_into(bytes);
-        self.behavior.serialize_into(bytes);
+        self.keycode.serialize_into_with_order(bytes, order);
+        self.behavior.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
     }
 }
@@ -3211,12 +1909,13 @@ impl Serialize for SetBehavior {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetExplicit {
     pub keycode: xproto::Keycode,
-    pub explicit: u8,
+    pub explicit: Explicit,
 }
 impl TryParse for SetExplicit {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (explicit, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let explicit = Explicit::from(explicit);
         let result = SetExplicit { keycode, explicit };
         Ok((result, remaining))
     }
@@ -3229,30 +1928,31 @@ impl TryFrom<&[u8]> for SetExplicit {
 }
 impl Serialize for SetExplicit {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let keycode_bytes = self.keycode.serialize();
-        let explicit_bytes = self.explicit.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let explicit_bytes = u8::from(self.explicit).serialize_with_order(order);
         [
             keycode_bytes[0],
             explicit_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.keycode.serialize_into(bytes);
-        self.explicit.serialize_into(bytes);
+        self.keycode.serialize_into_with_order(bytes, order);
+        u8::from(self.explicit).serialize_into_with_order(bytes, order);
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KeyModMap {
     pub keycode: xproto::Keycode,
-    pub mods: u8,
+    pub mods: xproto::ModMask,
 }
 impl TryParse for KeyModMap {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let mods = xproto::ModMask::from(u16::from(mods));
         let result = KeyModMap { keycode, mods };
         Ok((result, remaining))
     }
@@ -3265,31 +1965,32 @@ impl TryFrom<&[u8]> for KeyModMap {
 }
 impl Serialize for KeyModMap {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let keycode_bytes = self.keycode.serialize();
-        let mods_bytes = self.mods.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let mods_bytes = u8::try_from(u16::from(self.mods)).unwrap().serialize_with_order(order);
         [
             keycode_bytes[0],
             mods_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.keycode.serialize_into(bytes);
-        self.mods.serialize_into(bytes);
+        self.keycode.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mods)).unwrap().serialize_into_with_order(bytes, order);
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KeyVModMap {
     pub keycode: xproto::Keycode,
-    pub vmods: u16,
+    pub vmods: VMod,
 }
 impl TryParse for KeyVModMap {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (vmods, remaining) = u16::try_parse(remaining)?;
+        let (vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let vmods = VMod::from(vmods);
         let result = KeyVModMap { keycode, vmods };
         Ok((result, remaining))
     }
@@ -3302,9 +2003,9 @@ impl TryFrom<&[u8]> for KeyVModMap {
 }
 impl Serialize for KeyVModMap {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let keycode_bytes = self.keycode.serialize();
-        let vmods_bytes = self.vmods.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let vmods_bytes = u16::from(self.vmods).serialize_with_order(order);
         [
             keycode_bytes[0],
             0,
@@ -3312,25 +2013,27 @@ impl Serialize for KeyVModMap {
             vmods_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.keycode.serialize_into(bytes);
+        self.keycode.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
-        self.vmods.serialize_into(bytes);
+        u16::from(self.vmods).serialize_into_with_order(bytes, order);
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KTSetMapEntry {
     pub level: u8,
-    pub real_mods: u8,
-    pub virtual_mods: u16,
+    pub real_mods: xproto::ModMask,
+    pub virtual_mods: VMod,
 }
 impl TryParse for KTSetMapEntry {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (level, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (level, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let real_mods = xproto::ModMask::from(u16::from(real_mods));
+        let virtual_mods = VMod::from(virtual_mods);
         let result = KTSetMapEntry { level, real_mods, virtual_mods };
         Ok((result, remaining))
     }
@@ -3343,10 +2046,10 @@ impl TryFrom<&[u8]> for KTSetMapEntry {
 }
 impl Serialize for KTSetMapEntry {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let level_bytes = self.level.serialize();
-        let real_mods_bytes = self.real_mods.serialize();
-        let virtual_mods_bytes = self.virtual_mods.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let level_bytes = self.level.serialize_with_order(order);
+        let real_mods_bytes = u8::try_from(u16::from(self.real_mods)).unwrap().serialize_with_order(order);
+        let virtual_mods_bytes = u16::from(self.virtual_mods).serialize_with_order(order);
         [
             level_bytes[0],
             real_mods_bytes[0],
@@ -3354,35 +2057,38 @@ impl Serialize for KTSetMapEntry {
             virtual_mods_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.level.serialize_into(bytes);
-        self.real_mods.serialize_into(bytes);
-        self.virtual_mods.serialize_into(bytes);
+        self.level.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.real_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u16::from(self.virtual_mods).serialize_into_with_order(bytes, order);
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetKeyType {
-    pub mask: u8,
-    pub real_mods: u8,
-    pub virtual_mods: u16,
+    pub mask: xproto::ModMask,
+    pub real_mods: xproto::ModMask,
+    pub virtual_mods: VMod,
     pub num_levels: u8,
     pub preserve: bool,
     pub entries: Vec<KTSetMapEntry>,
     pub preserve_entries: Vec<KTSetMapEntry>,
 }
 impl TryParse for SetKeyType {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (num_levels, remaining) = u8::try_parse(remaining)?;
-        let (n_map_entries, remaining) = u8::try_parse(remaining)?;
-        let (preserve, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_levels, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_map_entries, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (preserve, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (entries, remaining) = crate::x11_utils::parse_list::<KTSetMapEntry>(remaining, n_map_entries.try_into().or(Err(ParseError::ParseError))?)?;
-        let (preserve_entries, remaining) = crate::x11_utils::parse_list::<KTSetMapEntry>(remaining, u32::from(preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (entries, remaining) = crate::x11_utils::parse_list_with_order::<KTSetMapEntry>(remaining, n_map_entries.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (preserve_entries, remaining) = crate::x11_utils::parse_list_with_order::<KTSetMapEntry>(remaining, u32::from(preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::ParseError)?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let mask = xproto::ModMask::from(u16::from(mask));
+        let real_mods = xproto::ModMask::from(u16::from(real_mods));
+        let virtual_mods = VMod::from(virtual_mods);
         let result = SetKeyType { mask, real_mods, virtual_mods, num_levels, preserve, entries, preserve_entries };
         Ok((result, remaining))
     }
@@ -3395,24 +2101,24 @@ impl TryFrom<&[u8]> for SetKeyType {
 }
 impl Serialize for SetKeyType {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.mask.serialize_into(bytes);
-        self.real_mods.serialize_into(bytes);
-        self.virtual_mods.serialize_into(bytes);
-        self.num_levels.serialize_into(bytes);
+        u8::try_from(u16::from(self.mask)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.real_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u16::from(self.virtual_mods).serialize_into_with_order(bytes, order);
+        self.num_levels.serialize_into_with_order(bytes, order);
         let n_map_entries = u8::try_from(self.entries.len()).expect("`entries` has too many elements");
-        n_map_entries.serialize_into(bytes);
-        self.preserve.serialize_into(bytes);
+        n_map_entries.serialize_into_with_order(bytes, order);
+        self.preserve.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
-        self.entries.serialize_into(bytes);
+        self.entries.serialize_into_with_order(bytes, order);
         assert_eq!(self.preserve_entries.len(), usize::try_from(u32::from(self.preserve).checked_mul(u32::from(n_map_entries)).unwrap()).unwrap(), "`preserve_entries` has an incorrect length");
-        self.preserve_entries.serialize_into(bytes);
+        self.preserve_entries.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3424,11 +2130,11 @@ pub struct Outline {
     pub points: Vec<xproto::Point>,
 }
 impl TryParse for Outline {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (n_points, remaining) = u8::try_parse(remaining)?;
-        let (corner_radius, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (n_points, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (corner_radius, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (points, remaining) = crate::x11_utils::parse_list::<xproto::Point>(remaining, n_points.try_into().or(Err(ParseError::ParseError))?)?;
+        let (points, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Point>(remaining, n_points.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = Outline { corner_radius, points };
         Ok((result, remaining))
     }
@@ -3441,18 +2147,18 @@ impl TryFrom<&[u8]> for Outline {
 }
 impl Serialize for Outline {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
         let n_points = u8::try_from(self.points.len()).expect("`points` has too many elements");
-        n_points.serialize_into(bytes);
-        self.corner_radius.serialize_into(bytes);
+        n_points.serialize_into_with_order(bytes, order);
+        self.corner_radius.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.points.serialize_into(bytes);
+        self.points.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3464,13 +2170,13 @@ pub struct Shape {
     pub outlines: Vec<Outline>,
 }
 impl TryParse for Shape {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (n_outlines, remaining) = u8::try_parse(remaining)?;
-        let (primary_ndx, remaining) = u8::try_parse(remaining)?;
-        let (approx_ndx, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (n_outlines, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (primary_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (approx_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (outlines, remaining) = crate::x11_utils::parse_list::<Outline>(remaining, n_outlines.try_into().or(Err(ParseError::ParseError))?)?;
+        let (outlines, remaining) = crate::x11_utils::parse_list_with_order::<Outline>(remaining, n_outlines.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = Shape { name, primary_ndx, approx_ndx, outlines };
         Ok((result, remaining))
     }
@@ -3483,20 +2189,20 @@ impl TryFrom<&[u8]> for Shape {
 }
 impl Serialize for Shape {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.name.serialize_into(bytes);
+        self.name.serialize_into_with_order(bytes, order);
         let n_outlines = u8::try_from(self.outlines.len()).expect("`outlines` has too many elements");
-        n_outlines.serialize_into(bytes);
-        self.primary_ndx.serialize_into(bytes);
-        self.approx_ndx.serialize_into(bytes);
+        n_outlines.serialize_into_with_order(bytes, order);
+        self.primary_ndx.serialize_into_with_order(bytes, order);
+        self.approx_ndx.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
-        self.outlines.serialize_into(bytes);
+        self.outlines.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3508,12 +2214,12 @@ pub struct Key {
     pub color_ndx: u8,
 }
 impl TryParse for Key {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
         let name = <[u8; 4]>::try_from(name).unwrap();
-        let (gap, remaining) = i16::try_parse(remaining)?;
-        let (shape_ndx, remaining) = u8::try_parse(remaining)?;
-        let (color_ndx, remaining) = u8::try_parse(remaining)?;
+        let (gap, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (shape_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (color_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
         let result = Key { name, gap, shape_ndx, color_ndx };
         Ok((result, remaining))
     }
@@ -3526,10 +2232,10 @@ impl TryFrom<&[u8]> for Key {
 }
 impl Serialize for Key {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let gap_bytes = self.gap.serialize();
-        let shape_ndx_bytes = self.shape_ndx.serialize();
-        let color_ndx_bytes = self.color_ndx.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let gap_bytes = self.gap.serialize_with_order(order);
+        let shape_ndx_bytes = self.shape_ndx.serialize_with_order(order);
+        let color_ndx_bytes = self.color_ndx.serialize_with_order(order);
         [
             self.name[0],
             self.name[1],
@@ -3541,12 +2247,12 @@ impl Serialize for Key {
             color_ndx_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
         bytes.extend_from_slice(&self.name);
-        self.gap.serialize_into(bytes);
-        self.shape_ndx.serialize_into(bytes);
-        self.color_ndx.serialize_into(bytes);
+        self.gap.serialize_into_with_order(bytes, order);
+        self.shape_ndx.serialize_into_with_order(bytes, order);
+        self.color_ndx.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3556,7 +2262,7 @@ pub struct OverlayKey {
     pub under: [String8; 4],
 }
 impl TryParse for OverlayKey {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], _order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let (over, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
         let over = <[u8; 4]>::try_from(over).unwrap();
         let (under, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
@@ -3573,7 +2279,7 @@ impl TryFrom<&[u8]> for OverlayKey {
 }
 impl Serialize for OverlayKey {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
+    fn serialize_with_order(&self, _order: ByteOrder) -> [u8; 8] {
         [
             self.over[0],
             self.over[1],
@@ -3585,7 +2291,7 @@ impl Serialize for OverlayKey {
             self.under[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {
         bytes.reserve(8);
         bytes.extend_from_slice(&self.over);
         bytes.extend_from_slice(&self.under);
@@ -3598,11 +2304,11 @@ pub struct OverlayRow {
     pub keys: Vec<OverlayKey>,
 }
 impl TryParse for OverlayRow {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (row_under, remaining) = u8::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (row_under, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (keys, remaining) = crate::x11_utils::parse_list::<OverlayKey>(remaining, n_keys.try_into().or(Err(ParseError::ParseError))?)?;
+        let (keys, remaining) = crate::x11_utils::parse_list_with_order::<OverlayKey>(remaining, n_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = OverlayRow { row_under, keys };
         Ok((result, remaining))
     }
@@ -3615,18 +2321,18 @@ impl TryFrom<&[u8]> for OverlayRow {
 }
 impl Serialize for OverlayRow {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.row_under.serialize_into(bytes);
+        self.row_under.serialize_into_with_order(bytes, order);
         let n_keys = u8::try_from(self.keys.len()).expect("`keys` has too many elements");
-        n_keys.serialize_into(bytes);
+        n_keys.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.keys.serialize_into(bytes);
+        self.keys.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3636,11 +2342,11 @@ pub struct Overlay {
     pub rows: Vec<OverlayRow>,
 }
 impl TryParse for Overlay {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (n_rows, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (n_rows, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (rows, remaining) = crate::x11_utils::parse_list::<OverlayRow>(remaining, n_rows.try_into().or(Err(ParseError::ParseError))?)?;
+        let (rows, remaining) = crate::x11_utils::parse_list_with_order::<OverlayRow>(remaining, n_rows.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = Overlay { name, rows };
         Ok((result, remaining))
     }
@@ -3653,18 +2359,18 @@ impl TryFrom<&[u8]> for Overlay {
 }
 impl Serialize for Overlay {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.name.serialize_into(bytes);
+        self.name.serialize_into_with_order(bytes, order);
         let n_rows = u8::try_from(self.rows.len()).expect("`rows` has too many elements");
-        n_rows.serialize_into(bytes);
+        n_rows.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
-        self.rows.serialize_into(bytes);
+        self.rows.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -3676,13 +2382,13 @@ pub struct Row {
     pub keys: Vec<Key>,
 }
 impl TryParse for Row {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (top, remaining) = i16::try_parse(remaining)?;
-        let (left, remaining) = i16::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let (vertical, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (top, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (left, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vertical, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (keys, remaining) = crate::x11_utils::parse_list::<Key>(remaining, n_keys.try_into().or(Err(ParseError::ParseError))?)?;
+        let (keys, remaining) = crate::x11_utils::parse_list_with_order::<Key>(remaining, n_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = Row { top, left, vertical, keys };
         Ok((result, remaining))
     }
@@ -3695,91 +2401,57 @@ impl TryFrom<&[u8]> for Row {
 }
 impl Serialize for Row {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.top.serialize_into(bytes);
-        self.left.serialize_into(bytes);
+        self.top.serialize_into_with_order(bytes, order);
+        self.left.serialize_into_with_order(bytes, order);
         let n_keys = u8::try_from(self.keys.len()).expect("`keys` has too many elements");
-        n_keys.serialize_into(bytes);
-        self.vertical.serialize_into(bytes);
+        n_keys.serialize_into_with_order(bytes, order);
+        self.vertical.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.keys.serialize_into(bytes);
+        self.keys.serialize_into_with_order(bytes, order);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum DoodadType {
-    Outline = 1,
-    Solid = 2,
-    Text = 3,
-    Indicator = 4,
-    Logo = 5,
-}
-impl From<DoodadType> for u8 {
-    fn from(input: DoodadType) -> Self {
-        match input {
-            DoodadType::Outline => 1,
-            DoodadType::Solid => 2,
-            DoodadType::Text => 3,
-            DoodadType::Indicator => 4,
-            DoodadType::Logo => 5,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DoodadType(u8);
+#[allow(non_upper_case_globals)]
+impl DoodadType {
+    pub const Outline: Self = Self(1);
+    pub const Solid: Self = Self(2);
+    pub const Text: Self = Self(3);
+    pub const Indicator: Self = Self(4);
+    pub const Logo: Self = Self(5);
 }
+enum_newtype!(DoodadType, u8, [Outline, Solid, Text, Indicator, Logo]);
 impl From<DoodadType> for Option<u8> {
     fn from(input: DoodadType) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<DoodadType> for u16 {
     fn from(input: DoodadType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DoodadType> for Option<u16> {
     fn from(input: DoodadType) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<DoodadType> for u32 {
     fn from(input: DoodadType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<DoodadType> for Option<u32> {
     fn from(input: DoodadType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for DoodadType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(DoodadType::Outline),
-            2 => Ok(DoodadType::Solid),
-            3 => Ok(DoodadType::Text),
-            4 => Ok(DoodadType::Indicator),
-            5 => Ok(DoodadType::Logo),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for DoodadType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for DoodadType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -3789,10 +2461,10 @@ pub struct Listing {
     pub string: Vec<String8>,
 }
 impl TryParse for Listing {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (flags, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u16::try_parse(remaining)?;
+        let (flags, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u16::try_parse_with_order(remaining, order)?;
         let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
         let string = string.to_vec();
         // Align offset to multiple of 2
@@ -3811,16 +2483,16 @@ impl TryFrom<&[u8]> for Listing {
 }
 impl Serialize for Listing {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.flags.serialize_into(bytes);
+        self.flags.serialize_into_with_order(bytes, order);
         let length = u16::try_from(self.string.len()).expect("`string` has too many elements");
-        length.serialize_into(bytes);
+        length.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.string);
         bytes.extend_from_slice(&[0; 1][..(2 - (bytes.len() % 2)) % 2]);
     }
@@ -3838,16 +2510,16 @@ pub struct DeviceLedInfo {
     pub maps: Vec<IndicatorMap>,
 }
 impl TryParse for DeviceLedInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
-        let (led_id, remaining) = IDSpec::try_parse(remaining)?;
-        let (names_present, remaining) = u32::try_parse(remaining)?;
-        let (maps_present, remaining) = u32::try_parse(remaining)?;
-        let (phys_indicators, remaining) = u32::try_parse(remaining)?;
-        let (state, remaining) = u32::try_parse(remaining)?;
-        let (names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, names_present.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
-        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, maps_present.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
-        let led_class = led_class.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (led_class, remaining) = LedClassSpec::try_parse_with_order(remaining, order)?;
+        let (led_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let (names_present, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (maps_present, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (phys_indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, names_present.count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (maps, remaining) = crate::x11_utils::parse_list_with_order::<IndicatorMap>(remaining, maps_present.count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
+        let led_class = LedClass::from(led_class);
         let result = DeviceLedInfo { led_class, led_id, names_present, maps_present, phys_indicators, state, names, maps };
         Ok((result, remaining))
     }
@@ -3860,88 +2532,58 @@ impl TryFrom<&[u8]> for DeviceLedInfo {
 }
 impl Serialize for DeviceLedInfo {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(20);
-        LedClassSpec::from(self.led_class).serialize_into(bytes);
-        self.led_id.serialize_into(bytes);
-        self.names_present.serialize_into(bytes);
-        self.maps_present.serialize_into(bytes);
-        self.phys_indicators.serialize_into(bytes);
-        self.state.serialize_into(bytes);
+        u16::from(self.led_class).serialize_into_with_order(bytes, order);
+        self.led_id.serialize_into_with_order(bytes, order);
+        self.names_present.serialize_into_with_order(bytes, order);
+        self.maps_present.serialize_into_with_order(bytes, order);
+        self.phys_indicators.serialize_into_with_order(bytes, order);
+        self.state.serialize_into_with_order(bytes, order);
         assert_eq!(self.names.len(), usize::try_from(self.names_present.count_ones()).unwrap(), "`names` has an incorrect length");
-        self.names.serialize_into(bytes);
+        self.names.serialize_into_with_order(bytes, order);
         assert_eq!(self.maps.len(), usize::try_from(self.maps_present.count_ones()).unwrap(), "`maps` has an incorrect length");
-        self.maps.serialize_into(bytes);
+        self.maps.serialize_into_with_order(bytes, order);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Error {
-    BadDevice = 255,
-    BadClass = 254,
-    BadId = 253,
-}
-impl From<Error> for u8 {
-    fn from(input: Error) -> Self {
-        match input {
-            Error::BadDevice => 255,
-            Error::BadClass => 254,
-            Error::BadId => 253,
-        }
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Error(u8);
+#[allow(non_upper_case_globals)]
+impl Error {
+    pub const BadDevice: Self = Self(255);
+    pub const BadClass: Self = Self(254);
+    pub const BadId: Self = Self(253);
 }
+enum_newtype!(Error, u8, [BadDevice, BadClass, BadId]);
 impl From<Error> for Option<u8> {
     fn from(input: Error) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Error> for u16 {
     fn from(input: Error) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Error> for Option<u16> {
     fn from(input: Error) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Error> for u32 {
     fn from(input: Error) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Error> for Option<u32> {
     fn from(input: Error) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Error {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            255 => Ok(Error::BadDevice),
-            254 => Ok(Error::BadClass),
-            253 => Ok(Error::BadId),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Error {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Error {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -3957,13 +2599,13 @@ pub struct KeyboardError {
     pub major_opcode: u8,
 }
 impl TryParse for KeyboardError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (value, remaining) = u32::try_parse(remaining)?;
-        let (minor_opcode, remaining) = u16::try_parse(remaining)?;
-        let (major_opcode, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_opcode, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (major_opcode, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(21..).ok_or(ParseError::ParseError)?;
         let result = KeyboardError { response_type, error_code, sequence, value, minor_opcode, major_opcode };
         Ok((result, remaining))
@@ -3977,12 +2619,23 @@ impl TryFrom<&[u8]> for KeyboardError {
 }
 impl From<&KeyboardError> for [u8; 32] {
     fn from(input: &KeyboardError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let value_bytes = input.value.serialize();
-        let minor_opcode_bytes = input.minor_opcode.serialize();
-        let major_opcode_bytes = input.major_opcode.serialize();
+        input.serialize()
+    }
+}
+impl From<KeyboardError> for [u8; 32] {
+    fn from(input: KeyboardError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for KeyboardError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
+        let minor_opcode_bytes = self.minor_opcode.serialize_with_order(order);
+        let major_opcode_bytes = self.major_opcode.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -4018,173 +2671,97 @@ impl From<&KeyboardError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<KeyboardError> for [u8; 32] {
-    fn from(input: KeyboardError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SA {
-    ClearLocks,
-    LatchToLock,
-    UseModMapMods,
-    GroupAbsolute,
-}
-impl From<SA> for u8 {
-    fn from(input: SA) -> Self {
-        match input {
-            SA::ClearLocks => 1 << 0,
-            SA::LatchToLock => 1 << 1,
-            SA::UseModMapMods => 1 << 2,
-            SA::GroupAbsolute => 1 << 2,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SA(u8);
+#[allow(non_upper_case_globals)]
+impl SA {
+    pub const ClearLocks: Self = Self(1 << 0);
+    pub const LatchToLock: Self = Self(1 << 1);
+    pub const UseModMapMods: Self = Self(1 << 2);
+    pub const GroupAbsolute: Self = Self(1 << 2);
 }
+bitmask_set!(SA, u8, [ClearLocks, LatchToLock, UseModMapMods, GroupAbsolute]);
 impl From<SA> for Option<u8> {
     fn from(input: SA) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SA> for u16 {
     fn from(input: SA) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SA> for Option<u16> {
     fn from(input: SA) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SA> for u32 {
     fn from(input: SA) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SA> for Option<u32> {
     fn from(input: SA) -> Self {
-        Some(u32::from(input))
-    }
-}
-bitmask_binop!(SA, u8);
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SAType {
-    NoAction = 0,
-    SetMods = 1,
-    LatchMods = 2,
-    LockMods = 3,
-    SetGroup = 4,
-    LatchGroup = 5,
-    LockGroup = 6,
-    MovePtr = 7,
-    PtrBtn = 8,
-    LockPtrBtn = 9,
-    SetPtrDflt = 10,
-    ISOLock = 11,
-    Terminate = 12,
-    SwitchScreen = 13,
-    SetControls = 14,
-    LockControls = 15,
-    ActionMessage = 16,
-    RedirectKey = 17,
-    DeviceBtn = 18,
-    LockDeviceBtn = 19,
-    DeviceValuator = 20,
-}
-impl From<SAType> for u8 {
-    fn from(input: SAType) -> Self {
-        match input {
-            SAType::NoAction => 0,
-            SAType::SetMods => 1,
-            SAType::LatchMods => 2,
-            SAType::LockMods => 3,
-            SAType::SetGroup => 4,
-            SAType::LatchGroup => 5,
-            SAType::LockGroup => 6,
-            SAType::MovePtr => 7,
-            SAType::PtrBtn => 8,
-            SAType::LockPtrBtn => 9,
-            SAType::SetPtrDflt => 10,
-            SAType::ISOLock => 11,
-            SAType::Terminate => 12,
-            SAType::SwitchScreen => 13,
-            SAType::SetControls => 14,
-            SAType::LockControls => 15,
-            SAType::ActionMessage => 16,
-            SAType::RedirectKey => 17,
-            SAType::DeviceBtn => 18,
-            SAType::LockDeviceBtn => 19,
-            SAType::DeviceValuator => 20,
-        }
-    }
-}
+        Some(u32::from(input.0))
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SAType(u8);
+#[allow(non_upper_case_globals)]
+impl SAType {
+    pub const NoAction: Self = Self(0);
+    pub const SetMods: Self = Self(1);
+    pub const LatchMods: Self = Self(2);
+    pub const LockMods: Self = Self(3);
+    pub const SetGroup: Self = Self(4);
+    pub const LatchGroup: Self = Self(5);
+    pub const LockGroup: Self = Self(6);
+    pub const MovePtr: Self = Self(7);
+    pub const PtrBtn: Self = Self(8);
+    pub const LockPtrBtn: Self = Self(9);
+    pub const SetPtrDflt: Self = Self(10);
+    pub const ISOLock: Self = Self(11);
+    pub const Terminate: Self = Self(12);
+    pub const SwitchScreen: Self = Self(13);
+    pub const SetControls: Self = Self(14);
+    pub const LockControls: Self = Self(15);
+    pub const ActionMessage: Self = Self(16);
+    pub const RedirectKey: Self = Self(17);
+    pub const DeviceBtn: Self = Self(18);
+    pub const LockDeviceBtn: Self = Self(19);
+    pub const DeviceValuator: Self = Self(20);
+}
+enum_newtype!(SAType, u8, [NoAction, SetMods, LatchMods, LockMods, SetGroup, LatchGroup, LockGroup, MovePtr, PtrBtn, LockPtrBtn, SetPtrDflt, ISOLock, Terminate, SwitchScreen, SetControls, LockControls, ActionMessage, RedirectKey, DeviceBtn, LockDeviceBtn, DeviceValuator]);
 impl From<SAType> for Option<u8> {
     fn from(input: SAType) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SAType> for u16 {
     fn from(input: SAType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SAType> for Option<u16> {
     fn from(input: SAType) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SAType> for u32 {
     fn from(input: SAType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SAType> for Option<u32> {
     fn from(input: SAType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SAType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SAType::NoAction),
-            1 => Ok(SAType::SetMods),
-            2 => Ok(SAType::LatchMods),
-            3 => Ok(SAType::LockMods),
-            4 => Ok(SAType::SetGroup),
-            5 => Ok(SAType::LatchGroup),
-            6 => Ok(SAType::LockGroup),
-            7 => Ok(SAType::MovePtr),
-            8 => Ok(SAType::PtrBtn),
-            9 => Ok(SAType::LockPtrBtn),
-            10 => Ok(SAType::SetPtrDflt),
-            11 => Ok(SAType::ISOLock),
-            12 => Ok(SAType::Terminate),
-            13 => Ok(SAType::SwitchScreen),
-            14 => Ok(SAType::SetControls),
-            15 => Ok(SAType::LockControls),
-            16 => Ok(SAType::ActionMessage),
-            17 => Ok(SAType::RedirectKey),
-            18 => Ok(SAType::DeviceBtn),
-            19 => Ok(SAType::LockDeviceBtn),
-            20 => Ok(SAType::DeviceValuator),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SAType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SAType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -4193,10 +2770,10 @@ pub struct SANoAction {
     pub type_: SAType,
 }
 impl TryParse for SANoAction {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(7..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
         let result = SANoAction { type_ };
         Ok((result, remaining))
     }
@@ -4209,8 +2786,8 @@ impl TryFrom<&[u8]> for SANoAction {
 }
 impl Serialize for SANoAction {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
         [
             type_bytes[0],
             0,
@@ -4222,9 +2799,9 @@ impl Serialize for SANoAction {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 7]);
     }
 }
@@ -4232,22 +2809,27 @@ impl Serialize for SANoAction {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SASetMods {
     pub type_: SAType,
-    pub flags: u8,
-    pub mask: u8,
-    pub real_mods: u8,
-    pub vmods_high: u8,
-    pub vmods_low: u8,
+    pub flags: SA,
+    pub mask: xproto::ModMask,
+    pub real_mods: xproto::ModMask,
+    pub vmods_high: VModsHigh,
+    pub vmods_low: VModsLow,
 }
 impl TryParse for SASetMods {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (vmods_high, remaining) = u8::try_parse(remaining)?;
-        let (vmods_low, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_low, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
+        let flags = SA::from(flags);
+        let mask = xproto::ModMask::from(u16::from(mask));
+        let real_mods = xproto::ModMask::from(u16::from(real_mods));
+        let vmods_high = VModsHigh::from(vmods_high);
+        let vmods_low = VModsLow::from(vmods_low);
         let result = SASetMods { type_, flags, mask, real_mods, vmods_high, vmods_low };
         Ok((result, remaining))
     }
@@ -4260,13 +2842,13 @@ impl TryFrom<&[u8]> for SASetMods {
 }
 impl Serialize for SASetMods {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let mask_bytes = self.mask.serialize();
-        let real_mods_bytes = self.real_mods.serialize();
-        let vmods_high_bytes = self.vmods_high.serialize();
-        let vmods_low_bytes = self.vmods_low.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = u8::from(self.flags).serialize_with_order(order);
+        let mask_bytes = u8::try_from(u16::from(self.mask)).unwrap().serialize_with_order(order);
+        let real_mods_bytes = u8::try_from(u16::from(self.real_mods)).unwrap().serialize_with_order(order);
+        let vmods_high_bytes = u8::from(self.vmods_high).serialize_with_order(order);
+        let vmods_low_bytes = u8::from(self.vmods_low).serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -4278,14 +2860,14 @@ impl Serialize for SASetMods {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
-        self.mask.serialize_into(bytes);
-        self.real_mods.serialize_into(bytes);
-        self.vmods_high.serialize_into(bytes);
-        self.vmods_low.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        u8::from(self.flags).serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mask)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.real_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::from(self.vmods_high).serialize_into_with_order(bytes, order);
+        u8::from(self.vmods_low).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
@@ -4297,16 +2879,17 @@ pub type SALockMods = SASetMods;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SASetGroup {
     pub type_: SAType,
-    pub flags: u8,
+    pub flags: SA,
     pub group: i8,
 }
 impl TryParse for SASetGroup {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = i8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = i8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(5..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
+        let flags = SA::from(flags);
         let result = SASetGroup { type_, flags, group };
         Ok((result, remaining))
     }
@@ -4319,10 +2902,10 @@ impl TryFrom<&[u8]> for SASetGroup {
 }
 impl Serialize for SASetGroup {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let group_bytes = self.group.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = u8::from(self.flags).serialize_with_order(order);
+        let group_bytes = self.group.serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -4334,11 +2917,11 @@ impl Serialize for SASetGroup {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
-        self.group.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        u8::from(self.flags).serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 5]);
     }
 }
@@ -4347,91 +2930,61 @@ pub type SALatchGroup = SASetGroup;
 
 pub type SALockGroup = SASetGroup;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SAMovePtrFlag {
-    NoAcceleration = 1 << 0,
-    MoveAbsoluteX = 1 << 1,
-    MoveAbsoluteY = 1 << 2,
-}
-impl From<SAMovePtrFlag> for u8 {
-    fn from(input: SAMovePtrFlag) -> Self {
-        match input {
-            SAMovePtrFlag::NoAcceleration => 1 << 0,
-            SAMovePtrFlag::MoveAbsoluteX => 1 << 1,
-            SAMovePtrFlag::MoveAbsoluteY => 1 << 2,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SAMovePtrFlag(u8);
+#[allow(non_upper_case_globals)]
+impl SAMovePtrFlag {
+    pub const NoAcceleration: Self = Self(1 << 0);
+    pub const MoveAbsoluteX: Self = Self(1 << 1);
+    pub const MoveAbsoluteY: Self = Self(1 << 2);
 }
+bitmask_set!(SAMovePtrFlag, u8, [NoAcceleration, MoveAbsoluteX, MoveAbsoluteY]);
 impl From<SAMovePtrFlag> for Option<u8> {
     fn from(input: SAMovePtrFlag) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SAMovePtrFlag> for u16 {
     fn from(input: SAMovePtrFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SAMovePtrFlag> for Option<u16> {
     fn from(input: SAMovePtrFlag) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SAMovePtrFlag> for u32 {
     fn from(input: SAMovePtrFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SAMovePtrFlag> for Option<u32> {
     fn from(input: SAMovePtrFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SAMovePtrFlag {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(SAMovePtrFlag::NoAcceleration),
-            2 => Ok(SAMovePtrFlag::MoveAbsoluteX),
-            4 => Ok(SAMovePtrFlag::MoveAbsoluteY),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SAMovePtrFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u32> for SAMovePtrFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(SAMovePtrFlag, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SAMovePtr {
     pub type_: SAType,
-    pub flags: u8,
+    pub flags: SAMovePtrFlag,
     pub x_high: i8,
     pub x_low: u8,
     pub y_high: i8,
     pub y_low: u8,
 }
 impl TryParse for SAMovePtr {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (x_high, remaining) = i8::try_parse(remaining)?;
-        let (x_low, remaining) = u8::try_parse(remaining)?;
-        let (y_high, remaining) = i8::try_parse(remaining)?;
-        let (y_low, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (x_high, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (x_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (y_high, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (y_low, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
+        let flags = SAMovePtrFlag::from(flags);
         let result = SAMovePtr { type_, flags, x_high, x_low, y_high, y_low };
         Ok((result, remaining))
     }
@@ -4444,13 +2997,13 @@ impl TryFrom<&[u8]> for SAMovePtr {
 }
 impl Serialize for SAMovePtr {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let x_high_bytes = self.x_high.serialize();
-        let x_low_bytes = self.x_low.serialize();
-        let y_high_bytes = self.y_high.serialize();
-        let y_low_bytes = self.y_low.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = u8::from(self.flags).serialize_with_order(order);
+        let x_high_bytes = self.x_high.serialize_with_order(order);
+        let x_low_bytes = self.x_low.serialize_with_order(order);
+        let y_high_bytes = self.y_high.serialize_with_order(order);
+        let y_low_bytes = self.y_low.serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -4462,14 +3015,14 @@ impl Serialize for SAMovePtr {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
-        self.x_high.serialize_into(bytes);
-        self.x_low.serialize_into(bytes);
-        self.y_high.serialize_into(bytes);
-        self.y_low.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        u8::from(self.flags).serialize_into_with_order(bytes, order);
+        self.x_high.serialize_into_with_order(bytes, order);
+        self.x_low.serialize_into_with_order(bytes, order);
+        self.y_high.serialize_into_with_order(bytes, order);
+        self.y_low.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
@@ -4482,13 +3035,13 @@ pub struct SAPtrBtn {
     pub button: u8,
 }
 impl TryParse for SAPtrBtn {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (count, remaining) = u8::try_parse(remaining)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
         let result = SAPtrBtn { type_, flags, count, button };
         Ok((result, remaining))
     }
@@ -4501,11 +3054,11 @@ impl TryFrom<&[u8]> for SAPtrBtn {
 }
 impl Serialize for SAPtrBtn {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let count_bytes = self.count.serialize();
-        let button_bytes = self.button.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let button_bytes = self.button.serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -4517,12 +3070,12 @@ impl Serialize for SAPtrBtn {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
-        self.count.serialize_into(bytes);
-        self.button.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.count.serialize_into_with_order(bytes, order);
+        self.button.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 4]);
     }
 }
@@ -4534,13 +3087,13 @@ pub struct SALockPtrBtn {
     pub button: u8,
 }
 impl TryParse for SALockPtrBtn {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
         let result = SALockPtrBtn { type_, flags, button };
         Ok((result, remaining))
     }
@@ -4553,10 +3106,10 @@ impl TryFrom<&[u8]> for SALockPtrBtn {
 }
 impl Serialize for SALockPtrBtn {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let button_bytes = self.button.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let button_bytes = self.button.serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -4568,94 +3121,67 @@ impl Serialize for SALockPtrBtn {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
-        self.button.serialize_into(bytes);
+        self.button.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 4]);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SASetPtrDfltFlag {
-    DfltBtnAbsolute = 1 << 2,
-    AffectDfltButton = 1 << 0,
-}
-impl From<SASetPtrDfltFlag> for u8 {
-    fn from(input: SASetPtrDfltFlag) -> Self {
-        match input {
-            SASetPtrDfltFlag::DfltBtnAbsolute => 1 << 2,
-            SASetPtrDfltFlag::AffectDfltButton => 1 << 0,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SASetPtrDfltFlag(u8);
+#[allow(non_upper_case_globals)]
+impl SASetPtrDfltFlag {
+    pub const DfltBtnAbsolute: Self = Self(1 << 2);
+    pub const AffectDfltButton: Self = Self(1 << 0);
 }
+bitmask_set!(SASetPtrDfltFlag, u8, [DfltBtnAbsolute, AffectDfltButton]);
 impl From<SASetPtrDfltFlag> for Option<u8> {
     fn from(input: SASetPtrDfltFlag) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SASetPtrDfltFlag> for u16 {
     fn from(input: SASetPtrDfltFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SASetPtrDfltFlag> for Option<u16> {
     fn from(input: SASetPtrDfltFlag) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SASetPtrDfltFlag> for u32 {
     fn from(input: SASetPtrDfltFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SASetPtrDfltFlag> for Option<u32> {
     fn from(input: SASetPtrDfltFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SASetPtrDfltFlag {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            4 => Ok(SASetPtrDfltFlag::DfltBtnAbsolute),
-            1 => Ok(SASetPtrDfltFlag::AffectDfltButton),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SASetPtrDfltFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u32> for SASetPtrDfltFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(SASetPtrDfltFlag, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SASetPtrDflt {
     pub type_: SAType,
-    pub flags: u8,
-    pub affect: u8,
+    pub flags: SASetPtrDfltFlag,
+    pub affect: SASetPtrDfltFlag,
     pub value: i8,
 }
 impl TryParse for SASetPtrDflt {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (affect, remaining) = u8::try_parse(remaining)?;
-        let (value, remaining) = i8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (affect, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = i8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
+        let flags = SASetPtrDfltFlag::from(flags);
+        let affect = SASetPtrDfltFlag::from(affect);
         let result = SASetPtrDflt { type_, flags, affect, value };
         Ok((result, remaining))
     }
@@ -4668,11 +3194,11 @@ impl TryFrom<&[u8]> for SASetPtrDflt {
 }
 impl Serialize for SASetPtrDflt {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let affect_bytes = self.affect.serialize();
-        let value_bytes = self.value.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = u8::from(self.flags).serialize_with_order(order);
+        let affect_bytes = u8::from(self.affect).serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -4684,153 +3210,117 @@ impl Serialize for SASetPtrDflt {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
-        self.affect.serialize_into(bytes);
-        self.value.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        u8::from(self.flags).serialize_into_with_order(bytes, order);
+        u8::from(self.affect).serialize_into_with_order(bytes, order);
+        self.value.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 4]);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SAIsoLockFlag {
-    NoLock,
-    NoUnlock,
-    UseModMapMods,
-    GroupAbsolute,
-    ISODfltIsGroup,
-}
-impl From<SAIsoLockFlag> for u8 {
-    fn from(input: SAIsoLockFlag) -> Self {
-        match input {
-            SAIsoLockFlag::NoLock => 1 << 0,
-            SAIsoLockFlag::NoUnlock => 1 << 1,
-            SAIsoLockFlag::UseModMapMods => 1 << 2,
-            SAIsoLockFlag::GroupAbsolute => 1 << 2,
-            SAIsoLockFlag::ISODfltIsGroup => 1 << 3,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SAIsoLockFlag(u8);
+#[allow(non_upper_case_globals)]
+impl SAIsoLockFlag {
+    pub const NoLock: Self = Self(1 << 0);
+    pub const NoUnlock: Self = Self(1 << 1);
+    pub const UseModMapMods: Self = Self(1 << 2);
+    pub const GroupAbsolute: Self = Self(1 << 2);
+    pub const ISODfltIsGroup: Self = Self(1 << 3);
 }
+bitmask_set!(SAIsoLockFlag, u8, [NoLock, NoUnlock, UseModMapMods, GroupAbsolute, ISODfltIsGroup]);
 impl From<SAIsoLockFlag> for Option<u8> {
     fn from(input: SAIsoLockFlag) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SAIsoLockFlag> for u16 {
     fn from(input: SAIsoLockFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SAIsoLockFlag> for Option<u16> {
     fn from(input: SAIsoLockFlag) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SAIsoLockFlag> for u32 {
     fn from(input: SAIsoLockFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SAIsoLockFlag> for Option<u32> {
     fn from(input: SAIsoLockFlag) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(SAIsoLockFlag, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SAIsoLockNoAffect {
-    Ctrls = 1 << 3,
-    Ptr = 1 << 4,
-    Group = 1 << 5,
-    Mods = 1 << 6,
-}
-impl From<SAIsoLockNoAffect> for u8 {
-    fn from(input: SAIsoLockNoAffect) -> Self {
-        match input {
-            SAIsoLockNoAffect::Ctrls => 1 << 3,
-            SAIsoLockNoAffect::Ptr => 1 << 4,
-            SAIsoLockNoAffect::Group => 1 << 5,
-            SAIsoLockNoAffect::Mods => 1 << 6,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SAIsoLockNoAffect(u8);
+#[allow(non_upper_case_globals)]
+impl SAIsoLockNoAffect {
+    pub const Ctrls: Self = Self(1 << 3);
+    pub const Ptr: Self = Self(1 << 4);
+    pub const Group: Self = Self(1 << 5);
+    pub const Mods: Self = Self(1 << 6);
 }
+bitmask_set!(SAIsoLockNoAffect, u8, [Ctrls, Ptr, Group, Mods]);
 impl From<SAIsoLockNoAffect> for Option<u8> {
     fn from(input: SAIsoLockNoAffect) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SAIsoLockNoAffect> for u16 {
     fn from(input: SAIsoLockNoAffect) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SAIsoLockNoAffect> for Option<u16> {
     fn from(input: SAIsoLockNoAffect) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<SAIsoLockNoAffect> for u32 {
-    fn from(input: SAIsoLockNoAffect) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<SAIsoLockNoAffect> for Option<u32> {
-    fn from(input: SAIsoLockNoAffect) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SAIsoLockNoAffect {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            8 => Ok(SAIsoLockNoAffect::Ctrls),
-            16 => Ok(SAIsoLockNoAffect::Ptr),
-            32 => Ok(SAIsoLockNoAffect::Group),
-            64 => Ok(SAIsoLockNoAffect::Mods),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u16::from(input.0))
     }
 }
-impl TryFrom<u16> for SAIsoLockNoAffect {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<SAIsoLockNoAffect> for u32 {
+    fn from(input: SAIsoLockNoAffect) -> Self {
+        Self::from(input.0)
     }
 }
-impl TryFrom<u32> for SAIsoLockNoAffect {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+impl From<SAIsoLockNoAffect> for Option<u32> {
+    fn from(input: SAIsoLockNoAffect) -> Self {
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(SAIsoLockNoAffect, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SAIsoLock {
     pub type_: SAType,
-    pub flags: u8,
-    pub mask: u8,
-    pub real_mods: u8,
+    pub flags: SAIsoLockFlag,
+    pub mask: xproto::ModMask,
+    pub real_mods: xproto::ModMask,
     pub group: i8,
-    pub affect: u8,
-    pub vmods_high: u8,
-    pub vmods_low: u8,
+    pub affect: SAIsoLockNoAffect,
+    pub vmods_high: VModsHigh,
+    pub vmods_low: VModsLow,
 }
 impl TryParse for SAIsoLock {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = i8::try_parse(remaining)?;
-        let (affect, remaining) = u8::try_parse(remaining)?;
-        let (vmods_high, remaining) = u8::try_parse(remaining)?;
-        let (vmods_low, remaining) = u8::try_parse(remaining)?;
-        let type_ = type_.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (affect, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let type_ = SAType::from(type_);
+        let flags = SAIsoLockFlag::from(flags);
+        let mask = xproto::ModMask::from(u16::from(mask));
+        let real_mods = xproto::ModMask::from(u16::from(real_mods));
+        let affect = SAIsoLockNoAffect::from(affect);
+        let vmods_high = VModsHigh::from(vmods_high);
+        let vmods_low = VModsLow::from(vmods_low);
         let result = SAIsoLock { type_, flags, mask, real_mods, group, affect, vmods_high, vmods_low };
         Ok((result, remaining))
     }
@@ -4843,15 +3333,15 @@ impl TryFrom<&[u8]> for SAIsoLock {
 }
 impl Serialize for SAIsoLock {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let mask_bytes = self.mask.serialize();
-        let real_mods_bytes = self.real_mods.serialize();
-        let group_bytes = self.group.serialize();
-        let affect_bytes = self.affect.serialize();
-        let vmods_high_bytes = self.vmods_high.serialize();
-        let vmods_low_bytes = self.vmods_low.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = u8::from(self.flags).serialize_with_order(order);
+        let mask_bytes = u8::try_from(u16::from(self.mask)).unwrap().serialize_with_order(order);
+        let real_mods_bytes = u8::try_from(u16::from(self.real_mods)).unwrap().serialize_with_order(order);
+        let group_bytes = self.group.serialize_with_order(order);
+        let affect_bytes = u8::from(self.affect).serialize_with_order(order);
+        let vmods_high_bytes = u8::from(self.vmods_high).serialize_with_order(order);
+        let vmods_low_bytes = u8::from(self.vmods_low).serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -4863,16 +3353,16 @@ impl Serialize for SAIsoLock {
             vmods_low_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
-        self.mask.serialize_into(bytes);
-        self.real_mods.serialize_into(bytes);
-        self.group.serialize_into(bytes);
-        self.affect.serialize_into(bytes);
-        self.vmods_high.serialize_into(bytes);
-        self.vmods_low.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        u8::from(self.flags).serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mask)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.real_mods)).unwrap().serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
+        u8::from(self.affect).serialize_into_with_order(bytes, order);
+        u8::from(self.vmods_high).serialize_into_with_order(bytes, order);
+        u8::from(self.vmods_low).serialize_into_with_order(bytes, order);
     }
 }
 
@@ -4881,10 +3371,10 @@ pub struct SATerminate {
     pub type_: SAType,
 }
 impl TryParse for SATerminate {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(7..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
         let result = SATerminate { type_ };
         Ok((result, remaining))
     }
@@ -4897,8 +3387,8 @@ impl TryFrom<&[u8]> for SATerminate {
 }
 impl Serialize for SATerminate {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
         [
             type_bytes[0],
             0,
@@ -4910,75 +3400,46 @@ impl Serialize for SATerminate {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 7]);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SwitchScreenFlag {
-    Application = 1 << 0,
-    Absolute = 1 << 2,
-}
-impl From<SwitchScreenFlag> for u8 {
-    fn from(input: SwitchScreenFlag) -> Self {
-        match input {
-            SwitchScreenFlag::Application => 1 << 0,
-            SwitchScreenFlag::Absolute => 1 << 2,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SwitchScreenFlag(u8);
+#[allow(non_upper_case_globals)]
+impl SwitchScreenFlag {
+    pub const Application: Self = Self(1 << 0);
+    pub const Absolute: Self = Self(1 << 2);
 }
+bitmask_set!(SwitchScreenFlag, u8, [Application, Absolute]);
 impl From<SwitchScreenFlag> for Option<u8> {
     fn from(input: SwitchScreenFlag) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SwitchScreenFlag> for u16 {
     fn from(input: SwitchScreenFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SwitchScreenFlag> for Option<u16> {
     fn from(input: SwitchScreenFlag) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SwitchScreenFlag> for u32 {
     fn from(input: SwitchScreenFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SwitchScreenFlag> for Option<u32> {
     fn from(input: SwitchScreenFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SwitchScreenFlag {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(SwitchScreenFlag::Application),
-            4 => Ok(SwitchScreenFlag::Absolute),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for SwitchScreenFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SwitchScreenFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(SwitchScreenFlag, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SASwitchScreen {
@@ -4987,12 +3448,12 @@ pub struct SASwitchScreen {
     pub new_screen: i8,
 }
 impl TryParse for SASwitchScreen {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (new_screen, remaining) = i8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (new_screen, remaining) = i8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(5..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
         let result = SASwitchScreen { type_, flags, new_screen };
         Ok((result, remaining))
     }
@@ -5005,10 +3466,10 @@ impl TryFrom<&[u8]> for SASwitchScreen {
 }
 impl Serialize for SASwitchScreen {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let new_screen_bytes = self.new_screen.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let new_screen_bytes = self.new_screen.serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -5020,182 +3481,108 @@ impl Serialize for SASwitchScreen {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
-        self.new_screen.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.new_screen.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 5]);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum BoolCtrlsHigh {
-    AccessXFeedback = 1 << 0,
-    AudibleBell = 1 << 1,
-    Overlay1 = 1 << 2,
-    Overlay2 = 1 << 3,
-    IgnoreGroupLock = 1 << 4,
-}
-impl From<BoolCtrlsHigh> for u8 {
-    fn from(input: BoolCtrlsHigh) -> Self {
-        match input {
-            BoolCtrlsHigh::AccessXFeedback => 1 << 0,
-            BoolCtrlsHigh::AudibleBell => 1 << 1,
-            BoolCtrlsHigh::Overlay1 => 1 << 2,
-            BoolCtrlsHigh::Overlay2 => 1 << 3,
-            BoolCtrlsHigh::IgnoreGroupLock => 1 << 4,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BoolCtrlsHigh(u8);
+#[allow(non_upper_case_globals)]
+impl BoolCtrlsHigh {
+    pub const AccessXFeedback: Self = Self(1 << 0);
+    pub const AudibleBell: Self = Self(1 << 1);
+    pub const Overlay1: Self = Self(1 << 2);
+    pub const Overlay2: Self = Self(1 << 3);
+    pub const IgnoreGroupLock: Self = Self(1 << 4);
 }
+bitmask_set!(BoolCtrlsHigh, u8, [AccessXFeedback, AudibleBell, Overlay1, Overlay2, IgnoreGroupLock]);
 impl From<BoolCtrlsHigh> for Option<u8> {
     fn from(input: BoolCtrlsHigh) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<BoolCtrlsHigh> for u16 {
     fn from(input: BoolCtrlsHigh) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<BoolCtrlsHigh> for Option<u16> {
     fn from(input: BoolCtrlsHigh) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<BoolCtrlsHigh> for u32 {
     fn from(input: BoolCtrlsHigh) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<BoolCtrlsHigh> for Option<u32> {
     fn from(input: BoolCtrlsHigh) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for BoolCtrlsHigh {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(BoolCtrlsHigh::AccessXFeedback),
-            2 => Ok(BoolCtrlsHigh::AudibleBell),
-            4 => Ok(BoolCtrlsHigh::Overlay1),
-            8 => Ok(BoolCtrlsHigh::Overlay2),
-            16 => Ok(BoolCtrlsHigh::IgnoreGroupLock),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for BoolCtrlsHigh {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for BoolCtrlsHigh {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(BoolCtrlsHigh, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum BoolCtrlsLow {
-    RepeatKeys = 1 << 0,
-    SlowKeys = 1 << 1,
-    BounceKeys = 1 << 2,
-    StickyKeys = 1 << 3,
-    MouseKeys = 1 << 4,
-    MouseKeysAccel = 1 << 5,
-    AccessXKeys = 1 << 6,
-    AccessXTimeout = 1 << 7,
-}
-impl From<BoolCtrlsLow> for u8 {
-    fn from(input: BoolCtrlsLow) -> Self {
-        match input {
-            BoolCtrlsLow::RepeatKeys => 1 << 0,
-            BoolCtrlsLow::SlowKeys => 1 << 1,
-            BoolCtrlsLow::BounceKeys => 1 << 2,
-            BoolCtrlsLow::StickyKeys => 1 << 3,
-            BoolCtrlsLow::MouseKeys => 1 << 4,
-            BoolCtrlsLow::MouseKeysAccel => 1 << 5,
-            BoolCtrlsLow::AccessXKeys => 1 << 6,
-            BoolCtrlsLow::AccessXTimeout => 1 << 7,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BoolCtrlsLow(u8);
+#[allow(non_upper_case_globals)]
+impl BoolCtrlsLow {
+    pub const RepeatKeys: Self = Self(1 << 0);
+    pub const SlowKeys: Self = Self(1 << 1);
+    pub const BounceKeys: Self = Self(1 << 2);
+    pub const StickyKeys: Self = Self(1 << 3);
+    pub const MouseKeys: Self = Self(1 << 4);
+    pub const MouseKeysAccel: Self = Self(1 << 5);
+    pub const AccessXKeys: Self = Self(1 << 6);
+    pub const AccessXTimeout: Self = Self(1 << 7);
 }
+bitmask_set!(BoolCtrlsLow, u8, [RepeatKeys, SlowKeys, BounceKeys, StickyKeys, MouseKeys, MouseKeysAccel, AccessXKeys, AccessXTimeout]);
 impl From<BoolCtrlsLow> for Option<u8> {
     fn from(input: BoolCtrlsLow) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<BoolCtrlsLow> for u16 {
     fn from(input: BoolCtrlsLow) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<BoolCtrlsLow> for Option<u16> {
     fn from(input: BoolCtrlsLow) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<BoolCtrlsLow> for u32 {
     fn from(input: BoolCtrlsLow) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<BoolCtrlsLow> for Option<u32> {
     fn from(input: BoolCtrlsLow) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for BoolCtrlsLow {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(BoolCtrlsLow::RepeatKeys),
-            2 => Ok(BoolCtrlsLow::SlowKeys),
-            4 => Ok(BoolCtrlsLow::BounceKeys),
-            8 => Ok(BoolCtrlsLow::StickyKeys),
-            16 => Ok(BoolCtrlsLow::MouseKeys),
-            32 => Ok(BoolCtrlsLow::MouseKeysAccel),
-            64 => Ok(BoolCtrlsLow::AccessXKeys),
-            128 => Ok(BoolCtrlsLow::AccessXTimeout),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for BoolCtrlsLow {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for BoolCtrlsLow {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(BoolCtrlsLow, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SASetControls {
     pub type_: SAType,
-    pub bool_ctrls_high: u8,
-    pub bool_ctrls_low: u8,
+    pub bool_ctrls_high: BoolCtrlsHigh,
+    pub bool_ctrls_low: BoolCtrlsLow,
 }
 impl TryParse for SASetControls {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (bool_ctrls_high, remaining) = u8::try_parse(remaining)?;
-        let (bool_ctrls_low, remaining) = u8::try_parse(remaining)?;
+        let (bool_ctrls_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bool_ctrls_low, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
+        let bool_ctrls_high = BoolCtrlsHigh::from(bool_ctrls_high);
+        let bool_ctrls_low = BoolCtrlsLow::from(bool_ctrls_low);
         let result = SASetControls { type_, bool_ctrls_high, bool_ctrls_low };
         Ok((result, remaining))
     }
@@ -5208,10 +3595,10 @@ impl TryFrom<&[u8]> for SASetControls {
 }
 impl Serialize for SASetControls {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let bool_ctrls_high_bytes = self.bool_ctrls_high.serialize();
-        let bool_ctrls_low_bytes = self.bool_ctrls_low.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let bool_ctrls_high_bytes = u8::from(self.bool_ctrls_high).serialize_with_order(order);
+        let bool_ctrls_low_bytes = u8::from(self.bool_ctrls_low).serialize_with_order(order);
         [
             type_bytes[0],
             0,
@@ -5223,97 +3610,67 @@ impl Serialize for SASetControls {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
-        self.bool_ctrls_high.serialize_into(bytes);
-        self.bool_ctrls_low.serialize_into(bytes);
+        u8::from(self.bool_ctrls_high).serialize_into_with_order(bytes, order);
+        u8::from(self.bool_ctrls_low).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
     }
 }
 
 pub type SALockControls = SASetControls;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ActionMessageFlag {
-    OnPress = 1 << 0,
-    OnRelease = 1 << 1,
-    GenKeyEvent = 1 << 2,
-}
-impl From<ActionMessageFlag> for u8 {
-    fn from(input: ActionMessageFlag) -> Self {
-        match input {
-            ActionMessageFlag::OnPress => 1 << 0,
-            ActionMessageFlag::OnRelease => 1 << 1,
-            ActionMessageFlag::GenKeyEvent => 1 << 2,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ActionMessageFlag(u8);
+#[allow(non_upper_case_globals)]
+impl ActionMessageFlag {
+    pub const OnPress: Self = Self(1 << 0);
+    pub const OnRelease: Self = Self(1 << 1);
+    pub const GenKeyEvent: Self = Self(1 << 2);
 }
+bitmask_set!(ActionMessageFlag, u8, [OnPress, OnRelease, GenKeyEvent]);
 impl From<ActionMessageFlag> for Option<u8> {
     fn from(input: ActionMessageFlag) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ActionMessageFlag> for u16 {
     fn from(input: ActionMessageFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ActionMessageFlag> for Option<u16> {
     fn from(input: ActionMessageFlag) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ActionMessageFlag> for u32 {
     fn from(input: ActionMessageFlag) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ActionMessageFlag> for Option<u32> {
     fn from(input: ActionMessageFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ActionMessageFlag {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(ActionMessageFlag::OnPress),
-            2 => Ok(ActionMessageFlag::OnRelease),
-            4 => Ok(ActionMessageFlag::GenKeyEvent),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ActionMessageFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ActionMessageFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(ActionMessageFlag, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SAActionMessage {
     pub type_: SAType,
-    pub flags: u8,
+    pub flags: ActionMessageFlag,
     pub message: [u8; 6],
 }
 impl TryParse for SAActionMessage {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
         let (message, remaining) = crate::x11_utils::parse_u8_list(remaining, 6)?;
         let message = <[u8; 6]>::try_from(message).unwrap();
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
+        let flags = ActionMessageFlag::from(flags);
         let result = SAActionMessage { type_, flags, message };
         Ok((result, remaining))
     }
@@ -5326,9 +3683,9 @@ impl TryFrom<&[u8]> for SAActionMessage {
 }
 impl Serialize for SAActionMessage {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = u8::from(self.flags).serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -5340,10 +3697,10 @@ impl Serialize for SAActionMessage {
             self.message[5],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        u8::from(self.flags).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.message);
     }
 }
@@ -5352,24 +3709,30 @@ impl Serialize for SAActionMessage {
 pub struct SARedirectKey {
     pub type_: SAType,
     pub newkey: xproto::Keycode,
-    pub mask: u8,
-    pub real_modifiers: u8,
-    pub vmods_mask_high: u8,
-    pub vmods_mask_low: u8,
-    pub vmods_high: u8,
-    pub vmods_low: u8,
+    pub mask: xproto::ModMask,
+    pub real_modifiers: xproto::ModMask,
+    pub vmods_mask_high: VModsHigh,
+    pub vmods_mask_low: VModsLow,
+    pub vmods_high: VModsHigh,
+    pub vmods_low: VModsLow,
 }
 impl TryParse for SARedirectKey {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (newkey, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_modifiers, remaining) = u8::try_parse(remaining)?;
-        let (vmods_mask_high, remaining) = u8::try_parse(remaining)?;
-        let (vmods_mask_low, remaining) = u8::try_parse(remaining)?;
-        let (vmods_high, remaining) = u8::try_parse(remaining)?;
-        let (vmods_low, remaining) = u8::try_parse(remaining)?;
-        let type_ = type_.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (newkey, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_modifiers, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_mask_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_mask_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let type_ = SAType::from(type_);
+        let mask = xproto::ModMask::from(u16::from(mask));
+        let real_modifiers = xproto::ModMask::from(u16::from(real_modifiers));
+        let vmods_mask_high = VModsHigh::from(vmods_mask_high);
+        let vmods_mask_low = VModsLow::from(vmods_mask_low);
+        let vmods_high = VModsHigh::from(vmods_high);
+        let vmods_low = VModsLow::from(vmods_low);
         let result = SARedirectKey { type_, newkey, mask, real_modifiers, vmods_mask_high, vmods_mask_low, vmods_high, vmods_low };
         Ok((result, remaining))
     }
@@ -5382,15 +3745,15 @@ impl TryFrom<&[u8]> for SARedirectKey {
 }
 impl Serialize for SARedirectKey {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let newkey_bytes = self.newkey.serialize();
-        let mask_bytes = self.mask.serialize();
-        let real_modifiers_bytes = self.real_modifiers.serialize();
-        let vmods_mask_high_bytes = self.vmods_mask_high.serialize();
-        let vmods_mask_low_bytes = self.vmods_mask_low.serialize();
-        let vmods_high_bytes = self.vmods_high.serialize();
-        let vmods_low_bytes = self.vmods_low.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let newkey_bytes = self.newkey.serialize_with_order(order);
+        let mask_bytes = u8::try_from(u16::from(self.mask)).unwrap().serialize_with_order(order);
+        let real_modifiers_bytes = u8::try_from(u16::from(self.real_modifiers)).unwrap().serialize_with_order(order);
+        let vmods_mask_high_bytes = u8::from(self.vmods_mask_high).serialize_with_order(order);
+        let vmods_mask_low_bytes = u8::from(self.vmods_mask_low).serialize_with_order(order);
+        let vmods_high_bytes = u8::from(self.vmods_high).serialize_with_order(order);
+        let vmods_low_bytes = u8::from(self.vmods_low).serialize_with_order(order);
         [
             type_bytes[0],
             newkey_bytes[0],
@@ -5402,16 +3765,16 @@ impl Serialize for SARedirectKey {
             vmods_low_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.newkey.serialize_into(bytes);
-        self.mask.serialize_into(bytes);
-        self.real_modifiers.serialize_into(bytes);
-        self.vmods_mask_high.serialize_into(bytes);
-        self.vmods_mask_low.serialize_into(bytes);
-        self.vmods_high.serialize_into(bytes);
-        self.vmods_low.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.newkey.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mask)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.real_modifiers)).unwrap().serialize_into_with_order(bytes, order);
+        u8::from(self.vmods_mask_high).serialize_into_with_order(bytes, order);
+        u8::from(self.vmods_mask_low).serialize_into_with_order(bytes, order);
+        u8::from(self.vmods_high).serialize_into_with_order(bytes, order);
+        u8::from(self.vmods_low).serialize_into_with_order(bytes, order);
     }
 }
 
@@ -5424,14 +3787,14 @@ pub struct SADeviceBtn {
     pub device: u8,
 }
 impl TryParse for SADeviceBtn {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (count, remaining) = u8::try_parse(remaining)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
-        let (device, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
         let result = SADeviceBtn { type_, flags, count, button, device };
         Ok((result, remaining))
     }
@@ -5444,12 +3807,12 @@ impl TryFrom<&[u8]> for SADeviceBtn {
 }
 impl Serialize for SADeviceBtn {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let count_bytes = self.count.serialize();
-        let button_bytes = self.button.serialize();
-        let device_bytes = self.device.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let button_bytes = self.button.serialize_with_order(order);
+        let device_bytes = self.device.serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -5461,96 +3824,68 @@ impl Serialize for SADeviceBtn {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
-        self.count.serialize_into(bytes);
-        self.button.serialize_into(bytes);
-        self.device.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.count.serialize_into_with_order(bytes, order);
+        self.button.serialize_into_with_order(bytes, order);
+        self.device.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum LockDeviceFlags {
-    NoLock = 1 << 0,
-    NoUnlock = 1 << 1,
-}
-impl From<LockDeviceFlags> for u8 {
-    fn from(input: LockDeviceFlags) -> Self {
-        match input {
-            LockDeviceFlags::NoLock => 1 << 0,
-            LockDeviceFlags::NoUnlock => 1 << 1,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LockDeviceFlags(u8);
+#[allow(non_upper_case_globals)]
+impl LockDeviceFlags {
+    pub const NoLock: Self = Self(1 << 0);
+    pub const NoUnlock: Self = Self(1 << 1);
 }
+bitmask_set!(LockDeviceFlags, u8, [NoLock, NoUnlock]);
 impl From<LockDeviceFlags> for Option<u8> {
     fn from(input: LockDeviceFlags) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<LockDeviceFlags> for u16 {
     fn from(input: LockDeviceFlags) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<LockDeviceFlags> for Option<u16> {
     fn from(input: LockDeviceFlags) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<LockDeviceFlags> for u32 {
     fn from(input: LockDeviceFlags) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<LockDeviceFlags> for Option<u32> {
     fn from(input: LockDeviceFlags) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for LockDeviceFlags {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(LockDeviceFlags::NoLock),
-            2 => Ok(LockDeviceFlags::NoUnlock),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for LockDeviceFlags {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for LockDeviceFlags {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(LockDeviceFlags, u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SALockDeviceBtn {
     pub type_: SAType,
-    pub flags: u8,
+    pub flags: LockDeviceFlags,
     pub button: u8,
     pub device: u8,
 }
 impl TryParse for SALockDeviceBtn {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
-        let (device, remaining) = u8::try_parse(remaining)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
+        let flags = LockDeviceFlags::from(flags);
         let result = SALockDeviceBtn { type_, flags, button, device };
         Ok((result, remaining))
     }
@@ -5563,11 +3898,11 @@ impl TryFrom<&[u8]> for SALockDeviceBtn {
 }
 impl Serialize for SALockDeviceBtn {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let flags_bytes = self.flags.serialize();
-        let button_bytes = self.button.serialize();
-        let device_bytes = self.device.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = u8::from(self.flags).serialize_with_order(order);
+        let button_bytes = self.button.serialize_with_order(order);
+        let device_bytes = self.device.serialize_with_order(order);
         [
             type_bytes[0],
             flags_bytes[0],
@@ -5579,88 +3914,52 @@ impl Serialize for SALockDeviceBtn {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.flags.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        u8::from(self.flags).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
-        self.button.serialize_into(bytes);
-        self.device.serialize_into(bytes);
+        self.button.serialize_into_with_order(bytes, order);
+        self.device.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SAValWhat {
-    IgnoreVal = 0,
-    SetValMin = 1,
-    SetValCenter = 2,
-    SetValMax = 3,
-    SetValRelative = 4,
-    SetValAbsolute = 5,
-}
-impl From<SAValWhat> for u8 {
-    fn from(input: SAValWhat) -> Self {
-        match input {
-            SAValWhat::IgnoreVal => 0,
-            SAValWhat::SetValMin => 1,
-            SAValWhat::SetValCenter => 2,
-            SAValWhat::SetValMax => 3,
-            SAValWhat::SetValRelative => 4,
-            SAValWhat::SetValAbsolute => 5,
-        }
-    }
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SAValWhat(u8);
+#[allow(non_upper_case_globals)]
+impl SAValWhat {
+    pub const IgnoreVal: Self = Self(0);
+    pub const SetValMin: Self = Self(1);
+    pub const SetValCenter: Self = Self(2);
+    pub const SetValMax: Self = Self(3);
+    pub const SetValRelative: Self = Self(4);
+    pub const SetValAbsolute: Self = Self(5);
 }
+enum_newtype!(SAValWhat, u8, [IgnoreVal, SetValMin, SetValCenter, SetValMax, SetValRelative, SetValAbsolute]);
 impl From<SAValWhat> for Option<u8> {
     fn from(input: SAValWhat) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<SAValWhat> for u16 {
     fn from(input: SAValWhat) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SAValWhat> for Option<u16> {
     fn from(input: SAValWhat) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<SAValWhat> for u32 {
     fn from(input: SAValWhat) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<SAValWhat> for Option<u32> {
     fn from(input: SAValWhat) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for SAValWhat {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(SAValWhat::IgnoreVal),
-            1 => Ok(SAValWhat::SetValMin),
-            2 => Ok(SAValWhat::SetValCenter),
-            3 => Ok(SAValWhat::SetValMax),
-            4 => Ok(SAValWhat::SetValRelative),
-            5 => Ok(SAValWhat::SetValAbsolute),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for SAValWhat {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for SAValWhat {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -5676,18 +3975,18 @@ pub struct SADeviceValuator {
     pub val2value: u8,
 }
 impl TryParse for SADeviceValuator {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (device, remaining) = u8::try_parse(remaining)?;
-        let (val1what, remaining) = u8::try_parse(remaining)?;
-        let (val1index, remaining) = u8::try_parse(remaining)?;
-        let (val1value, remaining) = u8::try_parse(remaining)?;
-        let (val2what, remaining) = u8::try_parse(remaining)?;
-        let (val2index, remaining) = u8::try_parse(remaining)?;
-        let (val2value, remaining) = u8::try_parse(remaining)?;
-        let type_ = type_.try_into()?;
-        let val1what = val1what.try_into()?;
-        let val2what = val2what.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val1what, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val1index, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val1value, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val2what, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val2index, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val2value, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let type_ = SAType::from(type_);
+        let val1what = SAValWhat::from(val1what);
+        let val2what = SAValWhat::from(val2what);
         let result = SADeviceValuator { type_, device, val1what, val1index, val1value, val2what, val2index, val2value };
         Ok((result, remaining))
     }
@@ -5700,15 +3999,15 @@ impl TryFrom<&[u8]> for SADeviceValuator {
 }
 impl Serialize for SADeviceValuator {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
-        let device_bytes = self.device.serialize();
-        let val1what_bytes = u8::from(self.val1what).serialize();
-        let val1index_bytes = self.val1index.serialize();
-        let val1value_bytes = self.val1value.serialize();
-        let val2what_bytes = u8::from(self.val2what).serialize();
-        let val2index_bytes = self.val2index.serialize();
-        let val2value_bytes = self.val2value.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let device_bytes = self.device.serialize_with_order(order);
+        let val1what_bytes = u8::from(self.val1what).serialize_with_order(order);
+        let val1index_bytes = self.val1index.serialize_with_order(order);
+        let val1value_bytes = self.val1value.serialize_with_order(order);
+        let val2what_bytes = u8::from(self.val2what).serialize_with_order(order);
+        let val2index_bytes = self.val2index.serialize_with_order(order);
+        let val2value_bytes = self.val2value.serialize_with_order(order);
         [
             type_bytes[0],
             device_bytes[0],
@@ -5720,16 +4019,16 @@ impl Serialize for SADeviceValuator {
             val2value_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
-        self.device.serialize_into(bytes);
-        u8::from(self.val1what).serialize_into(bytes);
-        self.val1index.serialize_into(bytes);
-        self.val1value.serialize_into(bytes);
-        u8::from(self.val2what).serialize_into(bytes);
-        self.val2index.serialize_into(bytes);
-        self.val2value.serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.device.serialize_into_with_order(bytes, order);
+        u8::from(self.val1what).serialize_into_with_order(bytes, order);
+        self.val1index.serialize_into_with_order(bytes, order);
+        self.val1value.serialize_into_with_order(bytes, order);
+        u8::from(self.val2what).serialize_into_with_order(bytes, order);
+        self.val2index.serialize_into_with_order(bytes, order);
+        self.val2value.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -5739,11 +4038,11 @@ pub struct SIAction {
     pub data: [u8; 7],
 }
 impl TryParse for SIAction {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, 7)?;
         let data = <[u8; 7]>::try_from(data).unwrap();
-        let type_ = type_.try_into()?;
+        let type_ = SAType::from(type_);
         let result = SIAction { type_, data };
         Ok((result, remaining))
     }
@@ -5756,8 +4055,8 @@ impl TryFrom<&[u8]> for SIAction {
 }
 impl Serialize for SIAction {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let type_bytes = u8::from(self.type_).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
         [
             type_bytes[0],
             self.data[0],
@@ -5769,9 +4068,9 @@ impl Serialize for SIAction {
             self.data[6],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        u8::from(self.type_).serialize_into(bytes);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.data);
     }
 }
@@ -5779,20 +4078,22 @@ impl Serialize for SIAction {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SymInterpret {
     pub sym: xproto::Keysym,
-    pub mods: u8,
+    pub mods: xproto::ModMask,
     pub match_: u8,
-    pub virtual_mod: u8,
+    pub virtual_mod: VModsLow,
     pub flags: u8,
     pub action: SIAction,
 }
 impl TryParse for SymInterpret {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (sym, remaining) = xproto::Keysym::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (match_, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mod, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (action, remaining) = SIAction::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (sym, remaining) = xproto::Keysym::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (match_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mod, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (action, remaining) = SIAction::try_parse_with_order(remaining, order)?;
+        let mods = xproto::ModMask::from(u16::from(mods));
+        let virtual_mod = VModsLow::from(virtual_mod);
         let result = SymInterpret { sym, mods, match_, virtual_mod, flags, action };
         Ok((result, remaining))
     }
@@ -5805,13 +4106,13 @@ impl TryFrom<&[u8]> for SymInterpret {
 }
 impl Serialize for SymInterpret {
     type Bytes = [u8; 16];
-    fn serialize(&self) -> [u8; 16] {
-        let sym_bytes = self.sym.serialize();
-        let mods_bytes = self.mods.serialize();
-        let match_bytes = self.match_.serialize();
-        let virtual_mod_bytes = self.virtual_mod.serialize();
-        let flags_bytes = self.flags.serialize();
-        let action_bytes = self.action.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 16] {
+        let sym_bytes = self.sym.serialize_with_order(order);
+        let mods_bytes = u8::try_from(u16::from(self.mods)).unwrap().serialize_with_order(order);
+        let match_bytes = self.match_.serialize_with_order(order);
+        let virtual_mod_bytes = u8::from(self.virtual_mod).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let action_bytes = self.action.serialize_with_order(order);
         [
             sym_bytes[0],
             sym_bytes[1],
@@ -5831,14 +4132,14 @@ impl Serialize for SymInterpret {
             action_bytes[7],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(16);
-        self.sym.serialize_into(bytes);
-        self.mods.serialize_into(bytes);
-        self.match_.serialize_into(bytes);
-        self.virtual_mod.serialize_into(bytes);
-        self.flags.serialize_into(bytes);
-        self.action.serialize_into(bytes);
+        self.sym.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mods)).unwrap().serialize_into_with_order(bytes, order);
+        self.match_.serialize_into_with_order(bytes, order);
+        u8::from(self.virtual_mod).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.action.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -5846,194 +4147,216 @@ impl Serialize for SymInterpret {
 pub struct Action([u8; 8]);
 impl Action {
     pub fn as_noaction(&self) -> SANoAction {
-        fn do_the_parse(remaining: &[u8]) -> Result<SANoAction, ParseError> {
-            let (noaction, remaining) = SANoAction::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SANoAction, ParseError> {
+            let (noaction, remaining) = SANoAction::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(noaction)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_setmods(&self) -> SASetMods {
-        fn do_the_parse(remaining: &[u8]) -> Result<SASetMods, ParseError> {
-            let (setmods, remaining) = SASetMods::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SASetMods, ParseError> {
+            let (setmods, remaining) = SASetMods::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(setmods)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_latchmods(&self) -> SALatchMods {
-        fn do_the_parse(remaining: &[u8]) -> Result<SALatchMods, ParseError> {
-            let (latchmods, remaining) = SALatchMods::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SALatchMods, ParseError> {
+            let (latchmods, remaining) = SALatchMods::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(latchmods)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_lockmods(&self) -> SALockMods {
-        fn do_the_parse(remaining: &[u8]) -> Result<SALockMods, ParseError> {
-            let (lockmods, remaining) = SALockMods::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SALockMods, ParseError> {
+            let (lockmods, remaining) = SALockMods::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(lockmods)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_setgroup(&self) -> SASetGroup {
-        fn do_the_parse(remaining: &[u8]) -> Result<SASetGroup, ParseError> {
-            let (setgroup, remaining) = SASetGroup::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SASetGroup, ParseError> {
+            let (setgroup, remaining) = SASetGroup::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(setgroup)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_latchgroup(&self) -> SALatchGroup {
-        fn do_the_parse(remaining: &[u8]) -> Result<SALatchGroup, ParseError> {
-            let (latchgroup, remaining) = SALatchGroup::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SALatchGroup, ParseError> {
+            let (latchgroup, remaining) = SALatchGroup::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(latchgroup)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_lockgroup(&self) -> SALockGroup {
-        fn do_the_parse(remaining: &[u8]) -> Result<SALockGroup, ParseError> {
-            let (lockgroup, remaining) = SALockGroup::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SALockGroup, ParseError> {
+            let (lockgroup, remaining) = SALockGroup::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(lockgroup)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_moveptr(&self) -> SAMovePtr {
-        fn do_the_parse(remaining: &[u8]) -> Result<SAMovePtr, ParseError> {
-            let (moveptr, remaining) = SAMovePtr::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SAMovePtr, ParseError> {
+            let (moveptr, remaining) = SAMovePtr::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(moveptr)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_ptrbtn(&self) -> SAPtrBtn {
-        fn do_the_parse(remaining: &[u8]) -> Result<SAPtrBtn, ParseError> {
-            let (ptrbtn, remaining) = SAPtrBtn::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SAPtrBtn, ParseError> {
+            let (ptrbtn, remaining) = SAPtrBtn::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(ptrbtn)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_lockptrbtn(&self) -> SALockPtrBtn {
-        fn do_the_parse(remaining: &[u8]) -> Result<SALockPtrBtn, ParseError> {
-            let (lockptrbtn, remaining) = SALockPtrBtn::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SALockPtrBtn, ParseError> {
+            let (lockptrbtn, remaining) = SALockPtrBtn::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(lockptrbtn)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_setptrdflt(&self) -> SASetPtrDflt {
-        fn do_the_parse(remaining: &[u8]) -> Result<SASetPtrDflt, ParseError> {
-            let (setptrdflt, remaining) = SASetPtrDflt::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SASetPtrDflt, ParseError> {
+            let (setptrdflt, remaining) = SASetPtrDflt::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(setptrdflt)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_isolock(&self) -> SAIsoLock {
-        fn do_the_parse(remaining: &[u8]) -> Result<SAIsoLock, ParseError> {
-            let (isolock, remaining) = SAIsoLock::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SAIsoLock, ParseError> {
+            let (isolock, remaining) = SAIsoLock::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(isolock)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_terminate(&self) -> SATerminate {
-        fn do_the_parse(remaining: &[u8]) -> Result<SATerminate, ParseError> {
-            let (terminate, remaining) = SATerminate::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SATerminate, ParseError> {
+            let (terminate, remaining) = SATerminate::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(terminate)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_switchscreen(&self) -> SASwitchScreen {
-        fn do_the_parse(remaining: &[u8]) -> Result<SASwitchScreen, ParseError> {
-            let (switchscreen, remaining) = SASwitchScreen::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SASwitchScreen, ParseError> {
+            let (switchscreen, remaining) = SASwitchScreen::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(switchscreen)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_setcontrols(&self) -> SASetControls {
-        fn do_the_parse(remaining: &[u8]) -> Result<SASetControls, ParseError> {
-            let (setcontrols, remaining) = SASetControls::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SASetControls, ParseError> {
+            let (setcontrols, remaining) = SASetControls::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(setcontrols)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_lockcontrols(&self) -> SALockControls {
-        fn do_the_parse(remaining: &[u8]) -> Result<SALockControls, ParseError> {
-            let (lockcontrols, remaining) = SALockControls::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SALockControls, ParseError> {
+            let (lockcontrols, remaining) = SALockControls::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(lockcontrols)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_message(&self) -> SAActionMessage {
-        fn do_the_parse(remaining: &[u8]) -> Result<SAActionMessage, ParseError> {
-            let (message, remaining) = SAActionMessage::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SAActionMessage, ParseError> {
+            let (message, remaining) = SAActionMessage::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(message)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_redirect(&self) -> SARedirectKey {
-        fn do_the_parse(remaining: &[u8]) -> Result<SARedirectKey, ParseError> {
-            let (redirect, remaining) = SARedirectKey::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SARedirectKey, ParseError> {
+            let (redirect, remaining) = SARedirectKey::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(redirect)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_devbtn(&self) -> SADeviceBtn {
-        fn do_the_parse(remaining: &[u8]) -> Result<SADeviceBtn, ParseError> {
-            let (devbtn, remaining) = SADeviceBtn::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SADeviceBtn, ParseError> {
+            let (devbtn, remaining) = SADeviceBtn::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(devbtn)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_lockdevbtn(&self) -> SALockDeviceBtn {
-        fn do_the_parse(remaining: &[u8]) -> Result<SALockDeviceBtn, ParseError> {
-            let (lockdevbtn, remaining) = SALockDeviceBtn::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SALockDeviceBtn, ParseError> {
+            let (lockdevbtn, remaining) = SALockDeviceBtn::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(lockdevbtn)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_devval(&self) -> SADeviceValuator {
-        fn do_the_parse(remaining: &[u8]) -> Result<SADeviceValuator, ParseError> {
-            let (devval, remaining) = SADeviceValuator::try_parse(remaining)?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SADeviceValuator, ParseError> {
+            let (devval, remaining) = SADeviceValuator::try_parse_with_order(remaining, order)?;
             let _ = remaining;
+            let _ = order;
             Ok(devval)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
     pub fn as_type(&self) -> SAType {
-        fn do_the_parse(remaining: &[u8]) -> Result<SAType, ParseError> {
-            let (type_, remaining) = u8::try_parse(remaining)?;
-            let type_ = type_.try_into()?;
+        fn do_the_parse(remaining: &[u8], order: ByteOrder) -> Result<SAType, ParseError> {
+            let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+            let type_ = SAType::from(type_);
             let _ = remaining;
+            let _ = order;
             Ok(type_)
         }
-        do_the_parse(&self.0).unwrap()
+        do_the_parse(&self.0, ByteOrder::native()).unwrap()
     }
 }
 impl Serialize for Action {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
+    fn serialize_with_order(&self, _order: ByteOrder) -> [u8; 8] {
         self.0
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {
         bytes.extend_from_slice(&self.0);
     }
 }
 impl TryParse for Action {
-    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], _order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let inner: [u8; 8] = value.get(..8)
             .ok_or(ParseError::ParseError)?
             .try_into()
@@ -6044,103 +4367,137 @@ impl TryParse for Action {
 }
 impl From<SANoAction> for Action {
     fn from(noaction: SANoAction) -> Self {
-        let noaction_bytes = noaction.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let noaction_bytes = noaction.serialize_with_order(order);
         Self(noaction_bytes)
     }
 }
 impl From<SASetMods> for Action {
     fn from(setmods: SASetMods) -> Self {
-        let setmods_bytes = setmods.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let setmods_bytes = setmods.serialize_with_order(order);
         Self(setmods_bytes)
     }
 }
 impl From<SASetGroup> for Action {
     fn from(setgroup: SASetGroup) -> Self {
-        let setgroup_bytes = setgroup.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let setgroup_bytes = setgroup.serialize_with_order(order);
         Self(setgroup_bytes)
     }
 }
 impl From<SAMovePtr> for Action {
     fn from(moveptr: SAMovePtr) -> Self {
-        let moveptr_bytes = moveptr.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let moveptr_bytes = moveptr.serialize_with_order(order);
         Self(moveptr_bytes)
     }
 }
 impl From<SAPtrBtn> for Action {
     fn from(ptrbtn: SAPtrBtn) -> Self {
-        let ptrbtn_bytes = ptrbtn.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let ptrbtn_bytes = ptrbtn.serialize_with_order(order);
         Self(ptrbtn_bytes)
     }
 }
 impl From<SALockPtrBtn> for Action {
     fn from(lockptrbtn: SALockPtrBtn) -> Self {
-        let lockptrbtn_bytes = lockptrbtn.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let lockptrbtn_bytes = lockptrbtn.serialize_with_order(order);
         Self(lockptrbtn_bytes)
     }
 }
 impl From<SASetPtrDflt> for Action {
     fn from(setptrdflt: SASetPtrDflt) -> Self {
-        let setptrdflt_bytes = setptrdflt.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let setptrdflt_bytes = setptrdflt.serialize_with_order(order);
         Self(setptrdflt_bytes)
     }
 }
 impl From<SAIsoLock> for Action {
     fn from(isolock: SAIsoLock) -> Self {
-        let isolock_bytes = isolock.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let isolock_bytes = isolock.serialize_with_order(order);
         Self(isolock_bytes)
     }
 }
 impl From<SATerminate> for Action {
     fn from(terminate: SATerminate) -> Self {
-        let terminate_bytes = terminate.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let terminate_bytes = terminate.serialize_with_order(order);
         Self(terminate_bytes)
     }
 }
 impl From<SASwitchScreen> for Action {
     fn from(switchscreen: SASwitchScreen) -> Self {
-        let switchscreen_bytes = switchscreen.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let switchscreen_bytes = switchscreen.serialize_with_order(order);
         Self(switchscreen_bytes)
     }
 }
 impl From<SASetControls> for Action {
     fn from(setcontrols: SASetControls) -> Self {
-        let setcontrols_bytes = setcontrols.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let setcontrols_bytes = setcontrols.serialize_with_order(order);
         Self(setcontrols_bytes)
     }
 }
 impl From<SAActionMessage> for Action {
     fn from(message: SAActionMessage) -> Self {
-        let message_bytes = message.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let message_bytes = message.serialize_with_order(order);
         Self(message_bytes)
     }
 }
 impl From<SARedirectKey> for Action {
     fn from(redirect: SARedirectKey) -> Self {
-        let redirect_bytes = redirect.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let redirect_bytes = redirect.serialize_with_order(order);
         Self(redirect_bytes)
     }
 }
 impl From<SADeviceBtn> for Action {
     fn from(devbtn: SADeviceBtn) -> Self {
-        let devbtn_bytes = devbtn.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let devbtn_bytes = devbtn.serialize_with_order(order);
         Self(devbtn_bytes)
     }
 }
 impl From<SALockDeviceBtn> for Action {
     fn from(lockdevbtn: SALockDeviceBtn) -> Self {
-        let lockdevbtn_bytes = lockdevbtn.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let lockdevbtn_bytes = lockdevbtn.serialize_with_order(order);
         Self(lockdevbtn_bytes)
     }
 }
 impl From<SADeviceValuator> for Action {
     fn from(devval: SADeviceValuator) -> Self {
-        let devval_bytes = devval.serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let devval_bytes = devval.serialize_with_order(order);
         Self(devval_bytes)
     }
 }
 impl From<SAType> for Action {
     fn from(type_: SAType) -> Self {
-        let type_bytes = u8::from(type_).serialize();
+        let order = ByteOrder::native();
+        let _ = order;
+        let type_bytes = u8::from(type_).serialize_with_order(order);
         let value = [
             type_bytes[0],
             0,
@@ -6157,15 +4514,49 @@ impl From<SAType> for Action {
 
 /// Opcode for the UseExtension request
 pub const USE_EXTENSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UseExtensionRequest {
+    pub wanted_major: u16,
+    pub wanted_minor: u16,
+}
+impl UseExtensionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != USE_EXTENSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (wanted_major, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (wanted_minor, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(UseExtensionRequest {
+            wanted_major,
+            wanted_minor,
+        })
+    }
+    /// Set the `wanted_major` field of this structure.
+    pub fn wanted_major(mut self, value: u16) -> Self {
+        self.wanted_major = value;
+        self
+    }
+    /// Set the `wanted_minor` field of this structure.
+    pub fn wanted_minor(mut self, value: u16) -> Self {
+        self.wanted_minor = value;
+        self
+    }
+}
+
 pub fn use_extension<Conn>(conn: &Conn, wanted_major: u16, wanted_minor: u16) -> Result<Cookie<'_, Conn, UseExtensionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let wanted_major_bytes = wanted_major.serialize();
-    let wanted_minor_bytes = wanted_minor.serialize();
+    let wanted_major_bytes = wanted_major.serialize_with_order(order);
+    let wanted_minor_bytes = wanted_minor.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         USE_EXTENSION_REQUEST,
@@ -6179,9 +4570,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for UseExtensionRequest {
+    type Reply = UseExtensionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UseExtensionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, UseExtensionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        use_extension(conn, self.wanted_major, self.wanted_minor)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UseExtensionReply {
@@ -6193,13 +4596,13 @@ pub struct UseExtensionReply {
     pub server_minor: u16,
 }
 impl TryParse for UseExtensionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (supported, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major, remaining) = u16::try_parse(remaining)?;
-        let (server_minor, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_major, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_minor, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = UseExtensionReply { response_type, supported, sequence, length, server_major, server_minor };
         Ok((result, remaining))
@@ -6211,19 +4614,60 @@ impl TryFrom<&[u8]> for UseExtensionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for UseExtensionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.supported.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.server_major.serialize_into_with_order(bytes, order);
+        self.server_minor.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SelectEvents request
 pub const SELECT_EVENTS_REQUEST: u8 = 1;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase1 {
-    pub affect_new_keyboard: u16,
-    pub new_keyboard_details: u16,
+    pub affect_new_keyboard: NKNDetail,
+    pub new_keyboard_details: NKNDetail,
+}
+impl TryParse for SelectEventsAuxBitcase1 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_new_keyboard, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (new_keyboard_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let affect_new_keyboard = NKNDetail::from(affect_new_keyboard);
+        let new_keyboard_details = NKNDetail::from(new_keyboard_details);
+        let result = SelectEventsAuxBitcase1 { affect_new_keyboard, new_keyboard_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase1 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
 }
 impl Serialize for SelectEventsAuxBitcase1 {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let affect_new_keyboard_bytes = self.affect_new_keyboard.serialize();
-        let new_keyboard_details_bytes = self.new_keyboard_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_new_keyboard_bytes = u16::from(self.affect_new_keyboard).serialize_with_order(order);
+        let new_keyboard_details_bytes = u16::from(self.new_keyboard_details).serialize_with_order(order);
         [
             affect_new_keyboard_bytes[0],
             affect_new_keyboard_bytes[1],
@@ -6231,22 +4675,38 @@ impl Serialize for SelectEventsAuxBitcase1 {
             new_keyboard_details_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.affect_new_keyboard.serialize_into(bytes);
-        self.new_keyboard_details.serialize_into(bytes);
+        u16::from(self.affect_new_keyboard).serialize_into_with_order(bytes, order);
+        u16::from(self.new_keyboard_details).serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase2 {
-    pub affect_state: u16,
-    pub state_details: u16,
+    pub affect_state: StatePart,
+    pub state_details: StatePart,
+}
+impl TryParse for SelectEventsAuxBitcase2 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_state, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (state_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let affect_state = StatePart::from(affect_state);
+        let state_details = StatePart::from(state_details);
+        let result = SelectEventsAuxBitcase2 { affect_state, state_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase2 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
 }
 impl Serialize for SelectEventsAuxBitcase2 {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let affect_state_bytes = self.affect_state.serialize();
-        let state_details_bytes = self.state_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_state_bytes = u16::from(self.affect_state).serialize_with_order(order);
+        let state_details_bytes = u16::from(self.state_details).serialize_with_order(order);
         [
             affect_state_bytes[0],
             affect_state_bytes[1],
@@ -6254,22 +4714,38 @@ impl Serialize for SelectEventsAuxBitcase2 {
             state_details_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.affect_state.serialize_into(bytes);
-        self.state_details.serialize_into(bytes);
+        u16::from(self.affect_state).serialize_into_with_order(bytes, order);
+        u16::from(self.state_details).serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase3 {
-    pub affect_ctrls: u32,
-    pub ctrl_details: u32,
+    pub affect_ctrls: Control,
+    pub ctrl_details: Control,
+}
+impl TryParse for SelectEventsAuxBitcase3 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ctrl_details, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let affect_ctrls = Control::from(affect_ctrls);
+        let ctrl_details = Control::from(ctrl_details);
+        let result = SelectEventsAuxBitcase3 { affect_ctrls, ctrl_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase3 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
 }
 impl Serialize for SelectEventsAuxBitcase3 {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let affect_ctrls_bytes = self.affect_ctrls.serialize();
-        let ctrl_details_bytes = self.ctrl_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let affect_ctrls_bytes = u32::from(self.affect_ctrls).serialize_with_order(order);
+        let ctrl_details_bytes = u32::from(self.ctrl_details).serialize_with_order(order);
         [
             affect_ctrls_bytes[0],
             affect_ctrls_bytes[1],
@@ -6281,10 +4757,10 @@ impl Serialize for SelectEventsAuxBitcase3 {
             ctrl_details_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.affect_ctrls.serialize_into(bytes);
-        self.ctrl_details.serialize_into(bytes);
+        u32::from(self.affect_ctrls).serialize_into_with_order(bytes, order);
+        u32::from(self.ctrl_details).serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -6292,11 +4768,25 @@ pub struct SelectEventsAuxBitcase4 {
     pub affect_indicator_state: u32,
     pub indicator_state_details: u32,
 }
+impl TryParse for SelectEventsAuxBitcase4 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_indicator_state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (indicator_state_details, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase4 { affect_indicator_state, indicator_state_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase4 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
 impl Serialize for SelectEventsAuxBitcase4 {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let affect_indicator_state_bytes = self.affect_indicator_state.serialize();
-        let indicator_state_details_bytes = self.indicator_state_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let affect_indicator_state_bytes = self.affect_indicator_state.serialize_with_order(order);
+        let indicator_state_details_bytes = self.indicator_state_details.serialize_with_order(order);
         [
             affect_indicator_state_bytes[0],
             affect_indicator_state_bytes[1],
@@ -6308,10 +4798,10 @@ impl Serialize for SelectEventsAuxBitcase4 {
             indicator_state_details_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.affect_indicator_state.serialize_into(bytes);
-        self.indicator_state_details.serialize_into(bytes);
+        self.affect_indicator_state.serialize_into_with_order(bytes, order);
+        self.indicator_state_details.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -6319,11 +4809,25 @@ pub struct SelectEventsAuxBitcase5 {
     pub affect_indicator_map: u32,
     pub indicator_map_details: u32,
 }
+impl TryParse for SelectEventsAuxBitcase5 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_indicator_map, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (indicator_map_details, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase5 { affect_indicator_map, indicator_map_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase5 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
 impl Serialize for SelectEventsAuxBitcase5 {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let affect_indicator_map_bytes = self.affect_indicator_map.serialize();
-        let indicator_map_details_bytes = self.indicator_map_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let affect_indicator_map_bytes = self.affect_indicator_map.serialize_with_order(order);
+        let indicator_map_details_bytes = self.indicator_map_details.serialize_with_order(order);
         [
             affect_indicator_map_bytes[0],
             affect_indicator_map_bytes[1],
@@ -6335,22 +4839,38 @@ impl Serialize for SelectEventsAuxBitcase5 {
             indicator_map_details_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.affect_indicator_map.serialize_into(bytes);
-        self.indicator_map_details.serialize_into(bytes);
+        self.affect_indicator_map.serialize_into_with_order(bytes, order);
+        self.indicator_map_details.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase6 {
-    pub affect_names: u16,
-    pub names_details: u16,
+    pub affect_names: NameDetail,
+    pub names_details: NameDetail,
+}
+impl TryParse for SelectEventsAuxBitcase6 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_names, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (names_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let affect_names = NameDetail::from(u32::from(affect_names));
+        let names_details = NameDetail::from(u32::from(names_details));
+        let result = SelectEventsAuxBitcase6 { affect_names, names_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase6 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
 }
 impl Serialize for SelectEventsAuxBitcase6 {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let affect_names_bytes = self.affect_names.serialize();
-        let names_details_bytes = self.names_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_names_bytes = u16::try_from(u32::from(self.affect_names)).unwrap().serialize_with_order(order);
+        let names_details_bytes = u16::try_from(u32::from(self.names_details)).unwrap().serialize_with_order(order);
         [
             affect_names_bytes[0],
             affect_names_bytes[1],
@@ -6358,31 +4878,47 @@ impl Serialize for SelectEventsAuxBitcase6 {
             names_details_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.affect_names.serialize_into(bytes);
-        self.names_details.serialize_into(bytes);
+        u16::try_from(u32::from(self.affect_names)).unwrap().serialize_into_with_order(bytes, order);
+        u16::try_from(u32::from(self.names_details)).unwrap().serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase7 {
-    pub affect_compat: u8,
-    pub compat_details: u8,
+    pub affect_compat: CMDetail,
+    pub compat_details: CMDetail,
+}
+impl TryParse for SelectEventsAuxBitcase7 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_compat, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_details, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let affect_compat = CMDetail::from(affect_compat);
+        let compat_details = CMDetail::from(compat_details);
+        let result = SelectEventsAuxBitcase7 { affect_compat, compat_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase7 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
 }
 impl Serialize for SelectEventsAuxBitcase7 {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let affect_compat_bytes = self.affect_compat.serialize();
-        let compat_details_bytes = self.compat_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let affect_compat_bytes = u8::from(self.affect_compat).serialize_with_order(order);
+        let compat_details_bytes = u8::from(self.compat_details).serialize_with_order(order);
         [
             affect_compat_bytes[0],
             compat_details_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.affect_compat.serialize_into(bytes);
-        self.compat_details.serialize_into(bytes);
+        u8::from(self.affect_compat).serialize_into_with_order(bytes, order);
+        u8::from(self.compat_details).serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -6390,20 +4926,34 @@ pub struct SelectEventsAuxBitcase8 {
     pub affect_bell: u8,
     pub bell_details: u8,
 }
+impl TryParse for SelectEventsAuxBitcase8 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_bell, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bell_details, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase8 { affect_bell, bell_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase8 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
 impl Serialize for SelectEventsAuxBitcase8 {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let affect_bell_bytes = self.affect_bell.serialize();
-        let bell_details_bytes = self.bell_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let affect_bell_bytes = self.affect_bell.serialize_with_order(order);
+        let bell_details_bytes = self.bell_details.serialize_with_order(order);
         [
             affect_bell_bytes[0],
             bell_details_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.affect_bell.serialize_into(bytes);
-        self.bell_details.serialize_into(bytes);
+        self.affect_bell.serialize_into_with_order(bytes, order);
+        self.bell_details.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -6411,32 +4961,62 @@ pub struct SelectEventsAuxBitcase9 {
     pub affect_msg_details: u8,
     pub msg_details: u8,
 }
+impl TryParse for SelectEventsAuxBitcase9 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_msg_details, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (msg_details, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase9 { affect_msg_details, msg_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase9 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
 impl Serialize for SelectEventsAuxBitcase9 {
     type Bytes = [u8; 2];
-    fn serialize(&self) -> [u8; 2] {
-        let affect_msg_details_bytes = self.affect_msg_details.serialize();
-        let msg_details_bytes = self.msg_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let affect_msg_details_bytes = self.affect_msg_details.serialize_with_order(order);
+        let msg_details_bytes = self.msg_details.serialize_with_order(order);
         [
             affect_msg_details_bytes[0],
             msg_details_bytes[0],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(2);
-        self.affect_msg_details.serialize_into(bytes);
-        self.msg_details.serialize_into(bytes);
+        self.affect_msg_details.serialize_into_with_order(bytes, order);
+        self.msg_details.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase10 {
-    pub affect_access_x: u16,
-    pub access_x_details: u16,
+    pub affect_access_x: AXNDetail,
+    pub access_x_details: AXNDetail,
+}
+impl TryParse for SelectEventsAuxBitcase10 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_access_x, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let affect_access_x = AXNDetail::from(affect_access_x);
+        let access_x_details = AXNDetail::from(access_x_details);
+        let result = SelectEventsAuxBitcase10 { affect_access_x, access_x_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase10 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
 }
 impl Serialize for SelectEventsAuxBitcase10 {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let affect_access_x_bytes = self.affect_access_x.serialize();
-        let access_x_details_bytes = self.access_x_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_access_x_bytes = u16::from(self.affect_access_x).serialize_with_order(order);
+        let access_x_details_bytes = u16::from(self.access_x_details).serialize_with_order(order);
         [
             affect_access_x_bytes[0],
             affect_access_x_bytes[1],
@@ -6444,22 +5024,38 @@ impl Serialize for SelectEventsAuxBitcase10 {
             access_x_details_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.affect_access_x.serialize_into(bytes);
-        self.access_x_details.serialize_into(bytes);
+        u16::from(self.affect_access_x).serialize_into_with_order(bytes, order);
+        u16::from(self.access_x_details).serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase11 {
-    pub affect_ext_dev: u16,
-    pub extdev_details: u16,
+    pub affect_ext_dev: XIFeature,
+    pub extdev_details: XIFeature,
+}
+impl TryParse for SelectEventsAuxBitcase11 {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_ext_dev, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (extdev_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let affect_ext_dev = XIFeature::from(affect_ext_dev);
+        let extdev_details = XIFeature::from(extdev_details);
+        let result = SelectEventsAuxBitcase11 { affect_ext_dev, extdev_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase11 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
 }
 impl Serialize for SelectEventsAuxBitcase11 {
     type Bytes = [u8; 4];
-    fn serialize(&self) -> [u8; 4] {
-        let affect_ext_dev_bytes = self.affect_ext_dev.serialize();
-        let extdev_details_bytes = self.extdev_details.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_ext_dev_bytes = u16::from(self.affect_ext_dev).serialize_with_order(order);
+        let extdev_details_bytes = u16::from(self.extdev_details).serialize_with_order(order);
         [
             affect_ext_dev_bytes[0],
             affect_ext_dev_bytes[1],
@@ -6467,68 +5063,153 @@ impl Serialize for SelectEventsAuxBitcase11 {
             extdev_details_bytes[1],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(4);
-        self.affect_ext_dev.serialize_into(bytes);
-        self.extdev_details.serialize_into(bytes);
+        u16::from(self.affect_ext_dev).serialize_into_with_order(bytes, order);
+        u16::from(self.extdev_details).serialize_into_with_order(bytes, order);
+    }
+}
+/// Auxiliary and optional information for the `select_events` function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectEventsAux {
+    pub bitcase1: Option<SelectEventsAuxBitcase1>,
+    pub bitcase2: Option<SelectEventsAuxBitcase2>,
+    pub bitcase3: Option<SelectEventsAuxBitcase3>,
+    pub bitcase4: Option<SelectEventsAuxBitcase4>,
+    pub bitcase5: Option<SelectEventsAuxBitcase5>,
+    pub bitcase6: Option<SelectEventsAuxBitcase6>,
+    pub bitcase7: Option<SelectEventsAuxBitcase7>,
+    pub bitcase8: Option<SelectEventsAuxBitcase8>,
+    pub bitcase9: Option<SelectEventsAuxBitcase9>,
+    pub bitcase10: Option<SelectEventsAuxBitcase10>,
+    pub bitcase11: Option<SelectEventsAuxBitcase11>,
+}
+impl SelectEventsAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, affect_which: EventType, clear: EventType, select_all: EventType) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(affect_which) & ((!u32::from(clear)) & (!u32::from(select_all)));
+        let mut outer_remaining = value;
+        let bitcase1 = if switch_expr & u32::from(EventType::NewKeyboardNotify) != 0 {
+            let (bitcase1, new_remaining) = SelectEventsAuxBitcase1::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase1)
+        } else {
+            None
+        };
+        let bitcase2 = if switch_expr & u32::from(EventType::StateNotify) != 0 {
+            let (bitcase2, new_remaining) = SelectEventsAuxBitcase2::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase2)
+        } else {
+            None
+        };
+        let bitcase3 = if switch_expr & u32::from(EventType::ControlsNotify) != 0 {
+            let (bitcase3, new_remaining) = SelectEventsAuxBitcase3::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase3)
+        } else {
+            None
+        };
+        let bitcase4 = if switch_expr & u32::from(EventType::IndicatorStateNotify) != 0 {
+            let (bitcase4, new_remaining) = SelectEventsAuxBitcase4::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase4)
+        } else {
+            None
+        };
+        let bitcase5 = if switch_expr & u32::from(EventType::IndicatorMapNotify) != 0 {
+            let (bitcase5, new_remaining) = SelectEventsAuxBitcase5::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase5)
+        } else {
+            None
+        };
+        let bitcase6 = if switch_expr & u32::from(EventType::NamesNotify) != 0 {
+            let (bitcase6, new_remaining) = SelectEventsAuxBitcase6::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase6)
+        } else {
+            None
+        };
+        let bitcase7 = if switch_expr & u32::from(EventType::CompatMapNotify) != 0 {
+            let (bitcase7, new_remaining) = SelectEventsAuxBitcase7::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase7)
+        } else {
+            None
+        };
+        let bitcase8 = if switch_expr & u32::from(EventType::BellNotify) != 0 {
+            let (bitcase8, new_remaining) = SelectEventsAuxBitcase8::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase8)
+        } else {
+            None
+        };
+        let bitcase9 = if switch_expr & u32::from(EventType::ActionMessage) != 0 {
+            let (bitcase9, new_remaining) = SelectEventsAuxBitcase9::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase9)
+        } else {
+            None
+        };
+        let bitcase10 = if switch_expr & u32::from(EventType::AccessXNotify) != 0 {
+            let (bitcase10, new_remaining) = SelectEventsAuxBitcase10::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase10)
+        } else {
+            None
+        };
+        let bitcase11 = if switch_expr & u32::from(EventType::ExtensionDeviceNotify) != 0 {
+            let (bitcase11, new_remaining) = SelectEventsAuxBitcase11::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase11)
+        } else {
+            None
+        };
+        let result = SelectEventsAux { bitcase1, bitcase2, bitcase3, bitcase4, bitcase5, bitcase6, bitcase7, bitcase8, bitcase9, bitcase10, bitcase11 };
+        Ok((result, outer_remaining))
     }
 }
-/// Auxiliary and optional information for the `select_events` function
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub struct SelectEventsAux {
-    pub bitcase1: Option<SelectEventsAuxBitcase1>,
-    pub bitcase2: Option<SelectEventsAuxBitcase2>,
-    pub bitcase3: Option<SelectEventsAuxBitcase3>,
-    pub bitcase4: Option<SelectEventsAuxBitcase4>,
-    pub bitcase5: Option<SelectEventsAuxBitcase5>,
-    pub bitcase6: Option<SelectEventsAuxBitcase6>,
-    pub bitcase7: Option<SelectEventsAuxBitcase7>,
-    pub bitcase8: Option<SelectEventsAuxBitcase8>,
-    pub bitcase9: Option<SelectEventsAuxBitcase9>,
-    pub bitcase10: Option<SelectEventsAuxBitcase10>,
-    pub bitcase11: Option<SelectEventsAuxBitcase11>,
-}
 #[allow(dead_code, unused_variables)]
 impl SelectEventsAux {
-    fn serialize(&self, affect_which: u16, clear: u16, select_all: u16) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, affect_which: EventType, clear: EventType, select_all: EventType) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, affect_which, clear, select_all);
+        self.serialize_into_with_order(&mut result, order, affect_which, clear, select_all);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, affect_which: u16, clear: u16, select_all: u16) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, affect_which: EventType, clear: EventType, select_all: EventType) {
         assert_eq!(self.switch_expr(), u32::from(affect_which) & ((!u32::from(clear)) & (!u32::from(select_all))), "switch `details` has an inconsistent discriminant");
         if let Some(ref bitcase1) = self.bitcase1 {
-            bitcase1.serialize_into(bytes);
+            bitcase1.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase2) = self.bitcase2 {
-            bitcase2.serialize_into(bytes);
+            bitcase2.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase3) = self.bitcase3 {
-            bitcase3.serialize_into(bytes);
+            bitcase3.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase4) = self.bitcase4 {
-            bitcase4.serialize_into(bytes);
+            bitcase4.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase5) = self.bitcase5 {
-            bitcase5.serialize_into(bytes);
+            bitcase5.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase6) = self.bitcase6 {
-            bitcase6.serialize_into(bytes);
+            bitcase6.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase7) = self.bitcase7 {
-            bitcase7.serialize_into(bytes);
+            bitcase7.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase8) = self.bitcase8 {
-            bitcase8.serialize_into(bytes);
+            bitcase8.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase9) = self.bitcase9 {
-            bitcase9.serialize_into(bytes);
+            bitcase9.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase10) = self.bitcase10 {
-            bitcase10.serialize_into(bytes);
+            bitcase10.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase11) = self.bitcase11 {
-            bitcase11.serialize_into(bytes);
+            bitcase11.serialize_into_with_order(bytes, order);
         }
     }
 }
@@ -6633,29 +5314,108 @@ impl SelectEventsAux {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectEventsRequest {
+    pub device_spec: DeviceSpec,
+    pub affect_which: EventType,
+    pub clear: EventType,
+    pub select_all: EventType,
+    pub affect_map: MapPart,
+    pub map: MapPart,
+    pub details: SelectEventsAux,
+}
+impl SelectEventsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_EVENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (affect_which, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (clear, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (select_all, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (affect_map, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (details, remaining) = SelectEventsAux::try_parse_with_order(remaining, order, EventType::from(affect_which), EventType::from(clear), EventType::from(select_all))?;
+        let _ = remaining;
+        let _ = order;
+        let affect_which = EventType::from(affect_which);
+        let clear = EventType::from(clear);
+        let select_all = EventType::from(select_all);
+        let affect_map = MapPart::from(affect_map);
+        let map = MapPart::from(map);
+        Ok(SelectEventsRequest {
+            device_spec,
+            affect_which,
+            clear,
+            select_all,
+            affect_map,
+            map,
+            details,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `affect_which` field of this structure.
+    pub fn affect_which<I>(mut self, value: I) -> Self where I: Into<EventType> {
+        self.affect_which = value.into();
+        self
+    }
+    /// Set the `clear` field of this structure.
+    pub fn clear<I>(mut self, value: I) -> Self where I: Into<EventType> {
+        self.clear = value.into();
+        self
+    }
+    /// Set the `select_all` field of this structure.
+    pub fn select_all<I>(mut self, value: I) -> Self where I: Into<EventType> {
+        self.select_all = value.into();
+        self
+    }
+    /// Set the `affect_map` field of this structure.
+    pub fn affect_map<I>(mut self, value: I) -> Self where I: Into<MapPart> {
+        self.affect_map = value.into();
+        self
+    }
+    /// Set the `map` field of this structure.
+    pub fn map<I>(mut self, value: I) -> Self where I: Into<MapPart> {
+        self.map = value.into();
+        self
+    }
+    /// Set the `details` field of this structure.
+    pub fn details<I>(mut self, value: I) -> Self where I: Into<SelectEventsAux> {
+        self.details = value.into();
+        self
+    }
+}
+
 pub fn select_events<'c, Conn, A, B, C, D, E>(conn: &'c Conn, device_spec: DeviceSpec, affect_which: A, clear: B, select_all: C, affect_map: D, map: E, details: &SelectEventsAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
-    B: Into<u16>,
-    C: Into<u16>,
-    D: Into<u16>,
-    E: Into<u16>,
+    A: Into<EventType>,
+    B: Into<EventType>,
+    C: Into<EventType>,
+    D: Into<MapPart>,
+    E: Into<MapPart>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let affect_which: u16 = affect_which.into();
-    let clear: u16 = clear.into();
-    let select_all: u16 = select_all.into();
-    let affect_map: u16 = affect_map.into();
-    let map: u16 = map.into();
+    let affect_which: EventType = affect_which.into();
+    let clear: EventType = clear.into();
+    let select_all: EventType = select_all.into();
+    let affect_map: MapPart = affect_map.into();
+    let map: MapPart = map.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let affect_which_bytes = affect_which.serialize();
-    let clear_bytes = clear.serialize();
-    let select_all_bytes = select_all.serialize();
-    let affect_map_bytes = affect_map.serialize();
-    let map_bytes = map.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let affect_which_bytes = u16::from(affect_which).serialize_with_order(order);
+    let clear_bytes = u16::from(clear).serialize_with_order(order);
+    let select_all_bytes = u16::from(select_all).serialize_with_order(order);
+    let affect_map_bytes = u16::from(affect_map).serialize_with_order(order);
+    let map_bytes = u16::from(map).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_EVENTS_REQUEST,
@@ -6675,35 +5435,147 @@ where
         map_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let details_bytes = details.serialize(affect_which, clear, select_all);
+    let details_bytes = details.serialize_with_order(order, affect_which, clear, select_all);
     let length_so_far = length_so_far + details_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&details_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectEventsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectEventsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_events(conn, self.device_spec, self.affect_which, self.clear, self.select_all, self.affect_map, self.map, &self.details)
+    }
+}
 
 /// Opcode for the Bell request
 pub const BELL_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BellRequest {
+    pub device_spec: DeviceSpec,
+    pub bell_class: BellClassSpec,
+    pub bell_id: IDSpec,
+    pub percent: i8,
+    pub force_sound: bool,
+    pub event_only: bool,
+    pub pitch: i16,
+    pub duration: i16,
+    pub name: xproto::Atom,
+    pub window: xproto::Window,
+}
+impl BellRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != BELL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (bell_class, remaining) = BellClassSpec::try_parse_with_order(remaining, order)?;
+        let (bell_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let (percent, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (force_sound, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (event_only, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (pitch, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(BellRequest {
+            device_spec,
+            bell_class,
+            bell_id,
+            percent,
+            force_sound,
+            event_only,
+            pitch,
+            duration,
+            name,
+            window,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `bell_class` field of this structure.
+    pub fn bell_class<I>(mut self, value: I) -> Self where I: Into<BellClassSpec> {
+        self.bell_class = value.into();
+        self
+    }
+    /// Set the `bell_id` field of this structure.
+    pub fn bell_id<I>(mut self, value: I) -> Self where I: Into<IDSpec> {
+        self.bell_id = value.into();
+        self
+    }
+    /// Set the `percent` field of this structure.
+    pub fn percent(mut self, value: i8) -> Self {
+        self.percent = value;
+        self
+    }
+    /// Set the `force_sound` field of this structure.
+    pub fn force_sound(mut self, value: bool) -> Self {
+        self.force_sound = value;
+        self
+    }
+    /// Set the `event_only` field of this structure.
+    pub fn event_only(mut self, value: bool) -> Self {
+        self.event_only = value;
+        self
+    }
+    /// Set the `pitch` field of this structure.
+    pub fn pitch(mut self, value: i16) -> Self {
+        self.pitch = value;
+        self
+    }
+    /// Set the `duration` field of this structure.
+    pub fn duration(mut self, value: i16) -> Self {
+        self.duration = value;
+        self
+    }
+    /// Set the `name` field of this structure.
+    pub fn name<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.name = value.into();
+        self
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn bell<Conn>(conn: &Conn, device_spec: DeviceSpec, bell_class: BellClassSpec, bell_id: IDSpec, percent: i8, force_sound: bool, event_only: bool, pitch: i16, duration: i16, name: xproto::Atom, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let bell_class_bytes = bell_class.serialize();
-    let bell_id_bytes = bell_id.serialize();
-    let percent_bytes = percent.serialize();
-    let force_sound_bytes = force_sound.serialize();
-    let event_only_bytes = event_only.serialize();
-    let pitch_bytes = pitch.serialize();
-    let duration_bytes = duration.serialize();
-    let name_bytes = name.serialize();
-    let window_bytes = window.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let bell_class_bytes = bell_class.serialize_with_order(order);
+    let bell_id_bytes = bell_id.serialize_with_order(order);
+    let percent_bytes = percent.serialize_with_order(order);
+    let force_sound_bytes = force_sound.serialize_with_order(order);
+    let event_only_bytes = event_only.serialize_with_order(order);
+    let pitch_bytes = pitch.serialize_with_order(order);
+    let duration_bytes = duration.serialize_with_order(order);
+    let name_bytes = name.serialize_with_order(order);
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         BELL_REQUEST,
@@ -6737,20 +5609,59 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for BellRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for BellRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        bell(conn, self.device_spec, self.bell_class, self.bell_id, self.percent, self.force_sound, self.event_only, self.pitch, self.duration, self.name, self.window)
+    }
+}
 
 /// Opcode for the GetState request
 pub const GET_STATE_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetStateRequest {
+    pub device_spec: DeviceSpec,
+}
+impl GetStateRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_STATE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetStateRequest {
+            device_spec,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+}
+
 pub fn get_state<Conn>(conn: &Conn, device_spec: DeviceSpec) -> Result<Cookie<'_, Conn, GetStateReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_STATE_REQUEST,
@@ -6764,9 +5675,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetStateRequest {
+    type Reply = GetStateReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetStateRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetStateReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_state(conn, self.device_spec)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetStateReply {
@@ -6774,45 +5697,55 @@ pub struct GetStateReply {
     pub device_id: u8,
     pub sequence: u16,
     pub length: u32,
-    pub mods: u8,
-    pub base_mods: u8,
-    pub latched_mods: u8,
-    pub locked_mods: u8,
+    pub mods: xproto::ModMask,
+    pub base_mods: xproto::ModMask,
+    pub latched_mods: xproto::ModMask,
+    pub locked_mods: xproto::ModMask,
     pub group: Group,
     pub locked_group: Group,
     pub base_group: i16,
     pub latched_group: i16,
-    pub compat_state: u8,
-    pub grab_mods: u8,
-    pub compat_grab_mods: u8,
-    pub lookup_mods: u8,
-    pub compat_lookup_mods: u8,
-    pub ptr_btn_state: u16,
+    pub compat_state: xproto::ModMask,
+    pub grab_mods: xproto::ModMask,
+    pub compat_grab_mods: xproto::ModMask,
+    pub lookup_mods: xproto::ModMask,
+    pub compat_lookup_mods: xproto::ModMask,
+    pub ptr_btn_state: xproto::KeyButMask,
 }
 impl TryParse for GetStateReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (base_mods, remaining) = u8::try_parse(remaining)?;
-        let (latched_mods, remaining) = u8::try_parse(remaining)?;
-        let (locked_mods, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = u8::try_parse(remaining)?;
-        let (locked_group, remaining) = u8::try_parse(remaining)?;
-        let (base_group, remaining) = i16::try_parse(remaining)?;
-        let (latched_group, remaining) = i16::try_parse(remaining)?;
-        let (compat_state, remaining) = u8::try_parse(remaining)?;
-        let (grab_mods, remaining) = u8::try_parse(remaining)?;
-        let (compat_grab_mods, remaining) = u8::try_parse(remaining)?;
-        let (lookup_mods, remaining) = u8::try_parse(remaining)?;
-        let (compat_lookup_mods, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (base_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (latched_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (locked_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (locked_group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (base_group, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (latched_group, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (compat_state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (grab_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_grab_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (lookup_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_lookup_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (ptr_btn_state, remaining) = u16::try_parse(remaining)?;
+        let (ptr_btn_state, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(6..).ok_or(ParseError::ParseError)?;
-        let group = group.try_into()?;
-        let locked_group = locked_group.try_into()?;
+        let mods = xproto::ModMask::from(u16::from(mods));
+        let base_mods = xproto::ModMask::from(u16::from(base_mods));
+        let latched_mods = xproto::ModMask::from(u16::from(latched_mods));
+        let locked_mods = xproto::ModMask::from(u16::from(locked_mods));
+        let group = Group::from(group);
+        let locked_group = Group::from(locked_group);
+        let compat_state = xproto::ModMask::from(u16::from(compat_state));
+        let grab_mods = xproto::ModMask::from(u16::from(grab_mods));
+        let compat_grab_mods = xproto::ModMask::from(u16::from(compat_grab_mods));
+        let lookup_mods = xproto::ModMask::from(u16::from(lookup_mods));
+        let compat_lookup_mods = xproto::ModMask::from(u16::from(compat_lookup_mods));
+        let ptr_btn_state = xproto::KeyButMask::from(ptr_btn_state);
         let result = GetStateReply { response_type, device_id, sequence, length, mods, base_mods, latched_mods, locked_mods, group, locked_group, base_group, latched_group, compat_state, grab_mods, compat_grab_mods, lookup_mods, compat_lookup_mods, ptr_btn_state };
         Ok((result, remaining))
     }
@@ -6823,30 +5756,158 @@ impl TryFrom<&[u8]> for GetStateReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetStateReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.base_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.latched_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.locked_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::from(self.group).serialize_into_with_order(bytes, order);
+        u8::from(self.locked_group).serialize_into_with_order(bytes, order);
+        self.base_group.serialize_into_with_order(bytes, order);
+        self.latched_group.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.compat_state)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.grab_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.compat_grab_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.lookup_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.compat_lookup_mods)).unwrap().serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        u16::from(self.ptr_btn_state).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 6]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the LatchLockState request
 pub const LATCH_LOCK_STATE_REQUEST: u8 = 5;
-pub fn latch_lock_state<Conn, A, B, C>(conn: &Conn, device_spec: DeviceSpec, affect_mod_locks: A, mod_locks: B, lock_group: bool, group_lock: Group, affect_mod_latches: C, latch_group: bool, group_latch: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LatchLockStateRequest {
+    pub device_spec: DeviceSpec,
+    pub affect_mod_locks: xproto::ModMask,
+    pub mod_locks: xproto::ModMask,
+    pub lock_group: bool,
+    pub group_lock: Group,
+    pub affect_mod_latches: xproto::ModMask,
+    pub latch_group: bool,
+    pub group_latch: u16,
+}
+impl LatchLockStateRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LATCH_LOCK_STATE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (affect_mod_locks, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mod_locks, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (lock_group, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (group_lock, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (affect_mod_latches, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (latch_group, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (group_latch, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let affect_mod_locks = xproto::ModMask::from(u16::from(affect_mod_locks));
+        let mod_locks = xproto::ModMask::from(u16::from(mod_locks));
+        let group_lock = Group::from(group_lock);
+        let affect_mod_latches = xproto::ModMask::from(u16::from(affect_mod_latches));
+        Ok(LatchLockStateRequest {
+            device_spec,
+            affect_mod_locks,
+            mod_locks,
+            lock_group,
+            group_lock,
+            affect_mod_latches,
+            latch_group,
+            group_latch,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `affect_mod_locks` field of this structure.
+    pub fn affect_mod_locks<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.affect_mod_locks = value.into();
+        self
+    }
+    /// Set the `mod_locks` field of this structure.
+    pub fn mod_locks<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.mod_locks = value.into();
+        self
+    }
+    /// Set the `lock_group` field of this structure.
+    pub fn lock_group(mut self, value: bool) -> Self {
+        self.lock_group = value;
+        self
+    }
+    /// Set the `group_lock` field of this structure.
+    pub fn group_lock<I>(mut self, value: I) -> Self where I: Into<Group> {
+        self.group_lock = value.into();
+        self
+    }
+    /// Set the `affect_mod_latches` field of this structure.
+    pub fn affect_mod_latches<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.affect_mod_latches = value.into();
+        self
+    }
+    /// Set the `latch_group` field of this structure.
+    pub fn latch_group(mut self, value: bool) -> Self {
+        self.latch_group = value;
+        self
+    }
+    /// Set the `group_latch` field of this structure.
+    pub fn group_latch(mut self, value: u16) -> Self {
+        self.group_latch = value;
+        self
+    }
+}
+
+pub fn latch_lock_state<Conn, A, B, C, D>(conn: &Conn, device_spec: DeviceSpec, affect_mod_locks: A, mod_locks: B, lock_group: bool, group_lock: C, affect_mod_latches: D, latch_group: bool, group_latch: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u8>,
-    B: Into<u8>,
-    C: Into<u8>,
+    A: Into<xproto::ModMask>,
+    B: Into<xproto::ModMask>,
+    C: Into<Group>,
+    D: Into<xproto::ModMask>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let affect_mod_locks: u8 = affect_mod_locks.into();
-    let mod_locks: u8 = mod_locks.into();
-    let affect_mod_latches: u8 = affect_mod_latches.into();
+    let affect_mod_locks: xproto::ModMask = affect_mod_locks.into();
+    let mod_locks: xproto::ModMask = mod_locks.into();
+    let group_lock: Group = group_lock.into();
+    let affect_mod_latches: xproto::ModMask = affect_mod_latches.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let affect_mod_locks_bytes = affect_mod_locks.serialize();
-    let mod_locks_bytes = mod_locks.serialize();
-    let lock_group_bytes = lock_group.serialize();
-    let group_lock_bytes = u8::from(group_lock).serialize();
-    let affect_mod_latches_bytes = affect_mod_latches.serialize();
-    let latch_group_bytes = latch_group.serialize();
-    let group_latch_bytes = group_latch.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let affect_mod_locks_bytes = u8::try_from(u16::from(affect_mod_locks)).unwrap().serialize_with_order(order);
+    let mod_locks_bytes = u8::try_from(u16::from(mod_locks)).unwrap().serialize_with_order(order);
+    let lock_group_bytes = lock_group.serialize_with_order(order);
+    let group_lock_bytes = u8::from(group_lock).serialize_with_order(order);
+    let affect_mod_latches_bytes = u8::try_from(u16::from(affect_mod_latches)).unwrap().serialize_with_order(order);
+    let latch_group_bytes = latch_group.serialize_with_order(order);
+    let group_latch_bytes = group_latch.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         LATCH_LOCK_STATE_REQUEST,
@@ -6868,20 +5929,59 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for LatchLockStateRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for LatchLockStateRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        latch_lock_state(conn, self.device_spec, self.affect_mod_locks, self.mod_locks, self.lock_group, self.group_lock, self.affect_mod_latches, self.latch_group, self.group_latch)
+    }
+}
 
 /// Opcode for the GetControls request
 pub const GET_CONTROLS_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetControlsRequest {
+    pub device_spec: DeviceSpec,
+}
+impl GetControlsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONTROLS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetControlsRequest {
+            device_spec,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+}
+
 pub fn get_controls<Conn>(conn: &Conn, device_spec: DeviceSpec) -> Result<Cookie<'_, Conn, GetControlsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_CONTROLS_REQUEST,
@@ -6895,9 +5995,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetControlsRequest {
+    type Reply = GetControlsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetControlsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetControlsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_controls(conn, self.device_spec)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetControlsReply {
@@ -6908,12 +6020,12 @@ pub struct GetControlsReply {
     pub mouse_keys_dflt_btn: u8,
     pub num_groups: u8,
     pub groups_wrap: u8,
-    pub internal_mods_mask: u8,
-    pub ignore_lock_mods_mask: u8,
-    pub internal_mods_real_mods: u8,
-    pub ignore_lock_mods_real_mods: u8,
-    pub internal_mods_vmods: u16,
-    pub ignore_lock_mods_vmods: u16,
+    pub internal_mods_mask: xproto::ModMask,
+    pub ignore_lock_mods_mask: xproto::ModMask,
+    pub internal_mods_real_mods: xproto::ModMask,
+    pub ignore_lock_mods_real_mods: xproto::ModMask,
+    pub internal_mods_vmods: VMod,
+    pub ignore_lock_mods_vmods: VMod,
     pub repeat_delay: u16,
     pub repeat_interval: u16,
     pub slow_keys_delay: u16,
@@ -6923,50 +6035,62 @@ pub struct GetControlsReply {
     pub mouse_keys_time_to_max: u16,
     pub mouse_keys_max_speed: u16,
     pub mouse_keys_curve: i16,
-    pub access_x_option: u16,
+    pub access_x_option: AXOption,
     pub access_x_timeout: u16,
-    pub access_x_timeout_options_mask: u16,
-    pub access_x_timeout_options_values: u16,
-    pub access_x_timeout_mask: u32,
-    pub access_x_timeout_values: u32,
-    pub enabled_controls: u32,
+    pub access_x_timeout_options_mask: AXOption,
+    pub access_x_timeout_options_values: AXOption,
+    pub access_x_timeout_mask: BoolCtrl,
+    pub access_x_timeout_values: BoolCtrl,
+    pub enabled_controls: BoolCtrl,
     pub per_key_repeat: [u8; 32],
 }
 impl TryParse for GetControlsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (mouse_keys_dflt_btn, remaining) = u8::try_parse(remaining)?;
-        let (num_groups, remaining) = u8::try_parse(remaining)?;
-        let (groups_wrap, remaining) = u8::try_parse(remaining)?;
-        let (internal_mods_mask, remaining) = u8::try_parse(remaining)?;
-        let (ignore_lock_mods_mask, remaining) = u8::try_parse(remaining)?;
-        let (internal_mods_real_mods, remaining) = u8::try_parse(remaining)?;
-        let (ignore_lock_mods_real_mods, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_dflt_btn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (groups_wrap, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (internal_mods_mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_mods_mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (internal_mods_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_mods_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (internal_mods_vmods, remaining) = u16::try_parse(remaining)?;
-        let (ignore_lock_mods_vmods, remaining) = u16::try_parse(remaining)?;
-        let (repeat_delay, remaining) = u16::try_parse(remaining)?;
-        let (repeat_interval, remaining) = u16::try_parse(remaining)?;
-        let (slow_keys_delay, remaining) = u16::try_parse(remaining)?;
-        let (debounce_delay, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_delay, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_interval, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_time_to_max, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_max_speed, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_curve, remaining) = i16::try_parse(remaining)?;
-        let (access_x_option, remaining) = u16::try_parse(remaining)?;
-        let (access_x_timeout, remaining) = u16::try_parse(remaining)?;
-        let (access_x_timeout_options_mask, remaining) = u16::try_parse(remaining)?;
-        let (access_x_timeout_options_values, remaining) = u16::try_parse(remaining)?;
+        let (internal_mods_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_mods_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (repeat_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (repeat_interval, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (slow_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (debounce_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_interval, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_time_to_max, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_max_speed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_curve, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (access_x_option, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_options_mask, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_options_values, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (access_x_timeout_mask, remaining) = u32::try_parse(remaining)?;
-        let (access_x_timeout_values, remaining) = u32::try_parse(remaining)?;
-        let (enabled_controls, remaining) = u32::try_parse(remaining)?;
+        let (access_x_timeout_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (enabled_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
         let (per_key_repeat, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let per_key_repeat = <[u8; 32]>::try_from(per_key_repeat).unwrap();
+        let internal_mods_mask = xproto::ModMask::from(u16::from(internal_mods_mask));
+        let ignore_lock_mods_mask = xproto::ModMask::from(u16::from(ignore_lock_mods_mask));
+        let internal_mods_real_mods = xproto::ModMask::from(u16::from(internal_mods_real_mods));
+        let ignore_lock_mods_real_mods = xproto::ModMask::from(u16::from(ignore_lock_mods_real_mods));
+        let internal_mods_vmods = VMod::from(internal_mods_vmods);
+        let ignore_lock_mods_vmods = VMod::from(ignore_lock_mods_vmods);
+        let access_x_option = AXOption::from(access_x_option);
+        let access_x_timeout_options_mask = AXOption::from(access_x_timeout_options_mask);
+        let access_x_timeout_options_values = AXOption::from(access_x_timeout_options_values);
+        let access_x_timeout_mask = BoolCtrl::from(access_x_timeout_mask);
+        let access_x_timeout_values = BoolCtrl::from(access_x_timeout_values);
+        let enabled_controls = BoolCtrl::from(enabled_controls);
         let result = GetControlsReply { response_type, device_id, sequence, length, mouse_keys_dflt_btn, num_groups, groups_wrap, internal_mods_mask, ignore_lock_mods_mask, internal_mods_real_mods, ignore_lock_mods_real_mods, internal_mods_vmods, ignore_lock_mods_vmods, repeat_delay, repeat_interval, slow_keys_delay, debounce_delay, mouse_keys_delay, mouse_keys_interval, mouse_keys_time_to_max, mouse_keys_max_speed, mouse_keys_curve, access_x_option, access_x_timeout, access_x_timeout_options_mask, access_x_timeout_options_values, access_x_timeout_mask, access_x_timeout_values, enabled_controls, per_key_repeat };
         Ok((result, remaining))
     }
@@ -6977,77 +6101,403 @@ impl TryFrom<&[u8]> for GetControlsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetControlsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.mouse_keys_dflt_btn.serialize_into_with_order(bytes, order);
+        self.num_groups.serialize_into_with_order(bytes, order);
+        self.groups_wrap.serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.internal_mods_mask)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.ignore_lock_mods_mask)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.internal_mods_real_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.ignore_lock_mods_real_mods)).unwrap().serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        u16::from(self.internal_mods_vmods).serialize_into_with_order(bytes, order);
+        u16::from(self.ignore_lock_mods_vmods).serialize_into_with_order(bytes, order);
+        self.repeat_delay.serialize_into_with_order(bytes, order);
+        self.repeat_interval.serialize_into_with_order(bytes, order);
+        self.slow_keys_delay.serialize_into_with_order(bytes, order);
+        self.debounce_delay.serialize_into_with_order(bytes, order);
+        self.mouse_keys_delay.serialize_into_with_order(bytes, order);
+        self.mouse_keys_interval.serialize_into_with_order(bytes, order);
+        self.mouse_keys_time_to_max.serialize_into_with_order(bytes, order);
+        self.mouse_keys_max_speed.serialize_into_with_order(bytes, order);
+        self.mouse_keys_curve.serialize_into_with_order(bytes, order);
+        u16::from(self.access_x_option).serialize_into_with_order(bytes, order);
+        self.access_x_timeout.serialize_into_with_order(bytes, order);
+        u16::from(self.access_x_timeout_options_mask).serialize_into_with_order(bytes, order);
+        u16::from(self.access_x_timeout_options_values).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        u32::from(self.access_x_timeout_mask).serialize_into_with_order(bytes, order);
+        u32::from(self.access_x_timeout_values).serialize_into_with_order(bytes, order);
+        u32::from(self.enabled_controls).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.per_key_repeat);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetControls request
 pub const SET_CONTROLS_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetControlsRequest {
+    pub device_spec: DeviceSpec,
+    pub affect_internal_real_mods: xproto::ModMask,
+    pub internal_real_mods: xproto::ModMask,
+    pub affect_ignore_lock_real_mods: xproto::ModMask,
+    pub ignore_lock_real_mods: xproto::ModMask,
+    pub affect_internal_virtual_mods: VMod,
+    pub internal_virtual_mods: VMod,
+    pub affect_ignore_lock_virtual_mods: VMod,
+    pub ignore_lock_virtual_mods: VMod,
+    pub mouse_keys_dflt_btn: u8,
+    pub groups_wrap: u8,
+    pub access_x_options: AXOption,
+    pub affect_enabled_controls: BoolCtrl,
+    pub enabled_controls: BoolCtrl,
+    pub change_controls: Control,
+    pub repeat_delay: u16,
+    pub repeat_interval: u16,
+    pub slow_keys_delay: u16,
+    pub debounce_delay: u16,
+    pub mouse_keys_delay: u16,
+    pub mouse_keys_interval: u16,
+    pub mouse_keys_time_to_max: u16,
+    pub mouse_keys_max_speed: u16,
+    pub mouse_keys_curve: i16,
+    pub access_x_timeout: u16,
+    pub access_x_timeout_mask: BoolCtrl,
+    pub access_x_timeout_values: BoolCtrl,
+    pub access_x_timeout_options_mask: AXOption,
+    pub access_x_timeout_options_values: AXOption,
+    pub per_key_repeat: [u8; 32],
+}
+impl SetControlsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CONTROLS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (affect_internal_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (internal_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (affect_ignore_lock_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (affect_internal_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (internal_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (affect_ignore_lock_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_dflt_btn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (groups_wrap, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (access_x_options, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (affect_enabled_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (enabled_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (change_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (repeat_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (repeat_interval, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (slow_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (debounce_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_interval, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_time_to_max, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_max_speed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_curve, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_options_mask, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_options_values, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (per_key_repeat, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
+        let per_key_repeat = <[u8; 32]>::try_from(per_key_repeat).unwrap();
+        let _ = remaining;
+        let _ = order;
+        let affect_internal_real_mods = xproto::ModMask::from(u16::from(affect_internal_real_mods));
+        let internal_real_mods = xproto::ModMask::from(u16::from(internal_real_mods));
+        let affect_ignore_lock_real_mods = xproto::ModMask::from(u16::from(affect_ignore_lock_real_mods));
+        let ignore_lock_real_mods = xproto::ModMask::from(u16::from(ignore_lock_real_mods));
+        let affect_internal_virtual_mods = VMod::from(affect_internal_virtual_mods);
+        let internal_virtual_mods = VMod::from(internal_virtual_mods);
+        let affect_ignore_lock_virtual_mods = VMod::from(affect_ignore_lock_virtual_mods);
+        let ignore_lock_virtual_mods = VMod::from(ignore_lock_virtual_mods);
+        let access_x_options = AXOption::from(access_x_options);
+        let affect_enabled_controls = BoolCtrl::from(affect_enabled_controls);
+        let enabled_controls = BoolCtrl::from(enabled_controls);
+        let change_controls = Control::from(change_controls);
+        let access_x_timeout_mask = BoolCtrl::from(access_x_timeout_mask);
+        let access_x_timeout_values = BoolCtrl::from(access_x_timeout_values);
+        let access_x_timeout_options_mask = AXOption::from(access_x_timeout_options_mask);
+        let access_x_timeout_options_values = AXOption::from(access_x_timeout_options_values);
+        Ok(SetControlsRequest {
+            device_spec,
+            affect_internal_real_mods,
+            internal_real_mods,
+            affect_ignore_lock_real_mods,
+            ignore_lock_real_mods,
+            affect_internal_virtual_mods,
+            internal_virtual_mods,
+            affect_ignore_lock_virtual_mods,
+            ignore_lock_virtual_mods,
+            mouse_keys_dflt_btn,
+            groups_wrap,
+            access_x_options,
+            affect_enabled_controls,
+            enabled_controls,
+            change_controls,
+            repeat_delay,
+            repeat_interval,
+            slow_keys_delay,
+            debounce_delay,
+            mouse_keys_delay,
+            mouse_keys_interval,
+            mouse_keys_time_to_max,
+            mouse_keys_max_speed,
+            mouse_keys_curve,
+            access_x_timeout,
+            access_x_timeout_mask,
+            access_x_timeout_values,
+            access_x_timeout_options_mask,
+            access_x_timeout_options_values,
+            per_key_repeat,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `affect_internal_real_mods` field of this structure.
+    pub fn affect_internal_real_mods<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.affect_internal_real_mods = value.into();
+        self
+    }
+    /// Set the `internal_real_mods` field of this structure.
+    pub fn internal_real_mods<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.internal_real_mods = value.into();
+        self
+    }
+    /// Set the `affect_ignore_lock_real_mods` field of this structure.
+    pub fn affect_ignore_lock_real_mods<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.affect_ignore_lock_real_mods = value.into();
+        self
+    }
+    /// Set the `ignore_lock_real_mods` field of this structure.
+    pub fn ignore_lock_real_mods<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.ignore_lock_real_mods = value.into();
+        self
+    }
+    /// Set the `affect_internal_virtual_mods` field of this structure.
+    pub fn affect_internal_virtual_mods<I>(mut self, value: I) -> Self where I: Into<VMod> {
+        self.affect_internal_virtual_mods = value.into();
+        self
+    }
+    /// Set the `internal_virtual_mods` field of this structure.
+    pub fn internal_virtual_mods<I>(mut self, value: I) -> Self where I: Into<VMod> {
+        self.internal_virtual_mods = value.into();
+        self
+    }
+    /// Set the `affect_ignore_lock_virtual_mods` field of this structure.
+    pub fn affect_ignore_lock_virtual_mods<I>(mut self, value: I) -> Self where I: Into<VMod> {
+        self.affect_ignore_lock_virtual_mods = value.into();
+        self
+    }
+    /// Set the `ignore_lock_virtual_mods` field of this structure.
+    pub fn ignore_lock_virtual_mods<I>(mut self, value: I) -> Self where I: Into<VMod> {
+        self.ignore_lock_virtual_mods = value.into();
+        self
+    }
+    /// Set the `mouse_keys_dflt_btn` field of this structure.
+    pub fn mouse_keys_dflt_btn(mut self, value: u8) -> Self {
+        self.mouse_keys_dflt_btn = value;
+        self
+    }
+    /// Set the `groups_wrap` field of this structure.
+    pub fn groups_wrap(mut self, value: u8) -> Self {
+        self.groups_wrap = value;
+        self
+    }
+    /// Set the `access_x_options` field of this structure.
+    pub fn access_x_options<I>(mut self, value: I) -> Self where I: Into<AXOption> {
+        self.access_x_options = value.into();
+        self
+    }
+    /// Set the `affect_enabled_controls` field of this structure.
+    pub fn affect_enabled_controls<I>(mut self, value: I) -> Self where I: Into<BoolCtrl> {
+        self.affect_enabled_controls = value.into();
+        self
+    }
+    /// Set the `enabled_controls` field of this structure.
+    pub fn enabled_controls<I>(mut self, value: I) -> Self where I: Into<BoolCtrl> {
+        self.enabled_controls = value.into();
+        self
+    }
+    /// Set the `change_controls` field of this structure.
+    pub fn change_controls<I>(mut self, value: I) -> Self where I: Into<Control> {
+        self.change_controls = value.into();
+        self
+    }
+    /// Set the `repeat_delay` field of this structure.
+    pub fn repeat_delay(mut self, value: u16) -> Self {
+        self.repeat_delay = value;
+        self
+    }
+    /// Set the `repeat_interval` field of this structure.
+    pub fn repeat_interval(mut self, value: u16) -> Self {
+        self.repeat_interval = value;
+        self
+    }
+    /// Set the `slow_keys_delay` field of this structure.
+    pub fn slow_keys_delay(mut self, value: u16) -> Self {
+        self.slow_keys_delay = value;
+        self
+    }
+    /// Set the `debounce_delay` field of this structure.
+    pub fn debounce_delay(mut self, value: u16) -> Self {
+        self.debounce_delay = value;
+        self
+    }
+    /// Set the `mouse_keys_delay` field of this structure.
+    pub fn mouse_keys_delay(mut self, value: u16) -> Self {
+        self.mouse_keys_delay = value;
+        self
+    }
+    /// Set the `mouse_keys_interval` field of this structure.
+    pub fn mouse_keys_interval(mut self, value: u16) -> Self {
+        self.mouse_keys_interval = value;
+        self
+    }
+    /// Set the `mouse_keys_time_to_max` field of this structure.
+    pub fn mouse_keys_time_to_max(mut self, value: u16) -> Self {
+        self.mouse_keys_time_to_max = value;
+        self
+    }
+    /// Set the `mouse_keys_max_speed` field of this structure.
+    pub fn mouse_keys_max_speed(mut self, value: u16) -> Self {
+        self.mouse_keys_max_speed = value;
+        self
+    }
+    /// Set the `mouse_keys_curve` field of this structure.
+    pub fn mouse_keys_curve(mut self, value: i16) -> Self {
+        self.mouse_keys_curve = value;
+        self
+    }
+    /// Set the `access_x_timeout` field of this structure.
+    pub fn access_x_timeout(mut self, value: u16) -> Self {
+        self.access_x_timeout = value;
+        self
+    }
+    /// Set the `access_x_timeout_mask` field of this structure.
+    pub fn access_x_timeout_mask<I>(mut self, value: I) -> Self where I: Into<BoolCtrl> {
+        self.access_x_timeout_mask = value.into();
+        self
+    }
+    /// Set the `access_x_timeout_values` field of this structure.
+    pub fn access_x_timeout_values<I>(mut self, value: I) -> Self where I: Into<BoolCtrl> {
+        self.access_x_timeout_values = value.into();
+        self
+    }
+    /// Set the `access_x_timeout_options_mask` field of this structure.
+    pub fn access_x_timeout_options_mask<I>(mut self, value: I) -> Self where I: Into<AXOption> {
+        self.access_x_timeout_options_mask = value.into();
+        self
+    }
+    /// Set the `access_x_timeout_options_values` field of this structure.
+    pub fn access_x_timeout_options_values<I>(mut self, value: I) -> Self where I: Into<AXOption> {
+        self.access_x_timeout_options_values = value.into();
+        self
+    }
+    /// Set the `per_key_repeat` field of this structure.
+    pub fn per_key_repeat<I>(mut self, value: I) -> Self where I: Into<[u8; 32]> {
+        self.per_key_repeat = value.into();
+        self
+    }
+}
+
 pub fn set_controls<'c, Conn, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P>(conn: &'c Conn, device_spec: DeviceSpec, affect_internal_real_mods: A, internal_real_mods: B, affect_ignore_lock_real_mods: C, ignore_lock_real_mods: D, affect_internal_virtual_mods: E, internal_virtual_mods: F, affect_ignore_lock_virtual_mods: G, ignore_lock_virtual_mods: H, mouse_keys_dflt_btn: u8, groups_wrap: u8, access_x_options: I, affect_enabled_controls: J, enabled_controls: K, change_controls: L, repeat_delay: u16, repeat_interval: u16, slow_keys_delay: u16, debounce_delay: u16, mouse_keys_delay: u16, mouse_keys_interval: u16, mouse_keys_time_to_max: u16, mouse_keys_max_speed: u16, mouse_keys_curve: i16, access_x_timeout: u16, access_x_timeout_mask: M, access_x_timeout_values: N, access_x_timeout_options_mask: O, access_x_timeout_options_values: P, per_key_repeat: &[u8; 32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u8>,
-    B: Into<u8>,
-    C: Into<u8>,
-    D: Into<u8>,
-    E: Into<u16>,
-    F: Into<u16>,
-    G: Into<u16>,
-    H: Into<u16>,
-    I: Into<u16>,
-    J: Into<u32>,
-    K: Into<u32>,
-    L: Into<u32>,
-    M: Into<u32>,
-    N: Into<u32>,
-    O: Into<u16>,
-    P: Into<u16>,
+    A: Into<xproto::ModMask>,
+    B: Into<xproto::ModMask>,
+    C: Into<xproto::ModMask>,
+    D: Into<xproto::ModMask>,
+    E: Into<VMod>,
+    F: Into<VMod>,
+    G: Into<VMod>,
+    H: Into<VMod>,
+    I: Into<AXOption>,
+    J: Into<BoolCtrl>,
+    K: Into<BoolCtrl>,
+    L: Into<Control>,
+    M: Into<BoolCtrl>,
+    N: Into<BoolCtrl>,
+    O: Into<AXOption>,
+    P: Into<AXOption>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let affect_internal_real_mods: u8 = affect_internal_real_mods.into();
-    let internal_real_mods: u8 = internal_real_mods.into();
-    let affect_ignore_lock_real_mods: u8 = affect_ignore_lock_real_mods.into();
-    let ignore_lock_real_mods: u8 = ignore_lock_real_mods.into();
-    let affect_internal_virtual_mods: u16 = affect_internal_virtual_mods.into();
-    let internal_virtual_mods: u16 = internal_virtual_mods.into();
-    let affect_ignore_lock_virtual_mods: u16 = affect_ignore_lock_virtual_mods.into();
-    let ignore_lock_virtual_mods: u16 = ignore_lock_virtual_mods.into();
-    let access_x_options: u16 = access_x_options.into();
-    let affect_enabled_controls: u32 = affect_enabled_controls.into();
-    let enabled_controls: u32 = enabled_controls.into();
-    let change_controls: u32 = change_controls.into();
-    let access_x_timeout_mask: u32 = access_x_timeout_mask.into();
-    let access_x_timeout_values: u32 = access_x_timeout_values.into();
-    let access_x_timeout_options_mask: u16 = access_x_timeout_options_mask.into();
-    let access_x_timeout_options_values: u16 = access_x_timeout_options_values.into();
+    let affect_internal_real_mods: xproto::ModMask = affect_internal_real_mods.into();
+    let internal_real_mods: xproto::ModMask = internal_real_mods.into();
+    let affect_ignore_lock_real_mods: xproto::ModMask = affect_ignore_lock_real_mods.into();
+    let ignore_lock_real_mods: xproto::ModMask = ignore_lock_real_mods.into();
+    let affect_internal_virtual_mods: VMod = affect_internal_virtual_mods.into();
+    let internal_virtual_mods: VMod = internal_virtual_mods.into();
+    let affect_ignore_lock_virtual_mods: VMod = affect_ignore_lock_virtual_mods.into();
+    let ignore_lock_virtual_mods: VMod = ignore_lock_virtual_mods.into();
+    let access_x_options: AXOption = access_x_options.into();
+    let affect_enabled_controls: BoolCtrl = affect_enabled_controls.into();
+    let enabled_controls: BoolCtrl = enabled_controls.into();
+    let change_controls: Control = change_controls.into();
+    let access_x_timeout_mask: BoolCtrl = access_x_timeout_mask.into();
+    let access_x_timeout_values: BoolCtrl = access_x_timeout_values.into();
+    let access_x_timeout_options_mask: AXOption = access_x_timeout_options_mask.into();
+    let access_x_timeout_options_values: AXOption = access_x_timeout_options_values.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let affect_internal_real_mods_bytes = affect_internal_real_mods.serialize();
-    let internal_real_mods_bytes = internal_real_mods.serialize();
-    let affect_ignore_lock_real_mods_bytes = affect_ignore_lock_real_mods.serialize();
-    let ignore_lock_real_mods_bytes = ignore_lock_real_mods.serialize();
-    let affect_internal_virtual_mods_bytes = affect_internal_virtual_mods.serialize();
-    let internal_virtual_mods_bytes = internal_virtual_mods.serialize();
-    let affect_ignore_lock_virtual_mods_bytes = affect_ignore_lock_virtual_mods.serialize();
-    let ignore_lock_virtual_mods_bytes = ignore_lock_virtual_mods.serialize();
-    let mouse_keys_dflt_btn_bytes = mouse_keys_dflt_btn.serialize();
-    let groups_wrap_bytes = groups_wrap.serialize();
-    let access_x_options_bytes = access_x_options.serialize();
-    let affect_enabled_controls_bytes = affect_enabled_controls.serialize();
-    let enabled_controls_bytes = enabled_controls.serialize();
-    let change_controls_bytes = change_controls.serialize();
-    let repeat_delay_bytes = repeat_delay.serialize();
-    let repeat_interval_bytes = repeat_interval.serialize();
-    let slow_keys_delay_bytes = slow_keys_delay.serialize();
-    let debounce_delay_bytes = debounce_delay.serialize();
-    let mouse_keys_delay_bytes = mouse_keys_delay.serialize();
-    let mouse_keys_interval_bytes = mouse_keys_interval.serialize();
-    let mouse_keys_time_to_max_bytes = mouse_keys_time_to_max.serialize();
-    let mouse_keys_max_speed_bytes = mouse_keys_max_speed.serialize();
-    let mouse_keys_curve_bytes = mouse_keys_curve.serialize();
-    let access_x_timeout_bytes = access_x_timeout.serialize();
-    let access_x_timeout_mask_bytes = access_x_timeout_mask.serialize();
-    let access_x_timeout_values_bytes = access_x_timeout_values.serialize();
-    let access_x_timeout_options_mask_bytes = access_x_timeout_options_mask.serialize();
-    let access_x_timeout_options_values_bytes = access_x_timeout_options_values.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let affect_internal_real_mods_bytes = u8::try_from(u16::from(affect_internal_real_mods)).unwrap().serialize_with_order(order);
+    let internal_real_mods_bytes = u8::try_from(u16::from(internal_real_mods)).unwrap().serialize_with_order(order);
+    let affect_ignore_lock_real_mods_bytes = u8::try_from(u16::from(affect_ignore_lock_real_mods)).unwrap().serialize_with_order(order);
+    let ignore_lock_real_mods_bytes = u8::try_from(u16::from(ignore_lock_real_mods)).unwrap().serialize_with_order(order);
+    let affect_internal_virtual_mods_bytes = u16::from(affect_internal_virtual_mods).serialize_with_order(order);
+    let internal_virtual_mods_bytes = u16::from(internal_virtual_mods).serialize_with_order(order);
+    let affect_ignore_lock_virtual_mods_bytes = u16::from(affect_ignore_lock_virtual_mods).serialize_with_order(order);
+    let ignore_lock_virtual_mods_bytes = u16::from(ignore_lock_virtual_mods).serialize_with_order(order);
+    let mouse_keys_dflt_btn_bytes = mouse_keys_dflt_btn.serialize_with_order(order);
+    let groups_wrap_bytes = groups_wrap.serialize_with_order(order);
+    let access_x_options_bytes = u16::from(access_x_options).serialize_with_order(order);
+    let affect_enabled_controls_bytes = u32::from(affect_enabled_controls).serialize_with_order(order);
+    let enabled_controls_bytes = u32::from(enabled_controls).serialize_with_order(order);
+    let change_controls_bytes = u32::from(change_controls).serialize_with_order(order);
+    let repeat_delay_bytes = repeat_delay.serialize_with_order(order);
+    let repeat_interval_bytes = repeat_interval.serialize_with_order(order);
+    let slow_keys_delay_bytes = slow_keys_delay.serialize_with_order(order);
+    let debounce_delay_bytes = debounce_delay.serialize_with_order(order);
+    let mouse_keys_delay_bytes = mouse_keys_delay.serialize_with_order(order);
+    let mouse_keys_interval_bytes = mouse_keys_interval.serialize_with_order(order);
+    let mouse_keys_time_to_max_bytes = mouse_keys_time_to_max.serialize_with_order(order);
+    let mouse_keys_max_speed_bytes = mouse_keys_max_speed.serialize_with_order(order);
+    let mouse_keys_curve_bytes = mouse_keys_curve.serialize_with_order(order);
+    let access_x_timeout_bytes = access_x_timeout.serialize_with_order(order);
+    let access_x_timeout_mask_bytes = u32::from(access_x_timeout_mask).serialize_with_order(order);
+    let access_x_timeout_values_bytes = u32::from(access_x_timeout_values).serialize_with_order(order);
+    let access_x_timeout_options_mask_bytes = u16::from(access_x_timeout_options_mask).serialize_with_order(order);
+    let access_x_timeout_options_values_bytes = u16::from(access_x_timeout_options_values).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_CONTROLS_REQUEST,
@@ -7122,43 +6572,221 @@ where
     let length_so_far = length_so_far + per_key_repeat.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(per_key_repeat)], vec![])?)
 }
+impl crate::x11_utils::Request for SetControlsRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetControlsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_controls(conn, self.device_spec, self.affect_internal_real_mods, self.internal_real_mods, self.affect_ignore_lock_real_mods, self.ignore_lock_real_mods, self.affect_internal_virtual_mods, self.internal_virtual_mods, self.affect_ignore_lock_virtual_mods, self.ignore_lock_virtual_mods, self.mouse_keys_dflt_btn, self.groups_wrap, self.access_x_options, self.affect_enabled_controls, self.enabled_controls, self.change_controls, self.repeat_delay, self.repeat_interval, self.slow_keys_delay, self.debounce_delay, self.mouse_keys_delay, self.mouse_keys_interval, self.mouse_keys_time_to_max, self.mouse_keys_max_speed, self.mouse_keys_curve, self.access_x_timeout, self.access_x_timeout_mask, self.access_x_timeout_values, self.access_x_timeout_options_mask, self.access_x_timeout_options_values, &self.per_key_repeat)
+    }
+}
+
+/// Opcode for the GetMap request
+pub const GET_MAP_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetMapRequest {
+    pub device_spec: DeviceSpec,
+    pub full: MapPart,
+    pub partial: MapPart,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub first_key_sym: xproto::Keycode,
+    pub n_key_syms: u8,
+    pub first_key_action: xproto::Keycode,
+    pub n_key_actions: u8,
+    pub first_key_behavior: xproto::Keycode,
+    pub n_key_behaviors: u8,
+    pub virtual_mods: VMod,
+    pub first_key_explicit: xproto::Keycode,
+    pub n_key_explicit: u8,
+    pub first_mod_map_key: xproto::Keycode,
+    pub n_mod_map_keys: u8,
+    pub first_v_mod_map_key: xproto::Keycode,
+    pub n_v_mod_map_keys: u8,
+}
+impl GetMapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (full, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (partial, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_action, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let full = MapPart::from(full);
+        let partial = MapPart::from(partial);
+        let virtual_mods = VMod::from(virtual_mods);
+        Ok(GetMapRequest {
+            device_spec,
+            full,
+            partial,
+            first_type,
+            n_types,
+            first_key_sym,
+            n_key_syms,
+            first_key_action,
+            n_key_actions,
+            first_key_behavior,
+            n_key_behaviors,
+            virtual_mods,
+            first_key_explicit,
+            n_key_explicit,
+            first_mod_map_key,
+            n_mod_map_keys,
+            first_v_mod_map_key,
+            n_v_mod_map_keys,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `full` field of this structure.
+    pub fn full<I>(mut self, value: I) -> Self where I: Into<MapPart> {
+        self.full = value.into();
+        self
+    }
+    /// Set the `partial` field of this structure.
+    pub fn partial<I>(mut self, value: I) -> Self where I: Into<MapPart> {
+        self.partial = value.into();
+        self
+    }
+    /// Set the `first_type` field of this structure.
+    pub fn first_type(mut self, value: u8) -> Self {
+        self.first_type = value;
+        self
+    }
+    /// Set the `n_types` field of this structure.
+    pub fn n_types(mut self, value: u8) -> Self {
+        self.n_types = value;
+        self
+    }
+    /// Set the `first_key_sym` field of this structure.
+    pub fn first_key_sym<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_key_sym = value.into();
+        self
+    }
+    /// Set the `n_key_syms` field of this structure.
+    pub fn n_key_syms(mut self, value: u8) -> Self {
+        self.n_key_syms = value;
+        self
+    }
+    /// Set the `first_key_action` field of this structure.
+    pub fn first_key_action<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_key_action = value.into();
+        self
+    }
+    /// Set the `n_key_actions` field of this structure.
+    pub fn n_key_actions(mut self, value: u8) -> Self {
+        self.n_key_actions = value;
+        self
+    }
+    /// Set the `first_key_behavior` field of this structure.
+    pub fn first_key_behavior<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_key_behavior = value.into();
+        self
+    }
+    /// Set the `n_key_behaviors` field of this structure.
+    pub fn n_key_behaviors(mut self, value: u8) -> Self {
+        self.n_key_behaviors = value;
+        self
+    }
+    /// Set the `virtual_mods` field of this structure.
+    pub fn virtual_mods<I>(mut self, value: I) -> Self where I: Into<VMod> {
+        self.virtual_mods = value.into();
+        self
+    }
+    /// Set the `first_key_explicit` field of this structure.
+    pub fn first_key_explicit<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_key_explicit = value.into();
+        self
+    }
+    /// Set the `n_key_explicit` field of this structure.
+    pub fn n_key_explicit(mut self, value: u8) -> Self {
+        self.n_key_explicit = value;
+        self
+    }
+    /// Set the `first_mod_map_key` field of this structure.
+    pub fn first_mod_map_key<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_mod_map_key = value.into();
+        self
+    }
+    /// Set the `n_mod_map_keys` field of this structure.
+    pub fn n_mod_map_keys(mut self, value: u8) -> Self {
+        self.n_mod_map_keys = value;
+        self
+    }
+    /// Set the `first_v_mod_map_key` field of this structure.
+    pub fn first_v_mod_map_key<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_v_mod_map_key = value.into();
+        self
+    }
+    /// Set the `n_v_mod_map_keys` field of this structure.
+    pub fn n_v_mod_map_keys(mut self, value: u8) -> Self {
+        self.n_v_mod_map_keys = value;
+        self
+    }
+}
 
-/// Opcode for the GetMap request
-pub const GET_MAP_REQUEST: u8 = 8;
 pub fn get_map<Conn, A, B, C>(conn: &Conn, device_spec: DeviceSpec, full: A, partial: B, first_type: u8, n_types: u8, first_key_sym: xproto::Keycode, n_key_syms: u8, first_key_action: xproto::Keycode, n_key_actions: u8, first_key_behavior: xproto::Keycode, n_key_behaviors: u8, virtual_mods: C, first_key_explicit: xproto::Keycode, n_key_explicit: u8, first_mod_map_key: xproto::Keycode, n_mod_map_keys: u8, first_v_mod_map_key: xproto::Keycode, n_v_mod_map_keys: u8) -> Result<Cookie<'_, Conn, GetMapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
-    B: Into<u16>,
-    C: Into<u16>,
+    A: Into<MapPart>,
+    B: Into<MapPart>,
+    C: Into<VMod>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let full: u16 = full.into();
-    let partial: u16 = partial.into();
-    let virtual_mods: u16 = virtual_mods.into();
+    let full: MapPart = full.into();
+    let partial: MapPart = partial.into();
+    let virtual_mods: VMod = virtual_mods.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let full_bytes = full.serialize();
-    let partial_bytes = partial.serialize();
-    let first_type_bytes = first_type.serialize();
-    let n_types_bytes = n_types.serialize();
-    let first_key_sym_bytes = first_key_sym.serialize();
-    let n_key_syms_bytes = n_key_syms.serialize();
-    let first_key_action_bytes = first_key_action.serialize();
-    let n_key_actions_bytes = n_key_actions.serialize();
-    let first_key_behavior_bytes = first_key_behavior.serialize();
-    let n_key_behaviors_bytes = n_key_behaviors.serialize();
-    let virtual_mods_bytes = virtual_mods.serialize();
-    let first_key_explicit_bytes = first_key_explicit.serialize();
-    let n_key_explicit_bytes = n_key_explicit.serialize();
-    let first_mod_map_key_bytes = first_mod_map_key.serialize();
-    let n_mod_map_keys_bytes = n_mod_map_keys.serialize();
-    let first_v_mod_map_key_bytes = first_v_mod_map_key.serialize();
-    let n_v_mod_map_keys_bytes = n_v_mod_map_keys.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let full_bytes = u16::from(full).serialize_with_order(order);
+    let partial_bytes = u16::from(partial).serialize_with_order(order);
+    let first_type_bytes = first_type.serialize_with_order(order);
+    let n_types_bytes = n_types.serialize_with_order(order);
+    let first_key_sym_bytes = first_key_sym.serialize_with_order(order);
+    let n_key_syms_bytes = n_key_syms.serialize_with_order(order);
+    let first_key_action_bytes = first_key_action.serialize_with_order(order);
+    let n_key_actions_bytes = n_key_actions.serialize_with_order(order);
+    let first_key_behavior_bytes = first_key_behavior.serialize_with_order(order);
+    let n_key_behaviors_bytes = n_key_behaviors.serialize_with_order(order);
+    let virtual_mods_bytes = u16::from(virtual_mods).serialize_with_order(order);
+    let first_key_explicit_bytes = first_key_explicit.serialize_with_order(order);
+    let n_key_explicit_bytes = n_key_explicit.serialize_with_order(order);
+    let first_mod_map_key_bytes = first_mod_map_key.serialize_with_order(order);
+    let n_mod_map_keys_bytes = n_mod_map_keys.serialize_with_order(order);
+    let first_v_mod_map_key_bytes = first_v_mod_map_key.serialize_with_order(order);
+    let n_v_mod_map_keys_bytes = n_v_mod_map_keys.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_MAP_REQUEST,
@@ -7192,9 +6820,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetMapRequest {
+    type Reply = GetMapReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetMapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetMapReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_map(conn, self.device_spec, self.full, self.partial, self.first_type, self.n_types, self.first_key_sym, self.n_key_syms, self.first_key_action, self.n_key_actions, self.first_key_behavior, self.n_key_behaviors, self.virtual_mods, self.first_key_explicit, self.n_key_explicit, self.first_mod_map_key, self.n_mod_map_keys, self.first_v_mod_map_key, self.n_v_mod_map_keys)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct GetMapMapBitcase3 {
@@ -7202,7 +6842,7 @@ pub struct GetMapMapBitcase3 {
     pub acts_rtrn_acts: Vec<Action>,
 }
 impl GetMapMapBitcase3 {
-    pub fn try_parse(remaining: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
+    pub fn try_parse_with_order(remaining: &[u8], order: ByteOrder, n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (acts_rtrn_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_into().or(Err(ParseError::ParseError))?)?;
         let acts_rtrn_count = acts_rtrn_count.to_vec();
@@ -7210,30 +6850,45 @@ impl GetMapMapBitcase3 {
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_into().or(Err(ParseError::ParseError))?)?;
+        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, total_actions.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetMapMapBitcase3 { acts_rtrn_count, acts_rtrn_acts };
         Ok((result, remaining))
     }
 }
 // Skipping TryFrom implementations because of unresolved members
+#[allow(dead_code, unused_variables)]
+impl GetMapMapBitcase3 {
+    fn serialize_with_order(&self, order: ByteOrder, n_key_actions: u8, total_actions: u16) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, n_key_actions, total_actions);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, n_key_actions: u8, total_actions: u16) {
+        assert_eq!(self.acts_rtrn_count.len(), usize::try_from(n_key_actions).unwrap(), "`acts_rtrn_count` has an incorrect length");
+        bytes.extend_from_slice(&self.acts_rtrn_count);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.acts_rtrn_acts.len(), usize::try_from(total_actions).unwrap(), "`acts_rtrn_acts` has an incorrect length");
+        self.acts_rtrn_acts.serialize_into_with_order(bytes, order);
+    }
+}
 #[derive(Debug, Clone, Default)]
 pub struct GetMapMap {
     pub types_rtrn: Option<Vec<KeyType>>,
     pub syms_rtrn: Option<Vec<KeySymMap>>,
     pub bitcase3: Option<GetMapMapBitcase3>,
     pub behaviors_rtrn: Option<Vec<SetBehavior>>,
-    pub vmods_rtrn: Option<Vec<u8>>,
+    pub vmods_rtrn: Option<Vec<xproto::ModMask>>,
     pub explicit_rtrn: Option<Vec<SetExplicit>>,
     pub modmap_rtrn: Option<Vec<KeyModMap>>,
     pub vmodmap_rtrn: Option<Vec<KeyVModMap>>,
 }
 impl GetMapMap {
-    fn try_parse(value: &[u8], present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, present: MapPart, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: VMod, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(present);
         let mut outer_remaining = value;
         let types_rtrn = if switch_expr & u32::from(MapPart::KeyTypes) != 0 {
             let remaining = outer_remaining;
-            let (types_rtrn, remaining) = crate::x11_utils::parse_list::<KeyType>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?)?;
+            let (types_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyType>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(types_rtrn)
         } else {
@@ -7241,14 +6896,14 @@ impl GetMapMap {
         };
         let syms_rtrn = if switch_expr & u32::from(MapPart::KeySyms) != 0 {
             let remaining = outer_remaining;
-            let (syms_rtrn, remaining) = crate::x11_utils::parse_list::<KeySymMap>(remaining, n_key_syms.try_into().or(Err(ParseError::ParseError))?)?;
+            let (syms_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeySymMap>(remaining, n_key_syms.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(syms_rtrn)
         } else {
             None
         };
         let bitcase3 = if switch_expr & u32::from(MapPart::KeyActions) != 0 {
-            let (bitcase3, new_remaining) = GetMapMapBitcase3::try_parse(outer_remaining, n_key_actions, total_actions)?;
+            let (bitcase3, new_remaining) = GetMapMapBitcase3::try_parse_with_order(outer_remaining, order, n_key_actions, total_actions)?;
             outer_remaining = new_remaining;
             Some(bitcase3)
         } else {
@@ -7256,7 +6911,7 @@ impl GetMapMap {
         };
         let behaviors_rtrn = if switch_expr & u32::from(MapPart::KeyBehaviors) != 0 {
             let remaining = outer_remaining;
-            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list::<SetBehavior>(remaining, total_key_behaviors.try_into().or(Err(ParseError::ParseError))?)?;
+            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SetBehavior>(remaining, total_key_behaviors.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(behaviors_rtrn)
         } else {
@@ -7265,8 +6920,15 @@ impl GetMapMap {
         let vmods_rtrn = if switch_expr & u32::from(MapPart::VirtualMods) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (vmods_rtrn, remaining) = crate::x11_utils::parse_u8_list(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
-            let vmods_rtrn = vmods_rtrn.to_vec();
+            let mut remaining = remaining;
+            let list_length = usize::try_from(u16::from(virtual_mods).count_ones()).or(Err(ParseError::ParseError))?;
+            let mut vmods_rtrn = Vec::with_capacity(list_length);
+            for _ in 0..list_length {
+                let (v, new_remaining) = u8::try_parse_with_order(remaining, order)?;
+                let v = xproto::ModMask::from(u16::from(v));
+                remaining = new_remaining;
+                vmods_rtrn.push(v);
+            }
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
@@ -7279,7 +6941,7 @@ impl GetMapMap {
         let explicit_rtrn = if switch_expr & u32::from(MapPart::ExplicitComponents) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_into().or(Err(ParseError::ParseError))?)?;
+            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SetExplicit>(remaining, total_key_explicit.try_into().or(Err(ParseError::ParseError))?, order)?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
@@ -7292,7 +6954,7 @@ impl GetMapMap {
         let modmap_rtrn = if switch_expr & u32::from(MapPart::ModifierMap) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_into().or(Err(ParseError::ParseError))?)?;
+            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyModMap>(remaining, total_mod_map_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
@@ -7304,7 +6966,7 @@ impl GetMapMap {
         };
         let vmodmap_rtrn = if switch_expr & u32::from(MapPart::VirtualModMap) != 0 {
             let remaining = outer_remaining;
-            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyVModMap>(remaining, total_v_mod_map_keys.try_into().or(Err(ParseError::ParseError))?)?;
+            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyVModMap>(remaining, total_v_mod_map_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(vmodmap_rtrn)
         } else {
@@ -7314,6 +6976,82 @@ impl GetMapMap {
         Ok((result, outer_remaining))
     }
 }
+#[allow(dead_code, unused_variables)]
+impl GetMapMap {
+    fn serialize_with_order(&self, order: ByteOrder, present: MapPart, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: VMod, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, present: MapPart, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: VMod, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) {
+        if let Some(ref types_rtrn) = self.types_rtrn {
+            assert_eq!(types_rtrn.len(), usize::try_from(n_types).unwrap(), "`types_rtrn` has an incorrect length");
+            types_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref syms_rtrn) = self.syms_rtrn {
+            assert_eq!(syms_rtrn.len(), usize::try_from(n_key_syms).unwrap(), "`syms_rtrn` has an incorrect length");
+            syms_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase3) = self.bitcase3 {
+            bitcase3.serialize_into_with_order(bytes, order, n_key_actions, total_actions);
+        }
+        if let Some(ref behaviors_rtrn) = self.behaviors_rtrn {
+            assert_eq!(behaviors_rtrn.len(), usize::try_from(total_key_behaviors).unwrap(), "`behaviors_rtrn` has an incorrect length");
+            behaviors_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref vmods_rtrn) = self.vmods_rtrn {
+            assert_eq!(vmods_rtrn.len(), usize::try_from(u16::from(virtual_mods).count_ones()).unwrap(), "`vmods_rtrn` has an incorrect length");
+            for element in vmods_rtrn.iter() {
+                u8::try_from(u16::from(*element)).unwrap().serialize_into_with_order(bytes, order);
+            }
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref explicit_rtrn) = self.explicit_rtrn {
+            assert_eq!(explicit_rtrn.len(), usize::try_from(total_key_explicit).unwrap(), "`explicit_rtrn` has an incorrect length");
+            explicit_rtrn.serialize_into_with_order(bytes, order);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref modmap_rtrn) = self.modmap_rtrn {
+            assert_eq!(modmap_rtrn.len(), usize::try_from(total_mod_map_keys).unwrap(), "`modmap_rtrn` has an incorrect length");
+            modmap_rtrn.serialize_into_with_order(bytes, order);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref vmodmap_rtrn) = self.vmodmap_rtrn {
+            assert_eq!(vmodmap_rtrn.len(), usize::try_from(total_v_mod_map_keys).unwrap(), "`vmodmap_rtrn` has an incorrect length");
+            vmodmap_rtrn.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl GetMapMap {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.types_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KeyTypes);
+        }
+        if self.syms_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KeySyms);
+        }
+        if self.bitcase3.is_some() {
+            expr_value |= u32::from(MapPart::KeyActions);
+        }
+        if self.behaviors_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KeyBehaviors);
+        }
+        if self.vmods_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::VirtualMods);
+        }
+        if self.explicit_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::ExplicitComponents);
+        }
+        if self.modmap_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::ModifierMap);
+        }
+        if self.vmodmap_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::VirtualModMap);
+        }
+        expr_value
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct GetMapReply {
@@ -7344,43 +7082,44 @@ pub struct GetMapReply {
     pub first_v_mod_map_key: xproto::Keycode,
     pub n_v_mod_map_keys: u8,
     pub total_v_mod_map_keys: u8,
-    pub virtual_mods: u16,
+    pub virtual_mods: VMod,
     pub map: GetMapMap,
 }
 impl TryParse for GetMapReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (present, remaining) = u16::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (total_types, remaining) = u8::try_parse(remaining)?;
-        let (first_key_sym, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (total_syms, remaining) = u16::try_parse(remaining)?;
-        let (n_key_syms, remaining) = u8::try_parse(remaining)?;
-        let (first_key_action, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (total_actions, remaining) = u16::try_parse(remaining)?;
-        let (n_key_actions, remaining) = u8::try_parse(remaining)?;
-        let (first_key_behavior, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (total_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (first_key_explicit, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (total_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (present, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (total_syms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_action, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (total_actions, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (map, remaining) = GetMapMap::try_parse(remaining, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = GetMapMap::try_parse_with_order(remaining, order, MapPart::from(present), n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, VMod::from(virtual_mods), total_key_explicit, total_mod_map_keys, total_v_mod_map_keys)?;
+        let virtual_mods = VMod::from(virtual_mods);
         let result = GetMapReply { response_type, device_id, sequence, length, min_key_code, max_key_code, first_type, n_types, total_types, first_key_sym, total_syms, n_key_syms, first_key_action, total_actions, n_key_actions, first_key_behavior, n_key_behaviors, total_key_behaviors, first_key_explicit, n_key_explicit, total_key_explicit, first_mod_map_key, n_mod_map_keys, total_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, total_v_mod_map_keys, virtual_mods, map };
         Ok((result, remaining))
     }
@@ -7391,6 +7130,66 @@ impl TryFrom<&[u8]> for GetMapReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetMapReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        let n_types = self.n_types;
+        let n_key_syms = self.n_key_syms;
+        let n_key_actions = self.n_key_actions;
+        let total_actions = self.total_actions;
+        let total_key_behaviors = self.total_key_behaviors;
+        let virtual_mods = self.virtual_mods;
+        let total_key_explicit = self.total_key_explicit;
+        let total_mod_map_keys = self.total_mod_map_keys;
+        let total_v_mod_map_keys = self.total_v_mod_map_keys;
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.min_key_code.serialize_into_with_order(bytes, order);
+        self.max_key_code.serialize_into_with_order(bytes, order);
+        let present = u16::try_from(self.map.switch_expr()).unwrap();
+        present.serialize_into_with_order(bytes, order);
+        self.first_type.serialize_into_with_order(bytes, order);
+        self.n_types.serialize_into_with_order(bytes, order);
+        self.total_types.serialize_into_with_order(bytes, order);
+        self.first_key_sym.serialize_into_with_order(bytes, order);
+        self.total_syms.serialize_into_with_order(bytes, order);
+        self.n_key_syms.serialize_into_with_order(bytes, order);
+        self.first_key_action.serialize_into_with_order(bytes, order);
+        self.total_actions.serialize_into_with_order(bytes, order);
+        self.n_key_actions.serialize_into_with_order(bytes, order);
+        self.first_key_behavior.serialize_into_with_order(bytes, order);
+        self.n_key_behaviors.serialize_into_with_order(bytes, order);
+        self.total_key_behaviors.serialize_into_with_order(bytes, order);
+        self.first_key_explicit.serialize_into_with_order(bytes, order);
+        self.n_key_explicit.serialize_into_with_order(bytes, order);
+        self.total_key_explicit.serialize_into_with_order(bytes, order);
+        self.first_mod_map_key.serialize_into_with_order(bytes, order);
+        self.n_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.total_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.first_v_mod_map_key.serialize_into_with_order(bytes, order);
+        self.n_v_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.total_v_mod_map_keys.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        u16::from(self.virtual_mods).serialize_into_with_order(bytes, order);
+        self.map.serialize_into_with_order(bytes, order, MapPart::from(present), n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetMap request
 pub const SET_MAP_REQUEST: u8 = 9;
@@ -7399,19 +7198,34 @@ pub struct SetMapAuxBitcase3 {
     pub actions_count: Vec<u8>,
     pub actions: Vec<Action>,
 }
+impl SetMapAuxBitcase3 {
+    pub fn try_parse_with_order(remaining: &[u8], order: ByteOrder, n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (actions_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_into().or(Err(ParseError::ParseError))?)?;
+        let actions_count = actions_count.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+        let (actions, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, total_actions.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let result = SetMapAuxBitcase3 { actions_count, actions };
+        Ok((result, remaining))
+    }
+}
+// Skipping TryFrom implementations because of unresolved members
 #[allow(dead_code, unused_variables)]
 impl SetMapAuxBitcase3 {
-    fn serialize(&self, n_key_actions: u8, total_actions: u16) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, n_key_actions: u8, total_actions: u16) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, n_key_actions, total_actions);
+        self.serialize_into_with_order(&mut result, order, n_key_actions, total_actions);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, n_key_actions: u8, total_actions: u16) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, n_key_actions: u8, total_actions: u16) {
         assert_eq!(self.actions_count.len(), usize::try_from(n_key_actions).unwrap(), "`actions_count` has an incorrect length");
         bytes.extend_from_slice(&self.actions_count);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
         assert_eq!(self.actions.len(), usize::try_from(total_actions).unwrap(), "`actions` has an incorrect length");
-        self.actions.serialize_into(bytes);
+        self.actions.serialize_into_with_order(bytes, order);
     }
 }
 /// Auxiliary and optional information for the `set_map` function
@@ -7426,45 +7240,122 @@ pub struct SetMapAux {
     pub modmap: Option<Vec<KeyModMap>>,
     pub vmodmap: Option<Vec<KeyVModMap>>,
 }
+impl SetMapAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, present: MapPart, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: VMod, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(present);
+        let mut outer_remaining = value;
+        let types = if switch_expr & u32::from(MapPart::KeyTypes) != 0 {
+            let remaining = outer_remaining;
+            let (types, remaining) = crate::x11_utils::parse_list_with_order::<SetKeyType>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(types)
+        } else {
+            None
+        };
+        let syms = if switch_expr & u32::from(MapPart::KeySyms) != 0 {
+            let remaining = outer_remaining;
+            let (syms, remaining) = crate::x11_utils::parse_list_with_order::<KeySymMap>(remaining, n_key_syms.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(syms)
+        } else {
+            None
+        };
+        let bitcase3 = if switch_expr & u32::from(MapPart::KeyActions) != 0 {
+            let (bitcase3, new_remaining) = SetMapAuxBitcase3::try_parse_with_order(outer_remaining, order, n_key_actions, total_actions)?;
+            outer_remaining = new_remaining;
+            Some(bitcase3)
+        } else {
+            None
+        };
+        let behaviors = if switch_expr & u32::from(MapPart::KeyBehaviors) != 0 {
+            let remaining = outer_remaining;
+            let (behaviors, remaining) = crate::x11_utils::parse_list_with_order::<SetBehavior>(remaining, total_key_behaviors.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(behaviors)
+        } else {
+            None
+        };
+        let vmods = if switch_expr & u32::from(MapPart::VirtualMods) != 0 {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (vmods, remaining) = crate::x11_utils::parse_u8_list(remaining, u16::from(virtual_mods).count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+            let vmods = vmods.to_vec();
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+            outer_remaining = remaining;
+            Some(vmods)
+        } else {
+            None
+        };
+        let explicit = if switch_expr & u32::from(MapPart::ExplicitComponents) != 0 {
+            let remaining = outer_remaining;
+            let (explicit, remaining) = crate::x11_utils::parse_list_with_order::<SetExplicit>(remaining, total_key_explicit.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(explicit)
+        } else {
+            None
+        };
+        let modmap = if switch_expr & u32::from(MapPart::ModifierMap) != 0 {
+            let remaining = outer_remaining;
+            let (modmap, remaining) = crate::x11_utils::parse_list_with_order::<KeyModMap>(remaining, total_mod_map_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(modmap)
+        } else {
+            None
+        };
+        let vmodmap = if switch_expr & u32::from(MapPart::VirtualModMap) != 0 {
+            let remaining = outer_remaining;
+            let (vmodmap, remaining) = crate::x11_utils::parse_list_with_order::<KeyVModMap>(remaining, total_v_mod_map_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(vmodmap)
+        } else {
+            None
+        };
+        let result = SetMapAux { types, syms, bitcase3, behaviors, vmods, explicit, modmap, vmodmap };
+        Ok((result, outer_remaining))
+    }
+}
 #[allow(dead_code, unused_variables)]
 impl SetMapAux {
-    fn serialize(&self, present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, present: MapPart, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: VMod, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys);
+        self.serialize_into_with_order(&mut result, order, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, present: MapPart, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: VMod, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) {
         if let Some(ref types) = self.types {
             assert_eq!(types.len(), usize::try_from(n_types).unwrap(), "`types` has an incorrect length");
-            types.serialize_into(bytes);
+            types.serialize_into_with_order(bytes, order);
         }
         if let Some(ref syms) = self.syms {
             assert_eq!(syms.len(), usize::try_from(n_key_syms).unwrap(), "`syms` has an incorrect length");
-            syms.serialize_into(bytes);
+            syms.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase3) = self.bitcase3 {
-            bitcase3.serialize_into(bytes, n_key_actions, total_actions);
+            bitcase3.serialize_into_with_order(bytes, order, n_key_actions, total_actions);
         }
         if let Some(ref behaviors) = self.behaviors {
             assert_eq!(behaviors.len(), usize::try_from(total_key_behaviors).unwrap(), "`behaviors` has an incorrect length");
-            behaviors.serialize_into(bytes);
+            behaviors.serialize_into_with_order(bytes, order);
         }
         if let Some(ref vmods) = self.vmods {
-            assert_eq!(vmods.len(), usize::try_from(virtual_mods.count_ones()).unwrap(), "`vmods` has an incorrect length");
+            assert_eq!(vmods.len(), usize::try_from(u16::from(virtual_mods).count_ones()).unwrap(), "`vmods` has an incorrect length");
             bytes.extend_from_slice(&vmods);
             bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
         }
         if let Some(ref explicit) = self.explicit {
             assert_eq!(explicit.len(), usize::try_from(total_key_explicit).unwrap(), "`explicit` has an incorrect length");
-            explicit.serialize_into(bytes);
+            explicit.serialize_into_with_order(bytes, order);
         }
         if let Some(ref modmap) = self.modmap {
             assert_eq!(modmap.len(), usize::try_from(total_mod_map_keys).unwrap(), "`modmap` has an incorrect length");
-            modmap.serialize_into(bytes);
+            modmap.serialize_into_with_order(bytes, order);
         }
         if let Some(ref vmodmap) = self.vmodmap {
             assert_eq!(vmodmap.len(), usize::try_from(total_v_mod_map_keys).unwrap(), "`vmodmap` has an incorrect length");
-            vmodmap.serialize_into(bytes);
+            vmodmap.serialize_into_with_order(bytes, order);
         }
     }
 }
@@ -7497,50 +7388,278 @@ impl SetMapAux {
         }
         expr_value
     }
-}
-impl SetMapAux {
-    /// Create a new instance with all fields unset / not present.
-    pub fn new() -> Self {
-        Default::default()
+}
+impl SetMapAux {
+    /// Create a new instance with all fields unset / not present.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Set the `types` field of this structure.
+    pub fn types<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetKeyType>>> {
+        self.types = value.into();
+        self
+    }
+    /// Set the `syms` field of this structure.
+    pub fn syms<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeySymMap>>> {
+        self.syms = value.into();
+        self
+    }
+    /// Set the `bitcase3` field of this structure.
+    pub fn bitcase3<I>(mut self, value: I) -> Self where I: Into<Option<SetMapAuxBitcase3>> {
+        self.bitcase3 = value.into();
+        self
+    }
+    /// Set the `behaviors` field of this structure.
+    pub fn behaviors<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetBehavior>>> {
+        self.behaviors = value.into();
+        self
+    }
+    /// Set the `vmods` field of this structure.
+    pub fn vmods<I>(mut self, value: I) -> Self where I: Into<Option<Vec<u8>>> {
+        self.vmods = value.into();
+        self
+    }
+    /// Set the `explicit` field of this structure.
+    pub fn explicit<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetExplicit>>> {
+        self.explicit = value.into();
+        self
+    }
+    /// Set the `modmap` field of this structure.
+    pub fn modmap<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyModMap>>> {
+        self.modmap = value.into();
+        self
+    }
+    /// Set the `vmodmap` field of this structure.
+    pub fn vmodmap<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyVModMap>>> {
+        self.vmodmap = value.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SetMapRequest {
+    pub device_spec: DeviceSpec,
+    pub flags: SetMapFlags,
+    pub min_key_code: xproto::Keycode,
+    pub max_key_code: xproto::Keycode,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub first_key_sym: xproto::Keycode,
+    pub n_key_syms: u8,
+    pub total_syms: u16,
+    pub first_key_action: xproto::Keycode,
+    pub n_key_actions: u8,
+    pub total_actions: u16,
+    pub first_key_behavior: xproto::Keycode,
+    pub n_key_behaviors: u8,
+    pub total_key_behaviors: u8,
+    pub first_key_explicit: xproto::Keycode,
+    pub n_key_explicit: u8,
+    pub total_key_explicit: u8,
+    pub first_mod_map_key: xproto::Keycode,
+    pub n_mod_map_keys: u8,
+    pub total_mod_map_keys: u8,
+    pub first_v_mod_map_key: xproto::Keycode,
+    pub n_v_mod_map_keys: u8,
+    pub total_v_mod_map_keys: u8,
+    pub virtual_mods: VMod,
+    pub values: SetMapAux,
+}
+impl SetMapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_MAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (present, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_syms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key_action, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_actions, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (values, remaining) = SetMapAux::try_parse_with_order(remaining, order, MapPart::from(present), n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, VMod::from(virtual_mods), total_key_explicit, total_mod_map_keys, total_v_mod_map_keys)?;
+        let _ = remaining;
+        let _ = order;
+        let flags = SetMapFlags::from(flags);
+        let virtual_mods = VMod::from(virtual_mods);
+        Ok(SetMapRequest {
+            device_spec,
+            flags,
+            min_key_code,
+            max_key_code,
+            first_type,
+            n_types,
+            first_key_sym,
+            n_key_syms,
+            total_syms,
+            first_key_action,
+            n_key_actions,
+            total_actions,
+            first_key_behavior,
+            n_key_behaviors,
+            total_key_behaviors,
+            first_key_explicit,
+            n_key_explicit,
+            total_key_explicit,
+            first_mod_map_key,
+            n_mod_map_keys,
+            total_mod_map_keys,
+            first_v_mod_map_key,
+            n_v_mod_map_keys,
+            total_v_mod_map_keys,
+            virtual_mods,
+            values,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `flags` field of this structure.
+    pub fn flags<I>(mut self, value: I) -> Self where I: Into<SetMapFlags> {
+        self.flags = value.into();
+        self
+    }
+    /// Set the `min_key_code` field of this structure.
+    pub fn min_key_code<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.min_key_code = value.into();
+        self
+    }
+    /// Set the `max_key_code` field of this structure.
+    pub fn max_key_code<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.max_key_code = value.into();
+        self
+    }
+    /// Set the `first_type` field of this structure.
+    pub fn first_type(mut self, value: u8) -> Self {
+        self.first_type = value;
+        self
+    }
+    /// Set the `n_types` field of this structure.
+    pub fn n_types(mut self, value: u8) -> Self {
+        self.n_types = value;
+        self
+    }
+    /// Set the `first_key_sym` field of this structure.
+    pub fn first_key_sym<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_key_sym = value.into();
+        self
+    }
+    /// Set the `n_key_syms` field of this structure.
+    pub fn n_key_syms(mut self, value: u8) -> Self {
+        self.n_key_syms = value;
+        self
+    }
+    /// Set the `total_syms` field of this structure.
+    pub fn total_syms(mut self, value: u16) -> Self {
+        self.total_syms = value;
+        self
+    }
+    /// Set the `first_key_action` field of this structure.
+    pub fn first_key_action<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_key_action = value.into();
+        self
+    }
+    /// Set the `n_key_actions` field of this structure.
+    pub fn n_key_actions(mut self, value: u8) -> Self {
+        self.n_key_actions = value;
+        self
+    }
+    /// Set the `total_actions` field of this structure.
+    pub fn total_actions(mut self, value: u16) -> Self {
+        self.total_actions = value;
+        self
+    }
+    /// Set the `first_key_behavior` field of this structure.
+    pub fn first_key_behavior<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_key_behavior = value.into();
+        self
+    }
+    /// Set the `n_key_behaviors` field of this structure.
+    pub fn n_key_behaviors(mut self, value: u8) -> Self {
+        self.n_key_behaviors = value;
+        self
+    }
+    /// Set the `total_key_behaviors` field of this structure.
+    pub fn total_key_behaviors(mut self, value: u8) -> Self {
+        self.total_key_behaviors = value;
+        self
     }
-    /// Set the `types` field of this structure.
-    pub fn types<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetKeyType>>> {
-        self.types = value.into();
+    /// Set the `first_key_explicit` field of this structure.
+    pub fn first_key_explicit<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_key_explicit = value.into();
         self
     }
-    /// Set the `syms` field of this structure.
-    pub fn syms<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeySymMap>>> {
-        self.syms = value.into();
+    /// Set the `n_key_explicit` field of this structure.
+    pub fn n_key_explicit(mut self, value: u8) -> Self {
+        self.n_key_explicit = value;
         self
     }
-    /// Set the `bitcase3` field of this structure.
-    pub fn bitcase3<I>(mut self, value: I) -> Self where I: Into<Option<SetMapAuxBitcase3>> {
-        self.bitcase3 = value.into();
+    /// Set the `total_key_explicit` field of this structure.
+    pub fn total_key_explicit(mut self, value: u8) -> Self {
+        self.total_key_explicit = value;
         self
     }
-    /// Set the `behaviors` field of this structure.
-    pub fn behaviors<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetBehavior>>> {
-        self.behaviors = value.into();
+    /// Set the `first_mod_map_key` field of this structure.
+    pub fn first_mod_map_key<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_mod_map_key = value.into();
         self
     }
-    /// Set the `vmods` field of this structure.
-    pub fn vmods<I>(mut self, value: I) -> Self where I: Into<Option<Vec<u8>>> {
-        self.vmods = value.into();
+    /// Set the `n_mod_map_keys` field of this structure.
+    pub fn n_mod_map_keys(mut self, value: u8) -> Self {
+        self.n_mod_map_keys = value;
         self
     }
-    /// Set the `explicit` field of this structure.
-    pub fn explicit<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetExplicit>>> {
-        self.explicit = value.into();
+    /// Set the `total_mod_map_keys` field of this structure.
+    pub fn total_mod_map_keys(mut self, value: u8) -> Self {
+        self.total_mod_map_keys = value;
         self
     }
-    /// Set the `modmap` field of this structure.
-    pub fn modmap<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyModMap>>> {
-        self.modmap = value.into();
+    /// Set the `first_v_mod_map_key` field of this structure.
+    pub fn first_v_mod_map_key<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_v_mod_map_key = value.into();
         self
     }
-    /// Set the `vmodmap` field of this structure.
-    pub fn vmodmap<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyVModMap>>> {
-        self.vmodmap = value.into();
+    /// Set the `n_v_mod_map_keys` field of this structure.
+    pub fn n_v_mod_map_keys(mut self, value: u8) -> Self {
+        self.n_v_mod_map_keys = value;
+        self
+    }
+    /// Set the `total_v_mod_map_keys` field of this structure.
+    pub fn total_v_mod_map_keys(mut self, value: u8) -> Self {
+        self.total_v_mod_map_keys = value;
+        self
+    }
+    /// Set the `virtual_mods` field of this structure.
+    pub fn virtual_mods<I>(mut self, value: I) -> Self where I: Into<VMod> {
+        self.virtual_mods = value.into();
+        self
+    }
+    /// Set the `values` field of this structure.
+    pub fn values<I>(mut self, value: I) -> Self where I: Into<SetMapAux> {
+        self.values = value.into();
         self
     }
 }
@@ -7548,41 +7667,42 @@ impl SetMapAux {
 pub fn set_map<'c, Conn, A, B>(conn: &'c Conn, device_spec: DeviceSpec, flags: A, min_key_code: xproto::Keycode, max_key_code: xproto::Keycode, first_type: u8, n_types: u8, first_key_sym: xproto::Keycode, n_key_syms: u8, total_syms: u16, first_key_action: xproto::Keycode, n_key_actions: u8, total_actions: u16, first_key_behavior: xproto::Keycode, n_key_behaviors: u8, total_key_behaviors: u8, first_key_explicit: xproto::Keycode, n_key_explicit: u8, total_key_explicit: u8, first_mod_map_key: xproto::Keycode, n_mod_map_keys: u8, total_mod_map_keys: u8, first_v_mod_map_key: xproto::Keycode, n_v_mod_map_keys: u8, total_v_mod_map_keys: u8, virtual_mods: B, values: &SetMapAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
-    B: Into<u16>,
+    A: Into<SetMapFlags>,
+    B: Into<VMod>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let flags: u16 = flags.into();
-    let virtual_mods: u16 = virtual_mods.into();
+    let flags: SetMapFlags = flags.into();
+    let virtual_mods: VMod = virtual_mods.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
     let present = u16::try_from(values.switch_expr()).unwrap();
-    let present_bytes = present.serialize();
-    let flags_bytes = flags.serialize();
-    let min_key_code_bytes = min_key_code.serialize();
-    let max_key_code_bytes = max_key_code.serialize();
-    let first_type_bytes = first_type.serialize();
-    let n_types_bytes = n_types.serialize();
-    let first_key_sym_bytes = first_key_sym.serialize();
-    let n_key_syms_bytes = n_key_syms.serialize();
-    let total_syms_bytes = total_syms.serialize();
-    let first_key_action_bytes = first_key_action.serialize();
-    let n_key_actions_bytes = n_key_actions.serialize();
-    let total_actions_bytes = total_actions.serialize();
-    let first_key_behavior_bytes = first_key_behavior.serialize();
-    let n_key_behaviors_bytes = n_key_behaviors.serialize();
-    let total_key_behaviors_bytes = total_key_behaviors.serialize();
-    let first_key_explicit_bytes = first_key_explicit.serialize();
-    let n_key_explicit_bytes = n_key_explicit.serialize();
-    let total_key_explicit_bytes = total_key_explicit.serialize();
-    let first_mod_map_key_bytes = first_mod_map_key.serialize();
-    let n_mod_map_keys_bytes = n_mod_map_keys.serialize();
-    let total_mod_map_keys_bytes = total_mod_map_keys.serialize();
-    let first_v_mod_map_key_bytes = first_v_mod_map_key.serialize();
-    let n_v_mod_map_keys_bytes = n_v_mod_map_keys.serialize();
-    let total_v_mod_map_keys_bytes = total_v_mod_map_keys.serialize();
-    let virtual_mods_bytes = virtual_mods.serialize();
+    let present_bytes = present.serialize_with_order(order);
+    let flags_bytes = u16::from(flags).serialize_with_order(order);
+    let min_key_code_bytes = min_key_code.serialize_with_order(order);
+    let max_key_code_bytes = max_key_code.serialize_with_order(order);
+    let first_type_bytes = first_type.serialize_with_order(order);
+    let n_types_bytes = n_types.serialize_with_order(order);
+    let first_key_sym_bytes = first_key_sym.serialize_with_order(order);
+    let n_key_syms_bytes = n_key_syms.serialize_with_order(order);
+    let total_syms_bytes = total_syms.serialize_with_order(order);
+    let first_key_action_bytes = first_key_action.serialize_with_order(order);
+    let n_key_actions_bytes = n_key_actions.serialize_with_order(order);
+    let total_actions_bytes = total_actions.serialize_with_order(order);
+    let first_key_behavior_bytes = first_key_behavior.serialize_with_order(order);
+    let n_key_behaviors_bytes = n_key_behaviors.serialize_with_order(order);
+    let total_key_behaviors_bytes = total_key_behaviors.serialize_with_order(order);
+    let first_key_explicit_bytes = first_key_explicit.serialize_with_order(order);
+    let n_key_explicit_bytes = n_key_explicit.serialize_with_order(order);
+    let total_key_explicit_bytes = total_key_explicit.serialize_with_order(order);
+    let first_mod_map_key_bytes = first_mod_map_key.serialize_with_order(order);
+    let n_mod_map_keys_bytes = n_mod_map_keys.serialize_with_order(order);
+    let total_mod_map_keys_bytes = total_mod_map_keys.serialize_with_order(order);
+    let first_v_mod_map_key_bytes = first_v_mod_map_key.serialize_with_order(order);
+    let n_v_mod_map_keys_bytes = n_v_mod_map_keys.serialize_with_order(order);
+    let total_v_mod_map_keys_bytes = total_v_mod_map_keys.serialize_with_order(order);
+    let virtual_mods_bytes = u16::from(virtual_mods).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_MAP_REQUEST,
@@ -7622,32 +7742,103 @@ where
         virtual_mods_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let values_bytes = values.serialize(present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys);
+    let values_bytes = values.serialize_with_order(order, MapPart::from(present), n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys);
     let length_so_far = length_so_far + values_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&values_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetMapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetMapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_map(conn, self.device_spec, self.flags, self.min_key_code, self.max_key_code, self.first_type, self.n_types, self.first_key_sym, self.n_key_syms, self.total_syms, self.first_key_action, self.n_key_actions, self.total_actions, self.first_key_behavior, self.n_key_behaviors, self.total_key_behaviors, self.first_key_explicit, self.n_key_explicit, self.total_key_explicit, self.first_mod_map_key, self.n_mod_map_keys, self.total_mod_map_keys, self.first_v_mod_map_key, self.n_v_mod_map_keys, self.total_v_mod_map_keys, self.virtual_mods, &self.values)
+    }
+}
 
 /// Opcode for the GetCompatMap request
 pub const GET_COMPAT_MAP_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetCompatMapRequest {
+    pub device_spec: DeviceSpec,
+    pub groups: SetOfGroup,
+    pub get_all_si: bool,
+    pub first_si: u16,
+    pub n_si: u16,
+}
+impl GetCompatMapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COMPAT_MAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (get_all_si, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (first_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let groups = SetOfGroup::from(groups);
+        Ok(GetCompatMapRequest {
+            device_spec,
+            groups,
+            get_all_si,
+            first_si,
+            n_si,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `groups` field of this structure.
+    pub fn groups<I>(mut self, value: I) -> Self where I: Into<SetOfGroup> {
+        self.groups = value.into();
+        self
+    }
+    /// Set the `get_all_si` field of this structure.
+    pub fn get_all_si(mut self, value: bool) -> Self {
+        self.get_all_si = value;
+        self
+    }
+    /// Set the `first_si` field of this structure.
+    pub fn first_si(mut self, value: u16) -> Self {
+        self.first_si = value;
+        self
+    }
+    /// Set the `n_si` field of this structure.
+    pub fn n_si(mut self, value: u16) -> Self {
+        self.n_si = value;
+        self
+    }
+}
+
 pub fn get_compat_map<Conn, A>(conn: &Conn, device_spec: DeviceSpec, groups: A, get_all_si: bool, first_si: u16, n_si: u16) -> Result<Cookie<'_, Conn, GetCompatMapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u8>,
+    A: Into<SetOfGroup>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let groups: u8 = groups.into();
+    let groups: SetOfGroup = groups.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let groups_bytes = groups.serialize();
-    let get_all_si_bytes = get_all_si.serialize();
-    let first_si_bytes = first_si.serialize();
-    let n_si_bytes = n_si.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let groups_bytes = u8::from(groups).serialize_with_order(order);
+    let get_all_si_bytes = get_all_si.serialize_with_order(order);
+    let first_si_bytes = first_si.serialize_with_order(order);
+    let n_si_bytes = n_si.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_COMPAT_MAP_REQUEST,
@@ -7665,9 +7856,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetCompatMapRequest {
+    type Reply = GetCompatMapReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetCompatMapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetCompatMapReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_compat_map(conn, self.device_spec, self.groups, self.get_all_si, self.first_si, self.n_si)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCompatMapReply {
@@ -7675,26 +7878,27 @@ pub struct GetCompatMapReply {
     pub device_id: u8,
     pub sequence: u16,
     pub length: u32,
-    pub groups_rtrn: u8,
+    pub groups_rtrn: SetOfGroup,
     pub first_si_rtrn: u16,
     pub n_total_si: u16,
     pub si_rtrn: Vec<SymInterpret>,
     pub group_rtrn: Vec<ModDef>,
 }
 impl TryParse for GetCompatMapReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (groups_rtrn, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (groups_rtrn, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (first_si_rtrn, remaining) = u16::try_parse(remaining)?;
-        let (n_si_rtrn, remaining) = u16::try_parse(remaining)?;
-        let (n_total_si, remaining) = u16::try_parse(remaining)?;
+        let (first_si_rtrn, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si_rtrn, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_total_si, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (si_rtrn, remaining) = crate::x11_utils::parse_list::<SymInterpret>(remaining, n_si_rtrn.try_into().or(Err(ParseError::ParseError))?)?;
-        let (group_rtrn, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, groups_rtrn.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+        let (si_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SymInterpret>(remaining, n_si_rtrn.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (group_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<ModDef>(remaining, u8::from(groups_rtrn).count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
+        let groups_rtrn = SetOfGroup::from(groups_rtrn);
         let result = GetCompatMapReply { response_type, device_id, sequence, length, groups_rtrn, first_si_rtrn, n_total_si, si_rtrn, group_rtrn };
         Ok((result, remaining))
     }
@@ -7705,25 +7909,135 @@ impl TryFrom<&[u8]> for GetCompatMapReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetCompatMapReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u8::from(self.groups_rtrn).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.first_si_rtrn.serialize_into_with_order(bytes, order);
+        let n_si_rtrn = u16::try_from(self.si_rtrn.len()).expect("`si_rtrn` has too many elements");
+        n_si_rtrn.serialize_into_with_order(bytes, order);
+        self.n_total_si.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.si_rtrn.serialize_into_with_order(bytes, order);
+        assert_eq!(self.group_rtrn.len(), usize::try_from(u8::from(self.groups_rtrn).count_ones()).unwrap(), "`group_rtrn` has an incorrect length");
+        self.group_rtrn.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetCompatMap request
 pub const SET_COMPAT_MAP_REQUEST: u8 = 11;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetCompatMapRequest {
+    pub device_spec: DeviceSpec,
+    pub recompute_actions: bool,
+    pub truncate_si: bool,
+    pub groups: SetOfGroup,
+    pub first_si: u16,
+    pub si: Vec<SymInterpret>,
+    pub group_maps: Vec<ModDef>,
+}
+impl SetCompatMapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_COMPAT_MAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (recompute_actions, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (truncate_si, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (si, remaining) = crate::x11_utils::parse_list_with_order::<SymInterpret>(remaining, n_si.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (group_maps, remaining) = crate::x11_utils::parse_list_with_order::<ModDef>(remaining, u8::from(groups).count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let groups = SetOfGroup::from(groups);
+        Ok(SetCompatMapRequest {
+            device_spec,
+            recompute_actions,
+            truncate_si,
+            groups,
+            first_si,
+            si,
+            group_maps,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `recompute_actions` field of this structure.
+    pub fn recompute_actions(mut self, value: bool) -> Self {
+        self.recompute_actions = value;
+        self
+    }
+    /// Set the `truncate_si` field of this structure.
+    pub fn truncate_si(mut self, value: bool) -> Self {
+        self.truncate_si = value;
+        self
+    }
+    /// Set the `groups` field of this structure.
+    pub fn groups<I>(mut self, value: I) -> Self where I: Into<SetOfGroup> {
+        self.groups = value.into();
+        self
+    }
+    /// Set the `first_si` field of this structure.
+    pub fn first_si(mut self, value: u16) -> Self {
+        self.first_si = value;
+        self
+    }
+    /// Set the `si` field of this structure.
+    pub fn si<I>(mut self, value: I) -> Self where I: Into<Vec<SymInterpret>> {
+        self.si = value.into();
+        self
+    }
+    /// Set the `group_maps` field of this structure.
+    pub fn group_maps<I>(mut self, value: I) -> Self where I: Into<Vec<ModDef>> {
+        self.group_maps = value.into();
+        self
+    }
+}
+
 pub fn set_compat_map<'c, Conn, A>(conn: &'c Conn, device_spec: DeviceSpec, recompute_actions: bool, truncate_si: bool, groups: A, first_si: u16, si: &[SymInterpret], group_maps: &[ModDef]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u8>,
+    A: Into<SetOfGroup>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let groups: u8 = groups.into();
+    let groups: SetOfGroup = groups.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let recompute_actions_bytes = recompute_actions.serialize();
-    let truncate_si_bytes = truncate_si.serialize();
-    let groups_bytes = groups.serialize();
-    let first_si_bytes = first_si.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let recompute_actions_bytes = recompute_actions.serialize_with_order(order);
+    let truncate_si_bytes = truncate_si.serialize_with_order(order);
+    let groups_bytes = u8::from(groups).serialize_with_order(order);
+    let first_si_bytes = first_si.serialize_with_order(order);
     let n_si = u16::try_from(si.len()).expect("`si` has too many elements");
-    let n_si_bytes = n_si.serialize();
+    let n_si_bytes = n_si.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_COMPAT_MAP_REQUEST,
@@ -7743,29 +8057,68 @@ where
         0,
     ];
     let length_so_far = length_so_far + request0.len();
-    let si_bytes = si.serialize();
+    let si_bytes = si.serialize_with_order(order);
     let length_so_far = length_so_far + si_bytes.len();
-    assert_eq!(group_maps.len(), usize::try_from(groups.count_ones()).unwrap(), "`group_maps` has an incorrect length");
-    let group_maps_bytes = group_maps.serialize();
+    assert_eq!(group_maps.len(), usize::try_from(u8::from(groups).count_ones()).unwrap(), "`group_maps` has an incorrect length");
+    let group_maps_bytes = group_maps.serialize_with_order(order);
     let length_so_far = length_so_far + group_maps_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&si_bytes), IoSlice::new(&group_maps_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetCompatMapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetCompatMapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_compat_map(conn, self.device_spec, self.recompute_actions, self.truncate_si, self.groups, self.first_si, &self.si, &self.group_maps)
+    }
+}
 
 /// Opcode for the GetIndicatorState request
 pub const GET_INDICATOR_STATE_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetIndicatorStateRequest {
+    pub device_spec: DeviceSpec,
+}
+impl GetIndicatorStateRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_INDICATOR_STATE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetIndicatorStateRequest {
+            device_spec,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+}
+
 pub fn get_indicator_state<Conn>(conn: &Conn, device_spec: DeviceSpec) -> Result<Cookie<'_, Conn, GetIndicatorStateReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_INDICATOR_STATE_REQUEST,
@@ -7779,9 +8132,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetIndicatorStateRequest {
+    type Reply = GetIndicatorStateReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetIndicatorStateRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetIndicatorStateReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_indicator_state(conn, self.device_spec)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetIndicatorStateReply {
@@ -7792,12 +8157,12 @@ pub struct GetIndicatorStateReply {
     pub state: u32,
 }
 impl TryParse for GetIndicatorStateReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (state, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let result = GetIndicatorStateReply { response_type, device_id, sequence, length, state };
         Ok((result, remaining))
@@ -7809,18 +8174,77 @@ impl TryFrom<&[u8]> for GetIndicatorStateReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetIndicatorStateReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.state.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetIndicatorMap request
 pub const GET_INDICATOR_MAP_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetIndicatorMapRequest {
+    pub device_spec: DeviceSpec,
+    pub which: u32,
+}
+impl GetIndicatorMapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_INDICATOR_MAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetIndicatorMapRequest {
+            device_spec,
+            which,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `which` field of this structure.
+    pub fn which(mut self, value: u32) -> Self {
+        self.which = value;
+        self
+    }
+}
+
 pub fn get_indicator_map<Conn>(conn: &Conn, device_spec: DeviceSpec, which: u32) -> Result<Cookie<'_, Conn, GetIndicatorMapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let which_bytes = which.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let which_bytes = which.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_INDICATOR_MAP_REQUEST,
@@ -7838,9 +8262,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetIndicatorMapRequest {
+    type Reply = GetIndicatorMapReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetIndicatorMapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetIndicatorMapReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_indicator_map(conn, self.device_spec, self.which)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetIndicatorMapReply {
@@ -7854,16 +8290,16 @@ pub struct GetIndicatorMapReply {
     pub maps: Vec<IndicatorMap>,
 }
 impl TryParse for GetIndicatorMapReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (real_indicators, remaining) = u32::try_parse(remaining)?;
-        let (n_indicators, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (real_indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_indicators, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(15..).ok_or(ParseError::ParseError)?;
-        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, which.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+        let (maps, remaining) = crate::x11_utils::parse_list_with_order::<IndicatorMap>(remaining, which.count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetIndicatorMapReply { response_type, device_id, sequence, length, which, real_indicators, n_indicators, maps };
         Ok((result, remaining))
     }
@@ -7874,18 +8310,89 @@ impl TryFrom<&[u8]> for GetIndicatorMapReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetIndicatorMapReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.which.serialize_into_with_order(bytes, order);
+        self.real_indicators.serialize_into_with_order(bytes, order);
+        self.n_indicators.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 15]);
+        assert_eq!(self.maps.len(), usize::try_from(self.which.count_ones()).unwrap(), "`maps` has an incorrect length");
+        self.maps.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetIndicatorMap request
 pub const SET_INDICATOR_MAP_REQUEST: u8 = 14;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetIndicatorMapRequest {
+    pub device_spec: DeviceSpec,
+    pub which: u32,
+    pub maps: Vec<IndicatorMap>,
+}
+impl SetIndicatorMapRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_INDICATOR_MAP_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (maps, remaining) = crate::x11_utils::parse_list_with_order::<IndicatorMap>(remaining, which.count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetIndicatorMapRequest {
+            device_spec,
+            which,
+            maps,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `which` field of this structure.
+    pub fn which(mut self, value: u32) -> Self {
+        self.which = value;
+        self
+    }
+    /// Set the `maps` field of this structure.
+    pub fn maps<I>(mut self, value: I) -> Self where I: Into<Vec<IndicatorMap>> {
+        self.maps = value.into();
+        self
+    }
+}
+
 pub fn set_indicator_map<'c, Conn>(conn: &'c Conn, device_spec: DeviceSpec, which: u32, maps: &[IndicatorMap]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let which_bytes = which.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let which_bytes = which.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_INDICATOR_MAP_REQUEST,
@@ -7902,31 +8409,97 @@ where
     ];
     let length_so_far = length_so_far + request0.len();
     assert_eq!(maps.len(), usize::try_from(which.count_ones()).unwrap(), "`maps` has an incorrect length");
-    let maps_bytes = maps.serialize();
+    let maps_bytes = maps.serialize_with_order(order);
     let length_so_far = length_so_far + maps_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&maps_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetIndicatorMapRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetIndicatorMapRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_indicator_map(conn, self.device_spec, self.which, &self.maps)
+    }
+}
 
 /// Opcode for the GetNamedIndicator request
 pub const GET_NAMED_INDICATOR_REQUEST: u8 = 15;
-pub fn get_named_indicator<Conn, A>(conn: &Conn, device_spec: DeviceSpec, led_class: LedClass, led_id: A, indicator: xproto::Atom) -> Result<Cookie<'_, Conn, GetNamedIndicatorReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetNamedIndicatorRequest {
+    pub device_spec: DeviceSpec,
+    pub led_class: LedClass,
+    pub led_id: IDSpec,
+    pub indicator: xproto::Atom,
+}
+impl GetNamedIndicatorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_NAMED_INDICATOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (led_class, remaining) = LedClassSpec::try_parse_with_order(remaining, order)?;
+        let (led_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (indicator, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let led_class = LedClass::from(led_class);
+        Ok(GetNamedIndicatorRequest {
+            device_spec,
+            led_class,
+            led_id,
+            indicator,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `led_class` field of this structure.
+    pub fn led_class<I>(mut self, value: I) -> Self where I: Into<LedClass> {
+        self.led_class = value.into();
+        self
+    }
+    /// Set the `led_id` field of this structure.
+    pub fn led_id<I>(mut self, value: I) -> Self where I: Into<IDSpec> {
+        self.led_id = value.into();
+        self
+    }
+    /// Set the `indicator` field of this structure.
+    pub fn indicator<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.indicator = value.into();
+        self
+    }
+}
+
+pub fn get_named_indicator<Conn, A, B>(conn: &Conn, device_spec: DeviceSpec, led_class: A, led_id: B, indicator: xproto::Atom) -> Result<Cookie<'_, Conn, GetNamedIndicatorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<IDSpec>,
+    A: Into<LedClass>,
+    B: Into<IDSpec>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let led_class: LedClass = led_class.into();
     let led_id: IDSpec = led_id.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let led_class_bytes = LedClassSpec::from(led_class).serialize();
-    let led_id_bytes = led_id.serialize();
-    let indicator_bytes = indicator.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let led_class_bytes = u16::from(led_class).serialize_with_order(order);
+    let led_id_bytes = led_id.serialize_with_order(order);
+    let indicator_bytes = indicator.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_NAMED_INDICATOR_REQUEST,
@@ -7948,9 +8521,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetNamedIndicatorRequest {
+    type Reply = GetNamedIndicatorReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetNamedIndicatorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetNamedIndicatorReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_named_indicator(conn, self.device_spec, self.led_class, self.led_id, self.indicator)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetNamedIndicatorReply {
@@ -7963,37 +8548,45 @@ pub struct GetNamedIndicatorReply {
     pub on: bool,
     pub real_indicator: bool,
     pub ndx: u8,
-    pub map_flags: u8,
-    pub map_which_groups: u8,
-    pub map_groups: u8,
-    pub map_which_mods: u8,
-    pub map_mods: u8,
-    pub map_real_mods: u8,
-    pub map_vmod: u16,
-    pub map_ctrls: u32,
+    pub map_flags: IMFlag,
+    pub map_which_groups: IMGroupsWhich,
+    pub map_groups: SetOfGroups,
+    pub map_which_mods: IMModsWhich,
+    pub map_mods: xproto::ModMask,
+    pub map_real_mods: xproto::ModMask,
+    pub map_vmod: VMod,
+    pub map_ctrls: BoolCtrl,
     pub supported: bool,
 }
 impl TryParse for GetNamedIndicatorReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (indicator, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (found, remaining) = bool::try_parse(remaining)?;
-        let (on, remaining) = bool::try_parse(remaining)?;
-        let (real_indicator, remaining) = bool::try_parse(remaining)?;
-        let (ndx, remaining) = u8::try_parse(remaining)?;
-        let (map_flags, remaining) = u8::try_parse(remaining)?;
-        let (map_which_groups, remaining) = u8::try_parse(remaining)?;
-        let (map_groups, remaining) = u8::try_parse(remaining)?;
-        let (map_which_mods, remaining) = u8::try_parse(remaining)?;
-        let (map_mods, remaining) = u8::try_parse(remaining)?;
-        let (map_real_mods, remaining) = u8::try_parse(remaining)?;
-        let (map_vmod, remaining) = u16::try_parse(remaining)?;
-        let (map_ctrls, remaining) = u32::try_parse(remaining)?;
-        let (supported, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (indicator, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (found, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (on, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (real_indicator, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_which_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_which_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_vmod, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let map_flags = IMFlag::from(map_flags);
+        let map_which_groups = IMGroupsWhich::from(map_which_groups);
+        let map_groups = SetOfGroups::from(map_groups);
+        let map_which_mods = IMModsWhich::from(map_which_mods);
+        let map_mods = xproto::ModMask::from(u16::from(map_mods));
+        let map_real_mods = xproto::ModMask::from(u16::from(map_real_mods));
+        let map_vmod = VMod::from(map_vmod);
+        let map_ctrls = BoolCtrl::from(map_ctrls);
         let result = GetNamedIndicatorReply { response_type, device_id, sequence, length, indicator, found, on, real_indicator, ndx, map_flags, map_which_groups, map_groups, map_which_mods, map_mods, map_real_mods, map_vmod, map_ctrls, supported };
         Ok((result, remaining))
     }
@@ -8004,47 +8597,234 @@ impl TryFrom<&[u8]> for GetNamedIndicatorReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetNamedIndicatorReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.indicator.serialize_into_with_order(bytes, order);
+        self.found.serialize_into_with_order(bytes, order);
+        self.on.serialize_into_with_order(bytes, order);
+        self.real_indicator.serialize_into_with_order(bytes, order);
+        self.ndx.serialize_into_with_order(bytes, order);
+        u8::from(self.map_flags).serialize_into_with_order(bytes, order);
+        u8::from(self.map_which_groups).serialize_into_with_order(bytes, order);
+        u8::from(self.map_groups).serialize_into_with_order(bytes, order);
+        u8::from(self.map_which_mods).serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.map_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u8::try_from(u16::from(self.map_real_mods)).unwrap().serialize_into_with_order(bytes, order);
+        u16::from(self.map_vmod).serialize_into_with_order(bytes, order);
+        u32::from(self.map_ctrls).serialize_into_with_order(bytes, order);
+        self.supported.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetNamedIndicator request
 pub const SET_NAMED_INDICATOR_REQUEST: u8 = 16;
-pub fn set_named_indicator<Conn, A, B, C, D, E, F, G, H>(conn: &Conn, device_spec: DeviceSpec, led_class: LedClass, led_id: A, indicator: xproto::Atom, set_state: bool, on: bool, set_map: bool, create_map: bool, map_flags: B, map_which_groups: C, map_groups: D, map_which_mods: E, map_real_mods: F, map_vmods: G, map_ctrls: H) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetNamedIndicatorRequest {
+    pub device_spec: DeviceSpec,
+    pub led_class: LedClass,
+    pub led_id: IDSpec,
+    pub indicator: xproto::Atom,
+    pub set_state: bool,
+    pub on: bool,
+    pub set_map: bool,
+    pub create_map: bool,
+    pub map_flags: IMFlag,
+    pub map_which_groups: IMGroupsWhich,
+    pub map_groups: SetOfGroups,
+    pub map_which_mods: IMModsWhich,
+    pub map_real_mods: xproto::ModMask,
+    pub map_vmods: VMod,
+    pub map_ctrls: BoolCtrl,
+}
+impl SetNamedIndicatorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_NAMED_INDICATOR_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (led_class, remaining) = LedClassSpec::try_parse_with_order(remaining, order)?;
+        let (led_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (indicator, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (set_state, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (on, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (set_map, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (create_map, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (map_flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_which_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_which_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let led_class = LedClass::from(led_class);
+        let map_flags = IMFlag::from(map_flags);
+        let map_which_groups = IMGroupsWhich::from(map_which_groups);
+        let map_groups = SetOfGroups::from(map_groups);
+        let map_which_mods = IMModsWhich::from(map_which_mods);
+        let map_real_mods = xproto::ModMask::from(u16::from(map_real_mods));
+        let map_vmods = VMod::from(map_vmods);
+        let map_ctrls = BoolCtrl::from(map_ctrls);
+        Ok(SetNamedIndicatorRequest {
+            device_spec,
+            led_class,
+            led_id,
+            indicator,
+            set_state,
+            on,
+            set_map,
+            create_map,
+            map_flags,
+            map_which_groups,
+            map_groups,
+            map_which_mods,
+            map_real_mods,
+            map_vmods,
+            map_ctrls,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `led_class` field of this structure.
+    pub fn led_class<I>(mut self, value: I) -> Self where I: Into<LedClass> {
+        self.led_class = value.into();
+        self
+    }
+    /// Set the `led_id` field of this structure.
+    pub fn led_id<I>(mut self, value: I) -> Self where I: Into<IDSpec> {
+        self.led_id = value.into();
+        self
+    }
+    /// Set the `indicator` field of this structure.
+    pub fn indicator<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.indicator = value.into();
+        self
+    }
+    /// Set the `set_state` field of this structure.
+    pub fn set_state(mut self, value: bool) -> Self {
+        self.set_state = value;
+        self
+    }
+    /// Set the `on` field of this structure.
+    pub fn on(mut self, value: bool) -> Self {
+        self.on = value;
+        self
+    }
+    /// Set the `set_map` field of this structure.
+    pub fn set_map(mut self, value: bool) -> Self {
+        self.set_map = value;
+        self
+    }
+    /// Set the `create_map` field of this structure.
+    pub fn create_map(mut self, value: bool) -> Self {
+        self.create_map = value;
+        self
+    }
+    /// Set the `map_flags` field of this structure.
+    pub fn map_flags<I>(mut self, value: I) -> Self where I: Into<IMFlag> {
+        self.map_flags = value.into();
+        self
+    }
+    /// Set the `map_which_groups` field of this structure.
+    pub fn map_which_groups<I>(mut self, value: I) -> Self where I: Into<IMGroupsWhich> {
+        self.map_which_groups = value.into();
+        self
+    }
+    /// Set the `map_groups` field of this structure.
+    pub fn map_groups<I>(mut self, value: I) -> Self where I: Into<SetOfGroups> {
+        self.map_groups = value.into();
+        self
+    }
+    /// Set the `map_which_mods` field of this structure.
+    pub fn map_which_mods<I>(mut self, value: I) -> Self where I: Into<IMModsWhich> {
+        self.map_which_mods = value.into();
+        self
+    }
+    /// Set the `map_real_mods` field of this structure.
+    pub fn map_real_mods<I>(mut self, value: I) -> Self where I: Into<xproto::ModMask> {
+        self.map_real_mods = value.into();
+        self
+    }
+    /// Set the `map_vmods` field of this structure.
+    pub fn map_vmods<I>(mut self, value: I) -> Self where I: Into<VMod> {
+        self.map_vmods = value.into();
+        self
+    }
+    /// Set the `map_ctrls` field of this structure.
+    pub fn map_ctrls<I>(mut self, value: I) -> Self where I: Into<BoolCtrl> {
+        self.map_ctrls = value.into();
+        self
+    }
+}
+
+pub fn set_named_indicator<Conn, A, B, C, D, E, F, G, H, I>(conn: &Conn, device_spec: DeviceSpec, led_class: A, led_id: B, indicator: xproto::Atom, set_state: bool, on: bool, set_map: bool, create_map: bool, map_flags: C, map_which_groups: D, map_groups: E, map_which_mods: F, map_real_mods: G, map_vmods: H, map_ctrls: I) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<IDSpec>,
-    B: Into<u8>,
-    C: Into<u8>,
-    D: Into<u8>,
-    E: Into<u8>,
-    F: Into<u8>,
-    G: Into<u16>,
-    H: Into<u32>,
+    A: Into<LedClass>,
+    B: Into<IDSpec>,
+    C: Into<IMFlag>,
+    D: Into<IMGroupsWhich>,
+    E: Into<SetOfGroups>,
+    F: Into<IMModsWhich>,
+    G: Into<xproto::ModMask>,
+    H: Into<VMod>,
+    I: Into<BoolCtrl>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let led_class: LedClass = led_class.into();
     let led_id: IDSpec = led_id.into();
-    let map_flags: u8 = map_flags.into();
-    let map_which_groups: u8 = map_which_groups.into();
-    let map_groups: u8 = map_groups.into();
-    let map_which_mods: u8 = map_which_mods.into();
-    let map_real_mods: u8 = map_real_mods.into();
-    let map_vmods: u16 = map_vmods.into();
-    let map_ctrls: u32 = map_ctrls.into();
+    let map_flags: IMFlag = map_flags.into();
+    let map_which_groups: IMGroupsWhich = map_which_groups.into();
+    let map_groups: SetOfGroups = map_groups.into();
+    let map_which_mods: IMModsWhich = map_which_mods.into();
+    let map_real_mods: xproto::ModMask = map_real_mods.into();
+    let map_vmods: VMod = map_vmods.into();
+    let map_ctrls: BoolCtrl = map_ctrls.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let led_class_bytes = LedClassSpec::from(led_class).serialize();
-    let led_id_bytes = led_id.serialize();
-    let indicator_bytes = indicator.serialize();
-    let set_state_bytes = set_state.serialize();
-    let on_bytes = on.serialize();
-    let set_map_bytes = set_map.serialize();
-    let create_map_bytes = create_map.serialize();
-    let map_flags_bytes = map_flags.serialize();
-    let map_which_groups_bytes = map_which_groups.serialize();
-    let map_groups_bytes = map_groups.serialize();
-    let map_which_mods_bytes = map_which_mods.serialize();
-    let map_real_mods_bytes = map_real_mods.serialize();
-    let map_vmods_bytes = map_vmods.serialize();
-    let map_ctrls_bytes = map_ctrls.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let led_class_bytes = u16::from(led_class).serialize_with_order(order);
+    let led_id_bytes = led_id.serialize_with_order(order);
+    let indicator_bytes = indicator.serialize_with_order(order);
+    let set_state_bytes = set_state.serialize_with_order(order);
+    let on_bytes = on.serialize_with_order(order);
+    let set_map_bytes = set_map.serialize_with_order(order);
+    let create_map_bytes = create_map.serialize_with_order(order);
+    let map_flags_bytes = u8::from(map_flags).serialize_with_order(order);
+    let map_which_groups_bytes = u8::from(map_which_groups).serialize_with_order(order);
+    let map_groups_bytes = u8::from(map_groups).serialize_with_order(order);
+    let map_which_mods_bytes = u8::from(map_which_mods).serialize_with_order(order);
+    let map_real_mods_bytes = u8::try_from(u16::from(map_real_mods)).unwrap().serialize_with_order(order);
+    let map_vmods_bytes = u16::from(map_vmods).serialize_with_order(order);
+    let map_ctrls_bytes = u32::from(map_ctrls).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_NAMED_INDICATOR_REQUEST,
@@ -8082,23 +8862,71 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetNamedIndicatorRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetNamedIndicatorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_named_indicator(conn, self.device_spec, self.led_class, self.led_id, self.indicator, self.set_state, self.on, self.set_map, self.create_map, self.map_flags, self.map_which_groups, self.map_groups, self.map_which_mods, self.map_real_mods, self.map_vmods, self.map_ctrls)
+    }
+}
 
 /// Opcode for the GetNames request
 pub const GET_NAMES_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetNamesRequest {
+    pub device_spec: DeviceSpec,
+    pub which: NameDetail,
+}
+impl GetNamesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_NAMES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let which = NameDetail::from(which);
+        Ok(GetNamesRequest {
+            device_spec,
+            which,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `which` field of this structure.
+    pub fn which<I>(mut self, value: I) -> Self where I: Into<NameDetail> {
+        self.which = value.into();
+        self
+    }
+}
+
 pub fn get_names<Conn, A>(conn: &Conn, device_spec: DeviceSpec, which: A) -> Result<Cookie<'_, Conn, GetNamesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
+    A: Into<NameDetail>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let which: u32 = which.into();
+    let which: NameDetail = which.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let which_bytes = which.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let which_bytes = u32::from(which).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_NAMES_REQUEST,
@@ -8116,9 +8944,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetNamesRequest {
+    type Reply = GetNamesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetNamesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetNamesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_names(conn, self.device_spec, self.which)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetNamesValueListBitcase8 {
@@ -8126,7 +8966,7 @@ pub struct GetNamesValueListBitcase8 {
     pub kt_level_names: Vec<xproto::Atom>,
 }
 impl GetNamesValueListBitcase8 {
-    pub fn try_parse(remaining: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
+    pub fn try_parse_with_order(remaining: &[u8], order: ByteOrder, n_types: u8) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_into().or(Err(ParseError::ParseError))?)?;
         let n_levels_per_type = n_levels_per_type.to_vec();
@@ -8134,12 +8974,27 @@ impl GetNamesValueListBitcase8 {
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, &x| acc.checked_add(u32::from(x)).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (kt_level_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, &x| acc.checked_add(u32::from(x)).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetNamesValueListBitcase8 { n_levels_per_type, kt_level_names };
         Ok((result, remaining))
     }
 }
-// Skipping TryFrom implementations because of unresolved members
+// Skipping TryFrom implementations because of unresolved members
+#[allow(dead_code, unused_variables)]
+impl GetNamesValueListBitcase8 {
+    fn serialize_with_order(&self, order: ByteOrder, n_types: u8) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, n_types);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, n_types: u8) {
+        assert_eq!(self.n_levels_per_type.len(), usize::try_from(n_types).unwrap(), "`n_levels_per_type` has an incorrect length");
+        bytes.extend_from_slice(&self.n_levels_per_type);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.kt_level_names.len(), usize::try_from(self.n_levels_per_type.iter().fold(0u32, |acc, &x| acc.checked_add(u32::from(x)).unwrap())).unwrap(), "`kt_level_names` has an incorrect length");
+        self.kt_level_names.serialize_into_with_order(bytes, order);
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct GetNamesValueList {
     pub keycodes_name: Option<xproto::Atom>,
@@ -8158,12 +9013,12 @@ pub struct GetNamesValueList {
     pub radio_group_names: Option<Vec<xproto::Atom>>,
 }
 impl GetNamesValueList {
-    fn try_parse(value: &[u8], which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Result<(Self, &[u8]), ParseError> {
-        let switch_expr = which;
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, which: NameDetail, n_types: u8, indicators: u32, virtual_mods: VMod, group_names: SetOfGroup, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(which);
         let mut outer_remaining = value;
         let keycodes_name = if switch_expr & u32::from(NameDetail::Keycodes) != 0 {
             let remaining = outer_remaining;
-            let (keycodes_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (keycodes_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(keycodes_name)
         } else {
@@ -8171,7 +9026,7 @@ impl GetNamesValueList {
         };
         let geometry_name = if switch_expr & u32::from(NameDetail::Geometry) != 0 {
             let remaining = outer_remaining;
-            let (geometry_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (geometry_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(geometry_name)
         } else {
@@ -8179,7 +9034,7 @@ impl GetNamesValueList {
         };
         let symbols_name = if switch_expr & u32::from(NameDetail::Symbols) != 0 {
             let remaining = outer_remaining;
-            let (symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(symbols_name)
         } else {
@@ -8187,7 +9042,7 @@ impl GetNamesValueList {
         };
         let phys_symbols_name = if switch_expr & u32::from(NameDetail::PhysSymbols) != 0 {
             let remaining = outer_remaining;
-            let (phys_symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (phys_symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(phys_symbols_name)
         } else {
@@ -8195,7 +9050,7 @@ impl GetNamesValueList {
         };
         let types_name = if switch_expr & u32::from(NameDetail::Types) != 0 {
             let remaining = outer_remaining;
-            let (types_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (types_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(types_name)
         } else {
@@ -8203,7 +9058,7 @@ impl GetNamesValueList {
         };
         let compat_name = if switch_expr & u32::from(NameDetail::Compat) != 0 {
             let remaining = outer_remaining;
-            let (compat_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (compat_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(compat_name)
         } else {
@@ -8211,14 +9066,14 @@ impl GetNamesValueList {
         };
         let type_names = if switch_expr & u32::from(NameDetail::KeyTypeNames) != 0 {
             let remaining = outer_remaining;
-            let (type_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?)?;
+            let (type_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(type_names)
         } else {
             None
         };
         let bitcase8 = if switch_expr & u32::from(NameDetail::KTLevelNames) != 0 {
-            let (bitcase8, new_remaining) = GetNamesValueListBitcase8::try_parse(outer_remaining, n_types)?;
+            let (bitcase8, new_remaining) = GetNamesValueListBitcase8::try_parse_with_order(outer_remaining, order, n_types)?;
             outer_remaining = new_remaining;
             Some(bitcase8)
         } else {
@@ -8226,7 +9081,7 @@ impl GetNamesValueList {
         };
         let indicator_names = if switch_expr & u32::from(NameDetail::IndicatorNames) != 0 {
             let remaining = outer_remaining;
-            let (indicator_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, indicators.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+            let (indicator_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, indicators.count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(indicator_names)
         } else {
@@ -8234,7 +9089,7 @@ impl GetNamesValueList {
         };
         let virtual_mod_names = if switch_expr & u32::from(NameDetail::VirtualModNames) != 0 {
             let remaining = outer_remaining;
-            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, u16::from(virtual_mods).count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(virtual_mod_names)
         } else {
@@ -8242,7 +9097,7 @@ impl GetNamesValueList {
         };
         let groups = if switch_expr & u32::from(NameDetail::GroupNames) != 0 {
             let remaining = outer_remaining;
-            let (groups, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, group_names.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+            let (groups, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, u8::from(group_names).count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(groups)
         } else {
@@ -8250,7 +9105,7 @@ impl GetNamesValueList {
         };
         let key_names = if switch_expr & u32::from(NameDetail::KeyNames) != 0 {
             let remaining = outer_remaining;
-            let (key_names, remaining) = crate::x11_utils::parse_list::<KeyName>(remaining, n_keys.try_into().or(Err(ParseError::ParseError))?)?;
+            let (key_names, remaining) = crate::x11_utils::parse_list_with_order::<KeyName>(remaining, n_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(key_names)
         } else {
@@ -8258,7 +9113,7 @@ impl GetNamesValueList {
         };
         let key_aliases = if switch_expr & u32::from(NameDetail::KeyAliases) != 0 {
             let remaining = outer_remaining;
-            let (key_aliases, remaining) = crate::x11_utils::parse_list::<KeyAlias>(remaining, n_key_aliases.try_into().or(Err(ParseError::ParseError))?)?;
+            let (key_aliases, remaining) = crate::x11_utils::parse_list_with_order::<KeyAlias>(remaining, n_key_aliases.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(key_aliases)
         } else {
@@ -8266,7 +9121,7 @@ impl GetNamesValueList {
         };
         let radio_group_names = if switch_expr & u32::from(NameDetail::RGNames) != 0 {
             let remaining = outer_remaining;
-            let (radio_group_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_radio_groups.try_into().or(Err(ParseError::ParseError))?)?;
+            let (radio_group_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_radio_groups.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(radio_group_names)
         } else {
@@ -8276,6 +9131,113 @@ impl GetNamesValueList {
         Ok((result, outer_remaining))
     }
 }
+#[allow(dead_code, unused_variables)]
+impl GetNamesValueList {
+    fn serialize_with_order(&self, order: ByteOrder, which: NameDetail, n_types: u8, indicators: u32, virtual_mods: VMod, group_names: SetOfGroup, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, which: NameDetail, n_types: u8, indicators: u32, virtual_mods: VMod, group_names: SetOfGroup, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) {
+        if let Some(keycodes_name) = self.keycodes_name {
+            keycodes_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(geometry_name) = self.geometry_name {
+            geometry_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(symbols_name) = self.symbols_name {
+            symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(phys_symbols_name) = self.phys_symbols_name {
+            phys_symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(types_name) = self.types_name {
+            types_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(compat_name) = self.compat_name {
+            compat_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref type_names) = self.type_names {
+            assert_eq!(type_names.len(), usize::try_from(n_types).unwrap(), "`type_names` has an incorrect length");
+            type_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase8) = self.bitcase8 {
+            bitcase8.serialize_into_with_order(bytes, order, n_types);
+        }
+        if let Some(ref indicator_names) = self.indicator_names {
+            assert_eq!(indicator_names.len(), usize::try_from(indicators.count_ones()).unwrap(), "`indicator_names` has an incorrect length");
+            indicator_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref virtual_mod_names) = self.virtual_mod_names {
+            assert_eq!(virtual_mod_names.len(), usize::try_from(u16::from(virtual_mods).count_ones()).unwrap(), "`virtual_mod_names` has an incorrect length");
+            virtual_mod_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref groups) = self.groups {
+            assert_eq!(groups.len(), usize::try_from(u8::from(group_names).count_ones()).unwrap(), "`groups` has an incorrect length");
+            groups.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_names) = self.key_names {
+            assert_eq!(key_names.len(), usize::try_from(n_keys).unwrap(), "`key_names` has an incorrect length");
+            key_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_aliases) = self.key_aliases {
+            assert_eq!(key_aliases.len(), usize::try_from(n_key_aliases).unwrap(), "`key_aliases` has an incorrect length");
+            key_aliases.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref radio_group_names) = self.radio_group_names {
+            assert_eq!(radio_group_names.len(), usize::try_from(n_radio_groups).unwrap(), "`radio_group_names` has an incorrect length");
+            radio_group_names.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl GetNamesValueList {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.keycodes_name.is_some() {
+            expr_value |= u32::from(NameDetail::Keycodes);
+        }
+        if self.geometry_name.is_some() {
+            expr_value |= u32::from(NameDetail::Geometry);
+        }
+        if self.symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::Symbols);
+        }
+        if self.phys_symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::PhysSymbols);
+        }
+        if self.types_name.is_some() {
+            expr_value |= u32::from(NameDetail::Types);
+        }
+        if self.compat_name.is_some() {
+            expr_value |= u32::from(NameDetail::Compat);
+        }
+        if self.type_names.is_some() {
+            expr_value |= u32::from(NameDetail::KeyTypeNames);
+        }
+        if self.bitcase8.is_some() {
+            expr_value |= u32::from(NameDetail::KTLevelNames);
+        }
+        if self.indicator_names.is_some() {
+            expr_value |= u32::from(NameDetail::IndicatorNames);
+        }
+        if self.virtual_mod_names.is_some() {
+            expr_value |= u32::from(NameDetail::VirtualModNames);
+        }
+        if self.groups.is_some() {
+            expr_value |= u32::from(NameDetail::GroupNames);
+        }
+        if self.key_names.is_some() {
+            expr_value |= u32::from(NameDetail::KeyNames);
+        }
+        if self.key_aliases.is_some() {
+            expr_value |= u32::from(NameDetail::KeyAliases);
+        }
+        if self.radio_group_names.is_some() {
+            expr_value |= u32::from(NameDetail::RGNames);
+        }
+        expr_value
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetNamesReply {
@@ -8286,8 +9248,8 @@ pub struct GetNamesReply {
     pub min_key_code: xproto::Keycode,
     pub max_key_code: xproto::Keycode,
     pub n_types: u8,
-    pub group_names: u8,
-    pub virtual_mods: u16,
+    pub group_names: SetOfGroup,
+    pub virtual_mods: VMod,
     pub first_key: xproto::Keycode,
     pub n_keys: u8,
     pub indicators: u32,
@@ -8297,25 +9259,27 @@ pub struct GetNamesReply {
     pub value_list: GetNamesValueList,
 }
 impl TryParse for GetNamesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (group_names, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (first_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let (indicators, remaining) = u32::try_parse(remaining)?;
-        let (n_radio_groups, remaining) = u8::try_parse(remaining)?;
-        let (n_key_aliases, remaining) = u8::try_parse(remaining)?;
-        let (n_kt_levels, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group_names, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_radio_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_kt_levels, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (value_list, remaining) = GetNamesValueList::try_parse(remaining, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups)?;
+        let (value_list, remaining) = GetNamesValueList::try_parse_with_order(remaining, order, NameDetail::from(which), n_types, indicators, VMod::from(virtual_mods), SetOfGroup::from(group_names), n_keys, n_key_aliases, n_radio_groups)?;
+        let group_names = SetOfGroup::from(group_names);
+        let virtual_mods = VMod::from(virtual_mods);
         let result = GetNamesReply { response_type, device_id, sequence, length, min_key_code, max_key_code, n_types, group_names, virtual_mods, first_key, n_keys, indicators, n_radio_groups, n_key_aliases, n_kt_levels, value_list };
         Ok((result, remaining))
     }
@@ -8326,6 +9290,50 @@ impl TryFrom<&[u8]> for GetNamesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetNamesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        let n_types = self.n_types;
+        let indicators = self.indicators;
+        let virtual_mods = self.virtual_mods;
+        let group_names = self.group_names;
+        let n_keys = self.n_keys;
+        let n_key_aliases = self.n_key_aliases;
+        let n_radio_groups = self.n_radio_groups;
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let which = u32::try_from(self.value_list.switch_expr()).unwrap();
+        which.serialize_into_with_order(bytes, order);
+        self.min_key_code.serialize_into_with_order(bytes, order);
+        self.max_key_code.serialize_into_with_order(bytes, order);
+        self.n_types.serialize_into_with_order(bytes, order);
+        u8::from(self.group_names).serialize_into_with_order(bytes, order);
+        u16::from(self.virtual_mods).serialize_into_with_order(bytes, order);
+        self.first_key.serialize_into_with_order(bytes, order);
+        self.n_keys.serialize_into_with_order(bytes, order);
+        self.indicators.serialize_into_with_order(bytes, order);
+        self.n_radio_groups.serialize_into_with_order(bytes, order);
+        self.n_key_aliases.serialize_into_with_order(bytes, order);
+        self.n_kt_levels.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.value_list.serialize_into_with_order(bytes, order, NameDetail::from(which), n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetNames request
 pub const SET_NAMES_REQUEST: u8 = 18;
@@ -8334,19 +9342,34 @@ pub struct SetNamesAuxBitcase8 {
     pub n_levels_per_type: Vec<u8>,
     pub kt_level_names: Vec<xproto::Atom>,
 }
+impl SetNamesAuxBitcase8 {
+    pub fn try_parse_with_order(remaining: &[u8], order: ByteOrder, n_types: u8) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_into().or(Err(ParseError::ParseError))?)?;
+        let n_levels_per_type = n_levels_per_type.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+        let (kt_level_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, &x| acc.checked_add(u32::from(x)).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let result = SetNamesAuxBitcase8 { n_levels_per_type, kt_level_names };
+        Ok((result, remaining))
+    }
+}
+// Skipping TryFrom implementations because of unresolved members
 #[allow(dead_code, unused_variables)]
 impl SetNamesAuxBitcase8 {
-    fn serialize(&self, n_types: u8) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, n_types: u8) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, n_types);
+        self.serialize_into_with_order(&mut result, order, n_types);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, n_types: u8) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, n_types: u8) {
         assert_eq!(self.n_levels_per_type.len(), usize::try_from(n_types).unwrap(), "`n_levels_per_type` has an incorrect length");
         bytes.extend_from_slice(&self.n_levels_per_type);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
         assert_eq!(self.kt_level_names.len(), usize::try_from(self.n_levels_per_type.iter().fold(0u32, |acc, &x| acc.checked_add(u32::from(x)).unwrap())).unwrap(), "`kt_level_names` has an incorrect length");
-        self.kt_level_names.serialize_into(bytes);
+        self.kt_level_names.serialize_into_with_order(bytes, order);
     }
 }
 /// Auxiliary and optional information for the `set_names` function
@@ -8367,62 +9390,181 @@ pub struct SetNamesAux {
     pub key_aliases: Option<Vec<KeyAlias>>,
     pub radio_group_names: Option<Vec<xproto::Atom>>,
 }
+impl SetNamesAux {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, which: NameDetail, n_types: u8, indicators: u32, virtual_mods: VMod, group_names: SetOfGroup, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(which);
+        let mut outer_remaining = value;
+        let keycodes_name = if switch_expr & u32::from(NameDetail::Keycodes) != 0 {
+            let remaining = outer_remaining;
+            let (keycodes_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(keycodes_name)
+        } else {
+            None
+        };
+        let geometry_name = if switch_expr & u32::from(NameDetail::Geometry) != 0 {
+            let remaining = outer_remaining;
+            let (geometry_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(geometry_name)
+        } else {
+            None
+        };
+        let symbols_name = if switch_expr & u32::from(NameDetail::Symbols) != 0 {
+            let remaining = outer_remaining;
+            let (symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(symbols_name)
+        } else {
+            None
+        };
+        let phys_symbols_name = if switch_expr & u32::from(NameDetail::PhysSymbols) != 0 {
+            let remaining = outer_remaining;
+            let (phys_symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(phys_symbols_name)
+        } else {
+            None
+        };
+        let types_name = if switch_expr & u32::from(NameDetail::Types) != 0 {
+            let remaining = outer_remaining;
+            let (types_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(types_name)
+        } else {
+            None
+        };
+        let compat_name = if switch_expr & u32::from(NameDetail::Compat) != 0 {
+            let remaining = outer_remaining;
+            let (compat_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(compat_name)
+        } else {
+            None
+        };
+        let type_names = if switch_expr & u32::from(NameDetail::KeyTypeNames) != 0 {
+            let remaining = outer_remaining;
+            let (type_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(type_names)
+        } else {
+            None
+        };
+        let bitcase8 = if switch_expr & u32::from(NameDetail::KTLevelNames) != 0 {
+            let (bitcase8, new_remaining) = SetNamesAuxBitcase8::try_parse_with_order(outer_remaining, order, n_types)?;
+            outer_remaining = new_remaining;
+            Some(bitcase8)
+        } else {
+            None
+        };
+        let indicator_names = if switch_expr & u32::from(NameDetail::IndicatorNames) != 0 {
+            let remaining = outer_remaining;
+            let (indicator_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, indicators.count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(indicator_names)
+        } else {
+            None
+        };
+        let virtual_mod_names = if switch_expr & u32::from(NameDetail::VirtualModNames) != 0 {
+            let remaining = outer_remaining;
+            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, u16::from(virtual_mods).count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(virtual_mod_names)
+        } else {
+            None
+        };
+        let groups = if switch_expr & u32::from(NameDetail::GroupNames) != 0 {
+            let remaining = outer_remaining;
+            let (groups, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, u8::from(group_names).count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(groups)
+        } else {
+            None
+        };
+        let key_names = if switch_expr & u32::from(NameDetail::KeyNames) != 0 {
+            let remaining = outer_remaining;
+            let (key_names, remaining) = crate::x11_utils::parse_list_with_order::<KeyName>(remaining, n_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(key_names)
+        } else {
+            None
+        };
+        let key_aliases = if switch_expr & u32::from(NameDetail::KeyAliases) != 0 {
+            let remaining = outer_remaining;
+            let (key_aliases, remaining) = crate::x11_utils::parse_list_with_order::<KeyAlias>(remaining, n_key_aliases.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(key_aliases)
+        } else {
+            None
+        };
+        let radio_group_names = if switch_expr & u32::from(NameDetail::RGNames) != 0 {
+            let remaining = outer_remaining;
+            let (radio_group_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_radio_groups.try_into().or(Err(ParseError::ParseError))?, order)?;
+            outer_remaining = remaining;
+            Some(radio_group_names)
+        } else {
+            None
+        };
+        let result = SetNamesAux { keycodes_name, geometry_name, symbols_name, phys_symbols_name, types_name, compat_name, type_names, bitcase8, indicator_names, virtual_mod_names, groups, key_names, key_aliases, radio_group_names };
+        Ok((result, outer_remaining))
+    }
+}
 #[allow(dead_code, unused_variables)]
 impl SetNamesAux {
-    fn serialize(&self, which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder, which: NameDetail, n_types: u8, indicators: u32, virtual_mods: VMod, group_names: SetOfGroup, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups);
+        self.serialize_into_with_order(&mut result, order, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>, which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, which: NameDetail, n_types: u8, indicators: u32, virtual_mods: VMod, group_names: SetOfGroup, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) {
         if let Some(keycodes_name) = self.keycodes_name {
-            keycodes_name.serialize_into(bytes);
+            keycodes_name.serialize_into_with_order(bytes, order);
         }
         if let Some(geometry_name) = self.geometry_name {
-            geometry_name.serialize_into(bytes);
+            geometry_name.serialize_into_with_order(bytes, order);
         }
         if let Some(symbols_name) = self.symbols_name {
-            symbols_name.serialize_into(bytes);
+            symbols_name.serialize_into_with_order(bytes, order);
         }
         if let Some(phys_symbols_name) = self.phys_symbols_name {
-            phys_symbols_name.serialize_into(bytes);
+            phys_symbols_name.serialize_into_with_order(bytes, order);
         }
         if let Some(types_name) = self.types_name {
-            types_name.serialize_into(bytes);
+            types_name.serialize_into_with_order(bytes, order);
         }
         if let Some(compat_name) = self.compat_name {
-            compat_name.serialize_into(bytes);
+            compat_name.serialize_into_with_order(bytes, order);
         }
         if let Some(ref type_names) = self.type_names {
             assert_eq!(type_names.len(), usize::try_from(n_types).unwrap(), "`type_names` has an incorrect length");
-            type_names.serialize_into(bytes);
+            type_names.serialize_into_with_order(bytes, order);
         }
         if let Some(ref bitcase8) = self.bitcase8 {
-            bitcase8.serialize_into(bytes, n_types);
+            bitcase8.serialize_into_with_order(bytes, order, n_types);
         }
         if let Some(ref indicator_names) = self.indicator_names {
             assert_eq!(indicator_names.len(), usize::try_from(indicators.count_ones()).unwrap(), "`indicator_names` has an incorrect length");
-            indicator_names.serialize_into(bytes);
+            indicator_names.serialize_into_with_order(bytes, order);
         }
         if let Some(ref virtual_mod_names) = self.virtual_mod_names {
-            assert_eq!(virtual_mod_names.len(), usize::try_from(virtual_mods.count_ones()).unwrap(), "`virtual_mod_names` has an incorrect length");
-            virtual_mod_names.serialize_into(bytes);
+            assert_eq!(virtual_mod_names.len(), usize::try_from(u16::from(virtual_mods).count_ones()).unwrap(), "`virtual_mod_names` has an incorrect length");
+            virtual_mod_names.serialize_into_with_order(bytes, order);
         }
         if let Some(ref groups) = self.groups {
-            assert_eq!(groups.len(), usize::try_from(group_names.count_ones()).unwrap(), "`groups` has an incorrect length");
-            groups.serialize_into(bytes);
+            assert_eq!(groups.len(), usize::try_from(u8::from(group_names).count_ones()).unwrap(), "`groups` has an incorrect length");
+            groups.serialize_into_with_order(bytes, order);
         }
         if let Some(ref key_names) = self.key_names {
             assert_eq!(key_names.len(), usize::try_from(n_keys).unwrap(), "`key_names` has an incorrect length");
-            key_names.serialize_into(bytes);
+            key_names.serialize_into_with_order(bytes, order);
         }
         if let Some(ref key_aliases) = self.key_aliases {
             assert_eq!(key_aliases.len(), usize::try_from(n_key_aliases).unwrap(), "`key_aliases` has an incorrect length");
-            key_aliases.serialize_into(bytes);
+            key_aliases.serialize_into_with_order(bytes, order);
         }
         if let Some(ref radio_group_names) = self.radio_group_names {
             assert_eq!(radio_group_names.len(), usize::try_from(n_radio_groups).unwrap(), "`radio_group_names` has an incorrect length");
-            radio_group_names.serialize_into(bytes);
+            radio_group_names.serialize_into_with_order(bytes, order);
         }
     }
 }
@@ -8551,32 +9693,166 @@ impl SetNamesAux {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetNamesRequest {
+    pub device_spec: DeviceSpec,
+    pub virtual_mods: VMod,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub first_kt_levelt: u8,
+    pub n_kt_levels: u8,
+    pub indicators: u32,
+    pub group_names: SetOfGroup,
+    pub n_radio_groups: u8,
+    pub first_key: xproto::Keycode,
+    pub n_keys: u8,
+    pub n_key_aliases: u8,
+    pub total_kt_level_names: u16,
+    pub values: SetNamesAux,
+}
+impl SetNamesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_NAMES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_kt_levelt, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_kt_levels, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (group_names, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_radio_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (total_kt_level_names, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (values, remaining) = SetNamesAux::try_parse_with_order(remaining, order, NameDetail::from(which), n_types, indicators, VMod::from(virtual_mods), SetOfGroup::from(group_names), n_keys, n_key_aliases, n_radio_groups)?;
+        let _ = remaining;
+        let _ = order;
+        let virtual_mods = VMod::from(virtual_mods);
+        let group_names = SetOfGroup::from(group_names);
+        Ok(SetNamesRequest {
+            device_spec,
+            virtual_mods,
+            first_type,
+            n_types,
+            first_kt_levelt,
+            n_kt_levels,
+            indicators,
+            group_names,
+            n_radio_groups,
+            first_key,
+            n_keys,
+            n_key_aliases,
+            total_kt_level_names,
+            values,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `virtual_mods` field of this structure.
+    pub fn virtual_mods<I>(mut self, value: I) -> Self where I: Into<VMod> {
+        self.virtual_mods = value.into();
+        self
+    }
+    /// Set the `first_type` field of this structure.
+    pub fn first_type(mut self, value: u8) -> Self {
+        self.first_type = value;
+        self
+    }
+    /// Set the `n_types` field of this structure.
+    pub fn n_types(mut self, value: u8) -> Self {
+        self.n_types = value;
+        self
+    }
+    /// Set the `first_kt_levelt` field of this structure.
+    pub fn first_kt_levelt(mut self, value: u8) -> Self {
+        self.first_kt_levelt = value;
+        self
+    }
+    /// Set the `n_kt_levels` field of this structure.
+    pub fn n_kt_levels(mut self, value: u8) -> Self {
+        self.n_kt_levels = value;
+        self
+    }
+    /// Set the `indicators` field of this structure.
+    pub fn indicators(mut self, value: u32) -> Self {
+        self.indicators = value;
+        self
+    }
+    /// Set the `group_names` field of this structure.
+    pub fn group_names<I>(mut self, value: I) -> Self where I: Into<SetOfGroup> {
+        self.group_names = value.into();
+        self
+    }
+    /// Set the `n_radio_groups` field of this structure.
+    pub fn n_radio_groups(mut self, value: u8) -> Self {
+        self.n_radio_groups = value;
+        self
+    }
+    /// Set the `first_key` field of this structure.
+    pub fn first_key<I>(mut self, value: I) -> Self where I: Into<xproto::Keycode> {
+        self.first_key = value.into();
+        self
+    }
+    /// Set the `n_keys` field of this structure.
+    pub fn n_keys(mut self, value: u8) -> Self {
+        self.n_keys = value;
+        self
+    }
+    /// Set the `n_key_aliases` field of this structure.
+    pub fn n_key_aliases(mut self, value: u8) -> Self {
+        self.n_key_aliases = value;
+        self
+    }
+    /// Set the `total_kt_level_names` field of this structure.
+    pub fn total_kt_level_names(mut self, value: u16) -> Self {
+        self.total_kt_level_names = value;
+        self
+    }
+    /// Set the `values` field of this structure.
+    pub fn values<I>(mut self, value: I) -> Self where I: Into<SetNamesAux> {
+        self.values = value.into();
+        self
+    }
+}
+
 pub fn set_names<'c, Conn, A, B>(conn: &'c Conn, device_spec: DeviceSpec, virtual_mods: A, first_type: u8, n_types: u8, first_kt_levelt: u8, n_kt_levels: u8, indicators: u32, group_names: B, n_radio_groups: u8, first_key: xproto::Keycode, n_keys: u8, n_key_aliases: u8, total_kt_level_names: u16, values: &SetNamesAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
-    B: Into<u8>,
+    A: Into<VMod>,
+    B: Into<SetOfGroup>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let virtual_mods: u16 = virtual_mods.into();
-    let group_names: u8 = group_names.into();
+    let virtual_mods: VMod = virtual_mods.into();
+    let group_names: SetOfGroup = group_names.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let virtual_mods_bytes = virtual_mods.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let virtual_mods_bytes = u16::from(virtual_mods).serialize_with_order(order);
     let which = u32::try_from(values.switch_expr()).unwrap();
-    let which_bytes = which.serialize();
-    let first_type_bytes = first_type.serialize();
-    let n_types_bytes = n_types.serialize();
-    let first_kt_levelt_bytes = first_kt_levelt.serialize();
-    let n_kt_levels_bytes = n_kt_levels.serialize();
-    let indicators_bytes = indicators.serialize();
-    let group_names_bytes = group_names.serialize();
-    let n_radio_groups_bytes = n_radio_groups.serialize();
-    let first_key_bytes = first_key.serialize();
-    let n_keys_bytes = n_keys.serialize();
-    let n_key_aliases_bytes = n_key_aliases.serialize();
-    let total_kt_level_names_bytes = total_kt_level_names.serialize();
+    let which_bytes = which.serialize_with_order(order);
+    let first_type_bytes = first_type.serialize_with_order(order);
+    let n_types_bytes = n_types.serialize_with_order(order);
+    let first_kt_levelt_bytes = first_kt_levelt.serialize_with_order(order);
+    let n_kt_levels_bytes = n_kt_levels.serialize_with_order(order);
+    let indicators_bytes = indicators.serialize_with_order(order);
+    let group_names_bytes = u8::from(group_names).serialize_with_order(order);
+    let n_radio_groups_bytes = n_radio_groups.serialize_with_order(order);
+    let first_key_bytes = first_key.serialize_with_order(order);
+    let n_keys_bytes = n_keys.serialize_with_order(order);
+    let n_key_aliases_bytes = n_key_aliases.serialize_with_order(order);
+    let total_kt_level_names_bytes = total_kt_level_names.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_NAMES_REQUEST,
@@ -8608,41 +9884,125 @@ where
         total_kt_level_names_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let values_bytes = values.serialize(which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups);
+    let values_bytes = values.serialize_with_order(order, NameDetail::from(which), n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups);
     let length_so_far = length_so_far + values_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&values_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetNamesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetNamesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_names(conn, self.device_spec, self.virtual_mods, self.first_type, self.n_types, self.first_kt_levelt, self.n_kt_levels, self.indicators, self.group_names, self.n_radio_groups, self.first_key, self.n_keys, self.n_key_aliases, self.total_kt_level_names, &self.values)
+    }
+}
 
 /// Opcode for the PerClientFlags request
 pub const PER_CLIENT_FLAGS_REQUEST: u8 = 21;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PerClientFlagsRequest {
+    pub device_spec: DeviceSpec,
+    pub change: PerClientFlag,
+    pub value: PerClientFlag,
+    pub ctrls_to_change: BoolCtrl,
+    pub auto_ctrls: BoolCtrl,
+    pub auto_ctrls_values: BoolCtrl,
+}
+impl PerClientFlagsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PER_CLIENT_FLAGS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (change, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ctrls_to_change, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (auto_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (auto_ctrls_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let change = PerClientFlag::from(change);
+        let value = PerClientFlag::from(value);
+        let ctrls_to_change = BoolCtrl::from(ctrls_to_change);
+        let auto_ctrls = BoolCtrl::from(auto_ctrls);
+        let auto_ctrls_values = BoolCtrl::from(auto_ctrls_values);
+        Ok(PerClientFlagsRequest {
+            device_spec,
+            change,
+            value,
+            ctrls_to_change,
+            auto_ctrls,
+            auto_ctrls_values,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `change` field of this structure.
+    pub fn change<I>(mut self, value: I) -> Self where I: Into<PerClientFlag> {
+        self.change = value.into();
+        self
+    }
+    /// Set the `value` field of this structure.
+    pub fn value<I>(mut self, value: I) -> Self where I: Into<PerClientFlag> {
+        self.value = value.into();
+        self
+    }
+    /// Set the `ctrls_to_change` field of this structure.
+    pub fn ctrls_to_change<I>(mut self, value: I) -> Self where I: Into<BoolCtrl> {
+        self.ctrls_to_change = value.into();
+        self
+    }
+    /// Set the `auto_ctrls` field of this structure.
+    pub fn auto_ctrls<I>(mut self, value: I) -> Self where I: Into<BoolCtrl> {
+        self.auto_ctrls = value.into();
+        self
+    }
+    /// Set the `auto_ctrls_values` field of this structure.
+    pub fn auto_ctrls_values<I>(mut self, value: I) -> Self where I: Into<BoolCtrl> {
+        self.auto_ctrls_values = value.into();
+        self
+    }
+}
+
 pub fn per_client_flags<Conn, A, B, C, D, E>(conn: &Conn, device_spec: DeviceSpec, change: A, value: B, ctrls_to_change: C, auto_ctrls: D, auto_ctrls_values: E) -> Result<Cookie<'_, Conn, PerClientFlagsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u32>,
-    B: Into<u32>,
-    C: Into<u32>,
-    D: Into<u32>,
-    E: Into<u32>,
+    A: Into<PerClientFlag>,
+    B: Into<PerClientFlag>,
+    C: Into<BoolCtrl>,
+    D: Into<BoolCtrl>,
+    E: Into<BoolCtrl>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let change: u32 = change.into();
-    let value: u32 = value.into();
-    let ctrls_to_change: u32 = ctrls_to_change.into();
-    let auto_ctrls: u32 = auto_ctrls.into();
-    let auto_ctrls_values: u32 = auto_ctrls_values.into();
+    let change: PerClientFlag = change.into();
+    let value: PerClientFlag = value.into();
+    let ctrls_to_change: BoolCtrl = ctrls_to_change.into();
+    let auto_ctrls: BoolCtrl = auto_ctrls.into();
+    let auto_ctrls_values: BoolCtrl = auto_ctrls_values.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let change_bytes = change.serialize();
-    let value_bytes = value.serialize();
-    let ctrls_to_change_bytes = ctrls_to_change.serialize();
-    let auto_ctrls_bytes = auto_ctrls.serialize();
-    let auto_ctrls_values_bytes = auto_ctrls_values.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let change_bytes = u32::from(change).serialize_with_order(order);
+    let value_bytes = u32::from(value).serialize_with_order(order);
+    let ctrls_to_change_bytes = u32::from(ctrls_to_change).serialize_with_order(order);
+    let auto_ctrls_bytes = u32::from(auto_ctrls).serialize_with_order(order);
+    let auto_ctrls_values_bytes = u32::from(auto_ctrls_values).serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PER_CLIENT_FLAGS_REQUEST,
@@ -8676,9 +10036,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PerClientFlagsRequest {
+    type Reply = PerClientFlagsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PerClientFlagsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PerClientFlagsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        per_client_flags(conn, self.device_spec, self.change, self.value, self.ctrls_to_change, self.auto_ctrls, self.auto_ctrls_values)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PerClientFlagsReply {
@@ -8686,22 +10058,26 @@ pub struct PerClientFlagsReply {
     pub device_id: u8,
     pub sequence: u16,
     pub length: u32,
-    pub supported: u32,
-    pub value: u32,
-    pub auto_ctrls: u32,
-    pub auto_ctrls_values: u32,
+    pub supported: PerClientFlag,
+    pub value: PerClientFlag,
+    pub auto_ctrls: BoolCtrl,
+    pub auto_ctrls_values: BoolCtrl,
 }
 impl TryParse for PerClientFlagsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (supported, remaining) = u32::try_parse(remaining)?;
-        let (value, remaining) = u32::try_parse(remaining)?;
-        let (auto_ctrls, remaining) = u32::try_parse(remaining)?;
-        let (auto_ctrls_values, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (auto_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (auto_ctrls_values, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
+        let supported = PerClientFlag::from(supported);
+        let value = PerClientFlag::from(value);
+        let auto_ctrls = BoolCtrl::from(auto_ctrls);
+        let auto_ctrls_values = BoolCtrl::from(auto_ctrls_values);
         let result = PerClientFlagsReply { response_type, device_id, sequence, length, supported, value, auto_ctrls, auto_ctrls_values };
         Ok((result, remaining))
     }
@@ -8712,18 +10088,79 @@ impl TryFrom<&[u8]> for PerClientFlagsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PerClientFlagsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u32::from(self.supported).serialize_into_with_order(bytes, order);
+        u32::from(self.value).serialize_into_with_order(bytes, order);
+        u32::from(self.auto_ctrls).serialize_into_with_order(bytes, order);
+        u32::from(self.auto_ctrls_values).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ListComponents request
 pub const LIST_COMPONENTS_REQUEST: u8 = 22;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListComponentsRequest {
+    pub device_spec: DeviceSpec,
+    pub max_names: u16,
+}
+impl ListComponentsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_COMPONENTS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (max_names, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ListComponentsRequest {
+            device_spec,
+            max_names,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `max_names` field of this structure.
+    pub fn max_names(mut self, value: u16) -> Self {
+        self.max_names = value;
+        self
+    }
+}
+
 pub fn list_components<Conn>(conn: &Conn, device_spec: DeviceSpec, max_names: u16) -> Result<Cookie<'_, Conn, ListComponentsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let max_names_bytes = max_names.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let max_names_bytes = max_names.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         LIST_COMPONENTS_REQUEST,
@@ -8737,9 +10174,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ListComponentsRequest {
+    type Reply = ListComponentsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ListComponentsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ListComponentsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        list_components(conn, self.device_spec, self.max_names)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListComponentsReply {
@@ -8756,25 +10205,25 @@ pub struct ListComponentsReply {
     pub geometries: Vec<Listing>,
 }
 impl TryParse for ListComponentsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (n_keymaps, remaining) = u16::try_parse(remaining)?;
-        let (n_keycodes, remaining) = u16::try_parse(remaining)?;
-        let (n_types, remaining) = u16::try_parse(remaining)?;
-        let (n_compat_maps, remaining) = u16::try_parse(remaining)?;
-        let (n_symbols, remaining) = u16::try_parse(remaining)?;
-        let (n_geometries, remaining) = u16::try_parse(remaining)?;
-        let (extra, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_keymaps, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_keycodes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_compat_maps, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_symbols, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_geometries, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (extra, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(10..).ok_or(ParseError::ParseError)?;
-        let (keymaps, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_keymaps.try_into().or(Err(ParseError::ParseError))?)?;
-        let (keycodes, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_keycodes.try_into().or(Err(ParseError::ParseError))?)?;
-        let (types, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?)?;
-        let (compat_maps, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_compat_maps.try_into().or(Err(ParseError::ParseError))?)?;
-        let (symbols, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_symbols.try_into().or(Err(ParseError::ParseError))?)?;
-        let (geometries, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_geometries.try_into().or(Err(ParseError::ParseError))?)?;
+        let (keymaps, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_keymaps.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (keycodes, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_keycodes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (types, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (compat_maps, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_compat_maps.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (symbols, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_symbols.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (geometries, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_geometries.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ListComponentsReply { response_type, device_id, sequence, length, extra, keymaps, keycodes, types, compat_maps, symbols, geometries };
         Ok((result, remaining))
     }
@@ -8785,24 +10234,119 @@ impl TryFrom<&[u8]> for ListComponentsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ListComponentsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let n_keymaps = u16::try_from(self.keymaps.len()).expect("`keymaps` has too many elements");
+        n_keymaps.serialize_into_with_order(bytes, order);
+        let n_keycodes = u16::try_from(self.keycodes.len()).expect("`keycodes` has too many elements");
+        n_keycodes.serialize_into_with_order(bytes, order);
+        let n_types = u16::try_from(self.types.len()).expect("`types` has too many elements");
+        n_types.serialize_into_with_order(bytes, order);
+        let n_compat_maps = u16::try_from(self.compat_maps.len()).expect("`compat_maps` has too many elements");
+        n_compat_maps.serialize_into_with_order(bytes, order);
+        let n_symbols = u16::try_from(self.symbols.len()).expect("`symbols` has too many elements");
+        n_symbols.serialize_into_with_order(bytes, order);
+        let n_geometries = u16::try_from(self.geometries.len()).expect("`geometries` has too many elements");
+        n_geometries.serialize_into_with_order(bytes, order);
+        self.extra.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 10]);
+        self.keymaps.serialize_into_with_order(bytes, order);
+        self.keycodes.serialize_into_with_order(bytes, order);
+        self.types.serialize_into_with_order(bytes, order);
+        self.compat_maps.serialize_into_with_order(bytes, order);
+        self.symbols.serialize_into_with_order(bytes, order);
+        self.geometries.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetKbdByName request
 pub const GET_KBD_BY_NAME_REQUEST: u8 = 23;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetKbdByNameRequest {
+    pub device_spec: DeviceSpec,
+    pub need: GBNDetail,
+    pub want: GBNDetail,
+    pub load: bool,
+}
+impl GetKbdByNameRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_KBD_BY_NAME_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (need, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (want, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (load, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        let need = GBNDetail::from(need);
+        let want = GBNDetail::from(want);
+        Ok(GetKbdByNameRequest {
+            device_spec,
+            need,
+            want,
+            load,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `need` field of this structure.
+    pub fn need<I>(mut self, value: I) -> Self where I: Into<GBNDetail> {
+        self.need = value.into();
+        self
+    }
+    /// Set the `want` field of this structure.
+    pub fn want<I>(mut self, value: I) -> Self where I: Into<GBNDetail> {
+        self.want = value.into();
+        self
+    }
+    /// Set the `load` field of this structure.
+    pub fn load(mut self, value: bool) -> Self {
+        self.load = value;
+        self
+    }
+}
+
 pub fn get_kbd_by_name<Conn, A, B>(conn: &Conn, device_spec: DeviceSpec, need: A, want: B, load: bool) -> Result<Cookie<'_, Conn, GetKbdByNameReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
-    B: Into<u16>,
+    A: Into<GBNDetail>,
+    B: Into<GBNDetail>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let need: u16 = need.into();
-    let want: u16 = want.into();
+    let need: GBNDetail = need.into();
+    let want: GBNDetail = want.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let need_bytes = need.serialize();
-    let want_bytes = want.serialize();
-    let load_bytes = load.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let need_bytes = u16::from(need).serialize_with_order(order);
+    let want_bytes = u16::from(want).serialize_with_order(order);
+    let load_bytes = load.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_KBD_BY_NAME_REQUEST,
@@ -8820,9 +10364,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetKbdByNameRequest {
+    type Reply = GetKbdByNameReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetKbdByNameRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetKbdByNameReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_kbd_by_name(conn, self.device_spec, self.need, self.want, self.load)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct GetKbdByNameRepliesTypesMapBitcase3 {
@@ -8830,7 +10386,7 @@ pub struct GetKbdByNameRepliesTypesMapBitcase3 {
     pub acts_rtrn_acts: Vec<Action>,
 }
 impl GetKbdByNameRepliesTypesMapBitcase3 {
-    pub fn try_parse(remaining: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
+    pub fn try_parse_with_order(remaining: &[u8], order: ByteOrder, n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (acts_rtrn_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_into().or(Err(ParseError::ParseError))?)?;
         let acts_rtrn_count = acts_rtrn_count.to_vec();
@@ -8838,30 +10394,45 @@ impl GetKbdByNameRepliesTypesMapBitcase3 {
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_into().or(Err(ParseError::ParseError))?)?;
+        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, total_actions.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetKbdByNameRepliesTypesMapBitcase3 { acts_rtrn_count, acts_rtrn_acts };
         Ok((result, remaining))
     }
 }
 // Skipping TryFrom implementations because of unresolved members
+#[allow(dead_code, unused_variables)]
+impl GetKbdByNameRepliesTypesMapBitcase3 {
+    fn serialize_with_order(&self, order: ByteOrder, n_key_actions: u8, total_actions: u16) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, n_key_actions, total_actions);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, n_key_actions: u8, total_actions: u16) {
+        assert_eq!(self.acts_rtrn_count.len(), usize::try_from(n_key_actions).unwrap(), "`acts_rtrn_count` has an incorrect length");
+        bytes.extend_from_slice(&self.acts_rtrn_count);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.acts_rtrn_acts.len(), usize::try_from(total_actions).unwrap(), "`acts_rtrn_acts` has an incorrect length");
+        self.acts_rtrn_acts.serialize_into_with_order(bytes, order);
+    }
+}
 #[derive(Debug, Clone, Default)]
 pub struct GetKbdByNameRepliesTypesMap {
     pub types_rtrn: Option<Vec<KeyType>>,
     pub syms_rtrn: Option<Vec<KeySymMap>>,
     pub bitcase3: Option<GetKbdByNameRepliesTypesMapBitcase3>,
     pub behaviors_rtrn: Option<Vec<SetBehavior>>,
-    pub vmods_rtrn: Option<Vec<u8>>,
+    pub vmods_rtrn: Option<Vec<xproto::ModMask>>,
     pub explicit_rtrn: Option<Vec<SetExplicit>>,
     pub modmap_rtrn: Option<Vec<KeyModMap>>,
     pub vmodmap_rtrn: Option<Vec<KeyVModMap>>,
 }
 impl GetKbdByNameRepliesTypesMap {
-    fn try_parse(value: &[u8], present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, present: MapPart, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: VMod, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(present);
         let mut outer_remaining = value;
         let types_rtrn = if switch_expr & u32::from(MapPart::KeyTypes) != 0 {
             let remaining = outer_remaining;
-            let (types_rtrn, remaining) = crate::x11_utils::parse_list::<KeyType>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?)?;
+            let (types_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyType>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(types_rtrn)
         } else {
@@ -8869,14 +10440,14 @@ impl GetKbdByNameRepliesTypesMap {
         };
         let syms_rtrn = if switch_expr & u32::from(MapPart::KeySyms) != 0 {
             let remaining = outer_remaining;
-            let (syms_rtrn, remaining) = crate::x11_utils::parse_list::<KeySymMap>(remaining, n_key_syms.try_into().or(Err(ParseError::ParseError))?)?;
+            let (syms_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeySymMap>(remaining, n_key_syms.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(syms_rtrn)
         } else {
             None
         };
         let bitcase3 = if switch_expr & u32::from(MapPart::KeyActions) != 0 {
-            let (bitcase3, new_remaining) = GetKbdByNameRepliesTypesMapBitcase3::try_parse(outer_remaining, n_key_actions, total_actions)?;
+            let (bitcase3, new_remaining) = GetKbdByNameRepliesTypesMapBitcase3::try_parse_with_order(outer_remaining, order, n_key_actions, total_actions)?;
             outer_remaining = new_remaining;
             Some(bitcase3)
         } else {
@@ -8884,7 +10455,7 @@ impl GetKbdByNameRepliesTypesMap {
         };
         let behaviors_rtrn = if switch_expr & u32::from(MapPart::KeyBehaviors) != 0 {
             let remaining = outer_remaining;
-            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list::<SetBehavior>(remaining, total_key_behaviors.try_into().or(Err(ParseError::ParseError))?)?;
+            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SetBehavior>(remaining, total_key_behaviors.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(behaviors_rtrn)
         } else {
@@ -8893,8 +10464,15 @@ impl GetKbdByNameRepliesTypesMap {
         let vmods_rtrn = if switch_expr & u32::from(MapPart::VirtualMods) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (vmods_rtrn, remaining) = crate::x11_utils::parse_u8_list(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
-            let vmods_rtrn = vmods_rtrn.to_vec();
+            let mut remaining = remaining;
+            let list_length = usize::try_from(u16::from(virtual_mods).count_ones()).or(Err(ParseError::ParseError))?;
+            let mut vmods_rtrn = Vec::with_capacity(list_length);
+            for _ in 0..list_length {
+                let (v, new_remaining) = u8::try_parse_with_order(remaining, order)?;
+                let v = xproto::ModMask::from(u16::from(v));
+                remaining = new_remaining;
+                vmods_rtrn.push(v);
+            }
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
@@ -8907,7 +10485,7 @@ impl GetKbdByNameRepliesTypesMap {
         let explicit_rtrn = if switch_expr & u32::from(MapPart::ExplicitComponents) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_into().or(Err(ParseError::ParseError))?)?;
+            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SetExplicit>(remaining, total_key_explicit.try_into().or(Err(ParseError::ParseError))?, order)?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
@@ -8920,7 +10498,7 @@ impl GetKbdByNameRepliesTypesMap {
         let modmap_rtrn = if switch_expr & u32::from(MapPart::ModifierMap) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_into().or(Err(ParseError::ParseError))?)?;
+            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyModMap>(remaining, total_mod_map_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
@@ -8932,7 +10510,7 @@ impl GetKbdByNameRepliesTypesMap {
         };
         let vmodmap_rtrn = if switch_expr & u32::from(MapPart::VirtualModMap) != 0 {
             let remaining = outer_remaining;
-            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyVModMap>(remaining, total_v_mod_map_keys.try_into().or(Err(ParseError::ParseError))?)?;
+            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyVModMap>(remaining, total_v_mod_map_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(vmodmap_rtrn)
         } else {
@@ -8942,6 +10520,82 @@ impl GetKbdByNameRepliesTypesMap {
         Ok((result, outer_remaining))
     }
 }
+#[allow(dead_code, unused_variables)]
+impl GetKbdByNameRepliesTypesMap {
+    fn serialize_with_order(&self, order: ByteOrder, present: MapPart, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: VMod, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, present: MapPart, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: VMod, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) {
+        if let Some(ref types_rtrn) = self.types_rtrn {
+            assert_eq!(types_rtrn.len(), usize::try_from(n_types).unwrap(), "`types_rtrn` has an incorrect length");
+            types_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref syms_rtrn) = self.syms_rtrn {
+            assert_eq!(syms_rtrn.len(), usize::try_from(n_key_syms).unwrap(), "`syms_rtrn` has an incorrect length");
+            syms_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase3) = self.bitcase3 {
+            bitcase3.serialize_into_with_order(bytes, order, n_key_actions, total_actions);
+        }
+        if let Some(ref behaviors_rtrn) = self.behaviors_rtrn {
+            assert_eq!(behaviors_rtrn.len(), usize::try_from(total_key_behaviors).unwrap(), "`behaviors_rtrn` has an incorrect length");
+            behaviors_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref vmods_rtrn) = self.vmods_rtrn {
+            assert_eq!(vmods_rtrn.len(), usize::try_from(u16::from(virtual_mods).count_ones()).unwrap(), "`vmods_rtrn` has an incorrect length");
+            for element in vmods_rtrn.iter() {
+                u8::try_from(u16::from(*element)).unwrap().serialize_into_with_order(bytes, order);
+            }
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref explicit_rtrn) = self.explicit_rtrn {
+            assert_eq!(explicit_rtrn.len(), usize::try_from(total_key_explicit).unwrap(), "`explicit_rtrn` has an incorrect length");
+            explicit_rtrn.serialize_into_with_order(bytes, order);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref modmap_rtrn) = self.modmap_rtrn {
+            assert_eq!(modmap_rtrn.len(), usize::try_from(total_mod_map_keys).unwrap(), "`modmap_rtrn` has an incorrect length");
+            modmap_rtrn.serialize_into_with_order(bytes, order);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref vmodmap_rtrn) = self.vmodmap_rtrn {
+            assert_eq!(vmodmap_rtrn.len(), usize::try_from(total_v_mod_map_keys).unwrap(), "`vmodmap_rtrn` has an incorrect length");
+            vmodmap_rtrn.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl GetKbdByNameRepliesTypesMap {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.types_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KeyTypes);
+        }
+        if self.syms_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KeySyms);
+        }
+        if self.bitcase3.is_some() {
+            expr_value |= u32::from(MapPart::KeyActions);
+        }
+        if self.behaviors_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KeyBehaviors);
+        }
+        if self.vmods_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::VirtualMods);
+        }
+        if self.explicit_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::ExplicitComponents);
+        }
+        if self.modmap_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::ModifierMap);
+        }
+        if self.vmodmap_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::VirtualModMap);
+        }
+        expr_value
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct GetKbdByNameRepliesTypes {
@@ -8972,43 +10626,44 @@ pub struct GetKbdByNameRepliesTypes {
     pub first_v_mod_map_key: xproto::Keycode,
     pub n_v_mod_map_keys: u8,
     pub total_v_mod_map_keys: u8,
-    pub virtual_mods: u16,
+    pub virtual_mods: VMod,
     pub map: GetKbdByNameRepliesTypesMap,
 }
 impl TryParse for GetKbdByNameRepliesTypes {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (getmap_type, remaining) = u8::try_parse(remaining)?;
-        let (type_device_id, remaining) = u8::try_parse(remaining)?;
-        let (getmap_sequence, remaining) = u16::try_parse(remaining)?;
-        let (getmap_length, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (getmap_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (type_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (getmap_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (getmap_length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (type_min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (type_max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (present, remaining) = u16::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (total_types, remaining) = u8::try_parse(remaining)?;
-        let (first_key_sym, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (total_syms, remaining) = u16::try_parse(remaining)?;
-        let (n_key_syms, remaining) = u8::try_parse(remaining)?;
-        let (first_key_action, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (total_actions, remaining) = u16::try_parse(remaining)?;
-        let (n_key_actions, remaining) = u8::try_parse(remaining)?;
-        let (first_key_behavior, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (total_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (first_key_explicit, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (total_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
+        let (type_min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (type_max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (present, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (total_syms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_action, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (total_actions, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (map, remaining) = GetKbdByNameRepliesTypesMap::try_parse(remaining, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = GetKbdByNameRepliesTypesMap::try_parse_with_order(remaining, order, MapPart::from(present), n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, VMod::from(virtual_mods), total_key_explicit, total_mod_map_keys, total_v_mod_map_keys)?;
+        let virtual_mods = VMod::from(virtual_mods);
         let result = GetKbdByNameRepliesTypes { getmap_type, type_device_id, getmap_sequence, getmap_length, type_min_key_code, type_max_key_code, first_type, n_types, total_types, first_key_sym, total_syms, n_key_syms, first_key_action, total_actions, n_key_actions, first_key_behavior, n_key_behaviors, total_key_behaviors, first_key_explicit, n_key_explicit, total_key_explicit, first_mod_map_key, n_mod_map_keys, total_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, total_v_mod_map_keys, virtual_mods, map };
         Ok((result, remaining))
     }
@@ -9019,32 +10674,86 @@ impl TryFrom<&[u8]> for GetKbdByNameRepliesTypes {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetKbdByNameRepliesTypes {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(40);
+        let n_types = self.n_types;
+        let n_key_syms = self.n_key_syms;
+        let n_key_actions = self.n_key_actions;
+        let total_actions = self.total_actions;
+        let total_key_behaviors = self.total_key_behaviors;
+        let virtual_mods = self.virtual_mods;
+        let total_key_explicit = self.total_key_explicit;
+        let total_mod_map_keys = self.total_mod_map_keys;
+        let total_v_mod_map_keys = self.total_v_mod_map_keys;
+        self.getmap_type.serialize_into_with_order(bytes, order);
+        self.type_device_id.serialize_into_with_order(bytes, order);
+        self.getmap_sequence.serialize_into_with_order(bytes, order);
+        self.getmap_length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.type_min_key_code.serialize_into_with_order(bytes, order);
+        self.type_max_key_code.serialize_into_with_order(bytes, order);
+        let present = u16::try_from(self.map.switch_expr()).unwrap();
+        present.serialize_into_with_order(bytes, order);
+        self.first_type.serialize_into_with_order(bytes, order);
+        self.n_types.serialize_into_with_order(bytes, order);
+        self.total_types.serialize_into_with_order(bytes, order);
+        self.first_key_sym.serialize_into_with_order(bytes, order);
+        self.total_syms.serialize_into_with_order(bytes, order);
+        self.n_key_syms.serialize_into_with_order(bytes, order);
+        self.first_key_action.serialize_into_with_order(bytes, order);
+        self.total_actions.serialize_into_with_order(bytes, order);
+        self.n_key_actions.serialize_into_with_order(bytes, order);
+        self.first_key_behavior.serialize_into_with_order(bytes, order);
+        self.n_key_behaviors.serialize_into_with_order(bytes, order);
+        self.total_key_behaviors.serialize_into_with_order(bytes, order);
+        self.first_key_explicit.serialize_into_with_order(bytes, order);
+        self.n_key_explicit.serialize_into_with_order(bytes, order);
+        self.total_key_explicit.serialize_into_with_order(bytes, order);
+        self.first_mod_map_key.serialize_into_with_order(bytes, order);
+        self.n_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.total_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.first_v_mod_map_key.serialize_into_with_order(bytes, order);
+        self.n_v_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.total_v_mod_map_keys.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        u16::from(self.virtual_mods).serialize_into_with_order(bytes, order);
+        self.map.serialize_into_with_order(bytes, order, MapPart::from(present), n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys);
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesCompatMap {
     pub compatmap_type: u8,
     pub compat_device_id: u8,
     pub compatmap_sequence: u16,
     pub compatmap_length: u32,
-    pub groups_rtrn: u8,
+    pub groups_rtrn: SetOfGroup,
     pub first_si_rtrn: u16,
     pub n_total_si: u16,
     pub si_rtrn: Vec<SymInterpret>,
     pub group_rtrn: Vec<ModDef>,
 }
 impl TryParse for GetKbdByNameRepliesCompatMap {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (compatmap_type, remaining) = u8::try_parse(remaining)?;
-        let (compat_device_id, remaining) = u8::try_parse(remaining)?;
-        let (compatmap_sequence, remaining) = u16::try_parse(remaining)?;
-        let (compatmap_length, remaining) = u32::try_parse(remaining)?;
-        let (groups_rtrn, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (compatmap_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compatmap_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (compatmap_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (groups_rtrn, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (first_si_rtrn, remaining) = u16::try_parse(remaining)?;
-        let (n_si_rtrn, remaining) = u16::try_parse(remaining)?;
-        let (n_total_si, remaining) = u16::try_parse(remaining)?;
+        let (first_si_rtrn, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si_rtrn, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_total_si, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (si_rtrn, remaining) = crate::x11_utils::parse_list::<SymInterpret>(remaining, n_si_rtrn.try_into().or(Err(ParseError::ParseError))?)?;
-        let (group_rtrn, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, groups_rtrn.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+        let (si_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SymInterpret>(remaining, n_si_rtrn.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (group_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<ModDef>(remaining, u8::from(groups_rtrn).count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
+        let groups_rtrn = SetOfGroup::from(groups_rtrn);
         let result = GetKbdByNameRepliesCompatMap { compatmap_type, compat_device_id, compatmap_sequence, compatmap_length, groups_rtrn, first_si_rtrn, n_total_si, si_rtrn, group_rtrn };
         Ok((result, remaining))
     }
@@ -9055,6 +10764,31 @@ impl TryFrom<&[u8]> for GetKbdByNameRepliesCompatMap {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetKbdByNameRepliesCompatMap {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(32);
+        self.compatmap_type.serialize_into_with_order(bytes, order);
+        self.compat_device_id.serialize_into_with_order(bytes, order);
+        self.compatmap_sequence.serialize_into_with_order(bytes, order);
+        self.compatmap_length.serialize_into_with_order(bytes, order);
+        u8::from(self.groups_rtrn).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.first_si_rtrn.serialize_into_with_order(bytes, order);
+        let n_si_rtrn = u16::try_from(self.si_rtrn.len()).expect("`si_rtrn` has too many elements");
+        n_si_rtrn.serialize_into_with_order(bytes, order);
+        self.n_total_si.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.si_rtrn.serialize_into_with_order(bytes, order);
+        assert_eq!(self.group_rtrn.len(), usize::try_from(u8::from(self.groups_rtrn).count_ones()).unwrap(), "`group_rtrn` has an incorrect length");
+        self.group_rtrn.serialize_into_with_order(bytes, order);
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesIndicatorMaps {
     pub indicatormap_type: u8,
@@ -9066,16 +10800,16 @@ pub struct GetKbdByNameRepliesIndicatorMaps {
     pub maps: Vec<IndicatorMap>,
 }
 impl TryParse for GetKbdByNameRepliesIndicatorMaps {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (indicatormap_type, remaining) = u8::try_parse(remaining)?;
-        let (indicator_device_id, remaining) = u8::try_parse(remaining)?;
-        let (indicatormap_sequence, remaining) = u16::try_parse(remaining)?;
-        let (indicatormap_length, remaining) = u32::try_parse(remaining)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (real_indicators, remaining) = u32::try_parse(remaining)?;
-        let (n_indicators, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (indicatormap_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicator_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicatormap_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (indicatormap_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (real_indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_indicators, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(15..).ok_or(ParseError::ParseError)?;
-        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, n_indicators.try_into().or(Err(ParseError::ParseError))?)?;
+        let (maps, remaining) = crate::x11_utils::parse_list_with_order::<IndicatorMap>(remaining, n_indicators.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetKbdByNameRepliesIndicatorMaps { indicatormap_type, indicator_device_id, indicatormap_sequence, indicatormap_length, which, real_indicators, maps };
         Ok((result, remaining))
     }
@@ -9086,13 +10820,34 @@ impl TryFrom<&[u8]> for GetKbdByNameRepliesIndicatorMaps {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetKbdByNameRepliesIndicatorMaps {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(32);
+        self.indicatormap_type.serialize_into_with_order(bytes, order);
+        self.indicator_device_id.serialize_into_with_order(bytes, order);
+        self.indicatormap_sequence.serialize_into_with_order(bytes, order);
+        self.indicatormap_length.serialize_into_with_order(bytes, order);
+        self.which.serialize_into_with_order(bytes, order);
+        self.real_indicators.serialize_into_with_order(bytes, order);
+        let n_indicators = u8::try_from(self.maps.len()).expect("`maps` has too many elements");
+        n_indicators.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 15]);
+        self.maps.serialize_into_with_order(bytes, order);
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesKeyNamesValueListBitcase8 {
     pub n_levels_per_type: Vec<u8>,
     pub kt_level_names: Vec<xproto::Atom>,
 }
 impl GetKbdByNameRepliesKeyNamesValueListBitcase8 {
-    pub fn try_parse(remaining: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
+    pub fn try_parse_with_order(remaining: &[u8], order: ByteOrder, n_types: u8) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_into().or(Err(ParseError::ParseError))?)?;
         let n_levels_per_type = n_levels_per_type.to_vec();
@@ -9100,12 +10855,27 @@ impl GetKbdByNameRepliesKeyNamesValueListBitcase8 {
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, &x| acc.checked_add(u32::from(x)).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?)?;
+        let (kt_level_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, &x| acc.checked_add(u32::from(x)).ok_or(ParseError::ParseError))?.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = GetKbdByNameRepliesKeyNamesValueListBitcase8 { n_levels_per_type, kt_level_names };
         Ok((result, remaining))
     }
 }
 // Skipping TryFrom implementations because of unresolved members
+#[allow(dead_code, unused_variables)]
+impl GetKbdByNameRepliesKeyNamesValueListBitcase8 {
+    fn serialize_with_order(&self, order: ByteOrder, n_types: u8) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, n_types);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, n_types: u8) {
+        assert_eq!(self.n_levels_per_type.len(), usize::try_from(n_types).unwrap(), "`n_levels_per_type` has an incorrect length");
+        bytes.extend_from_slice(&self.n_levels_per_type);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.kt_level_names.len(), usize::try_from(self.n_levels_per_type.iter().fold(0u32, |acc, &x| acc.checked_add(u32::from(x)).unwrap())).unwrap(), "`kt_level_names` has an incorrect length");
+        self.kt_level_names.serialize_into_with_order(bytes, order);
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct GetKbdByNameRepliesKeyNamesValueList {
     pub keycodes_name: Option<xproto::Atom>,
@@ -9124,12 +10894,12 @@ pub struct GetKbdByNameRepliesKeyNamesValueList {
     pub radio_group_names: Option<Vec<xproto::Atom>>,
 }
 impl GetKbdByNameRepliesKeyNamesValueList {
-    fn try_parse(value: &[u8], which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Result<(Self, &[u8]), ParseError> {
-        let switch_expr = which;
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, which: NameDetail, n_types: u8, indicators: u32, virtual_mods: VMod, group_names: SetOfGroup, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(which);
         let mut outer_remaining = value;
         let keycodes_name = if switch_expr & u32::from(NameDetail::Keycodes) != 0 {
             let remaining = outer_remaining;
-            let (keycodes_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (keycodes_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(keycodes_name)
         } else {
@@ -9137,7 +10907,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let geometry_name = if switch_expr & u32::from(NameDetail::Geometry) != 0 {
             let remaining = outer_remaining;
-            let (geometry_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (geometry_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(geometry_name)
         } else {
@@ -9145,7 +10915,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let symbols_name = if switch_expr & u32::from(NameDetail::Symbols) != 0 {
             let remaining = outer_remaining;
-            let (symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(symbols_name)
         } else {
@@ -9153,7 +10923,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let phys_symbols_name = if switch_expr & u32::from(NameDetail::PhysSymbols) != 0 {
             let remaining = outer_remaining;
-            let (phys_symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (phys_symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(phys_symbols_name)
         } else {
@@ -9161,7 +10931,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let types_name = if switch_expr & u32::from(NameDetail::Types) != 0 {
             let remaining = outer_remaining;
-            let (types_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (types_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(types_name)
         } else {
@@ -9169,7 +10939,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let compat_name = if switch_expr & u32::from(NameDetail::Compat) != 0 {
             let remaining = outer_remaining;
-            let (compat_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (compat_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
             outer_remaining = remaining;
             Some(compat_name)
         } else {
@@ -9177,14 +10947,14 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let type_names = if switch_expr & u32::from(NameDetail::KeyTypeNames) != 0 {
             let remaining = outer_remaining;
-            let (type_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?)?;
+            let (type_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_types.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(type_names)
         } else {
             None
         };
         let bitcase8 = if switch_expr & u32::from(NameDetail::KTLevelNames) != 0 {
-            let (bitcase8, new_remaining) = GetKbdByNameRepliesKeyNamesValueListBitcase8::try_parse(outer_remaining, n_types)?;
+            let (bitcase8, new_remaining) = GetKbdByNameRepliesKeyNamesValueListBitcase8::try_parse_with_order(outer_remaining, order, n_types)?;
             outer_remaining = new_remaining;
             Some(bitcase8)
         } else {
@@ -9192,7 +10962,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let indicator_names = if switch_expr & u32::from(NameDetail::IndicatorNames) != 0 {
             let remaining = outer_remaining;
-            let (indicator_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, indicators.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+            let (indicator_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, indicators.count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(indicator_names)
         } else {
@@ -9200,7 +10970,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let virtual_mod_names = if switch_expr & u32::from(NameDetail::VirtualModNames) != 0 {
             let remaining = outer_remaining;
-            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, u16::from(virtual_mods).count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(virtual_mod_names)
         } else {
@@ -9208,7 +10978,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let groups = if switch_expr & u32::from(NameDetail::GroupNames) != 0 {
             let remaining = outer_remaining;
-            let (groups, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, group_names.count_ones().try_into().or(Err(ParseError::ParseError))?)?;
+            let (groups, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, u8::from(group_names).count_ones().try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(groups)
         } else {
@@ -9216,7 +10986,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let key_names = if switch_expr & u32::from(NameDetail::KeyNames) != 0 {
             let remaining = outer_remaining;
-            let (key_names, remaining) = crate::x11_utils::parse_list::<KeyName>(remaining, n_keys.try_into().or(Err(ParseError::ParseError))?)?;
+            let (key_names, remaining) = crate::x11_utils::parse_list_with_order::<KeyName>(remaining, n_keys.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(key_names)
         } else {
@@ -9224,7 +10994,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let key_aliases = if switch_expr & u32::from(NameDetail::KeyAliases) != 0 {
             let remaining = outer_remaining;
-            let (key_aliases, remaining) = crate::x11_utils::parse_list::<KeyAlias>(remaining, n_key_aliases.try_into().or(Err(ParseError::ParseError))?)?;
+            let (key_aliases, remaining) = crate::x11_utils::parse_list_with_order::<KeyAlias>(remaining, n_key_aliases.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(key_aliases)
         } else {
@@ -9232,7 +11002,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let radio_group_names = if switch_expr & u32::from(NameDetail::RGNames) != 0 {
             let remaining = outer_remaining;
-            let (radio_group_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_radio_groups.try_into().or(Err(ParseError::ParseError))?)?;
+            let (radio_group_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_radio_groups.try_into().or(Err(ParseError::ParseError))?, order)?;
             outer_remaining = remaining;
             Some(radio_group_names)
         } else {
@@ -9242,6 +11012,113 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         Ok((result, outer_remaining))
     }
 }
+#[allow(dead_code, unused_variables)]
+impl GetKbdByNameRepliesKeyNamesValueList {
+    fn serialize_with_order(&self, order: ByteOrder, which: NameDetail, n_types: u8, indicators: u32, virtual_mods: VMod, group_names: SetOfGroup, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, which: NameDetail, n_types: u8, indicators: u32, virtual_mods: VMod, group_names: SetOfGroup, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) {
+        if let Some(keycodes_name) = self.keycodes_name {
+            keycodes_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(geometry_name) = self.geometry_name {
+            geometry_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(symbols_name) = self.symbols_name {
+            symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(phys_symbols_name) = self.phys_symbols_name {
+            phys_symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(types_name) = self.types_name {
+            types_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(compat_name) = self.compat_name {
+            compat_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref type_names) = self.type_names {
+            assert_eq!(type_names.len(), usize::try_from(n_types).unwrap(), "`type_names` has an incorrect length");
+            type_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase8) = self.bitcase8 {
+            bitcase8.serialize_into_with_order(bytes, order, n_types);
+        }
+        if let Some(ref indicator_names) = self.indicator_names {
+            assert_eq!(indicator_names.len(), usize::try_from(indicators.count_ones()).unwrap(), "`indicator_names` has an incorrect length");
+            indicator_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref virtual_mod_names) = self.virtual_mod_names {
+            assert_eq!(virtual_mod_names.len(), usize::try_from(u16::from(virtual_mods).count_ones()).unwrap(), "`virtual_mod_names` has an incorrect length");
+            virtual_mod_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref groups) = self.groups {
+            assert_eq!(groups.len(), usize::try_from(u8::from(group_names).count_ones()).unwrap(), "`groups` has an incorrect length");
+            groups.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_names) = self.key_names {
+            assert_eq!(key_names.len(), usize::try_from(n_keys).unwrap(), "`key_names` has an incorrect length");
+            key_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_aliases) = self.key_aliases {
+            assert_eq!(key_aliases.len(), usize::try_from(n_key_aliases).unwrap(), "`key_aliases` has an incorrect length");
+            key_aliases.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref radio_group_names) = self.radio_group_names {
+            assert_eq!(radio_group_names.len(), usize::try_from(n_radio_groups).unwrap(), "`radio_group_names` has an incorrect length");
+            radio_group_names.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl GetKbdByNameRepliesKeyNamesValueList {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.keycodes_name.is_some() {
+            expr_value |= u32::from(NameDetail::Keycodes);
+        }
+        if self.geometry_name.is_some() {
+            expr_value |= u32::from(NameDetail::Geometry);
+        }
+        if self.symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::Symbols);
+        }
+        if self.phys_symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::PhysSymbols);
+        }
+        if self.types_name.is_some() {
+            expr_value |= u32::from(NameDetail::Types);
+        }
+        if self.compat_name.is_some() {
+            expr_value |= u32::from(NameDetail::Compat);
+        }
+        if self.type_names.is_some() {
+            expr_value |= u32::from(NameDetail::KeyTypeNames);
+        }
+        if self.bitcase8.is_some() {
+            expr_value |= u32::from(NameDetail::KTLevelNames);
+        }
+        if self.indicator_names.is_some() {
+            expr_value |= u32::from(NameDetail::IndicatorNames);
+        }
+        if self.virtual_mod_names.is_some() {
+            expr_value |= u32::from(NameDetail::VirtualModNames);
+        }
+        if self.groups.is_some() {
+            expr_value |= u32::from(NameDetail::GroupNames);
+        }
+        if self.key_names.is_some() {
+            expr_value |= u32::from(NameDetail::KeyNames);
+        }
+        if self.key_aliases.is_some() {
+            expr_value |= u32::from(NameDetail::KeyAliases);
+        }
+        if self.radio_group_names.is_some() {
+            expr_value |= u32::from(NameDetail::RGNames);
+        }
+        expr_value
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesKeyNames {
@@ -9252,8 +11129,8 @@ pub struct GetKbdByNameRepliesKeyNames {
     pub key_min_key_code: xproto::Keycode,
     pub key_max_key_code: xproto::Keycode,
     pub n_types: u8,
-    pub group_names: u8,
-    pub virtual_mods: u16,
+    pub group_names: SetOfGroup,
+    pub virtual_mods: VMod,
     pub first_key: xproto::Keycode,
     pub n_keys: u8,
     pub indicators: u32,
@@ -9263,25 +11140,27 @@ pub struct GetKbdByNameRepliesKeyNames {
     pub value_list: GetKbdByNameRepliesKeyNamesValueList,
 }
 impl TryParse for GetKbdByNameRepliesKeyNames {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keyname_type, remaining) = u8::try_parse(remaining)?;
-        let (key_device_id, remaining) = u8::try_parse(remaining)?;
-        let (keyname_sequence, remaining) = u16::try_parse(remaining)?;
-        let (keyname_length, remaining) = u32::try_parse(remaining)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (key_min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (key_max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (group_names, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (first_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let (indicators, remaining) = u32::try_parse(remaining)?;
-        let (n_radio_groups, remaining) = u8::try_parse(remaining)?;
-        let (n_key_aliases, remaining) = u8::try_parse(remaining)?;
-        let (n_kt_levels, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keyname_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (key_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (keyname_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keyname_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (key_min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (key_max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group_names, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_radio_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_kt_levels, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
-        let (value_list, remaining) = GetKbdByNameRepliesKeyNamesValueList::try_parse(remaining, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups)?;
+        let (value_list, remaining) = GetKbdByNameRepliesKeyNamesValueList::try_parse_with_order(remaining, order, NameDetail::from(which), n_types, indicators, VMod::from(virtual_mods), SetOfGroup::from(group_names), n_keys, n_key_aliases, n_radio_groups)?;
+        let group_names = SetOfGroup::from(group_names);
+        let virtual_mods = VMod::from(virtual_mods);
         let result = GetKbdByNameRepliesKeyNames { keyname_type, key_device_id, keyname_sequence, keyname_length, key_min_key_code, key_max_key_code, n_types, group_names, virtual_mods, first_key, n_keys, indicators, n_radio_groups, n_key_aliases, n_kt_levels, value_list };
         Ok((result, remaining))
     }
@@ -9292,6 +11171,43 @@ impl TryFrom<&[u8]> for GetKbdByNameRepliesKeyNames {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetKbdByNameRepliesKeyNames {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(32);
+        let n_types = self.n_types;
+        let indicators = self.indicators;
+        let virtual_mods = self.virtual_mods;
+        let group_names = self.group_names;
+        let n_keys = self.n_keys;
+        let n_key_aliases = self.n_key_aliases;
+        let n_radio_groups = self.n_radio_groups;
+        self.keyname_type.serialize_into_with_order(bytes, order);
+        self.key_device_id.serialize_into_with_order(bytes, order);
+        self.keyname_sequence.serialize_into_with_order(bytes, order);
+        self.keyname_length.serialize_into_with_order(bytes, order);
+        let which = u32::try_from(self.value_list.switch_expr()).unwrap();
+        which.serialize_into_with_order(bytes, order);
+        self.key_min_key_code.serialize_into_with_order(bytes, order);
+        self.key_max_key_code.serialize_into_with_order(bytes, order);
+        self.n_types.serialize_into_with_order(bytes, order);
+        u8::from(self.group_names).serialize_into_with_order(bytes, order);
+        u16::from(self.virtual_mods).serialize_into_with_order(bytes, order);
+        self.first_key.serialize_into_with_order(bytes, order);
+        self.n_keys.serialize_into_with_order(bytes, order);
+        self.indicators.serialize_into_with_order(bytes, order);
+        self.n_radio_groups.serialize_into_with_order(bytes, order);
+        self.n_key_aliases.serialize_into_with_order(bytes, order);
+        self.n_kt_levels.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.value_list.serialize_into_with_order(bytes, order, NameDetail::from(which), n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups);
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesGeometry {
     pub geometry_type: u8,
@@ -9313,33 +11229,62 @@ pub struct GetKbdByNameRepliesGeometry {
     pub label_font: CountedString16,
 }
 impl TryParse for GetKbdByNameRepliesGeometry {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (geometry_type, remaining) = u8::try_parse(remaining)?;
-        let (geometry_device_id, remaining) = u8::try_parse(remaining)?;
-        let (geometry_sequence, remaining) = u16::try_parse(remaining)?;
-        let (geometry_length, remaining) = u32::try_parse(remaining)?;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (geometry_found, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (geometry_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (geometry_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (geometry_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (geometry_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (geometry_found, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (width_mm, remaining) = u16::try_parse(remaining)?;
-        let (height_mm, remaining) = u16::try_parse(remaining)?;
-        let (n_properties, remaining) = u16::try_parse(remaining)?;
-        let (n_colors, remaining) = u16::try_parse(remaining)?;
-        let (n_shapes, remaining) = u16::try_parse(remaining)?;
-        let (n_sections, remaining) = u16::try_parse(remaining)?;
-        let (n_doodads, remaining) = u16::try_parse(remaining)?;
-        let (n_key_aliases, remaining) = u16::try_parse(remaining)?;
-        let (base_color_ndx, remaining) = u8::try_parse(remaining)?;
-        let (label_color_ndx, remaining) = u8::try_parse(remaining)?;
-        let (label_font, remaining) = CountedString16::try_parse(remaining)?;
+        let (width_mm, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height_mm, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_properties, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_colors, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_shapes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_sections, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_doodads, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (base_color_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (label_color_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (label_font, remaining) = CountedString16::try_parse_with_order(remaining, order)?;
         let result = GetKbdByNameRepliesGeometry { geometry_type, geometry_device_id, geometry_sequence, geometry_length, name, geometry_found, width_mm, height_mm, n_properties, n_colors, n_shapes, n_sections, n_doodads, n_key_aliases, base_color_ndx, label_color_ndx, label_font };
         Ok((result, remaining))
     }
-}
-impl TryFrom<&[u8]> for GetKbdByNameRepliesGeometry {
-    type Error = ParseError;
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Ok(Self::try_parse(value)?.0)
+}
+impl TryFrom<&[u8]> for GetKbdByNameRepliesGeometry {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for GetKbdByNameRepliesGeometry {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(32);
+        self.geometry_type.serialize_into_with_order(bytes, order);
+        self.geometry_device_id.serialize_into_with_order(bytes, order);
+        self.geometry_sequence.serialize_into_with_order(bytes, order);
+        self.geometry_length.serialize_into_with_order(bytes, order);
+        self.name.serialize_into_with_order(bytes, order);
+        self.geometry_found.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.width_mm.serialize_into_with_order(bytes, order);
+        self.height_mm.serialize_into_with_order(bytes, order);
+        self.n_properties.serialize_into_with_order(bytes, order);
+        self.n_colors.serialize_into_with_order(bytes, order);
+        self.n_shapes.serialize_into_with_order(bytes, order);
+        self.n_sections.serialize_into_with_order(bytes, order);
+        self.n_doodads.serialize_into_with_order(bytes, order);
+        self.n_key_aliases.serialize_into_with_order(bytes, order);
+        self.base_color_ndx.serialize_into_with_order(bytes, order);
+        self.label_color_ndx.serialize_into_with_order(bytes, order);
+        self.label_font.serialize_into_with_order(bytes, order);
     }
 }
 #[derive(Debug, Clone, Default)]
@@ -9351,39 +11296,39 @@ pub struct GetKbdByNameReplies {
     pub geometry: Option<GetKbdByNameRepliesGeometry>,
 }
 impl GetKbdByNameReplies {
-    fn try_parse(value: &[u8], reported: u16) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder, reported: GBNDetail) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(reported);
         let mut outer_remaining = value;
         let types = if switch_expr & u32::from(GBNDetail::Types) != 0 || switch_expr & u32::from(GBNDetail::ClientSymbols) != 0 || switch_expr & u32::from(GBNDetail::ServerSymbols) != 0 {
-            let (types, new_remaining) = GetKbdByNameRepliesTypes::try_parse(outer_remaining)?;
+            let (types, new_remaining) = GetKbdByNameRepliesTypes::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             Some(types)
         } else {
             None
         };
         let compat_map = if switch_expr & u32::from(GBNDetail::CompatMap) != 0 {
-            let (compat_map, new_remaining) = GetKbdByNameRepliesCompatMap::try_parse(outer_remaining)?;
+            let (compat_map, new_remaining) = GetKbdByNameRepliesCompatMap::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             Some(compat_map)
         } else {
             None
         };
         let indicator_maps = if switch_expr & u32::from(GBNDetail::IndicatorMaps) != 0 {
-            let (indicator_maps, new_remaining) = GetKbdByNameRepliesIndicatorMaps::try_parse(outer_remaining)?;
+            let (indicator_maps, new_remaining) = GetKbdByNameRepliesIndicatorMaps::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             Some(indicator_maps)
         } else {
             None
         };
         let key_names = if switch_expr & u32::from(GBNDetail::KeyNames) != 0 || switch_expr & u32::from(GBNDetail::OtherNames) != 0 {
-            let (key_names, new_remaining) = GetKbdByNameRepliesKeyNames::try_parse(outer_remaining)?;
+            let (key_names, new_remaining) = GetKbdByNameRepliesKeyNames::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             Some(key_names)
         } else {
             None
         };
         let geometry = if switch_expr & u32::from(GBNDetail::Geometry) != 0 {
-            let (geometry, new_remaining) = GetKbdByNameRepliesGeometry::try_parse(outer_remaining)?;
+            let (geometry, new_remaining) = GetKbdByNameRepliesGeometry::try_parse_with_order(outer_remaining, order)?;
             outer_remaining = new_remaining;
             Some(geometry)
         } else {
@@ -9393,6 +11338,31 @@ impl GetKbdByNameReplies {
         Ok((result, outer_remaining))
     }
 }
+#[allow(dead_code, unused_variables)]
+impl GetKbdByNameReplies {
+    fn serialize_with_order(&self, order: ByteOrder, reported: GBNDetail) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order, reported);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder, reported: GBNDetail) {
+        if let Some(ref types) = self.types {
+            types.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref compat_map) = self.compat_map {
+            compat_map.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref indicator_maps) = self.indicator_maps {
+            indicator_maps.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_names) = self.key_names {
+            key_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref geometry) = self.geometry {
+            geometry.serialize_into_with_order(bytes, order);
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct GetKbdByNameReply {
@@ -9404,24 +11374,26 @@ pub struct GetKbdByNameReply {
     pub max_key_code: xproto::Keycode,
     pub loaded: bool,
     pub new_keyboard: bool,
-    pub found: u16,
-    pub reported: u16,
+    pub found: GBNDetail,
+    pub reported: GBNDetail,
     pub replies: GetKbdByNameReplies,
 }
 impl TryParse for GetKbdByNameReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (loaded, remaining) = bool::try_parse(remaining)?;
-        let (new_keyboard, remaining) = bool::try_parse(remaining)?;
-        let (found, remaining) = u16::try_parse(remaining)?;
-        let (reported, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (loaded, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (new_keyboard, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (found, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (reported, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (replies, remaining) = GetKbdByNameReplies::try_parse(remaining, reported)?;
+        let (replies, remaining) = GetKbdByNameReplies::try_parse_with_order(remaining, order, GBNDetail::from(reported))?;
+        let found = GBNDetail::from(found);
+        let reported = GBNDetail::from(reported);
         let result = GetKbdByNameReply { response_type, device_id, sequence, length, min_key_code, max_key_code, loaded, new_keyboard, found, reported, replies };
         Ok((result, remaining))
     }
@@ -9432,27 +11404,137 @@ impl TryFrom<&[u8]> for GetKbdByNameReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetKbdByNameReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        let reported = self.reported;
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.min_key_code.serialize_into_with_order(bytes, order);
+        self.max_key_code.serialize_into_with_order(bytes, order);
+        self.loaded.serialize_into_with_order(bytes, order);
+        self.new_keyboard.serialize_into_with_order(bytes, order);
+        u16::from(self.found).serialize_into_with_order(bytes, order);
+        u16::from(self.reported).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.replies.serialize_into_with_order(bytes, order, reported);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GetDeviceInfo request
 pub const GET_DEVICE_INFO_REQUEST: u8 = 24;
-pub fn get_device_info<Conn, A, B>(conn: &Conn, device_spec: DeviceSpec, wanted: A, all_buttons: bool, first_button: u8, n_buttons: u8, led_class: LedClass, led_id: B) -> Result<Cookie<'_, Conn, GetDeviceInfoReply>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetDeviceInfoRequest {
+    pub device_spec: DeviceSpec,
+    pub wanted: XIFeature,
+    pub all_buttons: bool,
+    pub first_button: u8,
+    pub n_buttons: u8,
+    pub led_class: LedClass,
+    pub led_id: IDSpec,
+}
+impl GetDeviceInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (wanted, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (all_buttons, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (first_button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_buttons, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (led_class, remaining) = LedClassSpec::try_parse_with_order(remaining, order)?;
+        let (led_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        let wanted = XIFeature::from(wanted);
+        let led_class = LedClass::from(led_class);
+        Ok(GetDeviceInfoRequest {
+            device_spec,
+            wanted,
+            all_buttons,
+            first_button,
+            n_buttons,
+            led_class,
+            led_id,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `wanted` field of this structure.
+    pub fn wanted<I>(mut self, value: I) -> Self where I: Into<XIFeature> {
+        self.wanted = value.into();
+        self
+    }
+    /// Set the `all_buttons` field of this structure.
+    pub fn all_buttons(mut self, value: bool) -> Self {
+        self.all_buttons = value;
+        self
+    }
+    /// Set the `first_button` field of this structure.
+    pub fn first_button(mut self, value: u8) -> Self {
+        self.first_button = value;
+        self
+    }
+    /// Set the `n_buttons` field of this structure.
+    pub fn n_buttons(mut self, value: u8) -> Self {
+        self.n_buttons = value;
+        self
+    }
+    /// Set the `led_class` field of this structure.
+    pub fn led_class<I>(mut self, value: I) -> Self where I: Into<LedClass> {
+        self.led_class = value.into();
+        self
+    }
+    /// Set the `led_id` field of this structure.
+    pub fn led_id<I>(mut self, value: I) -> Self where I: Into<IDSpec> {
+        self.led_id = value.into();
+        self
+    }
+}
+
+pub fn get_device_info<Conn, A, B, C>(conn: &Conn, device_spec: DeviceSpec, wanted: A, all_buttons: bool, first_button: u8, n_buttons: u8, led_class: B, led_id: C) -> Result<Cookie<'_, Conn, GetDeviceInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
-    B: Into<IDSpec>,
+    A: Into<XIFeature>,
+    B: Into<LedClass>,
+    C: Into<IDSpec>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let wanted: u16 = wanted.into();
+    let wanted: XIFeature = wanted.into();
+    let led_class: LedClass = led_class.into();
     let led_id: IDSpec = led_id.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let wanted_bytes = wanted.serialize();
-    let all_buttons_bytes = all_buttons.serialize();
-    let first_button_bytes = first_button.serialize();
-    let n_buttons_bytes = n_buttons.serialize();
-    let led_class_bytes = LedClassSpec::from(led_class).serialize();
-    let led_id_bytes = led_id.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let wanted_bytes = u16::from(wanted).serialize_with_order(order);
+    let all_buttons_bytes = all_buttons.serialize_with_order(order);
+    let first_button_bytes = first_button.serialize_with_order(order);
+    let n_buttons_bytes = n_buttons.serialize_with_order(order);
+    let led_class_bytes = u16::from(led_class).serialize_with_order(order);
+    let led_id_bytes = led_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_DEVICE_INFO_REQUEST,
@@ -9474,9 +11556,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetDeviceInfoRequest {
+    type Reply = GetDeviceInfoReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetDeviceInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetDeviceInfoReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_device_info(conn, self.device_spec, self.wanted, self.all_buttons, self.first_button, self.n_buttons, self.led_class, self.led_id)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct GetDeviceInfoReply {
@@ -9484,9 +11578,9 @@ pub struct GetDeviceInfoReply {
     pub device_id: u8,
     pub sequence: u16,
     pub length: u32,
-    pub present: u16,
-    pub supported: u16,
-    pub unsupported: u16,
+    pub present: XIFeature,
+    pub supported: XIFeature,
+    pub unsupported: XIFeature,
     pub first_btn_wanted: u8,
     pub n_btns_wanted: u8,
     pub first_btn_rtrn: u8,
@@ -9500,35 +11594,38 @@ pub struct GetDeviceInfoReply {
     pub leds: Vec<DeviceLedInfo>,
 }
 impl TryParse for GetDeviceInfoReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (present, remaining) = u16::try_parse(remaining)?;
-        let (supported, remaining) = u16::try_parse(remaining)?;
-        let (unsupported, remaining) = u16::try_parse(remaining)?;
-        let (n_device_led_f_bs, remaining) = u16::try_parse(remaining)?;
-        let (first_btn_wanted, remaining) = u8::try_parse(remaining)?;
-        let (n_btns_wanted, remaining) = u8::try_parse(remaining)?;
-        let (first_btn_rtrn, remaining) = u8::try_parse(remaining)?;
-        let (n_btns_rtrn, remaining) = u8::try_parse(remaining)?;
-        let (total_btns, remaining) = u8::try_parse(remaining)?;
-        let (has_own_state, remaining) = bool::try_parse(remaining)?;
-        let (dflt_kbd_fb, remaining) = u16::try_parse(remaining)?;
-        let (dflt_led_fb, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (present, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (unsupported, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_device_led_f_bs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_btn_wanted, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_btns_wanted, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_btn_rtrn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_btns_rtrn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_btns, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (has_own_state, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (dflt_kbd_fb, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (dflt_led_fb, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (dev_type, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
+        let (dev_type, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (btn_actions, remaining) = crate::x11_utils::parse_list::<Action>(remaining, n_btns_rtrn.try_into().or(Err(ParseError::ParseError))?)?;
-        let (leds, remaining) = crate::x11_utils::parse_list::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_into().or(Err(ParseError::ParseError))?)?;
+        let (btn_actions, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, n_btns_rtrn.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (leds, remaining) = crate::x11_utils::parse_list_with_order::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let present = XIFeature::from(present);
+        let supported = XIFeature::from(supported);
+        let unsupported = XIFeature::from(unsupported);
         let result = GetDeviceInfoReply { response_type, device_id, sequence, length, present, supported, unsupported, first_btn_wanted, n_btns_wanted, first_btn_rtrn, total_btns, has_own_state, dflt_kbd_fb, dflt_led_fb, dev_type, name, btn_actions, leds };
         Ok((result, remaining))
     }
@@ -9539,25 +11636,130 @@ impl TryFrom<&[u8]> for GetDeviceInfoReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetDeviceInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u16::from(self.present).serialize_into_with_order(bytes, order);
+        u16::from(self.supported).serialize_into_with_order(bytes, order);
+        u16::from(self.unsupported).serialize_into_with_order(bytes, order);
+        let n_device_led_f_bs = u16::try_from(self.leds.len()).expect("`leds` has too many elements");
+        n_device_led_f_bs.serialize_into_with_order(bytes, order);
+        self.first_btn_wanted.serialize_into_with_order(bytes, order);
+        self.n_btns_wanted.serialize_into_with_order(bytes, order);
+        self.first_btn_rtrn.serialize_into_with_order(bytes, order);
+        let n_btns_rtrn = u8::try_from(self.btn_actions.len()).expect("`btn_actions` has too many elements");
+        n_btns_rtrn.serialize_into_with_order(bytes, order);
+        self.total_btns.serialize_into_with_order(bytes, order);
+        self.has_own_state.serialize_into_with_order(bytes, order);
+        self.dflt_kbd_fb.serialize_into_with_order(bytes, order);
+        self.dflt_led_fb.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.dev_type.serialize_into_with_order(bytes, order);
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        name_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        self.btn_actions.serialize_into_with_order(bytes, order);
+        self.leds.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetDeviceInfo request
 pub const SET_DEVICE_INFO_REQUEST: u8 = 25;
+#[derive(Debug, Clone, Default)]
+pub struct SetDeviceInfoRequest {
+    pub device_spec: DeviceSpec,
+    pub first_btn: u8,
+    pub change: XIFeature,
+    pub btn_actions: Vec<Action>,
+    pub leds: Vec<DeviceLedInfo>,
+}
+impl SetDeviceInfoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_DEVICE_INFO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(remaining, order)?;
+        let (first_btn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_btns, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (change, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_device_led_f_bs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (btn_actions, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, n_btns.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (leds, remaining) = crate::x11_utils::parse_list_with_order::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let _ = remaining;
+        let _ = order;
+        let change = XIFeature::from(change);
+        Ok(SetDeviceInfoRequest {
+            device_spec,
+            first_btn,
+            change,
+            btn_actions,
+            leds,
+        })
+    }
+    /// Set the `device_spec` field of this structure.
+    pub fn device_spec<I>(mut self, value: I) -> Self where I: Into<DeviceSpec> {
+        self.device_spec = value.into();
+        self
+    }
+    /// Set the `first_btn` field of this structure.
+    pub fn first_btn(mut self, value: u8) -> Self {
+        self.first_btn = value;
+        self
+    }
+    /// Set the `change` field of this structure.
+    pub fn change<I>(mut self, value: I) -> Self where I: Into<XIFeature> {
+        self.change = value.into();
+        self
+    }
+    /// Set the `btn_actions` field of this structure.
+    pub fn btn_actions<I>(mut self, value: I) -> Self where I: Into<Vec<Action>> {
+        self.btn_actions = value.into();
+        self
+    }
+    /// Set the `leds` field of this structure.
+    pub fn leds<I>(mut self, value: I) -> Self where I: Into<Vec<DeviceLedInfo>> {
+        self.leds = value.into();
+        self
+    }
+}
+
 pub fn set_device_info<'c, Conn, A>(conn: &'c Conn, device_spec: DeviceSpec, first_btn: u8, change: A, btn_actions: &[Action], leds: &[DeviceLedInfo]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<u16>,
+    A: Into<XIFeature>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
-    let change: u16 = change.into();
+    let change: XIFeature = change.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let device_spec_bytes = device_spec.serialize();
-    let first_btn_bytes = first_btn.serialize();
+    let device_spec_bytes = device_spec.serialize_with_order(order);
+    let first_btn_bytes = first_btn.serialize_with_order(order);
     let n_btns = u8::try_from(btn_actions.len()).expect("`btn_actions` has too many elements");
-    let n_btns_bytes = n_btns.serialize();
-    let change_bytes = change.serialize();
+    let n_btns_bytes = n_btns.serialize_with_order(order);
+    let change_bytes = u16::from(change).serialize_with_order(order);
     let n_device_led_f_bs = u16::try_from(leds.len()).expect("`leds` has too many elements");
-    let n_device_led_f_bs_bytes = n_device_led_f_bs.serialize();
+    let n_device_led_f_bs_bytes = n_device_led_f_bs.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_DEVICE_INFO_REQUEST,
@@ -9573,33 +11775,106 @@ where
         n_device_led_f_bs_bytes[1],
     ];
     let length_so_far = length_so_far + request0.len();
-    let btn_actions_bytes = btn_actions.serialize();
+    let btn_actions_bytes = btn_actions.serialize_with_order(order);
     let length_so_far = length_so_far + btn_actions_bytes.len();
-    let leds_bytes = leds.serialize();
+    let leds_bytes = leds.serialize_with_order(order);
     let length_so_far = length_so_far + leds_bytes.len();
     let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(&btn_actions_bytes), IoSlice::new(&leds_bytes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetDeviceInfoRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetDeviceInfoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_device_info(conn, self.device_spec, self.first_btn, self.change, &self.btn_actions, &self.leds)
+    }
+}
 
 /// Opcode for the SetDebuggingFlags request
 pub const SET_DEBUGGING_FLAGS_REQUEST: u8 = 101;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetDebuggingFlagsRequest {
+    pub affect_flags: u32,
+    pub flags: u32,
+    pub affect_ctrls: u32,
+    pub ctrls: u32,
+    pub message: Vec<String8>,
+}
+impl SetDebuggingFlagsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_DEBUGGING_FLAGS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (msg_length, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (affect_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (affect_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (message, remaining) = crate::x11_utils::parse_u8_list(remaining, msg_length.try_into().or(Err(ParseError::ParseError))?)?;
+        let message = message.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(SetDebuggingFlagsRequest {
+            affect_flags,
+            flags,
+            affect_ctrls,
+            ctrls,
+            message,
+        })
+    }
+    /// Set the `affect_flags` field of this structure.
+    pub fn affect_flags(mut self, value: u32) -> Self {
+        self.affect_flags = value;
+        self
+    }
+    /// Set the `flags` field of this structure.
+    pub fn flags(mut self, value: u32) -> Self {
+        self.flags = value;
+        self
+    }
+    /// Set the `affect_ctrls` field of this structure.
+    pub fn affect_ctrls(mut self, value: u32) -> Self {
+        self.affect_ctrls = value;
+        self
+    }
+    /// Set the `ctrls` field of this structure.
+    pub fn ctrls(mut self, value: u32) -> Self {
+        self.ctrls = value;
+        self
+    }
+    /// Set the `message` field of this structure.
+    pub fn message<I>(mut self, value: I) -> Self where I: Into<Vec<String8>> {
+        self.message = value.into();
+        self
+    }
+}
+
 pub fn set_debugging_flags<'c, Conn>(conn: &'c Conn, affect_flags: u32, flags: u32, affect_ctrls: u32, ctrls: u32, message: &[String8]) -> Result<Cookie<'c, Conn, SetDebuggingFlagsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let msg_length = u16::try_from(message.len()).expect("`message` has too many elements");
-    let msg_length_bytes = msg_length.serialize();
-    let affect_flags_bytes = affect_flags.serialize();
-    let flags_bytes = flags.serialize();
-    let affect_ctrls_bytes = affect_ctrls.serialize();
-    let ctrls_bytes = ctrls.serialize();
+    let msg_length_bytes = msg_length.serialize_with_order(order);
+    let affect_flags_bytes = affect_flags.serialize_with_order(order);
+    let flags_bytes = flags.serialize_with_order(order);
+    let affect_ctrls_bytes = affect_ctrls.serialize_with_order(order);
+    let ctrls_bytes = ctrls.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_DEBUGGING_FLAGS_REQUEST,
@@ -9632,9 +11907,21 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(message), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetDebuggingFlagsRequest {
+    type Reply = SetDebuggingFlagsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetDebuggingFlagsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, SetDebuggingFlagsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_debugging_flags(conn, self.affect_flags, self.flags, self.affect_ctrls, self.ctrls, &self.message)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDebuggingFlagsReply {
@@ -9647,15 +11934,15 @@ pub struct SetDebuggingFlagsReply {
     pub supported_ctrls: u32,
 }
 impl TryParse for SetDebuggingFlagsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (current_flags, remaining) = u32::try_parse(remaining)?;
-        let (current_ctrls, remaining) = u32::try_parse(remaining)?;
-        let (supported_flags, remaining) = u32::try_parse(remaining)?;
-        let (supported_ctrls, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (current_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (current_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (supported_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (supported_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
         let result = SetDebuggingFlagsReply { response_type, sequence, length, current_flags, current_ctrls, supported_flags, supported_ctrls };
         Ok((result, remaining))
@@ -9667,6 +11954,33 @@ impl TryFrom<&[u8]> for SetDebuggingFlagsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for SetDebuggingFlagsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.current_flags.serialize_into_with_order(bytes, order);
+        self.current_ctrls.serialize_into_with_order(bytes, order);
+        self.supported_flags.serialize_into_with_order(bytes, order);
+        self.supported_ctrls.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the NewKeyboardNotify event
 pub const NEW_KEYBOARD_NOTIFY_EVENT: u8 = 0;
@@ -9684,24 +11998,25 @@ pub struct NewKeyboardNotifyEvent {
     pub old_max_key_code: xproto::Keycode,
     pub request_major: u8,
     pub request_minor: u8,
-    pub changed: u16,
+    pub changed: NKNDetail,
 }
 impl TryParse for NewKeyboardNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (old_device_id, remaining) = u8::try_parse(remaining)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (old_min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (old_max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (request_major, remaining) = u8::try_parse(remaining)?;
-        let (request_minor, remaining) = u8::try_parse(remaining)?;
-        let (changed, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (old_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (old_min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (old_max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (request_major, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_minor, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(14..).ok_or(ParseError::ParseError)?;
+        let changed = NKNDetail::from(changed);
         let result = NewKeyboardNotifyEvent { response_type, xkb_type, sequence, time, device_id, old_device_id, min_key_code, max_key_code, old_min_key_code, old_max_key_code, request_major, request_minor, changed };
         Ok((result, remaining))
     }
@@ -9714,19 +12029,30 @@ impl TryFrom<&[u8]> for NewKeyboardNotifyEvent {
 }
 impl From<&NewKeyboardNotifyEvent> for [u8; 32] {
     fn from(input: &NewKeyboardNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let old_device_id_bytes = input.old_device_id.serialize();
-        let min_key_code_bytes = input.min_key_code.serialize();
-        let max_key_code_bytes = input.max_key_code.serialize();
-        let old_min_key_code_bytes = input.old_min_key_code.serialize();
-        let old_max_key_code_bytes = input.old_max_key_code.serialize();
-        let request_major_bytes = input.request_major.serialize();
-        let request_minor_bytes = input.request_minor.serialize();
-        let changed_bytes = input.changed.serialize();
+        input.serialize()
+    }
+}
+impl From<NewKeyboardNotifyEvent> for [u8; 32] {
+    fn from(input: NewKeyboardNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for NewKeyboardNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let old_device_id_bytes = self.old_device_id.serialize_with_order(order);
+        let min_key_code_bytes = self.min_key_code.serialize_with_order(order);
+        let max_key_code_bytes = self.max_key_code.serialize_with_order(order);
+        let old_min_key_code_bytes = self.old_min_key_code.serialize_with_order(order);
+        let old_max_key_code_bytes = self.old_max_key_code.serialize_with_order(order);
+        let request_major_bytes = self.request_major.serialize_with_order(order);
+        let request_minor_bytes = self.request_minor.serialize_with_order(order);
+        let changed_bytes = u16::from(self.changed).serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -9762,10 +12088,8 @@ impl From<&NewKeyboardNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<NewKeyboardNotifyEvent> for [u8; 32] {
-    fn from(input: NewKeyboardNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -9779,7 +12103,7 @@ pub struct MapNotifyEvent {
     pub time: xproto::Timestamp,
     pub device_id: u8,
     pub ptr_btn_actions: u8,
-    pub changed: u16,
+    pub changed: MapPart,
     pub min_key_code: xproto::Keycode,
     pub max_key_code: xproto::Keycode,
     pub first_type: u8,
@@ -9796,35 +12120,37 @@ pub struct MapNotifyEvent {
     pub n_mod_map_keys: u8,
     pub first_v_mod_map_key: xproto::Keycode,
     pub n_v_mod_map_keys: u8,
-    pub virtual_mods: u16,
+    pub virtual_mods: VMod,
 }
 impl TryParse for MapNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (ptr_btn_actions, remaining) = u8::try_parse(remaining)?;
-        let (changed, remaining) = u16::try_parse(remaining)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (first_key_sym, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_syms, remaining) = u8::try_parse(remaining)?;
-        let (first_key_act, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_acts, remaining) = u8::try_parse(remaining)?;
-        let (first_key_behavior, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_behavior, remaining) = u8::try_parse(remaining)?;
-        let (first_key_explicit, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ptr_btn_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_act, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_acts, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behavior, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let changed = MapPart::from(changed);
+        let virtual_mods = VMod::from(virtual_mods);
         let result = MapNotifyEvent { response_type, xkb_type, sequence, time, device_id, ptr_btn_actions, changed, min_key_code, max_key_code, first_type, n_types, first_key_sym, n_key_syms, first_key_act, n_key_acts, first_key_behavior, n_key_behavior, first_key_explicit, n_key_explicit, first_mod_map_key, n_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, virtual_mods };
         Ok((result, remaining))
     }
@@ -9837,30 +12163,41 @@ impl TryFrom<&[u8]> for MapNotifyEvent {
 }
 impl From<&MapNotifyEvent> for [u8; 32] {
     fn from(input: &MapNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let ptr_btn_actions_bytes = input.ptr_btn_actions.serialize();
-        let changed_bytes = input.changed.serialize();
-        let min_key_code_bytes = input.min_key_code.serialize();
-        let max_key_code_bytes = input.max_key_code.serialize();
-        let first_type_bytes = input.first_type.serialize();
-        let n_types_bytes = input.n_types.serialize();
-        let first_key_sym_bytes = input.first_key_sym.serialize();
-        let n_key_syms_bytes = input.n_key_syms.serialize();
-        let first_key_act_bytes = input.first_key_act.serialize();
-        let n_key_acts_bytes = input.n_key_acts.serialize();
-        let first_key_behavior_bytes = input.first_key_behavior.serialize();
-        let n_key_behavior_bytes = input.n_key_behavior.serialize();
-        let first_key_explicit_bytes = input.first_key_explicit.serialize();
-        let n_key_explicit_bytes = input.n_key_explicit.serialize();
-        let first_mod_map_key_bytes = input.first_mod_map_key.serialize();
-        let n_mod_map_keys_bytes = input.n_mod_map_keys.serialize();
-        let first_v_mod_map_key_bytes = input.first_v_mod_map_key.serialize();
-        let n_v_mod_map_keys_bytes = input.n_v_mod_map_keys.serialize();
-        let virtual_mods_bytes = input.virtual_mods.serialize();
+        input.serialize()
+    }
+}
+impl From<MapNotifyEvent> for [u8; 32] {
+    fn from(input: MapNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for MapNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let ptr_btn_actions_bytes = self.ptr_btn_actions.serialize_with_order(order);
+        let changed_bytes = u16::from(self.changed).serialize_with_order(order);
+        let min_key_code_bytes = self.min_key_code.serialize_with_order(order);
+        let max_key_code_bytes = self.max_key_code.serialize_with_order(order);
+        let first_type_bytes = self.first_type.serialize_with_order(order);
+        let n_types_bytes = self.n_types.serialize_with_order(order);
+        let first_key_sym_bytes = self.first_key_sym.serialize_with_order(order);
+        let n_key_syms_bytes = self.n_key_syms.serialize_with_order(order);
+        let first_key_act_bytes = self.first_key_act.serialize_with_order(order);
+        let n_key_acts_bytes = self.n_key_acts.serialize_with_order(order);
+        let first_key_behavior_bytes = self.first_key_behavior.serialize_with_order(order);
+        let n_key_behavior_bytes = self.n_key_behavior.serialize_with_order(order);
+        let first_key_explicit_bytes = self.first_key_explicit.serialize_with_order(order);
+        let n_key_explicit_bytes = self.n_key_explicit.serialize_with_order(order);
+        let first_mod_map_key_bytes = self.first_mod_map_key.serialize_with_order(order);
+        let n_mod_map_keys_bytes = self.n_mod_map_keys.serialize_with_order(order);
+        let first_v_mod_map_key_bytes = self.first_v_mod_map_key.serialize_with_order(order);
+        let n_v_mod_map_keys_bytes = self.n_v_mod_map_keys.serialize_with_order(order);
+        let virtual_mods_bytes = u16::from(self.virtual_mods).serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -9896,10 +12233,8 @@ impl From<&MapNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<MapNotifyEvent> for [u8; 32] {
-    fn from(input: MapNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -9912,54 +12247,65 @@ pub struct StateNotifyEvent {
     pub sequence: u16,
     pub time: xproto::Timestamp,
     pub device_id: u8,
-    pub mods: u8,
-    pub base_mods: u8,
-    pub latched_mods: u8,
-    pub locked_mods: u8,
+    pub mods: xproto::ModMask,
+    pub base_mods: xproto::ModMask,
+    pub latched_mods: xproto::ModMask,
+    pub locked_mods: xproto::ModMask,
     pub group: Group,
     pub base_group: i16,
     pub latched_group: i16,
     pub locked_group: Group,
-    pub compat_state: u8,
-    pub grab_mods: u8,
-    pub compat_grab_mods: u8,
-    pub lookup_mods: u8,
-    pub compat_loockup_mods: u8,
-    pub ptr_btn_state: u16,
-    pub changed: u16,
+    pub compat_state: xproto::ModMask,
+    pub grab_mods: xproto::ModMask,
+    pub compat_grab_mods: xproto::ModMask,
+    pub lookup_mods: xproto::ModMask,
+    pub compat_loockup_mods: xproto::ModMask,
+    pub ptr_btn_state: xproto::KeyButMask,
+    pub changed: StatePart,
     pub keycode: xproto::Keycode,
     pub event_type: u8,
     pub request_major: u8,
     pub request_minor: u8,
 }
 impl TryParse for StateNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (base_mods, remaining) = u8::try_parse(remaining)?;
-        let (latched_mods, remaining) = u8::try_parse(remaining)?;
-        let (locked_mods, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = u8::try_parse(remaining)?;
-        let (base_group, remaining) = i16::try_parse(remaining)?;
-        let (latched_group, remaining) = i16::try_parse(remaining)?;
-        let (locked_group, remaining) = u8::try_parse(remaining)?;
-        let (compat_state, remaining) = u8::try_parse(remaining)?;
-        let (grab_mods, remaining) = u8::try_parse(remaining)?;
-        let (compat_grab_mods, remaining) = u8::try_parse(remaining)?;
-        let (lookup_mods, remaining) = u8::try_parse(remaining)?;
-        let (compat_loockup_mods, remaining) = u8::try_parse(remaining)?;
-        let (ptr_btn_state, remaining) = u16::try_parse(remaining)?;
-        let (changed, remaining) = u16::try_parse(remaining)?;
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (event_type, remaining) = u8::try_parse(remaining)?;
-        let (request_major, remaining) = u8::try_parse(remaining)?;
-        let (request_minor, remaining) = u8::try_parse(remaining)?;
-        let group = group.try_into()?;
-        let locked_group = locked_group.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (base_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (latched_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (locked_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (base_group, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (latched_group, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (locked_group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (grab_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_grab_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (lookup_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_loockup_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ptr_btn_state, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (changed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_major, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_minor, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let mods = xproto::ModMask::from(u16::from(mods));
+        let base_mods = xproto::ModMask::from(u16::from(base_mods));
+        let latched_mods = xproto::ModMask::from(u16::from(latched_mods));
+        let locked_mods = xproto::ModMask::from(u16::from(locked_mods));
+        let group = Group::from(group);
+        let locked_group = Group::from(locked_group);
+        let compat_state = xproto::ModMask::from(u16::from(compat_state));
+        let grab_mods = xproto::ModMask::from(u16::from(grab_mods));
+        let compat_grab_mods = xproto::ModMask::from(u16::from(compat_grab_mods));
+        let lookup_mods = xproto::ModMask::from(u16::from(lookup_mods));
+        let compat_loockup_mods = xproto::ModMask::from(u16::from(compat_loockup_mods));
+        let ptr_btn_state = xproto::KeyButMask::from(ptr_btn_state);
+        let changed = StatePart::from(changed);
         let result = StateNotifyEvent { response_type, xkb_type, sequence, time, device_id, mods, base_mods, latched_mods, locked_mods, group, base_group, latched_group, locked_group, compat_state, grab_mods, compat_grab_mods, lookup_mods, compat_loockup_mods, ptr_btn_state, changed, keycode, event_type, request_major, request_minor };
         Ok((result, remaining))
     }
@@ -9972,30 +12318,41 @@ impl TryFrom<&[u8]> for StateNotifyEvent {
 }
 impl From<&StateNotifyEvent> for [u8; 32] {
     fn from(input: &StateNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let mods_bytes = input.mods.serialize();
-        let base_mods_bytes = input.base_mods.serialize();
-        let latched_mods_bytes = input.latched_mods.serialize();
-        let locked_mods_bytes = input.locked_mods.serialize();
-        let group_bytes = u8::from(input.group).serialize();
-        let base_group_bytes = input.base_group.serialize();
-        let latched_group_bytes = input.latched_group.serialize();
-        let locked_group_bytes = u8::from(input.locked_group).serialize();
-        let compat_state_bytes = input.compat_state.serialize();
-        let grab_mods_bytes = input.grab_mods.serialize();
-        let compat_grab_mods_bytes = input.compat_grab_mods.serialize();
-        let lookup_mods_bytes = input.lookup_mods.serialize();
-        let compat_loockup_mods_bytes = input.compat_loockup_mods.serialize();
-        let ptr_btn_state_bytes = input.ptr_btn_state.serialize();
-        let changed_bytes = input.changed.serialize();
-        let keycode_bytes = input.keycode.serialize();
-        let event_type_bytes = input.event_type.serialize();
-        let request_major_bytes = input.request_major.serialize();
-        let request_minor_bytes = input.request_minor.serialize();
+        input.serialize()
+    }
+}
+impl From<StateNotifyEvent> for [u8; 32] {
+    fn from(input: StateNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for StateNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let mods_bytes = u8::try_from(u16::from(self.mods)).unwrap().serialize_with_order(order);
+        let base_mods_bytes = u8::try_from(u16::from(self.base_mods)).unwrap().serialize_with_order(order);
+        let latched_mods_bytes = u8::try_from(u16::from(self.latched_mods)).unwrap().serialize_with_order(order);
+        let locked_mods_bytes = u8::try_from(u16::from(self.locked_mods)).unwrap().serialize_with_order(order);
+        let group_bytes = u8::from(self.group).serialize_with_order(order);
+        let base_group_bytes = self.base_group.serialize_with_order(order);
+        let latched_group_bytes = self.latched_group.serialize_with_order(order);
+        let locked_group_bytes = u8::from(self.locked_group).serialize_with_order(order);
+        let compat_state_bytes = u8::try_from(u16::from(self.compat_state)).unwrap().serialize_with_order(order);
+        let grab_mods_bytes = u8::try_from(u16::from(self.grab_mods)).unwrap().serialize_with_order(order);
+        let compat_grab_mods_bytes = u8::try_from(u16::from(self.compat_grab_mods)).unwrap().serialize_with_order(order);
+        let lookup_mods_bytes = u8::try_from(u16::from(self.lookup_mods)).unwrap().serialize_with_order(order);
+        let compat_loockup_mods_bytes = u8::try_from(u16::from(self.compat_loockup_mods)).unwrap().serialize_with_order(order);
+        let ptr_btn_state_bytes = u16::from(self.ptr_btn_state).serialize_with_order(order);
+        let changed_bytes = u16::from(self.changed).serialize_with_order(order);
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let event_type_bytes = self.event_type.serialize_with_order(order);
+        let request_major_bytes = self.request_major.serialize_with_order(order);
+        let request_minor_bytes = self.request_minor.serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10031,10 +12388,8 @@ impl From<&StateNotifyEvent> for [u8; 32] {
             request_minor_bytes[0],
         ]
     }
-}
-impl From<StateNotifyEvent> for [u8; 32] {
-    fn from(input: StateNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10048,31 +12403,34 @@ pub struct ControlsNotifyEvent {
     pub time: xproto::Timestamp,
     pub device_id: u8,
     pub num_groups: u8,
-    pub changed_controls: u32,
-    pub enabled_controls: u32,
-    pub enabled_control_changes: u32,
+    pub changed_controls: Control,
+    pub enabled_controls: BoolCtrl,
+    pub enabled_control_changes: BoolCtrl,
     pub keycode: xproto::Keycode,
     pub event_type: u8,
     pub request_major: u8,
     pub request_minor: u8,
 }
 impl TryParse for ControlsNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (num_groups, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (changed_controls, remaining) = u32::try_parse(remaining)?;
-        let (enabled_controls, remaining) = u32::try_parse(remaining)?;
-        let (enabled_control_changes, remaining) = u32::try_parse(remaining)?;
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (event_type, remaining) = u8::try_parse(remaining)?;
-        let (request_major, remaining) = u8::try_parse(remaining)?;
-        let (request_minor, remaining) = u8::try_parse(remaining)?;
+        let (changed_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (enabled_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (enabled_control_changes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_major, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_minor, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
+        let changed_controls = Control::from(changed_controls);
+        let enabled_controls = BoolCtrl::from(enabled_controls);
+        let enabled_control_changes = BoolCtrl::from(enabled_control_changes);
         let result = ControlsNotifyEvent { response_type, xkb_type, sequence, time, device_id, num_groups, changed_controls, enabled_controls, enabled_control_changes, keycode, event_type, request_major, request_minor };
         Ok((result, remaining))
     }
@@ -10085,19 +12443,30 @@ impl TryFrom<&[u8]> for ControlsNotifyEvent {
 }
 impl From<&ControlsNotifyEvent> for [u8; 32] {
     fn from(input: &ControlsNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let num_groups_bytes = input.num_groups.serialize();
-        let changed_controls_bytes = input.changed_controls.serialize();
-        let enabled_controls_bytes = input.enabled_controls.serialize();
-        let enabled_control_changes_bytes = input.enabled_control_changes.serialize();
-        let keycode_bytes = input.keycode.serialize();
-        let event_type_bytes = input.event_type.serialize();
-        let request_major_bytes = input.request_major.serialize();
-        let request_minor_bytes = input.request_minor.serialize();
+        input.serialize()
+    }
+}
+impl From<ControlsNotifyEvent> for [u8; 32] {
+    fn from(input: ControlsNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ControlsNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let num_groups_bytes = self.num_groups.serialize_with_order(order);
+        let changed_controls_bytes = u32::from(self.changed_controls).serialize_with_order(order);
+        let enabled_controls_bytes = u32::from(self.enabled_controls).serialize_with_order(order);
+        let enabled_control_changes_bytes = u32::from(self.enabled_control_changes).serialize_with_order(order);
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let event_type_bytes = self.event_type.serialize_with_order(order);
+        let request_major_bytes = self.request_major.serialize_with_order(order);
+        let request_minor_bytes = self.request_minor.serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10133,10 +12502,8 @@ impl From<&ControlsNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ControlsNotifyEvent> for [u8; 32] {
-    fn from(input: ControlsNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10153,15 +12520,15 @@ pub struct IndicatorStateNotifyEvent {
     pub state_changed: u32,
 }
 impl TryParse for IndicatorStateNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (state, remaining) = u32::try_parse(remaining)?;
-        let (state_changed, remaining) = u32::try_parse(remaining)?;
+        let (state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (state_changed, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let result = IndicatorStateNotifyEvent { response_type, xkb_type, sequence, time, device_id, state, state_changed };
         Ok((result, remaining))
@@ -10175,13 +12542,24 @@ impl TryFrom<&[u8]> for IndicatorStateNotifyEvent {
 }
 impl From<&IndicatorStateNotifyEvent> for [u8; 32] {
     fn from(input: &IndicatorStateNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let state_bytes = input.state.serialize();
-        let state_changed_bytes = input.state_changed.serialize();
+        input.serialize()
+    }
+}
+impl From<IndicatorStateNotifyEvent> for [u8; 32] {
+    fn from(input: IndicatorStateNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for IndicatorStateNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let state_bytes = self.state.serialize_with_order(order);
+        let state_changed_bytes = self.state_changed.serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10217,10 +12595,8 @@ impl From<&IndicatorStateNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<IndicatorStateNotifyEvent> for [u8; 32] {
-    fn from(input: IndicatorStateNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10237,15 +12613,15 @@ pub struct IndicatorMapNotifyEvent {
     pub map_changed: u32,
 }
 impl TryParse for IndicatorMapNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (state, remaining) = u32::try_parse(remaining)?;
-        let (map_changed, remaining) = u32::try_parse(remaining)?;
+        let (state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (map_changed, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let result = IndicatorMapNotifyEvent { response_type, xkb_type, sequence, time, device_id, state, map_changed };
         Ok((result, remaining))
@@ -10259,13 +12635,24 @@ impl TryFrom<&[u8]> for IndicatorMapNotifyEvent {
 }
 impl From<&IndicatorMapNotifyEvent> for [u8; 32] {
     fn from(input: &IndicatorMapNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let state_bytes = input.state.serialize();
-        let map_changed_bytes = input.map_changed.serialize();
+        input.serialize()
+    }
+}
+impl From<IndicatorMapNotifyEvent> for [u8; 32] {
+    fn from(input: IndicatorMapNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for IndicatorMapNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let state_bytes = self.state.serialize_with_order(order);
+        let map_changed_bytes = self.map_changed.serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10301,10 +12688,8 @@ impl From<&IndicatorMapNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<IndicatorMapNotifyEvent> for [u8; 32] {
-    fn from(input: IndicatorMapNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10317,41 +12702,44 @@ pub struct NamesNotifyEvent {
     pub sequence: u16,
     pub time: xproto::Timestamp,
     pub device_id: u8,
-    pub changed: u16,
+    pub changed: NameDetail,
     pub first_type: u8,
     pub n_types: u8,
     pub first_level_name: u8,
     pub n_level_names: u8,
     pub n_radio_groups: u8,
     pub n_key_aliases: u8,
-    pub changed_group_names: u8,
-    pub changed_virtual_mods: u16,
+    pub changed_group_names: SetOfGroup,
+    pub changed_virtual_mods: VMod,
     pub first_key: xproto::Keycode,
     pub n_keys: u8,
     pub changed_indicators: u32,
 }
 impl TryParse for NamesNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (changed, remaining) = u16::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (first_level_name, remaining) = u8::try_parse(remaining)?;
-        let (n_level_names, remaining) = u8::try_parse(remaining)?;
+        let (changed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_level_name, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_level_names, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (n_radio_groups, remaining) = u8::try_parse(remaining)?;
-        let (n_key_aliases, remaining) = u8::try_parse(remaining)?;
-        let (changed_group_names, remaining) = u8::try_parse(remaining)?;
-        let (changed_virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (first_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let (changed_indicators, remaining) = u32::try_parse(remaining)?;
+        let (n_radio_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed_group_names, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed_indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(4..).ok_or(ParseError::ParseError)?;
+        let changed = NameDetail::from(u32::from(changed));
+        let changed_group_names = SetOfGroup::from(changed_group_names);
+        let changed_virtual_mods = VMod::from(changed_virtual_mods);
         let result = NamesNotifyEvent { response_type, xkb_type, sequence, time, device_id, changed, first_type, n_types, first_level_name, n_level_names, n_radio_groups, n_key_aliases, changed_group_names, changed_virtual_mods, first_key, n_keys, changed_indicators };
         Ok((result, remaining))
     }
@@ -10364,23 +12752,34 @@ impl TryFrom<&[u8]> for NamesNotifyEvent {
 }
 impl From<&NamesNotifyEvent> for [u8; 32] {
     fn from(input: &NamesNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let changed_bytes = input.changed.serialize();
-        let first_type_bytes = input.first_type.serialize();
-        let n_types_bytes = input.n_types.serialize();
-        let first_level_name_bytes = input.first_level_name.serialize();
-        let n_level_names_bytes = input.n_level_names.serialize();
-        let n_radio_groups_bytes = input.n_radio_groups.serialize();
-        let n_key_aliases_bytes = input.n_key_aliases.serialize();
-        let changed_group_names_bytes = input.changed_group_names.serialize();
-        let changed_virtual_mods_bytes = input.changed_virtual_mods.serialize();
-        let first_key_bytes = input.first_key.serialize();
-        let n_keys_bytes = input.n_keys.serialize();
-        let changed_indicators_bytes = input.changed_indicators.serialize();
+        input.serialize()
+    }
+}
+impl From<NamesNotifyEvent> for [u8; 32] {
+    fn from(input: NamesNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for NamesNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let changed_bytes = u16::try_from(u32::from(self.changed)).unwrap().serialize_with_order(order);
+        let first_type_bytes = self.first_type.serialize_with_order(order);
+        let n_types_bytes = self.n_types.serialize_with_order(order);
+        let first_level_name_bytes = self.first_level_name.serialize_with_order(order);
+        let n_level_names_bytes = self.n_level_names.serialize_with_order(order);
+        let n_radio_groups_bytes = self.n_radio_groups.serialize_with_order(order);
+        let n_key_aliases_bytes = self.n_key_aliases.serialize_with_order(order);
+        let changed_group_names_bytes = u8::from(self.changed_group_names).serialize_with_order(order);
+        let changed_virtual_mods_bytes = u16::from(self.changed_virtual_mods).serialize_with_order(order);
+        let first_key_bytes = self.first_key.serialize_with_order(order);
+        let n_keys_bytes = self.n_keys.serialize_with_order(order);
+        let changed_indicators_bytes = self.changed_indicators.serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10416,10 +12815,8 @@ impl From<&NamesNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<NamesNotifyEvent> for [u8; 32] {
-    fn from(input: NamesNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10432,23 +12829,24 @@ pub struct CompatMapNotifyEvent {
     pub sequence: u16,
     pub time: xproto::Timestamp,
     pub device_id: u8,
-    pub changed_groups: u8,
+    pub changed_groups: SetOfGroup,
     pub first_si: u16,
     pub n_si: u16,
     pub n_total_si: u16,
 }
 impl TryParse for CompatMapNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (changed_groups, remaining) = u8::try_parse(remaining)?;
-        let (first_si, remaining) = u16::try_parse(remaining)?;
-        let (n_si, remaining) = u16::try_parse(remaining)?;
-        let (n_total_si, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_total_si, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
+        let changed_groups = SetOfGroup::from(changed_groups);
         let result = CompatMapNotifyEvent { response_type, xkb_type, sequence, time, device_id, changed_groups, first_si, n_si, n_total_si };
         Ok((result, remaining))
     }
@@ -10461,15 +12859,26 @@ impl TryFrom<&[u8]> for CompatMapNotifyEvent {
 }
 impl From<&CompatMapNotifyEvent> for [u8; 32] {
     fn from(input: &CompatMapNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let changed_groups_bytes = input.changed_groups.serialize();
-        let first_si_bytes = input.first_si.serialize();
-        let n_si_bytes = input.n_si.serialize();
-        let n_total_si_bytes = input.n_total_si.serialize();
+        input.serialize()
+    }
+}
+impl From<CompatMapNotifyEvent> for [u8; 32] {
+    fn from(input: CompatMapNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for CompatMapNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let changed_groups_bytes = u8::from(self.changed_groups).serialize_with_order(order);
+        let first_si_bytes = self.first_si.serialize_with_order(order);
+        let n_si_bytes = self.n_si.serialize_with_order(order);
+        let n_total_si_bytes = self.n_total_si.serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10505,10 +12914,8 @@ impl From<&CompatMapNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<CompatMapNotifyEvent> for [u8; 32] {
-    fn from(input: CompatMapNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10531,22 +12938,22 @@ pub struct BellNotifyEvent {
     pub event_only: bool,
 }
 impl TryParse for BellNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (bell_class, remaining) = u8::try_parse(remaining)?;
-        let (bell_id, remaining) = u8::try_parse(remaining)?;
-        let (percent, remaining) = u8::try_parse(remaining)?;
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event_only, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bell_class, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bell_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (percent, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (pitch, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event_only, remaining) = bool::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(7..).ok_or(ParseError::ParseError)?;
-        let bell_class = bell_class.try_into()?;
+        let bell_class = BellClassResult::from(bell_class);
         let result = BellNotifyEvent { response_type, xkb_type, sequence, time, device_id, bell_class, bell_id, percent, pitch, duration, name, window, event_only };
         Ok((result, remaining))
     }
@@ -10559,19 +12966,30 @@ impl TryFrom<&[u8]> for BellNotifyEvent {
 }
 impl From<&BellNotifyEvent> for [u8; 32] {
     fn from(input: &BellNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let bell_class_bytes = u8::from(input.bell_class).serialize();
-        let bell_id_bytes = input.bell_id.serialize();
-        let percent_bytes = input.percent.serialize();
-        let pitch_bytes = input.pitch.serialize();
-        let duration_bytes = input.duration.serialize();
-        let name_bytes = input.name.serialize();
-        let window_bytes = input.window.serialize();
-        let event_only_bytes = input.event_only.serialize();
+        input.serialize()
+    }
+}
+impl From<BellNotifyEvent> for [u8; 32] {
+    fn from(input: BellNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BellNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let bell_class_bytes = u8::from(self.bell_class).serialize_with_order(order);
+        let bell_id_bytes = self.bell_id.serialize_with_order(order);
+        let percent_bytes = self.percent.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let duration_bytes = self.duration.serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let event_only_bytes = self.event_only.serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10607,10 +13025,8 @@ impl From<&BellNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BellNotifyEvent> for [u8; 32] {
-    fn from(input: BellNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10626,26 +13042,27 @@ pub struct ActionMessageEvent {
     pub keycode: xproto::Keycode,
     pub press: bool,
     pub key_event_follows: bool,
-    pub mods: u8,
+    pub mods: xproto::ModMask,
     pub group: Group,
     pub message: [String8; 8],
 }
 impl TryParse for ActionMessageEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (press, remaining) = bool::try_parse(remaining)?;
-        let (key_event_follows, remaining) = bool::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (press, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (key_event_follows, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = u8::try_parse_with_order(remaining, order)?;
         let (message, remaining) = crate::x11_utils::parse_u8_list(remaining, 8)?;
         let message = <[u8; 8]>::try_from(message).unwrap();
         let remaining = remaining.get(10..).ok_or(ParseError::ParseError)?;
-        let group = group.try_into()?;
+        let mods = xproto::ModMask::from(u16::from(mods));
+        let group = Group::from(group);
         let result = ActionMessageEvent { response_type, xkb_type, sequence, time, device_id, keycode, press, key_event_follows, mods, group, message };
         Ok((result, remaining))
     }
@@ -10658,16 +13075,27 @@ impl TryFrom<&[u8]> for ActionMessageEvent {
 }
 impl From<&ActionMessageEvent> for [u8; 32] {
     fn from(input: &ActionMessageEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let keycode_bytes = input.keycode.serialize();
-        let press_bytes = input.press.serialize();
-        let key_event_follows_bytes = input.key_event_follows.serialize();
-        let mods_bytes = input.mods.serialize();
-        let group_bytes = u8::from(input.group).serialize();
+        input.serialize()
+    }
+}
+impl From<ActionMessageEvent> for [u8; 32] {
+    fn from(input: ActionMessageEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ActionMessageEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let press_bytes = self.press.serialize_with_order(order);
+        let key_event_follows_bytes = self.key_event_follows.serialize_with_order(order);
+        let mods_bytes = u8::try_from(u16::from(self.mods)).unwrap().serialize_with_order(order);
+        let group_bytes = u8::from(self.group).serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10683,14 +13111,14 @@ impl From<&ActionMessageEvent> for [u8; 32] {
             key_event_follows_bytes[0],
             mods_bytes[0],
             group_bytes[0],
-            input.message[0],
-            input.message[1],
-            input.message[2],
-            input.message[3],
-            input.message[4],
-            input.message[5],
-            input.message[6],
-            input.message[7],
+            self.message[0],
+            self.message[1],
+            self.message[2],
+            self.message[3],
+            self.message[4],
+            self.message[5],
+            self.message[6],
+            self.message[7],
             0,
             0,
             0,
@@ -10703,10 +13131,8 @@ impl From<&ActionMessageEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ActionMessageEvent> for [u8; 32] {
-    fn from(input: ActionMessageEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10720,22 +13146,23 @@ pub struct AccessXNotifyEvent {
     pub time: xproto::Timestamp,
     pub device_id: u8,
     pub keycode: xproto::Keycode,
-    pub detailt: u16,
+    pub detailt: AXNDetail,
     pub slow_keys_delay: u16,
     pub debounce_delay: u16,
 }
 impl TryParse for AccessXNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (detailt, remaining) = u16::try_parse(remaining)?;
-        let (slow_keys_delay, remaining) = u16::try_parse(remaining)?;
-        let (debounce_delay, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (detailt, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (slow_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (debounce_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
+        let detailt = AXNDetail::from(detailt);
         let result = AccessXNotifyEvent { response_type, xkb_type, sequence, time, device_id, keycode, detailt, slow_keys_delay, debounce_delay };
         Ok((result, remaining))
     }
@@ -10748,15 +13175,26 @@ impl TryFrom<&[u8]> for AccessXNotifyEvent {
 }
 impl From<&AccessXNotifyEvent> for [u8; 32] {
     fn from(input: &AccessXNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let keycode_bytes = input.keycode.serialize();
-        let detailt_bytes = input.detailt.serialize();
-        let slow_keys_delay_bytes = input.slow_keys_delay.serialize();
-        let debounce_delay_bytes = input.debounce_delay.serialize();
+        input.serialize()
+    }
+}
+impl From<AccessXNotifyEvent> for [u8; 32] {
+    fn from(input: AccessXNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for AccessXNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let detailt_bytes = u16::from(self.detailt).serialize_with_order(order);
+        let slow_keys_delay_bytes = self.slow_keys_delay.serialize_with_order(order);
+        let debounce_delay_bytes = self.debounce_delay.serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10792,10 +13230,8 @@ impl From<&AccessXNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<AccessXNotifyEvent> for [u8; 32] {
-    fn from(input: AccessXNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10808,35 +13244,38 @@ pub struct ExtensionDeviceNotifyEvent {
     pub sequence: u16,
     pub time: xproto::Timestamp,
     pub device_id: u8,
-    pub reason: u16,
+    pub reason: XIFeature,
     pub led_class: LedClassResult,
     pub led_id: u16,
     pub leds_defined: u32,
     pub led_state: u32,
     pub first_button: u8,
     pub n_buttons: u8,
-    pub supported: u16,
-    pub unsupported: u16,
+    pub supported: XIFeature,
+    pub unsupported: XIFeature,
 }
 impl TryParse for ExtensionDeviceNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (reason, remaining) = u16::try_parse(remaining)?;
-        let (led_class, remaining) = u16::try_parse(remaining)?;
-        let (led_id, remaining) = u16::try_parse(remaining)?;
-        let (leds_defined, remaining) = u32::try_parse(remaining)?;
-        let (led_state, remaining) = u32::try_parse(remaining)?;
-        let (first_button, remaining) = u8::try_parse(remaining)?;
-        let (n_buttons, remaining) = u8::try_parse(remaining)?;
-        let (supported, remaining) = u16::try_parse(remaining)?;
-        let (unsupported, remaining) = u16::try_parse(remaining)?;
+        let (reason, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (led_class, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (led_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (leds_defined, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (first_button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_buttons, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (unsupported, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let led_class = led_class.try_into()?;
+        let reason = XIFeature::from(reason);
+        let led_class = LedClassResult::from(led_class);
+        let supported = XIFeature::from(supported);
+        let unsupported = XIFeature::from(unsupported);
         let result = ExtensionDeviceNotifyEvent { response_type, xkb_type, sequence, time, device_id, reason, led_class, led_id, leds_defined, led_state, first_button, n_buttons, supported, unsupported };
         Ok((result, remaining))
     }
@@ -10849,20 +13288,31 @@ impl TryFrom<&[u8]> for ExtensionDeviceNotifyEvent {
 }
 impl From<&ExtensionDeviceNotifyEvent> for [u8; 32] {
     fn from(input: &ExtensionDeviceNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let xkb_type_bytes = input.xkb_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let device_id_bytes = input.device_id.serialize();
-        let reason_bytes = input.reason.serialize();
-        let led_class_bytes = u16::from(input.led_class).serialize();
-        let led_id_bytes = input.led_id.serialize();
-        let leds_defined_bytes = input.leds_defined.serialize();
-        let led_state_bytes = input.led_state.serialize();
-        let first_button_bytes = input.first_button.serialize();
-        let n_buttons_bytes = input.n_buttons.serialize();
-        let supported_bytes = input.supported.serialize();
-        let unsupported_bytes = input.unsupported.serialize();
+        input.serialize()
+    }
+}
+impl From<ExtensionDeviceNotifyEvent> for [u8; 32] {
+    fn from(input: ExtensionDeviceNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for ExtensionDeviceNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let reason_bytes = u16::from(self.reason).serialize_with_order(order);
+        let led_class_bytes = u16::from(self.led_class).serialize_with_order(order);
+        let led_id_bytes = self.led_id.serialize_with_order(order);
+        let leds_defined_bytes = self.leds_defined.serialize_with_order(order);
+        let led_state_bytes = self.led_state.serialize_with_order(order);
+        let first_button_bytes = self.first_button.serialize_with_order(order);
+        let n_buttons_bytes = self.n_buttons.serialize_with_order(order);
+        let supported_bytes = u16::from(self.supported).serialize_with_order(order);
+        let unsupported_bytes = u16::from(self.unsupported).serialize_with_order(order);
         [
             response_type_bytes[0],
             xkb_type_bytes[0],
@@ -10898,10 +13348,8 @@ impl From<&ExtensionDeviceNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<ExtensionDeviceNotifyEvent> for [u8; 32] {
-    fn from(input: ExtensionDeviceNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -10913,11 +13361,11 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn xkb_select_events<'c, A, B, C, D, E>(&'c self, device_spec: DeviceSpec, affect_which: A, clear: B, select_all: C, affect_map: D, map: E, details: &SelectEventsAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u16>,
-        B: Into<u16>,
-        C: Into<u16>,
-        D: Into<u16>,
-        E: Into<u16>,
+        A: Into<EventType>,
+        B: Into<EventType>,
+        C: Into<EventType>,
+        D: Into<MapPart>,
+        E: Into<MapPart>,
     {
         select_events(self, device_spec, affect_which, clear, select_all, affect_map, map, details)
     }
@@ -10929,11 +13377,12 @@ pub trait ConnectionExt: RequestConnection {
     {
         get_state(self, device_spec)
     }
-    fn xkb_latch_lock_state<A, B, C>(&self, device_spec: DeviceSpec, affect_mod_locks: A, mod_locks: B, lock_group: bool, group_lock: Group, affect_mod_latches: C, latch_group: bool, group_latch: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xkb_latch_lock_state<A, B, C, D>(&self, device_spec: DeviceSpec, affect_mod_locks: A, mod_locks: B, lock_group: bool, group_lock: C, affect_mod_latches: D, latch_group: bool, group_latch: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<u8>,
-        B: Into<u8>,
-        C: Into<u8>,
+        A: Into<xproto::ModMask>,
+        B: Into<xproto::ModMask>,
+        C: Into<Group>,
+        D: Into<xproto::ModMask>,
     {
         latch_lock_state(self, device_spec, affect_mod_locks, mod_locks, lock_group, group_lock, affect_mod_latches, latch_group, group_latch)
     }
@@ -10943,49 +13392,49 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn xkb_set_controls<'c, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P>(&'c self, device_spec: DeviceSpec, affect_internal_real_mods: A, internal_real_mods: B, affect_ignore_lock_real_mods: C, ignore_lock_real_mods: D, affect_internal_virtual_mods: E, internal_virtual_mods: F, affect_ignore_lock_virtual_mods: G, ignore_lock_virtual_mods: H, mouse_keys_dflt_btn: u8, groups_wrap: u8, access_x_options: I, affect_enabled_controls: J, enabled_controls: K, change_controls: L, repeat_delay: u16, repeat_interval: u16, slow_keys_delay: u16, debounce_delay: u16, mouse_keys_delay: u16, mouse_keys_interval: u16, mouse_keys_time_to_max: u16, mouse_keys_max_speed: u16, mouse_keys_curve: i16, access_x_timeout: u16, access_x_timeout_mask: M, access_x_timeout_values: N, access_x_timeout_options_mask: O, access_x_timeout_options_values: P, per_key_repeat: &[u8; 32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u8>,
-        B: Into<u8>,
-        C: Into<u8>,
-        D: Into<u8>,
-        E: Into<u16>,
-        F: Into<u16>,
-        G: Into<u16>,
-        H: Into<u16>,
-        I: Into<u16>,
-        J: Into<u32>,
-        K: Into<u32>,
-        L: Into<u32>,
-        M: Into<u32>,
-        N: Into<u32>,
-        O: Into<u16>,
-        P: Into<u16>,
+        A: Into<xproto::ModMask>,
+        B: Into<xproto::ModMask>,
+        C: Into<xproto::ModMask>,
+        D: Into<xproto::ModMask>,
+        E: Into<VMod>,
+        F: Into<VMod>,
+        G: Into<VMod>,
+        H: Into<VMod>,
+        I: Into<AXOption>,
+        J: Into<BoolCtrl>,
+        K: Into<BoolCtrl>,
+        L: Into<Control>,
+        M: Into<BoolCtrl>,
+        N: Into<BoolCtrl>,
+        O: Into<AXOption>,
+        P: Into<AXOption>,
     {
         set_controls(self, device_spec, affect_internal_real_mods, internal_real_mods, affect_ignore_lock_real_mods, ignore_lock_real_mods, affect_internal_virtual_mods, internal_virtual_mods, affect_ignore_lock_virtual_mods, ignore_lock_virtual_mods, mouse_keys_dflt_btn, groups_wrap, access_x_options, affect_enabled_controls, enabled_controls, change_controls, repeat_delay, repeat_interval, slow_keys_delay, debounce_delay, mouse_keys_delay, mouse_keys_interval, mouse_keys_time_to_max, mouse_keys_max_speed, mouse_keys_curve, access_x_timeout, access_x_timeout_mask, access_x_timeout_values, access_x_timeout_options_mask, access_x_timeout_options_values, per_key_repeat)
     }
     fn xkb_get_map<A, B, C>(&self, device_spec: DeviceSpec, full: A, partial: B, first_type: u8, n_types: u8, first_key_sym: xproto::Keycode, n_key_syms: u8, first_key_action: xproto::Keycode, n_key_actions: u8, first_key_behavior: xproto::Keycode, n_key_behaviors: u8, virtual_mods: C, first_key_explicit: xproto::Keycode, n_key_explicit: u8, first_mod_map_key: xproto::Keycode, n_mod_map_keys: u8, first_v_mod_map_key: xproto::Keycode, n_v_mod_map_keys: u8) -> Result<Cookie<'_, Self, GetMapReply>, ConnectionError>
     where
-        A: Into<u16>,
-        B: Into<u16>,
-        C: Into<u16>,
+        A: Into<MapPart>,
+        B: Into<MapPart>,
+        C: Into<VMod>,
     {
         get_map(self, device_spec, full, partial, first_type, n_types, first_key_sym, n_key_syms, first_key_action, n_key_actions, first_key_behavior, n_key_behaviors, virtual_mods, first_key_explicit, n_key_explicit, first_mod_map_key, n_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys)
     }
     fn xkb_set_map<'c, A, B>(&'c self, device_spec: DeviceSpec, flags: A, min_key_code: xproto::Keycode, max_key_code: xproto::Keycode, first_type: u8, n_types: u8, first_key_sym: xproto::Keycode, n_key_syms: u8, total_syms: u16, first_key_action: xproto::Keycode, n_key_actions: u8, total_actions: u16, first_key_behavior: xproto::Keycode, n_key_behaviors: u8, total_key_behaviors: u8, first_key_explicit: xproto::Keycode, n_key_explicit: u8, total_key_explicit: u8, first_mod_map_key: xproto::Keycode, n_mod_map_keys: u8, total_mod_map_keys: u8, first_v_mod_map_key: xproto::Keycode, n_v_mod_map_keys: u8, total_v_mod_map_keys: u8, virtual_mods: B, values: &SetMapAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u16>,
-        B: Into<u16>,
+        A: Into<SetMapFlags>,
+        B: Into<VMod>,
     {
         set_map(self, device_spec, flags, min_key_code, max_key_code, first_type, n_types, first_key_sym, n_key_syms, total_syms, first_key_action, n_key_actions, total_actions, first_key_behavior, n_key_behaviors, total_key_behaviors, first_key_explicit, n_key_explicit, total_key_explicit, first_mod_map_key, n_mod_map_keys, total_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, total_v_mod_map_keys, virtual_mods, values)
     }
     fn xkb_get_compat_map<A>(&self, device_spec: DeviceSpec, groups: A, get_all_si: bool, first_si: u16, n_si: u16) -> Result<Cookie<'_, Self, GetCompatMapReply>, ConnectionError>
     where
-        A: Into<u8>,
+        A: Into<SetOfGroup>,
     {
         get_compat_map(self, device_spec, groups, get_all_si, first_si, n_si)
     }
     fn xkb_set_compat_map<'c, A>(&'c self, device_spec: DeviceSpec, recompute_actions: bool, truncate_si: bool, groups: A, first_si: u16, si: &[SymInterpret], group_maps: &[ModDef]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u8>,
+        A: Into<SetOfGroup>,
     {
         set_compat_map(self, device_spec, recompute_actions, truncate_si, groups, first_si, si, group_maps)
     }
@@ -11001,45 +13450,47 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_indicator_map(self, device_spec, which, maps)
     }
-    fn xkb_get_named_indicator<A>(&self, device_spec: DeviceSpec, led_class: LedClass, led_id: A, indicator: xproto::Atom) -> Result<Cookie<'_, Self, GetNamedIndicatorReply>, ConnectionError>
+    fn xkb_get_named_indicator<A, B>(&self, device_spec: DeviceSpec, led_class: A, led_id: B, indicator: xproto::Atom) -> Result<Cookie<'_, Self, GetNamedIndicatorReply>, ConnectionError>
     where
-        A: Into<IDSpec>,
+        A: Into<LedClass>,
+        B: Into<IDSpec>,
     {
         get_named_indicator(self, device_spec, led_class, led_id, indicator)
     }
-    fn xkb_set_named_indicator<A, B, C, D, E, F, G, H>(&self, device_spec: DeviceSpec, led_class: LedClass, led_id: A, indicator: xproto::Atom, set_state: bool, on: bool, set_map: bool, create_map: bool, map_flags: B, map_which_groups: C, map_groups: D, map_which_mods: E, map_real_mods: F, map_vmods: G, map_ctrls: H) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xkb_set_named_indicator<A, B, C, D, E, F, G, H, I>(&self, device_spec: DeviceSpec, led_class: A, led_id: B, indicator: xproto::Atom, set_state: bool, on: bool, set_map: bool, create_map: bool, map_flags: C, map_which_groups: D, map_groups: E, map_which_mods: F, map_real_mods: G, map_vmods: H, map_ctrls: I) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<IDSpec>,
-        B: Into<u8>,
-        C: Into<u8>,
-        D: Into<u8>,
-        E: Into<u8>,
-        F: Into<u8>,
-        G: Into<u16>,
-        H: Into<u32>,
+        A: Into<LedClass>,
+        B: Into<IDSpec>,
+        C: Into<IMFlag>,
+        D: Into<IMGroupsWhich>,
+        E: Into<SetOfGroups>,
+        F: Into<IMModsWhich>,
+        G: Into<xproto::ModMask>,
+        H: Into<VMod>,
+        I: Into<BoolCtrl>,
     {
         set_named_indicator(self, device_spec, led_class, led_id, indicator, set_state, on, set_map, create_map, map_flags, map_which_groups, map_groups, map_which_mods, map_real_mods, map_vmods, map_ctrls)
     }
     fn xkb_get_names<A>(&self, device_spec: DeviceSpec, which: A) -> Result<Cookie<'_, Self, GetNamesReply>, ConnectionError>
     where
-        A: Into<u32>,
+        A: Into<NameDetail>,
     {
         get_names(self, device_spec, which)
     }
     fn xkb_set_names<'c, A, B>(&'c self, device_spec: DeviceSpec, virtual_mods: A, first_type: u8, n_types: u8, first_kt_levelt: u8, n_kt_levels: u8, indicators: u32, group_names: B, n_radio_groups: u8, first_key: xproto::Keycode, n_keys: u8, n_key_aliases: u8, total_kt_level_names: u16, values: &SetNamesAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u16>,
-        B: Into<u8>,
+        A: Into<VMod>,
+        B: Into<SetOfGroup>,
     {
         set_names(self, device_spec, virtual_mods, first_type, n_types, first_kt_levelt, n_kt_levels, indicators, group_names, n_radio_groups, first_key, n_keys, n_key_aliases, total_kt_level_names, values)
     }
     fn xkb_per_client_flags<A, B, C, D, E>(&self, device_spec: DeviceSpec, change: A, value: B, ctrls_to_change: C, auto_ctrls: D, auto_ctrls_values: E) -> Result<Cookie<'_, Self, PerClientFlagsReply>, ConnectionError>
     where
-        A: Into<u32>,
-        B: Into<u32>,
-        C: Into<u32>,
-        D: Into<u32>,
-        E: Into<u32>,
+        A: Into<PerClientFlag>,
+        B: Into<PerClientFlag>,
+        C: Into<BoolCtrl>,
+        D: Into<BoolCtrl>,
+        E: Into<BoolCtrl>,
     {
         per_client_flags(self, device_spec, change, value, ctrls_to_change, auto_ctrls, auto_ctrls_values)
     }
@@ -11049,21 +13500,22 @@ pub trait ConnectionExt: RequestConnection {
     }
     fn xkb_get_kbd_by_name<A, B>(&self, device_spec: DeviceSpec, need: A, want: B, load: bool) -> Result<Cookie<'_, Self, GetKbdByNameReply>, ConnectionError>
     where
-        A: Into<u16>,
-        B: Into<u16>,
+        A: Into<GBNDetail>,
+        B: Into<GBNDetail>,
     {
         get_kbd_by_name(self, device_spec, need, want, load)
     }
-    fn xkb_get_device_info<A, B>(&self, device_spec: DeviceSpec, wanted: A, all_buttons: bool, first_button: u8, n_buttons: u8, led_class: LedClass, led_id: B) -> Result<Cookie<'_, Self, GetDeviceInfoReply>, ConnectionError>
+    fn xkb_get_device_info<A, B, C>(&self, device_spec: DeviceSpec, wanted: A, all_buttons: bool, first_button: u8, n_buttons: u8, led_class: B, led_id: C) -> Result<Cookie<'_, Self, GetDeviceInfoReply>, ConnectionError>
     where
-        A: Into<u16>,
-        B: Into<IDSpec>,
+        A: Into<XIFeature>,
+        B: Into<LedClass>,
+        C: Into<IDSpec>,
     {
         get_device_info(self, device_spec, wanted, all_buttons, first_button, n_buttons, led_class, led_id)
     }
     fn xkb_set_device_info<'c, A>(&'c self, device_spec: DeviceSpec, first_btn: u8, change: A, btn_actions: &[Action], leds: &[DeviceLedInfo]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
-        A: Into<u16>,
+        A: Into<XIFeature>,
     {
         set_device_info(self, device_spec, first_btn, change, btn_actions, leds)
     }
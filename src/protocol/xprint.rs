@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -39,16 +39,16 @@ pub struct Printer {
     pub description: Vec<String8>,
 }
 impl TryParse for Printer {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (name_len, remaining) = u32::try_parse(remaining)?;
+        let (name_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (desc_len, remaining) = u32::try_parse(remaining)?;
+        let (desc_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let (description, remaining) = crate::x11_utils::parse_u8_list(remaining, desc_len.try_into().or(Err(ParseError::ParseError))?)?;
         let description = description.to_vec();
         // Align offset to multiple of 4
@@ -67,320 +67,232 @@ impl TryFrom<&[u8]> for Printer {
 }
 impl Serialize for Printer {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let name_len = u32::try_from(self.name.len()).expect("`name` has too many elements");
-        name_len.serialize_into(bytes);
+        name_len.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.name);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
         let desc_len = u32::try_from(self.description.len()).expect("`description` has too many elements");
-        desc_len.serialize_into(bytes);
+        desc_len.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.description);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
-pub type Pcontext = u32;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GetDoc {
-    Finished = 0,
-    SecondConsumer = 1,
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pcontext(u32);
+impl Pcontext {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Pcontext(0);
 }
-impl From<GetDoc> for bool {
-    fn from(input: GetDoc) -> Self {
-        match input {
-            GetDoc::Finished => false,
-            GetDoc::SecondConsumer => true,
-        }
+impl From<u32> for Pcontext {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Pcontext> for u32 {
+    fn from(value: Pcontext) -> Self {
+        value.0
     }
 }
-impl From<GetDoc> for u8 {
+impl TryParse for Pcontext {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Pcontext {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GetDoc(u8);
+#[allow(non_upper_case_globals)]
+impl GetDoc {
+    pub const Finished: Self = Self(0);
+    pub const SecondConsumer: Self = Self(1);
+}
+enum_newtype!(GetDoc, u8, [Finished, SecondConsumer]);
+impl From<GetDoc> for bool {
     fn from(input: GetDoc) -> Self {
-        match input {
-            GetDoc::Finished => 0,
-            GetDoc::SecondConsumer => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<GetDoc> for Option<u8> {
     fn from(input: GetDoc) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<GetDoc> for u16 {
     fn from(input: GetDoc) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GetDoc> for Option<u16> {
     fn from(input: GetDoc) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<GetDoc> for u32 {
     fn from(input: GetDoc) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GetDoc> for Option<u32> {
     fn from(input: GetDoc) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for GetDoc {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(GetDoc::Finished),
-            1 => Ok(GetDoc::SecondConsumer),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for GetDoc {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for GetDoc {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum EvMask {
-    NoEventMask = 0,
-    PrintMask = 1 << 0,
-    AttributeMask = 1 << 1,
-}
-impl From<EvMask> for u8 {
-    fn from(input: EvMask) -> Self {
-        match input {
-            EvMask::NoEventMask => 0,
-            EvMask::PrintMask => 1 << 0,
-            EvMask::AttributeMask => 1 << 1,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EvMask(u8);
+#[allow(non_upper_case_globals)]
+impl EvMask {
+    pub const NoEventMask: Self = Self(0);
+    pub const PrintMask: Self = Self(1 << 0);
+    pub const AttributeMask: Self = Self(1 << 1);
+}
+bitmask_set!(EvMask, u8, [PrintMask, AttributeMask]);
 impl From<EvMask> for Option<u8> {
     fn from(input: EvMask) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<EvMask> for u16 {
     fn from(input: EvMask) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<EvMask> for Option<u16> {
     fn from(input: EvMask) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<EvMask> for u32 {
     fn from(input: EvMask) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<EvMask> for Option<u32> {
     fn from(input: EvMask) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for EvMask {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(EvMask::NoEventMask),
-            1 => Ok(EvMask::PrintMask),
-            2 => Ok(EvMask::AttributeMask),
-            _ => Err(ParseError::ParseError),
-        }
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u16> for EvMask {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for EvMask {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(EvMask, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Detail {
-    StartJobNotify = 1,
-    EndJobNotify = 2,
-    StartDocNotify = 3,
-    EndDocNotify = 4,
-    StartPageNotify = 5,
-    EndPageNotify = 6,
-}
-impl From<Detail> for u8 {
-    fn from(input: Detail) -> Self {
-        match input {
-            Detail::StartJobNotify => 1,
-            Detail::EndJobNotify => 2,
-            Detail::StartDocNotify => 3,
-            Detail::EndDocNotify => 4,
-            Detail::StartPageNotify => 5,
-            Detail::EndPageNotify => 6,
-        }
-    }
-}
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Detail(u8);
+#[allow(non_upper_case_globals)]
+impl Detail {
+    pub const StartJobNotify: Self = Self(1);
+    pub const EndJobNotify: Self = Self(2);
+    pub const StartDocNotify: Self = Self(3);
+    pub const EndDocNotify: Self = Self(4);
+    pub const StartPageNotify: Self = Self(5);
+    pub const EndPageNotify: Self = Self(6);
+}
+enum_newtype!(Detail, u8, [StartJobNotify, EndJobNotify, StartDocNotify, EndDocNotify, StartPageNotify, EndPageNotify]);
 impl From<Detail> for Option<u8> {
     fn from(input: Detail) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Detail> for u16 {
     fn from(input: Detail) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Detail> for Option<u16> {
     fn from(input: Detail) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Detail> for u32 {
     fn from(input: Detail) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Detail> for Option<u32> {
     fn from(input: Detail) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Detail {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(Detail::StartJobNotify),
-            2 => Ok(Detail::EndJobNotify),
-            3 => Ok(Detail::StartDocNotify),
-            4 => Ok(Detail::EndDocNotify),
-            5 => Ok(Detail::StartPageNotify),
-            6 => Ok(Detail::EndPageNotify),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Detail {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Detail {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Attr {
-    JobAttr = 1,
-    DocAttr = 2,
-    PageAttr = 3,
-    PrinterAttr = 4,
-    ServerAttr = 5,
-    MediumAttr = 6,
-    SpoolerAttr = 7,
-}
-impl From<Attr> for u8 {
-    fn from(input: Attr) -> Self {
-        match input {
-            Attr::JobAttr => 1,
-            Attr::DocAttr => 2,
-            Attr::PageAttr => 3,
-            Attr::PrinterAttr => 4,
-            Attr::ServerAttr => 5,
-            Attr::MediumAttr => 6,
-            Attr::SpoolerAttr => 7,
-        }
-    }
-}
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Attr(u8);
+#[allow(non_upper_case_globals)]
+impl Attr {
+    pub const JobAttr: Self = Self(1);
+    pub const DocAttr: Self = Self(2);
+    pub const PageAttr: Self = Self(3);
+    pub const PrinterAttr: Self = Self(4);
+    pub const ServerAttr: Self = Self(5);
+    pub const MediumAttr: Self = Self(6);
+    pub const SpoolerAttr: Self = Self(7);
+}
+enum_newtype!(Attr, u8, [JobAttr, DocAttr, PageAttr, PrinterAttr, ServerAttr, MediumAttr, SpoolerAttr]);
 impl From<Attr> for Option<u8> {
     fn from(input: Attr) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Attr> for u16 {
     fn from(input: Attr) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Attr> for Option<u16> {
     fn from(input: Attr) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Attr> for u32 {
     fn from(input: Attr) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Attr> for Option<u32> {
     fn from(input: Attr) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for Attr {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(Attr::JobAttr),
-            2 => Ok(Attr::DocAttr),
-            3 => Ok(Attr::PageAttr),
-            4 => Ok(Attr::PrinterAttr),
-            5 => Ok(Attr::ServerAttr),
-            6 => Ok(Attr::MediumAttr),
-            7 => Ok(Attr::SpoolerAttr),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the PrintQueryVersion request
+pub const PRINT_QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintQueryVersionRequest {
+}
+impl PrintQueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
         }
-    }
-}
-impl TryFrom<u16> for Attr {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Attr {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintQueryVersionRequest {
+        })
     }
 }
 
-/// Opcode for the PrintQueryVersion request
-pub const PRINT_QUERY_VERSION_REQUEST: u8 = 0;
 pub fn print_query_version<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, PrintQueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -391,9 +303,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintQueryVersionRequest {
+    type Reply = PrintQueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintQueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintQueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_query_version(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PrintQueryVersionReply {
@@ -404,13 +328,13 @@ pub struct PrintQueryVersionReply {
     pub minor_version: u16,
 }
 impl TryParse for PrintQueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = PrintQueryVersionReply { response_type, sequence, length, major_version, minor_version };
         Ok((result, remaining))
     }
@@ -421,20 +345,82 @@ impl TryFrom<&[u8]> for PrintQueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintQueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintGetPrinterList request
 pub const PRINT_GET_PRINTER_LIST_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PrintGetPrinterListRequest {
+    pub printer_name: Vec<String8>,
+    pub locale: Vec<String8>,
+}
+impl PrintGetPrinterListRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_GET_PRINTER_LIST_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (printer_name_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (locale_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (printer_name, remaining) = crate::x11_utils::parse_u8_list(remaining, printer_name_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let printer_name = printer_name.to_vec();
+        let (locale, remaining) = crate::x11_utils::parse_u8_list(remaining, locale_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let locale = locale.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintGetPrinterListRequest {
+            printer_name,
+            locale,
+        })
+    }
+    /// Set the `printer_name` field of this structure.
+    pub fn printer_name<I>(mut self, value: I) -> Self where I: Into<Vec<String8>> {
+        self.printer_name = value.into();
+        self
+    }
+    /// Set the `locale` field of this structure.
+    pub fn locale<I>(mut self, value: I) -> Self where I: Into<Vec<String8>> {
+        self.locale = value.into();
+        self
+    }
+}
+
 pub fn print_get_printer_list<'c, Conn>(conn: &'c Conn, printer_name: &[String8], locale: &[String8]) -> Result<Cookie<'c, Conn, PrintGetPrinterListReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let printer_name_len = u32::try_from(printer_name.len()).expect("`printer_name` has too many elements");
-    let printer_name_len_bytes = printer_name_len.serialize();
+    let printer_name_len_bytes = printer_name_len.serialize_with_order(order);
     let locale_len = u32::try_from(locale.len()).expect("`locale` has too many elements");
-    let locale_len_bytes = locale_len.serialize();
+    let locale_len_bytes = locale_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_GET_PRINTER_LIST_REQUEST,
@@ -456,9 +442,21 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(printer_name), IoSlice::new(locale), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintGetPrinterListRequest {
+    type Reply = PrintGetPrinterListReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintGetPrinterListRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintGetPrinterListReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_get_printer_list(conn, &self.printer_name, &self.locale)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PrintGetPrinterListReply {
@@ -468,14 +466,14 @@ pub struct PrintGetPrinterListReply {
     pub printers: Vec<Printer>,
 }
 impl TryParse for PrintGetPrinterListReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (list_count, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (list_count, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (printers, remaining) = crate::x11_utils::parse_list::<Printer>(remaining, list_count.try_into().or(Err(ParseError::ParseError))?)?;
+        let (printers, remaining) = crate::x11_utils::parse_list_with_order::<Printer>(remaining, list_count.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = PrintGetPrinterListReply { response_type, sequence, length, printers };
         Ok((result, remaining))
     }
@@ -486,15 +484,59 @@ impl TryFrom<&[u8]> for PrintGetPrinterListReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintGetPrinterListReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let list_count = u32::try_from(self.printers.len()).expect("`printers` has too many elements");
+        list_count.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.printers.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintRehashPrinterList request
 pub const PRINT_REHASH_PRINTER_LIST_REQUEST: u8 = 20;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintRehashPrinterListRequest {
+}
+impl PrintRehashPrinterListRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_REHASH_PRINTER_LIST_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintRehashPrinterListRequest {
+        })
+    }
+}
+
 pub fn print_rehash_printer_list<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -505,24 +547,82 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintRehashPrinterListRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintRehashPrinterListRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_rehash_printer_list(conn)
+    }
+}
 
 /// Opcode for the CreateContext request
 pub const CREATE_CONTEXT_REQUEST: u8 = 2;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreateContextRequest {
+    pub context_id: u32,
+    pub printer_name: Vec<String8>,
+    pub locale: Vec<String8>,
+}
+impl CreateContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (printer_name_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (locale_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (printer_name, remaining) = crate::x11_utils::parse_u8_list(remaining, printer_name_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let printer_name = printer_name.to_vec();
+        let (locale, remaining) = crate::x11_utils::parse_u8_list(remaining, locale_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let locale = locale.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateContextRequest {
+            context_id,
+            printer_name,
+            locale,
+        })
+    }
+    /// Set the `context_id` field of this structure.
+    pub fn context_id(mut self, value: u32) -> Self {
+        self.context_id = value;
+        self
+    }
+    /// Set the `printer_name` field of this structure.
+    pub fn printer_name<I>(mut self, value: I) -> Self where I: Into<Vec<String8>> {
+        self.printer_name = value.into();
+        self
+    }
+    /// Set the `locale` field of this structure.
+    pub fn locale<I>(mut self, value: I) -> Self where I: Into<Vec<String8>> {
+        self.locale = value.into();
+        self
+    }
+}
+
 pub fn create_context<'c, Conn>(conn: &'c Conn, context_id: u32, printer_name: &[String8], locale: &[String8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_id_bytes = context_id.serialize();
+    let context_id_bytes = context_id.serialize_with_order(order);
     let printer_name_len = u32::try_from(printer_name.len()).expect("`printer_name` has too many elements");
-    let printer_name_len_bytes = printer_name_len.serialize();
+    let printer_name_len_bytes = printer_name_len.serialize_with_order(order);
     let locale_len = u32::try_from(locale.len()).expect("`locale` has too many elements");
-    let locale_len_bytes = locale_len.serialize();
+    let locale_len_bytes = locale_len.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_CONTEXT_REQUEST,
@@ -548,20 +648,58 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(printer_name), IoSlice::new(locale), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_context(conn, self.context_id, &self.printer_name, &self.locale)
+    }
+}
 
 /// Opcode for the PrintSetContext request
 pub const PRINT_SET_CONTEXT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintSetContextRequest {
+    pub context: u32,
+}
+impl PrintSetContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_SET_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintSetContextRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context(mut self, value: u32) -> Self {
+        self.context = value;
+        self
+    }
+}
+
 pub fn print_set_context<Conn>(conn: &Conn, context: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_SET_CONTEXT_REQUEST,
@@ -575,18 +713,48 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintSetContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintSetContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_set_context(conn, self.context)
+    }
+}
 
 /// Opcode for the PrintGetContext request
 pub const PRINT_GET_CONTEXT_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintGetContextRequest {
+}
+impl PrintGetContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_GET_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintGetContextRequest {
+        })
+    }
+}
+
 pub fn print_get_context<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, PrintGetContextReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -597,9 +765,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintGetContextRequest {
+    type Reply = PrintGetContextReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintGetContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintGetContextReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_get_context(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PrintGetContextReply {
@@ -609,12 +789,12 @@ pub struct PrintGetContextReply {
     pub context: u32,
 }
 impl TryParse for PrintGetContextReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (context, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = PrintGetContextReply { response_type, sequence, length, context };
         Ok((result, remaining))
     }
@@ -625,17 +805,66 @@ impl TryFrom<&[u8]> for PrintGetContextReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintGetContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.context.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintDestroyContext request
 pub const PRINT_DESTROY_CONTEXT_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintDestroyContextRequest {
+    pub context: u32,
+}
+impl PrintDestroyContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_DESTROY_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintDestroyContextRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context(mut self, value: u32) -> Self {
+        self.context = value;
+        self
+    }
+}
+
 pub fn print_destroy_context<Conn>(conn: &Conn, context: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_DESTROY_CONTEXT_REQUEST,
@@ -649,18 +878,48 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintDestroyContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintDestroyContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_destroy_context(conn, self.context)
+    }
+}
 
 /// Opcode for the PrintGetScreenOfContext request
 pub const PRINT_GET_SCREEN_OF_CONTEXT_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintGetScreenOfContextRequest {
+}
+impl PrintGetScreenOfContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_GET_SCREEN_OF_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintGetScreenOfContextRequest {
+        })
+    }
+}
+
 pub fn print_get_screen_of_context<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, PrintGetScreenOfContextReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -671,9 +930,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintGetScreenOfContextRequest {
+    type Reply = PrintGetScreenOfContextReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintGetScreenOfContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintGetScreenOfContextReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_get_screen_of_context(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PrintGetScreenOfContextReply {
@@ -683,12 +954,12 @@ pub struct PrintGetScreenOfContextReply {
     pub root: xproto::Window,
 }
 impl TryParse for PrintGetScreenOfContextReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
         let result = PrintGetScreenOfContextReply { response_type, sequence, length, root };
         Ok((result, remaining))
     }
@@ -699,17 +970,66 @@ impl TryFrom<&[u8]> for PrintGetScreenOfContextReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintGetScreenOfContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintStartJob request
 pub const PRINT_START_JOB_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintStartJobRequest {
+    pub output_mode: u8,
+}
+impl PrintStartJobRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_START_JOB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (output_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintStartJobRequest {
+            output_mode,
+        })
+    }
+    /// Set the `output_mode` field of this structure.
+    pub fn output_mode(mut self, value: u8) -> Self {
+        self.output_mode = value;
+        self
+    }
+}
+
 pub fn print_start_job<Conn>(conn: &Conn, output_mode: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let output_mode_bytes = output_mode.serialize();
+    let output_mode_bytes = output_mode.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_START_JOB_REQUEST,
@@ -723,20 +1043,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintStartJobRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintStartJobRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_start_job(conn, self.output_mode)
+    }
+}
 
 /// Opcode for the PrintEndJob request
 pub const PRINT_END_JOB_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintEndJobRequest {
+    pub cancel: bool,
+}
+impl PrintEndJobRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_END_JOB_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (cancel, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintEndJobRequest {
+            cancel,
+        })
+    }
+    /// Set the `cancel` field of this structure.
+    pub fn cancel(mut self, value: bool) -> Self {
+        self.cancel = value;
+        self
+    }
+}
+
 pub fn print_end_job<Conn>(conn: &Conn, cancel: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let cancel_bytes = cancel.serialize();
+    let cancel_bytes = cancel.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_END_JOB_REQUEST,
@@ -750,20 +1108,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintEndJobRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintEndJobRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_end_job(conn, self.cancel)
+    }
+}
 
 /// Opcode for the PrintStartDoc request
 pub const PRINT_START_DOC_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintStartDocRequest {
+    pub driver_mode: u8,
+}
+impl PrintStartDocRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_START_DOC_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (driver_mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintStartDocRequest {
+            driver_mode,
+        })
+    }
+    /// Set the `driver_mode` field of this structure.
+    pub fn driver_mode(mut self, value: u8) -> Self {
+        self.driver_mode = value;
+        self
+    }
+}
+
 pub fn print_start_doc<Conn>(conn: &Conn, driver_mode: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let driver_mode_bytes = driver_mode.serialize();
+    let driver_mode_bytes = driver_mode.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_START_DOC_REQUEST,
@@ -777,20 +1173,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintStartDocRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintStartDocRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_start_doc(conn, self.driver_mode)
+    }
+}
 
 /// Opcode for the PrintEndDoc request
 pub const PRINT_END_DOC_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintEndDocRequest {
+    pub cancel: bool,
+}
+impl PrintEndDocRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_END_DOC_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (cancel, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintEndDocRequest {
+            cancel,
+        })
+    }
+    /// Set the `cancel` field of this structure.
+    pub fn cancel(mut self, value: bool) -> Self {
+        self.cancel = value;
+        self
+    }
+}
+
 pub fn print_end_doc<Conn>(conn: &Conn, cancel: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let cancel_bytes = cancel.serialize();
+    let cancel_bytes = cancel.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_END_DOC_REQUEST,
@@ -804,26 +1238,96 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintEndDocRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintEndDocRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_end_doc(conn, self.cancel)
+    }
+}
 
 /// Opcode for the PrintPutDocumentData request
 pub const PRINT_PUT_DOCUMENT_DATA_REQUEST: u8 = 11;
-pub fn print_put_document_data<'c, Conn>(conn: &'c Conn, drawable: xproto::Drawable, data: &[u8], doc_format: &[String8], options: &[String8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PrintPutDocumentDataRequest {
+    pub drawable: xproto::Drawable,
+    pub data: Vec<u8>,
+    pub doc_format: Vec<String8>,
+    pub options: Vec<String8>,
+}
+impl PrintPutDocumentDataRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_PUT_DOCUMENT_DATA_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (len_data, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (len_fmt, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (len_options, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, len_data.try_into().or(Err(ParseError::ParseError))?)?;
+        let data = data.to_vec();
+        let (doc_format, remaining) = crate::x11_utils::parse_u8_list(remaining, len_fmt.try_into().or(Err(ParseError::ParseError))?)?;
+        let doc_format = doc_format.to_vec();
+        let (options, remaining) = crate::x11_utils::parse_u8_list(remaining, len_options.try_into().or(Err(ParseError::ParseError))?)?;
+        let options = options.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintPutDocumentDataRequest {
+            drawable,
+            data,
+            doc_format,
+            options,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `data` field of this structure.
+    pub fn data<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.data = value.into();
+        self
+    }
+    /// Set the `doc_format` field of this structure.
+    pub fn doc_format<I>(mut self, value: I) -> Self where I: Into<Vec<String8>> {
+        self.doc_format = value.into();
+        self
+    }
+    /// Set the `options` field of this structure.
+    pub fn options<I>(mut self, value: I) -> Self where I: Into<Vec<String8>> {
+        self.options = value.into();
+        self
+    }
+}
+
+pub fn print_put_document_data<'c, Conn, A>(conn: &'c Conn, drawable: A, data: &[u8], doc_format: &[String8], options: &[String8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
     let len_data = u32::try_from(data.len()).expect("`data` has too many elements");
-    let len_data_bytes = len_data.serialize();
+    let len_data_bytes = len_data.serialize_with_order(order);
     let len_fmt = u16::try_from(doc_format.len()).expect("`doc_format` has too many elements");
-    let len_fmt_bytes = len_fmt.serialize();
+    let len_fmt_bytes = len_fmt.serialize_with_order(order);
     let len_options = u16::try_from(options.len()).expect("`options` has too many elements");
-    let len_options_bytes = len_options.serialize();
+    let len_options_bytes = len_options.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_PUT_DOCUMENT_DATA_REQUEST,
@@ -850,21 +1354,67 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(data), IoSlice::new(doc_format), IoSlice::new(options), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintPutDocumentDataRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintPutDocumentDataRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_put_document_data(conn, self.drawable, &self.data, &self.doc_format, &self.options)
+    }
+}
 
 /// Opcode for the PrintGetDocumentData request
 pub const PRINT_GET_DOCUMENT_DATA_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintGetDocumentDataRequest {
+    pub context: Pcontext,
+    pub max_bytes: u32,
+}
+impl PrintGetDocumentDataRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_GET_DOCUMENT_DATA_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let (max_bytes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintGetDocumentDataRequest {
+            context,
+            max_bytes,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Pcontext> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `max_bytes` field of this structure.
+    pub fn max_bytes(mut self, value: u32) -> Self {
+        self.max_bytes = value;
+        self
+    }
+}
+
 pub fn print_get_document_data<Conn>(conn: &Conn, context: Pcontext, max_bytes: u32) -> Result<Cookie<'_, Conn, PrintGetDocumentDataReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let max_bytes_bytes = max_bytes.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let max_bytes_bytes = max_bytes.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_GET_DOCUMENT_DATA_REQUEST,
@@ -882,9 +1432,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintGetDocumentDataRequest {
+    type Reply = PrintGetDocumentDataReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintGetDocumentDataRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintGetDocumentDataReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_get_document_data(conn, self.context, self.max_bytes)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PrintGetDocumentDataReply {
@@ -896,14 +1458,14 @@ pub struct PrintGetDocumentDataReply {
     pub data: Vec<u8>,
 }
 impl TryParse for PrintGetDocumentDataReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status_code, remaining) = u32::try_parse(remaining)?;
-        let (finished_flag, remaining) = u32::try_parse(remaining)?;
-        let (data_len, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status_code, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (finished_flag, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, data_len.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
@@ -917,17 +1479,71 @@ impl TryFrom<&[u8]> for PrintGetDocumentDataReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintGetDocumentDataReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.status_code.serialize_into_with_order(bytes, order);
+        self.finished_flag.serialize_into_with_order(bytes, order);
+        let data_len = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        data_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&self.data);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintStartPage request
 pub const PRINT_START_PAGE_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintStartPageRequest {
+    pub window: xproto::Window,
+}
+impl PrintStartPageRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_START_PAGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintStartPageRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn print_start_page<Conn>(conn: &Conn, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_START_PAGE_REQUEST,
@@ -941,20 +1557,59 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintStartPageRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintStartPageRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_start_page(conn, self.window)
+    }
+}
 
 /// Opcode for the PrintEndPage request
 pub const PRINT_END_PAGE_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintEndPageRequest {
+    pub cancel: bool,
+}
+impl PrintEndPageRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_END_PAGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (cancel, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintEndPageRequest {
+            cancel,
+        })
+    }
+    /// Set the `cancel` field of this structure.
+    pub fn cancel(mut self, value: bool) -> Self {
+        self.cancel = value;
+        self
+    }
+}
+
 pub fn print_end_page<Conn>(conn: &Conn, cancel: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let cancel_bytes = cancel.serialize();
+    let cancel_bytes = cancel.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_END_PAGE_REQUEST,
@@ -968,21 +1623,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintEndPageRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintEndPageRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_end_page(conn, self.cancel)
+    }
+}
 
 /// Opcode for the PrintSelectInput request
 pub const PRINT_SELECT_INPUT_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintSelectInputRequest {
+    pub context: Pcontext,
+    pub event_mask: u32,
+}
+impl PrintSelectInputRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_SELECT_INPUT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintSelectInputRequest {
+            context,
+            event_mask,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Pcontext> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `event_mask` field of this structure.
+    pub fn event_mask(mut self, value: u32) -> Self {
+        self.event_mask = value;
+        self
+    }
+}
+
 pub fn print_select_input<Conn>(conn: &Conn, context: Pcontext, event_mask: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let event_mask_bytes = event_mask.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let event_mask_bytes = event_mask.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_SELECT_INPUT_REQUEST,
@@ -1000,20 +1701,58 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintSelectInputRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintSelectInputRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_select_input(conn, self.context, self.event_mask)
+    }
+}
 
 /// Opcode for the PrintInputSelected request
 pub const PRINT_INPUT_SELECTED_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintInputSelectedRequest {
+    pub context: Pcontext,
+}
+impl PrintInputSelectedRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_INPUT_SELECTED_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintInputSelectedRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Pcontext> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn print_input_selected<Conn>(conn: &Conn, context: Pcontext) -> Result<Cookie<'_, Conn, PrintInputSelectedReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_INPUT_SELECTED_REQUEST,
@@ -1027,9 +1766,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintInputSelectedRequest {
+    type Reply = PrintInputSelectedReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintInputSelectedRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintInputSelectedReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_input_selected(conn, self.context)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PrintInputSelectedReply {
@@ -1040,13 +1791,13 @@ pub struct PrintInputSelectedReply {
     pub all_events_mask: u32,
 }
 impl TryParse for PrintInputSelectedReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
-        let (all_events_mask, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (all_events_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = PrintInputSelectedReply { response_type, sequence, length, event_mask, all_events_mask };
         Ok((result, remaining))
     }
@@ -1057,18 +1808,77 @@ impl TryFrom<&[u8]> for PrintInputSelectedReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintInputSelectedReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.event_mask.serialize_into_with_order(bytes, order);
+        self.all_events_mask.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintGetAttributes request
 pub const PRINT_GET_ATTRIBUTES_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintGetAttributesRequest {
+    pub context: Pcontext,
+    pub pool: u8,
+}
+impl PrintGetAttributesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_GET_ATTRIBUTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let (pool, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintGetAttributesRequest {
+            context,
+            pool,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Pcontext> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `pool` field of this structure.
+    pub fn pool(mut self, value: u8) -> Self {
+        self.pool = value;
+        self
+    }
+}
+
 pub fn print_get_attributes<Conn>(conn: &Conn, context: Pcontext, pool: u8) -> Result<Cookie<'_, Conn, PrintGetAttributesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let pool_bytes = pool.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let pool_bytes = pool.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_GET_ATTRIBUTES_REQUEST,
@@ -1086,9 +1896,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintGetAttributesRequest {
+    type Reply = PrintGetAttributesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintGetAttributesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintGetAttributesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_get_attributes(conn, self.context, self.pool)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PrintGetAttributesReply {
@@ -1098,12 +1920,12 @@ pub struct PrintGetAttributesReply {
     pub attributes: Vec<String8>,
 }
 impl TryParse for PrintGetAttributesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (string_len, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (string_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let (attributes, remaining) = crate::x11_utils::parse_u8_list(remaining, string_len.try_into().or(Err(ParseError::ParseError))?)?;
         let attributes = attributes.to_vec();
@@ -1117,20 +1939,91 @@ impl TryFrom<&[u8]> for PrintGetAttributesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintGetAttributesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let string_len = u32::try_from(self.attributes.len()).expect("`attributes` has too many elements");
+        string_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        bytes.extend_from_slice(&self.attributes);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintGetOneAttributes request
 pub const PRINT_GET_ONE_ATTRIBUTES_REQUEST: u8 = 19;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PrintGetOneAttributesRequest {
+    pub context: Pcontext,
+    pub pool: u8,
+    pub name: Vec<String8>,
+}
+impl PrintGetOneAttributesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_GET_ONE_ATTRIBUTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pool, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ParseError))?)?;
+        let name = name.to_vec();
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintGetOneAttributesRequest {
+            context,
+            pool,
+            name,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Pcontext> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `pool` field of this structure.
+    pub fn pool(mut self, value: u8) -> Self {
+        self.pool = value;
+        self
+    }
+    /// Set the `name` field of this structure.
+    pub fn name<I>(mut self, value: I) -> Self where I: Into<Vec<String8>> {
+        self.name = value.into();
+        self
+    }
+}
+
 pub fn print_get_one_attributes<'c, Conn>(conn: &'c Conn, context: Pcontext, pool: u8, name: &[String8]) -> Result<Cookie<'c, Conn, PrintGetOneAttributesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let name_len = u32::try_from(name.len()).expect("`name` has too many elements");
-    let name_len_bytes = name_len.serialize();
-    let pool_bytes = pool.serialize();
+    let name_len_bytes = name_len.serialize_with_order(order);
+    let pool_bytes = pool.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_GET_ONE_ATTRIBUTES_REQUEST,
@@ -1155,9 +2048,21 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0), IoSlice::new(name), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintGetOneAttributesRequest {
+    type Reply = PrintGetOneAttributesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintGetOneAttributesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintGetOneAttributesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_get_one_attributes(conn, self.context, self.pool, &self.name)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PrintGetOneAttributesReply {
@@ -1167,12 +2072,12 @@ pub struct PrintGetOneAttributesReply {
     pub value: Vec<String8>,
 }
 impl TryParse for PrintGetOneAttributesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (value_len, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_len, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
         let (value, remaining) = crate::x11_utils::parse_u8_list(remaining, value_len.try_into().or(Err(ParseError::ParseError))?)?;
         let value = value.to_vec();
@@ -1186,20 +2091,106 @@ impl TryFrom<&[u8]> for PrintGetOneAttributesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintGetOneAttributesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let value_len = u32::try_from(self.value.len()).expect("`value` has too many elements");
+        value_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        bytes.extend_from_slice(&self.value);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintSetAttributes request
 pub const PRINT_SET_ATTRIBUTES_REQUEST: u8 = 18;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PrintSetAttributesRequest {
+    pub context: Pcontext,
+    pub string_len: u32,
+    pub pool: u8,
+    pub rule: u8,
+    pub attributes: Vec<String8>,
+}
+impl PrintSetAttributesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_SET_ATTRIBUTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let (string_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pool, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (rule, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let attributes = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintSetAttributesRequest {
+            context,
+            string_len,
+            pool,
+            rule,
+            attributes,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Pcontext> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `string_len` field of this structure.
+    pub fn string_len(mut self, value: u32) -> Self {
+        self.string_len = value;
+        self
+    }
+    /// Set the `pool` field of this structure.
+    pub fn pool(mut self, value: u8) -> Self {
+        self.pool = value;
+        self
+    }
+    /// Set the `rule` field of this structure.
+    pub fn rule(mut self, value: u8) -> Self {
+        self.rule = value;
+        self
+    }
+    /// Set the `attributes` field of this structure.
+    pub fn attributes<I>(mut self, value: I) -> Self where I: Into<Vec<String8>> {
+        self.attributes = value.into();
+        self
+    }
+}
+
 pub fn print_set_attributes<'c, Conn>(conn: &'c Conn, context: Pcontext, string_len: u32, pool: u8, rule: u8, attributes: &[String8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let string_len_bytes = string_len.serialize();
-    let pool_bytes = pool.serialize();
-    let rule_bytes = rule.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let string_len_bytes = string_len.serialize_with_order(order);
+    let pool_bytes = pool.serialize_with_order(order);
+    let rule_bytes = rule.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_SET_ATTRIBUTES_REQUEST,
@@ -1224,20 +2215,58 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(attributes), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintSetAttributesRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintSetAttributesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_set_attributes(conn, self.context, self.string_len, self.pool, self.rule, &self.attributes)
+    }
+}
 
 /// Opcode for the PrintGetPageDimensions request
 pub const PRINT_GET_PAGE_DIMENSIONS_REQUEST: u8 = 21;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintGetPageDimensionsRequest {
+    pub context: Pcontext,
+}
+impl PrintGetPageDimensionsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_GET_PAGE_DIMENSIONS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintGetPageDimensionsRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Pcontext> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn print_get_page_dimensions<Conn>(conn: &Conn, context: Pcontext) -> Result<Cookie<'_, Conn, PrintGetPageDimensionsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_GET_PAGE_DIMENSIONS_REQUEST,
@@ -1251,9 +2280,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintGetPageDimensionsRequest {
+    type Reply = PrintGetPageDimensionsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintGetPageDimensionsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintGetPageDimensionsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_get_page_dimensions(conn, self.context)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PrintGetPageDimensionsReply {
@@ -1268,17 +2309,17 @@ pub struct PrintGetPageDimensionsReply {
     pub reproducible_height: u16,
 }
 impl TryParse for PrintGetPageDimensionsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (offset_x, remaining) = u16::try_parse(remaining)?;
-        let (offset_y, remaining) = u16::try_parse(remaining)?;
-        let (reproducible_width, remaining) = u16::try_parse(remaining)?;
-        let (reproducible_height, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (offset_x, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (offset_y, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (reproducible_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (reproducible_height, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = PrintGetPageDimensionsReply { response_type, sequence, length, width, height, offset_x, offset_y, reproducible_width, reproducible_height };
         Ok((result, remaining))
     }
@@ -1289,15 +2330,61 @@ impl TryFrom<&[u8]> for PrintGetPageDimensionsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintGetPageDimensionsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.offset_x.serialize_into_with_order(bytes, order);
+        self.offset_y.serialize_into_with_order(bytes, order);
+        self.reproducible_width.serialize_into_with_order(bytes, order);
+        self.reproducible_height.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintQueryScreens request
 pub const PRINT_QUERY_SCREENS_REQUEST: u8 = 22;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintQueryScreensRequest {
+}
+impl PrintQueryScreensRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_QUERY_SCREENS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintQueryScreensRequest {
+        })
+    }
+}
+
 pub fn print_query_screens<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, PrintQueryScreensReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -1308,9 +2395,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintQueryScreensRequest {
+    type Reply = PrintQueryScreensReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintQueryScreensRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintQueryScreensReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_query_screens(conn)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PrintQueryScreensReply {
@@ -1320,14 +2419,14 @@ pub struct PrintQueryScreensReply {
     pub roots: Vec<xproto::Window>,
 }
 impl TryParse for PrintQueryScreensReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (list_count, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (list_count, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (roots, remaining) = crate::x11_utils::parse_list::<xproto::Window>(remaining, list_count.try_into().or(Err(ParseError::ParseError))?)?;
+        let (roots, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Window>(remaining, list_count.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = PrintQueryScreensReply { response_type, sequence, length, roots };
         Ok((result, remaining))
     }
@@ -1338,18 +2437,78 @@ impl TryFrom<&[u8]> for PrintQueryScreensReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintQueryScreensReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let list_count = u32::try_from(self.roots.len()).expect("`roots` has too many elements");
+        list_count.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.roots.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintSetImageResolution request
 pub const PRINT_SET_IMAGE_RESOLUTION_REQUEST: u8 = 23;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintSetImageResolutionRequest {
+    pub context: Pcontext,
+    pub image_resolution: u16,
+}
+impl PrintSetImageResolutionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_SET_IMAGE_RESOLUTION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let (image_resolution, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintSetImageResolutionRequest {
+            context,
+            image_resolution,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Pcontext> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `image_resolution` field of this structure.
+    pub fn image_resolution(mut self, value: u16) -> Self {
+        self.image_resolution = value;
+        self
+    }
+}
+
 pub fn print_set_image_resolution<Conn>(conn: &Conn, context: Pcontext, image_resolution: u16) -> Result<Cookie<'_, Conn, PrintSetImageResolutionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
-    let image_resolution_bytes = image_resolution.serialize();
+    let context_bytes = context.serialize_with_order(order);
+    let image_resolution_bytes = image_resolution.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_SET_IMAGE_RESOLUTION_REQUEST,
@@ -1367,9 +2526,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintSetImageResolutionRequest {
+    type Reply = PrintSetImageResolutionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintSetImageResolutionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintSetImageResolutionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_set_image_resolution(conn, self.context, self.image_resolution)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PrintSetImageResolutionReply {
@@ -1380,12 +2551,12 @@ pub struct PrintSetImageResolutionReply {
     pub previous_resolutions: u16,
 }
 impl TryParse for PrintSetImageResolutionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (previous_resolutions, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (previous_resolutions, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = PrintSetImageResolutionReply { response_type, status, sequence, length, previous_resolutions };
         Ok((result, remaining))
     }
@@ -1396,17 +2567,66 @@ impl TryFrom<&[u8]> for PrintSetImageResolutionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintSetImageResolutionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.status.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.previous_resolutions.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PrintGetImageResolution request
 pub const PRINT_GET_IMAGE_RESOLUTION_REQUEST: u8 = 24;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintGetImageResolutionRequest {
+    pub context: Pcontext,
+}
+impl PrintGetImageResolutionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PRINT_GET_IMAGE_RESOLUTION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PrintGetImageResolutionRequest {
+            context,
+        })
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Pcontext> {
+        self.context = value.into();
+        self
+    }
+}
+
 pub fn print_get_image_resolution<Conn>(conn: &Conn, context: Pcontext) -> Result<Cookie<'_, Conn, PrintGetImageResolutionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_bytes = context.serialize();
+    let context_bytes = context.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PRINT_GET_IMAGE_RESOLUTION_REQUEST,
@@ -1420,9 +2640,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PrintGetImageResolutionRequest {
+    type Reply = PrintGetImageResolutionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PrintGetImageResolutionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, PrintGetImageResolutionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        print_get_image_resolution(conn, self.context)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PrintGetImageResolutionReply {
@@ -1432,12 +2664,12 @@ pub struct PrintGetImageResolutionReply {
     pub image_resolution: u16,
 }
 impl TryParse for PrintGetImageResolutionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (image_resolution, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (image_resolution, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = PrintGetImageResolutionReply { response_type, sequence, length, image_resolution };
         Ok((result, remaining))
     }
@@ -1448,6 +2680,29 @@ impl TryFrom<&[u8]> for PrintGetImageResolutionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for PrintGetImageResolutionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.image_resolution.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the Notify event
 pub const NOTIFY_EVENT: u8 = 0;
@@ -1460,12 +2715,12 @@ pub struct NotifyEvent {
     pub cancel: bool,
 }
 impl TryParse for NotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (context, remaining) = Pcontext::try_parse(remaining)?;
-        let (cancel, remaining) = bool::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
+        let (cancel, remaining) = bool::try_parse_with_order(remaining, order)?;
         let result = NotifyEvent { response_type, detail, sequence, context, cancel };
         Ok((result, remaining))
     }
@@ -1478,11 +2733,22 @@ impl TryFrom<&[u8]> for NotifyEvent {
 }
 impl From<&NotifyEvent> for [u8; 32] {
     fn from(input: &NotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let detail_bytes = input.detail.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let context_bytes = input.context.serialize();
-        let cancel_bytes = input.cancel.serialize();
+        input.serialize()
+    }
+}
+impl From<NotifyEvent> for [u8; 32] {
+    fn from(input: NotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for NotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let detail_bytes = self.detail.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let context_bytes = self.context.serialize_with_order(order);
+        let cancel_bytes = self.cancel.serialize_with_order(order);
         [
             response_type_bytes[0],
             detail_bytes[0],
@@ -1519,10 +2785,8 @@ impl From<&NotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<NotifyEvent> for [u8; 32] {
-    fn from(input: NotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1536,11 +2800,11 @@ pub struct AttributNotifyEvent {
     pub context: Pcontext,
 }
 impl TryParse for AttributNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (context, remaining) = Pcontext::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (context, remaining) = Pcontext::try_parse_with_order(remaining, order)?;
         let result = AttributNotifyEvent { response_type, detail, sequence, context };
         Ok((result, remaining))
     }
@@ -1553,10 +2817,21 @@ impl TryFrom<&[u8]> for AttributNotifyEvent {
 }
 impl From<&AttributNotifyEvent> for [u8; 32] {
     fn from(input: &AttributNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let detail_bytes = input.detail.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let context_bytes = input.context.serialize();
+        input.serialize()
+    }
+}
+impl From<AttributNotifyEvent> for [u8; 32] {
+    fn from(input: AttributNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for AttributNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let detail_bytes = self.detail.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let context_bytes = self.context.serialize_with_order(order);
         [
             response_type_bytes[0],
             detail_bytes[0],
@@ -1593,10 +2868,8 @@ impl From<&AttributNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<AttributNotifyEvent> for [u8; 32] {
-    fn from(input: AttributNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1609,10 +2882,10 @@ pub struct BadContextError {
     pub sequence: u16,
 }
 impl TryParse for BadContextError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadContextError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1625,9 +2898,20 @@ impl TryFrom<&[u8]> for BadContextError {
 }
 impl From<&BadContextError> for [u8; 32] {
     fn from(input: &BadContextError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadContextError> for [u8; 32] {
+    fn from(input: BadContextError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadContextError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1664,10 +2948,8 @@ impl From<&BadContextError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadContextError> for [u8; 32] {
-    fn from(input: BadContextError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1680,10 +2962,10 @@ pub struct BadSequenceError {
     pub sequence: u16,
 }
 impl TryParse for BadSequenceError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadSequenceError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1696,9 +2978,20 @@ impl TryFrom<&[u8]> for BadSequenceError {
 }
 impl From<&BadSequenceError> for [u8; 32] {
     fn from(input: &BadSequenceError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadSequenceError> for [u8; 32] {
+    fn from(input: BadSequenceError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadSequenceError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1735,10 +3028,8 @@ impl From<&BadSequenceError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadSequenceError> for [u8; 32] {
-    fn from(input: BadSequenceError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1792,7 +3083,9 @@ pub trait ConnectionExt: RequestConnection {
     {
         print_end_doc(self, cancel)
     }
-    fn xprint_print_put_document_data<'c>(&'c self, drawable: xproto::Drawable, data: &[u8], doc_format: &[String8], options: &[String8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn xprint_print_put_document_data<'c, A>(&'c self, drawable: A, data: &[u8], doc_format: &[String8], options: &[String8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         print_put_document_data(self, drawable, data, doc_format, options)
     }
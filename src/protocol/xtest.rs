@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -33,15 +33,50 @@ pub const X11_XML_VERSION: (u32, u32) = (2, 2);
 
 /// Opcode for the GetVersion request
 pub const GET_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetVersionRequest {
+    pub major_version: u8,
+    pub minor_version: u16,
+}
+impl GetVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (major_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+    /// Set the `major_version` field of this structure.
+    pub fn major_version(mut self, value: u8) -> Self {
+        self.major_version = value;
+        self
+    }
+    /// Set the `minor_version` field of this structure.
+    pub fn minor_version(mut self, value: u16) -> Self {
+        self.minor_version = value;
+        self
+    }
+}
+
 pub fn get_version<Conn>(conn: &Conn, major_version: u8, minor_version: u16) -> Result<Cookie<'_, Conn, GetVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let major_version_bytes = major_version.serialize();
-    let minor_version_bytes = minor_version.serialize();
+    let major_version_bytes = major_version.serialize_with_order(order);
+    let minor_version_bytes = minor_version.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_VERSION_REQUEST,
@@ -55,9 +90,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetVersionRequest {
+    type Reply = GetVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_version(conn, self.major_version, self.minor_version)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetVersionReply {
@@ -68,12 +115,12 @@ pub struct GetVersionReply {
     pub minor_version: u16,
 }
 impl TryParse for GetVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (major_version, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = GetVersionReply { response_type, major_version, sequence, length, minor_version };
         Ok((result, remaining))
     }
@@ -84,88 +131,114 @@ impl TryFrom<&[u8]> for GetVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Cursor {
-    None = 0,
-    Current = 1,
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Cursor(u8);
+#[allow(non_upper_case_globals)]
+impl Cursor {
+    pub const None: Self = Self(0);
+    pub const Current: Self = Self(1);
 }
+enum_newtype!(Cursor, u8, [None, Current]);
 impl From<Cursor> for bool {
     fn from(input: Cursor) -> Self {
-        match input {
-            Cursor::None => false,
-            Cursor::Current => true,
-        }
-    }
-}
-impl From<Cursor> for u8 {
-    fn from(input: Cursor) -> Self {
-        match input {
-            Cursor::None => 0,
-            Cursor::Current => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<Cursor> for Option<u8> {
     fn from(input: Cursor) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Cursor> for u16 {
     fn from(input: Cursor) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Cursor> for Option<u16> {
     fn from(input: Cursor) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Cursor> for u32 {
     fn from(input: Cursor) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Cursor> for Option<u32> {
     fn from(input: Cursor) -> Self {
-        Some(u32::from(input))
+        Some(u32::from(input.0))
     }
 }
-impl TryFrom<u8> for Cursor {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Cursor::None),
-            1 => Ok(Cursor::Current),
-            _ => Err(ParseError::ParseError),
+
+/// Opcode for the CompareCursor request
+pub const COMPARE_CURSOR_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CompareCursorRequest {
+    pub window: xproto::Window,
+    pub cursor: xproto::Cursor,
+}
+impl CompareCursorRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COMPARE_CURSOR_REQUEST {
+            return Err(ParseError::ParseError);
         }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (cursor, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CompareCursorRequest {
+            window,
+            cursor,
+        })
     }
-}
-impl TryFrom<u16> for Cursor {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
     }
-}
-impl TryFrom<u32> for Cursor {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+    /// Set the `cursor` field of this structure.
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<xproto::Cursor> {
+        self.cursor = value.into();
+        self
     }
 }
 
-/// Opcode for the CompareCursor request
-pub const COMPARE_CURSOR_REQUEST: u8 = 1;
 pub fn compare_cursor<Conn>(conn: &Conn, window: xproto::Window, cursor: xproto::Cursor) -> Result<Cookie<'_, Conn, CompareCursorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
-    let cursor_bytes = cursor.serialize();
+    let window_bytes = window.serialize_with_order(order);
+    let cursor_bytes = cursor.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         COMPARE_CURSOR_REQUEST,
@@ -183,9 +256,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CompareCursorRequest {
+    type Reply = CompareCursorReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CompareCursorRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, CompareCursorReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        compare_cursor(conn, self.window, self.cursor)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CompareCursorReply {
@@ -195,11 +280,11 @@ pub struct CompareCursorReply {
     pub length: u32,
 }
 impl TryParse for CompareCursorReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (same, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (same, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = CompareCursorReply { response_type, same, sequence, length };
         Ok((result, remaining))
     }
@@ -210,23 +295,122 @@ impl TryFrom<&[u8]> for CompareCursorReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CompareCursorReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        self.same.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the FakeInput request
 pub const FAKE_INPUT_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FakeInputRequest {
+    pub type_: u8,
+    pub detail: u8,
+    pub time: u32,
+    pub root: xproto::Window,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub deviceid: u8,
+}
+impl FakeInputRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FAKE_INPUT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (detail, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
+        let (time, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::ParseError)?;
+        let (root_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (root_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(7..).ok_or(ParseError::ParseError)?;
+        let (deviceid, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(FakeInputRequest {
+            type_,
+            detail,
+            time,
+            root,
+            root_x,
+            root_y,
+            deviceid,
+        })
+    }
+    /// Set the `type_` field of this structure.
+    pub fn type_(mut self, value: u8) -> Self {
+        self.type_ = value;
+        self
+    }
+    /// Set the `detail` field of this structure.
+    pub fn detail(mut self, value: u8) -> Self {
+        self.detail = value;
+        self
+    }
+    /// Set the `time` field of this structure.
+    pub fn time(mut self, value: u32) -> Self {
+        self.time = value;
+        self
+    }
+    /// Set the `root` field of this structure.
+    pub fn root<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.root = value.into();
+        self
+    }
+    /// Set the `root_x` field of this structure.
+    pub fn root_x(mut self, value: i16) -> Self {
+        self.root_x = value;
+        self
+    }
+    /// Set the `root_y` field of this structure.
+    pub fn root_y(mut self, value: i16) -> Self {
+        self.root_y = value;
+        self
+    }
+    /// Set the `deviceid` field of this structure.
+    pub fn deviceid(mut self, value: u8) -> Self {
+        self.deviceid = value;
+        self
+    }
+}
+
 pub fn fake_input<Conn>(conn: &Conn, type_: u8, detail: u8, time: u32, root: xproto::Window, root_x: i16, root_y: i16, deviceid: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let type_bytes = type_.serialize();
-    let detail_bytes = detail.serialize();
-    let time_bytes = time.serialize();
-    let root_bytes = root.serialize();
-    let root_x_bytes = root_x.serialize();
-    let root_y_bytes = root_y.serialize();
-    let deviceid_bytes = deviceid.serialize();
+    let type_bytes = type_.serialize_with_order(order);
+    let detail_bytes = detail.serialize_with_order(order);
+    let time_bytes = time.serialize_with_order(order);
+    let root_bytes = root.serialize_with_order(order);
+    let root_x_bytes = root_x.serialize_with_order(order);
+    let root_y_bytes = root_y.serialize_with_order(order);
+    let deviceid_bytes = deviceid.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         FAKE_INPUT_REQUEST,
@@ -268,20 +452,59 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for FakeInputRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for FakeInputRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        fake_input(conn, self.type_, self.detail, self.time, self.root, self.root_x, self.root_y, self.deviceid)
+    }
+}
 
 /// Opcode for the GrabControl request
 pub const GRAB_CONTROL_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GrabControlRequest {
+    pub impervious: bool,
+}
+impl GrabControlRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GRAB_CONTROL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (impervious, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GrabControlRequest {
+            impervious,
+        })
+    }
+    /// Set the `impervious` field of this structure.
+    pub fn impervious(mut self, value: bool) -> Self {
+        self.impervious = value;
+        self
+    }
+}
+
 pub fn grab_control<Conn>(conn: &Conn, impervious: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let impervious_bytes = impervious.serialize();
+    let impervious_bytes = impervious.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GRAB_CONTROL_REQUEST,
@@ -295,9 +518,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GrabControlRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GrabControlRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        grab_control(conn, self.impervious)
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
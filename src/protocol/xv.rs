@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -32,498 +32,310 @@ pub const X11_EXTENSION_NAME: &str = "XVideo";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (2, 2);
 
-pub type Port = u32;
-
-pub type Encoding = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Port(u32);
+impl Port {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Port(0);
+}
+impl From<u32> for Port {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Port> for u32 {
+    fn from(value: Port) -> Self {
+        value.0
+    }
+}
+impl TryParse for Port {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Port {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Type {
-    InputMask = 1 << 0,
-    OutputMask = 1 << 1,
-    VideoMask = 1 << 2,
-    StillMask = 1 << 3,
-    ImageMask = 1 << 4,
-}
-impl From<Type> for u8 {
-    fn from(input: Type) -> Self {
-        match input {
-            Type::InputMask => 1 << 0,
-            Type::OutputMask => 1 << 1,
-            Type::VideoMask => 1 << 2,
-            Type::StillMask => 1 << 3,
-            Type::ImageMask => 1 << 4,
-        }
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Encoding(u32);
+impl Encoding {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Encoding(0);
+}
+impl From<u32> for Encoding {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Encoding> for u32 {
+    fn from(value: Encoding) -> Self {
+        value.0
+    }
+}
+impl TryParse for Encoding {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Encoding {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Type(u8);
+#[allow(non_upper_case_globals)]
+impl Type {
+    pub const InputMask: Self = Self(1 << 0);
+    pub const OutputMask: Self = Self(1 << 1);
+    pub const VideoMask: Self = Self(1 << 2);
+    pub const StillMask: Self = Self(1 << 3);
+    pub const ImageMask: Self = Self(1 << 4);
+}
+bitmask_set!(Type, u8, [InputMask, OutputMask, VideoMask, StillMask, ImageMask]);
 impl From<Type> for Option<u8> {
     fn from(input: Type) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<Type> for u16 {
     fn from(input: Type) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Type> for Option<u16> {
     fn from(input: Type) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<Type> for u32 {
     fn from(input: Type) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<Type> for Option<u32> {
     fn from(input: Type) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for Type {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(Type::InputMask),
-            2 => Ok(Type::OutputMask),
-            4 => Ok(Type::VideoMask),
-            8 => Ok(Type::StillMask),
-            16 => Ok(Type::ImageMask),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for Type {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for Type {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
-bitmask_binop!(Type, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-pub enum ImageFormatInfoType {
-    RGB = 0,
-    YUV = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ImageFormatInfoType(u8);
+#[allow(non_upper_case_globals)]
+impl ImageFormatInfoType {
+    pub const RGB: Self = Self(0);
+    pub const YUV: Self = Self(1);
+}
+enum_newtype!(ImageFormatInfoType, u8, [RGB, YUV]);
 impl From<ImageFormatInfoType> for bool {
     fn from(input: ImageFormatInfoType) -> Self {
-        match input {
-            ImageFormatInfoType::RGB => false,
-            ImageFormatInfoType::YUV => true,
-        }
-    }
-}
-impl From<ImageFormatInfoType> for u8 {
-    fn from(input: ImageFormatInfoType) -> Self {
-        match input {
-            ImageFormatInfoType::RGB => 0,
-            ImageFormatInfoType::YUV => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<ImageFormatInfoType> for Option<u8> {
     fn from(input: ImageFormatInfoType) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ImageFormatInfoType> for u16 {
     fn from(input: ImageFormatInfoType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ImageFormatInfoType> for Option<u16> {
     fn from(input: ImageFormatInfoType) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ImageFormatInfoType> for u32 {
     fn from(input: ImageFormatInfoType) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ImageFormatInfoType> for Option<u32> {
     fn from(input: ImageFormatInfoType) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ImageFormatInfoType {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(ImageFormatInfoType::RGB),
-            1 => Ok(ImageFormatInfoType::YUV),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ImageFormatInfoType {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ImageFormatInfoType {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ImageFormatInfoFormat {
-    Packed = 0,
-    Planar = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ImageFormatInfoFormat(u8);
+#[allow(non_upper_case_globals)]
+impl ImageFormatInfoFormat {
+    pub const Packed: Self = Self(0);
+    pub const Planar: Self = Self(1);
+}
+enum_newtype!(ImageFormatInfoFormat, u8, [Packed, Planar]);
 impl From<ImageFormatInfoFormat> for bool {
     fn from(input: ImageFormatInfoFormat) -> Self {
-        match input {
-            ImageFormatInfoFormat::Packed => false,
-            ImageFormatInfoFormat::Planar => true,
-        }
-    }
-}
-impl From<ImageFormatInfoFormat> for u8 {
-    fn from(input: ImageFormatInfoFormat) -> Self {
-        match input {
-            ImageFormatInfoFormat::Packed => 0,
-            ImageFormatInfoFormat::Planar => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<ImageFormatInfoFormat> for Option<u8> {
     fn from(input: ImageFormatInfoFormat) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ImageFormatInfoFormat> for u16 {
     fn from(input: ImageFormatInfoFormat) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ImageFormatInfoFormat> for Option<u16> {
     fn from(input: ImageFormatInfoFormat) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ImageFormatInfoFormat> for u32 {
     fn from(input: ImageFormatInfoFormat) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ImageFormatInfoFormat> for Option<u32> {
     fn from(input: ImageFormatInfoFormat) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ImageFormatInfoFormat {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(ImageFormatInfoFormat::Packed),
-            1 => Ok(ImageFormatInfoFormat::Planar),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ImageFormatInfoFormat {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ImageFormatInfoFormat {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum AttributeFlag {
-    Gettable = 1 << 0,
-    Settable = 1 << 1,
-}
-impl From<AttributeFlag> for u8 {
-    fn from(input: AttributeFlag) -> Self {
-        match input {
-            AttributeFlag::Gettable => 1 << 0,
-            AttributeFlag::Settable => 1 << 1,
-        }
-    }
-}
-impl From<AttributeFlag> for Option<u8> {
-    fn from(input: AttributeFlag) -> Self {
-        Some(u8::from(input))
-    }
-}
-impl From<AttributeFlag> for u16 {
-    fn from(input: AttributeFlag) -> Self {
-        Self::from(u8::from(input))
-    }
-}
-impl From<AttributeFlag> for Option<u16> {
-    fn from(input: AttributeFlag) -> Self {
-        Some(u16::from(input))
-    }
-}
-impl From<AttributeFlag> for u32 {
-    fn from(input: AttributeFlag) -> Self {
-        Self::from(u8::from(input))
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AttributeFlag(u32);
+#[allow(non_upper_case_globals)]
+impl AttributeFlag {
+    pub const Gettable: Self = Self(1 << 0);
+    pub const Settable: Self = Self(1 << 1);
+}
+bitmask_set!(AttributeFlag, u32, [Gettable, Settable]);
 impl From<AttributeFlag> for Option<u32> {
     fn from(input: AttributeFlag) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for AttributeFlag {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(AttributeFlag::Gettable),
-            2 => Ok(AttributeFlag::Settable),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for AttributeFlag {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(input.0)
     }
 }
-impl TryFrom<u32> for AttributeFlag {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-bitmask_binop!(AttributeFlag, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum VideoNotifyReason {
-    Started = 0,
-    Stopped = 1,
-    Busy = 2,
-    Preempted = 3,
-    HardError = 4,
-}
-impl From<VideoNotifyReason> for u8 {
-    fn from(input: VideoNotifyReason) -> Self {
-        match input {
-            VideoNotifyReason::Started => 0,
-            VideoNotifyReason::Stopped => 1,
-            VideoNotifyReason::Busy => 2,
-            VideoNotifyReason::Preempted => 3,
-            VideoNotifyReason::HardError => 4,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct VideoNotifyReason(u8);
+#[allow(non_upper_case_globals)]
+impl VideoNotifyReason {
+    pub const Started: Self = Self(0);
+    pub const Stopped: Self = Self(1);
+    pub const Busy: Self = Self(2);
+    pub const Preempted: Self = Self(3);
+    pub const HardError: Self = Self(4);
+}
+enum_newtype!(VideoNotifyReason, u8, [Started, Stopped, Busy, Preempted, HardError]);
 impl From<VideoNotifyReason> for Option<u8> {
     fn from(input: VideoNotifyReason) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<VideoNotifyReason> for u16 {
     fn from(input: VideoNotifyReason) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<VideoNotifyReason> for Option<u16> {
     fn from(input: VideoNotifyReason) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<VideoNotifyReason> for u32 {
     fn from(input: VideoNotifyReason) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<VideoNotifyReason> for Option<u32> {
     fn from(input: VideoNotifyReason) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for VideoNotifyReason {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(VideoNotifyReason::Started),
-            1 => Ok(VideoNotifyReason::Stopped),
-            2 => Ok(VideoNotifyReason::Busy),
-            3 => Ok(VideoNotifyReason::Preempted),
-            4 => Ok(VideoNotifyReason::HardError),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for VideoNotifyReason {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for VideoNotifyReason {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ScanlineOrder {
-    TopToBottom = 0,
-    BottomToTop = 1,
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ScanlineOrder(u8);
+#[allow(non_upper_case_globals)]
+impl ScanlineOrder {
+    pub const TopToBottom: Self = Self(0);
+    pub const BottomToTop: Self = Self(1);
+}
+enum_newtype!(ScanlineOrder, u8, [TopToBottom, BottomToTop]);
 impl From<ScanlineOrder> for bool {
     fn from(input: ScanlineOrder) -> Self {
-        match input {
-            ScanlineOrder::TopToBottom => false,
-            ScanlineOrder::BottomToTop => true,
-        }
-    }
-}
-impl From<ScanlineOrder> for u8 {
-    fn from(input: ScanlineOrder) -> Self {
-        match input {
-            ScanlineOrder::TopToBottom => 0,
-            ScanlineOrder::BottomToTop => 1,
-        }
+        input.0 != 0
     }
 }
 impl From<ScanlineOrder> for Option<u8> {
     fn from(input: ScanlineOrder) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<ScanlineOrder> for u16 {
     fn from(input: ScanlineOrder) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ScanlineOrder> for Option<u16> {
     fn from(input: ScanlineOrder) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<ScanlineOrder> for u32 {
     fn from(input: ScanlineOrder) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<ScanlineOrder> for Option<u32> {
     fn from(input: ScanlineOrder) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for ScanlineOrder {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(ScanlineOrder::TopToBottom),
-            1 => Ok(ScanlineOrder::BottomToTop),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for ScanlineOrder {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for ScanlineOrder {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GrabPortStatus {
-    Success = 0,
-    BadExtension = 1,
-    AlreadyGrabbed = 2,
-    InvalidTime = 3,
-    BadReply = 4,
-    BadAlloc = 5,
-}
-impl From<GrabPortStatus> for u8 {
-    fn from(input: GrabPortStatus) -> Self {
-        match input {
-            GrabPortStatus::Success => 0,
-            GrabPortStatus::BadExtension => 1,
-            GrabPortStatus::AlreadyGrabbed => 2,
-            GrabPortStatus::InvalidTime => 3,
-            GrabPortStatus::BadReply => 4,
-            GrabPortStatus::BadAlloc => 5,
-        }
-    }
-}
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GrabPortStatus(u8);
+#[allow(non_upper_case_globals)]
+impl GrabPortStatus {
+    pub const Success: Self = Self(0);
+    pub const BadExtension: Self = Self(1);
+    pub const AlreadyGrabbed: Self = Self(2);
+    pub const InvalidTime: Self = Self(3);
+    pub const BadReply: Self = Self(4);
+    pub const BadAlloc: Self = Self(5);
+}
+enum_newtype!(GrabPortStatus, u8, [Success, BadExtension, AlreadyGrabbed, InvalidTime, BadReply, BadAlloc]);
 impl From<GrabPortStatus> for Option<u8> {
     fn from(input: GrabPortStatus) -> Self {
-        Some(u8::from(input))
+        Some(input.0)
     }
 }
 impl From<GrabPortStatus> for u16 {
     fn from(input: GrabPortStatus) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GrabPortStatus> for Option<u16> {
     fn from(input: GrabPortStatus) -> Self {
-        Some(u16::from(input))
+        Some(u16::from(input.0))
     }
 }
 impl From<GrabPortStatus> for u32 {
     fn from(input: GrabPortStatus) -> Self {
-        Self::from(u8::from(input))
+        Self::from(input.0)
     }
 }
 impl From<GrabPortStatus> for Option<u32> {
     fn from(input: GrabPortStatus) -> Self {
-        Some(u32::from(input))
-    }
-}
-impl TryFrom<u8> for GrabPortStatus {
-    type Error = ParseError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(GrabPortStatus::Success),
-            1 => Ok(GrabPortStatus::BadExtension),
-            2 => Ok(GrabPortStatus::AlreadyGrabbed),
-            3 => Ok(GrabPortStatus::InvalidTime),
-            4 => Ok(GrabPortStatus::BadReply),
-            5 => Ok(GrabPortStatus::BadAlloc),
-            _ => Err(ParseError::ParseError),
-        }
-    }
-}
-impl TryFrom<u16> for GrabPortStatus {
-    type Error = ParseError;
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
-    }
-}
-impl TryFrom<u32> for GrabPortStatus {
-    type Error = ParseError;
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(u8::try_from(value).or(Err(ParseError::ParseError))?)
+        Some(u32::from(input.0))
     }
 }
 
@@ -533,9 +345,9 @@ pub struct Rational {
     pub denominator: i32,
 }
 impl TryParse for Rational {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (numerator, remaining) = i32::try_parse(remaining)?;
-        let (denominator, remaining) = i32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (numerator, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (denominator, remaining) = i32::try_parse_with_order(remaining, order)?;
         let result = Rational { numerator, denominator };
         Ok((result, remaining))
     }
@@ -548,9 +360,9 @@ impl TryFrom<&[u8]> for Rational {
 }
 impl Serialize for Rational {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let numerator_bytes = self.numerator.serialize();
-        let denominator_bytes = self.denominator.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let numerator_bytes = self.numerator.serialize_with_order(order);
+        let denominator_bytes = self.denominator.serialize_with_order(order);
         [
             numerator_bytes[0],
             numerator_bytes[1],
@@ -562,10 +374,10 @@ impl Serialize for Rational {
             denominator_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.numerator.serialize_into(bytes);
-        self.denominator.serialize_into(bytes);
+        self.numerator.serialize_into_with_order(bytes, order);
+        self.denominator.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -575,9 +387,9 @@ pub struct Format {
     pub depth: u8,
 }
 impl TryParse for Format {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
         let result = Format { visual, depth };
         Ok((result, remaining))
@@ -591,9 +403,9 @@ impl TryFrom<&[u8]> for Format {
 }
 impl Serialize for Format {
     type Bytes = [u8; 8];
-    fn serialize(&self) -> [u8; 8] {
-        let visual_bytes = self.visual.serialize();
-        let depth_bytes = self.depth.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let visual_bytes = self.visual.serialize_with_order(order);
+        let depth_bytes = self.depth.serialize_with_order(order);
         [
             visual_bytes[0],
             visual_bytes[1],
@@ -605,10 +417,10 @@ impl Serialize for Format {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(8);
-        self.visual.serialize_into(bytes);
-        self.depth.serialize_into(bytes);
+        self.visual.serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
     }
 }
@@ -617,18 +429,18 @@ impl Serialize for Format {
 pub struct AdaptorInfo {
     pub base_id: Port,
     pub num_ports: u16,
-    pub type_: u8,
+    pub type_: Type,
     pub name: Vec<u8>,
     pub formats: Vec<Format>,
 }
 impl TryParse for AdaptorInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (base_id, remaining) = Port::try_parse(remaining)?;
-        let (name_size, remaining) = u16::try_parse(remaining)?;
-        let (num_ports, remaining) = u16::try_parse(remaining)?;
-        let (num_formats, remaining) = u16::try_parse(remaining)?;
-        let (type_, remaining) = u8::try_parse(remaining)?;
+        let (base_id, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (name_size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_ports, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_formats, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_size.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
@@ -636,7 +448,8 @@ impl TryParse for AdaptorInfo {
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
-        let (formats, remaining) = crate::x11_utils::parse_list::<Format>(remaining, num_formats.try_into().or(Err(ParseError::ParseError))?)?;
+        let (formats, remaining) = crate::x11_utils::parse_list_with_order::<Format>(remaining, num_formats.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let type_ = Type::from(type_);
         let result = AdaptorInfo { base_id, num_ports, type_, name, formats };
         Ok((result, remaining))
     }
@@ -649,24 +462,24 @@ impl TryFrom<&[u8]> for AdaptorInfo {
 }
 impl Serialize for AdaptorInfo {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(12);
-        self.base_id.serialize_into(bytes);
+        self.base_id.serialize_into_with_order(bytes, order);
         let name_size = u16::try_from(self.name.len()).expect("`name` has too many elements");
-        name_size.serialize_into(bytes);
-        self.num_ports.serialize_into(bytes);
+        name_size.serialize_into_with_order(bytes, order);
+        self.num_ports.serialize_into_with_order(bytes, order);
         let num_formats = u16::try_from(self.formats.len()).expect("`formats` has too many elements");
-        num_formats.serialize_into(bytes);
-        self.type_.serialize_into(bytes);
+        num_formats.serialize_into_with_order(bytes, order);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 1]);
         bytes.extend_from_slice(&self.name);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
-        self.formats.serialize_into(bytes);
+        self.formats.serialize_into_with_order(bytes, order);
     }
 }
 
@@ -679,14 +492,14 @@ pub struct EncodingInfo {
     pub name: Vec<u8>,
 }
 impl TryParse for EncodingInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (encoding, remaining) = Encoding::try_parse(remaining)?;
-        let (name_size, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (encoding, remaining) = Encoding::try_parse_with_order(remaining, order)?;
+        let (name_size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (rate, remaining) = Rational::try_parse(remaining)?;
+        let (rate, remaining) = Rational::try_parse_with_order(remaining, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_size.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
         // Align offset to multiple of 4
@@ -705,20 +518,20 @@ impl TryFrom<&[u8]> for EncodingInfo {
 }
 impl Serialize for EncodingInfo {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(20);
-        self.encoding.serialize_into(bytes);
+        self.encoding.serialize_into_with_order(bytes, order);
         let name_size = u16::try_from(self.name.len()).expect("`name` has too many elements");
-        name_size.serialize_into(bytes);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
+        name_size.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.rate.serialize_into(bytes);
+        self.rate.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.name);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
@@ -734,14 +547,14 @@ pub struct Image {
     pub data: Vec<u8>,
 }
 impl TryParse for Image {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (data_size, remaining) = u32::try_parse(remaining)?;
-        let (num_planes, remaining) = u32::try_parse(remaining)?;
-        let (pitches, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ParseError))?)?;
-        let (offsets, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ParseError))?)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data_size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_planes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pitches, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (offsets, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, data_size.try_into().or(Err(ParseError::ParseError))?)?;
         let data = data.to_vec();
         let result = Image { id, width, height, pitches, offsets, data };
@@ -756,47 +569,48 @@ impl TryFrom<&[u8]> for Image {
 }
 impl Serialize for Image {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(16);
-        self.id.serialize_into(bytes);
-        self.width.serialize_into(bytes);
-        self.height.serialize_into(bytes);
+        self.id.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
         let data_size = u32::try_from(self.data.len()).expect("`data` has too many elements");
-        data_size.serialize_into(bytes);
+        data_size.serialize_into_with_order(bytes, order);
         let num_planes = u32::try_from(self.pitches.len()).expect("`pitches` has too many elements");
-        num_planes.serialize_into(bytes);
-        self.pitches.serialize_into(bytes);
+        num_planes.serialize_into_with_order(bytes, order);
+        self.pitches.serialize_into_with_order(bytes, order);
         assert_eq!(self.offsets.len(), usize::try_from(num_planes).unwrap(), "`offsets` has an incorrect length");
-        self.offsets.serialize_into(bytes);
+        self.offsets.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.data);
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AttributeInfo {
-    pub flags: u32,
+    pub flags: AttributeFlag,
     pub min: i32,
     pub max: i32,
     pub name: Vec<u8>,
 }
 impl TryParse for AttributeInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (min, remaining) = i32::try_parse(remaining)?;
-        let (max, remaining) = i32::try_parse(remaining)?;
-        let (size, remaining) = u32::try_parse(remaining)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u32::try_parse_with_order(remaining, order)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, size.try_into().or(Err(ParseError::ParseError))?)?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
         let remaining = remaining.get(misalignment..).ok_or(ParseError::ParseError)?;
+        let flags = AttributeFlag::from(flags);
         let result = AttributeInfo { flags, min, max, name };
         Ok((result, remaining))
     }
@@ -809,18 +623,18 @@ impl TryFrom<&[u8]> for AttributeInfo {
 }
 impl Serialize for AttributeInfo {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Vec<u8> {
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(16);
-        self.flags.serialize_into(bytes);
-        self.min.serialize_into(bytes);
-        self.max.serialize_into(bytes);
+        u32::from(self.flags).serialize_into_with_order(bytes, order);
+        self.min.serialize_into_with_order(bytes, order);
+        self.max.serialize_into_with_order(bytes, order);
         let size = u32::try_from(self.name.len()).expect("`name` has too many elements");
-        size.serialize_into(bytes);
+        size.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.name);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
@@ -852,40 +666,40 @@ pub struct ImageFormatInfo {
     pub vscanline_order: ScanlineOrder,
 }
 impl TryParse for ImageFormatInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (byte_order, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (byte_order, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
         let (guid, remaining) = crate::x11_utils::parse_u8_list(remaining, 16)?;
         let guid = <[u8; 16]>::try_from(guid).unwrap();
-        let (bpp, remaining) = u8::try_parse(remaining)?;
-        let (num_planes, remaining) = u8::try_parse(remaining)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_planes, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(2..).ok_or(ParseError::ParseError)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (red_mask, remaining) = u32::try_parse(remaining)?;
-        let (green_mask, remaining) = u32::try_parse(remaining)?;
-        let (blue_mask, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
+        let (red_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (green_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (blue_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
-        let (y_sample_bits, remaining) = u32::try_parse(remaining)?;
-        let (u_sample_bits, remaining) = u32::try_parse(remaining)?;
-        let (v_sample_bits, remaining) = u32::try_parse(remaining)?;
-        let (vhorz_y_period, remaining) = u32::try_parse(remaining)?;
-        let (vhorz_u_period, remaining) = u32::try_parse(remaining)?;
-        let (vhorz_v_period, remaining) = u32::try_parse(remaining)?;
-        let (vvert_y_period, remaining) = u32::try_parse(remaining)?;
-        let (vvert_u_period, remaining) = u32::try_parse(remaining)?;
-        let (vvert_v_period, remaining) = u32::try_parse(remaining)?;
+        let (y_sample_bits, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (u_sample_bits, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (v_sample_bits, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vhorz_y_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vhorz_u_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vhorz_v_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vvert_y_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vvert_u_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vvert_v_period, remaining) = u32::try_parse_with_order(remaining, order)?;
         let (vcomp_order, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let vcomp_order = <[u8; 32]>::try_from(vcomp_order).unwrap();
-        let (vscanline_order, remaining) = u8::try_parse(remaining)?;
+        let (vscanline_order, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(11..).ok_or(ParseError::ParseError)?;
-        let type_ = type_.try_into()?;
-        let byte_order = byte_order.try_into()?;
-        let format = format.try_into()?;
-        let vscanline_order = vscanline_order.try_into()?;
+        let type_ = ImageFormatInfoType::from(type_);
+        let byte_order = xproto::ImageOrder::from(byte_order);
+        let format = ImageFormatInfoFormat::from(format);
+        let vscanline_order = ScanlineOrder::from(vscanline_order);
         let result = ImageFormatInfo { id, type_, byte_order, guid, bpp, num_planes, depth, red_mask, green_mask, blue_mask, format, y_sample_bits, u_sample_bits, v_sample_bits, vhorz_y_period, vhorz_u_period, vhorz_v_period, vvert_y_period, vvert_u_period, vvert_v_period, vcomp_order, vscanline_order };
         Ok((result, remaining))
     }
@@ -898,27 +712,27 @@ impl TryFrom<&[u8]> for ImageFormatInfo {
 }
 impl Serialize for ImageFormatInfo {
     type Bytes = [u8; 128];
-    fn serialize(&self) -> [u8; 128] {
-        let id_bytes = self.id.serialize();
-        let type_bytes = u8::from(self.type_).serialize();
-        let byte_order_bytes = u8::from(self.byte_order).serialize();
-        let bpp_bytes = self.bpp.serialize();
-        let num_planes_bytes = self.num_planes.serialize();
-        let depth_bytes = self.depth.serialize();
-        let red_mask_bytes = self.red_mask.serialize();
-        let green_mask_bytes = self.green_mask.serialize();
-        let blue_mask_bytes = self.blue_mask.serialize();
-        let format_bytes = u8::from(self.format).serialize();
-        let y_sample_bits_bytes = self.y_sample_bits.serialize();
-        let u_sample_bits_bytes = self.u_sample_bits.serialize();
-        let v_sample_bits_bytes = self.v_sample_bits.serialize();
-        let vhorz_y_period_bytes = self.vhorz_y_period.serialize();
-        let vhorz_u_period_bytes = self.vhorz_u_period.serialize();
-        let vhorz_v_period_bytes = self.vhorz_v_period.serialize();
-        let vvert_y_period_bytes = self.vvert_y_period.serialize();
-        let vvert_u_period_bytes = self.vvert_u_period.serialize();
-        let vvert_v_period_bytes = self.vvert_v_period.serialize();
-        let vscanline_order_bytes = u8::from(self.vscanline_order).serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 128] {
+        let id_bytes = self.id.serialize_with_order(order);
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let byte_order_bytes = u8::from(self.byte_order).serialize_with_order(order);
+        let bpp_bytes = self.bpp.serialize_with_order(order);
+        let num_planes_bytes = self.num_planes.serialize_with_order(order);
+        let depth_bytes = self.depth.serialize_with_order(order);
+        let red_mask_bytes = self.red_mask.serialize_with_order(order);
+        let green_mask_bytes = self.green_mask.serialize_with_order(order);
+        let blue_mask_bytes = self.blue_mask.serialize_with_order(order);
+        let format_bytes = u8::from(self.format).serialize_with_order(order);
+        let y_sample_bits_bytes = self.y_sample_bits.serialize_with_order(order);
+        let u_sample_bits_bytes = self.u_sample_bits.serialize_with_order(order);
+        let v_sample_bits_bytes = self.v_sample_bits.serialize_with_order(order);
+        let vhorz_y_period_bytes = self.vhorz_y_period.serialize_with_order(order);
+        let vhorz_u_period_bytes = self.vhorz_u_period.serialize_with_order(order);
+        let vhorz_v_period_bytes = self.vhorz_v_period.serialize_with_order(order);
+        let vvert_y_period_bytes = self.vvert_y_period.serialize_with_order(order);
+        let vvert_u_period_bytes = self.vvert_u_period.serialize_with_order(order);
+        let vvert_v_period_bytes = self.vvert_v_period.serialize_with_order(order);
+        let vscanline_order_bytes = u8::from(self.vscanline_order).serialize_with_order(order);
         [
             id_bytes[0],
             id_bytes[1],
@@ -1050,34 +864,34 @@ impl Serialize for ImageFormatInfo {
             0,
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(128);
-        self.id.serialize_into(bytes);
-        u8::from(self.type_).serialize_into(bytes);
-        u8::from(self.byte_order).serialize_into(bytes);
+        self.id.serialize_into_with_order(bytes, order);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        u8::from(self.byte_order).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
         bytes.extend_from_slice(&self.guid);
-        self.bpp.serialize_into(bytes);
-        self.num_planes.serialize_into(bytes);
+        self.bpp.serialize_into_with_order(bytes, order);
+        self.num_planes.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 2]);
-        self.depth.serialize_into(bytes);
+        self.depth.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
-        self.red_mask.serialize_into(bytes);
-        self.green_mask.serialize_into(bytes);
-        self.blue_mask.serialize_into(bytes);
-        u8::from(self.format).serialize_into(bytes);
+        self.red_mask.serialize_into_with_order(bytes, order);
+        self.green_mask.serialize_into_with_order(bytes, order);
+        self.blue_mask.serialize_into_with_order(bytes, order);
+        u8::from(self.format).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 3]);
-        self.y_sample_bits.serialize_into(bytes);
-        self.u_sample_bits.serialize_into(bytes);
-        self.v_sample_bits.serialize_into(bytes);
-        self.vhorz_y_period.serialize_into(bytes);
-        self.vhorz_u_period.serialize_into(bytes);
-        self.vhorz_v_period.serialize_into(bytes);
-        self.vvert_y_period.serialize_into(bytes);
-        self.vvert_u_period.serialize_into(bytes);
-        self.vvert_v_period.serialize_into(bytes);
+        self.y_sample_bits.serialize_into_with_order(bytes, order);
+        self.u_sample_bits.serialize_into_with_order(bytes, order);
+        self.v_sample_bits.serialize_into_with_order(bytes, order);
+        self.vhorz_y_period.serialize_into_with_order(bytes, order);
+        self.vhorz_u_period.serialize_into_with_order(bytes, order);
+        self.vhorz_v_period.serialize_into_with_order(bytes, order);
+        self.vvert_y_period.serialize_into_with_order(bytes, order);
+        self.vvert_u_period.serialize_into_with_order(bytes, order);
+        self.vvert_v_period.serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&self.vcomp_order);
-        u8::from(self.vscanline_order).serialize_into(bytes);
+        u8::from(self.vscanline_order).serialize_into_with_order(bytes, order);
         bytes.extend_from_slice(&[0; 11]);
     }
 }
@@ -1091,10 +905,10 @@ pub struct BadPortError {
     pub sequence: u16,
 }
 impl TryParse for BadPortError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadPortError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1107,9 +921,20 @@ impl TryFrom<&[u8]> for BadPortError {
 }
 impl From<&BadPortError> for [u8; 32] {
     fn from(input: &BadPortError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadPortError> for [u8; 32] {
+    fn from(input: BadPortError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadPortError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1146,10 +971,8 @@ impl From<&BadPortError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadPortError> for [u8; 32] {
-    fn from(input: BadPortError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1162,10 +985,10 @@ pub struct BadEncodingError {
     pub sequence: u16,
 }
 impl TryParse for BadEncodingError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadEncodingError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1178,9 +1001,20 @@ impl TryFrom<&[u8]> for BadEncodingError {
 }
 impl From<&BadEncodingError> for [u8; 32] {
     fn from(input: &BadEncodingError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadEncodingError> for [u8; 32] {
+    fn from(input: BadEncodingError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadEncodingError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1217,10 +1051,8 @@ impl From<&BadEncodingError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadEncodingError> for [u8; 32] {
-    fn from(input: BadEncodingError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1233,10 +1065,10 @@ pub struct BadControlError {
     pub sequence: u16,
 }
 impl TryParse for BadControlError {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (error_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = BadControlError { response_type, error_code, sequence };
         Ok((result, remaining))
     }
@@ -1249,9 +1081,20 @@ impl TryFrom<&[u8]> for BadControlError {
 }
 impl From<&BadControlError> for [u8; 32] {
     fn from(input: &BadControlError) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let error_code_bytes = input.error_code.serialize();
-        let sequence_bytes = input.sequence.serialize();
+        input.serialize()
+    }
+}
+impl From<BadControlError> for [u8; 32] {
+    fn from(input: BadControlError) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for BadControlError {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let error_code_bytes = self.error_code.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
         [
             response_type_bytes[0],
             error_code_bytes[0],
@@ -1288,10 +1131,8 @@ impl From<&BadControlError> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<BadControlError> for [u8; 32] {
-    fn from(input: BadControlError) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1307,14 +1148,14 @@ pub struct VideoNotifyEvent {
     pub port: Port,
 }
 impl TryParse for VideoNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (reason, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (port, remaining) = Port::try_parse(remaining)?;
-        let reason = reason.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (reason, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let reason = VideoNotifyReason::from(reason);
         let result = VideoNotifyEvent { response_type, reason, sequence, time, drawable, port };
         Ok((result, remaining))
     }
@@ -1327,12 +1168,23 @@ impl TryFrom<&[u8]> for VideoNotifyEvent {
 }
 impl From<&VideoNotifyEvent> for [u8; 32] {
     fn from(input: &VideoNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let reason_bytes = u8::from(input.reason).serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let drawable_bytes = input.drawable.serialize();
-        let port_bytes = input.port.serialize();
+        input.serialize()
+    }
+}
+impl From<VideoNotifyEvent> for [u8; 32] {
+    fn from(input: VideoNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for VideoNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let reason_bytes = u8::from(self.reason).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let port_bytes = self.port.serialize_with_order(order);
         [
             response_type_bytes[0],
             reason_bytes[0],
@@ -1369,10 +1221,8 @@ impl From<&VideoNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<VideoNotifyEvent> for [u8; 32] {
-    fn from(input: VideoNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
@@ -1388,14 +1238,14 @@ pub struct PortNotifyEvent {
     pub value: i32,
 }
 impl TryParse for PortNotifyEvent {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (port, remaining) = Port::try_parse(remaining)?;
-        let (attribute, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (value, remaining) = i32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (attribute, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = i32::try_parse_with_order(remaining, order)?;
         let result = PortNotifyEvent { response_type, sequence, time, port, attribute, value };
         Ok((result, remaining))
     }
@@ -1408,12 +1258,23 @@ impl TryFrom<&[u8]> for PortNotifyEvent {
 }
 impl From<&PortNotifyEvent> for [u8; 32] {
     fn from(input: &PortNotifyEvent) -> Self {
-        let response_type_bytes = input.response_type.serialize();
-        let sequence_bytes = input.sequence.serialize();
-        let time_bytes = input.time.serialize();
-        let port_bytes = input.port.serialize();
-        let attribute_bytes = input.attribute.serialize();
-        let value_bytes = input.value.serialize();
+        input.serialize()
+    }
+}
+impl From<PortNotifyEvent> for [u8; 32] {
+    fn from(input: PortNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+impl Serialize for PortNotifyEvent {
+    type Bytes = [u8; 32];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let port_bytes = self.port.serialize_with_order(order);
+        let attribute_bytes = self.attribute.serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
         [
             response_type_bytes[0],
             0,
@@ -1450,21 +1311,37 @@ impl From<&PortNotifyEvent> for [u8; 32] {
             0,
         ]
     }
-}
-impl From<PortNotifyEvent> for [u8; 32] {
-    fn from(input: PortNotifyEvent) -> Self {
-        Self::from(&input)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.extend_from_slice(&self.serialize_with_order(order));
     }
 }
 
 /// Opcode for the QueryExtension request
 pub const QUERY_EXTENSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryExtensionRequest {
+}
+impl QueryExtensionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_EXTENSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryExtensionRequest {
+        })
+    }
+}
+
 pub fn query_extension<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryExtensionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -1475,9 +1352,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryExtensionRequest {
+    type Reply = QueryExtensionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryExtensionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryExtensionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_extension(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryExtensionReply {
@@ -1488,13 +1377,13 @@ pub struct QueryExtensionReply {
     pub minor: u16,
 }
 impl TryParse for QueryExtensionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major, remaining) = u16::try_parse(remaining)?;
-        let (minor, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = QueryExtensionReply { response_type, sequence, length, major, minor };
         Ok((result, remaining))
     }
@@ -1505,17 +1394,67 @@ impl TryFrom<&[u8]> for QueryExtensionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryExtensionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major.serialize_into_with_order(bytes, order);
+        self.minor.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryAdaptors request
 pub const QUERY_ADAPTORS_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryAdaptorsRequest {
+    pub window: xproto::Window,
+}
+impl QueryAdaptorsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_ADAPTORS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryAdaptorsRequest {
+            window,
+        })
+    }
+    /// Set the `window` field of this structure.
+    pub fn window<I>(mut self, value: I) -> Self where I: Into<xproto::Window> {
+        self.window = value.into();
+        self
+    }
+}
+
 pub fn query_adaptors<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, QueryAdaptorsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let window_bytes = window.serialize();
+    let window_bytes = window.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_ADAPTORS_REQUEST,
@@ -1529,9 +1468,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryAdaptorsRequest {
+    type Reply = QueryAdaptorsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryAdaptorsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryAdaptorsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_adaptors(conn, self.window)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryAdaptorsReply {
@@ -1541,14 +1492,14 @@ pub struct QueryAdaptorsReply {
     pub info: Vec<AdaptorInfo>,
 }
 impl TryParse for QueryAdaptorsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_adaptors, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_adaptors, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (info, remaining) = crate::x11_utils::parse_list::<AdaptorInfo>(remaining, num_adaptors.try_into().or(Err(ParseError::ParseError))?)?;
+        let (info, remaining) = crate::x11_utils::parse_list_with_order::<AdaptorInfo>(remaining, num_adaptors.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryAdaptorsReply { response_type, sequence, length, info };
         Ok((result, remaining))
     }
@@ -1559,17 +1510,69 @@ impl TryFrom<&[u8]> for QueryAdaptorsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryAdaptorsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_adaptors = u16::try_from(self.info.len()).expect("`info` has too many elements");
+        num_adaptors.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.info.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryEncodings request
 pub const QUERY_ENCODINGS_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryEncodingsRequest {
+    pub port: Port,
+}
+impl QueryEncodingsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_ENCODINGS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryEncodingsRequest {
+            port,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+}
+
 pub fn query_encodings<Conn>(conn: &Conn, port: Port) -> Result<Cookie<'_, Conn, QueryEncodingsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
+    let port_bytes = port.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_ENCODINGS_REQUEST,
@@ -1583,9 +1586,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryEncodingsRequest {
+    type Reply = QueryEncodingsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryEncodingsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryEncodingsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_encodings(conn, self.port)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryEncodingsReply {
@@ -1595,14 +1610,14 @@ pub struct QueryEncodingsReply {
     pub info: Vec<EncodingInfo>,
 }
 impl TryParse for QueryEncodingsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_encodings, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_encodings, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(22..).ok_or(ParseError::ParseError)?;
-        let (info, remaining) = crate::x11_utils::parse_list::<EncodingInfo>(remaining, num_encodings.try_into().or(Err(ParseError::ParseError))?)?;
+        let (info, remaining) = crate::x11_utils::parse_list_with_order::<EncodingInfo>(remaining, num_encodings.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryEncodingsReply { response_type, sequence, length, info };
         Ok((result, remaining))
     }
@@ -1613,9 +1628,68 @@ impl TryFrom<&[u8]> for QueryEncodingsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryEncodingsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_encodings = u16::try_from(self.info.len()).expect("`info` has too many elements");
+        num_encodings.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.info.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the GrabPort request
 pub const GRAB_PORT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GrabPortRequest {
+    pub port: Port,
+    pub time: xproto::Timestamp,
+}
+impl GrabPortRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GRAB_PORT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GrabPortRequest {
+            port,
+            time,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+}
+
 pub fn grab_port<Conn, A>(conn: &Conn, port: Port, time: A) -> Result<Cookie<'_, Conn, GrabPortReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1624,9 +1698,10 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let time_bytes = time.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let time_bytes = time.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GRAB_PORT_REQUEST,
@@ -1644,9 +1719,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GrabPortRequest {
+    type Reply = GrabPortReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GrabPortRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GrabPortReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        grab_port(conn, self.port, self.time)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GrabPortReply {
@@ -1656,12 +1743,12 @@ pub struct GrabPortReply {
     pub length: u32,
 }
 impl TryParse for GrabPortReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (result, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let result = result.try_into()?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (result, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = GrabPortStatus::from(result);
         let result = GrabPortReply { response_type, result, sequence, length };
         Ok((result, remaining))
     }
@@ -1672,9 +1759,64 @@ impl TryFrom<&[u8]> for GrabPortReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GrabPortReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        u8::from(self.result).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the UngrabPort request
 pub const UNGRAB_PORT_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UngrabPortRequest {
+    pub port: Port,
+    pub time: xproto::Timestamp,
+}
+impl UngrabPortRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNGRAB_PORT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(UngrabPortRequest {
+            port,
+            time,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `time` field of this structure.
+    pub fn time<I>(mut self, value: I) -> Self where I: Into<xproto::Timestamp> {
+        self.time = value.into();
+        self
+    }
+}
+
 pub fn ungrab_port<Conn, A>(conn: &Conn, port: Port, time: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1683,9 +1825,10 @@ where
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
     let time: xproto::Timestamp = time.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let time_bytes = time.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let time_bytes = time.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         UNGRAB_PORT_REQUEST,
@@ -1703,30 +1846,150 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for UngrabPortRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for UngrabPortRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        ungrab_port(conn, self.port, self.time)
+    }
+}
 
 /// Opcode for the PutVideo request
 pub const PUT_VIDEO_REQUEST: u8 = 5;
-pub fn put_video<Conn>(conn: &Conn, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PutVideoRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+impl PutVideoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PUT_VIDEO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (vid_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PutVideoRequest {
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `gc` field of this structure.
+    pub fn gc<I>(mut self, value: I) -> Self where I: Into<xproto::Gcontext> {
+        self.gc = value.into();
+        self
+    }
+    /// Set the `vid_x` field of this structure.
+    pub fn vid_x(mut self, value: i16) -> Self {
+        self.vid_x = value;
+        self
+    }
+    /// Set the `vid_y` field of this structure.
+    pub fn vid_y(mut self, value: i16) -> Self {
+        self.vid_y = value;
+        self
+    }
+    /// Set the `vid_w` field of this structure.
+    pub fn vid_w(mut self, value: u16) -> Self {
+        self.vid_w = value;
+        self
+    }
+    /// Set the `vid_h` field of this structure.
+    pub fn vid_h(mut self, value: u16) -> Self {
+        self.vid_h = value;
+        self
+    }
+    /// Set the `drw_x` field of this structure.
+    pub fn drw_x(mut self, value: i16) -> Self {
+        self.drw_x = value;
+        self
+    }
+    /// Set the `drw_y` field of this structure.
+    pub fn drw_y(mut self, value: i16) -> Self {
+        self.drw_y = value;
+        self
+    }
+    /// Set the `drw_w` field of this structure.
+    pub fn drw_w(mut self, value: u16) -> Self {
+        self.drw_w = value;
+        self
+    }
+    /// Set the `drw_h` field of this structure.
+    pub fn drw_h(mut self, value: u16) -> Self {
+        self.drw_h = value;
+        self
+    }
+}
+
+pub fn put_video<Conn, A>(conn: &Conn, port: Port, drawable: A, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let drawable_bytes = drawable.serialize();
-    let gc_bytes = gc.serialize();
-    let vid_x_bytes = vid_x.serialize();
-    let vid_y_bytes = vid_y.serialize();
-    let vid_w_bytes = vid_w.serialize();
-    let vid_h_bytes = vid_h.serialize();
-    let drw_x_bytes = drw_x.serialize();
-    let drw_y_bytes = drw_y.serialize();
-    let drw_w_bytes = drw_w.serialize();
-    let drw_h_bytes = drw_h.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let gc_bytes = gc.serialize_with_order(order);
+    let vid_x_bytes = vid_x.serialize_with_order(order);
+    let vid_y_bytes = vid_y.serialize_with_order(order);
+    let vid_w_bytes = vid_w.serialize_with_order(order);
+    let vid_h_bytes = vid_h.serialize_with_order(order);
+    let drw_x_bytes = drw_x.serialize_with_order(order);
+    let drw_y_bytes = drw_y.serialize_with_order(order);
+    let drw_w_bytes = drw_w.serialize_with_order(order);
+    let drw_h_bytes = drw_h.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PUT_VIDEO_REQUEST,
@@ -1764,30 +2027,150 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PutVideoRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PutVideoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        put_video(conn, self.port, self.drawable, self.gc, self.vid_x, self.vid_y, self.vid_w, self.vid_h, self.drw_x, self.drw_y, self.drw_w, self.drw_h)
+    }
+}
 
 /// Opcode for the PutStill request
 pub const PUT_STILL_REQUEST: u8 = 6;
-pub fn put_still<Conn>(conn: &Conn, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PutStillRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+impl PutStillRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PUT_STILL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (vid_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(PutStillRequest {
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `gc` field of this structure.
+    pub fn gc<I>(mut self, value: I) -> Self where I: Into<xproto::Gcontext> {
+        self.gc = value.into();
+        self
+    }
+    /// Set the `vid_x` field of this structure.
+    pub fn vid_x(mut self, value: i16) -> Self {
+        self.vid_x = value;
+        self
+    }
+    /// Set the `vid_y` field of this structure.
+    pub fn vid_y(mut self, value: i16) -> Self {
+        self.vid_y = value;
+        self
+    }
+    /// Set the `vid_w` field of this structure.
+    pub fn vid_w(mut self, value: u16) -> Self {
+        self.vid_w = value;
+        self
+    }
+    /// Set the `vid_h` field of this structure.
+    pub fn vid_h(mut self, value: u16) -> Self {
+        self.vid_h = value;
+        self
+    }
+    /// Set the `drw_x` field of this structure.
+    pub fn drw_x(mut self, value: i16) -> Self {
+        self.drw_x = value;
+        self
+    }
+    /// Set the `drw_y` field of this structure.
+    pub fn drw_y(mut self, value: i16) -> Self {
+        self.drw_y = value;
+        self
+    }
+    /// Set the `drw_w` field of this structure.
+    pub fn drw_w(mut self, value: u16) -> Self {
+        self.drw_w = value;
+        self
+    }
+    /// Set the `drw_h` field of this structure.
+    pub fn drw_h(mut self, value: u16) -> Self {
+        self.drw_h = value;
+        self
+    }
+}
+
+pub fn put_still<Conn, A>(conn: &Conn, port: Port, drawable: A, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let drawable_bytes = drawable.serialize();
-    let gc_bytes = gc.serialize();
-    let vid_x_bytes = vid_x.serialize();
-    let vid_y_bytes = vid_y.serialize();
-    let vid_w_bytes = vid_w.serialize();
-    let vid_h_bytes = vid_h.serialize();
-    let drw_x_bytes = drw_x.serialize();
-    let drw_y_bytes = drw_y.serialize();
-    let drw_w_bytes = drw_w.serialize();
-    let drw_h_bytes = drw_h.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let gc_bytes = gc.serialize_with_order(order);
+    let vid_x_bytes = vid_x.serialize_with_order(order);
+    let vid_y_bytes = vid_y.serialize_with_order(order);
+    let vid_w_bytes = vid_w.serialize_with_order(order);
+    let vid_h_bytes = vid_h.serialize_with_order(order);
+    let drw_x_bytes = drw_x.serialize_with_order(order);
+    let drw_y_bytes = drw_y.serialize_with_order(order);
+    let drw_w_bytes = drw_w.serialize_with_order(order);
+    let drw_h_bytes = drw_h.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PUT_STILL_REQUEST,
@@ -1825,30 +2208,150 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for PutStillRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PutStillRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        put_still(conn, self.port, self.drawable, self.gc, self.vid_x, self.vid_y, self.vid_w, self.vid_h, self.drw_x, self.drw_y, self.drw_w, self.drw_h)
+    }
+}
 
 /// Opcode for the GetVideo request
 pub const GET_VIDEO_REQUEST: u8 = 7;
-pub fn get_video<Conn>(conn: &Conn, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetVideoRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+impl GetVideoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_VIDEO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (vid_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetVideoRequest {
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `gc` field of this structure.
+    pub fn gc<I>(mut self, value: I) -> Self where I: Into<xproto::Gcontext> {
+        self.gc = value.into();
+        self
+    }
+    /// Set the `vid_x` field of this structure.
+    pub fn vid_x(mut self, value: i16) -> Self {
+        self.vid_x = value;
+        self
+    }
+    /// Set the `vid_y` field of this structure.
+    pub fn vid_y(mut self, value: i16) -> Self {
+        self.vid_y = value;
+        self
+    }
+    /// Set the `vid_w` field of this structure.
+    pub fn vid_w(mut self, value: u16) -> Self {
+        self.vid_w = value;
+        self
+    }
+    /// Set the `vid_h` field of this structure.
+    pub fn vid_h(mut self, value: u16) -> Self {
+        self.vid_h = value;
+        self
+    }
+    /// Set the `drw_x` field of this structure.
+    pub fn drw_x(mut self, value: i16) -> Self {
+        self.drw_x = value;
+        self
+    }
+    /// Set the `drw_y` field of this structure.
+    pub fn drw_y(mut self, value: i16) -> Self {
+        self.drw_y = value;
+        self
+    }
+    /// Set the `drw_w` field of this structure.
+    pub fn drw_w(mut self, value: u16) -> Self {
+        self.drw_w = value;
+        self
+    }
+    /// Set the `drw_h` field of this structure.
+    pub fn drw_h(mut self, value: u16) -> Self {
+        self.drw_h = value;
+        self
+    }
+}
+
+pub fn get_video<Conn, A>(conn: &Conn, port: Port, drawable: A, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let drawable_bytes = drawable.serialize();
-    let gc_bytes = gc.serialize();
-    let vid_x_bytes = vid_x.serialize();
-    let vid_y_bytes = vid_y.serialize();
-    let vid_w_bytes = vid_w.serialize();
-    let vid_h_bytes = vid_h.serialize();
-    let drw_x_bytes = drw_x.serialize();
-    let drw_y_bytes = drw_y.serialize();
-    let drw_w_bytes = drw_w.serialize();
-    let drw_h_bytes = drw_h.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let gc_bytes = gc.serialize_with_order(order);
+    let vid_x_bytes = vid_x.serialize_with_order(order);
+    let vid_y_bytes = vid_y.serialize_with_order(order);
+    let vid_w_bytes = vid_w.serialize_with_order(order);
+    let vid_h_bytes = vid_h.serialize_with_order(order);
+    let drw_x_bytes = drw_x.serialize_with_order(order);
+    let drw_y_bytes = drw_y.serialize_with_order(order);
+    let drw_w_bytes = drw_w.serialize_with_order(order);
+    let drw_h_bytes = drw_h.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_VIDEO_REQUEST,
@@ -1886,30 +2389,150 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetVideoRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetVideoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_video(conn, self.port, self.drawable, self.gc, self.vid_x, self.vid_y, self.vid_w, self.vid_h, self.drw_x, self.drw_y, self.drw_w, self.drw_h)
+    }
+}
 
 /// Opcode for the GetStill request
 pub const GET_STILL_REQUEST: u8 = 8;
-pub fn get_still<Conn>(conn: &Conn, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetStillRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+impl GetStillRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_STILL_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (vid_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetStillRequest {
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `gc` field of this structure.
+    pub fn gc<I>(mut self, value: I) -> Self where I: Into<xproto::Gcontext> {
+        self.gc = value.into();
+        self
+    }
+    /// Set the `vid_x` field of this structure.
+    pub fn vid_x(mut self, value: i16) -> Self {
+        self.vid_x = value;
+        self
+    }
+    /// Set the `vid_y` field of this structure.
+    pub fn vid_y(mut self, value: i16) -> Self {
+        self.vid_y = value;
+        self
+    }
+    /// Set the `vid_w` field of this structure.
+    pub fn vid_w(mut self, value: u16) -> Self {
+        self.vid_w = value;
+        self
+    }
+    /// Set the `vid_h` field of this structure.
+    pub fn vid_h(mut self, value: u16) -> Self {
+        self.vid_h = value;
+        self
+    }
+    /// Set the `drw_x` field of this structure.
+    pub fn drw_x(mut self, value: i16) -> Self {
+        self.drw_x = value;
+        self
+    }
+    /// Set the `drw_y` field of this structure.
+    pub fn drw_y(mut self, value: i16) -> Self {
+        self.drw_y = value;
+        self
+    }
+    /// Set the `drw_w` field of this structure.
+    pub fn drw_w(mut self, value: u16) -> Self {
+        self.drw_w = value;
+        self
+    }
+    /// Set the `drw_h` field of this structure.
+    pub fn drw_h(mut self, value: u16) -> Self {
+        self.drw_h = value;
+        self
+    }
+}
+
+pub fn get_still<Conn, A>(conn: &Conn, port: Port, drawable: A, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let drawable_bytes = drawable.serialize();
-    let gc_bytes = gc.serialize();
-    let vid_x_bytes = vid_x.serialize();
-    let vid_y_bytes = vid_y.serialize();
-    let vid_w_bytes = vid_w.serialize();
-    let vid_h_bytes = vid_h.serialize();
-    let drw_x_bytes = drw_x.serialize();
-    let drw_y_bytes = drw_y.serialize();
-    let drw_w_bytes = drw_w.serialize();
-    let drw_h_bytes = drw_h.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let gc_bytes = gc.serialize_with_order(order);
+    let vid_x_bytes = vid_x.serialize_with_order(order);
+    let vid_y_bytes = vid_y.serialize_with_order(order);
+    let vid_w_bytes = vid_w.serialize_with_order(order);
+    let vid_h_bytes = vid_h.serialize_with_order(order);
+    let drw_x_bytes = drw_x.serialize_with_order(order);
+    let drw_y_bytes = drw_y.serialize_with_order(order);
+    let drw_w_bytes = drw_w.serialize_with_order(order);
+    let drw_h_bytes = drw_h.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_STILL_REQUEST,
@@ -1947,21 +2570,69 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetStillRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetStillRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_still(conn, self.port, self.drawable, self.gc, self.vid_x, self.vid_y, self.vid_w, self.vid_h, self.drw_x, self.drw_y, self.drw_w, self.drw_h)
+    }
+}
 
 /// Opcode for the StopVideo request
 pub const STOP_VIDEO_REQUEST: u8 = 9;
-pub fn stop_video<Conn>(conn: &Conn, port: Port, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StopVideoRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+}
+impl StopVideoRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != STOP_VIDEO_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(StopVideoRequest {
+            port,
+            drawable,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+}
+
+pub fn stop_video<Conn, A>(conn: &Conn, port: Port, drawable: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let drawable_bytes = drawable.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         STOP_VIDEO_REQUEST,
@@ -1979,21 +2650,70 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for StopVideoRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for StopVideoRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        stop_video(conn, self.port, self.drawable)
+    }
+}
 
 /// Opcode for the SelectVideoNotify request
 pub const SELECT_VIDEO_NOTIFY_REQUEST: u8 = 10;
-pub fn select_video_notify<Conn>(conn: &Conn, drawable: xproto::Drawable, onoff: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectVideoNotifyRequest {
+    pub drawable: xproto::Drawable,
+    pub onoff: bool,
+}
+impl SelectVideoNotifyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_VIDEO_NOTIFY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (onoff, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SelectVideoNotifyRequest {
+            drawable,
+            onoff,
+        })
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `onoff` field of this structure.
+    pub fn onoff(mut self, value: bool) -> Self {
+        self.onoff = value;
+        self
+    }
+}
+
+pub fn select_video_notify<Conn, A>(conn: &Conn, drawable: A, onoff: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let drawable_bytes = drawable.serialize();
-    let onoff_bytes = onoff.serialize();
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let onoff_bytes = onoff.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_VIDEO_NOTIFY_REQUEST,
@@ -2011,21 +2731,68 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectVideoNotifyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectVideoNotifyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_video_notify(conn, self.drawable, self.onoff)
+    }
+}
 
 /// Opcode for the SelectPortNotify request
 pub const SELECT_PORT_NOTIFY_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectPortNotifyRequest {
+    pub port: Port,
+    pub onoff: bool,
+}
+impl SelectPortNotifyRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_PORT_NOTIFY_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (onoff, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SelectPortNotifyRequest {
+            port,
+            onoff,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `onoff` field of this structure.
+    pub fn onoff(mut self, value: bool) -> Self {
+        self.onoff = value;
+        self
+    }
+}
+
 pub fn select_port_notify<Conn>(conn: &Conn, port: Port, onoff: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let onoff_bytes = onoff.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let onoff_bytes = onoff.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SELECT_PORT_NOTIFY_REQUEST,
@@ -2043,25 +2810,104 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SelectPortNotifyRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SelectPortNotifyRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        select_port_notify(conn, self.port, self.onoff)
+    }
+}
 
 /// Opcode for the QueryBestSize request
 pub const QUERY_BEST_SIZE_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryBestSizeRequest {
+    pub port: Port,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+    pub motion: bool,
+}
+impl QueryBestSizeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_BEST_SIZE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (motion, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryBestSizeRequest {
+            port,
+            vid_w,
+            vid_h,
+            drw_w,
+            drw_h,
+            motion,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `vid_w` field of this structure.
+    pub fn vid_w(mut self, value: u16) -> Self {
+        self.vid_w = value;
+        self
+    }
+    /// Set the `vid_h` field of this structure.
+    pub fn vid_h(mut self, value: u16) -> Self {
+        self.vid_h = value;
+        self
+    }
+    /// Set the `drw_w` field of this structure.
+    pub fn drw_w(mut self, value: u16) -> Self {
+        self.drw_w = value;
+        self
+    }
+    /// Set the `drw_h` field of this structure.
+    pub fn drw_h(mut self, value: u16) -> Self {
+        self.drw_h = value;
+        self
+    }
+    /// Set the `motion` field of this structure.
+    pub fn motion(mut self, value: bool) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
 pub fn query_best_size<Conn>(conn: &Conn, port: Port, vid_w: u16, vid_h: u16, drw_w: u16, drw_h: u16, motion: bool) -> Result<Cookie<'_, Conn, QueryBestSizeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let vid_w_bytes = vid_w.serialize();
-    let vid_h_bytes = vid_h.serialize();
-    let drw_w_bytes = drw_w.serialize();
-    let drw_h_bytes = drw_h.serialize();
-    let motion_bytes = motion.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let vid_w_bytes = vid_w.serialize_with_order(order);
+    let vid_h_bytes = vid_h.serialize_with_order(order);
+    let drw_w_bytes = drw_w.serialize_with_order(order);
+    let drw_h_bytes = drw_h.serialize_with_order(order);
+    let motion_bytes = motion.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_BEST_SIZE_REQUEST,
@@ -2087,9 +2933,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryBestSizeRequest {
+    type Reply = QueryBestSizeReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryBestSizeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryBestSizeReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_best_size(conn, self.port, self.vid_w, self.vid_h, self.drw_w, self.drw_h, self.motion)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryBestSizeReply {
@@ -2100,13 +2958,13 @@ pub struct QueryBestSizeReply {
     pub actual_height: u16,
 }
 impl TryParse for QueryBestSizeReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (actual_width, remaining) = u16::try_parse(remaining)?;
-        let (actual_height, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (actual_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (actual_height, remaining) = u16::try_parse_with_order(remaining, order)?;
         let result = QueryBestSizeReply { response_type, sequence, length, actual_width, actual_height };
         Ok((result, remaining))
     }
@@ -2117,19 +2975,85 @@ impl TryFrom<&[u8]> for QueryBestSizeReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryBestSizeReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.actual_width.serialize_into_with_order(bytes, order);
+        self.actual_height.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the SetPortAttribute request
 pub const SET_PORT_ATTRIBUTE_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SetPortAttributeRequest {
+    pub port: Port,
+    pub attribute: xproto::Atom,
+    pub value: i32,
+}
+impl SetPortAttributeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PORT_ATTRIBUTE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (attribute, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(SetPortAttributeRequest {
+            port,
+            attribute,
+            value,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `attribute` field of this structure.
+    pub fn attribute<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.attribute = value.into();
+        self
+    }
+    /// Set the `value` field of this structure.
+    pub fn value(mut self, value: i32) -> Self {
+        self.value = value;
+        self
+    }
+}
+
 pub fn set_port_attribute<Conn>(conn: &Conn, port: Port, attribute: xproto::Atom, value: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let attribute_bytes = attribute.serialize();
-    let value_bytes = value.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let attribute_bytes = attribute.serialize_with_order(order);
+    let value_bytes = value.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SET_PORT_ATTRIBUTE_REQUEST,
@@ -2151,21 +3075,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for SetPortAttributeRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for SetPortAttributeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        set_port_attribute(conn, self.port, self.attribute, self.value)
+    }
+}
 
 /// Opcode for the GetPortAttribute request
 pub const GET_PORT_ATTRIBUTE_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetPortAttributeRequest {
+    pub port: Port,
+    pub attribute: xproto::Atom,
+}
+impl GetPortAttributeRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PORT_ATTRIBUTE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (attribute, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(GetPortAttributeRequest {
+            port,
+            attribute,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `attribute` field of this structure.
+    pub fn attribute<I>(mut self, value: I) -> Self where I: Into<xproto::Atom> {
+        self.attribute = value.into();
+        self
+    }
+}
+
 pub fn get_port_attribute<Conn>(conn: &Conn, port: Port, attribute: xproto::Atom) -> Result<Cookie<'_, Conn, GetPortAttributeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let attribute_bytes = attribute.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let attribute_bytes = attribute.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         GET_PORT_ATTRIBUTE_REQUEST,
@@ -2183,9 +3153,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for GetPortAttributeRequest {
+    type Reply = GetPortAttributeReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for GetPortAttributeRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, GetPortAttributeReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        get_port_attribute(conn, self.port, self.attribute)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPortAttributeReply {
@@ -2195,12 +3177,12 @@ pub struct GetPortAttributeReply {
     pub value: i32,
 }
 impl TryParse for GetPortAttributeReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (value, remaining) = i32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = i32::try_parse_with_order(remaining, order)?;
         let result = GetPortAttributeReply { response_type, sequence, length, value };
         Ok((result, remaining))
     }
@@ -2211,17 +3193,66 @@ impl TryFrom<&[u8]> for GetPortAttributeReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for GetPortAttributeReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.value.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryPortAttributes request
 pub const QUERY_PORT_ATTRIBUTES_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryPortAttributesRequest {
+    pub port: Port,
+}
+impl QueryPortAttributesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_PORT_ATTRIBUTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryPortAttributesRequest {
+            port,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+}
+
 pub fn query_port_attributes<Conn>(conn: &Conn, port: Port) -> Result<Cookie<'_, Conn, QueryPortAttributesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
+    let port_bytes = port.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_PORT_ATTRIBUTES_REQUEST,
@@ -2235,9 +3266,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryPortAttributesRequest {
+    type Reply = QueryPortAttributesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryPortAttributesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryPortAttributesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_port_attributes(conn, self.port)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryPortAttributesReply {
@@ -2248,15 +3291,15 @@ pub struct QueryPortAttributesReply {
     pub attributes: Vec<AttributeInfo>,
 }
 impl TryParse for QueryPortAttributesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_attributes, remaining) = u32::try_parse(remaining)?;
-        let (text_size, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_attributes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (text_size, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(16..).ok_or(ParseError::ParseError)?;
-        let (attributes, remaining) = crate::x11_utils::parse_list::<AttributeInfo>(remaining, num_attributes.try_into().or(Err(ParseError::ParseError))?)?;
+        let (attributes, remaining) = crate::x11_utils::parse_list_with_order::<AttributeInfo>(remaining, num_attributes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryPortAttributesReply { response_type, sequence, length, text_size, attributes };
         Ok((result, remaining))
     }
@@ -2267,17 +3310,70 @@ impl TryFrom<&[u8]> for QueryPortAttributesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryPortAttributesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_attributes = u32::try_from(self.attributes.len()).expect("`attributes` has too many elements");
+        num_attributes.serialize_into_with_order(bytes, order);
+        self.text_size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.attributes.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ListImageFormats request
 pub const LIST_IMAGE_FORMATS_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListImageFormatsRequest {
+    pub port: Port,
+}
+impl ListImageFormatsRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_IMAGE_FORMATS_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ListImageFormatsRequest {
+            port,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+}
+
 pub fn list_image_formats<Conn>(conn: &Conn, port: Port) -> Result<Cookie<'_, Conn, ListImageFormatsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
+    let port_bytes = port.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         LIST_IMAGE_FORMATS_REQUEST,
@@ -2291,9 +3387,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ListImageFormatsRequest {
+    type Reply = ListImageFormatsReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ListImageFormatsRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ListImageFormatsReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        list_image_formats(conn, self.port)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListImageFormatsReply {
@@ -2303,14 +3411,14 @@ pub struct ListImageFormatsReply {
     pub format: Vec<ImageFormatInfo>,
 }
 impl TryParse for ListImageFormatsReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_formats, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_formats, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (format, remaining) = crate::x11_utils::parse_list::<ImageFormatInfo>(remaining, num_formats.try_into().or(Err(ParseError::ParseError))?)?;
+        let (format, remaining) = crate::x11_utils::parse_list_with_order::<ImageFormatInfo>(remaining, num_formats.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ListImageFormatsReply { response_type, sequence, length, format };
         Ok((result, remaining))
     }
@@ -2321,20 +3429,96 @@ impl TryFrom<&[u8]> for ListImageFormatsReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ListImageFormatsReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_formats = u32::try_from(self.format.len()).expect("`format` has too many elements");
+        num_formats.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.format.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the QueryImageAttributes request
 pub const QUERY_IMAGE_ATTRIBUTES_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryImageAttributesRequest {
+    pub port: Port,
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+}
+impl QueryImageAttributesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_IMAGE_ATTRIBUTES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryImageAttributesRequest {
+            port,
+            id,
+            width,
+            height,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `id` field of this structure.
+    pub fn id(mut self, value: u32) -> Self {
+        self.id = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+}
+
 pub fn query_image_attributes<Conn>(conn: &Conn, port: Port, id: u32, width: u16, height: u16) -> Result<Cookie<'_, Conn, QueryImageAttributesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let id_bytes = id.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let id_bytes = id.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         QUERY_IMAGE_ATTRIBUTES_REQUEST,
@@ -2356,9 +3540,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryImageAttributesRequest {
+    type Reply = QueryImageAttributesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryImageAttributesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryImageAttributesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_image_attributes(conn, self.port, self.id, self.width, self.height)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryImageAttributesReply {
@@ -2372,18 +3568,18 @@ pub struct QueryImageAttributesReply {
     pub offsets: Vec<u32>,
 }
 impl TryParse for QueryImageAttributesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_planes, remaining) = u32::try_parse(remaining)?;
-        let (data_size, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_planes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data_size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (pitches, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ParseError))?)?;
-        let (offsets, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ParseError))?)?;
+        let (pitches, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ParseError))?, order)?;
+        let (offsets, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = QueryImageAttributesReply { response_type, sequence, length, data_size, width, height, pitches, offsets };
         Ok((result, remaining))
     }
@@ -2394,30 +3590,202 @@ impl TryFrom<&[u8]> for QueryImageAttributesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryImageAttributesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_planes = u32::try_from(self.pitches.len()).expect("`pitches` has too many elements");
+        num_planes.serialize_into_with_order(bytes, order);
+        self.data_size.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.pitches.serialize_into_with_order(bytes, order);
+        assert_eq!(self.offsets.len(), usize::try_from(num_planes).unwrap(), "`offsets` has an incorrect length");
+        self.offsets.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the PutImage request
 pub const PUT_IMAGE_REQUEST: u8 = 18;
-pub fn put_image<'c, Conn>(conn: &'c Conn, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, id: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, data: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PutImageRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub id: u32,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub src_w: u16,
+    pub src_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+    pub width: u16,
+    pub height: u16,
+    pub data: Vec<u8>,
+}
+impl PutImageRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PUT_IMAGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (src_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let data = remaining.to_vec();
+        let remaining = &remaining[remaining.len()..];
+        let _ = remaining;
+        let _ = order;
+        Ok(PutImageRequest {
+            port,
+            drawable,
+            gc,
+            id,
+            src_x,
+            src_y,
+            src_w,
+            src_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+            width,
+            height,
+            data,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `gc` field of this structure.
+    pub fn gc<I>(mut self, value: I) -> Self where I: Into<xproto::Gcontext> {
+        self.gc = value.into();
+        self
+    }
+    /// Set the `id` field of this structure.
+    pub fn id(mut self, value: u32) -> Self {
+        self.id = value;
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `src_w` field of this structure.
+    pub fn src_w(mut self, value: u16) -> Self {
+        self.src_w = value;
+        self
+    }
+    /// Set the `src_h` field of this structure.
+    pub fn src_h(mut self, value: u16) -> Self {
+        self.src_h = value;
+        self
+    }
+    /// Set the `drw_x` field of this structure.
+    pub fn drw_x(mut self, value: i16) -> Self {
+        self.drw_x = value;
+        self
+    }
+    /// Set the `drw_y` field of this structure.
+    pub fn drw_y(mut self, value: i16) -> Self {
+        self.drw_y = value;
+        self
+    }
+    /// Set the `drw_w` field of this structure.
+    pub fn drw_w(mut self, value: u16) -> Self {
+        self.drw_w = value;
+        self
+    }
+    /// Set the `drw_h` field of this structure.
+    pub fn drw_h(mut self, value: u16) -> Self {
+        self.drw_h = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `data` field of this structure.
+    pub fn data<I>(mut self, value: I) -> Self where I: Into<Vec<u8>> {
+        self.data = value.into();
+        self
+    }
+}
+
+pub fn put_image<'c, Conn, A>(conn: &'c Conn, port: Port, drawable: A, gc: xproto::Gcontext, id: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, data: &[u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let drawable_bytes = drawable.serialize();
-    let gc_bytes = gc.serialize();
-    let id_bytes = id.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
-    let src_w_bytes = src_w.serialize();
-    let src_h_bytes = src_h.serialize();
-    let drw_x_bytes = drw_x.serialize();
-    let drw_y_bytes = drw_y.serialize();
-    let drw_w_bytes = drw_w.serialize();
-    let drw_h_bytes = drw_h.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let gc_bytes = gc.serialize_with_order(order);
+    let id_bytes = id.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
+    let src_w_bytes = src_w.serialize_with_order(order);
+    let src_h_bytes = src_h.serialize_with_order(order);
+    let drw_x_bytes = drw_x.serialize_with_order(order);
+    let drw_y_bytes = drw_y.serialize_with_order(order);
+    let drw_w_bytes = drw_w.serialize_with_order(order);
+    let drw_h_bytes = drw_h.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         PUT_IMAGE_REQUEST,
@@ -2466,36 +3834,205 @@ where
     let length_so_far = length_so_far + padding0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0), IoSlice::new(data), IoSlice::new(&padding0)], vec![])?)
 }
+impl crate::x11_utils::Request for PutImageRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for PutImageRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        put_image(conn, self.port, self.drawable, self.gc, self.id, self.src_x, self.src_y, self.src_w, self.src_h, self.drw_x, self.drw_y, self.drw_w, self.drw_h, self.width, self.height, &self.data)
+    }
+}
 
 /// Opcode for the ShmPutImage request
 pub const SHM_PUT_IMAGE_REQUEST: u8 = 19;
-pub fn shm_put_image<Conn>(conn: &Conn, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, shmseg: shm::Seg, id: u32, offset: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, send_event: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ShmPutImageRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub shmseg: shm::Seg,
+    pub id: u32,
+    pub offset: u32,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub src_w: u16,
+    pub src_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+    pub width: u16,
+    pub height: u16,
+    pub send_event: u8,
+}
+impl ShmPutImageRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SHM_PUT_IMAGE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (shmseg, remaining) = shm::Seg::try_parse_with_order(remaining, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (src_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (send_event, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::ParseError)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ShmPutImageRequest {
+            port,
+            drawable,
+            gc,
+            shmseg,
+            id,
+            offset,
+            src_x,
+            src_y,
+            src_w,
+            src_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+            width,
+            height,
+            send_event,
+        })
+    }
+    /// Set the `port` field of this structure.
+    pub fn port<I>(mut self, value: I) -> Self where I: Into<Port> {
+        self.port = value.into();
+        self
+    }
+    /// Set the `drawable` field of this structure.
+    pub fn drawable<I>(mut self, value: I) -> Self where I: Into<xproto::Drawable> {
+        self.drawable = value.into();
+        self
+    }
+    /// Set the `gc` field of this structure.
+    pub fn gc<I>(mut self, value: I) -> Self where I: Into<xproto::Gcontext> {
+        self.gc = value.into();
+        self
+    }
+    /// Set the `shmseg` field of this structure.
+    pub fn shmseg<I>(mut self, value: I) -> Self where I: Into<shm::Seg> {
+        self.shmseg = value.into();
+        self
+    }
+    /// Set the `id` field of this structure.
+    pub fn id(mut self, value: u32) -> Self {
+        self.id = value;
+        self
+    }
+    /// Set the `offset` field of this structure.
+    pub fn offset(mut self, value: u32) -> Self {
+        self.offset = value;
+        self
+    }
+    /// Set the `src_x` field of this structure.
+    pub fn src_x(mut self, value: i16) -> Self {
+        self.src_x = value;
+        self
+    }
+    /// Set the `src_y` field of this structure.
+    pub fn src_y(mut self, value: i16) -> Self {
+        self.src_y = value;
+        self
+    }
+    /// Set the `src_w` field of this structure.
+    pub fn src_w(mut self, value: u16) -> Self {
+        self.src_w = value;
+        self
+    }
+    /// Set the `src_h` field of this structure.
+    pub fn src_h(mut self, value: u16) -> Self {
+        self.src_h = value;
+        self
+    }
+    /// Set the `drw_x` field of this structure.
+    pub fn drw_x(mut self, value: i16) -> Self {
+        self.drw_x = value;
+        self
+    }
+    /// Set the `drw_y` field of this structure.
+    pub fn drw_y(mut self, value: i16) -> Self {
+        self.drw_y = value;
+        self
+    }
+    /// Set the `drw_w` field of this structure.
+    pub fn drw_w(mut self, value: u16) -> Self {
+        self.drw_w = value;
+        self
+    }
+    /// Set the `drw_h` field of this structure.
+    pub fn drw_h(mut self, value: u16) -> Self {
+        self.drw_h = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `send_event` field of this structure.
+    pub fn send_event(mut self, value: u8) -> Self {
+        self.send_event = value;
+        self
+    }
+}
+
+pub fn shm_put_image<Conn, A>(conn: &Conn, port: Port, drawable: A, gc: xproto::Gcontext, shmseg: shm::Seg, id: u32, offset: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, send_event: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
+    A: Into<xproto::Drawable>,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let drawable: xproto::Drawable = drawable.into();
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_bytes = port.serialize();
-    let drawable_bytes = drawable.serialize();
-    let gc_bytes = gc.serialize();
-    let shmseg_bytes = shmseg.serialize();
-    let id_bytes = id.serialize();
-    let offset_bytes = offset.serialize();
-    let src_x_bytes = src_x.serialize();
-    let src_y_bytes = src_y.serialize();
-    let src_w_bytes = src_w.serialize();
-    let src_h_bytes = src_h.serialize();
-    let drw_x_bytes = drw_x.serialize();
-    let drw_y_bytes = drw_y.serialize();
-    let drw_w_bytes = drw_w.serialize();
-    let drw_h_bytes = drw_h.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let send_event_bytes = send_event.serialize();
+    let port_bytes = port.serialize_with_order(order);
+    let drawable_bytes = drawable.serialize_with_order(order);
+    let gc_bytes = gc.serialize_with_order(order);
+    let shmseg_bytes = shmseg.serialize_with_order(order);
+    let id_bytes = id.serialize_with_order(order);
+    let offset_bytes = offset.serialize_with_order(order);
+    let src_x_bytes = src_x.serialize_with_order(order);
+    let src_y_bytes = src_y.serialize_with_order(order);
+    let src_w_bytes = src_w.serialize_with_order(order);
+    let src_h_bytes = src_h.serialize_with_order(order);
+    let drw_x_bytes = drw_x.serialize_with_order(order);
+    let drw_y_bytes = drw_y.serialize_with_order(order);
+    let drw_w_bytes = drw_w.serialize_with_order(order);
+    let drw_h_bytes = drw_h.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let send_event_bytes = send_event.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         SHM_PUT_IMAGE_REQUEST,
@@ -2553,9 +4090,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ShmPutImageRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ShmPutImageRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        shm_put_image(conn, self.port, self.drawable, self.gc, self.shmseg, self.id, self.offset, self.src_x, self.src_y, self.src_w, self.src_h, self.drw_x, self.drw_y, self.drw_w, self.drw_h, self.width, self.height, self.send_event)
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
@@ -2583,27 +4132,39 @@ pub trait ConnectionExt: RequestConnection {
     {
         ungrab_port(self, port, time)
     }
-    fn xv_put_video(&self, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xv_put_video<A>(&self, port: Port, drawable: A, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         put_video(self, port, drawable, gc, vid_x, vid_y, vid_w, vid_h, drw_x, drw_y, drw_w, drw_h)
     }
-    fn xv_put_still(&self, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xv_put_still<A>(&self, port: Port, drawable: A, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         put_still(self, port, drawable, gc, vid_x, vid_y, vid_w, vid_h, drw_x, drw_y, drw_w, drw_h)
     }
-    fn xv_get_video(&self, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xv_get_video<A>(&self, port: Port, drawable: A, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         get_video(self, port, drawable, gc, vid_x, vid_y, vid_w, vid_h, drw_x, drw_y, drw_w, drw_h)
     }
-    fn xv_get_still(&self, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xv_get_still<A>(&self, port: Port, drawable: A, gc: xproto::Gcontext, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         get_still(self, port, drawable, gc, vid_x, vid_y, vid_w, vid_h, drw_x, drw_y, drw_w, drw_h)
     }
-    fn xv_stop_video(&self, port: Port, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xv_stop_video<A>(&self, port: Port, drawable: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         stop_video(self, port, drawable)
     }
-    fn xv_select_video_notify(&self, drawable: xproto::Drawable, onoff: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xv_select_video_notify<A>(&self, drawable: A, onoff: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         select_video_notify(self, drawable, onoff)
     }
@@ -2635,11 +4196,15 @@ pub trait ConnectionExt: RequestConnection {
     {
         query_image_attributes(self, port, id, width, height)
     }
-    fn xv_put_image<'c>(&'c self, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, id: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, data: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    fn xv_put_image<'c, A>(&'c self, port: Port, drawable: A, gc: xproto::Gcontext, id: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, data: &[u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         put_image(self, port, drawable, gc, id, src_x, src_y, src_w, src_h, drw_x, drw_y, drw_w, drw_h, width, height, data)
     }
-    fn xv_shm_put_image(&self, port: Port, drawable: xproto::Drawable, gc: xproto::Gcontext, shmseg: shm::Seg, id: u32, offset: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, send_event: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    fn xv_shm_put_image<A>(&self, port: Port, drawable: A, gc: xproto::Gcontext, shmseg: shm::Seg, id: u32, offset: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, send_event: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    where
+        A: Into<xproto::Drawable>,
     {
         shm_put_image(self, port, drawable, gc, shmseg, id, offset, src_x, src_y, src_w, src_h, drw_x, drw_y, drw_w, drw_h, width, height, send_event)
     }
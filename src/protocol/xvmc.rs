@@ -13,7 +13,7 @@ use std::io::IoSlice;
 #[allow(unused_imports)]
 use crate::utils::RawFdContainer;
 #[allow(unused_imports)]
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, RequestHeader, Serialize, TryParse, TryParseFd};
 use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
@@ -31,11 +31,101 @@ pub const X11_EXTENSION_NAME: &str = "XVideo-MotionCompensation";
 /// send the maximum version of the extension that you need.
 pub const X11_XML_VERSION: (u32, u32) = (1, 1);
 
-pub type Context = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Context(u32);
+impl Context {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Context(0);
+}
+impl From<u32> for Context {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Context> for u32 {
+    fn from(value: Context) -> Self {
+        value.0
+    }
+}
+impl TryParse for Context {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Context {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Surface = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Surface(u32);
+impl Surface {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Surface(0);
+}
+impl From<u32> for Surface {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Surface> for u32 {
+    fn from(value: Surface) -> Self {
+        value.0
+    }
+}
+impl TryParse for Surface {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Surface {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
-pub type Subpicture = u32;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Subpicture(u32);
+impl Subpicture {
+    /// The XID with value zero, which usually means "no resource".
+    pub const NONE: Self = Subpicture(0);
+}
+impl From<u32> for Subpicture {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl From<Subpicture> for u32 {
+    fn from(value: Subpicture) -> Self {
+        value.0
+    }
+}
+impl TryParse for Subpicture {
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (value, remaining) = u32::try_parse_with_order(value, order)?;
+        Ok((Self(value), remaining))
+    }
+}
+impl Serialize for Subpicture {
+    type Bytes = [u8; 4];
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order);
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SurfaceInfo {
@@ -50,16 +140,16 @@ pub struct SurfaceInfo {
     pub flags: u32,
 }
 impl TryParse for SurfaceInfo {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = Surface::try_parse(remaining)?;
-        let (chroma_format, remaining) = u16::try_parse(remaining)?;
-        let (pad0, remaining) = u16::try_parse(remaining)?;
-        let (max_width, remaining) = u16::try_parse(remaining)?;
-        let (max_height, remaining) = u16::try_parse(remaining)?;
-        let (subpicture_max_width, remaining) = u16::try_parse(remaining)?;
-        let (subpicture_max_height, remaining) = u16::try_parse(remaining)?;
-        let (mc_type, remaining) = u32::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (id, remaining) = Surface::try_parse_with_order(remaining, order)?;
+        let (chroma_format, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pad0, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (max_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (max_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (subpicture_max_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (subpicture_max_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mc_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = SurfaceInfo { id, chroma_format, pad0, max_width, max_height, subpicture_max_width, subpicture_max_height, mc_type, flags };
         Ok((result, remaining))
     }
@@ -72,16 +162,16 @@ impl TryFrom<&[u8]> for SurfaceInfo {
 }
 impl Serialize for SurfaceInfo {
     type Bytes = [u8; 24];
-    fn serialize(&self) -> [u8; 24] {
-        let id_bytes = self.id.serialize();
-        let chroma_format_bytes = self.chroma_format.serialize();
-        let pad0_bytes = self.pad0.serialize();
-        let max_width_bytes = self.max_width.serialize();
-        let max_height_bytes = self.max_height.serialize();
-        let subpicture_max_width_bytes = self.subpicture_max_width.serialize();
-        let subpicture_max_height_bytes = self.subpicture_max_height.serialize();
-        let mc_type_bytes = self.mc_type.serialize();
-        let flags_bytes = self.flags.serialize();
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 24] {
+        let id_bytes = self.id.serialize_with_order(order);
+        let chroma_format_bytes = self.chroma_format.serialize_with_order(order);
+        let pad0_bytes = self.pad0.serialize_with_order(order);
+        let max_width_bytes = self.max_width.serialize_with_order(order);
+        let max_height_bytes = self.max_height.serialize_with_order(order);
+        let subpicture_max_width_bytes = self.subpicture_max_width.serialize_with_order(order);
+        let subpicture_max_height_bytes = self.subpicture_max_height.serialize_with_order(order);
+        let mc_type_bytes = self.mc_type.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
         [
             id_bytes[0],
             id_bytes[1],
@@ -109,28 +199,46 @@ impl Serialize for SurfaceInfo {
             flags_bytes[3],
         ]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         bytes.reserve(24);
-        self.id.serialize_into(bytes);
-        self.chroma_format.serialize_into(bytes);
-        self.pad0.serialize_into(bytes);
-        self.max_width.serialize_into(bytes);
-        self.max_height.serialize_into(bytes);
-        self.subpicture_max_width.serialize_into(bytes);
-        self.subpicture_max_height.serialize_into(bytes);
-        self.mc_type.serialize_into(bytes);
-        self.flags.serialize_into(bytes);
+        self.id.serialize_into_with_order(bytes, order);
+        self.chroma_format.serialize_into_with_order(bytes, order);
+        self.pad0.serialize_into_with_order(bytes, order);
+        self.max_width.serialize_into_with_order(bytes, order);
+        self.max_height.serialize_into_with_order(bytes, order);
+        self.subpicture_max_width.serialize_into_with_order(bytes, order);
+        self.subpicture_max_height.serialize_into_with_order(bytes, order);
+        self.mc_type.serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
     }
 }
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryVersionRequest {
+}
+impl QueryVersionRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let _ = remaining;
+        let _ = order;
+        Ok(QueryVersionRequest {
+        })
+    }
+}
+
 pub fn query_version<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
     let mut request0 = [
         extension_information.major_opcode,
@@ -141,9 +249,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for QueryVersionRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, QueryVersionReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        query_version(conn)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
@@ -154,13 +274,13 @@ pub struct QueryVersionReply {
     pub minor: u32,
 }
 impl TryParse for QueryVersionReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major, remaining) = u32::try_parse(remaining)?;
-        let (minor, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor, remaining) = u32::try_parse_with_order(remaining, order)?;
         let result = QueryVersionReply { response_type, sequence, length, major, minor };
         Ok((result, remaining))
     }
@@ -171,17 +291,67 @@ impl TryFrom<&[u8]> for QueryVersionReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major.serialize_into_with_order(bytes, order);
+        self.minor.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the ListSurfaceTypes request
 pub const LIST_SURFACE_TYPES_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListSurfaceTypesRequest {
+    pub port_id: xv::Port,
+}
+impl ListSurfaceTypesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_SURFACE_TYPES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port_id, remaining) = xv::Port::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ListSurfaceTypesRequest {
+            port_id,
+        })
+    }
+    /// Set the `port_id` field of this structure.
+    pub fn port_id<I>(mut self, value: I) -> Self where I: Into<xv::Port> {
+        self.port_id = value.into();
+        self
+    }
+}
+
 pub fn list_surface_types<Conn>(conn: &Conn, port_id: xv::Port) -> Result<Cookie<'_, Conn, ListSurfaceTypesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_id_bytes = port_id.serialize();
+    let port_id_bytes = port_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         LIST_SURFACE_TYPES_REQUEST,
@@ -195,9 +365,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ListSurfaceTypesRequest {
+    type Reply = ListSurfaceTypesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ListSurfaceTypesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ListSurfaceTypesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        list_surface_types(conn, self.port_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListSurfaceTypesReply {
@@ -207,14 +389,14 @@ pub struct ListSurfaceTypesReply {
     pub surfaces: Vec<SurfaceInfo>,
 }
 impl TryParse for ListSurfaceTypesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (surfaces, remaining) = crate::x11_utils::parse_list::<SurfaceInfo>(remaining, num.try_into().or(Err(ParseError::ParseError))?)?;
+        let (surfaces, remaining) = crate::x11_utils::parse_list_with_order::<SurfaceInfo>(remaining, num.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ListSurfaceTypesReply { response_type, sequence, length, surfaces };
         Ok((result, remaining))
     }
@@ -225,22 +407,114 @@ impl TryFrom<&[u8]> for ListSurfaceTypesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ListSurfaceTypesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num = u32::try_from(self.surfaces.len()).expect("`surfaces` has too many elements");
+        num.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.surfaces.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the CreateContext request
 pub const CREATE_CONTEXT_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateContextRequest {
+    pub context_id: Context,
+    pub port_id: xv::Port,
+    pub surface_id: Surface,
+    pub width: u16,
+    pub height: u16,
+    pub flags: u32,
+}
+impl CreateContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_id, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (port_id, remaining) = xv::Port::try_parse_with_order(remaining, order)?;
+        let (surface_id, remaining) = Surface::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateContextRequest {
+            context_id,
+            port_id,
+            surface_id,
+            width,
+            height,
+            flags,
+        })
+    }
+    /// Set the `context_id` field of this structure.
+    pub fn context_id<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context_id = value.into();
+        self
+    }
+    /// Set the `port_id` field of this structure.
+    pub fn port_id<I>(mut self, value: I) -> Self where I: Into<xv::Port> {
+        self.port_id = value.into();
+        self
+    }
+    /// Set the `surface_id` field of this structure.
+    pub fn surface_id<I>(mut self, value: I) -> Self where I: Into<Surface> {
+        self.surface_id = value.into();
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+    /// Set the `flags` field of this structure.
+    pub fn flags(mut self, value: u32) -> Self {
+        self.flags = value;
+        self
+    }
+}
+
 pub fn create_context<Conn>(conn: &Conn, context_id: Context, port_id: xv::Port, surface_id: Surface, width: u16, height: u16, flags: u32) -> Result<Cookie<'_, Conn, CreateContextReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_id_bytes = context_id.serialize();
-    let port_id_bytes = port_id.serialize();
-    let surface_id_bytes = surface_id.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
-    let flags_bytes = flags.serialize();
+    let context_id_bytes = context_id.serialize_with_order(order);
+    let port_id_bytes = port_id.serialize_with_order(order);
+    let surface_id_bytes = surface_id.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
+    let flags_bytes = flags.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_CONTEXT_REQUEST,
@@ -270,9 +544,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateContextRequest {
+    type Reply = CreateContextReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, CreateContextReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_context(conn, self.context_id, self.port_id, self.surface_id, self.width, self.height, self.flags)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateContextReply {
@@ -284,16 +570,16 @@ pub struct CreateContextReply {
     pub priv_data: Vec<u32>,
 }
 impl TryParse for CreateContextReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width_actual, remaining) = u16::try_parse(remaining)?;
-        let (height_actual, remaining) = u16::try_parse(remaining)?;
-        let (flags_return, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width_actual, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height_actual, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (flags_return, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (priv_data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (priv_data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = CreateContextReply { response_type, sequence, width_actual, height_actual, flags_return, priv_data };
         Ok((result, remaining))
     }
@@ -304,17 +590,71 @@ impl TryFrom<&[u8]> for CreateContextReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CreateContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.priv_data.len()).expect("`priv_data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.width_actual.serialize_into_with_order(bytes, order);
+        self.height_actual.serialize_into_with_order(bytes, order);
+        self.flags_return.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.priv_data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DestroyContext request
 pub const DESTROY_CONTEXT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroyContextRequest {
+    pub context_id: Context,
+}
+impl DestroyContextRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_CONTEXT_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (context_id, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroyContextRequest {
+            context_id,
+        })
+    }
+    /// Set the `context_id` field of this structure.
+    pub fn context_id<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context_id = value.into();
+        self
+    }
+}
+
 pub fn destroy_context<Conn>(conn: &Conn, context_id: Context) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let context_id_bytes = context_id.serialize();
+    let context_id_bytes = context_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_CONTEXT_REQUEST,
@@ -328,21 +668,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroyContextRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroyContextRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_context(conn, self.context_id)
+    }
+}
 
 /// Opcode for the CreateSurface request
 pub const CREATE_SURFACE_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateSurfaceRequest {
+    pub surface_id: Surface,
+    pub context_id: Context,
+}
+impl CreateSurfaceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_SURFACE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (surface_id, remaining) = Surface::try_parse_with_order(remaining, order)?;
+        let (context_id, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateSurfaceRequest {
+            surface_id,
+            context_id,
+        })
+    }
+    /// Set the `surface_id` field of this structure.
+    pub fn surface_id<I>(mut self, value: I) -> Self where I: Into<Surface> {
+        self.surface_id = value.into();
+        self
+    }
+    /// Set the `context_id` field of this structure.
+    pub fn context_id<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context_id = value.into();
+        self
+    }
+}
+
 pub fn create_surface<Conn>(conn: &Conn, surface_id: Surface, context_id: Context) -> Result<Cookie<'_, Conn, CreateSurfaceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let surface_id_bytes = surface_id.serialize();
-    let context_id_bytes = context_id.serialize();
+    let surface_id_bytes = surface_id.serialize_with_order(order);
+    let context_id_bytes = context_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_SURFACE_REQUEST,
@@ -360,9 +746,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateSurfaceRequest {
+    type Reply = CreateSurfaceReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateSurfaceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, CreateSurfaceReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_surface(conn, self.surface_id, self.context_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateSurfaceReply {
@@ -371,13 +769,13 @@ pub struct CreateSurfaceReply {
     pub priv_data: Vec<u32>,
 }
 impl TryParse for CreateSurfaceReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(24..).ok_or(ParseError::ParseError)?;
-        let (priv_data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (priv_data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = CreateSurfaceReply { response_type, sequence, priv_data };
         Ok((result, remaining))
     }
@@ -388,17 +786,68 @@ impl TryFrom<&[u8]> for CreateSurfaceReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CreateSurfaceReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.priv_data.len()).expect("`priv_data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        self.priv_data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DestroySurface request
 pub const DESTROY_SURFACE_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroySurfaceRequest {
+    pub surface_id: Surface,
+}
+impl DestroySurfaceRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_SURFACE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (surface_id, remaining) = Surface::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroySurfaceRequest {
+            surface_id,
+        })
+    }
+    /// Set the `surface_id` field of this structure.
+    pub fn surface_id<I>(mut self, value: I) -> Self where I: Into<Surface> {
+        self.surface_id = value.into();
+        self
+    }
+}
+
 pub fn destroy_surface<Conn>(conn: &Conn, surface_id: Surface) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let surface_id_bytes = surface_id.serialize();
+    let surface_id_bytes = surface_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_SURFACE_REQUEST,
@@ -412,24 +861,94 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroySurfaceRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroySurfaceRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_surface(conn, self.surface_id)
+    }
+}
 
 /// Opcode for the CreateSubpicture request
 pub const CREATE_SUBPICTURE_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreateSubpictureRequest {
+    pub subpicture_id: Subpicture,
+    pub context: Context,
+    pub xvimage_id: u32,
+    pub width: u16,
+    pub height: u16,
+}
+impl CreateSubpictureRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_SUBPICTURE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (subpicture_id, remaining) = Subpicture::try_parse_with_order(remaining, order)?;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (xvimage_id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(CreateSubpictureRequest {
+            subpicture_id,
+            context,
+            xvimage_id,
+            width,
+            height,
+        })
+    }
+    /// Set the `subpicture_id` field of this structure.
+    pub fn subpicture_id<I>(mut self, value: I) -> Self where I: Into<Subpicture> {
+        self.subpicture_id = value.into();
+        self
+    }
+    /// Set the `context` field of this structure.
+    pub fn context<I>(mut self, value: I) -> Self where I: Into<Context> {
+        self.context = value.into();
+        self
+    }
+    /// Set the `xvimage_id` field of this structure.
+    pub fn xvimage_id(mut self, value: u32) -> Self {
+        self.xvimage_id = value;
+        self
+    }
+    /// Set the `width` field of this structure.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = value;
+        self
+    }
+    /// Set the `height` field of this structure.
+    pub fn height(mut self, value: u16) -> Self {
+        self.height = value;
+        self
+    }
+}
+
 pub fn create_subpicture<Conn>(conn: &Conn, subpicture_id: Subpicture, context: Context, xvimage_id: u32, width: u16, height: u16) -> Result<Cookie<'_, Conn, CreateSubpictureReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let subpicture_id_bytes = subpicture_id.serialize();
-    let context_bytes = context.serialize();
-    let xvimage_id_bytes = xvimage_id.serialize();
-    let width_bytes = width.serialize();
-    let height_bytes = height.serialize();
+    let subpicture_id_bytes = subpicture_id.serialize_with_order(order);
+    let context_bytes = context.serialize_with_order(order);
+    let xvimage_id_bytes = xvimage_id.serialize_with_order(order);
+    let width_bytes = width.serialize_with_order(order);
+    let height_bytes = height.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         CREATE_SUBPICTURE_REQUEST,
@@ -455,9 +974,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for CreateSubpictureRequest {
+    type Reply = CreateSubpictureReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for CreateSubpictureRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, CreateSubpictureReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        create_subpicture(conn, self.subpicture_id, self.context, self.xvimage_id, self.width, self.height)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateSubpictureReply {
@@ -471,19 +1002,19 @@ pub struct CreateSubpictureReply {
     pub priv_data: Vec<u32>,
 }
 impl TryParse for CreateSubpictureReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width_actual, remaining) = u16::try_parse(remaining)?;
-        let (height_actual, remaining) = u16::try_parse(remaining)?;
-        let (num_palette_entries, remaining) = u16::try_parse(remaining)?;
-        let (entry_bytes, remaining) = u16::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width_actual, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height_actual, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_palette_entries, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (entry_bytes, remaining) = u16::try_parse_with_order(remaining, order)?;
         let (component_order, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
         let component_order = <[u8; 4]>::try_from(component_order).unwrap();
         let remaining = remaining.get(12..).ok_or(ParseError::ParseError)?;
-        let (priv_data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?)?;
+        let (priv_data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = CreateSubpictureReply { response_type, sequence, width_actual, height_actual, num_palette_entries, entry_bytes, component_order, priv_data };
         Ok((result, remaining))
     }
@@ -494,17 +1025,73 @@ impl TryFrom<&[u8]> for CreateSubpictureReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for CreateSubpictureReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.priv_data.len()).expect("`priv_data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.width_actual.serialize_into_with_order(bytes, order);
+        self.height_actual.serialize_into_with_order(bytes, order);
+        self.num_palette_entries.serialize_into_with_order(bytes, order);
+        self.entry_bytes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.component_order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.priv_data.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Opcode for the DestroySubpicture request
 pub const DESTROY_SUBPICTURE_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DestroySubpictureRequest {
+    pub subpicture_id: Subpicture,
+}
+impl DestroySubpictureRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_SUBPICTURE_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (subpicture_id, remaining) = Subpicture::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(DestroySubpictureRequest {
+            subpicture_id,
+        })
+    }
+    /// Set the `subpicture_id` field of this structure.
+    pub fn subpicture_id<I>(mut self, value: I) -> Self where I: Into<Subpicture> {
+        self.subpicture_id = value.into();
+        self
+    }
+}
+
 pub fn destroy_subpicture<Conn>(conn: &Conn, subpicture_id: Subpicture) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let subpicture_id_bytes = subpicture_id.serialize();
+    let subpicture_id_bytes = subpicture_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         DESTROY_SUBPICTURE_REQUEST,
@@ -518,21 +1105,67 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_without_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for DestroySubpictureRequest {
+    type Reply = ();
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for DestroySubpictureRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = VoidCookie<'c, Conn>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        destroy_subpicture(conn, self.subpicture_id)
+    }
+}
 
 /// Opcode for the ListSubpictureTypes request
 pub const LIST_SUBPICTURE_TYPES_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListSubpictureTypesRequest {
+    pub port_id: xv::Port,
+    pub surface_id: Surface,
+}
+impl ListSubpictureTypesRequest {
+    /// Parse this request given its header, its body, and the byte order of the client
+    pub fn try_parse_request(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_SUBPICTURE_TYPES_REQUEST {
+            return Err(ParseError::ParseError);
+        }
+        let remaining = value;
+        let (port_id, remaining) = xv::Port::try_parse_with_order(remaining, order)?;
+        let (surface_id, remaining) = Surface::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        let _ = order;
+        Ok(ListSubpictureTypesRequest {
+            port_id,
+            surface_id,
+        })
+    }
+    /// Set the `port_id` field of this structure.
+    pub fn port_id<I>(mut self, value: I) -> Self where I: Into<xv::Port> {
+        self.port_id = value.into();
+        self
+    }
+    /// Set the `surface_id` field of this structure.
+    pub fn surface_id<I>(mut self, value: I) -> Self where I: Into<Surface> {
+        self.surface_id = value.into();
+        self
+    }
+}
+
 pub fn list_subpicture_types<Conn>(conn: &Conn, port_id: xv::Port, surface_id: Surface) -> Result<Cookie<'_, Conn, ListSubpictureTypesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
         .ok_or(ConnectionError::UnsupportedExtension)?;
+    let order = conn.byte_order();
     let length_so_far = 0;
-    let port_id_bytes = port_id.serialize();
-    let surface_id_bytes = surface_id.serialize();
+    let port_id_bytes = port_id.serialize_with_order(order);
+    let surface_id_bytes = surface_id.serialize_with_order(order);
     let mut request0 = [
         extension_information.major_opcode,
         LIST_SUBPICTURE_TYPES_REQUEST,
@@ -550,9 +1183,21 @@ where
     let length_so_far = length_so_far + request0.len();
     assert_eq!(length_so_far % 4, 0);
     let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-    request0[2..4].copy_from_slice(&length.to_ne_bytes());
+    request0[2..4].copy_from_slice(&length.serialize_with_order(order));
     Ok(conn.send_request_with_reply(&[IoSlice::new(&request0)], vec![])?)
 }
+impl crate::x11_utils::Request for ListSubpictureTypesRequest {
+    type Reply = ListSubpictureTypesReply;
+}
+impl<'c, Conn> crate::connection::SendRequest<'c, Conn> for ListSubpictureTypesRequest
+where
+    Conn: RequestConnection + ?Sized + 'c,
+{
+    type Cookie = Cookie<'c, Conn, ListSubpictureTypesReply>;
+    fn send(self, conn: &'c Conn) -> Result<Self::Cookie, ConnectionError> {
+        list_subpicture_types(conn, self.port_id, self.surface_id)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListSubpictureTypesReply {
@@ -562,14 +1207,14 @@ pub struct ListSubpictureTypesReply {
     pub types: Vec<xv::ImageFormatInfo>,
 }
 impl TryParse for ListSubpictureTypesReply {
-    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(1..).ok_or(ParseError::ParseError)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num, remaining) = u32::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num, remaining) = u32::try_parse_with_order(remaining, order)?;
         let remaining = remaining.get(20..).ok_or(ParseError::ParseError)?;
-        let (types, remaining) = crate::x11_utils::parse_list::<xv::ImageFormatInfo>(remaining, num.try_into().or(Err(ParseError::ParseError))?)?;
+        let (types, remaining) = crate::x11_utils::parse_list_with_order::<xv::ImageFormatInfo>(remaining, num.try_into().or(Err(ParseError::ParseError))?, order)?;
         let result = ListSubpictureTypesReply { response_type, sequence, length, types };
         Ok((result, remaining))
     }
@@ -580,6 +1225,32 @@ impl TryFrom<&[u8]> for ListSubpictureTypesReply {
         Ok(Self::try_parse(value)?.0)
     }
 }
+impl Serialize for ListSubpictureTypesReply {
+    type Bytes = Vec<u8>;
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let initial_length = bytes.len();
+        bytes.reserve(32);
+        self.response_type.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num = u32::try_from(self.types.len()).expect("`types` has too many elements");
+        num.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.types.serialize_into_with_order(bytes, order);
+        // Pad to at least 32 bytes and to a multiple of four bytes
+        let packet_length = std::cmp::max(32, bytes.len() - initial_length);
+        bytes.resize(initial_length + (packet_length + 3) / 4 * 4, 0);
+        // Fix up the length field
+        let length = u32::try_from((bytes.len() - initial_length - 32) / 4).unwrap();
+        bytes[initial_length + 4..initial_length + 8].copy_from_slice(&length.serialize_with_order(order));
+    }
+}
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
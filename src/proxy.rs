@@ -0,0 +1,833 @@
+//! A framework for X11 proxies.
+//!
+//! A [`Proxy`] sits between an X11 client and an X11 server. It forwards the connection setup and
+//! all traffic in both directions. Every request of the client and every reply, error, and event
+//! of the server is parsed and passed to a [`ProxyHandler`], which can inspect, rewrite, or drop
+//! it. This can be used to build filtering proxies that restrict what a client may do.
+//!
+//! ```no_run
+//! use std::os::unix::net::UnixListener;
+//! use x11rb::connection::SequenceNumber;
+//! use x11rb::protocol::xproto::{AccessError, ACCESS_ERROR, GET_IMAGE_REQUEST};
+//! use x11rb::protocol::Request;
+//! use x11rb::proxy::{Proxy, ProxyHandler, RequestAction};
+//! use x11rb::rust_connection::DisplayName;
+//! use x11rb::x11_utils::Serialize;
+//!
+//! /// Prevent the client from taking screenshots.
+//! struct NoGetImage;
+//!
+//! impl ProxyHandler for NoGetImage {
+//!     fn request(
+//!         &mut self,
+//!         _sequence: SequenceNumber,
+//!         request: &Request,
+//!         _raw: &mut Vec<u8>,
+//!     ) -> RequestAction {
+//!         match request {
+//!             Request::GetImage(_) => {
+//!                 let error = AccessError {
+//!                     response_type: 0,
+//!                     error_code: ACCESS_ERROR,
+//!                     sequence: 0,
+//!                     bad_value: 0,
+//!                     minor_opcode: 0,
+//!                     major_opcode: GET_IMAGE_REQUEST,
+//!                 };
+//!                 RequestAction::Respond(error.serialize().to_vec())
+//!             }
+//!             _ => RequestAction::Forward,
+//!         }
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let display = DisplayName::from_env()?;
+//! let listener = UnixListener::bind("/tmp/.X11-unix/X42")?;
+//! loop {
+//!     let proxy = Proxy::accept(&listener, &display, NoGetImage)?;
+//!     std::thread::spawn(move || proxy.run());
+//! }
+//! # }
+//! ```
+//!
+//! The proxy keeps the sequence numbers of the client and the server in sync: A request that is
+//! not forwarded is replaced with a `GetInputFocus` request whose reply is hidden from the client.
+//! Thus, replies, errors, and events reach the client with the sequence numbers that it expects.
+//!
+//! The proxy talks to the server in the byte order that the client announced in its
+//! `SetupRequest`, so clients of any byte order are supported. Raw packets that are passed to the
+//! [`ProxyHandler`] or returned from it are encoded in that byte order.
+//!
+//! File descriptors are passed through in both directions. This module is only available on Unix.
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Error as IOError, ErrorKind};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::sync::{Arc, Mutex};
+
+use crate::connection::SequenceNumber;
+use crate::errors::{ConnectError, ConnectionError, ParseError};
+use crate::protocol::xproto::{
+    QueryExtensionReply, Setup, SetupRequest, GET_INPUT_FOCUS_REQUEST, KEYMAP_NOTIFY_EVENT,
+};
+use crate::protocol::{Error, Event, Reply, Request};
+use crate::rust_connection::{
+    connect_stream, packet_length, setup_length, AuthMethod, BufReadFD, DisplayName, ReadFD,
+    Stream, WriteFD,
+};
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{
+    parse_request_header, BigRequests, ByteOrder, ExtInfoProvider, ExtensionInformation,
+    RequestHeader, Serialize, TryParse,
+};
+
+/// What the proxy does with a request of the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestAction {
+    /// Send the request to the X11 server.
+    Forward,
+
+    /// Do not send the request to the X11 server.
+    ///
+    /// The client waits forever for the reply of a request with a reply that is dropped. Use
+    /// `Respond` for such requests.
+    Drop,
+
+    /// Do not send the request to the X11 server and send the given reply or error to the client
+    /// instead.
+    ///
+    /// The proxy fills in the sequence number of the response.
+    Respond(Vec<u8>),
+}
+
+/// What the proxy does with a reply, error, or event of the X11 server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseAction {
+    /// Send the packet to the client.
+    Forward,
+
+    /// Do not send the packet to the client.
+    Drop,
+}
+
+/// A handler that decides what a [`Proxy`] does with the traffic between client and server.
+///
+/// All functions get the raw bytes of the packet in question. These can be changed in place to
+/// rewrite the packet. The sequence numbers that are passed in are the ones of the client.
+///
+/// By default, everything is forwarded unchanged.
+pub trait ProxyHandler {
+    /// Called with the setup request of the client before it is sent to the server.
+    fn setup_request(&mut self, request: &mut SetupRequest) {
+        let _ = request;
+    }
+
+    /// Called with the setup of the server before it is sent to the client.
+    ///
+    /// This is only called if the connection setup was successful.
+    fn setup(&mut self, setup: &mut Setup) {
+        let _ = setup;
+    }
+
+    /// Called for every request of the client.
+    fn request(
+        &mut self,
+        sequence: SequenceNumber,
+        request: &Request,
+        raw: &mut Vec<u8>,
+    ) -> RequestAction {
+        let _ = (sequence, request, raw);
+        RequestAction::Forward
+    }
+
+    /// Called for every reply of the server.
+    ///
+    /// `request` is the header of the request that the reply belongs to.
+    fn reply(
+        &mut self,
+        sequence: SequenceNumber,
+        request: RequestHeader,
+        reply: &Reply,
+        raw: &mut Vec<u8>,
+    ) -> ResponseAction {
+        let _ = (sequence, request, reply, raw);
+        ResponseAction::Forward
+    }
+
+    /// Called for every error of the server.
+    fn error(
+        &mut self,
+        sequence: SequenceNumber,
+        error: &Error<Vec<u8>>,
+        raw: &mut Vec<u8>,
+    ) -> ResponseAction {
+        let _ = (sequence, error, raw);
+        ResponseAction::Forward
+    }
+
+    /// Called for every event of the server.
+    fn event(
+        &mut self,
+        sequence: SequenceNumber,
+        event: &Event<Vec<u8>>,
+        raw: &mut Vec<u8>,
+    ) -> ResponseAction {
+        let _ = (sequence, event, raw);
+        ResponseAction::Forward
+    }
+}
+
+/// A proxy between a single X11 client and an X11 server.
+pub struct Proxy<H> {
+    client: UnixStream,
+    server: Stream,
+    auth_info: Option<(Vec<u8>, Vec<u8>)>,
+    handler: H,
+}
+
+impl<H> fmt::Debug for Proxy<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Proxy")
+            .field("client", &self.client)
+            .field("server", &self.server)
+            .finish()
+    }
+}
+
+impl<H: ProxyHandler + Send + 'static> Proxy<H> {
+    /// Create a proxy between an already connected client and server.
+    ///
+    /// The authorization information of the client is forwarded to the server.
+    pub fn new(client: UnixStream, server: UnixStream, handler: H) -> Self {
+        Self {
+            client,
+            server: Stream::UnixStream(server),
+            auth_info: None,
+            handler,
+        }
+    }
+
+    /// Create a proxy between `client` and a new connection to the given display.
+    ///
+    /// If authorization information for the display is found in `~/.Xauthority`, it replaces the
+    /// authorization information of the client. Otherwise, the client's information is forwarded.
+    pub fn connect(
+        client: UnixStream,
+        display: &DisplayName,
+        handler: H,
+    ) -> Result<Self, ConnectError> {
+        let (server, auth_info) = connect_stream(display, AuthMethod::DEFAULT_PREFERENCE)?;
+        Ok(Self {
+            client,
+            server,
+            auth_info,
+            handler,
+        })
+    }
+
+    /// Accept a client on `listener` and create a proxy between it and the given display.
+    ///
+    /// See `connect()` for details.
+    pub fn accept(
+        listener: &UnixListener,
+        display: &DisplayName,
+        handler: H,
+    ) -> Result<Self, ConnectError> {
+        let (client, _) = listener.accept()?;
+        Self::connect(client, display, handler)
+    }
+
+    /// Forward traffic between the client and the server until one of them disconnects.
+    ///
+    /// Responses of the server are forwarded by a separate thread.
+    pub fn run(self) -> Result<(), ConnectionError> {
+        let Proxy {
+            client,
+            server,
+            auth_info,
+            mut handler,
+        } = self;
+        let client = Stream::UnixStream(client);
+
+        let order = match forward_setup(&client, &server, auth_info, &mut handler) {
+            Ok(Some(order)) => order,
+            result => {
+                shutdown(&client, &server);
+                return result.map(|_| ());
+            }
+        };
+
+        let state = Arc::new(Mutex::new(State::new(handler, order)));
+        let responses = {
+            let state = Arc::clone(&state);
+            let (client, server) = (client.try_clone()?, server.try_clone()?);
+            std::thread::spawn(move || {
+                let mut server_read = BufReadFD::new(server.try_clone()?);
+                let mut client_write = client.try_clone()?;
+                let result = forward_responses(&state, &mut server_read, &mut client_write);
+                shutdown(&client, &server);
+                result
+            })
+        };
+
+        let result = {
+            let mut client_read = BufReadFD::new(client.try_clone()?);
+            let mut server_write = server.try_clone()?;
+            forward_requests(&state, &mut client_read, &mut server_write)
+        };
+        shutdown(&client, &server);
+
+        let responses_result = match responses.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        };
+        result.and(responses_result)
+    }
+}
+
+// Shut down both connections. Errors are ignored, since the peer might already be gone.
+fn shutdown(client: &Stream, server: &Stream) {
+    let _ = client.shutdown();
+    let _ = server.shutdown();
+}
+
+// Check if an I/O error means that the peer closed its connection.
+fn is_disconnect(error: &IOError) -> bool {
+    match error.kind() {
+        ErrorKind::UnexpectedEof
+        | ErrorKind::BrokenPipe
+        | ErrorKind::ConnectionReset
+        | ErrorKind::NotConnected => true,
+        _ => false,
+    }
+}
+
+// Map errors that mean that the peer disconnected to `Ok(None)`.
+fn disconnect_to_none<T>(result: Result<T, IOError>) -> Result<Option<T>, IOError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(ref e) if is_disconnect(e) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Forward the connection setup.
+///
+/// Returns the byte order of the connection if the connection setup was successful.
+fn forward_setup(
+    client: &Stream,
+    server: &Stream,
+    auth_info: Option<(Vec<u8>, Vec<u8>)>,
+    handler: &mut impl ProxyHandler,
+) -> Result<Option<ByteOrder>, ConnectionError> {
+    let (mut client, mut server) = (client.try_clone()?, server.try_clone()?);
+    let mut fds = Vec::new();
+
+    let mut request = vec![0; 12];
+    if disconnect_to_none(client.read_exact(&mut request, &mut fds))?.is_none() {
+        return Ok(None);
+    }
+    // The server is told to use the same byte order as the client
+    let order = ByteOrder::from_setup_byte(request[0])
+        .ok_or_else(|| IOError::new(ErrorKind::InvalidData, "unsupported byte order"))?;
+    let name_length = usize::from(u16::try_parse_with_order(&request[6..8], order)?.0);
+    let data_length = usize::from(u16::try_parse_with_order(&request[8..10], order)?.0);
+    let padded = |length: usize| length + (4 - length % 4) % 4;
+    request.resize(12 + padded(name_length) + padded(data_length), 0);
+    client.read_exact(&mut request[12..], &mut fds)?;
+    let mut request = SetupRequest::try_parse_with_order(&request, order)?.0;
+    if let Some((name, data)) = auth_info {
+        request.authorization_protocol_name = name;
+        request.authorization_protocol_data = data;
+    }
+    handler.setup_request(&mut request);
+    server.write_all(&request.serialize_with_order(order), Vec::new())?;
+
+    let mut setup = vec![0; 8];
+    server.read_exact(&mut setup, &mut fds)?;
+    setup.resize(setup_length(&setup, order), 0);
+    server.read_exact(&mut setup[8..], &mut fds)?;
+    // Only a successful setup is passed to the handler
+    let success = setup[0] == 1;
+    if success {
+        let mut parsed = Setup::try_parse_with_order(&setup, order)?.0;
+        handler.setup(&mut parsed);
+        setup = parsed.serialize_with_order(order);
+        // The handler might have changed the size of the setup
+        let length = u16::try_from((setup.len() - 8) / 4).map_err(|_| ParseError::ParseError)?;
+        setup[6..8].copy_from_slice(&length.serialize_with_order(order));
+    }
+    client.write_all(&setup, Vec::new())?;
+    Ok(if success { Some(order) } else { None })
+}
+
+/// Read requests from the client and forward them to the server.
+fn forward_requests<H: ProxyHandler>(
+    state: &Mutex<State<H>>,
+    client: &mut impl ReadFD,
+    server: &mut impl WriteFD,
+) -> Result<(), ConnectionError> {
+    let mut sequence: SequenceNumber = 0;
+    let mut fds = Vec::new();
+    loop {
+        let mut request = vec![0; 4];
+        if disconnect_to_none(client.read_exact(&mut request, &mut fds))?.is_none() {
+            return Ok(());
+        }
+        let (big_requests, order) = {
+            let state = state.lock().unwrap();
+            (state.big_requests, state.order)
+        };
+        let mut length = usize::from(u16::try_parse_with_order(&request[2..4], order)?.0);
+        if length == 0 && big_requests == BigRequests::Enabled {
+            request.resize(8, 0);
+            client.read_exact(&mut request[4..], &mut fds)?;
+            let big_length = u32::try_parse_with_order(&request[4..8], order)?.0;
+            length = usize::try_from(big_length).map_err(|_| ParseError::ParseError)?;
+        }
+        if length * 4 < request.len() {
+            return Err(ParseError::ParseError.into());
+        }
+        let header_length = request.len();
+        request.resize(length * 4, 0);
+        client.read_exact(&mut request[header_length..], &mut fds)?;
+        sequence += 1;
+
+        let (header, body) = parse_request_header(&request, big_requests, order)?;
+        // Parsing the request takes the FDs that belong to it. Parse with duplicates, so that we
+        // can still forward the originals.
+        let mut parse_fds = try_clone_fds(&fds)?;
+        let mut state = state.lock().unwrap();
+        let parsed = Request::parse(header, body, &mut parse_fds, &state.extensions, order)
+            .unwrap_or_else(|_| Request::Unknown(header, body.to_vec()));
+        let consumed_fds = fds.len() - parse_fds.len();
+        let kind = match parsed {
+            Request::QueryExtension(ref query) => RequestKind::QueryExtension(query.name.clone()),
+            Request::BigreqEnable(_) => RequestKind::BigReqEnable,
+            _ => RequestKind::Forwarded,
+        };
+        let (kind, request, request_fds) =
+            match state.handler.request(sequence, &parsed, &mut request) {
+                RequestAction::Forward => {
+                    // FDs may be sent earlier than the data that they belong to
+                    (kind, request, std::mem::replace(&mut fds, Vec::new()))
+                }
+                action => {
+                    let response = match action {
+                        RequestAction::Respond(response) => Some(response),
+                        _ => None,
+                    };
+                    // The FDs of the dropped request are closed
+                    let _ = fds.drain(..consumed_fds);
+                    let length = 1u16.serialize_with_order(order);
+                    let placeholder = vec![GET_INPUT_FOCUS_REQUEST, 0, length[0], length[1]];
+                    (RequestKind::Dropped(response), placeholder, Vec::new())
+                }
+            };
+        // Only replies need the request, so requests without a reply are not remembered
+        let dropped = match kind {
+            RequestKind::Dropped(_) => true,
+            _ => false,
+        };
+        if parsed.has_reply() || dropped {
+            state.pending.push_back(PendingRequest {
+                sequence,
+                header,
+                kind,
+            });
+        }
+        drop(state);
+        if disconnect_to_none(server.write_all(&request, request_fds))?.is_none() {
+            return Ok(());
+        }
+        server.flush()?;
+    }
+}
+
+/// Read replies, errors, and events from the server and forward them to the client.
+fn forward_responses<H: ProxyHandler>(
+    state: &Mutex<State<H>>,
+    server: &mut impl ReadFD,
+    client: &mut impl WriteFD,
+) -> Result<(), ConnectionError> {
+    let order = state.lock().unwrap().order;
+    let mut fds = Vec::new();
+    loop {
+        let mut packet = vec![0; 32];
+        if disconnect_to_none(server.read_exact(&mut packet, &mut fds))?.is_none() {
+            return Ok(());
+        }
+        packet.resize(packet_length(&packet, order), 0);
+        server.read_exact(&mut packet[32..], &mut fds)?;
+
+        // Parse with duplicates of the FDs, like for requests
+        let mut parse_fds = try_clone_fds(&fds)?;
+        let packet = state
+            .lock()
+            .unwrap()
+            .handle_response(packet, &mut parse_fds);
+        let packet_fds = std::mem::replace(&mut fds, Vec::new());
+        if let Some(packet) = packet {
+            if disconnect_to_none(client.write_all(&packet, packet_fds))?.is_none() {
+                return Ok(());
+            }
+            client.flush()?;
+        }
+    }
+}
+
+fn try_clone_fds(fds: &[RawFdContainer]) -> Result<Vec<RawFdContainer>, IOError> {
+    fds.iter().map(RawFdContainer::try_clone).collect()
+}
+
+/// What the proxy needs to remember about a request.
+#[derive(Debug)]
+enum RequestKind {
+    /// A request that was forwarded unchanged.
+    Forwarded,
+    /// A `QueryExtension` request for the extension with the given name.
+    QueryExtension(Vec<u8>),
+    /// A `BigReqEnable` request.
+    BigReqEnable,
+    /// A request that was replaced with `GetInputFocus`, with the response for the client.
+    Dropped(Option<Vec<u8>>),
+}
+
+/// A request whose responses might still arrive.
+#[derive(Debug)]
+struct PendingRequest {
+    sequence: SequenceNumber,
+    header: RequestHeader,
+    kind: RequestKind,
+}
+
+/// The extensions that the client queried.
+#[derive(Debug, Default)]
+struct KnownExtensions(Vec<(String, ExtensionInformation)>);
+
+impl ExtInfoProvider for KnownExtensions {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .find(|(_, info)| info.major_opcode == major_opcode)
+            .map(|(name, info)| (&**name, *info))
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_event != 0 && info.first_event <= event_code)
+            .max_by_key(|(_, info)| info.first_event)
+            .map(|(name, info)| (&**name, *info))
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_error != 0 && info.first_error <= error_code)
+            .max_by_key(|(_, info)| info.first_error)
+            .map(|(name, info)| (&**name, *info))
+    }
+}
+
+/// The state that is shared between the two directions of the proxy.
+#[derive(Debug)]
+struct State<H> {
+    handler: H,
+    // The byte order of the client, which is also used for talking to the server
+    order: ByteOrder,
+    extensions: KnownExtensions,
+    big_requests: BigRequests,
+    // Requests whose responses might still arrive, in the order in which they were sent
+    pending: VecDeque<PendingRequest>,
+    // The sequence number of the last response that was received
+    last_sequence: SequenceNumber,
+}
+
+impl<H: ProxyHandler> State<H> {
+    fn new(handler: H, order: ByteOrder) -> Self {
+        Self {
+            handler,
+            order,
+            extensions: Default::default(),
+            big_requests: BigRequests::NotEnabled,
+            pending: VecDeque::new(),
+            last_sequence: 0,
+        }
+    }
+
+    /// Handle a packet from the server, returning the packet that should be sent to the client.
+    ///
+    /// `fds` are the FDs that were received with the packet.
+    fn handle_response(
+        &mut self,
+        mut packet: Vec<u8>,
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Option<Vec<u8>> {
+        // KeymapNotify events are the only packets without a sequence number
+        if packet[0] & 0x7f != KEYMAP_NOTIFY_EVENT {
+            let wire_sequence = u16::try_parse_with_order(&packet[2..4], self.order)
+                .unwrap()
+                .0;
+            self.last_sequence = widen_sequence(self.last_sequence, wire_sequence);
+        }
+        let sequence = self.last_sequence;
+        // Responses arrive in order, so earlier requests are complete
+        while let Some(request) = self.pending.front() {
+            if request.sequence >= sequence {
+                break;
+            }
+            let _ = self.pending.pop_front();
+        }
+
+        let action = match packet[0] {
+            0 => {
+                let error = Error::parse_with_order(packet.clone(), &self.extensions, self.order)
+                    .unwrap_or_else(|_| Error::Unknown(packet.clone()));
+                self.handler.error(sequence, &error, &mut packet)
+            }
+            1 => {
+                let request = match self.pending.front_mut() {
+                    Some(request) => request,
+                    None => return Some(packet),
+                };
+                if request.sequence != sequence {
+                    // A reply for an unknown request; let the client deal with it
+                    return Some(packet);
+                }
+                match request.kind {
+                    RequestKind::Forwarded => {}
+                    RequestKind::QueryExtension(ref name) => {
+                        if let Ok((reply, _)) =
+                            QueryExtensionReply::try_parse_with_order(&packet, self.order)
+                        {
+                            if reply.present {
+                                let info = ExtensionInformation {
+                                    major_opcode: reply.major_opcode,
+                                    first_event: reply.first_event,
+                                    first_error: reply.first_error,
+                                };
+                                let name = String::from_utf8_lossy(name).into_owned();
+                                self.extensions.0.push((name, info));
+                            }
+                        }
+                    }
+                    RequestKind::BigReqEnable => self.big_requests = BigRequests::Enabled,
+                    RequestKind::Dropped(ref mut response) => {
+                        // Replace the reply to the placeholder request
+                        return response.take().map(|mut response| {
+                            if response.len() >= 4 {
+                                let wire_sequence = sequence as u16;
+                                response[2..4].copy_from_slice(
+                                    &wire_sequence.serialize_with_order(self.order),
+                                );
+                            }
+                            response
+                        });
+                    }
+                }
+                let header = request.header;
+                let reply = Reply::parse_with_order(
+                    header.major_opcode,
+                    header.minor_opcode,
+                    &packet,
+                    fds,
+                    &self.extensions,
+                    self.order,
+                )
+                .unwrap_or_else(|_| Reply::Unknown(packet.clone()));
+                self.handler.reply(sequence, header, &reply, &mut packet)
+            }
+            _ => {
+                let event = Event::parse_with_order(packet.clone(), &self.extensions, self.order)
+                    .unwrap_or_else(|_| Event::Unknown(packet.clone()));
+                self.handler.event(sequence, &event, &mut packet)
+            }
+        };
+        match action {
+            ResponseAction::Forward => Some(packet),
+            ResponseAction::Drop => None,
+        }
+    }
+}
+
+/// Get the full sequence number of a response from the 16 bits that are sent on the wire.
+///
+/// Sequence numbers of responses never decrease. Thus, this is the smallest sequence number that
+/// is at least `last` and agrees with `wire_sequence` in its lower 16 bits.
+fn widen_sequence(last: SequenceNumber, wire_sequence: u16) -> SequenceNumber {
+    let sequence = (last & !0xffff) | SequenceNumber::from(wire_sequence);
+    if sequence < last {
+        sequence + 0x1_0000
+    } else {
+        sequence
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::widen_sequence;
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn filter_requests() {
+        use std::os::unix::net::UnixStream;
+
+        use super::{Proxy, ProxyHandler, RequestAction, ResponseAction};
+        use crate::connection::{Connection, SequenceNumber};
+        use crate::protocol::xproto::{
+            ChangeWindowAttributesAux, ConnectionExt, CreateWindowAux, EventMask,
+            GetInputFocusReply, InputFocus, MapState, WindowClass,
+        };
+        use crate::protocol::{Event, Reply, Request};
+        use crate::rust_connection::RustConnection;
+        use crate::testing::FakeServer;
+        use crate::x11_utils::{RequestHeader, Serialize};
+
+        // Drops MapWindow requests, answers GetInputFocus itself and hides CreateNotify events
+        struct Handler;
+
+        impl ProxyHandler for Handler {
+            fn request(
+                &mut self,
+                _sequence: SequenceNumber,
+                request: &Request,
+                _raw: &mut Vec<u8>,
+            ) -> RequestAction {
+                match request {
+                    Request::MapWindow(_) => RequestAction::Drop,
+                    Request::GetInputFocus(_) => {
+                        let reply = GetInputFocusReply {
+                            response_type: 1,
+                            revert_to: InputFocus::Parent,
+                            sequence: 0,
+                            length: 0,
+                            focus: 42.into(),
+                        };
+                        RequestAction::Respond(reply.serialize())
+                    }
+                    _ => RequestAction::Forward,
+                }
+            }
+
+            fn reply(
+                &mut self,
+                _sequence: SequenceNumber,
+                request: RequestHeader,
+                reply: &Reply,
+                _raw: &mut Vec<u8>,
+            ) -> ResponseAction {
+                // The client only sends requests that the proxy knows
+                if let Reply::Unknown(_) = reply {
+                    panic!("Unknown reply to {:?}", request);
+                }
+                ResponseAction::Forward
+            }
+
+            fn event(
+                &mut self,
+                _sequence: SequenceNumber,
+                event: &Event<Vec<u8>>,
+                _raw: &mut Vec<u8>,
+            ) -> ResponseAction {
+                match event {
+                    Event::CreateNotify(_) => ResponseAction::Drop,
+                    _ => ResponseAction::Forward,
+                }
+            }
+        }
+
+        let server = FakeServer::new();
+        let (proxy_server, server_stream) = UnixStream::pair().unwrap();
+        server.serve(server_stream).unwrap();
+        let (proxy_client, client_stream) = UnixStream::pair().unwrap();
+        let proxy = Proxy::new(proxy_client, proxy_server, Handler);
+        let proxy = std::thread::spawn(move || proxy.run());
+
+        let (conn, _) = RustConnection::connect_to_unix_stream(client_stream, 0, None).unwrap();
+        let root = conn.setup().roots[0].root;
+        let create_window = |window| {
+            let aux = CreateWindowAux::new();
+            let class = WindowClass::InputOutput;
+            conn.create_window(0, window, root, 0, 0, 10, 10, 0, class, 0, &aux)
+                .unwrap()
+                .check()
+                .unwrap()
+        };
+        let window = conn.generate_id().unwrap().into();
+        assert!(create_window(window).is_none());
+        let tree = conn.query_tree(root).unwrap().reply().unwrap();
+        assert_eq!(tree.children, [window]);
+
+        // The MapWindow request does not reach the server
+        assert!(conn.map_window(window).unwrap().check().unwrap().is_none());
+        let attributes = conn.get_window_attributes(window).unwrap();
+        assert_eq!(attributes.reply().unwrap().map_state, MapState::Unmapped);
+
+        // The reply comes from the proxy
+        let focus = conn.get_input_focus().unwrap().reply().unwrap();
+        assert_eq!(u32::from(focus.focus), 42);
+
+        // Events are filtered
+        let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::SubstructureNotify);
+        let cookie = conn.change_window_attributes(root, &aux).unwrap();
+        assert!(cookie.check().unwrap().is_none());
+        let child = conn.generate_id().unwrap().into();
+        assert!(create_window(child).is_none());
+        assert!(conn
+            .destroy_window(child)
+            .unwrap()
+            .check()
+            .unwrap()
+            .is_none());
+        match conn.wait_for_event().unwrap() {
+            Event::DestroyNotify(event) => assert_eq!(event.window, child),
+            event => panic!("Unexpected event {:?}", event),
+        }
+
+        drop(conn);
+        proxy.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn void_requests_are_not_pending() {
+        use std::sync::Mutex;
+
+        use super::{forward_requests, ProxyHandler, State};
+        use crate::rust_connection::{ReadFDWrapper, WriteFDWrapper};
+        use crate::x11_utils::{ByteOrder, Serialize};
+
+        struct Handler;
+
+        impl ProxyHandler for Handler {}
+
+        // Three NoOperation requests and a GetInputFocus request
+        let order = ByteOrder::native();
+        let mut requests = Vec::new();
+        for &opcode in &[127, 127, 127, 43] {
+            requests.extend_from_slice(&[opcode, 0]);
+            requests.extend_from_slice(&1u16.serialize_with_order(order));
+        }
+        let state = Mutex::new(State::new(Handler, order));
+        let mut client = ReadFDWrapper::new(&requests[..]);
+        let mut server = WriteFDWrapper::new(Vec::new());
+        forward_requests(&state, &mut client, &mut server).unwrap();
+
+        let pending = &state.lock().unwrap().pending;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].sequence, 4);
+    }
+
+    #[test]
+    fn widen() {
+        assert_eq!(widen_sequence(0, 0), 0);
+        assert_eq!(widen_sequence(0, 5), 5);
+        assert_eq!(widen_sequence(5, 5), 5);
+        assert_eq!(widen_sequence(0xfffe, 0xffff), 0xffff);
+        assert_eq!(widen_sequence(0xffff, 1), 0x1_0001);
+        assert_eq!(widen_sequence(0x2_0003, 2), 0x3_0002);
+    }
+}
@@ -0,0 +1,211 @@
+//! A connection wrapper that re-establishes lost connections.
+//!
+//! When the X11 server exits or restarts, all further operations on a connection fail with
+//! [`ConnectionError::ConnectionClosed`] (or an `IOError` if the connection broke in another
+//! way). Long-running clients like panels or tray daemons often want to connect to the new server
+//! instead. [`ReconnectingConnection`] helps with this: It remembers how to connect and how to
+//! initialise a new connection, e.g. which windows to create and which events to select.
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::{ConnectionExt, CreateWindowAux, WindowClass};
+//! use x11rb::reconnect::ReconnectingConnection;
+//! use x11rb::rust_connection::RustConnection;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut conn = ReconnectingConnection::new(
+//!     || RustConnection::connect(None),
+//!     |conn, screen_num| {
+//!         let screen = &conn.setup().roots[screen_num];
+//!         let window = conn.generate_id()?.into();
+//!         conn.create_window(0, window, screen.root, 0, 0, 100, 100, 0,
+//!                            WindowClass::InputOutput, 0, &CreateWindowAux::new())?;
+//!         conn.map_window(window)?;
+//!         conn.flush()?;
+//!         Ok(())
+//!     },
+//! )?;
+//! loop {
+//!     // If the connection is lost, this reconnects and waits for the next event
+//!     // on the new connection.
+//!     let event = conn.run(|conn| Ok(conn.wait_for_event()?))?;
+//!     println!("{:?}", event);
+//! }
+//! # }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::errors::{ConnectError, ConnectionError, ReconnectError, ReplyOrIdError};
+
+/// A connection that can be re-established after it was lost.
+///
+/// See the [module documentation](index.html) for an example.
+pub struct ReconnectingConnection<C, F, I> {
+    connect: F,
+    init: I,
+    conn: C,
+    screen_num: usize,
+}
+
+impl<C, F, I> std::fmt::Debug for ReconnectingConnection<C, F, I>
+where
+    C: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectingConnection")
+            .field("conn", &self.conn)
+            .field("screen_num", &self.screen_num)
+            .finish()
+    }
+}
+
+impl<C, F, I> ReconnectingConnection<C, F, I>
+where
+    C: RequestConnection,
+    F: FnMut() -> Result<(C, usize), ConnectError>,
+    I: FnMut(&C, usize) -> Result<(), ReplyOrIdError<C::Buf>>,
+{
+    /// Establish a new connection.
+    ///
+    /// The `connect` closure is called to establish the connection. It returns the connection and
+    /// the number of the preferred screen, just like e.g. `RustConnection::connect()`. Afterwards,
+    /// `init` is called with the new connection. Both closures are called again each time the
+    /// connection is re-established.
+    pub fn new(mut connect: F, mut init: I) -> Result<Self, ReconnectError<C::Buf>> {
+        let (conn, screen_num) = connect()?;
+        init(&conn, screen_num)?;
+        Ok(Self {
+            connect,
+            init,
+            conn,
+            screen_num,
+        })
+    }
+
+    /// Get the current connection.
+    ///
+    /// The returned connection may be broken. Use `run()` to retry operations on a new connection
+    /// when the connection is lost.
+    pub fn connection(&self) -> &C {
+        &self.conn
+    }
+
+    /// Get the number of the preferred screen of the current connection.
+    pub fn screen_num(&self) -> usize {
+        self.screen_num
+    }
+
+    /// Replace the current connection with a new one.
+    ///
+    /// This calls the `connect` and `init` closures that were given to `new()`. If this fails,
+    /// the old connection is kept and this function can be called again later, e.g. when the X11
+    /// server did not finish starting up yet.
+    pub fn reconnect(&mut self) -> Result<(), ReconnectError<C::Buf>> {
+        let (conn, screen_num) = (self.connect)()?;
+        (self.init)(&conn, screen_num)?;
+        self.conn = conn;
+        self.screen_num = screen_num;
+        Ok(())
+    }
+
+    /// Run a function with the connection, reconnecting if the connection was lost.
+    ///
+    /// If `f` fails because the connection was closed or broke due to an I/O error, the connection
+    /// is re-established with `reconnect()` and `f` is called again with the new connection. Any
+    /// other error is returned to the caller. `f` is retried at most once.
+    pub fn run<R, G>(&mut self, mut f: G) -> Result<R, ReconnectError<C::Buf>>
+    where
+        G: FnMut(&C) -> Result<R, ReplyOrIdError<C::Buf>>,
+    {
+        match f(&self.conn) {
+            Err(ref e) if is_connection_lost(e) => {}
+            result => return Ok(result?),
+        }
+        self.reconnect()?;
+        Ok(f(&self.conn)?)
+    }
+}
+
+/// Check if the given error means that the connection cannot be used anymore.
+fn is_connection_lost<B: AsRef<[u8]> + std::fmt::Debug>(error: &ReplyOrIdError<B>) -> bool {
+    match error {
+        ReplyOrIdError::ConnectionError(ConnectionError::ConnectionClosed)
+        | ReplyOrIdError::ConnectionError(ConnectionError::IOError(_)) => true,
+        _ => false,
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use std::net::Shutdown;
+    use std::os::unix::net::UnixStream;
+    use std::sync::{Arc, Mutex};
+
+    use super::ReconnectingConnection;
+    use crate::connection::Connection;
+    use crate::errors::{ConnectionError, ReplyOrIdError};
+    use crate::protocol::xproto::{AtomEnum, ConnectionExt, PropMode};
+    use crate::rust_connection::RustConnection;
+    use crate::testing::FakeServer;
+    use crate::wrapper::ConnectionExt as _;
+
+    #[test]
+    fn reconnect_after_server_shutdown() {
+        let server = FakeServer::new();
+        // The server side of the most recent connection, used to simulate a server crash
+        let server_side: Arc<Mutex<Option<UnixStream>>> = Default::default();
+        let mut init_count = 0;
+
+        let connect = {
+            let server_side = Arc::clone(&server_side);
+            move || {
+                let (client, server_stream) = UnixStream::pair()?;
+                *server_side.lock().unwrap() = Some(server_stream.try_clone()?);
+                server.serve(server_stream)?;
+                RustConnection::connect_to_unix_stream(client, 0, None)
+            }
+        };
+        let init = |conn: &RustConnection, screen_num: usize| {
+            init_count += 1;
+            let root = conn.setup().roots[screen_num].root;
+            conn.change_property8(
+                PropMode::Replace,
+                root,
+                AtomEnum::WM_NAME,
+                AtomEnum::STRING,
+                b"init",
+            )?
+            .ignore_error();
+            conn.flush()?;
+            Ok(())
+        };
+        let mut conn = ReconnectingConnection::new(connect, init).unwrap();
+
+        let get_name = |conn: &RustConnection| {
+            let root = conn.setup().roots[0].root;
+            let reply = conn
+                .get_property(false, root, AtomEnum::WM_NAME, AtomEnum::STRING, 0, 100)?
+                .reply()?;
+            Ok(reply.value)
+        };
+        assert_eq!(conn.run(get_name).unwrap(), b"init");
+
+        // Break the connection and check that the error is reported as such
+        let stream = server_side.lock().unwrap().take().unwrap();
+        stream.shutdown(Shutdown::Both).unwrap();
+        match conn.connection().wait_for_event() {
+            Err(ConnectionError::ConnectionClosed) => {}
+            result => panic!("Unexpected result {:?}", result),
+        }
+        match get_name(conn.connection()) {
+            Err(ReplyOrIdError::ConnectionError(ConnectionError::ConnectionClosed)) => {}
+            result => panic!("Unexpected result {:?}", result),
+        }
+
+        // run() re-establishes the connection
+        assert_eq!(conn.run(get_name).unwrap(), b"init");
+        assert!(conn.connection().poll_for_event().unwrap().is_none());
+        drop(conn);
+        assert_eq!(init_count, 2);
+    }
+}
@@ -0,0 +1,1146 @@
+//! A pure-rust implementation of an asynchronous connection to an X11 server.
+
+use std::convert::TryInto;
+use std::io::{Error, ErrorKind, IoSlice};
+use std::mem::take;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::Duration;
+
+use super::fd_read_write::{AsyncReadFD, AsyncWriteFD};
+use super::inner::{ConnectionInner, PollReply};
+#[cfg(feature = "async-tokio")]
+use super::{connect_stream, parse_display, AuthMethod, TokioStream};
+use super::{
+    id_allocator, io_error, packet_length, parse_setup, setup_length, setup_request,
+    MaxRequestBytes, ReplyFDKind,
+};
+use crate::async_connection::{poll_fn, AsyncConnection, AsyncRequestConnection};
+use crate::connection::{
+    compute_length_field, BufWithFds, Connection, DiscardMode, RawEventAndSeqNumber, ReplyOrError,
+    RequestConnection, RequestKind, SequenceNumber, SpecialEventQueue,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyOrIdError};
+use crate::extension_manager::ExtensionManager;
+use crate::protocol::bigreq::{ConnectionExt as _, EnableReply};
+use crate::protocol::xproto::{Setup, GET_INPUT_FOCUS_REQUEST};
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ByteOrder, ExtensionInformation, Serialize, TryParse, TryParseFd};
+
+/// An asynchronous connection to an X11 server implemented in pure rust.
+///
+/// This connection does not depend on a specific async runtime. Instead, the streams that are
+/// given to the constructors have to implement [`AsyncReadFD`] and [`AsyncWriteFD`].
+///
+/// Sending a request only appends it to an internal buffer and never blocks. The buffer is
+/// written to the X11 server when waiting for a reply, an error, or an event, or when the
+/// connection is explicitly flushed via [`AsyncConnection::flush_async`].
+///
+/// The blocking methods of `RequestConnection` and `Connection` never block the calling thread.
+/// They do whatever I/O is possible without blocking and then return the result if it is already
+/// available. Otherwise, they fail with [`ConnectionError::WouldBlock`] and the `*_timeout`
+/// variants return `None`, independent of the timeout. A reply is lost when waiting for it fails
+/// this way, so use the `*_async` variants instead. Note that the generated code calls
+/// `RequestConnection::extension_information()` when sending requests for an extension. Use
+/// [`AsyncRequestConnection::extension_information_async`] beforehand to make the information
+/// available.
+#[derive(Debug)]
+pub struct AsyncRustConnection<R: AsyncReadFD + Unpin, W: AsyncWriteFD + Unpin> {
+    inner: Mutex<ConnectionInner>,
+    read: Mutex<ReadState<R>>,
+    write: Mutex<WriteState<W>>,
+    waiters: Arc<Waiters>,
+    sync_waker: Waker,
+    id_allocator: Mutex<id_allocator::IDAllocator>,
+    setup: Setup,
+    byte_order: ByteOrder,
+    extension_manager: Mutex<ExtensionManager>,
+    maximum_request_bytes: Mutex<MaxRequestBytes>,
+}
+
+#[cfg(feature = "async-tokio")]
+impl AsyncRustConnection<TokioStream, TokioStream> {
+    /// Establish a new connection via tokio.
+    ///
+    /// If no `dpy_name` is provided, the value from `$DISPLAY` is used. This works like
+    /// `RustConnection::connect()`, but has to be called from within a tokio runtime that has I/O
+    /// enabled. Opening the socket and reading `~/.Xauthority` blocks, so this is done via
+    /// `tokio::task::spawn_blocking()`.
+    pub async fn connect(dpy_name: Option<&str>) -> Result<(Self, usize), ConnectError> {
+        Self::connect_with_auth_methods(dpy_name, AuthMethod::DEFAULT_PREFERENCE).await
+    }
+
+    /// Establish a new connection via tokio, using the given authentication methods.
+    ///
+    /// See `RustConnection::connect_with_auth_methods()` for the meaning of `auth_methods`.
+    pub async fn connect_with_auth_methods(
+        dpy_name: Option<&str>,
+        auth_methods: &[AuthMethod],
+    ) -> Result<(Self, usize), ConnectError> {
+        let parsed_display =
+            parse_display::parse_display(dpy_name).ok_or(ConnectError::DisplayParsingError)?;
+        let screen = parsed_display.screen.into();
+        let auth_methods = auth_methods.to_vec();
+        let (stream, auth_info) =
+            tokio::task::spawn_blocking(move || connect_stream(&parsed_display, &auth_methods))
+                .await
+                .map_err(|err| Error::new(ErrorKind::Other, err))??;
+        let stream = TokioStream::from_stream(stream)?;
+        let (auth_name, auth_data) = auth_info.unwrap_or_else(|| (Vec::new(), Vec::new()));
+        let conn = Self::connect_to_stream_with_auth_info(
+            stream.clone(),
+            stream,
+            screen,
+            auth_name,
+            auth_data,
+        )
+        .await?;
+        Ok((conn, screen))
+    }
+}
+
+impl<R: AsyncReadFD + Unpin, W: AsyncWriteFD + Unpin> AsyncRustConnection<R, W> {
+    /// Establish a new connection to the given streams.
+    ///
+    /// `read` is used for reading data from the X11 server and `write` is used for writing.
+    /// `screen` is the number of the screen that should be used. This function checks that a
+    /// screen with that number exists.
+    pub async fn connect_to_stream(read: R, write: W, screen: usize) -> Result<Self, ConnectError> {
+        Self::connect_to_stream_with_auth_info(read, write, screen, Vec::new(), Vec::new()).await
+    }
+
+    /// Establish a new connection to the given streams.
+    ///
+    /// `read` is used for reading data from the X11 server and `write` is used for writing.
+    /// `screen` is the number of the screen that should be used. This function checks that a
+    /// screen with that number exists.
+    ///
+    /// The parameters `auth_name` and `auth_data` are used for the members
+    /// `authorization_protocol_name` and `authorization_protocol_data` of the `SetupRequest` that
+    /// is sent to the X11 server.
+    pub async fn connect_to_stream_with_auth_info(
+        read: R,
+        write: W,
+        screen: usize,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+    ) -> Result<Self, ConnectError> {
+        Self::connect_to_stream_with_byte_order(
+            read,
+            write,
+            screen,
+            auth_name,
+            auth_data,
+            ByteOrder::native(),
+        )
+        .await
+    }
+
+    /// Establish a new connection to the given streams, using the given byte order.
+    ///
+    /// This works like `connect_to_stream_with_auth_info()`, but the connection uses `order`
+    /// instead of the native byte order of this machine.
+    pub async fn connect_to_stream_with_byte_order(
+        read: R,
+        write: W,
+        screen: usize,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+        order: ByteOrder,
+    ) -> Result<Self, ConnectError> {
+        let mut read = ReadState::new(read, order);
+        let mut write = WriteState::new(write);
+        write.buffer.extend_from_slice(
+            &setup_request(auth_name, auth_data, order).serialize_with_order(order),
+        );
+        poll_fn(|cx| write.poll_flush(cx)).await?;
+        let setup = poll_fn(|cx| read.poll_setup(cx)).await?;
+
+        // Check that we got a valid screen number
+        if screen >= setup.roots.len() {
+            return Err(ConnectError::InvalidScreen);
+        }
+
+        // Success! Set up our state
+        Self::for_state(read, write, setup)
+    }
+
+    /// Establish a new connection for an already connected stream.
+    ///
+    /// `read` is used for reading data from the X11 server and `write` is used for writing.
+    /// It is assumed that `setup` was just received from the server. Thus, the first reply to a
+    /// request that is sent will have sequence number one.
+    pub fn for_connected_stream(read: R, write: W, setup: Setup) -> Result<Self, ConnectError> {
+        Self::for_connected_stream_with_byte_order(read, write, setup, ByteOrder::native())
+    }
+
+    /// Establish a new connection for an already connected stream that uses the given byte order.
+    ///
+    /// See `for_connected_stream()`. `order` is the byte order that was announced to the X11
+    /// server in the `SetupRequest`.
+    pub fn for_connected_stream_with_byte_order(
+        read: R,
+        write: W,
+        setup: Setup,
+        order: ByteOrder,
+    ) -> Result<Self, ConnectError> {
+        Self::for_state(ReadState::new(read, order), WriteState::new(write), setup)
+    }
+
+    fn for_state(
+        read: ReadState<R>,
+        write: WriteState<W>,
+        setup: Setup,
+    ) -> Result<Self, ConnectError> {
+        let allocator =
+            id_allocator::IDAllocator::new(setup.resource_id_base, setup.resource_id_mask)?;
+        let byte_order = read.order;
+        Ok(AsyncRustConnection {
+            inner: Mutex::new(ConnectionInner::new(byte_order)),
+            read: Mutex::new(read),
+            write: Mutex::new(write),
+            waiters: Default::default(),
+            sync_waker: Waker::from(Arc::new(NoopWaker)),
+            id_allocator: Mutex::new(allocator),
+            setup,
+            byte_order,
+            extension_manager: Default::default(),
+            maximum_request_bytes: Mutex::new(MaxRequestBytes::Unknown),
+        })
+    }
+
+    /// Internal function for actually sending a request.
+    ///
+    /// The request is only appended to the write buffer.
+    fn send_request(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+        kind: ReplyFDKind,
+    ) -> Result<SequenceNumber, ConnectionError> {
+        let mut storage = Default::default();
+        let bufs = compute_length_field(self, bufs, &mut storage)?;
+
+        let mut write = self.write.lock().unwrap();
+        let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
+        loop {
+            match inner.send_request(kind) {
+                Some(seqno) => {
+                    for buf in bufs {
+                        write.buffer.extend_from_slice(buf);
+                    }
+                    write.fds.extend(fds);
+                    return Ok(seqno);
+                }
+                None => send_sync(&mut inner, &mut write, self.byte_order),
+            }
+        }
+    }
+
+    /// Get a waker for polling the underlying streams.
+    ///
+    /// This waker wakes up all tasks that are waiting for this connection. This ensures that
+    /// progress is made even when the task that last polled a stream went away.
+    fn io_waker(&self) -> Waker {
+        Waker::from(Arc::clone(&self.waiters))
+    }
+
+    /// Call the given poll function once, for implementing the blocking API.
+    ///
+    /// Returns `None` if the result is not yet available. The waker that is used does nothing, so
+    /// this never blocks.
+    fn poll_once<T>(&self, poll: impl FnOnce(&mut Context<'_>) -> Poll<T>) -> Option<T> {
+        match poll(&mut Context::from_waker(&self.sync_waker)) {
+            Poll::Ready(result) => Some(result),
+            Poll::Pending => None,
+        }
+    }
+
+    /// Read packets from the X11 server until `check` returns a result.
+    ///
+    /// This first tries to write out any pending requests, since otherwise the expected packets
+    /// might never arrive.
+    fn poll_until<T>(
+        &self,
+        cx: &mut Context<'_>,
+        mut check: impl FnMut(&mut ConnectionInner) -> Option<T>,
+    ) -> Poll<Result<T, ConnectionError>> {
+        // Register before doing any I/O so that no wakeups get lost
+        self.waiters.register(cx.waker());
+        let waker = self.io_waker();
+        let mut io_cx = Context::from_waker(&waker);
+
+        if let Poll::Ready(Err(err)) = self.poll_flush_impl(&mut io_cx) {
+            return Poll::Ready(Err(err));
+        }
+
+        let mut read = self.read.lock().unwrap();
+        loop {
+            {
+                let mut inner = self.inner.lock().unwrap();
+                inner.check_error()?;
+                if let Some(result) = check(&mut inner) {
+                    return Poll::Ready(Ok(result));
+                }
+            }
+            match read.poll_read_packet(&mut io_cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(err)) => {
+                    let err = self.inner.lock().unwrap().set_error(io_error(err));
+                    // Everyone else has to learn about the error, too
+                    self.waiters.wake_all();
+                    return Poll::Ready(Err(err));
+                }
+                Poll::Ready(Ok((packet, fds))) => {
+                    self.inner.lock().unwrap().enqueue_packet(packet, fds);
+                    // Someone else might be waiting for this packet
+                    self.waiters.wake_all();
+                }
+            }
+        }
+    }
+
+    /// Try to write out the write buffer, putting the connection into the error state on failure.
+    fn poll_flush_impl(&self, cx: &mut Context<'_>) -> Poll<Result<(), ConnectionError>> {
+        let mut write = self.write.lock().unwrap();
+        let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
+        write
+            .poll_flush(cx)
+            .map_err(|err| inner.set_error(io_error(err)))
+    }
+
+    fn prefetch_maximum_request_bytes_impl(&self, max_bytes: &mut MutexGuard<'_, MaxRequestBytes>) {
+        if let MaxRequestBytes::Unknown = **max_bytes {
+            let request = match self.bigreq_enable() {
+                // The information about the BIG-REQUESTS extension did not arrive yet, try again
+                // later
+                Err(ConnectionError::WouldBlock) => return,
+                result => result.map(|cookie| cookie.into_sequence_number()).ok(),
+            };
+            **max_bytes = MaxRequestBytes::Requested(request);
+        }
+    }
+}
+
+impl<R: AsyncReadFD + Unpin, W: AsyncWriteFD + Unpin> RequestConnection
+    for AsyncRustConnection<R, W>
+{
+    type Buf = Vec<u8>;
+
+    fn send_request_with_reply<Reply>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<Cookie<'_, Self, Reply>, ConnectionError>
+    where
+        Reply: TryParse,
+    {
+        Ok(Cookie::new(
+            self,
+            self.send_request(bufs, fds, ReplyFDKind::ReplyWithoutFDs)?,
+        ))
+    }
+
+    fn send_request_with_reply_with_fds<Reply>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<CookieWithFds<'_, Self, Reply>, ConnectionError>
+    where
+        Reply: TryParseFd,
+    {
+        Ok(CookieWithFds::new(
+            self,
+            self.send_request(bufs, fds, ReplyFDKind::ReplyWithFDs)?,
+        ))
+    }
+
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        Ok(VoidCookie::new(
+            self,
+            self.send_request(bufs, fds, ReplyFDKind::NoReply)?,
+        ))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, _kind: RequestKind, mode: DiscardMode) {
+        self.inner.lock().unwrap().discard_reply(sequence, mode);
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.extension_manager
+            .lock()
+            .unwrap()
+            .prefetch_extension_information(self, extension_name)
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        self.poll_once(|cx| self.poll_extension_information(extension_name, cx))
+            .unwrap_or(Err(ConnectionError::WouldBlock))
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        self.poll_once(|cx| self.poll_reply_or_raw_error(sequence, cx))
+            .unwrap_or(Err(ConnectionError::WouldBlock))
+    }
+
+    fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Vec<u8>>, ConnectionError> {
+        self.poll_once(|cx| self.poll_reply(sequence, cx))
+            .unwrap_or(Err(ConnectionError::WouldBlock))
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
+        self.poll_once(|cx| self.poll_reply_with_fds_raw(sequence, cx))
+            .unwrap_or(Err(ConnectionError::WouldBlock))
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        self.poll_once(|cx| self.poll_check_for_raw_error(sequence, cx))
+            .unwrap_or(Err(ConnectionError::WouldBlock))
+    }
+
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        _timeout: Duration,
+    ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+        self.poll_once(|cx| self.poll_reply_or_raw_error(sequence, cx))
+            .transpose()
+    }
+
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        sequence: SequenceNumber,
+        _timeout: Duration,
+    ) -> Result<Option<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>>, ConnectionError> {
+        self.poll_once(|cx| self.poll_reply_with_fds_raw(sequence, cx))
+            .transpose()
+    }
+
+    fn maximum_request_bytes(&self) -> usize {
+        let mut max_bytes = self.maximum_request_bytes.lock().unwrap();
+        self.prefetch_maximum_request_bytes_impl(&mut max_bytes);
+        // Until the BIG-REQUESTS extension is enabled, the length from the Setup applies
+        let setup_length = usize::from(self.setup.maximum_request_length) * 4;
+        use MaxRequestBytes::*;
+        match *max_bytes {
+            Unknown => setup_length,
+            Requested(seqno) => {
+                let length = match seqno {
+                    // If prefetching the request succeeded, check for its reply without blocking
+                    Some(seqno) => {
+                        match self.poll_once(|cx| self.poll_reply_or_raw_error(seqno, cx)) {
+                            None => return setup_length,
+                            Some(Ok(ReplyOrError::Reply(reply))) => {
+                                EnableReply::try_parse_with_order(&reply, self.byte_order)
+                                    .map(|(reply, _)| reply.maximum_request_length)
+                                    .ok()
+                            }
+                            Some(_) => None,
+                        }
+                    }
+                    None => None,
+                };
+                let length = length
+                    // If anything failed (sending the request, getting the reply), use Setup
+                    .unwrap_or_else(|| self.setup.maximum_request_length.into())
+                    // Turn the u32 into usize, using the max value in case of overflow
+                    .try_into()
+                    .unwrap_or(usize::MAX);
+                let length = length * 4;
+                *max_bytes = Known(length);
+                length
+            }
+            Known(length) => length,
+        }
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {
+        let mut max_bytes = self.maximum_request_bytes.lock().unwrap();
+        self.prefetch_maximum_request_bytes_impl(&mut max_bytes);
+    }
+
+    fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    fn parse_error<E>(&self, error: E) -> Result<crate::protocol::Error<E>, ParseError>
+    where
+        E: std::fmt::Debug + AsRef<[u8]>,
+    {
+        let ext_mgr = self.extension_manager.lock().unwrap();
+        crate::protocol::Error::parse_with_order(error, &*ext_mgr, self.byte_order)
+    }
+
+    fn parse_event<E>(&self, event: E) -> Result<crate::protocol::Event<E>, ParseError>
+    where
+        E: std::fmt::Debug + AsRef<[u8]>,
+    {
+        let ext_mgr = self.extension_manager.lock().unwrap();
+        crate::protocol::Event::parse_with_order(event, &*ext_mgr, self.byte_order)
+    }
+}
+
+impl<R: AsyncReadFD + Unpin, W: AsyncWriteFD + Unpin> AsyncRequestConnection
+    for AsyncRustConnection<R, W>
+{
+    fn poll_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<ReplyOrError<Vec<u8>>, ConnectionError>> {
+        self.poll_reply_with_fds_raw(sequence, cx)
+            .map_ok(|reply| match reply {
+                ReplyOrError::Reply((reply, _fds)) => ReplyOrError::Reply(reply),
+                ReplyOrError::Error(e) => ReplyOrError::Error(e),
+            })
+    }
+
+    fn poll_reply(
+        &self,
+        sequence: SequenceNumber,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Vec<u8>>, ConnectionError>> {
+        self.poll_until(cx, |inner| match inner.poll_for_reply(sequence) {
+            PollReply::TryAgain => None,
+            PollReply::NoReply => Some(None),
+            PollReply::Reply(buffer) => Some(Some(buffer)),
+        })
+    }
+
+    fn poll_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError>> {
+        self.poll_until(cx, |inner| {
+            inner.poll_for_reply_or_error(sequence).map(|reply| {
+                if reply.0[0] == 0 {
+                    ReplyOrError::Error(reply.0)
+                } else {
+                    ReplyOrError::Reply(reply)
+                }
+            })
+        })
+    }
+
+    fn poll_check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Vec<u8>>, ConnectionError>> {
+        {
+            let mut write = self.write.lock().unwrap();
+            let mut inner = self.inner.lock().unwrap();
+            inner.check_error()?;
+            if inner.prepare_check_for_reply_or_error(sequence) {
+                send_sync(&mut inner, &mut write, self.byte_order);
+                assert!(!inner.prepare_check_for_reply_or_error(sequence));
+            }
+        }
+        self.poll_until(cx, |inner| {
+            match inner.poll_check_for_reply_or_error(sequence) {
+                PollReply::TryAgain => None,
+                PollReply::NoReply => Some(None),
+                PollReply::Reply(buffer) => Some(Some(buffer)),
+            }
+        })
+    }
+
+    fn poll_extension_information(
+        &self,
+        extension_name: &'static str,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<ExtensionInformation>, ConnectionError>> {
+        let sequence = {
+            let mut ext_mgr = self.extension_manager.lock().unwrap();
+            if let Err(err) = ext_mgr.prefetch_extension_information(self, extension_name) {
+                return Poll::Ready(Err(err));
+            }
+            ext_mgr.prefetched_sequence_number(extension_name)
+        };
+        if let Some(sequence) = sequence {
+            // Wait for the reply to arrive so that extension_information() does not block
+            let available = self.poll_until(cx, |inner| {
+                if inner.reply_or_error_available(sequence) {
+                    Some(())
+                } else {
+                    None
+                }
+            });
+            match available {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Ready(Ok(())) => {}
+            }
+        }
+        // The reply is available, so this does not block
+        Poll::Ready(
+            self.extension_manager
+                .lock()
+                .unwrap()
+                .extension_information(self, extension_name),
+        )
+    }
+}
+
+impl<R: AsyncReadFD + Unpin, W: AsyncWriteFD + Unpin> Connection for AsyncRustConnection<R, W> {
+    fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        self.poll_once(|cx| self.poll_raw_event_with_sequence(cx))
+            .unwrap_or(Err(ConnectionError::WouldBlock))
+    }
+
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        _timeout: Duration,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        self.poll_once(|cx| self.poll_raw_event_with_sequence(cx))
+            .transpose()
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
+        Ok(inner.poll_for_event_with_sequence())
+    }
+
+    fn register_for_special_xge(
+        &self,
+        extension_name: &'static str,
+        eid: u32,
+    ) -> Result<SpecialEventQueue, ConnectionError> {
+        let ext_info = self
+            .extension_information(extension_name)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let queue = SpecialEventQueue::new(ext_info.major_opcode, eid);
+        self.inner.lock().unwrap().register_special_event(queue);
+        Ok(queue)
+    }
+
+    fn unregister_for_special_xge(&self, queue: SpecialEventQueue) {
+        self.inner.lock().unwrap().unregister_special_event(queue);
+    }
+
+    fn wait_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        self.poll_once(|cx| {
+            self.poll_until(cx, |inner| {
+                inner
+                    .poll_for_special_event_with_sequence(queue)
+                    .transpose()
+            })
+        })
+        .unwrap_or(Err(ConnectionError::WouldBlock))
+        .and_then(|result| result)
+    }
+
+    fn poll_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
+        inner.poll_for_special_event_with_sequence(queue)
+    }
+
+    fn flush(&self) -> Result<(), ConnectionError> {
+        self.poll_once(|cx| self.poll_flush(cx))
+            .unwrap_or(Err(ConnectionError::WouldBlock))
+    }
+
+    fn setup(&self) -> &Setup {
+        &self.setup
+    }
+
+    fn generate_id(&self) -> Result<u32, ReplyOrIdError<Vec<u8>>> {
+        self.id_allocator.lock().unwrap().generate_id(self)
+    }
+}
+
+impl<R: AsyncReadFD + Unpin, W: AsyncWriteFD + Unpin> AsyncConnection
+    for AsyncRustConnection<R, W>
+{
+    fn poll_raw_event_with_sequence(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError>> {
+        self.poll_until(cx, |inner| inner.poll_for_event_with_sequence())
+    }
+
+    fn poll_flush(&self, cx: &mut Context<'_>) -> Poll<Result<(), ConnectionError>> {
+        self.waiters.register(cx.waker());
+        let waker = self.io_waker();
+        let mut io_cx = Context::from_waker(&waker);
+        self.poll_flush_impl(&mut io_cx)
+    }
+}
+
+/// Append a synchronisation packet to the write buffer.
+///
+/// This function appends a `GetInputFocus` request and arranges for its reply to be ignored. This
+/// ensures that a reply is expected (`ConnectionInner.next_reply_expected` increases).
+fn send_sync<W>(inner: &mut ConnectionInner, write: &mut WriteState<W>, order: ByteOrder) {
+    let length = 1u16.serialize_with_order(order);
+    let request = [
+        GET_INPUT_FOCUS_REQUEST,
+        0, /* pad */
+        length[0],
+        length[1],
+    ];
+
+    let seqno = inner
+        .send_request(ReplyFDKind::ReplyWithoutFDs)
+        .expect("Sending a HasResponse request should not be blocked by syncs");
+    inner.discard_reply(seqno, DiscardMode::DiscardReplyAndError);
+    write.buffer.extend_from_slice(&request);
+}
+
+/// The tasks that are waiting for something to happen on a connection.
+#[derive(Debug, Default)]
+struct Waiters(Mutex<Vec<Waker>>);
+
+impl Waiters {
+    fn register(&self, waker: &Waker) {
+        let mut wakers = self.0.lock().unwrap();
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+
+    fn wake_all(&self) {
+        let wakers = take(&mut *self.0.lock().unwrap());
+        wakers.into_iter().for_each(Waker::wake);
+    }
+}
+
+/// A waker that does nothing.
+#[derive(Debug)]
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+impl Wake for Waiters {
+    fn wake(self: Arc<Self>) {
+        self.wake_all();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wake_all();
+    }
+}
+
+/// The reading half of the connection, together with a partially received packet.
+#[derive(Debug)]
+struct ReadState<R> {
+    read: R,
+    order: ByteOrder,
+    buffer: Vec<u8>,
+    filled: usize,
+    fds: Vec<RawFdContainer>,
+}
+
+impl<R: AsyncReadFD + Unpin> ReadState<R> {
+    fn new(read: R, order: ByteOrder) -> Self {
+        Self {
+            read,
+            order,
+            buffer: Vec::new(),
+            filled: 0,
+            fds: Vec::new(),
+        }
+    }
+
+    /// Read until the buffer contains at least `length` bytes.
+    fn poll_fill(&mut self, cx: &mut Context<'_>, length: usize) -> Poll<std::io::Result<()>> {
+        if self.buffer.len() < length {
+            self.buffer.resize(length, 0);
+        }
+        while self.filled < length {
+            let buf = &mut self.buffer[self.filled..length];
+            match Pin::new(&mut self.read).poll_read(cx, buf, &mut self.fds) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "failed to fill the whole buffer",
+                    )))
+                }
+                Poll::Ready(Ok(n)) => self.filled += n,
+                Poll::Ready(Err(ref e)) if e.kind() == ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Take the first `length` bytes out of the buffer, together with all received FDs.
+    fn take_packet(&mut self, length: usize) -> (Vec<u8>, Vec<RawFdContainer>) {
+        let mut packet = take(&mut self.buffer);
+        packet.truncate(length);
+        self.filled = 0;
+        (packet, take(&mut self.fds))
+    }
+
+    /// Read a single X11 packet from the connection.
+    ///
+    /// This function only supports errors, events, and replies. Namely, this cannot be used to
+    /// receive the initial setup reply from the X11 server.
+    fn poll_read_packet(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<std::io::Result<(Vec<u8>, Vec<RawFdContainer>)>> {
+        ready!(self.poll_fill(cx, 32))?;
+        let length = packet_length(&self.buffer, self.order);
+        ready!(self.poll_fill(cx, length))?;
+        Poll::Ready(Ok(self.take_packet(length)))
+    }
+
+    /// Read a `Setup` from the X11 server.
+    ///
+    /// If the server sends a `SetupFailed` or `SetupAuthenticate` packet, these will be returned
+    /// as errors.
+    fn poll_setup(&mut self, cx: &mut Context<'_>) -> Poll<Result<Setup, ConnectError>> {
+        ready!(self.poll_fill(cx, 8))?;
+        let length = setup_length(&self.buffer, self.order);
+        ready!(self.poll_fill(cx, length))?;
+        let (setup, fds) = self.take_packet(length);
+        Poll::Ready(parse_setup(&setup, &fds, self.order))
+    }
+}
+
+/// The writing half of the connection, together with the requests that were not yet sent.
+#[derive(Debug)]
+struct WriteState<W> {
+    write: W,
+    buffer: Vec<u8>,
+    fds: Vec<RawFdContainer>,
+}
+
+impl<W: AsyncWriteFD + Unpin> WriteState<W> {
+    fn new(write: W) -> Self {
+        Self {
+            write,
+            buffer: Vec::new(),
+            fds: Vec::new(),
+        }
+    }
+
+    /// Write all buffered requests to the X11 server.
+    fn poll_flush(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        while !self.buffer.is_empty() || !self.fds.is_empty() {
+            let old_fds_len = self.fds.len();
+            match Pin::new(&mut self.write).poll_write(cx, &self.buffer, &mut self.fds) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(0)) if self.fds.len() == old_fds_len => {
+                    return Poll::Ready(Err(Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write the buffered data",
+                    )));
+                }
+                Poll::Ready(Ok(n)) => {
+                    let _ = self.buffer.drain(..n);
+                }
+                Poll::Ready(Err(ref e)) if e.kind() == ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            }
+        }
+        Pin::new(&mut self.write).poll_flush(cx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::future::Future;
+    use std::io::Result;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+    use std::time::Duration;
+
+    use super::AsyncRustConnection;
+    use crate::async_connection::AsyncConnection;
+    use crate::connection::{Connection, RequestConnection};
+    use crate::errors::{ConnectError, ConnectionError, ReplyError};
+    use crate::protocol::xproto::{
+        ConnectionExt as _, MAP_NOTIFY_EVENT, QUERY_EXTENSION_REQUEST, WINDOW_ERROR,
+    };
+    use crate::protocol::{Error, Event};
+    use crate::rust_connection::test::test_setup;
+    use crate::rust_connection::{AsyncReadFD, AsyncWriteFD};
+    use crate::utils::RawFdContainer;
+    use crate::x11_utils::{ByteOrder, Serialize};
+
+    #[derive(Debug, Default)]
+    struct FakeStreamState {
+        to_client: Vec<u8>,
+        from_client: Vec<u8>,
+        waker: Option<Waker>,
+    }
+
+    /// An in-memory stream that plays the role of the X11 server.
+    #[derive(Debug, Clone, Default)]
+    struct FakeStream(Arc<Mutex<FakeStreamState>>);
+
+    impl FakeStream {
+        fn send_to_client(&self, data: &[u8]) {
+            let mut state = self.0.lock().unwrap();
+            state.to_client.extend_from_slice(data);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+
+        fn received(&self) -> Vec<u8> {
+            self.0.lock().unwrap().from_client.clone()
+        }
+    }
+
+    impl AsyncReadFD for FakeStream {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+            _fd_storage: &mut Vec<RawFdContainer>,
+        ) -> Poll<Result<usize>> {
+            let mut state = self.0.lock().unwrap();
+            if state.to_client.is_empty() {
+                state.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+            let length = buf.len().min(state.to_client.len());
+            buf[..length].copy_from_slice(&state.to_client[..length]);
+            let _ = state.to_client.drain(..length);
+            Poll::Ready(Ok(length))
+        }
+    }
+
+    impl AsyncWriteFD for FakeStream {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+            fds: &mut Vec<RawFdContainer>,
+        ) -> Poll<Result<usize>> {
+            assert!(fds.is_empty());
+            self.0.lock().unwrap().from_client.extend_from_slice(buf);
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    /// A waker that counts how often it was woken.
+    #[derive(Debug, Default)]
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            let _ = self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn connect() -> (AsyncRustConnection<FakeStream, FakeStream>, FakeStream) {
+        let stream = FakeStream::default();
+        let conn =
+            AsyncRustConnection::for_connected_stream(stream.clone(), stream.clone(), test_setup())
+                .unwrap();
+        (conn, stream)
+    }
+
+    /// A waker that unparks a thread.
+    #[derive(Debug)]
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Block the current thread until the given future is ready.
+    fn run<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(result) = future.as_mut().poll(&mut cx) {
+                return result;
+            }
+            thread::park();
+        }
+    }
+
+    // A packet where the first byte is the response type, followed by the sequence number
+    fn packet(response_type: u8, second_byte: u8, sequence: u16, data: &[u8]) -> Vec<u8> {
+        let mut packet = vec![0; 32];
+        let sequence = sequence.to_ne_bytes();
+        packet[..4].copy_from_slice(&[response_type, second_byte, sequence[0], sequence[1]]);
+        packet[4..4 + data.len()].copy_from_slice(data);
+        packet
+    }
+
+    #[test]
+    fn connect_setup() {
+        let stream = FakeStream::default();
+        stream.send_to_client(&test_setup().serialize());
+        let result = run(AsyncRustConnection::connect_to_stream(
+            stream.clone(),
+            stream.clone(),
+            0,
+        ));
+        // The fake setup does not contain any screens
+        match result {
+            Err(ConnectError::InvalidScreen) => {}
+            value => panic!("Unexpected value {:?}", value),
+        }
+        let received = stream.received();
+        assert_eq!(received[0], ByteOrder::native().to_setup_byte());
+        assert_eq!(&received[2..4], &11u16.to_ne_bytes());
+    }
+
+    #[test]
+    fn reply_async() {
+        let (conn, stream) = connect();
+        let mut future = Box::pin(conn.get_input_focus().unwrap().reply_async());
+
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(Arc::clone(&counter));
+        let mut cx = Context::from_waker(&waker);
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+        // The request was sent when waiting for the reply
+        assert_eq!(stream.received(), [43, 0, 1, 0]);
+
+        // length (0) and the focused window
+        let mut data = [0; 8];
+        data[4..].copy_from_slice(&42u32.to_ne_bytes());
+        stream.send_to_client(&packet(1, 0, 1, &data));
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(Ok(reply)) => assert_eq!(u32::from(reply.focus), 42),
+            value => panic!("Unexpected value {:?}", value),
+        }
+    }
+
+    #[test]
+    fn wait_for_event_async() {
+        let (conn, stream) = connect();
+        stream.send_to_client(&packet(MAP_NOTIFY_EVENT, 0, 0, &7u32.to_ne_bytes()));
+        match run(conn.wait_for_event_async()) {
+            Ok(Event::MapNotify(event)) => assert_eq!(u32::from(event.event), 7),
+            value => panic!("Unexpected value {:?}", value),
+        }
+    }
+
+    #[test]
+    fn check_async() {
+        let (conn, stream) = connect();
+        let cookie = conn.map_window(7.into()).unwrap();
+
+        // The X11 server answers the request with an error and the sync with a reply
+        stream.send_to_client(&packet(0, WINDOW_ERROR, 1, &7u32.to_ne_bytes()));
+        stream.send_to_client(&packet(1, 0, 2, &[]));
+        match run(cookie.check_async()) {
+            Ok(Some(Error::Window(error))) => assert_eq!(error.bad_value, 7),
+            value => panic!("Unexpected value {:?}", value),
+        }
+        // A GetInputFocus request was sent after the MapWindow request
+        assert_eq!(&stream.received()[8..], [43, 0, 1, 0]);
+    }
+
+    #[test]
+    fn blocking_api_does_not_block() {
+        let (conn, stream) = connect();
+        match conn.get_input_focus().unwrap().reply() {
+            Err(ReplyError::ConnectionError(ConnectionError::WouldBlock)) => {}
+            value => panic!("Unexpected value {:?}", value),
+        }
+        assert!(matches!(
+            conn.wait_for_event(),
+            Err(ConnectionError::WouldBlock)
+        ));
+        assert!(conn
+            .wait_for_event_timeout(Duration::from_secs(60))
+            .unwrap()
+            .is_none());
+
+        // The first reply was discarded, but the second one can be received
+        let cookie = conn.get_input_focus().unwrap();
+        let mut data = [0; 8];
+        data[4..].copy_from_slice(&42u32.to_ne_bytes());
+        stream.send_to_client(&packet(1, 0, 1, &data));
+        stream.send_to_client(&packet(1, 0, 2, &data));
+        assert_eq!(u32::from(cookie.reply().unwrap().focus), 42);
+    }
+
+    #[test]
+    fn maximum_request_bytes_does_not_block() {
+        let (conn, stream) = connect();
+        // The fake setup has a maximum request length of zero
+        assert_eq!(conn.maximum_request_bytes(), 0);
+        assert_eq!(conn.maximum_request_bytes(), 0);
+        // Only a single QueryExtension request was sent
+        assert_eq!(stream.received().len(), 20);
+        assert_eq!(stream.received()[0], QUERY_EXTENSION_REQUEST);
+
+        // BIG-REQUESTS is present with major opcode 130
+        stream.send_to_client(&packet(1, 0, 1, &[0, 0, 0, 0, 1, 130, 0, 0]));
+        assert_eq!(conn.maximum_request_bytes(), 0);
+        assert_eq!(&stream.received()[20..], [130, 0, 1, 0]);
+
+        let mut data = [0; 8];
+        data[4..].copy_from_slice(&0x10000u32.to_ne_bytes());
+        stream.send_to_client(&packet(1, 0, 2, &data));
+        assert_eq!(conn.maximum_request_bytes(), 0x40000);
+    }
+
+    #[test]
+    fn check_async_error_state() {
+        let (conn, stream) = connect();
+        let cookie = conn.map_window(7.into()).unwrap();
+        let error = ConnectionError::UnknownError;
+        let _ = conn.inner.lock().unwrap().set_error(error);
+
+        // The error is reported and no GetInputFocus request is queued after the MapWindow request
+        assert!(matches!(
+            run(cookie.check_async()),
+            Err(ConnectionError::UnknownError)
+        ));
+        assert_eq!(conn.write.lock().unwrap().buffer.len(), 8);
+        assert!(stream.received().is_empty());
+    }
+}
@@ -4,6 +4,13 @@
 //! file descriptors.
 
 use std::io::{Error, ErrorKind, IoSlice, Read, Result, Write};
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+use std::time::Duration;
 
 use crate::utils::RawFdContainer;
 
@@ -77,6 +84,45 @@ pub trait WriteFD {
 
     /// Flush this output stream, ensuring that all buffered contents are written out.
     fn flush(&mut self) -> Result<()>;
+
+    /// Like `write_vectored`, but without blocking.
+    ///
+    /// This function fails with an error of kind [`std::io::ErrorKind::WouldBlock`] instead of
+    /// blocking when nothing can be written. `RustConnection` then calls
+    /// [`WriteFD::wait_writable_or_readable`] and reads with [`ReadFD::read_nonblocking`] before
+    /// trying again.
+    ///
+    /// The default implementation calls `write_vectored`, so it might block.
+    fn write_vectored_nonblocking(
+        &mut self,
+        bufs: &[IoSlice<'_>],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        self.write_vectored(bufs, fds)
+    }
+
+    /// Like `flush`, but without blocking.
+    ///
+    /// This function fails with an error of kind [`std::io::ErrorKind::WouldBlock`] if not all
+    /// buffered contents could be written out without blocking.
+    ///
+    /// The default implementation calls `flush`, so it might block.
+    fn flush_nonblocking(&mut self) -> Result<()> {
+        self.flush()
+    }
+
+    /// Wait until this writer becomes writable or the connection that it writes to becomes
+    /// readable.
+    ///
+    /// Returns `true` if there is data to read. The X11 server stops reading requests while it
+    /// cannot write its replies and events, so these have to be read before writing can continue.
+    ///
+    /// The default implementation fails, since waiting is not supported in general. It is only
+    /// called after [`WriteFD::write_vectored_nonblocking`] or [`WriteFD::flush_nonblocking`]
+    /// failed with [`std::io::ErrorKind::WouldBlock`].
+    fn wait_writable_or_readable(&mut self) -> Result<bool> {
+        Err(Error::new(ErrorKind::Other, "Waiting is unsupported"))
+    }
 }
 
 /// Wraps a [`std::io::Write`] to implement the [`WriteFD`] trait.
@@ -151,14 +197,18 @@ impl<W: WriteFD + std::fmt::Debug> BufWriteFD<W> {
         }
     }
 
-    fn flush_buffer(&mut self) -> Result<()> {
+    fn flush_buffer(&mut self, nonblocking: bool) -> Result<()> {
         let mut written = 0;
         let mut ret = Ok(());
         while written < self.data_buf.len() || !self.fd_buf.is_empty() {
-            match self
-                .inner
-                .write(&self.data_buf[written..], &mut self.fd_buf)
-            {
+            let data = &self.data_buf[written..];
+            let result = if nonblocking {
+                self.inner
+                    .write_vectored_nonblocking(&[IoSlice::new(data)], &mut self.fd_buf)
+            } else {
+                self.inner.write(data, &mut self.fd_buf)
+            };
+            match result {
                 Ok(0) => {
                     if written == self.data_buf.len() {
                         assert!(!self.fd_buf.is_empty());
@@ -194,7 +244,7 @@ impl<W: WriteFD + std::fmt::Debug> WriteFD for BufWriteFD<W> {
         self.fd_buf.extend(fds.drain(..));
 
         if self.data_buf.len() + buf.len() > self.data_buf.capacity() {
-            self.flush_buffer()?;
+            self.flush_buffer(false)?;
         }
         if buf.len() >= self.data_buf.capacity() {
             self.inner.write(buf, &mut self.fd_buf)
@@ -212,7 +262,7 @@ impl<W: WriteFD + std::fmt::Debug> WriteFD for BufWriteFD<W> {
 
         let total_len: usize = bufs.iter().map(|b| b.len()).sum();
         if self.data_buf.len() + total_len > self.data_buf.capacity() {
-            self.flush_buffer()?;
+            self.flush_buffer(false)?;
         }
         if total_len >= self.data_buf.capacity() {
             self.inner.write_vectored(bufs, &mut self.fd_buf)
@@ -222,7 +272,42 @@ impl<W: WriteFD + std::fmt::Debug> WriteFD for BufWriteFD<W> {
     }
 
     fn flush(&mut self) -> Result<()> {
-        self.flush_buffer().and_then(|_| self.inner.flush())
+        self.flush_buffer(false).and_then(|_| self.inner.flush())
+    }
+
+    fn write_vectored_nonblocking(
+        &mut self,
+        bufs: &[IoSlice<'_>],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        self.fd_buf.append(fds);
+
+        let total_len: usize = bufs.iter().map(|b| b.len()).sum();
+        if self.data_buf.len() + total_len > self.data_buf.capacity() {
+            match self.flush_buffer(true) {
+                Ok(()) => {}
+                // Writing some of the buffer might have made enough room
+                Err(ref e)
+                    if e.kind() == ErrorKind::WouldBlock
+                        && self.data_buf.len() + total_len <= self.data_buf.capacity() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if total_len >= self.data_buf.capacity() {
+            self.inner
+                .write_vectored_nonblocking(bufs, &mut self.fd_buf)
+        } else {
+            self.data_buf.write_vectored(bufs)
+        }
+    }
+
+    fn flush_nonblocking(&mut self) -> Result<()> {
+        self.flush_buffer(true)
+            .and_then(|_| self.inner.flush_nonblocking())
+    }
+
+    fn wait_writable_or_readable(&mut self) -> Result<bool> {
+        self.inner.wait_writable_or_readable()
     }
 }
 
@@ -268,6 +353,46 @@ pub trait ReadFD {
         }
         Ok(())
     }
+
+    /// Read some bytes and FDs from this reader without blocking.
+    ///
+    /// This function works like [`ReadFD::read`], but fails with an error of kind
+    /// [`std::io::ErrorKind::WouldBlock`] instead of blocking when no data is available.
+    ///
+    /// The default implementation fails, since non-blocking reads are not supported in general.
+    fn read_nonblocking(
+        &mut self,
+        buf: &mut [u8],
+        fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        let _ = (buf, fd_storage);
+        Err(Error::new(
+            ErrorKind::Other,
+            "Non-blocking reads are unsupported",
+        ))
+    }
+
+    /// Wait until this reader becomes readable or the given timeout expires.
+    ///
+    /// Returns `true` if a following call to [`ReadFD::read`] will not block and `false` if the
+    /// timeout expired.
+    ///
+    /// The default implementation fails, since waiting with a timeout is not supported in general.
+    fn wait_readable(&mut self, timeout: Duration) -> Result<bool> {
+        let _ = timeout;
+        Err(Error::new(
+            ErrorKind::Other,
+            "Waiting with a timeout is unsupported",
+        ))
+    }
+
+    /// Check whether this reader has data buffered internally.
+    ///
+    /// Such data can be read without blocking, but waiting for the underlying file descriptor to
+    /// become readable does not notice it. The default implementation returns `false`.
+    fn has_buffered_data(&self) -> bool {
+        false
+    }
 }
 
 /// Wraps a [`std::io::Read`] to implement the [`ReadFD`] trait.
@@ -335,9 +460,103 @@ impl<R: ReadFD + std::fmt::Debug> ReadFD for BufReadFD<R> {
             self.end = self.inner.read(&mut self.buf, fd_storage)?;
             self.start = 0;
         }
-        // Read data from our buffer
-        let nread = (&self.buf[self.start..self.end]).read(buf)?;
+        Ok(self.read_from_buffer(buf))
+    }
+
+    fn read_nonblocking(
+        &mut self,
+        buf: &mut [u8],
+        fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        if self.start >= self.end {
+            // We have no data buffered, try to get something new from the inner reader
+            self.end = self.inner.read_nonblocking(&mut self.buf, fd_storage)?;
+            self.start = 0;
+        }
+        Ok(self.read_from_buffer(buf))
+    }
+
+    fn wait_readable(&mut self, timeout: Duration) -> Result<bool> {
+        if self.start < self.end {
+            Ok(true)
+        } else {
+            self.inner.wait_readable(timeout)
+        }
+    }
+
+    fn has_buffered_data(&self) -> bool {
+        self.start < self.end || self.inner.has_buffered_data()
+    }
+}
+
+impl<R: ReadFD + std::fmt::Debug> BufReadFD<R> {
+    // Read data from our buffer
+    fn read_from_buffer(&mut self, buf: &mut [u8]) -> usize {
+        let available = &self.buf[self.start..self.end];
+        let nread = available.len().min(buf.len());
+        buf[..nread].copy_from_slice(&available[..nread]);
         self.start += nread;
-        Ok(nread)
+        nread
+    }
+}
+
+#[cfg(unix)]
+impl<R: ReadFD + AsRawFd + std::fmt::Debug> AsRawFd for BufReadFD<R> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.inner.as_raw_fd()
     }
 }
+
+#[cfg(unix)]
+impl<W: WriteFD + AsRawFd + std::fmt::Debug> AsRawFd for BufWriteFD<W> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+/// An asynchronous version of [`WriteFD`].
+///
+/// This trait is used by [`AsyncRustConnection`](super::AsyncRustConnection) and works like
+/// `futures::io::AsyncWrite`, but also supports sending file descriptors.
+#[cfg(feature = "async")]
+pub trait AsyncWriteFD {
+    /// Attempt to write a buffer and some FDs into this writer.
+    ///
+    /// On success, returns the number of bytes that were written. If the writer is not ready to
+    /// accept data, `Poll::Pending` is returned and the waker of `cx` is woken once writing should
+    /// be attempted again.
+    ///
+    /// The same guarantees about file descriptors as for [`WriteFD::write`] apply. Any file
+    /// descriptors that were sent are removed from the beginning of the given `Vec`.
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>>;
+
+    /// Attempt to flush this output stream, ensuring that all buffered contents are written out.
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>>;
+}
+
+/// An asynchronous version of [`ReadFD`].
+///
+/// This trait is used by [`AsyncRustConnection`](super::AsyncRustConnection) and works like
+/// `futures::io::AsyncRead`, but also supports receiving file descriptors.
+#[cfg(feature = "async")]
+pub trait AsyncReadFD {
+    /// Attempt to read some bytes and FDs from this reader.
+    ///
+    /// On success, returns the number of bytes that were read. If no data is available,
+    /// `Poll::Pending` is returned and the waker of `cx` is woken once the reader becomes
+    /// readable.
+    ///
+    /// The same guarantees about file descriptors as for [`ReadFD::read`] apply. Any received
+    /// file descriptors are appended to the given `fd_storage`.
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+        fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>>;
+}
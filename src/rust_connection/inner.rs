@@ -1,10 +1,13 @@
 //! A pure-rust implementation of a connection to an X11 server.
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use super::{BufWithFds, RawEventAndSeqNumber, ReplyFDKind};
-use crate::connection::{DiscardMode, SequenceNumber};
+use crate::connection::{DiscardMode, SequenceNumber, SpecialEventQueue};
+use crate::errors::ConnectionError;
+use crate::stats::ConnectionStats;
 use crate::utils::RawFdContainer;
+use crate::x11_utils::{ByteOrder, TryParse};
 
 #[derive(Debug, Clone)]
 pub(crate) enum PollReply {
@@ -25,6 +28,9 @@ struct SentRequest {
 
 #[derive(Debug)]
 pub(crate) struct ConnectionInner {
+    // The byte order that the X11 server uses for this connection
+    byte_order: ByteOrder,
+
     // The sequence number of the last request that was written
     last_sequence_written: SequenceNumber,
     // Sorted(!) list with information on requests that were written, but no answer received yet.
@@ -37,11 +43,19 @@ pub(crate) struct ConnectionInner {
     last_sequence_read: SequenceNumber,
     // Events that were read, but not yet returned to the API user
     pending_events: VecDeque<(SequenceNumber, Vec<u8>)>,
+    // Events that were read for a registered special event queue, but not yet returned
+    special_events: HashMap<SpecialEventQueue, VecDeque<(SequenceNumber, Vec<u8>)>>,
     // Replies that were read, but not yet returned to the API user
     pending_replies: VecDeque<(SequenceNumber, BufWithFds)>,
 
     // FDs that were read, but not yet assigned to any reply
     pending_fds: VecDeque<RawFdContainer>,
+
+    // The error that made the connection unusable, if any
+    error: Option<ConnectionError>,
+
+    // Statistics about the traffic of the connection
+    stats: ConnectionStats,
 }
 
 impl ConnectionInner {
@@ -49,18 +63,63 @@ impl ConnectionInner {
     ///
     /// It is assumed that the connection was just established. This means that the next request
     /// that is sent will have sequence number one.
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(byte_order: ByteOrder) -> Self {
         ConnectionInner {
+            byte_order,
             last_sequence_written: 0,
             next_reply_expected: 0,
             last_sequence_read: 0,
             sent_requests: VecDeque::new(),
             pending_events: VecDeque::new(),
+            special_events: HashMap::new(),
             pending_replies: VecDeque::new(),
             pending_fds: VecDeque::new(),
+            error: None,
+            stats: Default::default(),
+        }
+    }
+
+    /// Get the statistics of this connection.
+    pub(crate) fn stats(&self) -> ConnectionStats {
+        let mut stats = self.stats.clone();
+        // The entry for the last request that we got a response for is kept, since more replies
+        // could arrive for it
+        let last_read = self.last_sequence_read;
+        stats.in_flight_requests = self
+            .sent_requests
+            .iter()
+            .filter(|request| request.seqno > last_read)
+            .count();
+        stats
+    }
+
+    /// Get mutable access to the statistics of this connection for recording traffic.
+    pub(crate) fn stats_mut(&mut self) -> &mut ConnectionStats {
+        &mut self.stats
+    }
+
+    /// Check if the connection is still usable.
+    ///
+    /// Once an error was recorded with `set_error()`, this returns a copy of that error.
+    pub(crate) fn check_error(&self) -> Result<(), ConnectionError> {
+        match &self.error {
+            None => Ok(()),
+            Some(error) => Err(copy_error(error)),
         }
     }
 
+    /// Put the connection into a permanent error state.
+    ///
+    /// This is used for errors after which the connection cannot be used anymore, e.g. I/O errors
+    /// or protocol violations by the server. Only the first error is remembered. The given error
+    /// is returned for convenience.
+    pub(crate) fn set_error(&mut self, error: ConnectionError) -> ConnectionError {
+        if self.error.is_none() {
+            self.error = Some(copy_error(&error));
+        }
+        error
+    }
+
     /// Send a request to the X11 server.
     ///
     /// When this returns `None`, a sync with the server is necessary. Afterwards, the caller
@@ -133,7 +192,7 @@ impl ConnectionInner {
             return None;
         }
         // We get the u16 from the wire...
-        let number = u16::from_ne_bytes([buffer[2], buffer[3]]);
+        let (number, _) = u16::try_parse_with_order(&buffer[2..4], self.byte_order).unwrap();
 
         // ...and use our state to reconstruct the high bytes
         let high_bytes = self.last_sequence_read & !SequenceNumber::from(u16::max_value());
@@ -154,6 +213,7 @@ impl ConnectionInner {
 
     /// An X11 packet was received from the connection and is now enqueued into our state.
     pub(crate) fn enqueue_packet(&mut self, packet: Vec<u8>, fds: Vec<RawFdContainer>) {
+        self.stats.bytes_read += packet.len() as u64;
         self.pending_fds.extend(fds);
 
         let kind = packet[0];
@@ -195,10 +255,10 @@ impl ConnectionInner {
                 // This reply has FDs, the number of FDs is always in the second byte
                 let num_fds = usize::from(packet[1]);
                 if num_fds > self.pending_fds.len() {
-                    // FIXME Turn this into some kind of "permanent error state" (so that
-                    // everything fails with said error) instead of using a panic (this panic will
-                    // likely poison some Mutex and produce an error state that way).
-                    panic!("FIXME: The server sent us too few FDs. The connection is now unusable since we will never be sure again which FD belongs to which reply.");
+                    // The connection is now unusable since we will never be sure again which FD
+                    // belongs to which reply.
+                    let _ = self.set_error(ConnectionError::FDPassingFailed);
+                    return;
                 }
                 self.pending_fds.drain(..num_fds).collect()
             } else {
@@ -213,7 +273,12 @@ impl ConnectionInner {
             }
         } else {
             // It is an event
-            self.pending_events.push_back((seqno, packet));
+            let special_queue = SpecialEventQueue::for_event(&packet, self.byte_order)
+                .and_then(|queue| self.special_events.get_mut(&queue));
+            match special_queue {
+                Some(queue) => queue.push_back((seqno, packet)),
+                None => self.pending_events.push_back((seqno, packet)),
+            }
         }
     }
 
@@ -233,6 +298,17 @@ impl ConnectionInner {
         None
     }
 
+    /// Check if the server already sent an answer to the request with the given sequence number.
+    ///
+    /// In contrast to `poll_for_reply_or_error()`, the answer is not removed from the queue of
+    /// pending replies.
+    #[cfg(feature = "async")]
+    pub(crate) fn reply_or_error_available(&self, sequence: SequenceNumber) -> bool {
+        self.pending_replies
+            .iter()
+            .any(|(seqno, _packet)| *seqno == sequence)
+    }
+
     /// Prepare for calling `poll_check_for_reply_or_error()`.
     ///
     /// To check if a request with a reply caused an error, one simply has to wait for the error or
@@ -290,18 +366,72 @@ impl ConnectionInner {
             .pop_front()
             .map(|(seqno, event)| (event, seqno))
     }
+
+    /// Start putting events for the given special event queue into a separate queue.
+    pub(crate) fn register_special_event(&mut self, queue: SpecialEventQueue) {
+        let _ = self.special_events.entry(queue).or_default();
+    }
+
+    /// Stop using the given special event queue and discard all events in it.
+    pub(crate) fn unregister_special_event(&mut self, queue: SpecialEventQueue) {
+        let _ = self.special_events.remove(&queue);
+    }
+
+    /// Get a pending event from the given special event queue.
+    ///
+    /// Fails if the queue is not registered.
+    pub(crate) fn poll_for_special_event_with_sequence(
+        &mut self,
+        queue: SpecialEventQueue,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        let events = self
+            .special_events
+            .get_mut(&queue)
+            .ok_or(ConnectionError::UnregisteredSpecialEventQueue)?;
+        Ok(events.pop_front().map(|(seqno, event)| (event, seqno)))
+    }
+}
+
+/// Create a copy of a `ConnectionError`.
+///
+/// `std::io::Error` cannot be cloned. Instead, a new error with the same OS error code or with the
+/// same kind and message is created.
+fn copy_error(error: &ConnectionError) -> ConnectionError {
+    use std::io::Error;
+    match error {
+        ConnectionError::UnknownError => ConnectionError::UnknownError,
+        ConnectionError::UnsupportedExtension => ConnectionError::UnsupportedExtension,
+        ConnectionError::MaximumRequestLengthExceeded => {
+            ConnectionError::MaximumRequestLengthExceeded
+        }
+        ConnectionError::FDPassingFailed => ConnectionError::FDPassingFailed,
+        ConnectionError::ParseError => ConnectionError::ParseError,
+        ConnectionError::InsufficientMemory => ConnectionError::InsufficientMemory,
+        ConnectionError::ConnectionClosed => ConnectionError::ConnectionClosed,
+        ConnectionError::UnregisteredSpecialEventQueue => {
+            ConnectionError::UnregisteredSpecialEventQueue
+        }
+        ConnectionError::WouldBlock => ConnectionError::WouldBlock,
+        ConnectionError::IOError(e) => ConnectionError::IOError(match e.raw_os_error() {
+            Some(code) => Error::from_raw_os_error(code),
+            None => Error::new(e.kind(), e.to_string()),
+        }),
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::{ConnectionInner, ReplyFDKind};
+    use crate::connection::SpecialEventQueue;
+    use crate::errors::ConnectionError;
+    use crate::x11_utils::ByteOrder;
 
     #[test]
     fn insert_sync_no_reply() {
         // The connection must send a sync (GetInputFocus) request every 2^16 requests (that do not
         // have a reply). Thus, this test sends more than that and tests for the sync to appear.
 
-        let mut connection = ConnectionInner::new();
+        let mut connection = ConnectionInner::new(ByteOrder::native());
 
         for num in 1..0x10000 {
             let seqno = connection.send_request(ReplyFDKind::NoReply);
@@ -323,7 +453,7 @@ mod test {
         // Compared to the previous test, this uses ReplyFDKind::ReplyWithoutFDs, so no sync needs to
         // be inserted.
 
-        let mut connection = ConnectionInner::new();
+        let mut connection = ConnectionInner::new(ByteOrder::native());
 
         for num in 1..=0x10001 {
             let seqno = connection.send_request(ReplyFDKind::ReplyWithoutFDs);
@@ -337,7 +467,7 @@ mod test {
         // the next request. Then it sends a ReplyFDKind::ReplyWithoutFDs request so that no sync is
         // necessary. This is a regression test: Once upon a time, an unnecessary sync was done.
 
-        let mut connection = ConnectionInner::new();
+        let mut connection = ConnectionInner::new(ByteOrder::native());
 
         for num in 1..0x10000 {
             let seqno = connection.send_request(ReplyFDKind::NoReply);
@@ -347,4 +477,71 @@ mod test {
         let seqno = connection.send_request(ReplyFDKind::ReplyWithoutFDs);
         assert_eq!(Some(0x10000), seqno);
     }
+
+    #[test]
+    fn too_few_fds() {
+        let mut connection = ConnectionInner::new(ByteOrder::native());
+        let seqno = connection.send_request(ReplyFDKind::ReplyWithFDs).unwrap();
+        assert!(connection.check_error().is_ok());
+
+        // A reply that claims to come with one FD, but no FD was received
+        let mut reply = vec![1, 1];
+        reply.extend_from_slice(&(seqno as u16).to_ne_bytes());
+        reply.resize(32, 0);
+        connection.enqueue_packet(reply, Vec::new());
+
+        match connection.check_error() {
+            Err(ConnectionError::FDPassingFailed) => {}
+            value => panic!("Unexpected value {:?}", value),
+        }
+        assert!(connection.poll_for_reply_or_error(seqno).is_none());
+    }
+
+    #[test]
+    fn special_event_queue() {
+        // A GE event of the extension with major opcode 130 for event context 7
+        fn ge_event(eid: u32) -> Vec<u8> {
+            let mut event = vec![35, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+            event.extend_from_slice(&eid.to_ne_bytes());
+            event.resize(32, 0);
+            event
+        }
+
+        let mut connection = ConnectionInner::new(ByteOrder::native());
+        let queue = SpecialEventQueue::new(130, 7);
+        connection.register_special_event(queue);
+
+        connection.enqueue_packet(ge_event(7), Vec::new());
+        connection.enqueue_packet(ge_event(8), Vec::new());
+        assert_eq!(
+            connection
+                .poll_for_special_event_with_sequence(queue)
+                .unwrap(),
+            Some((ge_event(7), 0))
+        );
+        assert_eq!(
+            connection
+                .poll_for_special_event_with_sequence(queue)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            connection.poll_for_event_with_sequence(),
+            Some((ge_event(8), 0))
+        );
+
+        // After unregistering, the events end up in the main queue again
+        connection.enqueue_packet(ge_event(7), Vec::new());
+        connection.unregister_special_event(queue);
+        connection.enqueue_packet(ge_event(7), Vec::new());
+        assert_eq!(
+            connection.poll_for_event_with_sequence(),
+            Some((ge_event(7), 0))
+        );
+        assert_eq!(connection.poll_for_event_with_sequence(), None);
+        match connection.poll_for_special_event_with_sequence(queue) {
+            Err(ConnectionError::UnregisteredSpecialEventQueue) => {}
+            result => panic!("Unexpected result {:?}", result),
+        }
+    }
 }
@@ -1,30 +1,63 @@
 //! A pure-rust implementation of a connection to an X11 server.
 
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryInto;
 use std::io::IoSlice;
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
 use std::sync::{Condvar, Mutex, MutexGuard, TryLockError};
+use std::time::{Duration, Instant};
 
 use crate::connection::{
     compute_length_field, Connection, DiscardMode, ReplyOrError, RequestConnection, RequestKind,
-    SequenceNumber,
+    SequenceNumber, SpecialEventQueue,
 };
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 pub use crate::errors::{ConnectError, ConnectionError, ParseError};
 use crate::extension_manager::ExtensionManager;
 use crate::protocol::bigreq::{ConnectionExt as _, EnableReply};
 use crate::protocol::xproto::{Setup, SetupRequest, GET_INPUT_FOCUS_REQUEST};
+use crate::stats::ConnectionStats;
 use crate::utils::RawFdContainer;
-use crate::x11_utils::{ExtensionInformation, Serialize};
+use crate::x11_utils::{ByteOrder, ExtensionInformation, Serialize, TryParse, TryParseFd};
+
+// Like `std::task::ready!`, which was only stabilised in Rust 1.64 and is thus not available in the
+// minimum Rust version 1.51 of the `async` feature.
+#[cfg(feature = "async")]
+macro_rules! ready {
+    ($e:expr) => {
+        match $e {
+            Poll::Ready(t) => t,
+            Poll::Pending => return Poll::Pending,
+        }
+    };
+}
 
+#[cfg(feature = "async")]
+mod async_connection;
+mod des;
 mod fd_read_write;
 mod id_allocator;
 mod inner;
 mod parse_display;
+pub mod replay;
 mod stream;
-mod xauth;
-
+#[cfg(feature = "async-tokio")]
+mod tokio_stream;
+pub mod xauth;
+
+#[cfg(feature = "async")]
+pub use async_connection::AsyncRustConnection;
+#[cfg(feature = "async")]
+pub use fd_read_write::{AsyncReadFD, AsyncWriteFD};
 pub use fd_read_write::{BufReadFD, BufWriteFD, ReadFD, ReadFDWrapper, WriteFD, WriteFDWrapper};
 use inner::PollReply;
+pub use parse_display::DisplayName;
+pub(crate) use stream::Stream;
+#[cfg(feature = "async-tokio")]
+pub use tokio_stream::TokioStream;
+pub use xauth::AuthMethod;
 
 type Buffer = <RustConnection as RequestConnection>::Buf;
 pub type ReplyOrIdError = crate::errors::ReplyOrIdError<Buffer>;
@@ -51,41 +84,106 @@ pub(crate) enum ReplyFDKind {
 
 /// A connection to an X11 server implemented in pure rust
 #[derive(Debug)]
-pub struct RustConnection<
-    R: ReadFD = BufReadFD<stream::Stream>,
-    W: WriteFD = BufWriteFD<stream::Stream>,
-> {
+pub struct RustConnection<R: ReadFD = BufReadFD<Stream>, W: WriteFD = BufWriteFD<Stream>> {
     inner: Mutex<inner::ConnectionInner>,
-    read: Mutex<R>,
+    read: Mutex<PacketReader<R>>,
     write: Mutex<W>,
     reader_condition: Condvar,
     id_allocator: Mutex<id_allocator::IDAllocator>,
     setup: Setup,
+    byte_order: ByteOrder,
     extension_manager: Mutex<ExtensionManager>,
     maximum_request_bytes: Mutex<MaxRequestBytes>,
 }
 
-impl RustConnection<BufReadFD<stream::Stream>, BufWriteFD<stream::Stream>> {
+impl RustConnection<BufReadFD<Stream>, BufWriteFD<Stream>> {
     /// Establish a new connection.
     ///
     /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
     pub fn connect(dpy_name: Option<&str>) -> Result<(Self, usize), ConnectError> {
+        Self::connect_with_auth_methods(dpy_name, AuthMethod::DEFAULT_PREFERENCE)
+    }
+
+    /// Establish a new connection, using the given authentication methods.
+    ///
+    /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
+    ///
+    /// `auth_methods` lists the acceptable authentication methods, most preferred first. The
+    /// best matching entry in `~/.Xauthority` is used for authenticating to the server.
+    pub fn connect_with_auth_methods(
+        dpy_name: Option<&str>,
+        auth_methods: &[AuthMethod],
+    ) -> Result<(Self, usize), ConnectError> {
         // Parse display information
         let parsed_display =
             parse_display::parse_display(dpy_name).ok_or(ConnectError::DisplayParsingError)?;
+        Self::connect_to_display(&parsed_display, auth_methods)
+    }
 
-        // Establish connection
-        let protocol = parsed_display.protocol.as_ref().map(|s| &**s);
-        let stream =
-            stream::Stream::connect(&*parsed_display.host, protocol, parsed_display.display)?;
+    /// Establish a new connection to the given display, using the given authentication methods.
+    ///
+    /// See `connect_with_auth_methods()` for the meaning of `auth_methods`.
+    pub fn connect_to_display(
+        parsed_display: &DisplayName,
+        auth_methods: &[AuthMethod],
+    ) -> Result<(Self, usize), ConnectError> {
+        let (stream, auth_info) = connect_stream(parsed_display, auth_methods)?;
         let screen = parsed_display.screen.into();
+        Self::connect_to_connected_stream(stream, screen, auth_info)
+    }
+
+    /// Establish a new connection over an already connected Unix socket.
+    ///
+    /// This is useful when the connection to the X11 server was set up by someone else, for
+    /// example a parent process. Passing file descriptors over the connection is supported.
+    ///
+    /// `screen` is the number of the screen that should be used. If `auth_info` is given, it
+    /// contains the name and data of the authorization protocol that is sent to the server.
+    #[cfg(unix)]
+    pub fn connect_to_unix_stream(
+        stream: UnixStream,
+        screen: usize,
+        auth_info: Option<(Vec<u8>, Vec<u8>)>,
+    ) -> Result<(Self, usize), ConnectError> {
+        Self::connect_to_connected_stream(Stream::UnixStream(stream), screen, auth_info)
+    }
 
-        let (family, address) = stream.peer_addr()?;
-        let (auth_name, auth_data) = xauth::get_auth(family, &address, parsed_display.display)
-            // Ignore all errors while determining auth; instead we just try without auth info.
-            .unwrap_or(None)
-            .unwrap_or_else(|| (Vec::new(), Vec::new()));
+    /// Establish a new connection over an already connected Unix socket file descriptor.
+    ///
+    /// This takes ownership of `fd`. It is an error if `fd` is not a socket. See
+    /// `connect_to_unix_stream()` for the meaning of the other arguments.
+    ///
+    /// This function requires the `allow-unsafe-code` feature.
+    #[cfg(all(unix, feature = "allow-unsafe-code"))]
+    pub fn connect_to_fd(
+        fd: RawFdContainer,
+        screen: usize,
+        auth_info: Option<(Vec<u8>, Vec<u8>)>,
+    ) -> Result<(Self, usize), ConnectError> {
+        use nix::sys::stat::{fstat, SFlag};
+        use std::os::unix::io::FromRawFd;
+
+        // Nothing touched errno since fstat() failed
+        let stat = fstat(fd.as_raw_fd()).map_err(|_| std::io::Error::last_os_error())?;
+        if SFlag::from_bits_truncate(stat.st_mode) & SFlag::S_IFMT != SFlag::S_IFSOCK {
+            let error = std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "file descriptor is not a socket",
+            );
+            return Err(error.into());
+        }
 
+        // Safety: RawFdContainer owns the file descriptor and we transfer ownership.
+        let stream = unsafe { UnixStream::from_raw_fd(fd.into_raw_fd()) };
+        Self::connect_to_unix_stream(stream, screen, auth_info)
+    }
+
+    fn connect_to_connected_stream(
+        stream: Stream,
+        screen: usize,
+        auth_info: Option<xauth::AuthInfo>,
+    ) -> Result<(Self, usize), ConnectError> {
+        let (auth_name, auth_data) = auth_info.unwrap_or_else(|| (Vec::new(), Vec::new()));
         let write = BufWriteFD::new(stream.try_clone()?);
         let read = BufReadFD::new(stream);
         Ok((
@@ -95,6 +193,43 @@ impl RustConnection<BufReadFD<stream::Stream>, BufWriteFD<stream::Stream>> {
     }
 }
 
+/// Connect to the given display and get the authorization information for it.
+pub(crate) fn connect_stream(
+    parsed_display: &DisplayName,
+    auth_methods: &[AuthMethod],
+) -> Result<(Stream, Option<xauth::AuthInfo>), std::io::Error> {
+    let stream = Stream::connect(parsed_display)?;
+    let auth_info = get_auth(&stream, parsed_display.display, auth_methods)
+        // Ignore all errors while determining auth; instead we just try without auth info.
+        .unwrap_or(None);
+    Ok((stream, auth_info))
+}
+
+/// Get the authorization information for connecting to the given display via `stream`.
+fn get_auth(
+    stream: &Stream,
+    display: u16,
+    auth_methods: &[AuthMethod],
+) -> Result<Option<xauth::AuthInfo>, std::io::Error> {
+    let (family, address) = stream.peer_addr()?;
+    let (name, data) = match xauth::get_auth(family, &address, display, auth_methods)? {
+        None => return Ok(None),
+        Some(info) => info,
+    };
+    if name == AuthMethod::XdmAuthorization1.name() {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let (address, port) = stream.xdm_address()?;
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs() as u32)
+            .unwrap_or(0);
+        let data = xauth::xdm_authorization_1(&data, address, port, time);
+        Ok(Some((name, data)))
+    } else {
+        Ok(Some((name, data)))
+    }
+}
+
 impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
     /// Establish a new connection to the given streams.
     ///
@@ -115,14 +250,37 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
     /// `authorization_protocol_name` and `authorization_protocol_data` of the `SetupRequest` that
     /// is sent to the X11 server.
     pub fn connect_to_stream_with_auth_info(
+        read: R,
+        write: W,
+        screen: usize,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+    ) -> Result<Self, ConnectError> {
+        Self::connect_to_stream_with_byte_order(
+            read,
+            write,
+            screen,
+            auth_name,
+            auth_data,
+            ByteOrder::native(),
+        )
+    }
+
+    /// Establish a new connection to the given streams, using the given byte order.
+    ///
+    /// This works like `connect_to_stream_with_auth_info()`, but the connection uses `order`
+    /// instead of the native byte order of this machine. All requests are encoded in this byte
+    /// order and the X11 server encodes its replies, events, and errors in it.
+    pub fn connect_to_stream_with_byte_order(
         mut read: R,
         mut write: W,
         screen: usize,
         auth_name: Vec<u8>,
         auth_data: Vec<u8>,
+        order: ByteOrder,
     ) -> Result<Self, ConnectError> {
-        write_setup(&mut write, auth_name, auth_data)?;
-        let setup = read_setup(&mut read)?;
+        write_setup(&mut write, auth_name, auth_data, order)?;
+        let setup = read_setup(&mut read, order)?;
 
         // Check that we got a valid screen number
         if screen >= setup.roots.len() {
@@ -130,7 +288,7 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
         }
 
         // Success! Set up our state
-        Self::for_connected_stream(read, write, setup)
+        Self::for_connected_stream_with_byte_order(read, write, setup, order)
     }
 
     /// Establish a new connection for an already connected stream.
@@ -139,7 +297,26 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
     /// It is assumed that `setup` was just received from the server. Thus, the first reply to a
     /// request that is sent will have sequence number one.
     pub fn for_connected_stream(read: R, write: W, setup: Setup) -> Result<Self, ConnectError> {
-        Self::for_inner(read, write, inner::ConnectionInner::new(), setup)
+        Self::for_connected_stream_with_byte_order(read, write, setup, ByteOrder::native())
+    }
+
+    /// Establish a new connection for an already connected stream that uses the given byte order.
+    ///
+    /// See `for_connected_stream()`. `order` is the byte order that was announced to the X11
+    /// server in the `SetupRequest`.
+    pub fn for_connected_stream_with_byte_order(
+        read: R,
+        write: W,
+        setup: Setup,
+        order: ByteOrder,
+    ) -> Result<Self, ConnectError> {
+        Self::for_inner(
+            read,
+            write,
+            inner::ConnectionInner::new(order),
+            setup,
+            order,
+        )
     }
 
     fn for_inner(
@@ -147,16 +324,18 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
         write: W,
         inner: inner::ConnectionInner,
         setup: Setup,
+        byte_order: ByteOrder,
     ) -> Result<Self, ConnectError> {
         let allocator =
             id_allocator::IDAllocator::new(setup.resource_id_base, setup.resource_id_mask)?;
         Ok(RustConnection {
             inner: Mutex::new(inner),
-            read: Mutex::new(read),
+            read: Mutex::new(PacketReader::new(read, byte_order)),
             write: Mutex::new(write),
             reader_condition: Condvar::new(),
             id_allocator: Mutex::new(allocator),
             setup,
+            byte_order,
             extension_manager: Default::default(),
             maximum_request_bytes: Mutex::new(MaxRequestBytes::Unknown),
         })
@@ -177,15 +356,23 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
 
         let mut write = self.write.lock().unwrap();
         let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
         loop {
             match inner.send_request(kind) {
                 Some(seqno) => {
                     // Now actually send the buffers
-                    // FIXME: We must always be able to read when we write
-                    write_all_vectored(&mut *write, bufs, fds)?;
+                    drop(inner);
+                    let result = self.write_all_vectored(&mut *write, bufs, fds);
+                    let mut inner = self.inner.lock().unwrap();
+                    if let Err(e) = result {
+                        // Only a part of the request might have been written. The connection is
+                        // now unusable.
+                        return Err(inner.set_error(e));
+                    }
+                    inner.stats_mut().record_request(bufs);
                     return Ok(seqno);
                 }
-                None => self.send_sync(&mut *inner, &mut *write)?,
+                None => inner = self.send_sync(inner, &mut *write)?,
             }
         }
     }
@@ -195,12 +382,12 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
     /// This function sends a `GetInputFocus` request to the X11 server and arranges for its reply
     /// to be ignored. This ensures that a reply is expected (`ConnectionInner.next_reply_expected`
     /// increases).
-    fn send_sync(
-        &self,
-        inner: &mut inner::ConnectionInner,
+    fn send_sync<'a>(
+        &'a self,
+        mut inner: MutexGuardInner<'a>,
         write: &mut W,
-    ) -> Result<(), std::io::Error> {
-        let length = 1u16.to_ne_bytes();
+    ) -> Result<MutexGuardInner<'a>, ConnectionError> {
+        let length = 1u16.serialize_with_order(self.byte_order);
         let request = [
             GET_INPUT_FOCUS_REQUEST,
             0, /* pad */
@@ -212,9 +399,85 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
             .send_request(ReplyFDKind::ReplyWithoutFDs)
             .expect("Sending a HasResponse request should not be blocked by syncs");
         inner.discard_reply(seqno, DiscardMode::DiscardReplyAndError);
-        write_all_vectored(write, &[IoSlice::new(&request)], Vec::new())?;
+        let bufs = [IoSlice::new(&request)];
+        drop(inner);
+        let result = self.write_all_vectored(write, &bufs, Vec::new());
+        let mut inner = self.inner.lock().unwrap();
+        result.map_err(|e| inner.set_error(e))?;
+        let stats = inner.stats_mut();
+        stats.record_request(&bufs);
+        stats.implicit_syncs += 1;
+        Ok(inner)
+    }
 
-        Ok(())
+    /// Flush the write buffer.
+    ///
+    /// If this fails, the connection is put into the error state. Like for
+    /// `write_all_vectored()`, `inner` is unlocked while writing.
+    fn flush_impl<'a>(
+        &'a self,
+        inner: MutexGuardInner<'a>,
+        write: &mut W,
+    ) -> Result<MutexGuardInner<'a>, ConnectionError> {
+        use std::io::ErrorKind;
+
+        inner.check_error()?;
+        drop(inner);
+        let result = loop {
+            match write.flush_nonblocking() {
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
+                    if let Err(e) = self.wait_writable(write) {
+                        break Err(e);
+                    }
+                }
+                result => break result.map_err(io_error),
+            }
+        };
+        let mut inner = self.inner.lock().unwrap();
+        result.map_err(|e| inner.set_error(e))?;
+        Ok(inner)
+    }
+
+    /// Write a set of buffers, reading packets from the connection while writing would block.
+    ///
+    /// The X11 server stops reading requests while it cannot write its replies and events to us.
+    /// Blocking in a write could thus wait forever. Instead, writing is done without blocking and
+    /// the available packets are read and enqueued whenever writing would block. The caller must
+    /// not have `inner` locked, since another thread that is currently reading from the connection
+    /// needs it for enqueuing its packets.
+    fn write_all_vectored(
+        &self,
+        write: &mut W,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<(), ConnectionError> {
+        write_all_vectored(write, bufs, fds, |write| self.wait_writable(write))
+    }
+
+    /// Read and enqueue packets until `write` becomes writable.
+    fn wait_writable(&self, write: &mut W) -> Result<(), ConnectionError> {
+        loop {
+            self.read_available()?;
+            match write.wait_writable_or_readable() {
+                Ok(false) => return Ok(()),
+                Ok(true) => {}
+                Err(e) => return Err(io_error(e)),
+            }
+        }
+    }
+
+    /// Check if the connection is in an error state.
+    ///
+    /// After an I/O error or a protocol violation by the X11 server, the connection cannot be
+    /// used anymore. All further operations on the connection fail with the error that is returned
+    /// here. This is similar to `xcb_connection_has_error()` from libxcb.
+    pub fn has_error(&self) -> Option<ConnectionError> {
+        self.inner.lock().unwrap().check_error().err()
+    }
+
+    /// Get statistics about the traffic of this connection.
+    pub fn stats(&self) -> ConnectionStats {
+        self.inner.lock().unwrap().stats()
     }
 
     /// Read a packet from the connection.
@@ -223,10 +486,14 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
     /// inner data while waiting for a packet so that other threads can make progress. For this
     /// reason, you need to pass in a `MutexGuard` to be dropped. This function locks the mutex
     /// again and returns a new `MutexGuard`.
+    ///
+    /// If the connection is in an error state, the error is returned instead.
     fn read_packet_and_enqueue<'a>(
         &'a self,
         mut inner: MutexGuardInner<'a>,
-    ) -> Result<MutexGuardInner<'a>, std::io::Error> {
+    ) -> Result<MutexGuardInner<'a>, ConnectionError> {
+        inner.check_error()?;
+
         // 0.1. Try to lock the `read` mutex.
         match self.read.try_lock() {
             Err(TryLockError::WouldBlock) => {
@@ -238,7 +505,10 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
                 // When `wait` finishes, other thread has enqueued a packet,
                 // so the purpose of this function has been fulfilled. `wait`
                 // will relock `inner` when it returns.
-                Ok(self.reader_condition.wait(inner).unwrap())
+                let start = Instant::now();
+                let mut inner = self.reader_condition.wait(inner).unwrap();
+                inner.stats_mut().record_wait(start.elapsed());
+                Ok(inner)
             }
             Err(TryLockError::Poisoned(e)) => panic!("{}", e),
             Ok(mut lock) => {
@@ -246,11 +516,19 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
                 // `read_packet` is blocking.
                 drop(inner);
 
-                // 2.2. Block the thread until a packet is received.
-                let (packet, fds) = read_packet(&mut *lock)?;
+                // 2.2. Block the thread until a packet is received. Also
+                // take the packets that the reader already buffered, since
+                // nothing would wake up an external event loop for them.
+                let start = Instant::now();
+                let result = lock.read_packet();
+                let buffered = match result {
+                    Ok(_) => lock.read_buffered_packets(),
+                    Err(_) => Vec::new(),
+                };
 
                 // 2.3. Relock `inner` to enqueue the packet.
                 inner = self.inner.lock().unwrap();
+                inner.stats_mut().record_wait(start.elapsed());
 
                 // 2.4. Once `inner` has been relocked, drop the
                 // lock on `read`. While inner is locked, other
@@ -261,19 +539,178 @@ impl<R: ReadFD, W: WriteFD> RustConnection<R, W> {
                 // for a reply that has been read but not enqueued yet.
                 drop(lock);
 
-                // 2.5. Actually enqueue the read packet.
-                inner.enqueue_packet(packet, fds);
+                // 2.5. Actually enqueue the read packet. A read error
+                // makes the connection unusable.
+                let result = match result {
+                    Ok((packet, fds)) => {
+                        inner.enqueue_packet(packet, fds);
+                        Ok(())
+                    }
+                    Err(e) => Err(inner.set_error(io_error(e))),
+                };
+                for (packet, fds) in buffered {
+                    inner.enqueue_packet(packet, fds);
+                }
 
-                // 2.6. Notify threads that a packet has been enqueued,
-                // so other threads waiting on 1.1 can return.
+                // 2.6. Notify threads that a packet has been enqueued (or
+                // that an error occurred), so other threads waiting on 1.1
+                // can return.
                 self.reader_condition.notify_all();
 
                 // 2.7. Return the locked `inner` to the caller.
-                Ok(inner)
+                result.map(|()| inner)
             }
         }
     }
 
+    /// Read a packet from the connection, but give up when the given deadline passes.
+    ///
+    /// This works like `read_packet_and_enqueue()`, but returns early if no packet arrived before
+    /// `deadline`. The caller has to check whether the packet it is waiting for was enqueued.
+    ///
+    /// The reader has to support `ReadFD::wait_readable()` and `ReadFD::read_nonblocking()`.
+    /// Failures of `wait_readable()` do not put the connection into an error state, since no data
+    /// was lost. A partially received packet is kept for the next read.
+    fn read_packet_and_enqueue_until<'a>(
+        &'a self,
+        mut inner: MutexGuardInner<'a>,
+        deadline: Instant,
+    ) -> Result<MutexGuardInner<'a>, ConnectionError> {
+        inner.check_error()?;
+
+        match self.read.try_lock() {
+            Err(TryLockError::WouldBlock) => {
+                // Someone else is reading; wait for it to enqueue a packet or for the deadline.
+                match time_until(deadline) {
+                    None => Ok(inner),
+                    Some(timeout) => {
+                        let start = Instant::now();
+                        let (mut inner, _) =
+                            self.reader_condition.wait_timeout(inner, timeout).unwrap();
+                        inner.stats_mut().record_wait(start.elapsed());
+                        Ok(inner)
+                    }
+                }
+            }
+            Err(TryLockError::Poisoned(e)) => panic!("{}", e),
+            Ok(mut lock) => {
+                // Like in `read_packet_and_enqueue()`, but only read what is available, so that a
+                // packet that arrives only partially cannot block us past the deadline
+                drop(inner);
+                let start = Instant::now();
+                let result = loop {
+                    let timeout = time_until(deadline).unwrap_or_else(|| Duration::from_secs(0));
+                    match lock.read.wait_readable(timeout) {
+                        Ok(true) => {}
+                        Ok(false) => break Ok(None),
+                        Err(e) => {
+                            drop(lock);
+                            return Err(e.into());
+                        }
+                    }
+                    match lock.try_read_packet() {
+                        Ok(Some(packet)) => break Ok(Some(packet)),
+                        Ok(None) => {}
+                        Err(e) => break Err(e),
+                    }
+                };
+                let buffered = match result {
+                    Ok(Some(_)) => lock.read_buffered_packets(),
+                    _ => Vec::new(),
+                };
+                inner = self.inner.lock().unwrap();
+                inner.stats_mut().record_wait(start.elapsed());
+                drop(lock);
+                let result = match result {
+                    Ok(Some((packet, fds))) => {
+                        inner.enqueue_packet(packet, fds);
+                        Ok(())
+                    }
+                    Ok(None) => Ok(()),
+                    Err(e) => Err(inner.set_error(io_error(e))),
+                };
+                for (packet, fds) in buffered {
+                    inner.enqueue_packet(packet, fds);
+                }
+                self.reader_condition.notify_all();
+                result.map(|()| inner)
+            }
+        }
+    }
+
+    /// Repeatedly call `poll` until it returns something or the timeout expires.
+    ///
+    /// Between calls to `poll`, packets are read from the connection and enqueued.
+    fn wait_timeout<'a, T>(
+        &'a self,
+        mut inner: MutexGuardInner<'a>,
+        timeout: Duration,
+        mut poll: impl FnMut(&mut inner::ConnectionInner) -> Option<T>,
+    ) -> Result<Option<T>, ConnectionError> {
+        // A timeout that cannot be represented is as good as no timeout at all
+        let deadline = Instant::now().checked_add(timeout);
+        loop {
+            if let Some(result) = poll(&mut inner) {
+                return Ok(Some(result));
+            }
+            inner = match deadline {
+                Some(deadline) => {
+                    if time_until(deadline).is_none() {
+                        return Ok(None);
+                    }
+                    self.read_packet_and_enqueue_until(inner, deadline)?
+                }
+                None => self.read_packet_and_enqueue(inner)?,
+            };
+        }
+    }
+
+    /// Read all packets that are available without blocking and enqueue them.
+    ///
+    /// This function enqueues the packets that are buffered by the reader and then reads from the
+    /// connection until reading would block. Afterwards, all received events can be obtained via
+    /// `Connection::poll_for_event()`. Together with the implementation of `AsRawFd`, this allows
+    /// to integrate a `RustConnection` into an external event loop: Wait for the file descriptor to
+    /// become readable, call this function, and then handle events until `poll_for_event()`
+    /// returns `None`.
+    ///
+    /// Callers must call `poll_for_event()` until it returns `None` after every wakeup. Other
+    /// functions of the connection, e.g. waiting for a reply, may read and enqueue events as well.
+    /// The file descriptor does not become readable again for these events.
+    ///
+    /// If another thread is currently blocked reading from the connection, this function returns
+    /// immediately, since the other thread will enqueue the packets that it receives.
+    ///
+    /// The reader has to support `ReadFD::read_nonblocking()`, which is the case for the streams
+    /// used by `RustConnection::connect()`.
+    pub fn read_available(&self) -> Result<(), ConnectionError> {
+        self.inner.lock().unwrap().check_error()?;
+        let mut read = match self.read.try_lock() {
+            Err(TryLockError::WouldBlock) => return Ok(()),
+            Err(TryLockError::Poisoned(e)) => panic!("{}", e),
+            Ok(lock) => lock,
+        };
+        let result = loop {
+            match read.try_read_packet() {
+                Ok(Some((packet, fds))) => {
+                    let mut inner = self.inner.lock().unwrap();
+                    inner.enqueue_packet(packet, fds);
+                    self.reader_condition.notify_all();
+                }
+                Ok(None) => break Ok(()),
+                Err(e) => break Err(e),
+            }
+        };
+        // Threads that found the reader locked are waiting for us to enqueue a packet. Wake them
+        // up with `inner` locked so that they can start reading themselves.
+        let mut inner = self.inner.lock().unwrap();
+        let result = result.map_err(|e| inner.set_error(io_error(e)));
+        drop(read);
+        self.reader_condition.notify_all();
+        drop(inner);
+        result
+    }
+
     fn prefetch_maximum_request_bytes_impl(&self, max_bytes: &mut MutexGuard<'_, MaxRequestBytes>) {
         if let MaxRequestBytes::Unknown = **max_bytes {
             let request = self
@@ -294,7 +731,7 @@ impl<R: ReadFD, W: WriteFD> RequestConnection for RustConnection<R, W> {
         fds: Vec<RawFdContainer>,
     ) -> Result<Cookie<'_, Self, Reply>, ConnectionError>
     where
-        Reply: for<'a> TryFrom<&'a [u8], Error = ParseError>,
+        Reply: TryParse,
     {
         Ok(Cookie::new(
             self,
@@ -308,7 +745,7 @@ impl<R: ReadFD, W: WriteFD> RequestConnection for RustConnection<R, W> {
         fds: Vec<RawFdContainer>,
     ) -> Result<CookieWithFds<'_, Self, Reply>, ConnectionError>
     where
-        Reply: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error = ParseError>,
+        Reply: TryParseFd,
     {
         Ok(CookieWithFds::new(
             self,
@@ -363,8 +800,8 @@ impl<R: ReadFD, W: WriteFD> RequestConnection for RustConnection<R, W> {
 
     fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Vec<u8>>, ConnectionError> {
         let mut write = self.write.lock().unwrap();
-        let mut inner = self.inner.lock().unwrap();
-        write.flush()?; // Ensure the request is sent
+        let inner = self.inner.lock().unwrap();
+        let mut inner = self.flush_impl(inner, &mut write)?; // Ensure the request is sent
         drop(write);
         loop {
             match inner.poll_for_reply(sequence) {
@@ -382,11 +819,12 @@ impl<R: ReadFD, W: WriteFD> RequestConnection for RustConnection<R, W> {
     ) -> Result<Option<Buffer>, ConnectionError> {
         let mut write = self.write.lock().unwrap();
         let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
         if inner.prepare_check_for_reply_or_error(sequence) {
-            self.send_sync(&mut *inner, &mut *write)?;
+            inner = self.send_sync(inner, &mut *write)?;
             assert!(!inner.prepare_check_for_reply_or_error(sequence));
         }
-        write.flush()?; // Ensure the request is sent
+        let mut inner = self.flush_impl(inner, &mut write)?; // Ensure the request is sent
         drop(write);
         loop {
             match inner.poll_check_for_reply_or_error(sequence) {
@@ -403,8 +841,8 @@ impl<R: ReadFD, W: WriteFD> RequestConnection for RustConnection<R, W> {
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
         let mut write = self.write.lock().unwrap();
-        let mut inner = self.inner.lock().unwrap();
-        write.flush()?; // Ensure the request is sent
+        let inner = self.inner.lock().unwrap();
+        let mut inner = self.flush_impl(inner, &mut write)?; // Ensure the request is sent
         drop(write);
         loop {
             if let Some(reply) = inner.poll_for_reply_or_error(sequence) {
@@ -418,6 +856,39 @@ impl<R: ReadFD, W: WriteFD> RequestConnection for RustConnection<R, W> {
         }
     }
 
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+        Ok(self
+            .wait_for_reply_with_fds_raw_timeout(sequence, timeout)?
+            .map(|reply| match reply {
+                ReplyOrError::Reply((reply, _fds)) => ReplyOrError::Reply(reply),
+                ReplyOrError::Error(e) => ReplyOrError::Error(e),
+            }))
+    }
+
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<ReplyOrError<BufWithFds, Buffer>>, ConnectionError> {
+        let mut write = self.write.lock().unwrap();
+        let inner = self.inner.lock().unwrap();
+        let inner = self.flush_impl(inner, &mut write)?; // Ensure the request is sent
+        drop(write);
+        self.wait_timeout(inner, timeout, |inner| {
+            inner.poll_for_reply_or_error(sequence).map(|reply| {
+                if reply.0[0] == 0 {
+                    ReplyOrError::Error(reply.0)
+                } else {
+                    ReplyOrError::Reply(reply)
+                }
+            })
+        })
+    }
+
     fn maximum_request_bytes(&self) -> usize {
         let mut max_bytes = self.maximum_request_bytes.lock().unwrap();
         self.prefetch_maximum_request_bytes_impl(&mut max_bytes);
@@ -452,12 +923,16 @@ impl<R: ReadFD, W: WriteFD> RequestConnection for RustConnection<R, W> {
         self.prefetch_maximum_request_bytes_impl(&mut max_bytes);
     }
 
+    fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
     fn parse_error<E>(&self, error: E) -> Result<crate::protocol::Error<E>, ParseError>
     where
         E: std::fmt::Debug + AsRef<[u8]>,
     {
         let ext_mgr = self.extension_manager.lock().unwrap();
-        crate::protocol::Error::parse(error, &*ext_mgr)
+        crate::protocol::Error::parse_with_order(error, &*ext_mgr, self.byte_order)
     }
 
     fn parse_event<E>(&self, event: E) -> Result<crate::protocol::Event<E>, ParseError>
@@ -465,13 +940,23 @@ impl<R: ReadFD, W: WriteFD> RequestConnection for RustConnection<R, W> {
         E: std::fmt::Debug + AsRef<[u8]>,
     {
         let ext_mgr = self.extension_manager.lock().unwrap();
-        crate::protocol::Event::parse(event, &*ext_mgr)
+        crate::protocol::Event::parse_with_order(event, &*ext_mgr, self.byte_order)
+    }
+}
+
+#[cfg(unix)]
+impl<R: ReadFD, W: WriteFD + AsRawFd> AsRawFd for RustConnection<R, W> {
+    fn as_raw_fd(&self) -> RawFd {
+        // The reader might be locked while another thread blocks in a read. Writes only block
+        // until the X11 server catches up, so use the writer instead.
+        self.write.lock().unwrap().as_raw_fd()
     }
 }
 
 impl<R: ReadFD, W: WriteFD> Connection for RustConnection<R, W> {
     fn wait_for_raw_event_with_sequence(&self) -> Result<RawEventAndSeqNumber, ConnectionError> {
         let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
         loop {
             if let Some(event) = inner.poll_for_event_with_sequence() {
                 return Ok(event);
@@ -480,15 +965,67 @@ impl<R: ReadFD, W: WriteFD> Connection for RustConnection<R, W> {
         }
     }
 
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        let inner = self.inner.lock().unwrap();
+        inner.check_error()?;
+        self.wait_timeout(inner, timeout, |inner| inner.poll_for_event_with_sequence())
+    }
+
     fn poll_for_raw_event_with_sequence(
         &self,
     ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
-        Ok(self.inner.lock().unwrap().poll_for_event_with_sequence())
+        let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
+        Ok(inner.poll_for_event_with_sequence())
+    }
+
+    fn register_for_special_xge(
+        &self,
+        extension_name: &'static str,
+        eid: u32,
+    ) -> Result<SpecialEventQueue, ConnectionError> {
+        let ext_info = self
+            .extension_information(extension_name)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let queue = SpecialEventQueue::new(ext_info.major_opcode, eid);
+        self.inner.lock().unwrap().register_special_event(queue);
+        Ok(queue)
+    }
+
+    fn unregister_for_special_xge(&self, queue: SpecialEventQueue) {
+        self.inner.lock().unwrap().unregister_special_event(queue);
+    }
+
+    fn wait_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
+        loop {
+            if let Some(event) = inner.poll_for_special_event_with_sequence(queue)? {
+                return Ok(event);
+            }
+            inner = self.read_packet_and_enqueue(inner)?;
+        }
+    }
+
+    fn poll_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.check_error()?;
+        inner.poll_for_special_event_with_sequence(queue)
     }
 
     fn flush(&self) -> Result<(), ConnectionError> {
-        self.write.lock().unwrap().flush()?;
-        Ok(())
+        let mut write = self.write.lock().unwrap();
+        let inner = self.inner.lock().unwrap();
+        self.flush_impl(inner, &mut write).map(drop)
     }
 
     fn setup(&self) -> &Setup {
@@ -500,41 +1037,151 @@ impl<R: ReadFD, W: WriteFD> Connection for RustConnection<R, W> {
     }
 }
 
-// Read a single X11 packet from the connection.
-//
-// This function only supports errors, events, and replies. Namely, this cannot be used to receive
-// the initial setup reply from the X11 server.
-fn read_packet(read: &mut impl ReadFD) -> Result<(Vec<u8>, Vec<RawFdContainer>), std::io::Error> {
-    let mut fds = Vec::new();
-    let mut buffer = vec![0; 32];
-    read.read_exact(&mut buffer, &mut fds)?;
+/// A reader for X11 packets.
+///
+/// This wraps a `ReadFD` and remembers partially received packets. This allows non-blocking
+/// reads to stop in the middle of a packet and a later read to continue where it left off.
+///
+/// Only errors, events, and replies are supported. Namely, this cannot be used to receive the
+/// initial setup reply from the X11 server.
+#[derive(Debug)]
+struct PacketReader<R> {
+    read: R,
+    // The byte order of the packets
+    order: ByteOrder,
+    // The bytes of the packet that is currently being received
+    packet: Vec<u8>,
+    // FDs that were received together with the packet
+    fds: Vec<RawFdContainer>,
+}
+
+impl<R: ReadFD> PacketReader<R> {
+    fn new(read: R, order: ByteOrder) -> Self {
+        Self {
+            read,
+            order,
+            packet: Vec::new(),
+            fds: Vec::new(),
+        }
+    }
+
+    /// Read a single X11 packet, blocking until it is completely received.
+    fn read_packet(&mut self) -> Result<(Vec<u8>, Vec<RawFdContainer>), std::io::Error> {
+        Ok(self
+            .read_packet_impl(false)?
+            .expect("Blocking reads cannot fail with WouldBlock"))
+    }
+
+    /// Read the packets that the reader already buffered, without blocking.
+    ///
+    /// Errors are ignored, since this reading is only opportunistic. No data is lost in this case
+    /// and the next read reports the error again.
+    fn read_buffered_packets(&mut self) -> Vec<(Vec<u8>, Vec<RawFdContainer>)> {
+        let mut packets = Vec::new();
+        while self.read.has_buffered_data() {
+            match self.try_read_packet() {
+                Ok(Some(packet)) => packets.push(packet),
+                Ok(None) | Err(_) => break,
+            }
+        }
+        packets
+    }
+
+    /// Read a single X11 packet without blocking.
+    ///
+    /// Returns `None` if the packet was not yet completely received.
+    fn try_read_packet(&mut self) -> Result<Option<BufWithFds>, std::io::Error> {
+        self.read_packet_impl(true)
+    }
+
+    fn read_packet_impl(
+        &mut self,
+        nonblocking: bool,
+    ) -> Result<Option<BufWithFds>, std::io::Error> {
+        use std::io::{Error, ErrorKind};
+        loop {
+            let length = if self.packet.len() < 32 {
+                32
+            } else {
+                packet_length(&self.packet, self.order)
+            };
+            let old_length = self.packet.len();
+            if old_length == length {
+                let packet = std::mem::replace(&mut self.packet, Vec::new());
+                let fds = std::mem::replace(&mut self.fds, Vec::new());
+                return Ok(Some((packet, fds)));
+            }
+
+            // Use `Vec::reserve_exact` because this will be the final
+            // length of the vector.
+            self.packet.reserve_exact(length - old_length);
+            self.packet.resize(length, 0);
+            let buf = &mut self.packet[old_length..];
+            let result = if nonblocking {
+                self.read.read_nonblocking(buf, &mut self.fds)
+            } else {
+                self.read.read(buf, &mut self.fds)
+            };
+            match result {
+                Ok(0) => {
+                    self.packet.truncate(old_length);
+                    return Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "failed to fill the whole buffer",
+                    ));
+                }
+                Ok(n) => self.packet.truncate(old_length + n),
+                Err(e) => {
+                    self.packet.truncate(old_length);
+                    match e.kind() {
+                        ErrorKind::Interrupted => {}
+                        ErrorKind::WouldBlock if nonblocking => return Ok(None),
+                        _ => return Err(e),
+                    }
+                }
+            }
+        }
+    }
+}
 
+// Get the time that remains until the given deadline, or `None` if it already passed.
+fn time_until(deadline: Instant) -> Option<Duration> {
+    let now = Instant::now();
+    if now < deadline {
+        Some(deadline - now)
+    } else {
+        None
+    }
+}
+
+// Get the total length of an X11 packet from its first 32 bytes.
+//
+// Most packets have exactly 32 bytes, but replies and generic events have a length field that
+// describes the amount of data that follows.
+pub(crate) fn packet_length(header: &[u8], order: ByteOrder) -> usize {
     use crate::protocol::xproto::GE_GENERIC_EVENT;
     const REPLY: u8 = 1;
     const SENT_GE_GENERIC_EVENT: u8 = GE_GENERIC_EVENT | 0x80;
-    let extra_length = match buffer[0] {
+    let extra_length = match header[0] {
         REPLY | GE_GENERIC_EVENT | SENT_GE_GENERIC_EVENT => {
-            4 * u32::from_ne_bytes([buffer[4], buffer[5], buffer[6], buffer[7]])
+            let (length, _) = u32::try_parse_with_order(&header[4..8], order).unwrap();
+            4 * length
         }
         _ => 0,
     } as usize;
-    // Use `Vec::reserve_exact` because this will be the final
-    // length of the vector.
-    buffer.reserve_exact(extra_length);
-    buffer.resize(32 + extra_length, 0);
-    read.read_exact(&mut buffer[32..], &mut fds)?;
-
-    Ok((buffer, fds))
+    32 + extra_length
 }
 
-#[cfg(target_endian = "little")]
-fn byte_order() -> u8 {
-    0x6c
-}
-
-#[cfg(target_endian = "big")]
-fn byte_order() -> u8 {
-    0x42
+/// Get the `SetupRequest` that is sent to the X11 server, with the given authorization
+/// information and byte order.
+fn setup_request(auth_name: Vec<u8>, auth_data: Vec<u8>, order: ByteOrder) -> SetupRequest {
+    SetupRequest {
+        byte_order: order.to_setup_byte(),
+        protocol_major_version: 11,
+        protocol_minor_version: 0,
+        authorization_protocol_name: auth_name,
+        authorization_protocol_data: auth_data,
+    }
 }
 
 /// Send a `SetupRequest` to the X11 server.
@@ -542,15 +1189,10 @@ fn write_setup(
     write: &mut impl WriteFD,
     auth_name: Vec<u8>,
     auth_data: Vec<u8>,
+    order: ByteOrder,
 ) -> Result<(), std::io::Error> {
-    let request = SetupRequest {
-        byte_order: byte_order(),
-        protocol_major_version: 11,
-        protocol_minor_version: 0,
-        authorization_protocol_name: auth_name,
-        authorization_protocol_data: auth_data,
-    };
-    write.write_all(&request.serialize(), Vec::new())?;
+    let request = setup_request(auth_name, auth_data, order);
+    write.write_all(&request.serialize_with_order(order), Vec::new())?;
     write.flush()?;
     Ok(())
 }
@@ -559,16 +1201,34 @@ fn write_setup(
 ///
 /// If the server sends a `SetupFailed` or `SetupAuthenticate` packet, these will be returned
 /// as errors.
-fn read_setup(read: &mut impl ReadFD) -> Result<Setup, ConnectError> {
+fn read_setup(read: &mut impl ReadFD, order: ByteOrder) -> Result<Setup, ConnectError> {
     let mut fds = Vec::new();
     let mut setup = vec![0; 8];
     read.read_exact(&mut setup, &mut fds)?;
-    let extra_length = usize::from(u16::from_ne_bytes([setup[6], setup[7]])) * 4;
+    let length = setup_length(&setup, order);
     // Use `Vec::reserve_exact` because this will be the final
     // length of the vector.
-    setup.reserve_exact(extra_length);
-    setup.resize(8 + extra_length, 0);
+    setup.reserve_exact(length - 8);
+    setup.resize(length, 0);
     read.read_exact(&mut setup[8..], &mut fds)?;
+    parse_setup(&setup, &fds, order)
+}
+
+/// Get the total length of the server's answer to the `SetupRequest` from its first eight bytes.
+pub(crate) fn setup_length(header: &[u8], order: ByteOrder) -> usize {
+    let (length, _) = u16::try_parse_with_order(&header[6..8], order).unwrap();
+    8 + usize::from(length) * 4
+}
+
+/// Parse the server's answer to the `SetupRequest`.
+///
+/// `fds` are the file descriptors that were received together with the answer. Since the X11
+/// server never sends any during connection setup, this is treated as an error.
+fn parse_setup(
+    setup: &[u8],
+    fds: &[RawFdContainer],
+    order: ByteOrder,
+) -> Result<Setup, ConnectError> {
     if !fds.is_empty() {
         return Err(std::io::Error::new(
             std::io::ErrorKind::Other,
@@ -578,65 +1238,106 @@ fn read_setup(read: &mut impl ReadFD) -> Result<Setup, ConnectError> {
     }
     match setup[0] {
         // 0 is SetupFailed
-        0 => Err(ConnectError::SetupFailed((&setup[..]).try_into()?)),
+        0 => Err(ConnectError::SetupFailed(
+            TryParse::try_parse_with_order(setup, order)?.0,
+        )),
         // Success
-        1 => Ok((&setup[..]).try_into()?),
+        1 => Ok(TryParse::try_parse_with_order(setup, order)?.0),
         // 2 is SetupAuthenticate
-        2 => Err(ConnectError::SetupAuthenticate((&setup[..]).try_into()?)),
+        2 => Err(ConnectError::SetupAuthenticate(
+            TryParse::try_parse_with_order(setup, order)?.0,
+        )),
         // Uhm... no other cases are defined
         _ => Err(ParseError::ParseError.into()),
     }
 }
 
+/// Convert an I/O error from reading or writing the X11 connection into a `ConnectionError`.
+///
+/// Hitting end of file while reading and writing to a socket whose other end was closed mean that
+/// the X11 server closed the connection. These are reported as `ConnectionError::ConnectionClosed`.
+fn io_error(err: std::io::Error) -> ConnectionError {
+    use std::io::ErrorKind;
+    match err.kind() {
+        ErrorKind::UnexpectedEof | ErrorKind::BrokenPipe => ConnectionError::ConnectionClosed,
+        _ => ConnectionError::IOError(err),
+    }
+}
+
 /// Write a set of buffers on a Writer.
 ///
-/// This is basically `Write::write_all_vectored`, but on stable and for `WriteFD`.
-fn write_all_vectored(
-    write: &mut impl WriteFD,
-    bufs: &[IoSlice<'_>],
+/// This is basically `Write::write_all_vectored`, but on stable and for `WriteFD`. Writing is done
+/// via `WriteFD::write_vectored_nonblocking()`. Whenever this would block, `wait` is called before
+/// trying again.
+fn write_all_vectored<W: WriteFD>(
+    write: &mut W,
+    mut bufs: &[IoSlice<'_>],
     mut fds: Vec<RawFdContainer>,
-) -> Result<(), std::io::Error> {
-    let mut bufs = bufs;
-    while !bufs.is_empty() {
-        let mut count = write.write_vectored(bufs, &mut fds)?;
-        if count == 0 {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::WriteZero,
-                "failed to write anything",
-            ));
-        }
-        while count > 0 {
-            if count >= bufs[0].len() {
-                count -= bufs[0].len();
-            } else {
-                let remaining = &bufs[0][count..];
-                write.write_all(remaining, fds)?;
-                fds = Vec::new();
-                count = 0;
-            }
+    mut wait: impl FnMut(&mut W) -> Result<(), ConnectionError>,
+) -> Result<(), ConnectionError> {
+    use std::io::{Error, ErrorKind};
+
+    // The number of bytes of bufs[0] that were already written
+    let mut offset = 0;
+    loop {
+        // Skip completely written and empty slices
+        while bufs.first().map(|s| s.len()) == Some(offset) {
             bufs = &bufs[1..];
+            offset = 0;
+        }
+        if bufs.is_empty() {
+            break;
+        }
 
-            // Skip empty slices
-            while bufs.first().map(|s| s.len()) == Some(0) {
-                bufs = &bufs[1..];
+        let result = if offset == 0 {
+            write.write_vectored_nonblocking(bufs, &mut fds)
+        } else {
+            let mut remaining = vec![IoSlice::new(&bufs[0][offset..])];
+            remaining.extend(bufs[1..].iter().map(|buf| IoSlice::new(buf)));
+            write.write_vectored_nonblocking(&remaining, &mut fds)
+        };
+        match result {
+            Ok(0) => {
+                let msg = if offset == 0 {
+                    "failed to write anything"
+                } else {
+                    "failed to write whole buffer"
+                };
+                return Err(io_error(Error::new(ErrorKind::WriteZero, msg)));
+            }
+            Ok(mut count) => {
+                while count > 0 {
+                    let remaining = bufs[0].len() - offset;
+                    if count >= remaining {
+                        count -= remaining;
+                        bufs = &bufs[1..];
+                        offset = 0;
+                    } else {
+                        offset += count;
+                        count = 0;
+                    }
+                }
             }
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(ref e) if e.kind() == ErrorKind::WouldBlock => wait(write)?,
+            Err(e) => return Err(io_error(e)),
         }
     }
     if !fds.is_empty() {
-        write.write_all(&[], fds)?;
+        write.write_all(&[], fds).map_err(io_error)?;
     }
     Ok(())
 }
 
 #[cfg(test)]
-mod test {
-    use std::io::{IoSlice, Read, Result, Write};
+pub(crate) mod test {
+    use std::io::{Error, ErrorKind, IoSlice, Read, Result, Write};
 
-    use super::{read_setup, write_all_vectored, ReadFD, WriteFD};
+    use super::{read_setup, write_all_vectored, PacketReader, ReadFD, WriteFD};
     use crate::errors::ConnectError;
     use crate::protocol::xproto::{ImageOrder, Setup, SetupAuthenticate, SetupFailed};
     use crate::utils::RawFdContainer;
-    use crate::x11_utils::Serialize;
+    use crate::x11_utils::{ByteOrder, Serialize};
 
     struct WriteFDSlice<'a>(&'a mut [u8]);
 
@@ -659,12 +1360,138 @@ mod test {
         }
     }
 
+    /// Get a `Setup` without any screens for connections that do not talk to a real X11 server.
+    pub(crate) fn test_setup() -> Setup {
+        let mut setup = Setup {
+            status: 1,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: 0,
+            resource_id_mask: 0xff,
+            motion_buffer_size: 0,
+            maximum_request_length: 0,
+            image_byte_order: ImageOrder::LSBFirst,
+            bitmap_format_bit_order: ImageOrder::LSBFirst,
+            bitmap_format_scanline_unit: 0,
+            bitmap_format_scanline_pad: 0,
+            min_keycode: 0,
+            max_keycode: 0,
+            vendor: vec![],
+            pixmap_formats: vec![],
+            roots: vec![],
+        };
+        setup.length = ((setup.serialize().len() - 8) / 4) as _;
+        setup
+    }
+
+    /// Get a `Setup` like `test_setup()`, but with a single screen.
+    pub(crate) fn test_setup_with_screen() -> Setup {
+        use crate::protocol::xproto::{BackingStore, Screen};
+
+        let screen = Screen {
+            root: 0x42.into(),
+            default_colormap: 0.into(),
+            white_pixel: 0,
+            black_pixel: 0,
+            current_input_masks: 0.into(),
+            width_in_pixels: 0,
+            height_in_pixels: 0,
+            width_in_millimeters: 0,
+            height_in_millimeters: 0,
+            min_installed_maps: 0,
+            max_installed_maps: 0,
+            root_visual: 0,
+            backing_stores: BackingStore::NotUseful,
+            save_unders: false,
+            root_depth: 0,
+            allowed_depths: vec![],
+        };
+        let mut setup = test_setup();
+        setup.roots.push(screen);
+        setup.length = ((setup.serialize().len() - 8) / 4) as _;
+        setup
+    }
+
+    /// Get a `RustConnection` and the other end of its Unix socket, which plays the X11 server.
+    #[cfg(unix)]
+    pub(crate) fn connected_pair() -> (
+        super::RustConnection<super::stream::Stream, super::stream::Stream>,
+        std::os::unix::net::UnixStream,
+    ) {
+        use super::stream::Stream;
+        use super::RustConnection;
+        use std::os::unix::net::UnixStream;
+
+        let (client, server) = UnixStream::pair().unwrap();
+        let read = Stream::UnixStream(client.try_clone().unwrap());
+        let write = Stream::UnixStream(client);
+        let conn = RustConnection::for_connected_stream(read, write, test_setup()).unwrap();
+        (conn, server)
+    }
+
+    // A reader that returns the given chunks and afterwards fails with WouldBlock
+    struct ChunkedReader(Vec<Vec<u8>>);
+
+    impl ReadFD for ChunkedReader {
+        fn read(
+            &mut self,
+            _buf: &mut [u8],
+            _fd_storage: &mut Vec<RawFdContainer>,
+        ) -> Result<usize> {
+            unimplemented!()
+        }
+
+        fn read_nonblocking(
+            &mut self,
+            buf: &mut [u8],
+            _fd_storage: &mut Vec<RawFdContainer>,
+        ) -> Result<usize> {
+            if self.0.is_empty() {
+                return Err(Error::new(ErrorKind::WouldBlock, "no data"));
+            }
+            let chunk = self.0.remove(0);
+            assert!(chunk.len() <= buf.len());
+            buf[..chunk.len()].copy_from_slice(&chunk);
+            Ok(chunk.len())
+        }
+    }
+
+    #[test]
+    fn try_read_packet_partial() {
+        let mut reader = PacketReader::new(ChunkedReader(vec![vec![2; 10]]), ByteOrder::native());
+        assert!(reader.try_read_packet().unwrap().is_none());
+        reader.read.0.push(vec![3; 22]);
+        let (packet, fds) = reader.try_read_packet().unwrap().unwrap();
+        assert_eq!(packet[..10], [2; 10]);
+        assert_eq!(packet[10..], [3; 22]);
+        assert!(fds.is_empty());
+        assert!(reader.try_read_packet().unwrap().is_none());
+    }
+
+    #[test]
+    fn try_read_packet_reply() {
+        // A reply with one additional 4 byte unit of data
+        let mut header = vec![1, 0, 0, 0];
+        header.extend_from_slice(&1u32.to_ne_bytes());
+        header.resize(32, 0);
+        let mut reader =
+            PacketReader::new(ChunkedReader(vec![header, vec![4; 2]]), ByteOrder::native());
+        assert!(reader.try_read_packet().unwrap().is_none());
+        reader.read.0.push(vec![5; 2]);
+        let (packet, _) = reader.try_read_packet().unwrap().unwrap();
+        assert_eq!(packet.len(), 36);
+        assert_eq!(packet[32..], [4, 4, 5, 5]);
+    }
+
     fn partial_write_test(request: &[u8], expected_err: &str) {
         let mut written = [0x21; 2];
         let mut output = &mut written[..];
         let request = [IoSlice::new(&request), IoSlice::new(&request)];
         let mut writer = WriteFDSlice(&mut output);
-        let error = write_all_vectored(&mut writer, &request, Vec::new()).unwrap_err();
+        let error =
+            write_all_vectored(&mut writer, &request, Vec::new(), |_| unreachable!()).unwrap_err();
         assert_eq!(expected_err, error.to_string());
     }
 
@@ -687,7 +1514,7 @@ mod test {
         let (request1, request2) = ([0; 4], [0; 0]);
         let request = [IoSlice::new(&request1), IoSlice::new(&request2)];
         let mut writer = WriteFDSlice(&mut output);
-        write_all_vectored(&mut writer, &request, Vec::new()).unwrap();
+        write_all_vectored(&mut writer, &request, Vec::new(), |_| unreachable!()).unwrap();
     }
 
     #[test]
@@ -716,10 +1543,307 @@ mod test {
         let setup_bytes = setup.serialize();
 
         let mut reader = ReadFDSlice(&setup_bytes[..]);
-        let read = read_setup(&mut reader);
+        let read = read_setup(&mut reader, ByteOrder::native());
         assert_eq!(setup, read.unwrap());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn read_available() {
+        use super::stream::Stream;
+        use super::{BufReadFD, BufWriteFD, RustConnection};
+        use crate::connection::Connection;
+        use crate::protocol::xproto::ConnectionExt as _;
+        use std::os::unix::net::UnixStream;
+
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let read = BufReadFD::new(Stream::UnixStream(client.try_clone().unwrap()));
+        let write = BufWriteFD::new(Stream::UnixStream(client));
+        let conn = RustConnection::for_connected_stream(read, write, test_setup()).unwrap();
+
+        let event = |detail: u8| {
+            let mut event = vec![2, detail];
+            event.extend_from_slice(&0u16.to_ne_bytes());
+            event.resize(32, 0);
+            event
+        };
+
+        // Two events arrive in a single write, so the reader buffers both of them
+        server.write_all(&[event(1), event(2)].concat()).unwrap();
+        conn.read_available().unwrap();
+        assert_eq!(conn.poll_for_raw_event().unwrap().unwrap()[1], 1);
+        assert_eq!(conn.poll_for_raw_event().unwrap().unwrap()[1], 2);
+        assert!(conn.poll_for_raw_event().unwrap().is_none());
+
+        // An event arrives together with a reply. Waiting for the reply also enqueues the event,
+        // although it is only in the buffer of the reader and not readable from the socket.
+        let cookie = conn.get_input_focus().unwrap();
+        let mut reply = vec![1, 0];
+        reply.extend_from_slice(&1u16.to_ne_bytes());
+        reply.resize(32, 0);
+        server
+            .write_all(&[reply, event(3), event(4)].concat())
+            .unwrap();
+        let _ = cookie.reply().unwrap();
+        assert_eq!(conn.poll_for_raw_event().unwrap().unwrap()[1], 3);
+        assert_eq!(conn.poll_for_raw_event().unwrap().unwrap()[1], 4);
+        assert!(conn.poll_for_raw_event().unwrap().is_none());
+        assert!(conn.has_error().is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn connect_to_unix_stream() {
+        use super::RustConnection;
+        use crate::connection::Connection;
+        use std::os::unix::net::UnixStream;
+
+        let setup = test_setup_with_screen();
+        let setup_bytes = setup.serialize();
+
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let server = std::thread::spawn(move || {
+            // The setup request with "name" and "data" as auth info, each padded to four bytes
+            let mut request = [0; 20];
+            server.read_exact(&mut request).unwrap();
+            assert_eq!(request[6..8], 4u16.to_ne_bytes());
+            assert_eq!(request[8..10], 4u16.to_ne_bytes());
+            assert_eq!(&request[12..], b"namedata");
+            server.write_all(&setup_bytes).unwrap();
+        });
+
+        let auth_info = Some((b"name".to_vec(), b"data".to_vec()));
+        let (conn, screen) = RustConnection::connect_to_unix_stream(client, 0, auth_info).unwrap();
+        server.join().unwrap();
+        assert_eq!(screen, 0);
+        assert_eq!(conn.setup(), &setup);
+    }
+
+    #[cfg(all(unix, feature = "allow-unsafe-code"))]
+    #[test]
+    fn connect_to_fd_not_a_socket() {
+        use super::RustConnection;
+
+        let file = std::fs::File::open("/dev/null").unwrap();
+        match RustConnection::connect_to_fd(file.into(), 0, None) {
+            Err(ConnectError::IOError(e)) => assert_eq!(e.kind(), ErrorKind::InvalidInput),
+            value => panic!("Unexpected value {:?}", value.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn error_state() {
+        use super::RustConnection;
+        use crate::connection::Connection;
+        use crate::errors::ConnectionError;
+        use crate::protocol::xproto::ConnectionExt as _;
+
+        // The server closes the connection immediately
+        let mut output = [0; 32];
+        let conn = RustConnection::for_connected_stream(
+            ReadFDSlice(&[]),
+            WriteFDSlice(&mut output),
+            test_setup(),
+        )
+        .unwrap();
+        assert!(conn.has_error().is_none());
+        assert!(conn.poll_for_event().unwrap().is_none());
+
+        let is_eof = |result: std::result::Result<(), ConnectionError>| match result {
+            Err(ConnectionError::ConnectionClosed) => true,
+            _ => false,
+        };
+        assert!(is_eof(conn.wait_for_event().map(|_| ())));
+
+        // All further operations fail with the same error
+        assert!(is_eof(conn.has_error().map_or(Ok(()), Err)));
+        assert!(is_eof(conn.poll_for_event().map(|_| ())));
+        assert!(is_eof(conn.flush()));
+        assert!(is_eof(conn.no_operation().map(|_| ())));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn timeouts() {
+        use crate::connection::Connection;
+        use crate::protocol::xproto::ConnectionExt as _;
+        use std::time::Duration;
+
+        let (conn, mut server) = connected_pair();
+
+        // Nothing arrives, so the timeouts expire
+        let short = Duration::from_millis(10);
+        let long = Duration::from_secs(10);
+        assert!(conn.wait_for_raw_event_timeout(short).unwrap().is_none());
+        let cookie = conn.get_input_focus().unwrap();
+        assert_eq!(cookie.sequence_number(), 1);
+        let first_cookie = cookie.reply_timeout(short).unwrap().unwrap_err();
+
+        // Only a part of a packet arrives, so the timeout still expires
+        let mut event = vec![2, 0];
+        event.extend_from_slice(&1u16.to_ne_bytes());
+        event.resize(32, 0);
+        server.write_all(&event[..1]).unwrap();
+        assert!(conn.wait_for_raw_event_timeout(short).unwrap().is_none());
+        server.write_all(&event[1..]).unwrap();
+        let event = conn.wait_for_raw_event_timeout(long).unwrap().unwrap();
+        assert_eq!(event[0], 2);
+
+        // The replies to both requests and an event arrive. The cookie that timed out can still
+        // be used to get the late reply.
+        let cookie = conn.get_input_focus().unwrap();
+        let reply = |sequence: u16| {
+            let mut reply = vec![1, 0];
+            reply.extend_from_slice(&sequence.to_ne_bytes());
+            reply.extend_from_slice(&0u32.to_ne_bytes());
+            reply.extend_from_slice(&(0x40u32 + u32::from(sequence)).to_ne_bytes());
+            reply.resize(32, 0);
+            reply
+        };
+        let mut event = vec![2, 0];
+        event.extend_from_slice(&2u16.to_ne_bytes());
+        event.resize(32, 0);
+        server.write_all(&reply(1)).unwrap();
+        server.write_all(&reply(2)).unwrap();
+        server.write_all(&event).unwrap();
+
+        let reply = cookie.reply_timeout(long).unwrap().unwrap();
+        assert_eq!(u32::from(reply.focus), 0x42);
+        let reply = first_cookie.reply_timeout(long).unwrap().unwrap();
+        assert_eq!(u32::from(reply.focus), 0x41);
+        let (event, sequence) = conn
+            .wait_for_raw_event_with_sequence_timeout(long)
+            .unwrap()
+            .unwrap();
+        assert_eq!(event[0], 2);
+        assert_eq!(sequence, 2);
+        assert!(conn.poll_for_raw_event().unwrap().is_none());
+        assert!(conn.has_error().is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stats() {
+        use crate::protocol::xproto::ConnectionExt as _;
+
+        let (conn, mut server) = connected_pair();
+        assert_eq!(conn.stats(), Default::default());
+
+        let cookie = conn.get_input_focus().unwrap();
+        let stats = conn.stats();
+        assert_eq!(stats.requests_sent, 1);
+        assert_eq!(stats.bytes_written, 4);
+        assert_eq!(stats.in_flight_requests, 1);
+
+        let mut reply = vec![1, 0];
+        reply.extend_from_slice(&1u16.to_ne_bytes());
+        reply.resize(32, 0);
+        server.write_all(&reply).unwrap();
+        let _ = cookie.reply().unwrap();
+
+        let stats = conn.stats();
+        assert_eq!(
+            stats.requests_by_opcode.into_iter().collect::<Vec<_>>(),
+            vec![((43, 0), 1)]
+        );
+        assert_eq!(stats.bytes_read, 32);
+        assert_eq!(stats.in_flight_requests, 0);
+        assert_eq!(stats.blocking_waits, 1);
+        assert_eq!(stats.implicit_syncs, 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_while_writing() {
+        use crate::connection::{Connection, RequestConnection};
+        use std::io::IoSlice;
+
+        const EVENTS: usize = 1 << 16;
+        const REQUESTS: usize = 16;
+        const REQUEST_WORDS: u16 = 0x4000;
+
+        let (conn, mut server) = connected_pair();
+
+        // The server sends more events than fit into the socket buffers before it reads anything.
+        // Writing the requests would deadlock if the client did not read in the meantime.
+        let server = std::thread::spawn(move || {
+            let mut event = vec![0; 32 * EVENTS];
+            for chunk in event.chunks_mut(32) {
+                chunk[0] = 19;
+            }
+            server.write_all(&event).unwrap();
+            let mut requests = vec![0; REQUESTS * 4 * usize::from(REQUEST_WORDS)];
+            server.read_exact(&mut requests).unwrap();
+            requests
+        });
+
+        // NoOperation requests with a lot of padding
+        let mut request = vec![127, 0];
+        request.extend_from_slice(&REQUEST_WORDS.to_ne_bytes());
+        request.resize(4 * usize::from(REQUEST_WORDS), 0);
+        for _ in 0..REQUESTS {
+            let _ = conn
+                .send_request_without_reply(&[IoSlice::new(&request)], Vec::new())
+                .unwrap();
+        }
+        conn.flush().unwrap();
+
+        let requests = server.join().unwrap();
+        assert!(requests.chunks(request.len()).all(|r| r == &request[..]));
+        let mut events = 0;
+        while conn.poll_for_raw_event().unwrap().is_some() {
+            events += 1;
+        }
+        assert_eq!(events, EVENTS);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_native_byte_order() {
+        use super::stream::Stream;
+        use super::RustConnection;
+        use crate::connection::RequestConnection;
+        use crate::protocol::xproto::ConnectionExt as _;
+        use std::os::unix::net::UnixStream;
+
+        let order = match ByteOrder::native() {
+            ByteOrder::LSBFirst => ByteOrder::MSBFirst,
+            ByteOrder::MSBFirst => ByteOrder::LSBFirst,
+        };
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let read = Stream::UnixStream(client.try_clone().unwrap());
+        let write = Stream::UnixStream(client);
+        let conn =
+            RustConnection::for_connected_stream_with_byte_order(read, write, test_setup(), order)
+                .unwrap();
+        assert_eq!(conn.byte_order(), order);
+
+        // The request is sent in the connection's byte order
+        let cookie = conn.get_geometry(0x1234_5678).unwrap();
+        let mut request = [0; 8];
+        server.read_exact(&mut request).unwrap();
+        let mut expected = vec![14, 0];
+        expected.extend_from_slice(&2u16.serialize_with_order(order));
+        expected.extend_from_slice(&0x1234_5678u32.serialize_with_order(order));
+        assert_eq!(&request[..], &expected[..]);
+
+        // ...and so is the reply
+        let mut reply = vec![1, 24];
+        reply.extend_from_slice(&1u16.serialize_with_order(order));
+        reply.extend_from_slice(&0u32.serialize_with_order(order));
+        reply.extend_from_slice(&0x100u32.serialize_with_order(order)); // root
+        reply.extend_from_slice(&(-1i16).serialize_with_order(order)); // x
+        reply.extend_from_slice(&2i16.serialize_with_order(order)); // y
+        reply.extend_from_slice(&300u16.serialize_with_order(order)); // width
+        reply.extend_from_slice(&400u16.serialize_with_order(order)); // height
+        reply.resize(32, 0);
+        server.write_all(&reply).unwrap();
+        let reply = cookie.reply().unwrap();
+        assert_eq!((reply.depth, u32::from(reply.root)), (24, 0x100));
+        assert_eq!((reply.x, reply.y), (-1, 2));
+        assert_eq!((reply.width, reply.height), (300, 400));
+    }
+
     #[test]
     fn read_setup_failed() {
         let mut setup = SetupFailed {
@@ -733,7 +1857,7 @@ mod test {
         let setup_bytes = setup.serialize();
 
         let mut reader = ReadFDSlice(&setup_bytes[..]);
-        match read_setup(&mut reader) {
+        match read_setup(&mut reader, ByteOrder::native()) {
             Err(ConnectError::SetupFailed(read)) => assert_eq!(setup, read),
             value => panic!("Unexpected value {:?}", value),
         }
@@ -748,7 +1872,7 @@ mod test {
         let setup_bytes = setup.serialize();
 
         let mut reader = ReadFDSlice(&setup_bytes[..]);
-        match read_setup(&mut reader) {
+        match read_setup(&mut reader, ByteOrder::native()) {
             Err(ConnectError::SetupAuthenticate(read)) => assert_eq!(setup, read),
             value => panic!("Unexpected value {:?}", value),
         }
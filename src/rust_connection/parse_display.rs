@@ -1,12 +1,82 @@
+//! Parsing of display names like `$DISPLAY`.
+
+use std::fmt;
+use std::net::Ipv6Addr;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::errors::ConnectError;
+
+/// A parsed display name, for example the value of `$DISPLAY`.
+///
+/// The syntax follows libxcb's `xcb_parse_display()`. The general form is
+/// `[protocol/]host:display[.screen]`, for example `:0`, `unix:1`, `tcp/localhost:0.1` or
+/// `[::1]:0`. The path to a Unix socket (e.g. `/tmp/.X11-unix/X0` or a launchd socket on macOS)
+/// is also accepted and can be followed by `:display[.screen]`. In this case, the path is used as
+/// `host` with protocol `unix`.
+///
+/// Formatting a `DisplayName` produces a string that parses back to the same value. For socket
+/// paths, this only holds while the socket exists, since parsing checks the file system.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct ParsedDisplay {
-    pub(crate) host: String,
-    pub(crate) protocol: Option<String>,
-    pub(crate) display: u16,
-    pub(crate) screen: u16,
+pub struct DisplayName {
+    /// The host to connect to.
+    ///
+    /// This is empty for a local connection. Brackets around IPv6 addresses are removed.
+    pub host: String,
+    /// The protocol to use, for example `unix` or `tcp`, if one was explicitly given.
+    pub protocol: Option<String>,
+    /// The number of the display on the host.
+    pub display: u16,
+    /// The screen that should be used by default.
+    pub screen: u16,
+}
+
+impl DisplayName {
+    /// Parse the value of the `$DISPLAY` environment variable.
+    pub fn from_env() -> Result<Self, ConnectError> {
+        parse_display(None).ok_or(ConnectError::DisplayParsingError)
+    }
+
+    /// Get the path to the Unix socket if this display was given as a socket path.
+    pub fn socket_path(&self) -> Option<&Path> {
+        if self.protocol.as_ref().map(|p| &**p) == Some("unix") && self.host.starts_with('/') {
+            Some(Path::new(&self.host))
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for DisplayName {
+    type Err = ConnectError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_display_impl(s).ok_or(ConnectError::DisplayParsingError)
+    }
 }
 
-pub(crate) fn parse_display(dpy_name: Option<&str>) -> Option<ParsedDisplay> {
+impl fmt::Display for DisplayName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.socket_path().is_some() {
+            write!(f, "{}:{}", self.host, self.display)?;
+        } else {
+            if let Some(protocol) = &self.protocol {
+                write!(f, "{}/", protocol)?;
+            }
+            if self.host.parse::<Ipv6Addr>().is_ok() {
+                write!(f, "[{}]:{}", self.host, self.display)?;
+            } else {
+                write!(f, "{}:{}", self.host, self.display)?;
+            }
+        }
+        if self.screen != 0 {
+            write!(f, ".{}", self.screen)?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn parse_display(dpy_name: Option<&str>) -> Option<DisplayName> {
     // If no dpy name was provided, use the env var. If no env var exists, return None.
     match dpy_name {
         Some(dpy_name) => parse_display_impl(dpy_name),
@@ -14,7 +84,13 @@ pub(crate) fn parse_display(dpy_name: Option<&str>) -> Option<ParsedDisplay> {
     }
 }
 
-fn parse_display_impl(dpy_name: &str) -> Option<ParsedDisplay> {
+fn parse_display_impl(dpy_name: &str) -> Option<DisplayName> {
+    if dpy_name.starts_with('/') {
+        if let Some(result) = parse_socket_path(dpy_name) {
+            return Some(result);
+        }
+    }
+
     // Everything up to the last '/' is the protocol. This part is optional.
     let (protocol, remaining) = if let Some(pos) = dpy_name.rfind('/') {
         (Some(&dpy_name[..pos]), &dpy_name[pos + 1..])
@@ -26,18 +102,19 @@ fn parse_display_impl(dpy_name: &str) -> Option<ParsedDisplay> {
     let pos = remaining.rfind(':')?;
     let (host, remaining) = (&remaining[..pos], &remaining[pos + 1..]);
 
-    // The remaining part is display.screen. The display is required and the screen optional.
-    let (display, screen) = match remaining.find('.') {
-        Some(pos) => (&remaining[..pos], &remaining[pos + 1..]),
-        None => (remaining, "0"),
-    };
+    // The remaining part is display.screen
+    let (display, screen) = parse_display_and_screen(remaining)?;
 
-    // Parse the display and screen number
-    let (display, screen) = (display.parse().ok()?, screen.parse().ok()?);
+    // IPv6 addresses can be enclosed in brackets, like in "[::1]:0"
+    let host = if host.starts_with('[') && host.ends_with(']') {
+        &host[1..host.len() - 1]
+    } else {
+        host
+    };
 
     let host = host.to_string();
     let protocol = protocol.map(|p| p.to_string());
-    Some(ParsedDisplay {
+    Some(DisplayName {
         host,
         protocol,
         display,
@@ -45,11 +122,64 @@ fn parse_display_impl(dpy_name: &str) -> Option<ParsedDisplay> {
     })
 }
 
+/// Parse `display[.screen]`. The display is required and the screen optional.
+fn parse_display_and_screen(input: &str) -> Option<(u16, u16)> {
+    let (display, screen) = match input.find('.') {
+        Some(pos) => (&input[..pos], &input[pos + 1..]),
+        None => (input, "0"),
+    };
+    Some((display.parse().ok()?, screen.parse().ok()?))
+}
+
+/// Parse a display name that is the path to a Unix socket, optionally followed by
+/// `:display[.screen]` or `.screen`.
+///
+/// Like libxcb, this checks the file system to decide whether the name refers to a socket.
+#[cfg(unix)]
+fn parse_socket_path(dpy_name: &str) -> Option<DisplayName> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let is_socket = |path: &str| {
+        std::fs::metadata(path)
+            .map(|metadata| metadata.file_type().is_socket())
+            .unwrap_or(false)
+    };
+    // The path can be followed by ":display[.screen]" or by ".screen"
+    let with_display = || {
+        let pos = dpy_name.rfind(':')?;
+        let (display, screen) = parse_display_and_screen(&dpy_name[pos + 1..])?;
+        Some((&dpy_name[..pos], display, screen))
+    };
+    let with_screen = || {
+        let pos = dpy_name.rfind('.')?;
+        Some((&dpy_name[..pos], 0, dpy_name[pos + 1..].parse().ok()?))
+    };
+
+    let (path, display, screen) = if is_socket(dpy_name) {
+        (dpy_name, 0, 0)
+    } else {
+        with_display()
+            .filter(|(path, _, _)| is_socket(path))
+            .or_else(|| with_screen().filter(|(path, _, _)| is_socket(path)))?
+    };
+    Some(DisplayName {
+        host: path.to_string(),
+        protocol: Some("unix".to_string()),
+        display,
+        screen,
+    })
+}
+
+#[cfg(not(unix))]
+fn parse_socket_path(_dpy_name: &str) -> Option<DisplayName> {
+    None
+}
+
 #[cfg(test)]
 mod test {
-    use super::{parse_display, ParsedDisplay};
+    use super::{parse_display, DisplayName};
 
-    fn do_parse_display(input: &str) -> Option<ParsedDisplay> {
+    fn do_parse_display(input: &str) -> Option<DisplayName> {
         std::env::set_var("DISPLAY", input);
         let result1 = parse_display(None);
 
@@ -71,6 +201,78 @@ mod test {
         own_good_cases();
     }
 
+    #[test]
+    fn format_round_trip() {
+        for input in &[
+            ":0",
+            ":1.2",
+            "unix:0",
+            "unix/:0",
+            "tcp/localhost:1",
+            "x.org:0",
+            "[::1]:0.1",
+            "[2002:83fc:d052::1]:0",
+            "hydra::0.1",
+            "a:b/c/foo:bar:1.2",
+        ] {
+            let parsed: DisplayName = input.parse().unwrap();
+            assert_eq!(&parsed.to_string(), input);
+        }
+        // Unbracketed IPv6 addresses and a screen of zero are normalised
+        let parsed: DisplayName = "::1:0.0".parse().unwrap();
+        assert_eq!(parsed.to_string(), "[::1]:0");
+        assert_eq!(parsed.to_string().parse::<DisplayName>().unwrap(), parsed);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn socket_path() {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("x11rb-parse-display-{}", std::process::id()));
+        std::fs::create_dir(&dir).unwrap();
+        let path = dir.join("X0");
+        let _listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        let path_str = path.to_str().unwrap().to_string();
+
+        let expected = DisplayName {
+            host: path_str.clone(),
+            protocol: Some("unix".to_string()),
+            display: 0,
+            screen: 0,
+        };
+        let parsed = parse_display(Some(&path_str)).unwrap();
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.socket_path(), Some(path.as_path()));
+        assert_eq!(parsed.to_string(), format!("{}:0", path_str));
+        assert_eq!(parsed.to_string().parse::<DisplayName>().unwrap(), parsed);
+
+        let parsed = parse_display(Some(&format!("{}.1", path_str))).unwrap();
+        assert_eq!(parsed.screen, 1);
+        assert_eq!(parsed.to_string(), format!("{}:0.1", path_str));
+        assert_eq!(parsed.to_string().parse::<DisplayName>().unwrap(), parsed);
+
+        // The display number is kept when formatting
+        let input = format!("{}:2.1", path_str);
+        let parsed = parse_display(Some(&input)).unwrap();
+        assert_eq!((parsed.display, parsed.screen), (2, 1));
+        assert_eq!(parsed.socket_path(), Some(path.as_path()));
+        assert_eq!(parsed.to_string(), input);
+        assert_eq!(parsed.to_string().parse::<DisplayName>().unwrap(), parsed);
+
+        // Something that is not a socket is not accepted
+        assert_eq!(parse_display(Some(dir.join("X1").to_str().unwrap())), None);
+
+        // If the path before the ':' is not a socket, the path before the '.' is tried
+        std::fs::write(dir.join("s"), b"").unwrap();
+        let path = dir.join("s:1");
+        let _listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        let parsed = parse_display(Some(&format!("{}.2", path.to_str().unwrap()))).unwrap();
+        assert_eq!(parsed.socket_path(), Some(path.as_path()));
+        assert_eq!((parsed.display, parsed.screen), (0, 2));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     fn test_missing_input() {
         std::env::remove_var("DISPLAY");
         assert_eq!(parse_display(None), None);
@@ -81,7 +283,7 @@ mod test {
         for (input, output) in &[
             (
                 "foo/bar:1",
-                ParsedDisplay {
+                DisplayName {
                     host: "bar".to_string(),
                     protocol: Some("foo".to_string()),
                     display: 1,
@@ -90,7 +292,7 @@ mod test {
             ),
             (
                 "foo/bar:1.2",
-                ParsedDisplay {
+                DisplayName {
                     host: "bar".to_string(),
                     protocol: Some("foo".to_string()),
                     display: 1,
@@ -99,7 +301,7 @@ mod test {
             ),
             (
                 "a:b/c/foo:bar:1.2",
-                ParsedDisplay {
+                DisplayName {
                     host: "foo:bar".to_string(),
                     protocol: Some("a:b/c".to_string()),
                     display: 1,
@@ -122,7 +324,7 @@ mod test {
             // unix
             (
                 ":0",
-                ParsedDisplay {
+                DisplayName {
                     host: "".to_string(),
                     protocol: None,
                     display: 0,
@@ -131,7 +333,7 @@ mod test {
             ),
             (
                 ":1",
-                ParsedDisplay {
+                DisplayName {
                     host: "".to_string(),
                     protocol: None,
                     display: 1,
@@ -140,7 +342,7 @@ mod test {
             ),
             (
                 ":0.1",
-                ParsedDisplay {
+                DisplayName {
                     host: "".to_string(),
                     protocol: None,
                     display: 0,
@@ -150,7 +352,7 @@ mod test {
             // ip
             (
                 "x.org:0",
-                ParsedDisplay {
+                DisplayName {
                     host: "x.org".to_string(),
                     protocol: None,
                     display: 0,
@@ -159,7 +361,7 @@ mod test {
             ),
             (
                 "expo:0",
-                ParsedDisplay {
+                DisplayName {
                     host: "expo".to_string(),
                     protocol: None,
                     display: 0,
@@ -168,7 +370,7 @@ mod test {
             ),
             (
                 "bigmachine:1",
-                ParsedDisplay {
+                DisplayName {
                     host: "bigmachine".to_string(),
                     protocol: None,
                     display: 1,
@@ -177,7 +379,7 @@ mod test {
             ),
             (
                 "hydra:0.1",
-                ParsedDisplay {
+                DisplayName {
                     host: "hydra".to_string(),
                     protocol: None,
                     display: 0,
@@ -187,7 +389,7 @@ mod test {
             // ipv4
             (
                 "198.112.45.11:0",
-                ParsedDisplay {
+                DisplayName {
                     host: "198.112.45.11".to_string(),
                     protocol: None,
                     display: 0,
@@ -196,7 +398,7 @@ mod test {
             ),
             (
                 "198.112.45.11:0.1",
-                ParsedDisplay {
+                DisplayName {
                     host: "198.112.45.11".to_string(),
                     protocol: None,
                     display: 0,
@@ -206,7 +408,7 @@ mod test {
             // ipv6
             (
                 ":::0",
-                ParsedDisplay {
+                DisplayName {
                     host: "::".to_string(),
                     protocol: None,
                     display: 0,
@@ -215,7 +417,7 @@ mod test {
             ),
             (
                 "1:::0",
-                ParsedDisplay {
+                DisplayName {
                     host: "1::".to_string(),
                     protocol: None,
                     display: 0,
@@ -224,7 +426,7 @@ mod test {
             ),
             (
                 "::1:0",
-                ParsedDisplay {
+                DisplayName {
                     host: "::1".to_string(),
                     protocol: None,
                     display: 0,
@@ -233,7 +435,7 @@ mod test {
             ),
             (
                 "::1:0.1",
-                ParsedDisplay {
+                DisplayName {
                     host: "::1".to_string(),
                     protocol: None,
                     display: 0,
@@ -242,7 +444,7 @@ mod test {
             ),
             (
                 "::127.0.0.1:0",
-                ParsedDisplay {
+                DisplayName {
                     host: "::127.0.0.1".to_string(),
                     protocol: None,
                     display: 0,
@@ -251,7 +453,7 @@ mod test {
             ),
             (
                 "::ffff:127.0.0.1:0",
-                ParsedDisplay {
+                DisplayName {
                     host: "::ffff:127.0.0.1".to_string(),
                     protocol: None,
                     display: 0,
@@ -260,7 +462,7 @@ mod test {
             ),
             (
                 "2002:83fc:3052::1:0",
-                ParsedDisplay {
+                DisplayName {
                     host: "2002:83fc:3052::1".to_string(),
                     protocol: None,
                     display: 0,
@@ -269,7 +471,7 @@ mod test {
             ),
             (
                 "2002:83fc:3052::1:0.1",
-                ParsedDisplay {
+                DisplayName {
                     host: "2002:83fc:3052::1".to_string(),
                     protocol: None,
                     display: 0,
@@ -278,8 +480,8 @@ mod test {
             ),
             (
                 "[::]:0",
-                ParsedDisplay {
-                    host: "[::]".to_string(),
+                DisplayName {
+                    host: "::".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -287,8 +489,8 @@ mod test {
             ),
             (
                 "[1::]:0",
-                ParsedDisplay {
-                    host: "[1::]".to_string(),
+                DisplayName {
+                    host: "1::".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -296,8 +498,8 @@ mod test {
             ),
             (
                 "[::1]:0",
-                ParsedDisplay {
-                    host: "[::1]".to_string(),
+                DisplayName {
+                    host: "::1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -305,8 +507,8 @@ mod test {
             ),
             (
                 "[::1]:0.1",
-                ParsedDisplay {
-                    host: "[::1]".to_string(),
+                DisplayName {
+                    host: "::1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 1,
@@ -314,8 +516,8 @@ mod test {
             ),
             (
                 "[::127.0.0.1]:0",
-                ParsedDisplay {
-                    host: "[::127.0.0.1]".to_string(),
+                DisplayName {
+                    host: "::127.0.0.1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -323,8 +525,8 @@ mod test {
             ),
             (
                 "[2002:83fc:d052::1]:0",
-                ParsedDisplay {
-                    host: "[2002:83fc:d052::1]".to_string(),
+                DisplayName {
+                    host: "2002:83fc:d052::1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -332,8 +534,8 @@ mod test {
             ),
             (
                 "[2002:83fc:d052::1]:0.1",
-                ParsedDisplay {
-                    host: "[2002:83fc:d052::1]".to_string(),
+                DisplayName {
+                    host: "2002:83fc:d052::1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 1,
@@ -342,7 +544,7 @@ mod test {
             // decnet
             (
                 "myws::0",
-                ParsedDisplay {
+                DisplayName {
                     host: "myws:".to_string(),
                     protocol: None,
                     display: 0,
@@ -351,7 +553,7 @@ mod test {
             ),
             (
                 "big::0",
-                ParsedDisplay {
+                DisplayName {
                     host: "big:".to_string(),
                     protocol: None,
                     display: 0,
@@ -360,7 +562,7 @@ mod test {
             ),
             (
                 "hydra::0.1",
-                ParsedDisplay {
+                DisplayName {
                     host: "hydra:".to_string(),
                     protocol: None,
                     display: 0,
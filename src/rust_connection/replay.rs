@@ -0,0 +1,766 @@
+//! Recording the traffic of a connection and replaying it later.
+//!
+//! A [`Recorder`] wraps the [`ReadFD`] and [`WriteFD`] of a [`RustConnection`] and records all
+//! bytes that are exchanged with the X11 server, including the connection setup. The resulting
+//! [`Recording`] can be saved to a file.
+//!
+//! A [`Replayer`] later plays the recording back without an X11 server. The client has to send
+//! exactly the same bytes as in the recording. Otherwise, writing fails and the connection enters
+//! an error state. Data from the server is only handed out after the client sent everything that
+//! it sent before this data in the recording. This makes the replay deterministic for programs
+//! that only use the connection from a single thread.
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::{ConnectionExt, Window};
+//! use x11rb::rust_connection::replay::{Recorder, Recording, Replayer};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! fn run(conn: &impl Connection) -> Result<Window, Box<dyn std::error::Error>> {
+//!     Ok(conn.get_input_focus()?.reply()?.focus)
+//! }
+//!
+//! // Record a session with the real X11 server
+//! let recorder = Recorder::new();
+//! let (conn, _) = recorder.connect(None)?;
+//! let focus = run(&conn)?;
+//! recorder.recording().save("session.x11rb")?;
+//!
+//! // Later: Replay the session
+//! let replayer = Replayer::new(Recording::load("session.x11rb")?);
+//! let conn = replayer.connect()?;
+//! assert_eq!(run(&conn)?, focus);
+//! assert!(replayer.is_finished());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! File descriptors cannot be recorded. Only their number is recorded. During the replay, file
+//! descriptors that the client sends are dropped and `/dev/null` is handed out in place of file
+//! descriptors that were received from the server.
+//!
+//! Note that a recording contains everything that was sent to the X11 server, including the
+//! authorization data that was used during the connection setup.
+
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Error, ErrorKind, IoSlice, Read, Result, Write};
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::fd_read_write::{BufReadFD, BufWriteFD, ReadFD, WriteFD};
+use super::stream::Stream;
+use super::{get_auth, parse_display, read_setup, write_setup, AuthMethod, RustConnection};
+use crate::errors::ConnectError;
+use crate::protocol::xproto::SetupRequest;
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ByteOrder, TryParse};
+
+/// A `RustConnection` whose traffic is recorded by a [`Recorder`].
+pub type RecordedConnection =
+    RustConnection<BufReadFD<RecordReadFD<Stream>>, BufWriteFD<RecordWriteFD<Stream>>>;
+
+/// A `RustConnection` that replays a [`Recording`].
+pub type ReplayConnection = RustConnection<ReplayReadFD, ReplayWriteFD>;
+
+/// The magic bytes at the beginning of a saved recording.
+const MAGIC: &[u8; 8] = b"X11RBREC";
+
+/// The version of the file format of saved recordings.
+const VERSION: u32 = 1;
+
+/// The direction in which some data was transferred, seen from the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The data was sent from the client to the X11 server.
+    Sent,
+    /// The data was received by the client from the X11 server.
+    Received,
+}
+
+/// Some data that was transferred over a connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transfer {
+    /// The direction of the transfer.
+    pub direction: Direction,
+    /// The bytes that were transferred.
+    pub data: Vec<u8>,
+    /// The number of file descriptors that were transferred together with the bytes.
+    pub fds: usize,
+}
+
+/// The recorded traffic of a connection.
+///
+/// A recording is a list of [`Transfer`]s in the order in which they happened. Consecutive
+/// transfers in the same direction are merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recording {
+    transfers: Vec<Transfer>,
+}
+
+impl Recording {
+    /// Create a new, empty recording.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Get the transfers in this recording.
+    pub fn transfers(&self) -> &[Transfer] {
+        &self.transfers
+    }
+
+    /// Append some transferred data to the recording.
+    ///
+    /// This can be used to write recordings by hand, for example for tests.
+    pub fn push(&mut self, direction: Direction, data: &[u8], fds: usize) {
+        if data.is_empty() && fds == 0 {
+            return;
+        }
+        if let Some(last) = self.transfers.last_mut() {
+            if last.direction == direction {
+                last.data.extend_from_slice(data);
+                last.fds += fds;
+                return;
+            }
+        }
+        self.transfers.push(Transfer {
+            direction,
+            data: data.to_vec(),
+            fds,
+        });
+    }
+
+    /// Write this recording to the given writer.
+    pub fn write_to(&self, mut write: impl Write) -> Result<()> {
+        write.write_all(MAGIC)?;
+        write.write_all(&VERSION.to_le_bytes())?;
+        for transfer in &self.transfers {
+            let direction = match transfer.direction {
+                Direction::Sent => 0u8,
+                Direction::Received => 1u8,
+            };
+            write.write_all(&[direction])?;
+            write.write_all(&to_u32(transfer.fds)?.to_le_bytes())?;
+            write.write_all(&to_u32(transfer.data.len())?.to_le_bytes())?;
+            write.write_all(&transfer.data)?;
+        }
+        write.flush()
+    }
+
+    /// Read a recording that was written with [`Recording::write_to`].
+    pub fn read_from(mut read: impl Read) -> Result<Self> {
+        let mut magic = [0; 8];
+        read.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not an x11rb recording"));
+        }
+        if read_u32(&mut read)? != VERSION {
+            return Err(invalid_data("unsupported version of the recording format"));
+        }
+        let mut recording = Recording::new();
+        loop {
+            let mut direction = [0];
+            if read.read(&mut direction)? == 0 {
+                return Ok(recording);
+            }
+            let direction = match direction[0] {
+                0 => Direction::Sent,
+                1 => Direction::Received,
+                _ => return Err(invalid_data("invalid direction in recording")),
+            };
+            let fds = read_u32(&mut read)? as usize;
+            // The length is not trusted, so do not allocate it upfront
+            let len = read_u32(&mut read)?;
+            let mut data = Vec::new();
+            let _ = (&mut read).take(len.into()).read_to_end(&mut data)?;
+            if data.len() != len as usize {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "recording ends in the middle of a transfer",
+                ));
+            }
+            recording.push(direction, &data, fds);
+        }
+    }
+
+    /// Save this recording to the file at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+
+    /// Load a recording from the file at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+fn to_u32(value: usize) -> Result<u32> {
+    u32::try_from(value).map_err(|_| invalid_data("transfer is too large for a recording"))
+}
+
+fn read_u32(read: &mut impl Read) -> Result<u32> {
+    let mut value = [0; 4];
+    read.read_exact(&mut value)?;
+    Ok(u32::from_le_bytes(value))
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+/// Records the traffic of a connection.
+///
+/// Cloning a `Recorder` produces a handle to the same recording.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder(Arc<Mutex<Recording>>);
+
+impl Recorder {
+    /// Create a new recorder with an empty recording.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Establish a new connection and record its traffic.
+    ///
+    /// This works like [`RustConnection::connect`].
+    pub fn connect(
+        &self,
+        dpy_name: Option<&str>,
+    ) -> std::result::Result<(RecordedConnection, usize), ConnectError> {
+        let parsed_display =
+            parse_display::parse_display(dpy_name).ok_or(ConnectError::DisplayParsingError)?;
+        let stream = Stream::connect(&parsed_display)?;
+        let screen = parsed_display.screen.into();
+
+        let (auth_name, auth_data) = get_auth(
+            &stream,
+            parsed_display.display,
+            AuthMethod::DEFAULT_PREFERENCE,
+        )
+        // Ignore all errors while determining auth; instead we just try without auth info.
+        .unwrap_or(None)
+        .unwrap_or_else(|| (Vec::new(), Vec::new()));
+        let write = BufWriteFD::new(self.writer(stream.try_clone()?));
+        let read = BufReadFD::new(self.reader(stream));
+        let conn = RustConnection::connect_to_stream_with_auth_info(
+            read, write, screen, auth_name, auth_data,
+        )?;
+        Ok((conn, screen))
+    }
+
+    /// Wrap the given reader so that all data that is read is recorded.
+    pub fn reader<R: ReadFD>(&self, read: R) -> RecordReadFD<R> {
+        RecordReadFD {
+            inner: read,
+            recorder: self.clone(),
+        }
+    }
+
+    /// Wrap the given writer so that all data that is written is recorded.
+    pub fn writer<W: WriteFD>(&self, write: W) -> RecordWriteFD<W> {
+        RecordWriteFD {
+            inner: write,
+            recorder: self.clone(),
+        }
+    }
+
+    /// Get a copy of everything that was recorded so far.
+    pub fn recording(&self) -> Recording {
+        self.0.lock().unwrap().clone()
+    }
+
+    fn push(&self, direction: Direction, data: &[u8], fds: usize) {
+        self.0.lock().unwrap().push(direction, data, fds);
+    }
+}
+
+/// A [`ReadFD`] that records all data that it reads. See [`Recorder::reader`].
+#[derive(Debug)]
+pub struct RecordReadFD<R> {
+    inner: R,
+    recorder: Recorder,
+}
+
+impl<R: ReadFD> RecordReadFD<R> {
+    fn record(&self, result: Result<usize>, buf: &[u8], fds: usize) -> Result<usize> {
+        if let Ok(n) = result {
+            self.recorder.push(Direction::Received, &buf[..n], fds);
+        }
+        result
+    }
+}
+
+impl<R: ReadFD> ReadFD for RecordReadFD<R> {
+    fn read(&mut self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let old_fds = fd_storage.len();
+        let result = self.inner.read(buf, fd_storage);
+        self.record(result, buf, fd_storage.len() - old_fds)
+    }
+
+    fn read_nonblocking(
+        &mut self,
+        buf: &mut [u8],
+        fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        let old_fds = fd_storage.len();
+        let result = self.inner.read_nonblocking(buf, fd_storage);
+        self.record(result, buf, fd_storage.len() - old_fds)
+    }
+
+    fn wait_readable(&mut self, timeout: Duration) -> Result<bool> {
+        self.inner.wait_readable(timeout)
+    }
+
+    fn has_buffered_data(&self) -> bool {
+        self.inner.has_buffered_data()
+    }
+}
+
+#[cfg(unix)]
+impl<R: AsRawFd> AsRawFd for RecordReadFD<R> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+/// A [`WriteFD`] that records all data that it writes. See [`Recorder::writer`].
+#[derive(Debug)]
+pub struct RecordWriteFD<W> {
+    inner: W,
+    recorder: Recorder,
+}
+
+impl<W: WriteFD> WriteFD for RecordWriteFD<W> {
+    fn write(&mut self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let old_fds = fds.len();
+        let n = self.inner.write(buf, fds)?;
+        self.recorder
+            .push(Direction::Sent, &buf[..n], old_fds - fds.len());
+        Ok(n)
+    }
+
+    fn write_vectored(
+        &mut self,
+        bufs: &[IoSlice<'_>],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        let old_fds = fds.len();
+        let result = self.inner.write_vectored(bufs, fds);
+        self.record_vectored(result, bufs, old_fds - fds.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+
+    fn write_vectored_nonblocking(
+        &mut self,
+        bufs: &[IoSlice<'_>],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        let old_fds = fds.len();
+        let result = self.inner.write_vectored_nonblocking(bufs, fds);
+        self.record_vectored(result, bufs, old_fds - fds.len())
+    }
+
+    fn flush_nonblocking(&mut self) -> Result<()> {
+        self.inner.flush_nonblocking()
+    }
+
+    fn wait_writable_or_readable(&mut self) -> Result<bool> {
+        self.inner.wait_writable_or_readable()
+    }
+}
+
+impl<W: WriteFD> RecordWriteFD<W> {
+    fn record_vectored(
+        &self,
+        result: Result<usize>,
+        bufs: &[IoSlice<'_>],
+        fds: usize,
+    ) -> Result<usize> {
+        let n = result?;
+        let written = bufs
+            .iter()
+            .flat_map(|buf| buf.iter())
+            .copied()
+            .take(n)
+            .collect::<Vec<_>>();
+        self.recorder.push(Direction::Sent, &written, fds);
+        Ok(n)
+    }
+}
+
+#[cfg(unix)]
+impl<W: AsRawFd> AsRawFd for RecordWriteFD<W> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+/// A position in a recording.
+#[derive(Debug, Clone, Copy, Default)]
+struct Position {
+    index: usize,
+    offset: usize,
+}
+
+#[derive(Debug)]
+struct ReplayState {
+    transfers: Vec<Transfer>,
+    write_pos: Position,
+    read_pos: Position,
+    // The number of bytes that the client sent so far
+    bytes_written: usize,
+    // The number of FDs that the client sent for the transfer at `write_pos`
+    fds_written: usize,
+}
+
+impl ReplayState {
+    /// Move `write_pos` to the next transfer from the client that is not yet complete.
+    fn next_write(&mut self) -> Result<Option<usize>> {
+        while let Some(transfer) = self.transfers.get(self.write_pos.index) {
+            if transfer.direction == Direction::Sent && self.write_pos.offset < transfer.data.len()
+            {
+                return Ok(Some(self.write_pos.index));
+            }
+            if transfer.direction == Direction::Sent && self.fds_written != transfer.fds {
+                return Err(invalid_data(&format!(
+                    "the client sent {} file descriptors, but {} were recorded",
+                    self.fds_written, transfer.fds
+                )));
+            }
+            self.write_pos = Position {
+                index: self.write_pos.index + 1,
+                offset: 0,
+            };
+            self.fds_written = 0;
+        }
+        Ok(None)
+    }
+
+    fn write(&mut self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let index = match self.next_write()? {
+            Some(index) => index,
+            None if buf.is_empty() && fds.is_empty() => return Ok(0),
+            None => return Err(invalid_data("the client sent more data than was recorded")),
+        };
+        let expected = &self.transfers[index].data[self.write_pos.offset..];
+        let n = expected.len().min(buf.len());
+        if let Some(pos) = (0..n).find(|&i| expected[i] != buf[i]) {
+            return Err(invalid_data(&format!(
+                "the client sent different data than was recorded at byte {}",
+                self.bytes_written + pos
+            )));
+        }
+        self.fds_written += fds.len();
+        fds.clear();
+        self.write_pos.offset += n;
+        self.bytes_written += n;
+        Ok(n)
+    }
+
+    /// Get the index of the next transfer from the server that the client can read from.
+    ///
+    /// Returns `Ok(None)` if the client has to send more data first and an error if the recording
+    /// has no more data from the server.
+    fn next_read(&mut self) -> Result<Option<usize>> {
+        while let Some(transfer) = self.transfers.get(self.read_pos.index) {
+            if transfer.direction == Direction::Received
+                && self.read_pos.offset < transfer.data.len()
+            {
+                break;
+            }
+            self.read_pos = Position {
+                index: self.read_pos.index + 1,
+                offset: 0,
+            };
+        }
+        let index = self.read_pos.index;
+        if index >= self.transfers.len() {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "the recording contains no more data from the server",
+            ));
+        }
+        // Check that the client already sent everything that comes before this data
+        let blocked = (self.write_pos.index..index).any(|i| {
+            let transfer = &self.transfers[i];
+            let complete =
+                i == self.write_pos.index && self.write_pos.offset == transfer.data.len();
+            transfer.direction == Direction::Sent && !complete
+        });
+        if blocked {
+            Ok(None)
+        } else {
+            Ok(Some(index))
+        }
+    }
+
+    fn read_from(
+        &mut self,
+        index: usize,
+        buf: &mut [u8],
+        fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        let transfer = &self.transfers[index];
+        if self.read_pos.offset == 0 {
+            for _ in 0..transfer.fds {
+                fd_storage.push(placeholder_fd()?);
+            }
+        }
+        let available = &transfer.data[self.read_pos.offset..];
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.read_pos.offset += n;
+        Ok(n)
+    }
+}
+
+#[cfg(unix)]
+fn placeholder_fd() -> Result<RawFdContainer> {
+    Ok(File::open("/dev/null")?.into())
+}
+
+#[cfg(not(unix))]
+fn placeholder_fd() -> Result<RawFdContainer> {
+    Err(Error::new(ErrorKind::Other, "FD passing is unsupported"))
+}
+
+/// Replays a [`Recording`].
+///
+/// Cloning a `Replayer` produces a handle to the same replay.
+#[derive(Debug, Clone)]
+pub struct Replayer(Arc<Mutex<ReplayState>>);
+
+impl Replayer {
+    /// Create a new replayer for the given recording.
+    pub fn new(recording: Recording) -> Self {
+        Replayer(Arc::new(Mutex::new(ReplayState {
+            transfers: recording.transfers,
+            write_pos: Default::default(),
+            read_pos: Default::default(),
+            bytes_written: 0,
+            fds_written: 0,
+        })))
+    }
+
+    /// Establish a connection that replays the recording.
+    ///
+    /// The recording must begin with the connection setup. This uses the same authorization
+    /// data as the recorded connection.
+    pub fn connect(&self) -> std::result::Result<ReplayConnection, ConnectError> {
+        let (request, order) = {
+            let state = self.0.lock().unwrap();
+            let data = match state.transfers.first() {
+                Some(transfer) if transfer.direction == Direction::Sent => &transfer.data,
+                _ => return Err(invalid_data("the recording does not begin with a setup").into()),
+            };
+            // Replay the connection in the byte order that was recorded
+            let order = data
+                .first()
+                .and_then(|byte| ByteOrder::from_setup_byte(*byte))
+                .ok_or_else(|| invalid_data("the recording uses an unknown byte order"))?;
+            (SetupRequest::try_parse_with_order(data, order)?.0, order)
+        };
+        let (mut read, mut write) = (self.reader(), self.writer());
+        write_setup(
+            &mut write,
+            request.authorization_protocol_name,
+            request.authorization_protocol_data,
+            order,
+        )?;
+        let setup = read_setup(&mut read, order)?;
+        RustConnection::for_connected_stream_with_byte_order(read, write, setup, order)
+    }
+
+    /// Get a reader that provides the data that the server sent in the recording.
+    pub fn reader(&self) -> ReplayReadFD {
+        ReplayReadFD(self.clone())
+    }
+
+    /// Get a writer that checks that the client sends the same data as in the recording.
+    pub fn writer(&self) -> ReplayWriteFD {
+        ReplayWriteFD(self.clone())
+    }
+
+    /// Check whether the client sent all the data that was recorded.
+    pub fn is_finished(&self) -> bool {
+        self.0.lock().unwrap().next_write().ok() == Some(None)
+    }
+}
+
+/// A [`ReadFD`] that provides the data from a recording. See [`Replayer::reader`].
+#[derive(Debug)]
+pub struct ReplayReadFD(Replayer);
+
+impl ReadFD for ReplayReadFD {
+    fn read(&mut self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let mut state = (self.0).0.lock().unwrap();
+        match state.next_read() {
+            Ok(Some(index)) => state.read_from(index, buf, fd_storage),
+            Ok(None) => Err(invalid_data(
+                "the client waits for data, but the recording expects it to send more data first",
+            )),
+            Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => Ok(0),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn read_nonblocking(
+        &mut self,
+        buf: &mut [u8],
+        fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        let mut state = (self.0).0.lock().unwrap();
+        match state.next_read() {
+            Ok(Some(index)) => state.read_from(index, buf, fd_storage),
+            Ok(None) => Err(Error::new(ErrorKind::WouldBlock, "no data available")),
+            // Like a real connection, the end of the recording does not cause an error here
+            Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => {
+                Err(Error::new(ErrorKind::WouldBlock, "no data available"))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn wait_readable(&mut self, _timeout: Duration) -> Result<bool> {
+        // Waiting would not help, since nothing changes while we wait
+        match (self.0).0.lock().unwrap().next_read() {
+            Ok(Some(_)) => Ok(true),
+            _ => Ok(false),
+        }
+    }
+}
+
+/// A [`WriteFD`] that checks that the written data matches a recording. See
+/// [`Replayer::writer`].
+#[derive(Debug)]
+pub struct ReplayWriteFD(Replayer);
+
+impl WriteFD for ReplayWriteFD {
+    fn write(&mut self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        (self.0).0.lock().unwrap().write(buf, fds)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(unix)]
+    use std::io::Write;
+
+    use super::{Direction, Recording, ReplayConnection, Replayer};
+    use crate::connection::Connection as _;
+    use crate::protocol::xproto::ConnectionExt as _;
+    use crate::rust_connection::test::test_setup;
+    use crate::rust_connection::RustConnection;
+
+    /// Create a connection that replays the given recording, skipping the connection setup.
+    fn replay(recording: Recording) -> (Replayer, ReplayConnection) {
+        let replayer = Replayer::new(recording);
+        let conn = RustConnection::for_connected_stream(
+            replayer.reader(),
+            replayer.writer(),
+            test_setup(),
+        )
+        .unwrap();
+        (replayer, conn)
+    }
+
+    fn get_input_focus_request() -> Vec<u8> {
+        let mut request = vec![43, 0];
+        request.extend_from_slice(&1u16.to_ne_bytes());
+        request
+    }
+
+    fn get_input_focus_reply(sequence: u16, focus: u32) -> Vec<u8> {
+        let mut reply = vec![1, 0];
+        reply.extend_from_slice(&sequence.to_ne_bytes());
+        reply.extend_from_slice(&0u32.to_ne_bytes());
+        reply.extend_from_slice(&focus.to_ne_bytes());
+        reply.resize(32, 0);
+        reply
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn record_and_replay() {
+        use super::super::stream::Stream;
+        use super::Recorder;
+        use std::os::unix::net::UnixStream;
+
+        // Record a session
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let recorder = Recorder::new();
+        let read = recorder.reader(Stream::UnixStream(client.try_clone().unwrap()));
+        let write = recorder.writer(Stream::UnixStream(client));
+        let conn = RustConnection::for_connected_stream(read, write, test_setup()).unwrap();
+        let cookie = conn.get_input_focus().unwrap();
+        server.write_all(&get_input_focus_reply(1, 0x42)).unwrap();
+        assert_eq!(u32::from(cookie.reply().unwrap().focus), 0x42);
+
+        // Save and load the recording
+        let recording = recorder.recording();
+        assert_eq!(recording.transfers().len(), 2);
+        let mut file = Vec::new();
+        recording.write_to(&mut file).unwrap();
+        assert_eq!(Recording::read_from(&file[..]).unwrap(), recording);
+
+        // Replay it
+        let (replayer, conn) = replay(recording);
+        assert!(!replayer.is_finished());
+        assert_eq!(
+            u32::from(conn.get_input_focus().unwrap().reply().unwrap().focus),
+            0x42
+        );
+        assert!(replayer.is_finished());
+    }
+
+    #[test]
+    fn read_truncated_recording() {
+        let mut recording = Recording::new();
+        recording.push(Direction::Sent, &get_input_focus_request(), 0);
+        let mut file = Vec::new();
+        recording.write_to(&mut file).unwrap();
+
+        // Claim that the transfer is huge
+        let len = file.len();
+        file[len - 8..len - 4].copy_from_slice(&u32::max_value().to_le_bytes());
+        let err = Recording::read_from(&file[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn replay_different_request() {
+        let mut recording = Recording::new();
+        recording.push(Direction::Sent, &get_input_focus_request(), 0);
+        recording.push(Direction::Received, &get_input_focus_reply(1, 0x42), 0);
+
+        let (_, conn) = replay(recording);
+        assert!(conn.get_input_focus().is_ok());
+        assert!(conn.get_input_focus().is_err());
+        assert!(conn.has_error().is_some());
+    }
+
+    #[test]
+    fn replay_waits_for_request() {
+        let mut recording = Recording::new();
+        recording.push(Direction::Sent, &get_input_focus_request(), 0);
+        recording.push(Direction::Received, &get_input_focus_reply(1, 0x42), 0);
+
+        // The reply is not handed out before the request was sent
+        let (_, conn) = replay(recording);
+        assert!(conn.poll_for_raw_event().unwrap().is_none());
+        assert_eq!(
+            u32::from(conn.get_input_focus().unwrap().reply().unwrap().focus),
+            0x42
+        );
+    }
+}
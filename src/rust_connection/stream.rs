@@ -1,11 +1,14 @@
-use std::io::{IoSlice, Result};
-use std::net::{Ipv4Addr, SocketAddr, TcpStream};
+use std::io::{Error, ErrorKind, IoSlice, Result};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpStream, ToSocketAddrs};
 #[cfg(unix)]
 use std::os::unix::io::{AsRawFd, RawFd};
 #[cfg(unix)]
 use std::os::unix::net::UnixStream;
+#[cfg(unix)]
+use std::time::Duration;
 
 use super::fd_read_write::{ReadFD, WriteFD};
+use super::parse_display::DisplayName;
 use super::xauth::Family;
 use crate::utils::RawFdContainer;
 
@@ -18,15 +21,40 @@ pub enum Stream {
 }
 
 impl Stream {
-    /// Try to connect to the X11 server described by the given arguments.
-    pub fn connect(host: &str, protocol: Option<&str>, display: u16) -> Result<Self> {
+    /// Try to connect to the X11 server described by the given display name.
+    ///
+    /// Like libxcb, an explicit protocol takes precedence: `unix` only tries the Unix socket and
+    /// any other protocol only tries TCP, using `localhost` for an empty host. Without a protocol,
+    /// an empty host or `unix` selects the Unix socket. For an empty host, TCP to `localhost` is
+    /// tried if that fails.
+    pub fn connect(display: &DisplayName) -> Result<Self> {
         const TCP_PORT_BASE: u16 = 6000;
 
-        if (protocol.is_none() || protocol != Some("unix")) && !host.is_empty() && host != "unix" {
-            Ok(Stream::TcpStream(TcpStream::connect((
+        let (host, protocol) = (&*display.host, display.protocol.as_ref().map(|p| &**p));
+
+        #[cfg(unix)]
+        {
+            if let Some(path) = display.socket_path() {
+                return Ok(Stream::UnixStream(UnixStream::connect(path)?));
+            }
+        }
+
+        let tcp_host = match protocol {
+            Some("unix") => None,
+            Some(protocol) => {
+                check_tcp_protocol(protocol)?;
+                Some(if host.is_empty() { "localhost" } else { host })
+            }
+            None if !host.is_empty() && host != "unix" => Some(host),
+            None => None,
+        };
+
+        if let Some(host) = tcp_host {
+            Ok(Stream::TcpStream(connect_tcp(
                 host,
-                TCP_PORT_BASE + display,
-            ))?))
+                TCP_PORT_BASE + display.display,
+                protocol,
+            )?))
         } else {
             // On non-unix, this variable is not mutated.
             #[allow(unused_mut)]
@@ -35,10 +63,19 @@ impl Stream {
             #[cfg(unix)]
             {
                 if protocol.is_none() || protocol == Some("unix") {
-                    let file_name = format!("/tmp/.X11-unix/X{}", display);
+                    let file_name = format!("/tmp/.X11-unix/X{}", display.display);
 
-                    // TODO: Try abstract socket (file name with prepended '\0')
-                    // Not supported on Rust right now: https://github.com/rust-lang/rust/issues/42048
+                    // Like libxcb, first try the abstract socket (file name with prepended '\0').
+                    // Errors are ignored, since the socket file is tried next.
+                    #[cfg(all(
+                        any(target_os = "linux", target_os = "android"),
+                        feature = "allow-unsafe-code"
+                    ))]
+                    {
+                        if let Ok(stream) = abstract_socket::connect(file_name.as_bytes()) {
+                            return Ok(Stream::UnixStream(stream));
+                        }
+                    }
 
                     match UnixStream::connect(file_name) {
                         Ok(stream) => return Ok(Stream::UnixStream(stream)),
@@ -48,60 +85,73 @@ impl Stream {
             }
 
             if protocol.is_none() && host.is_empty() {
-                Ok(Stream::TcpStream(TcpStream::connect((
+                Ok(Stream::TcpStream(connect_tcp(
                     "localhost",
-                    TCP_PORT_BASE + display,
-                ))?))
+                    TCP_PORT_BASE + display.display,
+                    None,
+                )?))
             } else {
-                use crate::errors::ConnectError;
-                use std::io::{Error, ErrorKind};
-                Err(error.unwrap_or_else(|| {
-                    Error::new(ErrorKind::Other, ConnectError::DisplayParsingError)
-                }))
+                Err(error.unwrap_or_else(display_parsing_error))
             }
         }
     }
 }
 
+/// Connect to the given host via TCP.
+///
+/// All addresses that the host name resolves to are tried in order until a connection succeeds.
+/// The protocols `inet` and `inet6` restrict this to IPv4 and IPv6 addresses, respectively.
+fn connect_tcp(host: &str, port: u16, protocol: Option<&str>) -> Result<TcpStream> {
+    let mut error = None;
+    for addr in (host, port).to_socket_addrs()? {
+        let acceptable = match protocol {
+            Some("inet") => addr.is_ipv4(),
+            Some("inet6") => addr.is_ipv6(),
+            _ => true,
+        };
+        if !acceptable {
+            continue;
+        }
+        match TcpStream::connect(addr) {
+            Ok(stream) => return Ok(stream),
+            Err(err) => error = Some(err),
+        }
+    }
+    Err(error.unwrap_or_else(|| {
+        Error::new(
+            ErrorKind::AddrNotAvailable,
+            "host name did not resolve to any usable address",
+        )
+    }))
+}
+
+/// Check that the given protocol can be used for a TCP connection.
+fn check_tcp_protocol(protocol: &str) -> Result<()> {
+    match protocol {
+        "tcp" | "inet" | "inet6" => Ok(()),
+        _ => Err(display_parsing_error()),
+    }
+}
+
+fn display_parsing_error() -> Error {
+    use crate::errors::ConnectError;
+    Error::new(ErrorKind::Other, ConnectError::DisplayParsingError)
+}
+
 impl Stream {
     /// Get the peer's address in a format suitable for xauth.
     ///
     /// The returned values can be directly given to `super::xauth::get_auth` as `family` and
     /// `address`.
     pub(crate) fn peer_addr(&self) -> Result<(Family, Vec<u8>)> {
-        match self {
-            Stream::TcpStream(stream) => {
-                // Get the v4 address of the other end (if there is one)
-                let ip = match stream.peer_addr()? {
-                    SocketAddr::V4(addr) => *addr.ip(),
-                    SocketAddr::V6(addr) => {
-                        let ip = addr.ip();
-                        if ip.is_loopback() {
-                            // This is a local connection.
-                            // Use LOCALHOST to cause a fall-through in the code below.
-                            Ipv4Addr::LOCALHOST
-                        } else if let Some(ip) = ip.to_ipv4() {
-                            // Let the ipv4 code below handle this
-                            ip
-                        } else {
-                            // Okay, this is really a v6 address
-                            return Ok((Family::Internet6, ip.octets().to_vec()));
-                        }
-                    }
-                };
-
-                // Handle the v4 address
-                if !ip.is_loopback() {
-                    return Ok((Family::Internet, ip.octets().to_vec()));
-                } else {
-                    // This is only reached for loopback addresses. The code below handles this.
-                }
-            }
+        let remote = match self {
+            Stream::TcpStream(stream) => family_address(stream.peer_addr()?.ip()),
             #[cfg(unix)]
-            Stream::UnixStream(_) => {
-                // Fall through to the code below.
-            }
+            _ => None,
         };
+        if let Some(remote) = remote {
+            return Ok(remote);
+        }
 
         // If we get to here: This is a local connection. Use the host name as address.
         let hostname = gethostname::gethostname()
@@ -111,6 +161,32 @@ impl Stream {
         Ok((Family::Local, hostname))
     }
 
+    /// Get the client's address in the format used by XDM-AUTHORIZATION-1.
+    ///
+    /// This returns the IPv4 address and port of our end of the connection. Like libxcb, a fake
+    /// address is used for Unix sockets and all zeros are used for real IPv6 connections, since
+    /// XDM-AUTHORIZATION-1 cannot represent them.
+    pub(crate) fn xdm_address(&self) -> Result<([u8; 4], [u8; 2])> {
+        match self {
+            Stream::TcpStream(stream) => match stream.local_addr()? {
+                SocketAddr::V4(addr) => Ok((addr.ip().octets(), addr.port().to_be_bytes())),
+                SocketAddr::V6(addr) => match ipv4_mapped(addr.ip()) {
+                    Some(ip) => Ok((ip.octets(), addr.port().to_be_bytes())),
+                    None => Ok(([0; 4], [0; 2])),
+                },
+            },
+            #[cfg(unix)]
+            _ => {
+                use std::sync::atomic::{AtomicU32, Ordering};
+                static NONCE: AtomicU32 = AtomicU32::new(0);
+
+                let address = 0xffff_ffff - NONCE.fetch_add(1, Ordering::Relaxed);
+                let port = std::process::id() as u16;
+                Ok((address.to_be_bytes(), port.to_be_bytes()))
+            }
+        }
+    }
+
     /// Creates a new independently owned handle to the underlying socket.
     ///
     /// The returned `Stream` is a reference to the same stream that this object references. Both
@@ -124,7 +200,49 @@ impl Stream {
         }
     }
 
-    #[cfg(unix)]
+    /// Shut down the reading and writing halves of the underlying socket.
+    ///
+    /// This also affects all handles that were created with `try_clone()`.
+    pub(crate) fn shutdown(&self) -> Result<()> {
+        match self {
+            Stream::TcpStream(stream) => stream.shutdown(Shutdown::Both),
+            #[cfg(unix)]
+            Stream::UnixStream(stream) => stream.shutdown(Shutdown::Both),
+        }
+    }
+}
+
+/// Get the xauth family and address for a connection to the given IP address.
+///
+/// This follows libxcb: IPv4-mapped IPv6 addresses are handled like IPv4 addresses and `None` is
+/// returned for the loopback addresses `127.0.0.1` and `::1`. These connections are local and
+/// have to use `Family::Local` with the host name as address.
+fn family_address(ip: IpAddr) -> Option<(Family, Vec<u8>)> {
+    let ip = match ip {
+        IpAddr::V4(ip) => ip,
+        IpAddr::V6(ip) => match ipv4_mapped(&ip) {
+            Some(ip) => ip,
+            None if ip.is_loopback() => return None,
+            None => return Some((Family::Internet6, ip.octets().to_vec())),
+        },
+    };
+    if ip == Ipv4Addr::LOCALHOST {
+        None
+    } else {
+        Some((Family::Internet, ip.octets().to_vec()))
+    }
+}
+
+/// Get the IPv4 address embedded in an IPv4-mapped IPv6 address (`::ffff:a.b.c.d`).
+fn ipv4_mapped(ip: &Ipv6Addr) -> Option<Ipv4Addr> {
+    match ip.octets() {
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, a, b, c, d] => Some(Ipv4Addr::new(a, b, c, d)),
+        _ => None,
+    }
+}
+
+#[cfg(unix)]
+impl AsRawFd for Stream {
     fn as_raw_fd(&self) -> RawFd {
         match self {
             Stream::TcpStream(stream) => stream.as_raw_fd(),
@@ -133,10 +251,156 @@ impl Stream {
     }
 }
 
+/// Support for Unix sockets in the abstract namespace.
+///
+/// The standard library does not support these sockets, see
+/// <https://github.com/rust-lang/rust/issues/42048>. The socket address is built by hand instead of
+/// via `nix`, since `nix` 0.17 computes the length of Unix socket addresses by dereferencing a null
+/// pointer. Thus, this needs the `allow-unsafe-code` feature.
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    feature = "allow-unsafe-code"
+))]
+mod abstract_socket {
+    use std::io::{Error, ErrorKind, Result};
+    use std::mem::size_of;
+    use std::os::unix::io::{AsRawFd, FromRawFd};
+    use std::os::unix::net::UnixStream;
+
+    use nix::sys::socket::{socket, AddressFamily, SockFlag, SockType};
+
+    use crate::utils::RawFdContainer;
+
+    /// The address of a socket in the abstract namespace.
+    pub(super) struct Address {
+        addr: libc::sockaddr_un,
+        len: libc::socklen_t,
+    }
+
+    impl Address {
+        pub(super) fn as_ptr(&self) -> *const libc::sockaddr {
+            let addr: *const libc::sockaddr_un = &self.addr;
+            addr as *const libc::sockaddr
+        }
+
+        pub(super) fn len(&self) -> libc::socklen_t {
+            self.len
+        }
+    }
+
+    /// Get the address of the abstract socket with the given name.
+    ///
+    /// The name does not include the leading null byte.
+    pub(super) fn address(name: &[u8]) -> Result<Address> {
+        let mut addr = libc::sockaddr_un {
+            sun_family: libc::AF_UNIX as libc::sa_family_t,
+            sun_path: [0; 108],
+        };
+        // The first byte of sun_path stays zero, which marks the address as abstract
+        if name.len() >= addr.sun_path.len() {
+            return Err(Error::new(ErrorKind::InvalidInput, "socket name too long"));
+        }
+        for (dest, &byte) in addr.sun_path[1..].iter_mut().zip(name) {
+            *dest = byte as libc::c_char;
+        }
+        // The name is not null-terminated, so the length has to be exact. On Linux, sun_path
+        // directly follows sun_family.
+        let len = size_of::<libc::sa_family_t>() + 1 + name.len();
+        Ok(Address {
+            addr,
+            len: len as libc::socklen_t,
+        })
+    }
+
+    /// Connect to the abstract socket with the given name.
+    pub(super) fn connect(name: &[u8]) -> Result<UnixStream> {
+        let addr = address(name)?;
+        let fd = socket(
+            AddressFamily::Unix,
+            SockType::Stream,
+            SockFlag::SOCK_CLOEXEC,
+            None,
+        )
+        .map_err(|_| Error::last_os_error())?;
+        // The container closes the FD again if connecting fails
+        let fd = RawFdContainer::new(fd);
+        // Safety: The address is a valid sockaddr_un and its length does not exceed its size.
+        if unsafe { libc::connect(fd.as_raw_fd(), addr.as_ptr(), addr.len()) } != 0 {
+            return Err(Error::last_os_error());
+        }
+        // Safety: RawFdContainer owns the file descriptor and we transfer ownership.
+        Ok(unsafe { UnixStream::from_raw_fd(fd.into_raw_fd()) })
+    }
+
+    #[cfg(test)]
+    mod test {
+        use std::io::{Error, Read, Write};
+        use std::os::unix::io::{AsRawFd, FromRawFd};
+        use std::os::unix::net::UnixStream;
+
+        use nix::sys::socket::{accept, listen, socket, AddressFamily, SockFlag, SockType};
+
+        use super::{address, connect};
+        use crate::rust_connection::fd_read_write::{ReadFD, WriteFD};
+        use crate::rust_connection::stream::Stream;
+        use crate::rust_connection::xauth::Family;
+        use crate::utils::RawFdContainer;
+
+        #[test]
+        fn connect_abstract() {
+            let name = format!("/tmp/.X11-unix/x11rb-test-{}", std::process::id());
+            let listener = socket(
+                AddressFamily::Unix,
+                SockType::Stream,
+                SockFlag::empty(),
+                None,
+            )
+            .unwrap();
+            let listener = RawFdContainer::new(listener);
+            let addr = address(name.as_bytes()).unwrap();
+            let res = unsafe { libc::bind(listener.as_raw_fd(), addr.as_ptr(), addr.len()) };
+            assert_eq!(res, 0, "{}", Error::last_os_error());
+            listen(listener.as_raw_fd(), 1).unwrap();
+
+            let mut stream = Stream::UnixStream(connect(name.as_bytes()).unwrap());
+            let server = accept(listener.as_raw_fd()).unwrap();
+            let mut server = unsafe { UnixStream::from_raw_fd(server) };
+
+            stream.write_all(b"ping", Vec::new()).unwrap();
+            let mut buf = [0; 4];
+            server.read_exact(&mut buf).unwrap();
+            assert_eq!(&buf, b"ping");
+
+            server.write_all(b"pong").unwrap();
+            stream.read_exact(&mut buf, &mut Vec::new()).unwrap();
+            assert_eq!(&buf, b"pong");
+
+            assert_eq!(stream.peer_addr().unwrap().0, Family::Local);
+        }
+
+        #[test]
+        fn connect_abstract_missing() {
+            let name = format!("/tmp/.X11-unix/x11rb-missing-{}", std::process::id());
+            assert!(connect(name.as_bytes()).is_err());
+        }
+
+        #[test]
+        fn name_too_long() {
+            assert!(address(&[b'a'; 107]).is_ok());
+            assert!(address(&[b'a'; 108]).is_err());
+        }
+    }
+}
+
 #[cfg(unix)]
-fn do_write(fd: RawFd, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+pub(super) fn do_write(
+    fd: RawFd,
+    bufs: &[IoSlice<'_>],
+    fds: &mut Vec<RawFdContainer>,
+    flags: nix::sys::socket::MsgFlags,
+) -> Result<usize> {
     use nix::sys::{
-        socket::{sendmsg, ControlMessage, MsgFlags},
+        socket::{sendmsg, ControlMessage},
         uio::IoVec,
     };
 
@@ -147,12 +411,12 @@ fn do_write(fd: RawFd, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> R
     let res = if !fds.is_empty() {
         let fds = fds.iter().map(|fd| fd.as_raw_fd()).collect::<Vec<_>>();
         let cmsgs = [ControlMessage::ScmRights(&fds[..])];
-        sendmsg(fd, &iov, &cmsgs, MsgFlags::empty(), None)
+        sendmsg(fd, &iov, &cmsgs, flags, None)
     } else {
-        sendmsg(fd, &iov, &[], MsgFlags::empty(), None)
+        sendmsg(fd, &iov, &[], flags, None)
     };
     // Nothing touched errno since sendmsg() failed
-    let res = res.map_err(|_| std::io::Error::last_os_error())?;
+    let res = res.map_err(|_| Error::last_os_error())?;
 
     // We successfully sent all FDs
     fds.clear();
@@ -164,7 +428,13 @@ impl WriteFD for Stream {
     fn write(&mut self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
         #[cfg(unix)]
         {
-            do_write(self.as_raw_fd(), &[IoSlice::new(buf)], fds)
+            use nix::sys::socket::MsgFlags;
+            do_write(
+                self.as_raw_fd(),
+                &[IoSlice::new(buf)],
+                fds,
+                MsgFlags::empty(),
+            )
         }
         #[cfg(not(unix))]
         {
@@ -185,7 +455,8 @@ impl WriteFD for Stream {
     ) -> Result<usize> {
         #[cfg(unix)]
         {
-            do_write(self.as_raw_fd(), bufs, fds)
+            use nix::sys::socket::MsgFlags;
+            do_write(self.as_raw_fd(), bufs, fds, MsgFlags::empty())
         }
         #[cfg(not(unix))]
         {
@@ -203,37 +474,140 @@ impl WriteFD for Stream {
         // We do no buffering
         Ok(())
     }
+
+    #[cfg(unix)]
+    fn write_vectored_nonblocking(
+        &mut self,
+        bufs: &[IoSlice<'_>],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        use nix::sys::socket::MsgFlags;
+        do_write(self.as_raw_fd(), bufs, fds, MsgFlags::MSG_DONTWAIT)
+    }
+
+    #[cfg(unix)]
+    fn wait_writable_or_readable(&mut self) -> Result<bool> {
+        use nix::poll::{poll, PollFd, PollFlags};
+
+        let mut fds = [PollFd::new(
+            self.as_raw_fd(),
+            PollFlags::POLLIN | PollFlags::POLLOUT,
+        )];
+        loop {
+            match poll(&mut fds, -1) {
+                Ok(_) => break,
+                Err(nix::Error::Sys(nix::errno::Errno::EINTR)) => {}
+                // Nothing touched errno since poll() failed
+                Err(_) => return Err(Error::last_os_error()),
+            }
+        }
+        // Errors and hangups are reported by the next read
+        let revents = fds[0].revents().unwrap_or_else(PollFlags::empty);
+        Ok(revents != PollFlags::POLLOUT)
+    }
+}
+
+// Chosen by checking what libxcb does
+#[cfg(unix)]
+const MAX_FDS_RECEIVED: usize = 16;
+
+/// Read from a socket, receiving file descriptors.
+///
+/// This calls `recvmsg()` without asking for the peer's address. `nix` 0.17 always asks for it and
+/// computes the length of Unix socket addresses by dereferencing a null pointer, which aborts in
+/// debug builds.
+#[cfg(all(unix, feature = "allow-unsafe-code"))]
+// The types of the fields of msghdr and cmsghdr differ between platforms
+#[allow(trivial_numeric_casts)]
+pub(super) fn do_read(
+    fd: RawFd,
+    buf: &mut [u8],
+    fd_storage: &mut Vec<RawFdContainer>,
+    flags: nix::sys::socket::MsgFlags,
+) -> Result<usize> {
+    use std::mem::size_of;
+
+    // Safety: CMSG_SPACE() only does arithmetic
+    let space = unsafe { libc::CMSG_SPACE((MAX_FDS_RECEIVED * size_of::<RawFd>()) as _) } as usize;
+    // Use u64 for the buffer so that it is suitably aligned for a cmsghdr
+    let mut cmsg_buffer = vec![0u64; space / size_of::<u64>() + 1];
+    let mut iov = libc::iovec {
+        iov_base: buf.as_mut_ptr() as *mut libc::c_void,
+        iov_len: buf.len(),
+    };
+    // Safety: A msghdr consisting of only zeros is valid
+    let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+    msg.msg_iov = &mut iov;
+    msg.msg_iovlen = 1;
+    msg.msg_control = cmsg_buffer.as_mut_ptr() as *mut libc::c_void;
+    msg.msg_controllen = space as _;
+
+    // Safety: msg points to valid buffers of the given sizes
+    let bytes = unsafe { libc::recvmsg(fd, &mut msg, flags.bits()) };
+    if bytes < 0 {
+        return Err(Error::last_os_error());
+    }
+
+    // Safety: recvmsg() filled in the control messages and their lengths
+    unsafe {
+        let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+        while !cmsg.is_null() {
+            if (*cmsg).cmsg_level == libc::SOL_SOCKET && (*cmsg).cmsg_type == libc::SCM_RIGHTS {
+                let data = libc::CMSG_DATA(cmsg) as *const RawFd;
+                let length = (*cmsg).cmsg_len as usize - libc::CMSG_LEN(0) as usize;
+                for i in 0..length / size_of::<RawFd>() {
+                    let fd = std::ptr::read_unaligned(data.add(i));
+                    fd_storage.push(RawFdContainer::new(fd));
+                }
+            }
+            cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+        }
+    }
+
+    Ok(bytes as usize)
+}
+
+/// Read from a socket, receiving file descriptors.
+///
+/// Without `allow-unsafe-code`, this has to use `nix`. Beware that `nix` 0.17 aborts in debug
+/// builds when the peer's address is a Unix socket address, see the other version of this function.
+#[cfg(all(unix, not(feature = "allow-unsafe-code")))]
+pub(super) fn do_read(
+    fd: RawFd,
+    buf: &mut [u8],
+    fd_storage: &mut Vec<RawFdContainer>,
+    flags: nix::sys::socket::MsgFlags,
+) -> Result<usize> {
+    use nix::sys::{
+        socket::{recvmsg, ControlMessageOwned},
+        uio::IoVec,
+    };
+
+    let mut cmsg = nix::cmsg_space!([RawFd; MAX_FDS_RECEIVED]);
+    let iov = [IoVec::from_mut_slice(buf)];
+
+    let msg = recvmsg(fd, &iov[..], Some(&mut cmsg), flags);
+    // Nothing touched errno since recvmsg() failed
+    let msg = msg.map_err(|_| Error::last_os_error())?;
+
+    let fds_received = msg
+        .cmsgs()
+        .flat_map(|cmsg| match cmsg {
+            ControlMessageOwned::ScmRights(r) => r,
+            _ => Vec::new(),
+        })
+        .map(RawFdContainer::new);
+    fd_storage.extend(fds_received);
+
+    Ok(msg.bytes)
 }
 
 impl ReadFD for Stream {
     fn read(&mut self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
         #[cfg(unix)]
         {
-            use nix::sys::{
-                socket::{recvmsg, ControlMessageOwned, MsgFlags},
-                uio::IoVec,
-            };
-
-            // Chosen by checking what libxcb does
-            const MAX_FDS_RECEIVED: usize = 16;
-            let mut cmsg = nix::cmsg_space!([RawFd; MAX_FDS_RECEIVED]);
-            let iov = [IoVec::from_mut_slice(buf)];
-
-            let fd = self.as_raw_fd();
-            let msg = recvmsg(fd, &iov[..], Some(&mut cmsg), MsgFlags::empty());
-            // Nothing touched errno since recvmsg() failed
-            let msg = msg.map_err(|_| std::io::Error::last_os_error())?;
-
-            let fds_received = msg
-                .cmsgs()
-                .flat_map(|cmsg| match cmsg {
-                    ControlMessageOwned::ScmRights(r) => r,
-                    _ => Vec::new(),
-                })
-                .map(RawFdContainer::new);
-            fd_storage.extend(fds_received);
-
-            Ok(msg.bytes)
+            use nix::sys::socket::MsgFlags;
+            do_read(self.as_raw_fd(), buf, fd_storage, MsgFlags::empty())
         }
         #[cfg(not(unix))]
         {
@@ -245,4 +619,97 @@ impl ReadFD for Stream {
             }
         }
     }
+
+    #[cfg(unix)]
+    fn read_nonblocking(
+        &mut self,
+        buf: &mut [u8],
+        fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Result<usize> {
+        use nix::sys::socket::MsgFlags;
+        do_read(self.as_raw_fd(), buf, fd_storage, MsgFlags::MSG_DONTWAIT)
+    }
+
+    #[cfg(unix)]
+    fn wait_readable(&mut self, timeout: Duration) -> Result<bool> {
+        use nix::poll::{poll, PollFd, PollFlags};
+
+        // poll() takes the timeout in milliseconds; round up so that we do not wake up early
+        let millis = timeout
+            .checked_add(Duration::from_nanos(999_999))
+            .unwrap_or(timeout)
+            .as_millis();
+        let millis = std::cmp::min(millis, i32::max_value() as u128) as i32;
+        let mut fds = [PollFd::new(self.as_raw_fd(), PollFlags::POLLIN)];
+        loop {
+            match poll(&mut fds, millis) {
+                Ok(0) => return Ok(false),
+                Ok(_) => return Ok(true),
+                Err(nix::Error::Sys(nix::errno::Errno::EINTR)) => {}
+                // Nothing touched errno since poll() failed
+                Err(_) => return Err(Error::last_os_error()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, TcpListener};
+
+    use super::{connect_tcp, family_address};
+    use crate::rust_connection::xauth::Family;
+
+    #[test]
+    fn family_address_v4() {
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2));
+        assert_eq!(
+            family_address(ip),
+            Some((Family::Internet, vec![192, 168, 1, 2]))
+        );
+        // Like libxcb, only 127.0.0.1 counts as a local connection
+        assert_eq!(family_address(IpAddr::V4(Ipv4Addr::LOCALHOST)), None);
+        let ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 2));
+        assert_eq!(
+            family_address(ip),
+            Some((Family::Internet, vec![127, 0, 0, 2]))
+        );
+    }
+
+    #[test]
+    fn family_address_v6() {
+        let ip: Ipv6Addr = "2001:db8::1".parse().unwrap();
+        assert_eq!(
+            family_address(IpAddr::V6(ip)),
+            Some((Family::Internet6, ip.octets().to_vec()))
+        );
+        assert_eq!(family_address(IpAddr::V6(Ipv6Addr::LOCALHOST)), None);
+        // IPv4-compatible addresses are not converted to IPv4
+        let ip: Ipv6Addr = "::2".parse().unwrap();
+        assert_eq!(
+            family_address(IpAddr::V6(ip)),
+            Some((Family::Internet6, ip.octets().to_vec()))
+        );
+    }
+
+    #[test]
+    fn family_address_v4_mapped() {
+        let ip: IpAddr = "::ffff:10.0.0.1".parse().unwrap();
+        assert_eq!(
+            family_address(ip),
+            Some((Family::Internet, vec![10, 0, 0, 1]))
+        );
+        let ip: IpAddr = "::ffff:127.0.0.1".parse().unwrap();
+        assert_eq!(family_address(ip), None);
+    }
+
+    #[test]
+    fn connect_tcp_protocol() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let stream = connect_tcp("127.0.0.1", port, Some("inet")).unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), listener.local_addr().unwrap());
+        assert!(connect_tcp("127.0.0.1", port, Some("inet6")).is_err());
+    }
 }
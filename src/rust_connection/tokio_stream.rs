@@ -0,0 +1,317 @@
+//! An implementation of `AsyncReadFD` and `AsyncWriteFD` on top of tokio.
+
+#[cfg(unix)]
+use std::io::IoSlice;
+use std::io::{Error, ErrorKind, Result};
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+#[cfg(unix)]
+use tokio::io::Interest;
+use tokio::net::TcpStream;
+#[cfg(unix)]
+use tokio::net::UnixStream;
+
+use super::fd_read_write::{AsyncReadFD, AsyncWriteFD};
+use super::Stream;
+use crate::utils::RawFdContainer;
+
+/// A connection to an X11 server via a tokio `TcpStream` or `UnixStream`.
+///
+/// Clones of a `TokioStream` refer to the same socket. This allows to use one clone for reading
+/// and another one for writing, as needed by [`AsyncRustConnection`](super::AsyncRustConnection).
+/// File descriptors can be passed over Unix sockets.
+///
+/// The stream must only be used from within a tokio runtime that has I/O enabled.
+#[derive(Debug, Clone)]
+pub struct TokioStream(Arc<Inner>);
+
+#[derive(Debug)]
+enum Inner {
+    TcpStream(TcpStream),
+    #[cfg(unix)]
+    UnixStream(UnixStream),
+}
+
+impl TokioStream {
+    /// Register an already connected blocking stream with the current tokio runtime.
+    pub(super) fn from_stream(stream: Stream) -> Result<Self> {
+        let inner = match stream {
+            Stream::TcpStream(stream) => {
+                stream.set_nonblocking(true)?;
+                Inner::TcpStream(TcpStream::from_std(stream)?)
+            }
+            #[cfg(unix)]
+            Stream::UnixStream(stream) => {
+                stream.set_nonblocking(true)?;
+                Inner::UnixStream(UnixStream::from_std(stream)?)
+            }
+        };
+        Ok(Self(Arc::new(inner)))
+    }
+}
+
+impl From<TcpStream> for TokioStream {
+    fn from(stream: TcpStream) -> Self {
+        Self(Arc::new(Inner::TcpStream(stream)))
+    }
+}
+
+#[cfg(unix)]
+impl From<UnixStream> for TokioStream {
+    fn from(stream: UnixStream) -> Self {
+        Self(Arc::new(Inner::UnixStream(stream)))
+    }
+}
+
+#[cfg(unix)]
+impl AsRawFd for TokioStream {
+    fn as_raw_fd(&self) -> RawFd {
+        match &*self.0 {
+            Inner::TcpStream(stream) => stream.as_raw_fd(),
+            Inner::UnixStream(stream) => stream.as_raw_fd(),
+        }
+    }
+}
+
+impl AsyncReadFD for TokioStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+        fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>> {
+        loop {
+            let result = match &*self.0 {
+                Inner::TcpStream(stream) => {
+                    ready!(stream.poll_read_ready(cx))?;
+                    stream.try_read(buf)
+                }
+                #[cfg(unix)]
+                Inner::UnixStream(stream) => {
+                    use nix::sys::socket::MsgFlags;
+                    ready!(stream.poll_read_ready(cx))?;
+                    stream.try_io(Interest::READABLE, || {
+                        super::stream::do_read(
+                            stream.as_raw_fd(),
+                            buf,
+                            fd_storage,
+                            MsgFlags::MSG_DONTWAIT,
+                        )
+                    })
+                }
+            };
+            match result {
+                // The readiness was cleared, so the next iteration waits for the socket
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+                result => return Poll::Ready(result),
+            }
+        }
+    }
+}
+
+impl AsyncWriteFD for TokioStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>> {
+        loop {
+            let result = match &*self.0 {
+                Inner::TcpStream(stream) => {
+                    if !fds.is_empty() {
+                        return Poll::Ready(Err(Error::new(
+                            ErrorKind::Other,
+                            "FD passing is unsupported",
+                        )));
+                    }
+                    ready!(stream.poll_write_ready(cx))?;
+                    stream.try_write(buf)
+                }
+                #[cfg(unix)]
+                Inner::UnixStream(stream) => {
+                    use nix::sys::socket::MsgFlags;
+                    ready!(stream.poll_write_ready(cx))?;
+                    stream.try_io(Interest::WRITABLE, || {
+                        super::stream::do_write(
+                            stream.as_raw_fd(),
+                            &[IoSlice::new(buf)],
+                            fds,
+                            MsgFlags::MSG_DONTWAIT,
+                        )
+                    })
+                }
+            };
+            match result {
+                // The readiness was cleared, so the next iteration waits for the socket
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+                result => return Poll::Ready(result),
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        // We do no buffering
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use std::io::{IoSlice, Read, Write};
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::net::UnixStream;
+    use std::pin::Pin;
+    use std::thread;
+
+    use nix::sys::socket::MsgFlags;
+    use nix::unistd::{pipe, read, write};
+    use tokio::runtime::{Builder, Runtime};
+
+    use super::TokioStream;
+    use crate::async_connection::poll_fn;
+    use crate::protocol::xproto::ConnectionExt as _;
+    use crate::rust_connection::stream::{do_read, do_write};
+    use crate::rust_connection::test::test_setup;
+    use crate::rust_connection::{AsyncReadFD, AsyncRustConnection, AsyncWriteFD};
+    use crate::utils::RawFdContainer;
+
+    fn runtime() -> Runtime {
+        Builder::new_current_thread().enable_io().build().unwrap()
+    }
+
+    fn tokio_stream(stream: UnixStream) -> TokioStream {
+        stream.set_nonblocking(true).unwrap();
+        tokio::net::UnixStream::from_std(stream).unwrap().into()
+    }
+
+    // Check that the given FD is the writing end of the given pipe
+    fn check_pipe(pipe_read: RawFdContainer, pipe_write: RawFdContainer) {
+        assert_eq!(write(pipe_write.as_raw_fd(), b"pipe").unwrap(), 4);
+        let mut buf = [0; 4];
+        assert_eq!(read(pipe_read.as_raw_fd(), &mut buf).unwrap(), 4);
+        assert_eq!(&buf, b"pipe");
+    }
+
+    fn new_pipe() -> (RawFdContainer, RawFdContainer) {
+        let (read, write) = pipe().unwrap();
+        (RawFdContainer::new(read), RawFdContainer::new(write))
+    }
+
+    #[test]
+    fn pass_fds() {
+        let (client, server) = UnixStream::pair().unwrap();
+        runtime().block_on(async {
+            let mut stream = tokio_stream(client);
+
+            // Send a file descriptor to the server
+            let (pipe_read, pipe_write) = new_pipe();
+            let mut fds = vec![pipe_write];
+            let written =
+                poll_fn(|cx| Pin::new(&mut stream).poll_write(cx, &[1, 2, 3, 4], &mut fds)).await;
+            assert_eq!(written.unwrap(), 4);
+            assert!(fds.is_empty());
+
+            let mut buf = [0; 4];
+            let mut received = Vec::new();
+            let length = do_read(
+                server.as_raw_fd(),
+                &mut buf,
+                &mut received,
+                MsgFlags::empty(),
+            );
+            assert_eq!(length.unwrap(), 4);
+            assert_eq!(buf, [1, 2, 3, 4]);
+            assert_eq!(received.len(), 1);
+            check_pipe(pipe_read, received.remove(0));
+
+            // Receive a file descriptor from the server, after the client started waiting
+            let (pipe_read, pipe_write) = new_pipe();
+            let server = thread::spawn(move || {
+                let mut fds = vec![pipe_write];
+                let bufs = [IoSlice::new(&[5, 6])];
+                let written = do_write(server.as_raw_fd(), &bufs, &mut fds, MsgFlags::empty());
+                assert_eq!(written.unwrap(), 2);
+                server
+            });
+            let mut buf = [0; 4];
+            let mut received = Vec::new();
+            let length =
+                poll_fn(|cx| Pin::new(&mut stream).poll_read(cx, &mut buf, &mut received)).await;
+            assert_eq!(length.unwrap(), 2);
+            assert_eq!(buf[..2], [5, 6]);
+            assert_eq!(received.len(), 1);
+            check_pipe(pipe_read, received.remove(0));
+            drop(server.join().unwrap());
+        });
+    }
+
+    #[test]
+    fn reply_on_current_thread_runtime() {
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let server = thread::spawn(move || {
+            // Wait for the GetInputFocus request and answer it
+            let mut request = [0; 4];
+            server.read_exact(&mut request).unwrap();
+            assert_eq!(request, [43, 0, 1, 0]);
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+            reply[8..12].copy_from_slice(&42u32.to_ne_bytes());
+            server.write_all(&reply).unwrap();
+            server
+        });
+        runtime().block_on(async {
+            let stream = tokio_stream(client);
+            let conn =
+                AsyncRustConnection::for_connected_stream(stream.clone(), stream, test_setup())
+                    .unwrap();
+            let reply = conn.get_input_focus().unwrap().reply_async().await.unwrap();
+            assert_eq!(u32::from(reply.focus), 42);
+        });
+        drop(server.join().unwrap());
+    }
+
+    // Without allow-unsafe-code, nix aborts when the peer has a named Unix socket address
+    #[cfg(feature = "allow-unsafe-code")]
+    #[test]
+    fn connect_to_socket_path() {
+        use std::os::unix::net::UnixListener;
+
+        use crate::connection::Connection;
+        use crate::rust_connection::test::test_setup_with_screen;
+        use crate::x11_utils::Serialize;
+
+        let path = std::env::temp_dir().join(format!("x11rb-tokio-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        let setup = test_setup_with_screen();
+        let setup_bytes = setup.serialize();
+        let server = thread::spawn(move || {
+            let (mut server, _) = listener.accept().unwrap();
+            // Skip the setup request, including any auth info from ~/.Xauthority
+            let mut request = [0; 12];
+            server.read_exact(&mut request).unwrap();
+            let padded_length = |offset: usize| {
+                let length = u16::from_ne_bytes([request[offset], request[offset + 1]]);
+                (usize::from(length) + 3) & !3
+            };
+            let mut auth = vec![0; padded_length(6) + padded_length(8)];
+            server.read_exact(&mut auth).unwrap();
+            server.write_all(&setup_bytes).unwrap();
+            server
+        });
+
+        let dpy_name = path.to_str().unwrap();
+        let result = runtime().block_on(AsyncRustConnection::connect(Some(dpy_name)));
+        std::fs::remove_file(&path).unwrap();
+        let (conn, screen) = result.unwrap();
+        assert_eq!(screen, 0);
+        assert_eq!(conn.setup(), &setup);
+        drop(server.join().unwrap());
+    }
+}
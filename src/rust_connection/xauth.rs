@@ -1,24 +1,90 @@
 //! Helpers for working with `~/.Xauthority`.
+//!
+//! This module allows to read and modify `.Xauthority` files, similar to what the `xauth` program
+//! does. For example, the following adds a new random cookie for display `:1`:
+//!
+//! ```no_run
+//! use x11rb::rust_connection::xauth::{self, AuthEntry, Family};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let hostname = b"localhost".to_vec();
+//! let entry = AuthEntry::new_mit_magic_cookie_1(Family::Local, hostname, 1)?;
+//! if let Some(file_name) = xauth::xauthority_file_name() {
+//!     xauth::add_entry(&file_name, entry)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
 
 use std::io::Error;
 
+use super::des::Des;
 use crate::protocol::xproto::Family as X11Family;
 
 const MIT_MAGIC_COOKIE_1: &[u8] = b"MIT-MAGIC-COOKIE-1";
+const XDM_AUTHORIZATION_1: &[u8] = b"XDM-AUTHORIZATION-1";
+
+/// A method for authenticating to the X11 server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMethod {
+    /// The client sends a secret cookie to the server.
+    MitMagicCookie1,
+    /// The client sends a timestamp and its own address, encrypted with DES.
+    XdmAuthorization1,
+}
+
+impl AuthMethod {
+    /// The order in which authentication methods are tried by default.
+    ///
+    /// Like libxcb, XDM-AUTHORIZATION-1 is preferred if `~/.Xauthority` contains suitable entries
+    /// for both methods.
+    pub const DEFAULT_PREFERENCE: &'static [AuthMethod] =
+        &[AuthMethod::XdmAuthorization1, AuthMethod::MitMagicCookie1];
+
+    /// Get the name of this method.
+    ///
+    /// This is the name that is used in `~/.Xauthority` and that is sent to the X11 server.
+    pub fn name(self) -> &'static [u8] {
+        match self {
+            AuthMethod::MitMagicCookie1 => MIT_MAGIC_COOKIE_1,
+            AuthMethod::XdmAuthorization1 => XDM_AUTHORIZATION_1,
+        }
+    }
+
+    /// Check if the given data from `~/.Xauthority` can be used with this method.
+    fn data_is_valid(self, data: &[u8]) -> bool {
+        match self {
+            AuthMethod::MitMagicCookie1 => true,
+            // 8 bytes of data that is sent to the server and 8 bytes of key
+            AuthMethod::XdmAuthorization1 => data.len() == 16,
+        }
+    }
+}
 
 /// A family describes how to interpret some bytes as an address in an `AuthEntry`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum Family {
+pub enum Family {
+    /// An IPv4 address.
     Internet,
+    /// A DECnet address.
     DECnet,
+    /// A Chaosnet address.
     Chaos,
+    /// An address that is interpreted by the server.
     ServerInterpreted,
+    /// An IPv6 address.
     Internet6,
+    /// Matches any address.
     Wild,
+    /// A local connection; the address is the host name.
     Local,
+    /// A secure RPC netname.
     Netname,
+    /// A Kerberos 5 principal.
     Krb5Principal,
+    /// A local connection that is identified by the host name.
     LocalHost,
+    /// A family that is not known to this library.
     Unknown(u16),
 }
 
@@ -30,6 +96,7 @@ impl From<X11Family> for Family {
             X11Family::Chaos => Family::Chaos,
             X11Family::ServerInterpreted => Family::ServerInterpreted,
             X11Family::Internet6 => Family::Internet6,
+            _ => Family::Unknown(value.into()),
         }
     }
 }
@@ -61,23 +128,93 @@ impl From<u16> for Family {
     }
 }
 
+impl From<Family> for u16 {
+    fn from(value: Family) -> u16 {
+        match value {
+            Family::Internet => X11Family::Internet.into(),
+            Family::DECnet => X11Family::DECnet.into(),
+            Family::Chaos => X11Family::Chaos.into(),
+            Family::ServerInterpreted => X11Family::ServerInterpreted.into(),
+            Family::Internet6 => X11Family::Internet6.into(),
+            Family::Wild => 65535,
+            Family::Local => 256,
+            Family::Netname => 254,
+            Family::Krb5Principal => 253,
+            Family::LocalHost => 252,
+            Family::Unknown(value) => value,
+        }
+    }
+}
+
 /// A single entry of an `.Xauthority` file.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct AuthEntry {
-    family: Family,
-    address: Vec<u8>,
-    number: Vec<u8>,
-    name: Vec<u8>,
-    data: Vec<u8>,
+pub struct AuthEntry {
+    /// How to interpret `address`.
+    pub family: Family,
+    /// The address of the X11 server.
+    pub address: Vec<u8>,
+    /// The display number as a decimal string, or empty to match any display.
+    pub number: Vec<u8>,
+    /// The name of the authentication method.
+    pub name: Vec<u8>,
+    /// The data for the authentication method.
+    pub data: Vec<u8>,
 }
 
+impl AuthEntry {
+    /// Create a new entry with a random MIT-MAGIC-COOKIE-1 for the given display.
+    pub fn new_mit_magic_cookie_1(
+        family: Family,
+        address: Vec<u8>,
+        display: u16,
+    ) -> Result<Self, Error> {
+        Ok(AuthEntry {
+            family,
+            address,
+            number: display.to_string().into_bytes(),
+            name: MIT_MAGIC_COOKIE_1.to_vec(),
+            data: generate_mit_magic_cookie_1()?,
+        })
+    }
+
+    /// Check if this entry is for the same display and authentication method as `other`.
+    ///
+    /// Like `xauth add`, `add_entry()` uses this to decide which entries to replace.
+    pub fn same_display_and_method(&self, other: &AuthEntry) -> bool {
+        self.family == other.family
+            && self.address == other.address
+            && self.number == other.number
+            && self.name == other.name
+    }
+}
+
+/// Generate a random cookie for MIT-MAGIC-COOKIE-1.
+///
+/// The 16 bytes of the cookie are read from `/dev/urandom`.
+pub fn generate_mit_magic_cookie_1() -> Result<Vec<u8>, Error> {
+    use std::io::Read;
+
+    let mut cookie = vec![0; 16];
+    std::fs::File::open("/dev/urandom")?.read_exact(&mut cookie)?;
+    Ok(cookie)
+}
+
+pub use file::{
+    add_entry, read_entries, remove_entries, update_file, write_entries, xauthority_file_name,
+    XAuthorityEntries, XAuthorityLock,
+};
+
 mod file {
-    //! Code for actually reading `~/.Xauthority`.
+    //! Code for actually reading and writing `~/.Xauthority`.
 
+    use std::convert::TryFrom;
     use std::env::var_os;
-    use std::fs::File;
-    use std::io::{BufReader, Error, ErrorKind, Read};
-    use std::path::PathBuf;
+    use std::ffi::OsString;
+    use std::fs::{self, File, OpenOptions};
+    use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Write};
+    use std::path::{Path, PathBuf};
+    use std::thread::sleep;
+    use std::time::{Duration, SystemTime};
 
     use super::AuthEntry;
 
@@ -126,11 +263,33 @@ mod file {
         }))
     }
 
+    /// Write a single `u16` to an `.Xauthority` file.
+    fn write_u16<W: Write>(write: &mut W, value: u16) -> Result<(), Error> {
+        write.write_all(&value.to_be_bytes())
+    }
+
+    /// Write a single "byte array" to an `.Xauthority` file.
+    fn write_string<W: Write>(write: &mut W, value: &[u8]) -> Result<(), Error> {
+        let length = u16::try_from(value.len())
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "entry too long for .Xauthority"))?;
+        write_u16(write, length)?;
+        write.write_all(value)
+    }
+
+    /// Write a single entry to an `.Xauthority` file.
+    fn write_entry<W: Write>(write: &mut W, entry: &AuthEntry) -> Result<(), Error> {
+        write_u16(write, entry.family.into())?;
+        write_string(write, &entry.address)?;
+        write_string(write, &entry.number)?;
+        write_string(write, &entry.name)?;
+        write_string(write, &entry.data)
+    }
+
     /// Get the file name for `~/.Xauthority` based on environment variables.
     ///
-    /// The code in libXau contains a special case for Windows (looks like cygwin) that is not
-    /// handled here (yet?).
-    fn get_xauthority_file_name() -> Option<PathBuf> {
+    /// This is `$XAUTHORITY` if set and `$HOME/.Xauthority` otherwise. The code in libXau contains
+    /// a special case for Windows (looks like cygwin) that is not handled here (yet?).
+    pub fn xauthority_file_name() -> Option<PathBuf> {
         if let Some(name) = var_os("XAUTHORITY") {
             return Some(name.into());
         }
@@ -144,7 +303,7 @@ mod file {
 
     /// An iterator over the entries of an `.Xauthority` file
     #[derive(Debug)]
-    pub(crate) struct XAuthorityEntries(BufReader<File>);
+    pub struct XAuthorityEntries<R: Read = BufReader<File>>(R);
 
     impl XAuthorityEntries {
         /// Open `~/.Xauthority` for reading.
@@ -152,18 +311,26 @@ mod file {
         /// This function returns `Ok(None)` when the location of the `.Xauthority` file could not
         /// be determined. If opening the file failed (for example, because it does not exist),
         /// that error is returned.
-        pub(crate) fn new() -> Result<Option<XAuthorityEntries>, Error> {
-            get_xauthority_file_name()
-                .map(File::open)
-                .transpose()?
-                // At this point we have Option<File> and errors while opening the file were
-                // returned to the caller.
-                .map(|file| Ok(XAuthorityEntries(BufReader::new(file))))
+        pub fn new() -> Result<Option<XAuthorityEntries>, Error> {
+            xauthority_file_name()
+                .map(|name| XAuthorityEntries::open(&name))
                 .transpose()
         }
+
+        /// Open the given `.Xauthority` file for reading.
+        pub fn open(path: &Path) -> Result<XAuthorityEntries, Error> {
+            Ok(XAuthorityEntries(BufReader::new(File::open(path)?)))
+        }
+    }
+
+    impl<R: Read> XAuthorityEntries<R> {
+        /// Read the entries from the given reader.
+        pub fn from_reader(read: R) -> Self {
+            XAuthorityEntries(read)
+        }
     }
 
-    impl Iterator for XAuthorityEntries {
+    impl<R: Read> Iterator for XAuthorityEntries<R> {
         type Item = Result<AuthEntry, Error>;
 
         fn next(&mut self) -> Option<Self::Item> {
@@ -171,11 +338,207 @@ mod file {
         }
     }
 
+    /// Read all entries of the given `.Xauthority` file.
+    ///
+    /// A file that does not exist is treated like an empty file.
+    pub fn read_entries(path: &Path) -> Result<Vec<AuthEntry>, Error> {
+        match XAuthorityEntries::open(path) {
+            Ok(entries) => entries.collect(),
+            Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Append a suffix to a path, e.g. turn `/home/foo/.Xauthority` into
+    /// `/home/foo/.Xauthority-c`.
+    fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+        let mut name: OsString = path.as_os_str().to_owned();
+        name.push(suffix);
+        name.into()
+    }
+
+    /// A lock on an `.Xauthority` file.
+    ///
+    /// The locking protocol is the same as the one used by `XauLockAuth()` from libXau, so that
+    /// this cooperates with other programs like `xauth`. The lock is released when this object is
+    /// dropped.
+    #[derive(Debug)]
+    pub struct XAuthorityLock {
+        creat_name: PathBuf,
+        link_name: PathBuf,
+    }
+
+    impl XAuthorityLock {
+        /// Lock the given `.Xauthority` file.
+        ///
+        /// The lock is tried `retries` times, waiting for `timeout` between attempts. An existing
+        /// lock that is older than `dead` is assumed to be stale and is removed. Like in libXau, a
+        /// `dead` of zero means that any existing lock is removed.
+        pub fn lock(
+            path: &Path,
+            retries: u32,
+            timeout: Duration,
+            dead: Duration,
+        ) -> Result<XAuthorityLock, Error> {
+            let creat_name = with_suffix(path, "-c");
+            let link_name = with_suffix(path, "-l");
+
+            if let Ok(metadata) = fs::metadata(&creat_name) {
+                let age = metadata
+                    .modified()
+                    .ok()
+                    .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+                let is_dead = match age {
+                    Some(age) => age > dead,
+                    None => false,
+                };
+                if dead == Duration::from_secs(0) || is_dead {
+                    let _ = fs::remove_file(&creat_name);
+                    let _ = fs::remove_file(&link_name);
+                }
+            }
+
+            let mut created = false;
+            let mut retries = retries;
+            while retries > 0 {
+                if !created {
+                    match create_lock_file(&creat_name) {
+                        Ok(()) => created = true,
+                        Err(ref e)
+                            if e.kind() == ErrorKind::AlreadyExists
+                                || e.kind() == ErrorKind::PermissionDenied => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                if created {
+                    match fs::hard_link(&creat_name, &link_name) {
+                        Ok(()) => {
+                            return Ok(XAuthorityLock {
+                                creat_name,
+                                link_name,
+                            })
+                        }
+                        Err(ref e) if e.kind() == ErrorKind::NotFound => {
+                            // Someone removed our file, try again
+                            created = false;
+                            continue;
+                        }
+                        Err(ref e) if e.kind() == ErrorKind::AlreadyExists => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                sleep(timeout);
+                retries -= 1;
+            }
+            Err(Error::new(
+                ErrorKind::TimedOut,
+                "timeout while locking .Xauthority",
+            ))
+        }
+    }
+
+    impl Drop for XAuthorityLock {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.creat_name);
+            let _ = fs::remove_file(&self.link_name);
+        }
+    }
+
+    /// Exclusively create a file that is only readable by the current user.
+    fn create_file(path: &Path) -> Result<File, Error> {
+        let mut options = OpenOptions::new();
+        let _ = options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            let _ = options.mode(0o600);
+        }
+        options.open(path)
+    }
+
+    fn create_lock_file(path: &Path) -> Result<(), Error> {
+        create_file(path).map(|_| ())
+    }
+
+    /// Replace the entries of the given `.Xauthority` file.
+    ///
+    /// The new entries are written to a temporary file which then atomically replaces the old
+    /// file. This function does not lock the file, see `update_file()` for that.
+    pub fn write_entries<'a>(
+        path: &Path,
+        entries: impl IntoIterator<Item = &'a AuthEntry>,
+    ) -> Result<(), Error> {
+        let temp_name = with_suffix(path, "-n");
+        // A leftover file from an earlier failed attempt is not a problem, since we hold the lock
+        match fs::remove_file(&temp_name) {
+            Err(ref e) if e.kind() == ErrorKind::NotFound => {}
+            result => result?,
+        }
+        let result = (|| {
+            let mut write = BufWriter::new(create_file(&temp_name)?);
+            for entry in entries {
+                write_entry(&mut write, entry)?;
+            }
+            write.into_inner()?.sync_all()?;
+            fs::rename(&temp_name, path)
+        })();
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_name);
+        }
+        result
+    }
+
+    /// Modify the entries of the given `.Xauthority` file.
+    ///
+    /// This function locks the file, reads its entries, calls `f` to modify them, and atomically
+    /// writes the result back. A file that does not exist yet is created.
+    pub fn update_file<F>(path: &Path, f: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut Vec<AuthEntry>),
+    {
+        // These are the values that xauth uses: 10 retries, 2 seconds between them, and locks
+        // older than 10 minutes are considered stale
+        let _lock =
+            XAuthorityLock::lock(path, 10, Duration::from_secs(2), Duration::from_secs(600))?;
+        let mut entries = read_entries(path)?;
+        f(&mut entries);
+        write_entries(path, &entries)
+    }
+
+    /// Add an entry to the given `.Xauthority` file.
+    ///
+    /// Like `xauth add`, this replaces existing entries for the same display and authentication
+    /// method.
+    pub fn add_entry(path: &Path, entry: AuthEntry) -> Result<(), Error> {
+        update_file(path, |entries| {
+            entries.retain(|e| !e.same_display_and_method(&entry));
+            entries.insert(0, entry);
+        })
+    }
+
+    /// Remove all entries for the given display from the given `.Xauthority` file.
+    ///
+    /// Like `xauth remove`, this removes the entries for all authentication methods.
+    pub fn remove_entries(
+        path: &Path,
+        family: super::Family,
+        address: &[u8],
+        display: u16,
+    ) -> Result<(), Error> {
+        let number = display.to_string().into_bytes();
+        update_file(path, |entries| {
+            entries.retain(|e| !(e.family == family && e.address == address && e.number == number))
+        })
+    }
+
     #[cfg(test)]
     mod test {
         use super::super::{AuthEntry, Family};
-        use super::read_entry;
-        use std::io::Cursor;
+        use super::{
+            add_entry, read_entries, read_entry, remove_entries, write_entry, XAuthorityLock,
+        };
+        use std::io::{Cursor, ErrorKind};
+        use std::time::Duration;
 
         #[test]
         fn test_read() {
@@ -232,6 +595,70 @@ mod file {
             let entry = read_entry(&mut cursor).unwrap();
             assert_eq!(entry, None);
         }
+
+        #[test]
+        fn test_write() {
+            let entry = AuthEntry {
+                family: Family::Local,
+                address: b"ZweiLED".to_vec(),
+                number: b"1".to_vec(),
+                name: b"bar".to_vec(),
+                data: u32::to_be_bytes(0xdead_beef).to_vec(),
+            };
+            let mut data = Vec::new();
+            write_entry(&mut data, &entry).unwrap();
+            // Same data as in test_read()
+            assert_eq!(
+                data,
+                [
+                    0x01, 0x00, 0x00, 0x07, 0x5a, 0x77, 0x65, 0x69, 0x4c, 0x45, 0x44, 0x00, 0x01,
+                    0x31, 0x00, 0x03, 0x62, 0x61, 0x72, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef,
+                ]
+            );
+        }
+
+        #[test]
+        fn test_add_remove() {
+            let mut path = std::env::temp_dir();
+            path.push(format!("x11rb-xauth-test-{}", std::process::id()));
+
+            let entry = |number: &[u8], data: &[u8]| AuthEntry {
+                family: Family::Local,
+                address: b"host".to_vec(),
+                number: number.to_vec(),
+                name: b"MIT-MAGIC-COOKIE-1".to_vec(),
+                data: data.to_vec(),
+            };
+            assert_eq!(read_entries(&path).unwrap(), []);
+            add_entry(&path, entry(b"1", b"a")).unwrap();
+            add_entry(&path, entry(b"2", b"b")).unwrap();
+            add_entry(&path, entry(b"1", b"c")).unwrap();
+            assert_eq!(
+                read_entries(&path).unwrap(),
+                [entry(b"1", b"c"), entry(b"2", b"b")]
+            );
+            remove_entries(&path, Family::Local, b"host", 1).unwrap();
+            assert_eq!(read_entries(&path).unwrap(), [entry(b"2", b"b")]);
+
+            std::fs::remove_file(&path).unwrap();
+        }
+
+        #[test]
+        fn test_lock() {
+            let mut path = std::env::temp_dir();
+            path.push(format!("x11rb-xauth-lock-test-{}", std::process::id()));
+            let lock = |retries| {
+                let timeout = Duration::from_millis(10);
+                XAuthorityLock::lock(&path, retries, timeout, Duration::from_secs(600))
+            };
+
+            let first = lock(1).unwrap();
+            // A second locker cannot break a fresh lock and gives up
+            assert_eq!(lock(3).unwrap_err().kind(), ErrorKind::TimedOut);
+            drop(first);
+            // After the lock was released, locking works again
+            drop(lock(1).unwrap());
+        }
     }
 }
 
@@ -243,17 +670,20 @@ pub(crate) type AuthInfo = (Vec<u8>, Vec<u8>);
 ///   the `address`.
 /// - `address` is the raw bytes describing the address that is being connected to.
 /// - `display` is the display number.
+/// - `methods` are the acceptable authentication methods, most preferred first.
 ///
-/// If successful, this function returns that can be written to the X11 server as authorization
-/// protocol name and data, respectively.
+/// If successful, this function returns the name and data of the best matching entry in
+/// `~/.Xauthority`. For XDM-AUTHORIZATION-1, the data still has to be turned into the actual
+/// authorization data via `xdm_authorization_1()`.
 pub(crate) fn get_auth(
     family: Family,
     address: &[u8],
     display: u16,
+    methods: &[AuthMethod],
 ) -> Result<Option<AuthInfo>, Error> {
-    match file::XAuthorityEntries::new()? {
+    match XAuthorityEntries::new()? {
         None => Ok(None),
-        Some(entries) => get_auth_impl(entries, family, address, display),
+        Some(entries) => get_auth_impl(entries, family, address, display, methods),
     }
 }
 
@@ -262,6 +692,7 @@ pub(crate) fn get_auth_impl(
     family: Family,
     address: &[u8],
     display: u16,
+    methods: &[AuthMethod],
 ) -> Result<Option<AuthInfo>, Error> {
     fn address_matches(
         (family1, address1): (Family, &[u8]),
@@ -284,22 +715,97 @@ pub(crate) fn get_auth_impl(
     let display = display.to_string();
     let display = display.as_bytes();
 
+    // The best entry found so far, together with the index of its method in `methods`
+    let mut best: Option<(usize, AuthEntry)> = None;
     for entry in entries {
         let entry = entry?;
 
-        if address_matches((family, address), (entry.family, &entry.address))
-            && display_number_matches(&entry.number, &display[..])
-            && entry.name == MIT_MAGIC_COOKIE_1
+        if !address_matches((family, address), (entry.family, &entry.address))
+            || !display_number_matches(&entry.number, display)
         {
-            return Ok(Some((entry.name, entry.data)));
+            continue;
         }
+        let index = methods.iter().position(|method| {
+            method.name() == &entry.name[..] && method.data_is_valid(&entry.data)
+        });
+        if let Some(index) = index {
+            let is_better = match &best {
+                Some((best_index, _)) => index < *best_index,
+                None => true,
+            };
+            if is_better {
+                best = Some((index, entry));
+                if index == 0 {
+                    // It cannot get any better than this
+                    break;
+                }
+            }
+        }
+    }
+    Ok(best.map(|(_, entry)| (entry.name, entry.data)))
+}
+
+/// Compute the authorization data for XDM-AUTHORIZATION-1.
+///
+/// - `data` is the 16 bytes of data from `~/.Xauthority`.
+/// - `address` and `port` describe the client's end of the connection.
+/// - `time` is the current time in seconds since the Unix epoch.
+///
+/// This works like libxcb: The first eight bytes of `data` are sent to the server together with
+/// the address and the time. This is encrypted with the last eight bytes of `data` as key.
+pub(crate) fn xdm_authorization_1(
+    data: &[u8],
+    address: [u8; 4],
+    port: [u8; 2],
+    time: u32,
+) -> Vec<u8> {
+    assert!(AuthMethod::XdmAuthorization1.data_is_valid(data));
+    let mut result = Vec::with_capacity(24);
+    result.extend_from_slice(&data[..8]);
+    result.extend_from_slice(&address);
+    result.extend_from_slice(&port);
+    result.extend_from_slice(&time.to_be_bytes());
+    result.resize(24, 0);
+
+    let mut key = [0; 8];
+    key.copy_from_slice(&data[8..]);
+    xdmcp_wrap(&mut result, key);
+    result
+}
+
+/// Encrypt the given data like `XdmcpWrap()` from libXdmcp does.
+///
+/// This is DES in CBC mode with an all-zero initialisation vector. The length of the data must be
+/// a multiple of eight.
+fn xdmcp_wrap(data: &mut [u8], key: [u8; 8]) {
+    // The key consists of the 56 bits following the first byte of `key`. Spread them over eight
+    // bytes, leaving the least significant bit of each byte for the parity bit that DES ignores.
+    let key_bits = u64::from_be_bytes(key);
+    let mut des_key = [0; 8];
+    for (index, byte) in des_key.iter_mut().enumerate() {
+        *byte = (((key_bits >> (49 - 7 * index)) & 0x7f) << 1) as u8;
+    }
+    let des = Des::new(des_key);
+
+    let mut previous = [0; 8];
+    for chunk in data.chunks_mut(8) {
+        let mut block = [0; 8];
+        for (block, (data, previous)) in block.iter_mut().zip(chunk.iter().zip(previous.iter())) {
+            *block = data ^ previous;
+        }
+        previous = des.encrypt(block);
+        chunk.copy_from_slice(&previous);
     }
-    Ok(None)
 }
 
 #[cfg(test)]
 mod test {
-    use super::{get_auth_impl, AuthEntry, Family, MIT_MAGIC_COOKIE_1};
+    use super::{
+        get_auth_impl, xdm_authorization_1, AuthEntry, AuthMethod, Family, MIT_MAGIC_COOKIE_1,
+        XDM_AUTHORIZATION_1,
+    };
+
+    const DEFAULT: &[AuthMethod] = AuthMethod::DEFAULT_PREFERENCE;
 
     // Call the given function on a matching auth entry. The function can change the entry.
     // Afterwards, it should still be a match.
@@ -317,7 +823,7 @@ mod test {
         f(&mut entry);
         let entries = vec![Ok(entry)];
         assert_eq!(
-            get_auth_impl(entries.into_iter(), Family::Local, b"whatever", 42)
+            get_auth_impl(entries.into_iter(), Family::Local, b"whatever", 42, DEFAULT)
                 .unwrap()
                 .unwrap(),
             (MIT_MAGIC_COOKIE_1.to_vec(), b"1234".to_vec())
@@ -340,7 +846,7 @@ mod test {
         f(&mut entry);
         let entries = vec![Ok(entry)];
         assert_eq!(
-            get_auth_impl(entries.into_iter(), Family::Local, b"whatever", 42).unwrap(),
+            get_auth_impl(entries.into_iter(), Family::Local, b"whatever", 42, DEFAULT).unwrap(),
             None
         );
     }
@@ -372,7 +878,7 @@ mod test {
         };
         let entries = vec![Ok(entry)];
         assert_eq!(
-            get_auth_impl(entries.into_iter(), Family::Wild, &[], 42)
+            get_auth_impl(entries.into_iter(), Family::Wild, &[], 42, DEFAULT)
                 .unwrap()
                 .unwrap(),
             (MIT_MAGIC_COOKIE_1.to_vec(), b"1234".to_vec())
@@ -396,6 +902,55 @@ mod test {
 
     #[test]
     fn protocol_mismatch() {
-        expect_mismatch(|entry| entry.name = b"XDM-AUTHORIZATION-1".to_vec());
+        expect_mismatch(|entry| entry.name = b"SUN-DES-1".to_vec());
+    }
+
+    #[test]
+    fn xdm_invalid_data() {
+        expect_mismatch(|entry| entry.name = XDM_AUTHORIZATION_1.to_vec());
+    }
+
+    fn entry(name: &[u8], data: &[u8]) -> AuthEntry {
+        AuthEntry {
+            family: Family::Local,
+            address: b"whatever".to_vec(),
+            number: b"42".to_vec(),
+            name: name.to_vec(),
+            data: data.to_vec(),
+        }
+    }
+
+    #[test]
+    fn method_preference() {
+        let mit = entry(MIT_MAGIC_COOKIE_1, b"1234");
+        let xdm = entry(XDM_AUTHORIZATION_1, &[7; 16]);
+        let get = |methods| {
+            let entries = vec![Ok(mit.clone()), Ok(xdm.clone())];
+            get_auth_impl(entries.into_iter(), Family::Local, b"whatever", 42, methods)
+                .unwrap()
+                .map(|(name, _)| name)
+        };
+        assert_eq!(get(DEFAULT), Some(XDM_AUTHORIZATION_1.to_vec()));
+        assert_eq!(
+            get(&[AuthMethod::MitMagicCookie1, AuthMethod::XdmAuthorization1]),
+            Some(MIT_MAGIC_COOKIE_1.to_vec())
+        );
+        assert_eq!(
+            get(&[AuthMethod::XdmAuthorization1]),
+            Some(XDM_AUTHORIZATION_1.to_vec())
+        );
+        assert_eq!(get(&[]), None);
+    }
+
+    #[test]
+    fn xdm_authorization_1_data() {
+        let data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        // Expected value computed with XdmcpWrap() from libXdmcp
+        let expected = [
+            73, 244, 172, 74, 227, 139, 52, 137, 96, 162, 177, 74, 93, 49, 38, 66, 0, 19, 12, 238,
+            238, 153, 237, 213,
+        ];
+        let result = xdm_authorization_1(&data, [127, 0, 0, 1], 6000u16.to_be_bytes(), 0x5e00_0000);
+        assert_eq!(result, expected);
     }
 }
@@ -0,0 +1,92 @@
+//! Statistics about the traffic of a connection.
+//!
+//! The statistics of a connection can be obtained via `RustConnection::stats()` or
+//! `XCBConnection::stats()`. For example, to count the round trips a program makes while drawing
+//! a frame, compare `blocking_waits` before and after drawing.
+
+use std::collections::BTreeMap;
+use std::io::IoSlice;
+use std::time::Duration;
+
+/// A snapshot of the statistics of a connection.
+///
+/// All counters start at zero when the connection is established. The traffic of the connection
+/// setup is not included.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionStats {
+    /// The number of requests that were sent, including `implicit_syncs`.
+    pub requests_sent: u64,
+    /// The number of requests that were sent, by opcode.
+    ///
+    /// The key is the major opcode and the minor opcode of the request. For requests from the
+    /// core protocol, which do not have a minor opcode, the minor opcode is zero.
+    pub requests_by_opcode: BTreeMap<(u8, u8), u64>,
+    /// The number of bytes that were written.
+    pub bytes_written: u64,
+    /// The number of bytes that were read.
+    ///
+    /// This is always zero for `XCBConnection`, since libxcb does not provide this information.
+    pub bytes_read: u64,
+    /// The number of requests that were sent, but whose answer was not yet received.
+    ///
+    /// For `XCBConnection`, this is the number of requests that were sent after the last reply,
+    /// error or event with a sequence number was received.
+    pub in_flight_requests: usize,
+    /// How often a thread blocked while waiting for the X11 server.
+    pub blocking_waits: u64,
+    /// The total time that threads spent blocking while waiting for the X11 server.
+    pub blocking_wait_time: Duration,
+    /// The number of `GetInputFocus` requests that the connection inserted to synchronise with
+    /// the X11 server.
+    ///
+    /// This is always zero for `XCBConnection`, since libxcb does not provide this information.
+    pub implicit_syncs: u64,
+}
+
+impl ConnectionStats {
+    /// Count a request that was sent.
+    pub(crate) fn record_request(&mut self, bufs: &[IoSlice<'_>]) {
+        let mut header = bufs.iter().flat_map(|buf| buf.iter()).copied();
+        let major_opcode = header.next().unwrap_or(0);
+        // Extensions use major opcodes starting at 128
+        let minor_opcode = if major_opcode >= 128 {
+            header.next().unwrap_or(0)
+        } else {
+            0
+        };
+        self.requests_sent += 1;
+        *self
+            .requests_by_opcode
+            .entry((major_opcode, minor_opcode))
+            .or_insert(0) += 1;
+        self.bytes_written += bufs.iter().map(|buf| buf.len() as u64).sum::<u64>();
+    }
+
+    /// Count a period of time in which a thread blocked.
+    pub(crate) fn record_wait(&mut self, duration: Duration) {
+        self.blocking_waits += 1;
+        self.blocking_wait_time += duration;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+
+    use super::ConnectionStats;
+
+    #[test]
+    fn record_request() {
+        let mut stats = ConnectionStats::default();
+        stats.record_request(&[IoSlice::new(&[43, 1, 1, 0])]);
+        stats.record_request(&[IoSlice::new(&[130, 2]), IoSlice::new(&[3, 0, 0, 0, 0, 0])]);
+        stats.record_request(&[IoSlice::new(&[43, 2, 1, 0])]);
+        assert_eq!(stats.requests_sent, 3);
+        assert_eq!(stats.bytes_written, 16);
+        let expected = vec![((43, 0), 2), ((130, 2), 1)];
+        assert_eq!(
+            stats.requests_by_opcode.into_iter().collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
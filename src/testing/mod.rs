@@ -0,0 +1,410 @@
+//! An in-process fake X11 server for tests.
+//!
+//! [`FakeServer`] implements a small part of the core X11 protocol in memory. A
+//! [`RustConnection`] can connect to it over a socket pair, so that code which talks to an X11
+//! server, for example the logic of a window manager, can be tested without a display.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::{ConnectionExt, CreateWindowAux, WindowClass};
+//! use x11rb::testing::FakeServer;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = FakeServer::new();
+//! let conn = server.connect()?;
+//! let screen = &conn.setup().roots[0];
+//! let window = conn.generate_id()?.into();
+//! conn.create_window(0, window, screen.root, 0, 0, 100, 100, 0, WindowClass::InputOutput,
+//!                    0, &CreateWindowAux::new())?;
+//! let tree = conn.query_tree(screen.root)?.reply()?;
+//! assert_eq!(tree.children, [window]);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The fake server supports:
+//!
+//! * a single screen whose size can be configured with [`ServerConfig`],
+//! * creating, destroying, mapping, unmapping, configuring and reparenting windows, and querying
+//!   the window tree,
+//! * properties and atoms,
+//! * selections,
+//! * the core events that these requests generate, including `MapRequest` and
+//!   `ConfigureRequest` for a client that selected `SubstructureRedirect`, and `SendEvent`,
+//! * `GetInputFocus`, `SetInputFocus` and `QueryExtension`, which reports all extensions as
+//!   missing.
+//!
+//! Other requests fail with a `Request` error. There is no input, no drawing and no `Expose`
+//! events. When a client disconnects, all its windows are destroyed. Only clients that use the
+//! native byte order are accepted.
+//!
+//! This module requires the `testing` feature and is only available on Unix.
+
+use std::io::Read;
+use std::os::unix::net::UnixStream;
+use std::sync::{Arc, Mutex};
+
+use crate::errors::ConnectError;
+use crate::rust_connection::RustConnection;
+use crate::x11_utils::Serialize;
+
+mod server;
+
+use server::{ClientId, State};
+
+/// The configuration of the screen of a [`FakeServer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerConfig {
+    /// The width of the screen in pixels.
+    pub width_in_pixels: u16,
+    /// The height of the screen in pixels.
+    pub height_in_pixels: u16,
+    /// The width of the screen in millimeters.
+    pub width_in_millimeters: u16,
+    /// The height of the screen in millimeters.
+    pub height_in_millimeters: u16,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig {
+            width_in_pixels: 1024,
+            height_in_pixels: 768,
+            width_in_millimeters: 271,
+            height_in_millimeters: 203,
+        }
+    }
+}
+
+/// A fake X11 server that runs inside the current process.
+///
+/// Each connection is served by its own thread. The threads exit when the client closes its
+/// connection. Cloning a `FakeServer` produces a handle to the same server.
+#[derive(Debug, Clone)]
+pub struct FakeServer {
+    state: Arc<Mutex<State>>,
+}
+
+impl Default for FakeServer {
+    fn default() -> Self {
+        Self::with_config(Default::default())
+    }
+}
+
+impl FakeServer {
+    /// Create a new fake server with the default configuration.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create a new fake server with the given configuration.
+    pub fn with_config(config: ServerConfig) -> Self {
+        FakeServer {
+            state: Arc::new(Mutex::new(State::new(config))),
+        }
+    }
+
+    /// Establish a new connection to this server.
+    pub fn connect(&self) -> Result<RustConnection, ConnectError> {
+        let (client_stream, server_stream) = UnixStream::pair()?;
+        self.serve(server_stream)?;
+        let (conn, _) = RustConnection::connect_to_unix_stream(client_stream, 0, None)?;
+        Ok(conn)
+    }
+
+    /// Serve a client that is connected to this server via `stream`.
+    ///
+    /// This is useful for clients that are not a [`RustConnection`], for example an X11 proxy.
+    pub fn serve(&self, stream: UnixStream) -> std::io::Result<()> {
+        let client = self.state.lock().unwrap().add_client(stream.try_clone()?);
+        let state = Arc::clone(&self.state);
+        let _ = std::thread::spawn(move || serve_client(&state, client, stream));
+        Ok(())
+    }
+}
+
+/// Handle the requests of a client until it disconnects.
+fn serve_client(state: &Mutex<State>, client: ClientId, mut stream: UnixStream) {
+    let _ = serve_requests(state, client, &mut stream);
+    state.lock().unwrap().remove_client(client);
+}
+
+fn serve_requests(
+    state: &Mutex<State>,
+    client: ClientId,
+    stream: &mut UnixStream,
+) -> std::io::Result<()> {
+    // The connection setup. The authorization data is ignored.
+    let mut header = [0; 12];
+    stream.read_exact(&mut header)?;
+    let expected_byte_order = if cfg!(target_endian = "little") {
+        b'l'
+    } else {
+        b'B'
+    };
+    if header[0] != expected_byte_order {
+        return Ok(());
+    }
+    let name_length = usize::from(u16::from_ne_bytes([header[6], header[7]]));
+    let data_length = usize::from(u16::from_ne_bytes([header[8], header[9]]));
+    let padded = |length: usize| length + (4 - length % 4) % 4;
+    let mut auth = vec![0; padded(name_length) + padded(data_length)];
+    stream.read_exact(&mut auth)?;
+    let setup = state.lock().unwrap().setup(client).serialize();
+    std::io::Write::write_all(stream, &setup)?;
+
+    loop {
+        let mut request = vec![0; 4];
+        stream.read_exact(&mut request)?;
+        let length = 4 * usize::from(u16::from_ne_bytes([request[2], request[3]]));
+        if length == 0 {
+            // BIG-REQUESTS is not supported
+            return Ok(());
+        }
+        request.resize(length, 0);
+        stream.read_exact(&mut request[4..])?;
+        state.lock().unwrap().handle_request(client, &request);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FakeServer, ServerConfig};
+    use crate::connection::{Connection, RequestConnection};
+    use crate::cookie::VoidCookie;
+    use crate::errors::ConnectionError;
+    use crate::protocol::xproto::{
+        Atom, AtomEnum, ChangeWindowAttributesAux, ConfigureWindowAux, ConnectionExt,
+        CreateWindowAux, EventMask, MapState, PropMode, Window, WindowClass,
+    };
+    use crate::protocol::{Error, Event};
+    use crate::wrapper::ConnectionExt as _;
+
+    fn check<C: RequestConnection>(cookie: Result<VoidCookie<'_, C>, ConnectionError>) {
+        assert!(cookie.unwrap().check().unwrap().is_none());
+    }
+
+    fn create_window(conn: &impl Connection, parent: Window) -> Window {
+        let window = conn.generate_id().unwrap().into();
+        let aux = CreateWindowAux::new().event_mask(EventMask::StructureNotify);
+        check(conn.create_window(
+            0,
+            window,
+            parent,
+            0,
+            0,
+            100,
+            100,
+            0,
+            WindowClass::InputOutput,
+            0,
+            &aux,
+        ));
+        window
+    }
+
+    #[test]
+    fn setup() {
+        let config = ServerConfig {
+            width_in_pixels: 800,
+            height_in_pixels: 600,
+            ..Default::default()
+        };
+        let conn = FakeServer::with_config(config).connect().unwrap();
+        let screen = &conn.setup().roots[0];
+        assert_eq!(
+            (screen.width_in_pixels, screen.height_in_pixels),
+            (800, 600)
+        );
+        let geometry = conn.get_geometry(screen.root).unwrap().reply().unwrap();
+        assert_eq!((geometry.width, geometry.height), (800, 600));
+        let extension = conn.query_extension(b"RANDR").unwrap().reply().unwrap();
+        assert!(!extension.present);
+    }
+
+    #[test]
+    fn window_manager() {
+        let server = FakeServer::new();
+        let wm = server.connect().unwrap();
+        let client = server.connect().unwrap();
+        let root = wm.setup().roots[0].root;
+
+        // Only one client can select SubstructureRedirect
+        let aux = ChangeWindowAttributesAux::new()
+            .event_mask(EventMask::SubstructureRedirect | EventMask::SubstructureNotify);
+        check(wm.change_window_attributes(root, &aux));
+        match client.change_window_attributes(root, &aux).unwrap().check() {
+            Ok(Some(Error::Access(_))) => {}
+            result => panic!("Unexpected result {:?}", result),
+        }
+
+        // The window manager is notified about new windows and map requests
+        let window = create_window(&client, root);
+        match wm.wait_for_event().unwrap() {
+            Event::CreateNotify(event) => assert_eq!(event.window, window),
+            event => panic!("Unexpected event {:?}", event),
+        }
+        check(client.map_window(window));
+        match wm.wait_for_event().unwrap() {
+            Event::MapRequest(event) => assert_eq!((event.parent, event.window), (root, window)),
+            event => panic!("Unexpected event {:?}", event),
+        }
+        let attributes = client
+            .get_window_attributes(window)
+            .unwrap()
+            .reply()
+            .unwrap();
+        assert_eq!(attributes.map_state, MapState::Unmapped);
+
+        // The window manager reparents the window into a frame and maps it
+        let frame = create_window(&wm, root);
+        match wm.wait_for_event().unwrap() {
+            Event::CreateNotify(event) => assert_eq!(event.window, frame),
+            event => panic!("Unexpected event {:?}", event),
+        }
+        check(wm.change_window_attributes(frame, &aux));
+        check(wm.reparent_window(window, frame, 5, 20));
+        check(wm.map_window(window));
+        check(wm.map_window(frame));
+        match client.wait_for_event().unwrap() {
+            Event::ReparentNotify(event) => assert_eq!((event.parent, event.x), (frame, 5)),
+            event => panic!("Unexpected event {:?}", event),
+        }
+        match client.wait_for_event().unwrap() {
+            Event::MapNotify(event) => assert_eq!(event.window, window),
+            event => panic!("Unexpected event {:?}", event),
+        }
+        let attributes = client
+            .get_window_attributes(window)
+            .unwrap()
+            .reply()
+            .unwrap();
+        assert_eq!(attributes.map_state, MapState::Viewable);
+        let tree = client.query_tree(root).unwrap().reply().unwrap();
+        assert_eq!(tree.children, [frame]);
+
+        // Configure requests are redirected, too
+        let aux = ConfigureWindowAux::new().width(200);
+        check(client.configure_window(window, &aux));
+        assert_eq!(
+            client.get_geometry(window).unwrap().reply().unwrap().width,
+            100
+        );
+
+        // When the client disconnects, its windows are destroyed
+        drop(client);
+        loop {
+            match wm.wait_for_event().unwrap() {
+                Event::DestroyNotify(event) => {
+                    assert_eq!(event.window, window);
+                    break;
+                }
+                Event::ConfigureRequest(event) => assert_eq!(event.width, 200),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn properties_and_atoms() {
+        let conn = FakeServer::new().connect().unwrap();
+        let root = conn.setup().roots[0].root;
+        let window = create_window(&conn, root);
+        let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::PropertyChange);
+        check(conn.change_window_attributes(window, &aux));
+
+        let atom = conn
+            .intern_atom(false, b"_NET_WM_NAME")
+            .unwrap()
+            .reply()
+            .unwrap()
+            .atom;
+        assert_eq!(
+            conn.intern_atom(true, b"_NET_WM_NAME")
+                .unwrap()
+                .reply()
+                .unwrap()
+                .atom,
+            atom
+        );
+        assert_eq!(
+            conn.intern_atom(true, b"nonexistent")
+                .unwrap()
+                .reply()
+                .unwrap()
+                .atom,
+            Atom::NONE
+        );
+        let name = conn.get_atom_name(atom).unwrap().reply().unwrap().name;
+        assert_eq!(name, b"_NET_WM_NAME");
+        let name = conn
+            .get_atom_name(AtomEnum::WM_NAME.into())
+            .unwrap()
+            .reply()
+            .unwrap()
+            .name;
+        assert_eq!(name, b"WM_NAME");
+
+        check(conn.change_property8(PropMode::Replace, window, atom, AtomEnum::STRING, b"Hello"));
+        check(conn.change_property8(PropMode::Append, window, atom, AtomEnum::STRING, b" World"));
+        let reply = conn
+            .get_property(false, window, atom, AtomEnum::Any, 0, 2)
+            .unwrap()
+            .reply()
+            .unwrap();
+        assert_eq!((reply.value, reply.bytes_after), (b"Hello Wo".to_vec(), 3));
+        let properties = conn.list_properties(window).unwrap().reply().unwrap();
+        assert_eq!(properties.atoms, [atom]);
+        check(conn.delete_property(window, atom));
+        let reply = conn
+            .get_property(false, window, atom, AtomEnum::Any, 0, 100)
+            .unwrap()
+            .reply()
+            .unwrap();
+        assert_eq!(reply.type_, Atom::NONE);
+
+        for _ in 0..3 {
+            match conn.wait_for_event().unwrap() {
+                Event::PropertyNotify(event) => assert_eq!(event.atom, atom),
+                event => panic!("Unexpected event {:?}", event),
+            }
+        }
+    }
+
+    #[test]
+    fn selections() {
+        let server = FakeServer::new();
+        let (conn1, conn2) = (server.connect().unwrap(), server.connect().unwrap());
+        let root = conn1.setup().roots[0].root;
+        let (window1, window2) = (create_window(&conn1, root), create_window(&conn2, root));
+        let selection = AtomEnum::PRIMARY.into();
+
+        check(conn1.set_selection_owner(window1, selection, 0u32));
+        let owner = conn2
+            .get_selection_owner(selection)
+            .unwrap()
+            .reply()
+            .unwrap();
+        assert_eq!(owner.owner, window1);
+
+        check(conn2.convert_selection(
+            window2,
+            selection,
+            AtomEnum::STRING.into(),
+            AtomEnum::STRING,
+            0u32,
+        ));
+        match conn1.wait_for_event().unwrap() {
+            Event::SelectionRequest(event) => {
+                assert_eq!((event.owner, event.requestor), (window1, window2))
+            }
+            event => panic!("Unexpected event {:?}", event),
+        }
+
+        check(conn2.set_selection_owner(window2, selection, 0u32));
+        match conn1.wait_for_event().unwrap() {
+            Event::SelectionClear(event) => assert_eq!(event.owner, window1),
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+}
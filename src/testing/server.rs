@@ -0,0 +1,1270 @@
+//! The state of the fake X11 server and the implementation of its requests.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+use std::time::Instant;
+
+use super::ServerConfig;
+use crate::protocol::xproto::{self, EventMask, ImageOrder, Property, StackMode};
+use crate::x11_utils::Serialize;
+
+/// The identifier of a client of the fake server.
+pub(super) type ClientId = u32;
+
+const ROOT_WINDOW: u32 = 0x100;
+const DEFAULT_COLORMAP: u32 = 0x20;
+const ROOT_VISUAL: u32 = 0x21;
+const ROOT_DEPTH: u8 = 24;
+
+/// Each client gets `RESOURCE_ID_MASK + 1` IDs, starting at `(client + 1) << 21`.
+const RESOURCE_ID_MASK: u32 = 0x1f_ffff;
+
+/// The names of the predefined atoms, starting with atom 1.
+const PREDEFINED_ATOMS: [&str; 68] = [
+    "PRIMARY",
+    "SECONDARY",
+    "ARC",
+    "ATOM",
+    "BITMAP",
+    "CARDINAL",
+    "COLORMAP",
+    "CURSOR",
+    "CUT_BUFFER0",
+    "CUT_BUFFER1",
+    "CUT_BUFFER2",
+    "CUT_BUFFER3",
+    "CUT_BUFFER4",
+    "CUT_BUFFER5",
+    "CUT_BUFFER6",
+    "CUT_BUFFER7",
+    "DRAWABLE",
+    "FONT",
+    "INTEGER",
+    "PIXMAP",
+    "POINT",
+    "RECTANGLE",
+    "RESOURCE_MANAGER",
+    "RGB_COLOR_MAP",
+    "RGB_BEST_MAP",
+    "RGB_BLUE_MAP",
+    "RGB_DEFAULT_MAP",
+    "RGB_GRAY_MAP",
+    "RGB_GREEN_MAP",
+    "RGB_RED_MAP",
+    "STRING",
+    "VISUALID",
+    "WINDOW",
+    "WM_COMMAND",
+    "WM_HINTS",
+    "WM_CLIENT_MACHINE",
+    "WM_ICON_NAME",
+    "WM_ICON_SIZE",
+    "WM_NAME",
+    "WM_NORMAL_HINTS",
+    "WM_SIZE_HINTS",
+    "WM_ZOOM_HINTS",
+    "MIN_SPACE",
+    "NORM_SPACE",
+    "MAX_SPACE",
+    "END_SPACE",
+    "SUPERSCRIPT_X",
+    "SUPERSCRIPT_Y",
+    "SUBSCRIPT_X",
+    "SUBSCRIPT_Y",
+    "UNDERLINE_POSITION",
+    "UNDERLINE_THICKNESS",
+    "STRIKEOUT_ASCENT",
+    "STRIKEOUT_DESCENT",
+    "ITALIC_ANGLE",
+    "X_HEIGHT",
+    "QUAD_WIDTH",
+    "WEIGHT",
+    "POINT_SIZE",
+    "RESOLUTION",
+    "COPYRIGHT",
+    "NOTICE",
+    "FONT_NAME",
+    "FAMILY_NAME",
+    "FULL_NAME",
+    "CAP_HEIGHT",
+    "WM_CLASS",
+    "WM_TRANSIENT_FOR",
+];
+
+fn mask(mask: EventMask) -> u32 {
+    mask.into()
+}
+
+/// An X11 error that is sent in response to a request.
+#[derive(Debug, Clone, Copy)]
+struct XError {
+    code: u8,
+    bad_value: u32,
+}
+
+impl XError {
+    fn new(code: u8, bad_value: u32) -> Self {
+        XError { code, bad_value }
+    }
+
+    fn length() -> Self {
+        Self::new(xproto::LENGTH_ERROR, 0)
+    }
+}
+
+/// A successful reply to a request.
+#[derive(Debug)]
+struct Reply {
+    /// The second byte of the reply.
+    data1: u8,
+    /// Everything after the length field.
+    body: Vec<u8>,
+}
+
+impl Reply {
+    fn new(data1: u8, body: Vec<u8>) -> Self {
+        Reply { data1, body }
+    }
+}
+
+type RequestResult = Result<Option<Reply>, XError>;
+
+/// Access to the fields of a request.
+#[derive(Debug, Clone, Copy)]
+struct Request<'a>(&'a [u8]);
+
+impl Request<'_> {
+    fn bytes(&self, offset: usize, length: usize) -> Result<&[u8], XError> {
+        self.0
+            .get(offset..offset + length)
+            .ok_or_else(XError::length)
+    }
+
+    fn u8(&self, offset: usize) -> Result<u8, XError> {
+        Ok(self.bytes(offset, 1)?[0])
+    }
+
+    fn u16(&self, offset: usize) -> Result<u16, XError> {
+        let bytes = self.bytes(offset, 2)?;
+        Ok(u16::from_ne_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn i16(&self, offset: usize) -> Result<i16, XError> {
+        Ok(self.u16(offset)? as i16)
+    }
+
+    fn u32(&self, offset: usize) -> Result<u32, XError> {
+        let bytes = self.bytes(offset, 4)?;
+        Ok(u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Parse a value list with the given mask, starting at the given offset.
+    ///
+    /// Returns the bit of each value together with the value.
+    fn values(&self, offset: usize, mask: u32) -> Result<Vec<(u32, u32)>, XError> {
+        (0..32)
+            .map(|bit| 1 << bit)
+            .filter(|bit| mask & bit != 0)
+            .enumerate()
+            .map(|(index, bit)| Ok((bit, self.u32(offset + 4 * index)?)))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct PropertyValue {
+    type_: u32,
+    format: u8,
+    data: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct Window {
+    /// The parent window. This is zero for the root window.
+    parent: u32,
+    /// The children of the window in stacking order, bottom-most first.
+    children: Vec<u32>,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    border_width: u16,
+    class: u16,
+    depth: u8,
+    visual: u32,
+    mapped: bool,
+    override_redirect: bool,
+    event_masks: BTreeMap<ClientId, u32>,
+    properties: BTreeMap<u32, PropertyValue>,
+    /// The client that created the window. This is `None` for the root window.
+    creator: Option<ClientId>,
+}
+
+#[derive(Debug)]
+struct Selection {
+    /// The window that owns the selection or zero if the selection has no owner.
+    owner: u32,
+    client: Option<ClientId>,
+    time: u32,
+}
+
+#[derive(Debug)]
+struct Client {
+    stream: UnixStream,
+    sequence: u16,
+}
+
+/// The state of the fake X11 server.
+#[derive(Debug)]
+pub(super) struct State {
+    config: ServerConfig,
+    start: Instant,
+    clients: BTreeMap<ClientId, Client>,
+    next_client: ClientId,
+    windows: HashMap<u32, Window>,
+    atoms: Vec<Vec<u8>>,
+    selections: BTreeMap<u32, Selection>,
+    focus: u32,
+    revert_to: u8,
+}
+
+impl State {
+    pub(super) fn new(config: ServerConfig) -> Self {
+        let root = Window {
+            parent: 0,
+            children: Vec::new(),
+            x: 0,
+            y: 0,
+            width: config.width_in_pixels,
+            height: config.height_in_pixels,
+            border_width: 0,
+            class: u16::from(xproto::WindowClass::InputOutput),
+            depth: ROOT_DEPTH,
+            visual: ROOT_VISUAL,
+            mapped: true,
+            override_redirect: false,
+            event_masks: Default::default(),
+            properties: Default::default(),
+            creator: None,
+        };
+        let mut windows = HashMap::new();
+        let _ = windows.insert(ROOT_WINDOW, root);
+        State {
+            config,
+            start: Instant::now(),
+            clients: Default::default(),
+            next_client: 0,
+            windows,
+            atoms: PREDEFINED_ATOMS
+                .iter()
+                .map(|name| name.as_bytes().to_vec())
+                .collect(),
+            selections: Default::default(),
+            focus: 1, // PointerRoot
+            revert_to: u8::from(xproto::InputFocus::PointerRoot),
+        }
+    }
+
+    /// Register a new client that is connected via the given stream.
+    pub(super) fn add_client(&mut self, stream: UnixStream) -> ClientId {
+        let client = self.next_client;
+        self.next_client += 1;
+        let _ = self.clients.insert(
+            client,
+            Client {
+                stream,
+                sequence: 0,
+            },
+        );
+        client
+    }
+
+    /// Get the `Setup` that is sent to the given client.
+    pub(super) fn setup(&self, client: ClientId) -> xproto::Setup {
+        let image_byte_order = if cfg!(target_endian = "little") {
+            ImageOrder::LSBFirst
+        } else {
+            ImageOrder::MSBFirst
+        };
+        let visual = xproto::Visualtype {
+            visual_id: ROOT_VISUAL,
+            class: xproto::VisualClass::TrueColor,
+            bits_per_rgb_value: 8,
+            colormap_entries: 256,
+            red_mask: 0xff_0000,
+            green_mask: 0xff00,
+            blue_mask: 0xff,
+        };
+        let screen = xproto::Screen {
+            root: ROOT_WINDOW.into(),
+            default_colormap: DEFAULT_COLORMAP.into(),
+            white_pixel: 0xff_ffff,
+            black_pixel: 0,
+            current_input_masks: EventMask::NoEvent,
+            width_in_pixels: self.config.width_in_pixels,
+            height_in_pixels: self.config.height_in_pixels,
+            width_in_millimeters: self.config.width_in_millimeters,
+            height_in_millimeters: self.config.height_in_millimeters,
+            min_installed_maps: 1,
+            max_installed_maps: 1,
+            root_visual: ROOT_VISUAL,
+            backing_stores: xproto::BackingStore::NotUseful,
+            save_unders: false,
+            root_depth: ROOT_DEPTH,
+            allowed_depths: vec![xproto::Depth {
+                depth: ROOT_DEPTH,
+                visuals: vec![visual],
+            }],
+        };
+        let mut setup = xproto::Setup {
+            status: 1,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: (client + 1) << 21,
+            resource_id_mask: RESOURCE_ID_MASK,
+            motion_buffer_size: 0,
+            maximum_request_length: u16::max_value(),
+            image_byte_order,
+            bitmap_format_bit_order: image_byte_order,
+            bitmap_format_scanline_unit: 32,
+            bitmap_format_scanline_pad: 32,
+            min_keycode: 8,
+            max_keycode: 255,
+            vendor: b"x11rb fake server".to_vec(),
+            pixmap_formats: vec![xproto::Format {
+                depth: ROOT_DEPTH,
+                bits_per_pixel: 32,
+                scanline_pad: 32,
+            }],
+            roots: vec![screen],
+        };
+        setup.length = ((setup.serialize().len() - 8) / 4) as u16;
+        setup
+    }
+
+    /// Forget about a client that disconnected and destroy its resources.
+    pub(super) fn remove_client(&mut self, client: ClientId) {
+        let mut windows = self
+            .windows
+            .iter()
+            .filter(|(_, window)| window.creator == Some(client))
+            .map(|(&id, _)| id)
+            .collect::<Vec<_>>();
+        windows.sort();
+        for window in windows {
+            if self.windows.contains_key(&window) {
+                self.destroy_window(window);
+            }
+        }
+        for window in self.windows.values_mut() {
+            let _ = window.event_masks.remove(&client);
+        }
+        // BTreeMap::retain() needs Rust 1.53
+        self.selections = std::mem::replace(&mut self.selections, BTreeMap::new())
+            .into_iter()
+            .filter(|(_, selection)| selection.client != Some(client))
+            .collect();
+        let _ = self.clients.remove(&client);
+    }
+
+    /// Handle a request from a client and send the reply or error to it.
+    pub(super) fn handle_request(&mut self, client: ClientId, request: &[u8]) {
+        let sequence = match self.clients.get_mut(&client) {
+            Some(state) => {
+                state.sequence = state.sequence.wrapping_add(1);
+                state.sequence
+            }
+            None => return,
+        };
+        let major_opcode = request[0];
+        let mut packet = Vec::with_capacity(32);
+        match self.dispatch(client, Request(request)) {
+            Ok(None) => return,
+            Ok(Some(reply)) => {
+                let mut body = reply.body;
+                let length = body.len().max(24);
+                body.resize(length + (4 - length % 4) % 4, 0);
+                packet.extend_from_slice(&[1, reply.data1]);
+                packet.extend_from_slice(&sequence.to_ne_bytes());
+                packet.extend_from_slice(&(((body.len() - 24) / 4) as u32).to_ne_bytes());
+                packet.extend_from_slice(&body);
+            }
+            Err(error) => {
+                packet.extend_from_slice(&[0, error.code]);
+                packet.extend_from_slice(&sequence.to_ne_bytes());
+                packet.extend_from_slice(&error.bad_value.to_ne_bytes());
+                packet.extend_from_slice(&0u16.to_ne_bytes());
+                packet.push(major_opcode);
+                packet.resize(32, 0);
+            }
+        }
+        self.send(client, &packet);
+    }
+
+    fn dispatch(&mut self, client: ClientId, request: Request<'_>) -> RequestResult {
+        match request.u8(0)? {
+            xproto::CREATE_WINDOW_REQUEST => self.create_window_request(client, request),
+            xproto::CHANGE_WINDOW_ATTRIBUTES_REQUEST => {
+                let window = self.check_window(request.u32(4)?)?;
+                let values = request.values(12, request.u32(8)?)?;
+                self.change_attributes(client, window, &values)?;
+                Ok(None)
+            }
+            xproto::GET_WINDOW_ATTRIBUTES_REQUEST => {
+                self.get_window_attributes_request(client, request)
+            }
+            xproto::DESTROY_WINDOW_REQUEST => {
+                let window = self.check_window(request.u32(4)?)?;
+                if window != ROOT_WINDOW {
+                    self.destroy_window(window);
+                }
+                Ok(None)
+            }
+            xproto::REPARENT_WINDOW_REQUEST => self.reparent_window_request(client, request),
+            xproto::MAP_WINDOW_REQUEST => {
+                let window = self.check_window(request.u32(4)?)?;
+                self.map_window(client, window);
+                Ok(None)
+            }
+            xproto::UNMAP_WINDOW_REQUEST => {
+                let window = self.check_window(request.u32(4)?)?;
+                self.unmap_window(window);
+                Ok(None)
+            }
+            xproto::CONFIGURE_WINDOW_REQUEST => self.configure_window_request(client, request),
+            xproto::GET_GEOMETRY_REQUEST => {
+                let window = &self.windows[&self.check_window(request.u32(4)?)?];
+                let mut body = Vec::new();
+                body.extend_from_slice(&ROOT_WINDOW.to_ne_bytes());
+                body.extend_from_slice(&window.x.to_ne_bytes());
+                body.extend_from_slice(&window.y.to_ne_bytes());
+                body.extend_from_slice(&window.width.to_ne_bytes());
+                body.extend_from_slice(&window.height.to_ne_bytes());
+                body.extend_from_slice(&window.border_width.to_ne_bytes());
+                Ok(Some(Reply::new(window.depth, body)))
+            }
+            xproto::QUERY_TREE_REQUEST => {
+                let window = &self.windows[&self.check_window(request.u32(4)?)?];
+                let mut body = Vec::new();
+                body.extend_from_slice(&ROOT_WINDOW.to_ne_bytes());
+                body.extend_from_slice(&window.parent.to_ne_bytes());
+                body.extend_from_slice(&(window.children.len() as u16).to_ne_bytes());
+                body.resize(24, 0);
+                for child in &window.children {
+                    body.extend_from_slice(&child.to_ne_bytes());
+                }
+                Ok(Some(Reply::new(0, body)))
+            }
+            xproto::INTERN_ATOM_REQUEST => {
+                let only_if_exists = request.u8(1)? != 0;
+                let name = request.bytes(8, request.u16(4)?.into())?;
+                let atom = match self.atoms.iter().position(|atom| &atom[..] == name) {
+                    Some(index) => index as u32 + 1,
+                    None if only_if_exists => 0,
+                    None => {
+                        self.atoms.push(name.to_vec());
+                        self.atoms.len() as u32
+                    }
+                };
+                Ok(Some(Reply::new(0, atom.to_ne_bytes().to_vec())))
+            }
+            xproto::GET_ATOM_NAME_REQUEST => {
+                let atom = self.check_atom(request.u32(4)?)?;
+                let name = &self.atoms[atom as usize - 1];
+                let mut body = Vec::new();
+                body.extend_from_slice(&(name.len() as u16).to_ne_bytes());
+                body.resize(24, 0);
+                body.extend_from_slice(name);
+                Ok(Some(Reply::new(0, body)))
+            }
+            xproto::CHANGE_PROPERTY_REQUEST => self.change_property_request(request),
+            xproto::DELETE_PROPERTY_REQUEST => {
+                let window = self.check_window(request.u32(4)?)?;
+                let property = self.check_atom(request.u32(8)?)?;
+                let window_state = self.windows.get_mut(&window).unwrap();
+                if window_state.properties.remove(&property).is_some() {
+                    self.property_notify(window, property, Property::Delete);
+                }
+                Ok(None)
+            }
+            xproto::GET_PROPERTY_REQUEST => self.get_property_request(request),
+            xproto::LIST_PROPERTIES_REQUEST => {
+                let window = &self.windows[&self.check_window(request.u32(4)?)?];
+                let mut body = Vec::new();
+                body.extend_from_slice(&(window.properties.len() as u16).to_ne_bytes());
+                body.resize(24, 0);
+                for atom in window.properties.keys() {
+                    body.extend_from_slice(&atom.to_ne_bytes());
+                }
+                Ok(Some(Reply::new(0, body)))
+            }
+            xproto::SET_SELECTION_OWNER_REQUEST => {
+                self.set_selection_owner_request(client, request)
+            }
+            xproto::GET_SELECTION_OWNER_REQUEST => {
+                let selection = self.check_atom(request.u32(4)?)?;
+                let owner = self.selections.get(&selection).map_or(0, |s| s.owner);
+                Ok(Some(Reply::new(0, owner.to_ne_bytes().to_vec())))
+            }
+            xproto::CONVERT_SELECTION_REQUEST => self.convert_selection_request(client, request),
+            xproto::SEND_EVENT_REQUEST => self.send_event_request(request),
+            xproto::SET_INPUT_FOCUS_REQUEST => {
+                let revert_to = request.u8(1)?;
+                let focus = request.u32(4)?;
+                if focus > 1 {
+                    let _ = self.check_window(focus)?;
+                }
+                self.focus = focus;
+                self.revert_to = revert_to;
+                Ok(None)
+            }
+            xproto::GET_INPUT_FOCUS_REQUEST => Ok(Some(Reply::new(
+                self.revert_to,
+                self.focus.to_ne_bytes().to_vec(),
+            ))),
+            xproto::QUERY_EXTENSION_REQUEST => {
+                // No extensions are supported
+                Ok(Some(Reply::new(0, vec![0; 4])))
+            }
+            xproto::LIST_EXTENSIONS_REQUEST => Ok(Some(Reply::new(0, Vec::new()))),
+            xproto::CHANGE_SAVE_SET_REQUEST
+            | xproto::GRAB_SERVER_REQUEST
+            | xproto::UNGRAB_SERVER_REQUEST
+            | xproto::NO_OPERATION_REQUEST => Ok(None),
+            _ => Err(XError::new(xproto::REQUEST_ERROR, 0)),
+        }
+    }
+
+    fn now(&self) -> u32 {
+        let elapsed = self.start.elapsed();
+        (elapsed.as_secs() * 1000 + u64::from(elapsed.subsec_millis())) as u32
+    }
+
+    fn check_window(&self, window: u32) -> Result<u32, XError> {
+        if self.windows.contains_key(&window) {
+            Ok(window)
+        } else {
+            Err(XError::new(xproto::WINDOW_ERROR, window))
+        }
+    }
+
+    fn check_atom(&self, atom: u32) -> Result<u32, XError> {
+        if atom != 0 && atom as usize <= self.atoms.len() {
+            Ok(atom)
+        } else {
+            Err(XError::new(xproto::ATOM_ERROR, atom))
+        }
+    }
+
+    /// Send some data to a client.
+    fn send(&mut self, client: ClientId, data: &[u8]) {
+        if let Some(client) = self.clients.get_mut(&client) {
+            // Errors are noticed by the thread that reads from the client
+            let _ = client.stream.write_all(data);
+        }
+    }
+
+    /// Send an event to a client.
+    fn send_event(&mut self, client: ClientId, mut event: [u8; 32]) {
+        if let Some(state) = self.clients.get(&client) {
+            // All core events except KeymapNotify contain a sequence number
+            if event[0] & 0x7f != xproto::KEYMAP_NOTIFY_EVENT {
+                event[2..4].copy_from_slice(&state.sequence.to_ne_bytes());
+            }
+            self.send(client, &event);
+        }
+    }
+
+    /// Send an event to all clients that selected any of the events in `event_mask` on the
+    /// given window.
+    fn deliver(&mut self, window: u32, event_mask: u32, event: [u8; 32]) {
+        let clients = match self.windows.get(&window) {
+            Some(window) => window
+                .event_masks
+                .iter()
+                .filter(|(_, &mask)| mask & event_mask != 0)
+                .map(|(&client, _)| client)
+                .collect::<Vec<_>>(),
+            None => return,
+        };
+        for client in clients {
+            self.send_event(client, event);
+        }
+    }
+
+    /// Get the client that selected `SubstructureRedirect` on the given window.
+    fn redirect_client(&self, window: u32) -> Option<ClientId> {
+        let redirect = mask(EventMask::SubstructureRedirect);
+        self.windows.get(&window).and_then(|window| {
+            window
+                .event_masks
+                .iter()
+                .find(|(_, &mask)| mask & redirect != 0)
+                .map(|(&client, _)| client)
+        })
+    }
+
+    /// Check whether requests from `client` that affect `window` are redirected to another
+    /// client.
+    fn redirected_to(&self, client: ClientId, window: u32) -> Option<ClientId> {
+        let state = &self.windows[&window];
+        if state.override_redirect {
+            return None;
+        }
+        self.redirect_client(state.parent)
+            .filter(|&redirect| redirect != client)
+    }
+
+    fn create_window_request(&mut self, client: ClientId, request: Request<'_>) -> RequestResult {
+        let depth = request.u8(1)?;
+        let window = request.u32(4)?;
+        let parent = self.check_window(request.u32(8)?)?;
+        let (x, y) = (request.i16(12)?, request.i16(14)?);
+        let (width, height) = (request.u16(16)?, request.u16(18)?);
+        let border_width = request.u16(20)?;
+        let class = request.u16(22)?;
+        let visual = request.u32(24)?;
+        let values = request.values(32, request.u32(28)?)?;
+
+        let resource_id_base = (client + 1) << 21;
+        if window & !RESOURCE_ID_MASK != resource_id_base || self.windows.contains_key(&window) {
+            return Err(XError::new(xproto::ID_CHOICE_ERROR, window));
+        }
+        if width == 0 || height == 0 {
+            return Err(XError::new(xproto::VALUE_ERROR, 0));
+        }
+        let parent_state = &self.windows[&parent];
+        let new_window = Window {
+            parent,
+            children: Vec::new(),
+            x,
+            y,
+            width,
+            height,
+            border_width,
+            class: if class == 0 {
+                parent_state.class
+            } else {
+                class
+            },
+            depth: if depth == 0 {
+                parent_state.depth
+            } else {
+                depth
+            },
+            visual: if visual == 0 {
+                parent_state.visual
+            } else {
+                visual
+            },
+            mapped: false,
+            override_redirect: false,
+            event_masks: Default::default(),
+            properties: Default::default(),
+            creator: Some(client),
+        };
+        let _ = self.windows.insert(window, new_window);
+        if let Err(error) = self.change_attributes(client, window, &values) {
+            let _ = self.windows.remove(&window);
+            return Err(error);
+        }
+        self.windows.get_mut(&parent).unwrap().children.push(window);
+
+        let event = xproto::CreateNotifyEvent {
+            response_type: xproto::CREATE_NOTIFY_EVENT,
+            sequence: 0,
+            parent: parent.into(),
+            window: window.into(),
+            x,
+            y,
+            width,
+            height,
+            border_width,
+            override_redirect: self.windows[&window].override_redirect,
+        };
+        self.deliver(parent, mask(EventMask::SubstructureNotify), event.into());
+        Ok(None)
+    }
+
+    /// Apply the values of a `CreateWindow` or `ChangeWindowAttributes` request.
+    ///
+    /// Only `override_redirect` and `event_mask` are supported, all other attributes are ignored.
+    fn change_attributes(
+        &mut self,
+        client: ClientId,
+        window: u32,
+        values: &[(u32, u32)],
+    ) -> Result<(), XError> {
+        let exclusive = mask(EventMask::SubstructureRedirect)
+            | mask(EventMask::ResizeRedirect)
+            | mask(EventMask::ButtonPress);
+        let state = self.windows.get_mut(&window).unwrap();
+        for &(bit, value) in values {
+            match bit {
+                // override_redirect
+                0x200 => state.override_redirect = value != 0,
+                // event_mask
+                0x800 => {
+                    let taken = state
+                        .event_masks
+                        .iter()
+                        .filter(|(&other, _)| other != client)
+                        .any(|(_, &mask)| mask & value & exclusive != 0);
+                    if taken {
+                        return Err(XError::new(xproto::ACCESS_ERROR, 0));
+                    }
+                    if value == 0 {
+                        let _ = state.event_masks.remove(&client);
+                    } else {
+                        let _ = state.event_masks.insert(client, value);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn get_window_attributes_request(
+        &mut self,
+        client: ClientId,
+        request: Request<'_>,
+    ) -> RequestResult {
+        let window = self.check_window(request.u32(4)?)?;
+        let map_state: u8 = if !self.windows[&window].mapped {
+            0 // Unmapped
+        } else if self.is_viewable(window) {
+            2 // Viewable
+        } else {
+            1 // Unviewable
+        };
+        let state = &self.windows[&window];
+        let all_event_masks = state.event_masks.values().fold(0, |a, b| a | b);
+        let your_event_mask = state.event_masks.get(&client).copied().unwrap_or(0);
+        let mut body = Vec::new();
+        body.extend_from_slice(&state.visual.to_ne_bytes());
+        body.extend_from_slice(&state.class.to_ne_bytes());
+        body.extend_from_slice(&[0, 0]); // bit_gravity, win_gravity
+        body.extend_from_slice(&0u32.to_ne_bytes()); // backing_planes
+        body.extend_from_slice(&0u32.to_ne_bytes()); // backing_pixel
+        body.extend_from_slice(&[0, 0, map_state, state.override_redirect as u8]);
+        body.extend_from_slice(&DEFAULT_COLORMAP.to_ne_bytes());
+        body.extend_from_slice(&all_event_masks.to_ne_bytes());
+        body.extend_from_slice(&your_event_mask.to_ne_bytes());
+        body.extend_from_slice(&[0; 4]); // do_not_propagate_mask and padding
+        Ok(Some(Reply::new(0, body)))
+    }
+
+    /// Check whether the window and all its ancestors are mapped.
+    fn is_viewable(&self, mut window: u32) -> bool {
+        while window != 0 {
+            let state = &self.windows[&window];
+            if !state.mapped {
+                return false;
+            }
+            window = state.parent;
+        }
+        true
+    }
+
+    fn destroy_window(&mut self, window: u32) {
+        if self.windows[&window].mapped {
+            self.unmap_window(window);
+        }
+        self.destroy_tree(window);
+    }
+
+    /// Destroy a window and all its inferiors, starting with the inferiors.
+    fn destroy_tree(&mut self, window: u32) {
+        for child in self.windows[&window].children.clone() {
+            self.destroy_tree(child);
+        }
+        let parent = self.windows[&window].parent;
+        let event = xproto::DestroyNotifyEvent {
+            response_type: xproto::DESTROY_NOTIFY_EVENT,
+            sequence: 0,
+            event: window.into(),
+            window: window.into(),
+        };
+        self.deliver(window, mask(EventMask::StructureNotify), event.into());
+        let event = xproto::DestroyNotifyEvent {
+            event: parent.into(),
+            ..event
+        };
+        self.deliver(parent, mask(EventMask::SubstructureNotify), event.into());
+
+        let _ = self.windows.remove(&window);
+        if let Some(parent) = self.windows.get_mut(&parent) {
+            parent.children.retain(|&child| child != window);
+        }
+        // A selection is released when its owner window is destroyed
+        self.selections = std::mem::replace(&mut self.selections, BTreeMap::new())
+            .into_iter()
+            .filter(|(_, selection)| selection.owner != window)
+            .collect();
+        if self.focus == window {
+            self.focus = match xproto::InputFocus::from(self.revert_to) {
+                xproto::InputFocus::Parent => parent,
+                xproto::InputFocus::PointerRoot => 1,
+                _ => 0,
+            };
+        }
+    }
+
+    fn map_window(&mut self, client: ClientId, window: u32) {
+        let state = &self.windows[&window];
+        if state.mapped {
+            return;
+        }
+        let parent = state.parent;
+        if let Some(redirect) = self.redirected_to(client, window) {
+            let event = xproto::MapRequestEvent {
+                response_type: xproto::MAP_REQUEST_EVENT,
+                sequence: 0,
+                parent: parent.into(),
+                window: window.into(),
+            };
+            self.send_event(redirect, event.into());
+            return;
+        }
+        let state = self.windows.get_mut(&window).unwrap();
+        state.mapped = true;
+        let event = xproto::MapNotifyEvent {
+            response_type: xproto::MAP_NOTIFY_EVENT,
+            sequence: 0,
+            event: window.into(),
+            window: window.into(),
+            override_redirect: state.override_redirect,
+        };
+        self.deliver(window, mask(EventMask::StructureNotify), event.into());
+        let event = xproto::MapNotifyEvent {
+            event: parent.into(),
+            ..event
+        };
+        self.deliver(parent, mask(EventMask::SubstructureNotify), event.into());
+    }
+
+    fn unmap_window(&mut self, window: u32) {
+        let state = self.windows.get_mut(&window).unwrap();
+        if !state.mapped || window == ROOT_WINDOW {
+            return;
+        }
+        state.mapped = false;
+        let parent = state.parent;
+        let event = xproto::UnmapNotifyEvent {
+            response_type: xproto::UNMAP_NOTIFY_EVENT,
+            sequence: 0,
+            event: window.into(),
+            window: window.into(),
+            from_configure: false,
+        };
+        self.deliver(window, mask(EventMask::StructureNotify), event.into());
+        let event = xproto::UnmapNotifyEvent {
+            event: parent.into(),
+            ..event
+        };
+        self.deliver(parent, mask(EventMask::SubstructureNotify), event.into());
+    }
+
+    fn configure_window_request(
+        &mut self,
+        client: ClientId,
+        request: Request<'_>,
+    ) -> RequestResult {
+        let window = self.check_window(request.u32(4)?)?;
+        let value_mask = request.u16(8)?;
+        let values = request.values(12, value_mask.into())?;
+
+        let state = &self.windows[&window];
+        let parent = state.parent;
+        let (mut x, mut y) = (state.x, state.y);
+        let (mut width, mut height) = (state.width, state.height);
+        let mut border_width = state.border_width;
+        let (mut sibling, mut stack_mode) = (None, None);
+        for (bit, value) in values {
+            match bit {
+                0x01 => x = value as i16,
+                0x02 => y = value as i16,
+                0x04 => width = value as u16,
+                0x08 => height = value as u16,
+                0x10 => border_width = value as u16,
+                0x20 => sibling = Some(self.check_window(value)?),
+                0x40 => match StackMode::from(value) {
+                    mode @ StackMode::Above
+                    | mode @ StackMode::Below
+                    | mode @ StackMode::TopIf
+                    | mode @ StackMode::BottomIf
+                    | mode @ StackMode::Opposite => stack_mode = Some(mode),
+                    _ => return Err(XError::new(xproto::VALUE_ERROR, value)),
+                },
+                _ => {}
+            }
+        }
+        if width == 0 || height == 0 {
+            return Err(XError::new(xproto::VALUE_ERROR, 0));
+        }
+        if let Some(sibling) = sibling {
+            if stack_mode.is_none() || sibling == window || self.windows[&sibling].parent != parent
+            {
+                return Err(XError::new(xproto::MATCH_ERROR, 0));
+            }
+        }
+        if window == ROOT_WINDOW {
+            return Ok(None);
+        }
+
+        if let Some(redirect) = self.redirected_to(client, window) {
+            let event = xproto::ConfigureRequestEvent {
+                response_type: xproto::CONFIGURE_REQUEST_EVENT,
+                stack_mode: stack_mode.unwrap_or(StackMode::Above),
+                sequence: 0,
+                parent: parent.into(),
+                window: window.into(),
+                sibling: sibling.unwrap_or(0).into(),
+                x,
+                y,
+                width,
+                height,
+                border_width,
+                value_mask: value_mask.into(),
+            };
+            self.send_event(redirect, event.into());
+            return Ok(None);
+        }
+
+        let state = self.windows.get_mut(&window).unwrap();
+        state.x = x;
+        state.y = y;
+        state.width = width;
+        state.height = height;
+        state.border_width = border_width;
+        let override_redirect = state.override_redirect;
+        let siblings = &mut self.windows.get_mut(&parent).unwrap().children;
+        match stack_mode {
+            Some(StackMode::Above) => {
+                siblings.retain(|&child| child != window);
+                let index = sibling
+                    .and_then(|sibling| siblings.iter().position(|&child| child == sibling))
+                    .map_or(siblings.len(), |index| index + 1);
+                siblings.insert(index, window);
+            }
+            Some(StackMode::Below) => {
+                siblings.retain(|&child| child != window);
+                let index = sibling
+                    .and_then(|sibling| siblings.iter().position(|&child| child == sibling))
+                    .unwrap_or(0);
+                siblings.insert(index, window);
+            }
+            _ => {}
+        }
+        let index = siblings.iter().position(|&child| child == window).unwrap();
+        let above_sibling = if index == 0 { 0 } else { siblings[index - 1] };
+
+        let event = xproto::ConfigureNotifyEvent {
+            response_type: xproto::CONFIGURE_NOTIFY_EVENT,
+            sequence: 0,
+            event: window.into(),
+            window: window.into(),
+            above_sibling: above_sibling.into(),
+            x,
+            y,
+            width,
+            height,
+            border_width,
+            override_redirect,
+        };
+        self.deliver(window, mask(EventMask::StructureNotify), event.into());
+        let event = xproto::ConfigureNotifyEvent {
+            event: parent.into(),
+            ..event
+        };
+        self.deliver(parent, mask(EventMask::SubstructureNotify), event.into());
+        Ok(None)
+    }
+
+    fn reparent_window_request(&mut self, client: ClientId, request: Request<'_>) -> RequestResult {
+        let window = self.check_window(request.u32(4)?)?;
+        let parent = self.check_window(request.u32(8)?)?;
+        let (x, y) = (request.i16(12)?, request.i16(14)?);
+
+        // The new parent must not be the window itself or one of its inferiors
+        let mut ancestor = parent;
+        while ancestor != 0 {
+            if ancestor == window {
+                return Err(XError::new(xproto::MATCH_ERROR, 0));
+            }
+            ancestor = self.windows[&ancestor].parent;
+        }
+
+        let was_mapped = self.windows[&window].mapped;
+        self.unmap_window(window);
+
+        let state = self.windows.get_mut(&window).unwrap();
+        let old_parent = state.parent;
+        state.parent = parent;
+        state.x = x;
+        state.y = y;
+        let override_redirect = state.override_redirect;
+        self.windows
+            .get_mut(&old_parent)
+            .unwrap()
+            .children
+            .retain(|&child| child != window);
+        self.windows.get_mut(&parent).unwrap().children.push(window);
+
+        let event = xproto::ReparentNotifyEvent {
+            response_type: xproto::REPARENT_NOTIFY_EVENT,
+            sequence: 0,
+            event: window.into(),
+            window: window.into(),
+            parent: parent.into(),
+            x,
+            y,
+            override_redirect,
+        };
+        self.deliver(window, mask(EventMask::StructureNotify), event.into());
+        let substructure = mask(EventMask::SubstructureNotify);
+        self.deliver(
+            old_parent,
+            substructure,
+            xproto::ReparentNotifyEvent {
+                event: old_parent.into(),
+                ..event
+            }
+            .into(),
+        );
+        self.deliver(
+            parent,
+            substructure,
+            xproto::ReparentNotifyEvent {
+                event: parent.into(),
+                ..event
+            }
+            .into(),
+        );
+
+        if was_mapped {
+            self.map_window(client, window);
+        }
+        Ok(None)
+    }
+
+    fn property_notify(&mut self, window: u32, atom: u32, state: Property) {
+        let event = xproto::PropertyNotifyEvent {
+            response_type: xproto::PROPERTY_NOTIFY_EVENT,
+            sequence: 0,
+            window: window.into(),
+            atom: atom.into(),
+            time: self.now(),
+            state,
+        };
+        self.deliver(window, mask(EventMask::PropertyChange), event.into());
+    }
+
+    fn change_property_request(&mut self, request: Request<'_>) -> RequestResult {
+        let mode = request.u8(1)?;
+        let window = self.check_window(request.u32(4)?)?;
+        let property = self.check_atom(request.u32(8)?)?;
+        let type_ = self.check_atom(request.u32(12)?)?;
+        let format = request.u8(16)?;
+        let data_len = request.u32(20)?;
+        if format != 8 && format != 16 && format != 32 {
+            return Err(XError::new(xproto::VALUE_ERROR, format.into()));
+        }
+        if mode > 2 {
+            return Err(XError::new(xproto::VALUE_ERROR, mode.into()));
+        }
+        let length = data_len as usize * usize::from(format / 8);
+        let data = request.bytes(24, length)?;
+
+        let properties = &mut self.windows.get_mut(&window).unwrap().properties;
+        match (mode, properties.get_mut(&property)) {
+            // Prepend and Append
+            (1, Some(value)) | (2, Some(value)) => {
+                if value.type_ != type_ || value.format != format {
+                    return Err(XError::new(xproto::MATCH_ERROR, 0));
+                }
+                if mode == 1 {
+                    let _ = value.data.splice(..0, data.iter().copied());
+                } else {
+                    value.data.extend_from_slice(data);
+                }
+            }
+            // Replace, or Prepend/Append to a non-existing property
+            _ => {
+                let value = PropertyValue {
+                    type_,
+                    format,
+                    data: data.to_vec(),
+                };
+                let _ = properties.insert(property, value);
+            }
+        }
+        self.property_notify(window, property, Property::NewValue);
+        Ok(None)
+    }
+
+    fn get_property_request(&mut self, request: Request<'_>) -> RequestResult {
+        let delete = request.u8(1)? != 0;
+        let window = self.check_window(request.u32(4)?)?;
+        let property = self.check_atom(request.u32(8)?)?;
+        let type_ = request.u32(12)?;
+        let long_offset = request.u32(16)?;
+        let long_length = request.u32(20)?;
+        if type_ != 0 {
+            let _ = self.check_atom(type_)?;
+        }
+
+        let mut body = Vec::new();
+        let value = match self.windows[&window].properties.get(&property) {
+            None => {
+                body.resize(24, 0);
+                return Ok(Some(Reply::new(0, body)));
+            }
+            Some(value) => value,
+        };
+        let format = value.format;
+        body.extend_from_slice(&value.type_.to_ne_bytes());
+        if type_ != 0 && type_ != value.type_ {
+            // The type does not match, only report the actual type and the length
+            body.extend_from_slice(&(value.data.len() as u32).to_ne_bytes());
+            body.resize(24, 0);
+            return Ok(Some(Reply::new(format, body)));
+        }
+        let offset = 4 * long_offset as usize;
+        if offset > value.data.len() {
+            return Err(XError::new(xproto::VALUE_ERROR, long_offset));
+        }
+        let length = (value.data.len() - offset).min(4 * long_length as usize);
+        let bytes_after = value.data.len() - offset - length;
+        let data = value.data[offset..offset + length].to_vec();
+        body.extend_from_slice(&(bytes_after as u32).to_ne_bytes());
+        body.extend_from_slice(&((length / usize::from(format / 8)) as u32).to_ne_bytes());
+        body.resize(24, 0);
+        body.extend_from_slice(&data);
+
+        if delete && bytes_after == 0 {
+            let properties = &mut self.windows.get_mut(&window).unwrap().properties;
+            let _ = properties.remove(&property);
+            self.property_notify(window, property, Property::Delete);
+        }
+        Ok(Some(Reply::new(format, body)))
+    }
+
+    fn set_selection_owner_request(
+        &mut self,
+        client: ClientId,
+        request: Request<'_>,
+    ) -> RequestResult {
+        let owner = request.u32(4)?;
+        let selection = self.check_atom(request.u32(8)?)?;
+        if owner != 0 {
+            let _ = self.check_window(owner)?;
+        }
+        let time = match request.u32(12)? {
+            0 => self.now(),
+            time => time,
+        };
+        let previous = self.selections.get(&selection);
+        match previous {
+            Some(previous) if time < previous.time => return Ok(None),
+            _ => {}
+        }
+        if let Some(previous) = previous {
+            if let (Some(previous_client), true) = (previous.client, previous.owner != owner) {
+                let event = xproto::SelectionClearEvent {
+                    response_type: xproto::SELECTION_CLEAR_EVENT,
+                    sequence: 0,
+                    time,
+                    owner: previous.owner.into(),
+                    selection: selection.into(),
+                };
+                self.send_event(previous_client, event.into());
+            }
+        }
+        let client = if owner == 0 { None } else { Some(client) };
+        let _ = self.selections.insert(
+            selection,
+            Selection {
+                owner,
+                client,
+                time,
+            },
+        );
+        Ok(None)
+    }
+
+    fn convert_selection_request(
+        &mut self,
+        client: ClientId,
+        request: Request<'_>,
+    ) -> RequestResult {
+        let requestor = self.check_window(request.u32(4)?)?;
+        let selection = self.check_atom(request.u32(8)?)?;
+        let target = self.check_atom(request.u32(12)?)?;
+        let property = request.u32(16)?;
+        let time = request.u32(20)?;
+        if property != 0 {
+            let _ = self.check_atom(property)?;
+        }
+        let owner = self
+            .selections
+            .get(&selection)
+            .and_then(|s| s.client.map(|client| (s.owner, client)));
+        match owner {
+            Some((owner, owner_client)) => {
+                let event = xproto::SelectionRequestEvent {
+                    response_type: xproto::SELECTION_REQUEST_EVENT,
+                    sequence: 0,
+                    time,
+                    owner: owner.into(),
+                    requestor: requestor.into(),
+                    selection: selection.into(),
+                    target: target.into(),
+                    property: property.into(),
+                };
+                self.send_event(owner_client, event.into());
+            }
+            None => {
+                let event = xproto::SelectionNotifyEvent {
+                    response_type: xproto::SELECTION_NOTIFY_EVENT,
+                    sequence: 0,
+                    time,
+                    requestor: requestor.into(),
+                    selection: selection.into(),
+                    target: target.into(),
+                    property: xproto::Atom::NONE,
+                };
+                self.send_event(client, event.into());
+            }
+        }
+        Ok(None)
+    }
+
+    fn send_event_request(&mut self, request: Request<'_>) -> RequestResult {
+        let destination = match request.u32(4)? {
+            // PointerWindow: There is no pointer, so use the root window
+            0 => ROOT_WINDOW,
+            // InputFocus
+            1 if self.focus > 1 => self.focus,
+            1 => ROOT_WINDOW,
+            window => self.check_window(window)?,
+        };
+        let event_mask = request.u32(8)?;
+        let mut event = [0; 32];
+        event.copy_from_slice(request.bytes(12, 32)?);
+        // Mark the event as coming from a SendEvent request
+        event[0] |= 0x80;
+        if event_mask == 0 {
+            if let Some(client) = self.windows[&destination].creator {
+                self.send_event(client, event);
+            }
+        } else {
+            self.deliver(destination, event_mask, event);
+        }
+        Ok(None)
+    }
+}
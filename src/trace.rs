@@ -0,0 +1,774 @@
+//! Tracing of the X11 protocol traffic of a connection.
+//!
+//! [`TracingConnection`] wraps another connection and reports every request that is sent and
+//! every reply, error, and event that is received to a [`TraceSink`]. This is similar to running
+//! a program under `xtrace`, but without an external tool.
+//!
+//! Requests are reported with their name, which is looked up from their major and minor opcode,
+//! and are parsed into [`Request`]. Replies are reported with the name of the request they belong
+//! to and are parsed into [`Reply`] based on the opcodes of that request. Errors and events are
+//! parsed into [`Error`] and [`Event`].
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::ConnectionExt;
+//! use x11rb::trace::{TraceRecord, TracingConnection};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (conn, _) = x11rb::connect(None)?;
+//! let conn = TracingConnection::new(conn, |record: &TraceRecord<'_>| eprintln!("{}", record));
+//! conn.get_input_focus()?.reply()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! With the `log` feature, [`LogSink`] sends all records to the `log` crate instead.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::IoSlice;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::connection::{
+    BufWithFds, Connection, DiscardMode, RawEventAndSeqNumber, ReplyOrError, RequestConnection,
+    RequestKind, SequenceNumber, SpecialEventQueue,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyOrIdError};
+use crate::protocol::xproto::Setup;
+use crate::protocol::{request_name, Error, Event, Reply, Request};
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{
+    parse_request_header, BigRequests, ByteOrder, ExtInfoProvider, ExtensionInformation,
+    RequestHeader, TryParse, TryParseFd,
+};
+
+/// A single item of protocol traffic.
+#[derive(Debug)]
+pub enum TraceRecord<'a> {
+    /// A request was sent to the X11 server.
+    Request {
+        /// The sequence number of the request.
+        sequence: SequenceNumber,
+        /// The name of the extension that the request belongs to, or `None` for core requests.
+        extension: Option<&'static str>,
+        /// The name of the request, if it is known.
+        name: Option<&'static str>,
+        /// The major opcode of the request.
+        major_opcode: u8,
+        /// The minor opcode of the request. This is only meaningful for extension requests.
+        minor_opcode: u8,
+        /// The length of the request in bytes.
+        length: usize,
+        /// The number of file descriptors that were sent with the request.
+        fds: usize,
+        /// The parsed request, or `None` if it could not be parsed.
+        request: Option<&'a Request>,
+    },
+    /// A reply was received from the X11 server.
+    Reply {
+        /// The sequence number of the request that this reply belongs to.
+        sequence: SequenceNumber,
+        /// The name of the request that this reply belongs to, if it is known.
+        request: Option<&'static str>,
+        /// The length of the reply in bytes.
+        length: usize,
+        /// The number of file descriptors that were received with the reply.
+        fds: usize,
+        /// The parsed reply, or `None` if it could not be parsed.
+        reply: Option<&'a Reply>,
+    },
+    /// An X11 error was received from the X11 server.
+    Error {
+        /// The sequence number of the request that caused this error.
+        sequence: SequenceNumber,
+        /// The error.
+        error: &'a Error<&'a [u8]>,
+    },
+    /// An event was received from the X11 server.
+    ///
+    /// Errors that are handled as events are also reported like this.
+    Event {
+        /// The sequence number of the last request that the X11 server processed.
+        sequence: SequenceNumber,
+        /// The event.
+        event: &'a Event<&'a [u8]>,
+    },
+}
+
+impl fmt::Display for TraceRecord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceRecord::Request {
+                sequence,
+                extension,
+                name,
+                major_opcode,
+                minor_opcode,
+                length,
+                fds,
+                request,
+            } => {
+                write!(f, "{:>6} > ", sequence)?;
+                if let Some(extension) = extension {
+                    write!(f, "{}::", extension)?;
+                }
+                write!(f, "{} (opcode {}", name.unwrap_or("Unknown"), major_opcode)?;
+                if extension.is_some() {
+                    write!(f, ".{}", minor_opcode)?;
+                }
+                write!(f, ", {} bytes", length)?;
+                if *fds != 0 {
+                    write!(f, ", {} FDs", fds)?;
+                }
+                write!(f, ")")?;
+                if let Some(request) = request {
+                    write!(f, ": {:?}", request)?;
+                }
+                Ok(())
+            }
+            TraceRecord::Reply {
+                sequence,
+                request,
+                length,
+                fds,
+                reply,
+            } => {
+                write!(
+                    f,
+                    "{:>6} < Reply to {} ({} bytes",
+                    sequence,
+                    request.unwrap_or("Unknown"),
+                    length
+                )?;
+                if *fds != 0 {
+                    write!(f, ", {} FDs", fds)?;
+                }
+                write!(f, ")")?;
+                if let Some(reply) = reply {
+                    write!(f, ": {:?}", reply)?;
+                }
+                Ok(())
+            }
+            TraceRecord::Error { sequence, error } => write!(f, "{:>6} < {:?}", sequence, error),
+            TraceRecord::Event { sequence, event } => write!(f, "{:>6} < {:?}", sequence, event),
+        }
+    }
+}
+
+/// A receiver for [`TraceRecord`]s.
+///
+/// This is implemented for all closures taking a `&TraceRecord`.
+pub trait TraceSink {
+    /// Handle a single record.
+    fn record(&self, record: &TraceRecord<'_>);
+}
+
+impl<F: Fn(&TraceRecord<'_>)> TraceSink for F {
+    fn record(&self, record: &TraceRecord<'_>) {
+        self(record)
+    }
+}
+
+/// A [`TraceSink`] that logs all records via the `log` crate.
+///
+/// Records are logged at the trace level with target `x11rb::trace`.
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogSink;
+
+#[cfg(feature = "log")]
+impl TraceSink for LogSink {
+    fn record(&self, record: &TraceRecord<'_>) {
+        log::trace!(target: "x11rb::trace", "{}", record);
+    }
+}
+
+/// The extensions that were seen in calls to `extension_information()`.
+#[derive(Debug, Default)]
+struct KnownExtensions(Vec<(&'static str, ExtensionInformation)>);
+
+impl ExtInfoProvider for KnownExtensions {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .find(|(_, info)| info.major_opcode == major_opcode)
+            .copied()
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_event <= event_code)
+            .max_by_key(|(_, info)| info.first_event)
+            .copied()
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_error <= error_code)
+            .max_by_key(|(_, info)| info.first_error)
+            .copied()
+    }
+}
+
+/// A connection that reports all protocol traffic of another connection to a [`TraceSink`].
+///
+/// All methods of `RequestConnection` and `Connection` are forwarded to the wrapped connection.
+pub struct TracingConnection<C, S> {
+    conn: C,
+    sink: S,
+    extensions: Mutex<KnownExtensions>,
+    // Name, major opcode, and minor opcode of the requests whose reply was not yet received
+    pending: Mutex<HashMap<SequenceNumber, PendingRequest>>,
+}
+
+type PendingRequest = (Option<&'static str>, u8, u8);
+
+/// Duplicate the given FDs, so that a request or reply can be parsed without taking the FDs away.
+fn clone_fds(fds: &[RawFdContainer]) -> Vec<RawFdContainer> {
+    #[cfg(unix)]
+    {
+        fds.iter().filter_map(|fd| fd.try_clone().ok()).collect()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = fds;
+        Vec::new()
+    }
+}
+
+impl<C: fmt::Debug, S> fmt::Debug for TracingConnection<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TracingConnection")
+            .field("conn", &self.conn)
+            .finish()
+    }
+}
+
+impl<C: RequestConnection, S: TraceSink> TracingConnection<C, S> {
+    /// Wrap the given connection and report its traffic to `sink`.
+    pub fn new(conn: C, sink: S) -> Self {
+        Self {
+            conn,
+            sink,
+            extensions: Default::default(),
+            pending: Default::default(),
+        }
+    }
+
+    /// Get a reference to the wrapped connection.
+    pub fn inner(&self) -> &C {
+        &self.conn
+    }
+
+    /// Get the wrapped connection back.
+    pub fn into_inner(self) -> C {
+        self.conn
+    }
+
+    /// Report a request that was sent. `fds` are copies of the FDs that were sent with it.
+    fn trace_request(
+        &self,
+        sequence: SequenceNumber,
+        bufs: &[IoSlice<'_>],
+        mut fds: Vec<RawFdContainer>,
+        kind: RequestKind,
+    ) {
+        let bytes = bufs
+            .iter()
+            .flat_map(|buf| buf.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        let major_opcode = bytes.first().copied().unwrap_or(0);
+        let minor_opcode = bytes.get(1).copied().unwrap_or(0);
+        let num_fds = fds.len();
+        let extensions = self.extensions.lock().unwrap();
+        let extension = extensions
+            .0
+            .iter()
+            .find(|(_, info)| info.major_opcode == major_opcode)
+            .map(|(name, _)| *name);
+        let name = request_name(&*extensions, major_opcode, minor_opcode);
+        // The connection only inserts the BIG-REQUESTS length when sending the request. Requests
+        // that are too large for the length field have a zero there, so take the length from the
+        // buffer instead.
+        let order = self.conn.byte_order();
+        let request = parse_request_header(&bytes, BigRequests::NotEnabled, order)
+            .or_else(|_| match bytes.get(2..4) {
+                Some([0, 0]) if bytes.len() % 4 == 0 => Ok((
+                    RequestHeader {
+                        major_opcode,
+                        minor_opcode,
+                        remaining_length: u32::try_from(bytes.len() / 4 - 1)
+                            .map_err(|_| ParseError::ParseError)?,
+                    },
+                    &bytes[4..],
+                )),
+                _ => Err(ParseError::ParseError),
+            })
+            .and_then(|(header, body)| Request::parse(header, body, &mut fds, &*extensions, order))
+            .ok();
+        drop(extensions);
+        if kind == RequestKind::HasResponse {
+            let _ = self
+                .pending
+                .lock()
+                .unwrap()
+                .insert(sequence, (name, major_opcode, minor_opcode));
+        }
+        self.sink.record(&TraceRecord::Request {
+            sequence,
+            extension,
+            name,
+            major_opcode,
+            minor_opcode,
+            length: bytes.len(),
+            fds: num_fds,
+            request: request.as_ref(),
+        });
+    }
+
+    /// Report a reply that was received. It is parsed based on the request that caused it.
+    fn trace_reply(&self, sequence: SequenceNumber, reply: &[u8], fds: &[RawFdContainer]) {
+        let pending = self.pending.lock().unwrap().remove(&sequence);
+        let (request, parsed) = match pending {
+            Some((name, major_opcode, minor_opcode)) => {
+                let extensions = self.extensions.lock().unwrap();
+                let parsed = Reply::parse_with_order(
+                    major_opcode,
+                    minor_opcode,
+                    reply,
+                    &mut clone_fds(fds),
+                    &*extensions,
+                    self.conn.byte_order(),
+                );
+                (name, parsed.ok())
+            }
+            None => (None, None),
+        };
+        self.sink.record(&TraceRecord::Reply {
+            sequence,
+            request,
+            length: reply.len(),
+            fds: fds.len(),
+            reply: parsed.as_ref(),
+        });
+    }
+
+    fn trace_error(&self, sequence: SequenceNumber, error: &[u8]) {
+        let _ = self.pending.lock().unwrap().remove(&sequence);
+        let error = self
+            .conn
+            .parse_error(error)
+            .unwrap_or(Error::Unknown(error));
+        self.sink.record(&TraceRecord::Error {
+            sequence,
+            error: &error,
+        });
+    }
+
+    fn trace_reply_or_error<R: fmt::Debug + AsRef<[u8]>>(
+        &self,
+        sequence: SequenceNumber,
+        reply: &ReplyOrError<R, C::Buf>,
+    ) {
+        match reply {
+            ReplyOrError::Reply(reply) => self.trace_reply(sequence, reply.as_ref(), &[]),
+            ReplyOrError::Error(error) => self.trace_error(sequence, error.as_ref()),
+        }
+    }
+
+    fn trace_event(&self, sequence: SequenceNumber, event: &[u8]) {
+        let event = self
+            .conn
+            .parse_event(event)
+            .unwrap_or(Event::Unknown(event));
+        self.sink.record(&TraceRecord::Event {
+            sequence,
+            event: &event,
+        });
+    }
+}
+
+impl<C: RequestConnection, S: TraceSink> RequestConnection for TracingConnection<C, S> {
+    type Buf = C::Buf;
+
+    fn send_request_with_reply<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+    where
+        R: TryParse,
+    {
+        let request_fds = clone_fds(&fds);
+        let sequence = self
+            .conn
+            .send_request_with_reply::<R>(bufs, fds)?
+            .into_sequence_number();
+        self.trace_request(sequence, bufs, request_fds, RequestKind::HasResponse);
+        Ok(Cookie::new(self, sequence))
+    }
+
+    fn send_request_with_reply_with_fds<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+    where
+        R: TryParseFd,
+    {
+        let request_fds = clone_fds(&fds);
+        let sequence = self
+            .conn
+            .send_request_with_reply_with_fds::<R>(bufs, fds)?
+            .into_sequence_number();
+        self.trace_request(sequence, bufs, request_fds, RequestKind::HasResponse);
+        Ok(CookieWithFds::new(self, sequence))
+    }
+
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        let request_fds = clone_fds(&fds);
+        let (_, sequence) = self.conn.send_request_without_reply(bufs, fds)?.consume();
+        self.trace_request(sequence, bufs, request_fds, RequestKind::IsVoid);
+        Ok(VoidCookie::new(self, sequence))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        let _ = self.pending.lock().unwrap().remove(&sequence);
+        self.conn.discard_reply(sequence, kind, mode)
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.conn.prefetch_extension_information(extension_name)
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        let info = self.conn.extension_information(extension_name)?;
+        if let Some(info) = info {
+            let mut extensions = self.extensions.lock().unwrap();
+            if extensions.0.iter().all(|(name, _)| *name != extension_name) {
+                extensions.0.push((extension_name, info));
+            }
+        }
+        Ok(info)
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<C::Buf>, ConnectionError> {
+        let reply = self.conn.wait_for_reply_or_raw_error(sequence)?;
+        self.trace_reply_or_error(sequence, &reply);
+        Ok(reply)
+    }
+
+    fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<C::Buf>, ConnectionError> {
+        let reply = self.conn.wait_for_reply(sequence)?;
+        match &reply {
+            Some(reply) => self.trace_reply(sequence, reply.as_ref(), &[]),
+            None => {
+                let _ = self.pending.lock().unwrap().remove(&sequence);
+            }
+        }
+        Ok(reply)
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<C::Buf>, C::Buf>, ConnectionError> {
+        let reply = self.conn.wait_for_reply_with_fds_raw(sequence)?;
+        match &reply {
+            ReplyOrError::Reply((reply, fds)) => self.trace_reply(sequence, reply.as_ref(), fds),
+            ReplyOrError::Error(error) => self.trace_error(sequence, error.as_ref()),
+        }
+        Ok(reply)
+    }
+
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<ReplyOrError<C::Buf>>, ConnectionError> {
+        let reply = self
+            .conn
+            .wait_for_reply_or_raw_error_timeout(sequence, timeout)?;
+        if let Some(reply) = &reply {
+            self.trace_reply_or_error(sequence, reply);
+        }
+        Ok(reply)
+    }
+
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<ReplyOrError<BufWithFds<C::Buf>, C::Buf>>, ConnectionError> {
+        let reply = self
+            .conn
+            .wait_for_reply_with_fds_raw_timeout(sequence, timeout)?;
+        match &reply {
+            Some(ReplyOrError::Reply((reply, fds))) => {
+                self.trace_reply(sequence, reply.as_ref(), fds)
+            }
+            Some(ReplyOrError::Error(error)) => self.trace_error(sequence, error.as_ref()),
+            None => {}
+        }
+        Ok(reply)
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<C::Buf>, ConnectionError> {
+        let error = self.conn.check_for_raw_error(sequence)?;
+        if let Some(error) = &error {
+            self.trace_error(sequence, error.as_ref());
+        }
+        Ok(error)
+    }
+
+    fn maximum_request_bytes(&self) -> usize {
+        self.conn.maximum_request_bytes()
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {
+        self.conn.prefetch_maximum_request_bytes()
+    }
+
+    fn byte_order(&self) -> ByteOrder {
+        self.conn.byte_order()
+    }
+
+    fn parse_error<E>(&self, error: E) -> Result<Error<E>, ParseError>
+    where
+        E: fmt::Debug + AsRef<[u8]>,
+    {
+        self.conn.parse_error(error)
+    }
+
+    fn parse_event<E>(&self, event: E) -> Result<Event<E>, ParseError>
+    where
+        E: fmt::Debug + AsRef<[u8]>,
+    {
+        self.conn.parse_event(event)
+    }
+}
+
+impl<C: Connection, S: TraceSink> Connection for TracingConnection<C, S> {
+    fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<C::Buf>, ConnectionError> {
+        let (event, sequence) = self.conn.wait_for_raw_event_with_sequence()?;
+        self.trace_event(sequence, event.as_ref());
+        Ok((event, sequence))
+    }
+
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<RawEventAndSeqNumber<C::Buf>>, ConnectionError> {
+        let event = self
+            .conn
+            .wait_for_raw_event_with_sequence_timeout(timeout)?;
+        if let Some((event, sequence)) = &event {
+            self.trace_event(*sequence, event.as_ref());
+        }
+        Ok(event)
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<C::Buf>>, ConnectionError> {
+        let event = self.conn.poll_for_raw_event_with_sequence()?;
+        if let Some((event, sequence)) = &event {
+            self.trace_event(*sequence, event.as_ref());
+        }
+        Ok(event)
+    }
+
+    fn register_for_special_xge(
+        &self,
+        extension_name: &'static str,
+        eid: u32,
+    ) -> Result<SpecialEventQueue, ConnectionError> {
+        self.conn.register_for_special_xge(extension_name, eid)
+    }
+
+    fn unregister_for_special_xge(&self, queue: SpecialEventQueue) {
+        self.conn.unregister_for_special_xge(queue)
+    }
+
+    fn wait_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<RawEventAndSeqNumber<C::Buf>, ConnectionError> {
+        let (event, sequence) = self.conn.wait_for_raw_special_event_with_sequence(queue)?;
+        self.trace_event(sequence, event.as_ref());
+        Ok((event, sequence))
+    }
+
+    fn poll_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Option<RawEventAndSeqNumber<C::Buf>>, ConnectionError> {
+        let event = self.conn.poll_for_raw_special_event_with_sequence(queue)?;
+        if let Some((event, sequence)) = &event {
+            self.trace_event(*sequence, event.as_ref());
+        }
+        Ok(event)
+    }
+
+    fn flush(&self) -> Result<(), ConnectionError> {
+        self.conn.flush()
+    }
+
+    fn setup(&self) -> &Setup {
+        self.conn.setup()
+    }
+
+    fn generate_id(&self) -> Result<u32, ReplyOrIdError<C::Buf>> {
+        self.conn.generate_id()
+    }
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use std::cell::RefCell;
+    use std::io::{IoSlice, Read, Write};
+
+    use super::{TraceRecord, TracingConnection};
+    use crate::connection::{Connection, RequestConnection, RequestKind};
+    use crate::protocol::xproto::ConnectionExt as _;
+    use crate::rust_connection::test::connected_pair;
+
+    #[test]
+    fn trace_traffic() {
+        let (conn, mut server) = connected_pair();
+
+        // The server answers GetInputFocus and sends a KeyPress event
+        let mut reply = vec![1, 0];
+        reply.extend_from_slice(&1u16.to_ne_bytes());
+        reply.extend_from_slice(&0u32.to_ne_bytes());
+        reply.extend_from_slice(&0x1234u32.to_ne_bytes());
+        reply.resize(32, 0);
+        let mut event = vec![2, 0];
+        event.extend_from_slice(&1u16.to_ne_bytes());
+        event.resize(32, 0);
+        server.write_all(&reply).unwrap();
+        server.write_all(&event).unwrap();
+
+        let records = RefCell::new(Vec::new());
+        let conn = TracingConnection::new(conn, |record: &TraceRecord<'_>| {
+            records.borrow_mut().push(record.to_string())
+        });
+        let _ = conn.get_input_focus().unwrap().reply().unwrap();
+        let _ = conn.wait_for_event().unwrap();
+
+        let records = records.into_inner();
+        assert_eq!(records.len(), 3, "{:?}", records);
+        assert_eq!(
+            records[0],
+            "     1 > GetInputFocus (opcode 43, 4 bytes): GetInputFocus(GetInputFocusRequest)"
+        );
+        assert!(
+            records[1].starts_with(
+                "     1 < Reply to GetInputFocus (32 bytes): GetInputFocus(GetInputFocusReply {"
+            ),
+            "{}",
+            records[1]
+        );
+        assert!(records[1].contains("focus: Window(4660)"), "{}", records[1]);
+        assert!(
+            records[2].starts_with("     1 < KeyPress("),
+            "{}",
+            records[2]
+        );
+    }
+
+    #[test]
+    fn trace_big_request() {
+        let (conn, _server) = connected_pair();
+        let records = RefCell::new(Vec::new());
+        let conn = TracingConnection::new(conn, |record: &TraceRecord<'_>| {
+            records.borrow_mut().push(record.to_string())
+        });
+
+        // A NoOperation request that is too large for the length field
+        let mut request = vec![127, 0, 0, 0];
+        request.resize(4 << 16, 0);
+        conn.trace_request(
+            1,
+            &[IoSlice::new(&request)],
+            Vec::new(),
+            RequestKind::IsVoid,
+        );
+
+        let records = records.into_inner();
+        assert!(
+            records[0].starts_with("     1 > NoOperation (opcode 127, 262144 bytes): NoOperation("),
+            "{}",
+            records[0]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn trace_non_native_byte_order() {
+        use crate::rust_connection::test::test_setup;
+        use crate::rust_connection::RustConnection;
+        use crate::rust_connection::Stream;
+        use crate::x11_utils::{ByteOrder, Serialize};
+        use std::os::unix::net::UnixStream;
+
+        let order = match ByteOrder::native() {
+            ByteOrder::LSBFirst => ByteOrder::MSBFirst,
+            ByteOrder::MSBFirst => ByteOrder::LSBFirst,
+        };
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let read = Stream::UnixStream(client.try_clone().unwrap());
+        let write = Stream::UnixStream(client);
+        let conn =
+            RustConnection::for_connected_stream_with_byte_order(read, write, test_setup(), order)
+                .unwrap();
+        let records = RefCell::new(Vec::new());
+        let conn = TracingConnection::new(conn, |record: &TraceRecord<'_>| {
+            records.borrow_mut().push(record.to_string())
+        });
+        assert_eq!(conn.byte_order(), order);
+
+        let cookie = conn.get_geometry(0x1234_5678).unwrap();
+        let mut request = [0; 8];
+        server.read_exact(&mut request).unwrap();
+        let mut reply = vec![1, 24];
+        reply.extend_from_slice(&1u16.serialize_with_order(order));
+        reply.extend_from_slice(&0u32.serialize_with_order(order));
+        reply.extend_from_slice(&0x1234u32.serialize_with_order(order));
+        reply.resize(32, 0);
+        server.write_all(&reply).unwrap();
+        assert_eq!(u32::from(cookie.reply().unwrap().root), 0x1234);
+
+        let records = records.into_inner();
+        assert!(
+            records[0].contains("drawable: Drawable(305419896)"),
+            "{}",
+            records[0]
+        );
+        assert!(records[1].contains("root: Window(4660)"), "{}", records[1]);
+    }
+}
@@ -6,7 +6,7 @@ use std::marker::PhantomData;
 use super::cookie::VoidCookie;
 use super::errors::{ConnectionError, ReplyError};
 use super::protocol::xproto::{Atom, ConnectionExt as XProtoConnectionExt, PropMode, Window};
-use super::x11_utils::TryParse;
+use super::x11_utils::{Serialize, TryParse};
 
 /// Iterator implementation used by `GetPropertyReply`.
 ///
@@ -87,9 +87,10 @@ pub trait ConnectionExt: XProtoConnectionExt {
         A: Into<Atom>,
         B: Into<Atom>,
     {
+        let order = self.byte_order();
         let mut data_u8 = Vec::with_capacity(data.len() * 2);
         for item in data {
-            data_u8.extend(&item.to_ne_bytes());
+            data_u8.extend(&item.serialize_with_order(order));
         }
         self.change_property(
             mode,
@@ -115,9 +116,10 @@ pub trait ConnectionExt: XProtoConnectionExt {
         A: Into<Atom>,
         B: Into<Atom>,
     {
+        let order = self.byte_order();
         let mut data_u8 = Vec::with_capacity(data.len() * 4);
         for item in data {
-            data_u8.extend(&item.to_ne_bytes());
+            data_u8.extend(&item.serialize_with_order(order));
         }
         self.change_property(
             mode,
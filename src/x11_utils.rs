@@ -1,6 +1,8 @@
 use std::convert::TryInto;
 
 use crate::errors::ParseError;
+use crate::protocol::xproto::ImageOrder;
+use crate::utils::RawFdContainer;
 
 /// Information about a X11 extension.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -28,13 +30,181 @@ pub trait ExtInfoProvider {
     fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)>;
 }
 
+/// The header of a request.
+///
+/// Every X11 request begins with a major opcode, a one byte field that is the minor opcode for
+/// extension requests and may carry data for core requests, and the length of the request.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RequestHeader {
+    /// The major opcode of the request.
+    pub major_opcode: u8,
+    /// The minor opcode of the request, or the first data byte of a core request.
+    pub minor_opcode: u8,
+    /// The length of the request's body in units of four bytes.
+    ///
+    /// This does not include the header itself.
+    pub remaining_length: u32,
+}
+
+/// Whether the BIG-REQUESTS extension is enabled on a connection.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BigRequests {
+    /// The extension is enabled, so a request length of zero is followed by an extended length.
+    Enabled,
+    /// The extension is not enabled.
+    NotEnabled,
+}
+
+/// Parse the header of a request.
+///
+/// On success, the header and the remaining data after the header are returned. The body of the
+/// request consists of the next `4 * header.remaining_length` bytes of the remaining data.
+///
+/// `order` is the byte order that the client announced in its `SetupRequest`.
+pub fn parse_request_header(
+    input: &[u8],
+    big_requests: BigRequests,
+    order: ByteOrder,
+) -> Result<(RequestHeader, &[u8]), ParseError> {
+    let (major_opcode, remaining) = u8::try_parse_with_order(input, order)?;
+    let (minor_opcode, remaining) = u8::try_parse_with_order(remaining, order)?;
+    let (length, remaining) = u16::try_parse_with_order(remaining, order)?;
+    let (length, remaining) = match (length, big_requests) {
+        (0, BigRequests::Enabled) => {
+            let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+            // The extended length field counts as part of the header
+            (
+                length.checked_sub(2).ok_or(ParseError::ParseError)?,
+                remaining,
+            )
+        }
+        (0, BigRequests::NotEnabled) => return Err(ParseError::ParseError),
+        (length, _) => (u32::from(length) - 1, remaining),
+    };
+    let header = RequestHeader {
+        major_opcode,
+        minor_opcode,
+        remaining_length: length,
+    };
+    Ok((header, remaining))
+}
+
+/// The byte order of multi-byte values on the wire.
+///
+/// A client announces the byte order that it wants to use in the `byte_order` field of its
+/// `SetupRequest`. The X11 server then uses this byte order for everything that it sends to the
+/// client and expects it for everything that the client sends. This does not have to be the
+/// byte order of the machine that the client runs on.
+///
+/// The byte order only applies to values with a known type. Opaque data like the value of a
+/// property or the contents of an image is sent unchanged. Images use the byte order given by the
+/// `image_byte_order` field of the server's `Setup` instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// The least significant byte comes first (little endian).
+    LSBFirst,
+    /// The most significant byte comes first (big endian).
+    MSBFirst,
+}
+
+impl ByteOrder {
+    /// Get the byte order of the machine that this code runs on.
+    pub fn native() -> Self {
+        if cfg!(target_endian = "little") {
+            ByteOrder::LSBFirst
+        } else {
+            ByteOrder::MSBFirst
+        }
+    }
+
+    /// Get the byte order that the given `byte_order` field of a `SetupRequest` announces.
+    ///
+    /// Returns `None` if the value is neither `b'l'` nor `b'B'`.
+    pub fn from_setup_byte(value: u8) -> Option<Self> {
+        match value {
+            b'l' => Some(ByteOrder::LSBFirst),
+            b'B' => Some(ByteOrder::MSBFirst),
+            _ => None,
+        }
+    }
+
+    /// Get the value for the `byte_order` field of a `SetupRequest` that announces this byte
+    /// order.
+    pub fn to_setup_byte(self) -> u8 {
+        match self {
+            ByteOrder::LSBFirst => b'l',
+            ByteOrder::MSBFirst => b'B',
+        }
+    }
+}
+
+impl From<ImageOrder> for ByteOrder {
+    /// Get the byte order that image data uses, e.g. from the `image_byte_order` field of `Setup`.
+    fn from(order: ImageOrder) -> Self {
+        match order {
+            ImageOrder::MSBFirst => ByteOrder::MSBFirst,
+            // Other values are invalid, just assume the more common byte order
+            _ => ByteOrder::LSBFirst,
+        }
+    }
+}
+
 /// A type implementing this trait can be parsed from some raw bytes.
 pub trait TryParse: Sized {
     /// Try to parse the given values into an instance of this type.
     ///
     /// If parsing is successful, an instance of the type and a slice for the remaining data should
     /// be returned. Otherwise, an error is returned.
-    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError>;
+    ///
+    /// The data is expected in the byte order of the machine that this code runs on.
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+
+    /// Try to parse the given values into an instance of this type, using the given byte order.
+    ///
+    /// This is like `try_parse()`, but the data is expected in the byte order `order`.
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError>;
+}
+
+/// A type implementing this trait can be parsed from some raw bytes and a list of fds.
+pub trait TryParseFd: Sized {
+    /// Try to parse the given values into an instance of this type.
+    ///
+    /// File descriptors are consumed by removing them from the beginning of the given `fds` `Vec`.
+    /// If a file descriptor is expected, but missing, a `ParseError` should be returned. If more
+    /// file descriptors are provided than are expected, this is not an error and the remaining
+    /// descriptors should be left in the `Vec`.
+    ///
+    /// If parsing is successful, an instance of the type and a slice for the remaining data should
+    /// be returned. Otherwise, an error is returned.
+    ///
+    /// The data is expected in the byte order of the machine that this code runs on.
+    fn try_parse_fd<'a>(
+        value: &'a [u8],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Result<(Self, &'a [u8]), ParseError> {
+        Self::try_parse_fd_with_order(value, fds, ByteOrder::native())
+    }
+
+    /// Try to parse the given values into an instance of this type, using the given byte order.
+    ///
+    /// This is like `try_parse_fd()`, but the data is expected in the byte order `order`.
+    fn try_parse_fd_with_order<'a>(
+        value: &'a [u8],
+        fds: &mut Vec<RawFdContainer>,
+        order: ByteOrder,
+    ) -> Result<(Self, &'a [u8]), ParseError>;
+}
+
+/// A request that is represented as a value.
+///
+/// This is implemented by the generated `*Request` structs, e.g.
+/// `xproto::CreateWindowRequest`. Such a value can be sent with
+/// `RequestConnection::send()`.
+pub trait Request {
+    /// The type of the reply to this request, or `()` if the request has no reply.
+    type Reply;
 }
 
 /// A type implementing this trait can be serialized into X11 raw bytes.
@@ -48,13 +218,30 @@ pub trait Serialize {
     type Bytes;
 
     /// Serialize this value into X11 raw bytes.
-    fn serialize(&self) -> Self::Bytes;
+    ///
+    /// The result uses the byte order of the machine that this code runs on.
+    fn serialize(&self) -> Self::Bytes {
+        self.serialize_with_order(ByteOrder::native())
+    }
+
+    /// Serialize this value into X11 raw bytes in the given byte order.
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes;
 
     /// Serialize this value into X11 raw bytes, appending the result into `bytes`.
     ///
     /// When calling this method, the given vector must satisfy `assert_eq!(bytes.len() % 4, 0);`.
     /// In words: Its length must be a multiple of four.
-    fn serialize_into(&self, bytes: &mut Vec<u8>);
+    ///
+    /// The result uses the byte order of the machine that this code runs on.
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+
+    /// Serialize this value into X11 raw bytes in the given byte order, appending the result into
+    /// `bytes`.
+    ///
+    /// The same requirements as for `serialize_into()` apply.
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder);
 }
 
 // Now implement TryParse and Serialize for some primitive data types that we need.
@@ -62,14 +249,21 @@ pub trait Serialize {
 macro_rules! implement_try_parse {
     ($t:ty) => {
         impl TryParse for $t {
-            fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+            fn try_parse_with_order(
+                value: &[u8],
+                order: ByteOrder,
+            ) -> Result<(Self, &[u8]), ParseError> {
                 let len = std::mem::size_of::<$t>();
                 let bytes = value
                     .get(..len)
                     .ok_or(ParseError::ParseError)?
                     .try_into() // TryInto<[u8; len]>
                     .unwrap();
-                Ok((<$t>::from_ne_bytes(bytes), &value[len..]))
+                let result = match order {
+                    ByteOrder::LSBFirst => <$t>::from_le_bytes(bytes),
+                    ByteOrder::MSBFirst => <$t>::from_be_bytes(bytes),
+                };
+                Ok((result, &value[len..]))
             }
         }
     };
@@ -79,11 +273,14 @@ macro_rules! implement_serialize {
     ($t:ty: $size:expr) => {
         impl Serialize for $t {
             type Bytes = [u8; $size];
-            fn serialize(&self) -> Self::Bytes {
-                self.to_ne_bytes()
+            fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+                match order {
+                    ByteOrder::LSBFirst => self.to_le_bytes(),
+                    ByteOrder::MSBFirst => self.to_be_bytes(),
+                }
             }
-            fn serialize_into(&self, bytes: &mut Vec<u8>) {
-                bytes.extend_from_slice(&self.to_ne_bytes());
+            fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+                bytes.extend_from_slice(&self.serialize_with_order(order));
             }
         }
     };
@@ -92,18 +289,21 @@ macro_rules! implement_serialize {
 macro_rules! forward_float {
     ($from:ty: $to:ty) => {
         impl TryParse for $from {
-            fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-                let (data, remaining) = <$to>::try_parse(value)?;
+            fn try_parse_with_order(
+                value: &[u8],
+                order: ByteOrder,
+            ) -> Result<(Self, &[u8]), ParseError> {
+                let (data, remaining) = <$to>::try_parse_with_order(value, order)?;
                 Ok((<$from>::from_bits(data), remaining))
             }
         }
         impl Serialize for $from {
             type Bytes = <$to as Serialize>::Bytes;
-            fn serialize(&self) -> Self::Bytes {
-                self.to_bits().serialize()
+            fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+                self.to_bits().serialize_with_order(order)
             }
-            fn serialize_into(&self, bytes: &mut Vec<u8>) {
-                self.to_bits().serialize_into(bytes);
+            fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+                self.to_bits().serialize_into_with_order(bytes, order);
             }
         }
     };
@@ -131,18 +331,18 @@ forward_float!(f32: u32);
 forward_float!(f64: u64);
 
 impl TryParse for bool {
-    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (data, remaining) = u8::try_parse(value)?;
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (data, remaining) = u8::try_parse_with_order(value, order)?;
         Ok((data != 0, remaining))
     }
 }
 
 impl Serialize for bool {
     type Bytes = [u8; 1];
-    fn serialize(&self) -> Self::Bytes {
+    fn serialize_with_order(&self, _order: ByteOrder) -> Self::Bytes {
         [u8::from(*self)]
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _order: ByteOrder) {
         bytes.push(u8::from(*self));
     }
 }
@@ -155,8 +355,11 @@ macro_rules! tuple_try_parse {
         where $($name: TryParse,)*
         {
             #[allow(non_snake_case)]
-            fn try_parse(remaining: &[u8]) -> Result<(($($name,)*), &[u8]), ParseError> {
-                $(let ($name, remaining) = $name::try_parse(remaining)?;)*
+            fn try_parse_with_order(
+                remaining: &[u8],
+                order: ByteOrder,
+            ) -> Result<(($($name,)*), &[u8]), ParseError> {
+                $(let ($name, remaining) = $name::try_parse_with_order(remaining, order)?;)*
                 Ok((($($name,)*), remaining))
             }
         }
@@ -169,13 +372,13 @@ macro_rules! tuple_serialize {
         where $($name: Serialize,)*
         {
             type Bytes = Vec<u8>;
-            fn serialize(&self) -> Self::Bytes {
+            fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
                 let mut result = Vec::new();
-                self.serialize_into(&mut result);
+                self.serialize_into_with_order(&mut result, order);
                 result
             }
-            fn serialize_into(&self, bytes: &mut Vec<u8>) {
-                $(self.$idx.serialize_into(bytes);)*
+            fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+                $(self.$idx.serialize_into_with_order(bytes, order);)*
             }
         }
     }
@@ -188,26 +391,31 @@ macro_rules! tuple_impls {
     }
 }
 
+impl TryParse for () {
+    fn try_parse_with_order(value: &[u8], _order: ByteOrder) -> Result<((), &[u8]), ParseError> {
+        Ok(((), value))
+    }
+}
+
 // We can optimise serialisation of empty tuples or one-element-tuples with different Bytes type
 impl Serialize for () {
     type Bytes = [u8; 0];
-    fn serialize(&self) -> Self::Bytes {
+    fn serialize_with_order(&self, _order: ByteOrder) -> Self::Bytes {
         []
     }
-    fn serialize_into(&self, _bytes: &mut Vec<u8>) {}
+    fn serialize_into_with_order(&self, _bytes: &mut Vec<u8>, _order: ByteOrder) {}
 }
 
 impl<T: Serialize> Serialize for (T,) {
     type Bytes = T::Bytes;
-    fn serialize(&self) -> Self::Bytes {
-        self.0.serialize()
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+        self.0.serialize_with_order(order)
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
-        self.0.serialize_into(bytes)
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order)
     }
 }
 
-tuple_try_parse!();
 tuple_try_parse!(A);
 tuple_impls!(A:0 B:1);
 tuple_impls!(A:0 B:1 C:2);
@@ -229,13 +437,27 @@ tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11 M:12 N:13 O:14);
 /// This function parses a list of objects where the length of the list was specified externally.
 /// The wire format for `list_length` instances of `T` will be read from the given data.
 pub fn parse_list<T>(data: &[u8], list_length: usize) -> Result<(Vec<T>, &[u8]), ParseError>
+where
+    T: TryParse,
+{
+    parse_list_with_order(data, list_length, ByteOrder::native())
+}
+
+/// Parse a list of objects from the given data, using the given byte order.
+///
+/// This is like `parse_list()`, but the data is expected in the byte order `order`.
+pub fn parse_list_with_order<T>(
+    data: &[u8],
+    list_length: usize,
+    order: ByteOrder,
+) -> Result<(Vec<T>, &[u8]), ParseError>
 where
     T: TryParse,
 {
     let mut remaining = data;
     let mut result = Vec::with_capacity(list_length);
     for _ in 0..list_length {
-        let (entry, new_remaining) = T::try_parse(remaining)?;
+        let (entry, new_remaining) = T::try_parse_with_order(remaining, order)?;
         result.push(entry);
         remaining = new_remaining;
     }
@@ -253,43 +475,145 @@ pub fn parse_u8_list(data: &[u8], list_length: usize) -> Result<(&[u8], &[u8]),
 
 impl<T: Serialize> Serialize for [T] {
     type Bytes = Vec<u8>;
-    fn serialize(&self) -> Self::Bytes {
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
         let mut result = Vec::new();
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         for item in self {
-            item.serialize_into(bytes);
+            item.serialize_into_with_order(bytes, order);
         }
     }
 }
 
-// This macro is used by the generated code to implement `std::ops::BitOr` and
-// `std::ops::BitOrAssign`.
-macro_rules! bitmask_binop {
-    ($t:ty, $u:ty) => {
+// This macro is used by the generated code to implement the methods and traits of the set types
+// for bit masks. `$t` is a newtype around `$u` and `$flag` lists the constants with a single bit
+// set. These are used for `iter()` and the `Debug` implementation.
+macro_rules! bitmask_set {
+    ($t:ident, $u:ty, [$($flag:ident),*]) => {
+        impl $t {
+            /// Get the set that contains no flags.
+            pub fn empty() -> Self {
+                Self(0)
+            }
+            /// Get the raw value of this set.
+            pub fn bits(self) -> $u {
+                self.0
+            }
+            /// Check whether no flags are set.
+            pub fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+            /// Check whether all flags of `other` are also set in `self`.
+            pub fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+            /// Check whether `self` and `other` have at least one flag in common.
+            pub fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+            /// Set all flags of `other` in `self`.
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+            /// Clear all flags of `other` in `self`.
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+            /// Iterate over the known flags that are set in `self`.
+            pub fn iter(self) -> impl Iterator<Item = Self> {
+                const FLAGS: &[$t] = &[$($t::$flag),*];
+                FLAGS.iter().cloned().filter(move |&flag| self.contains(flag))
+            }
+        }
+        impl std::fmt::Debug for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                const FLAGS: &[($t, &str)] = &[$(($t::$flag, stringify!($flag))),*];
+                write!(f, "{}(", stringify!($t))?;
+                let mut remaining = self.0;
+                let mut separator = "";
+                for &(flag, name) in FLAGS {
+                    if remaining & flag.0 != 0 {
+                        write!(f, "{}{}", separator, name)?;
+                        remaining &= !flag.0;
+                        separator = " | ";
+                    }
+                }
+                if remaining != 0 || separator.is_empty() {
+                    write!(f, "{}{:#x}", separator, remaining)?;
+                }
+                write!(f, ")")
+            }
+        }
+        impl From<$u> for $t {
+            fn from(value: $u) -> Self {
+                Self(value)
+            }
+        }
+        impl From<$t> for $u {
+            fn from(value: $t) -> Self {
+                value.0
+            }
+        }
         impl std::ops::BitOr for $t {
-            type Output = $u;
-            fn bitor(self, other: Self) -> Self::Output {
-                Self::Output::from(self) | Self::Output::from(other)
+            type Output = Self;
+            fn bitor(self, other: Self) -> Self {
+                Self(self.0 | other.0)
             }
         }
-        impl std::ops::BitOr<$u> for $t {
-            type Output = $u;
-            fn bitor(self, other: $u) -> Self::Output {
-                Self::Output::from(self) | other
+        impl std::ops::BitOrAssign for $t {
+            fn bitor_assign(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+        }
+        impl std::ops::BitAnd for $t {
+            type Output = Self;
+            fn bitand(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+        }
+        impl std::ops::BitAndAssign for $t {
+            fn bitand_assign(&mut self, other: Self) {
+                self.0 &= other.0;
             }
         }
         impl std::ops::BitOr<$t> for $u {
             type Output = $u;
-            fn bitor(self, other: $t) -> Self::Output {
-                self | Self::Output::from(other)
+            fn bitor(self, other: $t) -> $u {
+                self | other.0
             }
         }
         impl std::ops::BitOrAssign<$t> for $u {
             fn bitor_assign(&mut self, other: $t) {
-                *self |= Self::from(other)
+                *self |= other.0;
+            }
+        }
+    };
+}
+
+// This macro is used by the generated code to implement the traits of the types for enums
+// without bits. `$t` is a newtype around `$u` and `$item` lists its constants. Values without a
+// constant are kept as they are and shown as `$t(value)` by the `Debug` implementation.
+macro_rules! enum_newtype {
+    ($t:ident, $u:ty, [$($item:ident),*]) => {
+        impl std::fmt::Debug for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                const ITEMS: &[($t, &str)] = &[$(($t::$item, stringify!($item))),*];
+                match ITEMS.iter().find(|&&(item, _)| item == *self) {
+                    Some(&(_, name)) => f.write_str(name),
+                    None => write!(f, "{}({})", stringify!($t), self.0),
+                }
+            }
+        }
+        impl From<$u> for $t {
+            fn from(value: $u) -> Self {
+                Self(value)
+            }
+        }
+        impl From<$t> for $u {
+            fn from(value: $t) -> Self {
+                value.0
             }
         }
     };
@@ -2,29 +2,32 @@
 //!
 //! This module is only available when the `allow-unsafe-code` feature is enabled.
 
+use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::io::{Error as IOError, ErrorKind, IoSlice};
 #[cfg(unix)]
 use std::os::unix::io::{AsRawFd, RawFd};
 use std::ptr::{null, null_mut};
 use std::sync::{
-    atomic::{AtomicU64, Ordering},
-    Mutex,
+    atomic::{AtomicPtr, AtomicU64, Ordering},
+    Arc, Mutex, Once,
 };
+use std::time::{Duration, Instant};
 
 use libc::c_void;
 
 use crate::connection::{
     compute_length_field, Connection, DiscardMode, ReplyOrError, RequestConnection, RequestKind,
-    SequenceNumber,
+    SequenceNumber, SpecialEventQueue,
 };
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 pub use crate::errors::{ConnectError, ConnectionError, ParseError};
 use crate::extension_manager::ExtensionManager;
 use crate::protocol::xproto::Setup;
+use crate::stats::ConnectionStats;
 use crate::utils::{CSlice, RawFdContainer};
-use crate::x11_utils::ExtensionInformation;
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
 
 mod pending_errors;
 mod raw_ffi;
@@ -42,18 +45,47 @@ pub type BufWithFds = crate::connection::BufWithFds<Buffer>;
 /// interface to this C library.
 #[derive(Debug)]
 pub struct XCBConnection {
+    // The special event queues must be unregistered before the connection is closed. Since fields
+    // are dropped in declaration order, this has to come before `conn`.
+    special_events: Mutex<HashMap<SpecialEventQueue, Arc<raw_ffi::SpecialEventWrapper>>>,
     conn: raw_ffi::XCBConnectionWrapper,
     setup: Setup,
     ext_mgr: Mutex<ExtensionManager>,
     errors: pending_errors::PendingErrors,
     maximum_sequence_received: AtomicU64,
+    last_sequence_sent: AtomicU64,
+    stats: Mutex<ConnectionStats>,
 }
 
 impl XCBConnection {
     unsafe fn connection_error_from_connection(
         c: *mut raw_ffi::xcb_connection_t,
     ) -> ConnectionError {
-        Self::connection_error_from_c_error(raw_ffi::xcb_connection_has_error(c))
+        let error = raw_ffi::xcb_connection_has_error(c);
+        if error == raw_ffi::connection_errors::ERROR && Self::peer_closed(c) {
+            ConnectionError::ConnectionClosed
+        } else {
+            Self::connection_error_from_c_error(error)
+        }
+    }
+
+    /// Check if the X11 server closed its end of the connection.
+    ///
+    /// libxcb only reports a generic error when the connection breaks. To distinguish a clean
+    /// shutdown from other I/O errors, we peek at the socket: Receiving end of file means that the
+    /// server closed the connection.
+    #[cfg(unix)]
+    unsafe fn peer_closed(c: *mut raw_ffi::xcb_connection_t) -> bool {
+        let fd = raw_ffi::xcb_get_file_descriptor(c);
+        let mut byte = 0u8;
+        let ptr: *mut u8 = &mut byte;
+        let flags = libc::MSG_PEEK | libc::MSG_DONTWAIT;
+        libc::recv(fd, ptr as *mut _, 1, flags) == 0
+    }
+
+    #[cfg(not(unix))]
+    unsafe fn peer_closed(_c: *mut raw_ffi::xcb_connection_t) -> bool {
+        false
     }
 
     fn connection_error_from_c_error(error: i32) -> ConnectionError {
@@ -107,12 +139,15 @@ impl XCBConnection {
             } else {
                 let setup = raw_ffi::xcb_get_setup(connection.as_ptr());
                 let conn = XCBConnection {
+                    special_events: Default::default(),
                     // `xcb_connect` will never return null.
                     conn: connection,
                     setup: Self::parse_setup(setup)?,
                     ext_mgr: Default::default(),
                     errors: Default::default(),
                     maximum_sequence_received: AtomicU64::new(0),
+                    last_sequence_sent: AtomicU64::new(0),
+                    stats: Default::default(),
                 };
                 Ok((conn, screen as usize))
             }
@@ -135,11 +170,14 @@ impl XCBConnection {
         let ptr = ptr as *mut raw_ffi::xcb_connection_t;
         let setup = raw_ffi::xcb_get_setup(ptr);
         Ok(XCBConnection {
+            special_events: Default::default(),
             conn: raw_ffi::XCBConnectionWrapper::new(ptr, should_drop),
             setup: Self::parse_setup(setup)?,
             ext_mgr: Default::default(),
             errors: Default::default(),
             maximum_sequence_received: AtomicU64::new(0),
+            last_sequence_sent: AtomicU64::new(0),
+            stats: Default::default(),
         })
     }
 
@@ -237,6 +275,8 @@ impl XCBConnection {
         if seqno == 0 {
             unsafe { Err(Self::connection_error_from_connection(self.conn.as_ptr())) }
         } else {
+            atomic_u64_max(&self.last_sequence_sent, seqno);
+            self.stats.lock().unwrap().record_request(new_bufs);
             Ok(seqno)
         }
     }
@@ -248,11 +288,34 @@ impl XCBConnection {
             if error == 0 {
                 None
             } else {
-                Some(Self::connection_error_from_c_error(error))
+                Some(Self::connection_error_from_connection(self.conn.as_ptr()))
             }
         }
     }
 
+    /// Get statistics about the traffic of this connection.
+    ///
+    /// Some of the statistics are not available, since libxcb does not provide them. See
+    /// `ConnectionStats` for details.
+    pub fn stats(&self) -> ConnectionStats {
+        let mut stats = self.stats.lock().unwrap().clone();
+        let sent = self.last_sequence_sent.load(Ordering::Relaxed);
+        let received = self.maximum_sequence_received.load(Ordering::Relaxed);
+        stats.in_flight_requests = sent
+            .saturating_sub(received)
+            .try_into()
+            .unwrap_or(usize::max_value());
+        stats
+    }
+
+    /// Call the given function, which blocks, and record the time that it took.
+    fn blocking<T>(&self, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.stats.lock().unwrap().record_wait(start.elapsed());
+        result
+    }
+
     /// Get access to the raw libxcb `xcb_connection_t`.
     ///
     /// The returned pointer is valid for as long as the original object was not dropped. No
@@ -285,6 +348,59 @@ impl XCBConnection {
         }
     }
 
+    /// Repeatedly call `poll` until it returns something or the timeout expires.
+    ///
+    /// Between calls to `poll`, this waits for the connection's file descriptor to become
+    /// readable.
+    fn wait_timeout<T>(
+        &self,
+        timeout: Duration,
+        mut poll: impl FnMut() -> Result<Option<T>, ConnectionError>,
+    ) -> Result<Option<T>, ConnectionError> {
+        let deadline = Instant::now().checked_add(timeout);
+        loop {
+            if let Some(result) = poll()? {
+                return Ok(Some(result));
+            }
+            let timeout = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Ok(None);
+                    }
+                    deadline - now
+                }
+                // A timeout that cannot be represented is as good as no timeout at all
+                None => Duration::from_millis(i32::max_value() as u64),
+            };
+            self.wait_readable(timeout)?;
+        }
+    }
+
+    /// Wait until the connection's file descriptor becomes readable or the timeout expires.
+    #[cfg(unix)]
+    fn wait_readable(&self, timeout: Duration) -> Result<(), ConnectionError> {
+        use nix::poll::{poll, PollFd, PollFlags};
+
+        // poll() takes the timeout in milliseconds; round up so that we do not wake up early
+        let millis = timeout
+            .checked_add(Duration::from_nanos(999_999))
+            .unwrap_or(timeout)
+            .as_millis();
+        let millis = std::cmp::min(millis, i32::max_value() as u128) as i32;
+        let mut fds = [PollFd::new(self.as_raw_fd(), PollFlags::POLLIN)];
+        match self.blocking(|| poll(&mut fds, millis)) {
+            Ok(_) | Err(nix::Error::Sys(nix::errno::Errno::EINTR)) => Ok(()),
+            // Nothing touched errno since poll() failed
+            Err(_) => Err(IOError::last_os_error().into()),
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn wait_readable(&self, _timeout: Duration) -> Result<(), ConnectionError> {
+        Err(IOError::new(ErrorKind::Other, "Waiting with a timeout is unsupported").into())
+    }
+
     unsafe fn wrap_reply(&self, reply: *const u8, sequence: SequenceNumber) -> CSlice {
         // Update our "max sequence number received" field
         atomic_u64_max(&self.maximum_sequence_received, sequence);
@@ -327,6 +443,20 @@ impl XCBConnection {
         Ok((CSlice::new(header.into_ptr(), length), seqno))
     }
 
+    /// Get the libxcb special event queue for the given queue.
+    ///
+    /// Fails if the queue is not registered.
+    fn special_event(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Arc<raw_ffi::SpecialEventWrapper>, ConnectionError> {
+        let special_events = self.special_events.lock().unwrap();
+        let se = special_events
+            .get(&queue)
+            .ok_or(ConnectionError::UnregisteredSpecialEventQueue)?;
+        Ok(Arc::clone(se))
+    }
+
     /// Reconstruct a full sequence number based on a partial value.
     ///
     /// The assumption for the algorithm here is that the given sequence number was received
@@ -349,7 +479,7 @@ impl RequestConnection for XCBConnection {
         fds: Vec<RawFdContainer>,
     ) -> Result<Cookie<'_, Self, R>, ConnectionError>
     where
-        R: for<'a> TryFrom<&'a [u8], Error = ParseError>,
+        R: TryParse,
     {
         Ok(Cookie::new(
             self,
@@ -363,7 +493,7 @@ impl RequestConnection for XCBConnection {
         fds: Vec<RawFdContainer>,
     ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
     where
-        R: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error = ParseError>,
+        R: TryParseFd,
     {
         Ok(CookieWithFds::new(
             self,
@@ -419,7 +549,9 @@ impl RequestConnection for XCBConnection {
     ) -> Result<ReplyOrError<CSlice>, ConnectionError> {
         unsafe {
             let mut error = null_mut();
-            let reply = raw_ffi::xcb_wait_for_reply64(self.conn.as_ptr(), sequence, &mut error);
+            let reply = self.blocking(|| {
+                raw_ffi::xcb_wait_for_reply64(self.conn.as_ptr(), sequence, &mut error)
+            });
             match (reply.is_null(), error.is_null()) {
                 (true, true) => Err(Self::connection_error_from_connection(self.conn.as_ptr())),
                 (false, true) => Ok(ReplyOrError::Reply(self.wrap_reply(reply as _, sequence))),
@@ -445,23 +577,10 @@ impl RequestConnection for XCBConnection {
         &self,
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
-        let buffer = match self.wait_for_reply_or_raw_error(sequence)? {
-            ReplyOrError::Reply(reply) => reply,
-            ReplyOrError::Error(error) => return Ok(ReplyOrError::Error(error)),
-        };
-
-        // Get a pointer to the array of integers where libxcb saved the FD numbers.
-        // libxcb saves the list of FDs after the data of the reply. Since the reply's
-        // length is encoded in "number of 4 bytes block", the following pointer is aligned
-        // correctly (if malloc() returned an aligned chunk, which it does).
-        #[allow(clippy::cast_ptr_alignment)]
-        let fd_ptr = (unsafe { buffer.as_ptr().add(buffer.len()) }) as *const RawFd;
-
-        // The number of FDs is in the second byte (= buffer[1]) in all replies.
-        let fd_slice = unsafe { std::slice::from_raw_parts(fd_ptr, usize::from(buffer[1])) };
-        let fd_vec = fd_slice.iter().map(|&fd| RawFdContainer::new(fd)).collect();
-
-        Ok(ReplyOrError::Reply((buffer, fd_vec)))
+        match self.wait_for_reply_or_raw_error(sequence)? {
+            ReplyOrError::Reply(reply) => Ok(ReplyOrError::Reply(unsafe { reply_with_fds(reply) })),
+            ReplyOrError::Error(error) => Ok(ReplyOrError::Error(error)),
+        }
     }
 
     #[cfg(not(unix))]
@@ -479,7 +598,8 @@ impl RequestConnection for XCBConnection {
         let cookie = raw_ffi::xcb_void_cookie_t {
             sequence: sequence as _,
         };
-        let error = unsafe { raw_ffi::xcb_request_check(self.conn.as_ptr(), cookie) };
+        let error =
+            self.blocking(|| unsafe { raw_ffi::xcb_request_check(self.conn.as_ptr(), cookie) });
         if error.is_null() {
             Ok(None)
         } else {
@@ -487,6 +607,52 @@ impl RequestConnection for XCBConnection {
         }
     }
 
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<ReplyOrError<CSlice>>, ConnectionError> {
+        // Ensure the request is sent
+        self.flush()?;
+        self.wait_timeout(timeout, || match self.poll_for_reply(sequence) {
+            Err(()) => Ok(None),
+            Ok(Some(buffer)) => {
+                if buffer[0] == 0 {
+                    Ok(Some(ReplyOrError::Error(buffer)))
+                } else {
+                    Ok(Some(ReplyOrError::Reply(buffer)))
+                }
+            }
+            Ok(None) => Err(self.has_error().unwrap_or(ConnectionError::UnknownError)),
+        })
+    }
+
+    #[cfg(unix)]
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<ReplyOrError<BufWithFds, Buffer>>, ConnectionError> {
+        Ok(
+            match self.wait_for_reply_or_raw_error_timeout(sequence, timeout)? {
+                None => None,
+                Some(ReplyOrError::Reply(reply)) => {
+                    Some(ReplyOrError::Reply(unsafe { reply_with_fds(reply) }))
+                }
+                Some(ReplyOrError::Error(error)) => Some(ReplyOrError::Error(error)),
+            },
+        )
+    }
+
+    #[cfg(not(unix))]
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        _sequence: SequenceNumber,
+        _timeout: Duration,
+    ) -> Result<Option<ReplyOrError<BufWithFds, Buffer>>, ConnectionError> {
+        unimplemented!("FD passing is currently only implemented on Unix-like systems")
+    }
+
     fn maximum_request_bytes(&self) -> usize {
         4 * unsafe { raw_ffi::xcb_get_maximum_request_length(self.conn.as_ptr()) as usize }
     }
@@ -518,7 +684,7 @@ impl Connection for XCBConnection {
             return Ok((error.1, error.0));
         }
         unsafe {
-            let event = raw_ffi::xcb_wait_for_event(self.conn.as_ptr());
+            let event = self.blocking(|| raw_ffi::xcb_wait_for_event(self.conn.as_ptr()));
             if event.is_null() {
                 return Err(Self::connection_error_from_connection(self.conn.as_ptr()));
             }
@@ -526,6 +692,13 @@ impl Connection for XCBConnection {
         }
     }
 
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        self.wait_timeout(timeout, || self.poll_for_raw_event_with_sequence())
+    }
+
     fn poll_for_raw_event_with_sequence(
         &self,
     ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
@@ -539,7 +712,77 @@ impl Connection for XCBConnection {
                 if err == 0 {
                     return Ok(None);
                 } else {
-                    return Err(Self::connection_error_from_c_error(err));
+                    return Err(Self::connection_error_from_connection(self.conn.as_ptr()));
+                }
+            }
+            Ok(Some(self.wrap_event(event as _)?))
+        }
+    }
+
+    fn register_for_special_xge(
+        &self,
+        extension_name: &'static str,
+        eid: u32,
+    ) -> Result<SpecialEventQueue, ConnectionError> {
+        let ext_info = self
+            .extension_information(extension_name)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let queue = SpecialEventQueue::new(ext_info.major_opcode, eid);
+        let mut special_events = self.special_events.lock().unwrap();
+        if special_events.contains_key(&queue) {
+            return Ok(queue);
+        }
+        let ext = extension_struct(extension_name)?;
+        let se = unsafe {
+            let conn = self.conn.as_ptr();
+            let se = raw_ffi::xcb_register_for_special_xge(conn, ext, eid, null_mut());
+            raw_ffi::SpecialEventWrapper::new(conn, se)
+        };
+        match se {
+            Some(se) => {
+                let _ = special_events.insert(queue, Arc::new(se));
+                Ok(queue)
+            }
+            None => match self.has_error() {
+                Some(error) => Err(error),
+                None => Err(ConnectionError::UnknownError),
+            },
+        }
+    }
+
+    fn unregister_for_special_xge(&self, queue: SpecialEventQueue) {
+        // libxcb's queue is freed once no other thread is waiting on it anymore
+        let _ = self.special_events.lock().unwrap().remove(&queue);
+    }
+
+    fn wait_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        let se = self.special_event(queue)?;
+        unsafe {
+            let event = self
+                .blocking(|| raw_ffi::xcb_wait_for_special_event(self.conn.as_ptr(), se.as_ptr()));
+            if event.is_null() {
+                return Err(Self::connection_error_from_connection(self.conn.as_ptr()));
+            }
+            Ok(self.wrap_event(event as _)?)
+        }
+    }
+
+    fn poll_for_raw_special_event_with_sequence(
+        &self,
+        queue: SpecialEventQueue,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        let se = self.special_event(queue)?;
+        unsafe {
+            let event = raw_ffi::xcb_poll_for_special_event(self.conn.as_ptr(), se.as_ptr());
+            if event.is_null() {
+                let err = raw_ffi::xcb_connection_has_error(self.conn.as_ptr());
+                if err == 0 {
+                    return Ok(None);
+                } else {
+                    return Err(Self::connection_error_from_connection(self.conn.as_ptr()));
                 }
             }
             Ok(Some(self.wrap_event(event as _)?))
@@ -582,6 +825,56 @@ impl AsRawFd for XCBConnection {
     }
 }
 
+/// Get the FDs that libxcb received together with the given reply.
+///
+/// The reply must come from a request that has FDs in its reply.
+#[cfg(unix)]
+unsafe fn reply_with_fds(buffer: CSlice) -> BufWithFds {
+    // Get a pointer to the array of integers where libxcb saved the FD numbers.
+    // libxcb saves the list of FDs after the data of the reply. Since the reply's
+    // length is encoded in "number of 4 bytes block", the following pointer is aligned
+    // correctly (if malloc() returned an aligned chunk, which it does).
+    #[allow(clippy::cast_ptr_alignment)]
+    let fd_ptr = buffer.as_ptr().add(buffer.len()) as *const RawFd;
+
+    // The number of FDs is in the second byte (= buffer[1]) in all replies.
+    let fd_slice = std::slice::from_raw_parts(fd_ptr, usize::from(buffer[1]));
+    let fd_vec = fd_slice.iter().map(|&fd| RawFdContainer::new(fd)).collect();
+
+    (buffer, fd_vec)
+}
+
+/// Get the `xcb_extension_t` that identifies the given extension to libxcb.
+///
+/// libxcb assigns a `global_id` to such a struct when it first sees it and caches information
+/// about the extension under this id. Hence, there must be exactly one struct per extension and it
+/// has to live for the rest of the program, just like the static structs in libxcb's own code.
+fn extension_struct(
+    extension_name: &'static str,
+) -> Result<*mut raw_ffi::xcb_extension_t, ConnectionError> {
+    type ExtensionMap = Mutex<HashMap<&'static str, *mut raw_ffi::xcb_extension_t>>;
+    static INIT: Once = Once::new();
+    static EXTENSIONS: AtomicPtr<ExtensionMap> = AtomicPtr::new(null_mut());
+
+    INIT.call_once(|| {
+        let map = Box::into_raw(Box::new(ExtensionMap::default()));
+        EXTENSIONS.store(map, Ordering::Release);
+    });
+    // Safety: The map was initialised above and is never freed.
+    let extensions = unsafe { &*EXTENSIONS.load(Ordering::Acquire) };
+    let mut extensions = extensions.lock().unwrap();
+    if let Some(ext) = extensions.get(extension_name) {
+        return Ok(*ext);
+    }
+    let name = CString::new(extension_name).or(Err(ConnectionError::UnknownError))?;
+    let ext = Box::into_raw(Box::new(raw_ffi::xcb_extension_t {
+        name: name.into_raw(),
+        global_id: 0,
+    }));
+    let _ = extensions.insert(extension_name, ext);
+    Ok(ext)
+}
+
 /// Atomically sets `value` to the maximum of `value` and `new`.
 fn atomic_u64_max(value: &AtomicU64, new: u64) {
     // If only AtomicU64::fetch_max were stable...
@@ -675,6 +968,18 @@ mod test {
         assert_eq!(value.load(Ordering::Relaxed), 9);
     }
 
+    #[test]
+    fn extension_struct_per_name() {
+        use super::extension_struct;
+        let present = extension_struct("Present").unwrap();
+        assert_eq!(present, extension_struct("Present").unwrap());
+        assert_ne!(present, extension_struct("XInputExtension").unwrap());
+        match extension_struct("Nul\0byte") {
+            Err(ConnectionError::UnknownError) => {}
+            value => panic!("Unexpected value {:?}", value),
+        }
+    }
+
     #[test]
     fn reconstruct_full_sequence() {
         use super::reconstruct_full_sequence_impl;
@@ -107,6 +107,49 @@ pub(crate) struct xcb_setup_t {
     _unused: [u8; 0],
 }
 
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub(crate) struct xcb_special_event_t {
+    _unused: [u8; 0],
+}
+
+/// A special event queue that was registered with `xcb_register_for_special_xge()`.
+///
+/// The queue is unregistered on drop. Thus, this must be dropped before the connection.
+#[derive(Debug)]
+pub(crate) struct SpecialEventWrapper {
+    conn: NonNull<xcb_connection_t>,
+    ptr: NonNull<xcb_special_event_t>,
+}
+
+// The special event queue is only accessed through libxcb's functions, which lock the connection.
+unsafe impl Send for SpecialEventWrapper {}
+unsafe impl Sync for SpecialEventWrapper {}
+
+impl Drop for SpecialEventWrapper {
+    fn drop(&mut self) {
+        unsafe {
+            xcb_unregister_for_special_event(self.conn.as_ptr(), self.ptr.as_ptr());
+        }
+    }
+}
+
+impl SpecialEventWrapper {
+    pub(crate) unsafe fn new(
+        conn: *mut xcb_connection_t,
+        ptr: *mut xcb_special_event_t,
+    ) -> Option<Self> {
+        Some(Self {
+            conn: NonNull::new_unchecked(conn),
+            ptr: NonNull::new(ptr)?,
+        })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut xcb_special_event_t {
+        self.ptr.as_ptr()
+    }
+}
+
 pub(crate) mod connection_errors {
     pub(crate) const ERROR: i32 = 1;
     pub(crate) const EXT_NOTSUPPORTED: i32 = 2;
@@ -152,6 +195,24 @@ extern "C" {
     pub(crate) fn xcb_generate_id(c: *mut xcb_connection_t) -> u32;
 
     // From xcbext.h
+    pub(crate) fn xcb_register_for_special_xge(
+        c: *mut xcb_connection_t,
+        ext: *mut xcb_extension_t,
+        eid: u32,
+        stamp: *mut u32,
+    ) -> *mut xcb_special_event_t;
+    pub(crate) fn xcb_unregister_for_special_event(
+        c: *mut xcb_connection_t,
+        se: *mut xcb_special_event_t,
+    );
+    pub(crate) fn xcb_wait_for_special_event(
+        c: *mut xcb_connection_t,
+        se: *mut xcb_special_event_t,
+    ) -> *mut xcb_generic_event_t;
+    pub(crate) fn xcb_poll_for_special_event(
+        c: *mut xcb_connection_t,
+        se: *mut xcb_special_event_t,
+    ) -> *mut xcb_generic_event_t;
     pub(crate) fn xcb_send_request64(
         c: *mut xcb_connection_t,
         flags: c_int,
@@ -187,8 +248,8 @@ mod mock {
     use libc::{c_char, c_int, c_uint, c_void};
 
     use super::{
-        iovec, xcb_connection_t, xcb_generic_error_t, xcb_generic_event_t, xcb_protocol_request_t,
-        xcb_setup_t, xcb_void_cookie_t,
+        iovec, xcb_connection_t, xcb_extension_t, xcb_generic_error_t, xcb_generic_event_t,
+        xcb_protocol_request_t, xcb_setup_t, xcb_special_event_t, xcb_void_cookie_t,
     };
     use crate::protocol::xproto::{ImageOrder, Setup};
     use crate::x11_utils::Serialize;
@@ -297,6 +358,36 @@ mod mock {
     }
 
     // From xcbext.h
+    pub(crate) unsafe fn xcb_register_for_special_xge(
+        _c: *mut xcb_connection_t,
+        _ext: *mut xcb_extension_t,
+        _eid: u32,
+        _stamp: *mut u32,
+    ) -> *mut xcb_special_event_t {
+        unimplemented!();
+    }
+
+    pub(crate) unsafe fn xcb_unregister_for_special_event(
+        _c: *mut xcb_connection_t,
+        _se: *mut xcb_special_event_t,
+    ) {
+        unimplemented!();
+    }
+
+    pub(crate) unsafe fn xcb_wait_for_special_event(
+        _c: *mut xcb_connection_t,
+        _se: *mut xcb_special_event_t,
+    ) -> *mut xcb_generic_event_t {
+        unimplemented!();
+    }
+
+    pub(crate) unsafe fn xcb_poll_for_special_event(
+        _c: *mut xcb_connection_t,
+        _se: *mut xcb_special_event_t,
+    ) -> *mut xcb_generic_event_t {
+        unimplemented!();
+    }
+
     pub(crate) unsafe fn xcb_send_request64(
         _c: *mut xcb_connection_t,
         _flags: c_int,
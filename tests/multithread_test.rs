@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use x11rb::connection::Connection as _;
 use x11rb::protocol::xproto::{
-    ClientMessageData, ClientMessageEvent, ConnectionExt as _, EventMask, CLIENT_MESSAGE_EVENT,
+    Atom, ClientMessageData, ClientMessageEvent, ConnectionExt as _, EventMask, Window,
+    CLIENT_MESSAGE_EVENT,
 };
 
 // Regression test for https://github.com/psychon/x11rb/issues/231
@@ -31,9 +32,9 @@ fn multithread_test() {
             response_type: CLIENT_MESSAGE_EVENT,
             format: 32,
             sequence: 0,
-            window: 0,
+            window: Window::NONE,
             // Just anything, we don't care
-            type_: 1,
+            type_: Atom::from(1),
             data: ClientMessageData::from([0, 0, 0, 0, 0]),
         };
 
@@ -1,6 +1,13 @@
 use x11rb::errors::ParseError;
-use x11rb::protocol::xproto::{Setup, VisualClass};
-use x11rb::x11_utils::TryParse;
+use x11rb::protocol::xproto::{
+    AtomEnum, Char2b, Colormap, CreateWindowAux, Drawable, EventMask, Fontable, GetInputFocusReply,
+    GetPropertyReply, InputFocus, Setup, ValueError, VisualClass, Window, WindowClass,
+};
+use x11rb::protocol::{bigreq, Error, Event, Request};
+use x11rb::x11_utils::{
+    parse_request_header, BigRequests, ByteOrder, ExtInfoProvider, ExtensionInformation, Serialize,
+    TryParse,
+};
 
 fn get_setup_data() -> Vec<u8> {
     let mut s = Vec::new();
@@ -108,7 +115,7 @@ fn parse_setup() -> Result<(), ParseError> {
     assert_eq!(1, setup.roots.len());
     let root = &setup.roots[0];
     assert_eq!(
-        (1, 2, 3, 4),
+        (Window::from(1), Colormap::from(2), 3, 4),
         (
             root.root,
             root.default_colormap,
@@ -133,3 +140,381 @@ fn parse_setup() -> Result<(), ParseError> {
 
     Ok(())
 }
+
+/// An `ExtInfoProvider` that only knows the BIG-REQUESTS extension with major opcode 130.
+struct BigReqOnly;
+
+impl ExtInfoProvider for BigReqOnly {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        let info = ExtensionInformation {
+            major_opcode: 130,
+            first_event: 0,
+            first_error: 0,
+        };
+        if major_opcode == info.major_opcode {
+            Some((bigreq::X11_EXTENSION_NAME, info))
+        } else {
+            None
+        }
+    }
+
+    fn get_from_event_code(&self, _event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+
+    fn get_from_error_code(&self, _error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+}
+
+fn parse_request(data: &[u8]) -> Result<Request, ParseError> {
+    parse_request_with_order(data, ByteOrder::native())
+}
+
+fn parse_request_with_order(data: &[u8], order: ByteOrder) -> Result<Request, ParseError> {
+    let (header, body) = parse_request_header(data, BigRequests::Enabled, order)?;
+    Request::parse(header, body, &mut Vec::new(), &BigReqOnly, order)
+}
+
+#[test]
+fn parse_request_header_lengths() -> Result<(), ParseError> {
+    let data = [1, 2, 3, 0, 4, 5, 6, 7, 8, 9, 10, 11];
+    let (header, remaining) =
+        parse_request_header(&data, BigRequests::NotEnabled, ByteOrder::native())?;
+    assert_eq!((header.major_opcode, header.minor_opcode), (1, 2));
+    assert_eq!(header.remaining_length, 2);
+    assert_eq!(remaining, &data[4..]);
+
+    // With BIG-REQUESTS, a length of zero is followed by the real length
+    let mut data = vec![1, 2, 0, 0];
+    data.extend(&3u32.to_ne_bytes());
+    data.extend(&[0; 4]);
+    let (header, remaining) =
+        parse_request_header(&data, BigRequests::Enabled, ByteOrder::native())?;
+    assert_eq!(header.remaining_length, 1);
+    assert_eq!(remaining, &[0; 4]);
+    assert!(parse_request_header(&data, BigRequests::NotEnabled, ByteOrder::native()).is_err());
+    Ok(())
+}
+
+#[test]
+fn parse_create_window_request() -> Result<(), ParseError> {
+    let mut data = vec![1, 24];
+    data.extend(&10u16.to_ne_bytes()); // length
+    data.extend(&0x1234u32.to_ne_bytes()); // wid
+    data.extend(&0x100u32.to_ne_bytes()); // parent
+    data.extend(&1i16.to_ne_bytes()); // x
+    data.extend(&(-2i16).to_ne_bytes()); // y
+    data.extend(&30u16.to_ne_bytes()); // width
+    data.extend(&40u16.to_ne_bytes()); // height
+    data.extend(&5u16.to_ne_bytes()); // border width
+    data.extend(&1u16.to_ne_bytes()); // class: InputOutput
+    data.extend(&0x21u32.to_ne_bytes()); // visual
+    data.extend(&0x802u32.to_ne_bytes()); // value mask: BackPixel | EventMask
+    data.extend(&0xff00u32.to_ne_bytes()); // background pixel
+    data.extend(&u32::from(EventMask::Exposure).to_ne_bytes()); // event mask
+
+    let request = match parse_request(&data)? {
+        Request::CreateWindow(request) => request,
+        request => panic!("Unexpected request {:?}", request),
+    };
+    assert_eq!(
+        (request.depth, request.wid, request.parent),
+        (24, Window::from(0x1234), Window::from(0x100))
+    );
+    assert_eq!((request.x, request.y), (1, -2));
+    assert_eq!(
+        (request.width, request.height, request.border_width),
+        (30, 40, 5)
+    );
+    assert_eq!(request.class, WindowClass::InputOutput);
+    assert_eq!(request.visual, 0x21);
+    let aux = CreateWindowAux::new()
+        .background_pixel(0xff00)
+        .event_mask(EventMask::Exposure);
+    assert_eq!(request.value_list, aux);
+
+    // A truncated request cannot be parsed
+    assert!(parse_request(&data[..data.len() - 4]).is_err());
+    Ok(())
+}
+
+#[test]
+fn parse_request_in_both_byte_orders() -> Result<(), ParseError> {
+    // GetGeometry with drawable 0x1234_5678
+    let lsb = [14, 0, 2, 0, 0x78, 0x56, 0x34, 0x12];
+    let msb = [14, 0, 0, 2, 0x12, 0x34, 0x56, 0x78];
+    for &(data, order) in &[(lsb, ByteOrder::LSBFirst), (msb, ByteOrder::MSBFirst)] {
+        match parse_request_with_order(&data, order)? {
+            Request::GetGeometry(request) => {
+                assert_eq!(request.drawable, Drawable::from(0x1234_5678))
+            }
+            request => panic!("Unexpected request {:?}", request),
+        }
+    }
+
+    // In the wrong byte order, the length field says that the request is truncated
+    assert!(parse_request_with_order(&msb, ByteOrder::LSBFirst).is_err());
+    Ok(())
+}
+
+#[test]
+fn parse_query_text_extents_request() -> Result<(), ParseError> {
+    // Three characters, so the last two bytes are padding
+    let mut data = vec![48, 1];
+    data.extend(&4u16.to_ne_bytes()); // length
+    data.extend(&0x42u32.to_ne_bytes()); // font
+    data.extend(&[0, b'a', 0, b'b', 0, b'c', 0, 0]);
+
+    let request = match parse_request(&data)? {
+        Request::QueryTextExtents(request) => request,
+        request => panic!("Unexpected request {:?}", request),
+    };
+    assert_eq!(request.font, Fontable::from(0x42));
+    let chars = [b'a', b'b', b'c']
+        .iter()
+        .map(|&byte2| Char2b { byte1: 0, byte2 })
+        .collect::<Vec<_>>();
+    assert_eq!(request.string, chars);
+    Ok(())
+}
+
+#[test]
+fn parse_extension_request() -> Result<(), ParseError> {
+    let data = [130, bigreq::ENABLE_REQUEST, 1, 0];
+    match parse_request(&data)? {
+        Request::BigreqEnable(_) => {}
+        request => panic!("Unexpected request {:?}", request),
+    }
+
+    // Requests of unknown extensions are not parsed
+    let data = [131, 0, 2, 0, 1, 2, 3, 4];
+    match parse_request(&data)? {
+        Request::Unknown(header, body) => {
+            assert_eq!((header.major_opcode, header.minor_opcode), (131, 0));
+            assert_eq!(body, [1, 2, 3, 4]);
+        }
+        request => panic!("Unexpected request {:?}", request),
+    }
+    Ok(())
+}
+
+#[test]
+fn serialize_short_reply() -> Result<(), ParseError> {
+    let reply = GetInputFocusReply {
+        response_type: 1,
+        revert_to: InputFocus::Parent,
+        sequence: 42,
+        length: 0,
+        focus: Window::from(0x1234),
+    };
+    let bytes = reply.serialize();
+    // Replies are at least 32 bytes long
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(GetInputFocusReply::try_parse(&bytes)?.0, reply);
+    Ok(())
+}
+
+#[test]
+fn serialize_reply_in_both_byte_orders() -> Result<(), ParseError> {
+    let reply = GetInputFocusReply {
+        response_type: 1,
+        revert_to: InputFocus::Parent,
+        sequence: 0x0102,
+        length: 0,
+        focus: Window::from(0x0304_0506),
+    };
+    let lsb = reply.serialize_with_order(ByteOrder::LSBFirst);
+    assert_eq!(
+        &lsb[..12],
+        &[1, 2, 0x02, 0x01, 0, 0, 0, 0, 0x06, 0x05, 0x04, 0x03]
+    );
+    let msb = reply.serialize_with_order(ByteOrder::MSBFirst);
+    assert_eq!(
+        &msb[..12],
+        &[1, 2, 0x01, 0x02, 0, 0, 0, 0, 0x03, 0x04, 0x05, 0x06]
+    );
+
+    assert_eq!(
+        GetInputFocusReply::try_parse_with_order(&lsb, ByteOrder::LSBFirst)?.0,
+        reply
+    );
+    assert_eq!(
+        GetInputFocusReply::try_parse_with_order(&msb, ByteOrder::MSBFirst)?.0,
+        reply
+    );
+    Ok(())
+}
+
+#[test]
+fn unknown_packet_sequence_in_both_byte_orders() {
+    // An event and an error that are not known, with sequence number 0x0102 in MSB order
+    let mut event = vec![120, 0, 0x01, 0x02];
+    event.resize(32, 0);
+    let event = Event::Unknown(event);
+    assert_eq!(
+        event.wire_sequence_number_with_order(ByteOrder::MSBFirst),
+        Some(0x0102)
+    );
+    assert_eq!(
+        event.wire_sequence_number_with_order(ByteOrder::LSBFirst),
+        Some(0x0201)
+    );
+
+    let mut error = vec![0, 250, 0x01, 0x02];
+    error.resize(32, 0);
+    let error = Error::Unknown(error);
+    assert_eq!(
+        error.wire_sequence_number_with_order(ByteOrder::MSBFirst),
+        0x0102
+    );
+    assert_eq!(
+        error.wire_sequence_number_with_order(ByteOrder::LSBFirst),
+        0x0201
+    );
+}
+
+#[test]
+fn setup_round_trip_in_both_byte_orders() -> Result<(), ParseError> {
+    let setup = Setup::try_parse(&get_setup_data())?.0;
+    for &order in &[ByteOrder::LSBFirst, ByteOrder::MSBFirst] {
+        let bytes = setup.serialize_with_order(order);
+        assert_eq!(&bytes[2..4], &11u16.serialize_with_order(order)); // major version
+        assert_eq!(Setup::try_parse_with_order(&bytes, order)?.0, setup);
+    }
+    // The image byte order is independent of the byte order of the connection
+    assert_eq!(ByteOrder::from(setup.image_byte_order), ByteOrder::MSBFirst);
+    Ok(())
+}
+
+#[test]
+fn serialize_reply_fixes_length() -> Result<(), ParseError> {
+    let reply = GetPropertyReply {
+        response_type: 1,
+        format: 8,
+        sequence: 7,
+        // Deliberately wrong, serialize() computes the right value
+        length: 100,
+        type_: AtomEnum::STRING.into(),
+        bytes_after: 0,
+        value_len: 5,
+        value: b"Hello".to_vec(),
+    };
+    let bytes = reply.serialize();
+    assert_eq!(bytes.len(), 32 + 8);
+    assert_eq!(bytes[4..8], 2u32.to_ne_bytes());
+
+    let parsed = GetPropertyReply::try_parse(&bytes)?.0;
+    assert_eq!(parsed.length, 2);
+    assert_eq!(parsed.value, reply.value);
+    Ok(())
+}
+
+#[test]
+fn serialize_error() -> Result<(), ParseError> {
+    let error = ValueError {
+        response_type: 0,
+        error_code: 2,
+        sequence: 3,
+        bad_value: 0xdead_beef,
+        minor_opcode: 0,
+        major_opcode: 12,
+    };
+    let bytes = error.serialize();
+    assert_eq!(ValueError::try_parse(&bytes)?.0, error);
+    Ok(())
+}
+
+#[cfg(feature = "present")]
+#[test]
+fn serialize_generic_event() -> Result<(), ParseError> {
+    use x11rb::protocol::present::ConfigureNotifyEvent;
+
+    let event = ConfigureNotifyEvent {
+        response_type: 35,
+        extension: 130,
+        sequence: 1,
+        length: 2,
+        event_type: 0,
+        event: 10.into(),
+        window: Window::from(20),
+        x: 1,
+        y: 2,
+        width: 3,
+        height: 4,
+        off_x: 5,
+        off_y: 6,
+        pixmap_width: 7,
+        pixmap_height: 8,
+        pixmap_flags: 9,
+    };
+    let bytes = event.serialize();
+    assert_eq!(bytes.len(), 40);
+    assert_eq!(ConfigureNotifyEvent::try_parse(&bytes)?.0, event);
+    Ok(())
+}
+
+#[test]
+fn xid_types_convert_and_round_trip() -> Result<(), ParseError> {
+    use x11rb::protocol::xproto::{Atom, Gcontext, Pixmap};
+
+    let window = Window::from(0x1234);
+    assert_eq!(u32::from(Drawable::from(window)), 0x1234);
+    assert_eq!(Drawable::from(Pixmap::from(5)), Drawable::from(5));
+    assert_eq!(Fontable::from(Gcontext::from(7)), Fontable::from(7));
+    assert_eq!(Atom::from(AtomEnum::WM_NAME), Atom::from(39));
+    assert_eq!(Window::default(), Window::NONE);
+
+    let bytes = window.serialize_with_order(ByteOrder::MSBFirst);
+    assert_eq!(bytes, [0, 0, 0x12, 0x34]);
+    assert_eq!(
+        Window::try_parse_with_order(&bytes, ByteOrder::MSBFirst)?.0,
+        window
+    );
+    Ok(())
+}
+
+#[test]
+fn bitmask_set_types() {
+    let mut mask = EventMask::KeyPress | EventMask::Exposure;
+    assert!(mask.contains(EventMask::Exposure));
+    assert!(!mask.contains(EventMask::KeyPress | EventMask::ButtonPress));
+    assert!(mask.intersects(EventMask::KeyPress | EventMask::ButtonPress));
+
+    mask.insert(EventMask::ButtonPress);
+    mask.remove(EventMask::KeyPress);
+    assert_eq!(
+        mask.iter().collect::<Vec<_>>(),
+        [EventMask::ButtonPress, EventMask::Exposure]
+    );
+    assert_eq!(u32::from(mask), (1 << 2) | (1 << 15));
+
+    assert_eq!(format!("{:?}", mask), "EventMask(ButtonPress | Exposure)");
+    assert_eq!(
+        format!("{:?}", EventMask::from(1 << 30 | 1)),
+        "EventMask(KeyPress | 0x40000000)"
+    );
+    assert_eq!(format!("{:?}", EventMask::default()), "EventMask(0x0)");
+}
+
+#[test]
+fn parse_unknown_enum_value() -> Result<(), ParseError> {
+    let mut bytes = GetInputFocusReply {
+        response_type: 1,
+        revert_to: InputFocus::Parent,
+        sequence: 42,
+        length: 0,
+        focus: Window::from(0x1234),
+    }
+    .serialize();
+    // No value of `InputFocus` is 7, but the reply can still be parsed
+    bytes[1] = 7;
+    let reply = GetInputFocusReply::try_parse(&bytes)?.0;
+    assert_eq!(reply.revert_to, InputFocus::from(7));
+    assert_eq!(u8::from(reply.revert_to), 7);
+    assert_eq!(reply.serialize(), bytes);
+
+    assert_eq!(format!("{:?}", InputFocus::Parent), "Parent");
+    assert_eq!(format!("{:?}", reply.revert_to), "InputFocus(7)");
+    Ok(())
+}
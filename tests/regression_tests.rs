@@ -1,176 +1,83 @@
-use std::cell::RefCell;
 use std::convert::TryFrom;
-use std::io::IoSlice;
-use std::ops::Deref;
 
-use x11rb::connection::{
-    compute_length_field, BufWithFds, DiscardMode, ReplyOrError, RequestConnection, RequestKind,
-    SequenceNumber,
-};
-use x11rb::cookie::{Cookie, CookieWithFds, VoidCookie};
 use x11rb::errors::{ConnectionError, ParseError, ReplyError};
 use x11rb::protocol::xproto::{
-    ClientMessageData, ConnectionExt, KeymapNotifyEvent, Segment, SetupAuthenticate,
+    ClientMessageData, ConnectionExt, ImageOrder, KeymapNotifyEvent, Segment, Setup,
+    SetupAuthenticate,
 };
-use x11rb::utils::RawFdContainer;
-use x11rb::x11_utils::{ExtensionInformation, Serialize, TryParse};
-
-#[derive(Debug)]
-struct SavedRequest {
-    has_reply: bool,
-    data: Vec<u8>,
-}
-
-impl SavedRequest {
-    fn new(has_reply: bool, data: &[IoSlice]) -> SavedRequest {
-        let data = data
-            .iter()
-            .flat_map(|slice| slice.deref())
-            .copied()
-            .collect::<Vec<_>>();
-        SavedRequest { has_reply, data }
+use x11rb::rust_connection::replay::{Direction, Recording, ReplayConnection, Replayer};
+use x11rb::rust_connection::RustConnection;
+use x11rb::x11_utils::{Serialize, TryParse};
+
+fn setup() -> Setup {
+    Setup {
+        status: 1,
+        protocol_major_version: 11,
+        protocol_minor_version: 0,
+        length: 0,
+        release_number: 0,
+        resource_id_base: 0,
+        resource_id_mask: 0xff,
+        motion_buffer_size: 0,
+        maximum_request_length: 0,
+        image_byte_order: ImageOrder::LSBFirst,
+        bitmap_format_bit_order: ImageOrder::LSBFirst,
+        bitmap_format_scanline_unit: 0,
+        bitmap_format_scanline_pad: 0,
+        min_keycode: 0,
+        max_keycode: 0,
+        vendor: vec![],
+        pixmap_formats: vec![],
+        roots: vec![],
     }
 }
 
-#[derive(Debug, Default)]
-struct FakeConnection(RefCell<Vec<SavedRequest>>);
-
-impl FakeConnection {
-    fn check_requests(&self, expected: &[(bool, Vec<u8>)]) {
-        let vec = self.0.borrow();
-        for (expected, actual) in expected.iter().zip(vec.iter()) {
-            assert_eq!(expected.0, actual.has_reply);
-            assert_eq!(actual.data, expected.1);
-        }
-        assert_eq!(expected.len(), vec.len());
-    }
-
-    fn internal_send_request(
-        &self,
-        bufs: &[IoSlice],
-        fds: Vec<RawFdContainer>,
-    ) -> Result<SequenceNumber, ConnectionError> {
-        assert_eq!(fds.len(), 0);
-
-        let mut storage = Default::default();
-        let bufs = compute_length_field(self, bufs, &mut storage)?;
-
-        self.0.borrow_mut().push(SavedRequest::new(false, bufs));
-        Ok(0)
-    }
+/// Create a connection that replays the given recording.
+fn replay(recording: Recording) -> (Replayer, ReplayConnection) {
+    let replayer = Replayer::new(recording);
+    let conn = RustConnection::for_connected_stream(replayer.reader(), replayer.writer(), setup())
+        .unwrap();
+    (replayer, conn)
 }
 
-impl RequestConnection for FakeConnection {
-    type Buf = Vec<u8>;
-
-    fn send_request_with_reply<R>(
-        &self,
-        bufs: &[IoSlice],
-        fds: Vec<RawFdContainer>,
-    ) -> Result<Cookie<Self, R>, ConnectionError>
-    where
-        R: for<'a> TryFrom<&'a [u8], Error = ParseError>,
-    {
-        Ok(Cookie::new(self, self.internal_send_request(bufs, fds)?))
-    }
-
-    fn send_request_with_reply_with_fds<R>(
-        &self,
-        _bufs: &[IoSlice],
-        _fds: Vec<RawFdContainer>,
-    ) -> Result<CookieWithFds<Self, R>, ConnectionError>
-    where
-        R: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error = ParseError>,
-    {
-        unimplemented!()
-    }
-
-    fn send_request_without_reply(
-        &self,
-        bufs: &[IoSlice],
-        fds: Vec<RawFdContainer>,
-    ) -> Result<VoidCookie<Self>, ConnectionError> {
-        Ok(VoidCookie::new(
-            self,
-            self.internal_send_request(bufs, fds)?,
-        ))
-    }
-
-    fn discard_reply(&self, _sequence: SequenceNumber, _kind: RequestKind, _mode: DiscardMode) {
-        // Just ignore this
-    }
-
-    fn prefetch_extension_information(
-        &self,
-        _extension_name: &'static str,
-    ) -> Result<(), ConnectionError> {
-        unimplemented!();
-    }
-
-    fn extension_information(
-        &self,
-        _extension_name: &'static str,
-    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
-        unimplemented!()
-    }
-
-    fn wait_for_reply_or_raw_error(
-        &self,
-        _sequence: SequenceNumber,
-    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
-        unimplemented!()
-    }
-
-    fn wait_for_reply(
-        &self,
-        _sequence: SequenceNumber,
-    ) -> Result<Option<Vec<u8>>, ConnectionError> {
-        unimplemented!()
-    }
-
-    fn wait_for_reply_with_fds_raw(
-        &self,
-        _sequence: SequenceNumber,
-    ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
-        unimplemented!()
-    }
-
-    fn check_for_raw_error(
-        &self,
-        _sequence: SequenceNumber,
-    ) -> Result<Option<Vec<u8>>, ConnectionError> {
-        unimplemented!()
-    }
-
-    fn maximum_request_bytes(&self) -> usize {
-        // Must be at least 4 * 2^16 so that we can test BIG-REQUESTS
-        2usize.pow(19)
-    }
-
-    fn prefetch_maximum_request_bytes(&self) {
-        unimplemented!()
-    }
-
-    fn parse_error<E>(&self, _error: E) -> Result<x11rb::protocol::Error<E>, ParseError>
-    where
-        E: std::fmt::Debug + AsRef<[u8]>,
-    {
-        unimplemented!()
-    }
-
-    fn parse_event<E>(&self, _event: E) -> Result<x11rb::protocol::Event<E>, ParseError>
-    where
-        E: std::fmt::Debug + AsRef<[u8]>,
-    {
-        unimplemented!()
-    }
+/// Create a recording in which the client enables BIG-REQUESTS.
+///
+/// The server allows requests with up to 2^19 bytes.
+fn big_requests_recording() -> Recording {
+    let mut recording = Recording::new();
+
+    // QueryExtension("BIG-REQUESTS")
+    let mut request = vec![98, 0];
+    request.extend(&5u16.to_ne_bytes());
+    request.extend(&12u16.to_ne_bytes());
+    request.extend(&[0, 0]);
+    request.extend(b"BIG-REQUESTS");
+    recording.push(Direction::Sent, &request, 0);
+    let mut reply = vec![1, 0];
+    reply.extend(&1u16.to_ne_bytes());
+    reply.extend(&0u32.to_ne_bytes());
+    reply.extend(&[1, 133, 0, 0]); // present, major opcode, first event, first error
+    reply.resize(32, 0);
+    recording.push(Direction::Received, &reply, 0);
+
+    // BigReqEnable
+    let mut request = vec![133, 0];
+    request.extend(&1u16.to_ne_bytes());
+    recording.push(Direction::Sent, &request, 0);
+    let mut reply = vec![1, 0];
+    reply.extend(&2u16.to_ne_bytes());
+    reply.extend(&0u32.to_ne_bytes());
+    reply.extend(&(1u32 << 17).to_ne_bytes()); // maximum request length in units of four bytes
+    reply.resize(32, 0);
+    recording.push(Direction::Received, &reply, 0);
+
+    recording
 }
 
 #[test]
 fn test_poly_segment() -> Result<(), ReplyError<Vec<u8>>> {
-    let conn = FakeConnection::default();
-    let drawable = 42;
-    let gc = 0x1337;
+    let drawable: u32 = 42;
+    let gc: u32 = 0x1337;
     let segments = [
         Segment {
             x1: 1,
@@ -186,7 +93,6 @@ fn test_poly_segment() -> Result<(), ReplyError<Vec<u8>>> {
         },
     ];
     let length: u16 = (12 + segments.len() * 8) as u16 / 4;
-    conn.poly_segment(drawable, gc, &segments)?;
 
     let mut expected = Vec::new();
     expected.push(x11rb::protocol::xproto::POLY_SEGMENT_REQUEST);
@@ -198,13 +104,17 @@ fn test_poly_segment() -> Result<(), ReplyError<Vec<u8>>> {
     for x in 1u16..9u16 {
         expected.extend(&x.to_ne_bytes());
     }
-    conn.check_requests(&[(false, expected)]);
+    let mut recording = Recording::new();
+    recording.push(Direction::Sent, &expected, 0);
+
+    let (replayer, conn) = replay(recording);
+    conn.poly_segment(drawable, gc.into(), &segments)?;
+    assert!(replayer.is_finished());
     Ok(())
 }
 
 #[test]
 fn test_big_requests() -> Result<(), ConnectionError> {
-    let conn = FakeConnection::default();
     let big_buffer = [0; (1 << 18) + 1];
     let drawable: u32 = 42;
     let gc: u32 = 0x1337;
@@ -212,7 +122,6 @@ fn test_big_requests() -> Result<(), ConnectionError> {
     let y: i16 = 7;
     let padding = 3; // big_buffer's size rounded up to a multiple of 4
     let length: u32 = (16 + big_buffer.len() as u32 + padding) / 4;
-    conn.poly_text16(drawable, gc, x, y, &big_buffer)?;
 
     let mut expected = Vec::new();
     expected.push(x11rb::protocol::xproto::POLY_TEXT16_REQUEST);
@@ -230,24 +139,29 @@ fn test_big_requests() -> Result<(), ConnectionError> {
     expected.extend(&y.to_ne_bytes());
     expected.extend(big_buffer.iter());
     expected.extend((0..padding).map(|_| 0));
+    let mut recording = big_requests_recording();
+    recording.push(Direction::Sent, &expected, 0);
 
-    conn.check_requests(&[(false, expected)]);
+    let (replayer, conn) = replay(recording);
+    conn.poly_text16(drawable, gc.into(), x, y, &big_buffer)?;
+    assert!(replayer.is_finished());
     Ok(())
 }
 
 #[test]
 fn test_too_large_request() -> Result<(), ConnectionError> {
-    let conn = FakeConnection::default();
+    let (replayer, conn) = replay(big_requests_recording());
     let big_buffer = [0; (1 << 19) + 1];
     let drawable: u32 = 42;
     let gc: u32 = 0x1337;
     let x: i16 = 21;
     let y: i16 = 7;
-    let res = conn.poly_text16(drawable, gc, x, y, &big_buffer);
+    let res = conn.poly_text16(drawable, gc.into(), x, y, &big_buffer);
     match res.unwrap_err() {
         ConnectionError::MaximumRequestLengthExceeded => {}
         err => panic!("Wrong error: {:?}", err),
     };
+    assert!(replayer.is_finished());
     Ok(())
 }
 
@@ -259,13 +173,9 @@ fn test_send_event() -> Result<(), ConnectionError> {
         24, 25, 26, 27, 28, 29, 30,
     ];
     let event = KeymapNotifyEvent::try_from(&buffer[..])?;
-
-    // "Send" it
-    let conn = FakeConnection::default();
     let propagate = true;
     let destination: u32 = 0x1337;
     let event_mask: u32 = 7;
-    conn.send_event(propagate, destination, event_mask, event)?;
 
     let mut expected = Vec::new();
     expected.push(x11rb::protocol::xproto::SEND_EVENT_REQUEST);
@@ -274,18 +184,18 @@ fn test_send_event() -> Result<(), ConnectionError> {
     expected.extend(&destination.to_ne_bytes());
     expected.extend(&event_mask.to_ne_bytes());
     expected.extend(buffer.iter());
-    conn.check_requests(&[(false, expected)]);
+    let mut recording = Recording::new();
+    recording.push(Direction::Sent, &expected, 0);
+
+    // "Send" it
+    let (replayer, conn) = replay(recording);
+    conn.send_event(propagate, destination, event_mask, event)?;
+    assert!(replayer.is_finished());
     Ok(())
 }
 
 #[test]
 fn test_get_keyboard_mapping() -> Result<(), ConnectionError> {
-    let conn = FakeConnection::default();
-    let cookie = conn.get_keyboard_mapping(1, 2)?;
-
-    // Prevent call to discard_reply(), we only check request sending
-    std::mem::forget(cookie);
-
     let mut expected = Vec::new();
     let length: u16 = 2;
     expected.push(101); // request major code
@@ -294,8 +204,12 @@ fn test_get_keyboard_mapping() -> Result<(), ConnectionError> {
     expected.push(1); // first keycode
     expected.push(2); // length
     expected.extend(&[0, 0]); // padding
+    let mut recording = Recording::new();
+    recording.push(Direction::Sent, &expected, 0);
 
-    conn.check_requests(&[(false, expected)]);
+    let (replayer, conn) = replay(recording);
+    let _ = conn.get_keyboard_mapping(1, 2)?;
+    assert!(replayer.is_finished());
     Ok(())
 }
 
@@ -309,20 +223,18 @@ fn test_client_message_data_parse() {
 
 #[test]
 fn test_set_modifier_mapping() -> Result<(), ConnectionError> {
-    let conn = FakeConnection::default();
-    let cookie = conn.set_modifier_mapping(&(1..17).collect::<Vec<_>>())?;
-
-    // Prevent call to discard_reply(), we only check request sending
-    std::mem::forget(cookie);
-
     let mut expected = Vec::new();
     let length: u16 = 5;
     expected.push(118); // request major code
     expected.push(2); // keycodes per modifier
     expected.extend(&length.to_ne_bytes()); // length, not in the xml
     expected.extend(1u8..17u8);
+    let mut recording = Recording::new();
+    recording.push(Direction::Sent, &expected, 0);
 
-    conn.check_requests(&[(false, expected)]);
+    let (replayer, conn) = replay(recording);
+    let _ = conn.set_modifier_mapping(&(1..17).collect::<Vec<_>>())?;
+    assert!(replayer.is_finished());
     Ok(())
 }
 
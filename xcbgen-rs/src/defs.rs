@@ -847,6 +847,9 @@ pub struct ExternalParam {
 
     /// The type of the parameter that is referenced.
     pub type_: TypeRef,
+
+    /// The `enum` or `mask` of the field that is referenced, if any.
+    pub value_set: FieldValueSet,
 }
 
 /// A reference to an event by name.
@@ -991,7 +994,7 @@ impl ErrorRef {
 }
 
 /// A reference to a type by name.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct NamedTypeRef {
     /// The name of the type that is referenced
     pub name: String,
@@ -1700,7 +1703,7 @@ impl FieldValueType {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum FieldValueSet {
     None,
     Enum(NamedTypeRef),
@@ -1879,6 +1882,7 @@ pub struct FieldRefExpr {
 pub struct ResolvedFieldRef {
     pub ref_kind: FieldRefKind,
     pub field_type: TypeRef,
+    pub value_set: FieldValueSet,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
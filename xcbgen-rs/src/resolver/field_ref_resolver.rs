@@ -70,6 +70,7 @@ impl FieldRefResolveScope<'_, '_, '_> {
                         return Ok(defs::ResolvedFieldRef {
                             ref_kind: defs::FieldRefKind::LocalField,
                             field_type: field_type.type_.def.get().unwrap().clone(),
+                            value_set: field_type.value_set.clone(),
                         });
                     }
                 }
@@ -84,6 +85,7 @@ impl FieldRefResolveScope<'_, '_, '_> {
                         return Ok(defs::ResolvedFieldRef {
                             ref_kind: defs::FieldRefKind::SumOfRef,
                             field_type: field_type.type_.def.get().unwrap().clone(),
+                            value_set: field_type.value_set.clone(),
                         });
                     }
                 }
@@ -120,11 +122,13 @@ impl NormalFieldRefResolveScope<'_, '_, '_> {
                         break 'outer Some(defs::ResolvedFieldRef {
                             ref_kind: defs::FieldRefKind::LocalField,
                             field_type: field_type.type_.def.get().unwrap().clone(),
+                            value_set: field_type.value_set.clone(),
                         });
                     } else {
                         break 'outer Some(defs::ResolvedFieldRef {
                             ref_kind: defs::FieldRefKind::ExtParam,
                             field_type: field_type.type_.def.get().unwrap().clone(),
+                            value_set: field_type.value_set.clone(),
                         });
                     }
                 }
@@ -141,10 +145,11 @@ impl NormalFieldRefResolveScope<'_, '_, '_> {
 
         let mut current_scope = self;
         for _ in 0..depth {
-            current_scope
-                .ext_param_gatherer
-                .borrow_mut()
-                .add(name, &resolved.field_type)?;
+            current_scope.ext_param_gatherer.borrow_mut().add(
+                name,
+                &resolved.field_type,
+                &resolved.value_set,
+            )?;
             current_scope = current_scope.parent.unwrap();
         }
 
@@ -154,10 +159,11 @@ impl NormalFieldRefResolveScope<'_, '_, '_> {
     fn add_param_ref(&self, name: &str, type_: &defs::TypeRef) -> Result<(), ResolveError> {
         let mut current_scope = self;
         loop {
-            current_scope
-                .ext_param_gatherer
-                .borrow_mut()
-                .add(name, type_)?;
+            current_scope.ext_param_gatherer.borrow_mut().add(
+                name,
+                type_,
+                &defs::FieldValueSet::None,
+            )?;
             if let Some(parent_scope) = current_scope.parent {
                 current_scope = parent_scope;
             } else {
@@ -177,7 +183,12 @@ impl ExternalParamGatherer {
         Self { vec: Vec::new() }
     }
 
-    fn add(&mut self, name: &str, type_: &defs::TypeRef) -> Result<(), ResolveError> {
+    fn add(
+        &mut self,
+        name: &str,
+        type_: &defs::TypeRef,
+        value_set: &defs::FieldValueSet,
+    ) -> Result<(), ResolveError> {
         for ext_param in self.vec.iter() {
             if ext_param.name == *name {
                 if !ext_param.type_.same_as(type_) {
@@ -190,6 +201,7 @@ impl ExternalParamGatherer {
         self.vec.push(defs::ExternalParam {
             name: name.into(),
             type_: type_.clone(),
+            value_set: value_set.clone(),
         });
         Ok(())
     }
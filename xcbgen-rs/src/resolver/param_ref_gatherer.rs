@@ -30,6 +30,7 @@ impl StructParamRefGatherer {
         self.external_params.push(defs::ExternalParam {
             name: name.into(),
             type_,
+            value_set: defs::FieldValueSet::None,
         });
         Ok(())
     }